@@ -13,7 +13,9 @@ use iggy::client::{StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use integration::test_server::{login_root, ClientFactory};
 use std::{pin::Pin, sync::Arc};
@@ -88,6 +90,8 @@ pub trait Benchmarkable {
                         None,
                         IggyExpiry::NeverExpire,
                         MaxTopicSize::Unlimited,
+                        CleanupPolicy::Delete,
+                        TopicOverrides::default(),
                     )
                     .await?;
             }
@@ -5,8 +5,10 @@ use crate::{
 };
 use async_trait::async_trait;
 use iggy::{
-    client::ConsumerGroupClient, clients::client::IggyClient, error::IggyError,
-    utils::byte_size::IggyByteSize,
+    client::ConsumerGroupClient,
+    clients::client::IggyClient,
+    error::IggyError,
+    utils::{byte_size::IggyByteSize, rebalance_strategy::RebalanceStrategy},
 };
 use integration::test_server::{login_root, ClientFactory};
 use std::sync::Arc;
@@ -49,6 +51,7 @@ impl ConsumerGroupBenchmark {
                     &topic_id.try_into().unwrap(),
                     &consumer_group_name,
                     Some(consumer_group_id),
+                    RebalanceStrategy::default(),
                 )
                 .await;
             if cg.is_err() {
@@ -74,6 +74,7 @@ impl Consumer {
                         &stream_id,
                         &topic_id,
                         &consumer_group_id.try_into().unwrap(),
+                        None,
                     )
                     .await
                     .expect("Failed to join consumer group");
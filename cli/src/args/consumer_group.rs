@@ -1,6 +1,7 @@
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ConsumerGroupAction {
@@ -15,6 +16,7 @@ pub(crate) enum ConsumerGroupAction {
     ///  iggy consumer-group create stream 2 test
     ///  iggy consumer-group create 2 topic receiver
     ///  iggy consumer-group create -g 4 stream topic group
+    ///  iggy consumer-group create -r sticky stream topic group
     #[clap(verbatim_doc_comment, visible_alias = "c")]
     Create(ConsumerGroupCreateArgs),
     /// Delete consumer group with given ID for given stream ID and topic ID
@@ -80,6 +82,11 @@ pub(crate) struct ConsumerGroupCreateArgs {
     /// Consumer group ID to create
     #[clap(short, long)]
     pub(crate) group_id: Option<u32>,
+    /// Rebalance strategy of the consumer group
+    ///
+    /// ("eager" reassigns all the partitions, "sticky" moves only the ones needed to balance the members)
+    #[arg(short, long, default_value = "eager", verbatim_doc_comment)]
+    pub(crate) rebalance_strategy: RebalanceStrategy,
     /// Consumer group name to create
     pub(crate) name: String,
 }
@@ -1,4 +1,4 @@
-use clap::{Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand};
 use iggy::identifier::Identifier;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -36,6 +36,34 @@ pub(crate) enum ConsumerOffsetAction {
     ///  iggy consumer-offset set consumer stream topic 1 100
     #[clap(verbatim_doc_comment, visible_alias = "s")]
     Set(ConsumerOffsetSetArgs),
+    /// Reset the offsets of a consumer or consumer group for the selected partitions on the server
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// If no partition IDs are specified, the offsets are reset for all the partitions
+    ///
+    /// Examples:
+    ///  iggy consumer-offset reset 1 3 5 --earliest
+    ///  iggy consumer-offset reset consumer stream topic --latest -p 1,2
+    ///  iggy consumer-offset reset 1 3 5 --offset 100 -p 1
+    ///  iggy consumer-offset reset group stream topic --timestamp 1700000000000000 -g
+    ///  iggy consumer-offset reset consumer stream topic --earliest --dry-run
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    Reset(ConsumerOffsetResetArgs),
+    /// Delete the stored offsets of a consumer or consumer group for a given partition or all the partitions on the server
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// If no partition ID is specified, the offsets are deleted for all the partitions
+    ///
+    /// Examples:
+    ///  iggy consumer-offset delete 1 3 5
+    ///  iggy consumer-offset delete consumer stream topic -p 1
+    ///  iggy consumer-offset delete group stream topic -g
+    #[clap(verbatim_doc_comment, visible_alias = "d")]
+    Delete(ConsumerOffsetDeleteArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -85,3 +113,79 @@ pub(crate) struct ConsumerOffsetSetArgs {
     /// Offset to set
     pub(crate) offset: u64,
 }
+
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("reset_strategy").required(true))]
+pub(crate) struct ConsumerOffsetResetArgs {
+    /// Consumer or consumer group for which the offsets are reset
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer_id: Identifier,
+    /// Stream ID for which consumer offsets are reset
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID for which consumer offsets are reset
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition IDs for which consumer offsets are reset
+    ///
+    /// If not specified, the offsets are reset for all the partitions
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_delimiter = ',', value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_ids: Vec<u32>,
+    /// Reset strategy - continue from the first message still available in the partition
+    #[clap(short, long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) earliest: bool,
+    /// Reset strategy - continue from the next message appended to the partition
+    #[clap(short, long, default_value_t = false, group = "reset_strategy")]
+    pub(crate) latest: bool,
+    /// Reset strategy - continue from the specified offset
+    #[clap(short, long, group = "reset_strategy")]
+    pub(crate) offset: Option<u64>,
+    /// Reset strategy - continue from the first message with the timestamp
+    /// greater than or equal to the specified one (in microseconds)
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, group = "reset_strategy")]
+    pub(crate) timestamp: Option<u64>,
+    /// Reset the offsets of the consumer group instead of the regular consumer
+    #[clap(short = 'g', long, default_value_t = false)]
+    pub(crate) consumer_group: bool,
+    /// Only report the resulting offsets without storing them
+    #[clap(short, long, default_value_t = false)]
+    pub(crate) dry_run: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ConsumerOffsetDeleteArgs {
+    /// Consumer or consumer group for which the offsets are deleted
+    ///
+    /// Consumer ID can be specified as a consumer name or ID
+    #[clap(verbatim_doc_comment)]
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) consumer_id: Identifier,
+    /// Stream ID for which consumer offsets are deleted
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID for which consumer offsets are deleted
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID for which consumer offset is deleted
+    ///
+    /// If not specified, the offsets are deleted for all the partitions
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Option<u32>,
+    /// Delete the offsets of the consumer group instead of the regular consumer
+    #[clap(short = 'g', long, default_value_t = false)]
+    pub(crate) consumer_group: bool,
+}
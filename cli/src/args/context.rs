@@ -1,5 +1,7 @@
 use crate::args::common::ListMode;
 use clap::{Args, Subcommand};
+use iggy::args::ArgsOptional;
+use iggy::cli::context::common::ContextConfig;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum ContextAction {
@@ -17,6 +19,22 @@ pub(crate) enum ContextAction {
     ///  iggy context use default
     #[clap(verbatim_doc_comment, visible_alias = "u")]
     Use(ContextUseArgs),
+
+    /// Add a new context
+    ///
+    /// Context stores the server address, transport and the username or
+    /// the personal access token name which are used by all the commands
+    /// once the context is active. Passwords and tokens are never stored,
+    /// the password is prompted for when needed and the token is read from
+    /// the system keyring under the given name. Contexts are saved in the
+    /// contexts.toml file in the IGGY_HOME directory (~/.iggy by default),
+    /// readable only by its owner.
+    ///
+    /// Examples
+    ///  iggy context add dev --address 127.0.0.1:8090 -u iggy
+    ///  iggy context add prod --transport http --address https://iggy.example.com:3000 -n prod-token
+    #[clap(verbatim_doc_comment, visible_alias = "a")]
+    Add(ContextAddArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -32,3 +50,49 @@ pub(crate) struct ContextUseArgs {
     #[arg(value_parser = clap::value_parser!(String))]
     pub(crate) context_name: String,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ContextAddArgs {
+    /// Name of the context to add
+    #[arg(value_parser = clap::value_parser!(String))]
+    pub(crate) context_name: String,
+
+    /// Transport used to connect to the server
+    #[clap(long, value_parser = ["tcp", "quic", "http"])]
+    pub(crate) transport: Option<String>,
+
+    /// Server address for the transport (TCP or QUIC server address, HTTP API URL)
+    #[clap(long)]
+    pub(crate) address: Option<String>,
+
+    /// Iggy server username (password is prompted for when needed)
+    #[clap(short, long, conflicts_with = "token_name")]
+    pub(crate) username: Option<String>,
+
+    /// Iggy server personal access token name (token is read from the system keyring)
+    #[clap(short = 'n', long)]
+    pub(crate) token_name: Option<String>,
+}
+
+impl From<ContextAddArgs> for ContextConfig {
+    fn from(args: ContextAddArgs) -> Self {
+        let mut iggy = ArgsOptional {
+            transport: args.transport,
+            ..Default::default()
+        };
+        if let Some(address) = args.address {
+            match iggy.transport.as_deref() {
+                Some("quic") => iggy.quic_server_address = Some(address),
+                Some("http") => iggy.http_api_url = Some(address),
+                _ => iggy.tcp_server_address = Some(address),
+            }
+        }
+
+        ContextConfig {
+            username: args.username,
+            token_name: args.token_name,
+            iggy,
+            ..Default::default()
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use clap::builder::NonEmptyStringValueParser;
-use clap::{ArgGroup, Args, Subcommand};
+use clap::{ArgGroup, Args, Subcommand, ValueEnum};
+use iggy::cli::message::archive::ArchiveFormat;
 use iggy::error::IggyError;
 use iggy::error::IggyError::InvalidFormat;
 use iggy::identifier::Identifier;
@@ -30,6 +31,7 @@ pub(crate) enum MessageAction {
     ///  iggy message poll --offset 0 stream 2 1
     ///  iggy message poll --offset 0 1 topic 1
     ///  iggy message poll --offset 0 stream topic 1
+    ///  iggy message poll --last --backward -m 10 stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Poll(PollMessagesArgs),
     /// Flush messages from given topic ID and given stream ID
@@ -49,6 +51,54 @@ pub(crate) enum MessageAction {
     ///  iggy message flush stream topic 1
     #[clap(verbatim_doc_comment, visible_alias = "f")]
     Flush(FlushMessagesArgs),
+    /// Peek messages from given topic ID and given stream ID for debugging
+    ///
+    /// Command returns the first, the last or the random messages
+    /// of the partition with the decoded headers, sizes and timestamps,
+    /// without storing or using any consumer offsets.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message peek 1 2 1
+    ///  iggy message peek --last -m 5 stream 2 1
+    ///  iggy message peek --random -m 10 1 topic 1
+    ///  iggy message peek stream topic 1
+    #[clap(verbatim_doc_comment, visible_alias = "pk")]
+    Peek(PeekMessagesArgs),
+    /// Copy range of messages from given topic ID and given stream ID to another topic
+    ///
+    /// Command copies the messages with the offsets (or timestamps) within
+    /// the range from (inclusive) to (exclusive) of the source partition
+    /// to the target partition, e.g. to replay a subset of the traffic
+    /// into a staging topic. The message IDs and headers are preserved,
+    /// and the origin of each message is recorded in the copied-from header.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message copy --from 0 --to 100 1 2 1 1 3 1
+    ///  iggy message copy --from 10 --to 20 stream topic 1 stream staging 1
+    ///  iggy message copy --timestamps --from 1700000000000000 --to 1700000060000000 stream topic 1 staging topic 1
+    #[clap(verbatim_doc_comment, visible_alias = "cp")]
+    Copy(CopyMessagesArgs),
+    /// Get the latest message for given key from given topic ID and given stream ID
+    ///
+    /// Command returns the latest message with the given key (message ID)
+    /// in the partition of the compacted topic, without storing or using
+    /// any consumer offsets. Nothing is returned if the latest message
+    /// for the key is a tombstone.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples:
+    ///  iggy message key 1 2 1 42
+    ///  iggy message key stream topic 1 42
+    #[clap(verbatim_doc_comment, visible_alias = "k")]
+    Key(GetMessageByKeyArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -137,6 +187,14 @@ pub(crate) struct PollMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
     pub(crate) message_count: u32,
+    /// Maximum size of polled messages in bytes
+    ///
+    /// Polling stops when either the message count or the maximum
+    /// size is reached, 0 means no size limit. At least one message
+    /// is returned even if it is bigger than the maximum size.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short = 'b', long, default_value_t = 0)]
+    pub(crate) max_bytes: u32,
     /// Auto commit offset
     ///
     /// Flag indicates whether to commit offset on the server automatically
@@ -165,6 +223,15 @@ pub(crate) struct PollMessagesArgs {
     #[clap(verbatim_doc_comment)]
     #[clap(short, long, default_value_t = false, group = "polling_strategy")]
     pub(crate) next: bool,
+    /// Poll messages backward from the starting point
+    ///
+    /// Messages with the offsets lower than or equal to the starting one
+    /// are returned from the newest to the oldest, e.g. to page back
+    /// from the last message. Cannot be used with the next messages
+    /// or the auto commit.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long, default_value_t = false, conflicts_with_all = ["next", "auto_commit"])]
+    pub(crate) backward: bool,
     /// Regular consumer which will poll messages
     ///
     /// Consumer ID can be specified as a consumer name or ID
@@ -215,6 +282,209 @@ pub(crate) struct FlushMessagesArgs {
     pub(crate) fsync: bool,
 }
 
+#[derive(Debug, Clone, Args)]
+#[command(group = ArgGroup::new("peek_kind"))]
+pub(crate) struct PeekMessagesArgs {
+    /// ID of the stream from which messages will be peeked
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which messages will be peeked
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which messages will be peeked
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Number of messages to peek, max 1000
+    #[clap(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000))]
+    pub(crate) message_count: u32,
+    /// Peek the first messages still available in the partition (default)
+    #[clap(short, long, default_value_t = false, group = "peek_kind")]
+    pub(crate) first: bool,
+    /// Peek the last messages in the partition
+    #[clap(short, long, default_value_t = false, group = "peek_kind")]
+    pub(crate) last: bool,
+    /// Peek the messages at random offsets of the partition
+    #[clap(short, long, default_value_t = false, group = "peek_kind")]
+    pub(crate) random: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct GetMessageByKeyArgs {
+    /// ID of the stream from which message will be read
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which message will be read
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID from which message will be read
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Key (message ID) of the message
+    pub(crate) key: u128,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct CopyMessagesArgs {
+    /// ID of the stream from which messages will be copied
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) source_stream_id: Identifier,
+    /// ID of the topic from which messages will be copied
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) source_topic_id: Identifier,
+    /// Partition ID from which messages will be copied
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) source_partition_id: u32,
+    /// ID of the stream to which messages will be copied
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) target_stream_id: Identifier,
+    /// ID of the topic to which messages will be copied
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) target_topic_id: Identifier,
+    /// Partition ID to which messages will be copied
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) target_partition_id: u32,
+    /// Start of the range of offsets (or timestamps), inclusive
+    #[clap(short, long)]
+    pub(crate) from: u64,
+    /// End of the range of offsets (or timestamps), exclusive
+    #[clap(short, long)]
+    pub(crate) to: u64,
+    /// Treat the range as message timestamps (in microseconds) instead of offsets
+    #[clap(long, default_value_t = false)]
+    pub(crate) timestamps: bool,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub(crate) enum MessagesFileFormat {
+    Json,
+    Binary,
+}
+
+impl From<MessagesFileFormat> for ArchiveFormat {
+    fn from(format: MessagesFileFormat) -> Self {
+        match format {
+            MessagesFileFormat::Json => ArchiveFormat::Json,
+            MessagesFileFormat::Binary => ArchiveFormat::Binary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ExportMessagesArgs {
+    /// ID of the stream from which messages will be exported
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic from which messages will be exported
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Output file to which messages will be exported
+    ///
+    /// If the file already exists, it will be overwritten.
+    #[clap(verbatim_doc_comment)]
+    #[arg(value_parser = NonEmptyStringValueParser::new())]
+    pub(crate) output_file: String,
+    /// Comma separated list of partition IDs from which messages will be exported
+    ///
+    /// If not specified, messages from all partitions of the topic are exported.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_delimiter = ',', value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_ids: Vec<u32>,
+    /// Format of the output file
+    ///
+    /// json - newline-delimited JSON, one message per line with base64 encoded payload
+    /// binary - binary archive with messages stored in binary format
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_enum, default_value_t = MessagesFileFormat::Json)]
+    pub(crate) format: MessagesFileFormat,
+    /// Offset of the first message to export from each partition
+    #[clap(long, default_value_t = 0)]
+    pub(crate) from_offset: u64,
+    /// Offset of the last message to export from each partition (inclusive)
+    ///
+    /// If not specified, messages up to the current offset of each partition are exported.
+    #[clap(verbatim_doc_comment)]
+    #[clap(long)]
+    pub(crate) to_offset: Option<u64>,
+    /// Number of messages polled from the server in a single batch
+    #[clap(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Maximum number of messages exported per second
+    ///
+    /// If not specified, messages are exported as fast as possible.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) rate_limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct ImportMessagesArgs {
+    /// ID of the stream to which messages will be imported
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// ID of the topic to which messages will be imported
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Input file from which messages will be imported
+    ///
+    /// File must be created by the export command using the same format.
+    #[clap(verbatim_doc_comment)]
+    #[arg(value_parser = NonEmptyStringValueParser::new())]
+    pub(crate) input_file: String,
+    /// ID of the partition to which all messages will be imported
+    ///
+    /// If not specified, each message is imported to the partition
+    /// with the same ID as the one it was exported from.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: Option<u32>,
+    /// Format of the input file
+    ///
+    /// json - newline-delimited JSON, one message per line with base64 encoded payload
+    /// binary - binary archive with messages stored in binary format
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_enum, default_value_t = MessagesFileFormat::Json)]
+    pub(crate) format: MessagesFileFormat,
+    /// Offset (as exported) of the first message to import
+    #[clap(long, default_value_t = 0)]
+    pub(crate) from_offset: u64,
+    /// Offset (as exported) of the last message to import (inclusive)
+    #[clap(long)]
+    pub(crate) to_offset: Option<u64>,
+    /// Number of messages sent to the server in a single batch
+    #[clap(short, long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) batch_size: u32,
+    /// Maximum number of messages imported per second
+    ///
+    /// If not specified, messages are imported as fast as possible.
+    #[clap(verbatim_doc_comment)]
+    #[clap(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) rate_limit: Option<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
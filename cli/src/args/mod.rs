@@ -11,14 +11,15 @@ use system::SnapshotArgs;
 
 use crate::args::{
     client::ClientAction,
+    common::OutputFormat,
     consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction,
     context::ContextAction,
-    message::MessageAction,
+    message::{ExportMessagesArgs, ImportMessagesArgs, MessageAction},
     partition::PartitionAction,
     personal_access_token::PersonalAccessTokenAction,
     stream::StreamAction,
-    system::{PingArgs, StatsArgs},
+    system::{BackgroundIoRateArgs, LogLevelArgs, PingArgs, StatsArgs},
     topic::TopicAction,
 };
 
@@ -68,6 +69,14 @@ pub(crate) struct CliOptions {
     #[clap(short, long)]
     pub(crate) debug: Option<PathBuf>,
 
+    /// Output format of the command results
+    ///
+    /// In json mode only the results of the command are printed
+    /// on standard output as JSON, which can be parsed by scripts.
+    /// Commands which don't return any results print nothing.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Text, verbatim_doc_comment)]
+    pub(crate) output: OutputFormat,
+
     /// Iggy server username
     #[clap(short, long, group = "credentials")]
     pub(crate) username: Option<String>,
@@ -141,6 +150,27 @@ pub(crate) enum Command {
     /// collect iggy server troubleshooting data
     #[clap(verbatim_doc_comment)]
     Snapshot(SnapshotArgs),
+    /// change iggy server log level at runtime
+    ///
+    /// Change the log filter of the server without restarting it,
+    /// e.g. to temporarily enable the trace logs of a specific module.
+    ///
+    /// Examples:
+    ///  iggy log-level debug
+    ///  iggy log-level info,server::streaming::segments=trace
+    #[clap(verbatim_doc_comment)]
+    LogLevel(LogLevelArgs),
+    /// change iggy server background IO rate at runtime
+    ///
+    /// Change the maximum rate of the disk IO performed by the background tasks
+    /// (retention, compaction, archiving and recovery) without restarting the server,
+    /// e.g. to protect the latency of the appends and polls during the peak traffic.
+    ///
+    /// Examples:
+    ///  iggy background-io 50MB
+    ///  iggy background-io unlimited
+    #[clap(verbatim_doc_comment)]
+    BackgroundIo(BackgroundIoRateArgs),
     /// personal access token operations
     #[command(subcommand)]
     Pat(PersonalAccessTokenAction),
@@ -159,6 +189,28 @@ pub(crate) enum Command {
     /// message operations
     #[command(subcommand, visible_alias = "m")]
     Message(MessageAction),
+    /// export messages of the topic to the file
+    ///
+    /// Command polls the messages from the partitions of the topic, in the optional
+    /// offset range, and stores them in newline-delimited JSON or binary archive file.
+    ///
+    /// Examples:
+    ///  iggy export stream topic messages.json
+    ///  iggy export --format binary --partition-ids 1,2 1 2 messages.bin
+    ///  iggy export --from-offset 100 --to-offset 199 --rate-limit 1000 stream topic messages.json
+    #[clap(verbatim_doc_comment)]
+    Export(ExportMessagesArgs),
+    /// import messages from the file to the topic
+    ///
+    /// Command sends the messages stored in the file by the export command,
+    /// in the optional offset range, keeping their IDs and headers.
+    ///
+    /// Examples:
+    ///  iggy import stream topic messages.json
+    ///  iggy import --format binary --partition-id 1 1 2 messages.bin
+    ///  iggy import --to-offset 99 --rate-limit 1000 stream topic messages.json
+    #[clap(verbatim_doc_comment)]
+    Import(ImportMessagesArgs),
     /// context operations
     #[command(subcommand, visible_alias = "ctx")]
     Context(ContextAction),
@@ -225,6 +277,7 @@ impl IggyMergedConsoleArgs {
         let merged_cli_options = CliOptions {
             quiet: args.cli.quiet,
             debug: args.cli.debug,
+            output: args.cli.output,
             username: args.cli.username.or(context.username),
             password: args.cli.password.or(context.password),
             token: args.cli.token.or(context.token),
@@ -1,5 +1,6 @@
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
+use iggy::utils::duration::IggyDuration;
 
 #[derive(Debug, Clone, Subcommand)]
 pub(crate) enum PartitionAction {
@@ -29,6 +30,62 @@ pub(crate) enum PartitionAction {
     ///  iggy partition delete 1 sensor 16
     #[clap(verbatim_doc_comment, visible_alias = "d")]
     Delete(PartitionDeleteArgs),
+    /// Get partition details for the specified partition ID,
+    /// topic ID and stream ID, including its segments.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition get 1 1 1
+    ///  iggy partition get prod 2 3
+    ///  iggy partition get test sensor 2
+    ///  iggy partition get 1 sensor 16
+    #[clap(verbatim_doc_comment, visible_alias = "g")]
+    Get(PartitionGetArgs),
+    /// Get the offset of the first message with the timestamp at or after
+    /// the given one for the specified partition ID, topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Timestamp is specified in microseconds since the Unix epoch
+    ///
+    /// Examples
+    ///  iggy partition offset 1 1 1 1700000000000000
+    ///  iggy partition offset prod 2 3 1700000000000000
+    ///  iggy partition offset test sensor 2 1700000000000000
+    #[clap(verbatim_doc_comment, visible_alias = "o")]
+    Offset(PartitionOffsetArgs),
+    /// Get the count and size of the messages per time bucket within the given
+    /// time range for the specified partition ID, topic ID and stream ID.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    /// Time range is specified in microseconds since the Unix epoch,
+    /// from the inclusive start to the exclusive end
+    ///
+    /// Examples
+    ///  iggy partition histogram 1 1 1 1700000000000000 1700003600000000
+    ///  iggy partition histogram prod 2 3 1700000000000000 1700003600000000 --bucket 5m
+    ///  iggy partition histogram test sensor 2 1700000000000000 1700000060000000 -b 1s
+    #[clap(verbatim_doc_comment, visible_alias = "hist")]
+    Histogram(PartitionHistogramArgs),
+    /// Seal the specified partition ID for the given topic ID and stream ID
+    /// by closing its active segment at the current offset.
+    ///
+    /// All the messages appended so far belong to the closed segments,
+    /// which provides a stable boundary for archiving or external processing.
+    /// Optionally, the topic can be marked as read-only before sealing.
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy partition seal 1 1 1
+    ///  iggy partition seal prod 2 3 --read-only
+    ///  iggy partition seal test sensor 2
+    #[clap(verbatim_doc_comment, visible_alias = "s")]
+    Seal(PartitionSealArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -64,3 +121,83 @@ pub(crate) struct PartitionDeleteArgs {
     #[arg(value_parser = clap::value_parser!(u32).range(1..100_001))]
     pub(crate) partitions_count: u32,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionGetArgs {
+    /// Stream ID to get partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to get
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionOffsetArgs {
+    /// Stream ID to get offset
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get offset
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to get offset
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Timestamp (in microseconds) to get offset of the first message at or after it
+    pub(crate) timestamp: u64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionHistogramArgs {
+    /// Stream ID to get histogram
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to get histogram
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to get histogram
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Start of the time range (in microseconds), inclusive
+    pub(crate) from: u64,
+    /// End of the time range (in microseconds), exclusive
+    pub(crate) to: u64,
+    /// Size of the single time bucket in human readable format like 1s, 5m or 1h
+    #[arg(short, long, default_value = "1m", value_parser = clap::value_parser!(IggyDuration))]
+    pub(crate) bucket: IggyDuration,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct PartitionSealArgs {
+    /// Stream ID to seal partition
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to seal partition
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Partition ID to seal
+    #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) partition_id: u32,
+    /// Mark the topic as read-only before sealing the partition
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) read_only: bool,
+}
@@ -1,4 +1,5 @@
 use crate::args::common::ListMode;
+use clap::builder::NonEmptyStringValueParser;
 use clap::{Args, Subcommand};
 use iggy::identifier::Identifier;
 
@@ -46,6 +47,7 @@ pub(crate) enum StreamAction {
     ///  iggy stream list
     ///  iggy stream list --list-mode table
     ///  iggy stream list -l table
+    ///  iggy stream list --prefix prod --offset 100 --limit 100
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(StreamListArgs),
     /// Purge all topics in given stream ID
@@ -100,6 +102,15 @@ pub(crate) struct StreamListArgs {
     /// List mode (table or list)
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
+    /// Number of streams ordered by ID to skip
+    #[clap(long, default_value_t = 0)]
+    pub(crate) offset: u32,
+    /// Maximum number of streams to list
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) limit: Option<u32>,
+    /// List only streams which names start with given prefix
+    #[clap(long, value_parser = NonEmptyStringValueParser::new())]
+    pub(crate) prefix: Option<String>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -2,6 +2,7 @@ use crate::args::common::ListModeExt;
 use clap::Args;
 use iggy::cli::utils::login_session_expiry::LoginSessionExpiry;
 use iggy::snapshot::{SnapshotCompression, SystemSnapshotType};
+use iggy::utils::byte_size::IggyByteSize;
 
 #[derive(Debug, Clone, Args)]
 pub(crate) struct PingArgs {
@@ -28,6 +29,27 @@ pub(crate) struct StatsArgs {
     pub(crate) output: ListModeExt,
 }
 
+#[derive(Debug, Clone, Args)]
+pub(crate) struct LogLevelArgs {
+    /// Log filter, either a global level or the levels per module
+    ///
+    /// Available levels are: trace, debug, info, warn, error and off.
+    /// The filter remains active until the server is restarted.
+    #[arg(verbatim_doc_comment)]
+    pub(crate) filter: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct BackgroundIoRateArgs {
+    /// Maximum number of bytes read and written per second by the background tasks
+    ///
+    /// The rate is shared by the retention, compaction, archiving and recovery tasks.
+    /// Use "0" or "unlimited" to remove the limit.
+    /// The rate remains active until the server is restarted.
+    #[arg(verbatim_doc_comment)]
+    pub(crate) max_rate: IggyByteSize,
+}
+
 #[derive(Debug, Clone, Args)]
 pub(crate) struct SnapshotArgs {
     /// Specify snapshot compression method.
@@ -1,8 +1,12 @@
 use crate::args::common::ListMode;
+use clap::builder::NonEmptyStringValueParser;
 use clap::{Args, Subcommand};
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::identifier::Identifier;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp_type::TimestampType;
 use iggy::utils::topic_size::MaxTopicSize;
 
 #[derive(Debug, Clone, Subcommand)]
@@ -63,6 +67,7 @@ pub(crate) enum TopicAction {
     /// Examples
     ///  iggy topic list 1
     ///  iggy topic list prod
+    ///  iggy topic list prod --prefix orders --offset 100 --limit 100
     #[clap(verbatim_doc_comment, visible_alias = "l")]
     List(TopicListArgs),
     /// Purge topic with given ID in given stream ID
@@ -78,6 +83,18 @@ pub(crate) enum TopicAction {
     ///  iggy topic purge 2 debugs
     #[clap(verbatim_doc_comment, visible_alias = "p")]
     Purge(TopicPurgeArgs),
+    /// Set or unset read-only mode for topic with given ID in given stream ID
+    ///
+    /// Messages sent to the read-only topic are rejected, while they can still be polled
+    /// Stream ID can be specified as a stream name or ID
+    /// Topic ID can be specified as a topic name or ID
+    ///
+    /// Examples
+    ///  iggy topic read-only 1 1 true
+    ///  iggy topic read-only prod 2 false
+    ///  iggy topic read-only test debugs true
+    #[clap(verbatim_doc_comment, visible_alias = "r")]
+    ReadOnly(TopicReadOnlyArgs),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -106,6 +123,23 @@ pub(crate) struct TopicCreateArgs {
     /// Replication factor for the topic
     #[arg(short, long, default_value = "1")]
     pub(crate) replication_factor: u8,
+    /// Cleanup policy for the topic
+    ///
+    /// ("delete" removes whole expired segments, "compact" keeps only the latest message per key)
+    #[arg(short, long, default_value = "delete", verbatim_doc_comment)]
+    pub(crate) cleanup_policy: CleanupPolicy,
+    /// Segment size of the topic partitions, overriding the server default
+    #[arg(long)]
+    pub(crate) segment_size: Option<IggyByteSize>,
+    /// Number of buffered messages which triggers saving them to disk, overriding the server default
+    #[arg(long)]
+    pub(crate) messages_required_to_save: Option<u32>,
+    /// Cache size of each partition, overriding the server default
+    #[arg(long)]
+    pub(crate) cache_size: Option<IggyByteSize>,
+    /// Message timestamp type ("create_time" or "log_append_time"), overriding the server default
+    #[arg(long)]
+    pub(crate) message_timestamp_type: Option<TimestampType>,
     /// Message expiry time in human-readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter disables message expiry functionality in topic)
@@ -153,6 +187,23 @@ pub(crate) struct TopicUpdateArgs {
     #[arg(short, long, default_value = "1")]
     /// New replication factor for the topic
     pub(crate) replication_factor: u8,
+    /// New cleanup policy for the topic
+    ///
+    /// ("delete" removes whole expired segments, "compact" keeps only the latest message per key)
+    #[arg(short, long, default_value = "delete", verbatim_doc_comment)]
+    pub(crate) cleanup_policy: CleanupPolicy,
+    /// New segment size of the topic partitions, overriding the server default
+    #[arg(long)]
+    pub(crate) segment_size: Option<IggyByteSize>,
+    /// New number of buffered messages which triggers saving them to disk, overriding the server default
+    #[arg(long)]
+    pub(crate) messages_required_to_save: Option<u32>,
+    /// New cache size of each partition, overriding the server default
+    #[arg(long)]
+    pub(crate) cache_size: Option<IggyByteSize>,
+    /// New message timestamp type ("create_time" or "log_append_time"), overriding the server default
+    #[arg(long)]
+    pub(crate) message_timestamp_type: Option<TimestampType>,
     /// New message expiry time in human-readable format like 15days 2min 2s
     ///
     /// ("unlimited" or skipping parameter causes removal of expiry parameter in topic)
@@ -185,6 +236,15 @@ pub(crate) struct TopicListArgs {
     /// List mode (table or list)
     #[clap(short, long, value_enum, default_value_t = ListMode::Table)]
     pub(crate) list_mode: ListMode,
+    /// Number of topics ordered by ID to skip
+    #[clap(long, default_value_t = 0)]
+    pub(crate) offset: u32,
+    /// Maximum number of topics to list
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub(crate) limit: Option<u32>,
+    /// List only topics which names start with given prefix
+    #[clap(long, value_parser = NonEmptyStringValueParser::new())]
+    pub(crate) prefix: Option<String>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -200,3 +260,20 @@ pub(crate) struct TopicPurgeArgs {
     #[arg(value_parser = clap::value_parser!(Identifier))]
     pub(crate) topic_id: Identifier,
 }
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct TopicReadOnlyArgs {
+    /// Stream ID of the topic
+    ///
+    /// Stream ID can be specified as a stream name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) stream_id: Identifier,
+    /// Topic ID to set read-only mode for
+    ///
+    /// Topic ID can be specified as a topic name or ID
+    #[arg(value_parser = clap::value_parser!(Identifier))]
+    pub(crate) topic_id: Identifier,
+    /// Whether the topic is read-only
+    #[arg(action = clap::ArgAction::Set)]
+    pub(crate) read_only: bool,
+}
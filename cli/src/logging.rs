@@ -1,4 +1,5 @@
-use iggy::cli_command::PRINT_TARGET;
+use crate::args::common::OutputFormat;
+use iggy::cli_command::{PRINT_JSON_TARGET, PRINT_TARGET};
 use std::path::PathBuf;
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{
@@ -20,10 +21,18 @@ impl Logging {
         }
     }
 
-    pub(crate) fn init(&mut self, quiet: bool, debug: &Option<PathBuf>) -> &mut Self {
+    pub(crate) fn init(
+        &mut self,
+        quiet: bool,
+        output: OutputFormat,
+        debug: &Option<PathBuf>,
+    ) -> &mut Self {
         let mut layers = vec![];
 
-        let stdout_filter = filter::filter_fn(|metadata| metadata.target().contains(PRINT_TARGET));
+        let stdout_filter = filter::filter_fn(move |metadata| match output {
+            OutputFormat::Text => metadata.target().contains(PRINT_TARGET),
+            OutputFormat::Json => metadata.target() == PRINT_JSON_TARGET,
+        });
         let (stdout_writer, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
 
         let stdout_layer = fmt::Layer::default()
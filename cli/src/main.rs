@@ -4,7 +4,7 @@ mod error;
 mod logging;
 
 use crate::args::{
-    client::ClientAction, consumer_group::ConsumerGroupAction,
+    client::ClientAction, common::OutputFormat, consumer_group::ConsumerGroupAction,
     consumer_offset::ConsumerOffsetAction, permissions::PermissionsArgs,
     personal_access_token::PersonalAccessTokenAction, stream::StreamAction, topic::TopicAction,
     Command, IggyConsoleArgs,
@@ -19,8 +19,11 @@ use args::user::UserAction;
 use args::{CliOptions, IggyMergedConsoleArgs};
 use clap::Parser;
 use iggy::args::Args;
+use iggy::cli::context::add_context::AddContextCmd;
 use iggy::cli::context::common::ContextManager;
 use iggy::cli::context::use_context::UseContextCmd;
+use iggy::cli::system::background_io_rate::SetBackgroundIoRateCmd;
+use iggy::cli::system::log_level::SetLogLevelCmd;
 use iggy::cli::system::snapshot::GetSnapshotCmd;
 use iggy::cli::{
     client::{get_client::GetClientCmd, get_clients::GetClientsCmd},
@@ -30,14 +33,23 @@ use iggy::cli::{
         get_consumer_groups::GetConsumerGroupsCmd,
     },
     consumer_offset::{
-        get_consumer_offset::GetConsumerOffsetCmd, set_consumer_offset::SetConsumerOffsetCmd,
+        delete_consumer_offsets::DeleteConsumerOffsetsCmd,
+        get_consumer_offset::GetConsumerOffsetCmd, reset_consumer_offsets::ResetConsumerOffsetsCmd,
+        set_consumer_offset::SetConsumerOffsetCmd,
     },
     context::get_contexts::GetContextsCmd,
     message::{
-        flush_messages::FlushMessagesCmd, poll_messages::PollMessagesCmd,
-        send_messages::SendMessagesCmd,
+        copy_messages::CopyMessagesCmd, export_messages::ExportMessagesCmd,
+        flush_messages::FlushMessagesCmd, get_message_by_key::GetMessageByKeyCmd,
+        import_messages::ImportMessagesCmd, peek_messages::PeekMessagesCmd,
+        poll_messages::PollMessagesCmd, send_messages::SendMessagesCmd,
+    },
+    partitions::{
+        create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd,
+        get_messages_histogram::GetMessagesHistogramCmd,
+        get_offset_by_timestamp::GetOffsetByTimestampCmd, get_partition::GetPartitionCmd,
+        seal_partition::SealPartitionCmd,
     },
-    partitions::{create_partitions::CreatePartitionsCmd, delete_partitions::DeletePartitionsCmd},
     personal_access_tokens::{
         create_personal_access_token::CreatePersonalAccessTokenCmd,
         delete_personal_access_tokens::DeletePersonalAccessTokenCmd,
@@ -50,7 +62,8 @@ use iggy::cli::{
     system::{me::GetMeCmd, ping::PingCmd, stats::GetStatsCmd},
     topics::{
         create_topic::CreateTopicCmd, delete_topic::DeleteTopicCmd, get_topic::GetTopicCmd,
-        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd, update_topic::UpdateTopicCmd,
+        get_topics::GetTopicsCmd, purge_topic::PurgeTopicCmd,
+        set_topic_read_only::SetTopicReadOnlyCmd, update_topic::UpdateTopicCmd,
     },
     users::{
         change_password::ChangePasswordCmd,
@@ -65,8 +78,11 @@ use iggy::cli::{
 use iggy::cli_command::{CliCommand, PRINT_TARGET};
 use iggy::client_provider::{self, ClientProviderConfig};
 use iggy::clients::client::IggyClient;
+use iggy::messages::copy_messages::CopyRangeKind;
+use iggy::messages::peek_messages::PeekKind;
 use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use iggy::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use std::sync::Arc;
 use tracing::{event, Level};
 
@@ -96,7 +112,12 @@ fn get_command(
                 args.name.clone(),
             )),
             StreamAction::Get(args) => Box::new(GetStreamCmd::new(args.stream_id.clone())),
-            StreamAction::List(args) => Box::new(GetStreamsCmd::new(args.list_mode.into())),
+            StreamAction::List(args) => Box::new(GetStreamsCmd::new(
+                args.offset,
+                args.limit,
+                args.prefix.clone(),
+                args.list_mode.into(),
+            )),
             StreamAction::Purge(args) => Box::new(PurgeStreamCmd::new(args.stream_id.clone())),
         },
         Command::Topic(command) => match command {
@@ -109,6 +130,13 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
+                TopicOverrides {
+                    segment_size: args.segment_size,
+                    messages_required_to_save: args.messages_required_to_save,
+                    cache_size: args.cache_size,
+                    message_timestamp_type: args.message_timestamp_type,
+                },
             )),
             TopicAction::Delete(args) => Box::new(DeleteTopicCmd::new(
                 args.stream_id.clone(),
@@ -122,6 +150,13 @@ fn get_command(
                 args.message_expiry.clone().into(),
                 args.max_topic_size,
                 args.replication_factor,
+                args.cleanup_policy,
+                TopicOverrides {
+                    segment_size: args.segment_size,
+                    messages_required_to_save: args.messages_required_to_save,
+                    cache_size: args.cache_size,
+                    message_timestamp_type: args.message_timestamp_type,
+                },
             )),
             TopicAction::Get(args) => Box::new(GetTopicCmd::new(
                 args.stream_id.clone(),
@@ -129,12 +164,20 @@ fn get_command(
             )),
             TopicAction::List(args) => Box::new(GetTopicsCmd::new(
                 args.stream_id.clone(),
+                args.offset,
+                args.limit,
+                args.prefix.clone(),
                 args.list_mode.into(),
             )),
             TopicAction::Purge(args) => Box::new(PurgeTopicCmd::new(
                 args.stream_id.clone(),
                 args.topic_id.clone(),
             )),
+            TopicAction::ReadOnly(args) => Box::new(SetTopicReadOnlyCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.read_only,
+            )),
         },
         Command::Partition(command) => match command {
             PartitionAction::Create(args) => Box::new(CreatePartitionsCmd::new(
@@ -147,10 +190,37 @@ fn get_command(
                 args.topic_id.clone(),
                 args.partitions_count,
             )),
+            PartitionAction::Get(args) => Box::new(GetPartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+            )),
+            PartitionAction::Offset(args) => Box::new(GetOffsetByTimestampCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.timestamp,
+            )),
+            PartitionAction::Histogram(args) => Box::new(GetMessagesHistogramCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.from,
+                args.to,
+                args.bucket,
+            )),
+            PartitionAction::Seal(args) => Box::new(SealPartitionCmd::new(
+                args.stream_id.clone(),
+                args.topic_id.clone(),
+                args.partition_id,
+                args.read_only,
+            )),
         },
         Command::Ping(args) => Box::new(PingCmd::new(args.count)),
         Command::Me => Box::new(GetMeCmd::new()),
         Command::Stats(args) => Box::new(GetStatsCmd::new(cli_options.quiet, args.output.into())),
+        Command::LogLevel(args) => Box::new(SetLogLevelCmd::new(args.filter)),
+        Command::BackgroundIo(args) => Box::new(SetBackgroundIoRateCmd::new(args.max_rate)),
         Command::Snapshot(args) => Box::new(GetSnapshotCmd::new(
             args.compression,
             args.snapshot_types,
@@ -226,6 +296,7 @@ fn get_command(
                 create_args.topic_id.clone(),
                 create_args.name.clone(),
                 create_args.group_id,
+                create_args.rebalance_strategy,
             )),
             ConsumerGroupAction::Delete(delete_args) => Box::new(DeleteConsumerGroupCmd::new(
                 delete_args.stream_id.clone(),
@@ -258,11 +329,13 @@ fn get_command(
                 poll_args.topic_id.clone(),
                 poll_args.partition_id,
                 poll_args.message_count,
+                poll_args.max_bytes,
                 poll_args.auto_commit,
                 poll_args.offset,
                 poll_args.first,
                 poll_args.last,
                 poll_args.next,
+                poll_args.backward,
                 poll_args.consumer.clone(),
                 poll_args.show_headers,
                 poll_args.output_file.clone(),
@@ -273,7 +346,60 @@ fn get_command(
                 flush_args.partition_id,
                 flush_args.fsync,
             )),
+            MessageAction::Peek(peek_args) => Box::new(PeekMessagesCmd::new(
+                peek_args.stream_id.clone(),
+                peek_args.topic_id.clone(),
+                peek_args.partition_id,
+                match (peek_args.last, peek_args.random) {
+                    (true, _) => PeekKind::Last,
+                    (_, true) => PeekKind::Random,
+                    _ => PeekKind::First,
+                },
+                peek_args.message_count,
+            )),
+            MessageAction::Key(key_args) => Box::new(GetMessageByKeyCmd::new(
+                key_args.stream_id.clone(),
+                key_args.topic_id.clone(),
+                key_args.partition_id,
+                key_args.key,
+            )),
+            MessageAction::Copy(copy_args) => Box::new(CopyMessagesCmd::new(
+                copy_args.source_stream_id.clone(),
+                copy_args.source_topic_id.clone(),
+                copy_args.source_partition_id,
+                copy_args.target_stream_id.clone(),
+                copy_args.target_topic_id.clone(),
+                copy_args.target_partition_id,
+                match copy_args.timestamps {
+                    true => CopyRangeKind::Timestamp,
+                    false => CopyRangeKind::Offset,
+                },
+                copy_args.from,
+                copy_args.to,
+            )),
         },
+        Command::Export(export_args) => Box::new(ExportMessagesCmd::new(
+            export_args.stream_id,
+            export_args.topic_id,
+            export_args.partition_ids,
+            export_args.output_file,
+            export_args.format.into(),
+            export_args.from_offset,
+            export_args.to_offset,
+            export_args.batch_size,
+            export_args.rate_limit,
+        )),
+        Command::Import(import_args) => Box::new(ImportMessagesCmd::new(
+            import_args.stream_id,
+            import_args.topic_id,
+            import_args.partition_id,
+            import_args.input_file,
+            import_args.format.into(),
+            import_args.from_offset,
+            import_args.to_offset,
+            import_args.batch_size,
+            import_args.rate_limit,
+        )),
         Command::ConsumerOffset(command) => match command {
             ConsumerOffsetAction::Get(get_args) => Box::new(GetConsumerOffsetCmd::new(
                 get_args.consumer_id.clone(),
@@ -288,6 +414,25 @@ fn get_command(
                 set_args.partition_id,
                 set_args.offset,
             )),
+            ConsumerOffsetAction::Reset(reset_args) => Box::new(ResetConsumerOffsetsCmd::new(
+                reset_args.consumer_id.clone(),
+                reset_args.consumer_group,
+                reset_args.stream_id.clone(),
+                reset_args.topic_id.clone(),
+                reset_args.partition_ids.clone(),
+                reset_args.earliest,
+                reset_args.latest,
+                reset_args.offset,
+                reset_args.timestamp,
+                reset_args.dry_run,
+            )),
+            ConsumerOffsetAction::Delete(delete_args) => Box::new(DeleteConsumerOffsetsCmd::new(
+                delete_args.consumer_id.clone(),
+                delete_args.consumer_group,
+                delete_args.stream_id.clone(),
+                delete_args.topic_id.clone(),
+                delete_args.partition_id,
+            )),
         },
         Command::Context(command) => match command {
             ContextAction::List(list_args) => {
@@ -296,6 +441,10 @@ fn get_command(
             ContextAction::Use(use_args) => {
                 Box::new(UseContextCmd::new(use_args.context_name.clone()))
             }
+            ContextAction::Add(add_args) => Box::new(AddContextCmd::new(
+                add_args.context_name.clone(),
+                add_args.into(),
+            )),
         },
         #[cfg(feature = "login-session")]
         Command::Login(login_args) => Box::new(LoginCmd::new(
@@ -322,7 +471,7 @@ async fn main() -> Result<(), IggyCmdError> {
     }
 
     let mut logging = Logging::new();
-    logging.init(args.cli.quiet, &args.cli.debug);
+    logging.init(args.cli.quiet, args.cli.output, &args.cli.debug);
 
     let command = args.command.clone().unwrap();
 
@@ -358,10 +507,13 @@ async fn main() -> Result<(), IggyCmdError> {
     credentials.set_iggy_client(&client);
     credentials.login_user().await?;
 
-    if command.use_tracing() {
-        event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
-    } else {
-        println!("Executing {}", command.explain());
+    // Only the results of the command are printed in json mode
+    if cli_options.output == OutputFormat::Text {
+        if command.use_tracing() {
+            event!(target: PRINT_TARGET, Level::INFO, "Executing {}", command.explain());
+        } else {
+            println!("Executing {}", command.explain());
+        }
     }
     command.execute_cmd(&client).await?;
 
@@ -4,8 +4,10 @@ use iggy::clients::client::IggyClient;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::users::defaults::*;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::env;
 use std::error::Error;
@@ -60,6 +62,8 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await
     {
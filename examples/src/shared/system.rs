@@ -6,7 +6,9 @@ use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::models::messages::PolledMessage;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use tracing::info;
 
@@ -83,6 +85,8 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Ig
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await?;
     Ok(())
@@ -0,0 +1,11 @@
+#![no_main]
+
+use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use libfuzzer_sys::fuzz_target;
+use std::collections::HashMap;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = HashMap::<HeaderKey, HeaderValue>::from_bytes(Bytes::copy_from_slice(data));
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::permissions::Permissions;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Permissions::from_bytes(Bytes::copy_from_slice(data));
+});
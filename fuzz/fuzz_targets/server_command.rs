@@ -0,0 +1,11 @@
+#![no_main]
+
+use bytes::Bytes;
+use iggy::bytes_serializable::BytesSerializable;
+use libfuzzer_sys::fuzz_target;
+use server::command::ServerCommand;
+
+// The first 4 bytes are the command code, so every command decoder is reachable from this target.
+fuzz_target!(|data: &[u8]| {
+    let _ = ServerCommand::from_bytes(Bytes::copy_from_slice(data));
+});
@@ -5,7 +5,10 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::diff;
 use serial_test::parallel;
@@ -17,6 +20,7 @@ struct TestConsumerGroupCreateCmd {
     topic_name: String,
     group_id: Option<u32>,
     group_name: String,
+    rebalance_strategy: Option<RebalanceStrategy>,
     using_stream_id: TestStreamId,
     using_topic_id: TestTopicId,
 }
@@ -30,6 +34,7 @@ impl TestConsumerGroupCreateCmd {
         topic_name: String,
         group_id: Option<u32>,
         group_name: String,
+        rebalance_strategy: Option<RebalanceStrategy>,
         using_stream_id: TestStreamId,
         using_topic_id: TestTopicId,
     ) -> Self {
@@ -40,6 +45,7 @@ impl TestConsumerGroupCreateCmd {
             topic_name,
             group_id,
             group_name,
+            rebalance_strategy,
             using_stream_id,
             using_topic_id,
         }
@@ -61,6 +67,11 @@ impl TestConsumerGroupCreateCmd {
             command.push(format!("{}", group_id));
         }
 
+        if let Some(rebalance_strategy) = self.rebalance_strategy {
+            command.push("-r".to_string());
+            command.push(format!("{}", rebalance_strategy));
+        }
+
         command.push(self.group_name.clone());
 
         command
@@ -85,6 +96,8 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -136,6 +149,10 @@ impl IggyCmdTestCase for TestConsumerGroupCreateCmd {
         if let Some(group_id) = self.group_id {
             assert_eq!(consumer_group_details.id, group_id);
         }
+        assert_eq!(
+            consumer_group_details.rebalance_strategy,
+            self.rebalance_strategy.unwrap_or_default()
+        );
 
         let topic = client
             .delete_topic(
@@ -166,6 +183,7 @@ pub async fn should_be_successful() {
             String::from("sync"),
             Some(1),
             String::from("group1"),
+            None,
             TestStreamId::Numeric,
             TestTopicId::Numeric,
         ))
@@ -178,6 +196,7 @@ pub async fn should_be_successful() {
             String::from("topic"),
             Some(3),
             String::from("group3"),
+            None,
             TestStreamId::Named,
             TestTopicId::Numeric,
         ))
@@ -190,6 +209,7 @@ pub async fn should_be_successful() {
             String::from("probe"),
             Some(7),
             String::from("group7"),
+            None,
             TestStreamId::Numeric,
             TestTopicId::Named,
         ))
@@ -202,6 +222,7 @@ pub async fn should_be_successful() {
             String::from("test"),
             Some(4),
             String::from("group4"),
+            Some(RebalanceStrategy::Sticky),
             TestStreamId::Named,
             TestTopicId::Named,
         ))
@@ -228,6 +249,7 @@ Examples:
  iggy consumer-group create stream 2 test
  iggy consumer-group create 2 topic receiver
  iggy consumer-group create -g 4 stream topic group
+ iggy consumer-group create -r sticky stream topic group
 
 {USAGE_PREFIX} consumer-group create [OPTIONS] <STREAM_ID> <TOPIC_ID> <NAME>
 
@@ -249,6 +271,13 @@ Options:
   -g, --group-id <GROUP_ID>
           Consumer group ID to create
 
+  -r, --rebalance-strategy <REBALANCE_STRATEGY>
+          Rebalance strategy of the consumer group
+{CLAP_INDENT}
+          ("eager" reassigns all the partitions, "sticky" moves only the ones needed to balance the members)
+{CLAP_INDENT}
+          [default: eager]
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -276,8 +305,12 @@ Arguments:
   <NAME>       Consumer group name to create
 
 Options:
-  -g, --group-id <GROUP_ID>  Consumer group ID to create
-  -h, --help                 Print help (see more with '--help')
+  -g, --group-id <GROUP_ID>
+          Consumer group ID to create
+  -r, --rebalance-strategy <REBALANCE_STRATEGY>
+          Rebalance strategy of the consumer group [default: eager]
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -5,7 +5,10 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::diff;
 use serial_test::parallel;
@@ -86,6 +89,8 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -96,6 +101,7 @@ impl IggyCmdTestCase for TestConsumerGroupDeleteCmd {
                 &self.topic_id.try_into().unwrap(),
                 &self.group_name,
                 Some(self.group_id),
+                RebalanceStrategy::default(),
             )
             .await;
         assert!(consumer_group.is_ok());
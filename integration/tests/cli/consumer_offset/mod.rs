@@ -1,2 +1,4 @@
+mod test_consumer_offset_delete_command;
 mod test_consumer_offset_get_command;
+mod test_consumer_offset_reset_command;
 mod test_consumer_offset_set_command;
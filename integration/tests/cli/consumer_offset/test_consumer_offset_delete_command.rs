@@ -0,0 +1,223 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestConsumerOffsetDeleteCmd {
+    consumer_id: u32,
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partitions_count: u32,
+    partition_id: Option<u32>,
+}
+
+impl TestConsumerOffsetDeleteCmd {
+    fn new(
+        consumer_id: u32,
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partitions_count: u32,
+        partition_id: Option<u32>,
+    ) -> Self {
+        Self {
+            consumer_id,
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partitions_count,
+            partition_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            format!("{}", self.consumer_id),
+            format!("{}", self.stream_id),
+            format!("{}", self.topic_id),
+        ];
+        if let Some(partition_id) = self.partition_id {
+            command.push(String::from("--partition-id"));
+            command.push(format!("{partition_id}"));
+        }
+
+        command
+    }
+
+    fn partition_info(&self) -> String {
+        match self.partition_id {
+            Some(partition_id) => format!("partition with ID: {partition_id}"),
+            None => String::from("all partitions"),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerOffsetDeleteCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partitions_count,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        for partition_id in 1..=self.partitions_count {
+            let mut messages = (1..=10)
+                .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+                .collect::<Vec<_>>();
+            let send_status = client
+                .send_messages(
+                    &self.stream_id.try_into().unwrap(),
+                    &self.topic_id.try_into().unwrap(),
+                    &Partitioning::partition_id(partition_id),
+                    &mut messages,
+                )
+                .await;
+            assert!(send_status.is_ok());
+
+            let offset = client
+                .store_consumer_offset(
+                    &Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                    &self.stream_id.try_into().unwrap(),
+                    &self.topic_id.try_into().unwrap(),
+                    Some(partition_id),
+                    5,
+                )
+                .await;
+            assert!(offset.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-offset")
+            .arg("delete")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing delete consumer offsets for consumer with ID: {} for stream with ID: {} and topic with ID: {} and {}\nConsumer offsets for consumer with ID: {} for stream with ID: {} and topic with ID: {} and {} deleted\n",
+            self.consumer_id,
+            self.stream_id,
+            self.topic_id,
+            self.partition_info(),
+            self.consumer_id,
+            self.stream_id,
+            self.topic_id,
+            self.partition_info(),
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let lags = client
+            .get_consumer_lag(
+                &Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                None,
+            )
+            .await;
+        assert!(lags.is_ok());
+        for lag in lags.unwrap() {
+            let deleted = self
+                .partition_id
+                .map_or(true, |partition_id| partition_id == lag.partition_id);
+            assert_eq!(lag.stored_offset.is_none(), deleted);
+        }
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    for partition_id in [Some(2), None] {
+        iggy_cmd_test
+            .execute_test(TestConsumerOffsetDeleteCmd::new(
+                1,
+                2,
+                String::from("stream"),
+                3,
+                String::from("topic"),
+                3,
+                partition_id,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-offset", "delete", "-h"],
+            format!(
+                r#"Delete the stored offsets of a consumer or consumer group for a given partition or all the partitions on the server
+
+{USAGE_PREFIX} consumer-offset delete [OPTIONS] <CONSUMER_ID> <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <CONSUMER_ID>  Consumer or consumer group for which the offsets are deleted
+  <STREAM_ID>    Stream ID for which consumer offsets are deleted
+  <TOPIC_ID>     Topic ID for which consumer offsets are deleted
+
+Options:
+  -p, --partition-id <PARTITION_ID>  Partition ID for which consumer offset is deleted
+  -g, --consumer-group               Delete the offsets of the consumer group instead of the regular consumer
+  -h, --help                         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
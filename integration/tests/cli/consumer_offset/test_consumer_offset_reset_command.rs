@@ -0,0 +1,243 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, USAGE_PREFIX};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+struct TestConsumerOffsetResetCmd {
+    consumer_id: u32,
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    stored_offset: u64,
+    reset_offset: u64,
+    dry_run: bool,
+}
+
+impl TestConsumerOffsetResetCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        consumer_id: u32,
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        stored_offset: u64,
+        reset_offset: u64,
+        dry_run: bool,
+    ) -> Self {
+        Self {
+            consumer_id,
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            stored_offset,
+            reset_offset,
+            dry_run,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec![
+            format!("{}", self.consumer_id),
+            format!("{}", self.stream_id),
+            format!("{}", self.topic_id),
+            String::from("--offset"),
+            format!("{}", self.reset_offset),
+            String::from("--partition-ids"),
+            format!("{}", self.partition_id),
+        ];
+        if self.dry_run {
+            command.push(String::from("--dry-run"));
+        }
+
+        command
+    }
+
+    fn expected_offset(&self) -> u64 {
+        if self.dry_run {
+            self.stored_offset
+        } else {
+            self.reset_offset - 1
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestConsumerOffsetResetCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = (1..=self.stored_offset + 10)
+            .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(self.partition_id),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+
+        let offset = client
+            .store_consumer_offset(
+                &Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(self.partition_id),
+                self.stored_offset,
+            )
+            .await;
+        assert!(offset.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("consumer-offset")
+            .arg("reset")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message = format!(
+            "Executing {}reset consumer offsets for consumer with ID: {} for stream with ID: {} and topic with ID: {} to offset {}\n",
+            if self.dry_run { "dry run " } else { "" },
+            self.consumer_id,
+            self.stream_id,
+            self.topic_id,
+            self.reset_offset,
+        );
+
+        command_state
+            .success()
+            .stdout(starts_with(message))
+            .stdout(contains(format!(
+                "| {}            | {}             | {}               | {}             |",
+                self.partition_id,
+                self.stored_offset + 9,
+                self.stored_offset,
+                self.reset_offset - 1
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let offset = client
+            .get_consumer_offset(
+                &Consumer::new(Identifier::numeric(self.consumer_id).unwrap()),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(self.partition_id),
+            )
+            .await;
+        assert!(offset.is_ok());
+        let offset = offset.unwrap().expect("Failed to get consumer offset");
+        assert_eq!(offset.stored_offset, self.expected_offset());
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    for dry_run in [true, false] {
+        iggy_cmd_test
+            .execute_test(TestConsumerOffsetResetCmd::new(
+                1,
+                2,
+                String::from("stream"),
+                3,
+                String::from("topic"),
+                1,
+                5,
+                3,
+                dry_run,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["consumer-offset", "reset", "-h"],
+            format!(
+                r#"Reset the offsets of a consumer or consumer group for the selected partitions on the server
+
+{USAGE_PREFIX} consumer-offset reset [OPTIONS] <--earliest|--latest|--offset <OFFSET>|--timestamp <TIMESTAMP>> <CONSUMER_ID> <STREAM_ID> <TOPIC_ID>
+
+Arguments:
+  <CONSUMER_ID>  Consumer or consumer group for which the offsets are reset
+  <STREAM_ID>    Stream ID for which consumer offsets are reset
+  <TOPIC_ID>     Topic ID for which consumer offsets are reset
+
+Options:
+  -p, --partition-ids <PARTITION_IDS>  Partition IDs for which consumer offsets are reset
+  -e, --earliest                       Reset strategy - continue from the first message still available in the partition
+  -l, --latest                         Reset strategy - continue from the next message appended to the partition
+  -o, --offset <OFFSET>                Reset strategy - continue from the specified offset
+  -t, --timestamp <TIMESTAMP>          Reset strategy - continue from the first message with the timestamp
+                                       greater than or equal to the specified one (in microseconds)
+  -g, --consumer-group                 Reset the offsets of the consumer group instead of the regular consumer
+  -d, --dry-run                        Only report the resulting offsets without storing them
+  -h, --help                           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -1,5 +1,6 @@
 mod common;
 
+mod test_context_add_command;
 mod test_context_applied;
 mod test_context_list_command;
 mod test_context_use_command;
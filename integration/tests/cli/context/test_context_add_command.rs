@@ -0,0 +1,192 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::contains;
+use serial_test::parallel;
+
+use super::common::TestIggyContext;
+
+struct TestContextAddCmd {
+    test_iggy_context: TestIggyContext,
+    context_name: String,
+    transport: String,
+    address: String,
+    username: String,
+}
+
+impl TestContextAddCmd {
+    fn new(
+        test_iggy_context: TestIggyContext,
+        context_name: String,
+        transport: String,
+        address: String,
+        username: String,
+    ) -> Self {
+        Self {
+            test_iggy_context,
+            context_name,
+            transport,
+            address,
+            username,
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestContextAddCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {
+        self.test_iggy_context.prepare().await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .env(
+                "IGGY_HOME",
+                self.test_iggy_context.get_iggy_home().to_str().unwrap(),
+            )
+            .arg("context")
+            .arg("add")
+            .arg(self.context_name.clone())
+            .arg("--transport")
+            .arg(self.transport.clone())
+            .arg("--address")
+            .arg(self.address.clone())
+            .arg("--username")
+            .arg(self.username.clone())
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state
+            .success()
+            .stdout(contains(format!("context '{}' added", self.context_name)));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {
+        let contexts = self
+            .test_iggy_context
+            .read_saved_contexts()
+            .await
+            .expect("Contexts file not found");
+        let context = contexts
+            .get(&self.context_name)
+            .expect("Added context not found");
+        assert_eq!(context.iggy.transport, Some(self.transport.clone()));
+        assert_eq!(context.iggy.http_api_url, Some(self.address.clone()));
+        assert_eq!(context.iggy.tcp_server_address, None);
+        assert_eq!(context.username, Some(self.username.clone()));
+        assert_eq!(context.password, None);
+        assert_eq!(context.token, None);
+        assert!(contexts.contains_key("default"));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let contexts_path = self.test_iggy_context.get_iggy_home().join("contexts.toml");
+            let mode = std::fs::metadata(contexts_path)
+                .expect("Contexts file not found")
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    iggy_cmd_test.setup().await;
+
+    iggy_cmd_test
+        .execute_test(TestContextAddCmd::new(
+            TestIggyContext::new(None, None),
+            "staging".to_string(),
+            "http".to_string(),
+            "http://staging:3000".to_string(),
+            "operator".to_string(),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "add", "--help"],
+            format!(
+                r#"Add a new context
+
+Context stores the server address, transport and the username or
+the personal access token name which are used by all the commands
+once the context is active. Passwords and tokens are never stored,
+the password is prompted for when needed and the token is read from
+the system keyring under the given name. Contexts are saved in the
+contexts.toml file in the IGGY_HOME directory (~/.iggy by default),
+readable only by its owner.
+
+Examples
+ iggy context add dev --address 127.0.0.1:8090 -u iggy
+ iggy context add prod --transport http --address https://iggy.example.com:3000 -n prod-token
+
+{USAGE_PREFIX} context add [OPTIONS] <CONTEXT_NAME>
+
+Arguments:
+  <CONTEXT_NAME>
+{CLAP_INDENT}Name of the context to add
+
+Options:
+      --transport <TRANSPORT>
+{CLAP_INDENT}Transport used to connect to the server
+{CLAP_INDENT}
+{CLAP_INDENT}[possible values: tcp, quic, http]
+
+      --address <ADDRESS>
+{CLAP_INDENT}Server address for the transport (TCP or QUIC server address, HTTP API URL)
+
+  -u, --username <USERNAME>
+{CLAP_INDENT}Iggy server username (password is prompted for when needed)
+
+  -n, --token-name <TOKEN_NAME>
+{CLAP_INDENT}Iggy server personal access token name (token is read from the system keyring)
+
+  -h, --help
+{CLAP_INDENT}Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["context", "add", "-h"],
+            format!(
+                r#"Add a new context
+
+{USAGE_PREFIX} context add [OPTIONS] <CONTEXT_NAME>
+
+Arguments:
+  <CONTEXT_NAME>  Name of the context to add
+
+Options:
+      --transport <TRANSPORT>    Transport used to connect to the server [possible values: tcp, quic, http]
+      --address <ADDRESS>        Server address for the transport (TCP or QUIC server address, HTTP API URL)
+  -u, --username <USERNAME>      Iggy server username (password is prompted for when needed)
+  -n, --token-name <TOKEN_NAME>  Iggy server personal access token name (token is read from the system keyring)
+  -h, --help                     Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -22,12 +22,16 @@ Commands:
   me               get current client info
   stats            get iggy server statistics
   snapshot         collect iggy server troubleshooting data
+  log-level        change iggy server log level at runtime
+  background-io    change iggy server background IO rate at runtime
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
   message          message operations [aliases: m]
+  export           export messages of the topic to the file
+  import           import messages from the file to the topic
   context          context operations [aliases: ctx]
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
@@ -138,7 +142,7 @@ Options:
           [default: 100000]
 
       --quic-response-buffer-size <QUIC_RESPONSE_BUFFER_SIZE>
-          The optional response buffer size for QUIC
+          The optional size of the single chunk read when receiving the QUIC response
 {CLAP_INDENT}
           [default: 1048576]
 
@@ -161,6 +165,16 @@ Options:
   -d, --debug <DEBUG>
           Debug mode (verbose printing to given file)
 
+      --output <OUTPUT>
+          Output format of the command results
+{CLAP_INDENT}
+          In json mode only the results of the command are printed
+          on standard output as JSON, which can be parsed by scripts.
+          Commands which don't return any results print nothing.
+{CLAP_INDENT}
+          [default: text]
+          [possible values: text, json]
+
   -u, --username <USERNAME>
           Iggy server username
 
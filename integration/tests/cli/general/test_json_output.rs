@@ -0,0 +1,53 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::models::stream::Stream;
+use serial_test::parallel;
+
+struct TestJsonOutputCmd {
+    stream_id: u32,
+    name: String,
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestJsonOutputCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client.create_stream(&self.name, Some(self.stream_id)).await;
+        assert!(stream.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .opts(vec!["--output", "json"])
+            .arg("stream")
+            .arg("list")
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let command_state = command_state.success();
+        let stdout = String::from_utf8_lossy(&command_state.get_output().stdout);
+        let streams: Vec<Stream> =
+            serde_json::from_str(&stdout).expect("Output is not a valid JSON list of streams");
+        assert_eq!(streams.len(), 1);
+        assert_eq!(streams[0].id, self.stream_id);
+        assert_eq!(streams[0].name, self.name);
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_print_json_only() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestJsonOutputCmd {
+            stream_id: 1,
+            name: String::from("scripts"),
+        })
+        .await;
+}
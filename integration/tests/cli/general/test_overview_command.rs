@@ -33,12 +33,16 @@ Commands:
   me               get current client info
   stats            get iggy server statistics
   snapshot         collect iggy server troubleshooting data
+  log-level        change iggy server log level at runtime
+  background-io    change iggy server background IO rate at runtime
   pat              personal access token operations
   user             user operations [aliases: u]
   client           client operations [aliases: c]
   consumer-group   consumer group operations [aliases: g]
   consumer-offset  consumer offset operations [aliases: o]
   message          message operations [aliases: m]
+  export           export messages of the topic to the file
+  import           import messages from the file to the topic
   context          context operations [aliases: ctx]
   login            login to Iggy server [aliases: li]
   logout           logout from Iggy server [aliases: lo]
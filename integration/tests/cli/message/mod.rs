@@ -1,5 +1,9 @@
+mod test_message_copy_command;
+mod test_message_export_import_command;
 mod test_message_flush_command;
 mod test_message_help_command;
+mod test_message_key_command;
+mod test_message_peek_command;
 mod test_message_poll_command;
 mod test_message_poll_to_file_command;
 mod test_message_reply_via_file;
@@ -0,0 +1,319 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::diff;
+use serial_test::parallel;
+use std::str::FromStr;
+
+const STAGING_TOPIC_NAME: &str = "staging";
+
+struct TestMessageCopyCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages_count: u32,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    from: u64,
+    to: u64,
+}
+
+impl TestMessageCopyCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: &str,
+        topic_id: u32,
+        topic_name: &str,
+        messages_count: u32,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        from: u64,
+        to: u64,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name: stream_name.to_string(),
+            topic_id,
+            topic_name: topic_name.to_string(),
+            messages_count,
+            using_stream_id,
+            using_topic_id,
+            from,
+            to,
+        }
+    }
+
+    fn get_stream_id(&self) -> String {
+        match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        }
+    }
+
+    fn get_topic_id(&self) -> String {
+        match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        vec![
+            "--from".into(),
+            format!("{}", self.from),
+            "--to".into(),
+            format!("{}", self.to),
+            self.get_stream_id(),
+            self.get_topic_id(),
+            "1".into(),
+            self.get_stream_id(),
+            STAGING_TOPIC_NAME.into(),
+            "1".into(),
+        ]
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageCopyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let stream_id = Identifier::from_str(&self.stream_name).unwrap();
+        for (topic_id, topic_name) in [
+            (self.topic_id, self.topic_name.as_str()),
+            (self.topic_id + 1, STAGING_TOPIC_NAME),
+        ] {
+            let topic = client
+                .create_topic(
+                    &stream_id,
+                    topic_name,
+                    1,
+                    Default::default(),
+                    None,
+                    Some(topic_id),
+                    IggyExpiry::NeverExpire,
+                    MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
+                    TopicOverrides::default(),
+                )
+                .await;
+            assert!(topic.is_ok());
+        }
+
+        let mut messages = (0..self.messages_count)
+            .map(|index| Message::from_str(&format!("message {index}")).unwrap())
+            .collect::<Vec<_>>();
+        let send_status = client
+            .send_messages(
+                &stream_id,
+                &Identifier::from_str(&self.topic_name).unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("copy")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = self.get_stream_id();
+        let topic_id = self.get_topic_id();
+        let source_part = format!(
+            "from topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1) to topic with ID: {STAGING_TOPIC_NAME} and stream with ID: {stream_id} (partition with ID: 1)"
+        );
+        let copied_count = self.to - self.from;
+        let copied_messages = match copied_count {
+            1 => "1 message".into(),
+            count => format!("{count} messages"),
+        };
+        let message = format!(
+            "Executing copy messages with offset from: {} to: {} {source_part}\nCopied {copied_messages} {source_part}\n",
+            self.from, self.to
+        );
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream_id = Identifier::from_str(&self.stream_name).unwrap();
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &Identifier::from_str(STAGING_TOPIC_NAME).unwrap(),
+                Some(1),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                self.messages_count,
+                false,
+            )
+            .await;
+        assert!(polled_messages.is_ok());
+        let polled_messages = polled_messages.unwrap();
+        assert_eq!(polled_messages.messages.len() as u64, self.to - self.from);
+        assert_eq!(
+            polled_messages.messages[0].payload,
+            format!("message {}", self.from)
+        );
+
+        let stream = client.delete_stream(&stream_id).await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_parameters: Vec<(TestStreamId, TestTopicId, u64, u64)> = vec![
+        (TestStreamId::Numeric, TestTopicId::Numeric, 0, 10),
+        (TestStreamId::Numeric, TestTopicId::Named, 2, 6),
+        (TestStreamId::Named, TestTopicId::Numeric, 5, 10),
+        (TestStreamId::Named, TestTopicId::Named, 9, 10),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, from, to) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessageCopyCmd::new(
+                1,
+                "stream",
+                1,
+                "topic",
+                10,
+                using_stream_id,
+                using_topic_id,
+                from,
+                to,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "copy", "--help"],
+            format!(
+                r#"Copy range of messages from given topic ID and given stream ID to another topic
+
+Command copies the messages with the offsets (or timestamps) within
+the range from (inclusive) to (exclusive) of the source partition
+to the target partition, e.g. to replay a subset of the traffic
+into a staging topic. The message IDs and headers are preserved,
+and the origin of each message is recorded in the copied-from header.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message copy --from 0 --to 100 1 2 1 1 3 1
+ iggy message copy --from 10 --to 20 stream topic 1 stream staging 1
+ iggy message copy --timestamps --from 1700000000000000 --to 1700000060000000 stream topic 1 staging topic 1
+
+{USAGE_PREFIX} message copy [OPTIONS] --from <FROM> --to <TO> <SOURCE_STREAM_ID> <SOURCE_TOPIC_ID> <SOURCE_PARTITION_ID> <TARGET_STREAM_ID> <TARGET_TOPIC_ID> <TARGET_PARTITION_ID>
+
+Arguments:
+  <SOURCE_STREAM_ID>
+          ID of the stream from which messages will be copied
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <SOURCE_TOPIC_ID>
+          ID of the topic from which messages will be copied
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <SOURCE_PARTITION_ID>
+          Partition ID from which messages will be copied
+
+  <TARGET_STREAM_ID>
+          ID of the stream to which messages will be copied
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TARGET_TOPIC_ID>
+          ID of the topic to which messages will be copied
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <TARGET_PARTITION_ID>
+          Partition ID to which messages will be copied
+
+Options:
+  -f, --from <FROM>
+          Start of the range of offsets (or timestamps), inclusive
+
+  -t, --to <TO>
+          End of the range of offsets (or timestamps), exclusive
+
+      --timestamps
+          Treat the range as message timestamps (in microseconds) instead of offsets
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "copy", "-h"],
+            format!(
+                r#"Copy range of messages from given topic ID and given stream ID to another topic
+
+{USAGE_PREFIX} message copy [OPTIONS] --from <FROM> --to <TO> <SOURCE_STREAM_ID> <SOURCE_TOPIC_ID> <SOURCE_PARTITION_ID> <TARGET_STREAM_ID> <TARGET_TOPIC_ID> <TARGET_PARTITION_ID>
+
+Arguments:
+  <SOURCE_STREAM_ID>     ID of the stream from which messages will be copied
+  <SOURCE_TOPIC_ID>      ID of the topic from which messages will be copied
+  <SOURCE_PARTITION_ID>  Partition ID from which messages will be copied
+  <TARGET_STREAM_ID>     ID of the stream to which messages will be copied
+  <TARGET_TOPIC_ID>      ID of the topic to which messages will be copied
+  <TARGET_PARTITION_ID>  Partition ID to which messages will be copied
+
+Options:
+  -f, --from <FROM>  Start of the range of offsets (or timestamps), inclusive
+  -t, --to <TO>      End of the range of offsets (or timestamps), exclusive
+      --timestamps   Treat the range as message timestamps (in microseconds) instead of offsets
+  -h, --help         Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -0,0 +1,294 @@
+use crate::cli::common::{IggyCmdCommand, IggyCmdTest, IggyCmdTestCase};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use bytes::Bytes;
+use iggy::client::Client;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::path::Path;
+use std::str::FromStr;
+
+const PARTITIONS_COUNT: u32 = 2;
+
+async fn create_topic(client: &dyn Client, stream_name: &str, topic_name: &str) {
+    let stream = client.create_stream(stream_name, None).await;
+    assert!(stream.is_ok());
+
+    let topic = client
+        .create_topic(
+            &Identifier::from_str(stream_name).unwrap(),
+            topic_name,
+            PARTITIONS_COUNT,
+            Default::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await;
+    assert!(topic.is_ok());
+}
+
+fn partition_payload(partition_id: u32, message: &str) -> String {
+    format!("{message} (partition {partition_id})")
+}
+
+struct TestMessageExportCmd<'a> {
+    stream_name: String,
+    topic_name: String,
+    messages: Vec<&'a str>,
+    format: &'static str,
+    from_offset: u64,
+    to_offset: u64,
+    output_file: String,
+}
+
+impl<'a> TestMessageExportCmd<'a> {
+    fn new(
+        stream_name: &str,
+        topic_name: &str,
+        messages: &[&'a str],
+        format: &'static str,
+        from_offset: u64,
+        to_offset: u64,
+        output_file: &str,
+    ) -> Self {
+        Self {
+            stream_name: stream_name.into(),
+            topic_name: topic_name.into(),
+            messages: messages.to_owned(),
+            format,
+            from_offset,
+            to_offset,
+            output_file: output_file.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageExportCmd<'_> {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        create_topic(client, &self.stream_name, &self.topic_name).await;
+
+        for partition_id in 1..=PARTITIONS_COUNT {
+            let mut messages = self
+                .messages
+                .iter()
+                .map(|message| {
+                    let payload = Bytes::from(partition_payload(partition_id, message));
+                    Message::new(None, payload, None)
+                })
+                .collect::<Vec<_>>();
+
+            let send_status = client
+                .send_messages(
+                    &Identifier::from_str(&self.stream_name).unwrap(),
+                    &Identifier::from_str(&self.topic_name).unwrap(),
+                    &Partitioning::partition_id(partition_id),
+                    &mut messages,
+                )
+                .await;
+            assert!(send_status.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("export")
+            .arg("--format")
+            .arg(self.format)
+            .arg("--from-offset")
+            .arg(format!("{}", self.from_offset))
+            .arg("--to-offset")
+            .arg(format!("{}", self.to_offset))
+            .arg("--batch-size")
+            .arg("2")
+            .arg(self.stream_name.clone())
+            .arg(self.topic_name.clone())
+            .arg(self.output_file.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message_prefix = format!(
+            "Executing export messages from topic with ID: {} and stream with ID: {} to {} file: {}\n",
+            self.topic_name, self.stream_name, self.format, self.output_file
+        );
+        let partition_messages = self.to_offset - self.from_offset + 1;
+        let exported_messages = partition_messages * PARTITIONS_COUNT as u64;
+
+        command_state
+            .success()
+            .stdout(starts_with(message_prefix))
+            .stdout(contains(format!(
+                "Exported {partition_messages} messages from partition with ID: 1"
+            )))
+            .stdout(contains(format!(
+                "Exported {partition_messages} messages from partition with ID: 2"
+            )))
+            .stdout(contains(format!(
+                "Exported {exported_messages} messages of total size"
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream = client
+            .delete_stream(&Identifier::from_str(&self.stream_name).unwrap())
+            .await;
+        assert!(stream.is_ok());
+
+        assert!(Path::new(&self.output_file).is_file());
+    }
+}
+
+struct TestMessageImportCmd {
+    stream_name: String,
+    topic_name: String,
+    expected_messages: Vec<String>,
+    format: &'static str,
+    input_file: String,
+}
+
+impl TestMessageImportCmd {
+    fn new(
+        stream_name: &str,
+        topic_name: &str,
+        expected_messages: &[&str],
+        format: &'static str,
+        input_file: &str,
+    ) -> Self {
+        Self {
+            stream_name: stream_name.into(),
+            topic_name: topic_name.into(),
+            expected_messages: expected_messages.iter().map(|m| m.to_string()).collect(),
+            format,
+            input_file: input_file.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageImportCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        create_topic(client, &self.stream_name, &self.topic_name).await;
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("import")
+            .arg("--format")
+            .arg(self.format)
+            .arg(self.stream_name.clone())
+            .arg(self.topic_name.clone())
+            .arg(self.input_file.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let message_prefix = format!(
+            "Executing import messages to topic with ID: {} and stream with ID: {} from {} file: {}\n",
+            self.topic_name, self.stream_name, self.format, self.input_file
+        );
+        let imported_messages = self.expected_messages.len() * PARTITIONS_COUNT as usize;
+
+        command_state
+            .success()
+            .stdout(starts_with(message_prefix))
+            .stdout(contains(format!(
+                "Imported {imported_messages} messages (0 skipped) to topic with ID: {} and stream with ID: {}",
+                self.topic_name, self.stream_name
+            )));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let stream_id = Identifier::from_str(&self.stream_name).unwrap();
+        let topic_id = Identifier::from_str(&self.topic_name).unwrap();
+
+        for partition_id in 1..=PARTITIONS_COUNT {
+            let polled_messages = client
+                .poll_messages(
+                    &stream_id,
+                    &topic_id,
+                    Some(partition_id),
+                    &Consumer::default(),
+                    &PollingStrategy::offset(0),
+                    100,
+                    false,
+                )
+                .await
+                .unwrap();
+
+            let payloads = polled_messages
+                .messages
+                .iter()
+                .map(|message| String::from_utf8(message.payload.to_vec()).unwrap())
+                .collect::<Vec<_>>();
+            let expected_payloads = self
+                .expected_messages
+                .iter()
+                .map(|message| partition_payload(partition_id, message))
+                .collect::<Vec<_>>();
+            assert_eq!(payloads, expected_payloads);
+        }
+
+        let stream = client.delete_stream(&stream_id).await;
+        assert!(stream.is_ok());
+
+        let file_removal = std::fs::remove_file(&self.input_file);
+        assert!(file_removal.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<&str> = vec![
+        "Lorem ipsum dolor sit amet, consectetur adipiscing elit",
+        "sed do eiusmod tempor incididunt ut labore et dolore magna aliqua",
+        "Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris",
+        "nisi ut aliquip ex ea commodo consequat",
+        "Duis aute irure dolor in reprehenderit in voluptate velit esse",
+    ];
+
+    iggy_cmd_test.setup().await;
+    for format in ["json", "binary"] {
+        let temp_file = tempfile::Builder::new().tempfile().unwrap();
+        let temp_path = temp_file.path().to_path_buf();
+        temp_file.close().unwrap();
+        let temp_path_str = temp_path.to_str().unwrap();
+
+        iggy_cmd_test
+            .execute_test(TestMessageExportCmd::new(
+                "source_stream",
+                "source_topic",
+                &test_messages,
+                format,
+                1,
+                3,
+                temp_path_str,
+            ))
+            .await;
+
+        iggy_cmd_test
+            .execute_test(TestMessageImportCmd::new(
+                "target_stream",
+                "target_topic",
+                &test_messages[1..=3],
+                format,
+                temp_path_str,
+            ))
+            .await;
+    }
+}
@@ -18,6 +18,9 @@ Commands:
   send   Send messages to given topic ID and given stream ID [aliases: s]
   poll   Poll messages from given topic ID and given stream ID [aliases: p]
   flush  Flush messages from given topic ID and given stream ID [aliases: f]
+  peek   Peek messages from given topic ID and given stream ID for debugging [aliases: pk]
+  copy   Copy range of messages from given topic ID and given stream ID to another topic [aliases: cp]
+  key    Get the latest message for given key from given topic ID and given stream ID [aliases: k]
   help   Print this message or the help of the given subcommand(s)
 
 Options:
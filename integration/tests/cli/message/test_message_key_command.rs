@@ -0,0 +1,307 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+
+struct TestMessageKeyCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages: Vec<(u128, String)>,
+    key: u128,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestMessageKeyCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: &str,
+        topic_id: u32,
+        topic_name: &str,
+        messages: &[(u128, &str)],
+        key: u128,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name: stream_name.to_string(),
+            topic_id,
+            topic_name: topic_name.to_string(),
+            messages: messages
+                .iter()
+                .map(|(key, payload)| (*key, payload.to_string()))
+                .collect(),
+            key,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push("1".to_string());
+        command.push(format!("{}", self.key));
+
+        command
+    }
+
+    fn expected_message(&self) -> Option<(usize, &str)> {
+        self.messages
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (key, _))| *key == self.key)
+            .map(|(offset, (_, payload))| (offset, payload.as_str()))
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessageKeyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Compact,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = self
+            .messages
+            .iter()
+            .map(|(key, payload)| Message::new(Some(*key), payload.clone().into(), None))
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("key")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message_prefix = format!(
+            "Executing get message with key: {} from topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1)\n",
+            self.key
+        );
+        let status = command_state.success().stdout(starts_with(message_prefix));
+
+        match self.expected_message() {
+            Some((offset, payload)) => {
+                status
+                    .stdout(contains(format!(
+                        "Found message with key: {} in topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1, offset: {offset})\n",
+                        self.key
+                    )))
+                    .stdout(contains(payload));
+            }
+            None => {
+                status.stdout(contains(format!(
+                    "No message with key: {} found in topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1)\n",
+                    self.key
+                )));
+            }
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let messages = [
+        (1, "first value"),
+        (2, "other value"),
+        (1, "latest value"),
+        (3, "another value"),
+    ];
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestMessageKeyCmd::new(
+            1,
+            "stream",
+            2,
+            "topic",
+            &messages,
+            1,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMessageKeyCmd::new(
+            3,
+            "state",
+            4,
+            "store",
+            &messages,
+            3,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestMessageKeyCmd::new(
+            5,
+            "missing",
+            6,
+            "keys",
+            &messages,
+            42,
+            TestStreamId::Numeric,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "key", "--help"],
+            format!(
+                r#"Get the latest message for given key from given topic ID and given stream ID
+
+Command returns the latest message with the given key (message ID)
+in the partition of the compacted topic, without storing or using
+any consumer offsets. Nothing is returned if the latest message
+for the key is a tombstone.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message key 1 2 1 42
+ iggy message key stream topic 1 42
+
+{USAGE_PREFIX} message key <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <KEY>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream from which message will be read
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic from which message will be read
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID from which message will be read
+
+  <KEY>
+          Key (message ID) of the message
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "key", "-h"],
+            format!(
+                r#"Get the latest message for given key from given topic ID and given stream ID
+
+{USAGE_PREFIX} message key <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <KEY>
+
+Arguments:
+  <STREAM_ID>     ID of the stream from which message will be read
+  <TOPIC_ID>      ID of the topic from which message will be read
+  <PARTITION_ID>  Partition ID from which message will be read
+  <KEY>           Key (message ID) of the message
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
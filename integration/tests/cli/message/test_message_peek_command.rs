@@ -0,0 +1,346 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::messages::peek_messages::PeekKind;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+struct TestMessagePeekCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    messages: Vec<String>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+    kind: PeekKind,
+    count: usize,
+}
+
+impl TestMessagePeekCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: &str,
+        topic_id: u32,
+        topic_name: &str,
+        messages: &[&str],
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+        kind: PeekKind,
+        count: usize,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name: stream_name.to_string(),
+            topic_id,
+            topic_name: topic_name.to_string(),
+            messages: messages.iter().map(|s| s.to_string()).collect(),
+            using_stream_id,
+            using_topic_id,
+            kind,
+            count,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = vec!["-m".to_string(), format!("{}", self.count)];
+
+        match self.kind {
+            PeekKind::First => command.push("--first".to_string()),
+            PeekKind::Last => command.push("--last".to_string()),
+            PeekKind::Random => command.push("--random".to_string()),
+        }
+
+        command.extend(match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        });
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push("1".to_string());
+
+        command
+    }
+
+    fn expected_messages(&self) -> &[String] {
+        match self.kind {
+            PeekKind::Last => &self.messages[self.messages.len() - self.count..],
+            _ => &self.messages[..self.count],
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestMessagePeekCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, self.stream_id.into())
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = self
+            .messages
+            .iter()
+            .map(|payload| {
+                let headers = HashMap::from([(
+                    HeaderKey::new("source").unwrap(),
+                    HeaderValue::from_str("cli").unwrap(),
+                )]);
+                Message::new(None, payload.clone().into(), Some(headers))
+            })
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("message")
+            .arg("peek")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message_prefix = format!(
+            "Executing peek {} {} messages from topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1)\n",
+            self.kind, self.count
+        );
+        let message_count = match self.count {
+            1 => "1 message".into(),
+            count => format!("{} messages", count),
+        };
+        let message_peeked = format!(
+            "Peeked {message_count} from topic with ID: {topic_id} and stream with ID: {stream_id} (partition with ID: 1, current offset: {})\n",
+            self.messages.len() - 1
+        );
+
+        let mut status = command_state
+            .success()
+            .stdout(starts_with(message_prefix))
+            .stdout(contains(message_peeked))
+            .stdout(contains("source: string: cli"));
+
+        for message in self.expected_messages() {
+            status = status.stdout(contains(message.as_str()));
+        }
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let consumer_offset = client
+            .get_consumer_offset(
+                &Default::default(),
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                Some(1),
+            )
+            .await;
+        assert!(consumer_offset
+            .is_ok_and(|offset| offset.is_none_or(|offset| offset.stored_offset == 0)));
+
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    let test_messages: Vec<&str> = vec![
+        "Lorem ipsum dolor sit amet",
+        "consectetur adipiscing elit",
+        "sed do eiusmod tempor incididunt",
+        "ut labore et dolore magna aliqua",
+        "Ut enim ad minim veniam",
+    ];
+
+    let test_parameters = vec![
+        (
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            PeekKind::First,
+            2,
+        ),
+        (TestStreamId::Numeric, TestTopicId::Named, PeekKind::Last, 3),
+        (
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+            PeekKind::First,
+            5,
+        ),
+        (TestStreamId::Named, TestTopicId::Named, PeekKind::Last, 1),
+    ];
+
+    iggy_cmd_test.setup().await;
+    for (using_stream_id, using_topic_id, kind, count) in test_parameters {
+        iggy_cmd_test
+            .execute_test(TestMessagePeekCmd::new(
+                1,
+                "stream",
+                1,
+                "topic",
+                &test_messages,
+                using_stream_id,
+                using_topic_id,
+                kind,
+                count,
+            ))
+            .await;
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "peek", "--help"],
+            format!(
+                r#"Peek messages from given topic ID and given stream ID for debugging
+
+Command returns the first, the last or the random messages
+of the partition with the decoded headers, sizes and timestamps,
+without storing or using any consumer offsets.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples:
+ iggy message peek 1 2 1
+ iggy message peek --last -m 5 stream 2 1
+ iggy message peek --random -m 10 1 topic 1
+ iggy message peek stream topic 1
+
+{USAGE_PREFIX} message peek [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <STREAM_ID>
+          ID of the stream from which messages will be peeked
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          ID of the topic from which messages will be peeked
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID from which messages will be peeked
+
+Options:
+  -m, --message-count <MESSAGE_COUNT>
+          Number of messages to peek, max 1000
+{CLAP_INDENT}
+          [default: 10]
+
+  -f, --first
+          Peek the first messages still available in the partition (default)
+
+  -l, --last
+          Peek the last messages in the partition
+
+  -r, --random
+          Peek the messages at random offsets of the partition
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["message", "peek", "-h"],
+            format!(
+                r#"Peek messages from given topic ID and given stream ID for debugging
+
+{USAGE_PREFIX} message peek [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
+
+Arguments:
+  <STREAM_ID>     ID of the stream from which messages will be peeked
+  <TOPIC_ID>      ID of the topic from which messages will be peeked
+  <PARTITION_ID>  Partition ID from which messages will be peeked
+
+Options:
+  -m, --message-count <MESSAGE_COUNT>  Number of messages to peek, max 1000 [default: 10]
+  -f, --first                          Peek the first messages still available in the partition (default)
+  -l, --last                           Peek the last messages in the partition
+  -r, --random                         Peek the messages at random offsets of the partition
+  -h, --help                           Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
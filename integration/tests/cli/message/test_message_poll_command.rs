@@ -6,10 +6,12 @@ use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use bytes::Bytes;
 use iggy::client::Client;
-use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
+use iggy::messages::poll_messages::{PollingDirection, PollingKind, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -80,6 +82,10 @@ impl TestMessagePollCmd {
             PollingKind::Last => vec!["--last".into()],
         };
 
+        if self.strategy.direction == PollingDirection::Backward {
+            command.push("--backward".into());
+        }
+
         command.extend(vec![
             "--message-count".into(),
             format!("{}", self.message_count),
@@ -123,6 +129,8 @@ impl IggyCmdTestCase for TestMessagePollCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -185,6 +193,14 @@ impl IggyCmdTestCase for TestMessagePollCmd {
 
         // Check if messages are printed based on the strategy
         match self.strategy.kind {
+            PollingKind::Offset if self.strategy.direction == PollingDirection::Backward => {
+                self.messages
+                    .iter()
+                    .take(self.strategy.value as usize + 1)
+                    .rev()
+                    .take(self.message_count)
+                    .fold(status, |status, message| status.stdout(contains(message)));
+            }
             PollingKind::Offset => {
                 self.messages
                     .iter()
@@ -303,6 +319,22 @@ pub async fn should_be_successful() {
             TestTopicId::Named,
             false,
         ),
+        (
+            3,
+            3,
+            PollingStrategy::offset(6).direction(PollingDirection::Backward),
+            TestStreamId::Named,
+            TestTopicId::Named,
+            false,
+        ),
+        (
+            4,
+            2,
+            PollingStrategy::last().direction(PollingDirection::Backward),
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+            false,
+        ),
     ];
 
     iggy_cmd_test.setup().await;
@@ -348,6 +380,7 @@ Examples:
  iggy message poll --offset 0 stream 2 1
  iggy message poll --offset 0 1 topic 1
  iggy message poll --offset 0 stream topic 1
+ iggy message poll --last --backward -m 10 stream topic 1
 
 {USAGE_PREFIX} message poll [OPTIONS] <--offset <OFFSET>|--first|--last|--next> <STREAM_ID> <TOPIC_ID> <PARTITION_ID>
 
@@ -371,6 +404,15 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -b, --max-bytes <MAX_BYTES>
+          Maximum size of polled messages in bytes
+{CLAP_INDENT}
+          Polling stops when either the message count or the maximum
+          size is reached, 0 means no size limit. At least one message
+          is returned even if it is bigger than the maximum size.
+{CLAP_INDENT}
+          [default: 0]
+
   -a, --auto-commit
           Auto commit offset
 {CLAP_INDENT}
@@ -394,6 +436,14 @@ Options:
           Start polling after the last polled message based
           on the stored consumer offset
 
+      --backward
+          Poll messages backward from the starting point
+{CLAP_INDENT}
+          Messages with the offsets lower than or equal to the starting one
+          are returned from the newest to the oldest, e.g. to page back
+          from the last message. Cannot be used with the next messages
+          or the auto commit.
+
   -c, --consumer <CONSUMER>
           Regular consumer which will poll messages
 {CLAP_INDENT}
@@ -445,11 +495,13 @@ Arguments:
 
 Options:
   -m, --message-count <MESSAGE_COUNT>  Number of messages to poll [default: 1]
+  -b, --max-bytes <MAX_BYTES>          Maximum size of polled messages in bytes [default: 0]
   -a, --auto-commit                    Auto commit offset
   -o, --offset <OFFSET>                Polling strategy - offset to start polling messages from
   -f, --first                          Polling strategy - start polling from the first message in the partition
   -l, --last                           Polling strategy - start polling from the last message in the partition
   -n, --next                           Polling strategy - start polling from the next message
+      --backward                       Poll messages backward from the starting point
   -c, --consumer <CONSUMER>            Regular consumer which will poll messages [default: 1]
   -s, --show-headers                   Include the message headers in the output
       --output-file <OUTPUT_FILE>      Store polled message into file in binary format
@@ -1,3 +1,7 @@
 mod test_partition_create_command;
 mod test_partition_delete_command;
+mod test_partition_get_command;
 mod test_partition_help_command;
+mod test_partition_histogram_command;
+mod test_partition_offset_command;
+mod test_partition_seal_command;
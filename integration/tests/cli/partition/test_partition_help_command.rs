@@ -15,11 +15,19 @@ pub async fn should_help_match() {
 {USAGE_PREFIX} partition <COMMAND>
 
 Commands:
-  create  Create partitions for the specified topic ID
-          and stream ID based on the given count. [aliases: c]
-  delete  Delete partitions for the specified topic ID
-          and stream ID based on the given count. [aliases: d]
-  help    Print this message or the help of the given subcommand(s)
+  create     Create partitions for the specified topic ID
+             and stream ID based on the given count. [aliases: c]
+  delete     Delete partitions for the specified topic ID
+             and stream ID based on the given count. [aliases: d]
+  get        Get partition details for the specified partition ID,
+             topic ID and stream ID, including its segments. [aliases: g]
+  offset     Get the offset of the first message with the timestamp at or after
+             the given one for the specified partition ID, topic ID and stream ID. [aliases: o]
+  histogram  Get the count and size of the messages per time bucket within the given
+             time range for the specified partition ID, topic ID and stream ID. [aliases: hist]
+  seal       Seal the specified partition ID for the given topic ID and stream ID
+             by closing its active segment at the current offset. [aliases: s]
+  help       Print this message or the help of the given subcommand(s)
 
 Options:
   -h, --help  Print help
@@ -0,0 +1,306 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::{contains, starts_with};
+use serial_test::parallel;
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u64 = 10;
+
+struct TestPartitionHistogramCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    partition_id: u32,
+    from: u64,
+    to: u64,
+    bucket: Option<String>,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestPartitionHistogramCmd {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket: Option<String>,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            from,
+            to,
+            bucket,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+
+        command.push(format!("{}", self.partition_id));
+        command.push(format!("{}", self.from));
+        command.push(format!("{}", self.to));
+        if let Some(bucket) = &self.bucket {
+            command.push(String::from("--bucket"));
+            command.push(bucket.clone());
+        }
+
+        command
+    }
+
+    fn get_bucket_size(&self) -> IggyDuration {
+        IggyDuration::from_str(self.bucket.as_deref().unwrap_or("1m")).unwrap()
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestPartitionHistogramCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                self.partition_id,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let mut messages = (1..=MESSAGES_COUNT)
+            .filter_map(|id| Message::from_str(format!("Test message {id}").as_str()).ok())
+            .collect::<Vec<_>>();
+
+        let send_status = client
+            .send_messages(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+                &Partitioning::partition_id(self.partition_id),
+                &mut messages,
+            )
+            .await;
+        assert!(send_status.is_ok());
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("partition")
+            .arg("histogram")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let start_message = format!(
+            "Executing get messages histogram from: {} to: {} with bucket size: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.from,
+            self.to,
+            self.get_bucket_size(),
+            self.partition_id,
+            topic_id,
+            stream_id
+        );
+
+        let bucket_size = self.get_bucket_size().as_micros();
+        let mut command_state = command_state.success().stdout(starts_with(start_message));
+        let mut timestamp = self.from;
+        while timestamp < self.to {
+            command_state = command_state.stdout(contains(format!("| {timestamp} |")));
+            timestamp += bucket_size;
+        }
+        command_state.stdout(contains(format!("| {MESSAGES_COUNT} ")));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        let stream = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+    let now = IggyTimestamp::now().as_micros();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestPartitionHistogramCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            1,
+            now - 60_000_000,
+            now + 3_600_000_000,
+            Some(String::from("1h")),
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestPartitionHistogramCmd::new(
+            2,
+            String::from("stream"),
+            3,
+            String::from("topic"),
+            2,
+            now - 60_000_000,
+            now + 180_000_000,
+            None,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "histogram", "--help"],
+            format!(
+                r#"Get the count and size of the messages per time bucket within the given
+time range for the specified partition ID, topic ID and stream ID.
+
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+Time range is specified in microseconds since the Unix epoch,
+from the inclusive start to the exclusive end
+
+Examples
+ iggy partition histogram 1 1 1 1700000000000000 1700003600000000
+ iggy partition histogram prod 2 3 1700000000000000 1700003600000000 --bucket 5m
+ iggy partition histogram test sensor 2 1700000000000000 1700000060000000 -b 1s
+
+{USAGE_PREFIX} partition histogram [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <FROM> <TO>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID to get histogram
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to get histogram
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <PARTITION_ID>
+          Partition ID to get histogram
+
+  <FROM>
+          Start of the time range (in microseconds), inclusive
+
+  <TO>
+          End of the time range (in microseconds), exclusive
+
+Options:
+  -b, --bucket <BUCKET>
+          Size of the single time bucket in human readable format like 1s, 5m or 1h
+{CLAP_INDENT}
+          [default: 1m]
+
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["partition", "histogram", "-h"],
+            format!(
+                r#"Get the count and size of the messages per time bucket within the given
+time range for the specified partition ID, topic ID and stream ID.
+
+{USAGE_PREFIX} partition histogram [OPTIONS] <STREAM_ID> <TOPIC_ID> <PARTITION_ID> <FROM> <TO>
+
+Arguments:
+  <STREAM_ID>     Stream ID to get histogram
+  <TOPIC_ID>      Topic ID to get histogram
+  <PARTITION_ID>  Partition ID to get histogram
+  <FROM>          Start of the time range (in microseconds), inclusive
+  <TO>            End of the time range (in microseconds), exclusive
+
+Options:
+  -b, --bucket <BUCKET>  Size of the single time bucket in human readable format like 1s, 5m or 1h [default: 1m]
+  -h, --help             Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
@@ -100,6 +100,7 @@ Examples:
  iggy stream list
  iggy stream list --list-mode table
  iggy stream list -l table
+ iggy stream list --prefix prod --offset 100 --limit 100
 
 {USAGE_PREFIX} stream list [OPTIONS]
 
@@ -110,6 +111,17 @@ Options:
           [default: table]
           [possible values: table, list]
 
+      --offset <OFFSET>
+          Number of streams ordered by ID to skip
+{CLAP_INDENT}
+          [default: 0]
+
+      --limit <LIMIT>
+          Maximum number of streams to list
+
+      --prefix <PREFIX>
+          List only streams which names start with given prefix
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -133,6 +145,9 @@ pub async fn should_short_help_match() {
 
 Options:
   -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+      --offset <OFFSET>        Number of streams ordered by ID to skip [default: 0]
+      --limit <LIMIT>          Maximum number of streams to list
+      --prefix <PREFIX>        List only streams which names start with given prefix
   -h, --help                   Print help (see more with '--help')
 "#,
             ),
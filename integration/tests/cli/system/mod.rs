@@ -1,6 +1,8 @@
+mod test_background_io_command;
 // Disable tests due to missing keyring on macOS until #794 is implemented
 #[cfg(not(target_os = "macos"))]
 mod test_cli_session_scenario;
+mod test_log_level_command;
 mod test_login_cmd;
 mod test_login_command;
 mod test_logout_cmd;
@@ -0,0 +1,119 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestBackgroundIoCmd {
+    max_rate: String,
+    expected_rate: String,
+}
+
+impl TestBackgroundIoCmd {
+    fn new(max_rate: &str, expected_rate: &str) -> Self {
+        Self {
+            max_rate: max_rate.to_string(),
+            expected_rate: expected_rate.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestBackgroundIoCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("background-io")
+            .arg(self.max_rate.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing set server background IO rate to: {}/s\nServer background IO rate set to: {}/s\n",
+            self.expected_rate, self.expected_rate
+        )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestBackgroundIoCmd::new("50MB", "50.00 MB"))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestBackgroundIoCmd::new("unlimited", "unlimited"))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["background-io", "--help"],
+            format!(
+                r#"change iggy server background IO rate at runtime
+
+Change the maximum rate of the disk IO performed by the background tasks
+(retention, compaction, archiving and recovery) without restarting the server,
+e.g. to protect the latency of the appends and polls during the peak traffic.
+
+Examples:
+ iggy background-io 50MB
+ iggy background-io unlimited
+
+{USAGE_PREFIX} background-io <MAX_RATE>
+
+Arguments:
+  <MAX_RATE>
+          Maximum number of bytes read and written per second by the background tasks
+{CLAP_INDENT}
+          The rate is shared by the retention, compaction, archiving and recovery tasks.
+          Use "0" or "unlimited" to remove the limit.
+          The rate remains active until the server is restarted.
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["background-io", "-h"],
+            format!(
+                r#"change iggy server background IO rate at runtime
+
+{USAGE_PREFIX} background-io <MAX_RATE>
+
+Arguments:
+  <MAX_RATE>  Maximum number of bytes read and written per second by the background tasks
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
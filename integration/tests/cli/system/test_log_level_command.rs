@@ -0,0 +1,112 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestLogLevelCmd {
+    filter: String,
+}
+
+impl TestLogLevelCmd {
+    fn new(filter: &str) -> Self {
+        Self {
+            filter: filter.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestLogLevelCmd {
+    async fn prepare_server_state(&mut self, _client: &dyn Client) {}
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("log-level")
+            .arg(self.filter.clone())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        command_state.success().stdout(diff(format!(
+            "Executing set server log level with filter: {}\nServer log level set with filter: {}\n",
+            self.filter, self.filter
+        )));
+    }
+
+    async fn verify_server_state(&self, _client: &dyn Client) {}
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestLogLevelCmd::new("info,server::streaming=debug"))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["log-level", "--help"],
+            format!(
+                r#"change iggy server log level at runtime
+
+Change the log filter of the server without restarting it,
+e.g. to temporarily enable the trace logs of a specific module.
+
+Examples:
+ iggy log-level debug
+ iggy log-level info,server::streaming::segments=trace
+
+{USAGE_PREFIX} log-level <FILTER>
+
+Arguments:
+  <FILTER>
+          Log filter, either a global level or the levels per module
+{CLAP_INDENT}
+          Available levels are: trace, debug, info, warn, error and off.
+          The filter remains active until the server is restarted.
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+"#,
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["log-level", "-h"],
+            format!(
+                r#"change iggy server log level at runtime
+
+{USAGE_PREFIX} log-level <FILTER>
+
+Arguments:
+  <FILTER>  Log filter, either a global level or the levels per module
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
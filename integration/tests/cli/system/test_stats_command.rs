@@ -6,7 +6,9 @@ use async_trait::async_trait;
 use iggy::cli::system::stats::GetStatsOutput;
 use iggy::client::Client;
 use iggy::identifier::Identifier;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -59,6 +61,8 @@ impl IggyCmdTestCase for TestStatsCmd {
                 Some(1),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -74,13 +78,13 @@ impl IggyCmdTestCase for TestStatsCmd {
                 command_state
                     .success()
                     .stdout(starts_with("Executing stats command\n"))
-                    .stdout(contains("Streams Count            | 1"))
-                    .stdout(contains("Topics Count             | 1"))
-                    .stdout(contains("Partitions Count         | 5"))
-                    .stdout(contains("Segments Count           | 5"))
-                    .stdout(contains("Message Count            | 0"))
-                    .stdout(contains("Clients Count            | 2")) // 2 clients are connected during test
-                    .stdout(contains("Consumer Groups Count    | 0"));
+                    .stdout(contains("Streams Count              | 1"))
+                    .stdout(contains("Topics Count               | 1"))
+                    .stdout(contains("Partitions Count           | 5"))
+                    .stdout(contains("Segments Count             | 5"))
+                    .stdout(contains("Message Count              | 0"))
+                    .stdout(contains("Clients Count              | 2")) // 2 clients are connected during test
+                    .stdout(contains("Consumer Groups Count      | 0"));
             }
             TestStatsCmdOutput::Set(GetStatsOutput::List) => {
                 command_state
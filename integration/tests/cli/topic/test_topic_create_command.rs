@@ -7,6 +7,7 @@ use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::client::Client;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::diff;
@@ -114,12 +115,13 @@ impl IggyCmdTestCase for TestTopicCreateCmd {
         let max_topic_size = self.max_topic_size.to_string();
 
         let replication_factor = self.replication_factor;
+        let cleanup_policy = CleanupPolicy::default();
 
         let message = format!(
             "Executing create topic with name: {topic_name}, {topic_id}, message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}\n\
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: {cleanup_policy} in stream with ID: {stream_id}\n\
             Topic with name: {topic_name}, {topic_id}, partitions count: {partitions_count}, compression algorithm: {compression_algorithm}, message expiry: {message_expiry}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} created in stream with ID: {stream_id}\n",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: {cleanup_policy} created in stream with ID: {stream_id}\n",
         );
 
         command_state.success().stdout(diff(message));
@@ -298,6 +300,25 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic
+{CLAP_INDENT}
+          ("delete" removes whole expired segments, "compact" keeps only the latest message per key)
+{CLAP_INDENT}
+          [default: delete]
+
+      --segment-size <SEGMENT_SIZE>
+          Segment size of the topic partitions, overriding the server default
+
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages which triggers saving them to disk, overriding the server default
+
+      --cache-size <CACHE_SIZE>
+          Cache size of each partition, overriding the server default
+
+      --message-timestamp-type <MESSAGE_TIMESTAMP_TYPE>
+          Message timestamp type ("create_time" or "log_append_time"), overriding the server default
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -326,10 +347,24 @@ Arguments:
   [MESSAGE_EXPIRY]...      Message expiry time in human-readable format like 15days 2min 2s
 
 Options:
-  -t, --topic-id <TOPIC_ID>                      Topic ID to create
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          Max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  Replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -t, --topic-id <TOPIC_ID>
+          Topic ID to create
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          Max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          Replication factor for the topic [default: 1]
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          Cleanup policy for the topic [default: delete]
+      --segment-size <SEGMENT_SIZE>
+          Segment size of the topic partitions, overriding the server default
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          Number of buffered messages which triggers saving them to disk, overriding the server default
+      --cache-size <CACHE_SIZE>
+          Cache size of each partition, overriding the server default
+      --message-timestamp-type <MESSAGE_TIMESTAMP_TYPE>
+          Message timestamp type ("create_time" or "log_append_time"), overriding the server default
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -5,7 +5,9 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -71,6 +73,8 @@ impl IggyCmdTestCase for TestTopicGetCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -103,16 +107,19 @@ impl IggyCmdTestCase for TestTopicGetCmd {
         command_state
             .success()
             .stdout(starts_with(start_message))
-            .stdout(contains(format!("Topic id            | {}", self.topic_id)))
             .stdout(contains(format!(
-                "Topic name          | {}",
+                "Topic id                         | {}",
+                self.topic_id
+            )))
+            .stdout(contains(format!(
+                "Topic name                       | {}",
                 self.topic_name
             )))
-            .stdout(contains("Topic size          | 0"))
-            .stdout(contains("Message expiry      | unlimited"))
-            .stdout(contains("Max topic size      | 10.00 GB"))
-            .stdout(contains("Topic message count | 0"))
-            .stdout(contains("Partitions count    | 1"));
+            .stdout(contains("Topic size                       | 0"))
+            .stdout(contains("Message expiry                   | unlimited"))
+            .stdout(contains("Max topic size                   | 10.00 GB"))
+            .stdout(contains("Topic message count              | 0"))
+            .stdout(contains("Partitions count                 | 1"));
     }
 
     async fn verify_server_state(&self, client: &dyn Client) {
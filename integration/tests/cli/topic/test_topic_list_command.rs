@@ -5,7 +5,9 @@ use crate::cli::common::{
 use assert_cmd::assert::Assert;
 use async_trait::async_trait;
 use iggy::client::Client;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::{contains, starts_with};
 use serial_test::parallel;
@@ -68,6 +70,8 @@ impl IggyCmdTestCase for TestTopicListCmd {
                 Some(self.topic_id),
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -166,6 +170,7 @@ Stream ID can be specified as a stream name or ID
 Examples
  iggy topic list 1
  iggy topic list prod
+ iggy topic list prod --prefix orders --offset 100 --limit 100
 
 {USAGE_PREFIX} topic list [OPTIONS] <STREAM_ID>
 
@@ -182,6 +187,17 @@ Options:
           [default: table]
           [possible values: table, list]
 
+      --offset <OFFSET>
+          Number of topics ordered by ID to skip
+{CLAP_INDENT}
+          [default: 0]
+
+      --limit <LIMIT>
+          Maximum number of topics to list
+
+      --prefix <PREFIX>
+          List only topics which names start with given prefix
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -208,6 +224,9 @@ Arguments:
 
 Options:
   -l, --list-mode <LIST_MODE>  List mode (table or list) [default: table] [possible values: table, list]
+      --offset <OFFSET>        Number of topics ordered by ID to skip [default: 0]
+      --limit <LIMIT>          Maximum number of topics to list
+      --prefix <PREFIX>        List only topics which names start with given prefix
   -h, --help                   Print help (see more with '--help')
 "#,
             ),
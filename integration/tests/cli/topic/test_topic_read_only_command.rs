@@ -0,0 +1,261 @@
+use crate::cli::common::{
+    IggyCmdCommand, IggyCmdTest, IggyCmdTestCase, TestHelpCmd, TestStreamId, TestTopicId,
+    CLAP_INDENT, USAGE_PREFIX,
+};
+use assert_cmd::assert::Assert;
+use async_trait::async_trait;
+use iggy::client::Client;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use predicates::str::diff;
+use serial_test::parallel;
+
+struct TestTopicReadOnlyCmd {
+    stream_id: u32,
+    stream_name: String,
+    topic_id: u32,
+    topic_name: String,
+    read_only: bool,
+    using_stream_id: TestStreamId,
+    using_topic_id: TestTopicId,
+}
+
+impl TestTopicReadOnlyCmd {
+    fn new(
+        stream_id: u32,
+        stream_name: String,
+        topic_id: u32,
+        topic_name: String,
+        read_only: bool,
+        using_stream_id: TestStreamId,
+        using_topic_id: TestTopicId,
+    ) -> Self {
+        Self {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            read_only,
+            using_stream_id,
+            using_topic_id,
+        }
+    }
+
+    fn to_args(&self) -> Vec<String> {
+        let mut command = match self.using_stream_id {
+            TestStreamId::Numeric => vec![format!("{}", self.stream_id)],
+            TestStreamId::Named => vec![self.stream_name.clone()],
+        };
+
+        command.push(match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        });
+        command.push(format!("{}", self.read_only));
+
+        command
+    }
+}
+
+#[async_trait]
+impl IggyCmdTestCase for TestTopicReadOnlyCmd {
+    async fn prepare_server_state(&mut self, client: &dyn Client) {
+        let stream = client
+            .create_stream(&self.stream_name, Some(self.stream_id))
+            .await;
+        assert!(stream.is_ok());
+
+        let topic = client
+            .create_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_name,
+                1,
+                Default::default(),
+                None,
+                Some(self.topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await;
+        assert!(topic.is_ok());
+
+        if !self.read_only {
+            let read_only = client
+                .set_topic_read_only(
+                    &self.stream_id.try_into().unwrap(),
+                    &self.topic_id.try_into().unwrap(),
+                    true,
+                )
+                .await;
+            assert!(read_only.is_ok());
+        }
+    }
+
+    fn get_command(&self) -> IggyCmdCommand {
+        IggyCmdCommand::new()
+            .arg("topic")
+            .arg("read-only")
+            .args(self.to_args())
+            .with_env_credentials()
+    }
+
+    fn verify_command(&self, command_state: Assert) {
+        let stream_id = match self.using_stream_id {
+            TestStreamId::Numeric => format!("{}", self.stream_id),
+            TestStreamId::Named => self.stream_name.clone(),
+        };
+
+        let topic_id = match self.using_topic_id {
+            TestTopicId::Numeric => format!("{}", self.topic_id),
+            TestTopicId::Named => self.topic_name.clone(),
+        };
+
+        let message = format!("Executing set read-only: {} for topic with ID: {} in stream with ID: {}\nTopic with ID: {} in stream with ID: {} set read-only: {}\n",
+                              self.read_only, topic_id, stream_id, topic_id, stream_id, self.read_only);
+
+        command_state.success().stdout(diff(message));
+    }
+
+    async fn verify_server_state(&self, client: &dyn Client) {
+        let topic_state = client
+            .get_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic_state.is_ok());
+        let topic_state = topic_state.unwrap().expect("Topic not found");
+        assert_eq!(topic_state.read_only, self.read_only);
+
+        let topic_delete = client
+            .delete_topic(
+                &self.stream_id.try_into().unwrap(),
+                &self.topic_id.try_into().unwrap(),
+            )
+            .await;
+        assert!(topic_delete.is_ok());
+
+        let stream_delete = client
+            .delete_stream(&self.stream_id.try_into().unwrap())
+            .await;
+        assert!(stream_delete.is_ok());
+    }
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_be_successful() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test.setup().await;
+    iggy_cmd_test
+        .execute_test(TestTopicReadOnlyCmd::new(
+            1,
+            String::from("main"),
+            1,
+            String::from("sync"),
+            true,
+            TestStreamId::Numeric,
+            TestTopicId::Numeric,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicReadOnlyCmd::new(
+            2,
+            String::from("testing"),
+            2,
+            String::from("topic"),
+            false,
+            TestStreamId::Named,
+            TestTopicId::Named,
+        ))
+        .await;
+    iggy_cmd_test
+        .execute_test(TestTopicReadOnlyCmd::new(
+            3,
+            String::from("prod"),
+            1,
+            String::from("named"),
+            true,
+            TestStreamId::Named,
+            TestTopicId::Numeric,
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::help_message();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "read-only", "--help"],
+            format!(
+                r"Set or unset read-only mode for topic with given ID in given stream ID
+
+Messages sent to the read-only topic are rejected, while they can still be polled
+Stream ID can be specified as a stream name or ID
+Topic ID can be specified as a topic name or ID
+
+Examples
+ iggy topic read-only 1 1 true
+ iggy topic read-only prod 2 false
+ iggy topic read-only test debugs true
+
+{USAGE_PREFIX} topic read-only <STREAM_ID> <TOPIC_ID> <READ_ONLY>
+
+Arguments:
+  <STREAM_ID>
+          Stream ID of the topic
+{CLAP_INDENT}
+          Stream ID can be specified as a stream name or ID
+
+  <TOPIC_ID>
+          Topic ID to set read-only mode for
+{CLAP_INDENT}
+          Topic ID can be specified as a topic name or ID
+
+  <READ_ONLY>
+          Whether the topic is read-only
+{CLAP_INDENT}
+          [possible values: true, false]
+
+Options:
+  -h, --help
+          Print help (see a summary with '-h')
+",
+            ),
+        ))
+        .await;
+}
+
+#[tokio::test]
+#[parallel]
+pub async fn should_short_help_match() {
+    let mut iggy_cmd_test = IggyCmdTest::default();
+
+    iggy_cmd_test
+        .execute_test_for_help_command(TestHelpCmd::new(
+            vec!["topic", "read-only", "-h"],
+            format!(
+                r#"Set or unset read-only mode for topic with given ID in given stream ID
+
+{USAGE_PREFIX} topic read-only <STREAM_ID> <TOPIC_ID> <READ_ONLY>
+
+Arguments:
+  <STREAM_ID>  Stream ID of the topic
+  <TOPIC_ID>   Topic ID to set read-only mode for
+  <READ_ONLY>  Whether the topic is read-only [possible values: true, false]
+
+Options:
+  -h, --help  Print help (see more with '--help')
+"#,
+            ),
+        ))
+        .await;
+}
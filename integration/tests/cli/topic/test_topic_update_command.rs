@@ -7,7 +7,9 @@ use async_trait::async_trait;
 use humantime::Duration as HumanDuration;
 use iggy::client::Client;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use predicates::str::diff;
 use serial_test::parallel;
@@ -130,6 +132,8 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
                 Some(self.topic_id),
                 message_expiry,
                 self.max_topic_size,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await;
         assert!(topic.is_ok());
@@ -166,12 +170,13 @@ impl IggyCmdTestCase for TestTopicUpdateCmd {
 
         let replication_factor = self.replication_factor;
         let new_topic_name = &self.topic_new_name;
+        let cleanup_policy = CleanupPolicy::default();
 
         let expected_message = format!("Executing update topic with ID: {topic_id}, name: {new_topic_name}, \
                                 message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {max_topic_size}, \
-                                replication factor: {replication_factor}, in stream with ID: {stream_id}\n\
+                                replication factor: {replication_factor}, cleanup policy: {cleanup_policy}, in stream with ID: {stream_id}\n\
                                 Topic with ID: {topic_id} updated name: {new_topic_name}, updated message expiry: {message_expiry}, \
-                                updated compression algorithm: {compression_algorithm} in stream with ID: {stream_id}\n");
+                                updated compression algorithm: {compression_algorithm}, updated cleanup policy: {cleanup_policy} in stream with ID: {stream_id}\n");
 
         command_state.success().stdout(diff(expected_message));
     }
@@ -407,6 +412,25 @@ Options:
 {CLAP_INDENT}
           [default: 1]
 
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic
+{CLAP_INDENT}
+          ("delete" removes whole expired segments, "compact" keeps only the latest message per key)
+{CLAP_INDENT}
+          [default: delete]
+
+      --segment-size <SEGMENT_SIZE>
+          New segment size of the topic partitions, overriding the server default
+
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          New number of buffered messages which triggers saving them to disk, overriding the server default
+
+      --cache-size <CACHE_SIZE>
+          New cache size of each partition, overriding the server default
+
+      --message-timestamp-type <MESSAGE_TIMESTAMP_TYPE>
+          New message timestamp type ("create_time" or "log_append_time"), overriding the server default
+
   -h, --help
           Print help (see a summary with '-h')
 "#,
@@ -436,9 +460,22 @@ Arguments:
   [MESSAGE_EXPIRY]...      New message expiry time in human-readable format like 15days 2min 2s
 
 Options:
-  -m, --max-topic-size <MAX_TOPIC_SIZE>          New max topic size [default: unlimited]
-  -r, --replication-factor <REPLICATION_FACTOR>  New replication factor for the topic [default: 1]
-  -h, --help                                     Print help (see more with '--help')
+  -m, --max-topic-size <MAX_TOPIC_SIZE>
+          New max topic size [default: unlimited]
+  -r, --replication-factor <REPLICATION_FACTOR>
+          New replication factor for the topic [default: 1]
+  -c, --cleanup-policy <CLEANUP_POLICY>
+          New cleanup policy for the topic [default: delete]
+      --segment-size <SEGMENT_SIZE>
+          New segment size of the topic partitions, overriding the server default
+      --messages-required-to-save <MESSAGES_REQUIRED_TO_SAVE>
+          New number of buffered messages which triggers saving them to disk, overriding the server default
+      --cache-size <CACHE_SIZE>
+          New cache size of each partition, overriding the server default
+      --message-timestamp-type <MESSAGE_TIMESTAMP_TYPE>
+          New message timestamp type ("create_time" or "log_append_time"), overriding the server default
+  -h, --help
+          Print help (see more with '--help')
 "#,
             ),
         ))
@@ -0,0 +1,58 @@
+use assert_cmd::prelude::CommandCargoExt;
+use integration::test_server::TestServer;
+use serial_test::parallel;
+use std::process::Command;
+use tempfile::NamedTempFile;
+
+fn run_conformance(transport: &str, server_addr: &str, script: Option<&str>) -> bool {
+    let mut command = Command::cargo_bin("iggy-conformance").unwrap();
+    command.args(["--transport", transport, "--server-address", server_addr]);
+    if let Some(script) = script {
+        command.args(["--script", script]);
+    }
+    command.output().unwrap().status.success()
+}
+
+#[test]
+#[parallel]
+fn tcp_server_should_conform_to_default_script() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    assert!(run_conformance("tcp", &server_addr, None));
+}
+
+#[test]
+#[parallel]
+fn quic_server_should_conform_to_default_script() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    assert!(run_conformance("quic", &server_addr, None));
+}
+
+#[test]
+#[parallel]
+fn unexpected_response_should_fail_conformance() {
+    let script = NamedTempFile::with_suffix(".toml").unwrap();
+    std::fs::write(
+        script.path(),
+        r#"
+name = "unexpected"
+
+[[step]]
+name = "ping with response"
+code = 1
+response = "00"
+"#,
+    )
+    .unwrap();
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    assert!(!run_conformance(
+        "tcp",
+        &server_addr,
+        Some(script.path().to_str().unwrap())
+    ));
+}
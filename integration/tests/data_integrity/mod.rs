@@ -1 +1,2 @@
 mod verify_after_server_restart;
+mod verify_encryption_at_rest;
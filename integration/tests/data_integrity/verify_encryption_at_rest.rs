@@ -0,0 +1,264 @@
+use iggy::client::{MessageClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{login_root, ClientFactory, IpAddrKind, TestServer},
+};
+use serial_test::parallel;
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+use uuid::Uuid;
+
+const KEY: &str = "/rvT1xP4V8u1EAhk4xDdqzqM2UOPXyy9XYkl4uRShgE=";
+const PAYLOAD: &str = "sensitive-payload";
+const MESSAGES_COUNT: u32 = 10;
+const BATCHES_COUNT: u32 = 5;
+
+#[tokio::test]
+#[parallel]
+async fn should_store_encrypted_payloads_and_decrypt_them_on_poll() {
+    // 1. Start server with the encryption key read from the file
+    let key_path = std::env::temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+    std::fs::write(&key_path, KEY).unwrap();
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_ENCRYPTION_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_ENCRYPTION_PROVIDER".to_string(),
+            "file".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_ENCRYPTION_FILE_PATH".to_string(),
+            key_path.to_str().unwrap().to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client = TcpClientFactory { server_addr }.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+
+    // 2. Send the messages and flush them to disk
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    client.create_stream("stream", Some(1)).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "topic",
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(1),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|_| Message::from_str(PAYLOAD).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    client
+        .flush_unsaved_buffer(&stream_id, &topic_id, 1, true)
+        .await
+        .unwrap();
+
+    // 3. The payloads are transparently decrypted when polled
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for message in polled_messages.messages {
+        assert_eq!(message.payload, PAYLOAD);
+    }
+
+    // 4. The plain payloads are not stored in any file on disk
+    let streams_path = Path::new(test_server.get_local_data_path()).join("streams");
+    assert!(!contains_payload(&streams_path));
+    std::fs::remove_file(&key_path).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn should_reencrypt_closed_segments_after_key_rotation() {
+    // 1. Start server with the small segments and the frequent re-encryption
+    let key_path = std::env::temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+    std::fs::write(&key_path, KEY).unwrap();
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_ENCRYPTION_ENABLED".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_ENCRYPTION_PROVIDER".to_string(),
+            "file".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_ENCRYPTION_FILE_PATH".to_string(),
+            key_path.to_str().unwrap().to_string(),
+        ),
+        ("IGGY_SYSTEM_SEGMENT_SIZE".to_string(), "1 KiB".to_string()),
+        (
+            "IGGY_SYSTEM_SEGMENT_INDEX_INTERVAL".to_string(),
+            "1 KiB".to_string(),
+        ),
+        (
+            "IGGY_DATA_MAINTENANCE_MESSAGES_INTERVAL".to_string(),
+            "1 s".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client = TcpClientFactory { server_addr }.create_client().await;
+    let client = IggyClient::create(client, None, None);
+    login_root(&client).await;
+
+    // 2. Send the messages encrypted with the initial key, closing the segments
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    client.create_stream("stream", Some(1)).await.unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            "topic",
+            1,
+            CompressionAlgorithm::None,
+            None,
+            Some(1),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    send_and_flush(&client, &stream_id, &topic_id, BATCHES_COUNT).await;
+
+    // 3. Rotate the key, the new key is appended to the key file
+    let key_version = client.rotate_encryption_key().await.unwrap();
+    assert_eq!(key_version, 2);
+    let keys = std::fs::read_to_string(&key_path).unwrap();
+    assert_eq!(keys.lines().count(), 2);
+    assert!(keys.lines().last().unwrap().starts_with("2:"));
+
+    // 4. Send the messages encrypted with the rotated key
+    send_and_flush(&client, &stream_id, &topic_id, BATCHES_COUNT).await;
+
+    // 5. The closed segments are re-encrypted in the background
+    let partition_path =
+        Path::new(test_server.get_local_data_path()).join("streams/1/topics/1/partitions/1");
+    let mut key_versions = Vec::new();
+    for _ in 0..100 {
+        key_versions = read_segments_key_versions(&partition_path);
+        if !key_versions.is_empty() && key_versions.iter().all(|version| *version == 2) {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert!(!key_versions.is_empty());
+    assert!(key_versions.iter().all(|version| *version == 2));
+
+    // 6. All the payloads, including the ones from the active segment, are still readable
+    let messages_count = 2 * BATCHES_COUNT * MESSAGES_COUNT;
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(1),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            messages_count,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, messages_count);
+    for message in polled_messages.messages {
+        assert_eq!(message.payload, PAYLOAD);
+    }
+    std::fs::remove_file(&key_path).unwrap();
+}
+
+async fn send_and_flush(
+    client: &IggyClient,
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    batches_count: u32,
+) {
+    for _ in 0..batches_count {
+        let mut messages = (0..MESSAGES_COUNT)
+            .map(|_| Message::from_str(PAYLOAD).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                stream_id,
+                topic_id,
+                &Partitioning::partition_id(1),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+        client
+            .flush_unsaved_buffer(stream_id, topic_id, 1, true)
+            .await
+            .unwrap();
+    }
+}
+
+fn read_segments_key_versions(path: &Path) -> Vec<u32> {
+    std::fs::read_dir(path)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "key"))
+        .map(|path| u32::from_le_bytes(std::fs::read(path).unwrap().try_into().unwrap()))
+        .collect()
+}
+
+fn contains_payload(path: &Path) -> bool {
+    std::fs::read_dir(path).unwrap().any(|entry| {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            return contains_payload(&path);
+        }
+
+        let data = std::fs::read(&path).unwrap();
+        data.windows(PAYLOAD.len())
+            .any(|window| window == PAYLOAD.as_bytes())
+    })
+}
@@ -10,7 +10,9 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::tcp::client::TcpClient;
 use iggy::tcp::config::TcpClientConfig;
 use iggy::users::defaults::*;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use integration::test_server::{IpAddrKind, TestServer};
 use regex::Regex;
@@ -120,6 +122,8 @@ impl<'a> IggyExampleTest<'a> {
                     None,
                     IggyExpiry::NeverExpire,
                     MaxTopicSize::ServerDefault,
+                    CleanupPolicy::Delete,
+                    TopicOverrides::default(),
                 )
                 .await
                 .unwrap();
@@ -11,6 +11,7 @@ mod archiver;
 mod bench;
 mod cli;
 mod config_provider;
+mod conformance;
 mod data_integrity;
 mod examples;
 mod server;
@@ -1,5 +1,11 @@
 use crate::server::scenarios::{
-    create_message_payload, stream_size_validation_scenario, system_scenario, user_scenario,
+    background_io_scenario, blocking_client_scenario, cluster_metadata_scenario,
+    connection_string_scenario, consumer_offsets_delete_scenario, consumer_offsets_reset_scenario,
+    copy_messages_scenario, create_message_payload, get_message_by_key_scenario,
+    list_paging_scenario, log_level_scenario, message_schema_scenario, named_consumers_scenario,
+    peek_messages_scenario, poll_messages_from_partitions_scenario,
+    poll_messages_max_bytes_scenario, seal_partition_scenario, send_messages_ack_scenario,
+    stream_size_validation_scenario, system_scenario, user_scenario,
 };
 use integration::{http_client::HttpClientFactory, test_server::TestServer};
 use serial_test::parallel;
@@ -24,6 +30,16 @@ async fn message_headers_scenario_should_be_valid() {
     create_message_payload::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn list_paging_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    list_paging_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn stream_size_validation_scenario_should_be_valid() {
@@ -53,3 +69,161 @@ async fn user_scenario_should_be_valid() {
     let client_factory = HttpClientFactory { server_addr };
     user_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_schema_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    message_schema_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn copy_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    copy_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn seal_partition_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    seal_partition_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn get_message_by_key_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    get_message_by_key_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn peek_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    peek_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_from_partitions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    poll_messages_from_partitions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn send_messages_ack_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    send_messages_ack_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_reset_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    consumer_offsets_reset_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_delete_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    consumer_offsets_delete_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn named_consumers_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    named_consumers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_max_bytes_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    poll_messages_max_bytes_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_string_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    connection_string_scenario::run("http", &server_addr).await;
+}
+
+#[test]
+#[parallel]
+fn blocking_client_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    blocking_client_scenario::run("http", &server_addr);
+}
+
+#[tokio::test]
+#[parallel]
+async fn log_level_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    log_level_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn background_io_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    background_io_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn cluster_metadata_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_http_api_addr().unwrap();
+    let client_factory = HttpClientFactory { server_addr };
+    cluster_metadata_scenario::run(&client_factory, 1, "iggy-node-1", "").await;
+}
@@ -1,10 +1,21 @@
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    blocking_client_scenario, connection_string_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_offsets_delete_scenario,
+    consumer_offsets_reset_scenario, create_message_payload, exclusive_producer_scenario,
+    large_poll_response_scenario, list_paging_scenario, message_headers_scenario,
+    message_schema_scenario, named_consumers_scenario, peek_messages_scenario,
+    poll_messages_from_partitions_scenario, poll_messages_max_bytes_scenario,
+    producer_epoch_scenario, retry_policy_scenario, send_messages_ack_scenario,
+    session_management_scenario, stream_size_validation_scenario, system_scenario, user_scenario,
+    wire_checksum_scenario,
+};
+use integration::{
+    quic_client::QuicClientFactory,
+    test_server::{IpAddrKind, TestServer},
 };
-use integration::{quic_client::QuicClientFactory, test_server::TestServer};
 use serial_test::parallel;
+use std::collections::HashMap;
 
 #[tokio::test]
 #[parallel]
@@ -76,6 +87,16 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn list_paging_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    list_paging_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn stream_size_validation_scenario_should_be_valid() {
@@ -85,3 +106,178 @@ async fn stream_size_validation_scenario_should_be_valid() {
     let client_factory = QuicClientFactory { server_addr };
     stream_size_validation_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_schema_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    message_schema_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn peek_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    peek_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn exclusive_producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    exclusive_producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn session_management_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    session_management_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn producer_epoch_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    producer_epoch_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_from_partitions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    poll_messages_from_partitions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn send_messages_ack_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    send_messages_ack_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_reset_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_offsets_reset_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_delete_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    consumer_offsets_delete_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn named_consumers_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    named_consumers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_max_bytes_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    poll_messages_max_bytes_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn large_poll_response_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    large_poll_response_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn chunked_poll_response_scenarios_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_POLL_RESPONSE_CHUNK_SIZE".to_string(),
+        "1 KB".to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    let client_factory = QuicClientFactory { server_addr };
+    large_poll_response_scenario::run(&client_factory).await;
+    poll_messages_from_partitions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_string_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    connection_string_scenario::run("quic", &server_addr).await;
+}
+
+#[test]
+#[parallel]
+fn blocking_client_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    blocking_client_scenario::run("quic", &server_addr);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retry_policy_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    retry_policy_scenario::run("quic", &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn wire_checksum_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_POLL_RESPONSE_CHUNK_SIZE".to_string(),
+        "1 KB".to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_quic_udp_addr().unwrap();
+    wire_checksum_scenario::run("quic", &server_addr).await;
+}
@@ -0,0 +1,35 @@
+use crate::server::scenarios::create_client;
+use iggy::client::{SystemClient, UserClient};
+use iggy::identifier::Identifier;
+use iggy::models::user_status::UserStatus;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const USERNAME: &str = "background-io-user";
+const PASSWORD: &str = "secret";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+
+    // 1. The background IO rate can be limited and unlimited again
+    client.set_background_io_rate(50_000_000).await.unwrap();
+    client.set_background_io_rate(0).await.unwrap();
+
+    // 2. The user without the permission to manage the servers cannot change the rate
+    client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user_client = create_client(client_factory).await;
+    user_client.login_user(USERNAME, PASSWORD).await.unwrap();
+    assert!(user_client.set_background_io_rate(1_000_000).await.is_err());
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+
+    // 3. The server keeps working
+    client.ping().await.unwrap();
+
+    assert_clean_system(&client).await;
+}
@@ -0,0 +1,77 @@
+use iggy::clients::blocking::BlockingIggyClient;
+use iggy::consumer::Consumer;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const MESSAGES_COUNT: u32 = 10;
+
+pub fn run(transport: &str, server_addr: &str) {
+    let client = BlockingIggyClient::from_connection_string(&format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?transport={transport}"
+    ))
+    .unwrap();
+    client.connect().unwrap();
+    client.ping().unwrap();
+
+    // 1. Create the stream and topic
+    client.create_stream(STREAM_NAME, Some(STREAM_ID)).unwrap();
+    client
+        .create_topic(
+            &STREAM_ID.try_into().unwrap(),
+            TOPIC_NAME,
+            1,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .unwrap();
+
+    // 2. Send and poll the messages
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|id| Message::from_str(&format!("message-{id}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .unwrap();
+
+    let polled_messages = client
+        .poll_messages(
+            &STREAM_ID.try_into().unwrap(),
+            &TOPIC_ID.try_into().unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert_eq!(polled_messages.messages[0].payload.as_ref(), b"message-0");
+
+    // 3. Delete the stream
+    client
+        .delete_stream(&STREAM_ID.try_into().unwrap())
+        .unwrap();
+    assert!(client.get_streams().unwrap().is_empty());
+    client.disconnect().unwrap();
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use iggy::client::{Client, StreamClient, SystemClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::middleware::{ClientMiddleware, ClientRequest, ClientResponse};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use std::sync::{Arc, Mutex};
+
+const REJECTED_COMMAND: &str = "stream.purge";
+// The pings are sent in the background as the heartbeats.
+const IGNORED_COMMAND: &str = "ping";
+
+#[derive(Debug, Default)]
+struct RecordingMiddleware {
+    commands: Mutex<Vec<(String, bool)>>,
+}
+
+#[async_trait]
+impl ClientMiddleware for RecordingMiddleware {
+    async fn before_request(&self, request: &ClientRequest) -> Result<(), IggyError> {
+        if request.command == REJECTED_COMMAND {
+            return Err(IggyError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    async fn after_response(&self, request: &ClientRequest, response: &ClientResponse<'_>) {
+        if request.command == IGNORED_COMMAND {
+            return;
+        }
+
+        self.commands
+            .lock()
+            .unwrap()
+            .push((request.command.clone(), response.result.is_ok()));
+    }
+}
+
+pub async fn run(server_addr: &str) {
+    let middleware = Arc::new(RecordingMiddleware::default());
+    let client = IggyClient::builder()
+        .with_middleware(middleware.clone())
+        .with_tcp()
+        .with_server_address(server_addr.to_string())
+        .build()
+        .unwrap();
+    client.connect().await.unwrap();
+
+    // 1. Every command is observed along with its outcome
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    client.get_me().await.unwrap();
+    let stream_id = Identifier::numeric(1).unwrap();
+    assert!(client.delete_stream(&stream_id).await.is_err());
+    assert_eq!(
+        *middleware.commands.lock().unwrap(),
+        vec![
+            ("user.login".to_string(), true),
+            ("me".to_string(), true),
+            ("stream.delete".to_string(), false),
+        ]
+    );
+
+    // 2. The command rejected by the middleware is not sent to the server
+    let result = client.purge_stream(&stream_id).await;
+    assert!(matches!(result, Err(IggyError::Unauthorized)));
+    assert_eq!(middleware.commands.lock().unwrap().len(), 3);
+}
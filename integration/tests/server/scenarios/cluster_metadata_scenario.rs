@@ -0,0 +1,189 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{Client, MessageClient, StreamClient, SystemClient, TopicClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::clients::leader_router::NodeClientFactory;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::cluster_metadata::BrokerInfo;
+use iggy::models::user_status::UserStatus;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+const USERNAME: &str = "cluster-user";
+const PASSWORD: &str = "secret";
+const MESSAGES_COUNT: u32 = 10;
+const ROUTED_PARTITION_ID: u32 = 2;
+
+/// Counts the clients requested for the other nodes, which the single node never needs.
+#[derive(Debug, Default)]
+struct CountingNodeClientFactory {
+    created: AtomicU32,
+}
+
+impl NodeClientFactory for CountingNodeClientFactory {
+    fn create_client(&self, _address: &str) -> Result<Box<dyn Client>, IggyError> {
+        self.created.fetch_add(1, Ordering::SeqCst);
+        Err(IggyError::CannotEstablishConnection)
+    }
+}
+
+pub async fn run(
+    client_factory: &dyn ClientFactory,
+    node_id: u32,
+    node_name: &str,
+    node_zone: &str,
+) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The node returns itself as the only broker and the leader of all the partitions
+    let metadata = client.get_cluster_metadata().await.unwrap();
+    assert_eq!(metadata.node_id, node_id);
+    assert_eq!(
+        metadata.brokers,
+        vec![BrokerInfo {
+            id: node_id,
+            name: node_name.to_string(),
+            address: "".to_string(),
+            zone: node_zone.to_string(),
+        }]
+    );
+    assert_eq!(metadata.partitions.len(), PARTITIONS_COUNT as usize);
+    for (index, partition) in metadata.partitions.iter().enumerate() {
+        assert_eq!(partition.stream_id, STREAM_ID);
+        assert_eq!(partition.stream_name, STREAM_NAME);
+        assert_eq!(partition.topic_id, TOPIC_ID);
+        assert_eq!(partition.topic_name, TOPIC_NAME);
+        assert_eq!(partition.partition_id, index as u32 + 1);
+        assert_eq!(partition.leader_id, node_id);
+        assert_eq!(partition.leader_epoch, 0);
+    }
+    let leader = metadata
+        .get_leader(
+            &Identifier::named(STREAM_NAME).unwrap(),
+            &topic_id,
+            ROUTED_PARTITION_ID,
+        )
+        .unwrap();
+    assert_eq!(leader.id, node_id);
+
+    // 2. The leader epoch follows the epoch of the partition
+    client.purge_topic(&stream_id, &topic_id).await.unwrap();
+    let metadata = client.get_cluster_metadata().await.unwrap();
+    assert!(metadata
+        .partitions
+        .iter()
+        .all(|partition| partition.leader_epoch == 1));
+
+    // 3. The single node can't host the topic with more than one replica of its partitions
+    let replication_factor_not_supported = IggyError::ReplicationFactorNotSupported(2);
+    match client
+        .create_topic(
+            &stream_id,
+            "replicated-topic",
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            Some(2),
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            Default::default(),
+            Default::default(),
+        )
+        .await
+    {
+        Err(IggyError::InvalidResponse(status, _, _)) => {
+            assert_eq!(status, replication_factor_not_supported.as_code())
+        }
+        Err(IggyError::HttpResponseError(_, reason)) => {
+            assert!(reason.contains(replication_factor_not_supported.as_string()))
+        }
+        Err(error) => assert_eq!(error.as_code(), replication_factor_not_supported.as_code()),
+        Ok(_) => panic!("Expected the replication factor not to be supported."),
+    }
+
+    // 4. The user without the access to the topic sees the brokers, but not the partitions
+    client
+        .create_user(USERNAME, PASSWORD, UserStatus::Active, None)
+        .await
+        .unwrap();
+    let user_client = create_client(client_factory).await;
+    user_client.login_user(USERNAME, PASSWORD).await.unwrap();
+    let metadata = user_client.get_cluster_metadata().await.unwrap();
+    assert_eq!(metadata.brokers.len(), 1);
+    assert!(metadata.partitions.is_empty());
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+
+    // 5. The client routing to the leaders keeps using the connected node, which leads all the partitions
+    let node_client_factory = Arc::new(CountingNodeClientFactory::default());
+    let routed_client = IggyClient::create(client_factory.create_client().await, None, None)
+        .with_leader_routing(node_client_factory.clone());
+    login_root(&routed_client).await;
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::new(None, format!("value {index}").into(), None))
+        .collect::<Vec<_>>();
+    routed_client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(ROUTED_PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let polled_messages = routed_client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(ROUTED_PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), MESSAGES_COUNT as usize);
+    assert_eq!(polled_messages.partition_id, ROUTED_PARTITION_ID);
+    assert_eq!(node_client_factory.created.load(Ordering::SeqCst), 0);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            Default::default(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,153 @@
+use crate::server::scenarios::{
+    CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use futures::future::join_all;
+use iggy::client::{
+    Client, ConsumerGroupClient, MessageClient, StreamClient, SystemClient, TopicClient, UserClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::str::FromStr;
+
+const CONNECTION_POOL_SIZE: u32 = 3;
+const BATCHES_COUNT: u32 = 10;
+const MESSAGES_PER_BATCH: u32 = 10;
+
+pub async fn run(server_addr: &str) {
+    let connection_string = format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?connection_pool_size={CONNECTION_POOL_SIZE}"
+    );
+
+    // 1. All the pooled connections are opened and logged in automatically when connecting
+    let client = IggyClient::from_connection_string(&connection_string).unwrap();
+    client.connect().await.unwrap();
+    let clients = client.get_clients().await.unwrap();
+    assert_eq!(clients.len() as u32, CONNECTION_POOL_SIZE);
+    assert!(clients.iter().all(|client| client.user_id.is_some()));
+
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 2. The concurrent commands are routed across the connections
+    let results = join_all((0..BATCHES_COUNT).map(|batch| {
+        let client = &client;
+        let stream_id = &stream_id;
+        let topic_id = &topic_id;
+        async move {
+            let mut messages = (0..MESSAGES_PER_BATCH)
+                .map(|index| Message::from_str(&format!("message-{batch}-{index}")).unwrap())
+                .collect::<Vec<_>>();
+            client
+                .send_messages(
+                    stream_id,
+                    topic_id,
+                    &Partitioning::partition_id(PARTITION_ID),
+                    &mut messages,
+                )
+                .await
+        }
+    }))
+    .await;
+    assert!(results.iter().all(|result| result.is_ok()));
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            BATCHES_COUNT * MESSAGES_PER_BATCH,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        polled_messages.messages.len() as u32,
+        BATCHES_COUNT * MESSAGES_PER_BATCH
+    );
+
+    // 3. The consumer group commands bound to the session are sent over the same connection
+    let group_id = Identifier::numeric(CONSUMER_GROUP_ID).unwrap();
+    client
+        .create_consumer_group(
+            &stream_id,
+            &topic_id,
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            RebalanceStrategy::default(),
+        )
+        .await
+        .unwrap();
+    client
+        .join_consumer_group(&stream_id, &topic_id, &group_id, None)
+        .await
+        .unwrap();
+    let consumer = Consumer::group(group_id.clone());
+    for _ in 0..CONNECTION_POOL_SIZE {
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                None,
+                &consumer,
+                &PollingStrategy::next(),
+                MESSAGES_PER_BATCH,
+                true,
+            )
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.messages.len() as u32, MESSAGES_PER_BATCH);
+    }
+    client
+        .leave_consumer_group(&stream_id, &topic_id, &group_id)
+        .await
+        .unwrap();
+    client.delete_stream(&stream_id).await.unwrap();
+
+    // 4. All the pooled connections are logged out
+    client.logout_user().await.unwrap();
+    let admin_client = IggyClient::from_connection_string(&format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}"
+    ))
+    .unwrap();
+    admin_client.connect().await.unwrap();
+    let clients = admin_client.get_clients().await.unwrap();
+    assert_eq!(clients.len() as u32, CONNECTION_POOL_SIZE + 1);
+    assert_eq!(
+        clients
+            .iter()
+            .filter(|client| client.user_id.is_none())
+            .count() as u32,
+        CONNECTION_POOL_SIZE
+    );
+}
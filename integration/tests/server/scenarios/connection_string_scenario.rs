@@ -0,0 +1,29 @@
+use iggy::client::{Client, StreamClient};
+use iggy::clients::client::IggyClient;
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+
+pub async fn run(transport: &str, server_addr: &str) {
+    let connection_string = format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?transport={transport}&connection_timeout=5s&reconnection_retries=3"
+    );
+
+    // 1. The client for the selected transport is created and logged in automatically when connecting
+    let client = IggyClient::from_connection_string(&connection_string).unwrap();
+    client.connect().await.unwrap();
+    let streams = client.get_streams().await.unwrap();
+    assert!(streams.is_empty());
+
+    // 2. The same client is created by the builder
+    let client = IggyClient::builder_from_connection_string(&connection_string)
+        .unwrap()
+        .build()
+        .unwrap();
+    client.connect().await.unwrap();
+    assert!(client.get_streams().await.is_ok());
+
+    // 3. The unknown transport is rejected
+    let connection_string = format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?transport=udp"
+    );
+    assert!(IggyClient::from_connection_string(&connection_string).is_err());
+}
@@ -0,0 +1,157 @@
+use crate::server::scenarios::{
+    cleanup, create_client, CONSUMER_GROUP_NAME, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME,
+    TOPIC_ID, TOPIC_NAME,
+};
+use async_trait::async_trait;
+use iggy::client::{ConsumerGroupClient, StreamClient, TopicClient};
+use iggy::clients::consumer::IggyConsumer;
+use iggy::clients::consumer_group_coordinator::ConsumerGroupRebalanceListener;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::sleep;
+
+#[derive(Debug, Default)]
+struct RecordingListener {
+    revoked_partitions: Mutex<Vec<u32>>,
+    assigned_partitions: Mutex<Vec<u32>>,
+}
+
+#[async_trait]
+impl ConsumerGroupRebalanceListener for RecordingListener {
+    async fn on_partitions_revoked(&self, partitions: &[u32]) {
+        self.revoked_partitions
+            .lock()
+            .unwrap()
+            .extend_from_slice(partitions);
+    }
+
+    async fn on_partitions_assigned(&self, partitions: &[u32]) {
+        self.assigned_partitions
+            .lock()
+            .unwrap()
+            .extend_from_slice(partitions);
+    }
+}
+
+pub async fn run(client_factory: &dyn ClientFactory, rebalance_strategy: RebalanceStrategy) {
+    let system_client = create_client(client_factory).await;
+    login_root(&system_client).await;
+    system_client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    system_client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let all_partitions = (1..=PARTITIONS_COUNT).collect::<Vec<_>>();
+
+    // 1. The only consumer in the group is assigned all the partitions
+    let listener1 = Arc::new(RecordingListener::default());
+    let mut consumer1 =
+        create_consumer(client_factory, listener1.clone(), rebalance_strategy).await;
+    wait_until(|| consumer1.assigned_partitions() == all_partitions).await;
+    assert_eq!(
+        *listener1.assigned_partitions.lock().unwrap(),
+        all_partitions
+    );
+    let consumer_group = system_client
+        .get_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Identifier::named(CONSUMER_GROUP_NAME).unwrap(),
+        )
+        .await
+        .unwrap()
+        .expect("Consumer group not found");
+    assert_eq!(consumer_group.rebalance_strategy, rebalance_strategy);
+
+    // 2. Once another consumer joins, only the partitions which change the owner are revoked
+    let listener2 = Arc::new(RecordingListener::default());
+    let mut consumer2 =
+        create_consumer(client_factory, listener2.clone(), rebalance_strategy).await;
+    wait_until(|| {
+        let mut partitions = consumer1.assigned_partitions();
+        partitions.extend(consumer2.assigned_partitions());
+        partitions.sort_unstable();
+        partitions == all_partitions && !consumer2.assigned_partitions().is_empty()
+    })
+    .await;
+    let revoked_partitions = listener1.revoked_partitions.lock().unwrap().clone();
+    assert!(!revoked_partitions.is_empty());
+    assert_eq!(
+        *listener2.assigned_partitions.lock().unwrap(),
+        consumer2.assigned_partitions()
+    );
+    for partition_id in consumer1.assigned_partitions() {
+        assert!(!revoked_partitions.contains(&partition_id));
+    }
+
+    // 3. Once the consumer leaves the group, its partitions are revoked and assigned back to the other one
+    let consumer1_revoked_partitions_count = listener1.revoked_partitions.lock().unwrap().len();
+    consumer2.shutdown().await.unwrap();
+    assert!(consumer2.assigned_partitions().is_empty());
+    let mut revoked_partitions = listener2.revoked_partitions.lock().unwrap().clone();
+    revoked_partitions.sort_unstable();
+    let mut assigned_partitions = listener2.assigned_partitions.lock().unwrap().clone();
+    assigned_partitions.sort_unstable();
+    assert_eq!(revoked_partitions, assigned_partitions);
+    wait_until(|| consumer1.assigned_partitions() == all_partitions).await;
+    assert_eq!(
+        listener1.revoked_partitions.lock().unwrap().len(),
+        consumer1_revoked_partitions_count
+    );
+
+    consumer1.shutdown().await.unwrap();
+    cleanup(&system_client, false).await;
+    assert_clean_system(&system_client).await;
+}
+
+async fn create_consumer(
+    client_factory: &dyn ClientFactory,
+    listener: Arc<RecordingListener>,
+    rebalance_strategy: RebalanceStrategy,
+) -> IggyConsumer {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let mut consumer = client
+        .consumer_group(CONSUMER_GROUP_NAME, STREAM_NAME, TOPIC_NAME)
+        .unwrap()
+        .rebalance_strategy(rebalance_strategy)
+        .rebalance_listener(listener)
+        .heartbeat_interval(IggyDuration::from_str("10ms").unwrap())
+        .build();
+    consumer.init().await.unwrap();
+    consumer
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    for _ in 0..500 {
+        if condition() {
+            return;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    panic!("Condition was not met in time");
+}
@@ -0,0 +1,150 @@
+use crate::server::scenarios::{
+    cleanup, create_client, get_consumer_group, CONSUMER_GROUP_ID, CONSUMER_GROUP_NAME,
+    PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{Client, ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const INSTANCE_ID_1: &str = "instance-1";
+const INSTANCE_ID_2: &str = "instance-2";
+
+/// Expects the server to be started with the consumer group session timeout short enough to elapse within a few seconds.
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let system_client = create_client(client_factory).await;
+    login_root(&system_client).await;
+    system_client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    system_client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    system_client
+        .create_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            CONSUMER_GROUP_NAME,
+            Some(CONSUMER_GROUP_ID),
+            RebalanceStrategy::default(),
+        )
+        .await
+        .unwrap();
+
+    // 1. Join the consumer group by two static members
+    let client1 = join_static_member(client_factory, INSTANCE_ID_1).await;
+    let client2 = join_static_member(client_factory, INSTANCE_ID_2).await;
+    let client1_id = client1.get_me().await.unwrap().client_id;
+    let client2_id = client2.get_me().await.unwrap().client_id;
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.members_count, 2);
+    let client1_partitions = get_member_partitions(&consumer_group, client1_id);
+    let client2_partitions = get_member_partitions(&consumer_group, client2_id);
+
+    // 2. The disconnected static member keeps its partitions
+    client1.disconnect().await.unwrap();
+    wait_for_client_deletion(&system_client, client1_id).await;
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.members_count, 2);
+    assert_eq!(
+        get_member_partitions(&consumer_group, client1_id),
+        client1_partitions
+    );
+
+    // 3. The restarted static member reclaims its partitions, without rebalancing the other member
+    let client3 = join_static_member(client_factory, INSTANCE_ID_1).await;
+    let client3_id = client3.get_me().await.unwrap().client_id;
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.members_count, 2);
+    assert!(!consumer_group
+        .members
+        .iter()
+        .any(|member| member.id == client1_id));
+    assert_eq!(
+        get_member_partitions(&consumer_group, client3_id),
+        client1_partitions
+    );
+    assert_eq!(
+        get_member_partitions(&consumer_group, client2_id),
+        client2_partitions
+    );
+
+    // 4. Once the session timeout elapses, the partitions of the disconnected static member are reassigned
+    client3.disconnect().await.unwrap();
+    let mut consumer_group = get_consumer_group(&system_client).await;
+    for _ in 0..100 {
+        if consumer_group.members_count == 1 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+        consumer_group = get_consumer_group(&system_client).await;
+    }
+    assert_eq!(consumer_group.members_count, 1);
+    assert_eq!(
+        get_member_partitions(&consumer_group, client2_id),
+        (1..=PARTITIONS_COUNT).collect::<Vec<_>>()
+    );
+
+    client2.disconnect().await.unwrap();
+    cleanup(&system_client, false).await;
+    assert_clean_system(&system_client).await;
+}
+
+async fn join_static_member(client_factory: &dyn ClientFactory, instance_id: &str) -> IggyClient {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    client
+        .join_consumer_group(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            Some(instance_id),
+        )
+        .await
+        .unwrap();
+    client
+}
+
+async fn wait_for_client_deletion(client: &IggyClient, client_id: u32) {
+    for _ in 0..100 {
+        let clients = client.get_clients().await.unwrap();
+        if !clients.iter().any(|client| client.client_id == client_id) {
+            return;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    panic!("Client with ID: {client_id} has not been deleted");
+}
+
+fn get_member_partitions(consumer_group: &ConsumerGroupDetails, member_id: u32) -> Vec<u32> {
+    let member = consumer_group
+        .members
+        .iter()
+        .find(|member| member.id == member_id)
+        .expect("Member not found");
+    let mut partitions = member.partitions.clone();
+    partitions.sort_unstable();
+    partitions
+}
@@ -0,0 +1,119 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{
+    ConsumerOffsetClient, MessageClient, PartitionClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::consumer_offsets_storage_info::ConsumerOffsetsStorageInfo;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use tokio::time::{sleep, Duration};
+
+const MESSAGES_COUNT: u64 = 20;
+
+// The server is expected to run the consumer offsets compactor with a short interval and at most 10 stale records.
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::default();
+
+    // 1. Store each offset of the partition, appending a record for each commit
+    for offset in 0..MESSAGES_COUNT {
+        client
+            .store_consumer_offset(&consumer, &stream_id, &topic_id, Some(PARTITION_ID), offset)
+            .await
+            .unwrap();
+    }
+
+    // 2. Wait for the compactor to rewrite the records to the latest offset of the consumer
+    let mut consumer_offsets = get_consumer_offsets_storage_info(&client).await;
+    for _ in 0..50 {
+        if consumer_offsets.compactions_count > 0 {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+        consumer_offsets = get_consumer_offsets_storage_info(&client).await;
+    }
+    assert_eq!(consumer_offsets.consumers_count, 1);
+    assert_eq!(consumer_offsets.consumer_groups_count, 0);
+    assert_eq!(consumer_offsets.records_count, 1);
+    assert_eq!(consumer_offsets.size.as_bytes_u64(), 12);
+    assert!(consumer_offsets.compactions_count > 0);
+    assert!(consumer_offsets.last_compacted_at.is_some());
+
+    // 3. The latest offset is still stored after the compaction
+    let offset = client
+        .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(PARTITION_ID))
+        .await
+        .unwrap()
+        .expect("Failed to get consumer offset");
+    assert_eq!(offset.stored_offset, MESSAGES_COUNT - 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn get_consumer_offsets_storage_info(client: &IggyClient) -> ConsumerOffsetsStorageInfo {
+    client
+        .get_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get partition")
+        .consumer_offsets
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 3. Send the messages to the partition
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,120 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_PER_PARTITION: u64 = 10;
+const STORED_OFFSET: u64 = 5;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::default();
+
+    // 1. Store the consumer offset for each partition
+    for partition_id in 1..=PARTITIONS_COUNT {
+        client
+            .store_consumer_offset(
+                &consumer,
+                &stream_id,
+                &topic_id,
+                Some(partition_id),
+                STORED_OFFSET,
+            )
+            .await
+            .unwrap();
+    }
+
+    // 2. Delete the consumer offset for the first partition only
+    client
+        .delete_consumer_offsets(&consumer, &stream_id, &topic_id, Some(1))
+        .await
+        .unwrap();
+    let lags = client
+        .get_consumer_lag(&consumer, &stream_id, &topic_id, None)
+        .await
+        .unwrap();
+    for lag in &lags {
+        if lag.partition_id == 1 {
+            assert_eq!(lag.stored_offset, None);
+        } else {
+            assert_eq!(lag.stored_offset, Some(STORED_OFFSET));
+        }
+    }
+
+    // 3. Delete the consumer offsets for all the remaining partitions
+    client
+        .delete_consumer_offsets(&consumer, &stream_id, &topic_id, None)
+        .await
+        .unwrap();
+    let lags = client
+        .get_consumer_lag(&consumer, &stream_id, &topic_id, None)
+        .await
+        .unwrap();
+    assert_eq!(lags.len() as u32, PARTITIONS_COUNT);
+    assert!(lags.iter().all(|lag| lag.stored_offset.is_none()));
+
+    // 4. Deleting the offsets of the consumer without any stored offset should fail
+    let result = client
+        .delete_consumer_offsets(&consumer, &stream_id, &topic_id, None)
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 3. Send the messages to each partition
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let mut messages = (0..MESSAGES_PER_PARTITION)
+            .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                &Identifier::numeric(TOPIC_ID).unwrap(),
+                &Partitioning::partition_id(partition_id),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+}
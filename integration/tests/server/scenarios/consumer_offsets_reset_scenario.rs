@@ -0,0 +1,216 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_PER_PARTITION: u64 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::default();
+
+    // 1. Send the messages to each partition and store the consumer offset for the first one
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let mut messages = (0..MESSAGES_PER_PARTITION)
+            .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(partition_id),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+    client
+        .store_consumer_offset(&consumer, &stream_id, &topic_id, Some(1), 5)
+        .await
+        .unwrap();
+
+    // 2. Dry run reports the resulting offsets for all the partitions without storing them
+    let resets = client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[],
+            &OffsetResetStrategy::earliest(),
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(resets.len() as u32, PARTITIONS_COUNT);
+    for (index, reset) in resets.iter().enumerate() {
+        assert_eq!(reset.partition_id, index as u32 + 1);
+        assert_eq!(reset.current_offset, MESSAGES_PER_PARTITION - 1);
+        assert_eq!(reset.stored_offset, None);
+    }
+    assert_eq!(resets[0].previous_offset, Some(5));
+    assert_eq!(resets[1].previous_offset, None);
+    assert_next_offset(&client, &consumer, 1, 6).await;
+
+    // 3. Reset to the latest offset for the selected partitions
+    let resets = client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[1, 2],
+            &OffsetResetStrategy::latest(),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(resets.len(), 2);
+    for reset in &resets {
+        assert_eq!(reset.stored_offset, Some(MESSAGES_PER_PARTITION - 1));
+    }
+    let offset = client
+        .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(2))
+        .await
+        .unwrap()
+        .expect("Consumer offset should exist");
+    assert_eq!(offset.stored_offset, MESSAGES_PER_PARTITION - 1);
+
+    // 4. Reset to the specific offset, so the next polled message is the one at this offset
+    let resets = client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[1],
+            &OffsetResetStrategy::offset(3),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(resets[0].previous_offset, Some(MESSAGES_PER_PARTITION - 1));
+    assert_eq!(resets[0].stored_offset, Some(2));
+    assert_next_offset(&client, &consumer, 1, 3).await;
+
+    // 5. Reset to the earliest offset, so the consumption starts from the first message
+    client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[1],
+            &OffsetResetStrategy::earliest(),
+            false,
+        )
+        .await
+        .unwrap();
+    assert_next_offset(&client, &consumer, 1, 0).await;
+
+    // 6. Reset to the timestamp of the message, so the consumption starts from this message
+    let message = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(3),
+            &consumer,
+            &PollingStrategy::offset(4),
+            1,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .remove(0);
+    let resets = client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[3],
+            &OffsetResetStrategy::timestamp(message.timestamp.into()),
+            false,
+        )
+        .await
+        .unwrap();
+    // The messages sent in the same batch share the timestamp, so the earlier ones might be included.
+    let next_offset = resets[0].stored_offset.map_or(0, |offset| offset + 1);
+    assert!(next_offset <= message.offset);
+    assert_next_offset(&client, &consumer, 3, next_offset).await;
+
+    // 7. Reset to the offset above the high watermark fails
+    let result = client
+        .reset_consumer_offsets(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            &[1],
+            &OffsetResetStrategy::offset(MESSAGES_PER_PARTITION + 10),
+            false,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn assert_next_offset(
+    client: &IggyClient,
+    consumer: &Consumer,
+    partition_id: u32,
+    expected_offset: u64,
+) {
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(partition_id),
+            consumer,
+            &PollingStrategy::next(),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages[0].offset, expected_offset);
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,225 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::copy_messages::{CopyRangeKind, COPIED_FROM_HEADER};
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::PolledMessages;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const STAGING_TOPIC_ID: u32 = TOPIC_ID + 1;
+const STAGING_TOPIC_NAME: &str = "staging";
+const BATCH_MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let staging_topic_id = Identifier::numeric(STAGING_TOPIC_ID).unwrap();
+
+    // 1. Send two batches of the messages with headers to the source partition
+    send_messages(&client, 0).await;
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    send_messages(&client, BATCH_MESSAGES_COUNT).await;
+
+    // 2. Copy the range of offsets, preserving the IDs and headers and recording the origin
+    let copied_messages = client
+        .copy_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            &stream_id,
+            &staging_topic_id,
+            PARTITION_ID,
+            CopyRangeKind::Offset,
+            5,
+            15,
+        )
+        .await
+        .unwrap();
+    assert_eq!(copied_messages.messages_count, 10);
+    let staging_messages = poll_messages(&client, &staging_topic_id).await;
+    assert_eq!(staging_messages.messages.len(), 10);
+    for (index, message) in staging_messages.messages.iter().enumerate() {
+        let source_offset = index as u64 + 5;
+        assert_eq!(message.offset, index as u64);
+        assert_eq!(message.id, source_offset as u128 + 1);
+        assert_eq!(message.payload, format!("message {source_offset}"));
+        let headers = message.headers.as_ref().unwrap();
+        assert_eq!(
+            headers
+                .get(&HeaderKey::new("index").unwrap())
+                .unwrap()
+                .as_uint64()
+                .unwrap(),
+            source_offset
+        );
+        assert_eq!(
+            headers
+                .get(&HeaderKey::new(COPIED_FROM_HEADER).unwrap())
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            format!("{STREAM_ID}/{TOPIC_ID}/{PARTITION_ID}/{source_offset}")
+        );
+    }
+
+    // 3. Copy the range of timestamps covering the second batch
+    let source_messages = poll_messages(&client, &topic_id).await;
+    let second_batch_timestamp = source_messages.messages[BATCH_MESSAGES_COUNT as usize].timestamp;
+    let copied_messages = client
+        .copy_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            &stream_id,
+            &staging_topic_id,
+            PARTITION_ID,
+            CopyRangeKind::Timestamp,
+            second_batch_timestamp,
+            u64::MAX,
+        )
+        .await
+        .unwrap();
+    assert_eq!(copied_messages.messages_count, BATCH_MESSAGES_COUNT as u64);
+    let staging_messages = poll_messages(&client, &staging_topic_id).await;
+    assert_eq!(staging_messages.messages.len(), 20);
+    assert_eq!(
+        staging_messages.messages[10].payload,
+        format!("message {BATCH_MESSAGES_COUNT}")
+    );
+
+    // 4. Copying the range beyond the appended messages copies nothing
+    let copied_messages = client
+        .copy_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            &stream_id,
+            &staging_topic_id,
+            PARTITION_ID,
+            CopyRangeKind::Offset,
+            100,
+            200,
+        )
+        .await
+        .unwrap();
+    assert_eq!(copied_messages.messages_count, 0);
+
+    // 5. Copying the empty range or to the non-existing partition fails
+    let result = client
+        .copy_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            &stream_id,
+            &staging_topic_id,
+            PARTITION_ID,
+            CopyRangeKind::Offset,
+            10,
+            10,
+        )
+        .await;
+    assert!(result.is_err());
+    let result = client
+        .copy_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            &stream_id,
+            &staging_topic_id,
+            PARTITION_ID + 100,
+            CopyRangeKind::Offset,
+            0,
+            10,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_messages(client: &IggyClient, first_offset: u32) {
+    let mut messages = (first_offset..first_offset + BATCH_MESSAGES_COUNT)
+        .map(|offset| {
+            let headers = HashMap::from([(
+                HeaderKey::new("index").unwrap(),
+                HeaderValue::from_uint64(offset as u64).unwrap(),
+            )]);
+            Message::new(
+                Some(offset as u128 + 1),
+                format!("message {offset}").into(),
+                Some(headers),
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_messages(client: &IggyClient, topic_id: &Identifier) -> PolledMessages {
+    client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            100,
+            false,
+        )
+        .await
+        .unwrap()
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the source and the staging topics
+    for (topic_id, topic_name) in [
+        (TOPIC_ID, TOPIC_NAME),
+        (STAGING_TOPIC_ID, STAGING_TOPIC_NAME),
+    ] {
+        client
+            .create_topic(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                topic_name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                Some(topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,90 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Expects the server to be started with the free disk space threshold above the available space.
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The resources can still be created in the read-only mode
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 2. The sent messages are rejected once the monitor detects the insufficient free space
+    let mut result = Ok(());
+    for _ in 0..50 {
+        let mut messages = vec![Message::from_str("test").unwrap()];
+        result = client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await;
+        if result.is_err() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+
+    match result {
+        Err(IggyError::InvalidResponse(code, _, _)) => {
+            assert_eq!(code, IggyError::InsufficientDiskSpace.as_code())
+        }
+        result => panic!("Expected insufficient disk space error, got: {result:?}"),
+    }
+
+    // 3. The messages can still be polled
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.len() <= 1);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
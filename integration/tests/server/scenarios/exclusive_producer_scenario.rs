@@ -0,0 +1,169 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{Client, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const BATCHES_PER_CLIENT: u32 = 20;
+const MESSAGES_PER_BATCH: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+    login_root(&client1).await;
+    login_root(&client2).await;
+    init_system(&client1).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Send the batches concurrently from both clients to the same partition
+    tokio::join!(
+        send_batches(&client1, "client1"),
+        send_batches(&client2, "client2")
+    );
+
+    // 2. Each batch is stored contiguously, without being interleaved with the other one
+    let messages_count = 2 * BATCHES_PER_CLIENT * MESSAGES_PER_BATCH;
+    let polled_messages = client1
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            messages_count,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages;
+    assert_eq!(polled_messages.len() as u32, messages_count);
+    for batch in polled_messages.chunks(MESSAGES_PER_BATCH as usize) {
+        let batch_prefix = get_batch_prefix(&batch[0].payload);
+        for (index, message) in batch.iter().enumerate() {
+            let payload = String::from_utf8(message.payload.to_vec()).unwrap();
+            assert_eq!(payload, format!("{batch_prefix} {index}"));
+        }
+    }
+
+    // 3. Acquire the exclusive producer by the first client, also repeatedly
+    client1
+        .acquire_exclusive_producer(&stream_id, &topic_id)
+        .await
+        .unwrap();
+    client1
+        .acquire_exclusive_producer(&stream_id, &topic_id)
+        .await
+        .unwrap();
+
+    // 4. The second client is fenced, both when acquiring and when sending the messages
+    let result = client2
+        .acquire_exclusive_producer(&stream_id, &topic_id)
+        .await;
+    assert_fenced(result);
+    let result = client2
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut [Message::from_str("fenced").unwrap()],
+        )
+        .await;
+    assert_fenced(result);
+
+    // 5. The exclusive producer can still send the messages
+    client1
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut [Message::from_str("exclusive").unwrap()],
+        )
+        .await
+        .unwrap();
+
+    // 6. Once the first client disconnects, the topic is released and the second client can acquire it
+    client1.disconnect().await.unwrap();
+    let mut result = Err(IggyError::ProducerFenced(TOPIC_ID, STREAM_ID, 0));
+    for _ in 0..50 {
+        result = client2
+            .acquire_exclusive_producer(&stream_id, &topic_id)
+            .await;
+        if result.is_ok() {
+            break;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    assert!(result.is_ok());
+
+    cleanup(&client2, false).await;
+    assert_clean_system(&client2).await;
+}
+
+async fn send_batches(client: &IggyClient, name: &str) {
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+    for batch in 0..BATCHES_PER_CLIENT {
+        let mut messages = (0..MESSAGES_PER_BATCH)
+            .map(|index| Message::from_str(&format!("{name}-{batch} {index}")).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages(&stream_id, &topic_id, &partitioning, &mut messages)
+            .await
+            .unwrap();
+    }
+}
+
+fn get_batch_prefix(payload: &[u8]) -> String {
+    let payload = String::from_utf8(payload.to_vec()).unwrap();
+    payload.split(' ').next().unwrap().to_owned()
+}
+
+fn assert_fenced(result: Result<(), IggyError>) {
+    let fenced_code = IggyError::ProducerFenced(TOPIC_ID, STREAM_ID, 0).as_code();
+    match result {
+        Err(IggyError::InvalidResponse(status, _, _)) => assert_eq!(status, fenced_code),
+        Err(error) => assert_eq!(error.as_code(), fenced_code),
+        Ok(()) => panic!("Expected the producer to be fenced."),
+    }
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
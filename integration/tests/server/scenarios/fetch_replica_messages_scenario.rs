@@ -0,0 +1,260 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, PartitionClient, StreamClient, TopicClient, UserClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::user_status::UserStatus;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+pub const REPLICA_ID: u32 = 2;
+const UNKNOWN_REPLICA_ID: u32 = 3;
+const USERNAME: &str = "consumer";
+const PASSWORD: &str = "secret";
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Neither the consumer nor the unknown replica can join the in-sync replicas
+    send_messages(&client, "value").await;
+    client
+        .create_user(
+            USERNAME,
+            PASSWORD,
+            UserStatus::Active,
+            Some(Permissions {
+                global: GlobalPermissions {
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        )
+        .await
+        .unwrap();
+    let consumer_client = create_client(client_factory).await;
+    consumer_client
+        .login_user(USERNAME, PASSWORD)
+        .await
+        .unwrap();
+    assert_fetch_error(
+        &consumer_client,
+        REPLICA_ID,
+        IggyError::Unauthorized.as_code(),
+    )
+    .await;
+    assert_fetch_error(
+        &client,
+        UNKNOWN_REPLICA_ID,
+        IggyError::NodeNotFound(UNKNOWN_REPLICA_ID).as_code(),
+    )
+    .await;
+    let partition = client
+        .get_partition(&stream_id, &topic_id, PARTITION_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(partition.replicas.is_empty());
+    client
+        .delete_user(&Identifier::named(USERNAME).unwrap())
+        .await
+        .unwrap();
+
+    // 2. The follower fetches the messages in the current epoch page by page
+    let replica_messages = client
+        .fetch_replica_messages(&stream_id, &topic_id, PARTITION_ID, REPLICA_ID, 0, 0, 6)
+        .await
+        .unwrap();
+    assert!(replica_messages.diverging_epoch.is_none());
+    assert_eq!(replica_messages.messages.epoch, 0);
+    assert_eq!(replica_messages.messages.messages.len(), 6);
+    assert_eq!(replica_messages.messages.next_offset, Some(6));
+    let replica_messages = client
+        .fetch_replica_messages(&stream_id, &topic_id, PARTITION_ID, REPLICA_ID, 6, 0, 6)
+        .await
+        .unwrap();
+    assert_eq!(replica_messages.messages.messages.len(), 4);
+    assert_eq!(replica_messages.messages.messages[3].payload, "value 9");
+    assert!(replica_messages.messages.next_offset.is_none());
+
+    // 3. The epoch unknown to the leader is rejected
+    let unknown_epoch_code = IggyError::UnknownPartitionEpoch(1, PARTITION_ID, 0).as_code();
+    match client
+        .fetch_replica_messages(&stream_id, &topic_id, PARTITION_ID, REPLICA_ID, 0, 1, 6)
+        .await
+    {
+        Err(IggyError::InvalidResponse(status, _, _)) => assert_eq!(status, unknown_epoch_code),
+        Err(error) => assert_eq!(error.as_code(), unknown_epoch_code),
+        Ok(_) => panic!("Expected the epoch to be unknown."),
+    }
+
+    // 4. Once the leader's log is rewritten, the follower gets the end offset of its epoch to truncate to
+    client.purge_topic(&stream_id, &topic_id).await.unwrap();
+    send_messages(&client, "new value").await;
+    let replica_messages = client
+        .fetch_replica_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            REPLICA_ID,
+            MESSAGES_COUNT as u64,
+            0,
+            6,
+        )
+        .await
+        .unwrap();
+    let diverging_epoch = replica_messages.diverging_epoch.unwrap();
+    assert_eq!(diverging_epoch.epoch, 0);
+    assert_eq!(diverging_epoch.end_offset, 0);
+    assert_eq!(replica_messages.messages.epoch, 1);
+    assert!(replica_messages.messages.messages.is_empty());
+
+    // 5. After truncating its log, the follower fetches the messages of the new epoch
+    let replica_messages = client
+        .fetch_replica_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            REPLICA_ID,
+            diverging_epoch.end_offset,
+            0,
+            MESSAGES_COUNT,
+        )
+        .await
+        .unwrap();
+    assert!(replica_messages.diverging_epoch.is_none());
+    assert_eq!(
+        replica_messages.messages.messages.len(),
+        MESSAGES_COUNT as usize
+    );
+    assert_eq!(replica_messages.messages.messages[0].payload, "new value 0");
+
+    // 6. Once the follower catches up, the consumers see only the messages it has replicated
+    client
+        .fetch_replica_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            REPLICA_ID,
+            MESSAGES_COUNT as u64,
+            1,
+            MESSAGES_COUNT,
+        )
+        .await
+        .unwrap();
+    send_messages(&client, "unreplicated value").await;
+    let partition = client
+        .get_partition(&stream_id, &topic_id, PARTITION_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(partition.high_watermark, MESSAGES_COUNT as u64);
+    assert_eq!(partition.replicas.len(), 1);
+    assert_eq!(partition.replicas[0].id, REPLICA_ID);
+    assert_eq!(partition.replicas[0].fetched_offset, MESSAGES_COUNT as u64);
+    assert!(partition.replicas[0].is_in_sync);
+    assert_eq!(poll_messages_count(&client).await, MESSAGES_COUNT);
+
+    client
+        .fetch_replica_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            REPLICA_ID,
+            2 * MESSAGES_COUNT as u64,
+            1,
+            MESSAGES_COUNT,
+        )
+        .await
+        .unwrap();
+    assert_eq!(poll_messages_count(&client).await, 2 * MESSAGES_COUNT);
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn assert_fetch_error(client: &IggyClient, replica_id: u32, expected_code: u32) {
+    match client
+        .fetch_replica_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            replica_id,
+            MESSAGES_COUNT as u64,
+            0,
+            MESSAGES_COUNT,
+        )
+        .await
+    {
+        Err(IggyError::InvalidResponse(status, _, _)) => assert_eq!(status, expected_code),
+        Err(error) => assert_eq!(error.as_code(), expected_code),
+        Ok(_) => panic!("Expected the replica with ID: {replica_id} to be rejected."),
+    }
+}
+
+async fn send_messages(client: &IggyClient, prefix: &str) {
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::new(None, format!("{prefix} {index}").into(), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn poll_messages_count(client: &IggyClient) -> u32 {
+    client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            3 * MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .len() as u32
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            Default::default(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,152 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, PartitionClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const KEYS_COUNT: u32 = 5;
+const MESSAGES_COUNT: u32 = 20;
+const DELETE_TOPIC_ID: u32 = TOPIC_ID + 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The latest message for each key is returned from the active segment
+    send_messages(&client, 0).await;
+    assert_latest_messages(&client, 0).await;
+
+    // 2. The latest message for each key is returned from the sealed segments as well
+    client
+        .seal_partition(&stream_id, &topic_id, PARTITION_ID, false)
+        .await
+        .unwrap();
+    assert_latest_messages(&client, 0).await;
+    send_messages(&client, MESSAGES_COUNT).await;
+    client
+        .seal_partition(&stream_id, &topic_id, PARTITION_ID, false)
+        .await
+        .unwrap();
+    assert_latest_messages(&client, MESSAGES_COUNT).await;
+
+    // 3. Nothing is returned for the unknown key
+    let polled_messages = client
+        .get_message_by_key(&stream_id, &topic_id, PARTITION_ID, KEYS_COUNT as u128 + 1)
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.partition_id, PARTITION_ID);
+    assert!(polled_messages.messages.is_empty());
+
+    // 4. Nothing is returned for the key whose latest message is a tombstone
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut [Message::tombstone(1)],
+        )
+        .await
+        .unwrap();
+    let polled_messages = client
+        .get_message_by_key(&stream_id, &topic_id, PARTITION_ID, 1)
+        .await
+        .unwrap();
+    assert!(polled_messages.messages.is_empty());
+
+    // 5. The lookup by key is rejected for the topic which is not compacted, and for the non-existing partition
+    assert!(client
+        .get_message_by_key(
+            &stream_id,
+            &Identifier::numeric(DELETE_TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            2
+        )
+        .await
+        .is_err());
+    assert!(client
+        .get_message_by_key(&stream_id, &topic_id, PARTITION_ID + 1, 2)
+        .await
+        .is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_messages(client: &IggyClient, start_index: u32) {
+    let mut messages = (start_index..start_index + MESSAGES_COUNT)
+        .map(|index| {
+            Message::new(
+                Some((index % KEYS_COUNT) as u128 + 1),
+                format!("value {index}").into(),
+                None,
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn assert_latest_messages(client: &IggyClient, start_index: u32) {
+    for key in 1..=KEYS_COUNT {
+        let polled_messages = client
+            .get_message_by_key(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                &Identifier::numeric(TOPIC_ID).unwrap(),
+                PARTITION_ID,
+                key as u128,
+            )
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.messages.len(), 1);
+        let message = &polled_messages.messages[0];
+        let expected_index = start_index + MESSAGES_COUNT - KEYS_COUNT + key - 1;
+        assert_eq!(message.id, key as u128);
+        assert_eq!(message.offset, expected_index as u64);
+        assert_eq!(message.payload, format!("value {expected_index}"));
+    }
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    for (topic_id, name, cleanup_policy) in [
+        (TOPIC_ID, TOPIC_NAME, CleanupPolicy::Compact),
+        (DELETE_TOPIC_ID, "delete-topic", CleanupPolicy::Delete),
+    ] {
+        client
+            .create_topic(
+                &Identifier::numeric(STREAM_ID).unwrap(),
+                name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                Some(topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                cleanup_policy,
+                TopicOverrides::default(),
+            )
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+use crate::server::scenarios::create_client;
+use iggy::client::{StreamClient, TopicClient};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const STREAM_NAMES: [&str; 5] = [
+    "orders-1",
+    "payments-1",
+    "orders-2",
+    "orders-3",
+    "payments-2",
+];
+const TOPIC_NAMES: [&str; 5] = ["events-1", "audit-1", "events-2", "audit-2", "events-3"];
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+
+    // 1. Create the streams and the topics of the first stream
+    for (stream_id, name) in (1..).zip(STREAM_NAMES) {
+        client.create_stream(name, Some(stream_id)).await.unwrap();
+    }
+    let stream_id = Identifier::numeric(1).unwrap();
+    for (topic_id, name) in (1..).zip(TOPIC_NAMES) {
+        client
+            .create_topic(
+                &stream_id,
+                name,
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                Some(topic_id),
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                CleanupPolicy::default(),
+                TopicOverrides::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    // 2. Get the streams page by page and validate that they're ordered by ID
+    let streams = client.get_streams_page(0, Some(2), None).await.unwrap();
+    let stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+    assert_eq!(stream_ids, vec![1, 2]);
+    let streams = client.get_streams_page(2, Some(2), None).await.unwrap();
+    let stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+    assert_eq!(stream_ids, vec![3, 4]);
+    let streams = client.get_streams_page(4, Some(2), None).await.unwrap();
+    let stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+    assert_eq!(stream_ids, vec![5]);
+    let streams = client.get_streams_page(5, None, None).await.unwrap();
+    assert!(streams.is_empty());
+
+    // 3. Get the streams filtered by the name prefix
+    let streams = client
+        .get_streams_page(0, None, Some("orders"))
+        .await
+        .unwrap();
+    let stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+    assert_eq!(stream_ids, vec![1, 3, 4]);
+    let streams = client
+        .get_streams_page(1, Some(1), Some("orders"))
+        .await
+        .unwrap();
+    let stream_ids = streams.iter().map(|stream| stream.id).collect::<Vec<_>>();
+    assert_eq!(stream_ids, vec![3]);
+
+    // 4. Get the topics page by page and filtered by the name prefix
+    let topics = client
+        .get_topics_page(&stream_id, 1, Some(3), None)
+        .await
+        .unwrap();
+    let topic_ids = topics.iter().map(|topic| topic.id).collect::<Vec<_>>();
+    assert_eq!(topic_ids, vec![2, 3, 4]);
+    let topics = client
+        .get_topics_page(&stream_id, 0, None, Some("events"))
+        .await
+        .unwrap();
+    let topic_ids = topics.iter().map(|topic| topic.id).collect::<Vec<_>>();
+    assert_eq!(topic_ids, vec![1, 3, 5]);
+    let topics = client
+        .get_topics_page(&stream_id, 0, None, Some("unknown"))
+        .await
+        .unwrap();
+    assert!(topics.is_empty());
+
+    // 5. Get all the streams and topics without paging
+    let streams = client.get_streams().await.unwrap();
+    assert_eq!(streams.len(), STREAM_NAMES.len());
+    let topics = client.get_topics(&stream_id).await.unwrap();
+    assert_eq!(topics.len(), TOPIC_NAMES.len());
+
+    // 6. Delete the streams
+    for stream_id in 1..=STREAM_NAMES.len() as u32 {
+        client
+            .delete_stream(&Identifier::numeric(stream_id).unwrap())
+            .await
+            .unwrap();
+    }
+    assert_clean_system(&client).await;
+}
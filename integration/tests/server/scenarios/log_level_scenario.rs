@@ -0,0 +1,25 @@
+use crate::server::scenarios::create_client;
+use iggy::client::SystemClient;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+
+    // 1. The global level and the levels per module can be set
+    client.set_log_level("debug").await.unwrap();
+    client
+        .set_log_level("info,server::streaming::segments=trace")
+        .await
+        .unwrap();
+
+    // 2. The invalid filter is rejected
+    assert!(client.set_log_level("info,server=loud").await.is_err());
+    assert!(client.set_log_level(" ").await.is_err());
+
+    // 3. The filter can be restored, and the server keeps working
+    client.set_log_level("info").await.unwrap();
+    client.ping().await.unwrap();
+
+    assert_clean_system(&client).await;
+}
@@ -11,7 +11,10 @@ use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::content_type::ContentType;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use integration::test_server::{assert_clean_system, login_root, ClientFactory};
 use std::collections::HashMap;
@@ -89,6 +92,55 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             123456
         );
     }
+
+    // 3. Send the message with the content type, which has to match the payload
+    let mut messages = vec![Message::from_str("not a json")
+        .unwrap()
+        .with_content_type(ContentType::Json)
+        .unwrap()];
+    let result = client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await;
+    assert!(result.is_err());
+
+    let mut messages = vec![Message::from_str(r#"{"id":1}"#)
+        .unwrap()
+        .with_content_type(ContentType::Json)
+        .unwrap()];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 4. Poll the message and validate its content type
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(MESSAGES_COUNT as u64),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(
+        polled_messages.messages[0].content_type().unwrap(),
+        Some(ContentType::Json)
+    );
+
     cleanup(&client, false).await;
     assert_clean_system(&client).await;
 }
@@ -111,6 +163,8 @@ async fn init_system(client: &IggyClient) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await
         .unwrap();
@@ -0,0 +1,168 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{MessageClient, SchemaClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::schema_type::SchemaType;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const SCHEMA_V1: &str =
+    r#"{"type":"object","required":["id"],"properties":{"id":{"type":"integer"}}}"#;
+const SCHEMA_V2: &str = r#"{"type":"object","required":["id","name"],"properties":{"id":{"type":"integer"},"name":{"type":"string"}}}"#;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Initially, there are no schemas registered for the topic
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert!(schemas.is_empty());
+    let schema = client
+        .get_schema(&stream_id, &topic_id, None)
+        .await
+        .unwrap();
+    assert!(schema.is_none());
+
+    // 2. Validation is not supported for the Avro schemas
+    let result = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::Avro,
+            r#"{"type":"record","name":"Test","fields":[]}"#,
+            true,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 3. Register the first version of the JSON schema with the validation enabled
+    let schema = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            SCHEMA_V1,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema.version, 1);
+    assert_eq!(schema.schema_type, SchemaType::JsonSchema);
+    assert!(schema.validate);
+    assert_eq!(schema.definition, SCHEMA_V1);
+
+    // 4. Send the message matching the schema
+    send_message(&client, r#"{"id":1}"#).await.unwrap();
+
+    // 5. Sending the message violating the schema should fail
+    assert!(send_message(&client, r#"{"name":"test"}"#).await.is_err());
+    assert!(send_message(&client, "not a json").await.is_err());
+
+    // 6. Register the second version of the schema, which becomes the latest one
+    let schema = client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            SCHEMA_V2,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(schema.version, 2);
+    assert!(send_message(&client, r#"{"id":2}"#).await.is_err());
+    send_message(&client, r#"{"id":2,"name":"test"}"#)
+        .await
+        .unwrap();
+
+    // 7. Get the schemas by version and the latest one
+    let schema = client
+        .get_schema(&stream_id, &topic_id, Some(1))
+        .await
+        .unwrap()
+        .expect("Schema version 1 should exist");
+    assert_eq!(schema.definition, SCHEMA_V1);
+    let schema = client
+        .get_schema(&stream_id, &topic_id, None)
+        .await
+        .unwrap()
+        .expect("Latest schema should exist");
+    assert_eq!(schema.version, 2);
+    assert_eq!(schema.definition, SCHEMA_V2);
+    let schema = client
+        .get_schema(&stream_id, &topic_id, Some(3))
+        .await
+        .unwrap();
+    assert!(schema.is_none());
+
+    // 8. Get all the schemas
+    let schemas = client.get_schemas(&stream_id, &topic_id).await.unwrap();
+    assert_eq!(schemas.len(), 2);
+    assert_eq!(schemas[0].version, 1);
+    assert_eq!(schemas[1].version, 2);
+
+    // 9. Register the schema without the validation, which accepts any payload
+    client
+        .register_schema(
+            &stream_id,
+            &topic_id,
+            SchemaType::JsonSchema,
+            SCHEMA_V1,
+            false,
+        )
+        .await
+        .unwrap();
+    send_message(&client, "not a json").await.unwrap();
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_message(client: &IggyClient, payload: &str) -> Result<(), iggy::error::IggyError> {
+    let mut messages = vec![Message::from_str(payload).unwrap()];
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
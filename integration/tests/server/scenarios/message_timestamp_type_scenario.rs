@@ -0,0 +1,154 @@
+use crate::server::scenarios::{create_client, PARTITION_ID, STREAM_ID, STREAM_NAME};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::messages::PolledMessage;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::{
+    create_timestamp_header, get_append_timestamp, get_create_timestamp, TimestampType,
+};
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const CREATE_TIME_TOPIC_ID: u32 = 1;
+const LOG_APPEND_TIME_TOPIC_ID: u32 = 2;
+const CREATE_TIMESTAMPS: [u64; 3] = [1_000_000, 3_000_000, 2_000_000];
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    create_topic(
+        &client,
+        CREATE_TIME_TOPIC_ID,
+        Some(TimestampType::CreateTime),
+    )
+    .await;
+    create_topic(&client, LOG_APPEND_TIME_TOPIC_ID, None).await;
+
+    // 1. The topic reports the effective timestamp type along with its source
+    let topic = client
+        .get_topic(
+            &stream_id,
+            &Identifier::numeric(CREATE_TIME_TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap()
+        .expect("Topic not found");
+    let entry = topic
+        .config
+        .iter()
+        .find(|entry| entry.name == "message_timestamp_type")
+        .expect("Timestamp type not found");
+    assert_eq!(entry.value, TimestampType::CreateTime.to_string());
+
+    // 2. The create time is stored as the message timestamp, while the append time is kept in the header
+    let start = IggyTimestamp::now().as_micros();
+    let messages = send_and_poll_messages(&client, CREATE_TIME_TOPIC_ID).await;
+    assert_eq!(messages.len(), CREATE_TIMESTAMPS.len() + 1);
+    for (message, create_timestamp) in messages.iter().zip(CREATE_TIMESTAMPS) {
+        assert_eq!(message.timestamp, create_timestamp);
+        assert_eq!(
+            get_create_timestamp(&message.headers).unwrap(),
+            Some(create_timestamp)
+        );
+        assert!(get_append_timestamp(&message.headers).unwrap().unwrap() >= start);
+    }
+    // The message without the create time falls back to the append time.
+    let message = messages.last().unwrap();
+    assert!(message.timestamp >= start);
+    assert!(message.headers.is_none());
+
+    // 3. The append time is stored as the message timestamp, while the create time is preserved in the header
+    let messages = send_and_poll_messages(&client, LOG_APPEND_TIME_TOPIC_ID).await;
+    assert_eq!(messages.len(), CREATE_TIMESTAMPS.len() + 1);
+    for (message, create_timestamp) in messages.iter().zip(CREATE_TIMESTAMPS) {
+        assert!(message.timestamp >= start);
+        assert_eq!(
+            get_create_timestamp(&message.headers).unwrap(),
+            Some(create_timestamp)
+        );
+        assert!(get_append_timestamp(&message.headers).unwrap().is_none());
+    }
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(&client).await;
+}
+
+async fn create_topic(
+    client: &IggyClient,
+    topic_id: u32,
+    message_timestamp_type: Option<TimestampType>,
+) {
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &format!("topic-{topic_id}"),
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(topic_id),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides {
+                message_timestamp_type,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_and_poll_messages(client: &IggyClient, topic_id: u32) -> Vec<PolledMessage> {
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(topic_id).unwrap();
+    let mut messages = CREATE_TIMESTAMPS
+        .iter()
+        .map(|timestamp| {
+            let header = create_timestamp_header(IggyTimestamp::from(*timestamp)).unwrap();
+            Message::new(
+                None,
+                format!("message-{timestamp}").into(),
+                Some(HashMap::from([header])),
+            )
+        })
+        .collect::<Vec<_>>();
+    messages.push(Message::from_str("no-create-time").unwrap());
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            10,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+}
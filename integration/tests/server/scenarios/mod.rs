@@ -5,15 +5,53 @@ use iggy::identifier::Identifier;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use integration::test_server::{delete_user, ClientFactory};
 
+pub mod background_io_scenario;
+pub mod blocking_client_scenario;
+pub mod client_middleware_scenario;
+pub mod cluster_metadata_scenario;
+pub mod connection_pool_scenario;
+pub mod connection_string_scenario;
 pub mod consumer_group_join_scenario;
+pub mod consumer_group_rebalance_scenario;
+pub mod consumer_group_static_membership_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_offsets_compaction_scenario;
+pub mod consumer_offsets_delete_scenario;
+pub mod consumer_offsets_reset_scenario;
+pub mod copy_messages_scenario;
 pub mod create_message_payload;
+pub mod disk_space_scenario;
+pub mod exclusive_producer_scenario;
+pub mod fetch_replica_messages_scenario;
+pub mod get_message_by_key_scenario;
+pub mod large_poll_response_scenario;
+pub mod list_paging_scenario;
+pub mod log_level_scenario;
 pub mod message_headers_scenario;
+pub mod message_schema_scenario;
 pub mod message_size_scenario;
+pub mod message_timestamp_type_scenario;
+pub mod named_consumers_scenario;
+pub mod node_drain_scenario;
+pub mod peek_messages_scenario;
+pub mod poll_messages_from_partitions_scenario;
+pub mod poll_messages_max_bytes_scenario;
+pub mod poll_messages_pagination_scenario;
+pub mod producer_epoch_scenario;
+pub mod resource_limits_scenario;
+pub mod retry_policy_scenario;
+pub mod seal_partition_scenario;
+pub mod send_messages_ack_scenario;
+pub mod session_management_scenario;
 pub mod stream_size_validation_scenario;
+pub mod subscription_scenario;
+pub mod system_events_scenario;
 pub mod system_scenario;
+pub mod topic_read_only_scenario;
+pub mod typed_consumer_scenario;
 pub mod user_scenario;
+pub mod wire_checksum_scenario;
 
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
@@ -53,6 +91,7 @@ async fn join_consumer_group(client: &IggyClient) {
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             &Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            None,
         )
         .await
         .unwrap();
@@ -0,0 +1,176 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u64 = 10;
+const STORING_CONSUMER_NAME: &str = "orders-service";
+const POLLING_CONSUMER_NAME: &str = "audit-service";
+const NUMERIC_CONSUMER_ID: u32 = 7;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let storing_consumer = Consumer::new(Identifier::named(STORING_CONSUMER_NAME).unwrap());
+    let polling_consumer = Consumer::new(Identifier::named(POLLING_CONSUMER_NAME).unwrap());
+    let numeric_consumer = Consumer::new(Identifier::numeric(NUMERIC_CONSUMER_ID).unwrap());
+
+    // 1. Store the offsets for the consumers identified by name and by ID, and auto commit the offset by polling
+    client
+        .store_consumer_offset(
+            &storing_consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            3,
+        )
+        .await
+        .unwrap();
+    client
+        .store_consumer_offset(
+            &numeric_consumer,
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            MESSAGES_COUNT - 1,
+        )
+        .await
+        .unwrap();
+    client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &polling_consumer,
+            &PollingStrategy::offset(0),
+            5,
+            true,
+        )
+        .await
+        .unwrap();
+
+    // 2. The consumers are listed by ID along with the names of the ones identified by name
+    let consumers = get_consumers(&client).await;
+    assert_eq!(consumers.len(), 3);
+    assert!(consumers
+        .windows(2)
+        .all(|consumers| consumers[0].id < consumers[1].id));
+    let storing = find_consumer(&consumers, Some(STORING_CONSUMER_NAME));
+    assert_eq!(storing.stored_offset, 3);
+    assert_eq!(storing.lag, MESSAGES_COUNT - 1 - 3);
+    let polling = find_consumer(&consumers, Some(POLLING_CONSUMER_NAME));
+    assert_eq!(polling.stored_offset, 4);
+    assert_eq!(polling.lag, MESSAGES_COUNT - 1 - 4);
+    let numeric = find_consumer(&consumers, None);
+    assert_eq!(numeric.id, NUMERIC_CONSUMER_ID);
+    assert_eq!(numeric.stored_offset, MESSAGES_COUNT - 1);
+    assert_eq!(numeric.lag, 0);
+
+    // 3. Delete the consumer identified by name along with its offset
+    client
+        .delete_consumer(&storing_consumer.id, &stream_id, &topic_id, PARTITION_ID)
+        .await
+        .unwrap();
+    let consumers = get_consumers(&client).await;
+    assert_eq!(consumers.len(), 2);
+    assert!(consumers
+        .iter()
+        .all(|consumer| consumer.name.as_deref() != Some(STORING_CONSUMER_NAME)));
+    let lags = client
+        .get_consumer_lag(&storing_consumer, &stream_id, &topic_id, Some(PARTITION_ID))
+        .await
+        .unwrap();
+    assert!(lags[0].stored_offset.is_none());
+
+    // 4. Deleting the unknown consumer should fail
+    let result = client
+        .delete_consumer(&storing_consumer.id, &stream_id, &topic_id, PARTITION_ID)
+        .await;
+    assert!(result.is_err());
+
+    // 5. Delete the remaining consumers
+    for consumer in [&polling_consumer, &numeric_consumer] {
+        client
+            .delete_consumer(&consumer.id, &stream_id, &topic_id, PARTITION_ID)
+            .await
+            .unwrap();
+    }
+    assert!(get_consumers(&client).await.is_empty());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+fn find_consumer<'a>(consumers: &'a [ConsumerInfo], name: Option<&str>) -> &'a ConsumerInfo {
+    consumers
+        .iter()
+        .find(|consumer| consumer.name.as_deref() == name)
+        .expect("Failed to find consumer")
+}
+
+async fn get_consumers(client: &IggyClient) -> Vec<ConsumerInfo> {
+    client
+        .get_consumers(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+        )
+        .await
+        .unwrap()
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+
+    // 3. Send the messages to the partition
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::from_str(&format!("message {offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,211 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID,
+    TOPIC_NAME,
+};
+use iggy::client::{MessageClient, PartitionClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::node_status::{NodeState, NodeStatus};
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+const NODE_ID: u32 = 1;
+pub const REPLICA_ID: u32 = 2;
+const MESSAGES_COUNT: u32 = 10;
+const EMPTY_PARTITION_ID: u32 = 2;
+const LAGGING_PARTITION_ID: u32 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The active node leads all of its partitions
+    let status = client.get_node_status(NODE_ID).await.unwrap();
+    assert_eq!(status, node_status(NodeState::Active, PARTITIONS_COUNT));
+    assert_error(
+        client.get_node_status(REPLICA_ID).await,
+        IggyError::NodeNotFound(REPLICA_ID),
+    );
+    assert_error(
+        client.decommission_node(NODE_ID).await,
+        IggyError::NodeNotDraining(NODE_ID),
+    );
+
+    // 2. The replica catches up with the partition containing the messages and the empty one
+    send_messages(&client).await;
+    for offset in [0, MESSAGES_COUNT as u64] {
+        client
+            .fetch_replica_messages(
+                &stream_id,
+                &topic_id,
+                PARTITION_ID,
+                REPLICA_ID,
+                offset,
+                0,
+                MESSAGES_COUNT,
+            )
+            .await
+            .unwrap();
+    }
+    fetch_replica_messages(&client, EMPTY_PARTITION_ID).await;
+
+    // 3. Draining the node transfers the leadership of the partitions replicated by the in-sync replica
+    let status = client.drain_node(NODE_ID).await.unwrap();
+    assert_eq!(status, node_status(NodeState::Draining, 1));
+    let metadata = client.get_cluster_metadata().await.unwrap();
+    let leaders = metadata
+        .partitions
+        .iter()
+        .map(|partition| partition.leader_id)
+        .collect::<Vec<_>>();
+    assert_eq!(leaders, vec![REPLICA_ID, REPLICA_ID, NODE_ID]);
+
+    // 4. The partitions led by another node reject the messages sent to and polled from them
+    let mut messages = vec![Message::new(None, "value".into(), None)];
+    assert_error(
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await,
+        IggyError::NotLeader(NODE_ID, PARTITION_ID, TOPIC_ID, STREAM_ID),
+    );
+    assert_error(
+        client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(PARTITION_ID),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                MESSAGES_COUNT,
+                false,
+            )
+            .await,
+        IggyError::NotLeader(NODE_ID, PARTITION_ID, TOPIC_ID, STREAM_ID),
+    );
+
+    // 5. The draining node accepts no new partitions
+    assert_error(
+        client.create_partitions(&stream_id, &topic_id, 1).await,
+        IggyError::NodeDraining(NODE_ID),
+    );
+    assert_error(
+        client
+            .create_topic(
+                &stream_id,
+                "another-topic",
+                1,
+                CompressionAlgorithm::default(),
+                None,
+                None,
+                IggyExpiry::NeverExpire,
+                MaxTopicSize::ServerDefault,
+                Default::default(),
+                Default::default(),
+            )
+            .await,
+        IggyError::NodeDraining(NODE_ID),
+    );
+
+    // 6. The node still leading the partition can't be decommissioned
+    assert_error(
+        client.decommission_node(NODE_ID).await,
+        IggyError::NodeNotEmpty(NODE_ID, 1),
+    );
+
+    // 7. The leadership is transferred once the lagging replica catches up, and the empty node can be decommissioned
+    fetch_replica_messages(&client, LAGGING_PARTITION_ID).await;
+    let status = client.get_node_status(NODE_ID).await.unwrap();
+    assert_eq!(status, node_status(NodeState::Draining, 0));
+    let status = client.decommission_node(NODE_ID).await.unwrap();
+    assert_eq!(status, node_status(NodeState::Decommissioned, 0));
+    let status = client.drain_node(NODE_ID).await.unwrap();
+    assert_eq!(status, node_status(NodeState::Decommissioned, 0));
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+fn node_status(state: NodeState, led_partitions_count: u32) -> NodeStatus {
+    NodeStatus {
+        node_id: NODE_ID,
+        state,
+        partitions_count: PARTITIONS_COUNT,
+        led_partitions_count,
+    }
+}
+
+fn assert_error<T>(result: Result<T, IggyError>, expected_error: IggyError) {
+    match result {
+        Err(IggyError::InvalidResponse(status, _, _)) => {
+            assert_eq!(status, expected_error.as_code())
+        }
+        Err(error) => assert_eq!(error.as_code(), expected_error.as_code()),
+        Ok(_) => panic!("Expected the error: {expected_error}."),
+    }
+}
+
+async fn fetch_replica_messages(client: &IggyClient, partition_id: u32) {
+    client
+        .fetch_replica_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id,
+            REPLICA_ID,
+            0,
+            0,
+            MESSAGES_COUNT,
+        )
+        .await
+        .unwrap();
+}
+
+async fn send_messages(client: &IggyClient) {
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::new(None, format!("value {index}").into(), None))
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            Default::default(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+}
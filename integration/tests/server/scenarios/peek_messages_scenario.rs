@@ -0,0 +1,182 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::peek_messages::PeekKind;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+
+const MESSAGES_COUNT: u32 = 20;
+const PEEK_COUNT: u32 = 5;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Send the messages with headers to the partition
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| {
+            let headers = HashMap::from([(
+                HeaderKey::new("index").unwrap(),
+                HeaderValue::from_uint32(offset).unwrap(),
+            )]);
+            Message::new(None, format!("message {offset}").into(), Some(headers))
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 2. Peek the first messages
+    let peeked_messages = client
+        .peek_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            PeekKind::First,
+            PEEK_COUNT,
+        )
+        .await
+        .unwrap();
+    assert_eq!(peeked_messages.partition_id, PARTITION_ID);
+    assert_eq!(peeked_messages.current_offset, MESSAGES_COUNT as u64 - 1);
+    assert_eq!(peeked_messages.messages.len() as u32, PEEK_COUNT);
+    for (offset, message) in peeked_messages.messages.iter().enumerate() {
+        assert_eq!(message.offset, offset as u64);
+        assert_eq!(message.payload, format!("message {offset}"));
+        let index = message
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(&HeaderKey::new("index").unwrap())
+            .unwrap()
+            .as_uint32()
+            .unwrap();
+        assert_eq!(index, offset as u32);
+    }
+
+    // 3. Peek the last messages
+    let peeked_messages = client
+        .peek_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            PeekKind::Last,
+            PEEK_COUNT,
+        )
+        .await
+        .unwrap();
+    assert_eq!(peeked_messages.messages.len() as u32, PEEK_COUNT);
+    assert_eq!(
+        peeked_messages.messages.first().unwrap().offset,
+        (MESSAGES_COUNT - PEEK_COUNT) as u64
+    );
+    assert_eq!(
+        peeked_messages.messages.last().unwrap().offset,
+        MESSAGES_COUNT as u64 - 1
+    );
+
+    // 4. Peek the random messages, which are unique and ordered by the offset
+    let peeked_messages = client
+        .peek_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            PeekKind::Random,
+            PEEK_COUNT,
+        )
+        .await
+        .unwrap();
+    assert_eq!(peeked_messages.messages.len() as u32, PEEK_COUNT);
+    for messages in peeked_messages.messages.windows(2) {
+        assert!(messages[0].offset < messages[1].offset);
+    }
+    for message in &peeked_messages.messages {
+        assert!(message.offset < MESSAGES_COUNT as u64);
+        assert_eq!(message.payload, format!("message {}", message.offset));
+    }
+
+    // 5. Peeking more messages than available returns all the messages
+    let peeked_messages = client
+        .peek_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID,
+            PeekKind::Random,
+            MESSAGES_COUNT * 2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(peeked_messages.messages.len() as u32, MESSAGES_COUNT);
+
+    // 6. Peeking doesn't store any consumer offset, which would be the last offset after polling with the auto commit
+    let offset = client
+        .get_consumer_offset(
+            &Consumer::default(),
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap();
+    assert!(offset.is_none_or(|offset| offset.stored_offset == 0));
+
+    // 7. Peeking from the non-existing partition fails
+    let result = client
+        .peek_messages(
+            &stream_id,
+            &topic_id,
+            PARTITION_ID + 100,
+            PeekKind::First,
+            1,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,150 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITIONS_COUNT, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::OutOfRangePolicy;
+use iggy::messages::poll_messages_from_partitions::PartitionOffset;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_PER_PARTITION: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let consumer = Consumer::default();
+
+    // 1. Send the messages to each partition
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let mut messages = (0..MESSAGES_PER_PARTITION)
+            .map(|offset| {
+                Message::from_str(&format!("partition {partition_id}, message {offset}")).unwrap()
+            })
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(partition_id),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+
+    // 2. Poll the messages from all the partitions, each one starting at a different offset
+    let partitions = (1..=PARTITIONS_COUNT)
+        .map(|partition_id| PartitionOffset::new(partition_id, partition_id as u64))
+        .collect::<Vec<_>>();
+    let batches = client
+        .poll_messages_from_partitions(
+            &stream_id,
+            &topic_id,
+            &consumer,
+            &partitions,
+            OutOfRangePolicy::Error,
+            MESSAGES_PER_PARTITION,
+            true,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(batches.len() as u32, PARTITIONS_COUNT);
+    for (batch, partition) in batches.iter().zip(&partitions) {
+        assert_eq!(batch.partition_id, partition.partition_id);
+        assert_eq!(
+            batch.messages.len() as u64,
+            MESSAGES_PER_PARTITION as u64 - partition.offset
+        );
+        let first_message = batch.messages.first().unwrap();
+        assert_eq!(first_message.offset, partition.offset);
+        assert_eq!(
+            first_message.payload,
+            format!(
+                "partition {}, message {}",
+                partition.partition_id, partition.offset
+            )
+        );
+    }
+
+    // 3. The offsets are stored for each partition when the auto commit is enabled
+    for partition_id in 1..=PARTITIONS_COUNT {
+        let offset = client
+            .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(partition_id))
+            .await
+            .unwrap()
+            .expect("Consumer offset should exist");
+        assert_eq!(offset.stored_offset, MESSAGES_PER_PARTITION as u64 - 1);
+    }
+
+    // 4. Polling beyond the high watermark of any partition fails with the default policy
+    let result = client
+        .poll_messages_from_partitions(
+            &stream_id,
+            &topic_id,
+            &consumer,
+            &[
+                PartitionOffset::new(1, 0),
+                PartitionOffset::new(2, MESSAGES_PER_PARTITION as u64 + 10),
+            ],
+            OutOfRangePolicy::Error,
+            1,
+            false,
+        )
+        .await;
+    assert!(result.is_err());
+
+    // 5. The duplicated partitions are rejected
+    let result = client
+        .poll_messages_from_partitions(
+            &stream_id,
+            &topic_id,
+            &consumer,
+            &[PartitionOffset::new(1, 0), PartitionOffset::new(1, 5)],
+            OutOfRangePolicy::Error,
+            1,
+            false,
+        )
+        .await;
+    assert!(result.is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
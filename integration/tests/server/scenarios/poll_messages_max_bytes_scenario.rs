@@ -0,0 +1,122 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 20;
+const PAYLOAD_SIZE: u32 = 100;
+// ID (16) + length (4) + offset delta (4) + timestamp delta (4) + state (1) + headers length (4) + payload
+const MESSAGE_SIZE: u32 = 16 + 4 + 4 + 4 + 1 + 4 + PAYLOAD_SIZE;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Send the messages of the same size
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| {
+            Message::from_str(&format!("{index:0>width$}", width = PAYLOAD_SIZE as usize)).unwrap()
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 2. Polling stops when the maximum size is reached before the count
+    let polled_messages = poll_messages(&client, 2, MESSAGES_COUNT, 5 * MESSAGE_SIZE).await;
+    assert_eq!(polled_messages, (2..7).collect::<Vec<_>>());
+
+    // 3. The message which would exceed the maximum size is not included
+    let polled_messages = poll_messages(
+        &client,
+        0,
+        MESSAGES_COUNT,
+        5 * MESSAGE_SIZE + MESSAGE_SIZE / 2,
+    )
+    .await;
+    assert_eq!(polled_messages, (0..5).collect::<Vec<_>>());
+
+    // 4. Polling stops when the count is reached before the maximum size
+    let polled_messages = poll_messages(&client, 0, 3, 10 * MESSAGE_SIZE).await;
+    assert_eq!(polled_messages, (0..3).collect::<Vec<_>>());
+
+    // 5. At least one message is returned, even if it exceeds the maximum size
+    let polled_messages = poll_messages(&client, 10, MESSAGES_COUNT, 1).await;
+    assert_eq!(polled_messages, vec![10]);
+
+    // 6. No maximum size returns all the requested messages
+    let polled_messages = poll_messages(&client, 0, MESSAGES_COUNT, 0).await;
+    assert_eq!(
+        polled_messages,
+        (0..MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn poll_messages(client: &IggyClient, offset: u64, count: u32, max_bytes: u32) -> Vec<u64> {
+    client
+        .poll_messages_with_max_bytes(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(offset),
+            count,
+            max_bytes,
+            false,
+        )
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| message.offset)
+        .collect()
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
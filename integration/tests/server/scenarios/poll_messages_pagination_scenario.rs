@@ -0,0 +1,179 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{PollingDirection, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::messages::PolledMessages;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const BATCHES_COUNT: u32 = 10;
+const MESSAGES_PER_BATCH: u32 = 10;
+const MESSAGES_COUNT: u32 = BATCHES_COUNT * MESSAGES_PER_BATCH;
+const PAYLOAD_SIZE: u32 = 100;
+// ID (16) + length (4) + offset delta (4) + timestamp delta (4) + state (1) + headers length (4) + payload
+const MESSAGE_SIZE: u32 = 16 + 4 + 4 + 4 + 1 + 4 + PAYLOAD_SIZE;
+
+/// Expects the server to be started with the segment size of 1 KiB and the maximum of 7 messages returned by a single poll.
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Send the messages spanning multiple segments
+    for batch in 0..BATCHES_COUNT {
+        let mut messages = (0..MESSAGES_PER_BATCH)
+            .map(|index| {
+                let index = batch * MESSAGES_PER_BATCH + index;
+                Message::from_str(&format!("{index:0>width$}", width = PAYLOAD_SIZE as usize))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        client
+            .send_messages(
+                &stream_id,
+                &topic_id,
+                &Partitioning::partition_id(PARTITION_ID),
+                &mut messages,
+            )
+            .await
+            .unwrap();
+    }
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Topic not found");
+    assert!(topic.partitions[0].segments_count > 1);
+
+    // 2. The requested count is capped by the server, and the cursor pages through all the messages
+    let pages = poll_all_pages(&client, PollingStrategy::offset(0), MESSAGES_COUNT, 0).await;
+    assert_eq!(pages.len(), MESSAGES_COUNT.div_ceil(7) as usize);
+    assert!(pages.iter().all(|page| page.len() <= 7));
+    assert_eq!(
+        pages.concat(),
+        (0..MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+
+    // 3. The cursor is returned when the maximum size is reached before the count
+    let pages = poll_all_pages(&client, PollingStrategy::offset(0), 5, 3 * MESSAGE_SIZE).await;
+    assert_eq!(pages.len(), MESSAGES_COUNT.div_ceil(3) as usize);
+    assert_eq!(
+        pages.concat(),
+        (0..MESSAGES_COUNT as u64).collect::<Vec<_>>()
+    );
+
+    // 4. Paging backward continues from the message preceding the oldest polled one
+    let strategy =
+        PollingStrategy::offset(MESSAGES_COUNT as u64 - 1).direction(PollingDirection::Backward);
+    let pages = poll_all_pages(&client, strategy, 10, 0).await;
+    assert_eq!(pages.len(), MESSAGES_COUNT.div_ceil(7) as usize);
+    let mut offsets = pages.concat();
+    offsets.sort_unstable();
+    assert_eq!(offsets, (0..MESSAGES_COUNT as u64).collect::<Vec<_>>());
+
+    // 5. No cursor is returned once the end of the partition is reached
+    let polled_messages = poll_messages(
+        &client,
+        &PollingStrategy::offset(MESSAGES_COUNT as u64 - 2),
+        5,
+        0,
+    )
+    .await;
+    assert_eq!(polled_messages.messages.len(), 2);
+    assert!(polled_messages.next_offset.is_none());
+
+    // 6. Polling by the timestamp range stops at the end timestamp, also across the pages
+    let start_timestamp = poll_messages(&client, &PollingStrategy::offset(30), 1, 0)
+        .await
+        .messages[0]
+        .timestamp;
+    let end_timestamp = poll_messages(&client, &PollingStrategy::offset(60), 1, 0)
+        .await
+        .messages[0]
+        .timestamp;
+    let strategy = PollingStrategy::timestamp(start_timestamp.into()).until(end_timestamp.into());
+    let pages = poll_all_pages(&client, strategy, 10, 0).await;
+    assert_eq!(pages.len(), 30u32.div_ceil(7) as usize);
+    assert_eq!(pages.concat(), (30..60).collect::<Vec<_>>());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn poll_all_pages(
+    client: &IggyClient,
+    strategy: PollingStrategy,
+    count: u32,
+    max_bytes: u32,
+) -> Vec<Vec<u64>> {
+    let mut pages = Vec::new();
+    let mut strategy = Some(strategy);
+    while let Some(current_strategy) = strategy {
+        let polled_messages = poll_messages(client, &current_strategy, count, max_bytes).await;
+        assert!(!polled_messages.messages.is_empty());
+        pages.push(
+            polled_messages
+                .messages
+                .iter()
+                .map(|message| message.offset)
+                .collect(),
+        );
+        strategy = polled_messages.next_polling_strategy(&current_strategy);
+    }
+    pages
+}
+
+async fn poll_messages(
+    client: &IggyClient,
+    strategy: &PollingStrategy,
+    count: u32,
+    max_bytes: u32,
+) -> PolledMessages {
+    client
+        .poll_messages_with_max_bytes(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            strategy,
+            count,
+            max_bytes,
+            false,
+        )
+        .await
+        .unwrap()
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
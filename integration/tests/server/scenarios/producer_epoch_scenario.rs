@@ -0,0 +1,130 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{Client, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+    let client3 = create_client(client_factory).await;
+    login_root(&client1).await;
+    login_root(&client2).await;
+    login_root(&client3).await;
+    init_system(&client1).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The first producer instance gets the initial epoch and can send the messages
+    let epoch = client1
+        .init_producer_epoch(&stream_id, &topic_id, 0)
+        .await
+        .unwrap();
+    assert_eq!(epoch, 1);
+    send_message(&client1).await.unwrap();
+
+    // 2. The new producer instance bumps the epoch and fences the previous one, which is still connected
+    let epoch = client2
+        .init_producer_epoch(&stream_id, &topic_id, 0)
+        .await
+        .unwrap();
+    assert_eq!(epoch, 2);
+    send_message(&client2).await.unwrap();
+    assert_error(
+        send_message(&client1).await,
+        IggyError::ProducerFenced(TOPIC_ID, STREAM_ID, 0),
+    );
+
+    // 3. The previous instance cannot resume its stale epoch
+    assert_error(
+        client1
+            .init_producer_epoch(&stream_id, &topic_id, 1)
+            .await
+            .map(|_| ()),
+        IggyError::StaleProducerEpoch(1),
+    );
+    assert_error(
+        client1
+            .init_producer_epoch(&stream_id, &topic_id, 3)
+            .await
+            .map(|_| ()),
+        IggyError::InvalidProducerEpoch(3),
+    );
+
+    // 4. The current instance resumes its epoch after reconnecting (simulated by another client)
+    client2.disconnect().await.unwrap();
+    let mut result = Err(IggyError::ProducerFenced(TOPIC_ID, STREAM_ID, 0));
+    for _ in 0..50 {
+        result = client3.init_producer_epoch(&stream_id, &topic_id, 2).await;
+        if result.is_ok() {
+            break;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(result.unwrap(), 2);
+    send_message(&client3).await.unwrap();
+    assert_error(
+        send_message(&client1).await,
+        IggyError::ProducerFenced(TOPIC_ID, STREAM_ID, 0),
+    );
+
+    cleanup(&client3, false).await;
+    assert_clean_system(&client3).await;
+}
+
+async fn send_message(client: &IggyClient) -> Result<(), IggyError> {
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut [Message::from_str("message").unwrap()],
+        )
+        .await
+}
+
+fn assert_error(result: Result<(), IggyError>, expected_error: IggyError) {
+    let expected_code = expected_error.as_code();
+    match result {
+        Err(IggyError::InvalidResponse(status, _, _)) => assert_eq!(status, expected_code),
+        Err(error) => assert_eq!(error.as_code(), expected_code),
+        Ok(()) => panic!("Expected the error: {expected_error}."),
+    }
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,104 @@
+use crate::server::scenarios::{
+    cleanup, create_client, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{PartitionClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::models::topic::TopicDetails;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+
+/// Expects the server to be started with the limits of 1 stream, 1 topic per stream and 2 partitions per topic.
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. The stream over the limit is rejected
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    let result = client
+        .create_stream("another-stream", Some(STREAM_ID + 1))
+        .await;
+    assert_error(
+        result.map(|_| ()),
+        IggyError::StreamsLimitReached(1).as_code(),
+    );
+
+    // 2. The topic over the limit is rejected
+    create_topic(&client, TOPIC_NAME, TOPIC_ID, 1)
+        .await
+        .unwrap();
+    let result = create_topic(&client, "another-topic", TOPIC_ID + 1, 1).await;
+    assert_error(
+        result.map(|_| ()),
+        IggyError::TopicsLimitReached(1, STREAM_ID).as_code(),
+    );
+
+    // 3. The partitions over the limit are rejected, and none of them is created
+    client
+        .create_partitions(&stream_id, &topic_id, 1)
+        .await
+        .unwrap();
+    let result = client.create_partitions(&stream_id, &topic_id, 1).await;
+    assert_error(
+        result,
+        IggyError::PartitionsLimitReached(2, TOPIC_ID, STREAM_ID).as_code(),
+    );
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Topic not found");
+    assert_eq!(topic.partitions_count, 2);
+
+    // 4. The resources can be created again, once the other ones are deleted
+    cleanup(&client, false).await;
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    create_topic(&client, TOPIC_NAME, TOPIC_ID, 2)
+        .await
+        .unwrap();
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn create_topic(
+    client: &IggyClient,
+    name: &str,
+    topic_id: u32,
+    partitions_count: u32,
+) -> Result<TopicDetails, IggyError> {
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            name,
+            partitions_count,
+            CompressionAlgorithm::default(),
+            None,
+            Some(topic_id),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+}
+
+fn assert_error(result: Result<(), IggyError>, expected_code: u32) {
+    match result {
+        Err(IggyError::InvalidResponse(code, _, _)) => assert_eq!(code, expected_code),
+        result => panic!("Expected error with code: {expected_code}, got: {result:?}"),
+    }
+}
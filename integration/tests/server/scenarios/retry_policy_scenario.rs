@@ -0,0 +1,101 @@
+use iggy::client::{Client, MessageClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const PARTITION_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+
+pub async fn run(transport: &str, server_addr: &str) {
+    let connection_string = |idempotent_producer: bool| {
+        format!(
+            "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?transport={transport}&reestablish_after=1ms&retry_attempts=3&retry_backoff=10ms&idempotent_producer={idempotent_producer}"
+        )
+    };
+    let admin = IggyClient::from_connection_string(&connection_string(false)).unwrap();
+    let client = IggyClient::from_connection_string(&connection_string(false)).unwrap();
+    let idempotent_client = IggyClient::from_connection_string(&connection_string(true)).unwrap();
+    for client in [&admin, &client, &idempotent_client] {
+        client.connect().await.unwrap();
+    }
+    init_system(&admin).await;
+
+    // 1. The safe command is retried after reconnecting
+    kill_session(&admin, &client).await;
+    let streams = client.get_streams().await.unwrap();
+    assert_eq!(streams.len(), 1);
+
+    // 2. Sending the messages is not retried, but the client reconnects for the next commands
+    kill_session(&admin, &client).await;
+    assert!(send_message(&client).await.is_err());
+    send_message(&client).await.unwrap();
+
+    // 3. Sending the messages is retried by the idempotent producer
+    kill_session(&admin, &idempotent_client).await;
+    send_message(&idempotent_client).await.unwrap();
+
+    let topic = admin
+        .get_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(topic.messages_count, 2);
+
+    admin
+        .delete_stream(&Identifier::numeric(STREAM_ID).unwrap())
+        .await
+        .unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            Default::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
+
+async fn kill_session(admin: &IggyClient, client: &IggyClient) {
+    let client_id = client.get_me().await.unwrap().client_id;
+    admin.kill_session(client_id).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+}
+
+async fn send_message(client: &IggyClient) -> Result<(), iggy::error::IggyError> {
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut [Message::from_str("message").unwrap()],
+        )
+        .await
+}
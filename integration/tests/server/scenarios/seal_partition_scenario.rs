@@ -0,0 +1,113 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, PartitionClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 1. Sealing the partition closes its active segment at the current offset
+    send_messages(&client).await.unwrap();
+    let sealed_partition = client
+        .seal_partition(&stream_id, &topic_id, PARTITION_ID, false)
+        .await
+        .unwrap();
+    assert_eq!(sealed_partition.partition_id, PARTITION_ID);
+    assert_eq!(sealed_partition.high_watermark, MESSAGES_COUNT as u64);
+    assert_eq!(sealed_partition.segments_count, 1);
+    let partition = client
+        .get_partition(&stream_id, &topic_id, PARTITION_ID)
+        .await
+        .unwrap()
+        .expect("Partition not found");
+    assert!(partition.segments[0].is_closed);
+    assert_eq!(partition.segments[0].end_offset, MESSAGES_COUNT as u64 - 1);
+
+    // 2. The messages sent afterwards are appended to the new segment
+    send_messages(&client).await.unwrap();
+    let partition = client
+        .get_partition(&stream_id, &topic_id, PARTITION_ID)
+        .await
+        .unwrap()
+        .expect("Partition not found");
+    assert_eq!(partition.segments_count, 2);
+    assert_eq!(partition.segments[1].start_offset, MESSAGES_COUNT as u64);
+    assert!(!partition.segments[1].is_closed);
+
+    // 3. Sealing the partition with the read-only flag freezes the writes to the topic
+    let sealed_partition = client
+        .seal_partition(&stream_id, &topic_id, PARTITION_ID, true)
+        .await
+        .unwrap();
+    assert_eq!(sealed_partition.high_watermark, 2 * MESSAGES_COUNT as u64);
+    assert_eq!(sealed_partition.segments_count, 2);
+    let topic = client
+        .get_topic(&stream_id, &topic_id)
+        .await
+        .unwrap()
+        .expect("Topic not found");
+    assert!(topic.read_only);
+    assert!(send_messages(&client).await.is_err());
+
+    // 4. Sealing the non-existing partition fails
+    assert!(client
+        .seal_partition(&stream_id, &topic_id, PARTITION_ID + 1, false)
+        .await
+        .is_err());
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn send_messages(client: &IggyClient) -> Result<(), IggyError> {
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::from_str(&format!("message-{index}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+}
+
+async fn init_system(client: &IggyClient) {
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,116 @@
+use crate::server::scenarios::{
+    cleanup, create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const MESSAGES_PER_ACK_LEVEL: u32 = 10;
+const ACK_LEVELS: [AckLevel; 4] = [
+    AckLevel::Leader,
+    AckLevel::Fsync,
+    AckLevel::All,
+    AckLevel::None,
+];
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    init_system(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    let partitioning = Partitioning::partition_id(PARTITION_ID);
+
+    // 1. Send the messages using each acknowledgement level
+    for ack in ACK_LEVELS {
+        let mut messages = (0..MESSAGES_PER_ACK_LEVEL)
+            .map(|index| Message::from_str(&format!("{ack} {index}")).unwrap())
+            .collect::<Vec<_>>();
+        client
+            .send_messages_with_ack(&stream_id, &topic_id, &partitioning, ack, &mut messages)
+            .await
+            .unwrap();
+    }
+
+    // 2. All the messages are eventually appended, including the unacknowledged ones
+    let expected_count = MESSAGES_PER_ACK_LEVEL * ACK_LEVELS.len() as u32;
+    let mut polled_messages = Vec::new();
+    for _ in 0..50 {
+        polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(PARTITION_ID),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                expected_count,
+                false,
+            )
+            .await
+            .unwrap()
+            .messages;
+        if polled_messages.len() as u32 == expected_count {
+            break;
+        }
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(polled_messages.len() as u32, expected_count);
+    for (ack_index, ack) in ACK_LEVELS.iter().enumerate() {
+        let first_message = &polled_messages[ack_index * MESSAGES_PER_ACK_LEVEL as usize];
+        assert_eq!(first_message.payload, format!("{ack} 0"));
+    }
+
+    // 3. The unacknowledged messages sent to a non-existing partition do not fail the request
+    let mut messages = vec![Message::from_str("lost").unwrap()];
+    client
+        .send_messages_with_ack(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID + 100),
+            AckLevel::None,
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    cleanup(&client, false).await;
+    assert_clean_system(&client).await;
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+
+    // 2. Create the topic
+    client
+        .create_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+}
@@ -0,0 +1,53 @@
+use crate::server::scenarios::create_client;
+use iggy::client::{StreamClient, SystemClient};
+use iggy::command::GET_ME;
+use iggy::error::IggyError;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+const UNKNOWN_CLIENT_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+    login_root(&client1).await;
+    login_root(&client2).await;
+    let client2_id = client2.get_me().await.unwrap().client_id;
+
+    // 1. Both sessions are listed, including the last command sent by the client
+    let sessions = client1.get_sessions().await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    let session = sessions
+        .iter()
+        .find(|session| session.client_id == client2_id)
+        .expect("Session of the second client should be listed.");
+    assert_eq!(session.user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert!(!session.address.is_empty());
+    assert!(session.connected_at.as_micros() > 0);
+    assert_eq!(session.last_command.as_deref(), Some(GET_ME));
+    assert!(session.last_command_at.unwrap().as_micros() >= session.connected_at.as_micros());
+    let connected_at = session.connected_at;
+
+    // 2. Killing the session closes the connection of the second client
+    client1.kill_session(client2_id).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+    assert!(client2.get_streams().await.is_err());
+
+    // 3. The killed session is not listed anymore (the client might have reconnected with a new session)
+    let sessions = client1.get_sessions().await.unwrap();
+    assert!(sessions
+        .iter()
+        .all(|session| session.client_id != client2_id || session.connected_at != connected_at));
+
+    // 4. Killing the unknown session fails
+    let error = client1.kill_session(UNKNOWN_CLIENT_ID).await.unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::InvalidResponse(status, _, _)
+            if status == IggyError::SessionNotFound(UNKNOWN_CLIENT_ID).as_code()
+    ));
+
+    assert_clean_system(&client1).await;
+}
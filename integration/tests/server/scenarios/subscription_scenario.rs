@@ -0,0 +1,211 @@
+use crate::server::scenarios::{
+    create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use iggy::client::{ConsumerOffsetClient, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::clients::consumer::{AutoCommit, ReceivedMessage};
+use iggy::clients::subscription::{AckHandle, Subscription};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+use tokio::time::{sleep, timeout};
+
+const CONSUMER_NAME: &str = "subscriber";
+const MESSAGES_COUNT: u64 = 10;
+const ACKNOWLEDGED_MESSAGES_COUNT: u64 = 5;
+const PARALLEL_MESSAGES_COUNT: u64 = 20;
+const KEYS_COUNT: u64 = 4;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|offset| Message::from_str(&format!("message-{offset}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 1. All the messages are dispatched to the handler, but only the acknowledged ones have their offset stored
+    let (subscription, mut receiver) = subscribe(&client, ACKNOWLEDGED_MESSAGES_COUNT).await;
+    let offsets = receive_offsets(&mut receiver, MESSAGES_COUNT).await;
+    assert_eq!(offsets, (0..MESSAGES_COUNT).collect::<Vec<_>>());
+    assert!(subscription.is_active());
+    subscription.unsubscribe().await.unwrap();
+    assert_eq!(
+        get_stored_offset(&client).await,
+        Some(ACKNOWLEDGED_MESSAGES_COUNT - 1)
+    );
+
+    // 2. Once resubscribed, the unacknowledged messages are dispatched again
+    let (subscription, mut receiver) = subscribe(&client, MESSAGES_COUNT).await;
+    let offsets =
+        receive_offsets(&mut receiver, MESSAGES_COUNT - ACKNOWLEDGED_MESSAGES_COUNT).await;
+    assert_eq!(
+        offsets,
+        (ACKNOWLEDGED_MESSAGES_COUNT..MESSAGES_COUNT).collect::<Vec<_>>()
+    );
+    subscription.unsubscribe().await.unwrap();
+    assert_eq!(get_stored_offset(&client).await, Some(MESSAGES_COUNT - 1));
+    assert!(receiver.try_recv().is_err());
+
+    // 3. The parallel subscription processes the messages with the same key in order, storing the offset once all of them are acknowledged
+    let key_header = HeaderKey::new("key").unwrap();
+    let mut messages = (MESSAGES_COUNT..MESSAGES_COUNT + PARALLEL_MESSAGES_COUNT)
+        .map(|offset| {
+            let key = HeaderValue::from_str(&format!("key-{}", offset % KEYS_COUNT)).unwrap();
+            Message::new(
+                None,
+                format!("message-{offset}").into(),
+                Some(HashMap::from([(key_header.clone(), key)])),
+            )
+        })
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let (sender, mut receiver) = unbounded_channel();
+    let subscription = client
+        .consumer(CONSUMER_NAME, STREAM_NAME, TOPIC_NAME, PARTITION_ID)
+        .unwrap()
+        .auto_commit(AutoCommit::Disabled)
+        .build()
+        .subscribe_parallel(
+            KEYS_COUNT as u32,
+            Some(key_header),
+            move |message: ReceivedMessage, ack: AckHandle| {
+                let sender = sender.clone();
+                async move {
+                    let offset = message.message.offset;
+                    // The earlier messages take longer to process, so that they complete out of order.
+                    sleep(Duration::from_millis(
+                        (MESSAGES_COUNT + PARALLEL_MESSAGES_COUNT - offset) * 5,
+                    ))
+                    .await;
+                    ack.ack().await?;
+                    sender.send(offset).map_err(|_| IggyError::Error)
+                }
+            },
+        )
+        .await
+        .unwrap();
+    let offsets = receive_offsets(&mut receiver, PARALLEL_MESSAGES_COUNT).await;
+    for key in 0..KEYS_COUNT {
+        let key_offsets = offsets
+            .iter()
+            .filter(|offset| *offset % KEYS_COUNT == key)
+            .copied()
+            .collect::<Vec<_>>();
+        assert!(key_offsets.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            key_offsets.len() as u64,
+            PARALLEL_MESSAGES_COUNT / KEYS_COUNT
+        );
+    }
+    subscription.unsubscribe().await.unwrap();
+    assert_eq!(
+        get_stored_offset(&client).await,
+        Some(MESSAGES_COUNT + PARALLEL_MESSAGES_COUNT - 1)
+    );
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(&client).await;
+}
+
+/// Subscribes with the handler acknowledging only the messages with offsets lower than `ack_below_offset`.
+async fn subscribe(
+    client: &IggyClient,
+    ack_below_offset: u64,
+) -> (Subscription, UnboundedReceiver<u64>) {
+    let (sender, receiver) = unbounded_channel();
+    let subscription = client
+        .subscribe(
+            CONSUMER_NAME,
+            STREAM_NAME,
+            TOPIC_NAME,
+            PARTITION_ID,
+            move |message: ReceivedMessage, ack: AckHandle| {
+                let sender = sender.clone();
+                async move {
+                    let offset = message.message.offset;
+                    if offset < ack_below_offset {
+                        ack.ack().await?;
+                    }
+                    sender.send(offset).map_err(|_| IggyError::Error)
+                }
+            },
+        )
+        .await
+        .unwrap();
+    (subscription, receiver)
+}
+
+async fn receive_offsets(receiver: &mut UnboundedReceiver<u64>, count: u64) -> Vec<u64> {
+    let mut offsets = Vec::new();
+    while offsets.len() < count as usize {
+        let offset = timeout(Duration::from_secs(10), receiver.recv())
+            .await
+            .expect("Timed out waiting for the message")
+            .expect("Subscription has stopped");
+        offsets.push(offset);
+    }
+    offsets
+}
+
+async fn get_stored_offset(client: &IggyClient) -> Option<u64> {
+    client
+        .get_consumer_offset(
+            &Consumer::new(Identifier::named(CONSUMER_NAME).unwrap()),
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+        )
+        .await
+        .unwrap()
+        .map(|offset| offset.stored_offset)
+}
@@ -0,0 +1,145 @@
+use crate::server::scenarios::{create_client, PARTITIONS_COUNT, STREAM_NAME, TOPIC_NAME};
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::models::system_event::SystemEvent;
+use iggy::users::defaults::{DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_USER_ID};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{login_root, ClientFactory};
+use std::time::Duration;
+use tokio::time::sleep;
+
+pub const EVENTS_STREAM_NAME: &str = "_iggy";
+pub const EVENTS_TOPIC_NAME: &str = "_events";
+const EXPECTED_EVENTS_COUNT: usize = 5;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let events_stream_id = Identifier::named(EVENTS_STREAM_NAME).unwrap();
+    let events_topic_id = Identifier::named(EVENTS_TOPIC_NAME).unwrap();
+
+    // 1. The reserved stream and topic for the events are created on startup
+    let events_stream = client.get_stream(&events_stream_id).await.unwrap().unwrap();
+    assert_eq!(events_stream.topics_count, 1);
+    let events_topic = client
+        .get_topic(&events_stream_id, &events_topic_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(events_topic.partitions_count, 1);
+
+    // 2. Create and delete the stream and the topic, which publishes the events
+    let stream = client.create_stream(STREAM_NAME, None).await.unwrap();
+    let stream_id = Identifier::numeric(stream.id).unwrap();
+    let topic = client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            PARTITIONS_COUNT,
+            CompressionAlgorithm::default(),
+            None,
+            None,
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let topic_id = Identifier::numeric(topic.id).unwrap();
+    client.delete_topic(&stream_id, &topic_id).await.unwrap();
+    client.delete_stream(&stream_id).await.unwrap();
+
+    // 3. The reserved stream and topic cannot be updated or deleted
+    assert_reserved_stream(
+        client.delete_stream(&events_stream_id).await,
+        events_stream.id,
+    );
+    assert_reserved_stream(
+        client.update_stream(&events_stream_id, "stream").await,
+        events_stream.id,
+    );
+    assert_reserved_stream(
+        client
+            .delete_topic(&events_stream_id, &events_topic_id)
+            .await,
+        events_stream.id,
+    );
+
+    // 4. The events are appended to the reserved topic in order
+    let events = poll_events(&client).await;
+    assert_eq!(
+        events,
+        vec![
+            SystemEvent::UserLoggedIn {
+                user_id: DEFAULT_ROOT_USER_ID,
+                username: DEFAULT_ROOT_USERNAME.to_string(),
+            },
+            SystemEvent::StreamCreated {
+                stream_id: stream.id,
+                name: STREAM_NAME.to_string(),
+            },
+            SystemEvent::TopicCreated {
+                stream_id: stream.id,
+                topic_id: topic.id,
+                name: TOPIC_NAME.to_string(),
+                partitions_count: PARTITIONS_COUNT,
+            },
+            SystemEvent::TopicDeleted {
+                stream_id: stream.id,
+                topic_id: topic.id,
+                name: TOPIC_NAME.to_string(),
+            },
+            SystemEvent::StreamDeleted {
+                stream_id: stream.id,
+                name: STREAM_NAME.to_string(),
+            },
+        ]
+    );
+}
+
+async fn poll_events(client: &IggyClient) -> Vec<SystemEvent> {
+    let stream_id = Identifier::named(EVENTS_STREAM_NAME).unwrap();
+    let topic_id = Identifier::named(EVENTS_TOPIC_NAME).unwrap();
+    // The events are appended in the background, so they might not be available immediately.
+    for _ in 0..50 {
+        let polled_messages = client
+            .poll_messages(
+                &stream_id,
+                &topic_id,
+                Some(1),
+                &Consumer::default(),
+                &PollingStrategy::offset(0),
+                100,
+                false,
+            )
+            .await
+            .unwrap();
+        if polled_messages.messages.len() >= EXPECTED_EVENTS_COUNT {
+            return polled_messages
+                .messages
+                .iter()
+                .map(|message| serde_json::from_slice(&message.payload).unwrap())
+                .collect();
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    panic!("Expected {EXPECTED_EVENTS_COUNT} system events to be published.");
+}
+
+fn assert_reserved_stream(result: Result<(), IggyError>, stream_id: u32) {
+    let error = result.unwrap_err();
+    let expected = IggyError::ReservedStream(stream_id);
+    match error {
+        IggyError::InvalidResponse(status, _, _) => assert_eq!(status, expected.as_code()),
+        error => assert_eq!(error.as_code(), expected.as_code()),
+    }
+}
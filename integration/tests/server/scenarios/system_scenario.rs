@@ -11,16 +11,22 @@ use iggy::client::{
     SystemClient, TopicClient, UserClient,
 };
 use iggy::clients::client::IggyClient;
+use iggy::command::SEND_MESSAGES;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::Consumer;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{OutOfRangePolicy, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::messages::PolledMessage;
 use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use integration::test_server::{assert_clean_system, ClientFactory};
 
@@ -110,6 +116,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await
         .unwrap();
@@ -203,6 +211,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -218,6 +228,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(TOPIC_ID + 1),
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await;
     assert!(create_topic_result.is_err());
@@ -248,6 +260,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert_eq!(polled_messages.epoch, 0);
     for i in 0..MESSAGES_COUNT {
         let offset = i as u64;
         let message = polled_messages.messages.get(i as usize).unwrap();
@@ -279,7 +292,55 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         }
     }
 
-    // 20. Get topic details and validate the partition details
+    // 20. Polling above the high watermark should fail by default or apply the out of range policy
+    let out_of_range_offset = MESSAGES_COUNT as u64 + 10;
+    let poll_messages_result = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(out_of_range_offset),
+            batch_size,
+            false,
+        )
+        .await;
+    assert!(poll_messages_result.is_err());
+
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(out_of_range_offset).out_of_range(OutOfRangePolicy::Earliest),
+            batch_size,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, batch_size);
+    assert_message(polled_messages.messages.first().unwrap(), 0);
+
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(out_of_range_offset).out_of_range(OutOfRangePolicy::Latest),
+            batch_size,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_message(
+        polled_messages.messages.first().unwrap(),
+        (MESSAGES_COUNT - 1) as u64,
+    );
+
+    // 21. Get topic details and validate the partition details
     let topic = client
         .get_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -292,7 +353,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.name, TOPIC_NAME);
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
     assert_eq!(topic.partitions.len(), PARTITIONS_COUNT as usize);
-    assert_eq!(topic.size, 55890);
+    assert_eq!(topic.size, 47890);
     assert_eq!(topic.messages_count, MESSAGES_COUNT as u64);
     let topic_partition = topic.partitions.get((PARTITION_ID - 1) as usize).unwrap();
     assert_eq!(topic_partition.id, PARTITION_ID);
@@ -301,7 +362,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic_partition.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(topic_partition.messages_count, MESSAGES_COUNT as u64);
 
-    // 21. Ensure that messages do not exist in the second partition in the same topic
+    // 22. Ensure that messages do not exist in the second partition in the same topic
     let polled_messages = client
         .poll_messages(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -316,7 +377,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert!(polled_messages.messages.is_empty());
 
-    // 22. Get the existing customer offset and ensure it's 0
+    // 23. Get the existing customer offset and ensure it's 0
     let offset = client
         .get_consumer_offset(
             &consumer,
@@ -331,7 +392,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, 0);
 
-    // 23. Store the consumer offset
+    // 24. Store the consumer offset
     let stored_offset = 10;
     client
         .store_consumer_offset(
@@ -344,7 +405,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 24. Get the existing customer offset and ensure it's the previously stored value
+    // 25. Get the existing customer offset and ensure it's the previously stored value
     let offset = client
         .get_consumer_offset(
             &consumer,
@@ -359,7 +420,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, stored_offset);
 
-    // 25. Poll messages from the specific partition in topic using next with auto commit
+    // 26. Poll messages from the specific partition in topic using next with auto commit
     let messages_count = 10;
     let polled_messages = client
         .poll_messages(
@@ -380,7 +441,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(first_offset, stored_offset + 1);
     assert_eq!(last_offset, expected_last_offset);
 
-    // 26. Get the existing customer offset and ensure that auto commit during poll has worked
+    // 27. Get the existing customer offset and ensure that auto commit during poll has worked
     let offset = client
         .get_consumer_offset(
             &consumer,
@@ -395,7 +456,168 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(offset.current_offset, (MESSAGES_COUNT - 1) as u64);
     assert_eq!(offset.stored_offset, expected_last_offset);
 
-    // 27. Get the consumer groups and validate that there are no groups
+    // 28. Get the consumer lag for all the partitions and ensure it's calculated from the stored offset
+    let lags = client
+        .get_consumer_lag(
+            &consumer,
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(lags.len() as u32, PARTITIONS_COUNT);
+    for lag in lags {
+        if lag.partition_id == PARTITION_ID {
+            assert_eq!(lag.current_offset, (MESSAGES_COUNT - 1) as u64);
+            assert_eq!(lag.stored_offset, Some(expected_last_offset));
+            assert_eq!(lag.lag, (MESSAGES_COUNT - 1) as u64 - expected_last_offset);
+        } else {
+            assert_eq!(lag.stored_offset, None);
+            assert_eq!(lag.lag, 0);
+        }
+    }
+
+    // 29. Get the partition offsets and details and ensure the watermarks and the segment cover all the messages, then resolve the offsets by the timestamps and aggregate the messages histogram
+    let offsets = client
+        .get_partition_offsets(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(offsets.len() as u32, PARTITIONS_COUNT);
+    for offsets in offsets {
+        assert_eq!(offsets.low_watermark, 0);
+        if offsets.partition_id == PARTITION_ID {
+            assert_eq!(offsets.high_watermark, MESSAGES_COUNT as u64);
+        } else {
+            assert_eq!(offsets.high_watermark, 0);
+        }
+    }
+
+    let partition = client
+        .get_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get partition");
+    assert_eq!(partition.id, PARTITION_ID);
+    assert_eq!(partition.messages_count, MESSAGES_COUNT as u64);
+    assert_eq!(partition.segments_count, 1);
+    assert_eq!(partition.segments.len(), 1);
+    let segment = &partition.segments[0];
+    assert_eq!(segment.start_offset, 0);
+    assert_eq!(segment.end_offset, (MESSAGES_COUNT - 1) as u64);
+    assert_eq!(segment.messages_count, MESSAGES_COUNT as u64);
+    assert_eq!(segment.size, partition.size);
+    assert!(!segment.is_closed);
+    let consumer_offsets = &partition.consumer_offsets;
+    assert_eq!(consumer_offsets.consumers_count, 1);
+    assert_eq!(consumer_offsets.consumer_groups_count, 0);
+    assert!(consumer_offsets.records_count >= 2);
+    assert_eq!(
+        consumer_offsets.size.as_bytes_u64(),
+        consumer_offsets.records_count * 12
+    );
+    assert_eq!(consumer_offsets.compactions_count, 0);
+    assert!(consumer_offsets.last_compacted_at.is_none());
+
+    let partition = client
+        .get_partition(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITIONS_COUNT + 1,
+        )
+        .await
+        .unwrap();
+    assert!(partition.is_none());
+
+    let polled_messages = client
+        .poll_messages(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            Some(PARTITION_ID),
+            &consumer,
+            &PollingStrategy::offset(5),
+            1,
+            false,
+        )
+        .await
+        .unwrap();
+    let message = &polled_messages.messages[0];
+    let offset = client
+        .get_offset_by_timestamp(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            message.timestamp,
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get offset by timestamp");
+    assert_eq!(offset.partition_id, PARTITION_ID);
+    assert!(offset.offset <= message.offset);
+    assert_eq!(offset.timestamp, message.timestamp);
+
+    let offset = client
+        .get_offset_by_timestamp(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            0,
+        )
+        .await
+        .unwrap()
+        .expect("Failed to get offset by timestamp");
+    assert_eq!(offset.offset, 0);
+
+    let offset = client
+        .get_offset_by_timestamp(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            IggyTimestamp::now().as_micros() + 3_600_000_000,
+        )
+        .await
+        .unwrap();
+    assert!(offset.is_none());
+
+    let now = IggyTimestamp::now().as_micros();
+    let histogram = client
+        .get_messages_histogram(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            PARTITION_ID,
+            now - 3_600_000_000,
+            now + 3_600_000_000,
+            IggyDuration::new_from_secs(3600),
+        )
+        .await
+        .unwrap();
+    assert_eq!(histogram.partition_id, PARTITION_ID);
+    assert_eq!(histogram.bucket_size, IggyDuration::new_from_secs(3600));
+    assert_eq!(histogram.buckets.len(), 2);
+    assert_eq!(histogram.buckets[0].timestamp, now - 3_600_000_000);
+    assert_eq!(histogram.buckets[1].timestamp, now);
+    assert_eq!(
+        histogram
+            .buckets
+            .iter()
+            .map(|bucket| bucket.messages_count)
+            .sum::<u64>(),
+        MESSAGES_COUNT as u64
+    );
+    assert!(histogram
+        .buckets
+        .iter()
+        .all(|bucket| (bucket.messages_count == 0) == (bucket.size.as_bytes_u64() == 0)));
+
+    // 30. Get the consumer groups and validate that there are no groups
     let consumer_groups = client
         .get_consumer_groups(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -406,13 +628,14 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert!(consumer_groups.is_empty());
 
-    // 28. Create the consumer group
+    // 31. Create the consumer group
     let consumer_group = client
         .create_consumer_group(
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             CONSUMER_GROUP_NAME,
             Some(CONSUMER_GROUP_ID),
+            RebalanceStrategy::default(),
         )
         .await
         .unwrap();
@@ -420,7 +643,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
     assert_eq!(consumer_group.name, CONSUMER_GROUP_NAME);
 
-    // 29. Get the consumer groups and validate that there is one group
+    // 32. Get the consumer groups and validate that there is one group
     let consumer_groups = client
         .get_consumer_groups(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -435,7 +658,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
 
-    // 30. Get the consumer group details
+    // 33. Get the consumer group details
     let consumer_group = client
         .get_consumer_group(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -451,12 +674,13 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.members_count, 0);
     assert!(consumer_group.members.is_empty());
 
-    // 31. Join the consumer group and then leave it if the feature is available
+    // 34. Join the consumer group and then leave it if the feature is available
     let result = client
         .join_consumer_group(
             &Identifier::numeric(STREAM_ID).unwrap(),
             &Identifier::numeric(TOPIC_ID).unwrap(),
             &Identifier::numeric(CONSUMER_GROUP_ID).unwrap(),
+            None,
         )
         .await;
 
@@ -493,7 +717,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         Err(e) => assert_eq!(e.as_code(), IggyError::FeatureUnavailable.as_code()),
     }
 
-    // 32. Get the stats and validate that there is one stream
+    // 35. Get the stats and validate that there is one stream
     let stats = client.get_stats().await.unwrap();
     assert!(!stats.hostname.is_empty());
     assert!(!stats.os_name.is_empty());
@@ -504,8 +728,21 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stats.partitions_count, PARTITIONS_COUNT);
     assert_eq!(stats.segments_count, PARTITIONS_COUNT);
     assert_eq!(stats.messages_count, MESSAGES_COUNT as u64);
+    // The latencies are recorded only for the commands handled by the binary protocol dispatcher.
+    for latency in stats.command_latencies.iter() {
+        assert!(!latency.name.is_empty());
+        assert!(latency.count > 0);
+        assert!(latency.p50.as_micros() <= latency.p95.as_micros());
+        assert!(latency.p95.as_micros() <= latency.p99.as_micros());
+    }
+    if !stats.command_latencies.is_empty() {
+        assert!(stats
+            .command_latencies
+            .iter()
+            .any(|latency| latency.name == SEND_MESSAGES));
+    }
 
-    // 33. Delete the consumer group
+    // 36. Delete the consumer group
     client
         .delete_consumer_group(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -515,7 +752,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
-    // 34. Create new partitions and validate that the number of partitions is increased
+    // 37. Create new partitions and validate that the number of partitions is increased
     client
         .create_partitions(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -536,7 +773,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, 2 * PARTITIONS_COUNT);
 
-    // 35. Delete the partitions and validate that the number of partitions is decreased
+    // 38. Delete the partitions and validate that the number of partitions is decreased
     client
         .delete_partitions(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -557,12 +794,28 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(topic.partitions_count, PARTITIONS_COUNT);
 
-    // 36. Update the existing topic and ensure it's updated
+    // 39. Update the existing topic and ensure it's updated
     let updated_topic_name = format!("{}-updated", TOPIC_NAME);
     let updated_message_expiry = 1000;
     let message_expiry_duration = updated_message_expiry.into();
     let updated_max_topic_size = MaxTopicSize::Custom(IggyByteSize::from_str("2 GB").unwrap());
-    let updated_replication_factor = 5;
+    let updated_replication_factor = 1;
+
+    let unsupported_replication_factor = 5;
+    let result = client
+        .update_topic(
+            &Identifier::numeric(STREAM_ID).unwrap(),
+            &Identifier::numeric(TOPIC_ID).unwrap(),
+            &updated_topic_name,
+            CompressionAlgorithm::Gzip,
+            Some(unsupported_replication_factor),
+            IggyExpiry::ExpireDuration(message_expiry_duration),
+            updated_max_topic_size,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await;
+    assert!(result.is_err());
 
     client
         .update_topic(
@@ -573,6 +826,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             Some(updated_replication_factor),
             IggyExpiry::ExpireDuration(message_expiry_duration),
             updated_max_topic_size,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await
         .unwrap();
@@ -598,7 +853,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(updated_topic.max_topic_size, updated_max_topic_size);
     assert_eq!(updated_topic.replication_factor, updated_replication_factor);
 
-    // 37. Purge the existing topic and ensure it has no messages
+    // 40. Purge the existing topic and ensure it has no messages and its partitions have a new epoch
     client
         .purge_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -621,8 +876,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
+    assert_eq!(polled_messages.epoch, 1);
 
-    // 38. Update the existing stream and ensure it's updated
+    // 41. Update the existing stream and ensure it's updated
     let updated_stream_name = format!("{}-updated", STREAM_NAME);
 
     client
@@ -641,7 +897,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_stream.name, updated_stream_name);
 
-    // 39. Purge the existing stream and ensure it has no messages
+    // 42. Purge the existing stream and ensure it has no messages and its partitions have a new epoch
     let mut messages = create_messages();
     client
         .send_messages(
@@ -672,8 +928,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert_eq!(polled_messages.current_offset, 0);
     assert!(polled_messages.messages.is_empty());
+    assert_eq!(polled_messages.epoch, 2);
 
-    // 40. Delete the existing topic and ensure it doesn't exist anymore
+    // 43. Delete the existing topic and ensure it doesn't exist anymore
     client
         .delete_topic(
             &Identifier::numeric(STREAM_ID).unwrap(),
@@ -687,7 +944,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .unwrap();
     assert!(topics.is_empty());
 
-    // 41. Create the stream with automatically generated ID on the server
+    // 44. Create the stream with automatically generated ID on the server
     let stream_name = format!("{}-auto", STREAM_NAME);
     let stream_id = STREAM_ID + 1;
     client.create_stream(&stream_name, None).await.unwrap();
@@ -701,7 +958,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(stream.id, stream_id);
     assert_eq!(stream.name, stream_name);
 
-    // 42. Create the topic with automatically generated ID on the server
+    // 45. Create the topic with automatically generated ID on the server
     let topic_name = format!("{}-auto", TOPIC_NAME);
     let topic_id = 1;
     client
@@ -714,6 +971,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             None,
             IggyExpiry::NeverExpire,
             MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .await
         .unwrap();
@@ -730,7 +989,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.id, topic_id);
     assert_eq!(topic.name, topic_name);
 
-    // 43. Delete the existing streams and ensure there's no streams left
+    // 46. Delete the existing streams and ensure there's no streams left
     let streams = client.get_streams().await.unwrap();
     assert_eq!(streams.len(), 2);
 
@@ -744,7 +1003,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let streams = client.get_streams().await.unwrap();
     assert!(streams.is_empty());
 
-    // 44. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
+    // 47. Get clients and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) client
     let clients = client.get_clients().await.unwrap();
 
     assert!(clients.len() <= 1);
@@ -0,0 +1,98 @@
+use crate::server::scenarios::{
+    create_client, PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME,
+};
+use futures::StreamExt;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::consumer::AutoCommit;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use integration::test_server::{assert_clean_system, login_root, ClientFactory};
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const CONSUMER_NAME: &str = "typed-consumer";
+const PAYLOADS: [&str; 4] = [r#"{"id":1}"#, "not json", r#"{"id":2}"#, r#"{"id":3}"#];
+const INVALID_PAYLOAD_OFFSET: u64 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let client = create_client(client_factory).await;
+    login_root(&client).await;
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let mut messages = PAYLOADS
+        .iter()
+        .map(|payload| Message::from_str(payload).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+
+    // 1. The valid payloads are decoded, while the invalid one is delivered to the failures channel without stopping the stream
+    let (mut consumer, failures) = client
+        .consumer(CONSUMER_NAME, STREAM_NAME, TOPIC_NAME, PARTITION_ID)
+        .unwrap()
+        .polling_strategy(PollingStrategy::offset(0))
+        .auto_commit(AutoCommit::Disabled)
+        .build()
+        .typed::<Value>();
+    consumer.init().await.unwrap();
+    let mut values = Vec::new();
+    while values.len() < PAYLOADS.len() - 1 {
+        let message = timeout(Duration::from_secs(10), consumer.next())
+            .await
+            .expect("Timed out waiting for the message")
+            .expect("Consumer has stopped")
+            .unwrap();
+        assert_ne!(message.message.offset, INVALID_PAYLOAD_OFFSET);
+        values.push(message.value);
+    }
+    assert_eq!(
+        values,
+        vec![json!({"id": 1}), json!({"id": 2}), json!({"id": 3})]
+    );
+
+    let failure = failures.try_recv().unwrap();
+    assert_eq!(failure.partition_id, PARTITION_ID);
+    assert_eq!(failure.offset, INVALID_PAYLOAD_OFFSET);
+    assert_eq!(failure.payload, PAYLOADS[INVALID_PAYLOAD_OFFSET as usize]);
+    assert!(matches!(failure.error, IggyError::CannotParseJson(_)));
+    assert!(failures.try_recv().is_err());
+    consumer.shutdown().await.unwrap();
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert_clean_system(&client).await;
+}
@@ -0,0 +1,94 @@
+use crate::server::scenarios::{PARTITION_ID, STREAM_ID, STREAM_NAME, TOPIC_ID, TOPIC_NAME};
+use iggy::client::{Client, MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::IggyClient;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME};
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 100;
+const PAYLOAD_SIZE: usize = 100;
+
+pub async fn run(transport: &str, server_addr: &str) {
+    let connection_string = format!(
+        "iggy://{DEFAULT_ROOT_USERNAME}:{DEFAULT_ROOT_PASSWORD}@{server_addr}?transport={transport}&wire_checksum=true"
+    );
+
+    // 1. The client sending the checksums is logged in automatically when connecting
+    let client = IggyClient::from_connection_string(&connection_string).unwrap();
+    client.connect().await.unwrap();
+    let stream_id = Identifier::numeric(STREAM_ID).unwrap();
+    let topic_id = Identifier::numeric(TOPIC_ID).unwrap();
+
+    // 2. The empty and the regular responses are verified
+    client
+        .create_stream(STREAM_NAME, Some(STREAM_ID))
+        .await
+        .unwrap();
+    client
+        .create_topic(
+            &stream_id,
+            TOPIC_NAME,
+            1,
+            CompressionAlgorithm::default(),
+            None,
+            Some(TOPIC_ID),
+            IggyExpiry::NeverExpire,
+            MaxTopicSize::ServerDefault,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
+        )
+        .await
+        .unwrap();
+    let stream = client.get_stream(&stream_id).await.unwrap().unwrap();
+    assert_eq!(stream.name, STREAM_NAME);
+
+    // 3. The messages are sent and polled back, possibly as the chunked response
+    let mut messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::from_str(&format!("{index:0>PAYLOAD_SIZE$}")).unwrap())
+        .collect::<Vec<_>>();
+    client
+        .send_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(PARTITION_ID),
+            &mut messages,
+        )
+        .await
+        .unwrap();
+    let polled_messages = client
+        .poll_messages(
+            &stream_id,
+            &topic_id,
+            Some(PARTITION_ID),
+            &Consumer::default(),
+            &PollingStrategy::offset(0),
+            MESSAGES_COUNT,
+            false,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        assert_eq!(
+            message.payload,
+            format!("{index:0>PAYLOAD_SIZE$}").as_bytes()
+        );
+    }
+
+    // 4. The error response is verified as well
+    assert!(client
+        .delete_stream(&Identifier::numeric(STREAM_ID + 1).unwrap())
+        .await
+        .is_err());
+
+    client.delete_stream(&stream_id).await.unwrap();
+    assert!(client.get_streams().await.unwrap().is_empty());
+}
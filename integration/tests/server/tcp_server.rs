@@ -1,11 +1,48 @@
 use crate::server::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, create_message_payload,
-    message_headers_scenario, message_size_scenario, stream_size_validation_scenario,
-    system_scenario, user_scenario,
+    background_io_scenario, blocking_client_scenario, client_middleware_scenario,
+    cluster_metadata_scenario, connection_pool_scenario, connection_string_scenario,
+    consumer_group_join_scenario, consumer_group_rebalance_scenario,
+    consumer_group_static_membership_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario,
+    consumer_offsets_compaction_scenario, consumer_offsets_delete_scenario,
+    consumer_offsets_reset_scenario, copy_messages_scenario, create_message_payload,
+    disk_space_scenario, exclusive_producer_scenario, fetch_replica_messages_scenario,
+    get_message_by_key_scenario, large_poll_response_scenario, list_paging_scenario,
+    log_level_scenario, message_headers_scenario, message_schema_scenario, message_size_scenario,
+    message_timestamp_type_scenario, named_consumers_scenario, node_drain_scenario,
+    peek_messages_scenario, poll_messages_from_partitions_scenario,
+    poll_messages_max_bytes_scenario, poll_messages_pagination_scenario, producer_epoch_scenario,
+    resource_limits_scenario, retry_policy_scenario, seal_partition_scenario,
+    send_messages_ack_scenario, session_management_scenario, stream_size_validation_scenario,
+    subscription_scenario, system_events_scenario, system_scenario, topic_read_only_scenario,
+    typed_consumer_scenario, user_scenario, wire_checksum_scenario,
 };
-use integration::{tcp_client::TcpClientFactory, test_server::TestServer};
+use iggy::binary::CHECKSUM_FLAG;
+use iggy::client::{Client, StreamClient, SystemClient, UserClient};
+use iggy::error::IggyError;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use iggy::users::defaults::{DEFAULT_ROOT_PASSWORD, DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_USER_ID};
+use iggy::utils::checksum;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use integration::{
+    tcp_client::TcpClientFactory,
+    test_server::{IpAddrKind, TestServer},
+};
+use rcgen::{
+    BasicConstraints, Certificate, CertificateParams, DnType, ExtendedKeyUsagePurpose, IsCa,
+    KeyPair,
+};
+use serde_json::{json, Value};
 use serial_test::parallel;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tempfile::{NamedTempFile, TempDir};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
 
 #[tokio::test]
 #[parallel]
@@ -57,6 +94,46 @@ async fn consumer_group_join_scenario_should_be_valid() {
     consumer_group_join_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn consumer_group_rebalance_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_group_rebalance_scenario::run(&client_factory, RebalanceStrategy::Eager).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_sticky_rebalance_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_group_rebalance_scenario::run(&client_factory, RebalanceStrategy::Sticky).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_group_static_membership_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_CONSUMER_GROUP_SESSION_TIMEOUT".to_string(),
+            "500 ms".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_CONSUMER_GROUP_CLEANER_INTERVAL".to_string(),
+            "100 ms".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_group_static_membership_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn consumer_group_with_single_client_polling_messages_scenario_should_be_valid() {
@@ -77,6 +154,16 @@ async fn consumer_group_with_multiple_clients_polling_messages_scenario_should_b
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
 
+#[tokio::test]
+#[parallel]
+async fn list_paging_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    list_paging_scenario::run(&client_factory).await;
+}
+
 #[tokio::test]
 #[parallel]
 async fn stream_size_validation_scenario_should_be_valid() {
@@ -96,3 +183,782 @@ async fn message_size_scenario_should_be_valid() {
     let client_factory = TcpClientFactory { server_addr };
     message_size_scenario::run(&client_factory).await;
 }
+
+#[tokio::test]
+#[parallel]
+async fn message_timestamp_type_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    message_timestamp_type_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn subscription_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    subscription_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn typed_consumer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    typed_consumer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn message_schema_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    message_schema_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn copy_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    copy_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn peek_messages_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    peek_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn exclusive_producer_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    exclusive_producer_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn session_management_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    session_management_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn producer_epoch_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    producer_epoch_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn seal_partition_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    seal_partition_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn get_message_by_key_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    get_message_by_key_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn topic_read_only_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    topic_read_only_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn system_events_scenario_should_be_valid() {
+    let extra_envs =
+        HashMap::from([("IGGY_SYSTEM_EVENTS_ENABLED".to_string(), "true".to_string())]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    system_events_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn disk_space_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_DISK_MONITOR_INTERVAL".to_string(),
+            "1 s".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_DISK_MONITOR_MIN_FREE_SPACE".to_string(),
+            "1 EB".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    disk_space_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn resource_limits_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_SYSTEM_LIMITS_MAX_STREAMS".to_string(),
+            "1".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_LIMITS_MAX_TOPICS_PER_STREAM".to_string(),
+            "1".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_LIMITS_MAX_PARTITIONS_PER_TOPIC".to_string(),
+            "2".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    resource_limits_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_from_partitions_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    poll_messages_from_partitions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn send_messages_ack_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    send_messages_ack_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_reset_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_offsets_reset_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_delete_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_offsets_delete_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn named_consumers_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    named_consumers_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn consumer_offsets_compaction_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        (
+            "IGGY_DATA_MAINTENANCE_CONSUMER_OFFSETS_INTERVAL".to_string(),
+            "1 s".to_string(),
+        ),
+        (
+            "IGGY_DATA_MAINTENANCE_CONSUMER_OFFSETS_MIN_STALE_RECORDS".to_string(),
+            "10".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    consumer_offsets_compaction_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_max_bytes_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    poll_messages_max_bytes_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn poll_messages_pagination_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        ("IGGY_SYSTEM_SEGMENT_SIZE".to_string(), "1 KiB".to_string()),
+        (
+            "IGGY_SYSTEM_SEGMENT_INDEX_INTERVAL".to_string(),
+            "1 KiB".to_string(),
+        ),
+        (
+            "IGGY_SYSTEM_POLL_RESPONSE_MAX_MESSAGES_COUNT".to_string(),
+            "7".to_string(),
+        ),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    poll_messages_pagination_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn large_poll_response_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    large_poll_response_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn chunked_poll_response_scenarios_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_POLL_RESPONSE_CHUNK_SIZE".to_string(),
+        "1 KB".to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    large_poll_response_scenario::run(&client_factory).await;
+    poll_messages_from_partitions_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn json_protocol_should_be_supported() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let (reader, mut writer) = TcpStream::connect(server_addr).await.unwrap().into_split();
+    let mut reader = BufReader::new(reader).lines();
+    writer.write_all(b"JSON\n").await.unwrap();
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "user.login", "username": DEFAULT_ROOT_USERNAME, "password": DEFAULT_ROOT_PASSWORD}),
+    )
+    .await;
+    assert_eq!(response["status"], 0);
+    assert!(response["data"]["user_id"].as_u64().unwrap() > 0);
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "stream.create", "stream_id": 1, "name": "json-stream"}),
+    )
+    .await;
+    assert_eq!(response["status"], 0);
+    assert_eq!(response["data"]["name"], "json-stream");
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "topic.create", "stream_id": "json-stream", "topic_id": 1, "name": "json-topic",
+            "partitions_count": 1, "compression_algorithm": "none", "message_expiry": 0, "max_topic_size": 0}),
+    )
+    .await;
+    assert_eq!(response["status"], 0);
+    assert_eq!(response["data"]["partitions_count"], 1);
+
+    // Partition ID 1 as the little-endian bytes and "hello" payload, both encoded with Base64.
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "message.send", "stream_id": 1, "topic_id": 1,
+            "partitioning": {"kind": "partition_id", "value": "AQAAAA=="}, "messages": [{"payload": "aGVsbG8="}]}),
+    )
+    .await;
+    assert_eq!(response, json!({"status": 0}));
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "message.poll", "stream_id": 1, "topic_id": 1, "id": 1,
+            "partition_id": 1, "kind": "offset", "value": 0, "count": 10}),
+    )
+    .await;
+    assert_eq!(response["status"], 0);
+    assert_eq!(response["data"]["messages"][0]["payload"], "aGVsbG8=");
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "stream.get", "stream_id": 100}),
+    )
+    .await;
+    assert_eq!(response["status"], 0);
+    assert!(response.get("data").is_none());
+
+    let response = send_json(
+        &mut writer,
+        &mut reader,
+        json!({"command": "stream.delete", "stream_id": 100}),
+    )
+    .await;
+    assert_eq!(
+        response["status"],
+        IggyError::StreamIdNotFound(100).as_code()
+    );
+
+    let response = send_json(&mut writer, &mut reader, json!({"command": "unknown"})).await;
+    assert_eq!(response["status"], IggyError::InvalidCommand.as_code());
+}
+
+async fn send_json(
+    writer: &mut OwnedWriteHalf,
+    reader: &mut Lines<BufReader<OwnedReadHalf>>,
+    request: Value,
+) -> Value {
+    writer
+        .write_all(format!("{request}\n").as_bytes())
+        .await
+        .unwrap();
+    let response = reader.next_line().await.unwrap().unwrap();
+    serde_json::from_str(&response).unwrap()
+}
+
+#[tokio::test]
+#[parallel]
+async fn client_certificate_should_authenticate_mapped_user() {
+    let certs_dir = TempDir::new().unwrap();
+    let certs_path = certs_dir.path();
+    let ca_key = KeyPair::generate().unwrap();
+    let mut ca_params = CertificateParams::new(Vec::<String>::new()).unwrap();
+    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    ca_params
+        .distinguished_name
+        .push(DnType::CommonName, "iggy-ca");
+    let ca = ca_params.self_signed(&ca_key).unwrap();
+    std::fs::write(certs_path.join("ca.pem"), ca.pem()).unwrap();
+    generate_certificate(
+        certs_path,
+        "server",
+        "localhost",
+        ExtendedKeyUsagePurpose::ServerAuth,
+        &ca,
+        &ca_key,
+    );
+    // The default configuration maps the "iggy-client" identity to the root user.
+    generate_certificate(
+        certs_path,
+        "client",
+        "iggy-client",
+        ExtendedKeyUsagePurpose::ClientAuth,
+        &ca,
+        &ca_key,
+    );
+    generate_certificate(
+        certs_path,
+        "unmapped_client",
+        "unmapped-client",
+        ExtendedKeyUsagePurpose::ClientAuth,
+        &ca,
+        &ca_key,
+    );
+
+    let path = |file: &str| certs_path.join(file).to_str().unwrap().to_string();
+    let mut envs = HashMap::new();
+    envs.insert("IGGY_TCP_TLS_ENABLED".to_string(), "true".to_string());
+    envs.insert(
+        "IGGY_TCP_TLS_CLIENT_AUTH_ENABLED".to_string(),
+        "true".to_string(),
+    );
+    envs.insert(
+        "IGGY_TCP_TLS_CLIENT_AUTH_CERT_FILE".to_string(),
+        path("server_cert.pem"),
+    );
+    envs.insert(
+        "IGGY_TCP_TLS_CLIENT_AUTH_KEY_FILE".to_string(),
+        path("server_key.pem"),
+    );
+    envs.insert(
+        "IGGY_TCP_TLS_CLIENT_AUTH_CA_FILE".to_string(),
+        path("ca.pem"),
+    );
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let create_client = |certificate: Option<&str>| {
+        TcpClient::create(Arc::new(TcpClientConfig {
+            server_address: server_addr.clone(),
+            tls_enabled: true,
+            tls_domain: "localhost".to_string(),
+            tls_ca_file: Some(path("ca.pem")),
+            tls_client_cert_file: certificate.map(|name| path(&format!("{name}_cert.pem"))),
+            tls_client_key_file: certificate.map(|name| path(&format!("{name}_key.pem"))),
+            reconnection: TcpClientReconnectionConfig {
+                enabled: false,
+                ..TcpClientReconnectionConfig::default()
+            },
+            ..TcpClientConfig::default()
+        }))
+        .unwrap()
+    };
+
+    let client = create_client(Some("client"));
+    client.connect().await.unwrap();
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.user_id, Some(DEFAULT_ROOT_USER_ID));
+    assert!(client.get_streams().await.is_ok());
+
+    let client = create_client(Some("unmapped_client"));
+    client.connect().await.unwrap();
+    assert!(client.get_streams().await.is_err());
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+    assert!(client.get_streams().await.is_ok());
+
+    let client = create_client(None);
+    // With TLS 1.3 the missing client certificate is rejected by the server after the client completes the handshake.
+    if client.connect().await.is_ok() {
+        assert!(client.get_streams().await.is_err());
+    }
+}
+
+fn generate_certificate(
+    path: &Path,
+    name: &str,
+    common_name: &str,
+    usage: ExtendedKeyUsagePurpose,
+    ca: &Certificate,
+    ca_key: &KeyPair,
+) {
+    let key = KeyPair::generate().unwrap();
+    let mut params = CertificateParams::new(vec![common_name.to_string()]).unwrap();
+    params
+        .distinguished_name
+        .push(DnType::CommonName, common_name);
+    params.extended_key_usages = vec![usage];
+    let certificate = params.signed_by(&key, ca, ca_key).unwrap();
+    std::fs::write(path.join(format!("{name}_cert.pem")), certificate.pem()).unwrap();
+    std::fs::write(path.join(format!("{name}_key.pem")), key.serialize_pem()).unwrap();
+}
+
+#[tokio::test]
+#[parallel]
+async fn ip_filter_should_reject_connection_from_denied_address() {
+    let config = write_config(
+        r#"
+[tcp.ip_filter]
+enabled = true
+deny = ["127.0.0.0/8", "::1/128"]
+"#,
+    );
+    let mut test_server = start_server_with_config(&config);
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+
+    let mut stream = TcpStream::connect(&server_addr).await.unwrap();
+    let mut buffer = [0u8; 8];
+    let read_bytes = stream.read(&mut buffer).await.unwrap_or_default();
+    assert_eq!(read_bytes, 0);
+    test_server.stop();
+}
+
+#[tokio::test]
+#[parallel]
+async fn user_should_not_log_in_from_address_outside_allowed_sources() {
+    let config = write_config(
+        r#"
+[system.user_sources]
+enabled = true
+users = ["iggy=10.0.0.0/8"]
+"#,
+    );
+    let mut test_server = start_server_with_config(&config);
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client = TcpClient::create(Arc::new(TcpClientConfig {
+        server_address: server_addr,
+        ..TcpClientConfig::default()
+    }))
+    .unwrap();
+    client.connect().await.unwrap();
+
+    let result = client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await;
+    let expected_code = IggyError::UserSourceNotAllowed(String::new(), String::new()).as_code();
+    match result {
+        Err(IggyError::InvalidResponse(status, _, _)) => assert_eq!(status, expected_code),
+        _ => panic!("Expected user source not allowed error, got: {result:?}"),
+    }
+    test_server.stop();
+}
+
+#[tokio::test]
+#[parallel]
+async fn idle_connection_should_be_closed_while_active_client_is_kept() {
+    let config = write_config(
+        r#"
+[heartbeat]
+enabled = true
+interval = "200 ms"
+timeout = "500 ms"
+"#,
+    );
+    let mut test_server = start_server_with_config(&config);
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client = TcpClient::create(Arc::new(TcpClientConfig {
+        server_address: server_addr.clone(),
+        ..TcpClientConfig::default()
+    }))
+    .unwrap();
+    client.connect().await.unwrap();
+    client
+        .login_user(DEFAULT_ROOT_USERNAME, DEFAULT_ROOT_PASSWORD)
+        .await
+        .unwrap();
+
+    let mut idle_stream = TcpStream::connect(&server_addr).await.unwrap();
+    for _ in 0..15 {
+        client.ping().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    let mut buffer = [0u8; 8];
+    let read_bytes = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        idle_stream.read(&mut buffer),
+    )
+    .await
+    .expect("Idle connection should be closed by the server.")
+    .unwrap_or_default();
+    assert_eq!(read_bytes, 0);
+
+    let sessions = client.get_sessions().await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    test_server.stop();
+}
+
+fn write_config(content: &str) -> NamedTempFile {
+    let file = NamedTempFile::with_suffix(".toml").unwrap();
+    std::fs::write(file.path(), content).unwrap();
+    file
+}
+
+fn start_server_with_config(config: &NamedTempFile) -> TestServer {
+    let mut envs = HashMap::new();
+    envs.insert(
+        "IGGY_CONFIG_PATH".to_string(),
+        config.path().to_str().unwrap().to_string(),
+    );
+    let mut test_server = TestServer::new(Some(envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    test_server
+}
+
+#[tokio::test]
+#[parallel]
+async fn client_middleware_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    client_middleware_scenario::run(&server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_pool_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    connection_pool_scenario::run(&server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn connection_string_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    connection_string_scenario::run("tcp", &server_addr).await;
+}
+
+#[test]
+#[parallel]
+fn blocking_client_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    blocking_client_scenario::run("tcp", &server_addr);
+}
+
+#[tokio::test]
+#[parallel]
+async fn retry_policy_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    retry_policy_scenario::run("tcp", &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn wire_checksum_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_POLL_RESPONSE_CHUNK_SIZE".to_string(),
+        "1 KB".to_string(),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    wire_checksum_scenario::run("tcp", &server_addr).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn request_with_invalid_checksum_should_be_rejected() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let mut stream = TcpStream::connect(&server_addr).await.unwrap();
+
+    // The ping command (code 1) with the checksum flag set, followed by the invalid checksum.
+    let length = 8u32.to_le_bytes();
+    let code = (1u32 | CHECKSUM_FLAG).to_le_bytes();
+    stream
+        .write_all(&[&length[..], &code, &0u32.to_le_bytes()].concat())
+        .await
+        .unwrap();
+
+    let mut header = [0u8; 8];
+    stream.read_exact(&mut header).await.unwrap();
+    let status = u32::from_le_bytes(header[..4].try_into().unwrap());
+    let length = u32::from_le_bytes(header[4..].try_into().unwrap());
+    assert_eq!(status, IggyError::InvalidFrameChecksum(0, 0).as_code());
+    let mut details = vec![0u8; length as usize];
+    stream.read_exact(&mut details).await.unwrap();
+    let mut response_checksum = [0u8; 4];
+    stream.read_exact(&mut response_checksum).await.unwrap();
+    assert!(
+        checksum::verify_frame(u32::from_le_bytes(response_checksum), &[&header, &details]).is_ok()
+    );
+}
+
+#[tokio::test]
+#[parallel]
+async fn log_level_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    log_level_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn background_io_scenario_should_be_valid() {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    background_io_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn fetch_replica_messages_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_CLUSTER_FOLLOWERS".to_string(),
+        format!("[{}]", fetch_replica_messages_scenario::REPLICA_ID),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    fetch_replica_messages_scenario::run(&client_factory).await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn cluster_metadata_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([
+        ("IGGY_SYSTEM_CLUSTER_NODE_ID".to_string(), "3".to_string()),
+        ("IGGY_SYSTEM_CLUSTER_NAME".to_string(), "node-3".to_string()),
+        ("IGGY_SYSTEM_CLUSTER_ZONE".to_string(), "zone-a".to_string()),
+    ]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    cluster_metadata_scenario::run(&client_factory, 3, "node-3", "zone-a").await;
+}
+
+#[tokio::test]
+#[parallel]
+async fn node_drain_scenario_should_be_valid() {
+    let extra_envs = HashMap::from([(
+        "IGGY_SYSTEM_CLUSTER_FOLLOWERS".to_string(),
+        format!("[{}]", node_drain_scenario::REPLICA_ID),
+    )]);
+    let mut test_server = TestServer::new(Some(extra_envs), true, None, IpAddrKind::V4);
+    test_server.start();
+    let server_addr = test_server.get_raw_tcp_addr().unwrap();
+    let client_factory = TcpClientFactory { server_addr };
+    node_drain_scenario::run(&client_factory).await;
+}
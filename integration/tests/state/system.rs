@@ -4,6 +4,7 @@ use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAc
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
 use iggy::users::create_user::CreateUser;
 use iggy::utils::expiry::IggyExpiry;
 use server::state::command::EntryCommand;
@@ -50,6 +51,8 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        overrides: Default::default(),
     };
 
     let create_topic1_clone = CreateTopic {
@@ -61,6 +64,8 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic1".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        overrides: Default::default(),
     };
 
     let create_stream2 = CreateStream {
@@ -77,6 +82,8 @@ async fn should_be_initialized_based_on_state_entries() {
         max_topic_size: Default::default(),
         name: "topic2".to_string(),
         replication_factor: None,
+        cleanup_policy: Default::default(),
+        overrides: Default::default(),
     };
 
     let create_partitions = CreatePartitions {
@@ -85,6 +92,12 @@ async fn should_be_initialized_based_on_state_entries() {
         partitions_count: 2,
     };
 
+    let set_topic_read_only = SetTopicReadOnly {
+        stream_id: create_topic1.stream_id.clone(),
+        topic_id: create_topic1.topic_id.unwrap().try_into().unwrap(),
+        read_only: true,
+    };
+
     let delete_stream2 = DeleteStream {
         stream_id: create_stream2.stream_id.unwrap().try_into().unwrap(),
     };
@@ -128,6 +141,10 @@ async fn should_be_initialized_based_on_state_entries() {
         .apply(user_id, EntryCommand::CreatePartitions(create_partitions))
         .await
         .unwrap();
+    state
+        .apply(user_id, EntryCommand::SetTopicReadOnly(set_topic_read_only))
+        .await
+        .unwrap();
     state
         .apply(user_id, EntryCommand::DeleteStream(delete_stream2))
         .await
@@ -173,4 +190,5 @@ async fn should_be_initialized_based_on_state_entries() {
         .unwrap();
     assert_eq!(topic.name, create_topic1_clone.name);
     assert_eq!(topic.partitions.len(), 3);
+    assert!(topic.read_only);
 }
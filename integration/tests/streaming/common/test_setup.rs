@@ -1,4 +1,6 @@
 use server::configs::system::SystemConfig;
+use server::streaming::appends::AppendsBroadcaster;
+use server::streaming::events::EventsBus;
 use server::streaming::persistence::persister::FilePersister;
 use server::streaming::storage::SystemStorage;
 use std::sync::Arc;
@@ -8,6 +10,8 @@ use uuid::Uuid;
 pub struct TestSetup {
     pub config: Arc<SystemConfig>,
     pub storage: Arc<SystemStorage>,
+    pub appends_broadcaster: Arc<AppendsBroadcaster>,
+    pub events_bus: Arc<EventsBus>,
 }
 
 impl TestSetup {
@@ -17,12 +21,23 @@ impl TestSetup {
 
     pub async fn init_with_config(mut config: SystemConfig) -> TestSetup {
         config.path = format!("local_data_{}", Uuid::now_v7().to_u128_le());
+        config.placement.paths = config
+            .placement
+            .paths
+            .iter()
+            .map(|path| format!("{}/{path}", config.path))
+            .collect();
 
         let config = Arc::new(config);
         fs::create_dir(config.get_system_path()).await.unwrap();
         let persister = FilePersister {};
         let storage = Arc::new(SystemStorage::new(config.clone(), Arc::new(persister)));
-        TestSetup { config, storage }
+        TestSetup {
+            config,
+            storage,
+            appends_broadcaster: Arc::new(AppendsBroadcaster::default()),
+            events_bus: Arc::new(EventsBus::default()),
+        }
     }
 
     pub async fn create_streams_directory(&self) {
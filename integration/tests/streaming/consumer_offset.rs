@@ -1,8 +1,9 @@
 use crate::streaming::common::test_setup::TestSetup;
 use iggy::consumer::ConsumerKind;
 use server::configs::system::SystemConfig;
-use server::streaming::partitions::partition::ConsumerOffset;
+use server::streaming::partitions::partition::{ConsumerOffset, CONSUMER_OFFSET_RECORD_SIZE};
 use server::streaming::storage::PartitionStorage;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::fs;
 
@@ -14,6 +15,104 @@ async fn should_persist_consumer_offsets_and_then_load_them_from_disk() {
     assert_persisted_offsets(&setup.config, storage, ConsumerKind::ConsumerGroup).await;
 }
 
+#[tokio::test]
+async fn should_compact_consumer_offsets_keeping_the_latest_value_per_consumer() {
+    let setup = TestSetup::init().await;
+    let storage = setup.storage.partition.as_ref();
+    let kind = ConsumerKind::Consumer;
+    let path = format!("{}/consumer_offsets", setup.config.get_system_path());
+    fs::create_dir(&path).await.unwrap();
+    let legacy_consumer_id = 10;
+    fs::write(format!("{path}/{legacy_consumer_id}"), 7u64.to_le_bytes())
+        .await
+        .unwrap();
+    storage.load_consumer_offsets(kind, &path).await.unwrap();
+
+    let offsets_count = 10;
+    for consumer_id in 1..=3 {
+        for offset in 0..offsets_count {
+            let consumer_offset = ConsumerOffset::new(kind, consumer_id, offset, &path);
+            storage
+                .save_consumer_offset(&consumer_offset)
+                .await
+                .unwrap();
+        }
+    }
+    let deleted_consumer_offset = ConsumerOffset::new(kind, 2, offsets_count - 1, &path);
+    storage
+        .delete_consumer_offset(&deleted_consumer_offset)
+        .await
+        .unwrap();
+
+    let loaded_consumer_offsets = storage.load_consumer_offsets(kind, &path).await.unwrap();
+    let expected_offsets = vec![
+        ConsumerOffset::new(kind, 1, offsets_count - 1, &path),
+        ConsumerOffset::new(kind, 3, offsets_count - 1, &path),
+        ConsumerOffset::new(kind, legacy_consumer_id, 7, &path),
+    ];
+    assert_eq!(loaded_consumer_offsets.offsets, expected_offsets);
+    assert_eq!(loaded_consumer_offsets.records_count, 3 * offsets_count + 2);
+
+    let size = storage
+        .compact_consumer_offsets(
+            &path,
+            &loaded_consumer_offsets.offsets,
+            &loaded_consumer_offsets.names,
+        )
+        .await
+        .unwrap();
+    assert_eq!(size, 3 * CONSUMER_OFFSET_RECORD_SIZE);
+    assert!(fs::metadata(format!("{path}/{legacy_consumer_id}"))
+        .await
+        .is_err());
+
+    let loaded_consumer_offsets = storage.load_consumer_offsets(kind, &path).await.unwrap();
+    assert_eq!(loaded_consumer_offsets.offsets, expected_offsets);
+    assert_eq!(loaded_consumer_offsets.records_count, 3);
+    assert_eq!(loaded_consumer_offsets.size_bytes, size);
+}
+
+#[tokio::test]
+async fn should_persist_consumer_names_and_keep_them_after_compaction() {
+    let setup = TestSetup::init().await;
+    let storage = setup.storage.partition.as_ref();
+    let kind = ConsumerKind::Consumer;
+    let path = format!("{}/consumer_offsets", setup.config.get_system_path());
+    fs::create_dir(&path).await.unwrap();
+    storage.load_consumer_offsets(kind, &path).await.unwrap();
+
+    for (consumer_id, name) in [(1, "orders"), (2, "payments")] {
+        storage
+            .save_consumer_offset(&ConsumerOffset::new(kind, consumer_id, 5, &path))
+            .await
+            .unwrap();
+        storage
+            .save_consumer_name(&path, consumer_id, name)
+            .await
+            .unwrap();
+    }
+    storage
+        .delete_consumer_offset(&ConsumerOffset::new(kind, 2, 5, &path))
+        .await
+        .unwrap();
+
+    let loaded_consumer_offsets = storage.load_consumer_offsets(kind, &path).await.unwrap();
+    let expected_names = HashMap::from([(1, "orders".to_string())]);
+    assert_eq!(loaded_consumer_offsets.names, expected_names);
+
+    storage
+        .compact_consumer_offsets(
+            &path,
+            &loaded_consumer_offsets.offsets,
+            &loaded_consumer_offsets.names,
+        )
+        .await
+        .unwrap();
+    let loaded_consumer_offsets = storage.load_consumer_offsets(kind, &path).await.unwrap();
+    assert_eq!(loaded_consumer_offsets.names, expected_names);
+    assert_eq!(loaded_consumer_offsets.offsets.len(), 1);
+}
+
 async fn assert_persisted_offsets(
     config: &Arc<SystemConfig>,
     storage: &dyn PartitionStorage,
@@ -27,6 +126,8 @@ async fn assert_persisted_offsets(
     };
     let path = format!("{}/{}", config.get_system_path(), path);
     fs::create_dir(&path).await.unwrap();
+    let loaded_consumer_offsets = storage.load_consumer_offsets(kind, &path).await.unwrap();
+    assert!(loaded_consumer_offsets.offsets.is_empty());
     for consumer_id in 1..=consumer_ids_count {
         let expected_offsets_count = consumer_id;
         for offset in 0..=offsets_count {
@@ -46,7 +147,8 @@ async fn assert_persisted_offset(
     let consumer_offsets = storage
         .load_consumer_offsets(consumer_offset.kind, path)
         .await
-        .unwrap();
+        .unwrap()
+        .offsets;
     let expected_offsets_count = expected_offsets_count as usize;
     assert_eq!(consumer_offsets.len(), expected_offsets_count);
     let loaded_consumer_offset = consumer_offsets.get(expected_offsets_count - 1).unwrap();
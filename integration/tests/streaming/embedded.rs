@@ -0,0 +1,82 @@
+use crate::streaming::common::test_setup::TestSetup;
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::expiry::IggyExpiry;
+use server::configs::server::ServerConfig;
+use server::embedded::EmbeddedIggy;
+use std::str::FromStr;
+
+const MESSAGES_COUNT: u32 = 10;
+
+#[tokio::test]
+async fn embedded_iggy_should_persist_messages_and_offsets_across_restarts() {
+    let setup = TestSetup::init().await;
+    let config = ServerConfig {
+        system: setup.config.clone(),
+        ..ServerConfig::default()
+    };
+    let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+
+    let iggy = EmbeddedIggy::start(config.clone()).await.unwrap();
+    assert_eq!(iggy.create_stream(Some(1), "embedded").await.unwrap(), 1);
+    assert_eq!(
+        iggy.create_topic(&stream_id, Some(1), "embedded", 1, IggyExpiry::NeverExpire)
+            .await
+            .unwrap(),
+        1
+    );
+    let messages = (0..MESSAGES_COUNT)
+        .map(|index| Message::from_str(&format!("message {index}")).unwrap())
+        .collect();
+    iggy.send_messages(
+        &stream_id,
+        &topic_id,
+        &Partitioning::partition_id(1),
+        messages,
+    )
+    .await
+    .unwrap();
+    let polled_messages = iggy
+        .poll_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(1),
+            PollingStrategy::next(),
+            5,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 5);
+    iggy.shutdown().await.unwrap();
+
+    let iggy = EmbeddedIggy::start(config).await.unwrap();
+    let offset = iggy
+        .get_consumer_offset(&consumer, &stream_id, &topic_id, Some(1))
+        .await
+        .unwrap();
+    assert_eq!(offset.stored_offset, 4);
+    assert_eq!(offset.current_offset, MESSAGES_COUNT as u64 - 1);
+    let polled_messages = iggy
+        .poll_messages(
+            &consumer,
+            &stream_id,
+            &topic_id,
+            Some(1),
+            PollingStrategy::next(),
+            MESSAGES_COUNT,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(polled_messages.messages.len(), 5);
+    assert_eq!(polled_messages.messages[0].payload, "message 5".as_bytes());
+
+    iggy.delete_stream(&stream_id).await.unwrap();
+    iggy.shutdown().await.unwrap();
+}
@@ -39,6 +39,8 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         true,
         config.clone(),
         setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -129,7 +131,7 @@ async fn should_persist_messages_and_then_load_them_by_timestamp() {
         .unwrap();
 
     let loaded_messages = partition
-        .get_messages_by_timestamp(test_timestamp, messages_count)
+        .get_messages_by_timestamp(test_timestamp, messages_count, 0)
         .await
         .unwrap();
 
@@ -177,6 +179,8 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         true,
         config.clone(),
         setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -243,6 +247,8 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         false,
         config.clone(),
         setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -254,10 +260,11 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
     let partition_state = PartitionState {
         id: partition.partition_id,
         created_at: now,
+        epoch: 0,
     };
     loaded_partition.load(partition_state).await.unwrap();
     let loaded_messages = loaded_partition
-        .get_messages_by_offset(0, messages_count)
+        .get_messages_by_offset(0, messages_count, 0)
         .await
         .unwrap();
     assert_eq!(loaded_messages.len(), messages_count as usize);
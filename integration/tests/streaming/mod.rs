@@ -3,6 +3,7 @@ use iggy::messages::send_messages::Message;
 
 mod common;
 mod consumer_offset;
+mod embedded;
 mod messages;
 mod partition;
 mod segment;
@@ -1,17 +1,30 @@
 use crate::streaming::common::test_setup::TestSetup;
-use crate::streaming::create_messages;
+use crate::streaming::{create_message, create_messages};
+use iggy::error::IggyError;
+use iggy::messages::poll_messages::OutOfRangePolicy;
+use iggy::messages::send_messages::Message;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use server::configs::system::{
+    CacheConfig, ClusterConfig, PartitionConfig, SegmentConfig, SystemConfig,
+};
 use server::state::system::PartitionState;
+use server::streaming::appends::AppendNotification;
 use server::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION};
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs;
 
+const REPLICA_ID: u32 = 2;
+
 #[tokio::test]
 async fn should_persist_partition_with_segment() {
     let setup = TestSetup::init().await;
@@ -28,6 +41,8 @@ async fn should_persist_partition_with_segment() {
             with_segment,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -59,6 +74,8 @@ async fn should_load_existing_partition_from_disk() {
             with_segment,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -78,6 +95,8 @@ async fn should_load_existing_partition_from_disk() {
             false,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -89,6 +108,7 @@ async fn should_load_existing_partition_from_disk() {
         let partition_state = PartitionState {
             id: partition.partition_id,
             created_at: now,
+            epoch: 3,
         };
         loaded_partition.load(partition_state).await.unwrap();
 
@@ -96,6 +116,7 @@ async fn should_load_existing_partition_from_disk() {
         assert_eq!(loaded_partition.partition_id, partition.partition_id);
         assert_eq!(loaded_partition.partition_path, partition.partition_path);
         assert_eq!(loaded_partition.current_offset, partition.current_offset);
+        assert_eq!(loaded_partition.epoch, 3);
         assert_eq!(
             loaded_partition.unsaved_messages_count,
             partition.unsaved_messages_count
@@ -132,6 +153,8 @@ async fn should_delete_existing_partition_from_disk() {
             with_segment,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -165,6 +188,8 @@ async fn should_purge_existing_partition_on_disk() {
             with_segment,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -188,17 +213,930 @@ async fn should_purge_existing_partition_on_disk() {
             .append_messages(appendable_batch_info, messages)
             .await
             .unwrap();
-        let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        let loaded_messages = partition.get_messages_by_offset(0, 100, 0).await.unwrap();
         assert_eq!(loaded_messages.len(), messages_count);
         partition.purge().await.unwrap();
         assert_eq!(partition.current_offset, 0);
         assert_eq!(partition.unsaved_messages_count, 0);
         assert!(!partition.should_increment_offset);
-        let loaded_messages = partition.get_messages_by_offset(0, 100).await.unwrap();
+        assert_eq!(partition.epoch, 1);
+        let loaded_messages = partition.get_messages_by_offset(0, 100, 0).await.unwrap();
         assert!(loaded_messages.is_empty());
     }
 }
 
+#[tokio::test]
+async fn should_compact_closed_segments_keeping_latest_message_per_key() {
+    let setup = TestSetup::init().await;
+    let keys_count = 3;
+    let messages_count = 30;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        let key = (i % keys_count) as u128 + 1;
+        append_message(&mut partition, create_message(key, &format!("value {i}"))).await;
+    }
+
+    assert!(partition.get_segments_count() > 1);
+    let all_messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    assert_eq!(all_messages.len(), messages_count as usize);
+    let latest_offsets = all_messages
+        .iter()
+        .map(|message| (message.id, message.offset))
+        .collect::<HashMap<_, _>>();
+    let segments_last_offsets = partition
+        .get_segments()
+        .iter()
+        .filter(|segment| segment.is_closed)
+        .map(|segment| segment.current_offset)
+        .collect::<Vec<_>>();
+    let open_segment_start_offset = partition.get_segments().last().unwrap().start_offset;
+    let expected_offsets = all_messages
+        .iter()
+        .filter(|message| {
+            latest_offsets[&message.id] == message.offset
+                || segments_last_offsets.contains(&message.offset)
+                || message.offset >= open_segment_start_offset
+        })
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    let size_before_compaction = partition.get_size_bytes();
+
+    let compacted = partition.compact(IggyDuration::default()).await.unwrap();
+
+    assert_eq!(
+        compacted.removed_messages,
+        (messages_count as usize - expected_offsets.len()) as u64
+    );
+    assert!(compacted.removed_messages > 0);
+    assert_eq!(
+        partition.get_size_bytes().as_bytes_u64(),
+        size_before_compaction.as_bytes_u64() - compacted.removed_bytes
+    );
+    let compacted_messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    let compacted_offsets = compacted_messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<_>>();
+    assert_eq!(compacted_offsets, expected_offsets);
+    for (key, offset) in latest_offsets {
+        let message = compacted_messages
+            .iter()
+            .find(|message| message.offset == offset)
+            .unwrap();
+        assert_eq!(message.id, key);
+    }
+
+    let first_messages = partition
+        .get_messages_by_offset(0, keys_count, 0)
+        .await
+        .unwrap();
+    assert_eq!(first_messages.len(), keys_count as usize);
+    assert_eq!(
+        first_messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>(),
+        expected_offsets[..keys_count as usize]
+    );
+}
+
+#[tokio::test]
+async fn should_build_key_filters_for_closed_segments_and_skip_them_when_compacting() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30;
+    let mut partition = create_compactable_partition(&setup).await;
+    for key in 1..=messages_count {
+        append_message(&mut partition, create_message(key, &format!("value {key}"))).await;
+    }
+
+    let compacted = partition.compact(IggyDuration::default()).await.unwrap();
+
+    assert_eq!(compacted.removed_messages, 0);
+    let closed_segments = partition
+        .get_segments()
+        .iter()
+        .filter(|segment| segment.is_closed)
+        .collect::<Vec<_>>();
+    assert!(closed_segments.len() > 1);
+    for segment in closed_segments.iter() {
+        assert!(fs::metadata(&segment.key_filter_path).await.is_ok());
+        for key in segment.start_offset..=segment.current_offset {
+            assert!(segment.might_contain_key(key as u128 + 1));
+        }
+    }
+    let open_segment = partition.get_segments().last().unwrap();
+    assert!(!open_segment.is_closed);
+    assert!(fs::metadata(&open_segment.key_filter_path).await.is_err());
+    assert!(open_segment.might_contain_key(u128::MAX));
+    let ruled_out_keys = (messages_count + 1..=messages_count + 100)
+        .filter(|key| !closed_segments[0].might_contain_key(*key))
+        .count();
+    assert!(ruled_out_keys > 90);
+
+    let first_key = 1;
+    append_message(&mut partition, create_message(first_key, "updated value")).await;
+    let compacted = partition.compact(IggyDuration::default()).await.unwrap();
+
+    assert_eq!(compacted.removed_messages, 1);
+    let first_segment = &partition.get_segments()[0];
+    assert!(!first_segment.might_contain_key(first_key));
+}
+
+#[tokio::test]
+async fn should_get_latest_message_by_key_across_segments() {
+    let setup = TestSetup::init().await;
+    let keys_count = 5;
+    let messages_count = 30;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        let key = (i % keys_count) as u128 + 1;
+        append_message(&mut partition, create_message(key, &format!("value {i}"))).await;
+    }
+    assert!(partition.get_segments_count() > 1);
+
+    for key in 1..=keys_count as u128 {
+        let message = partition.get_message_by_key(key).await.unwrap().unwrap();
+        assert_eq!(message.id, key);
+        let expected_offset = (messages_count - keys_count) as u64 + key as u64 - 1;
+        assert_eq!(message.offset, expected_offset);
+        assert_eq!(message.payload, format!("value {expected_offset}"));
+    }
+    assert!(partition
+        .get_message_by_key(keys_count as u128 + 1)
+        .await
+        .unwrap()
+        .is_none());
+    for segment in partition.get_segments().iter().filter(|s| s.is_closed) {
+        assert!(fs::metadata(&segment.key_filter_path).await.is_ok());
+    }
+
+    let tombstone_key = 1;
+    append_message(&mut partition, Message::tombstone(tombstone_key)).await;
+    assert!(partition
+        .get_message_by_key(tombstone_key)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn should_retain_tombstone_until_its_retention_expires() {
+    let setup = TestSetup::init().await;
+    let tombstone_key = 1;
+    let tombstone_offset = 10u64;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count as u64 {
+        let message = if i < tombstone_offset {
+            create_message((i % 3) as u128 + 1, &format!("value {i}"))
+        } else if i == tombstone_offset {
+            Message::tombstone(tombstone_key)
+        } else {
+            create_message((i % 2) as u128 + 2, &format!("value {i}"))
+        };
+        append_message(&mut partition, message).await;
+    }
+
+    let segments_last_offsets = partition
+        .get_segments()
+        .iter()
+        .filter(|segment| segment.is_closed)
+        .map(|segment| segment.current_offset)
+        .collect::<Vec<_>>();
+    assert!(!segments_last_offsets.contains(&tombstone_offset));
+
+    partition
+        .compact(IggyDuration::from_str("1h").unwrap())
+        .await
+        .unwrap();
+    let messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    let tombstone_key_offsets = messages
+        .iter()
+        .filter(|message| message.id == tombstone_key)
+        .map(|message| message.offset)
+        .filter(|offset| !segments_last_offsets.contains(offset))
+        .collect::<Vec<_>>();
+    assert_eq!(tombstone_key_offsets, vec![tombstone_offset]);
+    let tombstone = messages
+        .iter()
+        .find(|message| message.offset == tombstone_offset)
+        .unwrap();
+    assert!(tombstone.payload.is_empty());
+
+    partition.compact(IggyDuration::default()).await.unwrap();
+    let messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    assert!(messages
+        .iter()
+        .filter(|message| message.id == tombstone_key)
+        .all(|message| segments_last_offsets.contains(&message.offset)));
+}
+
+#[tokio::test]
+async fn should_reject_polling_below_low_watermark_after_deleting_segment() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(&mut partition, create_message(i as u128 + 1, "value")).await;
+    }
+    assert!(partition.get_segments_count() > 1);
+    let offsets = partition.get_offsets_info();
+    assert_eq!(offsets.low_watermark, 0);
+    assert_eq!(offsets.high_watermark, messages_count as u64);
+
+    partition.delete_segment(0).await.unwrap();
+
+    let low_watermark = partition.get_segments()[0].start_offset;
+    assert!(low_watermark > 0);
+    let offsets = partition.get_offsets_info();
+    assert_eq!(offsets.partition_id, partition.partition_id);
+    assert_eq!(offsets.low_watermark, low_watermark);
+    assert_eq!(offsets.high_watermark, messages_count as u64);
+    let error = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        IggyError::OffsetBelowLowWatermark(0, offset, _) if offset == low_watermark
+    ));
+    let first_messages = partition.get_first_messages(1, 0).await.unwrap();
+    assert_eq!(first_messages[0].offset, low_watermark);
+    let last_messages = partition
+        .get_last_messages(messages_count, 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        last_messages.len() as u64,
+        messages_count as u64 - low_watermark
+    );
+}
+
+#[tokio::test]
+async fn should_notify_subscribers_about_appended_and_purged_messages() {
+    let setup = TestSetup::init().await;
+    let mut partition = create_compactable_partition(&setup).await;
+    let mut subscriber = partition.subscribe_to_messages();
+    assert_eq!(subscriber.high_watermark(), 0);
+    assert!(
+        !subscriber
+            .wait_for_offset(0, Duration::from_millis(10))
+            .await
+    );
+
+    append_message(&mut partition, create_message(1, "value")).await;
+    assert!(subscriber.wait_for_offset(0, Duration::ZERO).await);
+    append_message(&mut partition, create_message(2, "value")).await;
+    assert_eq!(subscriber.high_watermark(), 2);
+
+    partition.purge().await.unwrap();
+    assert_eq!(subscriber.high_watermark(), 0);
+}
+
+#[tokio::test]
+async fn should_fetch_replica_messages_and_return_diverging_epoch_after_log_is_rewritten() {
+    let setup = TestSetup::init().await;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..10 {
+        append_message(&mut partition, create_message(i + 1, &format!("value {i}"))).await;
+    }
+
+    let replica_messages = partition
+        .fetch_replica_messages(REPLICA_ID, 0, 0, 4)
+        .await
+        .unwrap();
+    assert!(replica_messages.diverging_epoch.is_none());
+    assert_eq!(replica_messages.messages.messages.len(), 4);
+    assert_eq!(replica_messages.messages.messages[3].offset, 3);
+    assert_eq!(replica_messages.messages.next_offset, Some(4));
+
+    let replica_messages = partition
+        .fetch_replica_messages(REPLICA_ID, 10, 0, 4)
+        .await
+        .unwrap();
+    assert!(replica_messages.diverging_epoch.is_none());
+    assert!(replica_messages.messages.messages.is_empty());
+    assert!(replica_messages.messages.next_offset.is_none());
+    assert!(matches!(
+        partition.fetch_replica_messages(REPLICA_ID, 11, 0, 4).await,
+        Err(IggyError::OffsetAboveHighWatermark(11, 10, 1))
+    ));
+    assert!(matches!(
+        partition.fetch_replica_messages(REPLICA_ID, 0, 1, 4).await,
+        Err(IggyError::UnknownPartitionEpoch(1, 1, 0))
+    ));
+
+    partition.purge().await.unwrap();
+    for i in 0..3 {
+        append_message(
+            &mut partition,
+            create_message(i + 1, &format!("new value {i}")),
+        )
+        .await;
+    }
+
+    let replica_messages = partition
+        .fetch_replica_messages(REPLICA_ID, 10, 0, 4)
+        .await
+        .unwrap();
+    let diverging_epoch = replica_messages.diverging_epoch.unwrap();
+    assert_eq!(diverging_epoch.epoch, 0);
+    assert_eq!(diverging_epoch.end_offset, 0);
+    assert!(replica_messages.messages.messages.is_empty());
+    assert_eq!(replica_messages.messages.epoch, 1);
+
+    let replica_messages = partition
+        .fetch_replica_messages(REPLICA_ID, 0, 0, 4)
+        .await
+        .unwrap();
+    assert!(replica_messages.diverging_epoch.is_none());
+    assert_eq!(replica_messages.messages.messages.len(), 3);
+    assert_eq!(replica_messages.messages.messages[0].payload, "new value 0");
+    assert!(replica_messages.messages.next_offset.is_none());
+    assert!(partition
+        .fetch_replica_messages(REPLICA_ID, 3, 1, 4)
+        .await
+        .unwrap()
+        .messages
+        .messages
+        .is_empty());
+}
+
+#[tokio::test]
+async fn should_advance_visible_high_watermark_only_when_in_sync_replicas_have_caught_up() {
+    let setup = TestSetup::init().await;
+    let replica_lag_timeout = Duration::from_millis(500);
+    let mut partition = create_partition_with_config(
+        &setup,
+        PartitionConfig {
+            messages_required_to_save: 1,
+            enforce_fsync: true,
+            replica_lag_timeout: replica_lag_timeout.into(),
+            ..Default::default()
+        },
+    )
+    .await;
+    for i in 0..10 {
+        append_message(&mut partition, create_message(i + 1, &format!("value {i}"))).await;
+    }
+    assert_eq!(partition.get_visible_high_watermark(), 10);
+
+    partition
+        .fetch_replica_messages(REPLICA_ID, 0, 0, 5)
+        .await
+        .unwrap();
+    let replicas = partition.get_replicas_info();
+    assert_eq!(replicas.len(), 1);
+    assert_eq!(replicas[0].id, REPLICA_ID);
+    assert_eq!(replicas[0].fetched_offset, 0);
+    assert!(!replicas[0].is_in_sync);
+    assert_eq!(partition.get_visible_high_watermark(), 10);
+
+    partition
+        .fetch_replica_messages(REPLICA_ID, 10, 0, 5)
+        .await
+        .unwrap();
+    assert!(partition.get_replicas_info()[0].is_in_sync);
+    for i in 10..15 {
+        append_message(&mut partition, create_message(i + 1, &format!("value {i}"))).await;
+    }
+    assert_eq!(partition.get_visible_high_watermark(), 10);
+
+    partition
+        .fetch_replica_messages(REPLICA_ID, 12, 0, 5)
+        .await
+        .unwrap();
+    assert_eq!(partition.get_visible_high_watermark(), 12);
+    partition
+        .fetch_replica_messages(REPLICA_ID, 15, 0, 5)
+        .await
+        .unwrap();
+    assert_eq!(partition.get_visible_high_watermark(), 15);
+
+    append_message(&mut partition, create_message(16, "value 15")).await;
+    assert_eq!(partition.get_visible_high_watermark(), 15);
+    tokio::time::sleep(replica_lag_timeout * 2).await;
+    assert_eq!(partition.get_visible_high_watermark(), 16);
+    assert!(partition.get_replicas_info().is_empty());
+
+    partition
+        .fetch_replica_messages(REPLICA_ID, 15, 0, 5)
+        .await
+        .unwrap();
+    assert!(!partition.get_replicas_info()[0].is_in_sync);
+    partition.purge().await.unwrap();
+    assert!(partition.get_replicas_info().is_empty());
+}
+
+#[tokio::test]
+async fn should_not_track_unknown_replicas() {
+    let setup = TestSetup::init().await;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..10 {
+        append_message(&mut partition, create_message(i + 1, &format!("value {i}"))).await;
+    }
+
+    let unknown_replica_id = REPLICA_ID + 1;
+    assert!(matches!(
+        partition
+            .fetch_replica_messages(unknown_replica_id, 10, 0, 5)
+            .await,
+        Err(IggyError::NodeNotFound(id)) if id == unknown_replica_id
+    ));
+    assert!(partition.get_replicas_info().is_empty());
+
+    append_message(&mut partition, create_message(11, "value 10")).await;
+    assert_eq!(partition.get_visible_high_watermark(), 11);
+}
+
+#[tokio::test]
+async fn should_broadcast_appended_messages_to_in_process_subscribers() {
+    let setup = TestSetup::init().await;
+    let mut partition = create_compactable_partition(&setup).await;
+    let mut subscriber = setup.appends_broadcaster.subscribe();
+    append_message(&mut partition, create_message(1, "broadcast")).await;
+
+    let Some(AppendNotification::Appended(appended)) =
+        tokio::time::timeout(Duration::from_secs(5), subscriber.recv())
+            .await
+            .unwrap()
+    else {
+        panic!("Expected the appended messages");
+    };
+    assert_eq!(appended.stream_id, partition.stream_id);
+    assert_eq!(appended.topic_id, partition.topic_id);
+    assert_eq!(appended.partition_id, partition.partition_id);
+    assert_eq!(appended.messages.len(), 1);
+    assert_eq!(appended.messages[0].offset, 0);
+    assert_eq!(appended.messages[0].payload, "broadcast".as_bytes());
+}
+
+#[tokio::test]
+async fn should_poll_messages_until_either_count_or_max_bytes_is_reached() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(&mut partition, create_message(i as u128 + 1, "value")).await;
+    }
+    assert!(partition.get_segments_count() > 1);
+    let all_messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    assert_eq!(all_messages.len() as u32, messages_count);
+    let message_size = all_messages[0].get_size_bytes().as_bytes_u64() as u32;
+
+    let messages = partition
+        .get_messages_by_offset(0, messages_count, 12 * message_size)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 12);
+    assert!(messages
+        .iter()
+        .enumerate()
+        .all(|(index, message)| message.offset == index as u64));
+
+    let messages = partition
+        .get_messages_by_offset(5, messages_count, 3 * message_size + message_size / 2)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[0].offset, 5);
+
+    let messages = partition
+        .get_messages_by_offset(5, 2, 10 * message_size)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 2);
+
+    let messages = partition
+        .get_messages_by_offset(5, messages_count, 1)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, 5);
+
+    let messages = partition
+        .get_first_messages(messages_count, 4 * message_size)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 4);
+}
+
+#[tokio::test]
+async fn should_resolve_out_of_range_offset_using_policy() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(&mut partition, create_message(i as u128 + 1, "value")).await;
+    }
+    partition.delete_segment(0).await.unwrap();
+    let low_watermark = partition.get_low_watermark();
+    let high_watermark = partition.get_high_watermark();
+    let last_offset = messages_count as u64 - 1;
+
+    assert_eq!(
+        partition
+            .resolve_offset(low_watermark, OutOfRangePolicy::Error)
+            .unwrap(),
+        low_watermark
+    );
+    assert_eq!(
+        partition
+            .resolve_offset(high_watermark, OutOfRangePolicy::Error)
+            .unwrap(),
+        high_watermark
+    );
+    assert!(matches!(
+        partition.resolve_offset(0, OutOfRangePolicy::Error),
+        Err(IggyError::OffsetBelowLowWatermark(0, offset, _)) if offset == low_watermark
+    ));
+    assert!(matches!(
+        partition.resolve_offset(high_watermark + 1, OutOfRangePolicy::Error),
+        Err(IggyError::OffsetAboveHighWatermark(_, offset, _)) if offset == high_watermark
+    ));
+    for offset in [0, high_watermark + 1] {
+        assert_eq!(
+            partition
+                .resolve_offset(offset, OutOfRangePolicy::Earliest)
+                .unwrap(),
+            low_watermark
+        );
+        assert_eq!(
+            partition
+                .resolve_offset(offset, OutOfRangePolicy::Latest)
+                .unwrap(),
+            last_offset
+        );
+    }
+}
+
+#[tokio::test]
+async fn should_poll_messages_backward_from_offset() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(&mut partition, create_message(i as u128 + 1, "value")).await;
+    }
+    assert!(partition.get_segments_count() > 1);
+    let last_offset = messages_count as u64 - 1;
+
+    let mut offsets = Vec::new();
+    let mut offset = last_offset;
+    loop {
+        let messages = partition.get_messages_backward(offset, 7, 0).await.unwrap();
+        assert!(!messages.is_empty());
+        assert!(messages.len() <= 7);
+        assert_eq!(messages[0].offset, offset);
+        offsets.extend(messages.iter().map(|message| message.offset));
+        let oldest_offset = messages.last().unwrap().offset;
+        if oldest_offset == 0 {
+            break;
+        }
+        offset = oldest_offset - 1;
+    }
+    assert_eq!(offsets, (0..=last_offset).rev().collect::<Vec<_>>());
+
+    let message_size = partition.get_messages_by_offset(0, 1, 0).await.unwrap()[0]
+        .get_size_bytes()
+        .as_bytes_u64() as u32;
+    let messages = partition
+        .get_messages_backward(last_offset, messages_count, 3 * message_size)
+        .await
+        .unwrap();
+    assert_eq!(
+        messages
+            .iter()
+            .map(|message| message.offset)
+            .collect::<Vec<_>>(),
+        vec![last_offset, last_offset - 1, last_offset - 2]
+    );
+
+    let messages = partition
+        .get_messages_backward(last_offset + 10, 2, 0)
+        .await
+        .unwrap();
+    assert_eq!(messages[0].offset, last_offset);
+
+    partition.delete_segment(0).await.unwrap();
+    let low_watermark = partition.get_low_watermark();
+    let messages = partition
+        .get_messages_backward(low_watermark, messages_count, 0)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, low_watermark);
+    let messages = partition
+        .get_messages_backward(low_watermark - 1, messages_count, 0)
+        .await
+        .unwrap();
+    assert!(messages.is_empty());
+}
+
+#[tokio::test]
+async fn should_aggregate_messages_histogram_per_time_bucket() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(&mut partition, create_message(i as u128 + 1, "value")).await;
+    }
+    assert!(partition.get_segments_count() > 1);
+    let messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    let from = messages.first().unwrap().timestamp;
+    let to = messages.last().unwrap().timestamp + 1;
+    // Each message is stored in the batch along with its 4 bytes length.
+    let total_size = messages
+        .iter()
+        .map(|message| message.get_size_bytes().as_bytes_u64() + 4)
+        .sum::<u64>();
+
+    let histogram = partition
+        .get_messages_histogram(
+            from,
+            to,
+            IggyDuration::new(Duration::from_micros(to - from)),
+        )
+        .await
+        .unwrap();
+    assert_eq!(histogram.partition_id, partition.partition_id);
+    assert_eq!(histogram.buckets.len(), 1);
+    assert_eq!(histogram.buckets[0].timestamp, from);
+    assert_eq!(histogram.buckets[0].messages_count, messages_count as u64);
+    assert_eq!(histogram.buckets[0].size.as_bytes_u64(), total_size);
+
+    let histogram = partition
+        .get_messages_histogram(from, to, IggyDuration::new(Duration::from_micros(1)))
+        .await
+        .unwrap();
+    assert_eq!(histogram.buckets.len() as u64, to - from);
+    for bucket in histogram.buckets {
+        let expected_messages_count = messages
+            .iter()
+            .filter(|message| message.timestamp == bucket.timestamp)
+            .count();
+        assert_eq!(bucket.messages_count, expected_messages_count as u64);
+    }
+
+    let histogram = partition
+        .get_messages_histogram(to, to + 1000, IggyDuration::new(Duration::from_micros(100)))
+        .await
+        .unwrap();
+    assert_eq!(histogram.buckets.len(), 10);
+    assert!(histogram
+        .buckets
+        .iter()
+        .all(|bucket| bucket.messages_count == 0 && bucket.size.as_bytes_u64() == 0));
+}
+
+#[tokio::test]
+async fn should_seal_partition_by_closing_active_segment_at_current_offset() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 1;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let create_partition = |with_segment| {
+        Partition::create(
+            stream_id,
+            topic_id,
+            partition_id,
+            with_segment,
+            setup.config.clone(),
+            setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
+            IggyExpiry::NeverExpire,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyTimestamp::now(),
+        )
+    };
+    let mut partition = create_partition(true);
+    partition.persist().await.unwrap();
+
+    let sealed_partition = partition.seal().await.unwrap();
+    assert_eq!(sealed_partition.high_watermark, 0);
+    assert_eq!(sealed_partition.segments_count, 1);
+    assert!(!partition.get_segments()[0].is_closed);
+
+    for i in 0..5 {
+        append_message(&mut partition, create_message(i + 1, "value")).await;
+    }
+    assert!(partition.unsaved_messages_count > 0);
+
+    let sealed_partition = partition.seal().await.unwrap();
+    assert_eq!(sealed_partition.partition_id, partition_id);
+    assert_eq!(sealed_partition.high_watermark, 5);
+    assert_eq!(sealed_partition.segments_count, 1);
+    assert_eq!(partition.unsaved_messages_count, 0);
+    let sealed_segment = &partition.get_segments()[0];
+    assert!(sealed_segment.is_closed);
+    assert_eq!(sealed_segment.end_offset, 4);
+
+    append_message(&mut partition, create_message(6, "value")).await;
+    partition.flush_unsaved_buffer(true).await.unwrap();
+    assert_eq!(partition.get_segments_count(), 2);
+    assert_eq!(partition.get_segments()[1].start_offset, 5);
+    let messages = partition.get_messages_by_offset(0, 10, 0).await.unwrap();
+    assert_eq!(messages.len(), 6);
+
+    let mut loaded_partition = create_partition(false);
+    loaded_partition
+        .load(PartitionState {
+            id: partition_id,
+            created_at: IggyTimestamp::now(),
+            epoch: 0,
+        })
+        .await
+        .unwrap();
+    assert_eq!(loaded_partition.current_offset, 5);
+    assert_eq!(loaded_partition.get_segments_count(), 2);
+    let loaded_segment = &loaded_partition.get_segments()[0];
+    assert!(loaded_segment.is_closed);
+    assert_eq!(loaded_segment.end_offset, 4);
+    assert!(!loaded_partition.get_segments()[1].is_closed);
+}
+
+#[tokio::test]
+async fn should_read_ahead_messages_of_closed_segments() {
+    let setup = TestSetup::init().await;
+    let messages_count = 30u32;
+    let mut partition = create_compactable_partition(&setup).await;
+    for i in 0..messages_count {
+        append_message(
+            &mut partition,
+            create_message(i as u128 + 1, &format!("value {i}")),
+        )
+        .await;
+    }
+    assert!(partition.get_segments_count() > 1);
+    let expected_messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    partition.read_ahead.start();
+
+    let messages = partition.get_messages_by_offset(0, 2, 0).await.unwrap();
+    assert_eq!(messages.len(), 2);
+    let read_ahead_offset = partition.read_ahead.start();
+    assert_eq!(read_ahead_offset, Some(2));
+    partition
+        .read_ahead_messages(read_ahead_offset.unwrap())
+        .await
+        .unwrap();
+    assert!(partition.read_ahead.get(2, 2).is_some());
+
+    let mut polled_messages = messages;
+    while polled_messages.len() < messages_count as usize {
+        let offset = polled_messages.last().unwrap().offset + 1;
+        let messages = partition
+            .get_messages_by_offset(offset, 3, 0)
+            .await
+            .unwrap();
+        assert!(!messages.is_empty());
+        polled_messages.extend(messages);
+    }
+    assert_eq!(polled_messages.len(), expected_messages.len());
+    for (polled_message, expected_message) in polled_messages.iter().zip(expected_messages.iter()) {
+        assert_eq!(polled_message.offset, expected_message.offset);
+        assert_eq!(polled_message.id, expected_message.id);
+        assert_eq!(polled_message.payload, expected_message.payload);
+    }
+}
+
+#[tokio::test]
+async fn should_roll_over_full_segment_on_append_without_losing_unsaved_messages() {
+    let setup = TestSetup::init().await;
+    let mut partition = create_partition_with_config(
+        &setup,
+        PartitionConfig {
+            messages_required_to_save: 2,
+            ..Default::default()
+        },
+    )
+    .await;
+    // The flush worker falls behind, so the segment becomes full before its unsaved messages are persisted.
+    let messages_count = 30u32;
+    for i in 0..messages_count / 3 {
+        let messages = (0..3)
+            .map(|j| create_message((3 * i + j) as u128 + 1, &format!("value {}", 3 * i + j)))
+            .collect::<Vec<_>>();
+        let batch_size = messages
+            .iter()
+            .map(|message| message.get_size_bytes())
+            .sum();
+        partition
+            .append_messages_to_buffer(
+                AppendableBatchInfo::new(batch_size, partition.partition_id),
+                messages,
+            )
+            .await
+            .unwrap();
+    }
+    partition.flush_unsaved_buffer(false).await.unwrap();
+
+    assert!(partition.get_segments_count() > 1);
+    let segments = partition.get_segments();
+    for segment in &segments[..segments.len() - 1] {
+        assert!(segment.is_closed);
+    }
+    let messages = partition
+        .get_messages_by_offset(0, messages_count, 0)
+        .await
+        .unwrap();
+    let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+    assert_eq!(offsets, (0..messages_count as u64).collect::<Vec<_>>());
+}
+
+async fn create_compactable_partition(setup: &TestSetup) -> Partition {
+    create_partition_with_config(
+        setup,
+        PartitionConfig {
+            messages_required_to_save: 1,
+            enforce_fsync: true,
+            ..Default::default()
+        },
+    )
+    .await
+}
+
+async fn create_partition_with_config(
+    setup: &TestSetup,
+    partition_config: PartitionConfig,
+) -> Partition {
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        cache: CacheConfig {
+            enabled: false,
+            ..Default::default()
+        },
+        partition: partition_config,
+        segment: SegmentConfig {
+            size: IggyByteSize::from(300),
+            ..Default::default()
+        },
+        cluster: ClusterConfig {
+            followers: vec![REPLICA_ID],
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config,
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyTimestamp::now(),
+    );
+    partition.persist().await.unwrap();
+    partition
+}
+
+async fn append_message(partition: &mut Partition, message: Message) {
+    let appendable_batch_info =
+        AppendableBatchInfo::new(message.get_size_bytes(), partition.partition_id);
+    partition
+        .append_messages(appendable_batch_info, vec![message])
+        .await
+        .unwrap();
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     assert!(fs::metadata(&partition_path).await.is_ok());
 
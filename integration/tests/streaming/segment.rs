@@ -1,17 +1,23 @@
 use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::bytes_serializable::BytesSerializable;
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{MessageState, PolledMessage};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::{checksum, timestamp::IggyTimestamp};
+use server::configs::system::{SegmentConfig, SystemConfig};
 use server::streaming::local_sizeable::LocalSizeable;
 use server::streaming::models::messages::RetainedMessage;
 use server::streaming::segments::segment;
-use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION};
+use server::streaming::segments::segment::{COMPACTED_EXTENSION, INDEX_EXTENSION, LOG_EXTENSION};
+use server::streaming::segments::storage::INDEX_SIZE;
+use server::streaming::systems::migrations::{BatchFormatMigration, DataMigration};
+use std::collections::HashMap;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 
 #[tokio::test]
 async fn should_persist_segment() {
@@ -28,6 +34,7 @@ async fn should_persist_segment() {
             start_offset,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -66,6 +73,7 @@ async fn should_load_existing_segment_from_disk() {
             start_offset,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -93,6 +101,7 @@ async fn should_load_existing_segment_from_disk() {
             start_offset,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.events_bus.clone(),
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -130,6 +139,7 @@ async fn should_persist_and_load_segment_with_messages() {
         start_offset,
         setup.config.clone(),
         setup.storage.clone(),
+        setup.events_bus.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -181,6 +191,7 @@ async fn should_persist_and_load_segment_with_messages() {
         start_offset,
         setup.config.clone(),
         setup.storage.clone(),
+        setup.events_bus.clone(),
         IggyExpiry::NeverExpire,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -213,6 +224,7 @@ async fn given_all_expired_messages_segment_should_be_expired() {
         start_offset,
         setup.config.clone(),
         setup.storage.clone(),
+        setup.events_bus.clone(),
         message_expiry,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -281,6 +293,7 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
         start_offset,
         setup.config.clone(),
         setup.storage.clone(),
+        setup.events_bus.clone(),
         message_expiry,
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
@@ -349,6 +362,485 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     assert!(!is_expired);
 }
 
+#[tokio::test]
+async fn should_load_messages_using_sparse_index() {
+    for cache_indexes in [true, false] {
+        let setup = TestSetup::init_with_config(SystemConfig {
+            segment: SegmentConfig {
+                cache_indexes,
+                index_interval: IggyByteSize::from(200),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+        let stream_id = 1;
+        let topic_id = 2;
+        let partition_id = 3;
+        let start_offset = 0;
+        let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+
+        setup
+            .create_partition_directory(stream_id, topic_id, partition_id)
+            .await;
+        segment.persist().await.unwrap();
+        let messages_count = 20;
+        for i in 0..messages_count {
+            let message = create_message(i, "test", IggyTimestamp::now());
+            let retained_message = Arc::new(RetainedMessage {
+                id: message.id,
+                offset: message.offset,
+                timestamp: message.timestamp,
+                checksum: message.checksum,
+                message_state: message.state,
+                headers: message.headers.map(|headers| headers.to_bytes()),
+                payload: message.payload.clone(),
+            });
+            let batch_size = retained_message.get_size_bytes();
+            segment
+                .append_batch(batch_size, 1, &[retained_message])
+                .await
+                .unwrap();
+            segment.persist_messages().await.unwrap();
+        }
+
+        let index_path = format!(
+            "{}/{:0>20}.{}",
+            setup
+                .config
+                .get_partition_path(stream_id, topic_id, partition_id),
+            start_offset,
+            INDEX_EXTENSION
+        );
+        let index_size = fs::metadata(&index_path).await.unwrap().len();
+        assert!(index_size > 0);
+        assert!(index_size < messages_count * INDEX_SIZE as u64);
+
+        let mut loaded_segment =
+            create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+        loaded_segment.load().await.unwrap();
+        assert_eq!(loaded_segment.current_offset, messages_count - 1);
+
+        let messages = loaded_segment.get_messages(7, 5).await.unwrap();
+        let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+        assert_eq!(offsets, (7..12).collect::<Vec<_>>());
+
+        let messages = loaded_segment.get_messages(18, 10).await.unwrap();
+        let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+        assert_eq!(offsets, vec![18, 19]);
+    }
+}
+
+#[tokio::test]
+async fn should_recover_segment_ending_with_partially_written_batch() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, "test", IggyTimestamp::now());
+        let retained_message = Arc::new(RetainedMessage {
+            id: message.id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            checksum: message.checksum,
+            message_state: message.state,
+            headers: message.headers.map(|headers| headers.to_bytes()),
+            payload: message.payload.clone(),
+        });
+        let batch_size = retained_message.get_size_bytes();
+        segment
+            .append_batch(batch_size, 1, &[retained_message])
+            .await
+            .unwrap();
+        segment.persist_messages().await.unwrap();
+    }
+
+    // Simulate the power loss in the middle of saving the next batch, after its index was already flushed.
+    let log_size = fs::metadata(&segment.log_path).await.unwrap().len();
+    let index_size = fs::metadata(&segment.index_path).await.unwrap().len();
+    let mut partial_batch = Vec::new();
+    partial_batch.extend(messages_count.to_le_bytes());
+    partial_batch.extend(1000u32.to_le_bytes());
+    partial_batch.extend([0; 20]);
+    append_bytes(&segment.log_path, &partial_batch).await;
+    let mut partial_indexes = Vec::new();
+    partial_indexes.extend((messages_count as u32).to_le_bytes());
+    partial_indexes.extend((log_size as u32).to_le_bytes());
+    partial_indexes.extend(IggyTimestamp::now().as_micros().to_le_bytes());
+    partial_indexes.extend([0; 5]);
+    append_bytes(&segment.index_path, &partial_indexes).await;
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        log_size
+    );
+    assert_eq!(
+        fs::metadata(&segment.index_path).await.unwrap().len(),
+        index_size
+    );
+    assert_eq!(loaded_segment.size_bytes, IggyByteSize::from(log_size));
+    assert_eq!(loaded_segment.current_offset, messages_count - 1);
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32 + 1)
+        .await
+        .unwrap();
+    let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+    assert_eq!(offsets, (0..messages_count).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn should_complete_or_discard_interrupted_segment_rewrite_on_load() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, "test", IggyTimestamp::now());
+        let retained_message = Arc::new(RetainedMessage {
+            id: message.id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            checksum: message.checksum,
+            message_state: message.state,
+            headers: message.headers.map(|headers| headers.to_bytes()),
+            payload: message.payload.clone(),
+        });
+        let batch_size = retained_message.get_size_bytes();
+        segment
+            .append_batch(batch_size, 1, &[retained_message])
+            .await
+            .unwrap();
+        segment.persist_messages().await.unwrap();
+    }
+    let log_size = fs::metadata(&segment.log_path).await.unwrap().len();
+    let index = fs::read(&segment.index_path).await.unwrap();
+    let compacted_log_path = format!("{}.{COMPACTED_EXTENSION}", segment.log_path);
+    let compacted_index_path = format!("{}.{COMPACTED_EXTENSION}", segment.index_path);
+    let compacted_key_version_path = format!("{}.{COMPACTED_EXTENSION}", segment.key_version_path);
+
+    // Simulate the crash after renaming the rewritten log file, but before renaming the index and the key version files.
+    fs::write(&compacted_index_path, &index).await.unwrap();
+    fs::write(&compacted_key_version_path, 7u32.to_le_bytes())
+        .await
+        .unwrap();
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(loaded_segment.key_version, Some(7));
+    assert!(fs::metadata(&compacted_index_path).await.is_err());
+    assert!(fs::metadata(&compacted_key_version_path).await.is_err());
+    assert_eq!(fs::read(&segment.index_path).await.unwrap(), index);
+
+    // Simulate the crash before renaming any of the rewritten files, which leaves the original ones intact.
+    fs::write(&compacted_log_path, [0; 10]).await.unwrap();
+    fs::write(&compacted_index_path, [0; 10]).await.unwrap();
+    fs::write(&compacted_key_version_path, 8u32.to_le_bytes())
+        .await
+        .unwrap();
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(loaded_segment.key_version, Some(7));
+    assert!(fs::metadata(&compacted_log_path).await.is_err());
+    assert!(fs::metadata(&compacted_index_path).await.is_err());
+    assert!(fs::metadata(&compacted_key_version_path).await.is_err());
+    assert_eq!(loaded_segment.size_bytes, IggyByteSize::from(log_size));
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+}
+
+#[tokio::test]
+async fn should_truncate_segment_at_corrupted_batch_and_rebuild_missing_indexes() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        segment: SegmentConfig {
+            index_interval: IggyByteSize::from(0),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, "test", IggyTimestamp::now());
+        let retained_message = Arc::new(RetainedMessage {
+            id: message.id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            checksum: message.checksum,
+            message_state: message.state,
+            headers: message.headers.map(|headers| headers.to_bytes()),
+            payload: message.payload.clone(),
+        });
+        let batch_size = retained_message.get_size_bytes();
+        segment
+            .append_batch(batch_size, 1, &[retained_message])
+            .await
+            .unwrap();
+        segment.persist_messages().await.unwrap();
+    }
+
+    // Simulate the crash which corrupted the batch with offset 8 and lost the indexes saved after the batch with offset 4.
+    let indexes = fs::read(&segment.index_path).await.unwrap();
+    assert_eq!(indexes.len(), messages_count as usize * INDEX_SIZE as usize);
+    let get_position = |index: usize| {
+        let position = index * INDEX_SIZE as usize + 4;
+        u32::from_le_bytes(indexes[position..position + 4].try_into().unwrap()) as usize
+    };
+    let corrupted_batch_position = get_position(8) as u64;
+    let next_batch_position = get_position(9);
+    let mut log = fs::read(&segment.log_path).await.unwrap();
+    log[next_batch_position - 1] ^= 0xFF;
+    fs::write(&segment.log_path, &log).await.unwrap();
+    fs::write(&segment.index_path, &indexes[..5 * INDEX_SIZE as usize])
+        .await
+        .unwrap();
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+
+    assert_eq!(
+        fs::metadata(&segment.log_path).await.unwrap().len(),
+        corrupted_batch_position
+    );
+    assert_eq!(
+        fs::read(&segment.index_path).await.unwrap(),
+        indexes[..8 * INDEX_SIZE as usize]
+    );
+    assert_eq!(loaded_segment.current_offset, 7);
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+    assert_eq!(offsets, (0..8).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn index_timestamps_should_not_go_backwards_when_clock_does() {
+    let setup = TestSetup::init_with_config(SystemConfig {
+        segment: SegmentConfig {
+            index_interval: IggyByteSize::from(0),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    // The clock goes backwards after the second message, e.g. due to the NTP correction.
+    let timestamps = [1000, 3000, 2000, 2500, 4000];
+    for (offset, timestamp) in timestamps.iter().enumerate() {
+        let message = create_message(offset as u64, "test", (*timestamp).into());
+        let retained_message = Arc::new(RetainedMessage {
+            id: message.id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            checksum: message.checksum,
+            message_state: message.state,
+            headers: message.headers.map(|headers| headers.to_bytes()),
+            payload: message.payload.clone(),
+        });
+        let batch_size = retained_message.get_size_bytes();
+        segment
+            .append_batch(batch_size, 1, &[retained_message])
+            .await
+            .unwrap();
+        segment.persist_messages().await.unwrap();
+    }
+
+    let indexes = fs::read(&segment.index_path).await.unwrap();
+    let index_timestamps = indexes
+        .chunks(INDEX_SIZE as usize)
+        .map(|index| u64::from_le_bytes(index[8..16].try_into().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(index_timestamps, vec![1000, 3000, 3000, 3000, 4000]);
+    assert_eq!(segment.max_timestamp, 4000);
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.max_timestamp, 4000);
+
+    // The messages keep their own timestamps, only the index is clamped.
+    let messages = loaded_segment
+        .get_messages(0, timestamps.len() as u32)
+        .await
+        .unwrap();
+    let loaded_timestamps = messages.iter().map(|m| m.timestamp).collect::<Vec<_>>();
+    assert_eq!(loaded_timestamps, timestamps.to_vec());
+}
+
+#[tokio::test]
+async fn should_load_segment_written_in_baseline_batch_format_after_migration() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let segment = create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+
+    // The batches written before the batch header was versioned, the second one spanning
+    // the timestamps which deltas don't fit into the current format.
+    let headers = HashMap::from([(
+        HeaderKey::new("key").unwrap(),
+        HeaderValue::from_uint64(1).unwrap(),
+    )])
+    .to_bytes();
+    let batches = [
+        vec![(0, 1000, Some(headers.clone())), (1, 1010, None)],
+        vec![(2, 1020, None), (3, 1020 + u32::MAX as u64 + 1, None)],
+    ];
+    let mut log = Vec::new();
+    let mut indexes = Vec::new();
+    for messages in &batches {
+        let position = log.len() as u32;
+        let payload = messages
+            .iter()
+            .flat_map(|(offset, timestamp, headers)| {
+                create_baseline_message(*offset, *timestamp, headers.clone())
+            })
+            .collect::<Vec<_>>();
+        let (base_offset, _, _) = messages[0];
+        let (last_offset, max_timestamp, _) = messages[messages.len() - 1];
+        log.extend(base_offset.to_le_bytes());
+        log.extend((payload.len() as u32).to_le_bytes());
+        log.extend(((last_offset - base_offset) as u32).to_le_bytes());
+        log.extend(max_timestamp.to_le_bytes());
+        log.extend(payload);
+        indexes.extend((last_offset as u32).to_le_bytes());
+        indexes.extend(position.to_le_bytes());
+        indexes.extend(max_timestamp.to_le_bytes());
+    }
+    fs::write(&segment.log_path, &log).await.unwrap();
+    fs::write(&segment.index_path, &indexes).await.unwrap();
+
+    let migration = BatchFormatMigration;
+    let paths = migration.plan(&setup.config).await.unwrap();
+    assert_eq!(paths, vec![segment.log_path.clone()]);
+    migration.apply(&paths[0]).await.unwrap();
+    assert!(migration.plan(&setup.config).await.unwrap().is_empty());
+
+    let mut loaded_segment =
+        create_segment(&setup, stream_id, topic_id, partition_id, start_offset);
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.current_offset, 3);
+    assert_eq!(
+        fs::metadata(&segment.index_path).await.unwrap().len(),
+        batches.len() as u64 * INDEX_SIZE as u64
+    );
+
+    let messages = loaded_segment.get_messages(0, 10).await.unwrap();
+    let expected_messages = batches.iter().flatten().collect::<Vec<_>>();
+    assert_eq!(messages.len(), expected_messages.len());
+    for (message, (offset, timestamp, headers)) in messages.iter().zip(expected_messages) {
+        assert_eq!(message.offset, *offset);
+        assert_eq!(message.timestamp, *timestamp);
+        assert_eq!(message.headers, *headers);
+        assert_eq!(message.payload, Bytes::from(format!("message {offset}")));
+    }
+
+    let messages = loaded_segment.get_messages(2, 2).await.unwrap();
+    let offsets = messages.iter().map(|m| m.offset).collect::<Vec<_>>();
+    assert_eq!(offsets, vec![2, 3]);
+}
+
+// length: 4 bytes, offset: 8 bytes, state: 1 byte, timestamp: 8 bytes, id: 16 bytes, checksum: 4 bytes,
+// headers length: 4 bytes, headers, payload
+fn create_baseline_message(offset: u64, timestamp: u64, headers: Option<Bytes>) -> Vec<u8> {
+    let payload = format!("message {offset}");
+    let headers = headers.unwrap_or_default();
+    let mut message = Vec::new();
+    message.extend(offset.to_le_bytes());
+    message.push(MessageState::Available.as_code());
+    message.extend(timestamp.to_le_bytes());
+    message.extend((offset as u128 + 1).to_le_bytes());
+    message.extend(checksum::calculate(payload.as_bytes()).to_le_bytes());
+    message.extend((headers.len() as u32).to_le_bytes());
+    message.extend(headers);
+    message.extend(payload.as_bytes());
+    [(message.len() as u32).to_le_bytes().to_vec(), message].concat()
+}
+
+async fn append_bytes(path: &str, bytes: &[u8]) {
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(path)
+        .await
+        .unwrap();
+    file.write_all(bytes).await.unwrap();
+    file.sync_all().await.unwrap();
+}
+
+fn create_segment(
+    setup: &TestSetup,
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    start_offset: u64,
+) -> segment::Segment {
+    segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.events_bus.clone(),
+        IggyExpiry::NeverExpire,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+    )
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
@@ -2,7 +2,7 @@ use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use iggy::identifier::Identifier;
 use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{AckLevel, Partitioning};
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
@@ -11,7 +11,9 @@ use iggy::utils::topic_size::MaxTopicSize;
 use server::state::system::StreamState;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::streams::stream::Stream;
+use server::streaming::topics::deletion::TopicDeletions;
 use std::collections::HashMap;
+use std::time::Duration;
 use tokio::fs;
 
 #[tokio::test]
@@ -26,6 +28,8 @@ async fn should_persist_stream_with_topics_directory_and_info_file() {
             &name,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
 
         stream.persist().await.unwrap();
@@ -46,6 +50,8 @@ async fn should_load_existing_stream_from_disk() {
             &name,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
         stream.persist().await.unwrap();
         assert_persisted_stream(&stream.path, &setup.config.topic.path).await;
@@ -55,6 +61,8 @@ async fn should_load_existing_stream_from_disk() {
             &name,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
         let state = StreamState {
             id: stream_id,
@@ -84,6 +92,8 @@ async fn should_delete_existing_stream_from_disk() {
             &name,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
         stream.persist().await.unwrap();
         assert_persisted_stream(&stream.path, &setup.config.topic.path).await;
@@ -106,6 +116,8 @@ async fn should_purge_existing_stream_on_disk() {
             &name,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
         stream.persist().await.unwrap();
         assert_persisted_stream(&stream.path, &setup.config.topic.path).await;
@@ -120,6 +132,8 @@ async fn should_purge_existing_stream_on_disk() {
                 Default::default(),
                 MaxTopicSize::ServerDefault,
                 1,
+                Default::default(),
+                Default::default(),
             )
             .await
             .unwrap();
@@ -134,7 +148,12 @@ async fn should_purge_existing_stream_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                AckLevel::Leader,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -143,6 +162,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                0,
             )
             .await
             .unwrap();
@@ -156,6 +176,7 @@ async fn should_purge_existing_stream_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                0,
             )
             .await
             .unwrap();
@@ -164,6 +185,85 @@ async fn should_purge_existing_stream_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_delete_topic_immediately_and_remove_its_files_in_background() {
+    let setup = TestSetup::init().await;
+    setup.create_streams_directory().await;
+    let stream_id = 1;
+    let mut stream = Stream::create(
+        stream_id,
+        "test",
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+    );
+    stream.persist().await.unwrap();
+
+    let topic_id = 1;
+    stream
+        .create_topic(
+            Some(topic_id),
+            "test",
+            1,
+            IggyExpiry::NeverExpire,
+            Default::default(),
+            MaxTopicSize::ServerDefault,
+            1,
+            Default::default(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    let messages = create_messages();
+    let batch_size = messages
+        .iter()
+        .map(|msg| msg.get_size_bytes())
+        .sum::<IggyByteSize>();
+    let topic = stream
+        .get_topic(&Identifier::numeric(topic_id).unwrap())
+        .unwrap();
+    topic
+        .append_messages(
+            batch_size,
+            Partitioning::partition_id(1),
+            messages,
+            AckLevel::Leader,
+        )
+        .await
+        .unwrap();
+    topic.persist_messages().await.unwrap();
+    let topic_path = topic.path.clone();
+    assert!(stream.get_messages_count() > 0);
+
+    let deletions = TopicDeletions::default();
+    stream
+        .delete_topic(&Identifier::numeric(topic_id).unwrap(), &deletions)
+        .await
+        .unwrap();
+
+    assert!(stream
+        .get_topic(&Identifier::numeric(topic_id).unwrap())
+        .is_err());
+    assert_eq!(stream.get_messages_count(), 0);
+    assert_eq!(stream.get_segments_count(), 0);
+    assert_eq!(stream.get_size().as_bytes_u64(), 0);
+    assert!(fs::metadata(&topic_path).await.is_err());
+    assert_eq!(deletions.get_topics_count(), 1);
+
+    for _ in 0..100 {
+        if deletions.get_topics_count() == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    assert_eq!(deletions.get_topics_count(), 0);
+    assert_eq!(deletions.get_size_bytes(), 0);
+    let mut dir_entries = fs::read_dir(&stream.topics_path).await.unwrap();
+    assert!(dir_entries.next_entry().await.unwrap().is_none());
+}
+
 async fn assert_persisted_stream(stream_path: &str, topics_directory: &str) {
     let stream_metadata = fs::metadata(stream_path).await.unwrap();
     assert!(stream_metadata.is_dir());
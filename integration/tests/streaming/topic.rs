@@ -2,18 +2,24 @@ use std::collections::HashMap;
 use std::default::Default;
 use std::sync::atomic::{AtomicU32, AtomicU64};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::streaming::common::test_setup::TestSetup;
 use crate::streaming::create_messages;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{AckLevel, Partitioning};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::schema_type::SchemaType;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
 use iggy::utils::topic_size::MaxTopicSize;
-use server::state::system::{PartitionState, TopicState};
+use server::configs::system::{PlacementPolicy, SystemConfig};
+use server::state::system::{PartitionState, SchemaState, TopicState};
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use tokio::fs;
@@ -34,6 +40,8 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             partitions_count,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
@@ -41,6 +49,8 @@ async fn should_persist_topics_with_partitions_directories_and_info_file() {
             CompressionAlgorithm::default(),
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap();
 
@@ -71,6 +81,8 @@ async fn should_load_existing_topic_from_disk() {
             partitions_count,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
@@ -78,6 +90,8 @@ async fn should_load_existing_topic_from_disk() {
             CompressionAlgorithm::default(),
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -98,6 +112,8 @@ async fn should_load_existing_topic_from_disk() {
             Arc::new(AtomicU32::new(0)),
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
         );
         let topic_state = TopicState {
             id: topic_id,
@@ -106,16 +122,36 @@ async fn should_load_existing_topic_from_disk() {
                 HashMap::new()
             } else {
                 (1..=partitions_count)
-                    .map(|id| (id, PartitionState { id, created_at }))
+                    .map(|id| {
+                        (
+                            id,
+                            PartitionState {
+                                id,
+                                created_at,
+                                epoch: 0,
+                            },
+                        )
+                    })
                     .collect()
             },
             consumer_groups: Default::default(),
+            schemas: vec![SchemaState {
+                version: 1,
+                schema_type: SchemaType::JsonSchema,
+                validate: true,
+                definition: r#"{"type":"object"}"#.to_string(),
+                created_at,
+            }],
             compression_algorithm: Default::default(),
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            cleanup_policy: Default::default(),
+            overrides: Default::default(),
             created_at: Default::default(),
             current_consumer_group_id: 0,
+            producer_epoch: 0,
+            read_only: false,
         };
         loaded_topic.load(topic_state).await.unwrap();
 
@@ -128,6 +164,8 @@ async fn should_load_existing_topic_from_disk() {
         );
         assert_eq!(loaded_topic.path, topic.path);
         assert_eq!(loaded_topic.get_partitions().len() as u32, partitions_count);
+        assert_eq!(loaded_topic.get_schemas().len(), 1);
+        assert!(loaded_topic.get_schema(None).unwrap().validate);
     }
 }
 
@@ -147,6 +185,8 @@ async fn should_delete_existing_topic_from_disk() {
             partitions_count,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
@@ -154,6 +194,8 @@ async fn should_delete_existing_topic_from_disk() {
             CompressionAlgorithm::default(),
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -170,6 +212,113 @@ async fn should_delete_existing_topic_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_distribute_partitions_across_data_paths_and_load_them() {
+    let mut config = SystemConfig::default();
+    config.placement.paths = vec!["disk_2".to_string()];
+    config.placement.policy = PlacementPolicy::RoundRobin;
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partitions_count = 3;
+    let name = "test";
+    setup.create_topics_directory(stream_id).await;
+    let topic = Topic::create(
+        stream_id,
+        topic_id,
+        name,
+        partitions_count,
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyExpiry::NeverExpire,
+        CompressionAlgorithm::default(),
+        MaxTopicSize::ServerDefault,
+        1,
+        CleanupPolicy::Delete,
+        Default::default(),
+    )
+    .unwrap();
+    topic.persist().await.unwrap();
+
+    let system_path = setup.config.get_system_path();
+    let extra_path = &setup.config.placement.paths[0];
+    for (partition_id, data_path) in [(1, &system_path), (2, extra_path), (3, &system_path)] {
+        let partition_path = format!(
+            "{}/{partition_id}",
+            setup
+                .config
+                .get_data_partitions_path(data_path, stream_id, topic_id)
+        );
+        assert_eq!(
+            setup
+                .config
+                .get_partition_path(stream_id, topic_id, partition_id),
+            partition_path
+        );
+        assert!(fs::metadata(&partition_path).await.unwrap().is_dir());
+    }
+
+    let created_at = IggyTimestamp::now();
+    let mut loaded_topic = Topic::empty(
+        stream_id,
+        topic_id,
+        name,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+    );
+    let topic_state = TopicState {
+        id: topic_id,
+        name: name.to_string(),
+        partitions: (1..=partitions_count)
+            .map(|id| {
+                (
+                    id,
+                    PartitionState {
+                        id,
+                        created_at,
+                        epoch: 0,
+                    },
+                )
+            })
+            .collect(),
+        consumer_groups: Default::default(),
+        schemas: Vec::new(),
+        compression_algorithm: Default::default(),
+        message_expiry: IggyExpiry::NeverExpire,
+        max_topic_size: MaxTopicSize::ServerDefault,
+        replication_factor: Some(1),
+        cleanup_policy: Default::default(),
+        overrides: Default::default(),
+        created_at: Default::default(),
+        current_consumer_group_id: 0,
+        producer_epoch: 0,
+        read_only: false,
+    };
+    loaded_topic.load(topic_state).await.unwrap();
+    assert_eq!(loaded_topic.get_partitions().len() as u32, partitions_count);
+
+    loaded_topic.delete().await.unwrap();
+
+    assert!(fs::metadata(&topic.path).await.is_err());
+    assert!(fs::metadata(
+        setup
+            .config
+            .get_data_topic_path(extra_path, stream_id, topic_id)
+    )
+    .await
+    .is_err());
+}
+
 #[tokio::test]
 async fn should_purge_existing_topic_on_disk() {
     let setup = TestSetup::init().await;
@@ -186,6 +335,8 @@ async fn should_purge_existing_topic_on_disk() {
             partitions_count,
             setup.config.clone(),
             setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU32::new(0)),
@@ -193,6 +344,8 @@ async fn should_purge_existing_topic_on_disk() {
             CompressionAlgorithm::default(),
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap();
         topic.persist().await.unwrap();
@@ -210,7 +363,12 @@ async fn should_purge_existing_topic_on_disk() {
             .map(|msg| msg.get_size_bytes())
             .sum::<IggyByteSize>();
         topic
-            .append_messages(batch_size, Partitioning::partition_id(1), messages)
+            .append_messages(
+                batch_size,
+                Partitioning::partition_id(1),
+                messages,
+                AckLevel::Leader,
+            )
             .await
             .unwrap();
         let loaded_messages = topic
@@ -219,6 +377,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                0,
             )
             .await
             .unwrap();
@@ -231,6 +390,7 @@ async fn should_purge_existing_topic_on_disk() {
                 1,
                 PollingStrategy::offset(0),
                 100,
+                0,
             )
             .await
             .unwrap();
@@ -239,6 +399,226 @@ async fn should_purge_existing_topic_on_disk() {
     }
 }
 
+#[tokio::test]
+async fn should_persist_appended_messages_by_flush_worker_when_fsync_is_required() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    setup.create_topics_directory(stream_id).await;
+    let topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        1,
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyExpiry::NeverExpire,
+        CompressionAlgorithm::default(),
+        MaxTopicSize::ServerDefault,
+        1,
+        CleanupPolicy::Delete,
+        Default::default(),
+    )
+    .unwrap();
+    topic.persist().await.unwrap();
+
+    for ack in [AckLevel::Leader, AckLevel::Fsync] {
+        let messages = create_messages();
+        let batch_size = messages
+            .iter()
+            .map(|msg| msg.get_size_bytes())
+            .sum::<IggyByteSize>();
+        topic
+            .append_messages(batch_size, Partitioning::partition_id(1), messages, ack)
+            .await
+            .unwrap();
+    }
+
+    let partition = topic.get_partition(1).unwrap();
+    let partition = partition.read().await;
+    assert_eq!(partition.unsaved_messages_count, 0);
+    let segment = partition.get_segments().last().unwrap();
+    let log_metadata = fs::metadata(&segment.log_path).await.unwrap();
+    assert_eq!(log_metadata.len(), segment.size_bytes.as_bytes_u64());
+}
+
+#[tokio::test]
+async fn should_reject_appends_once_background_flush_has_failed() {
+    let mut config = SystemConfig::default();
+    config.partition.messages_required_to_save = create_messages().len() as u32;
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 1;
+    setup.create_topics_directory(stream_id).await;
+    let topic = Topic::create(
+        stream_id,
+        topic_id,
+        "test",
+        1,
+        setup.config.clone(),
+        setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        IggyExpiry::NeverExpire,
+        CompressionAlgorithm::default(),
+        MaxTopicSize::ServerDefault,
+        1,
+        CleanupPolicy::Delete,
+        Default::default(),
+    )
+    .unwrap();
+    topic.persist().await.unwrap();
+    let partition = topic.get_partition(1).unwrap();
+    let log_path = partition
+        .read()
+        .await
+        .get_segments()
+        .last()
+        .unwrap()
+        .log_path
+        .clone();
+    fs::remove_file(&log_path).await.unwrap();
+
+    // The messages are acknowledged before the flush worker fails to persist them in the background
+    let messages = create_messages();
+    let batch_size = messages
+        .iter()
+        .map(|msg| msg.get_size_bytes())
+        .sum::<IggyByteSize>();
+    topic
+        .append_messages(
+            batch_size,
+            Partitioning::partition_id(1),
+            messages,
+            AckLevel::Leader,
+        )
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !partition.read().await.is_failed() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(topic.get_failed_partitions_count().await, 1);
+
+    // The failed partition rejects any later append, instead of reporting the error to the next one only
+    for ack in [AckLevel::Leader, AckLevel::Fsync, AckLevel::Leader] {
+        let messages = create_messages();
+        let batch_size = messages
+            .iter()
+            .map(|msg| msg.get_size_bytes())
+            .sum::<IggyByteSize>();
+        let result = topic
+            .append_messages(batch_size, Partitioning::partition_id(1), messages, ack)
+            .await;
+        assert!(matches!(result, Err(IggyError::PartitionFailed(1, 1, 1))));
+    }
+}
+
+#[tokio::test]
+async fn should_acknowledge_messages_once_in_sync_replicas_have_replicated_them() {
+    const REPLICA_ID: u32 = 2;
+    let mut config = SystemConfig::default();
+    config.cluster.followers = vec![REPLICA_ID];
+    let setup = TestSetup::init_with_config(config).await;
+    let stream_id = 1;
+    let topic_id = 1;
+    setup.create_topics_directory(stream_id).await;
+    let topic = Arc::new(
+        Topic::create(
+            stream_id,
+            topic_id,
+            "test",
+            1,
+            setup.config.clone(),
+            setup.storage.clone(),
+            setup.appends_broadcaster.clone(),
+            setup.events_bus.clone(),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyExpiry::NeverExpire,
+            CompressionAlgorithm::default(),
+            MaxTopicSize::ServerDefault,
+            1,
+            CleanupPolicy::Delete,
+            Default::default(),
+        )
+        .unwrap(),
+    );
+    topic.persist().await.unwrap();
+    let messages = create_messages();
+    let messages_count = messages.len() as u64;
+    let batch_size = messages
+        .iter()
+        .map(|msg| msg.get_size_bytes())
+        .sum::<IggyByteSize>();
+
+    // Without any in-sync replicas, the messages are acknowledged right after the fsync
+    topic
+        .append_messages(
+            batch_size,
+            Partitioning::partition_id(1),
+            messages,
+            AckLevel::All,
+        )
+        .await
+        .unwrap();
+    topic
+        .fetch_replica_messages(1, REPLICA_ID, messages_count, 0, 10, false)
+        .await
+        .unwrap();
+
+    // Once the replica is in sync, the messages are acknowledged only after it has fetched them
+    let append = tokio::spawn({
+        let topic = topic.clone();
+        async move {
+            let messages = create_messages();
+            let batch_size = messages
+                .iter()
+                .map(|msg| msg.get_size_bytes())
+                .sum::<IggyByteSize>();
+            topic
+                .append_messages(
+                    batch_size,
+                    Partitioning::partition_id(1),
+                    messages,
+                    AckLevel::All,
+                )
+                .await
+        }
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!append.is_finished());
+
+    topic
+        .fetch_replica_messages(1, REPLICA_ID, messages_count, 0, 10, false)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!append.is_finished());
+
+    topic
+        .fetch_replica_messages(1, REPLICA_ID, 2 * messages_count, 0, 10, false)
+        .await
+        .unwrap();
+    tokio::time::timeout(Duration::from_secs(5), append)
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+}
+
 async fn assert_persisted_topic(topic_path: &str, partitions_path: &str, partitions_count: u32) {
     let topic_metadata = fs::metadata(topic_path).await.unwrap();
     assert!(topic_metadata.is_dir());
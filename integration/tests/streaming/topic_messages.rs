@@ -2,8 +2,9 @@ use crate::streaming::common::test_setup::TestSetup;
 use bytes::Bytes;
 use iggy::locking::IggySharedMutFn;
 use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::topic_size::MaxTopicSize;
@@ -84,7 +85,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
         .map(|m| m.get_size_bytes())
         .sum::<IggyByteSize>();
     topic
-        .append_messages(batch_size, partitioning, messages)
+        .append_messages(batch_size, partitioning, messages, AckLevel::Leader)
         .await
         .unwrap();
 
@@ -95,6 +96,7 @@ async fn assert_polling_messages(cache: CacheConfig, expect_enabled_cache: bool)
             partition_id,
             PollingStrategy::offset(0),
             messages_count,
+            0,
         )
         .await
         .unwrap();
@@ -129,6 +131,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
                 batch_size,
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
+                AckLevel::Leader,
             )
             .await
             .unwrap();
@@ -154,6 +157,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
                 batch_size,
                 partitioning.clone(),
                 vec![get_message(i as u128, &payload)],
+                AckLevel::Leader,
             )
             .await
             .unwrap();
@@ -183,6 +187,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
                 batch_size,
                 partitioning,
                 vec![get_message(entity_id as u128, &payload)],
+                AckLevel::Leader,
             )
             .await
             .unwrap();
@@ -215,7 +220,7 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000, 0)
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
@@ -233,6 +238,8 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
         partitions_count,
         setup.config.clone(),
         setup.storage.clone(),
+        setup.appends_broadcaster.clone(),
+        setup.events_bus.clone(),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU64::new(0)),
         Arc::new(AtomicU32::new(0)),
@@ -240,6 +247,8 @@ async fn init_topic(setup: &TestSetup, partitions_count: u32) -> Topic {
         Default::default(),
         MaxTopicSize::ServerDefault,
         1,
+        CleanupPolicy::Delete,
+        Default::default(),
     )
     .unwrap();
     topic.persist().await.unwrap();
@@ -155,7 +155,7 @@ pub struct ArgsOptional {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quic_receive_window: Option<u64>,
 
-    /// The optional response buffer size for QUIC
+    /// The optional size of the single chunk read when receiving the QUIC response
     ///
     /// [default: 1048576]
     #[arg(long)]
@@ -230,6 +230,12 @@ pub struct Args {
     /// The optional CA file for the TCP transport
     pub tcp_tls_ca_file: Option<String>,
 
+    /// The optional client certificate file for the TCP transport with the mutual TLS
+    pub tcp_tls_client_cert_file: Option<String>,
+
+    /// The optional client certificate private key file for the TCP transport with the mutual TLS
+    pub tcp_tls_client_key_file: Option<String>,
+
     /// The optional client address for the QUIC transport
     pub quic_client_address: String,
 
@@ -320,6 +326,8 @@ impl Default for Args {
             tcp_tls_enabled: false,
             tcp_tls_domain: "localhost".to_string(),
             tcp_tls_ca_file: None,
+            tcp_tls_client_cert_file: None,
+            tcp_tls_client_key_file: None,
             quic_client_address: "127.0.0.1:0".to_string(),
             quic_server_address: "127.0.0.1:8080".to_string(),
             quic_server_name: "localhost".to_string(),
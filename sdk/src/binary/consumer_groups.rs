@@ -5,11 +5,13 @@ use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerGroupClient for B {
@@ -55,6 +57,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -63,6 +66,7 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
                 topic_id: topic_id.clone(),
                 name: name.to_string(),
                 group_id,
+                rebalance_strategy,
             })
             .await?;
         mapper::map_consumer_group(response)
@@ -89,12 +93,14 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         stream_id: &Identifier,
         topic_id: &Identifier,
         group_id: &Identifier,
+        group_instance_id: Option<&str>,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&JoinConsumerGroup {
             stream_id: stream_id.clone(),
             topic_id: topic_id.clone(),
             group_id: group_id.clone(),
+            group_instance_id: group_instance_id.map(|id| id.to_string()),
         })
         .await?;
         Ok(())
@@ -115,4 +121,21 @@ impl<B: BinaryClient> ConsumerGroupClient for B {
         .await?;
         Ok(())
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<ConsumerGroupMember, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&HeartbeatConsumerGroup {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                group_id: group_id.clone(),
+            })
+            .await?;
+        mapper::map_consumer_group_member(response)
+    }
 }
@@ -2,11 +2,19 @@ use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::ConsumerOffsetClient;
 use crate::consumer::Consumer;
+use crate::consumer_offsets::delete_consumer::DeleteConsumer;
+use crate::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::get_consumers::GetConsumers;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::consumer_info::ConsumerInfo;
+use crate::models::consumer_lag_info::ConsumerLagInfo;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> ConsumerOffsetClient for B {
@@ -52,4 +60,99 @@ impl<B: BinaryClient> ConsumerOffsetClient for B {
 
         mapper::map_consumer_offset(response).map(Some)
     }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<ConsumerLagInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetConsumerLag {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        mapper::map_consumer_lags(response)
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions: &[u32],
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&ResetConsumerOffsets {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partitions: partitions.to_vec(),
+                strategy: *strategy,
+                dry_run,
+            })
+            .await?;
+        mapper::map_consumer_offset_resets(response)
+    }
+
+    async fn get_consumers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Vec<ConsumerInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetConsumers {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        mapper::map_consumers(response)
+    }
+
+    async fn delete_consumer(
+        &self,
+        consumer_id: &Identifier,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteConsumer {
+            consumer_id: consumer_id.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&DeleteConsumerOffsets {
+            consumer: consumer.clone(),
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            partition_id,
+        })
+        .await?;
+        Ok(())
+    }
 }
@@ -1,30 +1,54 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::command::get_name_from_code;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
+use crate::models::cluster_metadata::{BrokerInfo, ClusterMetadata, PartitionLeadership};
 use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_info::ConsumerInfo;
+use crate::models::consumer_lag_info::ConsumerLagInfo;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
+use crate::models::consumer_offsets_storage_info::ConsumerOffsetsStorageInfo;
+use crate::models::copied_messages_info::CopiedMessagesInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::{MessageState, PolledMessage, PolledMessages};
-use crate::models::partition::Partition;
+use crate::models::messages_histogram::{MessagesHistogram, MessagesHistogramBucket};
+use crate::models::node_status::{NodeState, NodeStatus};
+use crate::models::partition::{Partition, PartitionDetails};
+use crate::models::partition_offsets_info::PartitionOffsetsInfo;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
-use crate::models::stats::Stats;
+use crate::models::replica_info::ReplicaInfo;
+use crate::models::replica_messages::{EpochEndOffset, ReplicaMessages};
+use crate::models::schema::Schema;
+use crate::models::sealed_partition_info::SealedPartitionInfo;
+use crate::models::segment_info::SegmentInfo;
+use crate::models::session_info::SessionInfo;
+use crate::models::stats::{CommandLatency, Stats};
 use crate::models::stream::{Stream, StreamDetails};
-use crate::models::topic::{Topic, TopicDetails};
+use crate::models::timestamp_offset_info::TimestampOffsetInfo;
+use crate::models::topic::{Topic, TopicConfigEntry, TopicConfigSource, TopicDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::cleanup_policy::CleanupPolicy;
+use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
+use crate::utils::schema_type::SchemaType;
+use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use bytes::Bytes;
 use std::collections::HashMap;
 use std::str::from_utf8;
+use std::time::Duration;
 
 const EMPTY_MESSAGES: Vec<PolledMessage> = vec![];
 const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
+const EMPTY_SESSIONS: Vec<SessionInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
 const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
@@ -71,6 +95,58 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
     let kernel_version =
         from_utf8(&payload[current_position + 4..current_position + 4 + kernel_version_length])?
             .to_string();
+    current_position += 4 + kernel_version_length;
+
+    // The command latencies are not sent by the older servers.
+    let mut command_latencies = Vec::new();
+    if current_position < payload.len() {
+        let latencies_count =
+            u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+        current_position += 4;
+        for _ in 0..latencies_count {
+            let code =
+                u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+            let count = u64::from_le_bytes(
+                payload[current_position + 4..current_position + 12].try_into()?,
+            );
+            let p50 = u64::from_le_bytes(
+                payload[current_position + 12..current_position + 20].try_into()?,
+            );
+            let p95 = u64::from_le_bytes(
+                payload[current_position + 20..current_position + 28].try_into()?,
+            );
+            let p99 = u64::from_le_bytes(
+                payload[current_position + 28..current_position + 36].try_into()?,
+            );
+            command_latencies.push(CommandLatency {
+                code,
+                name: get_name_from_code(code).unwrap_or("unknown").to_string(),
+                count,
+                p50: p50.into(),
+                p95: p95.into(),
+                p99: p99.into(),
+            });
+            current_position += 36;
+        }
+    }
+
+    // The progress of the topic deletions is not sent by the older servers.
+    let mut deleting_topics_count = 0;
+    let mut deleting_topics_size_bytes = 0;
+    if current_position < payload.len() {
+        deleting_topics_count =
+            u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+        deleting_topics_size_bytes =
+            u64::from_le_bytes(payload[current_position + 4..current_position + 12].try_into()?);
+        current_position += 12;
+    }
+
+    // The failed partitions are not reported by the older servers.
+    let mut failed_partitions_count = 0;
+    if current_position < payload.len() {
+        failed_partitions_count =
+            u32::from_le_bytes(payload[current_position..current_position + 4].try_into()?);
+    }
 
     Ok(Stats {
         process_id,
@@ -95,6 +171,10 @@ pub fn map_stats(payload: Bytes) -> Result<Stats, IggyError> {
         os_name,
         os_version,
         kernel_version,
+        command_latencies,
+        deleting_topics_count,
+        deleting_topics_size_bytes: deleting_topics_size_bytes.into(),
+        failed_partitions_count,
     })
 }
 
@@ -109,6 +189,193 @@ pub fn map_consumer_offset(payload: Bytes) -> Result<ConsumerOffsetInfo, IggyErr
     })
 }
 
+pub fn map_consumer_lags(payload: Bytes) -> Result<Vec<ConsumerLagInfo>, IggyError> {
+    let mut lags = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let current_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let has_stored_offset = payload[position + 12] == 1;
+        let stored_offset = u64::from_le_bytes(payload[position + 13..position + 21].try_into()?);
+        let lag = u64::from_le_bytes(payload[position + 21..position + 29].try_into()?);
+        lags.push(ConsumerLagInfo {
+            partition_id,
+            current_offset,
+            stored_offset: if has_stored_offset {
+                Some(stored_offset)
+            } else {
+                None
+            },
+            lag,
+        });
+        position += 29;
+    }
+    Ok(lags)
+}
+
+pub fn map_consumers(payload: Bytes) -> Result<Vec<ConsumerInfo>, IggyError> {
+    let mut consumers = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let stored_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let lag = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+        let name_length = payload[position + 20] as usize;
+        let name = if name_length > 0 {
+            Some(from_utf8(&payload[position + 21..position + 21 + name_length])?.to_string())
+        } else {
+            None
+        };
+        consumers.push(ConsumerInfo {
+            id,
+            name,
+            stored_offset,
+            lag,
+        });
+        position += 21 + name_length;
+    }
+    Ok(consumers)
+}
+
+pub fn map_partition_offsets(payload: Bytes) -> Result<Vec<PartitionOffsetsInfo>, IggyError> {
+    let mut offsets = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let low_watermark = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let high_watermark = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+        offsets.push(PartitionOffsetsInfo {
+            partition_id,
+            low_watermark,
+            high_watermark,
+        });
+        position += 20;
+    }
+    Ok(offsets)
+}
+
+pub fn map_timestamp_offset(payload: Bytes) -> Result<TimestampOffsetInfo, IggyError> {
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let offset = u64::from_le_bytes(payload[4..12].try_into()?);
+    let timestamp = u64::from_le_bytes(payload[12..20].try_into()?);
+    Ok(TimestampOffsetInfo {
+        partition_id,
+        offset,
+        timestamp,
+    })
+}
+
+pub fn map_sealed_partition(payload: Bytes) -> Result<SealedPartitionInfo, IggyError> {
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let high_watermark = u64::from_le_bytes(payload[4..12].try_into()?);
+    let segments_count = u32::from_le_bytes(payload[12..16].try_into()?);
+    Ok(SealedPartitionInfo {
+        partition_id,
+        high_watermark,
+        segments_count,
+    })
+}
+
+pub fn map_copied_messages(payload: Bytes) -> Result<CopiedMessagesInfo, IggyError> {
+    let messages_count = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(CopiedMessagesInfo { messages_count })
+}
+
+pub fn map_messages_histogram(payload: Bytes) -> Result<MessagesHistogram, IggyError> {
+    let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let bucket_size = u64::from_le_bytes(payload[4..12].try_into()?);
+    let length = payload.len();
+    let mut position = 12;
+    let mut buckets = Vec::new();
+    while position < length {
+        let timestamp = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        let messages_count = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+        let size = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?);
+        buckets.push(MessagesHistogramBucket {
+            timestamp,
+            messages_count,
+            size: IggyByteSize::from(size),
+        });
+        position += 24;
+    }
+    Ok(MessagesHistogram {
+        partition_id,
+        bucket_size: IggyDuration::new(Duration::from_micros(bucket_size)),
+        buckets,
+    })
+}
+
+pub fn map_schema(payload: Bytes) -> Result<Schema, IggyError> {
+    let (schema, _) = map_to_schema(payload, 0)?;
+    Ok(schema)
+}
+
+pub fn map_schemas(payload: Bytes) -> Result<Vec<Schema>, IggyError> {
+    let mut schemas = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (schema, read_bytes) = map_to_schema(payload.clone(), position)?;
+        schemas.push(schema);
+        position += read_bytes;
+    }
+    Ok(schemas)
+}
+
+fn map_to_schema(payload: Bytes, position: usize) -> Result<(Schema, usize), IggyError> {
+    let version = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let schema_type = SchemaType::from_code(payload[position + 4])?;
+    let validate = payload[position + 5] == 1;
+    let created_at = u64::from_le_bytes(payload[position + 6..position + 14].try_into()?);
+    let created_at = created_at.into();
+    let definition_length =
+        u32::from_le_bytes(payload[position + 14..position + 18].try_into()?) as usize;
+    let definition =
+        from_utf8(&payload[position + 18..position + 18 + definition_length])?.to_string();
+    let read_bytes = 18 + definition_length;
+    Ok((
+        Schema {
+            version,
+            schema_type,
+            validate,
+            created_at,
+            definition,
+        },
+        read_bytes,
+    ))
+}
+
+pub fn map_consumer_offset_resets(
+    payload: Bytes,
+) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError> {
+    let mut resets = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let current_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let previous_offset = map_optional_offset(&payload, position + 12)?;
+        let stored_offset = map_optional_offset(&payload, position + 21)?;
+        resets.push(ConsumerOffsetResetInfo {
+            partition_id,
+            current_offset,
+            previous_offset,
+            stored_offset,
+        });
+        position += 30;
+    }
+    Ok(resets)
+}
+
+fn map_optional_offset(payload: &[u8], position: usize) -> Result<Option<u64>, IggyError> {
+    let has_offset = payload[position] == 1;
+    let offset = u64::from_le_bytes(payload[position + 1..position + 9].try_into()?);
+    Ok(if has_offset { Some(offset) } else { None })
+}
+
 pub fn map_user(payload: Bytes) -> Result<UserInfoDetails, IggyError> {
     let (user, position) = map_to_user_info(payload.clone(), 0)?;
     let has_permissions = payload[position];
@@ -175,6 +442,16 @@ pub fn map_identity_info(payload: Bytes) -> Result<IdentityInfo, IggyError> {
     })
 }
 
+pub fn map_producer_epoch(payload: Bytes) -> Result<u32, IggyError> {
+    let epoch = u32::from_le_bytes(payload[..4].try_into()?);
+    Ok(epoch)
+}
+
+pub fn map_key_version(payload: Bytes) -> Result<u32, IggyError> {
+    let key_version = u32::from_le_bytes(payload[..4].try_into()?);
+    Ok(key_version)
+}
+
 pub fn map_raw_pat(payload: Bytes) -> Result<RawPersonalAccessToken, IggyError> {
     let token_length = payload[0];
     let token = from_utf8(&payload[1..1 + token_length as usize])?.to_string();
@@ -229,12 +506,31 @@ pub fn map_clients(payload: Bytes) -> Result<Vec<ClientInfo>, IggyError> {
     Ok(clients)
 }
 
+pub fn map_sessions(payload: Bytes) -> Result<Vec<SessionInfo>, IggyError> {
+    if payload.is_empty() {
+        return Ok(EMPTY_SESSIONS);
+    }
+
+    let mut sessions = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (session, read_bytes) = map_to_session_info(payload.clone(), position)?;
+        sessions.push(session);
+        position += read_bytes;
+    }
+    sessions.sort_by(|x, y| x.client_id.cmp(&y.client_id));
+    Ok(sessions)
+}
+
 pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError> {
     if payload.is_empty() {
         return Ok(PolledMessages {
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            epoch: 0,
+            next_offset: None,
         });
     }
 
@@ -243,7 +539,12 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
     let current_offset = u64::from_le_bytes(payload[4..12].try_into()?);
     // Currently ignored
     let _messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
-    let mut position = 16;
+    let epoch = u32::from_le_bytes(payload[16..20].try_into()?);
+    let next_offset = match payload[20] {
+        0 => None,
+        _ => Some(u64::from_le_bytes(payload[21..29].try_into()?)),
+    };
+    let mut position = 29;
     let mut messages = Vec::new();
     while position < length {
         let offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
@@ -289,10 +590,127 @@ pub fn map_polled_messages(payload: Bytes) -> Result<PolledMessages, IggyError>
     Ok(PolledMessages {
         partition_id,
         current_offset,
+        epoch,
+        next_offset,
+        messages,
+    })
+}
+
+pub fn map_replica_messages(payload: Bytes) -> Result<ReplicaMessages, IggyError> {
+    if payload.len() < 13 {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let diverging_epoch = match payload[0] {
+        0 => None,
+        _ => Some(EpochEndOffset {
+            epoch: u32::from_le_bytes(payload[1..5].try_into()?),
+            end_offset: u64::from_le_bytes(payload[5..13].try_into()?),
+        }),
+    };
+    let messages = map_polled_messages(payload.slice(13..))?;
+    Ok(ReplicaMessages {
+        diverging_epoch,
         messages,
     })
 }
 
+pub fn map_cluster_metadata(payload: Bytes) -> Result<ClusterMetadata, IggyError> {
+    let node_id = u32::from_le_bytes(payload[0..4].try_into()?);
+    let brokers_count = u32::from_le_bytes(payload[4..8].try_into()?);
+    let mut position = 8;
+    let mut brokers = Vec::with_capacity(brokers_count as usize);
+    for _ in 0..brokers_count {
+        let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let (name, read_bytes) = map_to_short_string(&payload, position + 4)?;
+        position += 4 + read_bytes;
+        let (address, read_bytes) = map_to_short_string(&payload, position)?;
+        position += read_bytes;
+        let (zone, read_bytes) = map_to_short_string(&payload, position)?;
+        position += read_bytes;
+        brokers.push(BrokerInfo {
+            id,
+            name,
+            address,
+            zone,
+        });
+    }
+
+    let partitions_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let mut partitions = Vec::with_capacity(partitions_count as usize);
+    for _ in 0..partitions_count {
+        let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let (stream_name, read_bytes) = map_to_short_string(&payload, position + 4)?;
+        position += 4 + read_bytes;
+        let topic_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let (topic_name, read_bytes) = map_to_short_string(&payload, position + 4)?;
+        position += 4 + read_bytes;
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let leader_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let leader_epoch = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+        position += 12;
+        partitions.push(PartitionLeadership {
+            stream_id,
+            stream_name,
+            topic_id,
+            topic_name,
+            partition_id,
+            leader_id,
+            leader_epoch,
+        });
+    }
+
+    Ok(ClusterMetadata {
+        node_id,
+        brokers,
+        partitions,
+    })
+}
+
+pub fn map_node_status(payload: Bytes) -> Result<NodeStatus, IggyError> {
+    let node_id = u32::from_le_bytes(payload[0..4].try_into()?);
+    let state = NodeState::from_code(payload[4])?;
+    let partitions_count = u32::from_le_bytes(payload[5..9].try_into()?);
+    let led_partitions_count = u32::from_le_bytes(payload[9..13].try_into()?);
+    Ok(NodeStatus {
+        node_id,
+        state,
+        partitions_count,
+        led_partitions_count,
+    })
+}
+
+fn map_to_short_string(payload: &[u8], position: usize) -> Result<(String, usize), IggyError> {
+    let length = payload[position] as usize;
+    let value = from_utf8(&payload[position + 1..position + 1 + length])?.to_string();
+    Ok((value, 1 + length))
+}
+
+pub fn map_polled_messages_from_partitions(
+    payload: Bytes,
+) -> Result<Vec<PolledMessages>, IggyError> {
+    if payload.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let partitions_count = u32::from_le_bytes(payload[..4].try_into()?);
+    let mut batches = Vec::with_capacity(partitions_count as usize);
+    let mut position = 4;
+    for _ in 0..partitions_count {
+        let length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        position += 4;
+        if position + length > payload.len() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let polled_messages = map_polled_messages(payload.slice(position..position + length))?;
+        batches.push(polled_messages);
+        position += length;
+    }
+    Ok(batches)
+}
+
 pub fn map_streams(payload: Bytes) -> Result<Vec<Stream>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_STREAMS);
@@ -375,6 +793,8 @@ pub fn map_topics(payload: Bytes) -> Result<Vec<Topic>, IggyError> {
 
 pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
     let (topic, mut position) = map_to_topic(payload.clone(), 0)?;
+    let (config, read_bytes) = map_to_topic_config_entries(payload.clone(), position)?;
+    position += read_bytes;
     let mut partitions = Vec::new();
     let length = payload.len();
     while position < length {
@@ -394,13 +814,46 @@ pub fn map_topic(payload: Bytes) -> Result<TopicDetails, IggyError> {
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        cleanup_policy: topic.cleanup_policy,
+        read_only: topic.read_only,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
+        config,
         partitions,
     };
     Ok(topic)
 }
 
+fn map_to_topic_config_entries(
+    payload: Bytes,
+    position: usize,
+) -> Result<(Vec<TopicConfigEntry>, usize), IggyError> {
+    let entries_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let mut entries = Vec::with_capacity(entries_count as usize);
+    let mut read_bytes = 4;
+    for _ in 0..entries_count {
+        let name_length = payload[position + read_bytes] as usize;
+        read_bytes += 1;
+        let name = from_utf8(&payload[position + read_bytes..position + read_bytes + name_length])?
+            .to_string();
+        read_bytes += name_length;
+        let value_length = payload[position + read_bytes] as usize;
+        read_bytes += 1;
+        let value =
+            from_utf8(&payload[position + read_bytes..position + read_bytes + value_length])?
+                .to_string();
+        read_bytes += value_length;
+        let source = TopicConfigSource::from_code(payload[position + read_bytes])?;
+        read_bytes += 1;
+        entries.push(TopicConfigEntry {
+            name,
+            value,
+            source,
+        });
+    }
+    Ok((entries, read_bytes))
+}
+
 fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyError> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
@@ -415,14 +868,16 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
     let max_topic_size = u64::from_le_bytes(payload[position + 25..position + 33].try_into()?);
     let max_topic_size: MaxTopicSize = max_topic_size.into();
     let replication_factor = payload[position + 33];
+    let cleanup_policy = CleanupPolicy::from_code(payload[position + 34])?;
+    let read_only = payload[position + 35] == 1;
     let size_bytes = IggyByteSize::from(u64::from_le_bytes(
-        payload[position + 34..position + 42].try_into()?,
+        payload[position + 36..position + 44].try_into()?,
     ));
-    let messages_count = u64::from_le_bytes(payload[position + 42..position + 50].try_into()?);
-    let name_length = payload[position + 50];
+    let messages_count = u64::from_le_bytes(payload[position + 44..position + 52].try_into()?);
+    let name_length = payload[position + 52];
     let name =
-        from_utf8(&payload[position + 51..position + 51 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + name_length as usize;
+        from_utf8(&payload[position + 53..position + 53 + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 4 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 1 + name_length as usize;
     Ok((
         Topic {
             id,
@@ -435,6 +890,8 @@ fn map_to_topic(payload: Bytes, position: usize) -> Result<(Topic, usize), IggyE
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
+            read_only,
         },
         read_bytes,
     ))
@@ -462,6 +919,124 @@ fn map_to_partition(payload: Bytes, position: usize) -> Result<(Partition, usize
     ))
 }
 
+pub fn map_partition(payload: Bytes) -> Result<PartitionDetails, IggyError> {
+    let (partition, mut position) = map_to_partition(payload.clone(), 0)?;
+    let high_watermark = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+    position += 8;
+    let (consumer_offsets, read_bytes) =
+        map_to_consumer_offsets_storage_info(payload.clone(), position)?;
+    position += read_bytes;
+    let replicas_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let mut replicas = Vec::with_capacity(replicas_count as usize);
+    for _ in 0..replicas_count {
+        let (replica, read_bytes) = map_to_replica_info(payload.clone(), position)?;
+        replicas.push(replica);
+        position += read_bytes;
+    }
+    let mut segments = Vec::new();
+    let length = payload.len();
+    while position < length {
+        let (segment, read_bytes) = map_to_segment_info(payload.clone(), position)?;
+        segments.push(segment);
+        position += read_bytes;
+    }
+
+    segments.sort_by_key(|segment| segment.start_offset);
+    let partition = PartitionDetails {
+        id: partition.id,
+        created_at: partition.created_at,
+        segments_count: partition.segments_count,
+        current_offset: partition.current_offset,
+        size: partition.size,
+        messages_count: partition.messages_count,
+        high_watermark,
+        consumer_offsets,
+        replicas,
+        segments,
+    };
+    Ok(partition)
+}
+
+fn map_to_replica_info(payload: Bytes, position: usize) -> Result<(ReplicaInfo, usize), IggyError> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let fetched_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let last_fetched_at = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+    let last_caught_up_at = u64::from_le_bytes(payload[position + 20..position + 28].try_into()?);
+    let last_caught_up_at = if last_caught_up_at == 0 {
+        None
+    } else {
+        Some(last_caught_up_at.into())
+    };
+    let is_in_sync = payload[position + 28] == 1;
+    let read_bytes = 4 + 8 + 8 + 8 + 1;
+    Ok((
+        ReplicaInfo {
+            id,
+            fetched_offset,
+            last_fetched_at: last_fetched_at.into(),
+            last_caught_up_at,
+            is_in_sync,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_consumer_offsets_storage_info(
+    payload: Bytes,
+    position: usize,
+) -> Result<(ConsumerOffsetsStorageInfo, usize), IggyError> {
+    let consumers_count = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let consumer_groups_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let records_count = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+    let size = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?).into();
+    let compactions_count = u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
+    let last_compacted_at = u64::from_le_bytes(payload[position + 32..position + 40].try_into()?);
+    let last_compacted_at = if last_compacted_at == 0 {
+        None
+    } else {
+        Some(last_compacted_at.into())
+    };
+    let read_bytes = 4 + 4 + 8 + 8 + 8 + 8;
+    Ok((
+        ConsumerOffsetsStorageInfo {
+            consumers_count,
+            consumer_groups_count,
+            records_count,
+            size,
+            compactions_count,
+            last_compacted_at,
+        },
+        read_bytes,
+    ))
+}
+
+fn map_to_segment_info(payload: Bytes, position: usize) -> Result<(SegmentInfo, usize), IggyError> {
+    let start_offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+    let end_offset = u64::from_le_bytes(payload[position + 8..position + 16].try_into()?);
+    let size = u64::from_le_bytes(payload[position + 16..position + 24].try_into()?).into();
+    let messages_count = u64::from_le_bytes(payload[position + 24..position + 32].try_into()?);
+    let is_closed = payload[position + 32] == 1;
+    let created_at = u64::from_le_bytes(payload[position + 33..position + 41].try_into()?);
+    let created_at = created_at.into();
+    let index_size = u64::from_le_bytes(payload[position + 41..position + 49].try_into()?).into();
+    let indexes_count = u32::from_le_bytes(payload[position + 49..position + 53].try_into()?);
+    let read_bytes = 8 + 8 + 8 + 8 + 1 + 8 + 8 + 4;
+    Ok((
+        SegmentInfo {
+            start_offset,
+            end_offset,
+            size,
+            messages_count,
+            is_closed,
+            created_at,
+            index_size,
+            indexes_count,
+        },
+        read_bytes,
+    ))
+}
+
 pub fn map_consumer_groups(payload: Bytes) -> Result<Vec<ConsumerGroup>, IggyError> {
     if payload.is_empty() {
         return Ok(EMPTY_CONSUMER_GROUPS);
@@ -494,6 +1069,7 @@ pub fn map_consumer_group(payload: Bytes) -> Result<ConsumerGroupDetails, IggyEr
         name: consumer_group.name,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
+        rebalance_strategy: consumer_group.rebalance_strategy,
         members,
     };
     Ok(consumer_group_details)
@@ -506,21 +1082,28 @@ fn map_to_consumer_group(
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let partitions_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
     let members_count = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
-    let name_length = payload[position + 12];
+    let rebalance_strategy = RebalanceStrategy::from_code(payload[position + 12])?;
+    let name_length = payload[position + 13];
     let name =
-        from_utf8(&payload[position + 13..position + 13 + name_length as usize])?.to_string();
-    let read_bytes = 13 + name_length as usize;
+        from_utf8(&payload[position + 14..position + 14 + name_length as usize])?.to_string();
+    let read_bytes = 14 + name_length as usize;
     Ok((
         ConsumerGroup {
             id,
             partitions_count,
             members_count,
+            rebalance_strategy,
             name,
         },
         read_bytes,
     ))
 }
 
+pub fn map_consumer_group_member(payload: Bytes) -> Result<ConsumerGroupMember, IggyError> {
+    let (member, _) = map_to_consumer_group_member(payload, 0)?;
+    Ok(member)
+}
+
 fn map_to_consumer_group_member(
     payload: Bytes,
     position: usize,
@@ -547,6 +1130,56 @@ fn map_to_consumer_group_member(
     ))
 }
 
+fn map_to_session_info(
+    payload: Bytes,
+    mut position: usize,
+) -> Result<(SessionInfo, usize), IggyError> {
+    let mut read_bytes;
+    let client_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let user_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let user_id = match user_id {
+        0 => None,
+        _ => Some(user_id),
+    };
+
+    let transport = payload[position + 8];
+    let transport = match transport {
+        1 => "TCP",
+        2 => "QUIC",
+        _ => "Unknown",
+    }
+    .to_string();
+
+    let address_length =
+        u32::from_le_bytes(payload[position + 9..position + 13].try_into()?) as usize;
+    let address = from_utf8(&payload[position + 13..position + 13 + address_length])?.to_string();
+    read_bytes = 4 + 4 + 1 + 4 + address_length;
+    position += read_bytes;
+    let connected_at = u64::from_le_bytes(payload[position..position + 8].try_into()?).into();
+    let last_command_code = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+    let last_command_at = u64::from_le_bytes(payload[position + 12..position + 20].try_into()?);
+    let (last_command, last_command_at) = match last_command_code {
+        0 => (None, None),
+        code => (
+            Some(get_name_from_code(code).unwrap_or("unknown").to_string()),
+            Some(IggyTimestamp::from(last_command_at)),
+        ),
+    };
+    read_bytes += 20;
+    Ok((
+        SessionInfo {
+            client_id,
+            user_id,
+            address,
+            transport,
+            connected_at,
+            last_command,
+            last_command_at,
+        },
+        read_bytes,
+    ))
+}
+
 fn map_to_client_info(
     payload: Bytes,
     mut position: usize,
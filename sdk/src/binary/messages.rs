@@ -1,15 +1,24 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::MessageClient;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{POLL_MESSAGES_CODE, POLL_MESSAGES_FROM_PARTITIONS_CODE, SEND_MESSAGES_CODE};
 use crate::consumer::Consumer;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::messages::acquire_exclusive_producer::AcquireExclusiveProducer;
+use crate::messages::copy_messages::{CopyMessages, CopyRangeKind};
+use crate::messages::fetch_replica_messages::FetchReplicaMessages;
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::PollingStrategy;
-use crate::messages::send_messages::{Message, Partitioning};
-use crate::messages::{poll_messages, send_messages};
+use crate::messages::get_message_by_key::GetMessageByKey;
+use crate::messages::init_producer_epoch::InitProducerEpoch;
+use crate::messages::peek_messages::{PeekKind, PeekMessages};
+use crate::messages::poll_messages::{OutOfRangePolicy, PollingStrategy};
+use crate::messages::poll_messages_from_partitions::PartitionOffset;
+use crate::messages::send_messages::{AckLevel, Message, Partitioning};
+use crate::messages::{poll_messages, poll_messages_from_partitions, send_messages};
+use crate::models::copied_messages_info::CopiedMessagesInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::replica_messages::ReplicaMessages;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> MessageClient for B {
@@ -22,6 +31,30 @@ impl<B: BinaryClient> MessageClient for B {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_max_bytes(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            0,
+            auto_commit,
+        )
+        .await
+    }
+
+    async fn poll_messages_with_max_bytes(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        max_bytes: u32,
+        auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -34,29 +67,201 @@ impl<B: BinaryClient> MessageClient for B {
                     consumer,
                     strategy,
                     count,
+                    max_bytes,
+                    auto_commit,
+                ),
+            )
+            .await?;
+        mapper::map_polled_messages(response)
+    }
+
+    async fn poll_messages_from_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        partitions: &[PartitionOffset],
+        out_of_range: OutOfRangePolicy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<PolledMessages>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_raw_with_response(
+                POLL_MESSAGES_FROM_PARTITIONS_CODE,
+                poll_messages_from_partitions::as_bytes(
+                    stream_id,
+                    topic_id,
+                    consumer,
+                    partitions,
+                    out_of_range,
+                    count,
                     auto_commit,
                 ),
             )
             .await?;
+        mapper::map_polled_messages_from_partitions(response)
+    }
+
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&PeekMessages {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                kind,
+                count,
+            })
+            .await?;
+        mapper::map_polled_messages(response)
+    }
+
+    async fn get_message_by_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        key: u128,
+    ) -> Result<PolledMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetMessageByKey {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                key,
+            })
+            .await?;
         mapper::map_polled_messages(response)
     }
 
+    async fn fetch_replica_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        epoch: u32,
+        count: u32,
+    ) -> Result<ReplicaMessages, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&FetchReplicaMessages {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                replica_id,
+                offset,
+                epoch,
+                count,
+            })
+            .await?;
+        mapper::map_replica_messages(response)
+    }
+
+    async fn copy_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        target_stream_id: &Identifier,
+        target_topic_id: &Identifier,
+        target_partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Result<CopiedMessagesInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&CopyMessages {
+                source_stream_id: source_stream_id.clone(),
+                source_topic_id: source_topic_id.clone(),
+                source_partition_id,
+                target_stream_id: target_stream_id.clone(),
+                target_topic_id: target_topic_id.clone(),
+                target_partition_id,
+                kind,
+                from,
+                to,
+            })
+            .await?;
+        mapper::map_copied_messages(response)
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_ack(
+            stream_id,
+            topic_id,
+            partitioning,
+            AckLevel::default(),
+            messages,
+        )
+        .await
+    }
+
+    async fn send_messages_with_ack(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        ack: AckLevel,
+        messages: &mut [Message],
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_raw_with_response(
             SEND_MESSAGES_CODE,
-            send_messages::as_bytes(stream_id, topic_id, partitioning, messages),
+            send_messages::as_bytes(stream_id, topic_id, partitioning, ack, messages),
         )
         .await?;
         Ok(())
     }
 
+    async fn acquire_exclusive_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&AcquireExclusiveProducer {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn init_producer_epoch(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        epoch: u32,
+    ) -> Result<u32, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&InitProducerEpoch {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                epoch,
+            })
+            .await?;
+        mapper::map_producer_epoch(response)
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -12,13 +12,16 @@ pub mod binary_client;
 pub mod consumer_groups;
 #[allow(deprecated)]
 pub mod consumer_offsets;
-mod mapper;
+pub mod mapper;
 #[allow(deprecated)]
 pub mod messages;
 #[allow(deprecated)]
 pub mod partitions;
 #[allow(deprecated)]
 pub mod personal_access_tokens;
+pub mod retry_policy;
+#[allow(deprecated)]
+pub mod schemas;
 #[allow(deprecated)]
 pub mod streams;
 #[allow(deprecated)]
@@ -28,6 +31,14 @@ pub mod topics;
 #[allow(deprecated)]
 pub mod users;
 
+/// The length set in the OK response streamed as multiple chunks instead of the single payload.
+/// Each chunk is prefixed with its length (u32), and the response is terminated by the empty chunk.
+pub const CHUNKED_RESPONSE_LENGTH: u32 = u32::MAX;
+
+/// The flag set in the command code of the request, which frame is followed by its checksum (u32, CRC32).
+/// Each frame of the response to such request is followed by its checksum as well.
+pub const CHECKSUM_FLAG: u32 = 1 << 31;
+
 /// The state of the client.
 #[derive(Debug, Copy, Clone, PartialEq, Display)]
 pub enum ClientState {
@@ -1,11 +1,22 @@
 #[allow(deprecated)]
 use crate::binary::binary_client::BinaryClient;
-use crate::binary::fail_if_not_authenticated;
+use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::PartitionClient;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::models::messages_histogram::MessagesHistogram;
+use crate::models::partition::PartitionDetails;
+use crate::models::partition_offsets_info::PartitionOffsetsInfo;
+use crate::models::sealed_partition_info::SealedPartitionInfo;
+use crate::models::timestamp_offset_info::TimestampOffsetInfo;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_messages_histogram::GetMessagesHistogram;
+use crate::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use crate::partitions::get_partition::GetPartition;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
+use crate::partitions::seal_partition::SealPartition;
+use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
 impl<B: BinaryClient> PartitionClient for B {
@@ -40,4 +51,107 @@ impl<B: BinaryClient> PartitionClient for B {
         .await?;
         Ok(())
     }
+
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Option<PartitionDetails>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetPartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_partition(response).map(Some)
+    }
+
+    async fn get_partition_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionOffsetsInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetPartitionOffsets {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            })
+            .await?;
+        mapper::map_partition_offsets(response)
+    }
+
+    async fn get_offset_by_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        timestamp: u64,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetOffsetByTimestamp {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                timestamp,
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_timestamp_offset(response).map(Some)
+    }
+
+    async fn get_messages_histogram(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetMessagesHistogram {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                from,
+                to,
+                bucket_size,
+            })
+            .await?;
+        mapper::map_messages_histogram(response)
+    }
+
+    async fn seal_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        read_only: bool,
+    ) -> Result<SealedPartitionInfo, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&SealPartition {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+                read_only,
+            })
+            .await?;
+        mapper::map_sealed_partition(response)
+    }
 }
@@ -0,0 +1,163 @@
+use crate::command::*;
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use std::str::FromStr;
+
+/// The policy of retrying the commands which failed due to the broken connection, after reconnecting to the server.
+///
+/// Only the safe commands, which can be executed more than once without changing the outcome (e.g. polling the messages
+/// or getting the metadata), are retried by default. Sending the messages is retried only if the idempotent producer
+/// is enabled, meaning that the messages have unique IDs and the server deduplicates them, as otherwise the messages
+/// appended before the connection was broken would be duplicated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to execute the command, including the first one. `1` disables the retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for each subsequent retry.
+    pub initial_backoff: IggyDuration,
+    /// The maximum delay between the retries.
+    pub max_backoff: IggyDuration,
+    /// Whether the messages can be sent more than once, so that the sending is retried as well.
+    pub idempotent_producer: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 2,
+            initial_backoff: IggyDuration::from_str("100ms").unwrap(),
+            max_backoff: IggyDuration::from_str("5s").unwrap(),
+            idempotent_producer: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether the command with the given code, which failed with the given error in the given attempt
+    /// (starting from 1), should be retried after reconnecting to the server.
+    pub fn should_retry(&self, code: u32, error: &IggyError, attempt: u32) -> bool {
+        attempt < self.max_attempts && is_retriable_error(error) && self.is_retriable_command(code)
+    }
+
+    /// Returns the delay before the retry following the given attempt (starting from 1).
+    pub fn backoff(&self, attempt: u32) -> IggyDuration {
+        let multiplier = 2u64.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .as_micros()
+            .saturating_mul(multiplier)
+            .min(self.max_backoff.as_micros());
+        IggyDuration::from(backoff)
+    }
+
+    /// Returns whether the command with the given code can be executed more than once.
+    pub fn is_retriable_command(&self, code: u32) -> bool {
+        match code {
+            SEND_MESSAGES_CODE => self.idempotent_producer,
+            PING_CODE
+            | GET_STATS_CODE
+            | GET_CLUSTER_METADATA_CODE
+            | GET_NODE_STATUS_CODE
+            | GET_ME_CODE
+            | GET_CLIENT_CODE
+            | GET_CLIENTS_CODE
+            | GET_SESSIONS_CODE
+            | GET_USER_CODE
+            | GET_USERS_CODE
+            | LOGIN_USER_CODE
+            | GET_PERSONAL_ACCESS_TOKENS_CODE
+            | LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE
+            | POLL_MESSAGES_CODE
+            | FLUSH_UNSAVED_BUFFER_CODE
+            | POLL_MESSAGES_FROM_PARTITIONS_CODE
+            | PEEK_MESSAGES_CODE
+            | GET_CONSUMER_OFFSET_CODE
+            | STORE_CONSUMER_OFFSET_CODE
+            | GET_CONSUMER_LAG_CODE
+            | GET_STREAM_CODE
+            | GET_STREAMS_CODE
+            | GET_TOPIC_CODE
+            | GET_TOPICS_CODE
+            | GET_SCHEMA_CODE
+            | GET_SCHEMAS_CODE
+            | GET_PARTITION_CODE
+            | GET_PARTITION_OFFSETS_CODE
+            | GET_OFFSET_BY_TIMESTAMP_CODE
+            | GET_MESSAGES_HISTOGRAM_CODE
+            | GET_CONSUMER_GROUP_CODE
+            | GET_CONSUMER_GROUPS_CODE
+            | JOIN_CONSUMER_GROUP_CODE
+            | HEARTBEAT_CONSUMER_GROUP_CODE => true,
+            _ => false,
+        }
+    }
+}
+
+/// Returns whether the error was caused by the broken connection, so that the command might succeed after reconnecting.
+pub fn is_retriable_error(error: &IggyError) -> bool {
+    matches!(
+        error,
+        IggyError::Disconnected
+            | IggyError::EmptyResponse
+            | IggyError::Unauthenticated
+            | IggyError::StaleClient
+            | IggyError::ConnectionClosed
+            | IggyError::IoError(_)
+            | IggyError::ConnectionError(_)
+            | IggyError::WriteError(_)
+            | IggyError::ReadError(_)
+            | IggyError::ReadToEndError(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_commands_should_be_retried_until_max_attempts() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            ..Default::default()
+        };
+
+        assert!(policy.should_retry(POLL_MESSAGES_CODE, &IggyError::Disconnected, 1));
+        assert!(policy.should_retry(GET_STREAMS_CODE, &IggyError::EmptyResponse, 2));
+        assert!(!policy.should_retry(GET_STREAMS_CODE, &IggyError::EmptyResponse, 3));
+        assert!(!policy.should_retry(CREATE_STREAM_CODE, &IggyError::Disconnected, 1));
+    }
+
+    #[test]
+    fn server_errors_should_not_be_retried() {
+        let policy = RetryPolicy::default();
+        let error = IggyError::InvalidResponse(1009, 0, "Stream not found".to_string());
+
+        assert!(!policy.should_retry(GET_STREAM_CODE, &error, 1));
+    }
+
+    #[test]
+    fn sending_messages_should_be_retried_only_by_idempotent_producer() {
+        let policy = RetryPolicy::default();
+        assert!(!policy.should_retry(SEND_MESSAGES_CODE, &IggyError::Disconnected, 1));
+
+        let policy = RetryPolicy {
+            idempotent_producer: true,
+            ..Default::default()
+        };
+        assert!(policy.should_retry(SEND_MESSAGES_CODE, &IggyError::Disconnected, 1));
+    }
+
+    #[test]
+    fn backoff_should_be_doubled_up_to_max_backoff() {
+        let policy = RetryPolicy {
+            initial_backoff: IggyDuration::from_str("100ms").unwrap(),
+            max_backoff: IggyDuration::from_str("300ms").unwrap(),
+            ..Default::default()
+        };
+
+        assert_eq!(policy.backoff(1), IggyDuration::from_str("100ms").unwrap());
+        assert_eq!(policy.backoff(2), IggyDuration::from_str("200ms").unwrap());
+        assert_eq!(policy.backoff(3), IggyDuration::from_str("300ms").unwrap());
+        assert_eq!(policy.backoff(40), IggyDuration::from_str("300ms").unwrap());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::{fail_if_not_authenticated, mapper};
+use crate::client::SchemaClient;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::schema::Schema;
+use crate::schemas::get_schema::GetSchema;
+use crate::schemas::get_schemas::GetSchemas;
+use crate::schemas::register_schema::RegisterSchema;
+use crate::utils::schema_type::SchemaType;
+
+#[async_trait::async_trait]
+impl<B: BinaryClient> SchemaClient for B {
+    async fn get_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                version,
+            })
+            .await?;
+        if response.is_empty() {
+            return Ok(None);
+        }
+
+        mapper::map_schema(response).map(Some)
+    }
+
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetSchemas {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+            })
+            .await?;
+        mapper::map_schemas(response)
+    }
+
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+        validate: bool,
+    ) -> Result<Schema, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&RegisterSchema {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                schema_type,
+                validate,
+                definition: definition.to_string(),
+            })
+            .await?;
+        mapper::map_schema(response)
+    }
+}
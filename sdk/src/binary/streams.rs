@@ -29,7 +29,24 @@ impl<B: BinaryClient> StreamClient for B {
 
     async fn get_streams(&self) -> Result<Vec<Stream>, IggyError> {
         fail_if_not_authenticated(self).await?;
-        let response = self.send_with_response(&GetStreams {}).await?;
+        let response = self.send_with_response(&GetStreams::default()).await?;
+        mapper::map_streams(response)
+    }
+
+    async fn get_streams_page(
+        &self,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Stream>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetStreams {
+                offset,
+                limit,
+                name_prefix: name_prefix.map(|name_prefix| name_prefix.to_string()),
+            })
+            .await?;
         mapper::map_streams(response)
     }
 
@@ -3,15 +3,27 @@ use crate::binary::{fail_if_not_authenticated, mapper};
 use crate::client::SystemClient;
 use crate::error::IggyError;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster_metadata::ClusterMetadata;
+use crate::models::node_status::NodeStatus;
+use crate::models::session_info::SessionInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::decommission_node::DecommissionNode;
+use crate::system::drain_node::DrainNode;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
+use crate::system::get_cluster_metadata::GetClusterMetadata;
 use crate::system::get_me::GetMe;
+use crate::system::get_node_status::GetNodeStatus;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_snapshot::GetSnapshot;
 use crate::system::get_stats::GetStats;
+use crate::system::kill_session::KillSession;
 use crate::system::ping::Ping;
+use crate::system::rotate_encryption_key::RotateEncryptionKey;
+use crate::system::set_background_io_rate::SetBackgroundIoRate;
+use crate::system::set_log_level::SetLogLevel;
 use crate::utils::duration::IggyDuration;
 
 #[async_trait::async_trait]
@@ -43,6 +55,18 @@ impl<B: BinaryClient> SystemClient for B {
         mapper::map_clients(response)
     }
 
+    async fn get_sessions(&self) -> Result<Vec<SessionInfo>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetSessions {}).await?;
+        mapper::map_sessions(response)
+    }
+
+    async fn kill_session(&self, client_id: u32) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&KillSession { client_id }).await?;
+        Ok(())
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.send_with_response(&Ping {}).await?;
         Ok(())
@@ -67,4 +91,52 @@ impl<B: BinaryClient> SystemClient for B {
         let snapshot = Snapshot::new(response.to_vec());
         Ok(snapshot)
     }
+
+    async fn rotate_encryption_key(&self) -> Result<u32, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&RotateEncryptionKey {}).await?;
+        mapper::map_key_version(response)
+    }
+
+    async fn set_log_level(&self, filter: &str) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetLogLevel {
+            filter: filter.to_string(),
+        })
+        .await?;
+        Ok(())
+    }
+
+    async fn set_background_io_rate(&self, bytes_per_second: u64) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetBackgroundIoRate { bytes_per_second })
+            .await?;
+        Ok(())
+    }
+
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetClusterMetadata {}).await?;
+        mapper::map_cluster_metadata(response)
+    }
+
+    async fn get_node_status(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&GetNodeStatus { node_id }).await?;
+        mapper::map_node_status(response)
+    }
+
+    async fn drain_node(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self.send_with_response(&DrainNode { node_id }).await?;
+        mapper::map_node_status(response)
+    }
+
+    async fn decommission_node(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&DecommissionNode { node_id })
+            .await?;
+        mapper::map_node_status(response)
+    }
 }
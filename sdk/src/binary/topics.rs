@@ -10,8 +10,11 @@ use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::purge_topic::PurgeTopic;
+use crate::topics::set_topic_read_only::SetTopicReadOnly;
 use crate::topics::update_topic::UpdateTopic;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 
 #[async_trait::async_trait]
@@ -40,6 +43,26 @@ impl<B: BinaryClient> TopicClient for B {
         let response = self
             .send_with_response(&GetTopics {
                 stream_id: stream_id.clone(),
+                ..Default::default()
+            })
+            .await?;
+        mapper::map_topics(response)
+    }
+
+    async fn get_topics_page(
+        &self,
+        stream_id: &Identifier,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Topic>, IggyError> {
+        fail_if_not_authenticated(self).await?;
+        let response = self
+            .send_with_response(&GetTopics {
+                stream_id: stream_id.clone(),
+                offset,
+                limit,
+                name_prefix: name_prefix.map(|name_prefix| name_prefix.to_string()),
             })
             .await?;
         mapper::map_topics(response)
@@ -55,6 +78,8 @@ impl<B: BinaryClient> TopicClient for B {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<TopicDetails, IggyError> {
         fail_if_not_authenticated(self).await?;
         let response = self
@@ -67,6 +92,8 @@ impl<B: BinaryClient> TopicClient for B {
                 topic_id,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy,
+                overrides,
             })
             .await?;
         mapper::map_topic(response)
@@ -81,6 +108,8 @@ impl<B: BinaryClient> TopicClient for B {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<(), IggyError> {
         fail_if_not_authenticated(self).await?;
         self.send_with_response(&UpdateTopic {
@@ -91,6 +120,8 @@ impl<B: BinaryClient> TopicClient for B {
             replication_factor,
             message_expiry,
             max_topic_size,
+            cleanup_policy,
+            overrides,
         })
         .await?;
         Ok(())
@@ -123,4 +154,20 @@ impl<B: BinaryClient> TopicClient for B {
         .await?;
         Ok(())
     }
+
+    async fn set_topic_read_only(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        read_only: bool,
+    ) -> Result<(), IggyError> {
+        fail_if_not_authenticated(self).await?;
+        self.send_with_response(&SetTopicReadOnly {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+            read_only,
+        })
+        .await?;
+        Ok(())
+    }
 }
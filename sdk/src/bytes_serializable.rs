@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use std::str::from_utf8;
 
 use crate::error::IggyError;
 
@@ -12,3 +13,76 @@ pub trait BytesSerializable {
     where
         Self: Sized;
 }
+
+/// Returns the slice of the given length starting at the position.
+/// Fails with `InvalidCommand` if the slice exceeds the bytes, so the malformed input can't cause a panic.
+pub fn read_slice(bytes: &[u8], position: usize, length: usize) -> Result<&[u8], IggyError> {
+    position
+        .checked_add(length)
+        .and_then(|end| bytes.get(position..end))
+        .ok_or(IggyError::InvalidCommand)
+}
+
+/// Returns the byte at the position, or `InvalidCommand` if it exceeds the bytes.
+pub fn read_u8(bytes: &[u8], position: usize) -> Result<u8, IggyError> {
+    bytes
+        .get(position)
+        .copied()
+        .ok_or(IggyError::InvalidCommand)
+}
+
+/// Returns the little-endian `u32` at the position, or `InvalidCommand` if it exceeds the bytes.
+pub fn read_u32_le(bytes: &[u8], position: usize) -> Result<u32, IggyError> {
+    Ok(u32::from_le_bytes(
+        read_slice(bytes, position, 4)?.try_into()?,
+    ))
+}
+
+/// Returns the little-endian `u64` at the position, or `InvalidCommand` if it exceeds the bytes.
+pub fn read_u64_le(bytes: &[u8], position: usize) -> Result<u64, IggyError> {
+    Ok(u64::from_le_bytes(
+        read_slice(bytes, position, 8)?.try_into()?,
+    ))
+}
+
+/// Returns the UTF-8 string of the given length starting at the position.
+/// Fails with `InvalidCommand` if the string exceeds the bytes, or `CannotParseUtf8` if it's not a valid UTF-8.
+pub fn read_str(bytes: &[u8], position: usize, length: usize) -> Result<String, IggyError> {
+    Ok(from_utf8(read_slice(bytes, position, length)?)?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_values_within_bounds() {
+        let bytes = [3, 1, 0, 0, 0, b'i', b'g', b'g', b'y'];
+        assert_eq!(read_u8(&bytes, 0).unwrap(), 3);
+        assert_eq!(read_u32_le(&bytes, 1).unwrap(), 1);
+        assert_eq!(read_str(&bytes, 5, 4).unwrap(), "iggy");
+        assert_eq!(read_slice(&bytes, 9, 0).unwrap(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn should_fail_to_read_values_out_of_bounds() {
+        let bytes = [3, 1, 0, 0, 0xff];
+        assert!(matches!(read_u8(&bytes, 5), Err(IggyError::InvalidCommand)));
+        assert!(matches!(
+            read_u32_le(&bytes, 2),
+            Err(IggyError::InvalidCommand)
+        ));
+        assert!(matches!(
+            read_u64_le(&bytes, 0),
+            Err(IggyError::InvalidCommand)
+        ));
+        assert!(matches!(
+            read_slice(&bytes, usize::MAX, 2),
+            Err(IggyError::InvalidCommand)
+        ));
+        assert!(matches!(
+            read_str(&bytes, 4, 1),
+            Err(IggyError::CannotParseUtf8(_))
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_client::GetClient;
 use anyhow::Context;
@@ -34,6 +34,7 @@ impl CliCommand for GetClientCmd {
                     self.get_client.client_id
                 )
             })?;
+        print_json(&client_details)?;
 
         if client_details.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Client with ID: {} was not found", self.get_client.client_id);
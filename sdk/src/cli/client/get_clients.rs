@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_clients::GetClients;
 use anyhow::Context;
@@ -49,6 +49,7 @@ impl CliCommand for GetClientsCmd {
             .get_clients()
             .await
             .with_context(|| String::from("Problem getting list of clients"))?;
+        print_json(&clients)?;
 
         if clients.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No clients found!");
@@ -1,7 +1,8 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::identifier::Identifier;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 use anyhow::Context;
 use async_trait::async_trait;
 use tracing::{event, Level};
@@ -16,6 +17,7 @@ impl CreateConsumerGroupCmd {
         topic_id: Identifier,
         name: String,
         group_id: Option<u32>,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Self {
         Self {
             create_consumer_group: CreateConsumerGroup {
@@ -23,6 +25,7 @@ impl CreateConsumerGroupCmd {
                 topic_id,
                 name,
                 group_id,
+                rebalance_strategy,
             },
         }
     }
@@ -48,8 +51,8 @@ impl CliCommand for CreateConsumerGroupCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
-            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.group_id)
+        let consumer_group = client
+            .create_consumer_group(&self.create_consumer_group.stream_id, &self.create_consumer_group.topic_id, &self.create_consumer_group.name, self.create_consumer_group.group_id, self.create_consumer_group.rebalance_strategy)
             .await
             .with_context(|| {
                 format!(
@@ -57,6 +60,7 @@ impl CliCommand for CreateConsumerGroupCmd {
                     self.get_group_id_info(), self.create_consumer_group.name, self.create_consumer_group.topic_id, self.create_consumer_group.stream_id
                 )
             })?;
+        print_json(&consumer_group)?;
 
         event!(target: PRINT_TARGET, Level::INFO,
             "Consumer group: {}, name: {} created for topic with ID: {} and stream with ID: {}",
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
 use crate::identifier::Identifier;
@@ -44,6 +44,7 @@ impl CliCommand for GetConsumerGroupCmd {
                     self.get_consumer_group.group_id, self.get_consumer_group.topic_id, self.get_consumer_group.stream_id
                 )
             })?;
+        print_json(&consumer_group)?;
 
         if consumer_group.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Consumer group with ID: {} was not found", self.get_consumer_group.group_id);
@@ -67,6 +68,10 @@ impl CliCommand for GetConsumerGroupCmd {
             "Members count",
             format!("{}", consumer_group.members_count).as_str(),
         ]);
+        table.add_row(vec![
+            "Rebalance strategy",
+            format!("{}", consumer_group.rebalance_strategy).as_str(),
+        ]);
 
         if consumer_group.members_count > 0 {
             let mut members_table = Table::new();
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
 use crate::identifier::Identifier;
@@ -67,6 +67,7 @@ impl CliCommand for GetConsumerGroupsCmd {
                     self.get_consumer_groups.stream_id, self.get_consumer_groups.topic_id
                 )
             })?;
+        print_json(&consumer_groups)?;
 
         match self.output {
             GetConsumerGroupsOutput::Table => {
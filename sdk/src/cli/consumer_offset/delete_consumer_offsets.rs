@@ -0,0 +1,99 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use crate::identifier::Identifier;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct DeleteConsumerOffsetsCmd {
+    delete_consumer_offsets: DeleteConsumerOffsets,
+}
+
+impl DeleteConsumerOffsetsCmd {
+    pub fn new(
+        consumer_id: Identifier,
+        consumer_group: bool,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+    ) -> Self {
+        let consumer = if consumer_group {
+            Consumer::group(consumer_id)
+        } else {
+            Consumer::new(consumer_id)
+        };
+        Self {
+            delete_consumer_offsets: DeleteConsumerOffsets {
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+            },
+        }
+    }
+
+    fn get_consumer_info(&self) -> String {
+        match self.delete_consumer_offsets.consumer.kind {
+            ConsumerKind::Consumer => format!(
+                "consumer with ID: {}",
+                self.delete_consumer_offsets.consumer.id
+            ),
+            ConsumerKind::ConsumerGroup => format!(
+                "consumer group with ID: {}",
+                self.delete_consumer_offsets.consumer.id
+            ),
+        }
+    }
+
+    fn get_partition_info(&self) -> String {
+        match self.delete_consumer_offsets.partition_id {
+            Some(partition_id) => format!("partition with ID: {partition_id}"),
+            None => String::from("all partitions"),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for DeleteConsumerOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "delete consumer offsets for {} for stream with ID: {} and topic with ID: {} and {}",
+            self.get_consumer_info(),
+            self.delete_consumer_offsets.stream_id,
+            self.delete_consumer_offsets.topic_id,
+            self.get_partition_info(),
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .delete_consumer_offsets(
+                &self.delete_consumer_offsets.consumer,
+                &self.delete_consumer_offsets.stream_id,
+                &self.delete_consumer_offsets.topic_id,
+                self.delete_consumer_offsets.partition_id,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem deleting consumer offsets for {} for stream with ID: {} and topic with ID: {} and {}",
+                    self.get_consumer_info(),
+                    self.delete_consumer_offsets.stream_id,
+                    self.delete_consumer_offsets.topic_id,
+                    self.get_partition_info(),
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Consumer offsets for {} for stream with ID: {} and topic with ID: {} and {} deleted",
+            self.get_consumer_info(),
+            self.delete_consumer_offsets.stream_id,
+            self.delete_consumer_offsets.topic_id,
+            self.get_partition_info(),
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
@@ -64,6 +64,7 @@ impl CliCommand for GetConsumerOffsetCmd {
                 self.get_consumer_info(), self.get_consumer_offset.stream_id, self.get_consumer_offset.topic_id, self.get_consumer_offset.partition_id.unwrap()
             )
         })?;
+        print_json(&consumer_offset)?;
 
         if consumer_offset.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Consumer offset for {} for stream with ID: {} and topic with ID: {} and partition with ID: {} was not found", self.get_consumer_info(), self.get_consumer_offset.stream_id, self.get_consumer_offset.topic_id, self.get_consumer_offset.partition_id.unwrap());
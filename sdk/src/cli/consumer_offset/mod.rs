@@ -1,2 +1,4 @@
+pub mod delete_consumer_offsets;
 pub mod get_consumer_offset;
+pub mod reset_consumer_offsets;
 pub mod set_consumer_offset;
@@ -0,0 +1,144 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::consumer_offsets::reset_consumer_offsets::{
+    OffsetResetKind, OffsetResetStrategy, ResetConsumerOffsets,
+};
+use crate::identifier::Identifier;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct ResetConsumerOffsetsCmd {
+    reset_consumer_offsets: ResetConsumerOffsets,
+}
+
+impl ResetConsumerOffsetsCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        consumer_id: Identifier,
+        consumer_group: bool,
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partitions: Vec<u32>,
+        earliest: bool,
+        latest: bool,
+        offset: Option<u64>,
+        timestamp: Option<u64>,
+        dry_run: bool,
+    ) -> Self {
+        let strategy = match (earliest, latest, offset, timestamp) {
+            (true, false, None, None) => OffsetResetStrategy::earliest(),
+            (false, true, None, None) => OffsetResetStrategy::latest(),
+            (false, false, Some(offset), None) => OffsetResetStrategy::offset(offset),
+            (false, false, None, Some(timestamp)) => {
+                OffsetResetStrategy::timestamp(IggyTimestamp::from(timestamp))
+            }
+            _ => unreachable!("Either earliest, latest, offset or timestamp must be specified"),
+        };
+        let consumer = if consumer_group {
+            Consumer::group(consumer_id)
+        } else {
+            Consumer::new(consumer_id)
+        };
+        Self {
+            reset_consumer_offsets: ResetConsumerOffsets {
+                consumer,
+                stream_id,
+                topic_id,
+                partitions,
+                strategy,
+                dry_run,
+            },
+        }
+    }
+
+    fn get_consumer_info(&self) -> String {
+        match self.reset_consumer_offsets.consumer.kind {
+            ConsumerKind::Consumer => format!(
+                "consumer with ID: {}",
+                self.reset_consumer_offsets.consumer.id
+            ),
+            ConsumerKind::ConsumerGroup => format!(
+                "consumer group with ID: {}",
+                self.reset_consumer_offsets.consumer.id
+            ),
+        }
+    }
+
+    fn get_strategy_info(&self) -> String {
+        let strategy = &self.reset_consumer_offsets.strategy;
+        match strategy.kind {
+            OffsetResetKind::Offset | OffsetResetKind::Timestamp => {
+                format!("{} {}", strategy.kind, strategy.value)
+            }
+            OffsetResetKind::Earliest | OffsetResetKind::Latest => format!("{}", strategy.kind),
+        }
+    }
+}
+
+fn format_offset(offset: Option<u64>) -> String {
+    offset.map_or(String::from("-"), |offset| format!("{offset}"))
+}
+
+#[async_trait]
+impl CliCommand for ResetConsumerOffsetsCmd {
+    fn explain(&self) -> String {
+        format!(
+            "{}reset consumer offsets for {} for stream with ID: {} and topic with ID: {} to {}",
+            if self.reset_consumer_offsets.dry_run {
+                "dry run "
+            } else {
+                ""
+            },
+            self.get_consumer_info(),
+            self.reset_consumer_offsets.stream_id,
+            self.reset_consumer_offsets.topic_id,
+            self.get_strategy_info(),
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let resets = client
+            .reset_consumer_offsets(
+                &self.reset_consumer_offsets.consumer,
+                &self.reset_consumer_offsets.stream_id,
+                &self.reset_consumer_offsets.topic_id,
+                &self.reset_consumer_offsets.partitions,
+                &self.reset_consumer_offsets.strategy,
+                self.reset_consumer_offsets.dry_run,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem resetting consumer offsets for {} for stream with ID: {} and topic with ID: {}",
+                    self.get_consumer_info(),
+                    self.reset_consumer_offsets.stream_id,
+                    self.reset_consumer_offsets.topic_id
+                )
+            })?;
+        print_json(&resets)?;
+
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Partition ID",
+            "Current offset",
+            "Previous offset",
+            "Stored offset",
+        ]);
+        for reset in resets {
+            table.add_row(vec![
+                format!("{}", reset.partition_id),
+                format!("{}", reset.current_offset),
+                format_offset(reset.previous_offset),
+                format_offset(reset.stored_offset),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+
+use super::common::{ContextConfig, ContextManager};
+
+pub struct AddContextCmd {
+    context_name: String,
+    context: ContextConfig,
+}
+
+impl AddContextCmd {
+    pub fn new(context_name: String, context: ContextConfig) -> Self {
+        Self {
+            context_name,
+            context,
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for AddContextCmd {
+    fn explain(&self) -> String {
+        let context_name = &self.context_name;
+        format!("add context {context_name}")
+    }
+
+    fn login_required(&self) -> bool {
+        false
+    }
+
+    fn connection_required(&self) -> bool {
+        false
+    }
+
+    async fn execute_cmd(&mut self, _client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let mut context_mgr = ContextManager::default();
+
+        context_mgr
+            .add_context(&self.context_name, self.context.clone())
+            .await?;
+
+        event!(target: PRINT_TARGET, Level::INFO, "context '{}' added", self.context_name);
+
+        return Ok(());
+    }
+}
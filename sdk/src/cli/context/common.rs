@@ -1,8 +1,9 @@
 use anyhow::{bail, Context, Result};
 use dirs::home_dir;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{collections::HashMap, env::var, path};
+use tokio::io::AsyncWriteExt;
 use tokio::join;
 
 use crate::args::ArgsOptional;
@@ -98,6 +99,28 @@ impl ContextManager {
         Ok(())
     }
 
+    pub async fn add_context(&mut self, context_name: &str, context: ContextConfig) -> Result<()> {
+        let cs = self.get_context_state().await?;
+
+        if cs.contexts.contains_key(context_name) {
+            bail!("context key '{context_name}' already exists in {CONTEXTS_FILE_NAME}")
+        }
+
+        let mut contexts = cs.contexts.clone();
+        contexts.insert(context_name.to_string(), context);
+
+        self.context_rw
+            .write_contexts(contexts.clone())
+            .await
+            .context(format!("failed writing context '{context_name}'"))?;
+
+        if let Some(cs) = self.context_state.as_mut() {
+            cs.contexts = contexts;
+        }
+
+        Ok(())
+    }
+
     pub async fn get_active_context_key(&mut self) -> Result<String> {
         let context_state = self.get_context_state().await?;
         Ok(context_state.active_context.clone())
@@ -196,7 +219,15 @@ impl ContextReaderWriter {
                 contexts_path.display()
             ))?;
 
-            tokio::fs::write(contexts_path, contents).await?;
+            if let Some(iggy_home) = contexts_path.parent() {
+                create_private_dir(iggy_home).await?;
+            }
+            write_private_file(&contexts_path, contents.as_bytes())
+                .await
+                .context(format!(
+                    "failed writing contexts file {}",
+                    contexts_path.display()
+                ))?;
         }
 
         Ok(())
@@ -257,6 +288,34 @@ impl Default for ContextReaderWriter {
     }
 }
 
+// Contexts file might contain the credentials, so it's readable and writable only by the owner.
+async fn create_private_dir(path: &Path) -> std::io::Result<()> {
+    let mut builder = tokio::fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    builder.mode(0o700);
+    builder.create(path).await
+}
+
+async fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).await?;
+
+    // The mode is applied only when the file is created, the existing one might be still readable by others.
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+
+    file.write_all(contents).await?;
+    file.sync_all().await
+}
+
 pub fn iggy_home() -> Option<PathBuf> {
     let iggy_home = match var(ENV_IGGY_HOME) {
         Ok(home) => Some(PathBuf::from(home)),
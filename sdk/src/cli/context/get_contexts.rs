@@ -1,8 +1,9 @@
 use async_trait::async_trait;
 use comfy_table::Table;
+use serde_json::json;
 use tracing::{event, Level};
 
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 
 use super::common::ContextManager;
@@ -60,6 +61,11 @@ impl CliCommand for GetContextsCmd {
         let mut context_mgr = ContextManager::default();
         let contexts_map = context_mgr.get_contexts().await?;
         let active_context_key = context_mgr.get_active_context_key().await?;
+        let contexts = contexts_map
+            .keys()
+            .map(|name| json!({ "name": name, "active": name.eq(&active_context_key) }))
+            .collect::<Vec<_>>();
+        print_json(&contexts)?;
 
         match self.output {
             GetContextsOutput::Table => {
@@ -1,4 +1,5 @@
 pub mod common;
 
+pub mod add_context;
 pub mod get_contexts;
 pub mod use_context;
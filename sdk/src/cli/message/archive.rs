@@ -0,0 +1,212 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::messages::send_messages::Message;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::messages::PolledMessage;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// The header of the binary archive file, followed by the archived messages.
+pub const BINARY_ARCHIVE_HEADER: &[u8; 8] = b"IGGYARC1";
+/// The size of the binary archived message metadata: partition ID, offset, timestamp and the message length.
+pub const BINARY_ARCHIVED_MESSAGE_METADATA: usize = 4 + 8 + 8 + 4;
+
+/// The format of the file to which the messages are exported or from which they are imported.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ArchiveFormat {
+    /// Newline-delimited JSON, one message per line with the base64 encoded payload.
+    Json,
+    /// Binary archive, starting with the header followed by the messages in the binary format.
+    Binary,
+}
+
+impl Display for ArchiveFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArchiveFormat::Json => write!(f, "json"),
+            ArchiveFormat::Binary => write!(f, "binary"),
+        }
+    }
+}
+
+/// The message stored in the archive file along with the partition and the offset it was exported from.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedMessage {
+    /// The ID of the partition from which the message was exported.
+    pub partition_id: u32,
+    /// The offset of the message in the partition.
+    pub offset: u64,
+    /// The timestamp of the message, informational only as the server assigns the new one on import.
+    pub timestamp: u64,
+    /// The identifier of the message.
+    pub id: u128,
+    /// The optional headers of the message.
+    pub headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    /// The binary payload of the message.
+    #[serde_as(as = "Base64")]
+    pub payload: Bytes,
+}
+
+impl ArchivedMessage {
+    pub fn from_polled_message(partition_id: u32, message: &PolledMessage) -> Self {
+        Self {
+            partition_id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+            id: message.id,
+            headers: message.headers.clone(),
+            payload: message.payload.clone(),
+        }
+    }
+
+    /// Returns the message to be sent, keeping the original ID so that it can be deduplicated by the server.
+    pub fn to_message(&self) -> Message {
+        Message::new(Some(self.id), self.payload.clone(), self.headers.clone())
+    }
+
+    /// Encodes the message as a single JSON line, including the trailing newline.
+    pub fn to_json_line(&self) -> Result<Vec<u8>, IggyError> {
+        let mut line = serde_json::to_vec(self).map_err(|_| IggyError::InvalidFormat)?;
+        line.push(b'\n');
+        Ok(line)
+    }
+
+    pub fn from_json_line(line: &str) -> Result<Self, IggyError> {
+        serde_json::from_str(line).map_err(|_| IggyError::InvalidFormat)
+    }
+
+    /// Encodes the message as the metadata followed by the message in the binary format.
+    pub fn to_binary(&self) -> Bytes {
+        let message = self.to_message().to_bytes();
+        let mut bytes = BytesMut::with_capacity(BINARY_ARCHIVED_MESSAGE_METADATA + message.len());
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u64_le(self.timestamp);
+        bytes.put_u32_le(message.len() as u32);
+        bytes.put_slice(&message);
+        bytes.freeze()
+    }
+
+    /// Returns the partition ID, offset, timestamp and the length of the message from the binary metadata.
+    pub fn read_binary_metadata(
+        metadata: &[u8; BINARY_ARCHIVED_MESSAGE_METADATA],
+    ) -> (u32, u64, u64, usize) {
+        let partition_id = u32::from_le_bytes(metadata[0..4].try_into().unwrap());
+        let offset = u64::from_le_bytes(metadata[4..12].try_into().unwrap());
+        let timestamp = u64::from_le_bytes(metadata[12..20].try_into().unwrap());
+        let length = u32::from_le_bytes(metadata[20..24].try_into().unwrap());
+        (partition_id, offset, timestamp, length as usize)
+    }
+
+    pub fn from_binary(
+        metadata: &[u8; BINARY_ARCHIVED_MESSAGE_METADATA],
+        message: Bytes,
+    ) -> Result<Self, IggyError> {
+        let (partition_id, offset, timestamp, _) = Self::read_binary_metadata(metadata);
+        let message = Message::from_bytes(message)?;
+        Ok(Self {
+            partition_id,
+            offset,
+            timestamp,
+            id: message.id,
+            headers: message.headers,
+            payload: message.payload,
+        })
+    }
+}
+
+/// Limits the number of the exported or imported messages per second, if the rate is specified.
+pub struct MessagesRateLimiter {
+    messages_per_second: Option<u32>,
+    start: Instant,
+    messages: u64,
+}
+
+impl MessagesRateLimiter {
+    pub fn new(messages_per_second: Option<u32>) -> Self {
+        Self {
+            messages_per_second,
+            start: Instant::now(),
+            messages: 0,
+        }
+    }
+
+    /// Records the processed messages and waits until their count no longer exceeds the rate.
+    pub async fn throttle(&mut self, messages: u64) {
+        self.messages += messages;
+        let Some(messages_per_second) = self.messages_per_second else {
+            return;
+        };
+
+        let expected = Duration::from_secs_f64(self.messages as f64 / messages_per_second as f64);
+        let elapsed = self.start.elapsed();
+        if expected > elapsed {
+            sleep(expected - elapsed).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn archived_message() -> ArchivedMessage {
+        ArchivedMessage {
+            partition_id: 2,
+            offset: 10,
+            timestamp: 1_700_000_000_000_000,
+            id: 123,
+            headers: Some(HashMap::from([(
+                HeaderKey::from_str("key").unwrap(),
+                HeaderValue::from_str("value").unwrap(),
+            )])),
+            payload: Bytes::from("payload"),
+        }
+    }
+
+    #[test]
+    fn message_should_be_encoded_and_decoded_as_json_line() {
+        let message = archived_message();
+
+        let line = message.to_json_line().unwrap();
+        assert_eq!(line.last(), Some(&b'\n'));
+
+        let line = std::str::from_utf8(&line).unwrap().trim_end();
+        assert_eq!(ArchivedMessage::from_json_line(line).unwrap(), message);
+    }
+
+    #[test]
+    fn message_should_be_encoded_and_decoded_as_binary() {
+        let message = archived_message();
+
+        let bytes = message.to_binary();
+        let metadata: [u8; BINARY_ARCHIVED_MESSAGE_METADATA] = bytes
+            [..BINARY_ARCHIVED_MESSAGE_METADATA]
+            .try_into()
+            .unwrap();
+        let (partition_id, offset, _, length) = ArchivedMessage::read_binary_metadata(&metadata);
+        assert_eq!(partition_id, message.partition_id);
+        assert_eq!(offset, message.offset);
+        assert_eq!(length, bytes.len() - BINARY_ARCHIVED_MESSAGE_METADATA);
+
+        let decoded = ArchivedMessage::from_binary(
+            &metadata,
+            bytes.slice(BINARY_ARCHIVED_MESSAGE_METADATA..),
+        )
+        .unwrap();
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn invalid_json_line_should_not_be_decoded() {
+        assert!(ArchivedMessage::from_json_line("{\"offset\":1}").is_err());
+    }
+}
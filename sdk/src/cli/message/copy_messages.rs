@@ -0,0 +1,96 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::copy_messages::{CopyMessages, CopyRangeKind};
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct CopyMessagesCmd {
+    copy_messages: CopyMessages,
+}
+
+impl CopyMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        source_stream_id: Identifier,
+        source_topic_id: Identifier,
+        source_partition_id: u32,
+        target_stream_id: Identifier,
+        target_topic_id: Identifier,
+        target_partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Self {
+        Self {
+            copy_messages: CopyMessages {
+                source_stream_id,
+                source_topic_id,
+                source_partition_id,
+                target_stream_id,
+                target_topic_id,
+                target_partition_id,
+                kind,
+                from,
+                to,
+            },
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "messages with {} from: {} to: {} from topic with ID: {} and stream with ID: {} (partition with ID: {}) to topic with ID: {} and stream with ID: {} (partition with ID: {})",
+            self.copy_messages.kind,
+            self.copy_messages.from,
+            self.copy_messages.to,
+            self.copy_messages.source_topic_id,
+            self.copy_messages.source_stream_id,
+            self.copy_messages.source_partition_id,
+            self.copy_messages.target_topic_id,
+            self.copy_messages.target_stream_id,
+            self.copy_messages.target_partition_id,
+        )
+    }
+}
+
+#[async_trait]
+impl CliCommand for CopyMessagesCmd {
+    fn explain(&self) -> String {
+        format!("copy {}", self.describe())
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let copied_messages = client
+            .copy_messages(
+                &self.copy_messages.source_stream_id,
+                &self.copy_messages.source_topic_id,
+                self.copy_messages.source_partition_id,
+                &self.copy_messages.target_stream_id,
+                &self.copy_messages.target_topic_id,
+                self.copy_messages.target_partition_id,
+                self.copy_messages.kind,
+                self.copy_messages.from,
+                self.copy_messages.to,
+            )
+            .await
+            .with_context(|| format!("Problem copying {}", self.describe()))?;
+        print_json(&copied_messages)?;
+
+        let message_count_message = match copied_messages.messages_count {
+            1 => "1 message".into(),
+            count => format!("{} messages", count),
+        };
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Copied {message_count_message} from topic with ID: {} and stream with ID: {} (partition with ID: {}) to topic with ID: {} and stream with ID: {} (partition with ID: {})",
+            self.copy_messages.source_topic_id,
+            self.copy_messages.source_stream_id,
+            self.copy_messages.source_partition_id,
+            self.copy_messages.target_topic_id,
+            self.copy_messages.target_stream_id,
+            self.copy_messages.target_partition_id,
+        );
+
+        Ok(())
+    }
+}
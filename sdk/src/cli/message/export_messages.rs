@@ -0,0 +1,193 @@
+use crate::cli::message::archive::{
+    ArchiveFormat, ArchivedMessage, MessagesRateLimiter, BINARY_ARCHIVE_HEADER,
+};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::consumer::Consumer;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::PollingStrategy;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use anyhow::Context;
+use async_trait::async_trait;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tracing::{event, Level};
+
+pub struct ExportMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_ids: Vec<u32>,
+    output_file: String,
+    format: ArchiveFormat,
+    from_offset: u64,
+    to_offset: Option<u64>,
+    batch_size: u32,
+    rate_limit: Option<u32>,
+}
+
+impl ExportMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_ids: Vec<u32>,
+        output_file: String,
+        format: ArchiveFormat,
+        from_offset: u64,
+        to_offset: Option<u64>,
+        batch_size: u32,
+        rate_limit: Option<u32>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_ids,
+            output_file,
+            format,
+            from_offset,
+            to_offset,
+            batch_size,
+            rate_limit,
+        }
+    }
+
+    async fn get_partition_ids(&self, client: &dyn Client) -> anyhow::Result<Vec<u32>> {
+        if !self.partition_ids.is_empty() {
+            return Ok(self.partition_ids.clone());
+        }
+
+        let topic = client
+            .get_topic(&self.stream_id, &self.topic_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Topic with ID: {} in stream with ID: {} was not found",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+        Ok((1..=topic.partitions_count).collect())
+    }
+}
+
+#[async_trait]
+impl CliCommand for ExportMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "export messages from topic with ID: {} and stream with ID: {} to {} file: {}",
+            self.topic_id, self.stream_id, self.format, self.output_file
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let start = std::time::Instant::now();
+        let partition_ids = self.get_partition_ids(client).await?;
+        let output_file = &self.output_file;
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(output_file)
+            .await
+            .with_context(|| format!("Problem opening file for writing: {output_file}"))?;
+        let mut writer = BufWriter::new(file);
+        if self.format == ArchiveFormat::Binary {
+            writer
+                .write_all(BINARY_ARCHIVE_HEADER)
+                .await
+                .with_context(|| format!("Problem writing to file: {output_file}"))?;
+        }
+
+        let consumer = Consumer::default();
+        let mut rate_limiter = MessagesRateLimiter::new(self.rate_limit);
+        let mut exported_messages = 0u64;
+        let mut exported_size = 0u64;
+        for partition_id in partition_ids {
+            let mut offset = self.from_offset;
+            let mut partition_messages = 0u64;
+            loop {
+                let count = match self.to_offset {
+                    Some(to_offset) if to_offset < offset => break,
+                    Some(to_offset) => (to_offset - offset)
+                        .saturating_add(1)
+                        .min(self.batch_size as u64) as u32,
+                    None => self.batch_size,
+                };
+                let polled_messages = client
+                    .poll_messages(
+                        &self.stream_id,
+                        &self.topic_id,
+                        Some(partition_id),
+                        &consumer,
+                        &PollingStrategy::offset(offset),
+                        count,
+                        false,
+                    )
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Problem polling messages from partition with ID: {partition_id}, topic with ID: {} and stream with ID: {}",
+                            self.topic_id, self.stream_id
+                        )
+                    })?;
+
+                let Some(last_message) = polled_messages.messages.last() else {
+                    break;
+                };
+                offset = last_message.offset + 1;
+
+                let mut batch_messages = 0u64;
+                for message in polled_messages.messages.iter() {
+                    if self
+                        .to_offset
+                        .is_some_and(|to_offset| message.offset > to_offset)
+                    {
+                        break;
+                    }
+
+                    let message = ArchivedMessage::from_polled_message(partition_id, message);
+                    let bytes = match self.format {
+                        ArchiveFormat::Json => message.to_json_line()?,
+                        ArchiveFormat::Binary => message.to_binary().to_vec(),
+                    };
+                    writer.write_all(&bytes).await.with_context(|| {
+                        format!("Problem writing message to file: {output_file}")
+                    })?;
+                    exported_size += bytes.len() as u64;
+                    batch_messages += 1;
+                }
+
+                partition_messages += batch_messages;
+                rate_limiter.throttle(batch_messages).await;
+                if offset > polled_messages.current_offset {
+                    break;
+                }
+            }
+
+            event!(target: PRINT_TARGET, Level::INFO,
+                "Exported {partition_messages} messages from partition with ID: {partition_id}",
+            );
+            exported_messages += partition_messages;
+        }
+
+        writer
+            .flush()
+            .await
+            .with_context(|| format!("Problem writing to file: {output_file}"))?;
+
+        let elapsed = IggyDuration::new(start.elapsed());
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Exported {exported_messages} messages of total size {} to {} file: {output_file}, it took {}",
+            IggyByteSize::from(exported_size).as_human_string(),
+            self.format,
+            elapsed.as_human_time_string(),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::cli::message::peek_messages::PeekMessagesCmd;
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::get_message_by_key::GetMessageByKey;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetMessageByKeyCmd {
+    get_message_by_key: GetMessageByKey,
+}
+
+impl GetMessageByKeyCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, partition_id: u32, key: u128) -> Self {
+        Self {
+            get_message_by_key: GetMessageByKey {
+                stream_id,
+                topic_id,
+                partition_id,
+                key,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetMessageByKeyCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get message with key: {} from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+            self.get_message_by_key.key,
+            self.get_message_by_key.topic_id,
+            self.get_message_by_key.stream_id,
+            self.get_message_by_key.partition_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let messages = client
+            .get_message_by_key(
+                &self.get_message_by_key.stream_id,
+                &self.get_message_by_key.topic_id,
+                self.get_message_by_key.partition_id,
+                self.get_message_by_key.key,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting message with key: {} from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+                    self.get_message_by_key.key,
+                    self.get_message_by_key.topic_id,
+                    self.get_message_by_key.stream_id,
+                    self.get_message_by_key.partition_id,
+                )
+            })?;
+        print_json(&messages)?;
+
+        let Some(message) = messages.messages.first() else {
+            event!(target: PRINT_TARGET, Level::INFO,
+                "No message with key: {} found in topic with ID: {} and stream with ID: {} (partition with ID: {})",
+                self.get_message_by_key.key,
+                self.get_message_by_key.topic_id,
+                self.get_message_by_key.stream_id,
+                messages.partition_id,
+            );
+            return Ok(());
+        };
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Found message with key: {} in topic with ID: {} and stream with ID: {} (partition with ID: {}, offset: {})",
+            self.get_message_by_key.key,
+            self.get_message_by_key.topic_id,
+            self.get_message_by_key.stream_id,
+            messages.partition_id,
+            message.offset,
+        );
+
+        let mut table = Table::new();
+        table.set_header(vec!["Offset", "Timestamp", "Size", "Payload", "Headers"]);
+        table.add_row(vec![
+            format!("{}", message.offset),
+            IggyTimestamp::from(message.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
+            message.get_size_bytes().as_human_string(),
+            String::from_utf8_lossy(&message.payload).to_string(),
+            PeekMessagesCmd::format_headers(message),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use crate::cli::message::archive::{
+    ArchiveFormat, ArchivedMessage, MessagesRateLimiter, BINARY_ARCHIVED_MESSAGE_METADATA,
+    BINARY_ARCHIVE_HEADER,
+};
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{Message, Partitioning};
+use crate::utils::duration::IggyDuration;
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::io::ErrorKind;
+use tokio::fs::File;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tracing::{event, Level};
+
+pub struct ImportMessagesCmd {
+    stream_id: Identifier,
+    topic_id: Identifier,
+    partition_id: Option<u32>,
+    input_file: String,
+    format: ArchiveFormat,
+    from_offset: u64,
+    to_offset: Option<u64>,
+    batch_size: u32,
+    rate_limit: Option<u32>,
+}
+
+impl ImportMessagesCmd {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: Option<u32>,
+        input_file: String,
+        format: ArchiveFormat,
+        from_offset: u64,
+        to_offset: Option<u64>,
+        batch_size: u32,
+        rate_limit: Option<u32>,
+    ) -> Self {
+        Self {
+            stream_id,
+            topic_id,
+            partition_id,
+            input_file,
+            format,
+            from_offset,
+            to_offset,
+            batch_size,
+            rate_limit,
+        }
+    }
+
+    fn is_in_range(&self, offset: u64) -> bool {
+        offset >= self.from_offset && self.to_offset.is_none_or(|to_offset| offset <= to_offset)
+    }
+
+    async fn read_message(
+        &self,
+        reader: &mut BufReader<File>,
+        line: &mut String,
+    ) -> anyhow::Result<Option<ArchivedMessage>> {
+        let input_file = &self.input_file;
+        match self.format {
+            ArchiveFormat::Json => loop {
+                line.clear();
+                let read = reader
+                    .read_line(line)
+                    .await
+                    .with_context(|| format!("Problem reading file: {input_file}"))?;
+                if read == 0 {
+                    return Ok(None);
+                }
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let message =
+                    ArchivedMessage::from_json_line(line.trim_end()).with_context(|| {
+                        format!("Problem parsing message from line: {line} in file: {input_file}")
+                    })?;
+                return Ok(Some(message));
+            },
+            ArchiveFormat::Binary => {
+                let mut metadata = [0u8; BINARY_ARCHIVED_MESSAGE_METADATA];
+                match reader.read_exact(&mut metadata).await {
+                    Ok(_) => {}
+                    Err(error) if error.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+                    Err(error) => {
+                        return Err(error)
+                            .with_context(|| format!("Problem reading file: {input_file}"))
+                    }
+                }
+
+                let (_, offset, _, length) = ArchivedMessage::read_binary_metadata(&metadata);
+                let mut message = vec![0u8; length];
+                reader.read_exact(&mut message).await.with_context(|| {
+                    format!("Problem reading message with offset: {offset} from file: {input_file}")
+                })?;
+                let message = ArchivedMessage::from_binary(&metadata, Bytes::from(message))
+                    .with_context(|| {
+                        format!(
+                            "Problem parsing message with offset: {offset} from file: {input_file}"
+                        )
+                    })?;
+                Ok(Some(message))
+            }
+        }
+    }
+
+    async fn send_messages(
+        &self,
+        client: &dyn Client,
+        partition_id: u32,
+        messages: &mut Vec<Message>,
+    ) -> anyhow::Result<()> {
+        client
+            .send_messages(
+                &self.stream_id,
+                &self.topic_id,
+                &Partitioning::partition_id(partition_id),
+                messages,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem sending messages to partition with ID: {partition_id}, topic with ID: {} and stream with ID: {}",
+                    self.topic_id, self.stream_id
+                )
+            })?;
+        messages.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CliCommand for ImportMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "import messages to topic with ID: {} and stream with ID: {} from {} file: {}",
+            self.topic_id, self.stream_id, self.format, self.input_file
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let start = std::time::Instant::now();
+        let input_file = &self.input_file;
+        let file = tokio::fs::OpenOptions::new()
+            .read(true)
+            .open(input_file)
+            .await
+            .with_context(|| format!("Problem opening file for reading: {input_file}"))?;
+        let mut reader = BufReader::new(file);
+        if self.format == ArchiveFormat::Binary {
+            let mut header = [0u8; BINARY_ARCHIVE_HEADER.len()];
+            let is_archive =
+                reader.read_exact(&mut header).await.is_ok() && &header == BINARY_ARCHIVE_HEADER;
+            if !is_archive {
+                bail!("File: {input_file} is not a binary archive of messages");
+            }
+        }
+
+        let mut rate_limiter = MessagesRateLimiter::new(self.rate_limit);
+        let mut line = String::new();
+        let mut batch = Vec::with_capacity(self.batch_size as usize);
+        let mut batch_partition_id = 0;
+        let mut imported_messages = 0u64;
+        let mut skipped_messages = 0u64;
+        while let Some(message) = self.read_message(&mut reader, &mut line).await? {
+            if !self.is_in_range(message.offset) {
+                skipped_messages += 1;
+                continue;
+            }
+
+            let partition_id = self.partition_id.unwrap_or(message.partition_id);
+            if !batch.is_empty()
+                && (partition_id != batch_partition_id || batch.len() >= self.batch_size as usize)
+            {
+                let batch_messages = batch.len() as u64;
+                self.send_messages(client, batch_partition_id, &mut batch)
+                    .await?;
+                imported_messages += batch_messages;
+                rate_limiter.throttle(batch_messages).await;
+            }
+
+            batch_partition_id = partition_id;
+            batch.push(message.to_message());
+        }
+
+        if !batch.is_empty() {
+            let batch_messages = batch.len() as u64;
+            self.send_messages(client, batch_partition_id, &mut batch)
+                .await?;
+            imported_messages += batch_messages;
+        }
+
+        let elapsed = IggyDuration::new(start.elapsed());
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Imported {imported_messages} messages ({skipped_messages} skipped) to topic with ID: {} and stream with ID: {}, it took {}",
+            self.topic_id,
+            self.stream_id,
+            elapsed.as_human_time_string(),
+        );
+
+        Ok(())
+    }
+}
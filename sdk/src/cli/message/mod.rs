@@ -1,3 +1,9 @@
+pub mod archive;
+pub mod copy_messages;
+pub mod export_messages;
 pub mod flush_messages;
+pub mod get_message_by_key;
+pub mod import_messages;
+pub mod peek_messages;
 pub mod poll_messages;
 pub mod send_messages;
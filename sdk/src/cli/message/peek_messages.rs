@@ -0,0 +1,119 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::messages::peek_messages::{PeekKind, PeekMessages};
+use crate::models::messages::PolledMessage;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct PeekMessagesCmd {
+    peek_messages: PeekMessages,
+}
+
+impl PeekMessagesCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Self {
+        Self {
+            peek_messages: PeekMessages {
+                stream_id,
+                topic_id,
+                partition_id,
+                kind,
+                count,
+            },
+        }
+    }
+
+    pub(crate) fn format_headers(message: &PolledMessage) -> String {
+        let Some(headers) = message.headers.as_ref() else {
+            return String::new();
+        };
+
+        let mut headers = headers
+            .iter()
+            .map(|(key, value)| format!("{}: {}", key.as_str(), value))
+            .collect::<Vec<_>>();
+        headers.sort();
+        headers.join("\n")
+    }
+}
+
+#[async_trait]
+impl CliCommand for PeekMessagesCmd {
+    fn explain(&self) -> String {
+        format!(
+            "peek {} {} messages from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+            self.peek_messages.kind,
+            self.peek_messages.count,
+            self.peek_messages.topic_id,
+            self.peek_messages.stream_id,
+            self.peek_messages.partition_id,
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let messages = client
+            .peek_messages(
+                &self.peek_messages.stream_id,
+                &self.peek_messages.topic_id,
+                self.peek_messages.partition_id,
+                self.peek_messages.kind,
+                self.peek_messages.count,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem peeking messages from topic with ID: {} and stream with ID: {} (partition with ID: {})",
+                    self.peek_messages.topic_id,
+                    self.peek_messages.stream_id,
+                    self.peek_messages.partition_id,
+                )
+            })?;
+        print_json(&messages)?;
+
+        let message_count_message = match messages.messages.len() {
+            1 => "1 message".into(),
+            count => format!("{} messages", count),
+        };
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Peeked {message_count_message} from topic with ID: {} and stream with ID: {} (partition with ID: {}, current offset: {})",
+            self.peek_messages.topic_id,
+            self.peek_messages.stream_id,
+            messages.partition_id,
+            messages.current_offset,
+        );
+
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Offset",
+            "Timestamp",
+            "ID",
+            "Size",
+            "Payload",
+            "Headers",
+        ]);
+        for message in messages.messages.iter() {
+            table.add_row(vec![
+                format!("{}", message.offset),
+                IggyTimestamp::from(message.timestamp).to_local_string("%Y-%m-%d %H:%M:%S%.6f"),
+                format!("{}", message.id),
+                message.get_size_bytes().as_human_string(),
+                String::from_utf8_lossy(&message.payload).to_string(),
+                Self::format_headers(message),
+            ]);
+        }
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
 use crate::bytes_serializable::BytesSerializable;
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::consumer::Consumer;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
+use crate::messages::poll_messages::{PollMessages, PollingDirection, PollingStrategy};
 use crate::messages::send_messages::Message;
 use crate::models::header::{HeaderKey, HeaderKind};
 use crate::models::messages::PolledMessages;
@@ -31,11 +31,13 @@ impl PollMessagesCmd {
         topic_id: Identifier,
         partition_id: u32,
         message_count: u32,
+        max_bytes: u32,
         auto_commit: bool,
         offset: Option<u64>,
         first: bool,
         last: bool,
         next: bool,
+        backward: bool,
         consumer: Identifier,
         show_headers: bool,
         output_file: Option<String>,
@@ -47,6 +49,11 @@ impl PollMessagesCmd {
             (None, false, false, true) => PollingStrategy::next(),
             _ => unreachable!("Either offset or first, last or next must be specified"),
         };
+        let strategy = if backward {
+            strategy.direction(PollingDirection::Backward)
+        } else {
+            strategy
+        };
         Self {
             poll_messages: PollMessages {
                 consumer: Consumer::new(consumer),
@@ -55,6 +62,7 @@ impl PollMessagesCmd {
                 partition_id: Some(partition_id),
                 strategy,
                 count: message_count,
+                max_bytes,
                 auto_commit,
             },
             show_headers,
@@ -151,13 +159,14 @@ impl CliCommand for PollMessagesCmd {
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let start = std::time::Instant::now();
         let messages = client
-            .poll_messages(
+            .poll_messages_with_max_bytes(
                 &self.poll_messages.stream_id,
                 &self.poll_messages.topic_id,
                 self.poll_messages.partition_id,
                 &self.poll_messages.consumer,
                 &self.poll_messages.strategy,
                 self.poll_messages.count,
+                self.poll_messages.max_bytes,
                 self.poll_messages.auto_commit,
             )
             .await
@@ -167,6 +176,7 @@ impl CliCommand for PollMessagesCmd {
                     self.poll_messages.topic_id, self.poll_messages.stream_id
                 )
             })?;
+        print_json(&messages)?;
         let elapsed = IggyDuration::new(start.elapsed());
 
         event!(target: PRINT_TARGET, Level::INFO,
@@ -189,6 +199,9 @@ impl CliCommand for PollMessagesCmd {
             count => format!("{} messages", count),
         };
         event!(target: PRINT_TARGET, Level::INFO, "Polled {message_count_message} of total size {polled_size}, it took {}", elapsed.as_human_time_string());
+        if let Some(next_offset) = messages.next_offset {
+            event!(target: PRINT_TARGET, Level::INFO, "More messages are available, continue polling from offset: {next_offset}");
+        }
 
         if let Some(output_file) = &self.output_file {
             event!(target: PRINT_TARGET, Level::INFO, "Storing messages to {output_file} binary file");
@@ -0,0 +1,91 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_messages_histogram::GetMessagesHistogram;
+use crate::utils::duration::IggyDuration;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetMessagesHistogramCmd {
+    get_messages_histogram: GetMessagesHistogram,
+}
+
+impl GetMessagesHistogramCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Self {
+        Self {
+            get_messages_histogram: GetMessagesHistogram {
+                stream_id,
+                topic_id,
+                partition_id,
+                from,
+                to,
+                bucket_size,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetMessagesHistogramCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get messages histogram from: {} to: {} with bucket size: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_messages_histogram.from,
+            self.get_messages_histogram.to,
+            self.get_messages_histogram.bucket_size,
+            self.get_messages_histogram.partition_id,
+            self.get_messages_histogram.topic_id,
+            self.get_messages_histogram.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let histogram = client
+            .get_messages_histogram(
+                &self.get_messages_histogram.stream_id,
+                &self.get_messages_histogram.topic_id,
+                self.get_messages_histogram.partition_id,
+                self.get_messages_histogram.from,
+                self.get_messages_histogram.to,
+                self.get_messages_histogram.bucket_size,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting messages histogram from: {} to: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_messages_histogram.from,
+                    self.get_messages_histogram.to,
+                    self.get_messages_histogram.partition_id,
+                    self.get_messages_histogram.topic_id,
+                    self.get_messages_histogram.stream_id
+                )
+            })?;
+        print_json(&histogram)?;
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Timestamp", "Time", "Messages", "Size"]);
+        histogram.buckets.iter().for_each(|bucket| {
+            table.add_row(vec![
+                format!("{}", bucket.timestamp),
+                IggyTimestamp::from(bucket.timestamp).to_utc_string("%Y-%m-%d %H:%M:%S%.6f"),
+                format!("{}", bucket.messages_count),
+                format!("{}", bucket.size),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use crate::utils::timestamp::IggyTimestamp;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetOffsetByTimestampCmd {
+    get_offset_by_timestamp: GetOffsetByTimestamp,
+}
+
+impl GetOffsetByTimestampCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            get_offset_by_timestamp: GetOffsetByTimestamp {
+                stream_id,
+                topic_id,
+                partition_id,
+                timestamp,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetOffsetByTimestampCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get offset by timestamp: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_offset_by_timestamp.timestamp,
+            self.get_offset_by_timestamp.partition_id,
+            self.get_offset_by_timestamp.topic_id,
+            self.get_offset_by_timestamp.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let offset = client
+            .get_offset_by_timestamp(
+                &self.get_offset_by_timestamp.stream_id,
+                &self.get_offset_by_timestamp.topic_id,
+                self.get_offset_by_timestamp.partition_id,
+                self.get_offset_by_timestamp.timestamp,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting offset by timestamp: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_offset_by_timestamp.timestamp,
+                    self.get_offset_by_timestamp.partition_id,
+                    self.get_offset_by_timestamp.topic_id,
+                    self.get_offset_by_timestamp.stream_id
+                )
+            })?;
+        print_json(&offset)?;
+
+        let Some(offset) = offset else {
+            event!(target: PRINT_TARGET, Level::INFO, "No message with timestamp at or after: {} in partition with ID: {} for topic with ID: {} and stream with ID: {}", self.get_offset_by_timestamp.timestamp, self.get_offset_by_timestamp.partition_id, self.get_offset_by_timestamp.topic_id, self.get_offset_by_timestamp.stream_id);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec![
+            "Partition id",
+            format!("{}", offset.partition_id).as_str(),
+        ]);
+        table.add_row(vec!["Offset", format!("{}", offset.offset).as_str()]);
+        table.add_row(vec!["Timestamp", format!("{}", offset.timestamp).as_str()]);
+        table.add_row(vec![
+            "Time",
+            IggyTimestamp::from(offset.timestamp)
+                .to_utc_string("%Y-%m-%d %H:%M:%S%.6f")
+                .as_str(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::get_partition::GetPartition;
+use anyhow::Context;
+use async_trait::async_trait;
+use comfy_table::Table;
+use tracing::{event, Level};
+
+pub struct GetPartitionCmd {
+    get_partition: GetPartition,
+}
+
+impl GetPartitionCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, partition_id: u32) -> Self {
+        Self {
+            get_partition: GetPartition {
+                stream_id,
+                topic_id,
+                partition_id,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for GetPartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "get partition with ID: {} for topic with ID: {} and stream with ID: {}",
+            self.get_partition.partition_id,
+            self.get_partition.topic_id,
+            self.get_partition.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let partition = client
+            .get_partition(
+                &self.get_partition.stream_id,
+                &self.get_partition.topic_id,
+                self.get_partition.partition_id,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem getting partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.get_partition.partition_id,
+                    self.get_partition.topic_id,
+                    self.get_partition.stream_id
+                )
+            })?;
+        print_json(&partition)?;
+
+        let Some(partition) = partition else {
+            event!(target: PRINT_TARGET, Level::INFO, "Partition with ID: {} for topic with ID: {} and stream with ID: {} was not found", self.get_partition.partition_id, self.get_partition.topic_id, self.get_partition.stream_id);
+            return Ok(());
+        };
+
+        let mut table = Table::new();
+
+        table.set_header(vec!["Property", "Value"]);
+        table.add_row(vec!["Partition id", format!("{}", partition.id).as_str()]);
+        table.add_row(vec![
+            "Created",
+            partition
+                .created_at
+                .to_utc_string("%Y-%m-%d %H:%M:%S")
+                .as_str(),
+        ]);
+        table.add_row(vec![
+            "Current offset",
+            format!("{}", partition.current_offset).as_str(),
+        ]);
+        table.add_row(vec![
+            "High watermark",
+            format!("{}", partition.high_watermark).as_str(),
+        ]);
+        table.add_row(vec![
+            "Partition size",
+            format!("{}", partition.size).as_str(),
+        ]);
+        table.add_row(vec![
+            "Messages count",
+            format!("{}", partition.messages_count).as_str(),
+        ]);
+        table.add_row(vec![
+            "Segments count",
+            format!("{}", partition.segments_count).as_str(),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        let consumer_offsets = &partition.consumer_offsets;
+        let mut table = Table::new();
+
+        table.set_header(vec![
+            "Consumers",
+            "Consumer Groups",
+            "Offset Records",
+            "Offsets Size",
+            "Compactions",
+            "Last Compacted",
+        ]);
+        table.add_row(vec![
+            format!("{}", consumer_offsets.consumers_count),
+            format!("{}", consumer_offsets.consumer_groups_count),
+            format!("{}", consumer_offsets.records_count),
+            format!("{}", consumer_offsets.size),
+            format!("{}", consumer_offsets.compactions_count),
+            consumer_offsets
+                .last_compacted_at
+                .map_or("-".to_string(), |timestamp| {
+                    timestamp.to_utc_string("%Y-%m-%d %H:%M:%S")
+                }),
+        ]);
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        if !partition.replicas.is_empty() {
+            let mut table = Table::new();
+
+            table.set_header(vec![
+                "Replica",
+                "Fetched Offset",
+                "Last Fetched",
+                "Last Caught Up",
+                "In Sync",
+            ]);
+            partition.replicas.iter().for_each(|replica| {
+                table.add_row(vec![
+                    format!("{}", replica.id),
+                    format!("{}", replica.fetched_offset),
+                    replica.last_fetched_at.to_utc_string("%Y-%m-%d %H:%M:%S"),
+                    replica
+                        .last_caught_up_at
+                        .map_or("-".to_string(), |timestamp| {
+                            timestamp.to_utc_string("%Y-%m-%d %H:%M:%S")
+                        }),
+                    format!("{}", replica.is_in_sync),
+                ]);
+            });
+
+            event!(target: PRINT_TARGET, Level::INFO, "{table}");
+        }
+
+        let mut table = Table::new();
+
+        table.set_header(vec![
+            "Start Offset",
+            "End Offset",
+            "Size",
+            "Messages",
+            "Closed",
+            "Created",
+            "Index Size",
+            "Indexes",
+        ]);
+        partition.segments.iter().for_each(|segment| {
+            table.add_row(vec![
+                format!("{}", segment.start_offset),
+                format!("{}", segment.end_offset),
+                format!("{}", segment.size),
+                format!("{}", segment.messages_count),
+                format!("{}", segment.is_closed),
+                segment.created_at.to_utc_string("%Y-%m-%d %H:%M:%S"),
+                format!("{}", segment.index_size),
+                format!("{}", segment.indexes_count),
+            ]);
+        });
+
+        event!(target: PRINT_TARGET, Level::INFO, "{table}");
+
+        Ok(())
+    }
+}
@@ -1,2 +1,6 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_messages_histogram;
+pub mod get_offset_by_timestamp;
+pub mod get_partition;
+pub mod seal_partition;
@@ -0,0 +1,73 @@
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::partitions::seal_partition::SealPartition;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SealPartitionCmd {
+    seal_partition: SealPartition,
+}
+
+impl SealPartitionCmd {
+    pub fn new(
+        stream_id: Identifier,
+        topic_id: Identifier,
+        partition_id: u32,
+        read_only: bool,
+    ) -> Self {
+        Self {
+            seal_partition: SealPartition {
+                stream_id,
+                topic_id,
+                partition_id,
+                read_only,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SealPartitionCmd {
+    fn explain(&self) -> String {
+        format!(
+            "seal partition with ID: {} for topic with ID: {} and stream with ID: {} (read-only: {})",
+            self.seal_partition.partition_id,
+            self.seal_partition.topic_id,
+            self.seal_partition.stream_id,
+            self.seal_partition.read_only
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        let sealed_partition = client
+            .seal_partition(
+                &self.seal_partition.stream_id,
+                &self.seal_partition.topic_id,
+                self.seal_partition.partition_id,
+                self.seal_partition.read_only,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem sealing partition with ID: {} for topic with ID: {} and stream with ID: {}",
+                    self.seal_partition.partition_id,
+                    self.seal_partition.topic_id,
+                    self.seal_partition.stream_id
+                )
+            })?;
+        print_json(&sealed_partition)?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Partition with ID: {} for topic with ID: {} and stream with ID: {} sealed at high watermark: {}, segments: {}",
+            sealed_partition.partition_id,
+            self.seal_partition.topic_id,
+            self.seal_partition.stream_id,
+            sealed_partition.high_watermark,
+            sealed_partition.segments_count
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
@@ -62,6 +62,7 @@ impl CliCommand for CreatePersonalAccessTokenCmd {
                     self.create_token.name
                 )
             })?;
+        print_json(&token)?;
 
         if self.store_token {
             let server_address = format!("iggy:{}", self.server_address);
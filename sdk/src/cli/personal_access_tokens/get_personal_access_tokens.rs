@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use anyhow::Context;
@@ -40,6 +40,7 @@ impl CliCommand for GetPersonalAccessTokensCmd {
             .get_personal_access_tokens()
             .await
             .with_context(|| String::from("Problem getting list of personal access tokens"))?;
+        print_json(&tokens)?;
 
         match self.output {
             GetPersonalAccessTokensOutput::Table => {
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::streams::create_stream::CreateStream;
 use anyhow::Context;
@@ -35,7 +35,7 @@ impl CliCommand for CreateStreamCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
+        let stream = client
             .create_stream(&self.create_stream.name, self.create_stream.stream_id)
             .await
             .with_context(|| {
@@ -45,6 +45,7 @@ impl CliCommand for CreateStreamCmd {
                     self.get_stream_id_info(),
                 )
             })?;
+        print_json(&stream)?;
 
         event!(target: PRINT_TARGET, Level::INFO,
             "Stream with name: {} and {} created",
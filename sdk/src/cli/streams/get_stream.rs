@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::streams::get_stream::GetStream;
@@ -35,6 +35,7 @@ impl CliCommand for GetStreamCmd {
                     self.get_stream.stream_id
                 )
             })?;
+        print_json(&stream)?;
 
         if stream.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Stream with ID: {} was not found", self.get_stream.stream_id);
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::streams::get_streams::GetStreams;
 use anyhow::Context;
@@ -12,14 +12,23 @@ pub enum GetStreamsOutput {
 }
 
 pub struct GetStreamsCmd {
-    _get_streams: GetStreams,
+    get_streams: GetStreams,
     output: GetStreamsOutput,
 }
 
 impl GetStreamsCmd {
-    pub fn new(output: GetStreamsOutput) -> Self {
+    pub fn new(
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<String>,
+        output: GetStreamsOutput,
+    ) -> Self {
         GetStreamsCmd {
-            _get_streams: GetStreams {},
+            get_streams: GetStreams {
+                offset,
+                limit,
+                name_prefix,
+            },
             output,
         }
     }
@@ -28,7 +37,7 @@ impl GetStreamsCmd {
 impl Default for GetStreamsCmd {
     fn default() -> Self {
         GetStreamsCmd {
-            _get_streams: GetStreams {},
+            get_streams: GetStreams::default(),
             output: GetStreamsOutput::Table,
         }
     }
@@ -46,9 +55,14 @@ impl CliCommand for GetStreamsCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let streams = client
-            .get_streams()
+            .get_streams_page(
+                self.get_streams.offset,
+                self.get_streams.limit,
+                self.get_streams.name_prefix.as_deref(),
+            )
             .await
             .with_context(|| String::from("Problem getting list of streams"))?;
+        print_json(&streams)?;
 
         if streams.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No streams found!");
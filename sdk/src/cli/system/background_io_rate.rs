@@ -0,0 +1,52 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::set_background_io_rate::SetBackgroundIoRate;
+use crate::utils::byte_size::IggyByteSize;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetBackgroundIoRateCmd {
+    set_background_io_rate: SetBackgroundIoRate,
+}
+
+impl SetBackgroundIoRateCmd {
+    pub fn new(max_rate: IggyByteSize) -> Self {
+        Self {
+            set_background_io_rate: SetBackgroundIoRate {
+                bytes_per_second: max_rate.as_bytes_u64(),
+            },
+        }
+    }
+
+    fn get_rate(&self) -> String {
+        IggyByteSize::from(self.set_background_io_rate.bytes_per_second)
+            .as_human_string_with_zero_as_unlimited()
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetBackgroundIoRateCmd {
+    fn explain(&self) -> String {
+        format!("set server background IO rate to: {}/s", self.get_rate())
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_background_io_rate(self.set_background_io_rate.bytes_per_second)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting server background IO rate to: {}/s",
+                    self.get_rate()
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Server background IO rate set to: {}/s",
+            self.get_rate()
+        );
+
+        Ok(())
+    }
+}
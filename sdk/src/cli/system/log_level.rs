@@ -0,0 +1,47 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::system::set_log_level::SetLogLevel;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetLogLevelCmd {
+    set_log_level: SetLogLevel,
+}
+
+impl SetLogLevelCmd {
+    pub fn new(filter: String) -> Self {
+        Self {
+            set_log_level: SetLogLevel { filter },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetLogLevelCmd {
+    fn explain(&self) -> String {
+        format!(
+            "set server log level with filter: {}",
+            self.set_log_level.filter
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_log_level(&self.set_log_level.filter)
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting server log level with filter: {}",
+                    self.set_log_level.filter
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Server log level set with filter: {}",
+            self.set_log_level.filter
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_me::GetMe;
 use anyhow::Context;
@@ -33,6 +33,7 @@ impl CliCommand for GetMeCmd {
             .get_me()
             .await
             .with_context(|| "Problem sending get_me command".to_owned())?;
+        print_json(&client_info)?;
 
         let mut table = Table::new();
 
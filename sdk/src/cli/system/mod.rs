@@ -1,3 +1,5 @@
+pub mod background_io_rate;
+pub mod log_level;
 pub mod login;
 pub mod logout;
 pub mod me;
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::system::get_stats::GetStats;
 use anyhow::Context;
@@ -54,6 +54,7 @@ impl CliCommand for GetStatsCmd {
             .get_stats()
             .await
             .with_context(|| "Problem sending get_stats command".to_owned())?;
+        print_json(&stats)?;
 
         let output = match self.output {
             GetStatsOutput::Table => {
@@ -112,6 +113,10 @@ impl CliCommand for GetStatsCmd {
                         .to_string()
                         .as_str(),
                 ]);
+                table.add_row(vec![
+                    "Failed Partitions Count",
+                    format!("{}", stats.failed_partitions_count).as_str(),
+                ]);
                 table.add_row(vec![
                     "Streams Count",
                     format!("{}", stats.streams_count).as_str(),
@@ -140,12 +145,39 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count",
                     format!("{}", stats.consumer_groups_count).as_str(),
                 ]);
+                table.add_row(vec![
+                    "Deleting Topics Count",
+                    format!("{}", stats.deleting_topics_count).as_str(),
+                ]);
+                table.add_row(vec![
+                    "Deleting Topics Size Bytes",
+                    stats
+                        .deleting_topics_size_bytes
+                        .as_bytes_u64()
+                        .to_string()
+                        .as_str(),
+                ]);
 
                 table.add_row(vec!["OS Name", stats.os_name.as_str()]);
                 table.add_row(vec!["OS Version", stats.os_version.as_str()]);
                 table.add_row(vec!["Kernel Version", stats.kernel_version.as_str()]);
 
-                table.to_string()
+                if stats.command_latencies.is_empty() {
+                    table.to_string()
+                } else {
+                    let mut latencies_table = Table::new();
+                    latencies_table.set_header(vec!["Command", "Count", "p50", "p95", "p99"]);
+                    for latency in stats.command_latencies.iter() {
+                        latencies_table.add_row(vec![
+                            latency.name.clone(),
+                            format!("{}", latency.count),
+                            latency.p50.as_human_time_string(),
+                            latency.p95.as_human_time_string(),
+                            latency.p99.as_human_time_string(),
+                        ]);
+                    }
+                    format!("{table}\n{latencies_table}")
+                }
             }
             GetStatsOutput::List => {
                 let mut list = Vec::new();
@@ -189,10 +221,32 @@ impl CliCommand for GetStatsCmd {
                     "Consumer Groups Count|{}",
                     stats.consumer_groups_count
                 ));
+                list.push(format!(
+                    "Deleting Topics Count|{}",
+                    stats.deleting_topics_count
+                ));
+                list.push(format!(
+                    "Deleting Topics Size Bytes|{}",
+                    stats.deleting_topics_size_bytes.as_bytes_u64()
+                ));
+                list.push(format!(
+                    "Failed Partitions Count|{}",
+                    stats.failed_partitions_count
+                ));
 
                 list.push(format!("OS Name|{}", stats.os_name));
                 list.push(format!("OS Version|{}", stats.os_version));
                 list.push(format!("Kernel Version|{}", stats.kernel_version));
+                for latency in stats.command_latencies.iter() {
+                    list.push(format!(
+                        "Command Latency|{}|count: {}, p50: {}, p95: {}, p99: {}",
+                        latency.name,
+                        latency.count,
+                        latency.p50.as_human_time_string(),
+                        latency.p95.as_human_time_string(),
+                        latency.p99.as_human_time_string()
+                    ));
+                }
 
                 list.join("\n")
             }
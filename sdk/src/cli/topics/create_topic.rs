@@ -1,9 +1,11 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
 use crate::topics::create_topic::CreateTopic;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -28,6 +30,8 @@ impl CreateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Self {
         Self {
             create_topic: CreateTopic {
@@ -39,6 +43,8 @@ impl CreateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
+                overrides,
             },
             message_expiry,
             max_topic_size,
@@ -61,8 +67,8 @@ impl CliCommand for CreateTopicCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
-            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry, self.create_topic.max_topic_size)
+        let topic = client
+            .create_topic(&self.create_topic.stream_id, &self.create_topic.name, self.create_topic.partitions_count, self.create_topic.compression_algorithm, self.create_topic.replication_factor, self.create_topic.topic_id, self.create_topic.message_expiry, self.create_topic.max_topic_size, self.create_topic.cleanup_policy, self.create_topic.overrides)
             .await
             .with_context(|| {
                 format!(
@@ -70,9 +76,10 @@ impl CliCommand for CreateTopicCmd {
                     self.create_topic.topic_id.unwrap_or(0), self.create_topic.name, self.create_topic.partitions_count, self.create_topic.stream_id
                 )
             })?;
+        print_json(&topic)?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with name: {}, {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {} created in stream with ID: {}",
+            "Topic with name: {}, {}, partitions count: {}, compression algorithm: {}, message expiry: {}, max topic size: {}, replication factor: {}, cleanup policy: {} created in stream with ID: {}",
             self.create_topic.name,
             self.get_topic_id_info(),
             self.create_topic.partitions_count,
@@ -80,6 +87,7 @@ impl CliCommand for CreateTopicCmd {
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor,
+            self.create_topic.cleanup_policy,
             self.create_topic.stream_id,
         );
 
@@ -95,12 +103,13 @@ impl fmt::Display for CreateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size;
         let replication_factor = self.replication_factor;
+        let cleanup_policy = &self.create_topic.cleanup_policy;
         let stream_id = &self.create_topic.stream_id;
 
         write!(
             f,
             "create topic with name: {topic_name}, {topic_id}, message expiry: {message_expiry}, compression algorithm: {compression_algorithm}, \
-            max topic size: {max_topic_size}, replication factor: {replication_factor} in stream with ID: {stream_id}",
+            max topic size: {max_topic_size}, replication factor: {replication_factor}, cleanup policy: {cleanup_policy} in stream with ID: {stream_id}",
         )
     }
 }
@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::topics::get_topic::GetTopic;
@@ -42,6 +42,7 @@ impl CliCommand for GetTopicCmd {
                     self.get_topic.topic_id, self.get_topic.stream_id
                 )
             })?;
+        print_json(&topic)?;
 
         if topic.is_none() {
             event!(target: PRINT_TARGET, Level::INFO, "Topic with ID: {} in stream {} was not found", self.get_topic.topic_id, self.get_topic.stream_id);
@@ -76,6 +77,11 @@ impl CliCommand for GetTopicCmd {
             "Max topic size",
             format!("{}", topic.max_topic_size).as_str(),
         ]);
+        table.add_row(vec![
+            "Cleanup policy",
+            topic.cleanup_policy.to_string().as_str(),
+        ]);
+        table.add_row(vec!["Read-only", topic.read_only.to_string().as_str()]);
         table.add_row(vec![
             "Topic message count",
             format!("{}", topic.messages_count).as_str(),
@@ -84,6 +90,12 @@ impl CliCommand for GetTopicCmd {
             "Partitions count",
             format!("{}", topic.partitions_count).as_str(),
         ]);
+        for entry in &topic.config {
+            table.add_row(vec![
+                format!("Config {}", entry.name).as_str(),
+                format!("{} ({})", entry.value, entry.source).as_str(),
+            ]);
+        }
 
         event!(target: PRINT_TARGET, Level::INFO,"{table}");
 
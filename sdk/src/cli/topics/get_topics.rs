@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::topics::get_topics::GetTopics;
@@ -31,9 +31,20 @@ pub struct GetTopicsCmd {
 }
 
 impl GetTopicsCmd {
-    pub fn new(stream_id: Identifier, output: GetTopicsOutput) -> Self {
+    pub fn new(
+        stream_id: Identifier,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<String>,
+        output: GetTopicsOutput,
+    ) -> Self {
         Self {
-            get_topics: GetTopics { stream_id },
+            get_topics: GetTopics {
+                stream_id,
+                offset,
+                limit,
+                name_prefix,
+            },
             output,
         }
     }
@@ -50,7 +61,12 @@ impl CliCommand for GetTopicsCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         let topics = client
-            .get_topics(&self.get_topics.stream_id)
+            .get_topics_page(
+                &self.get_topics.stream_id,
+                self.get_topics.offset,
+                self.get_topics.limit,
+                self.get_topics.name_prefix.as_deref(),
+            )
             .await
             .with_context(|| {
                 format!(
@@ -58,6 +74,7 @@ impl CliCommand for GetTopicsCmd {
                     self.get_topics.stream_id
                 )
             })?;
+        print_json(&topics)?;
 
         match self.output {
             GetTopicsOutput::Table => {
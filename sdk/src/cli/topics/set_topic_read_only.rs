@@ -0,0 +1,57 @@
+use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::client::Client;
+use crate::identifier::Identifier;
+use crate::topics::set_topic_read_only::SetTopicReadOnly;
+use anyhow::Context;
+use async_trait::async_trait;
+use tracing::{event, Level};
+
+pub struct SetTopicReadOnlyCmd {
+    set_topic_read_only: SetTopicReadOnly,
+}
+
+impl SetTopicReadOnlyCmd {
+    pub fn new(stream_id: Identifier, topic_id: Identifier, read_only: bool) -> Self {
+        Self {
+            set_topic_read_only: SetTopicReadOnly {
+                stream_id,
+                topic_id,
+                read_only,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand for SetTopicReadOnlyCmd {
+    fn explain(&self) -> String {
+        format!(
+            "set read-only: {} for topic with ID: {} in stream with ID: {}",
+            self.set_topic_read_only.read_only,
+            self.set_topic_read_only.topic_id,
+            self.set_topic_read_only.stream_id
+        )
+    }
+
+    async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
+        client
+            .set_topic_read_only(
+                &self.set_topic_read_only.stream_id,
+                &self.set_topic_read_only.topic_id,
+                self.set_topic_read_only.read_only,
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Problem setting read-only for topic with ID: {} in stream {}",
+                    self.set_topic_read_only.topic_id, self.set_topic_read_only.stream_id
+                )
+            })?;
+
+        event!(target: PRINT_TARGET, Level::INFO,
+            "Topic with ID: {} in stream with ID: {} set read-only: {}",
+            self.set_topic_read_only.topic_id, self.set_topic_read_only.stream_id, self.set_topic_read_only.read_only);
+
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@ use crate::client::Client;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::identifier::Identifier;
 use crate::topics::update_topic::UpdateTopic;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use anyhow::Context;
 use async_trait::async_trait;
@@ -18,6 +20,7 @@ pub struct UpdateTopicCmd {
 }
 
 impl UpdateTopicCmd {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         stream_id: Identifier,
         topic_id: Identifier,
@@ -26,6 +29,8 @@ impl UpdateTopicCmd {
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Self {
         Self {
             update_topic: UpdateTopic {
@@ -36,6 +41,8 @@ impl UpdateTopicCmd {
                 message_expiry,
                 max_topic_size,
                 replication_factor: Some(replication_factor),
+                cleanup_policy,
+                overrides,
             },
             message_expiry,
             max_topic_size,
@@ -52,7 +59,7 @@ impl CliCommand for UpdateTopicCmd {
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
         client
-            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size)
+            .update_topic(&self.update_topic.stream_id, &self.update_topic.topic_id, &self.update_topic.name, self.update_topic.compression_algorithm, self.replication_factor.into(), self.message_expiry, self.max_topic_size, self.update_topic.cleanup_policy, self.update_topic.overrides)
             .await
             .with_context(|| {
                 format!(
@@ -65,11 +72,12 @@ impl CliCommand for UpdateTopicCmd {
             })?;
 
         event!(target: PRINT_TARGET, Level::INFO,
-            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {} in stream with ID: {}",
+            "Topic with ID: {} updated name: {}, updated message expiry: {}, updated compression algorithm: {}, updated cleanup policy: {} in stream with ID: {}",
             self.update_topic.topic_id,
             self.update_topic.name,
             self.message_expiry,
             self.update_topic.compression_algorithm,
+            self.update_topic.cleanup_policy,
             self.update_topic.stream_id,
         );
 
@@ -85,13 +93,14 @@ impl fmt::Display for UpdateTopicCmd {
         let message_expiry = &self.message_expiry;
         let max_topic_size = &self.max_topic_size;
         let replication_factor = self.replication_factor;
+        let cleanup_policy = &self.update_topic.cleanup_policy;
         let stream_id = &self.update_topic.stream_id;
 
         write!(
             f,
             "update topic with ID: {topic_id}, name: {topic_name}, message expiry: \
             {message_expiry}, compression algorithm: {compression_algorithm}, max topic size: {max_topic_size}, replication \
-            factor: {replication_factor}, in stream with ID: {stream_id}",
+            factor: {replication_factor}, cleanup policy: {cleanup_policy}, in stream with ID: {stream_id}",
         )
     }
 }
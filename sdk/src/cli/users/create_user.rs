@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::models::permissions::Permissions;
 use crate::models::user_status::UserStatus;
@@ -39,7 +39,7 @@ impl CliCommand for CreateUserCmd {
     }
 
     async fn execute_cmd(&mut self, client: &dyn Client) -> anyhow::Result<(), anyhow::Error> {
-        client
+        let user = client
             .create_user(
                 &self.create_user.username,
                 &self.create_user.password,
@@ -53,6 +53,7 @@ impl CliCommand for CreateUserCmd {
                     self.create_user.username, self.create_user.password
                 )
             })?;
+        print_json(&user)?;
 
         event!(target: PRINT_TARGET, Level::INFO,
             "User with username: {} and password: {} created",
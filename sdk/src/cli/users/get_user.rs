@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::identifier::Identifier;
 use crate::models::permissions::{GlobalPermissions, StreamPermissions, TopicPermissions};
@@ -140,6 +140,7 @@ impl CliCommand for GetUserCmd {
             .get_user(&self.get_user.user_id)
             .await
             .with_context(|| format!("Problem getting user with ID: {}", self.get_user.user_id))?;
+        print_json(&user)?;
 
         if user.is_none() {
             event!(
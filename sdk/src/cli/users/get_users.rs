@@ -1,4 +1,4 @@
-use crate::cli_command::{CliCommand, PRINT_TARGET};
+use crate::cli_command::{print_json, CliCommand, PRINT_TARGET};
 use crate::client::Client;
 use crate::users::get_users::GetUsers;
 use anyhow::Context;
@@ -49,6 +49,7 @@ impl CliCommand for GetUsersCmd {
             .get_users()
             .await
             .with_context(|| String::from("Problem getting list of users"))?;
+        print_json(&users)?;
 
         if users.is_empty() {
             event!(target: PRINT_TARGET, Level::INFO, "No users found!");
@@ -1,8 +1,19 @@
 use crate::client::Client;
 use anyhow::{Error, Result};
 use async_trait::async_trait;
+use serde::Serialize;
+use tracing::{event, Level};
 
 pub static PRINT_TARGET: &str = "iggy::cli::output";
+/// Target of the command results printed as JSON, which replace the human-readable output when requested.
+pub static PRINT_JSON_TARGET: &str = "iggy::cli::json";
+
+/// Prints the result of the command as JSON, so that the scripts don't have to parse the human-readable output.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<(), Error> {
+    let json = serde_json::to_string_pretty(value)?;
+    event!(target: PRINT_JSON_TARGET, Level::INFO, "{json}");
+    Ok(())
+}
 
 #[async_trait]
 pub trait CliCommand {
@@ -1,35 +1,67 @@
+use crate::binary::retry_policy::RetryPolicy;
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::consumer::Consumer;
+use crate::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
+use crate::http::client::HttpClient;
+use crate::http::config::HttpClientConfig;
 use crate::identifier::Identifier;
-use crate::messages::poll_messages::PollingStrategy;
-use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::copy_messages::CopyRangeKind;
+use crate::messages::peek_messages::PeekKind;
+use crate::messages::poll_messages::{OutOfRangePolicy, PollingStrategy};
+use crate::messages::poll_messages_from_partitions::PartitionOffset;
+use crate::messages::send_messages::{AckLevel, Message, Partitioning};
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::cluster_metadata::ClusterMetadata;
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_info::ConsumerInfo;
+use crate::models::consumer_lag_info::ConsumerLagInfo;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
+use crate::models::copied_messages_info::CopiedMessagesInfo;
 use crate::models::identity_info::IdentityInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::messages_histogram::MessagesHistogram;
+use crate::models::node_status::NodeStatus;
+use crate::models::partition::PartitionDetails;
+use crate::models::partition_offsets_info::PartitionOffsetsInfo;
 use crate::models::permissions::Permissions;
 use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::replica_messages::ReplicaMessages;
+use crate::models::schema::Schema;
+use crate::models::sealed_partition_info::SealedPartitionInfo;
+use crate::models::session_info::SessionInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
+use crate::models::timestamp_offset_info::TimestampOffsetInfo;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
+use crate::quic::client::QuicClient;
+use crate::quic::config::{QuicClientConfig, QuicClientReconnectionConfig};
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::tcp::client::TcpClient;
 use crate::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::personal_access_token_expiry::PersonalAccessTokenExpiry;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
+use crate::utils::schema_type::SchemaType;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use async_broadcast::Receiver;
 use async_trait::async_trait;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::Arc;
 
 const CONNECTION_STRING_PREFIX: &str = "iggy://";
+const TCP_TRANSPORT: &str = "tcp";
+const QUIC_TRANSPORT: &str = "quic";
+const HTTP_TRANSPORT: &str = "http";
 
 #[derive(Debug)]
 pub(crate) struct ConnectionString {
@@ -61,6 +93,7 @@ pub trait Client:
     + StreamClient
     + TopicClient
     + PartitionClient
+    + SchemaClient
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
@@ -101,6 +134,15 @@ pub trait SystemClient {
     ///
     /// Authentication is required, and the permission to read the server info.
     async fn get_clients(&self) -> Result<Vec<ClientInfo>, IggyError>;
+    /// Get the info about all the active sessions of the currently connected clients,
+    /// including the time of connecting and the last command sent by the client.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_sessions(&self) -> Result<Vec<SessionInfo>, IggyError>;
+    /// Forcibly terminate the session of a specific client by unique ID, closing its connection.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn kill_session(&self, client_id: u32) -> Result<(), IggyError>;
     /// Ping the server to check if it's alive.
     async fn ping(&self) -> Result<(), IggyError>;
     async fn heartbeat_interval(&self) -> IggyDuration;
@@ -112,6 +154,40 @@ pub trait SystemClient {
         compression: SnapshotCompression,
         snapshot_types: Vec<SystemSnapshotType>,
     ) -> Result<Snapshot, IggyError>;
+    /// Rotate the server-side encryption key and return the version of the new key.
+    /// The new key is used for all the newly written data, while the existing data remains readable
+    /// and is re-encrypted with the new key in the background.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn rotate_encryption_key(&self) -> Result<u32, IggyError>;
+    /// Change the log filter of the server at runtime, e.g. to temporarily enable the trace logs of a specific module.
+    /// The filter is either a global level (e.g. `debug`) or the levels per module (e.g. `info,server::streaming::segments=trace`).
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_log_level(&self, filter: &str) -> Result<(), IggyError>;
+    /// Change the background IO budget of the server at runtime, i.e. the maximum total number of bytes
+    /// read and written per second by the retention, compaction, archiving and recovery tasks, where 0 means no limit.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn set_background_io_rate(&self, bytes_per_second: u64) -> Result<(), IggyError>;
+    /// Get the nodes (brokers) of the cluster and the leaders of the partitions of the topics visible to the user,
+    /// used to route the messages sent to and polled from the partition to the node leading it.
+    ///
+    /// Authentication is required.
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError>;
+    /// Get the state of the node and the number of the partitions it still leads, used to track the progress of draining it.
+    ///
+    /// Authentication is required, and the permission to read the server info.
+    async fn get_node_status(&self, node_id: u32) -> Result<NodeStatus, IggyError>;
+    /// Put the node in the draining state, so that it accepts no new partitions and transfers the leadership of its partitions
+    /// to the in-sync replicas as soon as they catch up. It can be invoked repeatedly to report the progress.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn drain_node(&self, node_id: u32) -> Result<NodeStatus, IggyError>;
+    /// Decommission the draining node which no longer leads any partitions, so that it can be safely removed from the cluster.
+    ///
+    /// Authentication is required, and the permission to manage the servers.
+    async fn decommission_node(&self, node_id: u32) -> Result<NodeStatus, IggyError>;
 }
 
 /// This trait defines the methods to interact with the user module.
@@ -202,6 +278,16 @@ pub trait StreamClient {
     ///
     /// Authentication is required, and the permission to read the streams.
     async fn get_streams(&self) -> Result<Vec<Stream>, IggyError>;
+    /// Get the info about the streams ordered by ID, skipping the `offset` streams and returning at most `limit` of them.
+    /// If the name prefix is specified, only the streams which names start with it are returned.
+    ///
+    /// Authentication is required, and the permission to read the streams.
+    async fn get_streams_page(
+        &self,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Stream>, IggyError>;
     /// Create a new stream.
     ///
     /// Authentication is required, and the permission to manage the streams.
@@ -240,6 +326,17 @@ pub trait TopicClient {
     ///
     /// Authentication is required, and the permission to read the topics.
     async fn get_topics(&self, stream_id: &Identifier) -> Result<Vec<Topic>, IggyError>;
+    /// Get the info about the topics ordered by ID, skipping the `offset` topics and returning at most `limit` of them.
+    /// If the name prefix is specified, only the topics which names start with it are returned.
+    ///
+    /// Authentication is required, and the permission to read the topics.
+    async fn get_topics_page(
+        &self,
+        stream_id: &Identifier,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Topic>, IggyError>;
     /// Create a new topic.
     ///
     /// Authentication is required, and the permission to manage the topics.
@@ -254,6 +351,8 @@ pub trait TopicClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<TopicDetails, IggyError>;
     /// Update a topic by unique ID or name.
     ///
@@ -267,6 +366,8 @@ pub trait TopicClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<(), IggyError>;
     /// Delete a topic by unique ID or name.
     ///
@@ -284,6 +385,17 @@ pub trait TopicClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Mark a topic by unique ID or name as read-only (or writable again).
+    ///
+    /// The messages sent to the read-only topic are rejected, while they can still be polled.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn set_topic_read_only(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        read_only: bool,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the partition module.
@@ -311,6 +423,103 @@ pub trait PartitionClient {
         topic_id: &Identifier,
         partitions_count: u32,
     ) -> Result<(), IggyError>;
+    /// Get the info about a specific partition of a topic by unique ID or name, including its segments.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Option<PartitionDetails>, IggyError>;
+    /// Get the low and high watermarks of the partitions for a topic by unique ID or name.
+    ///
+    /// The low watermark is the first offset still available after the retention or purge, and the high watermark is the offset of the next message to be appended.
+    /// If the partition ID is not specified, the offsets are returned for all the partitions.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_partition_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionOffsetsInfo>, IggyError>;
+    /// Get the offset of the first message in the partition with the timestamp (in microseconds) greater than or equal to the provided one, without fetching the messages.
+    ///
+    /// Returns `None` if there's no such message in the partition (yet).
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_offset_by_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        timestamp: u64,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError>;
+    /// Get the count and size of the messages in the partition aggregated per time bucket of the given size,
+    /// within the time range (in microseconds) from the inclusive start to the exclusive end, without fetching the messages.
+    ///
+    /// At most 10 000 buckets can be requested at once.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_messages_histogram(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError>;
+    /// Seal the partition of a topic by unique ID or name, closing its active segment at the current offset,
+    /// so that all the messages appended so far belong to the closed segments and can be archived or processed externally.
+    /// Optionally, the topic can be marked as read-only before sealing the partition, so that no more messages are appended.
+    ///
+    /// Authentication is required, and the permission to manage the topics.
+    async fn seal_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        read_only: bool,
+    ) -> Result<SealedPartitionInfo, IggyError>;
+}
+
+/// This trait defines the methods to interact with the schema registry module.
+#[async_trait]
+pub trait SchemaClient {
+    /// Get the schema registered for a topic by unique ID or name.
+    ///
+    /// If the version is not specified, the latest version of the schema is returned.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<Schema>, IggyError>;
+    /// Get all the versions of the schema registered for a topic by unique ID or name.
+    ///
+    /// Authentication is required, and the permission to read the topic.
+    async fn get_schemas(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<Schema>, IggyError>;
+    /// Register a new version of the schema for a topic by unique ID or name.
+    ///
+    /// If `validate` is set, the payloads of the messages sent to the topic will be validated against the latest schema (supported only by JSON Schema).
+    ///
+    /// Authentication is required, and the permission to manage the topic.
+    async fn register_schema(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+        validate: bool,
+    ) -> Result<Schema, IggyError>;
 }
 
 /// This trait defines the methods to interact with the messaging module.
@@ -330,6 +539,97 @@ pub trait MessageClient {
         count: u32,
         auto_commit: bool,
     ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages using the specified consumer and strategy from the specified stream and topic by unique IDs or names,
+    /// stopping when either the messages count or the maximum size of the messages in bytes is reached (`0` means no size limit).
+    /// At least one message is always returned if available, even if it exceeds the maximum size.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_with_max_bytes(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        max_bytes: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Poll given amount of messages from each of the specified partitions, starting at the provided offsets, using the specified consumer from the specified stream and topic by unique IDs or names.
+    /// The messages are returned in separate batches, one per partition, in the same order as the requested partitions.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn poll_messages_from_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        partitions: &[PartitionOffset],
+        out_of_range: OutOfRangePolicy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<PolledMessages>, IggyError>;
+    /// Peek given amount of the first, last or random messages of the specified partition for debugging purposes,
+    /// without storing or using any consumer offsets.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Get the latest message for the given key (message ID) in the specified partition of the compacted topic,
+    /// without storing or using any consumer offsets. The polled messages are empty if there's no message for the key,
+    /// or the latest one is a tombstone.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_message_by_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        key: u128,
+    ) -> Result<PolledMessages, IggyError>;
+    /// Fetch the messages of the specified partition as the follower replicating it from the leader, starting from the offset
+    /// of the next missing message, with the epoch of the last replicated message. If the follower's log has diverged
+    /// from the leader's one, the end offset of its epoch is returned instead of the messages, to truncate the log to it.
+    /// The messages are returned as stored, without being decrypted.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    #[allow(clippy::too_many_arguments)]
+    async fn fetch_replica_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        epoch: u32,
+        count: u32,
+    ) -> Result<ReplicaMessages, IggyError>;
+    /// Copy the range of messages (by offsets or timestamps) from the source partition to the target one,
+    /// e.g. to replay a subset of the traffic into a staging topic. The message IDs and headers are preserved,
+    /// and the origin of each message is recorded in the `copied-from` header.
+    ///
+    /// Authentication is required, and the permissions to poll the messages from the source topic and to send them to the target one.
+    #[allow(clippy::too_many_arguments)]
+    async fn copy_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        target_stream_id: &Identifier,
+        target_topic_id: &Identifier,
+        target_partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Result<CopiedMessagesInfo, IggyError>;
     /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names.
     ///
     /// Authentication is required, and the permission to send the messages.
@@ -340,6 +640,39 @@ pub trait MessageClient {
         partitioning: &Partitioning,
         messages: &mut [Message],
     ) -> Result<(), IggyError>;
+    /// Send messages using specified partitioning strategy to the given stream and topic by unique IDs or names,
+    /// with the acknowledgement level which determines when the server responds to the request.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn send_messages_with_ack(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        ack: AckLevel,
+        messages: &mut [Message],
+    ) -> Result<(), IggyError>;
+    /// Make the current client the exclusive producer of the given stream and topic by unique IDs or names, until it disconnects.
+    /// Any other client trying to acquire the topic or to send the messages to it is rejected with the `ProducerFenced` error.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn acquire_exclusive_producer(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError>;
+    /// Make the current client the exclusive producer of the given stream and topic by unique IDs or names, bound to the producer epoch.
+    /// The epoch `0` bumps the epoch for the new producer instance and fences the previous one, even if it's still connected,
+    /// while the previously returned epoch is resumed (e.g. after reconnecting), unless the topic was acquired with a newer epoch in the meantime.
+    /// Returns the current producer epoch.
+    ///
+    /// Authentication is required, and the permission to send the messages.
+    async fn init_producer_epoch(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        epoch: u32,
+    ) -> Result<u32, IggyError>;
     /// Force flush of the `unsaved_messages` buffer to disk, optionally fsyncing the data.
     #[allow(clippy::too_many_arguments)]
     async fn flush_unsaved_buffer(
@@ -375,6 +708,63 @@ pub trait ConsumerOffsetClient {
         topic_id: &Identifier,
         partition_id: Option<u32>,
     ) -> Result<Option<ConsumerOffsetInfo>, IggyError>;
+    /// Get the lag of a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    /// If the partition ID is not provided, the lag is returned for all the partitions of the topic.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<ConsumerLagInfo>, IggyError>;
+    /// Reset the stored offsets of a specific consumer or consumer group for the given stream and topic by unique IDs or names,
+    /// so that the consumption continues from the position specified by the strategy.
+    /// If no partition IDs are provided, the offsets are reset for all the partitions of the topic.
+    /// If `dry_run` is `true`, the resulting offsets are returned without being stored.
+    ///
+    /// Authentication is required, and the permission to store the consumer offsets.
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions: &[u32],
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError>;
+    /// Get the regular consumers with the stored offset in the partition of the given stream and topic by unique IDs or names,
+    /// along with their names if identified by name.
+    ///
+    /// Authentication is required, and the permission to poll the messages.
+    async fn get_consumers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Vec<ConsumerInfo>, IggyError>;
+    /// Delete the regular consumer by unique ID or name along with its stored offset and name from the partition of the given stream and topic by unique IDs or names.
+    ///
+    /// Authentication is required, and the permission to store the consumer offsets.
+    async fn delete_consumer(
+        &self,
+        consumer_id: &Identifier,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), IggyError>;
+    /// Delete the stored offsets of a specific consumer or consumer group for the given stream and topic by unique IDs or names.
+    /// If the partition ID is not provided, the offsets are deleted for all the partitions of the topic.
+    ///
+    /// Authentication is required, and the permission to store the consumer offsets.
+    async fn delete_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError>;
 }
 
 /// This trait defines the methods to interact with the consumer group module.
@@ -406,6 +796,7 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError>;
     /// Delete a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
@@ -417,6 +808,8 @@ pub trait ConsumerGroupClient {
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
     /// Join a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
+    /// The optional group instance ID makes the consumer a static member, which reclaims its previous partitions
+    /// without triggering the rebalance, if it rejoins the group within the session timeout (e.g. after a restart).
     ///
     /// Authentication is required, and the permission to read the streams or topics.
     async fn join_consumer_group(
@@ -424,6 +817,7 @@ pub trait ConsumerGroupClient {
         stream_id: &Identifier,
         topic_id: &Identifier,
         group_id: &Identifier,
+        group_instance_id: Option<&str>,
     ) -> Result<(), IggyError>;
     /// Leave a consumer group by unique ID or name for the given stream and topic by unique IDs or names.
     ///
@@ -434,6 +828,17 @@ pub trait ConsumerGroupClient {
         topic_id: &Identifier,
         group_id: &Identifier,
     ) -> Result<(), IggyError>;
+    /// Send the heartbeat to a consumer group by unique ID or name for the given stream and topic by unique IDs or names,
+    /// which keeps the membership of the client alive, and return the member along with the partitions assigned to it.
+    /// The member which hasn't sent the heartbeat within the session timeout configured on the server is removed from the group.
+    ///
+    /// Authentication is required, and the permission to read the streams or topics.
+    async fn heartbeat_consumer_group(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        group_id: &Identifier,
+    ) -> Result<ConsumerGroupMember, IggyError>;
 }
 
 impl FromStr for ConnectionString {
@@ -519,6 +924,11 @@ impl ConnectionString {
         let mut reconnection_interval = "1s".to_owned();
         let mut reestablish_after = "5s".to_owned();
         let mut heartbeat_interval = "5s".to_owned();
+        let mut transport = TCP_TRANSPORT.to_owned();
+        let mut connection_timeout = None;
+        let mut retry_policy = RetryPolicy::default();
+        let mut wire_checksum = false;
+        let mut connection_pool_size = 1;
 
         for option in options {
             let option_parts = option.split('=').collect::<Vec<&str>>();
@@ -544,12 +954,50 @@ impl ConnectionString {
                 "heartbeat_interval" => {
                     heartbeat_interval = option_parts[1].to_string();
                 }
+                "transport" => {
+                    transport = option_parts[1].to_string();
+                }
+                "retry_attempts" => {
+                    retry_policy.max_attempts = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
+                "retry_backoff" => {
+                    retry_policy.initial_backoff = IggyDuration::from_str(option_parts[1])
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
+                "idempotent_producer" => {
+                    retry_policy.idempotent_producer = option_parts[1] == "true";
+                }
+                "wire_checksum" => {
+                    wire_checksum = option_parts[1] == "true";
+                }
+                "connection_pool_size" => {
+                    connection_pool_size = option_parts[1]
+                        .parse()
+                        .map_err(|_| IggyError::InvalidConnectionString)?;
+                }
+                "connection_timeout" => {
+                    connection_timeout = Some(
+                        IggyDuration::from_str(option_parts[1])
+                            .map_err(|_| IggyError::InvalidConnectionString)?,
+                    );
+                }
                 _ => {
                     return Err(IggyError::InvalidConnectionString);
                 }
             }
         }
+        if ![TCP_TRANSPORT, QUIC_TRANSPORT, HTTP_TRANSPORT].contains(&transport.as_str()) {
+            return Err(IggyError::InvalidConnectionString);
+        }
+
         Ok(ConnectionStringOptions {
+            transport,
+            connection_timeout,
+            retry_policy,
+            wire_checksum,
+            connection_pool_size,
             tls_enabled,
             tls_domain,
             heartbeat_interval: IggyDuration::from_str(heartbeat_interval.as_str())
@@ -567,10 +1015,32 @@ impl ConnectionString {
             },
         })
     }
+
+    /// Returns the client for the transport selected in the connection string, TCP by default.
+    pub(crate) fn into_client(self) -> Result<Box<dyn Client>, IggyError> {
+        let client: Box<dyn Client> = match self.options.transport.as_str() {
+            QUIC_TRANSPORT => Box::new(QuicClient::create(Arc::new(self.try_into()?))?),
+            HTTP_TRANSPORT => Box::new(HttpClient::create(Arc::new(self.try_into()?))?),
+            _ => Box::new(TcpClient::create(Arc::new(self.try_into()?))?),
+        };
+        Ok(client)
+    }
+
+    fn ensure_transport(&self, transport: &str) -> Result<(), IggyError> {
+        if self.options.transport != transport {
+            return Err(IggyError::InvalidConnectionString);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
 struct ConnectionStringOptions {
+    transport: String,
+    connection_timeout: Option<IggyDuration>,
+    retry_policy: RetryPolicy,
+    wire_checksum: bool,
+    connection_pool_size: u32,
     tls_enabled: bool,
     tls_domain: String,
     reconnection: TcpClientReconnectionConfig,
@@ -580,6 +1050,11 @@ struct ConnectionStringOptions {
 impl Default for ConnectionStringOptions {
     fn default() -> Self {
         ConnectionStringOptions {
+            transport: TCP_TRANSPORT.to_string(),
+            connection_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            wire_checksum: false,
+            connection_pool_size: 1,
             tls_enabled: false,
             tls_domain: "".to_string(),
             reconnection: Default::default(),
@@ -588,17 +1063,79 @@ impl Default for ConnectionStringOptions {
     }
 }
 
-impl From<ConnectionString> for TcpClientConfig {
-    fn from(connection_string: ConnectionString) -> Self {
-        TcpClientConfig {
+impl TryFrom<ConnectionString> for TcpClientConfig {
+    type Error = IggyError;
+
+    fn try_from(connection_string: ConnectionString) -> Result<Self, Self::Error> {
+        connection_string.ensure_transport(TCP_TRANSPORT)?;
+        Ok(TcpClientConfig {
             server_address: connection_string.server_address,
             auto_login: connection_string.auto_login,
             tls_enabled: connection_string.options.tls_enabled,
             tls_domain: connection_string.options.tls_domain,
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             reconnection: connection_string.options.reconnection,
             heartbeat_interval: connection_string.options.heartbeat_interval,
-        }
+            connection_timeout: connection_string.options.connection_timeout,
+            retry_policy: connection_string.options.retry_policy,
+            wire_checksum: connection_string.options.wire_checksum,
+            connection_pool_size: connection_string.options.connection_pool_size,
+        })
+    }
+}
+
+impl TryFrom<ConnectionString> for QuicClientConfig {
+    type Error = IggyError;
+
+    /// The QUIC connection is always encrypted, and the TLS domain (if set) is used as the server name.
+    fn try_from(connection_string: ConnectionString) -> Result<Self, Self::Error> {
+        connection_string.ensure_transport(QUIC_TRANSPORT)?;
+        let default_config = QuicClientConfig::default();
+        let options = connection_string.options;
+        Ok(QuicClientConfig {
+            server_address: connection_string.server_address,
+            server_name: if options.tls_domain.is_empty() {
+                default_config.server_name
+            } else {
+                options.tls_domain
+            },
+            auto_login: connection_string.auto_login,
+            reconnection: QuicClientReconnectionConfig {
+                enabled: options.reconnection.enabled,
+                max_retries: options.reconnection.max_retries,
+                interval: options.reconnection.interval,
+                reestablish_after: options.reconnection.reestablish_after,
+            },
+            heartbeat_interval: options.heartbeat_interval,
+            connection_timeout: options.connection_timeout,
+            retry_policy: options.retry_policy,
+            wire_checksum: options.wire_checksum,
+            ..default_config
+        })
+    }
+}
+
+impl TryFrom<ConnectionString> for HttpClientConfig {
+    type Error = IggyError;
+
+    /// The HTTPS is used if the TLS is enabled, and the reconnection retries (if limited) are used
+    /// as the number of retries on transient errors.
+    fn try_from(connection_string: ConnectionString) -> Result<Self, Self::Error> {
+        connection_string.ensure_transport(HTTP_TRANSPORT)?;
+        let default_config = HttpClientConfig::default();
+        let options = connection_string.options;
+        let scheme = if options.tls_enabled { "https" } else { "http" };
+        Ok(HttpClientConfig {
+            api_url: format!("{scheme}://{}", connection_string.server_address),
+            retries: options
+                .reconnection
+                .max_retries
+                .unwrap_or(default_config.retries),
+            auto_login: connection_string.auto_login,
+            connection_timeout: options.connection_timeout,
+        })
     }
 }
 
@@ -705,4 +1242,101 @@ mod tests {
             IggyDuration::from_str(heartbeat_interval).unwrap()
         );
     }
+
+    #[test]
+    fn connection_string_with_transport_should_be_parsed_correctly() {
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user1:secret@localhost:8080?transport=quic&connection_timeout=3s"
+        );
+        let connection_string = ConnectionString::new(&value).unwrap();
+        assert_eq!(connection_string.options.transport, QUIC_TRANSPORT);
+        assert_eq!(
+            connection_string.options.connection_timeout,
+            Some(IggyDuration::from_str("3s").unwrap())
+        );
+
+        let value = format!("{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090");
+        let connection_string = ConnectionString::new(&value).unwrap();
+        assert_eq!(connection_string.options.transport, TCP_TRANSPORT);
+        assert!(connection_string.options.connection_timeout.is_none());
+    }
+
+    #[test]
+    fn connection_string_with_wire_checksum_should_be_parsed_correctly() {
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user1:secret@localhost:8080?transport=quic&wire_checksum=true"
+        );
+        let config = QuicClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert!(config.wire_checksum);
+
+        let value = format!("{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090");
+        let config = TcpClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert!(!config.wire_checksum);
+    }
+
+    #[test]
+    fn connection_string_with_connection_pool_size_should_be_parsed_correctly() {
+        let value =
+            format!("{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090?connection_pool_size=4");
+        let config = TcpClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert_eq!(config.connection_pool_size, 4);
+
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090?connection_pool_size=many"
+        );
+        assert!(ConnectionString::new(&value).is_err());
+    }
+
+    #[test]
+    fn connection_string_with_unknown_transport_should_fail() {
+        let value = format!("{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090?transport=udp");
+        let connection_string = ConnectionString::new(&value);
+        assert!(connection_string.is_err());
+    }
+
+    #[test]
+    fn connection_string_should_be_converted_to_config_of_selected_transport_only() {
+        let value = format!("{CONNECTION_STRING_PREFIX}user1:secret@localhost:8080?transport=quic&tls_domain=iggy.rs&reconnection_retries=5");
+        let config = QuicClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert_eq!(config.server_address, "localhost:8080");
+        assert_eq!(config.server_name, "iggy.rs");
+        assert_eq!(config.reconnection.max_retries, Some(5));
+        assert!(TcpClientConfig::try_from(ConnectionString::new(&value).unwrap()).is_err());
+
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user1:secret@localhost:3000?transport=http&tls=true&reconnection_retries=5&connection_timeout=1s"
+        );
+        let config = HttpClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert_eq!(config.api_url, "https://localhost:3000");
+        assert_eq!(config.retries, 5);
+        assert_eq!(
+            config.connection_timeout,
+            Some(IggyDuration::from_str("1s").unwrap())
+        );
+        assert_eq!(
+            config.auto_login,
+            AutoLogin::Enabled(Credentials::UsernamePassword(
+                "user1".to_string(),
+                "secret".to_string()
+            ))
+        );
+        assert!(QuicClientConfig::try_from(ConnectionString::new(&value).unwrap()).is_err());
+    }
+
+    #[test]
+    fn connection_string_with_retry_policy_should_be_parsed_correctly() {
+        let value = format!(
+            "{CONNECTION_STRING_PREFIX}user1:secret@localhost:8090?retry_attempts=5&retry_backoff=50ms&idempotent_producer=true"
+        );
+        let config = TcpClientConfig::try_from(ConnectionString::new(&value).unwrap()).unwrap();
+        assert_eq!(
+            config.retry_policy,
+            RetryPolicy {
+                max_attempts: 5,
+                initial_backoff: IggyDuration::from_str("50ms").unwrap(),
+                idempotent_producer: true,
+                ..RetryPolicy::default()
+            }
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::binary::retry_policy::RetryPolicy;
 use crate::client::{AutoLogin, Client, Credentials};
 use crate::client_error::ClientError;
 #[allow(deprecated)]
@@ -98,12 +99,17 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    connection_timeout: None,
+                    retry_policy: RetryPolicy::default(),
+                    wire_checksum: false,
                 }));
             }
             HTTP_TRANSPORT => {
                 config.http = Some(Arc::new(HttpClientConfig {
                     api_url: args.http_api_url,
                     retries: args.http_retries,
+                    auto_login: AutoLogin::Disabled,
+                    connection_timeout: None,
                 }));
             }
             TCP_TRANSPORT => {
@@ -112,6 +118,8 @@ impl ClientProviderConfig {
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
                     tls_ca_file: args.tcp_tls_ca_file,
+                    tls_client_cert_file: args.tcp_tls_client_cert_file,
+                    tls_client_key_file: args.tcp_tls_client_key_file,
                     heartbeat_interval: IggyDuration::from_str(&args.tcp_heartbeat_interval)
                         .unwrap(),
                     reconnection: TcpClientReconnectionConfig {
@@ -131,6 +139,10 @@ impl ClientProviderConfig {
                     } else {
                         AutoLogin::Disabled
                     },
+                    connection_timeout: None,
+                    retry_policy: RetryPolicy::default(),
+                    wire_checksum: false,
+                    connection_pool_size: 1,
                 }));
             }
             _ => return Err(ClientError::InvalidTransport(config.transport.clone())),
@@ -1,4 +1,8 @@
 use crate::client::Client;
+use crate::clients::consumer_group_coordinator::{
+    ConsumerGroupCoordinator, ConsumerGroupRebalanceListener,
+};
+use crate::clients::subscription::OffsetCommitter;
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
@@ -9,13 +13,14 @@ use crate::models::messages::{PolledMessage, PolledMessages};
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::crypto::Encryptor;
 use crate::utils::duration::IggyDuration;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 use crate::utils::timestamp::IggyTimestamp;
 use bytes::Bytes;
 use dashmap::DashMap;
 use futures::Stream;
 use futures_util::{FutureExt, StreamExt};
 use std::collections::VecDeque;
-use std::future::Future;
+use std::future::{poll_fn, Future};
 use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use std::sync::Arc;
@@ -76,9 +81,12 @@ pub struct IggyConsumer {
     auto_commit_after_polling: bool,
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
+    rebalance_strategy: RebalanceStrategy,
+    group_instance_id: Option<String>,
     last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
     last_consumed_offsets: Arc<DashMap<u32, AtomicU64>>,
     current_offsets: Arc<DashMap<u32, AtomicU64>>,
+    partition_epochs: Arc<DashMap<u32, AtomicU32>>,
     poll_future: Option<PollMessagesFuture>,
     buffered_messages: VecDeque<PolledMessage>,
     encryptor: Option<Arc<dyn Encryptor>>,
@@ -89,6 +97,9 @@ pub struct IggyConsumer {
     last_polled_at: Arc<AtomicU64>,
     current_partition_id: Arc<AtomicU32>,
     retry_interval: IggyDuration,
+    coordinator: Arc<ConsumerGroupCoordinator>,
+    heartbeat_interval: IggyDuration,
+    shutdown: Arc<AtomicBool>,
 }
 
 impl IggyConsumer {
@@ -106,26 +117,52 @@ impl IggyConsumer {
         auto_commit: AutoCommit,
         auto_join_consumer_group: bool,
         create_consumer_group_if_not_exists: bool,
+        rebalance_strategy: RebalanceStrategy,
+        group_instance_id: Option<String>,
         encryptor: Option<Arc<dyn Encryptor>>,
         retry_interval: IggyDuration,
+        rebalance_listener: Option<Arc<dyn ConsumerGroupRebalanceListener>>,
+        heartbeat_interval: IggyDuration,
     ) -> Self {
         let (store_offset_sender, _) = flume::unbounded();
+        let consumer = Arc::new(consumer);
+        let stream_id = Arc::new(stream_id);
+        let topic_id = Arc::new(topic_id);
+        let joined_consumer_group = Arc::new(AtomicBool::new(false));
+        let last_stored_offsets = Arc::new(DashMap::new());
+        let last_consumed_offsets = Arc::new(DashMap::new());
+        let coordinator = Arc::new(ConsumerGroupCoordinator::new(
+            client.clone(),
+            consumer.clone(),
+            consumer_name.clone(),
+            stream_id.clone(),
+            topic_id.clone(),
+            create_consumer_group_if_not_exists,
+            rebalance_strategy,
+            group_instance_id.clone(),
+            auto_commit != AutoCommit::Disabled,
+            joined_consumer_group.clone(),
+            last_consumed_offsets.clone(),
+            last_stored_offsets.clone(),
+            rebalance_listener,
+        ));
         Self {
             initialized: false,
             is_consumer_group: consumer.kind == ConsumerKind::ConsumerGroup,
-            joined_consumer_group: Arc::new(AtomicBool::new(false)),
+            joined_consumer_group,
             can_poll: Arc::new(AtomicBool::new(true)),
             client,
             consumer_name,
-            consumer: Arc::new(consumer),
-            stream_id: Arc::new(stream_id),
-            topic_id: Arc::new(topic_id),
+            consumer,
+            stream_id,
+            topic_id,
             partition_id,
             polling_strategy,
             poll_interval_micros: polling_interval.map_or(0, |interval| interval.as_micros()),
-            last_stored_offsets: Arc::new(DashMap::new()),
-            last_consumed_offsets: Arc::new(DashMap::new()),
+            last_stored_offsets,
+            last_consumed_offsets,
             current_offsets: Arc::new(DashMap::new()),
+            partition_epochs: Arc::new(DashMap::new()),
             poll_future: None,
             batch_size,
             auto_commit,
@@ -136,6 +173,8 @@ impl IggyConsumer {
             ),
             auto_join_consumer_group,
             create_consumer_group_if_not_exists,
+            rebalance_strategy,
+            group_instance_id,
             buffered_messages: VecDeque::new(),
             encryptor,
             store_offset_sender,
@@ -159,6 +198,9 @@ impl IggyConsumer {
             last_polled_at: Arc::new(AtomicU64::new(0)),
             current_partition_id: Arc::new(AtomicU32::new(0)),
             retry_interval,
+            coordinator,
+            heartbeat_interval,
+            shutdown: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -182,6 +224,42 @@ impl IggyConsumer {
         self.current_partition_id.load(ORDERING)
     }
 
+    /// Returns the partitions of the consumer group assigned to the consumer, once the assignment has been received.
+    pub fn assigned_partitions(&self) -> Vec<u32> {
+        self.coordinator.assigned_partitions().unwrap_or_default()
+    }
+
+    /// Returns the committer storing the offsets of the messages acknowledged by the subscription,
+    /// which tracks their completion if the messages are processed in parallel.
+    pub(crate) fn offset_committer(&self, parallel: bool) -> OffsetCommitter {
+        OffsetCommitter {
+            client: self.client.clone(),
+            consumer: self.consumer.clone(),
+            stream_id: self.stream_id.clone(),
+            topic_id: self.topic_id.clone(),
+            last_stored_offsets: self.last_stored_offsets.clone(),
+            tracker: parallel.then(Default::default),
+        }
+    }
+
+    pub(crate) fn retry_interval(&self) -> IggyDuration {
+        self.retry_interval
+    }
+
+    /// Waits until the pending poll request (if any) completes, discarding the polled messages,
+    /// so that the request isn't aborted midway on the connection shared with the other requests.
+    pub(crate) async fn complete_pending_poll(&mut self) {
+        poll_fn(|cx| self.poll_pending(cx)).await;
+        self.poll_future = None;
+    }
+
+    fn poll_pending(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        match self.poll_future.as_mut() {
+            Some(future) => future.poll_unpin(cx).map(|_| ()),
+            None => Poll::Ready(()),
+        }
+    }
+
     /// Stores the consumer offset on the server either for the current partition or the provided partition ID.
     pub async fn store_offset(
         &self,
@@ -233,6 +311,10 @@ impl IggyConsumer {
 
         self.subscribe_events().await;
         self.init_consumer_group().await?;
+        if self.is_consumer_group && self.auto_join_consumer_group {
+            self.coordinator
+                .send_heartbeats_in_background(self.heartbeat_interval, self.shutdown.clone());
+        }
 
         match self.auto_commit {
             AutoCommit::Interval(interval) => self.store_offsets_in_background(interval),
@@ -268,7 +350,23 @@ impl IggyConsumer {
         Ok(())
     }
 
-    async fn store_consumer_offset(
+    /// Shuts down the consumer, which stops polling the messages and leaves the consumer group (if any),
+    /// once the offsets consumed so far have been committed (unless the auto-commit is disabled).
+    pub async fn shutdown(&mut self) -> Result<(), IggyError> {
+        if self.shutdown.swap(true, ORDERING) {
+            return Ok(());
+        }
+
+        self.can_poll.store(false, ORDERING);
+        self.buffered_messages.clear();
+        if !self.is_consumer_group {
+            return Ok(());
+        }
+
+        self.coordinator.leave().await
+    }
+
+    pub(crate) async fn store_consumer_offset(
         client: &IggySharedMut<Box<dyn Client>>,
         consumer: &Consumer,
         stream_id: &Identifier,
@@ -336,6 +434,17 @@ impl IggyConsumer {
         });
     }
 
+    /// Stores the epoch of the partition and returns true if it has changed since the previous poll, i.e. the log was rewritten.
+    fn update_partition_epoch(&self, partition_id: u32, epoch: u32) -> bool {
+        if let Some(partition_epoch) = self.partition_epochs.get(&partition_id) {
+            return partition_epoch.swap(epoch, ORDERING) != epoch;
+        }
+
+        self.partition_epochs
+            .insert(partition_id, AtomicU32::new(epoch));
+        false
+    }
+
     fn send_store_offset(&self, partition_id: u32, offset: u64) {
         if let Err(error) = self.store_offset_sender.send((partition_id, offset)) {
             error!("Failed to send offset to store: {error}");
@@ -355,6 +464,8 @@ impl IggyConsumer {
         Self::initialize_consumer_group(
             self.client.clone(),
             self.create_consumer_group_if_not_exists,
+            self.rebalance_strategy,
+            self.group_instance_id.as_deref(),
             self.stream_id.clone(),
             self.topic_id.clone(),
             self.consumer.clone(),
@@ -376,18 +487,25 @@ impl IggyConsumer {
         let can_join_consumer_group = is_consumer_group && self.auto_join_consumer_group;
         let client = self.client.clone();
         let create_consumer_group_if_not_exists = self.create_consumer_group_if_not_exists;
+        let rebalance_strategy = self.rebalance_strategy;
+        let group_instance_id = self.group_instance_id.clone();
         let stream_id = self.stream_id.clone();
         let topic_id = self.topic_id.clone();
         let consumer = self.consumer.clone();
         let consumer_name = self.consumer_name.clone();
         let can_poll = self.can_poll.clone();
         let joined_consumer_group = self.joined_consumer_group.clone();
+        let shutdown = self.shutdown.clone();
         let mut reconnected = false;
         let mut disconnected = false;
 
         tokio::spawn(async move {
             while let Some(event) = receiver.next().await {
                 trace!("Received diagnostic event: {event}");
+                if shutdown.load(ORDERING) {
+                    break;
+                }
+
                 match event {
                     DiagnosticEvent::Shutdown => {
                         warn!("Consumer has been shutdown");
@@ -437,6 +555,8 @@ impl IggyConsumer {
                         if let Err(error) = Self::initialize_consumer_group(
                             client.clone(),
                             create_consumer_group_if_not_exists,
+                            rebalance_strategy,
+                            group_instance_id.as_deref(),
                             stream_id.clone(),
                             topic_id.clone(),
                             consumer.clone(),
@@ -478,6 +598,7 @@ impl IggyConsumer {
         let retry_interval = self.retry_interval;
         let last_stored_offset = self.last_stored_offsets.clone();
         let last_consumed_offset = self.last_consumed_offsets.clone();
+        let partition_epochs = self.partition_epochs.clone();
 
         async move {
             if interval > 0 {
@@ -506,11 +627,21 @@ impl IggyConsumer {
                 .await;
 
             if let Ok(mut polled_messages) = polled_messages {
+                let partition_id = polled_messages.partition_id;
+                let epoch = polled_messages.epoch;
+                if partition_epochs
+                    .get(&partition_id)
+                    .is_some_and(|partition_epoch| partition_epoch.load(ORDERING) != epoch)
+                {
+                    warn!("The log of partition ID: {partition_id}, topic: {topic_id}, stream: {stream_id} was rewritten (epoch: {epoch}), resynchronizing the offsets of consumer: {consumer}");
+                    last_consumed_offset.remove(&partition_id);
+                    last_stored_offset.remove(&partition_id);
+                }
+
                 if polled_messages.messages.is_empty() {
                     return Ok(polled_messages);
                 }
 
-                let partition_id = polled_messages.partition_id;
                 let consumed_offset;
                 let has_consumed_offset;
                 if let Some(offset_entry) = last_consumed_offset.get(&partition_id) {
@@ -531,6 +662,8 @@ impl IggyConsumer {
                             messages: EMPTY_MESSAGES,
                             current_offset: polled_messages.current_offset,
                             partition_id,
+                            epoch,
+                            next_offset: None,
                         });
                     }
                 }
@@ -584,6 +717,8 @@ impl IggyConsumer {
                         messages: EMPTY_MESSAGES,
                         current_offset: polled_messages.current_offset,
                         partition_id,
+                        epoch,
+                        next_offset: None,
                     });
                 }
 
@@ -620,9 +755,12 @@ impl IggyConsumer {
         sleep(Duration::from_micros(remaining)).await;
     }
 
-    async fn initialize_consumer_group(
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn initialize_consumer_group(
         client: IggySharedMut<Box<dyn Client>>,
         create_consumer_group_if_not_exists: bool,
+        rebalance_strategy: RebalanceStrategy,
+        group_instance_id: Option<&str>,
         stream_id: Arc<Identifier>,
         topic_id: Arc<Identifier>,
         consumer: Arc<Consumer>,
@@ -656,13 +794,13 @@ impl IggyConsumer {
 
             info!("Creating consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}");
             client
-                .create_consumer_group(&stream_id, &topic_id, &name, id)
+                .create_consumer_group(&stream_id, &topic_id, &name, id, rebalance_strategy)
                 .await?;
         }
 
         info!("Joining consumer group: {consumer_group_id} for topic: {topic_id}, stream: {stream_id}",);
         if let Err(error) = client
-            .join_consumer_group(&stream_id, &topic_id, &consumer_group_id)
+            .join_consumer_group(&stream_id, &topic_id, &consumer_group_id, group_instance_id)
             .await
         {
             joined_consumer_group.store(false, ORDERING);
@@ -699,6 +837,14 @@ impl Stream for IggyConsumer {
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let partition_id = self.current_partition_id.load(ORDERING);
+        if self.is_consumer_group
+            && !self.buffered_messages.is_empty()
+            && !self.coordinator.is_assigned(partition_id)
+        {
+            trace!("Partition ID: {partition_id} has been revoked, dropping the buffered messages");
+            self.buffered_messages.clear();
+        }
+
         if let Some(message) = self.buffered_messages.pop_front() {
             {
                 if let Some(last_consumed_offset_entry) =
@@ -752,11 +898,20 @@ impl Stream for IggyConsumer {
                 Poll::Ready(Ok(mut polled_messages)) => {
                     let partition_id = polled_messages.partition_id;
                     self.current_partition_id.store(partition_id, ORDERING);
+                    if self.update_partition_epoch(partition_id, polled_messages.epoch)
+                        && self.polling_strategy.kind == PollingKind::Offset
+                    {
+                        self.polling_strategy = PollingStrategy::offset(0);
+                    }
                     if polled_messages.messages.is_empty() {
                         self.poll_future = Some(Box::pin(self.create_poll_messages_future()));
                     } else {
                         if let Some(ref encryptor) = self.encryptor {
                             for message in &mut polled_messages.messages {
+                                if message.is_tombstone() {
+                                    continue;
+                                }
+
                                 let payload = encryptor.decrypt(&message.payload);
                                 if payload.is_err() {
                                     self.poll_future = None;
@@ -840,8 +995,12 @@ pub struct IggyConsumerBuilder {
     auto_commit: AutoCommit,
     auto_join_consumer_group: bool,
     create_consumer_group_if_not_exists: bool,
+    rebalance_strategy: RebalanceStrategy,
+    group_instance_id: Option<String>,
     encryptor: Option<Arc<dyn Encryptor>>,
     retry_interval: IggyDuration,
+    rebalance_listener: Option<Arc<dyn ConsumerGroupRebalanceListener>>,
+    heartbeat_interval: IggyDuration,
 }
 
 impl IggyConsumerBuilder {
@@ -871,9 +1030,13 @@ impl IggyConsumerBuilder {
             ),
             auto_join_consumer_group: true,
             create_consumer_group_if_not_exists: true,
+            rebalance_strategy: RebalanceStrategy::default(),
+            group_instance_id: None,
             encryptor,
             polling_interval,
             retry_interval: IggyDuration::ONE_SECOND,
+            rebalance_listener: None,
+            heartbeat_interval: IggyDuration::ONE_SECOND,
         }
     }
 
@@ -945,6 +1108,25 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the rebalance strategy of the consumer group, used if it's created by the consumer.
+    pub fn rebalance_strategy(self, rebalance_strategy: RebalanceStrategy) -> Self {
+        Self {
+            rebalance_strategy,
+            ..self
+        }
+    }
+
+    /// Sets the group instance ID, which makes the consumer a static member of the consumer group.
+    /// The static member reclaims its previous partitions without triggering the rebalance,
+    /// if it rejoins the group within the session timeout (e.g. after a restart),
+    /// which is why it doesn't leave the group on shutdown.
+    pub fn group_instance_id(self, group_instance_id: String) -> Self {
+        Self {
+            group_instance_id: Some(group_instance_id),
+            ..self
+        }
+    }
+
     /// Sets the polling interval for messages.
     pub fn poll_interval(self, interval: IggyDuration) -> Self {
         Self {
@@ -985,6 +1167,22 @@ impl IggyConsumerBuilder {
         }
     }
 
+    /// Sets the listener notified about the partitions of the consumer group assigned to and revoked from the consumer.
+    pub fn rebalance_listener(self, listener: Arc<dyn ConsumerGroupRebalanceListener>) -> Self {
+        Self {
+            rebalance_listener: Some(listener),
+            ..self
+        }
+    }
+
+    /// Sets the interval of the consumer group heartbeats, which keep the membership alive and receive the assigned partitions.
+    pub fn heartbeat_interval(self, interval: IggyDuration) -> Self {
+        Self {
+            heartbeat_interval: interval,
+            ..self
+        }
+    }
+
     pub fn build(self) -> IggyConsumer {
         IggyConsumer::new(
             self.client,
@@ -999,8 +1197,12 @@ impl IggyConsumerBuilder {
             self.auto_commit,
             self.auto_join_consumer_group,
             self.create_consumer_group_if_not_exists,
+            self.rebalance_strategy,
+            self.group_instance_id,
             self.encryptor,
             self.retry_interval,
+            self.rebalance_listener,
+            self.heartbeat_interval,
         )
     }
 }
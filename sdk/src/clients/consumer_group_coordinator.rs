@@ -0,0 +1,294 @@
+use crate::client::Client;
+use crate::clients::consumer::IggyConsumer;
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::locking::{IggySharedMut, IggySharedMutFn};
+use crate::models::consumer_group::ConsumerGroupMember;
+use crate::utils::duration::IggyDuration;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::{Arc, RwLock};
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+use tracing::{info, trace, warn};
+
+const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
+
+/// The listener notified whenever the partitions of the consumer group are assigned to or revoked from the consumer.
+/// The rebalancing is cooperative, i.e. only the partitions which have changed the owner are reported,
+/// while the messages from the other ones are consumed without any interruption.
+#[async_trait]
+pub trait ConsumerGroupRebalanceListener: Send + Sync + Debug {
+    /// Invoked once the partitions are revoked from the consumer,
+    /// after the offsets consumed so far have been committed (unless the auto-commit is disabled).
+    async fn on_partitions_revoked(&self, _partitions: &[u32]) {}
+
+    /// Invoked once the partitions are assigned to the consumer.
+    async fn on_partitions_assigned(&self, _partitions: &[u32]) {}
+}
+
+/// The client side of the consumer group, which keeps the membership alive by sending the heartbeats
+/// (rejoining the group if the member has been removed by the server, e.g. once its session timeout elapsed),
+/// receives the partitions assigned by the server with each heartbeat and releases the revoked ones,
+/// so that their next owner continues from the last consumed offset.
+#[derive(Debug)]
+pub(crate) struct ConsumerGroupCoordinator {
+    client: IggySharedMut<Box<dyn Client>>,
+    consumer: Arc<Consumer>,
+    consumer_name: String,
+    stream_id: Arc<Identifier>,
+    topic_id: Arc<Identifier>,
+    create_consumer_group_if_not_exists: bool,
+    rebalance_strategy: RebalanceStrategy,
+    group_instance_id: Option<String>,
+    auto_commit_enabled: bool,
+    joined_consumer_group: Arc<AtomicBool>,
+    last_consumed_offsets: Arc<DashMap<u32, AtomicU64>>,
+    last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
+    listener: Option<Arc<dyn ConsumerGroupRebalanceListener>>,
+    assigned_partitions: RwLock<Option<Vec<u32>>>,
+    heartbeat_lock: Mutex<()>,
+}
+
+impl ConsumerGroupCoordinator {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        client: IggySharedMut<Box<dyn Client>>,
+        consumer: Arc<Consumer>,
+        consumer_name: String,
+        stream_id: Arc<Identifier>,
+        topic_id: Arc<Identifier>,
+        create_consumer_group_if_not_exists: bool,
+        rebalance_strategy: RebalanceStrategy,
+        group_instance_id: Option<String>,
+        auto_commit_enabled: bool,
+        joined_consumer_group: Arc<AtomicBool>,
+        last_consumed_offsets: Arc<DashMap<u32, AtomicU64>>,
+        last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
+        listener: Option<Arc<dyn ConsumerGroupRebalanceListener>>,
+    ) -> Self {
+        Self {
+            client,
+            consumer,
+            consumer_name,
+            stream_id,
+            topic_id,
+            create_consumer_group_if_not_exists,
+            rebalance_strategy,
+            group_instance_id,
+            auto_commit_enabled,
+            joined_consumer_group,
+            last_consumed_offsets,
+            last_stored_offsets,
+            listener,
+            assigned_partitions: RwLock::new(None),
+            heartbeat_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the partitions assigned to the consumer, or `None` if the assignment hasn't been received yet.
+    pub(crate) fn assigned_partitions(&self) -> Option<Vec<u32>> {
+        self.assigned_partitions.read().unwrap().clone()
+    }
+
+    /// Returns true if the partition is assigned to the consumer, or the assignment hasn't been received yet.
+    pub(crate) fn is_assigned(&self, partition_id: u32) -> bool {
+        self.assigned_partitions
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_none_or(|partitions| partitions.contains(&partition_id))
+    }
+
+    pub(crate) fn send_heartbeats_in_background(
+        self: &Arc<Self>,
+        interval: IggyDuration,
+        shutdown: Arc<AtomicBool>,
+    ) {
+        let coordinator = self.clone();
+        tokio::spawn(async move {
+            while !shutdown.load(ORDERING) {
+                if let Err(error) = coordinator.heartbeat().await {
+                    warn!(
+                        "Failed to send the heartbeat of consumer group: {} for topic: {}, stream: {}. {error}",
+                        coordinator.consumer_name, coordinator.topic_id, coordinator.stream_id
+                    );
+                }
+                sleep(interval.get_duration()).await;
+            }
+        });
+    }
+
+    /// Sends the heartbeat, which keeps the membership alive and returns the partitions currently assigned to the consumer,
+    /// and rebalances the consumer if they have changed. Rejoins the group if the server no longer knows the member.
+    async fn heartbeat(&self) -> Result<(), IggyError> {
+        let _heartbeat = self.heartbeat_lock.lock().await;
+        if !self.joined_consumer_group.load(ORDERING) {
+            return Ok(());
+        }
+
+        trace!(
+            "Sending the heartbeat of consumer group: {} for topic: {}, stream: {}",
+            self.consumer_name,
+            self.topic_id,
+            self.stream_id
+        );
+        let member = match self.send_heartbeat().await {
+            Ok(member) => member,
+            Err(error) if is_member_not_found_error(&error) => {
+                warn!(
+                    "Consumer is no longer a member of consumer group: {} for topic: {}, stream: {}, rejoining...",
+                    self.consumer_name, self.topic_id, self.stream_id
+                );
+                self.joined_consumer_group.store(false, ORDERING);
+                IggyConsumer::initialize_consumer_group(
+                    self.client.clone(),
+                    self.create_consumer_group_if_not_exists,
+                    self.rebalance_strategy,
+                    self.group_instance_id.as_deref(),
+                    self.stream_id.clone(),
+                    self.topic_id.clone(),
+                    self.consumer.clone(),
+                    &self.consumer_name,
+                    self.joined_consumer_group.clone(),
+                )
+                .await?;
+                self.send_heartbeat().await?
+            }
+            Err(error) => return Err(error),
+        };
+
+        trace!(
+            "Received the partitions: {:?} assigned to member with ID: {} of consumer group: {} for topic: {}, stream: {}",
+            member.partitions,
+            member.id,
+            self.consumer_name,
+            self.topic_id,
+            self.stream_id
+        );
+        let mut partitions = member.partitions;
+        partitions.sort_unstable();
+        self.rebalance(partitions).await;
+        Ok(())
+    }
+
+    async fn send_heartbeat(&self) -> Result<ConsumerGroupMember, IggyError> {
+        self.client
+            .read()
+            .await
+            .heartbeat_consumer_group(&self.stream_id, &self.topic_id, &self.consumer.id)
+            .await
+    }
+
+    async fn rebalance(&self, partitions: Vec<u32>) {
+        let previous_partitions = self.assigned_partitions();
+        if previous_partitions.as_ref() == Some(&partitions) {
+            return;
+        }
+
+        let previous_partitions = previous_partitions.unwrap_or_default();
+        let revoked_partitions = previous_partitions
+            .iter()
+            .filter(|partition_id| !partitions.contains(partition_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let assigned_partitions = partitions
+            .iter()
+            .filter(|partition_id| !previous_partitions.contains(partition_id))
+            .copied()
+            .collect::<Vec<_>>();
+
+        self.release_partitions(&revoked_partitions).await;
+        *self.assigned_partitions.write().unwrap() = Some(partitions);
+        info!(
+            "Rebalanced consumer group: {} for topic: {}, stream: {}, revoked partitions: {revoked_partitions:?}, assigned partitions: {assigned_partitions:?}",
+            self.consumer_name, self.topic_id, self.stream_id
+        );
+
+        let Some(listener) = &self.listener else {
+            return;
+        };
+
+        if !revoked_partitions.is_empty() {
+            listener.on_partitions_revoked(&revoked_partitions).await;
+        }
+        if !assigned_partitions.is_empty() {
+            listener.on_partitions_assigned(&assigned_partitions).await;
+        }
+    }
+
+    /// Revokes all the partitions from the consumer and leaves the consumer group, unless the consumer is its static member,
+    /// whose partitions are kept by the server until it rejoins the group, or the session timeout elapses.
+    pub(crate) async fn leave(&self) -> Result<(), IggyError> {
+        let _heartbeat = self.heartbeat_lock.lock().await;
+        let partitions = self
+            .assigned_partitions
+            .write()
+            .unwrap()
+            .take()
+            .unwrap_or_default();
+        self.release_partitions(&partitions).await;
+        if let Some(listener) = &self.listener {
+            if !partitions.is_empty() {
+                listener.on_partitions_revoked(&partitions).await;
+            }
+        }
+
+        if !self.joined_consumer_group.swap(false, ORDERING) {
+            return Ok(());
+        }
+
+        if let Some(group_instance_id) = &self.group_instance_id {
+            info!(
+                "Keeping the membership of static member: {group_instance_id} in consumer group: {} for topic: {}, stream: {}",
+                self.consumer_name, self.topic_id, self.stream_id
+            );
+            return Ok(());
+        }
+
+        info!(
+            "Leaving consumer group: {} for topic: {}, stream: {}",
+            self.consumer_name, self.topic_id, self.stream_id
+        );
+        self.client
+            .read()
+            .await
+            .leave_consumer_group(&self.stream_id, &self.topic_id, &self.consumer.id)
+            .await
+    }
+
+    /// Commits the offsets consumed from the partitions (unless the auto-commit is disabled) and forgets them.
+    async fn release_partitions(&self, partitions: &[u32]) {
+        for partition_id in partitions {
+            if let Some((_, consumed_offset)) = self.last_consumed_offsets.remove(partition_id) {
+                if self.auto_commit_enabled {
+                    _ = IggyConsumer::store_consumer_offset(
+                        &self.client,
+                        &self.consumer,
+                        &self.stream_id,
+                        &self.topic_id,
+                        *partition_id,
+                        consumed_offset.load(ORDERING),
+                        &self.last_stored_offsets,
+                    )
+                    .await;
+                }
+            }
+            self.last_stored_offsets.remove(partition_id);
+        }
+    }
+}
+
+/// Returns whether the heartbeat was rejected, because the client is not a member of the consumer group,
+/// either directly or as the error code returned by the server in the binary response.
+fn is_member_not_found_error(error: &IggyError) -> bool {
+    let member_not_found_code = IggyError::ConsumerGroupMemberNotFound(0, 0, 0).as_code();
+    match error {
+        IggyError::InvalidResponse(status, _, _) => *status == member_not_found_code,
+        error => error.as_code() == member_not_found_code,
+    }
+}
@@ -0,0 +1,388 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::send_messages::{self, AckLevel, Message, Partitioning, SendMessages};
+use crate::utils::byte_size::IggyByteSize;
+use bytes::{BufMut, Bytes, BytesMut};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tracing::{trace, warn};
+
+const RECORD_LENGTH_BYTES: u64 = 4;
+
+/// `JournalOverflowPolicy` specifies what happens when the journal would exceed its maximum size:
+/// - `DropOldest` - the oldest journaled batches are dropped to make room for the new one.
+/// - `DropNewest` - the new batch is dropped, the journaled batches are kept.
+/// - `Fail` - the new batch is rejected with the `ProducerJournalFull` error.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum JournalOverflowPolicy {
+    /// The oldest journaled batches are dropped to make room for the new one.
+    #[default]
+    DropOldest,
+    /// The new batch is dropped, the journaled batches are kept.
+    DropNewest,
+    /// The new batch is rejected with the `ProducerJournalFull` error.
+    Fail,
+}
+
+/// Configuration of the local journal, to which the producer spills the messages when the server is unreachable.
+#[derive(Debug, Clone)]
+pub struct JournalConfig {
+    /// Path of the journal file, it's created if it doesn't exist and its content is replayed on the next start.
+    pub path: PathBuf,
+    /// Maximum size of the journal file.
+    pub max_size: IggyByteSize,
+    /// What happens when the journal would exceed its maximum size.
+    pub overflow_policy: JournalOverflowPolicy,
+}
+
+impl JournalConfig {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        max_size: IggyByteSize,
+        overflow_policy: JournalOverflowPolicy,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            max_size,
+            overflow_policy,
+        }
+    }
+}
+
+/// Append-only file of the batches which couldn't be sent, each record is the length-prefixed `SendMessages` command,
+/// so the batches can be replayed in the same order, with the same target and acknowledgement level.
+#[derive(Debug)]
+pub(crate) struct ProducerJournal {
+    config: JournalConfig,
+    records: VecDeque<Bytes>,
+    size_bytes: u64,
+}
+
+impl ProducerJournal {
+    /// Opens the journal, loading the batches which were journaled but not replayed before.
+    pub async fn open(config: JournalConfig) -> Result<Self, IggyError> {
+        if let Some(directory) = config.path.parent() {
+            if !directory.as_os_str().is_empty() {
+                fs::create_dir_all(directory).await?;
+            }
+        }
+
+        let mut journal = Self {
+            config,
+            records: VecDeque::new(),
+            size_bytes: 0,
+        };
+        if !fs::try_exists(&journal.config.path).await? {
+            return Ok(journal);
+        }
+
+        let bytes = Bytes::from(fs::read(&journal.config.path).await?);
+        let mut position = 0;
+        while position + RECORD_LENGTH_BYTES as usize <= bytes.len() {
+            let length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            let start = position + RECORD_LENGTH_BYTES as usize;
+            if start + length > bytes.len() {
+                break;
+            }
+
+            journal
+                .records
+                .push_back(bytes.slice(start..start + length));
+            journal.size_bytes += RECORD_LENGTH_BYTES + length as u64;
+            position = start + length;
+        }
+
+        if position < bytes.len() {
+            warn!(
+                "Discarding {} bytes of an incomplete record in the producer journal: {}",
+                bytes.len() - position,
+                journal.config.path.display()
+            );
+            journal.rewrite().await?;
+        }
+
+        trace!(
+            "Loaded {} batch(es) from the producer journal: {}",
+            journal.records.len(),
+            journal.config.path.display()
+        );
+        Ok(journal)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns the journaled batch at the given position, starting from the oldest one.
+    pub fn get(&self, index: usize) -> Result<Option<SendMessages>, IggyError> {
+        self.records
+            .get(index)
+            .map(|record| SendMessages::from_bytes(record.clone()))
+            .transpose()
+    }
+
+    /// Appends the batch to the journal, applying the overflow policy if it would exceed the maximum size.
+    pub async fn append(
+        &mut self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        ack: AckLevel,
+        messages: &[Message],
+    ) -> Result<(), IggyError> {
+        let record = send_messages::as_bytes(stream_id, topic_id, partitioning, ack, messages);
+        let record_size = RECORD_LENGTH_BYTES + record.len() as u64;
+        let max_size = self.config.max_size.as_bytes_u64();
+        if self.size_bytes + record_size > max_size {
+            match self.config.overflow_policy {
+                JournalOverflowPolicy::Fail => {
+                    return Err(IggyError::ProducerJournalFull);
+                }
+                JournalOverflowPolicy::DropNewest => {
+                    warn!(
+                        "Producer journal is full, dropping the batch of {} message(s).",
+                        messages.len()
+                    );
+                    return Ok(());
+                }
+                JournalOverflowPolicy::DropOldest if record_size > max_size => {
+                    warn!(
+                        "Batch of {} message(s) exceeds the producer journal size, dropping it.",
+                        messages.len()
+                    );
+                    return Ok(());
+                }
+                JournalOverflowPolicy::DropOldest => {
+                    let mut dropped_batches = 0;
+                    while self.size_bytes + record_size > max_size {
+                        let Some(oldest_record) = self.records.pop_front() else {
+                            break;
+                        };
+                        self.size_bytes -= RECORD_LENGTH_BYTES + oldest_record.len() as u64;
+                        dropped_batches += 1;
+                    }
+                    warn!("Producer journal is full, dropped {dropped_batches} oldest batch(es).");
+                    self.records.push_back(record);
+                    self.size_bytes += record_size;
+                    return self.rewrite().await;
+                }
+            }
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.config.path)
+            .await?;
+        let mut bytes = BytesMut::with_capacity(record_size as usize);
+        bytes.put_u32_le(record.len() as u32);
+        bytes.put_slice(&record);
+        file.write_all(&bytes).await?;
+        file.sync_data().await?;
+        self.records.push_back(record);
+        self.size_bytes += record_size;
+        Ok(())
+    }
+
+    /// Removes the given number of the oldest batches, once they've been replayed.
+    pub async fn remove_first(&mut self, count: usize) -> Result<(), IggyError> {
+        if count == 0 {
+            return Ok(());
+        }
+
+        let count = count.min(self.records.len());
+        for record in self.records.drain(..count) {
+            self.size_bytes -= RECORD_LENGTH_BYTES + record.len() as u64;
+        }
+        self.rewrite().await
+    }
+
+    async fn rewrite(&self) -> Result<(), IggyError> {
+        let mut bytes = BytesMut::with_capacity(self.size_bytes as usize);
+        for record in &self.records {
+            bytes.put_u32_le(record.len() as u32);
+            bytes.put_slice(record);
+        }
+
+        let temp_path = self.config.path.with_extension("tmp");
+        let mut file = File::create(&temp_path).await?;
+        file.write_all(&bytes).await?;
+        file.sync_data().await?;
+        fs::rename(&temp_path, &self.config.path).await?;
+        Ok(())
+    }
+}
+
+/// Returns `true` if the error means that the server is unreachable, so the messages can be journaled and sent later.
+pub(crate) fn is_connection_error(error: &IggyError) -> bool {
+    match error {
+        IggyError::Disconnected
+        | IggyError::NotConnected
+        | IggyError::CannotEstablishConnection
+        | IggyError::ConnectionClosed
+        | IggyError::EmptyResponse
+        | IggyError::StaleClient
+        | IggyError::IoError(_)
+        | IggyError::WriteError(_)
+        | IggyError::ConnectionError(_)
+        | IggyError::RequestMiddlewareError(_) => true,
+        IggyError::RequestError(error) => error.is_connect() || error.is_timeout(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn should_load_journaled_batches_in_order_after_reopening() {
+        let config = create_config(IggyByteSize::from(1024_u64), JournalOverflowPolicy::Fail);
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        journal.append_batch(1, "first").await.unwrap();
+        journal.append_batch(2, "second").await.unwrap();
+        drop(journal);
+
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        assert_eq!(journal.len(), 2);
+        assert_batch(&journal, 0, 1, "first");
+        assert_batch(&journal, 1, 2, "second");
+
+        journal.remove_first(1).await.unwrap();
+        drop(journal);
+
+        let journal = ProducerJournal::open(config.clone()).await.unwrap();
+        assert_eq!(journal.len(), 1);
+        assert_batch(&journal, 0, 2, "second");
+        remove_journal(&config).await;
+    }
+
+    #[tokio::test]
+    async fn should_apply_overflow_policy_when_journal_is_full() {
+        let record_size = RECORD_LENGTH_BYTES + create_record(1, "batch").len() as u64;
+        let max_size = IggyByteSize::from(2 * record_size);
+
+        let config = create_config(max_size, JournalOverflowPolicy::DropOldest);
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        for partition_id in 1..=3 {
+            journal.append_batch(partition_id, "batch").await.unwrap();
+        }
+        assert_eq!(journal.len(), 2);
+        assert_batch(&journal, 0, 2, "batch");
+        assert_batch(&journal, 1, 3, "batch");
+        remove_journal(&config).await;
+
+        let config = create_config(max_size, JournalOverflowPolicy::DropNewest);
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        for partition_id in 1..=3 {
+            journal.append_batch(partition_id, "batch").await.unwrap();
+        }
+        assert_eq!(journal.len(), 2);
+        assert_batch(&journal, 0, 1, "batch");
+        assert_batch(&journal, 1, 2, "batch");
+        remove_journal(&config).await;
+
+        let config = create_config(max_size, JournalOverflowPolicy::Fail);
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        journal.append_batch(1, "batch").await.unwrap();
+        journal.append_batch(2, "batch").await.unwrap();
+        let result = journal.append_batch(3, "batch").await;
+        assert!(matches!(result, Err(IggyError::ProducerJournalFull)));
+        assert_eq!(journal.len(), 2);
+        remove_journal(&config).await;
+    }
+
+    #[tokio::test]
+    async fn should_discard_incomplete_record() {
+        let config = create_config(IggyByteSize::from(1024_u64), JournalOverflowPolicy::Fail);
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        journal.append_batch(1, "first").await.unwrap();
+        drop(journal);
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(&config.path)
+            .await
+            .unwrap();
+        file.write_all(&[100, 0, 0, 0, 1, 2, 3]).await.unwrap();
+        drop(file);
+
+        let mut journal = ProducerJournal::open(config.clone()).await.unwrap();
+        journal.append_batch(2, "second").await.unwrap();
+        drop(journal);
+
+        let journal = ProducerJournal::open(config.clone()).await.unwrap();
+        assert_eq!(journal.len(), 2);
+        assert_batch(&journal, 0, 1, "first");
+        assert_batch(&journal, 1, 2, "second");
+        remove_journal(&config).await;
+    }
+
+    #[test]
+    fn should_treat_only_connectivity_errors_as_connection_errors() {
+        assert!(is_connection_error(&IggyError::Disconnected));
+        assert!(is_connection_error(&IggyError::NotConnected));
+        assert!(is_connection_error(&IggyError::CannotEstablishConnection));
+        assert!(!is_connection_error(&IggyError::InvalidMessagesCount));
+        assert!(!is_connection_error(&IggyError::Unauthorized));
+    }
+
+    impl ProducerJournal {
+        async fn append_batch(
+            &mut self,
+            partition_id: u32,
+            payload: &str,
+        ) -> Result<(), IggyError> {
+            self.append(
+                &Identifier::numeric(1).unwrap(),
+                &Identifier::numeric(2).unwrap(),
+                &Partitioning::partition_id(partition_id),
+                AckLevel::default(),
+                &[Message::from_str(payload).unwrap()],
+            )
+            .await
+        }
+    }
+
+    fn assert_batch(journal: &ProducerJournal, index: usize, partition_id: u32, payload: &str) {
+        let batch = journal.get(index).unwrap().unwrap();
+        assert_eq!(batch.stream_id, Identifier::numeric(1).unwrap());
+        assert_eq!(batch.topic_id, Identifier::numeric(2).unwrap());
+        assert_eq!(batch.partitioning, Partitioning::partition_id(partition_id));
+        assert_eq!(batch.messages.len(), 1);
+        assert_eq!(batch.messages[0].payload, Bytes::from(payload.to_owned()));
+    }
+
+    fn create_record(partition_id: u32, payload: &str) -> Bytes {
+        send_messages::as_bytes(
+            &Identifier::numeric(1).unwrap(),
+            &Identifier::numeric(2).unwrap(),
+            &Partitioning::partition_id(partition_id),
+            AckLevel::default(),
+            &[Message::from_str(payload).unwrap()],
+        )
+    }
+
+    fn create_config(
+        max_size: IggyByteSize,
+        overflow_policy: JournalOverflowPolicy,
+    ) -> JournalConfig {
+        let path = std::env::temp_dir().join(format!(
+            "iggy-producer-journal-{}.log",
+            uuid::Uuid::now_v7()
+        ));
+        JournalConfig::new(path, max_size, overflow_policy)
+    }
+
+    async fn remove_journal(config: &JournalConfig) {
+        let _ = fs::remove_file(&config.path).await;
+    }
+}
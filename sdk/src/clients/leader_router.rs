@@ -0,0 +1,147 @@
+use crate::client::Client;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::models::cluster_metadata::ClusterMetadata;
+use crate::tcp::client::TcpClient;
+use crate::tcp::config::TcpClientConfig;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+/// The trait represents the logic responsible for creating the clients connected to the other nodes of the cluster,
+/// used by the `IggyClient` to send the messages to and poll them from the node leading the partition.
+/// The created client is connected by the router, and should authenticate on its own, e.g. with the `AutoLogin`.
+pub trait NodeClientFactory: Send + Sync + Debug {
+    fn create_client(&self, address: &str) -> Result<Box<dyn Client>, IggyError>;
+}
+
+/// Creates the TCP clients with the same configuration as the provided one, except for the server address.
+#[derive(Debug)]
+pub struct TcpNodeClientFactory {
+    config: TcpClientConfig,
+}
+
+impl TcpNodeClientFactory {
+    /// Creates a new factory using the provided configuration (including the auto login) for all the nodes.
+    pub fn new(config: TcpClientConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl NodeClientFactory for TcpNodeClientFactory {
+    fn create_client(&self, address: &str) -> Result<Box<dyn Client>, IggyError> {
+        let mut config = self.config.clone();
+        config.server_address = address.to_string();
+        Ok(Box::new(TcpClient::create(Arc::new(config))?))
+    }
+}
+
+/// Keeps the cluster metadata fetched through the main client and the clients connected to the leaders of the partitions.
+/// The metadata is fetched lazily on the first routed command and fetched again once it's invalidated,
+/// e.g. after the node has rejected the command with the `NotLeader` error.
+#[derive(Debug)]
+pub(crate) struct LeaderRouter {
+    factory: Arc<dyn NodeClientFactory>,
+    metadata: Mutex<Option<ClusterMetadata>>,
+    nodes: Mutex<HashMap<u32, Arc<dyn Client>>>,
+}
+
+impl LeaderRouter {
+    pub fn new(factory: Arc<dyn NodeClientFactory>) -> Self {
+        Self {
+            factory,
+            metadata: Mutex::new(None),
+            nodes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the client connected to the leader of the partition, or `None` if the main client should be used instead,
+    /// i.e. when the partition is led by the node the main client is connected to, the leader doesn't advertise its address,
+    /// or the partition is unknown.
+    pub async fn get_leader_client(
+        &self,
+        client: &dyn Client,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Option<Arc<dyn Client>>, IggyError> {
+        let leader = {
+            let mut metadata = self.metadata.lock().await;
+            if metadata.is_none() {
+                *metadata = Some(client.get_cluster_metadata().await?);
+            }
+
+            let Some(metadata) = metadata.as_ref() else {
+                return Ok(None);
+            };
+            match metadata.get_leader(stream_id, topic_id, partition_id) {
+                Some(leader) if leader.id != metadata.node_id && !leader.address.is_empty() => {
+                    leader.clone()
+                }
+                _ => return Ok(None),
+            }
+        };
+
+        let mut nodes = self.nodes.lock().await;
+        if let Some(node) = nodes.get(&leader.id) {
+            return Ok(Some(node.clone()));
+        }
+
+        let node: Arc<dyn Client> = Arc::from(self.factory.create_client(&leader.address)?);
+        node.connect().await?;
+        info!(
+            "Connected to the node with ID: {}, name: {} at address: {}",
+            leader.id, leader.name, leader.address
+        );
+        nodes.insert(leader.id, node.clone());
+        Ok(Some(node))
+    }
+
+    /// Drops the cached metadata, so that it's fetched again on the next routed command.
+    pub async fn invalidate(&self) {
+        self.metadata.lock().await.take();
+    }
+
+    pub async fn shutdown(&self) {
+        let nodes = self.nodes.lock().await.drain().collect::<Vec<_>>();
+        for (node_id, node) in nodes {
+            if let Err(error) = node.shutdown().await {
+                warn!("Failed to shut down the client of the node with ID: {node_id}. {error}");
+            }
+        }
+    }
+}
+
+/// Returns whether the command was rejected by the node which is not the leader of the partition,
+/// either directly or as the error code returned by the server in the binary response.
+pub(crate) fn is_not_leader_error(error: &IggyError) -> bool {
+    let not_leader_code = IggyError::NotLeader(0, 0, 0, 0).as_code();
+    match error {
+        IggyError::InvalidResponse(status, _, _) => *status == not_leader_code,
+        error => error.as_code() == not_leader_code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_detect_not_leader_error() {
+        let not_leader_code = IggyError::NotLeader(2, 1, 1, 1).as_code();
+        assert!(is_not_leader_error(&IggyError::NotLeader(2, 1, 1, 1)));
+        assert!(is_not_leader_error(&IggyError::InvalidResponse(
+            not_leader_code,
+            0,
+            "".to_string()
+        )));
+        assert!(!is_not_leader_error(&IggyError::PartitionNotFound(1, 1, 1)));
+        assert!(!is_not_leader_error(&IggyError::InvalidResponse(
+            IggyError::PartitionNotFound(1, 1, 1).as_code(),
+            0,
+            "".to_string()
+        )));
+    }
+}
@@ -1,4 +1,10 @@
+pub mod blocking;
 pub mod builder;
 pub mod client;
 pub mod consumer;
+pub mod consumer_group_coordinator;
+pub mod journal;
+pub mod leader_router;
 pub mod producer;
+pub mod subscription;
+pub mod typed_consumer;
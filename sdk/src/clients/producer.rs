@@ -1,27 +1,34 @@
 use crate::client::Client;
+use crate::clients::journal::{self, JournalConfig, ProducerJournal};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::diagnostic::DiagnosticEvent;
 use crate::error::IggyError;
 use crate::identifier::{IdKind, Identifier};
 use crate::locking::{IggySharedMut, IggySharedMutFn};
-use crate::messages::send_messages::{Message, Partitioning};
+use crate::messages::send_messages::{AckLevel, Message, Partitioning};
 use crate::partitioner::Partitioner;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::crypto::Encryptor;
 use crate::utils::duration::IggyDuration;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::timestamp::IggyTimestamp;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use bytes::Bytes;
 use futures_util::StreamExt;
-use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::Mutex;
 use tokio::time::sleep;
 use tracing::{error, info, trace, warn};
 
 const ORDERING: std::sync::atomic::Ordering = std::sync::atomic::Ordering::SeqCst;
 const MAX_BATCH_SIZE: usize = 1000000;
 
+type PartitionedMessages = (Arc<Partitioning>, Vec<Message>);
+
 unsafe impl Send for IggyProducer {}
 unsafe impl Sync for IggyProducer {}
 
@@ -48,6 +55,13 @@ pub struct IggyProducer {
     can_send_immediately: bool,
     last_sent_at: Arc<AtomicU64>,
     retry_interval: IggyDuration,
+    ack: AckLevel,
+    journal_config: Option<JournalConfig>,
+    journal: Option<Arc<Mutex<ProducerJournal>>>,
+    exclusive: bool,
+    with_epoch: bool,
+    epoch: Arc<AtomicU32>,
+    fenced: Arc<AtomicBool>,
 }
 
 impl IggyProducer {
@@ -70,6 +84,10 @@ impl IggyProducer {
         topic_message_expiry: IggyExpiry,
         topic_max_size: MaxTopicSize,
         retry_interval: IggyDuration,
+        ack: AckLevel,
+        journal_config: Option<JournalConfig>,
+        exclusive: bool,
+        with_epoch: bool,
     ) -> Self {
         Self {
             initialized: false,
@@ -94,6 +112,13 @@ impl IggyProducer {
             can_send_immediately: interval.is_none(),
             last_sent_at: Arc::new(AtomicU64::new(0)),
             retry_interval,
+            ack,
+            journal_config,
+            journal: None,
+            exclusive,
+            with_epoch,
+            epoch: Arc::new(AtomicU32::new(0)),
+            fenced: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -105,12 +130,36 @@ impl IggyProducer {
         &self.topic_id
     }
 
+    /// Returns the acknowledgement level used when sending the messages.
+    pub fn ack(&self) -> AckLevel {
+        self.ack
+    }
+
+    /// Returns the producer epoch assigned by the server, if the producer was initialized with the epoch.
+    pub fn epoch(&self) -> Option<u32> {
+        match self.epoch.load(ORDERING) {
+            0 => None,
+            epoch => Some(epoch),
+        }
+    }
+
     /// Initializes the producer by subscribing to diagnostic events, creating the stream and topic if they do not exist etc.
     pub async fn init(&mut self) -> Result<(), IggyError> {
         if self.initialized {
             return Ok(());
         }
 
+        if let Some(journal_config) = &self.journal_config {
+            let journal = ProducerJournal::open(journal_config.clone()).await?;
+            if !journal.is_empty() {
+                info!(
+                    "Loaded {} batch(es) of messages to be replayed from the producer journal.",
+                    journal.len()
+                );
+            }
+            self.journal = Some(Arc::new(Mutex::new(journal)));
+        }
+
         self.subscribe_events().await;
         let client = self.client.clone();
         let client = client.read().await;
@@ -162,10 +211,31 @@ impl IggyProducer {
                     id,
                     self.topic_message_expiry,
                     self.topic_max_size,
+                    CleanupPolicy::Delete,
+                    TopicOverrides::default(),
                 )
                 .await?;
         }
 
+        if self.with_epoch {
+            let epoch = client
+                .init_producer_epoch(&self.stream_id, &self.topic_id, 0)
+                .await?;
+            info!(
+                "Acquired the exclusive producer with epoch: {epoch} for topic: {} in stream: {}",
+                self.topic_name, self.stream_name
+            );
+            self.epoch.store(epoch, ORDERING);
+        } else if self.exclusive {
+            info!(
+                "Acquiring the exclusive producer for topic: {} in stream: {}",
+                self.topic_name, self.stream_name
+            );
+            client
+                .acquire_exclusive_producer(&self.stream_id, &self.topic_id)
+                .await?;
+        }
+
         self.initialized = true;
         Ok(())
     }
@@ -179,6 +249,14 @@ impl IggyProducer {
         }
 
         let can_send = self.can_send.clone();
+        let client = self.client.clone();
+        let journal = self.journal.clone();
+        let exclusive = self.exclusive;
+        let with_epoch = self.with_epoch;
+        let epoch = self.epoch.clone();
+        let fenced = self.fenced.clone();
+        let stream_id = self.stream_id.clone();
+        let topic_id = self.topic_id.clone();
 
         tokio::spawn(async move {
             while let Some(event) = receiver.next().await {
@@ -198,6 +276,60 @@ impl IggyProducer {
                     }
                     DiagnosticEvent::SignedIn => {
                         can_send.store(true, ORDERING);
+                        if !exclusive && !with_epoch && journal.is_none() {
+                            continue;
+                        }
+
+                        // Re-acquiring and replaying in a separate task, as the event is published while the client might be in use.
+                        let client = client.clone();
+                        let journal = journal.clone();
+                        let stream_id = stream_id.clone();
+                        let topic_id = topic_id.clone();
+                        let epoch = epoch.clone();
+                        let fenced = fenced.clone();
+                        tokio::spawn(async move {
+                            let client = client.read().await;
+                            if with_epoch {
+                                let epoch = epoch.load(ORDERING);
+                                match client
+                                    .init_producer_epoch(&stream_id, &topic_id, epoch)
+                                    .await
+                                {
+                                    Ok(_) => {}
+                                    Err(IggyError::InvalidResponse(status, _, _))
+                                        if status == IggyError::StaleProducerEpoch(0).as_code() =>
+                                    {
+                                        fenced.store(true, ORDERING);
+                                        error!("Producer with epoch: {epoch} was fenced by the newer producer instance.");
+                                        return;
+                                    }
+                                    Err(error) => {
+                                        warn!(
+                                            "Failed to resume the producer epoch: {epoch}: {error}"
+                                        );
+                                        return;
+                                    }
+                                }
+                            } else if exclusive {
+                                // The exclusive producer is released by the server once the previous connection is closed.
+                                if let Err(error) = client
+                                    .acquire_exclusive_producer(&stream_id, &topic_id)
+                                    .await
+                                {
+                                    warn!("Failed to re-acquire the exclusive producer: {error}");
+                                    return;
+                                }
+                            }
+
+                            if let Some(journal) = journal {
+                                let mut journal = journal.lock().await;
+                                if let Err(error) =
+                                    Self::replay_journal(client.as_ref(), &mut journal).await
+                                {
+                                    warn!("Failed to replay the producer journal: {error}");
+                                }
+                            }
+                        });
                     }
                     DiagnosticEvent::SignedOut => {
                         can_send.store(false, ORDERING);
@@ -213,7 +345,7 @@ impl IggyProducer {
             return Ok(());
         }
 
-        if !self.can_send.load(ORDERING) {
+        if !self.can_send.load(ORDERING) && self.journal.is_none() {
             trace!("Trying to send messages in {}...", self.retry_interval);
             sleep(self.retry_interval.get_duration()).await;
         }
@@ -247,7 +379,7 @@ impl IggyProducer {
             return Ok(());
         }
 
-        if !self.can_send.load(ORDERING) {
+        if !self.can_send.load(ORDERING) && self.journal.is_none() {
             trace!("Trying to send messages in {}...", self.retry_interval);
             sleep(self.retry_interval.get_duration()).await;
         }
@@ -279,7 +411,7 @@ impl IggyProducer {
             return Ok(());
         }
 
-        if !self.can_send.load(ORDERING) {
+        if !self.can_send.load(ORDERING) && self.journal.is_none() {
             trace!("Trying to send messages in {}...", self.retry_interval);
             sleep(self.retry_interval.get_duration()).await;
         }
@@ -302,32 +434,42 @@ impl IggyProducer {
         partitioning: Option<Arc<Partitioning>>,
     ) -> Result<(), IggyError> {
         self.encrypt_messages(&mut messages)?;
-        let partitioning = self.get_partitioning(&stream, &topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
-        let batches = messages.chunks_mut(batch_size);
-        let mut current_batch = 1;
-        let batches_count = batches.len();
-        for batch in batches {
-            if self.send_interval_micros > 0 {
-                Self::wait_before_sending(
-                    self.send_interval_micros,
-                    self.last_sent_at.load(ORDERING),
-                )
-                .await;
-            }
+        for (partitioning, mut messages) in
+            self.partition_messages(&stream, &topic, messages, partitioning)?
+        {
+            let batches = messages.chunks_mut(batch_size);
+            let mut current_batch = 1;
+            let batches_count = batches.len();
+            for batch in batches {
+                if self.send_interval_micros > 0 {
+                    Self::wait_before_sending(
+                        self.send_interval_micros,
+                        self.last_sent_at.load(ORDERING),
+                    )
+                    .await;
+                }
 
-            let messages_count = batch.len();
-            trace!(
-                "Sending {messages_count} messages ({current_batch}/{batches_count} batch(es))..."
-            );
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            let client = self.client.read().await;
-            client
-                .send_messages(&self.stream_id, &self.topic_id, &partitioning, batch)
+                let messages_count = batch.len();
+                trace!(
+                    "Sending {messages_count} messages ({current_batch}/{batches_count} batch(es))..."
+                );
+                self.last_sent_at
+                    .store(IggyTimestamp::now().into(), ORDERING);
+                let client = self.client.read().await;
+                self.send_or_journal(
+                    client.as_ref(),
+                    &self.stream_id,
+                    &self.topic_id,
+                    &partitioning,
+                    batch,
+                )
                 .await?;
-            trace!("Sent {messages_count} messages ({current_batch}/{batches_count} batch(es)).");
-            current_batch += 1;
+                trace!(
+                    "Sent {messages_count} messages ({current_batch}/{batches_count} batch(es))."
+                );
+                current_batch += 1;
+            }
         }
         Ok(())
     }
@@ -341,26 +483,119 @@ impl IggyProducer {
     ) -> Result<(), IggyError> {
         trace!("No batch size specified, sending messages immediately.");
         self.encrypt_messages(&mut messages)?;
-        let partitioning = self.get_partitioning(stream, topic, &messages, partitioning)?;
         let batch_size = self.batch_size.unwrap_or(MAX_BATCH_SIZE);
         let client = self.client.read().await;
-        if messages.len() <= batch_size {
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            client
-                .send_messages(stream, topic, &partitioning, &mut messages)
-                .await?;
+        for (partitioning, mut messages) in
+            self.partition_messages(stream, topic, messages, partitioning)?
+        {
+            for batch in messages.chunks_mut(batch_size) {
+                self.last_sent_at
+                    .store(IggyTimestamp::now().into(), ORDERING);
+                self.send_or_journal(client.as_ref(), stream, topic, &partitioning, batch)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Replays the messages journaled while the server was unreachable, it's done automatically after signing in
+    /// and before sending the next messages, but can be also invoked manually. Does nothing if the journal is disabled.
+    pub async fn flush_journal(&self) -> Result<(), IggyError> {
+        let Some(journal) = &self.journal else {
             return Ok(());
+        };
+
+        let client = self.client.read().await;
+        let mut journal = journal.lock().await;
+        Self::replay_journal(client.as_ref(), &mut journal).await
+    }
+
+    /// Sends the batch, or appends it to the journal if the server is unreachable and the journal is enabled.
+    /// The journaled messages are replayed first, so the messages are always sent in order.
+    async fn send_or_journal(
+        &self,
+        client: &dyn Client,
+        stream: &Identifier,
+        topic: &Identifier,
+        partitioning: &Partitioning,
+        batch: &mut [Message],
+    ) -> Result<(), IggyError> {
+        if self.fenced.load(ORDERING) {
+            return Err(IggyError::StaleProducerEpoch(self.epoch.load(ORDERING)));
         }
 
-        for batch in messages.chunks_mut(batch_size) {
-            self.last_sent_at
-                .store(IggyTimestamp::now().into(), ORDERING);
-            client
-                .send_messages(stream, topic, &partitioning, batch)
-                .await?;
+        let Some(journal) = &self.journal else {
+            return client
+                .send_messages_with_ack(stream, topic, partitioning, self.ack, batch)
+                .await;
+        };
+
+        let mut journal = journal.lock().await;
+        let mut result = Self::replay_journal(client, &mut journal).await;
+        if result.is_ok() {
+            result = client
+                .send_messages_with_ack(stream, topic, partitioning, self.ack, batch)
+                .await;
+        }
+
+        match result {
+            Err(error) if journal::is_connection_error(&error) => {
+                warn!(
+                    "Server is unreachable: {error}, journaling {} message(s) to be sent later.",
+                    batch.len()
+                );
+                journal
+                    .append(stream, topic, partitioning, self.ack, batch)
+                    .await
+            }
+            result => result,
         }
-        Ok(())
+    }
+
+    async fn replay_journal(
+        client: &dyn Client,
+        journal: &mut ProducerJournal,
+    ) -> Result<(), IggyError> {
+        if journal.is_empty() {
+            return Ok(());
+        }
+
+        let batches_count = journal.len();
+        trace!("Replaying {batches_count} batch(es) from the producer journal...");
+        let mut replayed_batches = 0;
+        let mut result = Ok(());
+        while let Some(mut batch) = journal.get(replayed_batches)? {
+            if let Err(error) = client
+                .send_messages_with_ack(
+                    &batch.stream_id,
+                    &batch.topic_id,
+                    &batch.partitioning,
+                    batch.ack,
+                    &mut batch.messages,
+                )
+                .await
+            {
+                if journal::is_connection_error(&error) {
+                    result = Err(error);
+                    break;
+                }
+
+                // The batch rejected by the server would block the journal forever, so it's dropped.
+                error!(
+                    "Failed to replay {} journaled message(s): {error}, dropping them.",
+                    batch.messages.len()
+                );
+            }
+            replayed_batches += 1;
+        }
+
+        journal.remove_first(replayed_batches).await?;
+        if replayed_batches > 0 {
+            info!(
+                "Replayed {replayed_batches}/{batches_count} batch(es) from the producer journal."
+            );
+        }
+        result
     }
 
     async fn wait_before_sending(interval: u64, last_sent_at: u64) {
@@ -383,6 +618,10 @@ impl IggyProducer {
     fn encrypt_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
         if let Some(encryptor) = &self.encryptor {
             for message in messages {
+                if message.is_tombstone() {
+                    continue;
+                }
+
                 message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
                 message.length = message.payload.len() as u32;
             }
@@ -390,25 +629,45 @@ impl IggyProducer {
         Ok(())
     }
 
-    fn get_partitioning(
+    /// Groups the messages by the target partition, preserving the order of the messages within each partition.
+    fn partition_messages(
         &self,
         stream: &Identifier,
         topic: &Identifier,
-        messages: &[Message],
+        messages: Vec<Message>,
         partitioning: Option<Arc<Partitioning>>,
-    ) -> Result<Arc<Partitioning>, IggyError> {
-        if let Some(partitioner) = &self.partitioner {
-            trace!("Calculating partition id using custom partitioner.");
-            let partition_id = partitioner.calculate_partition_id(stream, topic, messages)?;
-            Ok(Arc::new(Partitioning::partition_id(partition_id)))
-        } else {
+    ) -> Result<Vec<PartitionedMessages>, IggyError> {
+        let Some(partitioner) = &self.partitioner else {
             trace!("Using the provided partitioning.");
-            Ok(partitioning.unwrap_or_else(|| {
+            let partitioning = partitioning.unwrap_or_else(|| {
                 self.partitioning
                     .clone()
                     .unwrap_or_else(|| self.default_partitioning.clone())
-            }))
+            });
+            return Ok(vec![(partitioning, messages)]);
+        };
+
+        trace!("Calculating partition ids using the partitioner.");
+        let partition_ids = partitioner.calculate_partition_ids(stream, topic, &messages)?;
+        if partition_ids.len() != messages.len() {
+            error!(
+                "Partitioner returned {} partition ids for {} messages.",
+                partition_ids.len(),
+                messages.len()
+            );
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let mut partitions: BTreeMap<u32, Vec<Message>> = BTreeMap::new();
+        for (partition_id, message) in partition_ids.into_iter().zip(messages) {
+            partitions.entry(partition_id).or_default().push(message);
         }
+        Ok(partitions
+            .into_iter()
+            .map(|(partition_id, messages)| {
+                (Arc::new(Partitioning::partition_id(partition_id)), messages)
+            })
+            .collect())
     }
 }
 
@@ -429,6 +688,10 @@ pub struct IggyProducerBuilder {
     topic_partitions_count: u32,
     topic_replication_factor: Option<u8>,
     retry_interval: IggyDuration,
+    ack: AckLevel,
+    journal: Option<JournalConfig>,
+    exclusive: bool,
+    with_epoch: bool,
     pub topic_message_expiry: IggyExpiry,
     pub topic_max_size: MaxTopicSize,
 }
@@ -460,6 +723,10 @@ impl IggyProducerBuilder {
             topic_partitions_count: 1,
             topic_replication_factor: None,
             retry_interval: IggyDuration::ONE_SECOND,
+            ack: AckLevel::default(),
+            journal: None,
+            exclusive: false,
+            with_epoch: false,
             topic_message_expiry: IggyExpiry::ServerDefault,
             topic_max_size: MaxTopicSize::ServerDefault,
         }
@@ -609,6 +876,57 @@ impl IggyProducerBuilder {
         }
     }
 
+    /// Sets the acknowledgement level which determines when the server responds to the sent messages.
+    pub fn ack(self, ack: AckLevel) -> Self {
+        Self { ack, ..self }
+    }
+
+    /// Enables the local journal, to which the messages are spilled when the server is unreachable,
+    /// and from which they're replayed in order after reconnecting, also when the producer is restarted.
+    pub fn journal(self, config: JournalConfig) -> Self {
+        Self {
+            journal: Some(config),
+            ..self
+        }
+    }
+
+    /// Disables the local journal, the sending fails when the server is unreachable.
+    pub fn without_journal(self) -> Self {
+        Self {
+            journal: None,
+            ..self
+        }
+    }
+
+    /// Makes the producer the only one allowed to append to the topic, as long as its connection is open.
+    /// The initialization fails with `ProducerFenced` error if another producer already holds the topic.
+    pub fn exclusive(self) -> Self {
+        Self {
+            exclusive: true,
+            ..self
+        }
+    }
+
+    /// Allows the other producers to append to the topic at the same time.
+    pub fn non_exclusive(self) -> Self {
+        Self {
+            exclusive: false,
+            with_epoch: false,
+            ..self
+        }
+    }
+
+    /// Makes the producer the exclusive one, bound to the epoch bumped by the server during the initialization.
+    /// Unlike `exclusive()`, the new producer instance takes over the topic and fences the previous one (e.g. after failover),
+    /// which is then no longer able to send the messages, even after reconnecting.
+    pub fn exclusive_with_epoch(self) -> Self {
+        Self {
+            exclusive: true,
+            with_epoch: true,
+            ..self
+        }
+    }
+
     pub fn build(self) -> IggyProducer {
         IggyProducer::new(
             self.client,
@@ -628,6 +946,10 @@ impl IggyProducerBuilder {
             self.topic_message_expiry,
             self.topic_max_size,
             self.retry_interval,
+            self.ack,
+            self.journal,
+            self.exclusive,
+            self.with_epoch,
         )
     }
 }
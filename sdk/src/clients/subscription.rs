@@ -0,0 +1,398 @@
+use crate::client::Client;
+use crate::clients::consumer::{IggyConsumer, ReceivedMessage};
+use crate::consumer::Consumer;
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::locking::IggySharedMut;
+use crate::models::header::HeaderKey;
+use crate::models::messages::PolledMessage;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use futures_util::StreamExt;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+use tokio::select;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tracing::{error, info, warn};
+use xxhash_rust::xxh32::xxh32;
+
+/// The maximum number of messages queued for a single worker of the parallel subscription,
+/// after which the messages are no longer polled until the worker catches up.
+const WORKER_QUEUE_CAPACITY: usize = 1000;
+
+/// The handler of the messages received by the subscription, invoked for each message, one at a time and in order
+/// (or in order per message key, when the subscription is parallel).
+///
+/// The message is acknowledged (i.e. its offset is stored on the server) only once the handler calls `ack` on the provided handle,
+/// which also acknowledges all the previous messages in the same partition.
+#[async_trait]
+pub trait MessageHandler: Send + Sync {
+    async fn handle(&self, message: ReceivedMessage, ack: AckHandle) -> Result<(), IggyError>;
+}
+
+#[async_trait]
+impl<F, Fut> MessageHandler for F
+where
+    F: Fn(ReceivedMessage, AckHandle) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), IggyError>> + Send,
+{
+    async fn handle(&self, message: ReceivedMessage, ack: AckHandle) -> Result<(), IggyError> {
+        self(message, ack).await
+    }
+}
+
+/// The handle passed to the `MessageHandler` along with the message, which stores its offset on the server once acknowledged.
+///
+/// When the subscription is parallel, the offset is stored only once all the earlier messages in the partition have been acknowledged,
+/// thus the message which is never acknowledged holds back the offset of the partition.
+#[derive(Debug)]
+pub struct AckHandle {
+    committer: Arc<OffsetCommitter>,
+    partition_id: u32,
+    offset: u64,
+}
+
+impl AckHandle {
+    /// Returns the partition ID of the message.
+    pub fn partition_id(&self) -> u32 {
+        self.partition_id
+    }
+
+    /// Returns the offset of the message.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Acknowledges the message by storing its offset on the server (or marking it as completed, when the subscription is parallel).
+    pub async fn ack(self) -> Result<(), IggyError> {
+        self.committer
+            .complete(self.partition_id, self.offset)
+            .await
+    }
+}
+
+/// Stores the offsets of the acknowledged messages on the server,
+/// tracking the completion of the messages dispatched in parallel (if any) to keep the stored offsets contiguous.
+#[derive(Debug)]
+pub(crate) struct OffsetCommitter {
+    pub(crate) client: IggySharedMut<Box<dyn Client>>,
+    pub(crate) consumer: Arc<Consumer>,
+    pub(crate) stream_id: Arc<Identifier>,
+    pub(crate) topic_id: Arc<Identifier>,
+    pub(crate) last_stored_offsets: Arc<DashMap<u32, AtomicU64>>,
+    pub(crate) tracker: Option<Mutex<OffsetTracker>>,
+}
+
+impl OffsetCommitter {
+    async fn dispatch(&self, partition_id: u32, offset: u64) {
+        if let Some(tracker) = &self.tracker {
+            tracker.lock().await.dispatch(partition_id, offset);
+        }
+    }
+
+    async fn complete(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        let Some(tracker) = &self.tracker else {
+            return self.store(partition_id, offset).await;
+        };
+
+        // The lock is held while storing the offset, so that the offsets completed concurrently aren't stored out of order.
+        let mut tracker = tracker.lock().await;
+        let Some(offset) = tracker.complete(partition_id, offset) else {
+            return Ok(());
+        };
+
+        self.store(partition_id, offset).await
+    }
+
+    async fn store(&self, partition_id: u32, offset: u64) -> Result<(), IggyError> {
+        IggyConsumer::store_consumer_offset(
+            &self.client,
+            &self.consumer,
+            &self.stream_id,
+            &self.topic_id,
+            partition_id,
+            offset,
+            &self.last_stored_offsets,
+        )
+        .await
+    }
+}
+
+/// Tracks the offsets of the messages dispatched in parallel, which might complete in any order.
+#[derive(Debug, Default)]
+pub(crate) struct OffsetTracker {
+    partitions: HashMap<u32, PartitionOffsets>,
+}
+
+#[derive(Debug, Default)]
+struct PartitionOffsets {
+    pending: BTreeSet<u64>,
+    completed: BTreeSet<u64>,
+}
+
+impl OffsetTracker {
+    fn dispatch(&mut self, partition_id: u32, offset: u64) {
+        self.partitions
+            .entry(partition_id)
+            .or_default()
+            .pending
+            .insert(offset);
+    }
+
+    /// Marks the message as completed and returns the highest offset, up to which all the dispatched messages have completed (if changed).
+    fn complete(&mut self, partition_id: u32, offset: u64) -> Option<u64> {
+        let partition = self.partitions.get_mut(&partition_id)?;
+        if !partition.pending.remove(&offset) {
+            return None;
+        }
+
+        partition.completed.insert(offset);
+        let completed_offset = match partition.pending.first() {
+            Some(first_pending_offset) => partition
+                .completed
+                .range(..*first_pending_offset)
+                .next_back()
+                .copied()?,
+            None => partition.completed.last().copied()?,
+        };
+        partition.completed = partition.completed.split_off(&(completed_offset + 1));
+        Some(completed_offset)
+    }
+}
+
+/// The subscription which polls the messages in the background and dispatches them to the `MessageHandler`,
+/// until it's unsubscribed or dropped.
+#[derive(Debug)]
+pub struct Subscription {
+    consumer_name: String,
+    stop_sender: Option<oneshot::Sender<()>>,
+    task: JoinHandle<Result<(), IggyError>>,
+}
+
+impl Subscription {
+    /// Returns the name of the consumer.
+    pub fn name(&self) -> &str {
+        &self.consumer_name
+    }
+
+    /// Returns `true` if the messages are still being polled and dispatched to the handler.
+    pub fn is_active(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Stops polling the messages, waits until the handler has processed the already dispatched messages (if any) and shuts down the consumer.
+    pub async fn unsubscribe(mut self) -> Result<(), IggyError> {
+        if let Some(stop_sender) = self.stop_sender.take() {
+            let _ = stop_sender.send(());
+        }
+
+        match (&mut self.task).await {
+            Ok(result) => result,
+            Err(error) => {
+                error!(
+                    "Subscription of consumer: {} has failed. {error}",
+                    self.consumer_name
+                );
+                Err(IggyError::Error)
+            }
+        }
+    }
+}
+
+impl IggyConsumer {
+    /// Initializes the consumer (if needed) and starts dispatching the received messages to the handler in the background.
+    /// The auto-commit should be disabled, so that only the messages acknowledged by the handler have their offsets stored.
+    pub async fn subscribe<H: MessageHandler + 'static>(
+        mut self,
+        handler: H,
+    ) -> Result<Subscription, IggyError> {
+        self.init().await?;
+        let consumer_name = self.name().to_owned();
+        let committer = Arc::new(self.offset_committer(false));
+        let (stop_sender, mut stop_receiver) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            info!("Subscribed consumer: {}", self.name());
+            while let Some(message) = self.next_subscribed_message(&mut stop_receiver).await {
+                handle_message(&handler, &committer, message, self.name()).await;
+            }
+
+            self.complete_pending_poll().await;
+            info!("Unsubscribed consumer: {}", self.name());
+            self.shutdown().await
+        });
+
+        Ok(Subscription {
+            consumer_name,
+            stop_sender: Some(stop_sender),
+            task,
+        })
+    }
+
+    /// Initializes the consumer (if needed) and starts dispatching the received messages to the pool of workers in the background,
+    /// each invoking the handler for one message at a time. The messages with the same key (the value of the provided header,
+    /// or the message ID if not provided) are always dispatched to the same worker, thus they're processed in order.
+    /// The offset is stored only once all the earlier messages in the partition have been acknowledged.
+    pub async fn subscribe_parallel<H: MessageHandler + 'static>(
+        mut self,
+        workers: u32,
+        key_header: Option<HeaderKey>,
+        handler: H,
+    ) -> Result<Subscription, IggyError> {
+        if workers == 0 {
+            return Err(IggyError::InvalidConfiguration);
+        }
+
+        self.init().await?;
+        let consumer_name = self.name().to_owned();
+        let committer = Arc::new(self.offset_committer(true));
+        let handler = Arc::new(handler);
+        let mut senders = Vec::with_capacity(workers as usize);
+        let mut worker_tasks = Vec::with_capacity(workers as usize);
+        for _ in 0..workers {
+            let (sender, receiver) = flume::bounded::<ReceivedMessage>(WORKER_QUEUE_CAPACITY);
+            let handler = handler.clone();
+            let committer = committer.clone();
+            let consumer_name = consumer_name.clone();
+            worker_tasks.push(tokio::spawn(async move {
+                while let Ok(message) = receiver.recv_async().await {
+                    handle_message(handler.as_ref(), &committer, message, &consumer_name).await;
+                }
+            }));
+            senders.push(sender);
+        }
+
+        let (stop_sender, mut stop_receiver) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            info!(
+                "Subscribed consumer: {} with {workers} parallel workers",
+                self.name()
+            );
+            while let Some(message) = self.next_subscribed_message(&mut stop_receiver).await {
+                let worker = get_worker(&message.message, key_header.as_ref(), workers);
+                committer
+                    .dispatch(message.partition_id, message.message.offset)
+                    .await;
+                select! {
+                    _ = &mut stop_receiver => break,
+                    result = senders[worker].send_async(message) => {
+                        if result.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // The workers stop once they've processed all the messages already dispatched to them.
+            senders.clear();
+            for worker_task in worker_tasks {
+                let _ = worker_task.await;
+            }
+            self.complete_pending_poll().await;
+            info!("Unsubscribed consumer: {}", self.name());
+            self.shutdown().await
+        });
+
+        Ok(Subscription {
+            consumer_name,
+            stop_sender: Some(stop_sender),
+            task,
+        })
+    }
+
+    /// Returns the next received message, retrying on failures, or `None` once the subscription has been stopped.
+    async fn next_subscribed_message(
+        &mut self,
+        stop_receiver: &mut oneshot::Receiver<()>,
+    ) -> Option<ReceivedMessage> {
+        loop {
+            let message = select! {
+                _ = &mut *stop_receiver => return None,
+                message = self.next() => message?,
+            };
+
+            match message {
+                Ok(message) => return Some(message),
+                Err(error) => {
+                    warn!(
+                        "Failed to receive the message for consumer: {}. {error}",
+                        self.name()
+                    );
+                    select! {
+                        _ = &mut *stop_receiver => return None,
+                        _ = sleep(self.retry_interval().get_duration()) => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn handle_message<H: MessageHandler>(
+    handler: &H,
+    committer: &Arc<OffsetCommitter>,
+    message: ReceivedMessage,
+    consumer_name: &str,
+) {
+    let partition_id = message.partition_id;
+    let offset = message.message.offset;
+    let ack = AckHandle {
+        committer: committer.clone(),
+        partition_id,
+        offset,
+    };
+    if let Err(error) = handler.handle(message, ack).await {
+        error!(
+            "Failed to handle the message at offset: {offset}, partition ID: {partition_id} for consumer: {consumer_name}. {error}"
+        );
+    }
+}
+
+fn get_worker(message: &PolledMessage, key_header: Option<&HeaderKey>, workers: u32) -> usize {
+    let header_value = key_header.and_then(|key| {
+        message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(key))
+    });
+    let hash = match header_value {
+        Some(value) => xxh32(&value.value, 0),
+        None => xxh32(&message.id.to_le_bytes(), 0),
+    };
+    (hash % workers) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_should_be_completed_only_once_all_earlier_offsets_are_completed() {
+        let mut tracker = OffsetTracker::default();
+        for offset in 10..15 {
+            tracker.dispatch(1, offset);
+        }
+        tracker.dispatch(2, 0);
+
+        assert_eq!(tracker.complete(1, 12), None);
+        assert_eq!(tracker.complete(1, 11), None);
+        assert_eq!(tracker.complete(1, 10), Some(12));
+        assert_eq!(tracker.complete(1, 14), None);
+        assert_eq!(tracker.complete(2, 0), Some(0));
+        assert_eq!(tracker.complete(1, 13), Some(14));
+    }
+
+    #[test]
+    fn offset_which_is_not_pending_should_not_be_completed() {
+        let mut tracker = OffsetTracker::default();
+        assert_eq!(tracker.complete(1, 0), None);
+
+        tracker.dispatch(1, 0);
+        assert_eq!(tracker.complete(1, 0), Some(0));
+        assert_eq!(tracker.complete(1, 0), None);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::clients::consumer::IggyConsumer;
+use crate::error::IggyError;
+use crate::models::messages::PolledMessage;
+use bytes::Bytes;
+use futures::Stream;
+use futures_util::StreamExt;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tracing::warn;
+
+/// Decodes the payload of the polled message into the user type.
+pub trait MessageDecoder<T>: Send + Sync {
+    fn decode(&self, message: &PolledMessage) -> Result<T, IggyError>;
+}
+
+impl<T, F> MessageDecoder<T> for F
+where
+    F: Fn(&PolledMessage) -> Result<T, IggyError> + Send + Sync,
+{
+    fn decode(&self, message: &PolledMessage) -> Result<T, IggyError> {
+        self(message)
+    }
+}
+
+/// Decodes the JSON payload into any type implementing `DeserializeOwned`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonDecoder;
+
+impl<T: DeserializeOwned> MessageDecoder<T> for JsonDecoder {
+    fn decode(&self, message: &PolledMessage) -> Result<T, IggyError> {
+        serde_json::from_slice(&message.payload)
+            .map_err(|error| IggyError::CannotParseJson(error.to_string()))
+    }
+}
+
+/// The message decoded into the user type, along with the polled message it was decoded from.
+#[derive(Debug)]
+pub struct DecodedMessage<T> {
+    pub value: T,
+    pub message: PolledMessage,
+    pub current_offset: u64,
+    pub partition_id: u32,
+}
+
+/// The message which couldn't be decoded, delivered to the failures channel instead of the stream of the decoded messages.
+#[derive(Debug)]
+pub struct DecodeFailure {
+    pub partition_id: u32,
+    pub offset: u64,
+    pub payload: Bytes,
+    pub error: IggyError,
+}
+
+/// The consumer decoding the received messages into the user type, which yields only the decoded messages,
+/// while the messages which couldn't be decoded are sent to the separate failures channel and skipped.
+pub struct TypedConsumer<T, D = JsonDecoder> {
+    consumer: IggyConsumer,
+    decoder: D,
+    failures: flume::Sender<DecodeFailure>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T, D: MessageDecoder<T>> TypedConsumer<T, D> {
+    /// Initializes the underlying consumer.
+    pub async fn init(&mut self) -> Result<(), IggyError> {
+        self.consumer.init().await
+    }
+
+    /// Shuts down the underlying consumer.
+    pub async fn shutdown(&mut self) -> Result<(), IggyError> {
+        self.consumer.shutdown().await
+    }
+
+    /// Returns the underlying consumer, e.g. to store the offset manually.
+    pub fn consumer(&self) -> &IggyConsumer {
+        &self.consumer
+    }
+}
+
+impl IggyConsumer {
+    /// Returns the consumer decoding the JSON payloads into the user type, along with the receiver of the decode failures.
+    pub fn typed<T: DeserializeOwned>(
+        self,
+    ) -> (
+        TypedConsumer<T, JsonDecoder>,
+        flume::Receiver<DecodeFailure>,
+    ) {
+        self.with_decoder(JsonDecoder)
+    }
+
+    /// Returns the consumer decoding the payloads into the user type with the custom decoder, along with the receiver of the decode failures.
+    pub fn with_decoder<T, D: MessageDecoder<T>>(
+        self,
+        decoder: D,
+    ) -> (TypedConsumer<T, D>, flume::Receiver<DecodeFailure>) {
+        let (failures, failures_receiver) = flume::unbounded();
+        (
+            TypedConsumer {
+                consumer: self,
+                decoder,
+                failures,
+                _type: PhantomData,
+            },
+            failures_receiver,
+        )
+    }
+}
+
+impl<T, D: MessageDecoder<T> + Unpin> Stream for TypedConsumer<T, D> {
+    type Item = Result<DecodedMessage<T>, IggyError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let received_message = match self.consumer.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(received_message))) => received_message,
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let message = received_message.message;
+            match self.decoder.decode(&message) {
+                Ok(value) => {
+                    return Poll::Ready(Some(Ok(DecodedMessage {
+                        value,
+                        message,
+                        current_offset: received_message.current_offset,
+                        partition_id: received_message.partition_id,
+                    })))
+                }
+                Err(error) => {
+                    warn!(
+                        "Failed to decode the message at offset: {}, partition ID: {} for consumer: {}. {error}",
+                        message.offset,
+                        received_message.partition_id,
+                        self.consumer.name()
+                    );
+                    // The failures are dropped if nobody receives them.
+                    let _ = self.failures.send(DecodeFailure {
+                        partition_id: received_message.partition_id,
+                        offset: message.offset,
+                        payload: message.payload,
+                        error,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::messages::MessageState;
+    use crate::utils::timestamp::IggyTimestamp;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Order {
+        id: u32,
+    }
+
+    fn create_message(payload: &str) -> PolledMessage {
+        PolledMessage::create(
+            1,
+            MessageState::Available,
+            IggyTimestamp::now(),
+            0,
+            Bytes::from(payload.to_owned()),
+            0,
+            None,
+        )
+    }
+
+    #[test]
+    fn json_payload_should_be_decoded() {
+        let order: Order = JsonDecoder.decode(&create_message(r#"{"id":7}"#)).unwrap();
+        assert_eq!(order, Order { id: 7 });
+
+        let result: Result<Order, IggyError> = JsonDecoder.decode(&create_message("not json"));
+        assert!(matches!(result, Err(IggyError::CannotParseJson(_))));
+    }
+
+    #[test]
+    fn payload_should_be_decoded_with_custom_decoder() {
+        let decoder =
+            |message: &PolledMessage| -> Result<usize, IggyError> { Ok(message.payload.len()) };
+        assert_eq!(decoder.decode(&create_message("test")).unwrap(), 4);
+    }
+}
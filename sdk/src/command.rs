@@ -13,12 +13,30 @@ pub const GET_STATS: &str = "stats";
 pub const GET_STATS_CODE: u32 = 10;
 pub const GET_SNAPSHOT_FILE: &str = "snapshot";
 pub const GET_SNAPSHOT_FILE_CODE: u32 = 11;
+pub const ROTATE_ENCRYPTION_KEY: &str = "encryption_key.rotate";
+pub const ROTATE_ENCRYPTION_KEY_CODE: u32 = 12;
+pub const SET_LOG_LEVEL: &str = "log_level.set";
+pub const SET_LOG_LEVEL_CODE: u32 = 13;
+pub const SET_BACKGROUND_IO_RATE: &str = "background_io_rate.set";
+pub const SET_BACKGROUND_IO_RATE_CODE: u32 = 14;
+pub const GET_CLUSTER_METADATA: &str = "cluster.metadata";
+pub const GET_CLUSTER_METADATA_CODE: u32 = 15;
+pub const DRAIN_NODE: &str = "node.drain";
+pub const DRAIN_NODE_CODE: u32 = 16;
+pub const DECOMMISSION_NODE: &str = "node.decommission";
+pub const DECOMMISSION_NODE_CODE: u32 = 17;
+pub const GET_NODE_STATUS: &str = "node.status";
+pub const GET_NODE_STATUS_CODE: u32 = 18;
 pub const GET_ME: &str = "me";
 pub const GET_ME_CODE: u32 = 20;
 pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const GET_SESSIONS: &str = "session.list";
+pub const GET_SESSIONS_CODE: u32 = 23;
+pub const KILL_SESSION: &str = "session.kill";
+pub const KILL_SESSION_CODE: u32 = 24;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -51,10 +69,34 @@ pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
 pub const FLUSH_UNSAVED_BUFFER: &str = "message.flush_unsaved_buffer";
 pub const FLUSH_UNSAVED_BUFFER_CODE: u32 = 102;
+pub const POLL_MESSAGES_FROM_PARTITIONS: &str = "message.poll_partitions";
+pub const POLL_MESSAGES_FROM_PARTITIONS_CODE: u32 = 103;
+pub const PEEK_MESSAGES: &str = "message.peek";
+pub const PEEK_MESSAGES_CODE: u32 = 104;
+pub const ACQUIRE_EXCLUSIVE_PRODUCER: &str = "message.acquire_exclusive_producer";
+pub const ACQUIRE_EXCLUSIVE_PRODUCER_CODE: u32 = 105;
+pub const INIT_PRODUCER_EPOCH: &str = "message.init_producer_epoch";
+pub const INIT_PRODUCER_EPOCH_CODE: u32 = 106;
+pub const COPY_MESSAGES: &str = "message.copy";
+pub const COPY_MESSAGES_CODE: u32 = 107;
+pub const GET_MESSAGE_BY_KEY: &str = "message.get_by_key";
+pub const GET_MESSAGE_BY_KEY_CODE: u32 = 108;
+pub const FETCH_REPLICA_MESSAGES: &str = "message.fetch_replica";
+pub const FETCH_REPLICA_MESSAGES_CODE: u32 = 109;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
+pub const GET_CONSUMER_LAG: &str = "consumer_offset.lag";
+pub const GET_CONSUMER_LAG_CODE: u32 = 122;
+pub const RESET_CONSUMER_OFFSETS: &str = "consumer_offset.reset";
+pub const RESET_CONSUMER_OFFSETS_CODE: u32 = 123;
+pub const GET_CONSUMERS: &str = "consumer_offset.consumers";
+pub const GET_CONSUMERS_CODE: u32 = 124;
+pub const DELETE_CONSUMER: &str = "consumer_offset.delete_consumer";
+pub const DELETE_CONSUMER_CODE: u32 = 125;
+pub const DELETE_CONSUMER_OFFSETS: &str = "consumer_offset.delete";
+pub const DELETE_CONSUMER_OFFSETS_CODE: u32 = 126;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -79,10 +121,28 @@ pub const UPDATE_TOPIC: &str = "topic.update";
 pub const UPDATE_TOPIC_CODE: u32 = 304;
 pub const PURGE_TOPIC: &str = "topic.purge";
 pub const PURGE_TOPIC_CODE: u32 = 305;
+pub const GET_SCHEMA: &str = "schema.get";
+pub const GET_SCHEMA_CODE: u32 = 306;
+pub const GET_SCHEMAS: &str = "schema.list";
+pub const GET_SCHEMAS_CODE: u32 = 307;
+pub const REGISTER_SCHEMA: &str = "schema.register";
+pub const REGISTER_SCHEMA_CODE: u32 = 308;
+pub const SET_TOPIC_READ_ONLY: &str = "topic.read_only";
+pub const SET_TOPIC_READ_ONLY_CODE: u32 = 309;
+pub const GET_PARTITION: &str = "partition.get";
+pub const GET_PARTITION_CODE: u32 = 400;
 pub const CREATE_PARTITIONS: &str = "partition.create";
 pub const CREATE_PARTITIONS_CODE: u32 = 402;
 pub const DELETE_PARTITIONS: &str = "partition.delete";
 pub const DELETE_PARTITIONS_CODE: u32 = 403;
+pub const GET_PARTITION_OFFSETS: &str = "partition.offsets";
+pub const GET_PARTITION_OFFSETS_CODE: u32 = 404;
+pub const GET_OFFSET_BY_TIMESTAMP: &str = "partition.offset_by_timestamp";
+pub const GET_OFFSET_BY_TIMESTAMP_CODE: u32 = 405;
+pub const GET_MESSAGES_HISTOGRAM: &str = "partition.histogram";
+pub const GET_MESSAGES_HISTOGRAM_CODE: u32 = 406;
+pub const SEAL_PARTITION: &str = "partition.seal";
+pub const SEAL_PARTITION_CODE: u32 = 407;
 pub const GET_CONSUMER_GROUP: &str = "consumer_group.get";
 pub const GET_CONSUMER_GROUP_CODE: u32 = 600;
 pub const GET_CONSUMER_GROUPS: &str = "consumer_group.list";
@@ -95,6 +155,8 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const HEARTBEAT_CONSUMER_GROUP: &str = "consumer_group.heartbeat";
+pub const HEARTBEAT_CONSUMER_GROUP_CODE: u32 = 606;
 
 pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
     match code {
@@ -103,6 +165,8 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         GET_ME_CODE => Ok(GET_ME),
         GET_CLIENT_CODE => Ok(GET_CLIENT),
         GET_CLIENTS_CODE => Ok(GET_CLIENTS),
+        GET_SESSIONS_CODE => Ok(GET_SESSIONS),
+        KILL_SESSION_CODE => Ok(KILL_SESSION),
         GET_USER_CODE => Ok(GET_USER),
         GET_USERS_CODE => Ok(GET_USERS),
         CREATE_USER_CODE => Ok(CREATE_USER),
@@ -118,9 +182,21 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Ok(LOGIN_WITH_PERSONAL_ACCESS_TOKEN),
         SEND_MESSAGES_CODE => Ok(SEND_MESSAGES),
         POLL_MESSAGES_CODE => Ok(POLL_MESSAGES),
+        POLL_MESSAGES_FROM_PARTITIONS_CODE => Ok(POLL_MESSAGES_FROM_PARTITIONS),
+        PEEK_MESSAGES_CODE => Ok(PEEK_MESSAGES),
+        ACQUIRE_EXCLUSIVE_PRODUCER_CODE => Ok(ACQUIRE_EXCLUSIVE_PRODUCER),
+        INIT_PRODUCER_EPOCH_CODE => Ok(INIT_PRODUCER_EPOCH),
+        COPY_MESSAGES_CODE => Ok(COPY_MESSAGES),
+        GET_MESSAGE_BY_KEY_CODE => Ok(GET_MESSAGE_BY_KEY),
+        FETCH_REPLICA_MESSAGES_CODE => Ok(FETCH_REPLICA_MESSAGES),
         FLUSH_UNSAVED_BUFFER_CODE => Ok(FLUSH_UNSAVED_BUFFER),
         STORE_CONSUMER_OFFSET_CODE => Ok(STORE_CONSUMER_OFFSET),
         GET_CONSUMER_OFFSET_CODE => Ok(GET_CONSUMER_OFFSET),
+        GET_CONSUMER_LAG_CODE => Ok(GET_CONSUMER_LAG),
+        RESET_CONSUMER_OFFSETS_CODE => Ok(RESET_CONSUMER_OFFSETS),
+        GET_CONSUMERS_CODE => Ok(GET_CONSUMERS),
+        DELETE_CONSUMER_CODE => Ok(DELETE_CONSUMER),
+        DELETE_CONSUMER_OFFSETS_CODE => Ok(DELETE_CONSUMER_OFFSETS),
         GET_STREAM_CODE => Ok(GET_STREAM),
         GET_STREAMS_CODE => Ok(GET_STREAMS),
         CREATE_STREAM_CODE => Ok(CREATE_STREAM),
@@ -133,15 +209,32 @@ pub fn get_name_from_code(code: u32) -> Result<&'static str, IggyError> {
         DELETE_TOPIC_CODE => Ok(DELETE_TOPIC),
         UPDATE_TOPIC_CODE => Ok(UPDATE_TOPIC),
         PURGE_TOPIC_CODE => Ok(PURGE_TOPIC),
+        GET_SCHEMA_CODE => Ok(GET_SCHEMA),
+        GET_SCHEMAS_CODE => Ok(GET_SCHEMAS),
+        REGISTER_SCHEMA_CODE => Ok(REGISTER_SCHEMA),
+        SET_TOPIC_READ_ONLY_CODE => Ok(SET_TOPIC_READ_ONLY),
+        GET_PARTITION_CODE => Ok(GET_PARTITION),
         CREATE_PARTITIONS_CODE => Ok(CREATE_PARTITIONS),
         DELETE_PARTITIONS_CODE => Ok(DELETE_PARTITIONS),
+        GET_PARTITION_OFFSETS_CODE => Ok(GET_PARTITION_OFFSETS),
+        GET_OFFSET_BY_TIMESTAMP_CODE => Ok(GET_OFFSET_BY_TIMESTAMP),
+        GET_MESSAGES_HISTOGRAM_CODE => Ok(GET_MESSAGES_HISTOGRAM),
+        SEAL_PARTITION_CODE => Ok(SEAL_PARTITION),
         GET_CONSUMER_GROUP_CODE => Ok(GET_CONSUMER_GROUP),
         GET_CONSUMER_GROUPS_CODE => Ok(GET_CONSUMER_GROUPS),
         CREATE_CONSUMER_GROUP_CODE => Ok(CREATE_CONSUMER_GROUP),
         DELETE_CONSUMER_GROUP_CODE => Ok(DELETE_CONSUMER_GROUP),
         JOIN_CONSUMER_GROUP_CODE => Ok(JOIN_CONSUMER_GROUP),
         LEAVE_CONSUMER_GROUP_CODE => Ok(LEAVE_CONSUMER_GROUP),
+        HEARTBEAT_CONSUMER_GROUP_CODE => Ok(HEARTBEAT_CONSUMER_GROUP),
         GET_SNAPSHOT_FILE_CODE => Ok(GET_SNAPSHOT_FILE),
+        ROTATE_ENCRYPTION_KEY_CODE => Ok(ROTATE_ENCRYPTION_KEY),
+        SET_LOG_LEVEL_CODE => Ok(SET_LOG_LEVEL),
+        SET_BACKGROUND_IO_RATE_CODE => Ok(SET_BACKGROUND_IO_RATE),
+        GET_CLUSTER_METADATA_CODE => Ok(GET_CLUSTER_METADATA),
+        DRAIN_NODE_CODE => Ok(DRAIN_NODE),
+        DECOMMISSION_NODE_CODE => Ok(DECOMMISSION_NODE),
+        GET_NODE_STATUS_CODE => Ok(GET_NODE_STATUS),
         _ => Err(IggyError::InvalidCommand),
     }
 }
@@ -1,13 +1,22 @@
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use serde::{
     de::{self, Deserializer, Visitor},
     Deserialize, Serialize, Serializer,
 };
+use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
 use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+
+/// The name of the message header which holds the compression algorithm of the payload.
+pub const CONTENT_ENCODING_HEADER: &str = "content-encoding";
 
 // for now only those, in the future will add snappy, lz4, zstd (same as in confluent kafka) in addition to that
 // we should consider brotli as well.
@@ -48,6 +57,62 @@ impl CompressionAlgorithm {
             _ => Err(IggyError::InvalidCommand),
         }
     }
+
+    /// Compresses the data, returns it as is for `CompressionAlgorithm::None`.
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder
+                    .write_all(data)
+                    .map_err(|_| IggyError::CannotCompressData)?;
+                encoder.finish().map_err(|_| IggyError::CannotCompressData)
+            }
+        }
+    }
+
+    /// Decompresses the data, returns it as is for `CompressionAlgorithm::None`.
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        match self {
+            CompressionAlgorithm::None => Ok(data.to_vec()),
+            CompressionAlgorithm::Gzip => {
+                let mut decompressed = Vec::new();
+                GzDecoder::new(data)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|_| IggyError::CannotDecompressData)?;
+                Ok(decompressed)
+            }
+        }
+    }
+
+    /// Returns the header key and value representing the compression of the payload.
+    pub fn to_header(&self) -> Result<(HeaderKey, HeaderValue), IggyError> {
+        Ok((
+            HeaderKey::new(CONTENT_ENCODING_HEADER)?,
+            HeaderValue::from_str(&self.to_string())?,
+        ))
+    }
+
+    /// Returns the compression of the payload recorded in the provided headers, if any.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+
+        let Some(value) = headers.get(&HeaderKey::new(CONTENT_ENCODING_HEADER)?) else {
+            return Ok(None);
+        };
+
+        let value = value
+            .as_str()
+            .map_err(|_| IggyError::InvalidContentEncoding)?;
+        CompressionAlgorithm::from_str(value)
+            .map(Some)
+            .map_err(|_| IggyError::InvalidContentEncoding)
+    }
 }
 
 impl Display for CompressionAlgorithm {
@@ -128,6 +193,33 @@ mod tests {
         assert_eq!(gzip_alg.unwrap(), CompressionAlgorithm::Gzip);
     }
 
+    #[test]
+    fn should_compress_and_decompress_data() {
+        let data = b"iggy".repeat(100);
+        for algorithm in [CompressionAlgorithm::None, CompressionAlgorithm::Gzip] {
+            let compressed = algorithm.compress(&data).unwrap();
+            assert_eq!(algorithm.decompress(&compressed).unwrap(), data);
+        }
+        assert!(CompressionAlgorithm::Gzip.compress(&data).unwrap().len() < data.len());
+    }
+
+    #[test]
+    fn should_read_compression_from_headers() {
+        let (key, value) = CompressionAlgorithm::Gzip.to_header().unwrap();
+        let headers = Some(HashMap::from([(key, value)]));
+        assert_eq!(
+            CompressionAlgorithm::from_headers(&headers).unwrap(),
+            Some(CompressionAlgorithm::Gzip)
+        );
+        assert_eq!(CompressionAlgorithm::from_headers(&None).unwrap(), None);
+
+        let headers = Some(HashMap::from([(
+            HeaderKey::new(CONTENT_ENCODING_HEADER).unwrap(),
+            HeaderValue::from_str("brotli").unwrap(),
+        )]));
+        assert!(CompressionAlgorithm::from_headers(&headers).is_err());
+    }
+
     #[test]
     fn test_from_invalid_input() {
         let invalid_compression_kind = CompressionAlgorithm::from_str("invalid");
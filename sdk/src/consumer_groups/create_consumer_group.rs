@@ -1,15 +1,15 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u32_le, read_u8, BytesSerializable};
 use crate::command::{Command, CREATE_CONSUMER_GROUP_CODE};
 use crate::consumer_groups::MAX_NAME_LENGTH;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `CreateConsumerGroup` command creates a new consumer group for the topic.
 /// It has additional payload:
@@ -17,6 +17,7 @@ use std::str::from_utf8;
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID.
 /// - `name` - unique consumer group name, max length is 255 characters. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
+/// - `rebalance_strategy` - the way the partitions are reassigned to the members, either `eager` (default) or `sticky`.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -29,6 +30,9 @@ pub struct CreateConsumerGroup {
     pub group_id: Option<u32>,
     /// Unique consumer group name, max length is 255 characters.
     pub name: String,
+    /// The way the partitions are reassigned to the members whenever the group or the topic changes.
+    #[serde(default)]
+    pub rebalance_strategy: RebalanceStrategy,
 }
 
 impl Command for CreateConsumerGroup {
@@ -44,6 +48,7 @@ impl Default for CreateConsumerGroup {
             topic_id: Identifier::default(),
             group_id: None,
             name: "consumer_group_1".to_string(),
+            rebalance_strategy: RebalanceStrategy::default(),
         }
     }
 }
@@ -73,7 +78,7 @@ impl BytesSerializable for CreateConsumerGroup {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            4 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            6 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
@@ -81,6 +86,7 @@ impl BytesSerializable for CreateConsumerGroup {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.rebalance_strategy.as_code());
         bytes.freeze()
     }
 
@@ -94,16 +100,21 @@ impl BytesSerializable for CreateConsumerGroup {
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
-        let group_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let group_id = read_u32_le(&bytes, position)?;
         let group_id = if group_id == 0 { None } else { Some(group_id) };
-        let name_length = bytes[position + 4];
-        let name =
-            from_utf8(&bytes[position + 5..position + 5 + name_length as usize])?.to_string();
+        let name_length = read_u8(&bytes, position + 4)?;
+        let name = read_str(&bytes, position + 5, name_length as usize)?;
+        // The rebalance strategy is optional to keep the commands stored in the older state readable.
+        let rebalance_strategy = match bytes.get(position + 5 + name_length as usize) {
+            Some(code) => RebalanceStrategy::from_code(*code)?,
+            None => RebalanceStrategy::default(),
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             group_id,
             name,
+            rebalance_strategy,
         };
         Ok(command)
     }
@@ -113,11 +124,12 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.group_id.unwrap_or(0),
-            self.name
+            self.name,
+            self.rebalance_strategy
         )
     }
 }
@@ -125,6 +137,7 @@ impl Display for CreateConsumerGroup {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -133,6 +146,7 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Some(3),
             name: "test".to_string(),
+            rebalance_strategy: RebalanceStrategy::Sticky,
         };
 
         let bytes = command.to_bytes();
@@ -145,11 +159,14 @@ mod tests {
 
         let name_length = bytes[position + 4];
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize]).unwrap();
+        let rebalance_strategy =
+            RebalanceStrategy::from_code(bytes[position + 5 + name_length as usize]).unwrap();
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id.unwrap());
         assert_eq!(name, command.name);
+        assert_eq!(rebalance_strategy, command.rebalance_strategy);
     }
 
     #[test]
@@ -168,6 +185,7 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_u8(RebalanceStrategy::Sticky.as_code());
         let command = CreateConsumerGroup::from_bytes(bytes.freeze());
         assert!(command.is_ok());
 
@@ -176,5 +194,22 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id.unwrap(), group_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.rebalance_strategy, RebalanceStrategy::Sticky);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_without_rebalance_strategy() {
+        let command = CreateConsumerGroup {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            group_id: Some(3),
+            name: "test".to_string(),
+            rebalance_strategy: RebalanceStrategy::Sticky,
+        };
+        let bytes = command.to_bytes();
+
+        let command = CreateConsumerGroup::from_bytes(bytes.slice(..bytes.len() - 1)).unwrap();
+        assert_eq!(command.name, "test");
+        assert_eq!(command.rebalance_strategy, RebalanceStrategy::Eager);
     }
 }
@@ -1,5 +1,6 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u8, BytesSerializable};
 use crate::command::{Command, JOIN_CONSUMER_GROUP_CODE};
+use crate::consumer_groups::MAX_NAME_LENGTH;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::utils::sizeable::Sizeable;
@@ -13,6 +14,9 @@ use std::fmt::Display;
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `group_id` - unique consumer group ID (numeric or name).
+/// - `group_instance_id` - optional ID of the static member, max length is 255 characters.
+///   The member rejoining the group with the same instance ID (e.g. after a restart) within the session timeout
+///   reclaims its previous partitions, without triggering the rebalance.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct JoinConsumerGroup {
     /// Unique stream ID (numeric or name).
@@ -24,6 +28,9 @@ pub struct JoinConsumerGroup {
     /// Unique consumer group ID (numeric or name).
     #[serde(skip)]
     pub group_id: Identifier,
+    /// Optional ID of the static member, which keeps its partitions across the reconnections.
+    #[serde(default)]
+    pub group_instance_id: Option<String>,
 }
 
 impl Command for JoinConsumerGroup {
@@ -34,6 +41,12 @@ impl Command for JoinConsumerGroup {
 
 impl Validatable<IggyError> for JoinConsumerGroup {
     fn validate(&self) -> Result<(), IggyError> {
+        if let Some(group_instance_id) = &self.group_instance_id {
+            if group_instance_id.is_empty() || group_instance_id.len() > MAX_NAME_LENGTH {
+                return Err(IggyError::InvalidGroupInstanceId);
+            }
+        }
+
         Ok(())
     }
 }
@@ -43,12 +56,19 @@ impl BytesSerializable for JoinConsumerGroup {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let group_id_bytes = self.group_id.to_bytes();
+        let group_instance_id = self.group_instance_id.as_deref().unwrap_or_default();
         let mut bytes = BytesMut::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + group_id_bytes.len(),
+            1 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + group_id_bytes.len()
+                + group_instance_id.len(),
         );
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&group_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(group_instance_id.len() as u8);
+        bytes.put_slice(group_instance_id.as_bytes());
         bytes.freeze()
     }
 
@@ -63,10 +83,20 @@ impl BytesSerializable for JoinConsumerGroup {
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += group_id.get_size_bytes().as_bytes_usize();
+        // The group instance ID is optional to keep the commands sent by the older clients readable.
+        let group_instance_id = match bytes.get(position) {
+            Some(0) | None => None,
+            Some(_) => {
+                let length = read_u8(&bytes, position)?;
+                Some(read_str(&bytes, position + 1, length as usize)?)
+            }
+        };
         let command = JoinConsumerGroup {
             stream_id,
             topic_id,
             group_id,
+            group_instance_id,
         };
         Ok(command)
     }
@@ -74,7 +104,14 @@ impl BytesSerializable for JoinConsumerGroup {
 
 impl Display for JoinConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.group_id)
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.group_id,
+            self.group_instance_id.as_deref().unwrap_or_default()
+        )
     }
 }
 
@@ -88,6 +125,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             group_id: Identifier::numeric(3).unwrap(),
+            group_instance_id: Some("instance-1".to_string()),
         };
 
         let bytes = command.to_bytes();
@@ -97,11 +135,20 @@ mod tests {
         let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
         position += topic_id.get_size_bytes().as_bytes_usize();
         let group_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += group_id.get_size_bytes().as_bytes_usize();
+        let group_instance_id_length = bytes[position] as usize;
+        let group_instance_id =
+            std::str::from_utf8(&bytes[position + 1..position + 1 + group_instance_id_length])
+                .unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(group_id, command.group_id);
+        assert_eq!(
+            Some(group_instance_id),
+            command.group_instance_id.as_deref()
+        );
     }
 
     #[test]
@@ -125,5 +172,19 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.group_id, group_id);
+        assert!(command.group_instance_id.is_none());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_group_instance_id() {
+        let command = JoinConsumerGroup {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            group_id: Identifier::named("group").unwrap(),
+            group_instance_id: Some("instance-1".to_string()),
+        };
+
+        let deserialized_command = JoinConsumerGroup::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized_command, command);
     }
 }
@@ -0,0 +1,168 @@
+use crate::bytes_serializable::{read_u32_le, BytesSerializable};
+use crate::command::{Command, DELETE_CONSUMER_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DeleteConsumer` command that deletes the regular consumer along with its stored offset and name from the partition.
+/// It has additional payload:
+/// - `consumer_id` - unique consumer ID (numeric or name).
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct DeleteConsumer {
+    /// Unique consumer ID (numeric or name).
+    #[serde(skip)]
+    pub consumer_id: Identifier,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+}
+
+impl Command for DeleteConsumer {
+    fn code(&self) -> u32 {
+        DELETE_CONSUMER_CODE
+    }
+}
+
+impl Validatable<IggyError> for DeleteConsumer {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DeleteConsumer {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_id_bytes = self.consumer_id.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            4 + consumer_id_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_id_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DeleteConsumer, IggyError> {
+        if bytes.len() < 13 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_id = Identifier::from_bytes(bytes.clone())?;
+        position += consumer_id.get_size_bytes().as_bytes_usize();
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let command = DeleteConsumer {
+            consumer_id,
+            stream_id,
+            topic_id,
+            partition_id,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for DeleteConsumer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.consumer_id, self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteConsumer {
+            consumer_id: Identifier::named("consumer").unwrap(),
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let consumer_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += consumer_id.get_size_bytes().as_bytes_usize();
+        let stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(consumer_id, command.consumer_id);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer_id = Identifier::named("consumer").unwrap();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let consumer_id_bytes = consumer_id.to_bytes();
+        let stream_id_bytes = stream_id.to_bytes();
+        let topic_id_bytes = topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            4 + consumer_id_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_id_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(3);
+
+        let command = DeleteConsumer::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer_id, consumer_id);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, 3);
+    }
+
+    #[test]
+    fn should_not_be_validated_for_zero_partition_id() {
+        let command = DeleteConsumer {
+            consumer_id: Identifier::numeric(1).unwrap(),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: 0,
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -1,2 +1,7 @@
+pub mod delete_consumer;
+pub mod delete_consumer_offsets;
+pub mod get_consumer_lag;
 pub mod get_consumer_offset;
+pub mod get_consumers;
+pub mod reset_consumer_offsets;
 pub mod store_consumer_offset;
@@ -0,0 +1,366 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, read_u8, BytesSerializable};
+use crate::command::{Command, RESET_CONSUMER_OFFSETS_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::utils::timestamp::IggyTimestamp;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// `ResetConsumerOffsets` command resets the stored offsets of a consumer (or consumer group) for the selected partitions of the topic.
+/// It has additional payload:
+/// - `consumer` - the consumer whose offsets are reset, either the regular consumer or the consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partitions` - partition IDs for which the offsets are reset. If empty, the offsets are reset for all the partitions.
+/// - `strategy` - strategy which specifies from where the consumer should continue consuming the messages.
+/// - `dry_run` - if `true`, the resulting offsets are only calculated and returned, without being stored.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ResetConsumerOffsets {
+    /// The consumer whose offsets are reset, either the regular consumer or the consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Partition IDs for which the offsets are reset. If empty, the offsets are reset for all the partitions.
+    #[serde(default)]
+    pub partitions: Vec<u32>,
+    /// Strategy which specifies from where the consumer should continue consuming the messages.
+    #[serde(flatten)]
+    pub strategy: OffsetResetStrategy,
+    /// If `true`, the resulting offsets are only calculated and returned, without being stored.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// `OffsetResetStrategy` specifies from where the consumer should continue consuming the messages after the reset.
+/// It has the following kinds:
+/// - `Earliest` - continue from the first message still available in the partition.
+/// - `Latest` - continue from the next message appended to the partition.
+/// - `Offset` - continue from the specified offset.
+/// - `Timestamp` - continue from the first message with the timestamp greater than or equal to the specified one.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct OffsetResetStrategy {
+    /// Kind of the reset strategy.
+    pub kind: OffsetResetKind,
+    /// Value of the reset strategy, used only by `Offset` and `Timestamp` kinds.
+    #[serde(default)]
+    pub value: u64,
+}
+
+/// `OffsetResetKind` is an enum which specifies from where to continue consuming the messages and is used by `OffsetResetStrategy`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum OffsetResetKind {
+    #[default]
+    /// Continue from the first message still available in the partition.
+    Earliest,
+    /// Continue from the next message appended to the partition.
+    Latest,
+    /// Continue from the specified offset.
+    Offset,
+    /// Continue from the first message with the timestamp greater than or equal to the specified one.
+    Timestamp,
+}
+
+impl OffsetResetStrategy {
+    /// Continue from the first message still available in the partition.
+    pub fn earliest() -> Self {
+        Self {
+            kind: OffsetResetKind::Earliest,
+            value: 0,
+        }
+    }
+
+    /// Continue from the next message appended to the partition.
+    pub fn latest() -> Self {
+        Self {
+            kind: OffsetResetKind::Latest,
+            value: 0,
+        }
+    }
+
+    /// Continue from the specified offset.
+    pub fn offset(value: u64) -> Self {
+        Self {
+            kind: OffsetResetKind::Offset,
+            value,
+        }
+    }
+
+    /// Continue from the first message with the timestamp greater than or equal to the specified one.
+    pub fn timestamp(value: IggyTimestamp) -> Self {
+        Self {
+            kind: OffsetResetKind::Timestamp,
+            value: value.into(),
+        }
+    }
+}
+
+impl OffsetResetKind {
+    /// Returns code of the reset kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            OffsetResetKind::Earliest => 1,
+            OffsetResetKind::Latest => 2,
+            OffsetResetKind::Offset => 3,
+            OffsetResetKind::Timestamp => 4,
+        }
+    }
+
+    /// Returns reset kind from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(OffsetResetKind::Earliest),
+            2 => Ok(OffsetResetKind::Latest),
+            3 => Ok(OffsetResetKind::Offset),
+            4 => Ok(OffsetResetKind::Timestamp),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Command for ResetConsumerOffsets {
+    fn code(&self) -> u32 {
+        RESET_CONSUMER_OFFSETS_CODE
+    }
+}
+
+impl Validatable<IggyError> for ResetConsumerOffsets {
+    fn validate(&self) -> Result<(), IggyError> {
+        let mut partitions = HashSet::with_capacity(self.partitions.len());
+        for partition_id in &self.partitions {
+            if *partition_id == 0 || !partitions.insert(partition_id) {
+                return Err(IggyError::InvalidPartitionsToReset);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for ResetConsumerOffsets {
+    fn to_bytes(&self) -> Bytes {
+        let consumer_bytes = self.consumer.to_bytes();
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            14 + 4 * self.partitions.len()
+                + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.strategy.kind.as_code());
+        bytes.put_u64_le(self.strategy.value);
+        bytes.put_u8(u8::from(self.dry_run));
+        bytes.put_u32_le(self.partitions.len() as u32);
+        for partition_id in &self.partitions {
+            bytes.put_u32_le(*partition_id);
+        }
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<ResetConsumerOffsets, IggyError> {
+        if bytes.len() < 25 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 14 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let kind = OffsetResetKind::from_code(read_u8(&bytes, position)?)?;
+        let value = read_u64_le(&bytes, position + 1)?;
+        let dry_run = match read_u8(&bytes, position + 9)? {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let partitions_count = read_u32_le(&bytes, position + 10)? as usize;
+        position += 14;
+        if bytes.len() != position + 4 * partitions_count {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut partitions = Vec::with_capacity(partitions_count);
+        for _ in 0..partitions_count {
+            partitions.push(read_u32_le(&bytes, position)?);
+            position += 4;
+        }
+
+        let command = ResetConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            partitions,
+            strategy: OffsetResetStrategy { kind, value },
+            dry_run,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for ResetConsumerOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.strategy,
+            self.dry_run,
+            self.partitions
+                .iter()
+                .map(|partition_id| partition_id.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    }
+}
+
+impl Display for OffsetResetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.kind, self.value)
+    }
+}
+
+impl Display for OffsetResetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetResetKind::Earliest => write!(f, "earliest"),
+            OffsetResetKind::Latest => write!(f, "latest"),
+            OffsetResetKind::Offset => write!(f, "offset"),
+            OffsetResetKind::Timestamp => write!(f, "timestamp"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ResetConsumerOffsets {
+            consumer: Consumer::group(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partitions: vec![1, 3],
+            strategy: OffsetResetStrategy::offset(100),
+            dry_run: true,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0]).unwrap();
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..)).unwrap();
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let kind = OffsetResetKind::from_code(bytes[position]).unwrap();
+        let value = u64::from_le_bytes(bytes[position + 1..position + 9].try_into().unwrap());
+        let dry_run = bytes[position + 9] == 1;
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 10..position + 14].try_into().unwrap());
+        let first_partition_id =
+            u32::from_le_bytes(bytes[position + 14..position + 18].try_into().unwrap());
+        let second_partition_id =
+            u32::from_le_bytes(bytes[position + 18..position + 22].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(kind, command.strategy.kind);
+        assert_eq!(value, command.strategy.value);
+        assert_eq!(dry_run, command.dry_run);
+        assert_eq!(partitions_count, 2);
+        assert_eq!(first_partition_id, 1);
+        assert_eq!(second_partition_id, 3);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer = Consumer::new(Identifier::numeric(1).unwrap());
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+
+        let consumer_bytes = consumer.to_bytes();
+        let stream_id_bytes = stream_id.to_bytes();
+        let topic_id_bytes = topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            18 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.put_slice(&consumer_bytes);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(OffsetResetKind::Timestamp.as_code());
+        bytes.put_u64_le(1000);
+        bytes.put_u8(0);
+        bytes.put_u32_le(1);
+        bytes.put_u32_le(2);
+
+        let command = ResetConsumerOffsets::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.strategy.kind, OffsetResetKind::Timestamp);
+        assert_eq!(command.strategy.value, 1000);
+        assert!(!command.dry_run);
+        assert_eq!(command.partitions, vec![2]);
+    }
+
+    #[test]
+    fn should_fail_validation_for_duplicated_or_zero_partitions() {
+        let mut command = ResetConsumerOffsets {
+            partitions: vec![1, 2],
+            ..ResetConsumerOffsets::default()
+        };
+        assert!(command.validate().is_ok());
+
+        command.partitions = vec![1, 1];
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPartitionsToReset)
+        ));
+
+        command.partitions = vec![0];
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPartitionsToReset)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, read_u64_le, BytesSerializable};
 use crate::command::{Command, STORE_CONSUMER_OFFSET_CODE};
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
@@ -94,13 +94,13 @@ impl BytesSerializable for StoreConsumerOffset {
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
-        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partition_id = read_u32_le(&bytes, position)?;
         let partition_id = if partition_id == 0 {
             None
         } else {
             Some(partition_id)
         };
-        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        let offset = read_u64_le(&bytes, position + 4)?;
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
@@ -58,6 +58,14 @@ pub enum IggyError {
     CannotSerializeResource(#[source] anyhow::Error) = 24,
     #[error("Cannot deserialize resource. Reason: {0:#}")]
     CannotDeserializeResource(#[source] anyhow::Error) = 25,
+    #[error(
+        "Insufficient disk space, the server is in read-only mode until enough space is freed"
+    )]
+    InsufficientDiskSpace = 26,
+    #[error("Invalid log filter: {0}")]
+    InvalidLogFilter(String) = 27,
+    #[error("Cannot apply migration with ID: {0}. Reason: {1}")]
+    CannotApplyMigration(u32, String) = 28,
     #[error("Stale client")]
     StaleClient = 30,
     #[error("Unauthenticated")]
@@ -92,6 +100,8 @@ pub enum IggyError {
     PersonalAccessTokenExpired(String, u32) = 54,
     #[error("Users limit reached.")]
     UsersLimitReached = 55,
+    #[error("User: {0} is not allowed to log in from address: {1}")]
+    UserSourceNotAllowed(String, String) = 56,
     #[error("Not connected")]
     NotConnected = 61,
     #[error("Request error")]
@@ -106,6 +116,8 @@ pub enum IggyError {
     InvalidTlsCertificate = 66,
     #[error("Failed to add certificate")]
     FailedToAddCertificate = 67,
+    #[error("Producer journal is full")]
+    ProducerJournalFull = 68,
     #[error("Invalid encryption key")]
     InvalidEncryptionKey = 70,
     #[error("Cannot encrypt data")]
@@ -124,10 +136,16 @@ pub enum IggyError {
     AccessTokenMissing = 77,
     #[error("Invalid access token")]
     InvalidAccessToken = 78,
+    #[error("Cannot compress data")]
+    CannotCompressData = 79,
+    #[error("Cannot decompress data")]
+    CannotDecompressData = 80,
     #[error("Client with ID: {0} was not found.")]
     ClientNotFound(u32) = 100,
     #[error("Invalid client ID")]
     InvalidClientId = 101,
+    #[error("Session for client with ID: {0} was not found.")]
+    SessionNotFound(u32) = 102,
     #[error("IO error")]
     IoError(#[from] std::io::Error) = 200,
     #[error("Write error")]
@@ -148,6 +166,8 @@ pub enum IggyError {
     CannotParseBool(#[from] std::str::ParseBoolError) = 208,
     #[error("Cannot parse header kind from {0}")]
     CannotParseHeaderKind(String) = 209,
+    #[error("Cannot parse JSON: {0}")]
+    CannotParseJson(String) = 210,
     #[error("HTTP response error, status: {0}, body: {1}")]
     HttpResponseError(u16, String) = 300,
     #[error("Request middleware error")]
@@ -170,6 +190,10 @@ pub enum IggyError {
     ReadToEndError(#[from] quinn::ReadToEndError) = 309,
     #[error("Closed error")]
     ClosedError(#[from] quinn::ClosedStream) = 310,
+    #[error("Incomplete response, received {0} of {1} bytes")]
+    IncompleteResponse(u64, u64) = 311,
+    #[error("Invalid frame checksum: {0}, expected: {1}")]
+    InvalidFrameChecksum(u32, u32) = 312,
     #[error("Cannot create streams directory, Path: {0}")]
     CannotCreateStreamsDirectory(String) = 1000,
     #[error("Cannot create stream with ID: {0} directory, Path: {1}")]
@@ -210,6 +234,10 @@ pub enum IggyError {
     MissingPartitions(u32, u32) = 1018,
     #[error("Max topic size cannot be lower than segment size. Max topic size: {0} < segment size: {1}.")]
     InvalidTopicSize(MaxTopicSize, IggyByteSize) = 1019,
+    #[error("Stream with ID: {0} is reserved for the internal topics.")]
+    ReservedStream(u32) = 1020,
+    #[error("Cannot create stream, the limit of {0} streams has been reached.")]
+    StreamsLimitReached(u32) = 1021,
     #[error("Cannot create topics directory for stream with ID: {0}, Path: {1}")]
     CannotCreateTopicsDirectory(u32, String) = 2000,
     #[error(
@@ -250,6 +278,34 @@ pub enum IggyError {
     CannotReadTopics(u32) = 2017,
     #[error("Invalid replication factor")]
     InvalidReplicationFactor = 2018,
+    #[error("Invalid cleanup policy")]
+    InvalidCleanupPolicy = 2019,
+    #[error(
+        "Cannot create topic, the limit of {0} topics for stream with ID: {1} has been reached."
+    )]
+    TopicsLimitReached(u32, u32) = 2020,
+    #[error(
+        "Replication factor: {0} is not supported, only the replication factor of 1 is available."
+    )]
+    ReplicationFactorNotSupported(u8) = 2021,
+    #[error("Topic with ID: {0} for stream with ID: {1} is read-only.")]
+    TopicReadOnly(u32, u32) = 2022,
+    #[error("Invalid timestamp type")]
+    InvalidTimestampType = 2023,
+    #[error("Topic with ID: {0} for stream with ID: {1} is not compacted.")]
+    TopicNotCompacted(u32, u32) = 2024,
+    #[error("Invalid schema type")]
+    InvalidSchemaType = 2100,
+    #[error("Invalid schema definition: {0}")]
+    InvalidSchemaDefinition(String) = 2101,
+    #[error("Payload validation is not supported for schema type: {0}")]
+    SchemaValidationNotSupported(String) = 2102,
+    #[error(
+        "Schema with version: {0} for topic with ID: {1} and stream with ID: {2} was not found."
+    )]
+    SchemaVersionNotFound(u32, u32, u32) = 2103,
+    #[error("Message payload violates the schema with version: {0}. Reason: {1}")]
+    SchemaViolation(u32, String) = 2104,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
     CannotCreatePartition(u32, u32, u32) = 3000,
     #[error(
@@ -282,8 +338,26 @@ pub enum IggyError {
     CannotDeleteConsumerOffsetFile(String) = 3011,
     #[error("Failed to create consumer offsets directory for path: {0}")]
     CannotCreateConsumerOffsetsDirectory(String) = 3012,
+    #[error("Failed to compact consumer offsets for path: {0}")]
+    CannotCompactConsumerOffsets(String) = 3013,
     #[error("Failed to read consumers offsets from path: {0}")]
     CannotReadConsumerOffsets(String) = 3020,
+    #[error("Invalid partitions count")]
+    InvalidPartitionsCount = 3021,
+    #[error("Cannot create partitions, the limit of {0} partitions for topic with ID: {1} and stream with ID: {2} has been reached.")]
+    PartitionsLimitReached(u32, u32, u32) = 3022,
+    #[error("Node with ID: {0} is not the leader of partition with ID: {1} for topic with ID: {2} and stream with ID: {3}")]
+    NotLeader(u32, u32, u32, u32) = 3023,
+    #[error("Node with ID: {0} was not found")]
+    NodeNotFound(u32) = 3024,
+    #[error("Node with ID: {0} is draining or decommissioned and accepts no new partitions")]
+    NodeDraining(u32) = 3025,
+    #[error("Node with ID: {0} must be draining before being decommissioned")]
+    NodeNotDraining(u32) = 3026,
+    #[error("Node with ID: {0} still leads {1} partitions and cannot be decommissioned")]
+    NodeNotEmpty(u32, u32) = 3027,
+    #[error("Partition with ID: {0} for topic with ID: {1} for stream with ID: {2} failed to persist the acknowledged messages and rejects the appends until the server is restarted")]
+    PartitionFailed(u32, u32, u32) = 3028,
     #[error("Segment not found")]
     SegmentNotFound = 4000,
     #[error("Segment with start offset: {0} and partition with ID: {1} is closed")]
@@ -344,8 +418,52 @@ pub enum IggyError {
     InvalidKeyValueLength = 4028,
     #[error("Command length error: {0}")]
     CommandLengthError(String) = 4029,
+    #[error("Invalid content type")]
+    InvalidContentType = 4030,
+    #[error("Payload does not match the content type: {0}")]
+    PayloadContentTypeMismatch(String) = 4031,
+    #[error("Invalid payload format")]
+    InvalidPayloadFormat = 4032,
+    #[error("Invalid partitions to poll, expected unique, non-zero partition IDs")]
+    InvalidPartitionsToPoll = 4033,
+    #[error("Producer was fenced, topic with ID: {0} for stream with ID: {1} has the exclusive producer with client ID: {2}")]
+    ProducerFenced(u32, u32, u32) = 4034,
+    #[error(
+        "Producer epoch: {0} is stale, the topic was acquired by the producer with a newer epoch"
+    )]
+    StaleProducerEpoch(u32) = 4035,
+    #[error("Invalid producer epoch: {0}")]
+    InvalidProducerEpoch(u32) = 4036,
+    #[error("Invalid polling direction, polling backward is not supported for the next messages or with the auto commit")]
+    InvalidPollingDirection = 4037,
+    #[error("Invalid histogram range, the start timestamp must be lower than the end one and the bucket size must be greater than zero")]
+    InvalidHistogramRange = 4038,
+    #[error("Too many histogram buckets: {0}, the maximum is: {1}")]
+    TooManyHistogramBuckets(u64, u32) = 4039,
+    #[error("Invalid content encoding")]
+    InvalidContentEncoding = 4040,
+    #[error("Invalid polling timestamp range, the end timestamp can be set only when polling forward and must be greater than the start one")]
+    InvalidPollingTimestampRange = 4041,
+    #[error("Invalid copy range, the start offset or timestamp must be lower than the end one")]
+    InvalidCopyRange = 4042,
+    #[error("Invalid key filter")]
+    InvalidKeyFilter = 4043,
+    #[error("Epoch: {0} is unknown to partition with ID: {1}, the current epoch is: {2}")]
+    UnknownPartitionEpoch(u32, u32, u32) = 4044,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64) = 4100,
+    #[error("Offset: {0} is below the low watermark: {1} for partition with ID: {2}")]
+    OffsetBelowLowWatermark(u64, u64, u32) = 4101,
+    #[error("Offset: {0} is above the high watermark: {1} for partition with ID: {2}")]
+    OffsetAboveHighWatermark(u64, u64, u32) = 4102,
+    #[error(
+        "Invalid partitions to reset consumer offsets, expected unique, non-zero partition IDs"
+    )]
+    InvalidPartitionsToReset = 4103,
+    #[error("Consumer with ID: {0} was not found in partition with ID: {1}")]
+    ConsumerNotFound(u32, u32) = 4104,
+    #[error("Stored offset for consumer with ID: {0} was not found")]
+    ConsumerOffsetNotFound(u32) = 4105,
     #[error("Consumer group with ID: {0} for topic with ID: {1} was not found.")]
     ConsumerGroupIdNotFound(u32, u32) = 5000,
     #[error("Consumer group with ID: {0} for topic with ID: {1} already exists.")]
@@ -364,6 +482,10 @@ pub enum IggyError {
     CannotCreateConsumerGroupInfo(u32, u32, u32) = 5007,
     #[error("Failed to delete consumer group info file for ID: {0} for topic with ID: {1} for stream with ID: {2}.")]
     CannotDeleteConsumerGroupInfo(u32, u32, u32) = 5008,
+    #[error("Invalid rebalance strategy")]
+    InvalidRebalanceStrategy = 5009,
+    #[error("Invalid group instance ID")]
+    InvalidGroupInstanceId = 5010,
     #[error("Base offset is missing")]
     MissingBaseOffsetRetainedMessageBatch = 6000,
     #[error("Last offset delta is missing")]
@@ -384,6 +506,24 @@ pub enum IggyError {
     CannotReadMaxTimestamp = 7003,
     #[error("Cannot read batch payload")]
     CannotReadBatchPayload = 7004,
+    #[error("Cannot read batch messages count")]
+    CannotReadBatchMessagesCount = 7005,
+    #[error("Cannot read batch base timestamp")]
+    CannotReadBaseTimestamp = 7006,
+    #[error("Cannot read batch attributes")]
+    CannotReadBatchAttributes = 7007,
+    #[error("Cannot read batch checksum")]
+    CannotReadBatchChecksum = 7008,
+    #[error("Invalid batch checksum: {0}, expected: {1}, for batch with base offset: {2}")]
+    InvalidBatchChecksum(u32, u32, u64) = 7009,
+    #[error("Cannot read batch format version")]
+    CannotReadBatchFormatVersion = 7010,
+    #[error(
+        "Unsupported batch format version: {0}, expected: {1}, for batch with base offset: {2}"
+    )]
+    UnsupportedBatchFormatVersion(u8, u8, u64) = 7011,
+    #[error("Truncated message, expected at least: {0} bytes, got: {1}")]
+    TruncatedMessage(u64, u64) = 7012,
     #[error("Invalid connection string")]
     InvalidConnectionString = 8000,
     #[error("Snaphot file completion failed")]
@@ -1,3 +1,6 @@
+use crate::client::AutoLogin;
+use crate::utils::duration::IggyDuration;
+
 /// Configuration for the HTTP client.
 #[derive(Debug, Clone)]
 pub struct HttpClientConfig {
@@ -5,6 +8,10 @@ pub struct HttpClientConfig {
     pub api_url: String,
     /// The number of retries to perform on transient errors.
     pub retries: u32,
+    /// Whether to automatically login user when connecting the client.
+    pub auto_login: AutoLogin,
+    /// The maximum time to wait for the connection to be established, unlimited if not set.
+    pub connection_timeout: Option<IggyDuration>,
 }
 
 impl Default for HttpClientConfig {
@@ -12,6 +19,8 @@ impl Default for HttpClientConfig {
         HttpClientConfig {
             api_url: "http://127.0.0.1:3000".to_string(),
             retries: 3,
+            auto_login: AutoLogin::Disabled,
+            connection_timeout: None,
         }
     }
 }
@@ -20,6 +29,8 @@ impl Default for HttpClientConfig {
 /// Allows configuring the HTTP client with custom settings or using defaults:
 /// - `api_url`: Default is "http://127.0.0.1:3000"
 /// - `retries`: Default is 3.
+/// - `auto_login`: Default is AutoLogin::Disabled.
+/// - `connection_timeout`: Default is None.
 #[derive(Debug, Default)]
 pub struct HttpClientConfigBuilder {
     config: HttpClientConfig,
@@ -43,6 +54,18 @@ impl HttpClientConfigBuilder {
         self
     }
 
+    /// Sets the auto login when connecting the client.
+    pub fn with_auto_login(mut self, auto_login: AutoLogin) -> Self {
+        self.config.auto_login = auto_login;
+        self
+    }
+
+    /// Sets the maximum time to wait for the connection to be established.
+    pub fn with_connection_timeout(mut self, connection_timeout: IggyDuration) -> Self {
+        self.config.connection_timeout = Some(connection_timeout);
+        self
+    }
+
     /// Builds the `HttpClientConfig` instance.
     pub fn build(self) -> HttpClientConfig {
         self.config
@@ -4,7 +4,8 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -48,6 +49,7 @@ impl ConsumerGroupClient for HttpClient {
         topic_id: &Identifier,
         name: &str,
         group_id: Option<u32>,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Result<ConsumerGroupDetails, IggyError> {
         let response = self
             .post(
@@ -57,6 +59,7 @@ impl ConsumerGroupClient for HttpClient {
                     topic_id: topic_id.clone(),
                     name: name.to_string(),
                     group_id,
+                    rebalance_strategy,
                 },
             )
             .await?;
@@ -84,6 +87,7 @@ impl ConsumerGroupClient for HttpClient {
         _: &Identifier,
         _: &Identifier,
         _: &Identifier,
+        _: Option<&str>,
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
@@ -96,6 +100,15 @@ impl ConsumerGroupClient for HttpClient {
     ) -> Result<(), IggyError> {
         Err(IggyError::FeatureUnavailable)
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: &Identifier,
+    ) -> Result<ConsumerGroupMember, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
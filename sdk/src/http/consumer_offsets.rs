@@ -1,12 +1,18 @@
 use crate::client::ConsumerOffsetClient;
 use crate::consumer::Consumer;
+use crate::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::{OffsetResetStrategy, ResetConsumerOffsets};
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::consumer_info::ConsumerInfo;
+use crate::models::consumer_lag_info::ConsumerLagInfo;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -58,8 +64,124 @@ impl ConsumerOffsetClient for HttpClient {
         let offset = response.json().await?;
         Ok(Some(offset))
     }
+
+    async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<ConsumerLagInfo>, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_lag_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &GetConsumerLag {
+                    consumer: consumer.clone(),
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                },
+            )
+            .await?;
+        let lags = response.json().await?;
+        Ok(lags)
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions: &[u32],
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/reset",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &ResetConsumerOffsets {
+                    consumer: consumer.clone(),
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitions: partitions.to_vec(),
+                    strategy: *strategy,
+                    dry_run,
+                },
+            )
+            .await?;
+        let resets = response.json().await?;
+        Ok(resets)
+    }
+
+    async fn get_consumers(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Vec<ConsumerInfo>, IggyError> {
+        let response = self
+            .get(&get_consumers_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                partition_id,
+            ))
+            .await?;
+        let consumers = response.json().await?;
+        Ok(consumers)
+    }
+
+    async fn delete_consumer(
+        &self,
+        consumer_id: &Identifier,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        self.delete(&format!(
+            "{}/{}",
+            get_consumers_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                partition_id
+            ),
+            consumer_id.as_cow_str()
+        ))
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.delete_with_query(
+            &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+            &DeleteConsumerOffsets {
+                consumer: consumer.clone(),
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                partition_id,
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/consumer-offsets")
 }
+
+fn get_lag_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/consumer-lag")
+}
+
+fn get_consumers_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/partitions/{partition_id}/consumers")
+}
@@ -4,10 +4,16 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::messages::copy_messages::{CopyMessages, CopyRangeKind};
 use crate::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
-use crate::messages::poll_messages::{PollMessages, PollingStrategy};
-use crate::messages::send_messages::{Message, Partitioning, SendMessages};
+use crate::messages::get_message_by_key::GetMessageByKey;
+use crate::messages::peek_messages::{PeekKind, PeekMessages};
+use crate::messages::poll_messages::{OutOfRangePolicy, PollMessages, PollingStrategy};
+use crate::messages::poll_messages_from_partitions::{PartitionOffset, PollMessagesFromPartitions};
+use crate::messages::send_messages::{AckLevel, Message, Partitioning, SendMessages};
+use crate::models::copied_messages_info::CopiedMessagesInfo;
 use crate::models::messages::PolledMessages;
+use crate::models::replica_messages::ReplicaMessages;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -21,6 +27,30 @@ impl MessageClient for HttpClient {
         strategy: &PollingStrategy,
         count: u32,
         auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        self.poll_messages_with_max_bytes(
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer,
+            strategy,
+            count,
+            0,
+            auto_commit,
+        )
+        .await
+    }
+
+    async fn poll_messages_with_max_bytes(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        consumer: &Consumer,
+        strategy: &PollingStrategy,
+        count: u32,
+        max_bytes: u32,
+        auto_commit: bool,
     ) -> Result<PolledMessages, IggyError> {
         let response = self
             .get_with_query(
@@ -32,6 +62,7 @@ impl MessageClient for HttpClient {
                     consumer: consumer.clone(),
                     strategy: *strategy,
                     count,
+                    max_bytes,
                     auto_commit,
                 },
             )
@@ -40,12 +71,151 @@ impl MessageClient for HttpClient {
         Ok(messages)
     }
 
+    async fn poll_messages_from_partitions(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer: &Consumer,
+        partitions: &[PartitionOffset],
+        out_of_range: OutOfRangePolicy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<PolledMessages>, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/poll",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &PollMessagesFromPartitions {
+                    consumer: consumer.clone(),
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partitions: partitions.to_vec(),
+                    out_of_range,
+                    count,
+                    auto_commit,
+                },
+            )
+            .await?;
+        let messages = response.json().await?;
+        Ok(messages)
+    }
+
+    async fn peek_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/peek",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &PeekMessages {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    kind,
+                    count,
+                },
+            )
+            .await?;
+        let messages = response.json().await?;
+        Ok(messages)
+    }
+
+    async fn get_message_by_key(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        key: u128,
+    ) -> Result<PolledMessages, IggyError> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/key",
+                    get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+                ),
+                &GetMessageByKey {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    key,
+                },
+            )
+            .await?;
+        let messages = response.json().await?;
+        Ok(messages)
+    }
+
+    async fn copy_messages(
+        &self,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        target_stream_id: &Identifier,
+        target_topic_id: &Identifier,
+        target_partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Result<CopiedMessagesInfo, IggyError> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/copy",
+                    get_path(
+                        &source_stream_id.as_cow_str(),
+                        &source_topic_id.as_cow_str()
+                    )
+                ),
+                &CopyMessages {
+                    source_stream_id: source_stream_id.clone(),
+                    source_topic_id: source_topic_id.clone(),
+                    source_partition_id,
+                    target_stream_id: target_stream_id.clone(),
+                    target_topic_id: target_topic_id.clone(),
+                    target_partition_id,
+                    kind,
+                    from,
+                    to,
+                },
+            )
+            .await?;
+        let copied_messages = response.json().await?;
+        Ok(copied_messages)
+    }
+
     async fn send_messages(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
         messages: &mut [Message],
+    ) -> Result<(), IggyError> {
+        self.send_messages_with_ack(
+            stream_id,
+            topic_id,
+            partitioning,
+            AckLevel::default(),
+            messages,
+        )
+        .await
+    }
+
+    async fn send_messages_with_ack(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        ack: AckLevel,
+        messages: &mut [Message],
     ) -> Result<(), IggyError> {
         self.post(
             &get_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -53,6 +223,7 @@ impl MessageClient for HttpClient {
                 stream_id: stream_id.clone(),
                 topic_id: topic_id.clone(),
                 partitioning: partitioning.clone(),
+                ack,
                 messages: messages.to_vec(),
             },
         )
@@ -60,6 +231,36 @@ impl MessageClient for HttpClient {
         Ok(())
     }
 
+    async fn acquire_exclusive_producer(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+    ) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn fetch_replica_messages(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+        _: u32,
+        _: u64,
+        _: u32,
+        _: u32,
+    ) -> Result<ReplicaMessages, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn init_producer_epoch(
+        &self,
+        _: &Identifier,
+        _: &Identifier,
+        _: u32,
+    ) -> Result<u32, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
     async fn flush_unsaved_buffer(
         &self,
         stream_id: &Identifier,
@@ -3,8 +3,18 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::identifier::Identifier;
+use crate::models::messages_histogram::MessagesHistogram;
+use crate::models::partition::PartitionDetails;
+use crate::models::partition_offsets_info::PartitionOffsetsInfo;
+use crate::models::sealed_partition_info::SealedPartitionInfo;
+use crate::models::timestamp_offset_info::TimestampOffsetInfo;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::partitions::get_messages_histogram::GetMessagesHistogram;
+use crate::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use crate::partitions::get_partition_offsets::GetPartitionOffsets;
+use crate::partitions::seal_partition::SealPartition;
+use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -44,8 +54,168 @@ impl PartitionClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn get_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Option<PartitionDetails>, IggyError> {
+        let response = match self
+            .get(&get_details_path(
+                &stream_id.as_cow_str(),
+                &topic_id.as_cow_str(),
+                partition_id,
+            ))
+            .await
+        {
+            Ok(response) => response,
+            Err(IggyError::HttpResponseError(404, _)) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        let partition = response.json().await?;
+        Ok(Some(partition))
+    }
+
+    async fn get_partition_offsets(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionOffsetsInfo>, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_offsets_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
+                &GetPartitionOffsets {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                },
+            )
+            .await?;
+        let offsets = response.json().await?;
+        Ok(offsets)
+    }
+
+    async fn get_offset_by_timestamp(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        timestamp: u64,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError> {
+        let response = match self
+            .get_with_query(
+                &get_offset_by_timestamp_path(
+                    &stream_id.as_cow_str(),
+                    &topic_id.as_cow_str(),
+                    partition_id,
+                ),
+                &GetOffsetByTimestamp {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    timestamp,
+                },
+            )
+            .await
+        {
+            Ok(response) => response,
+            Err(IggyError::HttpResponseError(404, _)) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        let offset = response.json().await?;
+        Ok(Some(offset))
+    }
+
+    async fn get_messages_histogram(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_histogram_path(
+                    &stream_id.as_cow_str(),
+                    &topic_id.as_cow_str(),
+                    partition_id,
+                ),
+                &GetMessagesHistogram {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    from,
+                    to,
+                    bucket_size,
+                },
+            )
+            .await?;
+        let histogram = response.json().await?;
+        Ok(histogram)
+    }
+
+    async fn seal_partition(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        read_only: bool,
+    ) -> Result<SealedPartitionInfo, IggyError> {
+        let response = self
+            .post(
+                &get_seal_path(
+                    &stream_id.as_cow_str(),
+                    &topic_id.as_cow_str(),
+                    partition_id,
+                ),
+                &SealPartition {
+                    stream_id: stream_id.clone(),
+                    topic_id: topic_id.clone(),
+                    partition_id,
+                    read_only,
+                },
+            )
+            .await?;
+        let sealed_partition = response.json().await?;
+        Ok(sealed_partition)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
     format!("streams/{stream_id}/topics/{topic_id}/partitions")
 }
+
+fn get_details_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!("{}/{partition_id}", get_path(stream_id, topic_id))
+}
+
+fn get_offsets_path(stream_id: &str, topic_id: &str) -> String {
+    format!("streams/{stream_id}/topics/{topic_id}/partitions/offsets")
+}
+
+fn get_offset_by_timestamp_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!(
+        "{}/offset-by-timestamp",
+        get_details_path(stream_id, topic_id, partition_id)
+    )
+}
+
+fn get_histogram_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!(
+        "{}/histogram",
+        get_details_path(stream_id, topic_id, partition_id)
+    )
+}
+
+fn get_seal_path(stream_id: &str, topic_id: &str, partition_id: u32) -> String {
+    format!(
+        "{}/seal",
+        get_details_path(stream_id, topic_id, partition_id)
+    )
+}
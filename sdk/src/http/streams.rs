@@ -5,6 +5,7 @@ use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::streams::create_stream::CreateStream;
+use crate::streams::get_streams::GetStreams;
 use crate::streams::update_stream::UpdateStream;
 use async_trait::async_trait;
 
@@ -28,6 +29,26 @@ impl StreamClient for HttpClient {
         Ok(streams)
     }
 
+    async fn get_streams_page(
+        &self,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Stream>, IggyError> {
+        let response = self
+            .get_with_query(
+                PATH,
+                &GetStreams {
+                    offset,
+                    limit,
+                    name_prefix: name_prefix.map(|name_prefix| name_prefix.to_string()),
+                },
+            )
+            .await?;
+        let streams = response.json().await?;
+        Ok(streams)
+    }
+
     async fn create_stream(
         &self,
         name: &str,
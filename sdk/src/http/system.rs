@@ -3,17 +3,29 @@ use crate::error::IggyError;
 use crate::http::client::HttpClient;
 use crate::http::HttpTransport;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
+use crate::models::cluster_metadata::ClusterMetadata;
+use crate::models::node_status::NodeStatus;
+use crate::models::session_info::SessionInfo;
 use crate::models::snapshot::Snapshot;
 use crate::models::stats::Stats;
 use crate::snapshot::{SnapshotCompression, SystemSnapshotType};
+use crate::system::decommission_node::DecommissionNode;
+use crate::system::drain_node::DrainNode;
 use crate::system::get_snapshot::GetSnapshot;
+use crate::system::set_background_io_rate::SetBackgroundIoRate;
+use crate::system::set_log_level::SetLogLevel;
 use crate::utils::duration::IggyDuration;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
+const SESSIONS: &str = "/sessions";
 const STATS: &str = "/stats";
 const SNAPSHOT: &str = "/snapshot";
+const LOG_LEVEL: &str = "/log_level";
+const BACKGROUND_IO_RATE: &str = "/background_io_rate";
+const CLUSTER_METADATA: &str = "/cluster/metadata";
+const NODES: &str = "/nodes";
 
 #[async_trait]
 impl SystemClient for HttpClient {
@@ -43,6 +55,17 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn get_sessions(&self) -> Result<Vec<SessionInfo>, IggyError> {
+        let response = self.get(SESSIONS).await?;
+        let sessions = response.json().await?;
+        Ok(sessions)
+    }
+
+    async fn kill_session(&self, client_id: u32) -> Result<(), IggyError> {
+        self.delete(&format!("{}/{}", SESSIONS, client_id)).await?;
+        Ok(())
+    }
+
     async fn ping(&self) -> Result<(), IggyError> {
         self.get(PING).await?;
         Ok(())
@@ -70,4 +93,59 @@ impl SystemClient for HttpClient {
         let snapshot = Snapshot::new(file.to_vec());
         Ok(snapshot)
     }
+
+    async fn rotate_encryption_key(&self) -> Result<u32, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn set_log_level(&self, filter: &str) -> Result<(), IggyError> {
+        self.put(
+            LOG_LEVEL,
+            &SetLogLevel {
+                filter: filter.to_string(),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn set_background_io_rate(&self, bytes_per_second: u64) -> Result<(), IggyError> {
+        self.put(
+            BACKGROUND_IO_RATE,
+            &SetBackgroundIoRate { bytes_per_second },
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn get_cluster_metadata(&self) -> Result<ClusterMetadata, IggyError> {
+        let response = self.get(CLUSTER_METADATA).await?;
+        let metadata = response.json().await?;
+        Ok(metadata)
+    }
+
+    async fn get_node_status(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        let response = self.get(&format!("{NODES}/{node_id}/status")).await?;
+        let status = response.json().await?;
+        Ok(status)
+    }
+
+    async fn drain_node(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        let response = self
+            .put(&format!("{NODES}/{node_id}/drain"), &DrainNode { node_id })
+            .await?;
+        let status = response.json().await?;
+        Ok(status)
+    }
+
+    async fn decommission_node(&self, node_id: u32) -> Result<NodeStatus, IggyError> {
+        let response = self
+            .put(
+                &format!("{NODES}/{node_id}/decommission"),
+                &DecommissionNode { node_id },
+            )
+            .await?;
+        let status = response.json().await?;
+        Ok(status)
+    }
 }
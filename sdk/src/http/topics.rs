@@ -6,8 +6,12 @@ use crate::http::HttpTransport;
 use crate::identifier::Identifier;
 use crate::models::topic::{Topic, TopicDetails};
 use crate::topics::create_topic::CreateTopic;
+use crate::topics::get_topics::GetTopics;
+use crate::topics::set_topic_read_only::SetTopicReadOnly;
 use crate::topics::update_topic::UpdateTopic;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use async_trait::async_trait;
 
@@ -38,6 +42,28 @@ impl TopicClient for HttpClient {
         Ok(topics)
     }
 
+    async fn get_topics_page(
+        &self,
+        stream_id: &Identifier,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<Topic>, IggyError> {
+        let response = self
+            .get_with_query(
+                &get_path(&stream_id.as_cow_str()),
+                &GetTopics {
+                    stream_id: stream_id.clone(),
+                    offset,
+                    limit,
+                    name_prefix: name_prefix.map(|name_prefix| name_prefix.to_string()),
+                },
+            )
+            .await?;
+        let topics = response.json().await?;
+        Ok(topics)
+    }
+
     async fn create_topic(
         &self,
         stream_id: &Identifier,
@@ -48,6 +74,8 @@ impl TopicClient for HttpClient {
         topic_id: Option<u32>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<TopicDetails, IggyError> {
         let response = self
             .post(
@@ -61,6 +89,8 @@ impl TopicClient for HttpClient {
                     topic_id,
                     message_expiry,
                     max_topic_size,
+                    cleanup_policy,
+                    overrides,
                 },
             )
             .await?;
@@ -77,6 +107,8 @@ impl TopicClient for HttpClient {
         replication_factor: Option<u8>,
         message_expiry: IggyExpiry,
         max_topic_size: MaxTopicSize,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<(), IggyError> {
         self.put(
             &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str()),
@@ -88,6 +120,8 @@ impl TopicClient for HttpClient {
                 replication_factor,
                 message_expiry,
                 max_topic_size,
+                cleanup_policy,
+                overrides,
             },
         )
         .await?;
@@ -119,6 +153,27 @@ impl TopicClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn set_topic_read_only(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        read_only: bool,
+    ) -> Result<(), IggyError> {
+        self.put(
+            &format!(
+                "{}/read_only",
+                &get_details_path(&stream_id.as_cow_str(), &topic_id.as_cow_str())
+            ),
+            &SetTopicReadOnly {
+                stream_id: stream_id.clone(),
+                topic_id: topic_id.clone(),
+                read_only,
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 fn get_path(stream_id: &str) -> String {
@@ -191,10 +191,10 @@ impl BytesSerializable for Identifier {
 
         let kind = IdKind::from_code(bytes[0])?;
         let length = bytes[1];
-        let value = bytes[2..2 + length as usize].to_vec();
-        if value.len() != length as usize {
-            return Err(IggyError::InvalidIdentifier);
-        }
+        let value = bytes
+            .get(2..2 + length as usize)
+            .ok_or(IggyError::InvalidIdentifier)?
+            .to_vec();
 
         let identifier = Identifier {
             kind,
@@ -22,11 +22,13 @@ pub mod http;
 pub mod identifier;
 pub mod locking;
 pub mod messages;
+pub mod middleware;
 pub mod models;
 pub mod partitioner;
 pub mod partitions;
 pub mod personal_access_tokens;
 pub mod quic;
+pub mod schemas;
 pub mod snapshot;
 pub mod streams;
 pub mod system;
@@ -0,0 +1,101 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ACQUIRE_EXCLUSIVE_PRODUCER_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `AcquireExclusiveProducer` command makes the currently connected client the only one allowed to send the messages to the topic,
+/// until it disconnects. Any other client trying to acquire the topic or to send the messages to it is rejected with the fencing error.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct AcquireExclusiveProducer {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+}
+
+impl Command for AcquireExclusiveProducer {
+    fn code(&self) -> u32 {
+        ACQUIRE_EXCLUSIVE_PRODUCER_CODE
+    }
+}
+
+impl Validatable<IggyError> for AcquireExclusiveProducer {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for AcquireExclusiveProducer {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<AcquireExclusiveProducer, IggyError> {
+        if bytes.len() < 6 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        let command = AcquireExclusiveProducer {
+            stream_id,
+            topic_id,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for AcquireExclusiveProducer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.stream_id, self.topic_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AcquireExclusiveProducer {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+        };
+
+        let bytes = command.to_bytes();
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = AcquireExclusiveProducer {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+        };
+
+        let deserialized = AcquireExclusiveProducer::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, read_u8, BytesSerializable};
+use crate::command::{Command, COPY_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The header recording the origin of the copied message as `stream_id/topic_id/partition_id/offset`.
+pub const COPIED_FROM_HEADER: &str = "copied-from";
+
+/// `CopyMessages` command is used to copy the range of messages from one partition to another,
+/// e.g. to replay a subset of the traffic into a staging topic.
+/// The messages are appended to the target partition with their IDs and headers preserved,
+/// and the origin of each message is recorded in the `copied-from` header.
+/// It has additional payload:
+/// - `source_stream_id` - unique stream ID (numeric or name) of the copied messages.
+/// - `source_topic_id` - unique topic ID (numeric or name) of the copied messages.
+/// - `source_partition_id` - unique partition ID of the copied messages.
+/// - `target_stream_id` - unique stream ID (numeric or name) the messages are copied to.
+/// - `target_topic_id` - unique topic ID (numeric or name) the messages are copied to.
+/// - `target_partition_id` - unique partition ID the messages are copied to.
+/// - `kind` - whether the range is specified by the offsets or the timestamps.
+/// - `from` - the start of the range, inclusive.
+/// - `to` - the end of the range, exclusive.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CopyMessages {
+    /// Unique stream ID (numeric or name) of the copied messages.
+    #[serde(skip)]
+    pub source_stream_id: Identifier,
+    /// Unique topic ID (numeric or name) of the copied messages.
+    #[serde(skip)]
+    pub source_topic_id: Identifier,
+    /// Unique partition ID of the copied messages.
+    pub source_partition_id: u32,
+    /// Unique stream ID (numeric or name) the messages are copied to.
+    #[serde_as(as = "DisplayFromStr")]
+    pub target_stream_id: Identifier,
+    /// Unique topic ID (numeric or name) the messages are copied to.
+    #[serde_as(as = "DisplayFromStr")]
+    pub target_topic_id: Identifier,
+    /// Unique partition ID the messages are copied to.
+    pub target_partition_id: u32,
+    /// Whether the range is specified by the offsets or the timestamps.
+    #[serde(default)]
+    pub kind: CopyRangeKind,
+    /// The start of the range (offset or timestamp in microseconds), inclusive.
+    pub from: u64,
+    /// The end of the range (offset or timestamp in microseconds), exclusive.
+    pub to: u64,
+}
+
+/// `CopyRangeKind` specifies how the range of the copied messages is expressed:
+/// - `Offset` - the range of the message offsets.
+/// - `Timestamp` - the range of the message timestamps (in microseconds).
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyRangeKind {
+    #[default]
+    /// The range of the message offsets.
+    Offset,
+    /// The range of the message timestamps (in microseconds).
+    Timestamp,
+}
+
+impl Default for CopyMessages {
+    fn default() -> Self {
+        Self {
+            source_stream_id: Identifier::numeric(1).unwrap(),
+            source_topic_id: Identifier::numeric(1).unwrap(),
+            source_partition_id: 1,
+            target_stream_id: Identifier::numeric(1).unwrap(),
+            target_topic_id: Identifier::numeric(2).unwrap(),
+            target_partition_id: 1,
+            kind: CopyRangeKind::default(),
+            from: 0,
+            to: 1000,
+        }
+    }
+}
+
+impl Command for CopyMessages {
+    fn code(&self) -> u32 {
+        COPY_MESSAGES_CODE
+    }
+}
+
+impl Validatable<IggyError> for CopyMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.source_partition_id == 0 || self.target_partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self.from >= self.to {
+            return Err(IggyError::InvalidCopyRange);
+        }
+
+        Ok(())
+    }
+}
+
+impl CopyRangeKind {
+    /// Returns code of the copy range kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CopyRangeKind::Offset => 1,
+            CopyRangeKind::Timestamp => 2,
+        }
+    }
+
+    /// Returns copy range kind from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(CopyRangeKind::Offset),
+            2 => Ok(CopyRangeKind::Timestamp),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for CopyRangeKind {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "o" | "offset" => Ok(CopyRangeKind::Offset),
+            "t" | "timestamp" => Ok(CopyRangeKind::Timestamp),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for CopyRangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CopyRangeKind::Offset => write!(f, "offset"),
+            CopyRangeKind::Timestamp => write!(f, "timestamp"),
+        }
+    }
+}
+
+impl BytesSerializable for CopyMessages {
+    fn to_bytes(&self) -> Bytes {
+        let source_stream_id_bytes = self.source_stream_id.to_bytes();
+        let source_topic_id_bytes = self.source_topic_id.to_bytes();
+        let target_stream_id_bytes = self.target_stream_id.to_bytes();
+        let target_topic_id_bytes = self.target_topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            25 + source_stream_id_bytes.len()
+                + source_topic_id_bytes.len()
+                + target_stream_id_bytes.len()
+                + target_topic_id_bytes.len(),
+        );
+        bytes.put_slice(&source_stream_id_bytes);
+        bytes.put_slice(&source_topic_id_bytes);
+        bytes.put_u32_le(self.source_partition_id);
+        bytes.put_slice(&target_stream_id_bytes);
+        bytes.put_slice(&target_topic_id_bytes);
+        bytes.put_u32_le(self.target_partition_id);
+        bytes.put_u8(self.kind.as_code());
+        bytes.put_u64_le(self.from);
+        bytes.put_u64_le(self.to);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 37 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let source_stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += source_stream_id.get_size_bytes().as_bytes_usize();
+        let source_topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += source_topic_id.get_size_bytes().as_bytes_usize();
+        let source_partition_id = read_u32_le(&bytes, position)?;
+        position += 4;
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let target_stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += target_stream_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() <= position {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let target_topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += target_topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 21 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let target_partition_id = read_u32_le(&bytes, position)?;
+        let kind = CopyRangeKind::from_code(read_u8(&bytes, position + 4)?)?;
+        let from = read_u64_le(&bytes, position + 5)?;
+        let to = read_u64_le(&bytes, position + 13)?;
+        let command = CopyMessages {
+            source_stream_id,
+            source_topic_id,
+            source_partition_id,
+            target_stream_id,
+            target_topic_id,
+            target_partition_id,
+            kind,
+            from,
+            to,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for CopyMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.source_stream_id,
+            self.source_topic_id,
+            self.source_partition_id,
+            self.target_stream_id,
+            self.target_topic_id,
+            self.target_partition_id,
+            self.kind,
+            self.from,
+            self.to
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_command() -> CopyMessages {
+        CopyMessages {
+            source_stream_id: Identifier::numeric(1).unwrap(),
+            source_topic_id: Identifier::named("orders").unwrap(),
+            source_partition_id: 2,
+            target_stream_id: Identifier::named("staging").unwrap(),
+            target_topic_id: Identifier::numeric(3).unwrap(),
+            target_partition_id: 4,
+            kind: CopyRangeKind::Timestamp,
+            from: 100,
+            to: 200,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = create_command();
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let source_stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += source_stream_id.get_size_bytes().as_bytes_usize();
+        let source_topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += source_topic_id.get_size_bytes().as_bytes_usize();
+        let source_partition_id =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let target_stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += target_stream_id.get_size_bytes().as_bytes_usize();
+        let target_topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += target_topic_id.get_size_bytes().as_bytes_usize();
+        let target_partition_id =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let kind = CopyRangeKind::from_code(bytes[position + 4]).unwrap();
+        let from = u64::from_le_bytes(bytes[position + 5..position + 13].try_into().unwrap());
+        let to = u64::from_le_bytes(bytes[position + 13..position + 21].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(source_stream_id, command.source_stream_id);
+        assert_eq!(source_topic_id, command.source_topic_id);
+        assert_eq!(source_partition_id, command.source_partition_id);
+        assert_eq!(target_stream_id, command.target_stream_id);
+        assert_eq!(target_topic_id, command.target_topic_id);
+        assert_eq!(target_partition_id, command.target_partition_id);
+        assert_eq!(kind, command.kind);
+        assert_eq!(from, command.from);
+        assert_eq!(to, command.to);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = create_command();
+        let deserialized = CopyMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = create_command().to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 1);
+        assert!(CopyMessages::from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn should_reject_empty_range() {
+        let mut command = create_command();
+        assert!(command.validate().is_ok());
+        command.to = command.from;
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidCopyRange)
+        ));
+    }
+}
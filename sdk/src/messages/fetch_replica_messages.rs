@@ -0,0 +1,212 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, BytesSerializable};
+use crate::command::{Command, FETCH_REPLICA_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `FetchReplicaMessages` command is the internal replication protocol used by the follower to fetch the messages from the leader of the partition.
+/// The follower fetches by the offset of the next message it's missing and the epoch of the last message it has replicated.
+/// The leader validates the epoch, and if the follower's log has diverged (e.g. the leader's log was rewritten in a newer epoch),
+/// it returns the end offset of the follower's epoch instead of the messages, so that the follower can truncate its log to it and fetch again.
+/// The messages are returned as stored, without being decrypted or passed through the interceptors.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `replica_id` - unique ID of the follower fetching the messages.
+/// - `offset` - the offset of the next message to fetch.
+/// - `epoch` - the epoch of the last message replicated by the follower.
+/// - `count` - the maximum number of messages to fetch.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct FetchReplicaMessages {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(default = "default_partition_id")]
+    pub partition_id: u32,
+    /// Unique ID of the follower fetching the messages.
+    pub replica_id: u32,
+    /// The offset of the next message to fetch.
+    #[serde(default)]
+    pub offset: u64,
+    /// The epoch of the last message replicated by the follower.
+    #[serde(default)]
+    pub epoch: u32,
+    /// The maximum number of messages to fetch.
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+impl Default for FetchReplicaMessages {
+    fn default() -> Self {
+        Self {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partition_id: default_partition_id(),
+            replica_id: 1,
+            offset: 0,
+            epoch: 0,
+            count: default_count(),
+        }
+    }
+}
+
+impl Command for FetchReplicaMessages {
+    fn code(&self) -> u32 {
+        FETCH_REPLICA_MESSAGES_CODE
+    }
+}
+
+fn default_partition_id() -> u32 {
+    1
+}
+
+fn default_count() -> u32 {
+    1000
+}
+
+impl Validatable<IggyError> for FetchReplicaMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 || self.replica_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self.count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for FetchReplicaMessages {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(24 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u32_le(self.replica_id);
+        bytes.put_u64_le(self.offset);
+        bytes.put_u32_le(self.epoch);
+        bytes.put_u32_le(self.count);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 30 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 24 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let replica_id = read_u32_le(&bytes, position + 4)?;
+        let offset = read_u64_le(&bytes, position + 8)?;
+        let epoch = read_u32_le(&bytes, position + 16)?;
+        let count = read_u32_le(&bytes, position + 20)?;
+        let command = FetchReplicaMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            replica_id,
+            offset,
+            epoch,
+            count,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for FetchReplicaMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.replica_id,
+            self.offset,
+            self.epoch,
+            self.count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_command() -> FetchReplicaMessages {
+        FetchReplicaMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partition_id: 2,
+            replica_id: 3,
+            offset: 100,
+            epoch: 4,
+            count: 10,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = create_command();
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let replica_id = u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 8..position + 16].try_into().unwrap());
+        let epoch = u32::from_le_bytes(bytes[position + 16..position + 20].try_into().unwrap());
+        let count = u32::from_le_bytes(bytes[position + 20..position + 24].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(replica_id, command.replica_id);
+        assert_eq!(offset, command.offset);
+        assert_eq!(epoch, command.epoch);
+        assert_eq!(count, command.count);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = create_command();
+        let deserialized = FetchReplicaMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_validated_without_replica_id_or_count() {
+        let mut command = create_command();
+        command.replica_id = 0;
+        assert!(command.validate().is_err());
+
+        let mut command = create_command();
+        command.count = 0;
+        assert!(command.validate().is_err());
+    }
+}
@@ -4,7 +4,7 @@ use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    bytes_serializable::BytesSerializable,
+    bytes_serializable::{read_u32_le, read_u8, BytesSerializable},
     command::{Command, FLUSH_UNSAVED_BUFFER_CODE},
     error::IggyError,
     identifier::Identifier,
@@ -75,9 +75,9 @@ impl BytesSerializable for FlushUnsavedBuffer {
         position += stream_id.to_bytes().len();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.to_bytes().len();
-        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partition_id = read_u32_le(&bytes, position)?;
         position += 4;
-        let fsync = bytes[position] == 1;
+        let fsync = read_u8(&bytes, position)? == 1;
         Ok(FlushUnsavedBuffer {
             stream_id,
             topic_id,
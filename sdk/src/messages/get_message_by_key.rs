@@ -0,0 +1,166 @@
+use crate::bytes_serializable::{read_slice, read_u32_le, BytesSerializable};
+use crate::command::{Command, GET_MESSAGE_BY_KEY_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+
+/// `GetMessageByKey` command is used to read the latest message for the given key (message ID) in the partition of the compacted topic,
+/// without storing or using the consumer offsets, e.g. to use the topic as a lightweight state store.
+/// The segments which definitely don't contain the key are skipped based on their key filters.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `key` - the key (message ID) of the message.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetMessageByKey {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(default = "default_partition_id")]
+    pub partition_id: u32,
+    /// The key (message ID) of the message.
+    #[serde_as(as = "DisplayFromStr")]
+    pub key: u128,
+}
+
+impl Default for GetMessageByKey {
+    fn default() -> Self {
+        Self {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partition_id: default_partition_id(),
+            key: 0,
+        }
+    }
+}
+
+impl Command for GetMessageByKey {
+    fn code(&self) -> u32 {
+        GET_MESSAGE_BY_KEY_CODE
+    }
+}
+
+fn default_partition_id() -> u32 {
+    1
+}
+
+impl Validatable<IggyError> for GetMessageByKey {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetMessageByKey {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(20 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u128_le(self.key);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 26 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 20 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let key = u128::from_le_bytes(
+            read_slice(&bytes, position + 4, 16)?
+                .try_into()
+                .map_err(|_| IggyError::InvalidCommand)?,
+        );
+        let command = GetMessageByKey {
+            stream_id,
+            topic_id,
+            partition_id,
+            key,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for GetMessageByKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.key
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_command() -> GetMessageByKey {
+        GetMessageByKey {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partition_id: 2,
+            key: u128::MAX - 1,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = create_command();
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let key = u128::from_le_bytes(bytes[position + 4..position + 20].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(key, command.key);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = create_command();
+        let deserialized = GetMessageByKey::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = create_command().to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 1);
+        assert!(GetMessageByKey::from_bytes(bytes).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::bytes_serializable::{read_u32_le, BytesSerializable};
+use crate::command::{Command, INIT_PRODUCER_EPOCH_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `InitProducerEpoch` command makes the currently connected client the exclusive producer of the topic, bound to the producer epoch.
+/// A new producer instance bumps the epoch, which fences the previous instance, even if it's still connected,
+/// while the same instance resumes its epoch after reconnecting. The appends from the stale epochs are rejected.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `epoch` - the epoch to resume, or `0` to bump the epoch for the new producer instance.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct InitProducerEpoch {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// The epoch to resume, or `0` to bump the epoch for the new producer instance.
+    #[serde(default)]
+    pub epoch: u32,
+}
+
+impl Command for InitProducerEpoch {
+    fn code(&self) -> u32 {
+        INIT_PRODUCER_EPOCH_CODE
+    }
+}
+
+impl Validatable<IggyError> for InitProducerEpoch {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for InitProducerEpoch {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + topic_id_bytes.len() + 4);
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.epoch);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<InitProducerEpoch, IggyError> {
+        if bytes.len() < 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let epoch = read_u32_le(&bytes, position)?;
+        let command = InitProducerEpoch {
+            stream_id,
+            topic_id,
+            epoch,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for InitProducerEpoch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = InitProducerEpoch {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            epoch: 3,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let epoch = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(epoch, command.epoch);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = InitProducerEpoch {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            epoch: 3,
+        };
+
+        let deserialized = InitProducerEpoch::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+}
@@ -1,6 +1,15 @@
+pub mod acquire_exclusive_producer;
+pub mod copy_messages;
+pub mod fetch_replica_messages;
 pub mod flush_unsaved_buffer;
+pub mod get_message_by_key;
+pub mod init_producer_epoch;
+pub mod peek_messages;
 pub mod poll_messages;
+pub mod poll_messages_from_partitions;
 pub mod send_messages;
 
 const MAX_HEADERS_SIZE: u32 = 100 * 1000;
+const MAX_PARTITIONS_TO_POLL: usize = 1000;
+const MAX_MESSAGES_TO_PEEK: u32 = 1000;
 pub const MAX_PAYLOAD_SIZE: u32 = 10 * 1000 * 1000;
@@ -0,0 +1,244 @@
+use crate::bytes_serializable::{read_u32_le, read_u8, BytesSerializable};
+use crate::command::{Command, PEEK_MESSAGES_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::MAX_MESSAGES_TO_PEEK;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `PeekMessages` command is used to sample the messages of a partition for debugging purposes, without storing or using the consumer offsets.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `kind` - which messages to return: the first, the last or the random ones.
+/// - `count` - number of messages to return, max 1000.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PeekMessages {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(default = "default_partition_id")]
+    pub partition_id: u32,
+    /// Which messages to return: the first, the last or the random ones.
+    #[serde(default)]
+    pub kind: PeekKind,
+    /// Number of messages to return, max 1000.
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+/// `PeekKind` specifies which messages of the partition are returned by `PeekMessages`:
+/// - `First` - the oldest messages still available in the partition.
+/// - `Last` - the newest messages in the partition.
+/// - `Random` - the messages at random offsets, ordered by the offset.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PeekKind {
+    #[default]
+    /// The oldest messages still available in the partition.
+    First,
+    /// The newest messages in the partition.
+    Last,
+    /// The messages at random offsets, ordered by the offset.
+    Random,
+}
+
+impl Default for PeekMessages {
+    fn default() -> Self {
+        Self {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partition_id: default_partition_id(),
+            kind: PeekKind::default(),
+            count: default_count(),
+        }
+    }
+}
+
+impl Command for PeekMessages {
+    fn code(&self) -> u32 {
+        PEEK_MESSAGES_CODE
+    }
+}
+
+fn default_partition_id() -> u32 {
+    1
+}
+
+fn default_count() -> u32 {
+    10
+}
+
+impl Validatable<IggyError> for PeekMessages {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.count == 0 || self.count > MAX_MESSAGES_TO_PEEK {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        Ok(())
+    }
+}
+
+impl PeekKind {
+    /// Returns code of the peek kind.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PeekKind::First => 1,
+            PeekKind::Last => 2,
+            PeekKind::Random => 3,
+        }
+    }
+
+    /// Returns peek kind from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PeekKind::First),
+            2 => Ok(PeekKind::Last),
+            3 => Ok(PeekKind::Random),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for PeekKind {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "f" | "first" => Ok(PeekKind::First),
+            "l" | "last" => Ok(PeekKind::Last),
+            "r" | "random" => Ok(PeekKind::Random),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PeekKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PeekKind::First => write!(f, "first"),
+            PeekKind::Last => write!(f, "last"),
+            PeekKind::Random => write!(f, "random"),
+        }
+    }
+}
+
+impl BytesSerializable for PeekMessages {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(9 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u8(self.kind.as_code());
+        bytes.put_u32_le(self.count);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 15 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let kind = PeekKind::from_code(read_u8(&bytes, position + 4)?)?;
+        let count = read_u32_le(&bytes, position + 5)?;
+        let command = PeekMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            kind,
+            count,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for PeekMessages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.kind, self.count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_command() -> PeekMessages {
+        PeekMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::named("topic").unwrap(),
+            partition_id: 2,
+            kind: PeekKind::Random,
+            count: 5,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = create_command();
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let kind = PeekKind::from_code(bytes[position + 4]).unwrap();
+        let count = u32::from_le_bytes(bytes[position + 5..position + 9].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(kind, command.kind);
+        assert_eq!(count, command.count);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = create_command();
+        let deserialized = PeekMessages::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = create_command().to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 1);
+        assert!(PeekMessages::from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_count() {
+        let mut command = create_command();
+        assert!(command.validate().is_ok());
+        command.count = 0;
+        assert!(command.validate().is_err());
+        command.count = MAX_MESSAGES_TO_PEEK + 1;
+        assert!(command.validate().is_err());
+    }
+}
@@ -1,8 +1,9 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, read_u64_le, read_u8, BytesSerializable};
 use crate::command::{Command, POLL_MESSAGES_CODE};
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::utils::duration::IggyDuration;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::validatable::Validatable;
@@ -20,6 +21,7 @@ use std::str::FromStr;
 /// - `partition_id` - partition ID from which messages will be polled. Has to be specified for the regular consumer. For consumer group it is ignored (use `None`).
 /// - `strategy` - polling strategy which specifies from where to start polling messages.
 /// - `count` - number of messages to poll.
+/// - `max_bytes` - maximum size of the polled messages in bytes, `0` means no limit. Polling stops when either `count` or `max_bytes` is reached.
 /// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
@@ -42,6 +44,9 @@ pub struct PollMessages {
     /// Number of messages to poll.
     pub count: u32,
     #[serde(default)]
+    /// Maximum size of the polled messages in bytes, `0` means no limit. At least one message is always returned, even if it exceeds the limit.
+    pub max_bytes: u32,
+    #[serde(default)]
     /// Whether to commit offset on the server automatically after polling the messages.
     pub auto_commit: bool,
 }
@@ -53,6 +58,10 @@ pub struct PollMessages {
 /// - `First` - start polling from the first message in the partition.
 /// - `Last` - start polling from the last message in the partition.
 /// - `Next` - start polling from the next message after the last polled message based on the stored consumer offset.
+///
+/// Additionally, the `out_of_range` policy specifies what happens when the requested offset is out of the range of the available offsets,
+/// the `direction` specifies whether the messages are polled forward or backward from the starting point,
+/// and the `end_timestamp` bounds the forward polling to the messages created before it, e.g. to fetch exactly one time window.
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
 pub struct PollingStrategy {
@@ -64,6 +73,18 @@ pub struct PollingStrategy {
     #[serde_as(as = "DisplayFromStr")]
     #[serde(default = "default_value")]
     pub value: u64,
+    /// Policy applied when the requested offset is out of the range of the available offsets.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub out_of_range: OutOfRangePolicy,
+    /// Direction in which the messages are polled from the starting point.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub direction: PollingDirection,
+    /// Timestamp (exclusive) at which the forward polling stops, `0` means no end.
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(default)]
+    pub end_timestamp: u64,
 }
 
 /// `PollingKind` is an enum which specifies from where to start polling messages and is used by `PollingStrategy`.
@@ -83,6 +104,39 @@ pub enum PollingKind {
     Next,
 }
 
+/// `OutOfRangePolicy` specifies what happens when the requested offset (either the explicit one or the next one based on the stored consumer offset)
+/// is below the low watermark (e.g. the messages were already removed by the retention) or above the high watermark of the partition (e.g. the partition was purged).
+/// It is used by `PollingStrategy` and has the following kinds:
+/// - `Error` - return an error containing the low or high watermark, so the consumer can adjust the offset on its own.
+/// - `Earliest` - start polling from the first message still available in the partition.
+/// - `Latest` - start polling from the latest message in the partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum OutOfRangePolicy {
+    #[default]
+    /// Return an error containing the low or high watermark of the partition.
+    Error,
+    /// Start polling from the first message still available in the partition.
+    Earliest,
+    /// Start polling from the latest message in the partition.
+    Latest,
+}
+
+/// `PollingDirection` specifies in which direction the messages are polled from the starting point and is used by `PollingStrategy`.
+/// It has the following kinds:
+/// - `Forward` - poll the messages with the offsets greater than or equal to the starting one, from the oldest to the newest.
+/// - `Backward` - poll the messages with the offsets lower than or equal to the starting one, from the newest to the oldest,
+///   e.g. to page back from the head of the partition. It cannot be used for the `Next` kind or with `auto_commit`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PollingDirection {
+    #[default]
+    /// Poll the messages from the oldest to the newest.
+    Forward,
+    /// Poll the messages from the newest to the oldest.
+    Backward,
+}
+
 impl Default for PollMessages {
     fn default() -> Self {
         Self {
@@ -92,6 +146,7 @@ impl Default for PollMessages {
             partition_id: default_partition_id(),
             strategy: default_strategy(),
             count: default_count(),
+            max_bytes: 0,
             auto_commit: false,
         }
     }
@@ -102,6 +157,9 @@ impl Default for PollingStrategy {
         Self {
             kind: PollingKind::Offset,
             value: 0,
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 }
@@ -134,6 +192,20 @@ fn default_count() -> u32 {
 
 impl Validatable<IggyError> for PollMessages {
     fn validate(&self) -> Result<(), IggyError> {
+        if self.strategy.direction == PollingDirection::Backward
+            && (self.strategy.kind == PollingKind::Next || self.auto_commit)
+        {
+            return Err(IggyError::InvalidPollingDirection);
+        }
+
+        if self.strategy.end_timestamp > 0
+            && (self.strategy.direction == PollingDirection::Backward
+                || (self.strategy.kind == PollingKind::Timestamp
+                    && self.strategy.end_timestamp <= self.strategy.value))
+        {
+            return Err(IggyError::InvalidPollingTimestampRange);
+        }
+
         Ok(())
     }
 }
@@ -144,6 +216,9 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Offset,
             value,
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 
@@ -152,6 +227,9 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Timestamp,
             value: value.into(),
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 
@@ -160,6 +238,9 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::First,
             value: 0,
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 
@@ -168,6 +249,9 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Last,
             value: 0,
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 
@@ -176,9 +260,38 @@ impl PollingStrategy {
         Self {
             kind: PollingKind::Next,
             value: 0,
+            out_of_range: OutOfRangePolicy::default(),
+            direction: PollingDirection::default(),
+            end_timestamp: 0,
         }
     }
 
+    /// Set the policy applied when the requested offset is out of the range of the available offsets, affects only `Offset` and `Next` kinds.
+    pub fn out_of_range(mut self, policy: OutOfRangePolicy) -> Self {
+        self.out_of_range = policy;
+        self
+    }
+
+    /// Set the direction in which the messages are polled from the starting point.
+    pub fn direction(mut self, direction: PollingDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Stop polling forward at the specified timestamp (exclusive), so that only the messages created before it are returned.
+    pub fn until(mut self, end_timestamp: IggyTimestamp) -> Self {
+        self.end_timestamp = end_timestamp.into();
+        self
+    }
+
+    /// Stop polling forward once the specified duration elapses from the starting timestamp, affects only the `Timestamp` kind.
+    pub fn within(mut self, duration: IggyDuration) -> Self {
+        if self.kind == PollingKind::Timestamp {
+            self.end_timestamp = self.value.saturating_add(duration.as_micros());
+        }
+        self
+    }
+
     /// Change the value of the polling strategy, affects only `Offset` and `Timestamp` kinds.
     pub fn set_value(&mut self, value: u64) {
         if self.kind == PollingKind::Offset || self.kind == PollingKind::Timestamp {
@@ -238,6 +351,88 @@ impl Display for PollingKind {
     }
 }
 
+impl OutOfRangePolicy {
+    /// Returns code of the out of range policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            OutOfRangePolicy::Error => 1,
+            OutOfRangePolicy::Earliest => 2,
+            OutOfRangePolicy::Latest => 3,
+        }
+    }
+
+    /// Returns out of range policy from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(OutOfRangePolicy::Error),
+            2 => Ok(OutOfRangePolicy::Earliest),
+            3 => Ok(OutOfRangePolicy::Latest),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for OutOfRangePolicy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "error" => Ok(OutOfRangePolicy::Error),
+            "earliest" => Ok(OutOfRangePolicy::Earliest),
+            "latest" => Ok(OutOfRangePolicy::Latest),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for OutOfRangePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutOfRangePolicy::Error => write!(f, "error"),
+            OutOfRangePolicy::Earliest => write!(f, "earliest"),
+            OutOfRangePolicy::Latest => write!(f, "latest"),
+        }
+    }
+}
+
+impl PollingDirection {
+    /// Returns code of the polling direction.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            PollingDirection::Forward => 1,
+            PollingDirection::Backward => 2,
+        }
+    }
+
+    /// Returns polling direction from the specified code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(PollingDirection::Forward),
+            2 => Ok(PollingDirection::Backward),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for PollingDirection {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "f" | "forward" => Ok(PollingDirection::Forward),
+            "b" | "backward" => Ok(PollingDirection::Backward),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for PollingDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PollingDirection::Forward => write!(f, "forward"),
+            PollingDirection::Backward => write!(f, "backward"),
+        }
+    }
+}
+
 impl BytesSerializable for PollMessages {
     fn to_bytes(&self) -> Bytes {
         as_bytes(
@@ -247,12 +442,13 @@ impl BytesSerializable for PollMessages {
             &self.consumer,
             &self.strategy,
             self.count,
+            self.max_bytes,
             self.auto_commit,
         )
     }
 
     fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
-        if bytes.len() < 29 {
+        if bytes.len() < 43 {
             return Err(IggyError::InvalidCommand);
         }
 
@@ -268,20 +464,28 @@ impl BytesSerializable for PollMessages {
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
-        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partition_id = read_u32_le(&bytes, position)?;
         let partition_id = match partition_id {
             0 => None,
             partition_id => Some(partition_id),
         };
-        let polling_kind = PollingKind::from_code(bytes[position + 4])?;
+        let polling_kind = PollingKind::from_code(read_u8(&bytes, position + 4)?)?;
         position += 5;
-        let value = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let value = read_u64_le(&bytes, position)?;
+        let out_of_range = OutOfRangePolicy::from_code(read_u8(&bytes, position + 8)?)?;
+        let direction = PollingDirection::from_code(read_u8(&bytes, position + 9)?)?;
+        let end_timestamp = read_u64_le(&bytes, position + 10)?;
+        position += 18;
         let strategy = PollingStrategy {
             kind: polling_kind,
             value,
+            out_of_range,
+            direction,
+            end_timestamp,
         };
-        let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
-        let auto_commit = bytes[position + 12];
+        let count = read_u32_le(&bytes, position)?;
+        let max_bytes = read_u32_le(&bytes, position + 4)?;
+        let auto_commit = read_u8(&bytes, position + 8)?;
         let auto_commit = matches!(auto_commit, 1);
         let command = PollMessages {
             consumer,
@@ -290,6 +494,7 @@ impl BytesSerializable for PollMessages {
             partition_id,
             strategy,
             count,
+            max_bytes,
             auto_commit,
         };
         Ok(command)
@@ -297,6 +502,7 @@ impl BytesSerializable for PollMessages {
 }
 
 // This method is used by the new version of `IggyClient` to serialize `PollMessages` without cloning the args.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn as_bytes(
     stream_id: &Identifier,
     topic_id: &Identifier,
@@ -304,6 +510,7 @@ pub(crate) fn as_bytes(
     consumer: &Consumer,
     strategy: &PollingStrategy,
     count: u32,
+    max_bytes: u32,
     auto_commit: bool,
 ) -> Bytes {
     let consumer_bytes = consumer.to_bytes();
@@ -311,7 +518,7 @@ pub(crate) fn as_bytes(
     let topic_id_bytes = topic_id.to_bytes();
     let strategy_bytes = strategy.to_bytes();
     let mut bytes = BytesMut::with_capacity(
-        9 + consumer_bytes.len()
+        13 + consumer_bytes.len()
             + stream_id_bytes.len()
             + topic_id_bytes.len()
             + strategy_bytes.len(),
@@ -326,6 +533,7 @@ pub(crate) fn as_bytes(
     }
     bytes.put_slice(&strategy_bytes);
     bytes.put_u32_le(count);
+    bytes.put_u32_le(max_bytes);
     if auto_commit {
         bytes.put_u8(1);
     } else {
@@ -339,13 +547,14 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
+            self.max_bytes,
             auto_commit_to_string(self.auto_commit)
         )
     }
@@ -353,7 +562,11 @@ impl Display for PollMessages {
 
 impl Display for PollingStrategy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.kind, self.value)
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.kind, self.value, self.out_of_range, self.direction, self.end_timestamp
+        )
     }
 }
 
@@ -367,20 +580,32 @@ fn auto_commit_to_string(auto_commit: bool) -> &'static str {
 
 impl BytesSerializable for PollingStrategy {
     fn to_bytes(&self) -> Bytes {
-        let mut bytes = BytesMut::with_capacity(9);
+        let mut bytes = BytesMut::with_capacity(19);
         bytes.put_u8(self.kind.as_code());
         bytes.put_u64_le(self.value);
+        bytes.put_u8(self.out_of_range.as_code());
+        bytes.put_u8(self.direction.as_code());
+        bytes.put_u64_le(self.end_timestamp);
         bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
-        if bytes.len() != 9 {
+        if bytes.len() != 19 {
             return Err(IggyError::InvalidCommand);
         }
 
         let kind = PollingKind::from_code(bytes[0])?;
-        let value = u64::from_le_bytes(bytes[1..9].try_into()?);
-        let strategy = PollingStrategy { kind, value };
+        let value = read_u64_le(&bytes, 1)?;
+        let out_of_range = OutOfRangePolicy::from_code(bytes[9])?;
+        let direction = PollingDirection::from_code(bytes[10])?;
+        let end_timestamp = read_u64_le(&bytes, 11)?;
+        let strategy = PollingStrategy {
+            kind,
+            value,
+            out_of_range,
+            direction,
+            end_timestamp,
+        };
         Ok(strategy)
     }
 }
@@ -396,8 +621,11 @@ mod tests {
             stream_id: Identifier::numeric(2).unwrap(),
             topic_id: Identifier::numeric(3).unwrap(),
             partition_id: Some(4),
-            strategy: PollingStrategy::offset(2),
+            strategy: PollingStrategy::offset(2)
+                .out_of_range(OutOfRangePolicy::Earliest)
+                .until(IggyTimestamp::from(1000)),
             count: 3,
+            max_bytes: 1024,
             auto_commit: true,
         };
 
@@ -418,12 +646,21 @@ mod tests {
         let polling_kind = PollingKind::from_code(bytes[position + 4]).unwrap();
         position += 5;
         let value = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let out_of_range = OutOfRangePolicy::from_code(bytes[position + 8]).unwrap();
+        let direction = PollingDirection::from_code(bytes[position + 9]).unwrap();
+        let end_timestamp =
+            u64::from_le_bytes(bytes[position + 10..position + 18].try_into().unwrap());
+        position += 18;
         let strategy = PollingStrategy {
             kind: polling_kind,
             value,
+            out_of_range,
+            direction,
+            end_timestamp,
         };
-        let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
-        let auto_commit = bytes[position + 12];
+        let count = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let max_bytes = u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+        let auto_commit = bytes[position + 8];
         let auto_commit = matches!(auto_commit, 1);
 
         assert!(!bytes.is_empty());
@@ -433,6 +670,7 @@ mod tests {
         assert_eq!(Some(partition_id), command.partition_id);
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
+        assert_eq!(max_bytes, command.max_bytes);
         assert_eq!(auto_commit, command.auto_commit);
     }
 
@@ -442,8 +680,11 @@ mod tests {
         let stream_id = Identifier::numeric(2).unwrap();
         let topic_id = Identifier::numeric(3).unwrap();
         let partition_id = 4u32;
-        let strategy = PollingStrategy::offset(2);
+        let strategy = PollingStrategy::offset(2)
+            .out_of_range(OutOfRangePolicy::Latest)
+            .direction(PollingDirection::Backward);
         let count = 3u32;
+        let max_bytes = 1024u32;
         let auto_commit = 1u8;
 
         let consumer_bytes = consumer.to_bytes();
@@ -451,7 +692,7 @@ mod tests {
         let topic_id_bytes = topic_id.to_bytes();
         let strategy_bytes = strategy.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            9 + consumer_bytes.len()
+            13 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -462,6 +703,7 @@ mod tests {
         bytes.put_u32_le(partition_id);
         bytes.put_slice(&strategy_bytes);
         bytes.put_u32_le(count);
+        bytes.put_u32_le(max_bytes);
         bytes.put_u8(auto_commit);
 
         let command = PollMessages::from_bytes(bytes.freeze());
@@ -476,6 +718,54 @@ mod tests {
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
+        assert_eq!(command.max_bytes, max_bytes);
         assert_eq!(command.auto_commit, auto_commit);
     }
+
+    #[test]
+    fn should_not_be_validated_for_backward_direction_with_next_kind_or_auto_commit() {
+        let mut command = PollMessages {
+            strategy: PollingStrategy::last().direction(PollingDirection::Backward),
+            ..PollMessages::default()
+        };
+        assert!(command.validate().is_ok());
+
+        command.auto_commit = true;
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPollingDirection)
+        ));
+
+        command.auto_commit = false;
+        command.strategy = PollingStrategy::next().direction(PollingDirection::Backward);
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPollingDirection)
+        ));
+    }
+
+    #[test]
+    fn should_not_be_validated_for_invalid_timestamp_range() {
+        let start = IggyTimestamp::from(1000);
+        let mut command = PollMessages {
+            strategy: PollingStrategy::timestamp(start).within(IggyDuration::from(500)),
+            ..PollMessages::default()
+        };
+        assert_eq!(command.strategy.end_timestamp, 1500);
+        assert!(command.validate().is_ok());
+
+        command.strategy = PollingStrategy::timestamp(start).until(start);
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPollingTimestampRange)
+        ));
+
+        command.strategy = PollingStrategy::last()
+            .direction(PollingDirection::Backward)
+            .until(start);
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidPollingTimestampRange)
+        ));
+    }
 }
@@ -0,0 +1,313 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, read_u8, BytesSerializable};
+use crate::command::{Command, POLL_MESSAGES_FROM_PARTITIONS_CODE};
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::OutOfRangePolicy;
+use crate::messages::MAX_PARTITIONS_TO_POLL;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// `PollMessagesFromPartitions` command is used to poll messages from multiple partitions of a topic in a single request.
+/// It has additional payload:
+/// - `consumer` - consumer which will poll messages. Either regular consumer or consumer group.
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partitions` - set of partition IDs and the offsets from which the messages will be polled, max 1000 partitions.
+/// - `out_of_range` - policy applied when the requested offset is out of the range of the available offsets.
+/// - `count` - number of messages to poll from each partition.
+/// - `auto_commit` - whether to commit offset on the server automatically after polling the messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PollMessagesFromPartitions {
+    /// Consumer which will poll messages. Either regular consumer or consumer group.
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Set of partition IDs and the offsets from which the messages will be polled, max 1000 partitions.
+    pub partitions: Vec<PartitionOffset>,
+    /// Policy applied when the requested offset is out of the range of the available offsets.
+    #[serde(default)]
+    pub out_of_range: OutOfRangePolicy,
+    /// Number of messages to poll from each partition.
+    #[serde(default = "default_count")]
+    pub count: u32,
+    /// Whether to commit offset on the server automatically after polling the messages.
+    #[serde(default)]
+    pub auto_commit: bool,
+}
+
+/// `PartitionOffset` represents the partition and the offset from which the messages will be polled.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct PartitionOffset {
+    /// Unique partition ID.
+    pub partition_id: u32,
+    /// Offset from which the messages will be polled.
+    pub offset: u64,
+}
+
+impl PartitionOffset {
+    /// Creates a new partition offset.
+    pub fn new(partition_id: u32, offset: u64) -> Self {
+        Self {
+            partition_id,
+            offset,
+        }
+    }
+}
+
+impl Default for PollMessagesFromPartitions {
+    fn default() -> Self {
+        Self {
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(1).unwrap(),
+            partitions: vec![PartitionOffset::new(1, 0)],
+            out_of_range: OutOfRangePolicy::default(),
+            count: default_count(),
+            auto_commit: false,
+        }
+    }
+}
+
+impl Command for PollMessagesFromPartitions {
+    fn code(&self) -> u32 {
+        POLL_MESSAGES_FROM_PARTITIONS_CODE
+    }
+}
+
+fn default_count() -> u32 {
+    10
+}
+
+impl Validatable<IggyError> for PollMessagesFromPartitions {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        if self.partitions.is_empty() {
+            return Err(IggyError::InvalidPartitionsToPoll);
+        }
+
+        if self.partitions.len() > MAX_PARTITIONS_TO_POLL {
+            return Err(IggyError::TooManyPartitions);
+        }
+
+        let mut partition_ids = HashSet::with_capacity(self.partitions.len());
+        for partition in &self.partitions {
+            if partition.partition_id == 0 || !partition_ids.insert(partition.partition_id) {
+                return Err(IggyError::InvalidPartitionsToPoll);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for PollMessagesFromPartitions {
+    fn to_bytes(&self) -> Bytes {
+        as_bytes(
+            &self.stream_id,
+            &self.topic_id,
+            &self.consumer,
+            &self.partitions,
+            self.out_of_range,
+            self.count,
+            self.auto_commit,
+        )
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
+        if bytes.len() < 25 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..))?;
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() < position + 10 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let count = read_u32_le(&bytes, position)?;
+        let auto_commit = matches!(read_u8(&bytes, position + 4)?, 1);
+        let out_of_range = OutOfRangePolicy::from_code(read_u8(&bytes, position + 5)?)?;
+        let partitions_count = read_u32_le(&bytes, position + 6)?;
+        position += 10;
+        if bytes.len() != position + partitions_count as usize * 12 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut partitions = Vec::with_capacity(partitions_count as usize);
+        for _ in 0..partitions_count {
+            let partition_id = read_u32_le(&bytes, position)?;
+            let offset = read_u64_le(&bytes, position + 4)?;
+            partitions.push(PartitionOffset::new(partition_id, offset));
+            position += 12;
+        }
+
+        let command = PollMessagesFromPartitions {
+            consumer,
+            stream_id,
+            topic_id,
+            partitions,
+            out_of_range,
+            count,
+            auto_commit,
+        };
+        Ok(command)
+    }
+}
+
+// This method is used by the new version of `IggyClient` to serialize `PollMessagesFromPartitions` without cloning the args.
+pub(crate) fn as_bytes(
+    stream_id: &Identifier,
+    topic_id: &Identifier,
+    consumer: &Consumer,
+    partitions: &[PartitionOffset],
+    out_of_range: OutOfRangePolicy,
+    count: u32,
+    auto_commit: bool,
+) -> Bytes {
+    let consumer_bytes = consumer.to_bytes();
+    let stream_id_bytes = stream_id.to_bytes();
+    let topic_id_bytes = topic_id.to_bytes();
+    let mut bytes = BytesMut::with_capacity(
+        10 + consumer_bytes.len()
+            + stream_id_bytes.len()
+            + topic_id_bytes.len()
+            + 12 * partitions.len(),
+    );
+    bytes.put_slice(&consumer_bytes);
+    bytes.put_slice(&stream_id_bytes);
+    bytes.put_slice(&topic_id_bytes);
+    bytes.put_u32_le(count);
+    if auto_commit {
+        bytes.put_u8(1);
+    } else {
+        bytes.put_u8(0);
+    }
+    bytes.put_u8(out_of_range.as_code());
+    bytes.put_u32_le(partitions.len() as u32);
+    for partition in partitions {
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u64_le(partition.offset);
+    }
+
+    bytes.freeze()
+}
+
+impl Display for PollMessagesFromPartitions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let partitions = self
+            .partitions
+            .iter()
+            .map(|partition| format!("{}:{}", partition.partition_id, partition.offset))
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}",
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            partitions,
+            self.out_of_range,
+            self.count,
+            if self.auto_commit { "a" } else { "n" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_command() -> PollMessagesFromPartitions {
+        PollMessagesFromPartitions {
+            consumer: Consumer::new(Identifier::numeric(1).unwrap()),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partitions: vec![PartitionOffset::new(1, 10), PartitionOffset::new(2, 20)],
+            out_of_range: OutOfRangePolicy::Earliest,
+            count: 5,
+            auto_commit: true,
+        }
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = create_command();
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0]).unwrap();
+        let consumer_id = Identifier::from_bytes(bytes.slice(1..)).unwrap();
+        position += 1 + consumer_id.get_size_bytes().as_bytes_usize();
+        let stream_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let count = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let auto_commit = bytes[position + 4];
+        let out_of_range = OutOfRangePolicy::from_code(bytes[position + 5]).unwrap();
+        let partitions_count =
+            u32::from_le_bytes(bytes[position + 6..position + 10].try_into().unwrap());
+        position += 10;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+
+        assert_eq!(consumer_kind, command.consumer.kind);
+        assert_eq!(consumer_id, command.consumer.id);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(count, command.count);
+        assert_eq!(auto_commit, 1);
+        assert_eq!(out_of_range, command.out_of_range);
+        assert_eq!(partitions_count, 2);
+        assert_eq!(partition_id, 1);
+        assert_eq!(offset, 10);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let command = create_command();
+        let deserialized = PollMessagesFromPartitions::from_bytes(command.to_bytes()).unwrap();
+        assert_eq!(deserialized, command);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_truncated_bytes() {
+        let bytes = create_command().to_bytes();
+        let bytes = bytes.slice(..bytes.len() - 1);
+        assert!(PollMessagesFromPartitions::from_bytes(bytes).is_err());
+    }
+
+    #[test]
+    fn should_reject_duplicated_partitions() {
+        let mut command = create_command();
+        assert!(command.validate().is_ok());
+        command.partitions.push(PartitionOffset::new(1, 0));
+        assert!(command.validate().is_err());
+        command.partitions.clear();
+        assert!(command.validate().is_err());
+    }
+}
@@ -1,11 +1,13 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_slice, read_u32_le, BytesSerializable};
 use crate::command::{Command, SEND_MESSAGES_CODE};
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::{MAX_HEADERS_SIZE, MAX_PAYLOAD_SIZE};
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::content_type::ContentType;
 use crate::utils::sizeable::Sizeable;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -25,6 +27,7 @@ const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 /// - `stream_id` - unique stream ID (numeric or name).
 /// - `topic_id` - unique topic ID (numeric or name).
 /// - `partitioning` - to which partition the messages should be sent - either provided by the client or calculated by the server.
+/// - `ack` - acknowledgement level which determines when the server responds to the request.
 /// - `messages` - collection of messages to be sent.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
@@ -36,10 +39,33 @@ pub struct SendMessages {
     pub topic_id: Identifier,
     /// To which partition the messages should be sent - either provided by the client or calculated by the server.
     pub partitioning: Partitioning,
+    /// Acknowledgement level which determines when the server responds to the request.
+    #[serde(default)]
+    pub ack: AckLevel,
     /// Collection of messages to be sent.
     pub messages: Vec<Message>,
 }
 
+/// `AckLevel` specifies when the server acknowledges the sent messages:
+/// - `None` - fire-and-forget, the server responds before the messages are appended.
+/// - `Leader` - the server responds once the messages are appended in memory.
+/// - `Fsync` - the server responds once the messages are persisted to disk with fsync.
+/// - `All` - the server responds once the messages are persisted to disk with fsync and replicated by all the in-sync replicas.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AckLevel {
+    /// Fire-and-forget, the server responds before the messages are appended, so the append errors are only logged by the server.
+    None,
+    /// The server responds once the messages are appended in memory.
+    #[default]
+    Leader,
+    /// The server responds once the messages are persisted to disk with fsync.
+    Fsync,
+    /// The server responds once the messages are persisted to disk with fsync and replicated by all the in-sync replicas,
+    /// i.e. once the visible high watermark of the partition has passed them.
+    All,
+}
+
 /// `Partitioning` is used to specify to which partition the messages should be sent.
 /// It has the following kinds:
 /// - `Balanced` - the partition ID is calculated by the server using the round-robin algorithm.
@@ -116,6 +142,7 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            ack: AckLevel::default(),
             messages: vec![Message::default()],
         }
     }
@@ -231,6 +258,20 @@ impl Validatable<IggyError> for SendMessages {
         let mut headers_size = 0;
         let mut payload_size = 0;
         for message in &self.messages {
+            if message.payload.is_empty() && message.id == 0 {
+                return Err(IggyError::EmptyMessagePayload);
+            }
+
+            if let Some(content_type) = ContentType::from_headers(&message.headers)? {
+                // The compressed payload can't be validated against its content type.
+                let compression = CompressionAlgorithm::from_headers(&message.headers)?;
+                if !message.payload.is_empty()
+                    && compression.unwrap_or_default() == CompressionAlgorithm::None
+                {
+                    content_type.validate_payload(&message.payload)?;
+                }
+            }
+
             if let Some(headers) = &message.headers {
                 for value in headers.values() {
                     headers_size += value.value.len() as u32;
@@ -245,10 +286,6 @@ impl Validatable<IggyError> for SendMessages {
             }
         }
 
-        if payload_size == 0 {
-            return Err(IggyError::EmptyMessagePayload);
-        }
-
         Ok(())
     }
 }
@@ -274,6 +311,58 @@ impl PartitioningKind {
     }
 }
 
+impl AckLevel {
+    /// Get the code of the acknowledgement level.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AckLevel::None => 1,
+            AckLevel::Leader => 2,
+            AckLevel::Fsync => 3,
+            AckLevel::All => 4,
+        }
+    }
+
+    /// Get the acknowledgement level from the provided code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(AckLevel::None),
+            2 => Ok(AckLevel::Leader),
+            3 => Ok(AckLevel::Fsync),
+            4 => Ok(AckLevel::All),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+
+    /// Returns `true` if the messages should be persisted to disk before the response is sent.
+    pub fn requires_fsync(&self) -> bool {
+        matches!(self, AckLevel::Fsync | AckLevel::All)
+    }
+}
+
+impl FromStr for AckLevel {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "none" => Ok(AckLevel::None),
+            "leader" => Ok(AckLevel::Leader),
+            "fsync" => Ok(AckLevel::Fsync),
+            "all" => Ok(AckLevel::All),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for AckLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AckLevel::None => write!(f, "none"),
+            AckLevel::Leader => write!(f, "leader"),
+            AckLevel::Fsync => write!(f, "fsync"),
+            AckLevel::All => write!(f, "all"),
+        }
+    }
+}
+
 impl Message {
     /// Create a new message with the optional ID, payload and headers.
     pub fn new(
@@ -289,6 +378,44 @@ impl Message {
             headers,
         }
     }
+
+    /// Create a new tombstone message for the provided ID (key), which has no payload.
+    /// Once appended to the compacted topic, it marks the key for removal by the compactor.
+    pub fn tombstone(id: u128) -> Self {
+        Message::new(Some(id), Bytes::new(), None)
+    }
+
+    /// Returns `true` if the message is a tombstone (has no payload).
+    pub fn is_tombstone(&self) -> bool {
+        self.payload.is_empty()
+    }
+
+    /// Records the content type of the payload in the `content-type` header.
+    pub fn with_content_type(mut self, content_type: ContentType) -> Result<Self, IggyError> {
+        let (key, value) = content_type.to_header()?;
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(key, value);
+        Ok(self)
+    }
+
+    /// Compresses the payload with the provided algorithm and records it in the `content-encoding` header.
+    pub fn with_compression(
+        mut self,
+        compression: CompressionAlgorithm,
+    ) -> Result<Self, IggyError> {
+        if CompressionAlgorithm::from_headers(&self.headers)?.is_some() {
+            return Err(IggyError::InvalidContentEncoding);
+        }
+
+        self.payload = Bytes::from(compression.compress(&self.payload)?);
+        self.length = self.payload.len() as u32;
+        let (key, value) = compression.to_header()?;
+        self.headers
+            .get_or_insert_with(HashMap::new)
+            .insert(key, value);
+        Ok(self)
+    }
 }
 
 impl Sizeable for Message {
@@ -347,10 +474,7 @@ impl BytesSerializable for Partitioning {
 
         let kind = PartitioningKind::from_code(bytes[0])?;
         let length = bytes[1];
-        let value = bytes[2..2 + length as usize].to_vec();
-        if value.len() != length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let value = read_slice(&bytes, 2, length as usize)?.to_vec();
 
         Ok(Partitioning {
             kind,
@@ -382,10 +506,12 @@ impl BytesSerializable for Message {
         }
 
         let mut id = u128::from_le_bytes(bytes[..16].try_into()?);
-        if id == 0 {
+        let is_id_generated = id == 0;
+        if is_id_generated {
             id = Uuid::now_v7().to_u128_le();
         }
-        let headers_length = u32::from_le_bytes(bytes[16..20].try_into()?);
+        let headers_length = read_u32_le(&bytes, 16)?;
+        read_slice(&bytes, 20, headers_length as usize)?;
         let headers = if headers_length > 0 {
             Some(HashMap::from_bytes(
                 bytes.slice(20..20 + headers_length as usize),
@@ -394,20 +520,19 @@ impl BytesSerializable for Message {
             None
         };
 
-        let payload_length = u32::from_le_bytes(
-            bytes[20 + headers_length as usize..24 + headers_length as usize].try_into()?,
-        );
-        if payload_length == 0 {
+        let payload_length = read_u32_le(&bytes, 20 + headers_length as usize)?;
+        // Only the tombstones, which must have the ID (key) provided, can have an empty payload.
+        if payload_length == 0 && is_id_generated {
             return Err(IggyError::EmptyMessagePayload);
         }
 
-        let payload = bytes.slice(
-            24 + headers_length as usize..24 + headers_length as usize + payload_length as usize,
-        );
-        if payload.len() != payload_length as usize {
+        let payload_position = 24 + headers_length as usize;
+        if read_slice(&bytes, payload_position, payload_length as usize).is_err() {
             return Err(IggyError::InvalidMessagePayloadLength);
         }
 
+        let payload = bytes.slice(payload_position..payload_position + payload_length as usize);
+
         Ok(Message {
             id,
             length: payload_length,
@@ -422,6 +547,7 @@ pub(crate) fn as_bytes(
     stream_id: &Identifier,
     topic_id: &Identifier,
     partitioning: &Partitioning,
+    ack: AckLevel,
     messages: &[Message],
 ) -> Bytes {
     let messages_size = messages
@@ -435,11 +561,13 @@ pub(crate) fn as_bytes(
         stream_id_bytes.len()
             + topic_id_bytes.len()
             + key_bytes.len()
+            + 1
             + messages_size.as_bytes_usize(),
     );
     bytes.put_slice(&stream_id_bytes);
     bytes.put_slice(&topic_id_bytes);
     bytes.put_slice(&key_bytes);
+    bytes.put_u8(ack.as_code());
     for message in messages {
         bytes.put_slice(&message.to_bytes());
     }
@@ -472,12 +600,13 @@ impl BytesSerializable for SendMessages {
             &self.stream_id,
             &self.topic_id,
             &self.partitioning,
+            self.ack,
             &self.messages,
         )
     }
 
     fn from_bytes(bytes: Bytes) -> Result<SendMessages, IggyError> {
-        if bytes.len() < 11 {
+        if bytes.len() < 12 {
             return Err(IggyError::InvalidCommand);
         }
 
@@ -488,6 +617,8 @@ impl BytesSerializable for SendMessages {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let key = Partitioning::from_bytes(bytes.slice(position..))?;
         position += key.get_size_bytes().as_bytes_usize();
+        let ack = AckLevel::from_code(*bytes.get(position).ok_or(IggyError::InvalidCommand)?)?;
+        position += 1;
         let messages_payloads = bytes.slice(position..);
         position = 0;
         let mut messages = Vec::new();
@@ -501,6 +632,7 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            ack,
             messages,
         };
         Ok(command)
@@ -511,10 +643,11 @@ impl Display for SendMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.partitioning,
+            self.ack,
             self.messages
                 .iter()
                 .map(std::string::ToString::to_string)
@@ -565,6 +698,7 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            ack: AckLevel::Fsync,
             messages,
         };
 
@@ -577,6 +711,8 @@ mod tests {
         position += topic_id.get_size_bytes().as_bytes_usize();
         let key = Partitioning::from_bytes(bytes.slice(position..)).unwrap();
         position += key.get_size_bytes().as_bytes_usize();
+        let ack = AckLevel::from_code(bytes[position]).unwrap();
+        position += 1;
         let messages = bytes.slice(position..);
         let command_messages = command
             .messages
@@ -591,6 +727,7 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(ack, command.ack);
         assert_eq!(messages, command_messages);
     }
 
@@ -613,11 +750,12 @@ mod tests {
         let key_bytes = key.to_bytes();
         let stream_id_bytes = stream_id.to_bytes();
         let topic_id_bytes = topic_id.to_bytes();
-        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
+        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + 1;
         let mut bytes = BytesMut::with_capacity(current_position);
         bytes.put_slice(&stream_id_bytes);
         bytes.put_slice(&topic_id_bytes);
         bytes.put_slice(&key_bytes);
+        bytes.put_u8(AckLevel::None.as_code());
         bytes.put_slice(&messages);
         let bytes = bytes.freeze();
         let command = SendMessages::from_bytes(bytes.clone());
@@ -636,6 +774,7 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(command.ack, AckLevel::None);
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -644,6 +783,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_validate_payload_against_content_type() {
+        let valid = Message::from_str(r#"{"id":1}"#)
+            .unwrap()
+            .with_content_type(ContentType::Json)
+            .unwrap();
+        let invalid = Message::from_str("not a json")
+            .unwrap()
+            .with_content_type(ContentType::Json)
+            .unwrap();
+        let mut command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            ack: AckLevel::default(),
+            messages: vec![valid],
+        };
+        assert!(command.validate().is_ok());
+
+        command.messages.push(invalid);
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::PayloadContentTypeMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn should_compress_payload_and_skip_content_type_validation() {
+        let message = Message::from_str(r#"{"id":1}"#)
+            .unwrap()
+            .with_content_type(ContentType::Json)
+            .unwrap()
+            .with_compression(CompressionAlgorithm::Gzip)
+            .unwrap();
+        assert_eq!(
+            CompressionAlgorithm::from_headers(&message.headers).unwrap(),
+            Some(CompressionAlgorithm::Gzip)
+        );
+        assert_eq!(message.length, message.payload.len() as u32);
+        assert_eq!(
+            CompressionAlgorithm::Gzip
+                .decompress(&message.payload)
+                .unwrap(),
+            br#"{"id":1}"#
+        );
+
+        let command = SendMessages {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partitioning: Partitioning::partition_id(4),
+            ack: AckLevel::default(),
+            messages: vec![message.clone()],
+        };
+        assert!(command.validate().is_ok());
+        assert!(message
+            .with_compression(CompressionAlgorithm::Gzip)
+            .is_err());
+    }
+
+    #[test]
+    fn ack_level_should_be_parsed_from_code_and_str() {
+        for ack in [
+            AckLevel::None,
+            AckLevel::Leader,
+            AckLevel::Fsync,
+            AckLevel::All,
+        ] {
+            assert_eq!(AckLevel::from_code(ack.as_code()).unwrap(), ack);
+            assert_eq!(AckLevel::from_str(&ack.to_string()).unwrap(), ack);
+        }
+        assert_eq!(AckLevel::default(), AckLevel::Leader);
+        assert!(AckLevel::from_code(5).is_err());
+        assert!(AckLevel::from_str("replicas").is_err());
+    }
+
     #[test]
     fn key_of_type_balanced_should_have_empty_value() {
         let key = Partitioning::balanced();
@@ -695,4 +909,22 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn tombstone_with_id_should_be_deserialized_from_bytes() {
+        let tombstone = Message::tombstone(1);
+        let message = Message::from_bytes(tombstone.to_bytes()).unwrap();
+        assert!(message.is_tombstone());
+        assert_eq!(message.id, 1);
+    }
+
+    #[test]
+    fn message_without_id_and_payload_should_fail_validation() {
+        let command = SendMessages {
+            messages: vec![Message::new(None, Bytes::new(), None)],
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_err());
+        assert!(Message::from_bytes(command.messages[0].to_bytes()).is_err());
+    }
 }
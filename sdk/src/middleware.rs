@@ -0,0 +1,203 @@
+use crate::error::IggyError;
+use crate::utils::duration::IggyDuration;
+use async_trait::async_trait;
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// The command sent by the client, as observed by the middlewares.
+#[derive(Debug, Clone)]
+pub struct ClientRequest {
+    /// The name of the command, e.g. `message.send`, or the HTTP method and path, e.g. `POST /streams` for the HTTP client.
+    pub command: String,
+    /// The code of the command, set only for the binary (TCP and QUIC) clients.
+    pub code: Option<u32>,
+    /// The size of the request payload in bytes.
+    pub size: usize,
+}
+
+/// The outcome of the command, as observed by the middlewares.
+#[derive(Debug)]
+pub struct ClientResponse<'a> {
+    /// The time elapsed since the command was sent, including the retries.
+    pub elapsed: IggyDuration,
+    /// The size of the response payload in bytes, or the error returned by the server or the transport.
+    pub result: Result<usize, &'a IggyError>,
+}
+
+/// The hooks invoked around every command sent by the client, e.g. to collect the metrics, log or trace the requests,
+/// without wrapping each of the SDK methods.
+/// The `before_request` hooks are invoked in the order of the registration, and the `after_response` hooks in the reverse order.
+#[async_trait]
+pub trait ClientMiddleware: Debug + Send + Sync {
+    /// Invoked before the command is sent. Returning an error rejects the command, which is then neither sent,
+    /// nor observed by the remaining middlewares.
+    async fn before_request(&self, _request: &ClientRequest) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    /// Invoked once the response has been received, or the command has failed (after all the retries).
+    async fn after_response(&self, _request: &ClientRequest, _response: &ClientResponse<'_>) {}
+}
+
+/// The middlewares registered in the client, invoked around every command.
+#[derive(Debug, Clone, Default)]
+pub struct ClientMiddlewares {
+    middlewares: Vec<Arc<dyn ClientMiddleware>>,
+}
+
+impl ClientMiddlewares {
+    /// Registers the middleware, invoked after the already registered ones.
+    pub fn add(&mut self, middleware: Arc<dyn ClientMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.middlewares.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.middlewares.len()
+    }
+
+    /// Sends the command with the middlewares invoked around it.
+    /// The request is created only if there's any middleware registered, so that it costs nothing otherwise.
+    pub(crate) async fn run<T>(
+        &self,
+        request: impl FnOnce() -> ClientRequest,
+        response_size: impl FnOnce(&T) -> usize,
+        send: impl Future<Output = Result<T, IggyError>>,
+    ) -> Result<T, IggyError> {
+        if self.middlewares.is_empty() {
+            return send.await;
+        }
+
+        let request = request();
+        for middleware in &self.middlewares {
+            middleware.before_request(&request).await?;
+        }
+
+        let now = Instant::now();
+        let result = send.await;
+        let response = ClientResponse {
+            elapsed: IggyDuration::from(now.elapsed()),
+            result: result.as_ref().map(response_size),
+        };
+        for middleware in self.middlewares.iter().rev() {
+            middleware.after_response(&request, &response).await;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingMiddleware {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+        reject: bool,
+    }
+
+    #[async_trait]
+    impl ClientMiddleware for RecordingMiddleware {
+        async fn before_request(&self, request: &ClientRequest) -> Result<(), IggyError> {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} before {}", self.name, request.command));
+            if self.reject {
+                return Err(IggyError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        async fn after_response(&self, request: &ClientRequest, response: &ClientResponse<'_>) {
+            let result = match response.result {
+                Ok(size) => size.to_string(),
+                Err(error) => error.as_code().to_string(),
+            };
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} after {} {result}", self.name, request.command));
+        }
+    }
+
+    fn create_request() -> ClientRequest {
+        ClientRequest {
+            command: "ping".to_string(),
+            code: Some(1),
+            size: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn middlewares_should_be_invoked_around_the_command() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut middlewares = ClientMiddlewares::default();
+        for name in ["first", "second"] {
+            middlewares.add(Arc::new(RecordingMiddleware {
+                name,
+                events: events.clone(),
+                reject: false,
+            }));
+        }
+
+        let response = middlewares
+            .run(create_request, |response: &Vec<u8>| response.len(), async {
+                Ok(vec![1, 2, 3])
+            })
+            .await
+            .unwrap();
+        assert_eq!(response, vec![1, 2, 3]);
+
+        let result = middlewares
+            .run(create_request, |response: &Vec<u8>| response.len(), async {
+                Err(IggyError::Disconnected)
+            })
+            .await;
+        assert!(matches!(result, Err(IggyError::Disconnected)));
+
+        let disconnected = IggyError::Disconnected.as_code();
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "first before ping".to_string(),
+                "second before ping".to_string(),
+                "second after ping 3".to_string(),
+                "first after ping 3".to_string(),
+                "first before ping".to_string(),
+                "second before ping".to_string(),
+                format!("second after ping {disconnected}"),
+                format!("first after ping {disconnected}"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn rejected_command_should_not_be_sent() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut middlewares = ClientMiddlewares::default();
+        middlewares.add(Arc::new(RecordingMiddleware {
+            name: "rejecting",
+            events: events.clone(),
+            reject: true,
+        }));
+
+        let result = middlewares
+            .run(create_request, |response: &Vec<u8>| response.len(), async {
+                panic!("The rejected command should not be sent")
+            })
+            .await;
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec!["rejecting before ping".to_string()]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::identifier::{IdKind, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// `ClusterMetadata` represents the nodes (brokers) of the cluster and the leaders of the partitions.
+/// It consists of the following fields:
+/// - `node_id`: the unique identifier of the node which has returned the metadata.
+/// - `brokers`: the collection of the nodes of the cluster.
+/// - `partitions`: the leadership of the partitions visible to the user, ordered by the stream, topic and partition IDs.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ClusterMetadata {
+    /// The unique identifier of the node which has returned the metadata.
+    pub node_id: u32,
+    /// The collection of the nodes of the cluster.
+    pub brokers: Vec<BrokerInfo>,
+    /// The leadership of the partitions visible to the user, ordered by the stream, topic and partition IDs.
+    pub partitions: Vec<PartitionLeadership>,
+}
+
+/// `BrokerInfo` represents the node of the cluster.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the node.
+/// - `name`: the name of the node.
+/// - `address`: the address advertised to the clients, empty if the clients should keep using the address they're connected to.
+/// - `zone`: the rack or availability zone of the node, empty if not specified.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct BrokerInfo {
+    /// The unique identifier of the node.
+    pub id: u32,
+    /// The name of the node.
+    pub name: String,
+    /// The address advertised to the clients, empty if the clients should keep using the address they're connected to.
+    pub address: String,
+    /// The rack or availability zone of the node, empty if not specified.
+    pub zone: String,
+}
+
+/// `PartitionLeadership` represents the node leading the partition.
+/// It consists of the following fields:
+/// - `stream_id`: the unique identifier of the stream.
+/// - `stream_name`: the name of the stream.
+/// - `topic_id`: the unique identifier of the topic.
+/// - `topic_name`: the name of the topic.
+/// - `partition_id`: the unique identifier of the partition.
+/// - `leader_id`: the unique identifier of the node leading the partition.
+/// - `leader_epoch`: the epoch of the partition on the leader.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct PartitionLeadership {
+    /// The unique identifier of the stream.
+    pub stream_id: u32,
+    /// The name of the stream.
+    pub stream_name: String,
+    /// The unique identifier of the topic.
+    pub topic_id: u32,
+    /// The name of the topic.
+    pub topic_name: String,
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The unique identifier of the node leading the partition.
+    pub leader_id: u32,
+    /// The epoch of the partition on the leader.
+    pub leader_epoch: u32,
+}
+
+impl ClusterMetadata {
+    /// Returns the node leading the partition of the topic and stream identified by the numeric IDs or names, if known.
+    pub fn get_leader(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Option<&BrokerInfo> {
+        let partition = self.partitions.iter().find(|partition| {
+            partition.partition_id == partition_id
+                && matches_identifier(stream_id, partition.stream_id, &partition.stream_name)
+                && matches_identifier(topic_id, partition.topic_id, &partition.topic_name)
+        })?;
+        self.brokers
+            .iter()
+            .find(|broker| broker.id == partition.leader_id)
+    }
+}
+
+fn matches_identifier(identifier: &Identifier, id: u32, name: &str) -> bool {
+    match identifier.kind {
+        IdKind::Numeric => identifier.get_u32_value().is_ok_and(|value| value == id),
+        IdKind::String => identifier
+            .get_cow_str_value()
+            .is_ok_and(|value| value == name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_metadata() -> ClusterMetadata {
+        ClusterMetadata {
+            node_id: 1,
+            brokers: vec![
+                BrokerInfo {
+                    id: 1,
+                    name: "node-1".to_string(),
+                    address: "127.0.0.1:8090".to_string(),
+                    zone: "zone-a".to_string(),
+                },
+                BrokerInfo {
+                    id: 2,
+                    name: "node-2".to_string(),
+                    address: "127.0.0.1:8091".to_string(),
+                    zone: "zone-b".to_string(),
+                },
+            ],
+            partitions: vec![
+                PartitionLeadership {
+                    stream_id: 1,
+                    stream_name: "stream".to_string(),
+                    topic_id: 2,
+                    topic_name: "topic".to_string(),
+                    partition_id: 1,
+                    leader_id: 1,
+                    leader_epoch: 0,
+                },
+                PartitionLeadership {
+                    stream_id: 1,
+                    stream_name: "stream".to_string(),
+                    topic_id: 2,
+                    topic_name: "topic".to_string(),
+                    partition_id: 2,
+                    leader_id: 2,
+                    leader_epoch: 3,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn should_find_leader_of_partition_by_numeric_ids_or_names() {
+        let metadata = create_metadata();
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("topic").unwrap();
+
+        let leader = metadata.get_leader(&stream_id, &topic_id, 2).unwrap();
+        assert_eq!(leader.id, 2);
+        let leader = metadata
+            .get_leader(&Identifier::named("stream").unwrap(), &topic_id, 1)
+            .unwrap();
+        assert_eq!(leader.id, 1);
+    }
+
+    #[test]
+    fn should_not_find_leader_of_unknown_partition() {
+        let metadata = create_metadata();
+        let stream_id = Identifier::numeric(1).unwrap();
+
+        assert!(metadata
+            .get_leader(&stream_id, &Identifier::numeric(2).unwrap(), 3)
+            .is_none());
+        assert!(metadata
+            .get_leader(&stream_id, &Identifier::named("other").unwrap(), 1)
+            .is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::rebalance_strategy::RebalanceStrategy;
 use serde::{Deserialize, Serialize};
 
 /// `ConsumerGroup` represents the information about a consumer group.
@@ -6,6 +7,7 @@ use serde::{Deserialize, Serialize};
 /// - `name`: the name of the consumer group.
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
+/// - `rebalance_strategy`: the way the partitions are reassigned to the members.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroup {
     /// The unique identifier (numeric) of the consumer group.
@@ -16,6 +18,9 @@ pub struct ConsumerGroup {
     pub partitions_count: u32,
     /// The number of members in the consumer group.
     pub members_count: u32,
+    /// The way the partitions are reassigned to the members.
+    #[serde(default)]
+    pub rebalance_strategy: RebalanceStrategy,
 }
 
 /// `ConsumerGroupDetails` represents the detailed information about a consumer group.
@@ -24,6 +29,8 @@ pub struct ConsumerGroup {
 /// - `name`: the name of the consumer group.
 /// - `partitions_count`: the number of partitions the consumer group is consuming.
 /// - `members_count`: the number of members in the consumer group.
+/// - `rebalance_strategy`: the way the partitions are reassigned to the members.
+/// - `members`: the collection of members in the consumer group.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroupDetails {
     /// The unique identifier (numeric) of the consumer group.
@@ -34,6 +41,9 @@ pub struct ConsumerGroupDetails {
     pub partitions_count: u32,
     /// The number of members in the consumer group.
     pub members_count: u32,
+    /// The way the partitions are reassigned to the members.
+    #[serde(default)]
+    pub rebalance_strategy: RebalanceStrategy,
     /// The collection of members in the consumer group.
     pub members: Vec<ConsumerGroupMember>,
 }
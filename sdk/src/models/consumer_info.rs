@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerInfo` represents the consumer with the stored offset in a partition.
+/// It consists of the following fields:
+/// - `id`: the unique identifier of the consumer, resolved by hashing the name if the consumer is identified by name.
+/// - `name`: the name of the consumer, if identified by name.
+/// - `stored_offset`: the stored offset by the consumer in the partition.
+/// - `lag`: the number of messages in the partition that the consumer hasn't consumed yet.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerInfo {
+    /// The unique identifier of the consumer.
+    pub id: u32,
+    /// The name of the consumer, `None` if the consumer is identified by the numeric ID.
+    pub name: Option<String>,
+    /// The stored offset by the consumer in the partition.
+    pub stored_offset: u64,
+    /// The number of messages in the partition that the consumer hasn't consumed yet.
+    pub lag: u64,
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerLagInfo` represents the lag of a consumer (or consumer group) in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `current_offset`: the current (end) offset of the partition.
+/// - `stored_offset`: the stored offset by the consumer in the partition, if any.
+/// - `lag`: the number of messages in the partition that the consumer hasn't consumed yet.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerLagInfo {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The current (end) offset of the partition.
+    pub current_offset: u64,
+    /// The stored offset by the consumer in the partition, `None` if the offset hasn't been stored yet.
+    pub stored_offset: Option<u64>,
+    /// The number of messages in the partition that the consumer hasn't consumed yet.
+    pub lag: u64,
+}
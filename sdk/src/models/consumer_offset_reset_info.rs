@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerOffsetResetInfo` represents the result of resetting the offset of a consumer (or consumer group) in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `current_offset`: the current (end) offset of the partition.
+/// - `previous_offset`: the stored offset by the consumer in the partition before the reset, if any.
+/// - `stored_offset`: the stored offset by the consumer in the partition after the reset, if any.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ConsumerOffsetResetInfo {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The current (end) offset of the partition.
+    pub current_offset: u64,
+    /// The stored offset by the consumer in the partition before the reset, `None` if the offset hasn't been stored.
+    pub previous_offset: Option<u64>,
+    /// The stored offset by the consumer in the partition after the reset, `None` if the consumer starts from the first message.
+    pub stored_offset: Option<u64>,
+}
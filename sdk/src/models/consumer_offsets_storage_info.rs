@@ -0,0 +1,28 @@
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `ConsumerOffsetsStorageInfo` represents the information about the storage of the consumer offsets in a partition.
+/// Each stored offset is appended as a record to the log, which is periodically compacted to the latest offset per consumer.
+/// It consists of the following fields:
+/// - `consumers_count`: the number of consumers with the stored offset.
+/// - `consumer_groups_count`: the number of consumer groups with the stored offset.
+/// - `records_count`: the number of the persisted records, including the stale ones.
+/// - `size`: the size of the persisted records in bytes.
+/// - `compactions_count`: the number of compactions since the server has started.
+/// - `last_compacted_at`: the timestamp of the last compaction since the server has started, if any.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ConsumerOffsetsStorageInfo {
+    /// The number of consumers with the stored offset.
+    pub consumers_count: u32,
+    /// The number of consumer groups with the stored offset.
+    pub consumer_groups_count: u32,
+    /// The number of the persisted records, including the stale ones.
+    pub records_count: u64,
+    /// The size of the persisted records in bytes.
+    pub size: IggyByteSize,
+    /// The number of compactions since the server has started.
+    pub compactions_count: u64,
+    /// The timestamp of the last compaction since the server has started, `None` if there was no compaction.
+    pub last_compacted_at: Option<IggyTimestamp>,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// `CopiedMessagesInfo` represents the result of copying the range of messages from one partition to another.
+/// It consists of the following fields:
+/// - `messages_count`: the number of the messages appended to the target partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CopiedMessagesInfo {
+    /// The number of the messages appended to the target partition.
+    pub messages_count: u64,
+}
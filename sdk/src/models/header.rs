@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_slice, read_str, read_u32_le, read_u8, BytesSerializable};
 use crate::error::IggyError;
 use crate::utils::byte_size::IggyByteSize;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -589,26 +589,22 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
         let mut headers = Self::new();
         let mut position = 0;
         while position < bytes.len() {
-            let key_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            let key_length = read_u32_le(&bytes, position)? as usize;
             if key_length == 0 || key_length > 255 {
                 return Err(IggyError::InvalidHeaderKey);
             }
             position += 4;
-            let key = String::from_utf8(bytes[position..position + key_length].to_vec());
-            if key.is_err() {
-                return Err(IggyError::InvalidHeaderKey);
-            }
-            let key = key.unwrap();
+            let key =
+                read_str(&bytes, position, key_length).map_err(|_| IggyError::InvalidHeaderKey)?;
             position += key_length;
-            let kind = HeaderKind::from_code(bytes[position])?;
+            let kind = HeaderKind::from_code(read_u8(&bytes, position)?)?;
             position += 1;
-            let value_length =
-                u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            let value_length = read_u32_le(&bytes, position)? as usize;
             if value_length == 0 || value_length > 255 {
                 return Err(IggyError::InvalidHeaderValue);
             }
             position += 4;
-            let value = bytes[position..position + value_length].to_vec();
+            let value = read_slice(&bytes, position, value_length)?.to_vec();
             position += value_length;
             headers.insert(
                 HeaderKey(key),
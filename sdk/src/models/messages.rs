@@ -1,8 +1,11 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
+use crate::messages::poll_messages::PollingStrategy;
 use crate::models::header;
 use crate::models::header::{HeaderKey, HeaderValue};
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::content_type::ContentType;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::timestamp::IggyTimestamp;
 use bytes::{BufMut, Bytes, BytesMut};
@@ -19,6 +22,8 @@ pub const POLLED_MESSAGE_METADATA: u32 = 8 + 1 + 8 + 4;
 /// It consists of the following fields:
 /// - `partition_id`: the identifier of the partition.
 /// - `current_offset`: the current offset of the partition.
+/// - `epoch`: the epoch of the partition, incremented whenever its log is rewritten.
+/// - `next_offset`: the offset to continue polling from, if the poll has been limited by the count or size.
 /// - `messages`: the collection of messages.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
@@ -26,10 +31,34 @@ pub struct PolledMessages {
     pub partition_id: u32,
     /// The current offset of the partition.
     pub current_offset: u64,
+    /// The epoch of the partition, incremented whenever its log is rewritten (e.g. purged).
+    /// Once it changes, the offsets read so far are no longer valid and should be resynchronized.
+    #[serde(default)]
+    pub epoch: u32,
+    /// The offset from which the next poll in the same direction continues, returned if the poll has been limited
+    /// by the count or size, before reaching the end of the partition (or its beginning, when polling backward) or the end timestamp.
+    /// Unlike the offset of the last polled message, it accounts for the gaps in the offsets (e.g. after the compaction),
+    /// so the partition can be paged through deterministically, including across the segment boundaries.
+    #[serde(default)]
+    pub next_offset: Option<u64>,
     /// The collection of messages.
     pub messages: Vec<PolledMessage>,
 }
 
+impl PolledMessages {
+    /// Returns the polling strategy continuing from the `next_offset` in the same direction (and up to the same end timestamp)
+    /// as the provided one, or `None` if there are no more messages to poll.
+    pub fn next_polling_strategy(&self, strategy: &PollingStrategy) -> Option<PollingStrategy> {
+        self.next_offset.map(|offset| {
+            let mut next_strategy = PollingStrategy::offset(offset)
+                .out_of_range(strategy.out_of_range)
+                .direction(strategy.direction);
+            next_strategy.end_timestamp = strategy.end_timestamp;
+            next_strategy
+        })
+    }
+}
+
 /// The single message that is polled from the partition.
 /// It consists of the following fields:
 /// - `offset`: the offset of the message.
@@ -152,6 +181,21 @@ impl PolledMessage {
         self.timestamp.into()
     }
 
+    /// Returns the content type of the payload recorded in the `content-type` header, if any.
+    pub fn content_type(&self) -> Result<Option<ContentType>, IggyError> {
+        ContentType::from_headers(&self.headers)
+    }
+
+    /// Returns the compression of the payload recorded in the `content-encoding` header, if any.
+    pub fn content_encoding(&self) -> Result<Option<CompressionAlgorithm>, IggyError> {
+        CompressionAlgorithm::from_headers(&self.headers)
+    }
+
+    /// Returns `true` if the message is a tombstone (has no payload), marking its ID (key) for removal.
+    pub fn is_tombstone(&self) -> bool {
+        self.payload.is_empty()
+    }
+
     /// Extends the provided bytes with the message.
     pub fn extend(&self, bytes: &mut BytesMut) {
         bytes.put_u64_le(self.offset);
@@ -0,0 +1,33 @@
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::duration::IggyDuration;
+use serde::{Deserialize, Serialize};
+
+/// `MessagesHistogram` represents the count and size of the messages in a partition aggregated per time bucket.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `bucket_size`: the duration of the single time bucket.
+/// - `buckets`: the consecutive time buckets covering the requested time range, including the empty ones.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MessagesHistogram {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The duration of the single time bucket.
+    pub bucket_size: IggyDuration,
+    /// The consecutive time buckets covering the requested time range, including the empty ones.
+    pub buckets: Vec<MessagesHistogramBucket>,
+}
+
+/// `MessagesHistogramBucket` represents the messages appended to a partition within a single time bucket.
+/// It consists of the following fields:
+/// - `timestamp`: the start of the time bucket (in microseconds).
+/// - `messages_count`: the number of the messages within the time bucket.
+/// - `size`: the size of the messages within the time bucket.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct MessagesHistogramBucket {
+    /// The start of the time bucket (in microseconds).
+    pub timestamp: u64,
+    /// The number of the messages within the time bucket.
+    pub messages_count: u64,
+    /// The size of the messages within the time bucket.
+    pub size: IggyByteSize,
+}
@@ -1,15 +1,32 @@
 pub mod client_info;
+pub mod cluster_metadata;
 pub mod consumer_group;
+pub mod consumer_info;
+pub mod consumer_lag_info;
 pub mod consumer_offset_info;
+pub mod consumer_offset_reset_info;
+pub mod consumer_offsets_storage_info;
+pub mod copied_messages_info;
 pub mod header;
 pub mod identity_info;
 pub mod messages;
+pub mod messages_histogram;
+pub mod node_status;
 pub mod partition;
+pub mod partition_offsets_info;
 pub mod permissions;
 pub mod personal_access_token;
+pub mod replica_info;
+pub mod replica_messages;
+pub mod schema;
+pub mod sealed_partition_info;
+pub mod segment_info;
+pub mod session_info;
 pub mod snapshot;
 pub mod stats;
 pub mod stream;
+pub mod system_event;
+pub mod timestamp_offset_info;
 pub mod topic;
 pub mod user_info;
 pub mod user_status;
@@ -0,0 +1,65 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `NodeStatus` represents the state of the node and the progress of draining it.
+/// It consists of the following fields:
+/// - `node_id`: the unique identifier of the node.
+/// - `state`: the state of the node.
+/// - `partitions_count`: the number of the partitions stored on the node.
+/// - `led_partitions_count`: the number of the partitions still led by the node, which must drop to 0 before decommissioning it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct NodeStatus {
+    /// The unique identifier of the node.
+    pub node_id: u32,
+    /// The state of the node.
+    pub state: NodeState,
+    /// The number of the partitions stored on the node.
+    pub partitions_count: u32,
+    /// The number of the partitions still led by the node.
+    pub led_partitions_count: u32,
+}
+
+/// `NodeState` represents the state of the node in the cluster.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeState {
+    /// The node accepts the new partitions and leads its partitions.
+    #[default]
+    Active,
+    /// The node accepts no new partitions and transfers the leadership of its partitions to the replicas.
+    Draining,
+    /// The node leads no partitions and can be removed from the cluster.
+    Decommissioned,
+}
+
+impl Display for NodeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeState::Active => write!(f, "active"),
+            NodeState::Draining => write!(f, "draining"),
+            NodeState::Decommissioned => write!(f, "decommissioned"),
+        }
+    }
+}
+
+impl NodeState {
+    /// Returns the code of the node state.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            NodeState::Active => 1,
+            NodeState::Draining => 2,
+            NodeState::Decommissioned => 3,
+        }
+    }
+
+    /// Returns the node state from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(NodeState::Active),
+            2 => Ok(NodeState::Draining),
+            3 => Ok(NodeState::Decommissioned),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
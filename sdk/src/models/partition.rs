@@ -1,3 +1,6 @@
+use crate::models::consumer_offsets_storage_info::ConsumerOffsetsStorageInfo;
+use crate::models::replica_info::ReplicaInfo;
+use crate::models::segment_info::SegmentInfo;
 use crate::utils::byte_size::IggyByteSize;
 use crate::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
@@ -25,3 +28,39 @@ pub struct Partition {
     /// The number of messages in the partition.
     pub messages_count: u64,
 }
+
+/// `PartitionDetails` represents the detailed information about a partition, including its segments.
+/// It consists of the following fields:
+/// - `id`: unique identifier of the partition.
+/// - `created_at`: the timestamp of the partition creation.
+/// - `segments_count`: the number of segments in the partition.
+/// - `current_offset`: the current offset of the partition.
+/// - `size_bytes`: the size of the partition in bytes.
+/// - `messages_count`: the number of messages in the partition.
+/// - `high_watermark`: the offset below which the messages have been replicated by all the in-sync replicas and are visible to the consumers.
+/// - `consumer_offsets`: the information about the storage of the consumer offsets in the partition.
+/// - `replicas`: the replication progress of the followers which have fetched the messages of the partition.
+/// - `segments`: the collection of segments in the partition ordered by the start offset.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionDetails {
+    /// Unique identifier of the partition.
+    pub id: u32,
+    /// The timestamp of the partition creation.
+    pub created_at: IggyTimestamp,
+    /// The number of segments in the partition.
+    pub segments_count: u32,
+    /// The current offset of the partition.
+    pub current_offset: u64,
+    /// The size of the partition in bytes.
+    pub size: IggyByteSize,
+    /// The number of messages in the partition.
+    pub messages_count: u64,
+    /// The offset below which the messages have been replicated by all the in-sync replicas and are visible to the consumers.
+    pub high_watermark: u64,
+    /// The information about the storage of the consumer offsets in the partition.
+    pub consumer_offsets: ConsumerOffsetsStorageInfo,
+    /// The replication progress of the followers which have fetched the messages of the partition, ordered by the replica ID.
+    pub replicas: Vec<ReplicaInfo>,
+    /// The collection of segments in the partition ordered by the start offset.
+    pub segments: Vec<SegmentInfo>,
+}
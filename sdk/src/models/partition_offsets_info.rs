@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// `PartitionOffsetsInfo` represents the range of offsets available in a partition.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `low_watermark`: the first offset still available in the partition, after the retention or purge.
+/// - `high_watermark`: the offset of the next message to be appended to the partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PartitionOffsetsInfo {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The first offset still available in the partition, after the retention or purge.
+    pub low_watermark: u64,
+    /// The offset of the next message to be appended to the partition.
+    /// If it's equal to the low watermark, there are no messages available in the partition.
+    pub high_watermark: u64,
+}
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, read_u8, BytesSerializable};
 use crate::error::IggyError;
 use ahash::AHashMap;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
@@ -28,6 +28,7 @@ pub struct GlobalPermissions {
     /// - get_stats
     /// - get_clients
     /// - get_client
+    /// - get_sessions
     pub read_servers: bool,
 
     /// `manage_users` permission allows to manage the users and includes all the permissions of `read_users`.
@@ -262,36 +263,36 @@ impl BytesSerializable for Permissions {
         Self: Sized,
     {
         let mut bytes = bytes;
-        let manage_servers = bytes.get_u8() == 1;
-        let read_servers = bytes.get_u8() == 1;
-        let manage_users = bytes.get_u8() == 1;
-        let read_users = bytes.get_u8() == 1;
-        let manage_streams = bytes.get_u8() == 1;
-        let read_streams = bytes.get_u8() == 1;
-        let manage_topics = bytes.get_u8() == 1;
-        let read_topics = bytes.get_u8() == 1;
-        let poll_messages = bytes.get_u8() == 1;
-        let send_messages = bytes.get_u8() == 1;
+        let manage_servers = get_u8(&mut bytes)? == 1;
+        let read_servers = get_u8(&mut bytes)? == 1;
+        let manage_users = get_u8(&mut bytes)? == 1;
+        let read_users = get_u8(&mut bytes)? == 1;
+        let manage_streams = get_u8(&mut bytes)? == 1;
+        let read_streams = get_u8(&mut bytes)? == 1;
+        let manage_topics = get_u8(&mut bytes)? == 1;
+        let read_topics = get_u8(&mut bytes)? == 1;
+        let poll_messages = get_u8(&mut bytes)? == 1;
+        let send_messages = get_u8(&mut bytes)? == 1;
         let mut streams = None;
-        if bytes.get_u8() == 1 {
+        if get_u8(&mut bytes)? == 1 {
             let mut streams_map = AHashMap::new();
             loop {
-                let stream_id = bytes.get_u32_le();
-                let manage_stream = bytes.get_u8() == 1;
-                let read_stream = bytes.get_u8() == 1;
-                let manage_topics = bytes.get_u8() == 1;
-                let read_topics = bytes.get_u8() == 1;
-                let poll_messages = bytes.get_u8() == 1;
-                let send_messages = bytes.get_u8() == 1;
+                let stream_id = get_u32_le(&mut bytes)?;
+                let manage_stream = get_u8(&mut bytes)? == 1;
+                let read_stream = get_u8(&mut bytes)? == 1;
+                let manage_topics = get_u8(&mut bytes)? == 1;
+                let read_topics = get_u8(&mut bytes)? == 1;
+                let poll_messages = get_u8(&mut bytes)? == 1;
+                let send_messages = get_u8(&mut bytes)? == 1;
                 let mut topics = None;
-                if bytes.get_u8() == 1 {
+                if get_u8(&mut bytes)? == 1 {
                     let mut topics_map = AHashMap::new();
                     loop {
-                        let topic_id = bytes.get_u32_le();
-                        let manage_topic = bytes.get_u8() == 1;
-                        let read_topic = bytes.get_u8() == 1;
-                        let poll_messages = bytes.get_u8() == 1;
-                        let send_messages = bytes.get_u8() == 1;
+                        let topic_id = get_u32_le(&mut bytes)?;
+                        let manage_topic = get_u8(&mut bytes)? == 1;
+                        let read_topic = get_u8(&mut bytes)? == 1;
+                        let poll_messages = get_u8(&mut bytes)? == 1;
+                        let send_messages = get_u8(&mut bytes)? == 1;
                         topics_map.insert(
                             topic_id,
                             TopicPermissions {
@@ -301,7 +302,7 @@ impl BytesSerializable for Permissions {
                                 send_messages,
                             },
                         );
-                        if bytes.get_u8() == 0 {
+                        if get_u8(&mut bytes)? == 0 {
                             break;
                         }
                     }
@@ -319,7 +320,7 @@ impl BytesSerializable for Permissions {
                         topics,
                     },
                 );
-                if bytes.get_u8() == 0 {
+                if get_u8(&mut bytes)? == 0 {
                     break;
                 }
             }
@@ -343,6 +344,18 @@ impl BytesSerializable for Permissions {
     }
 }
 
+fn get_u8(bytes: &mut Bytes) -> Result<u8, IggyError> {
+    let value = read_u8(bytes, 0)?;
+    bytes.advance(1);
+    Ok(value)
+}
+
+fn get_u32_le(bytes: &mut Bytes) -> Result<u32, IggyError> {
+    let value = read_u32_le(bytes, 0)?;
+    bytes.advance(4);
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
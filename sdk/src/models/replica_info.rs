@@ -0,0 +1,23 @@
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `ReplicaInfo` represents the replication progress of the follower of the partition, as tracked by the leader.
+/// It consists of the following fields:
+/// - `id`: unique identifier of the replica.
+/// - `fetched_offset`: the offset of the next message to be fetched by the replica, all the messages below it have been replicated.
+/// - `last_fetched_at`: the timestamp of the last fetch of the replica.
+/// - `last_caught_up_at`: the timestamp of the last fetch at which the replica has replicated all the messages of the leader.
+/// - `is_in_sync`: whether the replica is in the in-sync replicas set, i.e. it has caught up within the replica lag timeout.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ReplicaInfo {
+    /// Unique identifier of the replica.
+    pub id: u32,
+    /// The offset of the next message to be fetched by the replica, all the messages below it have been replicated.
+    pub fetched_offset: u64,
+    /// The timestamp of the last fetch of the replica.
+    pub last_fetched_at: IggyTimestamp,
+    /// The timestamp of the last fetch at which the replica has replicated all the messages of the leader.
+    pub last_caught_up_at: Option<IggyTimestamp>,
+    /// Whether the replica is in the in-sync replicas set, i.e. it has caught up within the replica lag timeout.
+    pub is_in_sync: bool,
+}
@@ -0,0 +1,27 @@
+use crate::models::messages::PolledMessages;
+use serde::{Deserialize, Serialize};
+
+/// `ReplicaMessages` represents the response of the leader to the follower fetching the messages of the partition.
+/// It consists of the following fields:
+/// - `diverging_epoch`: the end offset of the follower's epoch, returned instead of the messages if the follower's log has diverged from the leader's one.
+/// - `messages`: the fetched messages along with the current offset and epoch of the leader.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReplicaMessages {
+    /// The end offset of the follower's epoch, returned instead of the messages if the follower's log has diverged from the leader's one.
+    /// The follower should truncate its log to the end offset and fetch again from it.
+    pub diverging_epoch: Option<EpochEndOffset>,
+    /// The fetched messages along with the current offset and epoch of the leader.
+    pub messages: PolledMessages,
+}
+
+/// `EpochEndOffset` represents the end of the epoch in the leader's log.
+/// It consists of the following fields:
+/// - `epoch`: the epoch of the partition.
+/// - `end_offset`: the offset (exclusive) at which the epoch ends in the leader's log.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub struct EpochEndOffset {
+    /// The epoch of the partition.
+    pub epoch: u32,
+    /// The offset (exclusive) at which the epoch ends in the leader's log.
+    pub end_offset: u64,
+}
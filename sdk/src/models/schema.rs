@@ -0,0 +1,24 @@
+use crate::utils::schema_type::SchemaType;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `Schema` represents the version of the schema registered for the topic.
+/// It consists of the following fields:
+/// - `version`: the version of the schema, starting from 1 and incremented on each registration.
+/// - `schema_type`: the type of the schema (JSON Schema, Avro or Protobuf).
+/// - `validate`: whether the payloads of the messages sent to the topic are validated against the schema.
+/// - `created_at`: the timestamp when the schema was registered.
+/// - `definition`: the definition of the schema.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Schema {
+    /// The version of the schema, starting from 1 and incremented on each registration.
+    pub version: u32,
+    /// The type of the schema (JSON Schema, Avro or Protobuf).
+    pub schema_type: SchemaType,
+    /// Whether the payloads of the messages sent to the topic are validated against the schema.
+    pub validate: bool,
+    /// The timestamp when the schema was registered.
+    pub created_at: IggyTimestamp,
+    /// The definition of the schema.
+    pub definition: String,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// `SealedPartitionInfo` represents the boundary at which a partition has been sealed.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `high_watermark`: the offset of the next message to be appended to the partition, all the messages below it belong to the closed segments.
+/// - `segments_count`: the number of the segments in the partition.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SealedPartitionInfo {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the next message to be appended to the partition, all the messages below it belong to the closed segments.
+    pub high_watermark: u64,
+    /// The number of the segments in the partition.
+    pub segments_count: u32,
+}
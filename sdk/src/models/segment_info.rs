@@ -0,0 +1,34 @@
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `SegmentInfo` represents the information about a segment of the partition.
+/// It consists of the following fields:
+/// - `start_offset`: the offset of the first message in the segment.
+/// - `end_offset`: the offset of the last message in the segment.
+/// - `size`: the size of the segment log in bytes.
+/// - `messages_count`: the number of messages in the segment.
+/// - `is_closed`: whether the segment is closed (rolled), so that no more messages are appended to it.
+/// - `created_at`: the timestamp of the segment creation.
+/// - `index_size`: the size of the segment index in bytes.
+/// - `indexes_count`: the number of entries in the segment index.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SegmentInfo {
+    /// The offset of the first message in the segment.
+    pub start_offset: u64,
+    /// The offset of the last message in the segment.
+    /// If it's equal to the start offset and there are no messages, the segment is empty.
+    pub end_offset: u64,
+    /// The size of the segment log in bytes.
+    pub size: IggyByteSize,
+    /// The number of messages in the segment.
+    pub messages_count: u64,
+    /// Whether the segment is closed (rolled), so that no more messages are appended to it.
+    pub is_closed: bool,
+    /// The timestamp of the segment creation.
+    pub created_at: IggyTimestamp,
+    /// The size of the segment index in bytes.
+    pub index_size: IggyByteSize,
+    /// The number of entries in the segment index.
+    pub indexes_count: u32,
+}
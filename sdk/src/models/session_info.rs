@@ -0,0 +1,29 @@
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+
+/// `SessionInfo` represents the information about an active session of the connected client.
+/// It consists of the following fields:
+/// - `client_id`: the unique identifier of the client owning the session.
+/// - `user_id`: the unique identifier of the user. This field is optional, as the client might be connected but not authenticated yet.
+/// - `address`: the remote address of the client.
+/// - `transport`: the transport protocol used by the client.
+/// - `connected_at`: the timestamp when the client has connected.
+/// - `last_command`: the name of the last command sent by the client, if any.
+/// - `last_command_at`: the timestamp of the last command sent by the client, if any.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// The unique identifier of the client owning the session.
+    pub client_id: u32,
+    /// The unique identifier of the user. This field is optional, as the client might be connected but not authenticated yet.
+    pub user_id: Option<u32>,
+    /// The remote address of the client.
+    pub address: String,
+    /// The transport protocol used by the client.
+    pub transport: String,
+    /// The timestamp when the client has connected.
+    pub connected_at: IggyTimestamp,
+    /// The name of the last command sent by the client, if any.
+    pub last_command: Option<String>,
+    /// The timestamp of the last command sent by the client, if any.
+    pub last_command_at: Option<IggyTimestamp>,
+}
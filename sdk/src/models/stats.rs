@@ -48,6 +48,35 @@ pub struct Stats {
     pub os_version: String,
     /// The version of the kernel.
     pub kernel_version: String,
+    /// The latencies of the commands handled by the server, ordered by the command code.
+    #[serde(default)]
+    pub command_latencies: Vec<CommandLatency>,
+    /// The number of the deleted topics, which files are still being removed in the background.
+    #[serde(default)]
+    pub deleting_topics_count: u32,
+    /// The size of the messages of the deleted topics, which files are still being removed in the background.
+    #[serde(default)]
+    pub deleting_topics_size_bytes: IggyByteSize,
+    /// The number of the partitions which failed to persist the messages and reject the appends until the server is restarted.
+    #[serde(default)]
+    pub failed_partitions_count: u32,
+}
+
+/// `CommandLatency` represents the estimated latency percentiles of the command handled by the server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandLatency {
+    /// The code of the command.
+    pub code: u32,
+    /// The name of the command.
+    pub name: String,
+    /// The total number of the handled commands.
+    pub count: u64,
+    /// The latency below which 50% of the commands were handled.
+    pub p50: IggyDuration,
+    /// The latency below which 95% of the commands were handled.
+    pub p95: IggyDuration,
+    /// The latency below which 99% of the commands were handled.
+    pub p99: IggyDuration,
 }
 
 impl Default for Stats {
@@ -75,6 +104,10 @@ impl Default for Stats {
             os_name: "unknown_os_name".to_string(),
             os_version: "unknown_os_version".to_string(),
             kernel_version: "unknown_kernel_version".to_string(),
+            command_latencies: Vec::new(),
+            deleting_topics_count: 0,
+            deleting_topics_size_bytes: 0.into(),
+            failed_partitions_count: 0,
         }
     }
 }
@@ -0,0 +1,122 @@
+use crate::consumer::ConsumerKind;
+use serde::{Deserialize, Serialize};
+
+/// `SystemEvent` represents the internal event published by the server to the reserved events topic (if enabled),
+/// as the JSON payload of the message, which can be consumed with the regular consumer API e.g. for auditing.
+/// The same payload is sent to the configured webhooks (if enabled) for the selected events.
+/// The `type` field identifies the kind of the event, while the time of the event is the timestamp of the message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SystemEvent {
+    /// The stream was created.
+    StreamCreated { stream_id: u32, name: String },
+    /// The stream was deleted, including all its topics.
+    StreamDeleted { stream_id: u32, name: String },
+    /// The topic was created.
+    TopicCreated {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+        partitions_count: u32,
+    },
+    /// The topic was deleted.
+    TopicDeleted {
+        stream_id: u32,
+        topic_id: u32,
+        name: String,
+    },
+    /// The user logged in, either with the credentials or the personal access token.
+    UserLoggedIn { user_id: u32, username: String },
+    /// The segment became full and was closed, no more messages will be appended to it.
+    SegmentClosed {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        start_offset: u64,
+        end_offset: u64,
+    },
+    /// The retention deleted the expired or the oldest segments of the topic.
+    RetentionRan {
+        stream_id: u32,
+        topic_id: u32,
+        deleted_segments: u32,
+        deleted_messages: u64,
+    },
+    /// The lag of the consumer (the difference between the current offset of the partition and the stored consumer offset)
+    /// crossed the configured threshold. It's published again only after the lag drops below the threshold.
+    ConsumerLagExceeded {
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+        lag: u64,
+    },
+}
+
+impl SystemEvent {
+    /// The names of all the events, as used in the `type` field.
+    pub const NAMES: [&'static str; 8] = [
+        "stream_created",
+        "stream_deleted",
+        "topic_created",
+        "topic_deleted",
+        "user_logged_in",
+        "segment_closed",
+        "retention_ran",
+        "consumer_lag_exceeded",
+    ];
+
+    /// Returns the name of the event, as used in the `type` field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SystemEvent::StreamCreated { .. } => "stream_created",
+            SystemEvent::StreamDeleted { .. } => "stream_deleted",
+            SystemEvent::TopicCreated { .. } => "topic_created",
+            SystemEvent::TopicDeleted { .. } => "topic_deleted",
+            SystemEvent::UserLoggedIn { .. } => "user_logged_in",
+            SystemEvent::SegmentClosed { .. } => "segment_closed",
+            SystemEvent::RetentionRan { .. } => "retention_ran",
+            SystemEvent::ConsumerLagExceeded { .. } => "consumer_lag_exceeded",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_with_type_tag() {
+        let event = SystemEvent::StreamCreated {
+            stream_id: 1,
+            name: "test".to_string(),
+        };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"stream_created","stream_id":1,"name":"test"}"#
+        );
+
+        let deserialized: SystemEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, event);
+    }
+
+    #[test]
+    fn name_should_match_type_tag() {
+        let event = SystemEvent::ConsumerLagExceeded {
+            stream_id: 1,
+            topic_id: 2,
+            partition_id: 3,
+            consumer_kind: ConsumerKind::ConsumerGroup,
+            consumer_id: 4,
+            lag: 1000,
+        };
+
+        let json: serde_json::Value = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], event.name());
+        assert_eq!(json["consumer_kind"], "consumer_group");
+        assert!(SystemEvent::NAMES.contains(&event.name()));
+    }
+}
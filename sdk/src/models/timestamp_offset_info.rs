@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// `TimestampOffsetInfo` represents the position of the first message in a partition at or after the requested timestamp.
+/// It consists of the following fields:
+/// - `partition_id`: the unique identifier of the partition.
+/// - `offset`: the offset of the first message with the timestamp greater than or equal to the requested one.
+/// - `timestamp`: the timestamp of that message.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct TimestampOffsetInfo {
+    /// The unique identifier of the partition.
+    pub partition_id: u32,
+    /// The offset of the first message with the timestamp greater than or equal to the requested one.
+    pub offset: u64,
+    /// The timestamp of the message, which might be later than the requested one.
+    pub timestamp: u64,
+}
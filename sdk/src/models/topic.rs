@@ -1,10 +1,13 @@
 use crate::compression::compression_algorithm::CompressionAlgorithm;
+use crate::error::IggyError;
 use crate::models::partition::Partition;
 use crate::utils::byte_size::IggyByteSize;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::timestamp::IggyTimestamp;
 use crate::utils::topic_size::MaxTopicSize;
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
 /// `Topic` represents the medium level of logical separation of data as it's a part of the stream.
 /// It consists of the following fields:
@@ -15,6 +18,8 @@ use serde::{Deserialize, Serialize};
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic in bytes.
 /// - `replication_factor`: replication factor for the topic.
+/// - `cleanup_policy`: the way the old messages in the topic are cleaned up.
+/// - `read_only`: whether the topic is read-only, i.e. the messages cannot be sent to it.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,6 +41,12 @@ pub struct Topic {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Cleanup policy for the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Whether the topic is read-only, i.e. the messages cannot be sent to it.
+    #[serde(default)]
+    pub read_only: bool,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
@@ -51,8 +62,11 @@ pub struct Topic {
 /// - `message_expiry`: the optional expiry of the messages in the topic in seconds.
 /// - `max_topic_size`: the optional maximum size of the topic.
 /// - `replication_factor`: replication factor for the topic.
+/// - `cleanup_policy`: the way the old messages in the topic are cleaned up.
+/// - `read_only`: whether the topic is read-only, i.e. the messages cannot be sent to it.
 /// - `messages_count`: the total number of messages in the topic.
 /// - `partitions_count`: the total number of partitions in the topic.
+/// - `config`: the effective configuration values of the topic and their sources.
 /// - `partitions`: the collection of partitions in the topic.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TopicDetails {
@@ -73,10 +87,73 @@ pub struct TopicDetails {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: u8,
+    /// Cleanup policy for the topic.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Whether the topic is read-only, i.e. the messages cannot be sent to it.
+    #[serde(default)]
+    pub read_only: bool,
     /// The total number of messages in the topic.
     pub messages_count: u64,
     /// The total number of partitions in the topic.
     pub partitions_count: u32,
+    /// The effective configuration values of the topic and their sources.
+    #[serde(default)]
+    pub config: Vec<TopicConfigEntry>,
     /// The collection of partitions in the topic.
     pub partitions: Vec<Partition>,
 }
+
+/// `TopicConfigEntry` represents the effective value of a single configuration setting of the topic.
+/// It consists of the following fields:
+/// - `name`: the name of the setting, e.g. `segment_size`.
+/// - `value`: the effective value of the setting.
+/// - `source`: whether the value is inherited from the server or overridden for the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct TopicConfigEntry {
+    /// The name of the setting.
+    pub name: String,
+    /// The effective value of the setting.
+    pub value: String,
+    /// The source of the value.
+    pub source: TopicConfigSource,
+}
+
+/// `TopicConfigSource` represents where the effective value of the topic setting comes from.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicConfigSource {
+    /// The value is inherited from the server configuration.
+    #[default]
+    ServerDefault,
+    /// The value is overridden for the topic.
+    Topic,
+}
+
+impl TopicConfigSource {
+    /// Returns the code of the config source.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TopicConfigSource::ServerDefault => 1,
+            TopicConfigSource::Topic => 2,
+        }
+    }
+
+    /// Returns the config source from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(TopicConfigSource::ServerDefault),
+            2 => Ok(TopicConfigSource::Topic),
+            _ => Err(IggyError::InvalidCommand),
+        }
+    }
+}
+
+impl Display for TopicConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicConfigSource::ServerDefault => write!(f, "server_default"),
+            TopicConfigSource::Topic => write!(f, "topic"),
+        }
+    }
+}
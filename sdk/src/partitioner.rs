@@ -1,7 +1,10 @@
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::messages::send_messages::Message;
+use crate::models::header::HeaderKey;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU32, Ordering};
+use xxhash_rust::xxh32::xxh32;
 
 /// The trait represent the logic responsible for calculating the partition ID and is used by the `IggyClient`.
 /// This might be especially useful when the partition ID is not constant and might be calculated based on the stream ID, topic ID and other parameters.
@@ -12,4 +15,264 @@ pub trait Partitioner: Send + Sync + Debug {
         topic_id: &Identifier,
         messages: &[Message],
     ) -> Result<u32, IggyError>;
+
+    /// Calculates the partition ID for each of the messages, which allows to distribute a single batch across multiple partitions.
+    /// By default, all the messages are sent to the partition returned by `calculate_partition_id`.
+    fn calculate_partition_ids(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<Vec<u32>, IggyError> {
+        let partition_id = self.calculate_partition_id(stream_id, topic_id, messages)?;
+        Ok(vec![partition_id; messages.len()])
+    }
+}
+
+/// Distributes the messages evenly across all the partitions, one message at a time.
+#[derive(Debug)]
+pub struct RoundRobinPartitioner {
+    partitions_count: u32,
+    counter: AtomicU32,
+}
+
+impl RoundRobinPartitioner {
+    /// Creates a new round-robin partitioner for the topic with the given number of partitions.
+    pub fn new(partitions_count: u32) -> Result<Self, IggyError> {
+        validate_partitions_count(partitions_count)?;
+        Ok(Self {
+            partitions_count,
+            counter: AtomicU32::new(0),
+        })
+    }
+
+    fn next_partition_id(&self) -> u32 {
+        self.counter.fetch_add(1, Ordering::Relaxed) % self.partitions_count + 1
+    }
+}
+
+impl Partitioner for RoundRobinPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        Ok(self.next_partition_id())
+    }
+
+    fn calculate_partition_ids(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<Vec<u32>, IggyError> {
+        Ok(messages.iter().map(|_| self.next_partition_id()).collect())
+    }
+}
+
+/// Sends the whole batch of messages to a single partition and moves to the next partition for the following batch,
+/// which keeps the batches intact while still spreading the load across all the partitions over time.
+#[derive(Debug)]
+pub struct StickyPartitioner {
+    partitions_count: u32,
+    batches_per_partition: u32,
+    counter: AtomicU32,
+}
+
+impl StickyPartitioner {
+    /// Creates a new sticky partitioner for the topic with the given number of partitions, switching the partition after every batch.
+    pub fn new(partitions_count: u32) -> Result<Self, IggyError> {
+        Self::with_batches_per_partition(partitions_count, 1)
+    }
+
+    /// Creates a new sticky partitioner, which sends the given number of consecutive batches to the same partition.
+    pub fn with_batches_per_partition(
+        partitions_count: u32,
+        batches_per_partition: u32,
+    ) -> Result<Self, IggyError> {
+        validate_partitions_count(partitions_count)?;
+        Ok(Self {
+            partitions_count,
+            batches_per_partition: batches_per_partition.max(1),
+            counter: AtomicU32::new(0),
+        })
+    }
+}
+
+impl Partitioner for StickyPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        _messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        let batch = self.counter.fetch_add(1, Ordering::Relaxed);
+        Ok(batch / self.batches_per_partition % self.partitions_count + 1)
+    }
+}
+
+/// Calculates the partition for each message based on the hash of its key, so the messages with the same key always end up in the same partition.
+/// The key is either the message ID or the value of the selected header (falling back to the message ID when the header is missing).
+/// The hashing is the same as the one used by the server for `Partitioning::messages_key`.
+#[derive(Debug)]
+pub struct KeyHashPartitioner {
+    partitions_count: u32,
+    header_key: Option<HeaderKey>,
+}
+
+impl KeyHashPartitioner {
+    /// Creates a new key-hash partitioner for the topic with the given number of partitions, using the message ID as the key.
+    pub fn new(partitions_count: u32) -> Result<Self, IggyError> {
+        validate_partitions_count(partitions_count)?;
+        Ok(Self {
+            partitions_count,
+            header_key: None,
+        })
+    }
+
+    /// Creates a new key-hash partitioner, using the value of the given header as the key.
+    pub fn with_header_key(
+        partitions_count: u32,
+        header_key: HeaderKey,
+    ) -> Result<Self, IggyError> {
+        validate_partitions_count(partitions_count)?;
+        Ok(Self {
+            partitions_count,
+            header_key: Some(header_key),
+        })
+    }
+
+    fn calculate_message_partition_id(&self, message: &Message) -> u32 {
+        let header_value = self.header_key.as_ref().and_then(|key| {
+            message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(key))
+        });
+        let hash = match header_value {
+            Some(value) => xxh32(&value.value, 0),
+            None => xxh32(&message.id.to_le_bytes(), 0),
+        };
+        match hash % self.partitions_count {
+            0 => self.partitions_count,
+            partition_id => partition_id,
+        }
+    }
+}
+
+impl Partitioner for KeyHashPartitioner {
+    fn calculate_partition_id(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<u32, IggyError> {
+        let message = messages.first().ok_or(IggyError::InvalidMessagesCount)?;
+        Ok(self.calculate_message_partition_id(message))
+    }
+
+    fn calculate_partition_ids(
+        &self,
+        _stream_id: &Identifier,
+        _topic_id: &Identifier,
+        messages: &[Message],
+    ) -> Result<Vec<u32>, IggyError> {
+        Ok(messages
+            .iter()
+            .map(|message| self.calculate_message_partition_id(message))
+            .collect())
+    }
+}
+
+fn validate_partitions_count(partitions_count: u32) -> Result<(), IggyError> {
+    if partitions_count == 0 {
+        return Err(IggyError::InvalidPartitionsCount);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::header::HeaderValue;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    fn create_messages(count: u128) -> Vec<Message> {
+        (1..=count)
+            .map(|id| Message::new(Some(id), "test".into(), None))
+            .collect()
+    }
+
+    fn ids() -> (Identifier, Identifier) {
+        (
+            Identifier::numeric(1).unwrap(),
+            Identifier::numeric(1).unwrap(),
+        )
+    }
+
+    #[test]
+    fn round_robin_partitioner_should_distribute_messages_evenly() {
+        let (stream_id, topic_id) = ids();
+        let partitioner = RoundRobinPartitioner::new(3).unwrap();
+        let partition_ids = partitioner
+            .calculate_partition_ids(&stream_id, &topic_id, &create_messages(5))
+            .unwrap();
+        assert_eq!(partition_ids, vec![1, 2, 3, 1, 2]);
+    }
+
+    #[test]
+    fn sticky_partitioner_should_keep_batches_in_single_partition() {
+        let (stream_id, topic_id) = ids();
+        let messages = create_messages(3);
+        let partitioner = StickyPartitioner::with_batches_per_partition(2, 2).unwrap();
+        let mut partition_ids = Vec::new();
+        for _ in 0..5 {
+            let batch_partition_ids = partitioner
+                .calculate_partition_ids(&stream_id, &topic_id, &messages)
+                .unwrap();
+            assert!(batch_partition_ids
+                .iter()
+                .all(|id| *id == batch_partition_ids[0]));
+            partition_ids.push(batch_partition_ids[0]);
+        }
+        assert_eq!(partition_ids, vec![1, 1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn key_hash_partitioner_should_assign_same_partition_to_same_key() {
+        let (stream_id, topic_id) = ids();
+        let header_key = HeaderKey::new("key").unwrap();
+        let partitioner = KeyHashPartitioner::with_header_key(10, header_key.clone()).unwrap();
+        let messages = (1..=10)
+            .map(|id| {
+                let headers = HashMap::from([(
+                    header_key.clone(),
+                    HeaderValue::from_str(if id % 2 == 0 { "even" } else { "odd" }).unwrap(),
+                )]);
+                Message::new(Some(id), "test".into(), Some(headers))
+            })
+            .collect::<Vec<_>>();
+        let partition_ids = partitioner
+            .calculate_partition_ids(&stream_id, &topic_id, &messages)
+            .unwrap();
+        assert!(partition_ids.iter().all(|id| (1..=10).contains(id)));
+        assert!(partition_ids
+            .iter()
+            .step_by(2)
+            .all(|id| *id == partition_ids[0]));
+        assert!(partition_ids
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .all(|id| *id == partition_ids[1]));
+    }
+
+    #[test]
+    fn partitioners_should_require_partitions() {
+        assert!(RoundRobinPartitioner::new(0).is_err());
+        assert!(StickyPartitioner::new(0).is_err());
+        assert!(KeyHashPartitioner::new(0).is_err());
+    }
 }
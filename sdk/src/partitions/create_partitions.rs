@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, BytesSerializable};
 use crate::command::{Command, CREATE_PARTITIONS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -73,7 +73,7 @@ impl BytesSerializable for CreatePartitions {
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
-        let partitions_count = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partitions_count = read_u32_le(&bytes, position)?;
         let command = CreatePartitions {
             stream_id,
             topic_id,
@@ -0,0 +1,247 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, BytesSerializable};
+use crate::command::{Command, GET_MESSAGES_HISTOGRAM_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::partitions::MAX_HISTOGRAM_BUCKETS;
+use crate::utils::duration::IggyDuration;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// `GetMessagesHistogram` command is used to aggregate the count and size of the messages in the partition
+/// per time bucket, without fetching the messages.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `from` - the start of the time range (in microseconds), inclusive.
+/// - `to` - the end of the time range (in microseconds), exclusive.
+/// - `bucket_size` - the duration of the single time bucket, at most 10 000 buckets can be requested.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetMessagesHistogram {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+    /// The start of the time range (in microseconds), inclusive.
+    pub from: u64,
+    /// The end of the time range (in microseconds), exclusive.
+    pub to: u64,
+    /// The duration of the single time bucket.
+    pub bucket_size: IggyDuration,
+}
+
+impl Default for GetMessagesHistogram {
+    fn default() -> Self {
+        GetMessagesHistogram {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: 1,
+            from: 0,
+            to: 60_000_000,
+            bucket_size: IggyDuration::new(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl GetMessagesHistogram {
+    /// Returns the number of the time buckets within the requested time range, including the last partial one.
+    pub fn get_buckets_count(&self) -> u64 {
+        let bucket_size = self.bucket_size.as_micros();
+        if bucket_size == 0 || self.to <= self.from {
+            return 0;
+        }
+
+        (self.to - self.from).div_ceil(bucket_size)
+    }
+}
+
+impl Command for GetMessagesHistogram {
+    fn code(&self) -> u32 {
+        GET_MESSAGES_HISTOGRAM_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetMessagesHistogram {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if self.from >= self.to || self.bucket_size.as_micros() == 0 {
+            return Err(IggyError::InvalidHistogramRange);
+        }
+
+        let buckets_count = self.get_buckets_count();
+        if buckets_count > MAX_HISTOGRAM_BUCKETS as u64 {
+            return Err(IggyError::TooManyHistogramBuckets(
+                buckets_count,
+                MAX_HISTOGRAM_BUCKETS,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetMessagesHistogram {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(28 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u64_le(self.from);
+        bytes.put_u64_le(self.to);
+        bytes.put_u64_le(self.bucket_size.as_micros());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> std::result::Result<GetMessagesHistogram, IggyError> {
+        if bytes.len() < 34 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 28 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let from = read_u64_le(&bytes, position + 4)?;
+        let to = read_u64_le(&bytes, position + 12)?;
+        let bucket_size = read_u64_le(&bytes, position + 20)?;
+        let command = GetMessagesHistogram {
+            stream_id,
+            topic_id,
+            partition_id,
+            from,
+            to,
+            bucket_size: IggyDuration::new(Duration::from_micros(bucket_size)),
+        };
+        Ok(command)
+    }
+}
+
+impl Display for GetMessagesHistogram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.from, self.to, self.bucket_size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetMessagesHistogram {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            from: 1_700_000_000_000_000,
+            to: 1_700_000_060_000_000,
+            bucket_size: IggyDuration::new_from_secs(10),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let from = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let to = u64::from_le_bytes(bytes[position + 12..position + 20].try_into().unwrap());
+        let bucket_size =
+            u64::from_le_bytes(bytes[position + 20..position + 28].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(from, command.from);
+        assert_eq!(to, command.to);
+        assert_eq!(bucket_size, command.bucket_size.as_micros());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let stream_id_bytes = stream_id.to_bytes();
+        let topic_id_bytes = topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(28 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(3);
+        bytes.put_u64_le(1_700_000_000_000_000);
+        bytes.put_u64_le(1_700_000_060_000_000);
+        bytes.put_u64_le(10_000_000);
+
+        let command = GetMessagesHistogram::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, 3);
+        assert_eq!(command.from, 1_700_000_000_000_000);
+        assert_eq!(command.to, 1_700_000_060_000_000);
+        assert_eq!(command.bucket_size, IggyDuration::new_from_secs(10));
+        assert_eq!(command.get_buckets_count(), 6);
+    }
+
+    #[test]
+    fn should_not_be_validated_for_invalid_range_or_too_many_buckets() {
+        let command = GetMessagesHistogram {
+            from: 10,
+            to: 10,
+            ..GetMessagesHistogram::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidHistogramRange)
+        ));
+
+        let command = GetMessagesHistogram {
+            bucket_size: IggyDuration::new(Duration::from_micros(0)),
+            ..GetMessagesHistogram::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::InvalidHistogramRange)
+        ));
+
+        let command = GetMessagesHistogram {
+            from: 0,
+            to: MAX_HISTOGRAM_BUCKETS as u64 + 1,
+            bucket_size: IggyDuration::new(Duration::from_micros(1)),
+            ..GetMessagesHistogram::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(IggyError::TooManyHistogramBuckets(buckets, MAX_HISTOGRAM_BUCKETS))
+                if buckets == MAX_HISTOGRAM_BUCKETS as u64 + 1
+        ));
+
+        assert!(GetMessagesHistogram::default().validate().is_ok());
+    }
+}
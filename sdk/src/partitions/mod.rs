@@ -1,4 +1,10 @@
 pub mod create_partitions;
 pub mod delete_partitions;
+pub mod get_messages_histogram;
+pub mod get_offset_by_timestamp;
+pub mod get_partition;
+pub mod get_partition_offsets;
+pub mod seal_partition;
 
 const MAX_PARTITIONS_COUNT: u32 = 1000;
+const MAX_HISTOGRAM_BUCKETS: u32 = 10_000;
@@ -0,0 +1,160 @@
+use crate::bytes_serializable::{read_u32_le, BytesSerializable};
+use crate::command::{Command, SEAL_PARTITION_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `SealPartition` command is used to close the active segment of the partition at its current offset,
+/// producing a stable boundary for archiving or external processing of the closed segments.
+/// The messages appended afterwards are stored in the new segment.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `partition_id` - unique partition ID.
+/// - `read_only` - whether the topic should be marked as read-only before sealing the partition.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct SealPartition {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Unique partition ID.
+    #[serde(skip)]
+    pub partition_id: u32,
+    /// Whether the topic should be marked as read-only before sealing the partition.
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+impl Command for SealPartition {
+    fn code(&self) -> u32 {
+        SEAL_PARTITION_CODE
+    }
+}
+
+impl Validatable<IggyError> for SealPartition {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.partition_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SealPartition {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u32_le(self.partition_id);
+        bytes.put_u8(self.read_only as u8);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SealPartition, IggyError> {
+        if bytes.len() < 11 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() != position + 5 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let partition_id = read_u32_le(&bytes, position)?;
+        let read_only = match bytes[position + 4] {
+            0 => false,
+            1 => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let command = SealPartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            read_only,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for SealPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id, self.topic_id, self.partition_id, self.read_only
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SealPartition {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            partition_id: 3,
+            read_only: true,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let read_only = bytes[position + 4];
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(partition_id, command.partition_id);
+        assert_eq!(read_only, 1);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::named("orders").unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u32_le(3);
+        bytes.put_u8(0);
+        let command = SealPartition::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, 3);
+        assert!(!command.read_only);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_flag() {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&Identifier::numeric(1).unwrap().to_bytes());
+        bytes.put_slice(&Identifier::numeric(2).unwrap().to_bytes());
+        bytes.put_u32_le(3);
+        bytes.put_u8(2);
+        assert!(SealPartition::from_bytes(bytes.freeze()).is_err());
+    }
+}
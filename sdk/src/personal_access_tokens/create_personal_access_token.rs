@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u64_le, BytesSerializable};
 use crate::command::{Command, CREATE_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
 use crate::users::defaults::*;
@@ -8,7 +8,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
-use std::str::from_utf8;
 
 /// `CreatePersonalAccessToken` command is used to create a new personal access token for the authenticated user.
 /// It has additional payload:
@@ -70,13 +69,9 @@ impl BytesSerializable for CreatePersonalAccessToken {
         }
 
         let name_length = bytes[0];
-        let name = from_utf8(&bytes.slice(1..1 + name_length as usize))?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
-
+        let name = read_str(&bytes, 1, name_length as usize)?;
         let position = 1 + name_length as usize;
-        let expiry = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let expiry = read_u64_le(&bytes, position)?;
         let expiry: IggyExpiry = expiry.into();
 
         let command = CreatePersonalAccessToken { name, expiry };
@@ -93,6 +88,7 @@ impl Display for CreatePersonalAccessToken {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
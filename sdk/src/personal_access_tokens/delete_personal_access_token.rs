@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, BytesSerializable};
 use crate::command::{Command, DELETE_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
 use crate::users::defaults::*;
@@ -7,7 +7,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
-use std::str::from_utf8;
 
 /// `DeletePersonalAccessToken` command is used to delete a personal access token for the authenticated user.
 /// It has additional payload:
@@ -64,10 +63,7 @@ impl BytesSerializable for DeletePersonalAccessToken {
         }
 
         let name_length = bytes[0];
-        let name = from_utf8(&bytes[1..1 + name_length as usize])?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let name = read_str(&bytes, 1, name_length as usize)?;
 
         let command = DeletePersonalAccessToken { name };
         Ok(command)
@@ -83,6 +79,7 @@ impl Display for DeletePersonalAccessToken {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
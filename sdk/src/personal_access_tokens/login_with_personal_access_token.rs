@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, BytesSerializable};
 use crate::command::{Command, LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE};
 use crate::error::IggyError;
 use crate::users::defaults::*;
@@ -6,7 +6,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
-use std::str::from_utf8;
 
 /// `LoginWithPersonalAccessToken` command is used to login the user with a personal access token, instead of the username and password.
 /// It has additional payload:
@@ -56,10 +55,7 @@ impl BytesSerializable for LoginWithPersonalAccessToken {
         }
 
         let token_length = bytes[0];
-        let token = from_utf8(&bytes[1..1 + token_length as usize])?.to_string();
-        if token.len() != token_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let token = read_str(&bytes, 1, token_length as usize)?;
 
         let command = LoginWithPersonalAccessToken { token };
         Ok(command)
@@ -75,6 +71,7 @@ impl Display for LoginWithPersonalAccessToken {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
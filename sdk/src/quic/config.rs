@@ -1,3 +1,4 @@
+use crate::binary::retry_policy::RetryPolicy;
 use crate::client::AutoLogin;
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
@@ -15,7 +16,7 @@ pub struct QuicClientConfig {
     pub auto_login: AutoLogin,
     // Whether to automatically reconnect when disconnected.
     pub reconnection: QuicClientReconnectionConfig,
-    /// The size of the response buffer.
+    /// The max size of the single chunk read when receiving the response, the response itself can be larger.
     pub response_buffer_size: u64,
     /// The maximum number of concurrent bidirectional streams.
     pub max_concurrent_bidi_streams: u64,
@@ -35,6 +36,12 @@ pub struct QuicClientConfig {
     pub validate_certificate: bool,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
+    /// The maximum time to wait for the connection to be established, unlimited if not set.
+    pub connection_timeout: Option<IggyDuration>,
+    /// The policy of retrying the commands failed due to the broken connection.
+    pub retry_policy: RetryPolicy,
+    /// Whether to checksum each request and response frame sent over the wire.
+    pub wire_checksum: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -74,6 +81,9 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            connection_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            wire_checksum: false,
         }
     }
 }
@@ -86,7 +96,7 @@ impl Default for QuicClientConfig {
 /// - `server_name`: Default is "localhost".
 /// - `auto_login`: Default is AutoLogin::Disabled.
 /// - `reconnection`: Default is enabled unlimited retries and 1 second interval.
-/// - `response_buffer_size`: Default is 10MB (10,000,000 bytes).
+/// - `response_buffer_size`: Default is 10MB (10,000,000 bytes) read at once, the response itself can be larger.
 /// - `max_concurrent_bidi_streams`: Default is 10,000 streams.
 /// - `datagram_send_buffer_size`: Default is 100,000 bytes.
 /// - `initial_mtu`: Default is 1200 bytes.
@@ -95,6 +105,9 @@ impl Default for QuicClientConfig {
 /// - `keep_alive_interval`: Default is 5000 milliseconds.
 /// - `max_idle_timeout`: Default is 10,000 milliseconds.
 /// - `validate_certificate`: Default is false (certificate validation is disabled).
+/// - `connection_timeout`: Default is None (waits until the connection is established or fails).
+/// - `retry_policy`: Default is a single retry of the safe commands after 100 milliseconds.
+/// - `wire_checksum`: Default is false.
 #[derive(Debug, Default)]
 pub struct QuicClientConfigBuilder {
     config: QuicClientConfig,
@@ -147,7 +160,7 @@ impl QuicClientConfigBuilder {
         self
     }
 
-    /// Sets the response buffer size in bytes. Defaults to 10MB (10,000,000 bytes).
+    /// Sets the max size in bytes of the single chunk read when receiving the response. Defaults to 10MB (10,000,000 bytes).
     pub fn with_response_buffer_size(mut self, response_buffer_size: u64) -> Self {
         self.config.response_buffer_size = response_buffer_size;
         self
@@ -208,6 +221,24 @@ impl QuicClientConfigBuilder {
     }
 
     /// Finalizes the builder and returns the `QuicClientConfig`.
+    /// Sets the maximum time to wait for the connection to be established.
+    pub fn with_connection_timeout(mut self, connection_timeout: IggyDuration) -> Self {
+        self.config.connection_timeout = Some(connection_timeout);
+        self
+    }
+
+    /// Sets the policy of retrying the commands failed due to the broken connection.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets whether to checksum each request and response frame sent over the wire. Defaults to false.
+    pub fn with_wire_checksum(mut self, wire_checksum: bool) -> Self {
+        self.config.wire_checksum = wire_checksum;
+        self
+    }
+
     pub fn build(self) -> QuicClientConfig {
         self.config
     }
@@ -0,0 +1,5 @@
+pub mod get_schema;
+pub mod get_schemas;
+pub mod register_schema;
+
+const MAX_DEFINITION_LENGTH: usize = 1_000_000;
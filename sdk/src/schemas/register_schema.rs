@@ -0,0 +1,205 @@
+use crate::bytes_serializable::{read_u32_le, read_u8, BytesSerializable};
+use crate::command::{Command, REGISTER_SCHEMA_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::schemas::MAX_DEFINITION_LENGTH;
+use crate::utils::schema_type::SchemaType;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// `RegisterSchema` command registers a new version of the schema for the topic.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `schema_type` - type of the schema (JSON Schema, Avro or Protobuf).
+/// - `validate` - whether the payloads of the messages sent to the topic should be validated against the schema, supported only by JSON Schema.
+/// - `definition` - definition of the schema, max length is 1 MB.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RegisterSchema {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Type of the schema (JSON Schema, Avro or Protobuf).
+    #[serde(default)]
+    pub schema_type: SchemaType,
+    /// Whether the payloads of the messages sent to the topic should be validated against the schema, supported only by JSON Schema.
+    #[serde(default)]
+    pub validate: bool,
+    /// Definition of the schema, max length is 1 MB.
+    pub definition: String,
+}
+
+impl Command for RegisterSchema {
+    fn code(&self) -> u32 {
+        REGISTER_SCHEMA_CODE
+    }
+}
+
+impl Default for RegisterSchema {
+    fn default() -> Self {
+        RegisterSchema {
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            schema_type: SchemaType::default(),
+            validate: false,
+            definition: r#"{"type":"object"}"#.to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for RegisterSchema {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.definition.trim().is_empty() || self.definition.len() > MAX_DEFINITION_LENGTH {
+            return Err(IggyError::InvalidSchemaDefinition(
+                "definition cannot be empty or longer than 1 MB".to_string(),
+            ));
+        }
+
+        if self.validate && !self.schema_type.supports_validation() {
+            return Err(IggyError::SchemaValidationNotSupported(
+                self.schema_type.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RegisterSchema {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(
+            6 + stream_id_bytes.len() + topic_id_bytes.len() + self.definition.len(),
+        );
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.schema_type.as_code());
+        bytes.put_u8(if self.validate { 1 } else { 0 });
+        bytes.put_u32_le(self.definition.len() as u32);
+        bytes.put_slice(self.definition.as_bytes());
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RegisterSchema, IggyError> {
+        if bytes.len() < 13 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let schema_type = SchemaType::from_code(read_u8(&bytes, position)?)?;
+        let validate = read_u8(&bytes, position + 1)? == 1;
+        let definition_length = read_u32_le(&bytes, position + 2)? as usize;
+        position += 6;
+        if bytes.len() < position + definition_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let definition = from_utf8(&bytes[position..position + definition_length])?.to_string();
+        let command = RegisterSchema {
+            stream_id,
+            topic_id,
+            schema_type,
+            validate,
+            definition,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for RegisterSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.schema_type,
+            self.validate,
+            self.definition.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = RegisterSchema {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            schema_type: SchemaType::Avro,
+            validate: false,
+            definition: r#"{"type":"string"}"#.to_string(),
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let schema_type = SchemaType::from_code(bytes[position]).unwrap();
+        let validate = bytes[position + 1] == 1;
+        let definition_length =
+            u32::from_le_bytes(bytes[position + 2..position + 6].try_into().unwrap()) as usize;
+        let definition = from_utf8(&bytes[position + 6..position + 6 + definition_length]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(schema_type, command.schema_type);
+        assert_eq!(validate, command.validate);
+        assert_eq!(definition, command.definition);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let schema_type = SchemaType::JsonSchema;
+        let definition = r#"{"type":"object","required":["id"]}"#;
+        let mut bytes = BytesMut::new();
+        bytes.put(stream_id.to_bytes());
+        bytes.put(topic_id.to_bytes());
+        bytes.put_u8(schema_type.as_code());
+        bytes.put_u8(1);
+        bytes.put_u32_le(definition.len() as u32);
+        bytes.put_slice(definition.as_bytes());
+
+        let command = RegisterSchema::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.schema_type, schema_type);
+        assert!(command.validate);
+        assert_eq!(command.definition, definition);
+    }
+
+    #[test]
+    fn validation_should_fail_for_unsupported_schema_type() {
+        let command = RegisterSchema {
+            schema_type: SchemaType::Protobuf,
+            validate: true,
+            definition: "syntax = \"proto3\";".to_string(),
+            ..Default::default()
+        };
+
+        assert!(command.validate().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u32_le, BytesSerializable};
 use crate::command::{Command, CREATE_STREAM_CODE};
 use crate::error::IggyError;
 use crate::streams::MAX_NAME_LENGTH;
@@ -7,7 +7,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `CreateStream` command is used to create a new stream.
 /// It has additional payload:
@@ -71,17 +70,14 @@ impl BytesSerializable for CreateStream {
             return Err(IggyError::InvalidCommand);
         }
 
-        let stream_id = u32::from_le_bytes(bytes[..4].try_into()?);
+        let stream_id = read_u32_le(&bytes, 0)?;
         let stream_id = if stream_id == 0 {
             None
         } else {
             Some(stream_id)
         };
         let name_length = bytes[4];
-        let name = from_utf8(&bytes[5..5 + name_length as usize])?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let name = read_str(&bytes, 5, name_length as usize)?;
 
         let command = CreateStream { stream_id, name };
         Ok(command)
@@ -97,6 +93,7 @@ impl Display for CreateStream {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -1,15 +1,30 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, BytesSerializable};
 use crate::command::{Command, GET_STREAMS_CODE};
 use crate::error::IggyError;
+use crate::streams::MAX_NAME_LENGTH;
 use crate::validatable::Validatable;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::from_utf8;
 
-/// `GetStreams` command is used to retrieve the information about all streams.
-/// It has no additional payload.
+/// `GetStreams` command is used to retrieve the information about the streams ordered by ID.
+/// It has optional payload, which is empty if all the streams are returned:
+/// - `offset` - the number of the streams to skip.
+/// - `limit` - the maximum number of the streams to return. If not specified (use `None`), all the remaining streams are returned.
+/// - `name_prefix` - the prefix of the stream names. If not specified (use `None`), the streams aren't filtered by name.
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
-pub struct GetStreams {}
+pub struct GetStreams {
+    /// The number of the streams to skip.
+    #[serde(default)]
+    pub offset: u32,
+    /// The maximum number of the streams to return. If not specified (use `None`), all the remaining streams are returned.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// The prefix of the stream names. If not specified (use `None`), the streams aren't filtered by name.
+    #[serde(default)]
+    pub name_prefix: Option<String>,
+}
 
 impl Command for GetStreams {
     fn code(&self) -> u32 {
@@ -19,27 +34,77 @@ impl Command for GetStreams {
 
 impl Validatable<IggyError> for GetStreams {
     fn validate(&self) -> Result<(), IggyError> {
+        if self.limit == Some(0) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if let Some(name_prefix) = &self.name_prefix {
+            if name_prefix.is_empty() || name_prefix.len() > MAX_NAME_LENGTH {
+                return Err(IggyError::InvalidStreamName);
+            }
+        }
+
         Ok(())
     }
 }
 
 impl BytesSerializable for GetStreams {
     fn to_bytes(&self) -> Bytes {
-        Bytes::new()
+        if self == &GetStreams::default() {
+            return Bytes::new();
+        }
+
+        let name_prefix = self.name_prefix.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(9 + name_prefix.len());
+        bytes.put_u32_le(self.offset);
+        bytes.put_u32_le(self.limit.unwrap_or(0));
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name_prefix.len() as u8);
+        bytes.put_slice(name_prefix.as_bytes());
+        bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> std::result::Result<GetStreams, IggyError> {
-        if !bytes.is_empty() {
+        if bytes.is_empty() {
+            return Ok(GetStreams::default());
+        }
+
+        if bytes.len() < 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let offset = read_u32_le(&bytes, 0)?;
+        let limit = read_u32_le(&bytes, 4)?;
+        let limit = if limit == 0 { None } else { Some(limit) };
+        let name_prefix_length = bytes[8] as usize;
+        if bytes.len() != 9 + name_prefix_length {
             return Err(IggyError::InvalidCommand);
         }
 
-        Ok(GetStreams {})
+        let name_prefix = if name_prefix_length == 0 {
+            None
+        } else {
+            Some(from_utf8(&bytes[9..])?.to_string())
+        };
+        let command = GetStreams {
+            offset,
+            limit,
+            name_prefix,
+        };
+        command.validate()?;
+        Ok(command)
     }
 }
 
 impl Display for GetStreams {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        write!(
+            f,
+            "{}|{}|{}",
+            self.offset,
+            self.limit.unwrap_or(0),
+            self.name_prefix.as_deref().unwrap_or_default()
+        )
     }
 }
 
@@ -49,7 +114,7 @@ mod tests {
 
     #[test]
     fn should_be_serialized_as_empty_bytes() {
-        let command = GetStreams {};
+        let command = GetStreams::default();
         let bytes = command.to_bytes();
         assert!(bytes.is_empty());
     }
@@ -58,11 +123,50 @@ mod tests {
     fn should_be_deserialized_from_empty_bytes() {
         let command = GetStreams::from_bytes(Bytes::new());
         assert!(command.is_ok());
+        assert_eq!(command.unwrap(), GetStreams::default());
     }
 
     #[test]
-    fn should_not_be_deserialized_from_empty_bytes() {
+    fn should_not_be_deserialized_from_invalid_bytes() {
         let command = GetStreams::from_bytes(Bytes::from_static(&[0]));
         assert!(command.is_err());
     }
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetStreams {
+            offset: 10,
+            limit: Some(20),
+            name_prefix: Some("orders".to_string()),
+        };
+
+        let bytes = command.to_bytes();
+        let offset = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+        let limit = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let name_prefix_length = bytes[8];
+        let name_prefix = from_utf8(&bytes[9..]).unwrap();
+
+        assert_eq!(offset, command.offset);
+        assert_eq!(Some(limit), command.limit);
+        assert_eq!(name_prefix_length as usize, name_prefix.len());
+        assert_eq!(Some(name_prefix), command.name_prefix.as_deref());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name_prefix = "orders";
+        let mut bytes = BytesMut::new();
+        bytes.put_u32_le(10);
+        bytes.put_u32_le(0);
+        bytes.put_u8(name_prefix.len() as u8);
+        bytes.put_slice(name_prefix.as_bytes());
+
+        let command = GetStreams::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.offset, 10);
+        assert_eq!(command.limit, None);
+        assert_eq!(command.name_prefix.as_deref(), Some(name_prefix));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u8, BytesSerializable};
 use crate::command::{Command, UPDATE_STREAM_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -9,7 +9,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `UpdateStream` command is used to update an existing stream.
 /// It has additional payload:
@@ -72,12 +71,8 @@ impl BytesSerializable for UpdateStream {
         let mut position = 0;
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes().as_bytes_usize();
-        let name_length = bytes[position];
-        let name =
-            from_utf8(&bytes[position + 1..position + 1 + name_length as usize])?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let name_length = read_u8(&bytes, position)?;
+        let name = read_str(&bytes, position + 1, name_length as usize)?;
 
         let command = UpdateStream { stream_id, name };
         Ok(command)
@@ -93,6 +88,7 @@ impl Display for UpdateStream {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
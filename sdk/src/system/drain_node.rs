@@ -0,0 +1,99 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, DRAIN_NODE_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `DrainNode` command is used to put the node in the draining state before removing it from the cluster.
+/// The draining node accepts no new partitions, and transfers the leadership of its partitions to the replicas
+/// which have replicated all of their messages.
+/// It has additional payload:
+/// - `node_id` - unique node ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct DrainNode {
+    /// Unique node ID.
+    pub node_id: u32,
+}
+
+impl Default for DrainNode {
+    fn default() -> Self {
+        DrainNode { node_id: 1 }
+    }
+}
+
+impl Command for DrainNode {
+    fn code(&self) -> u32 {
+        DRAIN_NODE_CODE
+    }
+}
+
+impl Validatable<IggyError> for DrainNode {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.node_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for DrainNode {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.node_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<DrainNode, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let node_id = u32::from_le_bytes(bytes.as_ref().try_into()?);
+        let command = DrainNode { node_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for DrainNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.node_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DrainNode { node_id: 3 };
+
+        let bytes = command.to_bytes();
+        let node_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(node_id, command.node_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let node_id = 3u32;
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(node_id);
+
+        let command = DrainNode::from_bytes(bytes.freeze());
+
+        assert!(command.is_ok());
+        assert_eq!(command.unwrap().node_id, node_id);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(DrainNode::from_bytes(Bytes::from_static(&[1, 2, 3])).is_err());
+        assert!(DrainNode::from_bytes(Bytes::from_static(&[0, 0, 0, 0])).is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_CLUSTER_METADATA_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetClusterMetadata` command is used to get the nodes (brokers) of the cluster and the leaders of all the partitions,
+/// so that the client can route the commands of each partition to its leader.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetClusterMetadata {}
+
+impl Command for GetClusterMetadata {
+    fn code(&self) -> u32 {
+        GET_CLUSTER_METADATA_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetClusterMetadata {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetClusterMetadata {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetClusterMetadata, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(GetClusterMetadata {})
+    }
+}
+
+impl Display for GetClusterMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = GetClusterMetadata {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = GetClusterMetadata::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = GetClusterMetadata::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, GET_NODE_STATUS_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `GetNodeStatus` command is used to get the state of the node and the progress of draining it,
+/// i.e. the number of the partitions it still leads.
+/// It has additional payload:
+/// - `node_id` - unique node ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GetNodeStatus {
+    /// Unique node ID.
+    pub node_id: u32,
+}
+
+impl Default for GetNodeStatus {
+    fn default() -> Self {
+        GetNodeStatus { node_id: 1 }
+    }
+}
+
+impl Command for GetNodeStatus {
+    fn code(&self) -> u32 {
+        GET_NODE_STATUS_CODE
+    }
+}
+
+impl Validatable<IggyError> for GetNodeStatus {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.node_id == 0 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for GetNodeStatus {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.node_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<GetNodeStatus, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let node_id = u32::from_le_bytes(bytes.as_ref().try_into()?);
+        let command = GetNodeStatus { node_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetNodeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.node_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetNodeStatus { node_id: 3 };
+
+        let bytes = command.to_bytes();
+        let node_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(node_id, command.node_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let node_id = 3u32;
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(node_id);
+
+        let command = GetNodeStatus::from_bytes(bytes.freeze());
+
+        assert!(command.is_ok());
+        assert_eq!(command.unwrap().node_id, node_id);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        assert!(GetNodeStatus::from_bytes(Bytes::from_static(&[1, 2, 3])).is_err());
+        assert!(GetNodeStatus::from_bytes(Bytes::from_static(&[0, 0, 0, 0])).is_err());
+    }
+}
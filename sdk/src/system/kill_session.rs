@@ -0,0 +1,90 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, KILL_SESSION_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `KillSession` command is used to forcibly terminate the session of a specific client by unique ID, closing its connection.
+/// It has additional payload:
+/// - `client_id` - unique ID (numeric) of the client.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct KillSession {
+    /// Unique ID (numeric) of the client.
+    pub client_id: u32,
+}
+
+impl Command for KillSession {
+    fn code(&self) -> u32 {
+        KILL_SESSION_CODE
+    }
+}
+
+impl Default for KillSession {
+    fn default() -> Self {
+        KillSession { client_id: 1 }
+    }
+}
+
+impl Validatable<IggyError> for KillSession {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.client_id == 0 {
+            return Err(IggyError::InvalidClientId);
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for KillSession {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(self.client_id);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<KillSession, IggyError> {
+        if bytes.len() != 4 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let client_id = u32::from_le_bytes(bytes.as_ref().try_into()?);
+        let command = KillSession { client_id };
+        Ok(command)
+    }
+}
+
+impl Display for KillSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.client_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = KillSession { client_id: 1 };
+
+        let bytes = command.to_bytes();
+        let client_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(client_id, command.client_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let client_id = 1u32;
+        let mut bytes = BytesMut::with_capacity(4);
+        bytes.put_u32_le(client_id);
+        let command = KillSession::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.client_id, client_id);
+    }
+}
@@ -1,6 +1,15 @@
+pub mod decommission_node;
+pub mod drain_node;
 pub mod get_client;
 pub mod get_clients;
+pub mod get_cluster_metadata;
 pub mod get_me;
+pub mod get_node_status;
+pub mod get_sessions;
 pub mod get_snapshot;
 pub mod get_stats;
+pub mod kill_session;
 pub mod ping;
+pub mod rotate_encryption_key;
+pub mod set_background_io_rate;
+pub mod set_log_level;
@@ -0,0 +1,69 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, ROTATE_ENCRYPTION_KEY_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `RotateEncryptionKey` command is used to rotate the server-side encryption key.
+/// The new key is used for all the newly written data, while the existing segments are re-encrypted in the background.
+/// It has no additional payload.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RotateEncryptionKey {}
+
+impl Command for RotateEncryptionKey {
+    fn code(&self) -> u32 {
+        ROTATE_ENCRYPTION_KEY_CODE
+    }
+}
+
+impl Validatable<IggyError> for RotateEncryptionKey {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for RotateEncryptionKey {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::new()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<RotateEncryptionKey, IggyError> {
+        if !bytes.is_empty() {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        Ok(RotateEncryptionKey {})
+    }
+}
+
+impl Display for RotateEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_empty_bytes() {
+        let command = RotateEncryptionKey {};
+        let bytes = command.to_bytes();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_empty_bytes() {
+        let command = RotateEncryptionKey::from_bytes(Bytes::new());
+        assert!(command.is_ok());
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_non_empty_bytes() {
+        let command = RotateEncryptionKey::from_bytes(Bytes::from_static(&[0]));
+        assert!(command.is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_BACKGROUND_IO_RATE_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `SetBackgroundIoRate` command is used to change the background IO budget of the server at runtime, without restarting it.
+/// The budget is shared by the retention, compaction, archiving and recovery tasks, protecting the latency of the appends and polls.
+/// It has additional payload:
+/// - `bytes_per_second` - the maximum total number of bytes read and written per second by the background tasks, 0 means no limit.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct SetBackgroundIoRate {
+    /// The maximum total number of bytes read and written per second by the background tasks, 0 means no limit.
+    pub bytes_per_second: u64,
+}
+
+impl Command for SetBackgroundIoRate {
+    fn code(&self) -> u32 {
+        SET_BACKGROUND_IO_RATE_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetBackgroundIoRate {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetBackgroundIoRate {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(self.bytes_per_second);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetBackgroundIoRate, IggyError> {
+        if bytes.len() != 8 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let bytes_per_second = u64::from_le_bytes(bytes.as_ref().try_into()?);
+        Ok(SetBackgroundIoRate { bytes_per_second })
+    }
+}
+
+impl Display for SetBackgroundIoRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bytes_per_second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetBackgroundIoRate {
+            bytes_per_second: 50_000_000,
+        };
+
+        let bytes = command.to_bytes();
+        let bytes_per_second = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(bytes_per_second, command.bytes_per_second);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let bytes_per_second = 1_000_000u64;
+        let mut bytes = BytesMut::with_capacity(8);
+        bytes.put_u64_le(bytes_per_second);
+
+        let command = SetBackgroundIoRate::from_bytes(bytes.freeze());
+
+        assert!(command.is_ok());
+        assert_eq!(command.unwrap().bytes_per_second, bytes_per_second);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_invalid_bytes() {
+        let command = SetBackgroundIoRate::from_bytes(Bytes::from_static(&[1, 2, 3]));
+        assert!(command.is_err());
+    }
+}
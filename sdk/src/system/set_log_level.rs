@@ -0,0 +1,98 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_LOG_LEVEL_CODE};
+use crate::error::IggyError;
+use crate::validatable::Validatable;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::from_utf8;
+
+/// The maximum length of the log filter.
+pub const MAX_LOG_FILTER_LENGTH: usize = 1000;
+
+/// `SetLogLevel` command is used to change the log filter of the server at runtime, without restarting it.
+/// It has additional payload:
+/// - `filter` - the log filter (string) using the `tracing` directives syntax, either a global level (e.g. `debug`)
+///   or the levels per module (e.g. `info,server::streaming::segments=trace`), max length is 1000 characters.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct SetLogLevel {
+    /// The log filter, either a global level (e.g. `debug`) or the levels per module (e.g. `info,server::streaming::segments=trace`).
+    pub filter: String,
+}
+
+impl Command for SetLogLevel {
+    fn code(&self) -> u32 {
+        SET_LOG_LEVEL_CODE
+    }
+}
+
+impl Default for SetLogLevel {
+    fn default() -> Self {
+        SetLogLevel {
+            filter: "info".to_string(),
+        }
+    }
+}
+
+impl Validatable<IggyError> for SetLogLevel {
+    fn validate(&self) -> Result<(), IggyError> {
+        if self.filter.trim().is_empty() || self.filter.len() > MAX_LOG_FILTER_LENGTH {
+            return Err(IggyError::InvalidLogFilter(self.filter.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetLogLevel {
+    fn to_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(self.filter.as_bytes())
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetLogLevel, IggyError> {
+        if bytes.is_empty() || bytes.len() > MAX_LOG_FILTER_LENGTH {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let filter = from_utf8(&bytes)?.to_string();
+        Ok(SetLogLevel { filter })
+    }
+}
+
+impl Display for SetLogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.filter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetLogLevel {
+            filter: "info,server::streaming=trace".to_string(),
+        };
+
+        let bytes = command.to_bytes();
+
+        assert_eq!(bytes.as_ref(), command.filter.as_bytes());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let filter = "debug";
+
+        let command = SetLogLevel::from_bytes(Bytes::from_static(filter.as_bytes()));
+
+        assert!(command.is_ok());
+        assert_eq!(command.unwrap().filter, filter);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_empty_bytes() {
+        let command = SetLogLevel::from_bytes(Bytes::new());
+        assert!(command.is_err());
+    }
+}
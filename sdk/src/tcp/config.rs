@@ -1,3 +1,4 @@
+use crate::binary::retry_policy::RetryPolicy;
 use crate::client::AutoLogin;
 use crate::utils::duration::IggyDuration;
 use std::str::FromStr;
@@ -13,12 +14,25 @@ pub struct TcpClientConfig {
     pub tls_domain: String,
     /// The path to the CA file for TLS.
     pub tls_ca_file: Option<String>,
+    /// The path to the PEM encoded client certificate file, presented to the server requiring the mutual TLS.
+    pub tls_client_cert_file: Option<String>,
+    /// The path to the PEM encoded private key file of the client certificate.
+    pub tls_client_key_file: Option<String>,
     /// Whether to automatically login user after establishing connection.
     pub auto_login: AutoLogin,
     /// Whether to automatically reconnect when disconnected.
     pub reconnection: TcpClientReconnectionConfig,
     /// Interval of heartbeats sent by the client
     pub heartbeat_interval: IggyDuration,
+    /// The maximum time to wait for the connection to be established, unlimited if not set.
+    pub connection_timeout: Option<IggyDuration>,
+    /// The policy of retrying the commands failed due to the broken connection.
+    pub retry_policy: RetryPolicy,
+    /// Whether to checksum each request and response frame sent over the wire.
+    pub wire_checksum: bool,
+    /// The number of connections opened by the client. The commands bound to the session (e.g. the consumer group polls)
+    /// are sent over the primary connection, while the others are routed to the idle connection of the pool.
+    pub connection_pool_size: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -36,9 +50,15 @@ impl Default for TcpClientConfig {
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
             tls_ca_file: None,
+            tls_client_cert_file: None,
+            tls_client_key_file: None,
             heartbeat_interval: IggyDuration::from_str("5s").unwrap(),
             auto_login: AutoLogin::Disabled,
             reconnection: TcpClientReconnectionConfig::default(),
+            connection_timeout: None,
+            retry_policy: RetryPolicy::default(),
+            wire_checksum: false,
+            connection_pool_size: 1,
         }
     }
 }
@@ -62,6 +82,12 @@ impl Default for TcpClientReconnectionConfig {
 /// - `tls_enabled`: Default is false.
 /// - `tls_domain`: Default is "localhost".
 /// - `tls_ca_file`: Default is None.
+/// - `tls_client_cert_file`: Default is None.
+/// - `tls_client_key_file`: Default is None.
+/// - `connection_timeout`: Default is None.
+/// - `retry_policy`: Default is a single retry of the safe commands after 100 milliseconds.
+/// - `wire_checksum`: Default is false.
+/// - `connection_pool_size`: Default is 1 (no pooling).
 #[derive(Debug, Default)]
 pub struct TcpClientConfigBuilder {
     config: TcpClientConfig,
@@ -119,6 +145,38 @@ impl TcpClientConfigBuilder {
         self
     }
 
+    /// Sets the paths to the client certificate and its private key files for the mutual TLS.
+    /// The server authenticates the client as the user mapped to the certificate identity, if any.
+    pub fn with_tls_client_certificate(mut self, cert_file: String, key_file: String) -> Self {
+        self.config.tls_client_cert_file = Some(cert_file);
+        self.config.tls_client_key_file = Some(key_file);
+        self
+    }
+
+    /// Sets the maximum time to wait for the connection to be established.
+    pub fn with_connection_timeout(mut self, connection_timeout: IggyDuration) -> Self {
+        self.config.connection_timeout = Some(connection_timeout);
+        self
+    }
+
+    /// Sets the policy of retrying the commands failed due to the broken connection.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets whether to checksum each request and response frame sent over the wire. Defaults to false.
+    pub fn with_wire_checksum(mut self, wire_checksum: bool) -> Self {
+        self.config.wire_checksum = wire_checksum;
+        self
+    }
+
+    /// Sets the number of connections opened by the client. Defaults to 1.
+    pub fn with_connection_pool_size(mut self, connection_pool_size: u32) -> Self {
+        self.config.connection_pool_size = connection_pool_size;
+        self
+    }
+
     /// Builds the TCP client configuration.
     pub fn build(self) -> TcpClientConfig {
         self.config
@@ -0,0 +1,106 @@
+use crate::command::{
+    ACQUIRE_EXCLUSIVE_PRODUCER_CODE, GET_CONSUMER_OFFSET_CODE, GET_ME_CODE,
+    HEARTBEAT_CONSUMER_GROUP_CODE, INIT_PRODUCER_EPOCH_CODE, JOIN_CONSUMER_GROUP_CODE,
+    LEAVE_CONSUMER_GROUP_CODE, LOGIN_USER_CODE, LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+    LOGOUT_USER_CODE, PING_CODE, POLL_MESSAGES_CODE, POLL_MESSAGES_FROM_PARTITIONS_CODE,
+    SEND_MESSAGES_CODE, STORE_CONSUMER_OFFSET_CODE,
+};
+use crate::consumer::ConsumerKind;
+
+/// The connection of the pool the command is sent over.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Route {
+    /// The command depends on the server session of the client (e.g. consumer group membership or exclusive producer),
+    /// which is bound to the primary connection.
+    Primary,
+    /// The command changes or keeps alive the session, so it's sent over the primary connection first,
+    /// and then over each of the pooled connections.
+    Broadcast,
+    /// The command may be sent over any connection, preferably the idle one.
+    Any,
+}
+
+/// Returns the route of the command.
+/// Once the client has acquired the exclusive producer or initialized the producer epoch,
+/// the sent messages are bound to the primary connection as well.
+pub(crate) fn route(code: u32, payload: &[u8], session_bound_sends: bool) -> Route {
+    match code {
+        LOGIN_USER_CODE | LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE | LOGOUT_USER_CODE | PING_CODE => {
+            Route::Broadcast
+        }
+        GET_ME_CODE
+        | JOIN_CONSUMER_GROUP_CODE
+        | LEAVE_CONSUMER_GROUP_CODE
+        | HEARTBEAT_CONSUMER_GROUP_CODE
+        | ACQUIRE_EXCLUSIVE_PRODUCER_CODE
+        | INIT_PRODUCER_EPOCH_CODE => Route::Primary,
+        SEND_MESSAGES_CODE if session_bound_sends => Route::Primary,
+        // The payload of these commands starts with the consumer kind.
+        POLL_MESSAGES_CODE
+        | POLL_MESSAGES_FROM_PARTITIONS_CODE
+        | STORE_CONSUMER_OFFSET_CODE
+        | GET_CONSUMER_OFFSET_CODE
+            if payload.first() == Some(&ConsumerKind::ConsumerGroup.as_code()) =>
+        {
+            Route::Primary
+        }
+        _ => Route::Any,
+    }
+}
+
+/// Returns true if the successful command binds the subsequently sent messages to the session of the client.
+pub(crate) fn binds_sends_to_session(code: u32) -> bool {
+    matches!(
+        code,
+        ACQUIRE_EXCLUSIVE_PRODUCER_CODE | INIT_PRODUCER_EPOCH_CODE
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bytes_serializable::BytesSerializable;
+    use crate::command::CREATE_STREAM_CODE;
+    use crate::consumer::Consumer;
+    use crate::identifier::Identifier;
+    use crate::messages::poll_messages::PollMessages;
+
+    fn poll_messages_payload(consumer: Consumer) -> Vec<u8> {
+        PollMessages {
+            consumer,
+            ..PollMessages::default()
+        }
+        .to_bytes()
+        .to_vec()
+    }
+
+    #[test]
+    fn session_commands_should_be_broadcast() {
+        for code in [
+            LOGIN_USER_CODE,
+            LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE,
+            LOGOUT_USER_CODE,
+            PING_CODE,
+        ] {
+            assert_eq!(route(code, &[], false), Route::Broadcast);
+        }
+    }
+
+    #[test]
+    fn consumer_group_polls_should_be_bound_to_primary_connection() {
+        let id = Identifier::numeric(1).unwrap();
+        let payload = poll_messages_payload(Consumer::group(id.clone()));
+        assert_eq!(route(POLL_MESSAGES_CODE, &payload, false), Route::Primary);
+
+        let payload = poll_messages_payload(Consumer::new(id));
+        assert_eq!(route(POLL_MESSAGES_CODE, &payload, false), Route::Any);
+        assert_eq!(route(CREATE_STREAM_CODE, &[], false), Route::Any);
+    }
+
+    #[test]
+    fn sends_should_be_bound_to_primary_connection_once_producer_is_bound_to_session() {
+        assert_eq!(route(SEND_MESSAGES_CODE, &[], false), Route::Any);
+        assert!(binds_sends_to_session(ACQUIRE_EXCLUSIVE_PRODUCER_CODE));
+        assert_eq!(route(SEND_MESSAGES_CODE, &[], true), Route::Primary);
+    }
+}
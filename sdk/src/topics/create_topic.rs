@@ -1,18 +1,19 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u32_le, read_u64_le, read_u8, BytesSerializable};
 use crate::command::{Command, CREATE_TOPIC_CODE};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::topics::{MAX_NAME_LENGTH, MAX_PARTITIONS_COUNT};
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::text;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `CreateTopic` command is used to create a new topic in a stream.
 /// It has additional payload:
@@ -22,7 +23,9 @@ use std::str::from_utf8;
 /// - `message_expiry` - optional message expiry in seconds, if `None` then messages will never expire.
 /// - `max_topic_size` - optional maximum size of the topic, if `None` then topic size is unlimited.
 ///                      Can't be lower than segment size in the config.
-/// - `replication_factor` - replication factor for the topic.
+/// - `replication_factor` - replication factor for the topic, reserved for the replication (only 1 is supported for now).
+/// - `cleanup_policy` - the way the old messages are cleaned up, either `delete` (default) or `compact`.
+/// - `overrides` - the server configuration values overridden for the topic, by default all of them are inherited.
 /// - `name` - unique topic name, max length is 255 characters. The name will be always converted to lowercase and all whitespaces will be replaced with dots.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct CreateTopic {
@@ -41,6 +44,12 @@ pub struct CreateTopic {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: Option<u8>,
+    /// Cleanup policy for the topic, `delete` by default.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Server configuration values overridden for the topic.
+    #[serde(default)]
+    pub overrides: TopicOverrides,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -61,6 +70,8 @@ impl Default for CreateTopic {
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            overrides: TopicOverrides::default(),
             name: "topic".to_string(),
         }
     }
@@ -99,7 +110,7 @@ impl Validatable<IggyError> for CreateTopic {
 impl BytesSerializable for CreateTopic {
     fn to_bytes(&self) -> Bytes {
         let stream_id_bytes = self.stream_id.to_bytes();
-        let mut bytes = BytesMut::with_capacity(23 + stream_id_bytes.len() + self.name.len());
+        let mut bytes = BytesMut::with_capacity(24 + stream_id_bytes.len() + self.name.len());
         bytes.put_slice(&stream_id_bytes);
         bytes.put_u32_le(self.topic_id.unwrap_or(0));
         bytes.put_u32_le(self.partitions_count);
@@ -113,6 +124,8 @@ impl BytesSerializable for CreateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.cleanup_policy.as_code());
+        bytes.put_slice(&self.overrides.to_bytes());
         bytes.freeze()
     }
 
@@ -123,24 +136,33 @@ impl BytesSerializable for CreateTopic {
         let mut position = 0;
         let stream_id = Identifier::from_bytes(bytes.clone())?;
         position += stream_id.get_size_bytes().as_bytes_usize();
-        let topic_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let topic_id = read_u32_le(&bytes, position)?;
         let topic_id = if topic_id == 0 { None } else { Some(topic_id) };
-        let partitions_count = u32::from_le_bytes(bytes[position + 4..position + 8].try_into()?);
-        let compression_algorithm = CompressionAlgorithm::from_code(bytes[position + 8])?;
-        let message_expiry = u64::from_le_bytes(bytes[position + 9..position + 17].try_into()?);
+        let partitions_count = read_u32_le(&bytes, position + 4)?;
+        let compression_algorithm =
+            CompressionAlgorithm::from_code(read_u8(&bytes, position + 8)?)?;
+        let message_expiry = read_u64_le(&bytes, position + 9)?;
         let message_expiry: IggyExpiry = message_expiry.into();
-        let max_topic_size = u64::from_le_bytes(bytes[position + 17..position + 25].try_into()?);
+        let max_topic_size = read_u64_le(&bytes, position + 17)?;
         let max_topic_size: MaxTopicSize = max_topic_size.into();
-        let replication_factor = match bytes[position + 25] {
+        let replication_factor = match read_u8(&bytes, position + 25)? {
             0 => None,
             factor => Some(factor),
         };
-        let name_length = bytes[position + 26];
-        let name =
-            from_utf8(&bytes[position + 27..(position + 27 + name_length as usize)])?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let name_length = read_u8(&bytes, position + 26)?;
+        let name = read_str(&bytes, position + 27, name_length as usize)?;
+        // The cleanup policy is optional to keep the commands stored in the older state readable.
+        let cleanup_policy = match bytes.get(position + 27 + name_length as usize) {
+            Some(code) => CleanupPolicy::from_code(*code)?,
+            None => CleanupPolicy::default(),
+        };
+        // Same as the cleanup policy, the overrides are missing in the commands stored in the older state.
+        let overrides_position = position + 27 + name_length as usize + 1;
+        let overrides = if bytes.len() > overrides_position {
+            TopicOverrides::from_bytes(bytes.slice(overrides_position..))?
+        } else {
+            TopicOverrides::default()
+        };
         let command = CreateTopic {
             stream_id,
             topic_id,
@@ -149,6 +171,8 @@ impl BytesSerializable for CreateTopic {
             message_expiry,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
+            overrides,
             name,
         };
         Ok(command)
@@ -159,13 +183,15 @@ impl Display for CreateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id.unwrap_or(0),
             self.partitions_count,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
+            self.cleanup_policy,
+            self.overrides,
             self.name
         )
     }
@@ -174,7 +200,10 @@ impl Display for CreateTopic {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::timestamp_type::TimestampType;
     use bytes::BufMut;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -186,6 +215,13 @@ mod tests {
             compression_algorithm: CompressionAlgorithm::None,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            cleanup_policy: CleanupPolicy::Compact,
+            overrides: TopicOverrides {
+                segment_size: Some(IggyByteSize::from(1_000_000)),
+                messages_required_to_save: Some(100),
+                cache_size: None,
+                message_timestamp_type: Some(TimestampType::CreateTime),
+            },
             name: "test".to_string(),
         };
         let bytes = command.to_bytes();
@@ -207,6 +243,11 @@ mod tests {
         let name = from_utf8(&bytes[position + 27..(position + 27 + name_length as usize)])
             .unwrap()
             .to_string();
+        let cleanup_policy =
+            CleanupPolicy::from_code(bytes[position + 27 + name_length as usize]).unwrap();
+        let overrides =
+            TopicOverrides::from_bytes(bytes.slice(position + 28 + name_length as usize..))
+                .unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -218,6 +259,8 @@ mod tests {
         assert_eq!(replication_factor, command.replication_factor.unwrap());
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(overrides, command.overrides);
     }
 
     #[test]
@@ -230,6 +273,7 @@ mod tests {
         let message_expiry = IggyExpiry::NeverExpire;
         let max_topic_size = MaxTopicSize::ServerDefault;
         let replication_factor = 1;
+        let cleanup_policy = CleanupPolicy::Compact;
         let stream_id_bytes = stream_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(14 + stream_id_bytes.len() + name.len());
         bytes.put_slice(&stream_id_bytes);
@@ -242,6 +286,7 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_u8(cleanup_policy.as_code());
 
         let command = CreateTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
@@ -256,5 +301,7 @@ mod tests {
         assert_eq!(command.max_topic_size, max_topic_size);
         assert_eq!(command.replication_factor.unwrap(), replication_factor);
         assert_eq!(command.partitions_count, partitions_count);
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.overrides, TopicOverrides::default());
     }
 }
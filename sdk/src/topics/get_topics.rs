@@ -1,20 +1,37 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_u32_le, read_u8, BytesSerializable};
 use crate::command::{Command, GET_TOPICS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
+use crate::topics::MAX_NAME_LENGTH;
+use crate::utils::sizeable::Sizeable;
 use crate::validatable::Validatable;
-use bytes::Bytes;
+use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
+use std::str::from_utf8;
 
-/// `GetTopics` command is used to retrieve the collection of topics from a stream.
+/// `GetTopics` command is used to retrieve the collection of topics from a stream ordered by ID.
 /// It has additional payload:
 /// - `stream_id` - unique stream ID (numeric or name).
+/// - `offset` - the number of the topics to skip.
+/// - `limit` - the maximum number of the topics to return. If not specified (use `None`), all the remaining topics are returned.
+/// - `name_prefix` - the prefix of the topic names. If not specified (use `None`), the topics aren't filtered by name.
+///
+/// The `offset`, `limit` and `name_prefix` are omitted from the payload if all the topics are returned.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct GetTopics {
     /// Unique stream ID (numeric or name).
     #[serde(skip)]
     pub stream_id: Identifier,
+    /// The number of the topics to skip.
+    #[serde(default)]
+    pub offset: u32,
+    /// The maximum number of the topics to return. If not specified (use `None`), all the remaining topics are returned.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// The prefix of the topic names. If not specified (use `None`), the topics aren't filtered by name.
+    #[serde(default)]
+    pub name_prefix: Option<String>,
 }
 
 impl Command for GetTopics {
@@ -25,13 +42,36 @@ impl Command for GetTopics {
 
 impl Validatable<IggyError> for GetTopics {
     fn validate(&self) -> Result<(), IggyError> {
+        if self.limit == Some(0) {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        if let Some(name_prefix) = &self.name_prefix {
+            if name_prefix.is_empty() || name_prefix.len() > MAX_NAME_LENGTH {
+                return Err(IggyError::InvalidTopicName);
+            }
+        }
+
         Ok(())
     }
 }
 
 impl BytesSerializable for GetTopics {
     fn to_bytes(&self) -> Bytes {
-        self.stream_id.to_bytes()
+        let stream_id_bytes = self.stream_id.to_bytes();
+        if self.offset == 0 && self.limit.is_none() && self.name_prefix.is_none() {
+            return stream_id_bytes;
+        }
+
+        let name_prefix = self.name_prefix.as_deref().unwrap_or_default();
+        let mut bytes = BytesMut::with_capacity(stream_id_bytes.len() + 9 + name_prefix.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_u32_le(self.offset);
+        bytes.put_u32_le(self.limit.unwrap_or(0));
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name_prefix.len() as u8);
+        bytes.put_slice(name_prefix.as_bytes());
+        bytes.freeze()
     }
 
     fn from_bytes(bytes: Bytes) -> std::result::Result<GetTopics, IggyError> {
@@ -39,15 +79,53 @@ impl BytesSerializable for GetTopics {
             return Err(IggyError::InvalidCommand);
         }
 
-        let stream_id = Identifier::from_bytes(bytes)?;
-        let command = GetTopics { stream_id };
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        if bytes.len() == position {
+            return Ok(GetTopics {
+                stream_id,
+                ..Default::default()
+            });
+        }
+
+        if bytes.len() < position + 9 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let offset = read_u32_le(&bytes, position)?;
+        let limit = read_u32_le(&bytes, position + 4)?;
+        let limit = if limit == 0 { None } else { Some(limit) };
+        let name_prefix_length = read_u8(&bytes, position + 8)? as usize;
+        if bytes.len() != position + 9 + name_prefix_length {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let name_prefix = if name_prefix_length == 0 {
+            None
+        } else {
+            Some(from_utf8(&bytes[position + 9..])?.to_string())
+        };
+        let command = GetTopics {
+            stream_id,
+            offset,
+            limit,
+            name_prefix,
+        };
+        command.validate()?;
         Ok(command)
     }
 }
 
 impl Display for GetTopics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.stream_id)
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.stream_id,
+            self.offset,
+            self.limit.unwrap_or(0),
+            self.name_prefix.as_deref().unwrap_or_default()
+        )
     }
 }
 
@@ -59,6 +137,7 @@ mod tests {
     fn should_be_serialized_as_bytes() {
         let command = GetTopics {
             stream_id: Identifier::numeric(1).unwrap(),
+            ..Default::default()
         };
 
         let bytes = command.to_bytes();
@@ -77,5 +156,53 @@ mod tests {
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.offset, 0);
+        assert_eq!(command.limit, None);
+        assert_eq!(command.name_prefix, None);
+    }
+
+    #[test]
+    fn should_be_serialized_as_bytes_with_page() {
+        let command = GetTopics {
+            stream_id: Identifier::numeric(1).unwrap(),
+            offset: 10,
+            limit: Some(20),
+            name_prefix: Some("orders".to_string()),
+        };
+
+        let bytes = command.to_bytes();
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        let position = stream_id.get_size_bytes().as_bytes_usize();
+        let offset = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let limit = u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap());
+        let name_prefix_length = bytes[position + 8];
+        let name_prefix = from_utf8(&bytes[position + 9..]).unwrap();
+
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(offset, command.offset);
+        assert_eq!(Some(limit), command.limit);
+        assert_eq!(name_prefix_length as usize, name_prefix.len());
+        assert_eq!(Some(name_prefix), command.name_prefix.as_deref());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes_with_page() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let name_prefix = "orders";
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_u32_le(10);
+        bytes.put_u32_le(20);
+        bytes.put_u8(name_prefix.len() as u8);
+        bytes.put_slice(name_prefix.as_bytes());
+
+        let command = GetTopics::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.offset, 10);
+        assert_eq!(command.limit, Some(20));
+        assert_eq!(command.name_prefix.as_deref(), Some(name_prefix));
     }
 }
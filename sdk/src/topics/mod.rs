@@ -3,6 +3,7 @@ pub mod delete_topic;
 pub mod get_topic;
 pub mod get_topics;
 pub mod purge_topic;
+pub mod set_topic_read_only;
 pub mod update_topic;
 
 const MAX_NAME_LENGTH: usize = 255;
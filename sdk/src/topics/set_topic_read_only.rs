@@ -0,0 +1,133 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{Command, SET_TOPIC_READ_ONLY_CODE};
+use crate::error::IggyError;
+use crate::identifier::Identifier;
+use crate::utils::sizeable::Sizeable;
+use crate::validatable::Validatable;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// `SetTopicReadOnly` command is used to freeze (or unfreeze) the writes to a topic, e.g. during the migration or incident forensics.
+/// The messages sent to the read-only topic are rejected, while polling them continues to work.
+/// It has additional payload:
+/// - `stream_id` - unique stream ID (numeric or name).
+/// - `topic_id` - unique topic ID (numeric or name).
+/// - `read_only` - whether the topic is read-only.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct SetTopicReadOnly {
+    /// Unique stream ID (numeric or name).
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    /// Unique topic ID (numeric or name).
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    /// Whether the topic is read-only.
+    pub read_only: bool,
+}
+
+impl Command for SetTopicReadOnly {
+    fn code(&self) -> u32 {
+        SET_TOPIC_READ_ONLY_CODE
+    }
+}
+
+impl Validatable<IggyError> for SetTopicReadOnly {
+    fn validate(&self) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl BytesSerializable for SetTopicReadOnly {
+    fn to_bytes(&self) -> Bytes {
+        let stream_id_bytes = self.stream_id.to_bytes();
+        let topic_id_bytes = self.topic_id.to_bytes();
+        let mut bytes = BytesMut::with_capacity(1 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.put_slice(&stream_id_bytes);
+        bytes.put_slice(&topic_id_bytes);
+        bytes.put_u8(self.read_only as u8);
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<SetTopicReadOnly, IggyError> {
+        if bytes.len() < 11 {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone())?;
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let read_only = match bytes.get(position) {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return Err(IggyError::InvalidCommand),
+        };
+        let command = SetTopicReadOnly {
+            stream_id,
+            topic_id,
+            read_only,
+        };
+        Ok(command)
+    }
+}
+
+impl Display for SetTopicReadOnly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}|{}", self.stream_id, self.topic_id, self.read_only)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = SetTopicReadOnly {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            read_only: true,
+        };
+
+        let bytes = command.to_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes.clone()).unwrap();
+        position += stream_id.get_size_bytes().as_bytes_usize();
+        let topic_id = Identifier::from_bytes(bytes.slice(position..)).unwrap();
+        position += topic_id.get_size_bytes().as_bytes_usize();
+        let read_only = bytes[position];
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(read_only, 1);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&stream_id.to_bytes());
+        bytes.put_slice(&topic_id.to_bytes());
+        bytes.put_u8(1);
+        let command = SetTopicReadOnly::from_bytes(bytes.freeze());
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert!(command.read_only);
+    }
+
+    #[test]
+    fn should_not_be_deserialized_from_bytes_with_invalid_flag() {
+        let mut bytes = BytesMut::new();
+        bytes.put_slice(&Identifier::numeric(1).unwrap().to_bytes());
+        bytes.put_slice(&Identifier::numeric(2).unwrap().to_bytes());
+        bytes.put_u8(2);
+        assert!(SetTopicReadOnly::from_bytes(bytes.freeze()).is_err());
+    }
+}
@@ -1,18 +1,19 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u64_le, read_u8, BytesSerializable};
 use crate::command::{Command, UPDATE_TOPIC_CODE};
 use crate::compression::compression_algorithm::CompressionAlgorithm;
 use crate::error::IggyError;
 use crate::identifier::Identifier;
 use crate::topics::MAX_NAME_LENGTH;
+use crate::utils::cleanup_policy::CleanupPolicy;
 use crate::utils::expiry::IggyExpiry;
 use crate::utils::sizeable::Sizeable;
 use crate::utils::text;
+use crate::utils::topic_overrides::TopicOverrides;
 use crate::utils::topic_size::MaxTopicSize;
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `UpdateTopic` command is used to update a topic in a stream.
 /// It has additional payload:
@@ -21,7 +22,9 @@ use std::str::from_utf8;
 /// - `message_expiry` - optional message expiry in seconds, if `None` then messages will never expire.
 /// - `max_topic_size` - optional maximum size of the topic in bytes, if `None` then topic size is unlimited.
 ///                      Can't be lower than segment size in the config.
-/// - `replication_factor` - replication factor for the topic.
+/// - `replication_factor` - replication factor for the topic, reserved for the replication (only 1 is supported for now).
+/// - `cleanup_policy` - the way the old messages are cleaned up, either `delete` (default) or `compact`.
+/// - `overrides` - the server configuration values overridden for the topic, by default all of them are inherited.
 /// - `name` - unique topic name, max length is 255 characters.
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct UpdateTopic {
@@ -40,6 +43,12 @@ pub struct UpdateTopic {
     pub max_topic_size: MaxTopicSize,
     /// Replication factor for the topic.
     pub replication_factor: Option<u8>,
+    /// Cleanup policy for the topic, `delete` by default.
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    /// Server configuration values overridden for the topic.
+    #[serde(default)]
+    pub overrides: TopicOverrides,
     /// Unique topic name, max length is 255 characters.
     pub name: String,
 }
@@ -59,6 +68,8 @@ impl Default for UpdateTopic {
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            overrides: TopicOverrides::default(),
             name: "topic".to_string(),
         }
     }
@@ -89,7 +100,7 @@ impl BytesSerializable for UpdateTopic {
         let stream_id_bytes = self.stream_id.to_bytes();
         let topic_id_bytes = self.topic_id.to_bytes();
         let mut bytes = BytesMut::with_capacity(
-            14 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
+            15 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len(),
         );
         bytes.put_slice(&stream_id_bytes.clone());
         bytes.put_slice(&topic_id_bytes.clone());
@@ -103,6 +114,8 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.put_slice(self.name.as_bytes());
+        bytes.put_u8(self.cleanup_policy.as_code());
+        bytes.put_slice(&self.overrides.to_bytes());
         bytes.freeze()
     }
 
@@ -115,22 +128,30 @@ impl BytesSerializable for UpdateTopic {
         position += stream_id.get_size_bytes().as_bytes_usize();
         let topic_id = Identifier::from_bytes(bytes.slice(position..))?;
         position += topic_id.get_size_bytes().as_bytes_usize();
-        let compression_algorithm = CompressionAlgorithm::from_code(bytes[position])?;
+        let compression_algorithm = CompressionAlgorithm::from_code(read_u8(&bytes, position)?)?;
         position += 1;
-        let message_expiry = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let message_expiry = read_u64_le(&bytes, position)?;
         let message_expiry: IggyExpiry = message_expiry.into();
-        let max_topic_size = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+        let max_topic_size = read_u64_le(&bytes, position + 8)?;
         let max_topic_size: MaxTopicSize = max_topic_size.into();
-        let replication_factor = match bytes[position + 16] {
+        let replication_factor = match read_u8(&bytes, position + 16)? {
             0 => None,
             factor => Some(factor),
         };
-        let name_length = bytes[position + 17];
-        let name =
-            from_utf8(&bytes[position + 18..(position + 18 + name_length as usize)])?.to_string();
-        if name.len() != name_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let name_length = read_u8(&bytes, position + 17)?;
+        let name = read_str(&bytes, position + 18, name_length as usize)?;
+        // The cleanup policy is optional to keep the commands stored in the older state readable.
+        let cleanup_policy = match bytes.get(position + 18 + name_length as usize) {
+            Some(code) => CleanupPolicy::from_code(*code)?,
+            None => CleanupPolicy::default(),
+        };
+        // Same as the cleanup policy, the overrides are missing in the commands stored in the older state.
+        let overrides_position = position + 18 + name_length as usize + 1;
+        let overrides = if bytes.len() > overrides_position {
+            TopicOverrides::from_bytes(bytes.slice(overrides_position..))?
+        } else {
+            TopicOverrides::default()
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
@@ -138,6 +159,8 @@ impl BytesSerializable for UpdateTopic {
             message_expiry,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
+            overrides,
             name,
         };
         Ok(command)
@@ -148,12 +171,14 @@ impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry,
             self.max_topic_size,
             self.replication_factor.unwrap_or(0),
+            self.cleanup_policy,
+            self.overrides,
             self.name,
         )
     }
@@ -163,7 +188,9 @@ impl Display for UpdateTopic {
 mod tests {
     use super::*;
     use crate::utils::byte_size::IggyByteSize;
+    use crate::utils::timestamp_type::TimestampType;
     use bytes::BufMut;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -174,6 +201,13 @@ mod tests {
             message_expiry: IggyExpiry::NeverExpire,
             max_topic_size: MaxTopicSize::ServerDefault,
             replication_factor: Some(1),
+            cleanup_policy: CleanupPolicy::Compact,
+            overrides: TopicOverrides {
+                segment_size: Some(IggyByteSize::from(1_000_000)),
+                messages_required_to_save: Some(100),
+                cache_size: None,
+                message_timestamp_type: Some(TimestampType::CreateTime),
+            },
             name: "test".to_string(),
         };
 
@@ -195,6 +229,11 @@ mod tests {
         let name = from_utf8(&bytes[position + 18..position + 18 + name_length as usize])
             .unwrap()
             .to_string();
+        let cleanup_policy =
+            CleanupPolicy::from_code(bytes[position + 18 + name_length as usize]).unwrap();
+        let overrides =
+            TopicOverrides::from_bytes(bytes.slice(position + 19 + name_length as usize..))
+                .unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -205,6 +244,8 @@ mod tests {
         assert_eq!(replication_factor, command.replication_factor.unwrap());
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(overrides, command.overrides);
     }
 
     #[test]
@@ -216,6 +257,7 @@ mod tests {
         let message_expiry = IggyExpiry::NeverExpire;
         let max_topic_size = MaxTopicSize::Custom(IggyByteSize::from(100));
         let replication_factor = 1;
+        let cleanup_policy = CleanupPolicy::Compact;
 
         let stream_id_bytes = stream_id.to_bytes();
         let topic_id_bytes = topic_id.to_bytes();
@@ -231,6 +273,7 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.put_slice(name.as_bytes());
+        bytes.put_u8(cleanup_policy.as_code());
 
         let command = UpdateTopic::from_bytes(bytes.freeze());
         assert!(command.is_ok());
@@ -241,5 +284,7 @@ mod tests {
         assert_eq!(command.message_expiry, message_expiry);
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.overrides, TopicOverrides::default());
     }
 }
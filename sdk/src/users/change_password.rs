@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u8, BytesSerializable};
 use crate::command::{Command, CHANGE_PASSWORD_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -8,7 +8,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `ChangePassword` command is used to change a user's password.
 /// It has additional payload:
@@ -83,15 +82,13 @@ impl BytesSerializable for ChangePassword {
 
         let user_id = Identifier::from_bytes(bytes.clone())?;
         let mut position = user_id.get_size_bytes().as_bytes_usize();
-        let current_password_length = bytes[position];
+        let current_password_length = read_u8(&bytes, position)?;
         position += 1;
-        let current_password =
-            from_utf8(&bytes[position..position + current_password_length as usize])?.to_string();
+        let current_password = read_str(&bytes, position, current_password_length as usize)?;
         position += current_password_length as usize;
-        let new_password_length = bytes[position];
+        let new_password_length = read_u8(&bytes, position)?;
         position += 1;
-        let new_password =
-            from_utf8(&bytes[position..position + new_password_length as usize])?.to_string();
+        let new_password = read_str(&bytes, position, new_password_length as usize)?;
 
         let command = ChangePassword {
             user_id,
@@ -111,6 +108,7 @@ impl Display for ChangePassword {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_slice, read_str, read_u32_le, read_u8, BytesSerializable};
 use crate::command::{Command, CREATE_USER_CODE};
 use crate::error::IggyError;
 use crate::models::permissions::Permissions;
@@ -9,7 +9,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `CreateUser` command is used to create a new user.
 /// It has additional payload:
@@ -98,32 +97,24 @@ impl BytesSerializable for CreateUser {
         }
 
         let username_length = bytes[0];
-        let username = from_utf8(&bytes[1..1 + username_length as usize])?.to_string();
-        if username.len() != username_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
-
+        let username = read_str(&bytes, 1, username_length as usize)?;
         let mut position = 1 + username_length as usize;
-        let password_length = bytes[position];
+        let password_length = read_u8(&bytes, position)?;
         position += 1;
-        let password =
-            from_utf8(&bytes[position..position + password_length as usize])?.to_string();
-        if password.len() != password_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
-
+        let password = read_str(&bytes, position, password_length as usize)?;
         position += password_length as usize;
-        let status = UserStatus::from_code(bytes[position])?;
+        let status = UserStatus::from_code(read_u8(&bytes, position)?)?;
         position += 1;
-        let has_permissions = bytes[position];
+        let has_permissions = read_u8(&bytes, position)?;
         if has_permissions > 1 {
             return Err(IggyError::InvalidCommand);
         }
 
         position += 1;
         let permissions = if has_permissions == 1 {
-            let permissions_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+            let permissions_length = read_u32_le(&bytes, position)?;
             position += 4;
+            read_slice(&bytes, position, permissions_length as usize)?;
             Some(Permissions::from_bytes(
                 bytes.slice(position..position + permissions_length as usize),
             )?)
@@ -160,6 +151,7 @@ impl Display for CreateUser {
 mod tests {
     use super::*;
     use crate::models::permissions::GlobalPermissions;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
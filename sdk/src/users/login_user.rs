@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u32_le, read_u8, BytesSerializable};
 use crate::command::{Command, LOGIN_USER_CODE};
 use crate::error::IggyError;
 use crate::users::defaults::*;
@@ -7,7 +7,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `LoginUser` command is used to login a user by username and password.
 /// It has additional payload:
@@ -102,42 +101,25 @@ impl BytesSerializable for LoginUser {
         }
 
         let username_length = bytes[0];
-        let username = from_utf8(&bytes[1..=(username_length as usize)])?.to_string();
-        if username.len() != username_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
-
-        let password_length = bytes[1 + username_length as usize];
-        let password = from_utf8(
-            &bytes[2 + username_length as usize
-                ..2 + username_length as usize + password_length as usize],
-        )?
-        .to_string();
-        if password.len() != password_length as usize {
-            return Err(IggyError::InvalidCommand);
-        }
+        let username = read_str(&bytes, 1, username_length as usize)?;
+        let password_length = read_u8(&bytes, 1 + username_length as usize)?;
+        let password = read_str(
+            &bytes,
+            2 + username_length as usize,
+            password_length as usize,
+        )?;
 
         let position = 2 + username_length as usize + password_length as usize;
-        let version_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let version_length = read_u32_le(&bytes, position)?;
         let version = match version_length {
             0 => None,
-            _ => {
-                let version =
-                    from_utf8(&bytes[position + 4..position + 4 + version_length as usize])?
-                        .to_string();
-                Some(version)
-            }
+            _ => Some(read_str(&bytes, position + 4, version_length as usize)?),
         };
         let position = position + 4 + version_length as usize;
-        let context_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let context_length = read_u32_le(&bytes, position)?;
         let context = match context_length {
             0 => None,
-            _ => {
-                let context =
-                    from_utf8(&bytes[position + 4..position + 4 + context_length as usize])?
-                        .to_string();
-                Some(context)
-            }
+            _ => Some(read_str(&bytes, position + 4, context_length as usize)?),
         };
 
         let command = LoginUser {
@@ -159,6 +141,7 @@ impl Display for LoginUser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
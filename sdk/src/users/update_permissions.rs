@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_slice, read_u32_le, read_u8, BytesSerializable};
 use crate::command::{Command, UPDATE_PERMISSIONS_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -57,16 +57,16 @@ impl BytesSerializable for UpdatePermissions {
 
         let user_id = Identifier::from_bytes(bytes.clone())?;
         let mut position = user_id.get_size_bytes().as_bytes_usize();
-        let has_permissions = bytes[position];
+        let has_permissions = read_u8(&bytes, position)?;
         if has_permissions > 1 {
             return Err(IggyError::InvalidCommand);
         }
 
         position += 1;
         let permissions = if has_permissions == 1 {
-            let permissions_length =
-                u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+            let permissions_length = read_u32_le(&bytes, position)?;
             position += 4;
+            read_slice(&bytes, position, permissions_length as usize)?;
             let permissions = Permissions::from_bytes(
                 bytes.slice(position..position + permissions_length as usize),
             )?;
@@ -1,4 +1,4 @@
-use crate::bytes_serializable::BytesSerializable;
+use crate::bytes_serializable::{read_str, read_u8, BytesSerializable};
 use crate::command::{Command, UPDATE_USER_CODE};
 use crate::error::IggyError;
 use crate::identifier::Identifier;
@@ -10,7 +10,6 @@ use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes, BytesMut};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use std::str::from_utf8;
 
 /// `UpdateUser` command is used to update a user's username and status.
 /// It has additional payload:
@@ -83,31 +82,30 @@ impl BytesSerializable for UpdateUser {
 
         let user_id = Identifier::from_bytes(bytes.clone())?;
         let mut position = user_id.get_size_bytes().as_bytes_usize();
-        let has_username = bytes[position];
+        let has_username = read_u8(&bytes, position)?;
         if has_username > 1 {
             return Err(IggyError::InvalidCommand);
         }
 
         position += 1;
         let username = if has_username == 1 {
-            let username_length = bytes[position];
+            let username_length = read_u8(&bytes, position)?;
             position += 1;
-            let username =
-                from_utf8(&bytes[position..position + username_length as usize])?.to_string();
+            let username = read_str(&bytes, position, username_length as usize)?;
             position += username_length as usize;
             Some(username)
         } else {
             None
         };
 
-        let has_status = bytes[position];
+        let has_status = read_u8(&bytes, position)?;
         if has_status > 1 {
             return Err(IggyError::InvalidCommand);
         }
 
         let status = if has_status == 1 {
             position += 1;
-            let status = UserStatus::from_code(bytes[position])?;
+            let status = UserStatus::from_code(read_u8(&bytes, position)?)?;
             Some(status)
         } else {
             None
@@ -136,6 +134,7 @@ impl Display for UpdateUser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::from_utf8;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -1,3 +1,23 @@
+use crate::error::IggyError;
+
 pub fn calculate(data: &[u8]) -> u32 {
     crc32fast::hash(data)
 }
+
+/// Calculates the checksum of the data split into multiple parts, e.g. the frame header and its payload.
+pub fn calculate_parts(parts: &[&[u8]]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize()
+}
+
+/// Verifies the checksum of the frame sent over the wire, split into multiple parts.
+pub fn verify_frame(checksum: u32, parts: &[&[u8]]) -> Result<(), IggyError> {
+    let expected_checksum = calculate_parts(parts);
+    if checksum != expected_checksum {
+        return Err(IggyError::InvalidFrameChecksum(checksum, expected_checksum));
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `CleanupPolicy` represents the way the old messages of the topic are cleaned up.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupPolicy {
+    /// The whole segments are deleted once they expire or the topic size limit is reached.
+    #[default]
+    Delete,
+    /// The closed segments are compacted in the background, keeping only the latest message for each key (message ID).
+    Compact,
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "delete" => Ok(CleanupPolicy::Delete),
+            "compact" => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCleanupPolicy),
+        }
+    }
+}
+
+impl Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupPolicy::Delete => write!(f, "delete"),
+            CleanupPolicy::Compact => write!(f, "compact"),
+        }
+    }
+}
+
+impl CleanupPolicy {
+    /// Returns the code of the cleanup policy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            CleanupPolicy::Delete => 1,
+            CleanupPolicy::Compact => 2,
+        }
+    }
+
+    /// Returns the cleanup policy from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(CleanupPolicy::Delete),
+            2 => Ok(CleanupPolicy::Compact),
+            _ => Err(IggyError::InvalidCleanupPolicy),
+        }
+    }
+
+    /// Returns `true` if the topic is compacted.
+    pub fn is_compact(&self) -> bool {
+        *self == CleanupPolicy::Compact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            CleanupPolicy::from_str("delete").unwrap(),
+            CleanupPolicy::Delete
+        );
+        assert_eq!(
+            CleanupPolicy::from_str("Compact").unwrap(),
+            CleanupPolicy::Compact
+        );
+        assert!(CleanupPolicy::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_code() {
+        for policy in [CleanupPolicy::Delete, CleanupPolicy::Compact] {
+            assert_eq!(CleanupPolicy::from_code(policy.as_code()).unwrap(), policy);
+        }
+        assert!(CleanupPolicy::from_code(0).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::from_utf8;
+use std::str::FromStr;
+
+/// The name of the message header which holds the content type of the payload.
+pub const CONTENT_TYPE_HEADER: &str = "content-type";
+
+/// `ContentType` represents the format of the message payload, recorded in the `content-type` header.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    /// Opaque binary payload (`application/octet-stream`).
+    #[default]
+    Binary,
+    /// JSON document (`application/json`).
+    Json,
+    /// UTF-8 encoded text (`text/plain`).
+    Text,
+}
+
+impl FromStr for ContentType {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mime = input.split(';').next().unwrap_or_default().trim();
+        match mime.to_lowercase().as_str() {
+            "application/octet-stream" | "binary" => Ok(ContentType::Binary),
+            "application/json" | "json" => Ok(ContentType::Json),
+            "text/plain" | "text" => Ok(ContentType::Text),
+            _ => Err(IggyError::InvalidContentType),
+        }
+    }
+}
+
+impl Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentType::Binary => write!(f, "application/octet-stream"),
+            ContentType::Json => write!(f, "application/json"),
+            ContentType::Text => write!(f, "text/plain"),
+        }
+    }
+}
+
+impl ContentType {
+    /// Returns the header key and value representing the content type.
+    pub fn to_header(&self) -> Result<(HeaderKey, HeaderValue), IggyError> {
+        Ok((
+            HeaderKey::new(CONTENT_TYPE_HEADER)?,
+            HeaderValue::from_str(&self.to_string())?,
+        ))
+    }
+
+    /// Returns the content type recorded in the provided headers, if any.
+    pub fn from_headers(
+        headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    ) -> Result<Option<Self>, IggyError> {
+        let Some(headers) = headers else {
+            return Ok(None);
+        };
+
+        let Some(value) = headers.get(&HeaderKey::new(CONTENT_TYPE_HEADER)?) else {
+            return Ok(None);
+        };
+
+        let value = value.as_str().map_err(|_| IggyError::InvalidContentType)?;
+        Ok(Some(ContentType::from_str(value)?))
+    }
+
+    /// Validates that the payload is encoded according to the content type.
+    pub fn validate_payload(&self, payload: &[u8]) -> Result<(), IggyError> {
+        match self {
+            ContentType::Binary => Ok(()),
+            ContentType::Json => serde_json::from_slice::<serde::de::IgnoredAny>(payload)
+                .map(|_| ())
+                .map_err(|error| IggyError::PayloadContentTypeMismatch(error.to_string())),
+            ContentType::Text => from_utf8(payload)
+                .map(|_| ())
+                .map_err(|error| IggyError::PayloadContentTypeMismatch(error.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_mime_type() {
+        assert_eq!(
+            ContentType::from_str("application/json; charset=utf-8").unwrap(),
+            ContentType::Json
+        );
+        assert_eq!(
+            ContentType::from_str("Text/Plain").unwrap(),
+            ContentType::Text
+        );
+        assert_eq!(
+            ContentType::from_str("application/octet-stream").unwrap(),
+            ContentType::Binary
+        );
+        assert!(ContentType::from_str("image/png").is_err());
+    }
+
+    #[test]
+    fn should_be_read_from_headers() {
+        let (key, value) = ContentType::Json.to_header().unwrap();
+        let headers = Some(HashMap::from([(key, value)]));
+        assert_eq!(
+            ContentType::from_headers(&headers).unwrap(),
+            Some(ContentType::Json)
+        );
+        assert_eq!(ContentType::from_headers(&None).unwrap(), None);
+    }
+
+    #[test]
+    fn should_validate_payload() {
+        assert!(ContentType::Json.validate_payload(br#"{"id":1}"#).is_ok());
+        assert!(ContentType::Json.validate_payload(b"{id").is_err());
+        assert!(ContentType::Text.validate_payload(b"hello").is_ok());
+        assert!(ContentType::Text.validate_payload(&[0xff, 0xfe]).is_err());
+        assert!(ContentType::Binary.validate_payload(&[0xff, 0xfe]).is_ok());
+    }
+}
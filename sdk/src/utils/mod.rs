@@ -1,10 +1,17 @@
 pub mod byte_size;
 pub mod checksum;
+pub mod cleanup_policy;
+pub mod content_type;
 pub mod crypto;
 pub mod duration;
 pub mod expiry;
+pub mod payload_format;
 pub mod personal_access_token_expiry;
+pub mod rebalance_strategy;
+pub mod schema_type;
 pub mod sizeable;
 pub mod text;
 pub mod timestamp;
+pub mod timestamp_type;
+pub mod topic_overrides;
 pub mod topic_size;
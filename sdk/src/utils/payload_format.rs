@@ -0,0 +1,40 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `PayloadFormat` specifies how the message payloads are returned by the HTTP API when polling the messages.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadFormat {
+    /// Payload encoded as base64 string.
+    #[default]
+    Base64,
+    /// Payload parsed as JSON document, based on the content type of the message.
+    /// JSON payloads (or the ones without the content type) are returned as documents, text payloads as strings and binary payloads as base64 strings.
+    Json,
+    /// Payload returned as UTF-8 string.
+    Raw,
+}
+
+impl FromStr for PayloadFormat {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "base64" => Ok(PayloadFormat::Base64),
+            "json" => Ok(PayloadFormat::Json),
+            "raw" => Ok(PayloadFormat::Raw),
+            _ => Err(IggyError::InvalidPayloadFormat),
+        }
+    }
+}
+
+impl Display for PayloadFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PayloadFormat::Base64 => write!(f, "base64"),
+            PayloadFormat::Json => write!(f, "json"),
+            PayloadFormat::Raw => write!(f, "raw"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `RebalanceStrategy` represents the way the partitions of the topic are reassigned to the members of the consumer group,
+/// whenever a member joins or leaves the group, or the partitions are added to or deleted from the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum RebalanceStrategy {
+    /// All the partitions are revoked from the members and assigned again using the round-robin.
+    #[default]
+    Eager,
+    /// The members keep their partitions, and only the ones needed to balance the group are moved to the other members.
+    Sticky,
+}
+
+impl FromStr for RebalanceStrategy {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "eager" => Ok(RebalanceStrategy::Eager),
+            "sticky" => Ok(RebalanceStrategy::Sticky),
+            _ => Err(IggyError::InvalidRebalanceStrategy),
+        }
+    }
+}
+
+impl Display for RebalanceStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RebalanceStrategy::Eager => write!(f, "eager"),
+            RebalanceStrategy::Sticky => write!(f, "sticky"),
+        }
+    }
+}
+
+impl RebalanceStrategy {
+    /// Returns the code of the rebalance strategy.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            RebalanceStrategy::Eager => 1,
+            RebalanceStrategy::Sticky => 2,
+        }
+    }
+
+    /// Returns the rebalance strategy from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(RebalanceStrategy::Eager),
+            2 => Ok(RebalanceStrategy::Sticky),
+            _ => Err(IggyError::InvalidRebalanceStrategy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            RebalanceStrategy::from_str("eager").unwrap(),
+            RebalanceStrategy::Eager
+        );
+        assert_eq!(
+            RebalanceStrategy::from_str("Sticky").unwrap(),
+            RebalanceStrategy::Sticky
+        );
+        assert!(RebalanceStrategy::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_code() {
+        for strategy in [RebalanceStrategy::Eager, RebalanceStrategy::Sticky] {
+            assert_eq!(
+                RebalanceStrategy::from_code(strategy.as_code()).unwrap(),
+                strategy
+            );
+        }
+        assert!(RebalanceStrategy::from_code(0).is_err());
+    }
+}
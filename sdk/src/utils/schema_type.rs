@@ -0,0 +1,96 @@
+use crate::error::IggyError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// `SchemaType` represents the format of the schema registered for the topic.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaType {
+    /// JSON Schema definition, the only type which supports the payload validation on the server.
+    #[default]
+    JsonSchema,
+    /// Avro schema definition (JSON encoded).
+    Avro,
+    /// Protobuf schema definition (`.proto` file content).
+    Protobuf,
+}
+
+impl FromStr for SchemaType {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "json_schema" | "json" => Ok(SchemaType::JsonSchema),
+            "avro" => Ok(SchemaType::Avro),
+            "protobuf" | "proto" => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidSchemaType),
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaType::JsonSchema => write!(f, "json_schema"),
+            SchemaType::Avro => write!(f, "avro"),
+            SchemaType::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
+
+impl SchemaType {
+    /// Returns the code of the schema type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            SchemaType::JsonSchema => 1,
+            SchemaType::Avro => 2,
+            SchemaType::Protobuf => 3,
+        }
+    }
+
+    /// Returns the schema type from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(SchemaType::JsonSchema),
+            2 => Ok(SchemaType::Avro),
+            3 => Ok(SchemaType::Protobuf),
+            _ => Err(IggyError::InvalidSchemaType),
+        }
+    }
+
+    /// Returns `true` if the payloads can be validated against the schema of this type on the server.
+    pub fn supports_validation(&self) -> bool {
+        *self == SchemaType::JsonSchema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            SchemaType::from_str("json_schema").unwrap(),
+            SchemaType::JsonSchema
+        );
+        assert_eq!(SchemaType::from_str("Avro").unwrap(), SchemaType::Avro);
+        assert_eq!(SchemaType::from_str("proto").unwrap(), SchemaType::Protobuf);
+        assert!(SchemaType::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn should_be_converted_from_code() {
+        for schema_type in [
+            SchemaType::JsonSchema,
+            SchemaType::Avro,
+            SchemaType::Protobuf,
+        ] {
+            assert_eq!(
+                SchemaType::from_code(schema_type.as_code()).unwrap(),
+                schema_type
+            );
+        }
+        assert!(SchemaType::from_code(0).is_err());
+    }
+}
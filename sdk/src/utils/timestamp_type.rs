@@ -0,0 +1,151 @@
+use crate::error::IggyError;
+use crate::models::header::{HeaderKey, HeaderValue};
+use crate::utils::timestamp::IggyTimestamp;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The name of the message header which holds the timestamp (in microseconds) at which the message was created by the producer.
+pub const CREATE_TIMESTAMP_HEADER: &str = "create-timestamp";
+/// The name of the message header which holds the timestamp (in microseconds) at which the message was appended to the partition,
+/// recorded by the server when the message timestamp is the create time.
+pub const APPEND_TIMESTAMP_HEADER: &str = "append-timestamp";
+
+/// `TimestampType` represents which of the timestamps is stored as the message timestamp,
+/// used for polling by the timestamp, the time index and the message expiry.
+/// The other timestamp (if available) is kept in the message headers, so that both of them are stored.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampType {
+    /// The timestamp provided by the producer in the `create-timestamp` header, or the append time if the header is missing.
+    CreateTime,
+    /// The timestamp at which the message was appended to the partition.
+    #[default]
+    LogAppendTime,
+}
+
+impl FromStr for TimestampType {
+    type Err = IggyError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "create_time" | "create" => Ok(TimestampType::CreateTime),
+            "log_append_time" | "append" => Ok(TimestampType::LogAppendTime),
+            _ => Err(IggyError::InvalidTimestampType),
+        }
+    }
+}
+
+impl Display for TimestampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampType::CreateTime => write!(f, "create_time"),
+            TimestampType::LogAppendTime => write!(f, "log_append_time"),
+        }
+    }
+}
+
+impl TimestampType {
+    /// Returns the code of the timestamp type.
+    pub fn as_code(&self) -> u8 {
+        match self {
+            TimestampType::CreateTime => 1,
+            TimestampType::LogAppendTime => 2,
+        }
+    }
+
+    /// Returns the timestamp type from the code.
+    pub fn from_code(code: u8) -> Result<Self, IggyError> {
+        match code {
+            1 => Ok(TimestampType::CreateTime),
+            2 => Ok(TimestampType::LogAppendTime),
+            _ => Err(IggyError::InvalidTimestampType),
+        }
+    }
+}
+
+/// Returns the header key and value holding the create timestamp of the message, to be set by the producer.
+pub fn create_timestamp_header(
+    timestamp: IggyTimestamp,
+) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    Ok((
+        HeaderKey::new(CREATE_TIMESTAMP_HEADER)?,
+        HeaderValue::from_uint64(timestamp.as_micros())?,
+    ))
+}
+
+/// Returns the header key and value holding the append timestamp of the message, recorded by the server.
+pub fn append_timestamp_header(
+    timestamp: IggyTimestamp,
+) -> Result<(HeaderKey, HeaderValue), IggyError> {
+    Ok((
+        HeaderKey::new(APPEND_TIMESTAMP_HEADER)?,
+        HeaderValue::from_uint64(timestamp.as_micros())?,
+    ))
+}
+
+/// Returns the create timestamp recorded in the provided headers, if any.
+pub fn get_create_timestamp(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u64>, IggyError> {
+    get_timestamp(headers, CREATE_TIMESTAMP_HEADER)
+}
+
+/// Returns the append timestamp recorded in the provided headers, if any.
+pub fn get_append_timestamp(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+) -> Result<Option<u64>, IggyError> {
+    get_timestamp(headers, APPEND_TIMESTAMP_HEADER)
+}
+
+fn get_timestamp(
+    headers: &Option<HashMap<HeaderKey, HeaderValue>>,
+    name: &str,
+) -> Result<Option<u64>, IggyError> {
+    let Some(headers) = headers else {
+        return Ok(None);
+    };
+
+    let Some(value) = headers.get(&HeaderKey::new(name)?) else {
+        return Ok(None);
+    };
+
+    value.as_uint64().map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_parsed_from_str() {
+        assert_eq!(
+            TimestampType::from_str("create_time").unwrap(),
+            TimestampType::CreateTime
+        );
+        assert_eq!(
+            TimestampType::from_str("Log_Append_Time").unwrap(),
+            TimestampType::LogAppendTime
+        );
+        assert!(TimestampType::from_str("unknown").is_err());
+    }
+
+    #[test]
+    fn create_timestamp_should_be_read_from_headers() {
+        let (key, value) = create_timestamp_header(IggyTimestamp::from(1000)).unwrap();
+        let headers = Some(HashMap::from([(key, value)]));
+
+        assert_eq!(get_create_timestamp(&headers).unwrap(), Some(1000));
+        assert_eq!(get_append_timestamp(&headers).unwrap(), None);
+        assert_eq!(get_create_timestamp(&None).unwrap(), None);
+
+        let headers = Some(HashMap::from([(
+            HeaderKey::new(CREATE_TIMESTAMP_HEADER).unwrap(),
+            HeaderValue::from_str("yesterday").unwrap(),
+        )]));
+        assert!(matches!(
+            get_create_timestamp(&headers),
+            Err(IggyError::InvalidHeaderValue)
+        ));
+    }
+}
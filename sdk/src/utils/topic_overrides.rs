@@ -0,0 +1,144 @@
+use crate::bytes_serializable::{read_u32_le, read_u64_le, BytesSerializable};
+use crate::error::IggyError;
+use crate::utils::byte_size::IggyByteSize;
+use crate::utils::timestamp_type::TimestampType;
+use bytes::{BufMut, Bytes, BytesMut};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// The size of the serialized overrides: segment size (8), messages required to save (4), cache size (8) and message timestamp type (1).
+pub const TOPIC_OVERRIDES_SIZE: usize = 21;
+/// The size of the overrides serialized by the older versions, without the message timestamp type.
+const LEGACY_TOPIC_OVERRIDES_SIZE: usize = 20;
+
+/// `TopicOverrides` represents the server configuration values overridden for a single topic.
+/// Each of the values is optional, and if not set, the topic inherits the server default.
+/// - `segment_size` - the maximum size of a single segment of the topic partitions.
+/// - `messages_required_to_save` - the number of buffered messages of the partition which triggers saving them to disk.
+/// - `cache_size` - the maximum size of the cached messages of each partition of the topic.
+/// - `message_timestamp_type` - whether the message timestamp is the create time provided by the producer or the append time.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+pub struct TopicOverrides {
+    /// The maximum size of a single segment, inherited from the server if not set.
+    #[serde(default)]
+    pub segment_size: Option<IggyByteSize>,
+    /// The number of buffered messages which triggers saving them to disk, inherited from the server if not set.
+    #[serde(default)]
+    pub messages_required_to_save: Option<u32>,
+    /// The maximum size of the cached messages of each partition, inherited from the server if not set.
+    #[serde(default)]
+    pub cache_size: Option<IggyByteSize>,
+    /// Whether the message timestamp is the create time provided by the producer or the append time, inherited from the server if not set.
+    #[serde(default)]
+    pub message_timestamp_type: Option<TimestampType>,
+}
+
+impl TopicOverrides {
+    /// Returns `true` if none of the server defaults is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.segment_size.is_none()
+            && self.messages_required_to_save.is_none()
+            && self.cache_size.is_none()
+            && self.message_timestamp_type.is_none()
+    }
+}
+
+impl BytesSerializable for TopicOverrides {
+    fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(TOPIC_OVERRIDES_SIZE);
+        bytes.put_u64_le(self.segment_size.map_or(0, |size| size.as_bytes_u64()));
+        bytes.put_u32_le(self.messages_required_to_save.unwrap_or(0));
+        bytes.put_u64_le(self.cache_size.map_or(0, |size| size.as_bytes_u64()));
+        bytes.put_u8(
+            self.message_timestamp_type
+                .map_or(0, |timestamp_type| timestamp_type.as_code()),
+        );
+        bytes.freeze()
+    }
+
+    fn from_bytes(bytes: Bytes) -> Result<TopicOverrides, IggyError> {
+        if bytes.len() != TOPIC_OVERRIDES_SIZE && bytes.len() != LEGACY_TOPIC_OVERRIDES_SIZE {
+            return Err(IggyError::InvalidCommand);
+        }
+
+        let segment_size = match read_u64_le(&bytes, 0)? {
+            0 => None,
+            size => Some(IggyByteSize::from(size)),
+        };
+        let messages_required_to_save = match read_u32_le(&bytes, 8)? {
+            0 => None,
+            count => Some(count),
+        };
+        let cache_size = match read_u64_le(&bytes, 12)? {
+            0 => None,
+            size => Some(IggyByteSize::from(size)),
+        };
+        let message_timestamp_type = match bytes.get(LEGACY_TOPIC_OVERRIDES_SIZE) {
+            None | Some(0) => None,
+            Some(code) => Some(TimestampType::from_code(*code)?),
+        };
+        Ok(TopicOverrides {
+            segment_size,
+            messages_required_to_save,
+            cache_size,
+            message_timestamp_type,
+        })
+    }
+}
+
+impl Display for TopicOverrides {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.segment_size.map_or(0, |size| size.as_bytes_u64()),
+            self.messages_required_to_save.unwrap_or(0),
+            self.cache_size.map_or(0, |size| size.as_bytes_u64()),
+            self.message_timestamp_type
+                .map_or(0, |timestamp_type| timestamp_type.as_code())
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_and_deserialized() {
+        let overrides = TopicOverrides {
+            segment_size: Some(IggyByteSize::from(1_000_000)),
+            messages_required_to_save: None,
+            cache_size: Some(IggyByteSize::from(2_000_000)),
+            message_timestamp_type: Some(TimestampType::CreateTime),
+        };
+
+        let bytes = overrides.to_bytes();
+
+        assert_eq!(bytes.len(), TOPIC_OVERRIDES_SIZE);
+        assert_eq!(TopicOverrides::from_bytes(bytes).unwrap(), overrides);
+    }
+
+    #[test]
+    fn should_inherit_all_the_values_by_default() {
+        let overrides = TopicOverrides::default();
+
+        assert!(overrides.is_empty());
+        assert_eq!(
+            TopicOverrides::from_bytes(overrides.to_bytes()).unwrap(),
+            overrides
+        );
+    }
+
+    #[test]
+    fn should_be_deserialized_from_legacy_bytes_without_timestamp_type() {
+        let overrides = TopicOverrides {
+            segment_size: Some(IggyByteSize::from(1_000_000)),
+            ..Default::default()
+        };
+
+        let bytes = overrides.to_bytes().slice(..LEGACY_TOPIC_OVERRIDES_SIZE);
+
+        assert_eq!(TopicOverrides::from_bytes(bytes).unwrap(), overrides);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::log::format::LogFormat;
 use clap::Parser;
 
 #[derive(Parser, Debug)]
@@ -5,4 +6,16 @@ use clap::Parser;
 pub struct Args {
     #[arg(short, long, default_value = "file")]
     pub config_provider: String,
+
+    /// Path of the file storing the process ID, created on startup and removed on shutdown
+    #[arg(long)]
+    pub pid_file: Option<String>,
+
+    /// Format of the logs printed to stdout and stored in the log files
+    #[arg(long, value_enum, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+
+    /// Report the data directory migrations pending on startup, without applying them, and exit
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
 }
@@ -0,0 +1,436 @@
+use crate::command::ServerCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::command::*;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+
+type GlobalRule = fn(&Permissioner, u32) -> Result<(), IggyError>;
+type StreamRule = fn(&Permissioner, u32, u32) -> Result<(), IggyError>;
+type TopicRule = fn(&Permissioner, u32, u32, u32) -> Result<(), IggyError>;
+
+/// The permission required to invoke the command, checked by the dispatcher before the command handler is executed.
+/// The system keeps checking the permissions on its own, as it's shared with the HTTP API, and might also apply
+/// the stricter rules depending on the command payload (e.g. changing the password of another user).
+#[derive(Clone, Copy)]
+pub(crate) enum Requirement {
+    /// The command can be invoked by anyone, e.g. to ping the server or to log in.
+    Anonymous,
+    /// The command can be invoked by any authenticated user, e.g. to manage own personal access tokens.
+    Authenticated,
+    /// The command requires the global permission.
+    Global(GlobalRule),
+    /// The command requires the permission to the stream.
+    Stream(StreamRule),
+    /// The command requires the permission to the topic.
+    Topic(TopicRule),
+}
+
+/// Returns the permission required to invoke the command with the given code, or `None` if the code is unknown.
+pub(crate) fn get_requirement(code: u32) -> Option<Requirement> {
+    use Requirement::*;
+    let requirement = match code {
+        PING_CODE => Anonymous,
+        GET_STATS_CODE => Global(Permissioner::get_stats),
+        GET_SNAPSHOT_FILE_CODE => Authenticated,
+        ROTATE_ENCRYPTION_KEY_CODE => Global(Permissioner::rotate_encryption_key),
+        SET_LOG_LEVEL_CODE => Global(Permissioner::set_log_level),
+        SET_BACKGROUND_IO_RATE_CODE => Global(Permissioner::set_background_io_rate),
+        // The partitions of the topics the user has no access to are skipped by the system.
+        GET_CLUSTER_METADATA_CODE => Authenticated,
+        DRAIN_NODE_CODE => Global(Permissioner::drain_node),
+        DECOMMISSION_NODE_CODE => Global(Permissioner::decommission_node),
+        GET_NODE_STATUS_CODE => Global(Permissioner::get_node_status),
+        GET_ME_CODE => Authenticated,
+        GET_CLIENT_CODE => Global(Permissioner::get_client),
+        GET_CLIENTS_CODE => Global(Permissioner::get_clients),
+        GET_SESSIONS_CODE => Global(Permissioner::get_sessions),
+        KILL_SESSION_CODE => Global(Permissioner::kill_session),
+        GET_USER_CODE => Authenticated,
+        GET_USERS_CODE => Global(Permissioner::get_users),
+        CREATE_USER_CODE => Global(Permissioner::create_user),
+        DELETE_USER_CODE => Global(Permissioner::delete_user),
+        UPDATE_USER_CODE => Global(Permissioner::update_user),
+        UPDATE_PERMISSIONS_CODE => Global(Permissioner::update_permissions),
+        CHANGE_PASSWORD_CODE => Authenticated,
+        LOGIN_USER_CODE => Anonymous,
+        LOGOUT_USER_CODE => Authenticated,
+        GET_PERSONAL_ACCESS_TOKENS_CODE => Authenticated,
+        CREATE_PERSONAL_ACCESS_TOKEN_CODE => Authenticated,
+        DELETE_PERSONAL_ACCESS_TOKEN_CODE => Authenticated,
+        LOGIN_WITH_PERSONAL_ACCESS_TOKEN_CODE => Anonymous,
+        POLL_MESSAGES_CODE => Topic(Permissioner::poll_messages),
+        SEND_MESSAGES_CODE => Topic(Permissioner::append_messages),
+        FLUSH_UNSAVED_BUFFER_CODE => Topic(Permissioner::append_messages),
+        POLL_MESSAGES_FROM_PARTITIONS_CODE => Topic(Permissioner::poll_messages),
+        PEEK_MESSAGES_CODE => Topic(Permissioner::poll_messages),
+        GET_MESSAGE_BY_KEY_CODE => Topic(Permissioner::poll_messages),
+        // Replication affects the visibility of the messages to all the consumers, so it's reserved for the cluster nodes.
+        FETCH_REPLICA_MESSAGES_CODE => Global(Permissioner::fetch_replica_messages),
+        ACQUIRE_EXCLUSIVE_PRODUCER_CODE => Topic(Permissioner::append_messages),
+        INIT_PRODUCER_EPOCH_CODE => Topic(Permissioner::append_messages),
+        // The permission to send the messages to the target topic is checked by the system.
+        COPY_MESSAGES_CODE => Topic(Permissioner::poll_messages),
+        GET_CONSUMER_OFFSET_CODE => Topic(Permissioner::get_consumer_offset),
+        STORE_CONSUMER_OFFSET_CODE => Topic(Permissioner::store_consumer_offset),
+        GET_CONSUMER_LAG_CODE => Topic(Permissioner::get_consumer_offset),
+        // The offsets are stored unless it's a dry run, which is checked by the system.
+        RESET_CONSUMER_OFFSETS_CODE => Topic(Permissioner::get_consumer_offset),
+        GET_CONSUMERS_CODE => Topic(Permissioner::get_consumer_offset),
+        DELETE_CONSUMER_CODE => Topic(Permissioner::store_consumer_offset),
+        DELETE_CONSUMER_OFFSETS_CODE => Topic(Permissioner::store_consumer_offset),
+        GET_STREAM_CODE => Stream(Permissioner::get_stream),
+        GET_STREAMS_CODE => Global(Permissioner::get_streams),
+        CREATE_STREAM_CODE => Global(Permissioner::create_stream),
+        DELETE_STREAM_CODE => Stream(Permissioner::delete_stream),
+        UPDATE_STREAM_CODE => Stream(Permissioner::update_stream),
+        PURGE_STREAM_CODE => Stream(Permissioner::purge_stream),
+        GET_TOPIC_CODE => Topic(Permissioner::get_topic),
+        GET_TOPICS_CODE => Stream(Permissioner::get_topics),
+        CREATE_TOPIC_CODE => Stream(Permissioner::create_topic),
+        DELETE_TOPIC_CODE => Topic(Permissioner::delete_topic),
+        UPDATE_TOPIC_CODE => Topic(Permissioner::update_topic),
+        PURGE_TOPIC_CODE => Topic(Permissioner::purge_topic),
+        SET_TOPIC_READ_ONLY_CODE => Topic(Permissioner::update_topic),
+        GET_SCHEMA_CODE => Topic(Permissioner::get_schemas),
+        GET_SCHEMAS_CODE => Topic(Permissioner::get_schemas),
+        REGISTER_SCHEMA_CODE => Topic(Permissioner::register_schema),
+        GET_PARTITION_CODE => Topic(Permissioner::get_topic),
+        CREATE_PARTITIONS_CODE => Topic(Permissioner::create_partitions),
+        DELETE_PARTITIONS_CODE => Topic(Permissioner::delete_partitions),
+        GET_PARTITION_OFFSETS_CODE => Topic(Permissioner::get_topic),
+        GET_OFFSET_BY_TIMESTAMP_CODE => Topic(Permissioner::get_topic),
+        GET_MESSAGES_HISTOGRAM_CODE => Topic(Permissioner::get_topic),
+        SEAL_PARTITION_CODE => Topic(Permissioner::update_topic),
+        GET_CONSUMER_GROUP_CODE => Topic(Permissioner::get_consumer_group),
+        GET_CONSUMER_GROUPS_CODE => Topic(Permissioner::get_consumer_groups),
+        CREATE_CONSUMER_GROUP_CODE => Topic(Permissioner::create_consumer_group),
+        DELETE_CONSUMER_GROUP_CODE => Topic(Permissioner::delete_consumer_group),
+        JOIN_CONSUMER_GROUP_CODE => Topic(Permissioner::join_consumer_group),
+        LEAVE_CONSUMER_GROUP_CODE => Topic(Permissioner::leave_consumer_group),
+        HEARTBEAT_CONSUMER_GROUP_CODE => Topic(Permissioner::join_consumer_group),
+        _ => return None,
+    };
+    Some(requirement)
+}
+
+impl Requirement {
+    /// Checks the permission of the user to the resource. The stream and topic IDs are required only by the scoped permissions,
+    /// and if they're missing, the check is skipped, so that the command handler can report the resource as not found.
+    fn check(
+        &self,
+        permissioner: &Permissioner,
+        user_id: u32,
+        stream_id: Option<u32>,
+        topic_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        match (self, stream_id, topic_id) {
+            (Requirement::Anonymous | Requirement::Authenticated, _, _) => Ok(()),
+            (Requirement::Global(rule), _, _) => rule(permissioner, user_id),
+            (Requirement::Stream(rule), Some(stream_id), _) => {
+                rule(permissioner, user_id, stream_id)
+            }
+            (Requirement::Topic(rule), Some(stream_id), Some(topic_id)) => {
+                rule(permissioner, user_id, stream_id, topic_id)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Rejects the command if the session is not authenticated or the user lacks the permission required by the command.
+pub(crate) fn authorize(
+    command: &ServerCommand,
+    session: &Session,
+    system: &System,
+) -> Result<(), IggyError> {
+    let Some(requirement) = get_requirement(command.code()) else {
+        return Err(IggyError::InvalidCommand);
+    };
+    if let Requirement::Anonymous = requirement {
+        return Ok(());
+    }
+
+    system.ensure_authenticated(session)?;
+    let (stream_id, topic_id) = match get_resource(command) {
+        (Some(stream_id), topic_id) => match system.get_stream(stream_id) {
+            Ok(stream) => (
+                Some(stream.stream_id),
+                topic_id
+                    .and_then(|topic_id| stream.get_topic(topic_id).ok())
+                    .map(|topic| topic.topic_id),
+            ),
+            Err(_) => (None, None),
+        },
+        _ => (None, None),
+    };
+    requirement.check(
+        &system.permissioner,
+        session.get_user_id(),
+        stream_id,
+        topic_id,
+    )
+}
+
+fn get_resource(command: &ServerCommand) -> (Option<&Identifier>, Option<&Identifier>) {
+    match command {
+        ServerCommand::GetStream(command) => (Some(&command.stream_id), None),
+        ServerCommand::UpdateStream(command) => (Some(&command.stream_id), None),
+        ServerCommand::DeleteStream(command) => (Some(&command.stream_id), None),
+        ServerCommand::PurgeStream(command) => (Some(&command.stream_id), None),
+        ServerCommand::GetTopics(command) => (Some(&command.stream_id), None),
+        ServerCommand::CreateTopic(command) => (Some(&command.stream_id), None),
+        ServerCommand::GetTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::UpdateTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::DeleteTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::PurgeTopic(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::SetTopicReadOnly(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetSchema(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::GetSchemas(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::RegisterSchema(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetPartition(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::CreatePartitions(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::DeletePartitions(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetPartitionOffsets(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetOffsetByTimestamp(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetMessagesHistogram(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::SealPartition(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::SendMessages(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::PollMessages(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::PollMessagesFromPartitions(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::PeekMessages(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::GetMessageByKey(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::FetchReplicaMessages(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::FlushUnsavedBuffer(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::AcquireExclusiveProducer(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::InitProducerEpoch(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::CopyMessages(command) => (
+            Some(&command.source_stream_id),
+            Some(&command.source_topic_id),
+        ),
+        ServerCommand::GetConsumerOffset(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::StoreConsumerOffset(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetConsumerLag(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::ResetConsumerOffsets(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetConsumers(command) => (Some(&command.stream_id), Some(&command.topic_id)),
+        ServerCommand::DeleteConsumer(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::DeleteConsumerOffsets(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::GetConsumerGroups(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::CreateConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::DeleteConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::JoinConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::LeaveConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        ServerCommand::HeartbeatConsumerGroup(command) => {
+            (Some(&command.stream_id), Some(&command.topic_id))
+        }
+        _ => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ahash::AHashMap;
+    use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
+
+    const STREAM_ID: u32 = 1;
+    const TOPIC_ID: u32 = 1;
+    const NO_PERMISSIONS: u32 = 1;
+    const READ_SERVERS: u32 = 2;
+    const MANAGE_SERVERS: u32 = 3;
+    const READ_USERS: u32 = 4;
+    const MANAGE_USERS: u32 = 5;
+    const READ_STREAMS: u32 = 6;
+    const MANAGE_STREAMS: u32 = 7;
+    const POLL_MESSAGES: u32 = 8;
+    const SEND_MESSAGES: u32 = 9;
+    const READ_STREAM: u32 = 10;
+    const MANAGE_STREAM: u32 = 11;
+    const ALL_USERS: [u32; 11] = [
+        NO_PERMISSIONS,
+        READ_SERVERS,
+        MANAGE_SERVERS,
+        READ_USERS,
+        MANAGE_USERS,
+        READ_STREAMS,
+        MANAGE_STREAMS,
+        POLL_MESSAGES,
+        SEND_MESSAGES,
+        READ_STREAM,
+        MANAGE_STREAM,
+    ];
+
+    fn global(set: fn(&mut GlobalPermissions)) -> Option<Permissions> {
+        let mut global = GlobalPermissions::default();
+        set(&mut global);
+        Some(Permissions {
+            global,
+            streams: None,
+        })
+    }
+
+    fn stream(set: fn(&mut StreamPermissions)) -> Option<Permissions> {
+        let mut stream = StreamPermissions::default();
+        set(&mut stream);
+        Some(Permissions {
+            global: GlobalPermissions::default(),
+            streams: Some(AHashMap::from([(STREAM_ID, stream)])),
+        })
+    }
+
+    fn init_permissioner() -> Permissioner {
+        let mut permissioner = Permissioner::default();
+        let users = [
+            (NO_PERMISSIONS, None),
+            (READ_SERVERS, global(|p| p.read_servers = true)),
+            (MANAGE_SERVERS, global(|p| p.manage_servers = true)),
+            (READ_USERS, global(|p| p.read_users = true)),
+            (MANAGE_USERS, global(|p| p.manage_users = true)),
+            (READ_STREAMS, global(|p| p.read_streams = true)),
+            (MANAGE_STREAMS, global(|p| p.manage_streams = true)),
+            (POLL_MESSAGES, global(|p| p.poll_messages = true)),
+            (SEND_MESSAGES, global(|p| p.send_messages = true)),
+            (READ_STREAM, stream(|p| p.read_stream = true)),
+            (MANAGE_STREAM, stream(|p| p.manage_stream = true)),
+        ];
+        for (user_id, permissions) in users {
+            permissioner.init_permissions_for_user(user_id, permissions);
+        }
+        permissioner
+    }
+
+    #[test]
+    fn every_command_should_have_requirement() {
+        for code in 0..1000 {
+            assert_eq!(
+                get_name_from_code(code).is_ok(),
+                get_requirement(code).is_some(),
+                "Command with code: {code} should have the requirement only if it exists."
+            );
+        }
+    }
+
+    #[test]
+    fn commands_should_be_authorized_according_to_matrix() {
+        let permissioner = init_permissioner();
+        let matrix: [(u32, &[u32]); 22] = [
+            (PING_CODE, &ALL_USERS),
+            (LOGIN_USER_CODE, &ALL_USERS),
+            (GET_ME_CODE, &ALL_USERS),
+            (CREATE_PERSONAL_ACCESS_TOKEN_CODE, &ALL_USERS),
+            (GET_STATS_CODE, &[READ_SERVERS, MANAGE_SERVERS]),
+            (GET_SESSIONS_CODE, &[READ_SERVERS, MANAGE_SERVERS]),
+            (KILL_SESSION_CODE, &[MANAGE_SERVERS]),
+            (ROTATE_ENCRYPTION_KEY_CODE, &[MANAGE_SERVERS]),
+            (GET_USERS_CODE, &[READ_USERS, MANAGE_USERS]),
+            (CREATE_USER_CODE, &[MANAGE_USERS]),
+            (GET_STREAMS_CODE, &[READ_STREAMS, MANAGE_STREAMS]),
+            (CREATE_STREAM_CODE, &[MANAGE_STREAMS]),
+            (
+                GET_STREAM_CODE,
+                &[READ_STREAMS, MANAGE_STREAMS, READ_STREAM, MANAGE_STREAM],
+            ),
+            (DELETE_STREAM_CODE, &[MANAGE_STREAMS, MANAGE_STREAM]),
+            (GET_TOPIC_CODE, &[READ_STREAMS, MANAGE_STREAMS]),
+            (CREATE_TOPIC_CODE, &[MANAGE_STREAMS]),
+            (JOIN_CONSUMER_GROUP_CODE, &[READ_STREAMS, MANAGE_STREAMS]),
+            (
+                HEARTBEAT_CONSUMER_GROUP_CODE,
+                &[READ_STREAMS, MANAGE_STREAMS],
+            ),
+            (POLL_MESSAGES_CODE, &[POLL_MESSAGES, READ_STREAM]),
+            (FETCH_REPLICA_MESSAGES_CODE, &[MANAGE_SERVERS]),
+            (STORE_CONSUMER_OFFSET_CODE, &[POLL_MESSAGES, READ_STREAM]),
+            (SEND_MESSAGES_CODE, &[SEND_MESSAGES, MANAGE_STREAM]),
+        ];
+
+        for (code, allowed_users) in matrix {
+            let requirement = get_requirement(code).unwrap();
+            let name = get_name_from_code(code).unwrap();
+            for user_id in ALL_USERS {
+                let result =
+                    requirement.check(&permissioner, user_id, Some(STREAM_ID), Some(TOPIC_ID));
+                assert_eq!(
+                    result.is_ok(),
+                    allowed_users.contains(&user_id),
+                    "Command: {name} authorization for user with ID: {user_id} is invalid."
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scoped_permission_should_not_be_checked_for_unknown_resource() {
+        let permissioner = init_permissioner();
+        let requirement = get_requirement(DELETE_TOPIC_CODE).unwrap();
+
+        assert!(requirement
+            .check(&permissioner, NO_PERMISSIONS, None, None)
+            .is_ok());
+        assert!(requirement
+            .check(&permissioner, NO_PERMISSIONS, Some(STREAM_ID), None)
+            .is_ok());
+        assert!(requirement
+            .check(
+                &permissioner,
+                NO_PERMISSIONS,
+                Some(STREAM_ID),
+                Some(TOPIC_ID)
+            )
+            .is_err());
+    }
+}
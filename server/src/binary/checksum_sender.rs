@@ -0,0 +1,157 @@
+use crate::binary::sender::Sender;
+use async_trait::async_trait;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::binary::{CHECKSUM_FLAG, CHUNKED_RESPONSE_LENGTH};
+use iggy::error::IggyError;
+use iggy::utils::checksum;
+use std::mem::size_of;
+use tracing::debug;
+
+const STATUS_OK: &[u8] = &[0; 4];
+const CHECKSUM_LENGTH: usize = 4;
+const CODE_LENGTH: usize = 4;
+
+/// Wraps the sender of the connection to append the checksum (u32, CRC32) to each response frame,
+/// when the request has been sent with the `CHECKSUM_FLAG` set in its command code.
+/// The frames are written with the `write` of the wrapped sender, so for QUIC the stream is finished
+/// when the wrapped sender is dropped after handling the request.
+pub struct ChecksumSender<'a> {
+    inner: &'a mut dyn Sender,
+}
+
+impl<'a> ChecksumSender<'a> {
+    pub fn new(inner: &'a mut dyn Sender) -> Self {
+        Self { inner }
+    }
+
+    async fn send_frame(&mut self, parts: &[&[u8]]) -> Result<(), IggyError> {
+        let checksum = checksum::calculate_parts(parts).to_le_bytes();
+        let mut frame = parts.concat();
+        frame.extend_from_slice(&checksum);
+        self.inner.write(&frame).await
+    }
+
+    async fn send_response(&mut self, status: &[u8], payload: &[u8]) -> Result<(), IggyError> {
+        debug!("Sending response with status: {:?} and checksum...", status);
+        let length = (payload.len() as u32).to_le_bytes();
+        self.send_frame(&[status, &length, payload]).await?;
+        debug!("Sent response with status: {:?} and checksum", status);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Sender for ChecksumSender<'_> {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        self.inner.read(buffer).await
+    }
+
+    async fn write(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.inner.write(payload).await
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.send_ok_response(&[]).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.send_response(STATUS_OK, payload).await
+    }
+
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError> {
+        debug!(
+            "Sending chunked response with status: {:?} and checksum...",
+            STATUS_OK
+        );
+        self.send_frame(&[STATUS_OK, &CHUNKED_RESPONSE_LENGTH.to_le_bytes()])
+            .await
+    }
+
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError> {
+        let length = (chunk.len() as u32).to_le_bytes();
+        self.send_frame(&[&length, chunk]).await?;
+        if chunk.is_empty() {
+            debug!(
+                "Sent chunked response with status: {:?} and checksum",
+                STATUS_OK
+            );
+        }
+        Ok(())
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        let error_message = error.to_string();
+        let length = error_message.len() as u32;
+
+        let mut error_details_buffer =
+            BytesMut::with_capacity(error_message.len() + size_of::<u32>());
+        error_details_buffer.put_u32_le(length);
+        error_details_buffer.put_slice(error_message.as_bytes());
+
+        self.send_response(&error.as_code().to_le_bytes(), &error_details_buffer)
+            .await
+    }
+}
+
+/// Checks if the command (starting with its code) has been sent with the `CHECKSUM_FLAG` set.
+pub fn has_checksum(command: &[u8]) -> bool {
+    command.len() >= CODE_LENGTH
+        && u32::from_le_bytes(command[..CODE_LENGTH].try_into().unwrap()) & CHECKSUM_FLAG != 0
+}
+
+/// Verifies the checksum trailing the command, calculated over the request length, the flagged code and the payload.
+/// Returns the command with the flag cleared from its code and without the trailing checksum.
+pub fn verify_checksum(length: &[u8], command: &[u8]) -> Result<Bytes, IggyError> {
+    if command.len() < CODE_LENGTH + CHECKSUM_LENGTH {
+        return Err(IggyError::InvalidCommand);
+    }
+
+    let (frame, checksum) = command.split_at(command.len() - CHECKSUM_LENGTH);
+    checksum::verify_frame(
+        u32::from_le_bytes(checksum.try_into().unwrap()),
+        &[length, frame],
+    )?;
+
+    let code = u32::from_le_bytes(frame[..CODE_LENGTH].try_into().unwrap()) & !CHECKSUM_FLAG;
+    let mut command = BytesMut::with_capacity(frame.len());
+    command.put_u32_le(code);
+    command.put_slice(&frame[CODE_LENGTH..]);
+    Ok(command.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_checksum_should_strip_flag_and_checksum() {
+        let code = 1u32 | CHECKSUM_FLAG;
+        let payload = [1u8, 2, 3];
+        let length = ((CODE_LENGTH + payload.len() + CHECKSUM_LENGTH) as u32).to_le_bytes();
+        let mut command = Vec::new();
+        command.extend_from_slice(&code.to_le_bytes());
+        command.extend_from_slice(&payload);
+        let checksum = checksum::calculate_parts(&[&length, &command]);
+        command.extend_from_slice(&checksum.to_le_bytes());
+
+        assert!(has_checksum(&command));
+        let command = verify_checksum(&length, &command).unwrap();
+        assert_eq!(&command[..CODE_LENGTH], &1u32.to_le_bytes());
+        assert_eq!(&command[CODE_LENGTH..], &payload);
+    }
+
+    #[test]
+    fn verify_checksum_should_fail_for_corrupted_payload() {
+        let code = 1u32 | CHECKSUM_FLAG;
+        let length = ((CODE_LENGTH + 1 + CHECKSUM_LENGTH) as u32).to_le_bytes();
+        let mut command = Vec::new();
+        command.extend_from_slice(&code.to_le_bytes());
+        command.push(1);
+        let checksum = checksum::calculate_parts(&[&length, &command]);
+        command.extend_from_slice(&checksum.to_le_bytes());
+        command[CODE_LENGTH] = 2;
+
+        let result = verify_checksum(&length, &command);
+        assert!(matches!(result, Err(IggyError::InvalidFrameChecksum(_, _))));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use bytes::{BufMut, BytesMut};
+use iggy::error::IggyError;
+use iggy::models::messages::PolledMessages;
+
+/// Streams the OK response as multiple chunks of at most `chunk_size` bytes,
+/// so that only a single chunk of the mapped response is kept in memory at once.
+struct ChunkedResponse<'a> {
+    sender: &'a mut dyn Sender,
+    chunk_size: usize,
+    buffer: BytesMut,
+}
+
+impl<'a> ChunkedResponse<'a> {
+    async fn start(sender: &'a mut dyn Sender, chunk_size: usize) -> Result<Self, IggyError> {
+        sender.send_chunked_ok_response().await?;
+        Ok(Self {
+            sender,
+            chunk_size,
+            buffer: BytesMut::with_capacity(chunk_size),
+        })
+    }
+
+    async fn send_full_chunks(&mut self) -> Result<(), IggyError> {
+        while self.buffer.len() >= self.chunk_size {
+            let chunk = self.buffer.split_to(self.chunk_size);
+            self.sender.send_response_chunk(&chunk).await?;
+        }
+        Ok(())
+    }
+
+    async fn finish(mut self) -> Result<(), IggyError> {
+        self.send_full_chunks().await?;
+        if !self.buffer.is_empty() {
+            self.sender.send_response_chunk(&self.buffer).await?;
+        }
+        self.sender.send_response_chunk(&[]).await
+    }
+}
+
+/// Sends the polled messages in the same format as `mapper::map_polled_messages`, but streamed in chunks.
+pub async fn send_polled_messages(
+    sender: &mut dyn Sender,
+    polled_messages: &PolledMessages,
+    chunk_size: usize,
+) -> Result<(), IggyError> {
+    let mut response = ChunkedResponse::start(sender, chunk_size).await?;
+    extend_polled_messages(&mut response, polled_messages).await?;
+    response.finish().await
+}
+
+/// Sends the polled batches in the same format as `mapper::map_polled_messages_from_partitions`, but streamed in chunks.
+pub async fn send_polled_messages_from_partitions(
+    sender: &mut dyn Sender,
+    batches: &[PolledMessages],
+    chunk_size: usize,
+) -> Result<(), IggyError> {
+    let mut response = ChunkedResponse::start(sender, chunk_size).await?;
+    response.buffer.put_u32_le(batches.len() as u32);
+    for polled_messages in batches {
+        let size = mapper::get_polled_messages_size(polled_messages);
+        response.buffer.put_u32_le(size as u32);
+        extend_polled_messages(&mut response, polled_messages).await?;
+    }
+    response.finish().await
+}
+
+async fn extend_polled_messages(
+    response: &mut ChunkedResponse<'_>,
+    polled_messages: &PolledMessages,
+) -> Result<(), IggyError> {
+    mapper::extend_polled_messages_header(polled_messages, &mut response.buffer);
+    for message in polled_messages.messages.iter() {
+        message.extend(&mut response.buffer);
+        response.send_full_chunks().await?;
+    }
+    Ok(())
+}
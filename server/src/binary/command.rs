@@ -1,6 +1,8 @@
+use crate::binary::authorization;
 use crate::binary::handlers::consumer_groups::{
     create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
-    get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    get_consumer_groups_handler, heartbeat_consumer_group_handler, join_consumer_group_handler,
+    leave_consumer_group_handler,
 };
 use crate::binary::handlers::consumer_offsets::*;
 use crate::binary::handlers::messages::*;
@@ -9,6 +11,7 @@ use crate::binary::handlers::personal_access_tokens::{
     create_personal_access_token_handler, delete_personal_access_token_handler,
     get_personal_access_tokens_handler, login_with_personal_access_token_handler,
 };
+use crate::binary::handlers::schemas::*;
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
@@ -22,6 +25,7 @@ use crate::command::ServerCommand;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use iggy::error::IggyError;
+use std::time::Instant;
 use tracing::{debug, error};
 
 pub async fn handle(
@@ -30,7 +34,16 @@ pub async fn handle(
     session: &Session,
     system: SharedSystem,
 ) -> Result<(), IggyError> {
-    match try_handle(command, sender, session, &system).await {
+    let code = command.code();
+    session.set_last_command(code);
+    let start = Instant::now();
+    let result = try_handle(command, sender, session, &system).await;
+    system
+        .read()
+        .await
+        .metrics
+        .record_command_latency(code, start.elapsed());
+    match result {
         Ok(_) => {
             debug!("Command was handled successfully, session: {session}. TCP response was sent.");
             Ok(())
@@ -58,6 +71,7 @@ async fn try_handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("Handling command '{command}', session: {session}...");
+    authorization::authorize(&command, session, &*system.read().await)?;
     match command {
         ServerCommand::Ping(command) => {
             ping_handler::handle(command, sender, session, system).await
@@ -74,6 +88,12 @@ async fn try_handle(
         ServerCommand::GetClients(command) => {
             get_clients_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetSessions(command) => {
+            get_sessions_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::KillSession(command) => {
+            kill_session_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetUser(command) => {
             get_user_handler::handle(command, sender, session, system).await
         }
@@ -119,9 +139,45 @@ async fn try_handle(
         ServerCommand::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::PollMessagesFromPartitions(command) => {
+            poll_messages_from_partitions_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::PeekMessages(command) => {
+            peek_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetMessageByKey(command) => {
+            get_message_by_key_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::FetchReplicaMessages(command) => {
+            fetch_replica_messages_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::AcquireExclusiveProducer(command) => {
+            acquire_exclusive_producer_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::InitProducerEpoch(command) => {
+            init_producer_epoch_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::CopyMessages(command) => {
+            copy_messages_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetConsumerLag(command) => {
+            get_consumer_lag_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::ResetConsumerOffsets(command) => {
+            reset_consumer_offsets_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetConsumers(command) => {
+            get_consumers_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeleteConsumer(command) => {
+            delete_consumer_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DeleteConsumerOffsets(command) => {
+            delete_consumer_offsets_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(command, sender, session, system).await
         }
@@ -161,12 +217,39 @@ async fn try_handle(
         ServerCommand::PurgeTopic(command) => {
             purge_topic_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::SetTopicReadOnly(command) => {
+            set_topic_read_only_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetSchema(command) => {
+            get_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetSchemas(command) => {
+            get_schemas_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::RegisterSchema(command) => {
+            register_schema_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetPartition(command) => {
+            get_partition_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::CreatePartitions(command) => {
             create_partitions_handler::handle(command, sender, session, system).await
         }
         ServerCommand::DeletePartitions(command) => {
             delete_partitions_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::GetPartitionOffsets(command) => {
+            get_partition_offsets_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetOffsetByTimestamp(command) => {
+            get_offset_by_timestamp_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetMessagesHistogram(command) => {
+            get_messages_histogram_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::SealPartition(command) => {
+            seal_partition_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::GetConsumerGroup(command) => {
             get_consumer_group_handler::handle(command, sender, session, system).await
         }
@@ -185,11 +268,35 @@ async fn try_handle(
         ServerCommand::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, session, system).await
         }
+        ServerCommand::HeartbeatConsumerGroup(command) => {
+            heartbeat_consumer_group_handler::handle(command, sender, session, system).await
+        }
         ServerCommand::FlushUnsavedBuffer(command) => {
             flush_unsaved_buffer_handler::handle(command, sender, session, system).await
         }
         ServerCommand::GetSnapshotFile(command) => {
             get_snapshot::handle(command, sender, session, system).await
         }
+        ServerCommand::RotateEncryptionKey(command) => {
+            rotate_encryption_key_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::SetLogLevel(command) => {
+            set_log_level_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::SetBackgroundIoRate(command) => {
+            set_background_io_rate_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetClusterMetadata(command) => {
+            get_cluster_metadata_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DrainNode(command) => {
+            drain_node_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::DecommissionNode(command) => {
+            decommission_node_handler::handle(command, sender, session, system).await
+        }
+        ServerCommand::GetNodeStatus(command) => {
+            get_node_status_handler::handle(command, sender, session, system).await
+        }
     }
 }
@@ -26,6 +26,7 @@ pub async fn handle(
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.rebalance_strategy,
             )
             .await?;
         let consumer_group = consumer_group.read().await;
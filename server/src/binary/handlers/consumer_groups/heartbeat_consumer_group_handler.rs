@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::error::IggyError;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string(), iggy_group_id = command.group_id.as_string()))]
+pub async fn handle(
+    command: HeartbeatConsumerGroup,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let partitions = system
+        .heartbeat_consumer_group(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            &command.group_id,
+        )
+        .await?;
+    let member = mapper::map_consumer_group_member(session.client_id, &partitions);
+    sender.send_ok_response(&member).await?;
+    Ok(())
+}
@@ -21,6 +21,7 @@ pub async fn handle(
             &command.stream_id,
             &command.topic_id,
             &command.group_id,
+            command.group_instance_id.as_deref(),
         )
         .await?;
     sender.send_empty_ok_response().await?;
@@ -0,0 +1,28 @@
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_offsets::delete_consumer::DeleteConsumer;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: DeleteConsumer,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    system
+        .delete_consumer(
+            session,
+            &command.consumer_id,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
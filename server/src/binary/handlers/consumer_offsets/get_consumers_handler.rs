@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_offsets::get_consumers::GetConsumers;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetConsumers,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let consumers = system
+        .get_consumers(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    let response = mapper::map_consumers(&consumers);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -1,2 +1,7 @@
+pub mod delete_consumer_handler;
+pub mod delete_consumer_offsets_handler;
+pub mod get_consumer_lag_handler;
 pub mod get_consumer_offset_handler;
+pub mod get_consumers_handler;
+pub mod reset_consumer_offsets_handler;
 pub mod store_consumer_offset_handler;
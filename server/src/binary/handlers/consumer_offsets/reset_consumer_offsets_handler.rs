@@ -0,0 +1,32 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
+use iggy::error::IggyError;
+use tracing::debug;
+
+pub async fn handle(
+    command: ResetConsumerOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let resets = system
+        .reset_consumer_offsets(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            &command.partitions,
+            &command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    let response = mapper::map_consumer_offset_resets(&resets);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
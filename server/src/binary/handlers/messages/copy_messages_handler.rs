@@ -0,0 +1,35 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::copy_messages::CopyMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: CopyMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let copied_messages = system
+        .copy_messages(
+            session,
+            &command.source_stream_id,
+            &command.source_topic_id,
+            command.source_partition_id,
+            &command.target_stream_id,
+            &command.target_topic_id,
+            command.target_partition_id,
+            command.kind,
+            command.from,
+            command.to,
+        )
+        .await?;
+    let response = mapper::map_copied_messages(&copied_messages);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::fetch_replica_messages::FetchReplicaMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: FetchReplicaMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let replica_messages = system
+        .fetch_replica_messages(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.replica_id,
+            command.offset,
+            command.epoch,
+            command.count,
+        )
+        .await?;
+    let replica_messages = mapper::map_replica_messages(&replica_messages);
+    sender.send_ok_response(&replica_messages).await?;
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::get_message_by_key::GetMessageByKey;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetMessageByKey,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let messages = system
+        .get_message_by_key(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.key,
+        )
+        .await?;
+    let messages = mapper::map_polled_messages(&messages);
+    sender.send_ok_response(&messages).await?;
+    Ok(())
+}
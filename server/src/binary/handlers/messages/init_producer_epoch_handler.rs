@@ -0,0 +1,44 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::init_producer_epoch::InitProducerEpoch;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string()))]
+pub async fn handle(
+    command: InitProducerEpoch,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let epoch;
+    {
+        let mut system = system.write().await;
+        epoch = system.init_producer_epoch(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.epoch,
+        )?;
+    }
+    // Only the bumped epoch is stored, so the stale producer instances remain fenced after the server restart.
+    if command.epoch == 0 {
+        let system = system.read().await;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::InitProducerEpoch(command),
+            )
+            .await?;
+    }
+    sender
+        .send_ok_response(&mapper::map_producer_epoch(epoch))
+        .await?;
+    Ok(())
+}
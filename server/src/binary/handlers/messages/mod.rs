@@ -1,3 +1,10 @@
+pub mod acquire_exclusive_producer_handler;
+pub mod copy_messages_handler;
+pub mod fetch_replica_messages_handler;
 pub mod flush_unsaved_buffer_handler;
+pub mod get_message_by_key_handler;
+pub mod init_producer_epoch_handler;
+pub mod peek_messages_handler;
+pub mod poll_messages_from_partitions_handler;
 pub mod poll_messages_handler;
 pub mod send_messages_handler;
@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::peek_messages::PeekMessages;
+use tracing::debug;
+
+pub async fn handle(
+    command: PeekMessages,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let messages = system
+        .peek_messages(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.kind,
+            command.count,
+        )
+        .await?;
+    let messages = mapper::map_polled_messages(&messages);
+    sender.send_ok_response(&messages).await?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::binary::chunked_response;
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::messages::poll_messages_from_partitions::PollMessagesFromPartitions;
+use tracing::debug;
+
+pub async fn handle(
+    command: PollMessagesFromPartitions,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let batches = system
+        .poll_messages_from_partitions(
+            session,
+            &command.consumer,
+            &command.stream_id,
+            &command.topic_id,
+            &command.partitions,
+            command.out_of_range,
+            command.count,
+            command.auto_commit,
+        )
+        .await?;
+    let config = &system.config.poll_response;
+    let response_size = 4 + batches
+        .iter()
+        .map(|polled_messages| 4 + mapper::get_polled_messages_size(polled_messages))
+        .sum::<u64>();
+    if config.chunked && response_size > config.chunk_size.as_bytes_u64() {
+        chunked_response::send_polled_messages_from_partitions(
+            sender,
+            &batches,
+            config.chunk_size.as_bytes_usize(),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let batches = mapper::map_polled_messages_from_partitions(&batches);
+    sender.send_ok_response(&batches).await?;
+    Ok(())
+}
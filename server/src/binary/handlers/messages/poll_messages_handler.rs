@@ -1,3 +1,4 @@
+use crate::binary::chunked_response;
 use crate::binary::mapper;
 use crate::binary::sender::Sender;
 use crate::streaming::session::Session;
@@ -23,9 +24,27 @@ pub async fn handle(
             &command.stream_id,
             &command.topic_id,
             command.partition_id,
-            PollingArgs::new(command.strategy, command.count, command.auto_commit),
+            PollingArgs::new(
+                command.strategy,
+                command.count,
+                command.max_bytes,
+                command.auto_commit,
+            ),
         )
         .await?;
+    let config = &system.config.poll_response;
+    if config.chunked
+        && mapper::get_polled_messages_size(&messages) > config.chunk_size.as_bytes_u64()
+    {
+        chunked_response::send_polled_messages(
+            sender,
+            &messages,
+            config.chunk_size.as_bytes_usize(),
+        )
+        .await?;
+        return Ok(());
+    }
+
     let messages = mapper::map_polled_messages(&messages);
     sender.send_ok_response(&messages).await?;
     Ok(())
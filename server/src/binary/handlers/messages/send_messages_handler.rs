@@ -3,8 +3,8 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::Result;
 use iggy::error::IggyError;
-use iggy::messages::send_messages::SendMessages;
-use tracing::debug;
+use iggy::messages::send_messages::{AckLevel, SendMessages};
+use tracing::{debug, error};
 
 pub async fn handle(
     command: SendMessages,
@@ -17,9 +17,27 @@ pub async fn handle(
     let stream_id = command.stream_id;
     let topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let ack = command.ack;
     let messages = command.messages;
+    if ack == AckLevel::None {
+        sender.send_empty_ok_response().await?;
+        let messages_count = messages.len() as u64;
+        if let Err(error) = system
+            .append_messages(session, stream_id, topic_id, partitioning, ack, messages)
+            .await
+        {
+            error!(
+                "Failed to append unacknowledged messages for session: {session}. Error: {error}"
+            );
+            system
+                .metrics
+                .increment_failed_unacknowledged_appends(messages_count);
+        }
+        return Ok(());
+    }
+
     system
-        .append_messages(session, stream_id, topic_id, partitioning, messages)
+        .append_messages(session, stream_id, topic_id, partitioning, ack, messages)
         .await?;
     sender.send_empty_ok_response().await?;
     Ok(())
@@ -0,0 +1,32 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_messages_histogram::GetMessagesHistogram;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetMessagesHistogram,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let histogram = system
+        .get_messages_histogram(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.from,
+            command.to,
+            command.bucket_size,
+        )
+        .await?;
+    let response = mapper::map_messages_histogram(&histogram);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetOffsetByTimestamp,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let offset = system
+        .get_offset_by_timestamp(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.timestamp,
+        )
+        .await?;
+    let Some(offset) = offset else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let response = mapper::map_timestamp_offset(&offset);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_partition::GetPartition;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetPartition,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let partition = system
+        .get_partition(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await;
+    if partition.is_err() {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    }
+
+    let partition = mapper::map_partition(&partition?);
+    sender.send_ok_response(&partition).await?;
+    Ok(())
+}
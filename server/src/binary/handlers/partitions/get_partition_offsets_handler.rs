@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetPartitionOffsets,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let offsets = system
+        .get_partition_offsets(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    let response = mapper::map_partition_offsets(&offsets);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -1,2 +1,7 @@
 pub mod create_partitions_handler;
 pub mod delete_partitions_handler;
+pub mod get_messages_histogram_handler;
+pub mod get_offset_by_timestamp_handler;
+pub mod get_partition_handler;
+pub mod get_partition_offsets_handler;
+pub mod seal_partition_handler;
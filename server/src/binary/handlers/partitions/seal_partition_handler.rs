@@ -0,0 +1,53 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::partitions::seal_partition::SealPartition;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string()))]
+pub async fn handle(
+    command: SealPartition,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    // The topic is frozen before sealing the partition, so that no message is appended after the boundary.
+    if command.read_only {
+        {
+            let mut system = system.write().await;
+            system.set_topic_read_only(session, &command.stream_id, &command.topic_id, true)?;
+        }
+
+        let system = system.read().await;
+        system
+            .state
+            .apply(
+                session.get_user_id(),
+                EntryCommand::SetTopicReadOnly(SetTopicReadOnly {
+                    stream_id: command.stream_id.clone(),
+                    topic_id: command.topic_id.clone(),
+                    read_only: true,
+                }),
+            )
+            .await?;
+    }
+
+    let system = system.read().await;
+    let sealed_partition = system
+        .seal_partition(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    let response = mapper::map_sealed_partition(&sealed_partition);
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::schemas::get_schema::GetSchema;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let schema = system.get_schema(
+        session,
+        &command.stream_id,
+        &command.topic_id,
+        command.version,
+    )?;
+    let Some(schema) = schema else {
+        sender.send_empty_ok_response().await?;
+        return Ok(());
+    };
+
+    let schema = mapper::map_schema(schema);
+    sender.send_ok_response(&schema).await?;
+    Ok(())
+}
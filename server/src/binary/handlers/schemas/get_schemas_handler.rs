@@ -0,0 +1,22 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::schemas::get_schemas::GetSchemas;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetSchemas,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let schemas = system.get_schemas(session, &command.stream_id, &command.topic_id)?;
+    let schemas = mapper::map_schemas(schemas);
+    sender.send_ok_response(&schemas).await?;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use anyhow::Result;
+use iggy::error::IggyError;
+use iggy::schemas::register_schema::RegisterSchema;
+use tracing::{debug, instrument};
+
+#[instrument(skip_all, fields(iggy_user_id = session.get_user_id(), iggy_client_id = session.client_id, iggy_stream_id = command.stream_id.as_string(), iggy_topic_id = command.topic_id.as_string()))]
+pub async fn handle(
+    command: RegisterSchema,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let response;
+    {
+        let mut system = system.write().await;
+        let schema = system.register_schema(
+            session,
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            &command.definition,
+            command.validate,
+        )?;
+        response = mapper::map_schema(schema);
+    }
+    let system = system.read().await;
+    system
+        .state
+        .apply(session.get_user_id(), EntryCommand::RegisterSchema(command))
+        .await?;
+    sender.send_ok_response(&response).await?;
+    Ok(())
+}
@@ -15,7 +15,12 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
-    let streams = system.find_streams(session)?;
+    let streams = system.find_streams(
+        session,
+        command.offset,
+        command.limit,
+        command.name_prefix.as_deref(),
+    )?;
     let response = mapper::map_streams(&streams);
     sender.send_ok_response(&response).await?;
     Ok(())
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::get_cluster_metadata::GetClusterMetadata;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetClusterMetadata,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let metadata = system.get_cluster_metadata(session).await?;
+    let bytes = mapper::map_cluster_metadata(&metadata);
+    sender.send_ok_response(&bytes).await?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use iggy::error::IggyError;
+use iggy::system::get_sessions::GetSessions;
+use tracing::debug;
+
+pub async fn handle(
+    command: GetSessions,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), IggyError> {
+    debug!("session: {session}, command: {command}");
+    let system = system.read().await;
+    let clients = system.get_sessions(session).await?;
+    let sessions = mapper::map_sessions(&clients).await;
+    sender.send_ok_response(&sessions).await?;
+    Ok(())
+}
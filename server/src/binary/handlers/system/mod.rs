@@ -1,6 +1,15 @@
+pub mod decommission_node_handler;
+pub mod drain_node_handler;
 pub mod get_client_handler;
 pub mod get_clients_handler;
+pub mod get_cluster_metadata_handler;
 pub mod get_me_handler;
+pub mod get_node_status_handler;
+pub mod get_sessions_handler;
 pub mod get_snapshot;
 pub mod get_stats_handler;
+pub mod kill_session_handler;
 pub mod ping_handler;
+pub mod rotate_encryption_key_handler;
+pub mod set_background_io_rate_handler;
+pub mod set_log_level_handler;
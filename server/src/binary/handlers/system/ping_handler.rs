@@ -5,7 +5,6 @@ use anyhow::Result;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
 use iggy::system::ping::Ping;
-use iggy::utils::timestamp::IggyTimestamp;
 use tracing::debug;
 
 pub async fn handle(
@@ -15,13 +14,14 @@ pub async fn handle(
     system: &SharedSystem,
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
+    // The last activity is tracked by the session for every command, so the heartbeat only ensures the client is still known.
     let system = system.read().await;
     let client_manager = system.client_manager.read().await;
-    let client = client_manager.get_client(session.client_id)?;
-    let mut client = client.write().await;
-    let now = IggyTimestamp::now();
-    client.last_heartbeat = now;
-    debug!("Updated last heartbeat to: {now} for session: {session}");
+    client_manager.get_client(session.client_id)?;
+    debug!(
+        "Received heartbeat at: {} for session: {session}",
+        session.get_last_activity()
+    );
     sender.send_empty_ok_response().await?;
     Ok(())
 }
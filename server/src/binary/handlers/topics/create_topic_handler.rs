@@ -30,6 +30,8 @@ pub async fn handle(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.cleanup_policy,
+                command.overrides,
             )
             .await?;
         command.message_expiry = topic.message_expiry;
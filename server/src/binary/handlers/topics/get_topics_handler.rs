@@ -15,7 +15,13 @@ pub async fn handle(
 ) -> Result<(), IggyError> {
     debug!("session: {session}, command: {command}");
     let system = system.read().await;
-    let topics = system.find_topics(session, &command.stream_id)?;
+    let topics = system.find_topics(
+        session,
+        &command.stream_id,
+        command.offset,
+        command.limit,
+        command.name_prefix.as_deref(),
+    )?;
     let response = mapper::map_topics(&topics);
     sender.send_ok_response(&response).await?;
     Ok(())
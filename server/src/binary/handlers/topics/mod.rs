@@ -3,4 +3,5 @@ pub mod delete_topic_handler;
 pub mod get_topic_handler;
 pub mod get_topics_handler;
 pub mod purge_topic_handler;
+pub mod set_topic_read_only_handler;
 pub mod update_topic_handler;
@@ -3,14 +3,27 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::schema::Schema;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::cluster_metadata::ClusterMetadata;
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::models::consumer_lag_info::ConsumerLagInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
+use iggy::models::copied_messages_info::CopiedMessagesInfo;
 use iggy::models::messages::PolledMessages;
+use iggy::models::messages_histogram::MessagesHistogram;
+use iggy::models::node_status::NodeStatus;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::partition_offsets_info::PartitionOffsetsInfo;
+use iggy::models::replica_messages::ReplicaMessages;
+use iggy::models::sealed_partition_info::SealedPartitionInfo;
 use iggy::models::stats::Stats;
+use iggy::models::timestamp_offset_info::TimestampOffsetInfo;
 use iggy::models::user_info::UserId;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
@@ -44,6 +57,17 @@ pub fn map_stats(stats: &Stats) -> Bytes {
     bytes.put_slice(stats.os_version.as_bytes());
     bytes.put_u32_le(stats.kernel_version.len() as u32);
     bytes.put_slice(stats.kernel_version.as_bytes());
+    bytes.put_u32_le(stats.command_latencies.len() as u32);
+    for latency in &stats.command_latencies {
+        bytes.put_u32_le(latency.code);
+        bytes.put_u64_le(latency.count);
+        bytes.put_u64_le(latency.p50.as_micros());
+        bytes.put_u64_le(latency.p95.as_micros());
+        bytes.put_u64_le(latency.p99.as_micros());
+    }
+    bytes.put_u32_le(stats.deleting_topics_count);
+    bytes.put_u64_le(stats.deleting_topics_size_bytes.as_bytes_u64());
+    bytes.put_u32_le(stats.failed_partitions_count);
     bytes.freeze()
 }
 
@@ -55,6 +79,173 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_consumer_lags(lags: &[ConsumerLagInfo]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(29 * lags.len());
+    for lag in lags {
+        bytes.put_u32_le(lag.partition_id);
+        bytes.put_u64_le(lag.current_offset);
+        if let Some(stored_offset) = lag.stored_offset {
+            bytes.put_u8(1);
+            bytes.put_u64_le(stored_offset);
+        } else {
+            bytes.put_u8(0);
+            bytes.put_u64_le(0);
+        }
+        bytes.put_u64_le(lag.lag);
+    }
+    bytes.freeze()
+}
+
+pub fn map_consumers(consumers: &[ConsumerInfo]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for consumer in consumers {
+        bytes.put_u32_le(consumer.id);
+        bytes.put_u64_le(consumer.stored_offset);
+        bytes.put_u64_le(consumer.lag);
+        if let Some(name) = &consumer.name {
+            bytes.put_u8(name.len() as u8);
+            bytes.put_slice(name.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+    }
+    bytes.freeze()
+}
+
+pub fn map_partition(partition: &PartitionDetails) -> Bytes {
+    let mut bytes =
+        BytesMut::with_capacity(92 + 29 * partition.replicas.len() + 53 * partition.segments.len());
+    bytes.put_u32_le(partition.id);
+    bytes.put_u64_le(partition.created_at.into());
+    bytes.put_u32_le(partition.segments_count);
+    bytes.put_u64_le(partition.current_offset);
+    bytes.put_u64_le(partition.size.as_bytes_u64());
+    bytes.put_u64_le(partition.messages_count);
+    bytes.put_u64_le(partition.high_watermark);
+    let consumer_offsets = &partition.consumer_offsets;
+    bytes.put_u32_le(consumer_offsets.consumers_count);
+    bytes.put_u32_le(consumer_offsets.consumer_groups_count);
+    bytes.put_u64_le(consumer_offsets.records_count);
+    bytes.put_u64_le(consumer_offsets.size.as_bytes_u64());
+    bytes.put_u64_le(consumer_offsets.compactions_count);
+    bytes.put_u64_le(
+        consumer_offsets
+            .last_compacted_at
+            .map_or(0, |timestamp| timestamp.into()),
+    );
+    bytes.put_u32_le(partition.replicas.len() as u32);
+    for replica in &partition.replicas {
+        bytes.put_u32_le(replica.id);
+        bytes.put_u64_le(replica.fetched_offset);
+        bytes.put_u64_le(replica.last_fetched_at.into());
+        bytes.put_u64_le(
+            replica
+                .last_caught_up_at
+                .map_or(0, |timestamp| timestamp.into()),
+        );
+        bytes.put_u8(if replica.is_in_sync { 1 } else { 0 });
+    }
+    for segment in &partition.segments {
+        bytes.put_u64_le(segment.start_offset);
+        bytes.put_u64_le(segment.end_offset);
+        bytes.put_u64_le(segment.size.as_bytes_u64());
+        bytes.put_u64_le(segment.messages_count);
+        bytes.put_u8(if segment.is_closed { 1 } else { 0 });
+        bytes.put_u64_le(segment.created_at.into());
+        bytes.put_u64_le(segment.index_size.as_bytes_u64());
+        bytes.put_u32_le(segment.indexes_count);
+    }
+    bytes.freeze()
+}
+
+pub fn map_partition_offsets(offsets: &[PartitionOffsetsInfo]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(20 * offsets.len());
+    for offset in offsets {
+        bytes.put_u32_le(offset.partition_id);
+        bytes.put_u64_le(offset.low_watermark);
+        bytes.put_u64_le(offset.high_watermark);
+    }
+    bytes.freeze()
+}
+
+pub fn map_timestamp_offset(offset: &TimestampOffsetInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(20);
+    bytes.put_u32_le(offset.partition_id);
+    bytes.put_u64_le(offset.offset);
+    bytes.put_u64_le(offset.timestamp);
+    bytes.freeze()
+}
+
+pub fn map_sealed_partition(sealed_partition: &SealedPartitionInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(16);
+    bytes.put_u32_le(sealed_partition.partition_id);
+    bytes.put_u64_le(sealed_partition.high_watermark);
+    bytes.put_u32_le(sealed_partition.segments_count);
+    bytes.freeze()
+}
+
+pub fn map_copied_messages(copied_messages: &CopiedMessagesInfo) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8);
+    bytes.put_u64_le(copied_messages.messages_count);
+    bytes.freeze()
+}
+
+pub fn map_messages_histogram(histogram: &MessagesHistogram) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(12 + 24 * histogram.buckets.len());
+    bytes.put_u32_le(histogram.partition_id);
+    bytes.put_u64_le(histogram.bucket_size.as_micros());
+    for bucket in &histogram.buckets {
+        bytes.put_u64_le(bucket.timestamp);
+        bytes.put_u64_le(bucket.messages_count);
+        bytes.put_u64_le(bucket.size.as_bytes_u64());
+    }
+    bytes.freeze()
+}
+
+pub fn map_schema(schema: &Schema) -> Bytes {
+    let mut bytes = BytesMut::new();
+    extend_schema(schema, &mut bytes);
+    bytes.freeze()
+}
+
+pub fn map_schemas(schemas: &[Schema]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for schema in schemas {
+        extend_schema(schema, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+fn extend_schema(schema: &Schema, bytes: &mut BytesMut) {
+    bytes.put_u32_le(schema.version);
+    bytes.put_u8(schema.schema_type.as_code());
+    bytes.put_u8(if schema.validate { 1 } else { 0 });
+    bytes.put_u64_le(schema.created_at.into());
+    bytes.put_u32_le(schema.definition.len() as u32);
+    bytes.put_slice(schema.definition.as_bytes());
+}
+
+pub fn map_consumer_offset_resets(resets: &[ConsumerOffsetResetInfo]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(30 * resets.len());
+    for reset in resets {
+        bytes.put_u32_le(reset.partition_id);
+        bytes.put_u64_le(reset.current_offset);
+        extend_optional_offset(reset.previous_offset, &mut bytes);
+        extend_optional_offset(reset.stored_offset, &mut bytes);
+    }
+    bytes.freeze()
+}
+
+fn extend_optional_offset(offset: Option<u64>, bytes: &mut BytesMut) {
+    if let Some(offset) = offset {
+        bytes.put_u8(1);
+        bytes.put_u64_le(offset);
+    } else {
+        bytes.put_u8(0);
+        bytes.put_u64_le(0);
+    }
+}
+
 pub fn map_client(client: &Client) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_client(client, &mut bytes);
@@ -75,6 +266,15 @@ pub async fn map_clients(clients: &[IggySharedMut<Client>]) -> Bytes {
     bytes.freeze()
 }
 
+pub async fn map_sessions(clients: &[IggySharedMut<Client>]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    for client in clients {
+        let client = client.read().await;
+        extend_session(&client, &mut bytes);
+    }
+    bytes.freeze()
+}
+
 pub fn map_user(user: &User) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_user(user, &mut bytes);
@@ -104,6 +304,18 @@ pub fn map_identity_info(user_id: UserId) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_producer_epoch(epoch: u32) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(4);
+    bytes.put_u32_le(epoch);
+    bytes.freeze()
+}
+
+pub fn map_key_version(key_version: u32) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(4);
+    bytes.put_u32_le(key_version);
+    bytes.freeze()
+}
+
 pub fn map_raw_pat(token: &str) -> Bytes {
     let mut bytes = BytesMut::with_capacity(1 + token.len());
     bytes.put_u8(token.len() as u8);
@@ -120,22 +332,112 @@ pub fn map_personal_access_tokens(personal_access_tokens: &[&PersonalAccessToken
 }
 
 pub fn map_polled_messages(polled_messages: &PolledMessages) -> Bytes {
-    let messages_count = polled_messages.messages.len() as u32;
+    let mut bytes = BytesMut::with_capacity(get_polled_messages_size(polled_messages) as usize);
+    extend_polled_messages_header(polled_messages, &mut bytes);
+    for message in polled_messages.messages.iter() {
+        message.extend(&mut bytes);
+    }
+
+    bytes.freeze()
+}
+
+pub fn map_cluster_metadata(metadata: &ClusterMetadata) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(metadata.node_id);
+    bytes.put_u32_le(metadata.brokers.len() as u32);
+    for broker in &metadata.brokers {
+        bytes.put_u32_le(broker.id);
+        bytes.put_u8(broker.name.len() as u8);
+        bytes.put_slice(broker.name.as_bytes());
+        bytes.put_u8(broker.address.len() as u8);
+        bytes.put_slice(broker.address.as_bytes());
+        bytes.put_u8(broker.zone.len() as u8);
+        bytes.put_slice(broker.zone.as_bytes());
+    }
+    bytes.put_u32_le(metadata.partitions.len() as u32);
+    for partition in &metadata.partitions {
+        bytes.put_u32_le(partition.stream_id);
+        bytes.put_u8(partition.stream_name.len() as u8);
+        bytes.put_slice(partition.stream_name.as_bytes());
+        bytes.put_u32_le(partition.topic_id);
+        bytes.put_u8(partition.topic_name.len() as u8);
+        bytes.put_slice(partition.topic_name.as_bytes());
+        bytes.put_u32_le(partition.partition_id);
+        bytes.put_u32_le(partition.leader_id);
+        bytes.put_u32_le(partition.leader_epoch);
+    }
+    bytes.freeze()
+}
+
+pub fn map_node_status(status: &NodeStatus) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(13);
+    bytes.put_u32_le(status.node_id);
+    bytes.put_u8(status.state.as_code());
+    bytes.put_u32_le(status.partitions_count);
+    bytes.put_u32_le(status.led_partitions_count);
+    bytes.freeze()
+}
+
+pub fn map_replica_messages(replica_messages: &ReplicaMessages) -> Bytes {
+    let polled_messages = &replica_messages.messages;
+    let mut bytes =
+        BytesMut::with_capacity(13 + get_polled_messages_size(polled_messages) as usize);
+    match replica_messages.diverging_epoch {
+        Some(diverging_epoch) => {
+            bytes.put_u8(1);
+            bytes.put_u32_le(diverging_epoch.epoch);
+            bytes.put_u64_le(diverging_epoch.end_offset);
+        }
+        None => {
+            bytes.put_u8(0);
+            bytes.put_u32_le(0);
+            bytes.put_u64_le(0);
+        }
+    }
+    extend_polled_messages_header(polled_messages, &mut bytes);
+    for message in polled_messages.messages.iter() {
+        message.extend(&mut bytes);
+    }
+
+    bytes.freeze()
+}
+
+pub fn map_polled_messages_from_partitions(batches: &[PolledMessages]) -> Bytes {
+    let mut bytes = BytesMut::new();
+    bytes.put_u32_le(batches.len() as u32);
+    for polled_messages in batches {
+        let polled_messages = map_polled_messages(polled_messages);
+        bytes.put_u32_le(polled_messages.len() as u32);
+        bytes.put_slice(&polled_messages);
+    }
+    bytes.freeze()
+}
+
+/// Returns the size of the polled messages mapped by `map_polled_messages`, without mapping them.
+pub fn get_polled_messages_size(polled_messages: &PolledMessages) -> u64 {
     let messages_size = polled_messages
         .messages
         .iter()
         .map(|message| message.get_size_bytes())
         .sum::<IggyByteSize>();
+    29 + messages_size.as_bytes_u64()
+}
 
-    let mut bytes = BytesMut::with_capacity(20 + messages_size.as_bytes_usize());
+pub fn extend_polled_messages_header(polled_messages: &PolledMessages, bytes: &mut BytesMut) {
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
-    bytes.put_u32_le(messages_count);
-    for message in polled_messages.messages.iter() {
-        message.extend(&mut bytes);
+    bytes.put_u32_le(polled_messages.messages.len() as u32);
+    bytes.put_u32_le(polled_messages.epoch);
+    match polled_messages.next_offset {
+        Some(next_offset) => {
+            bytes.put_u8(1);
+            bytes.put_u64_le(next_offset);
+        }
+        None => {
+            bytes.put_u8(0);
+            bytes.put_u64_le(0);
+        }
     }
-
-    bytes.freeze()
 }
 
 pub fn map_stream(stream: &Stream) -> Bytes {
@@ -166,6 +468,15 @@ pub fn map_topics(topics: &[&Topic]) -> Bytes {
 pub async fn map_topic(topic: &Topic) -> Bytes {
     let mut bytes = BytesMut::new();
     extend_topic(topic, &mut bytes);
+    let config_entries = topic.get_config_entries();
+    bytes.put_u32_le(config_entries.len() as u32);
+    for entry in config_entries {
+        bytes.put_u8(entry.name.len() as u8);
+        bytes.put_slice(entry.name.as_bytes());
+        bytes.put_u8(entry.value.len() as u8);
+        bytes.put_slice(entry.value.as_bytes());
+        bytes.put_u8(entry.source.as_code());
+    }
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
         extend_partition(&partition, &mut bytes);
@@ -189,6 +500,16 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Bytes {
     bytes.freeze()
 }
 
+pub fn map_consumer_group_member(member_id: u32, partitions: &[u32]) -> Bytes {
+    let mut bytes = BytesMut::with_capacity(8 + 4 * partitions.len());
+    bytes.put_u32_le(member_id);
+    bytes.put_u32_le(partitions.len() as u32);
+    for partition in partitions {
+        bytes.put_u32_le(*partition);
+    }
+    bytes.freeze()
+}
+
 pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) -> Bytes {
     let mut bytes = BytesMut::new();
     for consumer_group in consumer_groups {
@@ -216,6 +537,8 @@ fn extend_topic(topic: &Topic, bytes: &mut BytesMut) {
     bytes.put_u8(topic.compression_algorithm.as_code());
     bytes.put_u64_le(topic.max_topic_size.into());
     bytes.put_u8(topic.replication_factor);
+    bytes.put_u8(topic.cleanup_policy.as_code());
+    bytes.put_u8(topic.read_only as u8);
     bytes.put_u64_le(topic.get_size_bytes().as_bytes_u64());
     bytes.put_u64_le(topic.get_messages_count());
     bytes.put_u8(topic.name.len() as u8);
@@ -235,10 +558,32 @@ fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut BytesMut) {
     bytes.put_u32_le(consumer_group.group_id);
     bytes.put_u32_le(consumer_group.partitions_count);
     bytes.put_u32_le(consumer_group.get_members().len() as u32);
+    bytes.put_u8(consumer_group.rebalance_strategy.as_code());
     bytes.put_u8(consumer_group.name.len() as u8);
     bytes.put_slice(consumer_group.name.as_bytes());
 }
 
+fn extend_session(client: &Client, bytes: &mut BytesMut) {
+    bytes.put_u32_le(client.session.client_id);
+    bytes.put_u32_le(client.user_id.unwrap_or(0));
+    let transport: u8 = match client.transport {
+        Transport::Tcp => 1,
+        Transport::Quic => 2,
+    };
+    bytes.put_u8(transport);
+    let address = client.session.ip_address.to_string();
+    bytes.put_u32_le(address.len() as u32);
+    bytes.put_slice(address.as_bytes());
+    bytes.put_u64_le(client.session.connected_at.into());
+    let (last_command_code, last_command_at) = client
+        .session
+        .get_last_command()
+        .map(|(code, timestamp)| (code, timestamp.into()))
+        .unwrap_or((0, 0));
+    bytes.put_u32_le(last_command_code);
+    bytes.put_u64_le(last_command_at);
+}
+
 fn extend_client(client: &Client, bytes: &mut BytesMut) {
     bytes.put_u32_le(client.session.client_id);
     bytes.put_u32_le(client.user_id.unwrap_or(0));
@@ -1,3 +1,6 @@
+mod authorization;
+pub mod checksum_sender;
+mod chunked_response;
 pub mod command;
 mod handlers;
 mod mapper;
@@ -4,7 +4,11 @@ use iggy::error::IggyError;
 #[async_trait]
 pub trait Sender: Sync + Send {
     async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError>;
+    async fn write(&mut self, payload: &[u8]) -> Result<(), IggyError>;
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError>;
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError>;
+    /// Starts the OK response streamed in chunks, sent with `send_response_chunk` and terminated by the empty chunk.
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError>;
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError>;
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError>;
 }
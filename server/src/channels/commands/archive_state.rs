@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use tokio::task::JoinHandle;
 use tokio::time;
 use tracing::{error, info, instrument, warn};
 
@@ -33,17 +34,17 @@ impl StateArchiver {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
         if !self.enabled {
             info!("State archiver is disabled.");
-            return;
+            return None;
         }
 
         let overwrite = self.overwrite;
         let interval = self.interval;
         let sender = self.sender.clone();
         info!("State archiver is enabled, state will be archived every: {interval}.");
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
@@ -53,7 +54,7 @@ impl StateArchiver {
                         error!("Failed to send ArchiveStateCommand. Error: {}", err);
                     });
             }
-        });
+        }))
     }
 }
 
@@ -89,15 +90,15 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<ArchiveStateCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         if !config.data_maintenance.archiver.enabled
             || !config.data_maintenance.state.archiver_enabled
         {
-            return;
+            return None;
         }
 
         let state_archiver = StateArchiver::new(&config.data_maintenance.state, sender);
-        state_archiver.start();
+        state_archiver.start()
     }
 
     fn start_command_consumer(
@@ -105,19 +106,19 @@ impl ServerCommand<ArchiveStateCommand> for ArchiveStateExecutor {
         system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         receiver: flume::Receiver<ArchiveStateCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         if !config.data_maintenance.archiver.enabled
             || !config.data_maintenance.state.archiver_enabled
         {
-            return;
+            return None;
         }
 
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             info!("State archiver receiver stopped.");
-        });
+        }))
     }
 }
@@ -0,0 +1,109 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ServerConfig;
+use crate::configs::system::DiskMonitorConfig;
+use crate::streaming::diagnostics::disk_space::get_free_space;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use sysinfo::Disks;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{debug, error, info, instrument, warn};
+
+pub struct CheckDiskSpace {
+    enabled: bool,
+    interval: IggyDuration,
+    sender: Sender<CheckDiskSpaceCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckDiskSpaceCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct CheckDiskSpaceExecutor;
+
+impl CheckDiskSpace {
+    pub fn new(config: &DiskMonitorConfig, sender: Sender<CheckDiskSpaceCommand>) -> Self {
+        Self {
+            enabled: config.enabled,
+            interval: config.interval,
+            sender,
+        }
+    }
+
+    pub fn start(&self) -> Option<JoinHandle<()>> {
+        if !self.enabled {
+            info!("Disk space monitor is disabled.");
+            return None;
+        }
+
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!("Disk space monitor is enabled, free disk space will be checked every: {interval}.");
+        Some(tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender.send(CheckDiskSpaceCommand).unwrap_or_else(|error| {
+                    error!("Failed to send CheckDiskSpaceCommand. Error: {}", error);
+                });
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CheckDiskSpaceCommand> for CheckDiskSpaceExecutor {
+    #[instrument(skip_all)]
+    async fn execute(&mut self, system: &SharedSystem, _command: CheckDiskSpaceCommand) {
+        let system = system.read().await;
+        let disks = Disks::new_with_refreshed_list();
+        let mut min_free_space = None;
+        for path in system.config.get_data_paths() {
+            let Some(free_space) = get_free_space(&disks, &path) else {
+                warn!("Cannot determine free disk space for path: {path}.");
+                continue;
+            };
+
+            debug!("Free disk space for path: {path} is: {free_space}.");
+            let free_space = free_space.as_bytes_u64();
+            min_free_space =
+                Some(min_free_space.map_or(free_space, |min: u64| min.min(free_space)));
+        }
+
+        let Some(min_free_space) = min_free_space else {
+            return;
+        };
+
+        let min_free_space = IggyByteSize::from(min_free_space);
+        system.metrics.set_free_disk_space(min_free_space);
+        system.disk_space.record_free_space(min_free_space);
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &ServerConfig,
+        sender: Sender<CheckDiskSpaceCommand>,
+    ) -> Option<JoinHandle<()>> {
+        let check_disk_space = CheckDiskSpace::new(&config.system.disk_monitor, sender);
+        check_disk_space.start()
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &ServerConfig,
+        receiver: flume::Receiver<CheckDiskSpaceCommand>,
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Disk space monitor receiver stopped.");
+        }))
+    }
+}
@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use flume::Sender;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use tokio::task::JoinHandle;
 use tokio::time;
 use tracing::{debug, error, info, instrument};
 
@@ -32,16 +33,16 @@ impl PersonalAccessTokenCleaner {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
         if !self.enabled {
             info!("Personal access token cleaner is disabled.");
-            return;
+            return None;
         }
 
         let interval = self.interval;
         let sender = self.sender.clone();
         info!("Personal access token cleaner is enabled, expired tokens will be deleted every: {interval}.");
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
@@ -54,7 +55,7 @@ impl PersonalAccessTokenCleaner {
                         );
                     });
             }
-        });
+        }))
     }
 }
 
@@ -95,10 +96,10 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<CleanPersonalAccessTokensCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         let personal_access_token_cleaner =
             PersonalAccessTokenCleaner::new(&config.personal_access_token.cleaner, sender);
-        personal_access_token_cleaner.start();
+        personal_access_token_cleaner.start()
     }
 
     fn start_command_consumer(
@@ -106,13 +107,13 @@ impl ServerCommand<CleanPersonalAccessTokensCommand> for CleanPersonalAccessToke
         system: SharedSystem,
         _config: &crate::configs::server::ServerConfig,
         receiver: flume::Receiver<CleanPersonalAccessTokensCommand>,
-    ) {
-        tokio::spawn(async move {
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             info!("Personal access token cleaner receiver stopped.");
-        });
+        }))
     }
 }
@@ -0,0 +1,130 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ConsumerOffsetsMaintenanceConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::duration::IggyDuration;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{error, info, instrument};
+
+pub struct ConsumerOffsetsCompactor {
+    enabled: bool,
+    interval: IggyDuration,
+    min_stale_records: u64,
+    sender: Sender<CompactConsumerOffsetsCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactConsumerOffsetsCommand {
+    min_stale_records: u64,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompactConsumerOffsetsExecutor;
+
+impl ConsumerOffsetsCompactor {
+    pub fn new(
+        config: &ConsumerOffsetsMaintenanceConfig,
+        sender: Sender<CompactConsumerOffsetsCommand>,
+    ) -> Self {
+        Self {
+            enabled: config.compactor_enabled,
+            interval: config.interval,
+            min_stale_records: config.min_stale_records,
+            sender,
+        }
+    }
+
+    pub fn start(&self) -> Option<JoinHandle<()>> {
+        if !self.enabled {
+            info!("Consumer offsets compactor is disabled.");
+            return None;
+        }
+
+        let interval = self.interval;
+        let min_stale_records = self.min_stale_records;
+        let sender = self.sender.clone();
+        info!(
+            "Consumer offsets compactor is enabled, consumer offsets with at least {min_stale_records} stale records will be compacted every: {interval}."
+        );
+        Some(tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(CompactConsumerOffsetsCommand { min_stale_records })
+                    .unwrap_or_else(|err| {
+                        error!(
+                            "Failed to send CompactConsumerOffsetsCommand. Error: {}",
+                            err
+                        );
+                    });
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl ServerCommand<CompactConsumerOffsetsCommand> for CompactConsumerOffsetsExecutor {
+    #[instrument(skip_all)]
+    async fn execute(&mut self, system: &SharedSystem, command: CompactConsumerOffsetsCommand) {
+        let system = system.read().await;
+        let mut compacted_partitions = 0;
+        for stream in system.get_streams() {
+            for topic in stream.get_topics() {
+                for partition in topic.partitions.values() {
+                    let stale_records = partition.read().await.get_stale_consumer_offsets_count();
+                    if stale_records == 0 || stale_records < command.min_stale_records {
+                        continue;
+                    }
+
+                    let mut partition = partition.write().await;
+                    if let Err(error) = partition.compact_consumer_offsets().await {
+                        error!(
+                            "Failed to compact consumer offsets for partition with ID: {} for stream ID: {}, topic ID: {}. Error: {}",
+                            partition.partition_id, topic.stream_id, topic.topic_id, error
+                        );
+                        continue;
+                    }
+                    compacted_partitions += 1;
+                }
+            }
+        }
+
+        if compacted_partitions > 0 {
+            info!("Compacted consumer offsets for {compacted_partitions} partitions.");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<CompactConsumerOffsetsCommand>,
+    ) -> Option<JoinHandle<()>> {
+        let compactor =
+            ConsumerOffsetsCompactor::new(&config.data_maintenance.consumer_offsets, sender);
+        compactor.start()
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<CompactConsumerOffsetsCommand>,
+    ) -> Option<JoinHandle<()>> {
+        if !config.data_maintenance.consumer_offsets.compactor_enabled {
+            return None;
+        }
+
+        Some(tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer offsets compactor receiver stopped.");
+        }))
+    }
+}
@@ -0,0 +1,166 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::server::ConsumerLagConfig;
+use crate::streaming::systems::system::SharedSystem;
+use crate::webhooks::WebhookDispatcher;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::consumer::ConsumerKind;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::system_event::SystemEvent;
+use iggy::utils::duration::IggyDuration;
+use std::collections::HashSet;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{debug, error, info, instrument};
+
+type ConsumerLagKey = (u32, u32, u32, ConsumerKind, u32);
+
+pub struct ConsumerLagChecker {
+    threshold: u64,
+    interval: IggyDuration,
+    system: SharedSystem,
+}
+
+#[derive(Debug, Default)]
+pub struct DeliverWebhooksExecutor {
+    dispatcher: Option<WebhookDispatcher>,
+}
+
+impl ConsumerLagChecker {
+    pub fn new(config: &ConsumerLagConfig, system: SharedSystem) -> Self {
+        Self {
+            threshold: config.threshold,
+            interval: config.interval,
+            system,
+        }
+    }
+
+    pub fn start(&self) -> Option<JoinHandle<()>> {
+        if self.threshold == 0 {
+            info!("Consumer lag checker is disabled.");
+            return None;
+        }
+
+        let threshold = self.threshold;
+        let interval = self.interval;
+        let system = self.system.clone();
+        info!("Consumer lag checker is enabled, lag above: {threshold} messages will be checked every: {interval}.");
+        Some(tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            let mut exceeded = HashSet::new();
+            loop {
+                interval_timer.tick().await;
+                check_consumer_lags(&system, threshold, &mut exceeded).await;
+            }
+        }))
+    }
+}
+
+/// Publishes the event for each consumer which lag crossed the threshold since the last check.
+async fn check_consumer_lags(
+    system: &SharedSystem,
+    threshold: u64,
+    exceeded: &mut HashSet<ConsumerLagKey>,
+) {
+    let system = system.read().await;
+    let mut current = HashSet::new();
+    for stream in system.get_streams() {
+        for topic in stream.get_topics() {
+            for partition in topic.get_partitions() {
+                let partition = partition.read().await;
+                let offsets = partition
+                    .consumer_offsets
+                    .iter()
+                    .chain(partition.consumer_group_offsets.iter())
+                    .map(|offset| (offset.kind, offset.consumer_id, offset.offset))
+                    .collect::<Vec<_>>();
+                for (consumer_kind, consumer_id, offset) in offsets {
+                    let lag = partition.current_offset.saturating_sub(offset);
+                    if lag <= threshold {
+                        continue;
+                    }
+
+                    let key = (
+                        topic.stream_id,
+                        topic.topic_id,
+                        partition.partition_id,
+                        consumer_kind,
+                        consumer_id,
+                    );
+                    if !exceeded.contains(&key) {
+                        debug!(
+                            "Consumer lag: {lag} exceeded the threshold: {threshold} for {key:?}."
+                        );
+                        system.events_bus.publish(SystemEvent::ConsumerLagExceeded {
+                            stream_id: topic.stream_id,
+                            topic_id: topic.topic_id,
+                            partition_id: partition.partition_id,
+                            consumer_kind,
+                            consumer_id,
+                            lag,
+                        });
+                    }
+                    current.insert(key);
+                }
+            }
+        }
+    }
+    *exceeded = current;
+}
+
+#[async_trait]
+impl ServerCommand<SystemEvent> for DeliverWebhooksExecutor {
+    #[instrument(skip_all)]
+    async fn execute(&mut self, _system: &SharedSystem, event: SystemEvent) {
+        if let Some(dispatcher) = &self.dispatcher {
+            dispatcher.dispatch(&event).await;
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<SystemEvent>,
+    ) -> Option<JoinHandle<()>> {
+        if !config.webhooks.enabled {
+            info!("Webhooks are disabled.");
+            return None;
+        }
+
+        match WebhookDispatcher::new(config.webhooks.clone()) {
+            Ok(dispatcher) => self.dispatcher = Some(dispatcher),
+            Err(error) => {
+                error!("Failed to initialize webhooks. Error: {error}");
+                return None;
+            }
+        }
+
+        info!(
+            "Webhooks are enabled, events: {:?} will be sent to: {:?}.",
+            config.webhooks.events, config.webhooks.urls
+        );
+        system.subscribe_to_events(sender);
+        let consumer_lag_checker = ConsumerLagChecker::new(&config.webhooks.consumer_lag, system);
+        consumer_lag_checker.start()
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<SystemEvent>,
+    ) -> Option<JoinHandle<()>> {
+        if self.dispatcher.is_none() {
+            return None;
+        }
+
+        Some(tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(event) = receiver.recv_async().await {
+                self.execute(&system, event).await;
+            }
+            info!("Webhooks receiver stopped.");
+        }))
+    }
+}
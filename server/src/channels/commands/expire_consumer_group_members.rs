@@ -0,0 +1,100 @@
+use crate::channels::server_command::ServerCommand;
+use crate::configs::system::ConsumerGroupConfig;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::utils::duration::IggyDuration;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tracing::{debug, error, info, instrument};
+
+pub struct ConsumerGroupMembersExpirer {
+    interval: IggyDuration,
+    session_timeout: IggyDuration,
+    sender: Sender<ExpireConsumerGroupMembersCommand>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ExpireConsumerGroupMembersCommand;
+
+#[derive(Debug, Default, Clone)]
+pub struct ExpireConsumerGroupMembersExecutor;
+
+impl ConsumerGroupMembersExpirer {
+    pub fn new(
+        config: &ConsumerGroupConfig,
+        sender: Sender<ExpireConsumerGroupMembersCommand>,
+    ) -> Self {
+        Self {
+            interval: config.cleaner_interval,
+            session_timeout: config.session_timeout,
+            sender,
+        }
+    }
+
+    pub fn start(&self) -> Option<JoinHandle<()>> {
+        let interval = self.interval;
+        let sender = self.sender.clone();
+        info!(
+            "Consumer group members expirer is enabled, static members disconnected and members without the heartbeat for longer than: {} will be deleted every: {interval}.",
+            self.session_timeout
+        );
+        Some(tokio::spawn(async move {
+            let mut interval_timer = time::interval(interval.get_duration());
+            loop {
+                interval_timer.tick().await;
+                sender
+                    .send(ExpireConsumerGroupMembersCommand)
+                    .unwrap_or_else(|error| {
+                        error!(
+                            "Failed to send ExpireConsumerGroupMembersCommand. Error: {}",
+                            error
+                        );
+                    });
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl ServerCommand<ExpireConsumerGroupMembersCommand> for ExpireConsumerGroupMembersExecutor {
+    #[instrument(skip_all)]
+    async fn execute(
+        &mut self,
+        system: &SharedSystem,
+        _command: ExpireConsumerGroupMembersCommand,
+    ) {
+        let system = system.read().await;
+        let deleted_members = system.delete_expired_consumer_group_members().await;
+        if deleted_members > 0 {
+            info!("Deleted {deleted_members} expired members of consumer groups.");
+        } else {
+            debug!("No expired members of consumer groups found.");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        _system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<ExpireConsumerGroupMembersCommand>,
+    ) -> Option<JoinHandle<()>> {
+        let expirer = ConsumerGroupMembersExpirer::new(&config.system.consumer_group, sender);
+        expirer.start()
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        _config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<ExpireConsumerGroupMembersCommand>,
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(command) = receiver.recv_async().await {
+                self.execute(&system, command).await;
+            }
+            info!("Consumer group members expirer receiver stopped.");
+        }))
+    }
+}
@@ -1,23 +1,35 @@
 use crate::archiver::Archiver;
 use crate::channels::server_command::ServerCommand;
+use crate::configs::maintenance_window::MaintenanceWindow;
 use crate::configs::server::MessagesMaintenanceConfig;
 use crate::map_toggle_str;
-use crate::streaming::systems::system::SharedSystem;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::systems::system::{SharedSystem, System};
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::io_budget::{BackgroundTask, IoBudget};
+use crate::streaming::utils::keyring::Keyring;
 use async_trait::async_trait;
+use chrono::Utc;
 use flume::Sender;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::system_event::SystemEvent;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::Arc;
+use tokio::task::JoinHandle;
 use tokio::time;
 use tracing::{debug, error, info, instrument};
 
 pub struct MessagesMaintainer {
     cleaner_enabled: bool,
     archiver_enabled: bool,
+    compactor_enabled: bool,
+    reencryptor_enabled: bool,
     interval: IggyDuration,
+    tombstone_retention: IggyDuration,
+    windows: Vec<MaintenanceWindow>,
     sender: Sender<MaintainMessagesCommand>,
 }
 
@@ -25,6 +37,10 @@ pub struct MessagesMaintainer {
 pub struct MaintainMessagesCommand {
     clean_messages: bool,
     archive_messages: bool,
+    compact_messages: bool,
+    reencrypt_messages: bool,
+    tombstone_retention: IggyDuration,
+    windows: Vec<MaintenanceWindow>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -38,27 +54,45 @@ impl MessagesMaintainer {
         Self {
             cleaner_enabled: config.cleaner_enabled,
             archiver_enabled: config.archiver_enabled,
+            compactor_enabled: config.compactor_enabled,
+            reencryptor_enabled: config.reencryptor_enabled,
             interval: config.interval,
+            tombstone_retention: config.tombstone_retention,
+            windows: config.windows.clone(),
             sender,
         }
     }
 
-    pub fn start(&self) {
-        if !self.cleaner_enabled && !self.archiver_enabled {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
+        if !self.cleaner_enabled
+            && !self.archiver_enabled
+            && !self.compactor_enabled
+            && !self.reencryptor_enabled
+        {
             info!("Messages maintainer is disabled.");
-            return;
+            return None;
         }
 
         let interval = self.interval;
         let sender = self.sender.clone();
         info!(
-            "Message maintainer, cleaner is {}, archiver is {}, interval: {interval}",
+            "Message maintainer, cleaner is {}, archiver is {}, compactor is {}, re-encryptor is {}, interval: {interval}, windows: {:?}",
             map_toggle_str(self.cleaner_enabled),
-            map_toggle_str(self.archiver_enabled)
+            map_toggle_str(self.archiver_enabled),
+            map_toggle_str(self.compactor_enabled),
+            map_toggle_str(self.reencryptor_enabled),
+            self.windows
+                .iter()
+                .map(|window| window.to_string())
+                .collect::<Vec<_>>()
         );
         let clean_messages = self.cleaner_enabled;
         let archive_messages = self.archiver_enabled;
-        tokio::spawn(async move {
+        let compact_messages = self.compactor_enabled;
+        let reencrypt_messages = self.reencryptor_enabled;
+        let tombstone_retention = self.tombstone_retention;
+        let windows = self.windows.clone();
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
@@ -66,12 +100,16 @@ impl MessagesMaintainer {
                     .send(MaintainMessagesCommand {
                         clean_messages,
                         archive_messages,
+                        compact_messages,
+                        reencrypt_messages,
+                        tombstone_retention,
+                        windows: windows.clone(),
                     })
                     .unwrap_or_else(|err| {
                         error!("Failed to send MaintainMessagesCommand. Error: {}", err);
                     });
             }
-        });
+        }))
     }
 }
 
@@ -79,77 +117,42 @@ impl MessagesMaintainer {
 impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
     #[instrument(skip_all)]
     async fn execute(&mut self, system: &SharedSystem, command: MaintainMessagesCommand) {
-        let system = system.read().await;
-        let streams = system.get_streams();
-        for stream in streams {
-            let topics = stream.get_topics();
-            for topic in topics {
-                let archiver = if command.archive_messages {
-                    system.archiver.clone()
-                } else {
-                    None
-                };
-                let expired_segments = handle_expired_segments(
-                    topic,
-                    archiver.clone(),
-                    system.config.segment.archive_expired,
-                    command.clean_messages,
-                )
-                .await;
-                if expired_segments.is_err() {
-                    error!(
-                        "Failed to get expired segments for stream ID: {}, topic ID: {}",
-                        topic.stream_id, topic.topic_id
-                    );
-                    continue;
-                }
-
-                let oldest_segments = handle_oldest_segments(
-                    topic,
-                    archiver.clone(),
-                    system.config.topic.delete_oldest_segments,
-                )
-                .await;
-                if oldest_segments.is_err() {
-                    error!(
-                        "Failed to get oldest segments for stream ID: {}, topic ID: {}",
-                        topic.stream_id, topic.topic_id
-                    );
-                    continue;
-                }
+        if !MaintenanceWindow::is_allowed(&command.windows, Utc::now().time()) {
+            debug!("Messages maintenance is not allowed outside of the configured windows.");
+            return;
+        }
 
-                let deleted_expired_segments = expired_segments.unwrap();
-                let deleted_oldest_segments = oldest_segments.unwrap();
-                let deleted_segments = HandledSegments {
-                    segments_count: deleted_expired_segments.segments_count
-                        + deleted_oldest_segments.segments_count,
-                    messages_count: deleted_expired_segments.messages_count
-                        + deleted_oldest_segments.messages_count,
-                };
+        let topics = {
+            let system = system.read().await;
+            system
+                .get_streams()
+                .iter()
+                .flat_map(|stream| stream.get_topics())
+                .map(|topic| (topic.stream_id, topic.topic_id))
+                .collect::<Vec<_>>()
+        };
+
+        for (stream_id, topic_id) in topics {
+            if !MaintenanceWindow::is_allowed(&command.windows, Utc::now().time()) {
+                info!("Messages maintenance window has ended, the remaining topics will be maintained in the next window.");
+                return;
+            }
 
-                if deleted_segments.segments_count == 0 {
-                    info!(
-                        "No segments were deleted for stream ID: {}, topic ID: {}",
-                        topic.stream_id, topic.topic_id
-                    );
+            // The system is locked only for a single topic, so that the throttled maintenance doesn't block the other operations.
+            let (io, io_budget) = {
+                let system = system.read().await;
+                let Ok(topic) = system
+                    .get_stream(&Identifier::numeric(stream_id).unwrap())
+                    .and_then(|stream| stream.get_topic(&Identifier::numeric(topic_id).unwrap()))
+                else {
                     continue;
-                }
-
-                info!(
-                    "Deleted {} segments and {} messages for stream ID: {}, topic ID: {}",
-                    deleted_segments.segments_count,
-                    deleted_segments.messages_count,
-                    topic.stream_id,
-                    topic.topic_id
-                );
-
-                system
-                    .metrics
-                    .decrement_segments(deleted_segments.segments_count);
-                system
-                    .metrics
-                    .decrement_messages(deleted_segments.messages_count);
-            }
+                };
+                (
+                    maintain_topic(&system, topic, &command).await,
+                    system.storage.io_budget.clone(),
+                )
+            };
+            io.throttle(&io_budget).await;
         }
     }
 
@@ -158,17 +161,20 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<MaintainMessagesCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
+            && (!config.system.encryption.enabled
+                || !config.data_maintenance.messages.reencryptor_enabled)
         {
-            return;
+            return None;
         }
 
         let messages_maintainer =
             MessagesMaintainer::new(&config.data_maintenance.messages, sender);
-        messages_maintainer.start();
+        messages_maintainer.start()
     }
 
     fn start_command_consumer(
@@ -176,42 +182,177 @@ impl ServerCommand<MaintainMessagesCommand> for MaintainMessagesExecutor {
         system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         receiver: flume::Receiver<MaintainMessagesCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         if (!config.data_maintenance.archiver.enabled
             || !config.data_maintenance.messages.archiver_enabled)
             && !config.data_maintenance.messages.cleaner_enabled
+            && !config.data_maintenance.messages.compactor_enabled
+            && (!config.system.encryption.enabled
+                || !config.data_maintenance.messages.reencryptor_enabled)
         {
-            return;
+            return None;
         }
 
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             info!("Messages maintainer receiver stopped.");
-        });
+        }))
+    }
+}
+
+/// The number of bytes read and written by the maintenance tasks, accounted in the background IO budget.
+#[derive(Debug, Default)]
+struct MaintenanceIo {
+    compacted_bytes: u64,
+    archived_bytes: u64,
+    deleted_bytes: u64,
+}
+
+impl MaintenanceIo {
+    /// Waits until the bytes of all the tasks fit into their shares of the budget, throttling the tasks concurrently,
+    /// so that the rate is split between them by their weights.
+    async fn throttle(&self, io_budget: &Arc<IoBudget>) {
+        tokio::join!(
+            throttle(io_budget, BackgroundTask::Compaction, self.compacted_bytes),
+            throttle(io_budget, BackgroundTask::Archiving, self.archived_bytes),
+            throttle(io_budget, BackgroundTask::Retention, self.deleted_bytes),
+        );
     }
 }
 
+async fn throttle(io_budget: &Arc<IoBudget>, task: BackgroundTask, bytes: u64) {
+    if bytes > 0 {
+        io_budget.throttler(task).throttle(bytes).await;
+    }
+}
+
+/// Maintains the messages of the topic and returns the number of bytes read and written by each task.
+async fn maintain_topic(
+    system: &System,
+    topic: &Topic,
+    command: &MaintainMessagesCommand,
+) -> MaintenanceIo {
+    let mut io = MaintenanceIo::default();
+    if command.compact_messages && topic.cleanup_policy.is_compact() {
+        io.compacted_bytes += compact_topic(topic, command.tombstone_retention).await;
+    }
+
+    if command.reencrypt_messages && system.get_topic_encryptor(topic).is_some() {
+        if let Some(keyring) = &system.keyring {
+            io.compacted_bytes += reencrypt_topic(topic, keyring).await;
+        }
+    }
+
+    let archiver = if command.archive_messages {
+        system.archiver.clone()
+    } else {
+        None
+    };
+    let expired_segments = handle_expired_segments(
+        topic,
+        archiver.clone(),
+        system.config.segment.archive_expired,
+        command.clean_messages,
+    )
+    .await;
+    if expired_segments.is_err() {
+        error!(
+            "Failed to get expired segments for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return io;
+    }
+
+    let oldest_segments = handle_oldest_segments(
+        topic,
+        archiver.clone(),
+        system.config.topic.delete_oldest_segments,
+    )
+    .await;
+    if oldest_segments.is_err() {
+        error!(
+            "Failed to get oldest segments for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return io;
+    }
+
+    let deleted_expired_segments = expired_segments.unwrap();
+    let deleted_oldest_segments = oldest_segments.unwrap();
+    let deleted_segments = HandledSegments {
+        segments_count: deleted_expired_segments.segments_count
+            + deleted_oldest_segments.segments_count,
+        messages_count: deleted_expired_segments.messages_count
+            + deleted_oldest_segments.messages_count,
+        archived_bytes: deleted_expired_segments.archived_bytes
+            + deleted_oldest_segments.archived_bytes,
+        deleted_bytes: deleted_expired_segments.deleted_bytes
+            + deleted_oldest_segments.deleted_bytes,
+    };
+    io.archived_bytes = deleted_segments.archived_bytes;
+    io.deleted_bytes = deleted_segments.deleted_bytes;
+
+    if deleted_segments.segments_count == 0 {
+        info!(
+            "No segments were deleted for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return io;
+    }
+
+    info!(
+        "Deleted {} segments and {} messages for stream ID: {}, topic ID: {}",
+        deleted_segments.segments_count,
+        deleted_segments.messages_count,
+        topic.stream_id,
+        topic.topic_id
+    );
+
+    system
+        .metrics
+        .decrement_segments(deleted_segments.segments_count);
+    system
+        .metrics
+        .decrement_messages(deleted_segments.messages_count);
+    system.events_bus.publish(SystemEvent::RetentionRan {
+        stream_id: topic.stream_id,
+        topic_id: topic.topic_id,
+        deleted_segments: deleted_segments.segments_count,
+        deleted_messages: deleted_segments.messages_count,
+    });
+    io
+}
+
 async fn handle_expired_segments(
     topic: &Topic,
     archiver: Option<Arc<dyn Archiver>>,
     archive: bool,
     clean: bool,
 ) -> Result<HandledSegments, IggyError> {
+    if topic.cleanup_policy.is_compact() {
+        debug!(
+            "Topic is compacted, expired segments will not be handled for stream ID: {}, topic ID: {}",
+            topic.stream_id, topic.topic_id
+        );
+        return Ok(HandledSegments::none());
+    }
+
     let expired_segments = get_expired_segments(topic, IggyTimestamp::now()).await;
     if expired_segments.is_empty() {
         return Ok(HandledSegments::none());
     }
 
+    let mut archived_bytes = 0;
     if archive {
         if let Some(archiver) = archiver {
             info!(
                 "Archiving expired segments for stream ID: {}, topic ID: {}",
                 topic.stream_id, topic.topic_id
             );
-            archive_segments(topic, &expired_segments, archiver.clone()).await?;
+            archived_bytes = archive_segments(topic, &expired_segments, archiver.clone()).await?;
         } else {
             error!(
                 "Archiver is not enabled, yet archive_expired is set to true. Cannot archive expired segments for stream ID: {}, topic ID: {}",
@@ -226,16 +367,86 @@ async fn handle_expired_segments(
             "Deleting expired segments for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        delete_segments(topic, &expired_segments).await
+        let deleted_segments = delete_segments(topic, &expired_segments).await?;
+        Ok(HandledSegments {
+            archived_bytes,
+            ..deleted_segments
+        })
     } else {
         info!(
             "Deleting expired segments is disabled for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        Ok(HandledSegments::none())
+        Ok(HandledSegments::archived(archived_bytes))
     }
 }
 
+/// Compacts the closed segments of the topic and returns their total size in bytes (read and rewritten).
+async fn compact_topic(topic: &Topic, tombstone_retention: IggyDuration) -> u64 {
+    let mut removed_messages = 0;
+    let mut io_bytes = 0;
+    for partition in topic.partitions.values() {
+        let mut partition = partition.write().await;
+        io_bytes += get_closed_segments_size(&partition);
+        match partition.compact(tombstone_retention).await {
+            Ok(compacted) => removed_messages += compacted.removed_messages,
+            Err(error) => {
+                error!(
+                    "Failed to compact partition with ID: {} for stream ID: {}, topic ID: {}. Error: {}",
+                    partition.partition_id, topic.stream_id, topic.topic_id, error
+                );
+            }
+        }
+    }
+
+    if removed_messages > 0 {
+        info!(
+            "Compacted {} messages for stream ID: {}, topic ID: {}",
+            removed_messages, topic.stream_id, topic.topic_id
+        );
+    }
+    io_bytes
+}
+
+/// Re-encrypts the closed segments of the topic and returns their total size in bytes (read and rewritten).
+async fn reencrypt_topic(topic: &Topic, keyring: &Keyring) -> u64 {
+    let mut reencrypted_messages = 0;
+    let mut io_bytes = 0;
+    for partition in topic.partitions.values() {
+        let mut partition = partition.write().await;
+        io_bytes += get_closed_segments_size(&partition);
+        match partition.reencrypt(keyring).await {
+            Ok(messages_count) => reencrypted_messages += messages_count,
+            Err(error) => {
+                error!(
+                    "Failed to re-encrypt partition with ID: {} for stream ID: {}, topic ID: {}. Error: {}",
+                    partition.partition_id, topic.stream_id, topic.topic_id, error
+                );
+            }
+        }
+    }
+
+    if reencrypted_messages > 0 {
+        info!(
+            "Re-encrypted {} messages with key version: {} for stream ID: {}, topic ID: {}",
+            reencrypted_messages,
+            keyring.current_version(),
+            topic.stream_id,
+            topic.topic_id
+        );
+    }
+    io_bytes
+}
+
+fn get_closed_segments_size(partition: &Partition) -> u64 {
+    partition
+        .get_segments()
+        .iter()
+        .filter(|segment| segment.is_closed)
+        .map(|segment| segment.size_bytes.as_bytes_u64())
+        .sum()
+}
+
 async fn get_expired_segments(topic: &Topic, now: IggyTimestamp) -> Vec<SegmentsToHandle> {
     let expired_segments = topic
         .get_expired_segments_start_offsets_per_partition(now)
@@ -269,6 +480,7 @@ async fn handle_oldest_segments(
     archiver: Option<Arc<dyn Archiver>>,
     delete_oldest_segments: bool,
 ) -> Result<HandledSegments, IggyError> {
+    let mut archived_bytes = 0;
     if let Some(archiver) = archiver {
         let mut segments_to_archive = Vec::new();
         for partition in topic.partitions.values() {
@@ -317,7 +529,7 @@ async fn handle_oldest_segments(
             topic.stream_id,
             topic.topic_id,
         );
-        archive_segments(topic, &segments_to_archive, archiver.clone()).await?;
+        archived_bytes = archive_segments(topic, &segments_to_archive, archiver.clone()).await?;
     }
 
     if topic.is_unlimited() {
@@ -325,7 +537,7 @@ async fn handle_oldest_segments(
             "Topic is unlimited, oldest segments will not be deleted for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        return Ok(HandledSegments::none());
+        return Ok(HandledSegments::archived(archived_bytes));
     }
 
     if !delete_oldest_segments {
@@ -333,7 +545,7 @@ async fn handle_oldest_segments(
             "Delete oldest segments is disabled, oldest segments will not be deleted for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        return Ok(HandledSegments::none());
+        return Ok(HandledSegments::archived(archived_bytes));
     }
 
     if !topic.is_almost_full() {
@@ -341,15 +553,19 @@ async fn handle_oldest_segments(
             "Topic is not almost full, oldest segments will not be deleted for stream ID: {}, topic ID: {}",
             topic.stream_id, topic.topic_id
         );
-        return Ok(HandledSegments::none());
+        return Ok(HandledSegments::archived(archived_bytes));
     }
 
     let oldest_segments = get_oldest_segments(topic).await;
     if oldest_segments.is_empty() {
-        return Ok(HandledSegments::none());
+        return Ok(HandledSegments::archived(archived_bytes));
     }
 
-    delete_segments(topic, &oldest_segments).await
+    let deleted_segments = delete_segments(topic, &oldest_segments).await?;
+    Ok(HandledSegments {
+        archived_bytes,
+        ..deleted_segments
+    })
 }
 
 async fn get_oldest_segments(topic: &Topic) -> Vec<SegmentsToHandle> {
@@ -396,17 +612,26 @@ struct SegmentsToHandle {
 struct HandledSegments {
     pub segments_count: u32,
     pub messages_count: u64,
+    pub archived_bytes: u64,
+    pub deleted_bytes: u64,
 }
 
 impl HandledSegments {
     pub fn none() -> Self {
+        Self::archived(0)
+    }
+
+    pub fn archived(archived_bytes: u64) -> Self {
         Self {
             segments_count: 0,
             messages_count: 0,
+            archived_bytes,
+            deleted_bytes: 0,
         }
     }
 }
 
+/// Archives the segments and returns their total size in bytes.
 async fn archive_segments(
     topic: &Topic,
     segments_to_archive: &[SegmentsToHandle],
@@ -423,7 +648,7 @@ async fn archive_segments(
         topic.topic_id
     );
 
-    let mut archived_segments = 0;
+    let mut archived_bytes = 0;
     for segment_to_archive in segments_to_archive {
         match topic.get_partition(segment_to_archive.partition_id) {
             Ok(partition) => {
@@ -451,7 +676,7 @@ async fn archive_segments(
                         "Archived Segment with start offset: {}, for stream ID: {}, topic ID: {}, partition ID: {}",
                         start_offset, topic.stream_id, topic.topic_id, partition.partition_id
                     );
-                    archived_segments += 1;
+                    archived_bytes += segment.size_bytes.as_bytes_u64();
                 }
             }
             Err(error) => {
@@ -464,7 +689,7 @@ async fn archive_segments(
         }
     }
 
-    Ok(archived_segments)
+    Ok(archived_bytes)
 }
 
 async fn delete_segments(
@@ -480,6 +705,7 @@ async fn delete_segments(
 
     let mut segments_count = 0;
     let mut messages_count = 0;
+    let mut deleted_bytes = 0;
     for segment_to_delete in segments_to_delete {
         match topic.get_partition(segment_to_delete.partition_id) {
             Ok(partition) => {
@@ -490,6 +716,7 @@ async fn delete_segments(
                     last_end_offset = deleted_segment.end_offset;
                     segments_count += 1;
                     messages_count += deleted_segment.messages_count;
+                    deleted_bytes += deleted_segment.size_bytes;
                 }
 
                 if partition.get_segments().is_empty() {
@@ -510,5 +737,7 @@ async fn delete_segments(
     Ok(HandledSegments {
         segments_count,
         messages_count,
+        archived_bytes: 0,
+        deleted_bytes,
     })
 }
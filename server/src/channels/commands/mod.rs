@@ -1,6 +1,11 @@
 pub mod archive_state;
+pub mod check_disk_space;
 pub mod clean_personal_access_tokens;
+pub mod compact_consumer_offsets;
+pub mod deliver_webhooks;
+pub mod expire_consumer_group_members;
 pub mod maintain_messages;
 pub mod print_sysinfo;
+pub mod publish_events;
 pub mod save_messages;
 pub mod verify_heartbeats;
@@ -5,6 +5,7 @@ use crate::{
 use async_trait::async_trait;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
+use tokio::task::JoinHandle;
 use tokio::time::{self};
 use tracing::{error, info, warn};
 
@@ -23,16 +24,16 @@ impl SysInfoPrinter {
         Self { interval, sender }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
         let interval = self.interval;
         let sender = self.sender.clone();
         if interval.is_zero() {
             info!("SysInfoPrinter is disabled.");
-            return;
+            return None;
         }
 
         info!("SysInfoPrinter is enabled, system information will be printed every {interval}.");
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
@@ -41,7 +42,7 @@ impl SysInfoPrinter {
                     error!("Failed to send SysInfoPrintCommand. Error: {e}");
                 });
             }
-        });
+        }))
     }
 }
 
@@ -79,9 +80,9 @@ impl ServerCommand<SysInfoPrintCommand> for SysInfoPrintExecutor {
         _system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<SysInfoPrintCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         let printer = SysInfoPrinter::new(config.system.logging.sysinfo_print_interval, sender);
-        printer.start();
+        printer.start()
     }
 
     fn start_command_consumer(
@@ -89,13 +90,13 @@ impl ServerCommand<SysInfoPrintCommand> for SysInfoPrintExecutor {
         system: SharedSystem,
         _config: &ServerConfig,
         receiver: Receiver<SysInfoPrintCommand>,
-    ) {
-        tokio::spawn(async move {
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             warn!("Sysinfo printer stopped receiving commands.");
-        });
+        }))
     }
 }
@@ -0,0 +1,54 @@
+use crate::channels::server_command::ServerCommand;
+use crate::streaming::systems::system::SharedSystem;
+use async_trait::async_trait;
+use flume::Sender;
+use iggy::models::system_event::SystemEvent;
+use tokio::task::JoinHandle;
+use tracing::{error, info, instrument};
+
+#[derive(Debug, Default, Clone)]
+pub struct PublishEventsExecutor;
+
+#[async_trait]
+impl ServerCommand<SystemEvent> for PublishEventsExecutor {
+    #[instrument(skip_all)]
+    async fn execute(&mut self, system: &SharedSystem, event: SystemEvent) {
+        let system = system.read().await;
+        if let Err(error) = system.append_event(&event).await {
+            error!("Failed to append system event: {event:?}. Error: {error}");
+        }
+    }
+
+    fn start_command_sender(
+        &mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        sender: Sender<SystemEvent>,
+    ) -> Option<JoinHandle<()>> {
+        if !config.system.events.enabled {
+            return None;
+        }
+
+        system.subscribe_to_events(sender);
+        None
+    }
+
+    fn start_command_consumer(
+        mut self,
+        system: SharedSystem,
+        config: &crate::configs::server::ServerConfig,
+        receiver: flume::Receiver<SystemEvent>,
+    ) -> Option<JoinHandle<()>> {
+        if !config.system.events.enabled {
+            return None;
+        }
+
+        Some(tokio::spawn(async move {
+            let system = system.clone();
+            while let Ok(event) = receiver.recv_async().await {
+                self.execute(&system, event).await;
+            }
+            info!("System events publisher receiver stopped.");
+        }))
+    }
+}
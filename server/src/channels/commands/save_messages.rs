@@ -5,6 +5,7 @@ use crate::streaming::systems::system::SharedSystem;
 use async_trait::async_trait;
 use flume::{Receiver, Sender};
 use iggy::utils::duration::IggyDuration;
+use tokio::task::JoinHandle;
 use tokio::time;
 use tracing::{error, info, instrument, warn};
 
@@ -33,17 +34,17 @@ impl MessagesSaver {
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
         if !self.enabled {
             info!("Message saver is disabled.");
-            return;
+            return None;
         }
 
         let enforce_fsync = self.enforce_fsync;
         let interval = self.interval;
         let sender = self.sender.clone();
         info!("Message saver is enabled, buffered messages will be automatically saved every: {interval}, enforce fsync: {enforce_fsync}.");
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
@@ -52,7 +53,7 @@ impl MessagesSaver {
                     error!("Failed to send SaveMessagesCommand. Error: {e}",);
                 });
             }
-        });
+        }))
     }
 }
 
@@ -78,9 +79,9 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
         _system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<SaveMessagesCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         let messages_saver = MessagesSaver::new(&config.message_saver, sender);
-        messages_saver.start();
+        messages_saver.start()
     }
 
     fn start_command_consumer(
@@ -88,13 +89,13 @@ impl ServerCommand<SaveMessagesCommand> for SaveMessagesExecutor {
         system: SharedSystem,
         _config: &ServerConfig,
         receiver: Receiver<SaveMessagesCommand>,
-    ) {
-        tokio::spawn(async move {
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             warn!("Server command handler stopped receiving commands.");
-        });
+        }))
     }
 }
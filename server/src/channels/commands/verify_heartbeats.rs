@@ -6,20 +6,20 @@ use flume::Sender;
 use iggy::locking::IggySharedMutFn;
 use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
+use tokio::task::JoinHandle;
 use tokio::time;
 use tracing::{debug, error, info, instrument, warn};
 
-const MAX_THRESHOLD: f64 = 1.2;
-
 pub struct VerifyHeartbeats {
     enabled: bool,
     interval: IggyDuration,
+    timeout: IggyDuration,
     sender: Sender<VerifyHeartbeatsCommand>,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct VerifyHeartbeatsCommand {
-    interval: IggyDuration,
+    timeout: IggyDuration,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -30,36 +30,33 @@ impl VerifyHeartbeats {
         Self {
             enabled: config.enabled,
             interval: config.interval,
+            timeout: config.timeout,
             sender,
         }
     }
 
-    pub fn start(&self) {
+    pub fn start(&self) -> Option<JoinHandle<()>> {
         if !self.enabled {
             info!("Heartbeats verification is disabled.");
-            return;
+            return None;
         }
 
         let interval = self.interval;
-        let max_interval = IggyDuration::from((MAX_THRESHOLD * interval.as_micros() as f64) as u64);
+        let timeout = self.timeout;
         let sender = self.sender.clone();
-        info!(
-            "Heartbeats will be verified every: {interval}. Max allowed interval: {max_interval}."
-        );
-        tokio::spawn(async move {
+        info!("Heartbeats will be verified every: {interval}. Idle clients timeout: {timeout}.");
+        Some(tokio::spawn(async move {
             let mut interval_timer = time::interval(interval.get_duration());
             loop {
                 interval_timer.tick().await;
                 debug!("Verifying heartbeats...");
                 sender
-                    .send(VerifyHeartbeatsCommand {
-                        interval: max_interval,
-                    })
+                    .send(VerifyHeartbeatsCommand { timeout })
                     .unwrap_or_else(|error| {
                         error!("Failed to send VerifyHeartbeats. Error: {}", error);
                     });
             }
-        });
+        }))
     }
 }
 
@@ -75,23 +72,24 @@ impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
         }
 
         let now = IggyTimestamp::now();
-        let heartbeat_to = IggyTimestamp::from(now.as_micros() - command.interval.as_micros());
+        let heartbeat_to =
+            IggyTimestamp::from(now.as_micros().saturating_sub(command.timeout.as_micros()));
         debug!("Verifying heartbeats at: {now}, max allowed timestamp: {heartbeat_to}");
         let mut stale_clients = Vec::new();
         for client in clients {
             let client = client.read().await;
-            if client.last_heartbeat.as_micros() < heartbeat_to.as_micros() {
+            let last_activity = client.session.get_last_activity();
+            if last_activity.as_micros() < heartbeat_to.as_micros() {
                 warn!(
-                    "Stale client session: {}, last heartbeat at: {}, max allowed timestamp: {heartbeat_to}",
+                    "Stale client session: {}, last activity at: {last_activity}, max allowed timestamp: {heartbeat_to}",
                     client.session,
-                    client.last_heartbeat,
                 );
-                client.session.set_stale();
+                // Terminating the session closes the idle connection right away, instead of waiting for the next command.
+                client.session.terminate();
                 stale_clients.push(client.session.client_id);
             } else {
                 debug!(
-                    "Valid heartbeat at: {} for client session: {}, max allowed timestamp: {heartbeat_to}",
-                    client.last_heartbeat,
+                    "Valid heartbeat at: {last_activity} for client session: {}, max allowed timestamp: {heartbeat_to}",
                     client.session,
                 );
             }
@@ -114,9 +112,9 @@ impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
         _system: SharedSystem,
         config: &crate::configs::server::ServerConfig,
         sender: Sender<VerifyHeartbeatsCommand>,
-    ) {
+    ) -> Option<JoinHandle<()>> {
         let verify_heartbeats = VerifyHeartbeats::new(&config.heartbeat, sender);
-        verify_heartbeats.start();
+        verify_heartbeats.start()
     }
 
     fn start_command_consumer(
@@ -124,13 +122,13 @@ impl ServerCommand<VerifyHeartbeatsCommand> for VerifyHeartbeatsExecutor {
         system: SharedSystem,
         _config: &crate::configs::server::ServerConfig,
         receiver: flume::Receiver<VerifyHeartbeatsCommand>,
-    ) {
-        tokio::spawn(async move {
+    ) -> Option<JoinHandle<()>> {
+        Some(tokio::spawn(async move {
             let system = system.clone();
             while let Ok(command) = receiver.recv_async().await {
                 self.execute(&system, command).await;
             }
             info!("Heartbeats verifier receiver stopped.");
-        });
+        }))
     }
 }
@@ -1,28 +1,66 @@
 use super::server_command::ServerCommand;
 use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
+use tokio::task::JoinHandle;
 
 pub struct ServerCommandHandler<'a> {
     system: SharedSystem,
     config: &'a ServerConfig,
+    senders: Vec<JoinHandle<()>>,
+    consumers: Vec<JoinHandle<()>>,
 }
 
 impl<'a> ServerCommandHandler<'a> {
     pub fn new(system: SharedSystem, config: &'a ServerConfig) -> Self {
-        Self { system, config }
+        Self {
+            system,
+            config,
+            senders: Vec::new(),
+            consumers: Vec::new(),
+        }
     }
 
-    pub fn install_handler<C, E>(&mut self, mut executor: E) -> Self
+    pub fn install_handler<C, E>(mut self, mut executor: E) -> Self
     where
         E: ServerCommand<C> + Send + Sync + 'static,
     {
         let (sender, receiver) = flume::unbounded();
         let system = self.system.clone();
-        executor.start_command_sender(system.clone(), self.config, sender);
-        executor.start_command_consumer(system.clone(), self.config, receiver);
-        Self {
-            system,
-            config: self.config,
+        self.senders
+            .extend(executor.start_command_sender(system.clone(), self.config, sender));
+        self.consumers
+            .extend(executor.start_command_consumer(system, self.config, receiver));
+        self
+    }
+
+    /// Returns the background tasks of the installed handlers, which can be stopped with `ServerCommandTasks::stop`.
+    pub fn into_tasks(self) -> ServerCommandTasks {
+        ServerCommandTasks {
+            senders: self.senders,
+            consumers: self.consumers,
+        }
+    }
+}
+
+/// The background tasks sending and executing the server commands.
+#[derive(Debug)]
+pub struct ServerCommandTasks {
+    senders: Vec<JoinHandle<()>>,
+    consumers: Vec<JoinHandle<()>>,
+}
+
+impl ServerCommandTasks {
+    /// Stops sending the commands and waits until the already sent ones are executed,
+    /// so that none of the tasks uses the system anymore once this returns.
+    pub async fn stop(self) {
+        for sender in &self.senders {
+            sender.abort();
+        }
+        for sender in self.senders {
+            _ = sender.await;
+        }
+        for consumer in self.consumers {
+            _ = consumer.await;
         }
     }
 }
@@ -2,22 +2,26 @@ use crate::configs::server::ServerConfig;
 use crate::streaming::systems::system::SharedSystem;
 use async_trait::async_trait;
 use flume::{Receiver, Sender};
+use tokio::task::JoinHandle;
 
 #[async_trait]
 pub trait ServerCommand<C> {
     async fn execute(&mut self, system: &SharedSystem, command: C);
 
+    /// Starts sending the commands, returning the spawned task, if any.
     fn start_command_sender(
         &mut self,
         system: SharedSystem,
         config: &ServerConfig,
         sender: Sender<C>,
-    );
+    ) -> Option<JoinHandle<()>>;
 
+    /// Starts executing the received commands, returning the spawned task, if any,
+    /// which finishes once all the senders of the commands are dropped.
     fn start_command_consumer(
         self,
         system: SharedSystem,
         config: &ServerConfig,
         receiver: Receiver<C>,
-    );
+    ) -> Option<JoinHandle<()>>;
 }
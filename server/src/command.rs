@@ -4,36 +4,67 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_offsets::delete_consumer::DeleteConsumer;
+use iggy::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::get_consumers::GetConsumers;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::IggyError;
+use iggy::messages::acquire_exclusive_producer::AcquireExclusiveProducer;
+use iggy::messages::copy_messages::CopyMessages;
+use iggy::messages::fetch_replica_messages::FetchReplicaMessages;
+use iggy::messages::get_message_by_key::GetMessageByKey;
+use iggy::messages::init_producer_epoch::InitProducerEpoch;
+use iggy::messages::peek_messages::PeekMessages;
 use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::poll_messages_from_partitions::PollMessagesFromPartitions;
 use iggy::messages::send_messages::SendMessages;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_messages_histogram::GetMessagesHistogram;
+use iggy::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use iggy::partitions::get_partition::GetPartition;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
+use iggy::partitions::seal_partition::SealPartition;
 use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
+use iggy::system::decommission_node::DecommissionNode;
+use iggy::system::drain_node::DrainNode;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
+use iggy::system::get_cluster_metadata::GetClusterMetadata;
 use iggy::system::get_me::GetMe;
+use iggy::system::get_node_status::GetNodeStatus;
+use iggy::system::get_sessions::GetSessions;
 use iggy::system::get_snapshot::GetSnapshot;
 use iggy::system::get_stats::GetStats;
+use iggy::system::kill_session::KillSession;
 use iggy::system::ping::Ping;
+use iggy::system::rotate_encryption_key::RotateEncryptionKey;
+use iggy::system::set_background_io_rate::SetBackgroundIoRate;
+use iggy::system::set_log_level::SetLogLevel;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::get_topic::GetTopic;
 use iggy::topics::get_topics::GetTopics;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
@@ -46,7 +77,8 @@ use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
 use iggy::{
-    bytes_serializable::BytesSerializable, messages::flush_unsaved_buffer::FlushUnsavedBuffer,
+    bytes_serializable::{read_u32_le, BytesSerializable},
+    messages::flush_unsaved_buffer::FlushUnsavedBuffer,
 };
 use std::fmt::{Display, Formatter};
 use strum::EnumString;
@@ -58,6 +90,8 @@ pub enum ServerCommand {
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
+    GetSessions(GetSessions),
+    KillSession(KillSession),
     GetUser(GetUser),
     GetUsers(GetUsers),
     CreateUser(CreateUser),
@@ -73,8 +107,20 @@ pub enum ServerCommand {
     LoginWithPersonalAccessToken(LoginWithPersonalAccessToken),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    PollMessagesFromPartitions(PollMessagesFromPartitions),
+    PeekMessages(PeekMessages),
+    GetMessageByKey(GetMessageByKey),
+    FetchReplicaMessages(FetchReplicaMessages),
+    AcquireExclusiveProducer(AcquireExclusiveProducer),
+    InitProducerEpoch(InitProducerEpoch),
+    CopyMessages(CopyMessages),
     FlushUnsavedBuffer(FlushUnsavedBuffer),
     GetConsumerOffset(GetConsumerOffset),
+    GetConsumerLag(GetConsumerLag),
+    ResetConsumerOffsets(ResetConsumerOffsets),
+    GetConsumers(GetConsumers),
+    DeleteConsumer(DeleteConsumer),
+    DeleteConsumerOffsets(DeleteConsumerOffsets),
     StoreConsumerOffset(StoreConsumerOffset),
     GetStream(GetStream),
     GetStreams(GetStreams),
@@ -88,15 +134,114 @@ pub enum ServerCommand {
     DeleteTopic(DeleteTopic),
     UpdateTopic(UpdateTopic),
     PurgeTopic(PurgeTopic),
+    SetTopicReadOnly(SetTopicReadOnly),
+    GetSchema(GetSchema),
+    GetSchemas(GetSchemas),
+    RegisterSchema(RegisterSchema),
+    GetPartition(GetPartition),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
+    GetPartitionOffsets(GetPartitionOffsets),
+    GetOffsetByTimestamp(GetOffsetByTimestamp),
+    GetMessagesHistogram(GetMessagesHistogram),
+    SealPartition(SealPartition),
     GetConsumerGroup(GetConsumerGroup),
     GetConsumerGroups(GetConsumerGroups),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup),
     GetSnapshotFile(GetSnapshot),
+    RotateEncryptionKey(RotateEncryptionKey),
+    SetLogLevel(SetLogLevel),
+    SetBackgroundIoRate(SetBackgroundIoRate),
+    GetClusterMetadata(GetClusterMetadata),
+    DrainNode(DrainNode),
+    DecommissionNode(DecommissionNode),
+    GetNodeStatus(GetNodeStatus),
+}
+
+impl ServerCommand {
+    pub fn code(&self) -> u32 {
+        match self {
+            ServerCommand::Ping(command) => command.code(),
+            ServerCommand::GetStats(command) => command.code(),
+            ServerCommand::GetMe(command) => command.code(),
+            ServerCommand::GetClient(command) => command.code(),
+            ServerCommand::GetClients(command) => command.code(),
+            ServerCommand::GetSessions(command) => command.code(),
+            ServerCommand::KillSession(command) => command.code(),
+            ServerCommand::GetUser(command) => command.code(),
+            ServerCommand::GetUsers(command) => command.code(),
+            ServerCommand::CreateUser(command) => command.code(),
+            ServerCommand::DeleteUser(command) => command.code(),
+            ServerCommand::UpdateUser(command) => command.code(),
+            ServerCommand::UpdatePermissions(command) => command.code(),
+            ServerCommand::ChangePassword(command) => command.code(),
+            ServerCommand::LoginUser(command) => command.code(),
+            ServerCommand::LogoutUser(command) => command.code(),
+            ServerCommand::GetPersonalAccessTokens(command) => command.code(),
+            ServerCommand::CreatePersonalAccessToken(command) => command.code(),
+            ServerCommand::DeletePersonalAccessToken(command) => command.code(),
+            ServerCommand::LoginWithPersonalAccessToken(command) => command.code(),
+            ServerCommand::SendMessages(command) => command.code(),
+            ServerCommand::PollMessages(command) => command.code(),
+            ServerCommand::PollMessagesFromPartitions(command) => command.code(),
+            ServerCommand::PeekMessages(command) => command.code(),
+            ServerCommand::GetMessageByKey(command) => command.code(),
+            ServerCommand::FetchReplicaMessages(command) => command.code(),
+            ServerCommand::AcquireExclusiveProducer(command) => command.code(),
+            ServerCommand::InitProducerEpoch(command) => command.code(),
+            ServerCommand::CopyMessages(command) => command.code(),
+            ServerCommand::StoreConsumerOffset(command) => command.code(),
+            ServerCommand::GetConsumerOffset(command) => command.code(),
+            ServerCommand::GetConsumerLag(command) => command.code(),
+            ServerCommand::ResetConsumerOffsets(command) => command.code(),
+            ServerCommand::GetConsumers(command) => command.code(),
+            ServerCommand::DeleteConsumer(command) => command.code(),
+            ServerCommand::DeleteConsumerOffsets(command) => command.code(),
+            ServerCommand::GetStream(command) => command.code(),
+            ServerCommand::GetStreams(command) => command.code(),
+            ServerCommand::CreateStream(command) => command.code(),
+            ServerCommand::DeleteStream(command) => command.code(),
+            ServerCommand::UpdateStream(command) => command.code(),
+            ServerCommand::PurgeStream(command) => command.code(),
+            ServerCommand::GetTopic(command) => command.code(),
+            ServerCommand::GetTopics(command) => command.code(),
+            ServerCommand::CreateTopic(command) => command.code(),
+            ServerCommand::DeleteTopic(command) => command.code(),
+            ServerCommand::UpdateTopic(command) => command.code(),
+            ServerCommand::PurgeTopic(command) => command.code(),
+            ServerCommand::SetTopicReadOnly(command) => command.code(),
+            ServerCommand::GetSchema(command) => command.code(),
+            ServerCommand::GetSchemas(command) => command.code(),
+            ServerCommand::RegisterSchema(command) => command.code(),
+            ServerCommand::GetPartition(command) => command.code(),
+            ServerCommand::CreatePartitions(command) => command.code(),
+            ServerCommand::DeletePartitions(command) => command.code(),
+            ServerCommand::GetPartitionOffsets(command) => command.code(),
+            ServerCommand::GetOffsetByTimestamp(command) => command.code(),
+            ServerCommand::GetMessagesHistogram(command) => command.code(),
+            ServerCommand::SealPartition(command) => command.code(),
+            ServerCommand::GetConsumerGroup(command) => command.code(),
+            ServerCommand::GetConsumerGroups(command) => command.code(),
+            ServerCommand::CreateConsumerGroup(command) => command.code(),
+            ServerCommand::DeleteConsumerGroup(command) => command.code(),
+            ServerCommand::JoinConsumerGroup(command) => command.code(),
+            ServerCommand::LeaveConsumerGroup(command) => command.code(),
+            ServerCommand::HeartbeatConsumerGroup(command) => command.code(),
+            ServerCommand::FlushUnsavedBuffer(command) => command.code(),
+            ServerCommand::GetSnapshotFile(command) => command.code(),
+            ServerCommand::RotateEncryptionKey(command) => command.code(),
+            ServerCommand::SetLogLevel(command) => command.code(),
+            ServerCommand::SetBackgroundIoRate(command) => command.code(),
+            ServerCommand::GetClusterMetadata(command) => command.code(),
+            ServerCommand::DrainNode(command) => command.code(),
+            ServerCommand::DecommissionNode(command) => command.code(),
+            ServerCommand::GetNodeStatus(command) => command.code(),
+        }
+    }
 }
 
 impl BytesSerializable for ServerCommand {
@@ -107,6 +252,8 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::GetMe(payload) => as_bytes(payload),
             ServerCommand::GetClient(payload) => as_bytes(payload),
             ServerCommand::GetClients(payload) => as_bytes(payload),
+            ServerCommand::GetSessions(payload) => as_bytes(payload),
+            ServerCommand::KillSession(payload) => as_bytes(payload),
             ServerCommand::GetUser(payload) => as_bytes(payload),
             ServerCommand::GetUsers(payload) => as_bytes(payload),
             ServerCommand::CreateUser(payload) => as_bytes(payload),
@@ -122,8 +269,20 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::LoginWithPersonalAccessToken(payload) => as_bytes(payload),
             ServerCommand::SendMessages(payload) => as_bytes(payload),
             ServerCommand::PollMessages(payload) => as_bytes(payload),
+            ServerCommand::PollMessagesFromPartitions(payload) => as_bytes(payload),
+            ServerCommand::PeekMessages(payload) => as_bytes(payload),
+            ServerCommand::GetMessageByKey(payload) => as_bytes(payload),
+            ServerCommand::FetchReplicaMessages(payload) => as_bytes(payload),
+            ServerCommand::AcquireExclusiveProducer(payload) => as_bytes(payload),
+            ServerCommand::InitProducerEpoch(payload) => as_bytes(payload),
+            ServerCommand::CopyMessages(payload) => as_bytes(payload),
             ServerCommand::StoreConsumerOffset(payload) => as_bytes(payload),
             ServerCommand::GetConsumerOffset(payload) => as_bytes(payload),
+            ServerCommand::GetConsumerLag(payload) => as_bytes(payload),
+            ServerCommand::ResetConsumerOffsets(payload) => as_bytes(payload),
+            ServerCommand::GetConsumers(payload) => as_bytes(payload),
+            ServerCommand::DeleteConsumer(payload) => as_bytes(payload),
+            ServerCommand::DeleteConsumerOffsets(payload) => as_bytes(payload),
             ServerCommand::GetStream(payload) => as_bytes(payload),
             ServerCommand::GetStreams(payload) => as_bytes(payload),
             ServerCommand::CreateStream(payload) => as_bytes(payload),
@@ -136,21 +295,38 @@ impl BytesSerializable for ServerCommand {
             ServerCommand::DeleteTopic(payload) => as_bytes(payload),
             ServerCommand::UpdateTopic(payload) => as_bytes(payload),
             ServerCommand::PurgeTopic(payload) => as_bytes(payload),
+            ServerCommand::SetTopicReadOnly(payload) => as_bytes(payload),
+            ServerCommand::GetSchema(payload) => as_bytes(payload),
+            ServerCommand::GetSchemas(payload) => as_bytes(payload),
+            ServerCommand::RegisterSchema(payload) => as_bytes(payload),
+            ServerCommand::GetPartition(payload) => as_bytes(payload),
             ServerCommand::CreatePartitions(payload) => as_bytes(payload),
             ServerCommand::DeletePartitions(payload) => as_bytes(payload),
+            ServerCommand::GetPartitionOffsets(payload) => as_bytes(payload),
+            ServerCommand::GetOffsetByTimestamp(payload) => as_bytes(payload),
+            ServerCommand::GetMessagesHistogram(payload) => as_bytes(payload),
+            ServerCommand::SealPartition(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::GetConsumerGroups(payload) => as_bytes(payload),
             ServerCommand::CreateConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::DeleteConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::JoinConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::LeaveConsumerGroup(payload) => as_bytes(payload),
+            ServerCommand::HeartbeatConsumerGroup(payload) => as_bytes(payload),
             ServerCommand::FlushUnsavedBuffer(payload) => as_bytes(payload),
             ServerCommand::GetSnapshotFile(payload) => as_bytes(payload),
+            ServerCommand::RotateEncryptionKey(payload) => as_bytes(payload),
+            ServerCommand::SetLogLevel(payload) => as_bytes(payload),
+            ServerCommand::SetBackgroundIoRate(payload) => as_bytes(payload),
+            ServerCommand::GetClusterMetadata(payload) => as_bytes(payload),
+            ServerCommand::DrainNode(payload) => as_bytes(payload),
+            ServerCommand::DecommissionNode(payload) => as_bytes(payload),
+            ServerCommand::GetNodeStatus(payload) => as_bytes(payload),
         }
     }
 
     fn from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
-        let code = u32::from_le_bytes(bytes[..4].try_into()?);
+        let code = read_u32_le(&bytes, 0)?;
         let payload = bytes.slice(4..);
         match code {
             PING_CODE => Ok(ServerCommand::Ping(Ping::from_bytes(payload)?)),
@@ -158,6 +334,12 @@ impl BytesSerializable for ServerCommand {
             GET_ME_CODE => Ok(ServerCommand::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(ServerCommand::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(ServerCommand::GetClients(GetClients::from_bytes(payload)?)),
+            GET_SESSIONS_CODE => Ok(ServerCommand::GetSessions(GetSessions::from_bytes(
+                payload,
+            )?)),
+            KILL_SESSION_CODE => Ok(ServerCommand::KillSession(KillSession::from_bytes(
+                payload,
+            )?)),
             GET_USER_CODE => Ok(ServerCommand::GetUser(GetUser::from_bytes(payload)?)),
             GET_USERS_CODE => Ok(ServerCommand::GetUsers(GetUsers::from_bytes(payload)?)),
             CREATE_USER_CODE => Ok(ServerCommand::CreateUser(CreateUser::from_bytes(payload)?)),
@@ -191,6 +373,27 @@ impl BytesSerializable for ServerCommand {
             POLL_MESSAGES_CODE => Ok(ServerCommand::PollMessages(PollMessages::from_bytes(
                 payload,
             )?)),
+            POLL_MESSAGES_FROM_PARTITIONS_CODE => Ok(ServerCommand::PollMessagesFromPartitions(
+                PollMessagesFromPartitions::from_bytes(payload)?,
+            )),
+            PEEK_MESSAGES_CODE => Ok(ServerCommand::PeekMessages(PeekMessages::from_bytes(
+                payload,
+            )?)),
+            GET_MESSAGE_BY_KEY_CODE => Ok(ServerCommand::GetMessageByKey(
+                GetMessageByKey::from_bytes(payload)?,
+            )),
+            FETCH_REPLICA_MESSAGES_CODE => Ok(ServerCommand::FetchReplicaMessages(
+                FetchReplicaMessages::from_bytes(payload)?,
+            )),
+            ACQUIRE_EXCLUSIVE_PRODUCER_CODE => Ok(ServerCommand::AcquireExclusiveProducer(
+                AcquireExclusiveProducer::from_bytes(payload)?,
+            )),
+            INIT_PRODUCER_EPOCH_CODE => Ok(ServerCommand::InitProducerEpoch(
+                InitProducerEpoch::from_bytes(payload)?,
+            )),
+            COPY_MESSAGES_CODE => Ok(ServerCommand::CopyMessages(CopyMessages::from_bytes(
+                payload,
+            )?)),
             FLUSH_UNSAVED_BUFFER_CODE => Ok(ServerCommand::FlushUnsavedBuffer(
                 FlushUnsavedBuffer::from_bytes(payload)?,
             )),
@@ -200,6 +403,21 @@ impl BytesSerializable for ServerCommand {
             GET_CONSUMER_OFFSET_CODE => Ok(ServerCommand::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
+            GET_CONSUMER_LAG_CODE => Ok(ServerCommand::GetConsumerLag(GetConsumerLag::from_bytes(
+                payload,
+            )?)),
+            RESET_CONSUMER_OFFSETS_CODE => Ok(ServerCommand::ResetConsumerOffsets(
+                ResetConsumerOffsets::from_bytes(payload)?,
+            )),
+            GET_CONSUMERS_CODE => Ok(ServerCommand::GetConsumers(GetConsumers::from_bytes(
+                payload,
+            )?)),
+            DELETE_CONSUMER_CODE => Ok(ServerCommand::DeleteConsumer(DeleteConsumer::from_bytes(
+                payload,
+            )?)),
+            DELETE_CONSUMER_OFFSETS_CODE => Ok(ServerCommand::DeleteConsumerOffsets(
+                DeleteConsumerOffsets::from_bytes(payload)?,
+            )),
             GET_STREAM_CODE => Ok(ServerCommand::GetStream(GetStream::from_bytes(payload)?)),
             GET_STREAMS_CODE => Ok(ServerCommand::GetStreams(GetStreams::from_bytes(payload)?)),
             CREATE_STREAM_CODE => Ok(ServerCommand::CreateStream(CreateStream::from_bytes(
@@ -226,12 +444,35 @@ impl BytesSerializable for ServerCommand {
                 payload,
             )?)),
             PURGE_TOPIC_CODE => Ok(ServerCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            SET_TOPIC_READ_ONLY_CODE => Ok(ServerCommand::SetTopicReadOnly(
+                SetTopicReadOnly::from_bytes(payload)?,
+            )),
+            GET_SCHEMA_CODE => Ok(ServerCommand::GetSchema(GetSchema::from_bytes(payload)?)),
+            GET_SCHEMAS_CODE => Ok(ServerCommand::GetSchemas(GetSchemas::from_bytes(payload)?)),
+            REGISTER_SCHEMA_CODE => Ok(ServerCommand::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            GET_PARTITION_CODE => Ok(ServerCommand::GetPartition(GetPartition::from_bytes(
+                payload,
+            )?)),
             CREATE_PARTITIONS_CODE => Ok(ServerCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
             DELETE_PARTITIONS_CODE => Ok(ServerCommand::DeletePartitions(
                 DeletePartitions::from_bytes(payload)?,
             )),
+            GET_PARTITION_OFFSETS_CODE => Ok(ServerCommand::GetPartitionOffsets(
+                GetPartitionOffsets::from_bytes(payload)?,
+            )),
+            GET_OFFSET_BY_TIMESTAMP_CODE => Ok(ServerCommand::GetOffsetByTimestamp(
+                GetOffsetByTimestamp::from_bytes(payload)?,
+            )),
+            GET_MESSAGES_HISTOGRAM_CODE => Ok(ServerCommand::GetMessagesHistogram(
+                GetMessagesHistogram::from_bytes(payload)?,
+            )),
+            SEAL_PARTITION_CODE => Ok(ServerCommand::SealPartition(SealPartition::from_bytes(
+                payload,
+            )?)),
             GET_CONSUMER_GROUP_CODE => Ok(ServerCommand::GetConsumerGroup(
                 GetConsumerGroup::from_bytes(payload)?,
             )),
@@ -250,9 +491,31 @@ impl BytesSerializable for ServerCommand {
             LEAVE_CONSUMER_GROUP_CODE => Ok(ServerCommand::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            HEARTBEAT_CONSUMER_GROUP_CODE => Ok(ServerCommand::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_bytes(payload)?,
+            )),
             GET_SNAPSHOT_FILE_CODE => Ok(ServerCommand::GetSnapshotFile(GetSnapshot::from_bytes(
                 payload,
             )?)),
+            ROTATE_ENCRYPTION_KEY_CODE => Ok(ServerCommand::RotateEncryptionKey(
+                RotateEncryptionKey::from_bytes(payload)?,
+            )),
+            SET_LOG_LEVEL_CODE => Ok(ServerCommand::SetLogLevel(SetLogLevel::from_bytes(
+                payload,
+            )?)),
+            SET_BACKGROUND_IO_RATE_CODE => Ok(ServerCommand::SetBackgroundIoRate(
+                SetBackgroundIoRate::from_bytes(payload)?,
+            )),
+            GET_CLUSTER_METADATA_CODE => Ok(ServerCommand::GetClusterMetadata(
+                GetClusterMetadata::from_bytes(payload)?,
+            )),
+            DRAIN_NODE_CODE => Ok(ServerCommand::DrainNode(DrainNode::from_bytes(payload)?)),
+            DECOMMISSION_NODE_CODE => Ok(ServerCommand::DecommissionNode(
+                DecommissionNode::from_bytes(payload)?,
+            )),
+            GET_NODE_STATUS_CODE => Ok(ServerCommand::GetNodeStatus(GetNodeStatus::from_bytes(
+                payload,
+            )?)),
             _ => Err(IggyError::InvalidCommand),
         }
     }
@@ -274,6 +537,8 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::GetMe(command) => command.validate(),
             ServerCommand::GetClient(command) => command.validate(),
             ServerCommand::GetClients(command) => command.validate(),
+            ServerCommand::GetSessions(command) => command.validate(),
+            ServerCommand::KillSession(command) => command.validate(),
             ServerCommand::GetUser(command) => command.validate(),
             ServerCommand::GetUsers(command) => command.validate(),
             ServerCommand::CreateUser(command) => command.validate(),
@@ -289,8 +554,20 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::LoginWithPersonalAccessToken(command) => command.validate(),
             ServerCommand::SendMessages(command) => command.validate(),
             ServerCommand::PollMessages(command) => command.validate(),
+            ServerCommand::PollMessagesFromPartitions(command) => command.validate(),
+            ServerCommand::PeekMessages(command) => command.validate(),
+            ServerCommand::GetMessageByKey(command) => command.validate(),
+            ServerCommand::FetchReplicaMessages(command) => command.validate(),
+            ServerCommand::AcquireExclusiveProducer(command) => command.validate(),
+            ServerCommand::InitProducerEpoch(command) => command.validate(),
+            ServerCommand::CopyMessages(command) => command.validate(),
             ServerCommand::StoreConsumerOffset(command) => command.validate(),
             ServerCommand::GetConsumerOffset(command) => command.validate(),
+            ServerCommand::GetConsumerLag(command) => command.validate(),
+            ServerCommand::ResetConsumerOffsets(command) => command.validate(),
+            ServerCommand::GetConsumers(command) => command.validate(),
+            ServerCommand::DeleteConsumer(command) => command.validate(),
+            ServerCommand::DeleteConsumerOffsets(command) => command.validate(),
             ServerCommand::GetStream(command) => command.validate(),
             ServerCommand::GetStreams(command) => command.validate(),
             ServerCommand::CreateStream(command) => command.validate(),
@@ -303,16 +580,33 @@ impl Validatable<IggyError> for ServerCommand {
             ServerCommand::DeleteTopic(command) => command.validate(),
             ServerCommand::UpdateTopic(command) => command.validate(),
             ServerCommand::PurgeTopic(command) => command.validate(),
+            ServerCommand::SetTopicReadOnly(command) => command.validate(),
+            ServerCommand::GetSchema(command) => command.validate(),
+            ServerCommand::GetSchemas(command) => command.validate(),
+            ServerCommand::RegisterSchema(command) => command.validate(),
+            ServerCommand::GetPartition(command) => command.validate(),
             ServerCommand::CreatePartitions(command) => command.validate(),
             ServerCommand::DeletePartitions(command) => command.validate(),
+            ServerCommand::GetPartitionOffsets(command) => command.validate(),
+            ServerCommand::GetOffsetByTimestamp(command) => command.validate(),
+            ServerCommand::GetMessagesHistogram(command) => command.validate(),
+            ServerCommand::SealPartition(command) => command.validate(),
             ServerCommand::GetConsumerGroup(command) => command.validate(),
             ServerCommand::GetConsumerGroups(command) => command.validate(),
             ServerCommand::CreateConsumerGroup(command) => command.validate(),
             ServerCommand::DeleteConsumerGroup(command) => command.validate(),
             ServerCommand::JoinConsumerGroup(command) => command.validate(),
             ServerCommand::LeaveConsumerGroup(command) => command.validate(),
+            ServerCommand::HeartbeatConsumerGroup(command) => command.validate(),
             ServerCommand::FlushUnsavedBuffer(command) => command.validate(),
             ServerCommand::GetSnapshotFile(command) => command.validate(),
+            ServerCommand::RotateEncryptionKey(command) => command.validate(),
+            ServerCommand::SetLogLevel(command) => command.validate(),
+            ServerCommand::SetBackgroundIoRate(command) => command.validate(),
+            ServerCommand::GetClusterMetadata(command) => command.validate(),
+            ServerCommand::DrainNode(command) => command.validate(),
+            ServerCommand::DecommissionNode(command) => command.validate(),
+            ServerCommand::GetNodeStatus(command) => command.validate(),
         }
     }
 }
@@ -325,6 +619,8 @@ impl Display for ServerCommand {
             ServerCommand::GetMe(_) => write!(formatter, "{GET_ME}"),
             ServerCommand::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             ServerCommand::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
+            ServerCommand::GetSessions(_) => write!(formatter, "{GET_SESSIONS}"),
+            ServerCommand::KillSession(payload) => write!(formatter, "{KILL_SESSION}|{payload}"),
             ServerCommand::GetUser(payload) => write!(formatter, "{GET_USER}|{payload}"),
             ServerCommand::GetUsers(_) => write!(formatter, "{GET_USERS}"),
             ServerCommand::CreateUser(payload) => write!(formatter, "{CREATE_USER}|{payload}"),
@@ -362,13 +658,53 @@ impl Display for ServerCommand {
             ServerCommand::DeleteTopic(payload) => write!(formatter, "{DELETE_TOPIC}|{payload}"),
             ServerCommand::UpdateTopic(payload) => write!(formatter, "{UPDATE_TOPIC}|{payload}"),
             ServerCommand::PurgeTopic(payload) => write!(formatter, "{PURGE_TOPIC}|{payload}"),
+            ServerCommand::SetTopicReadOnly(payload) => {
+                write!(formatter, "{SET_TOPIC_READ_ONLY}|{payload}")
+            }
+            ServerCommand::GetSchema(payload) => write!(formatter, "{GET_SCHEMA}|{payload}"),
+            ServerCommand::GetSchemas(payload) => write!(formatter, "{GET_SCHEMAS}|{payload}"),
+            ServerCommand::RegisterSchema(payload) => {
+                write!(formatter, "{REGISTER_SCHEMA}|{payload}")
+            }
+            ServerCommand::GetPartition(payload) => {
+                write!(formatter, "{GET_PARTITION}|{payload}")
+            }
             ServerCommand::CreatePartitions(payload) => {
                 write!(formatter, "{CREATE_PARTITIONS}|{payload}")
             }
             ServerCommand::DeletePartitions(payload) => {
                 write!(formatter, "{DELETE_PARTITIONS}|{payload}")
             }
+            ServerCommand::GetPartitionOffsets(payload) => {
+                write!(formatter, "{GET_PARTITION_OFFSETS}|{payload}")
+            }
+            ServerCommand::GetOffsetByTimestamp(payload) => {
+                write!(formatter, "{GET_OFFSET_BY_TIMESTAMP}|{payload}")
+            }
+            ServerCommand::GetMessagesHistogram(payload) => {
+                write!(formatter, "{GET_MESSAGES_HISTOGRAM}|{payload}")
+            }
+            ServerCommand::SealPartition(payload) => {
+                write!(formatter, "{SEAL_PARTITION}|{payload}")
+            }
             ServerCommand::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
+            ServerCommand::PollMessagesFromPartitions(payload) => {
+                write!(formatter, "{POLL_MESSAGES_FROM_PARTITIONS}|{payload}")
+            }
+            ServerCommand::PeekMessages(payload) => write!(formatter, "{PEEK_MESSAGES}|{payload}"),
+            ServerCommand::GetMessageByKey(payload) => {
+                write!(formatter, "{GET_MESSAGE_BY_KEY}|{payload}")
+            }
+            ServerCommand::FetchReplicaMessages(payload) => {
+                write!(formatter, "{FETCH_REPLICA_MESSAGES}|{payload}")
+            }
+            ServerCommand::AcquireExclusiveProducer(payload) => {
+                write!(formatter, "{ACQUIRE_EXCLUSIVE_PRODUCER}|{payload}")
+            }
+            ServerCommand::InitProducerEpoch(payload) => {
+                write!(formatter, "{INIT_PRODUCER_EPOCH}|{payload}")
+            }
+            ServerCommand::CopyMessages(payload) => write!(formatter, "{COPY_MESSAGES}|{payload}"),
             ServerCommand::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
             ServerCommand::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
@@ -376,6 +712,21 @@ impl Display for ServerCommand {
             ServerCommand::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
+            ServerCommand::GetConsumerLag(payload) => {
+                write!(formatter, "{GET_CONSUMER_LAG}|{payload}")
+            }
+            ServerCommand::ResetConsumerOffsets(payload) => {
+                write!(formatter, "{RESET_CONSUMER_OFFSETS}|{payload}")
+            }
+            ServerCommand::GetConsumers(payload) => {
+                write!(formatter, "{GET_CONSUMERS}|{payload}")
+            }
+            ServerCommand::DeleteConsumer(payload) => {
+                write!(formatter, "{DELETE_CONSUMER}|{payload}")
+            }
+            ServerCommand::DeleteConsumerOffsets(payload) => {
+                write!(formatter, "{DELETE_CONSUMER_OFFSETS}|{payload}")
+            }
             ServerCommand::GetConsumerGroup(payload) => {
                 write!(formatter, "{GET_CONSUMER_GROUP}|{payload}")
             }
@@ -394,12 +745,28 @@ impl Display for ServerCommand {
             ServerCommand::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            ServerCommand::HeartbeatConsumerGroup(payload) => {
+                write!(formatter, "{HEARTBEAT_CONSUMER_GROUP}|{payload}")
+            }
             ServerCommand::FlushUnsavedBuffer(payload) => {
                 write!(formatter, "{FLUSH_UNSAVED_BUFFER}|{payload}")
             }
             ServerCommand::GetSnapshotFile(payload) => {
                 write!(formatter, "{GET_SNAPSHOT_FILE}|{payload}")
             }
+            ServerCommand::RotateEncryptionKey(_) => write!(formatter, "{ROTATE_ENCRYPTION_KEY}"),
+            ServerCommand::SetLogLevel(payload) => write!(formatter, "{SET_LOG_LEVEL}|{payload}"),
+            ServerCommand::SetBackgroundIoRate(payload) => {
+                write!(formatter, "{SET_BACKGROUND_IO_RATE}|{payload}")
+            }
+            ServerCommand::GetClusterMetadata(_) => write!(formatter, "{GET_CLUSTER_METADATA}"),
+            ServerCommand::DrainNode(payload) => write!(formatter, "{DRAIN_NODE}|{payload}"),
+            ServerCommand::DecommissionNode(payload) => {
+                write!(formatter, "{DECOMMISSION_NODE}|{payload}")
+            }
+            ServerCommand::GetNodeStatus(payload) => {
+                write!(formatter, "{GET_NODE_STATUS}|{payload}")
+            }
         }
     }
 }
@@ -407,6 +774,9 @@ impl Display for ServerCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use iggy::messages::send_messages::Message;
+    use iggy::models::permissions::Permissions;
+    use std::str::FromStr;
 
     #[test]
     fn should_be_serialized_as_bytes_and_deserialized_from_bytes() {
@@ -420,6 +790,41 @@ mod tests {
             GET_STATS_CODE,
             &GetStats::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RotateEncryptionKey(RotateEncryptionKey::default()),
+            ROTATE_ENCRYPTION_KEY_CODE,
+            &RotateEncryptionKey::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetLogLevel(SetLogLevel::default()),
+            SET_LOG_LEVEL_CODE,
+            &SetLogLevel::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetBackgroundIoRate(SetBackgroundIoRate::default()),
+            SET_BACKGROUND_IO_RATE_CODE,
+            &SetBackgroundIoRate::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetClusterMetadata(GetClusterMetadata::default()),
+            GET_CLUSTER_METADATA_CODE,
+            &GetClusterMetadata::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DrainNode(DrainNode::default()),
+            DRAIN_NODE_CODE,
+            &DrainNode::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DecommissionNode(DecommissionNode::default()),
+            DECOMMISSION_NODE_CODE,
+            &DecommissionNode::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetNodeStatus(GetNodeStatus::default()),
+            GET_NODE_STATUS_CODE,
+            &GetNodeStatus::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetMe(GetMe::default()),
             GET_ME_CODE,
@@ -435,6 +840,16 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSessions(GetSessions::default()),
+            GET_SESSIONS_CODE,
+            &GetSessions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::KillSession(KillSession::default()),
+            KILL_SESSION_CODE,
+            &KillSession::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -510,6 +925,41 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::PollMessagesFromPartitions(PollMessagesFromPartitions::default()),
+            POLL_MESSAGES_FROM_PARTITIONS_CODE,
+            &PollMessagesFromPartitions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::PeekMessages(PeekMessages::default()),
+            PEEK_MESSAGES_CODE,
+            &PeekMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetMessageByKey(GetMessageByKey::default()),
+            GET_MESSAGE_BY_KEY_CODE,
+            &GetMessageByKey::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::FetchReplicaMessages(FetchReplicaMessages::default()),
+            FETCH_REPLICA_MESSAGES_CODE,
+            &FetchReplicaMessages::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::AcquireExclusiveProducer(AcquireExclusiveProducer::default()),
+            ACQUIRE_EXCLUSIVE_PRODUCER_CODE,
+            &AcquireExclusiveProducer::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::InitProducerEpoch(InitProducerEpoch::default()),
+            INIT_PRODUCER_EPOCH_CODE,
+            &InitProducerEpoch::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::CopyMessages(CopyMessages::default()),
+            COPY_MESSAGES_CODE,
+            &CopyMessages::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -520,6 +970,31 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG_CODE,
+            &GetConsumerLag::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::ResetConsumerOffsets(ResetConsumerOffsets::default()),
+            RESET_CONSUMER_OFFSETS_CODE,
+            &ResetConsumerOffsets::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetConsumers(GetConsumers::default()),
+            GET_CONSUMERS_CODE,
+            &GetConsumers::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteConsumer(DeleteConsumer::default()),
+            DELETE_CONSUMER_CODE,
+            &DeleteConsumer::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::DeleteConsumerOffsets(DeleteConsumerOffsets::default()),
+            DELETE_CONSUMER_OFFSETS_CODE,
+            &DeleteConsumerOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetStream(GetStream::default()),
             GET_STREAM_CODE,
@@ -580,6 +1055,31 @@ mod tests {
             PURGE_TOPIC_CODE,
             &PurgeTopic::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SetTopicReadOnly(SetTopicReadOnly::default()),
+            SET_TOPIC_READ_ONLY_CODE,
+            &SetTopicReadOnly::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchema(GetSchema::default()),
+            GET_SCHEMA_CODE,
+            &GetSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetSchemas(GetSchemas::default()),
+            GET_SCHEMAS_CODE,
+            &GetSchemas::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::RegisterSchema(RegisterSchema::default()),
+            REGISTER_SCHEMA_CODE,
+            &RegisterSchema::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartition(GetPartition::default()),
+            GET_PARTITION_CODE,
+            &GetPartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::CreatePartitions(CreatePartitions::default()),
             CREATE_PARTITIONS_CODE,
@@ -590,6 +1090,26 @@ mod tests {
             DELETE_PARTITIONS_CODE,
             &DeletePartitions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetPartitionOffsets(GetPartitionOffsets::default()),
+            GET_PARTITION_OFFSETS_CODE,
+            &GetPartitionOffsets::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetOffsetByTimestamp(GetOffsetByTimestamp::default()),
+            GET_OFFSET_BY_TIMESTAMP_CODE,
+            &GetOffsetByTimestamp::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::GetMessagesHistogram(GetMessagesHistogram::default()),
+            GET_MESSAGES_HISTOGRAM_CODE,
+            &GetMessagesHistogram::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::SealPartition(SealPartition::default()),
+            SEAL_PARTITION_CODE,
+            &SealPartition::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::GetConsumerGroup(GetConsumerGroup::default()),
             GET_CONSUMER_GROUP_CODE,
@@ -620,6 +1140,11 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &ServerCommand::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer::default()),
             FLUSH_UNSAVED_BUFFER_CODE,
@@ -627,6 +1152,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_fail_to_deserialize_truncated_or_malformed_bytes_without_panicking() {
+        for length in 0..4 {
+            assert!(ServerCommand::from_bytes(Bytes::from(vec![1; length])).is_err());
+        }
+
+        let commands = [
+            ServerCommand::LoginUser(LoginUser {
+                version: Some("1.0".to_string()),
+                context: Some("test".to_string()),
+                ..LoginUser::default()
+            }),
+            ServerCommand::CreateUser(CreateUser {
+                permissions: Some(Permissions::default()),
+                ..CreateUser::default()
+            }),
+            ServerCommand::UpdateUser(UpdateUser {
+                username: Some("user".to_string()),
+                ..UpdateUser::default()
+            }),
+            ServerCommand::CreateTopic(CreateTopic::default()),
+            ServerCommand::PollMessages(PollMessages::default()),
+            ServerCommand::SendMessages(SendMessages {
+                messages: vec![Message::from_str("message").unwrap()],
+                ..SendMessages::default()
+            }),
+        ];
+        for command in commands {
+            let bytes = command.to_bytes();
+            for length in 4..bytes.len() {
+                let _ = ServerCommand::from_bytes(bytes.slice(..length));
+            }
+
+            let mut bytes = bytes.to_vec();
+            for byte in bytes.iter_mut().skip(4) {
+                *byte = u8::MAX;
+            }
+            let _ = ServerCommand::from_bytes(Bytes::from(bytes));
+        }
+    }
+
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
         command: &ServerCommand,
         code: u32,
@@ -0,0 +1,224 @@
+use crate::server_error::ServerError;
+use crate::streaming::batching::batch_accumulator::BatchAccumulator;
+use crate::streaming::batching::message_batch::{
+    RETAINED_BATCH_FORMAT_VERSION, RETAINED_BATCH_OVERHEAD,
+};
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::segments::storage::INDEX_SIZE;
+use crate::streaming::utils::file;
+use bytes::{Bytes, BytesMut};
+use iggy::models::messages::MessageState;
+use iggy::utils::checksum;
+use iggy::utils::sizeable::Sizeable;
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tracing::{trace, warn};
+
+// base offset: 8 bytes, length: 4 bytes, last offset delta: 4 bytes, max timestamp: 8 bytes
+const LEGACY_BATCH_OVERHEAD: u64 = 8 + 4 + 4 + 8;
+// offset: 8 bytes, state: 1 byte, timestamp: 8 bytes, id: 16 bytes, checksum: 4 bytes, headers length: 4 bytes
+const LEGACY_MESSAGE_HEADER_SIZE: usize = 8 + 1 + 8 + 16 + 4 + 4;
+const MIGRATING_EXTENSION: &str = "migrating";
+
+/// Converts the segment log file written before the batch header was versioned, in which the messages store
+/// their absolute offsets and timestamps, into the current batch format, and updates the positions of the index entries.
+/// The converted files are written next to the original ones and renamed over them, the log file first,
+/// so that the conversion interrupted after renaming the log file can be completed by renaming the index file.
+pub struct BatchConverter {
+    pub log_path: String,
+    pub index_path: String,
+}
+
+impl BatchConverter {
+    pub fn new(log_path: String, index_path: String) -> Self {
+        Self {
+            log_path,
+            index_path,
+        }
+    }
+
+    /// Returns true if the log file needs to be converted, or its conversion has to be completed.
+    pub async fn is_required(&self) -> Result<bool, ServerError> {
+        if file::exists(&self.get_migrating_path(&self.index_path)).await? {
+            return Ok(true);
+        }
+
+        Ok(!is_current_format(&self.log_path).await?)
+    }
+
+    pub async fn migrate(&self) -> Result<(), ServerError> {
+        let migrating_index_path = self.get_migrating_path(&self.index_path);
+        if !is_current_format(&self.log_path).await? {
+            let migrating_log_path = self.get_migrating_path(&self.log_path);
+            let positions = self.convert_log(&migrating_log_path).await?;
+            self.convert_indexes(&positions, &migrating_index_path)
+                .await?;
+            file::rename(&migrating_log_path, &self.log_path).await?;
+        }
+
+        if file::exists(&migrating_index_path).await? {
+            file::rename(&migrating_index_path, &self.index_path).await?;
+        }
+
+        trace!("Converted batches of segment log file: {}", self.log_path);
+        Ok(())
+    }
+
+    /// Writes the converted batches to the given path and returns the positions of the legacy batches
+    /// along with the positions of the converted batches they start at.
+    async fn convert_log(&self, path: &str) -> Result<Vec<(u32, u32)>, ServerError> {
+        let log_file = file::open(&self.log_path).await?;
+        let log_size = log_file.metadata().await?.len();
+        let mut reader = BufReader::new(log_file);
+        let mut writer = BufWriter::new(File::create(path).await?);
+        let mut positions = Vec::new();
+        let mut read_position = 0u64;
+        let mut write_position = 0u64;
+        while log_size - read_position >= LEGACY_BATCH_OVERHEAD {
+            let base_offset = reader.read_u64_le().await?;
+            let batch_length = reader.read_u32_le().await? as u64;
+            let _last_offset_delta = reader.read_u32_le().await?;
+            let _max_timestamp = reader.read_u64_le().await?;
+            if read_position + LEGACY_BATCH_OVERHEAD + batch_length > log_size {
+                warn!(
+                    "Skipping partially written batch with base offset: {base_offset} at position: {read_position} of segment log file: {}",
+                    self.log_path
+                );
+                break;
+            }
+
+            let mut payload = vec![0; batch_length as usize];
+            reader.read_exact(&mut payload).await?;
+            let messages = read_legacy_messages(Bytes::from(payload))?;
+            positions.push((read_position as u32, write_position as u32));
+            read_position += LEGACY_BATCH_OVERHEAD + batch_length;
+            let Some(first_message) = messages.first() else {
+                continue;
+            };
+
+            let mut accumulator = BatchAccumulator::new(first_message.offset, messages.len());
+            let size = messages
+                .iter()
+                .map(|message| message.get_size_bytes())
+                .sum();
+            accumulator.append(size, &messages);
+            loop {
+                let (has_remainder, batch) = accumulator.materialize_batch_and_maybe_update_state();
+                let mut bytes = BytesMut::with_capacity(batch.get_size_bytes().as_bytes_usize());
+                batch.extend(&mut bytes);
+                writer.write_all(&bytes).await?;
+                write_position += bytes.len() as u64;
+                if !has_remainder {
+                    break;
+                }
+            }
+        }
+
+        writer.flush().await?;
+        writer.get_ref().sync_all().await?;
+        Ok(positions)
+    }
+
+    /// Writes the index entries pointing at the converted batches to the given path,
+    /// skipping the ones which point at the partially written batch.
+    async fn convert_indexes(
+        &self,
+        positions: &[(u32, u32)],
+        path: &str,
+    ) -> Result<(), ServerError> {
+        let indexes = tokio::fs::read(&self.index_path).await.unwrap_or_default();
+        let mut writer = BufWriter::new(File::create(path).await?);
+        for index in indexes.chunks_exact(INDEX_SIZE as usize) {
+            let position = u32::from_le_bytes(index[4..8].try_into()?);
+            let Ok(position_index) = positions.binary_search_by_key(&position, |(old, _)| *old)
+            else {
+                break;
+            };
+
+            writer.write_all(&index[..4]).await?;
+            writer
+                .write_all(&positions[position_index].1.to_le_bytes())
+                .await?;
+            writer.write_all(&index[8..]).await?;
+        }
+
+        writer.flush().await?;
+        writer.get_ref().sync_all().await?;
+        Ok(())
+    }
+
+    fn get_migrating_path(&self, path: &str) -> String {
+        format!("{path}.{MIGRATING_EXTENSION}")
+    }
+}
+
+/// Returns true if the log file is empty or starts with the batch written in the current format,
+/// which checksum matches its payload.
+async fn is_current_format(log_path: &str) -> Result<bool, ServerError> {
+    let log_file = file::open(log_path).await?;
+    let log_size = log_file.metadata().await?.len();
+    if log_size == 0 {
+        return Ok(true);
+    }
+
+    if log_size < RETAINED_BATCH_OVERHEAD {
+        return Ok(false);
+    }
+
+    let mut reader = BufReader::new(log_file);
+    let mut header = vec![0; RETAINED_BATCH_OVERHEAD as usize];
+    reader.read_exact(&mut header).await?;
+    let batch_length = u32::from_le_bytes(header[8..12].try_into()?) as u64;
+    let version = header[12];
+    if version != RETAINED_BATCH_FORMAT_VERSION || RETAINED_BATCH_OVERHEAD + batch_length > log_size
+    {
+        return Ok(false);
+    }
+
+    let batch_checksum = u32::from_le_bytes(header[header.len() - 4..].try_into()?);
+    let mut payload = vec![0; batch_length as usize];
+    reader.read_exact(&mut payload).await?;
+    Ok(checksum::calculate(&payload) == batch_checksum)
+}
+
+fn read_legacy_messages(payload: Bytes) -> Result<Vec<Arc<RetainedMessage>>, ServerError> {
+    let mut messages = Vec::new();
+    let mut position = 0;
+    while position + 4 <= payload.len() {
+        let length = u32::from_le_bytes(payload[position..position + 4].try_into()?) as usize;
+        let start = position + 4;
+        let end = start + length;
+        if length < LEGACY_MESSAGE_HEADER_SIZE || end > payload.len() {
+            return Err(ServerError::BatchMigrationError(format!(
+                "Invalid length: {length} of the message at position: {position} of the batch"
+            )));
+        }
+
+        let message = &payload[start..end];
+        let headers_length = u32::from_le_bytes(message[37..41].try_into()?) as usize;
+        let headers_end = LEGACY_MESSAGE_HEADER_SIZE + headers_length;
+        if headers_end > length {
+            return Err(ServerError::BatchMigrationError(format!(
+                "Invalid headers length: {headers_length} of the message at position: {position} of the batch"
+            )));
+        }
+
+        messages.push(Arc::new(RetainedMessage {
+            offset: u64::from_le_bytes(message[..8].try_into()?),
+            message_state: MessageState::from_code(message[8])?,
+            timestamp: u64::from_le_bytes(message[9..17].try_into()?),
+            id: u128::from_le_bytes(message[17..33].try_into()?),
+            checksum: u32::from_le_bytes(message[33..37].try_into()?),
+            headers: if headers_length > 0 {
+                Some(payload.slice(start + LEGACY_MESSAGE_HEADER_SIZE..start + headers_end))
+            } else {
+                None
+            },
+            payload: payload.slice(start + headers_end..end),
+        }));
+        position = end;
+    }
+
+    Ok(messages)
+}
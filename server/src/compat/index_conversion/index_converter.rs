@@ -29,10 +29,6 @@ impl IndexConverter {
         Ok(())
     }
 
-    pub async fn needs_migration(&self) -> Result<bool, ServerError> {
-        Ok(file::exists(&self.time_index_path).await?)
-    }
-
     async fn convert_indexes(&self) -> Result<Vec<u8>, ServerError> {
         let index_file = file::open(&self.index_path).await?;
         let time_index_file = file::open(&self.time_index_path).await?;
@@ -1,2 +1,3 @@
+pub mod batch_conversion;
 pub mod index_conversion;
 pub mod storage_conversion;
@@ -106,6 +106,8 @@ pub async fn convert(
                         } else {
                             None
                         },
+                        cleanup_policy: topic.cleanup_policy,
+                        overrides: topic.overrides,
                         name: topic.name,
                     }),
                 )
@@ -126,6 +128,7 @@ pub async fn convert(
                             topic_id: topic.topic_id.try_into()?,
                             group_id: Some(group.group_id),
                             name: group.name.to_owned(),
+                            rebalance_strategy: group.rebalance_strategy,
                         }),
                     )
                     .await?;
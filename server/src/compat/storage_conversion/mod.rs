@@ -5,10 +5,13 @@ use crate::compat::storage_conversion::persistency::{personal_access_tokens, str
 use crate::configs::system::SystemConfig;
 use crate::state::system::{PartitionState, StreamState, TopicState};
 use crate::state::State;
+use crate::streaming::appends::AppendsBroadcaster;
 use crate::streaming::batching::message_batch::RetainedMessageBatch;
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::events::EventsBus;
+use crate::streaming::partitions::partition::{ConsumerOffset, LoadedConsumerOffsets, Partition};
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::key_filter::KeyFilter;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::{
     PartitionStorage, SegmentStorage, StreamStorage, SystemInfoStorage, SystemStorage, TopicStorage,
@@ -16,10 +19,12 @@ use crate::streaming::storage::{
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::io_budget::IoBudget;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::{read_dir, rename};
@@ -73,6 +78,7 @@ pub async fn init(
         partition: Arc::new(NoopPartitionStorage {}),
         segment: Arc::new(NoopSegmentStorage {}),
         persister: Arc::new(NoopPersister {}),
+        io_budget: Arc::new(IoBudget::new(&config.background_io)),
     };
     let noop_storage = Arc::new(noop_storage);
     let mut dir_entries = dir_entries.unwrap();
@@ -85,7 +91,14 @@ pub async fn init(
         }
 
         let stream_id = stream_id.unwrap();
-        let mut stream = Stream::empty(stream_id, "stream", config.clone(), noop_storage.clone());
+        let mut stream = Stream::empty(
+            stream_id,
+            "stream",
+            config.clone(),
+            noop_storage.clone(),
+            Arc::new(AppendsBroadcaster::default()),
+            Arc::new(EventsBus::default()),
+        );
         streams::load(&config, &db, &mut stream).await?;
         streams.push(stream);
     }
@@ -188,15 +201,33 @@ impl PartitionStorage for NoopPartitionStorage {
         &self,
         _kind: ConsumerKind,
         _path: &str,
-    ) -> Result<Vec<ConsumerOffset>, IggyError> {
-        Ok(vec![])
+    ) -> Result<LoadedConsumerOffsets, IggyError> {
+        Ok(LoadedConsumerOffsets::default())
     }
 
     async fn delete_consumer_offsets(&self, _path: &str) -> Result<(), IggyError> {
         Ok(())
     }
 
-    async fn delete_consumer_offset(&self, _path: &str) -> Result<(), IggyError> {
+    async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn compact_consumer_offsets(
+        &self,
+        _path: &str,
+        _offsets: &[ConsumerOffset],
+        _names: &HashMap<u32, String>,
+    ) -> Result<u64, IggyError> {
+        Ok(0)
+    }
+
+    async fn save_consumer_name(
+        &self,
+        _path: &str,
+        _consumer_id: u32,
+        _name: &str,
+    ) -> Result<(), IggyError> {
         Ok(())
     }
 }
@@ -215,6 +246,8 @@ impl SegmentStorage for NoopSegmentStorage {
         Ok(())
     }
 
+    fn close_files(&self, _segment: &Segment) {}
+
     async fn load_message_batches(
         &self,
         _segment: &Segment,
@@ -239,6 +272,32 @@ impl SegmentStorage for NoopSegmentStorage {
         Ok(IggyByteSize::default())
     }
 
+    async fn save_compacted_batches(
+        &self,
+        _segment: &Segment,
+        _batches: &[RetainedMessageBatch],
+        _indexes: &[Index],
+        _key_version: Option<u32>,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save_key_version(&self, _segment: &Segment) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn save_key_filter(
+        &self,
+        _segment: &Segment,
+        _key_filter: &KeyFilter,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    async fn load_key_filter(&self, _segment: &Segment) -> Result<Option<KeyFilter>, IggyError> {
+        Ok(None)
+    }
+
     async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, IggyError> {
         Ok(vec![])
     }
@@ -180,6 +180,7 @@ pub async fn load(
             start_offset,
             partition.config.clone(),
             partition.storage.clone(),
+            partition.events_bus.clone(),
             partition.message_expiry,
             partition.size_of_parent_stream.clone(),
             partition.size_of_parent_topic.clone(),
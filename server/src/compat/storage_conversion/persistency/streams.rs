@@ -81,6 +81,8 @@ pub async fn load(config: &SystemConfig, db: &Db, stream: &mut Stream) -> Result
             stream.segments_count.clone(),
             stream.config.clone(),
             stream.storage.clone(),
+            stream.appends_broadcaster.clone(),
+            stream.events_bus.clone(),
         );
         topics::load(config, db, &mut topic).await?;
         topics.push(topic);
@@ -11,6 +11,7 @@ use iggy::locking::IggySharedMutFn;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::{IggyDuration, SEC_IN_MICRO};
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
 use iggy::utils::timestamp::IggyTimestamp;
 use serde::{Deserialize, Serialize};
 use sled::Db;
@@ -95,6 +96,8 @@ pub async fn load(config: &SystemConfig, db: &Db, topic: &mut Topic) -> Result<(
             false,
             topic.config.clone(),
             topic.storage.clone(),
+            topic.appends_broadcaster.clone(),
+            topic.events_bus.clone(),
             topic.message_expiry,
             topic.messages_count_of_parent_stream.clone(),
             topic.messages_count.clone(),
@@ -151,6 +154,7 @@ pub async fn load_consumer_groups(db: &Db, topic: &Topic) -> Result<Vec<Consumer
             consumer_group.id,
             &consumer_group.name,
             topic.get_partitions_count(),
+            RebalanceStrategy::default(),
         );
         consumer_groups.push(consumer_group);
     }
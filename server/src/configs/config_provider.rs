@@ -13,13 +13,14 @@ use tracing::debug;
 
 const DEFAULT_CONFIG_PROVIDER: &str = "file";
 const DEFAULT_CONFIG_PATH: &str = "configs/server.toml";
-const SECRET_KEYS: [&str; 6] = [
+const SECRET_KEYS: [&str; 7] = [
     IGGY_ROOT_PASSWORD_ENV,
     "IGGY_DATA_MAINTENANCE_ARCHIVER_S3_KEY_SECRET",
     "IGGY_HTTP_JWT_ENCODING_SECRET",
     "IGGY_HTTP_JWT_DECODING_SECRET",
     "IGGY_TCP_TLS_PASSWORD",
     "IGGY_SYSTEM_ENCRYPTION_KEY",
+    "IGGY_WEBHOOKS_SECRET",
 ];
 
 #[async_trait]
@@ -164,6 +165,15 @@ impl CustomEnvProvider {
         if let Ok(float_val) = value.parse::<f64>() {
             return FigmentValue::from(float_val);
         }
+        if value.starts_with('[') {
+            if let Ok(mut table) =
+                toml::from_str::<Map<String, TomlValue>>(&format!("value = {value}"))
+            {
+                if let Some(array @ TomlValue::Array(_)) = table.remove("value") {
+                    return Self::toml_to_figment_value(&array);
+                }
+            }
+        }
         FigmentValue::from(value)
     }
 }
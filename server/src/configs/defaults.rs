@@ -4,19 +4,23 @@ use iggy::utils::duration::IggyDuration;
 use crate::configs::http::{
     HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpMetricsConfig, HttpTlsConfig,
 };
+use crate::configs::ip_filter::{IpFilterConfig, UserSourcesConfig};
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, HeartbeatConfig, MessageSaverConfig,
-    MessagesMaintenanceConfig, PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig,
-    ServerConfig, StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig,
-    TelemetryTracesConfig,
+    ArchiverConfig, ConsumerLagConfig, ConsumerOffsetsMaintenanceConfig, DataMaintenanceConfig,
+    HeartbeatConfig, MessageSaverConfig, MessagesMaintenanceConfig,
+    PersonalAccessTokenCleanerConfig, PersonalAccessTokenConfig, ServerConfig,
+    StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
+    WebhooksConfig,
 };
 use crate::configs::system::{
-    BackupConfig, CacheConfig, CompatibilityConfig, CompressionConfig, EncryptionConfig,
-    LoggingConfig, MessageDeduplicationConfig, PartitionConfig, RecoveryConfig, RuntimeConfig,
-    SegmentConfig, StateConfig, StreamConfig, SystemConfig, TopicConfig,
+    BackgroundIoConfig, BackupConfig, CacheConfig, ClusterConfig, CompatibilityConfig,
+    CompressionConfig, ConsumerGroupConfig, DiskMonitorConfig, EncryptionConfig, EventsConfig,
+    LimitsConfig, LoggingConfig, MessageDeduplicationConfig, PartitionConfig, PlacementConfig,
+    PollResponseConfig, RecoveryConfig, RuntimeConfig, SegmentConfig, SlowConsumerConfig,
+    StateConfig, StreamConfig, SystemConfig, TopicConfig,
 };
-use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use crate::configs::tcp::{TcpConfig, TcpTlsClientAuthConfig, TcpTlsConfig};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -39,6 +43,7 @@ impl Default for ServerConfig {
             tcp: TcpConfig::default(),
             http: HttpConfig::default(),
             telemetry: TelemetryConfig::default(),
+            webhooks: WebhooksConfig::default(),
         }
     }
 }
@@ -64,12 +69,21 @@ impl Default for MessagesMaintenanceConfig {
         MessagesMaintenanceConfig {
             archiver_enabled: SERVER_CONFIG.data_maintenance.messages.archiver_enabled,
             cleaner_enabled: SERVER_CONFIG.data_maintenance.messages.cleaner_enabled,
+            compactor_enabled: SERVER_CONFIG.data_maintenance.messages.compactor_enabled,
+            reencryptor_enabled: SERVER_CONFIG.data_maintenance.messages.reencryptor_enabled,
             interval: SERVER_CONFIG
                 .data_maintenance
                 .messages
                 .interval
                 .parse()
                 .unwrap(),
+            tombstone_retention: SERVER_CONFIG
+                .data_maintenance
+                .messages
+                .tombstone_retention
+                .parse()
+                .unwrap(),
+            windows: Vec::new(),
         }
     }
 }
@@ -89,6 +103,27 @@ impl Default for StateMaintenanceConfig {
     }
 }
 
+impl Default for ConsumerOffsetsMaintenanceConfig {
+    fn default() -> ConsumerOffsetsMaintenanceConfig {
+        ConsumerOffsetsMaintenanceConfig {
+            compactor_enabled: SERVER_CONFIG
+                .data_maintenance
+                .consumer_offsets
+                .compactor_enabled,
+            interval: SERVER_CONFIG
+                .data_maintenance
+                .consumer_offsets
+                .interval
+                .parse()
+                .unwrap(),
+            min_stale_records: SERVER_CONFIG
+                .data_maintenance
+                .consumer_offsets
+                .min_stale_records as u64,
+        }
+    }
+}
+
 impl Default for QuicConfig {
     fn default() -> QuicConfig {
         QuicConfig {
@@ -105,6 +140,11 @@ impl Default for QuicConfig {
             receive_window: SERVER_CONFIG.quic.receive_window.parse().unwrap(),
             keep_alive_interval: SERVER_CONFIG.quic.keep_alive_interval.parse().unwrap(),
             max_idle_timeout: SERVER_CONFIG.quic.max_idle_timeout.parse().unwrap(),
+            ip_filter: IpFilterConfig {
+                enabled: SERVER_CONFIG.quic.ip_filter.enabled,
+                allow: Vec::new(),
+                deny: Vec::new(),
+            },
             certificate: QuicCertificateConfig::default(),
         }
     }
@@ -126,6 +166,12 @@ impl Default for TcpConfig {
             enabled: SERVER_CONFIG.tcp.enabled,
             address: SERVER_CONFIG.tcp.address.parse().unwrap(),
             ipv6: SERVER_CONFIG.tcp.ipv_6,
+            json_protocol: SERVER_CONFIG.tcp.json_protocol,
+            ip_filter: IpFilterConfig {
+                enabled: SERVER_CONFIG.tcp.ip_filter.enabled,
+                allow: Vec::new(),
+                deny: Vec::new(),
+            },
             tls: TcpTlsConfig::default(),
             socket: TcpSocketConfig::default(),
         }
@@ -138,6 +184,26 @@ impl Default for TcpTlsConfig {
             enabled: SERVER_CONFIG.tcp.tls.enabled,
             certificate: SERVER_CONFIG.tcp.tls.certificate.parse().unwrap(),
             password: SERVER_CONFIG.tcp.tls.password.parse().unwrap(),
+            client_auth: TcpTlsClientAuthConfig::default(),
+        }
+    }
+}
+
+impl Default for TcpTlsClientAuthConfig {
+    fn default() -> TcpTlsClientAuthConfig {
+        TcpTlsClientAuthConfig {
+            enabled: SERVER_CONFIG.tcp.tls.client_auth.enabled,
+            cert_file: SERVER_CONFIG.tcp.tls.client_auth.cert_file.parse().unwrap(),
+            key_file: SERVER_CONFIG.tcp.tls.client_auth.key_file.parse().unwrap(),
+            ca_file: SERVER_CONFIG.tcp.tls.client_auth.ca_file.parse().unwrap(),
+            users: SERVER_CONFIG
+                .tcp
+                .tls
+                .client_auth
+                .users
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
         }
     }
 }
@@ -161,6 +227,11 @@ impl Default for HttpConfig {
             enabled: SERVER_CONFIG.http.enabled,
             address: SERVER_CONFIG.http.address.parse().unwrap(),
             max_request_size: SERVER_CONFIG.http.max_request_size.parse().unwrap(),
+            ip_filter: IpFilterConfig {
+                enabled: SERVER_CONFIG.http.ip_filter.enabled,
+                allow: Vec::new(),
+                deny: Vec::new(),
+            },
             cors: HttpCorsConfig::default(),
             jwt: HttpJwtConfig::default(),
             metrics: HttpMetricsConfig::default(),
@@ -302,11 +373,21 @@ impl Default for SystemConfig {
             encryption: EncryptionConfig::default(),
             topic: TopicConfig::default(),
             partition: PartitionConfig::default(),
+            placement: PlacementConfig::default(),
             segment: SegmentConfig::default(),
             state: StateConfig::default(),
             compression: CompressionConfig::default(),
             message_deduplication: MessageDeduplicationConfig::default(),
             recovery: RecoveryConfig::default(),
+            events: EventsConfig::default(),
+            user_sources: UserSourcesConfig::default(),
+            slow_consumer: SlowConsumerConfig::default(),
+            consumer_group: ConsumerGroupConfig::default(),
+            disk_monitor: DiskMonitorConfig::default(),
+            poll_response: PollResponseConfig::default(),
+            background_io: BackgroundIoConfig::default(),
+            limits: LimitsConfig::default(),
+            cluster: ClusterConfig::default(),
         }
     }
 }
@@ -334,11 +415,51 @@ impl Default for CompatibilityConfig {
     }
 }
 
+impl Default for WebhooksConfig {
+    fn default() -> WebhooksConfig {
+        WebhooksConfig {
+            enabled: SERVER_CONFIG.webhooks.enabled,
+            urls: SERVER_CONFIG
+                .webhooks
+                .urls
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            events: SERVER_CONFIG
+                .webhooks
+                .events
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            secret: SERVER_CONFIG.webhooks.secret.to_string(),
+            timeout: SERVER_CONFIG.webhooks.timeout.parse().unwrap(),
+            max_retries: SERVER_CONFIG.webhooks.max_retries as u32,
+            retry_interval: SERVER_CONFIG.webhooks.retry_interval.parse().unwrap(),
+            consumer_lag: ConsumerLagConfig::default(),
+        }
+    }
+}
+
+impl Default for ConsumerLagConfig {
+    fn default() -> ConsumerLagConfig {
+        ConsumerLagConfig {
+            threshold: SERVER_CONFIG.webhooks.consumer_lag.threshold as u64,
+            interval: SERVER_CONFIG
+                .webhooks
+                .consumer_lag
+                .interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
 impl Default for HeartbeatConfig {
     fn default() -> HeartbeatConfig {
         HeartbeatConfig {
             enabled: SERVER_CONFIG.heartbeat.enabled,
             interval: SERVER_CONFIG.heartbeat.interval.parse().unwrap(),
+            timeout: SERVER_CONFIG.heartbeat.timeout.parse().unwrap(),
         }
     }
 }
@@ -396,6 +517,15 @@ impl Default for EncryptionConfig {
         EncryptionConfig {
             enabled: SERVER_CONFIG.system.encryption.enabled,
             key: SERVER_CONFIG.system.encryption.key.parse().unwrap(),
+            provider: SERVER_CONFIG.system.encryption.provider.parse().unwrap(),
+            file_path: SERVER_CONFIG.system.encryption.file_path.to_string(),
+            topics: SERVER_CONFIG
+                .system
+                .encryption
+                .topics
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         }
     }
 }
@@ -414,6 +544,17 @@ impl Default for TopicConfig {
             path: SERVER_CONFIG.system.topic.path.parse().unwrap(),
             max_size: SERVER_CONFIG.system.topic.max_size.parse().unwrap(),
             delete_oldest_segments: SERVER_CONFIG.system.topic.delete_oldest_segments,
+            compression: SERVER_CONFIG.system.topic.compression.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for PlacementConfig {
+    fn default() -> PlacementConfig {
+        PlacementConfig {
+            // The default config has no additional data paths, so the empty array has no element type to map from.
+            paths: Vec::new(),
+            policy: SERVER_CONFIG.system.placement.policy.parse().unwrap(),
         }
     }
 }
@@ -426,6 +567,19 @@ impl Default for PartitionConfig {
                 as u32,
             enforce_fsync: SERVER_CONFIG.system.partition.enforce_fsync,
             validate_checksum: SERVER_CONFIG.system.partition.validate_checksum,
+            read_ahead_depth: SERVER_CONFIG.system.partition.read_ahead_depth as u32,
+            message_timestamp_type: SERVER_CONFIG
+                .system
+                .partition
+                .message_timestamp_type
+                .parse()
+                .unwrap(),
+            replica_lag_timeout: SERVER_CONFIG
+                .system
+                .partition
+                .replica_lag_timeout
+                .parse()
+                .unwrap(),
         }
     }
 }
@@ -435,8 +589,10 @@ impl Default for SegmentConfig {
         SegmentConfig {
             size: SERVER_CONFIG.system.segment.size.parse().unwrap(),
             cache_indexes: SERVER_CONFIG.system.segment.cache_indexes,
+            index_interval: SERVER_CONFIG.system.segment.index_interval.parse().unwrap(),
             message_expiry: SERVER_CONFIG.system.segment.message_expiry.parse().unwrap(),
             archive_expired: SERVER_CONFIG.system.segment.archive_expired,
+            max_open_files: SERVER_CONFIG.system.segment.max_open_files as u32,
         }
     }
 }
@@ -472,6 +628,128 @@ impl Default for RecoveryConfig {
     }
 }
 
+impl Default for EventsConfig {
+    fn default() -> EventsConfig {
+        EventsConfig {
+            enabled: SERVER_CONFIG.system.events.enabled,
+            stream: SERVER_CONFIG.system.events.stream.parse().unwrap(),
+            topic: SERVER_CONFIG.system.events.topic.parse().unwrap(),
+            message_expiry: SERVER_CONFIG.system.events.message_expiry.parse().unwrap(),
+        }
+    }
+}
+
+impl Default for UserSourcesConfig {
+    fn default() -> UserSourcesConfig {
+        UserSourcesConfig {
+            enabled: SERVER_CONFIG.system.user_sources.enabled,
+            users: SERVER_CONFIG
+                .system
+                .user_sources
+                .users
+                .iter()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        }
+    }
+}
+
+impl Default for ConsumerGroupConfig {
+    fn default() -> ConsumerGroupConfig {
+        ConsumerGroupConfig {
+            session_timeout: SERVER_CONFIG
+                .system
+                .consumer_group
+                .session_timeout
+                .parse()
+                .unwrap(),
+            cleaner_interval: SERVER_CONFIG
+                .system
+                .consumer_group
+                .cleaner_interval
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for SlowConsumerConfig {
+    fn default() -> SlowConsumerConfig {
+        SlowConsumerConfig {
+            enabled: SERVER_CONFIG.system.slow_consumer.enabled,
+            cold_reads_threshold: SERVER_CONFIG.system.slow_consumer.cold_reads_threshold as u32,
+            lag_threshold: SERVER_CONFIG.system.slow_consumer.lag_threshold as u64,
+            deprioritize: SERVER_CONFIG.system.slow_consumer.deprioritize,
+            deprioritized_poll_count: SERVER_CONFIG.system.slow_consumer.deprioritized_poll_count
+                as u32,
+        }
+    }
+}
+
+impl Default for DiskMonitorConfig {
+    fn default() -> DiskMonitorConfig {
+        DiskMonitorConfig {
+            enabled: SERVER_CONFIG.system.disk_monitor.enabled,
+            interval: SERVER_CONFIG.system.disk_monitor.interval.parse().unwrap(),
+            min_free_space: SERVER_CONFIG
+                .system
+                .disk_monitor
+                .min_free_space
+                .parse()
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for PollResponseConfig {
+    fn default() -> PollResponseConfig {
+        PollResponseConfig {
+            chunked: SERVER_CONFIG.system.poll_response.chunked,
+            chunk_size: SERVER_CONFIG
+                .system
+                .poll_response
+                .chunk_size
+                .parse()
+                .unwrap(),
+            max_messages_count: SERVER_CONFIG.system.poll_response.max_messages_count as u32,
+        }
+    }
+}
+
+impl Default for BackgroundIoConfig {
+    fn default() -> BackgroundIoConfig {
+        BackgroundIoConfig {
+            max_rate: SERVER_CONFIG.system.background_io.max_rate.parse().unwrap(),
+            retention_weight: SERVER_CONFIG.system.background_io.retention_weight as u32,
+            compaction_weight: SERVER_CONFIG.system.background_io.compaction_weight as u32,
+            archiving_weight: SERVER_CONFIG.system.background_io.archiving_weight as u32,
+            recovery_weight: SERVER_CONFIG.system.background_io.recovery_weight as u32,
+        }
+    }
+}
+
+impl Default for LimitsConfig {
+    fn default() -> LimitsConfig {
+        LimitsConfig {
+            max_streams: SERVER_CONFIG.system.limits.max_streams as u32,
+            max_topics_per_stream: SERVER_CONFIG.system.limits.max_topics_per_stream as u32,
+            max_partitions_per_topic: SERVER_CONFIG.system.limits.max_partitions_per_topic as u32,
+        }
+    }
+}
+
+impl Default for ClusterConfig {
+    fn default() -> ClusterConfig {
+        ClusterConfig {
+            node_id: SERVER_CONFIG.system.cluster.node_id as u32,
+            name: SERVER_CONFIG.system.cluster.name.parse().unwrap(),
+            address: SERVER_CONFIG.system.cluster.address.parse().unwrap(),
+            zone: SERVER_CONFIG.system.cluster.zone.parse().unwrap(),
+            followers: Vec::new(),
+        }
+    }
+}
+
 impl Default for TelemetryConfig {
     fn default() -> TelemetryConfig {
         TelemetryConfig {
@@ -1,8 +1,10 @@
+use crate::configs::ip_filter::{IpFilterConfig, UserSourcesConfig};
 use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
 use crate::configs::server::{
-    ArchiverConfig, DataMaintenanceConfig, DiskArchiverConfig, HeartbeatConfig,
-    MessagesMaintenanceConfig, S3ArchiverConfig, StateMaintenanceConfig, TelemetryConfig,
-    TelemetryLogsConfig, TelemetryTracesConfig,
+    ArchiverConfig, ConsumerLagConfig, ConsumerOffsetsMaintenanceConfig, DataMaintenanceConfig,
+    DiskArchiverConfig, HeartbeatConfig, MessagesMaintenanceConfig, S3ArchiverConfig,
+    StateMaintenanceConfig, TelemetryConfig, TelemetryLogsConfig, TelemetryTracesConfig,
+    WebhooksConfig,
 };
 use crate::configs::system::MessageDeduplicationConfig;
 use crate::configs::{
@@ -10,10 +12,12 @@ use crate::configs::{
     resource_quota::MemoryResourceQuota,
     server::{MessageSaverConfig, ServerConfig},
     system::{
-        CacheConfig, CompressionConfig, EncryptionConfig, LoggingConfig, PartitionConfig,
-        SegmentConfig, StreamConfig, SystemConfig, TopicConfig,
+        BackgroundIoConfig, CacheConfig, ClusterConfig, CompressionConfig, ConsumerGroupConfig,
+        DiskMonitorConfig, EncryptionConfig, EventsConfig, LimitsConfig, LoggingConfig,
+        PartitionConfig, PlacementConfig, PollResponseConfig, SegmentConfig, SlowConsumerConfig,
+        StreamConfig, SystemConfig, TopicConfig,
     },
-    tcp::{TcpConfig, TcpSocketConfig, TcpTlsConfig},
+    tcp::{TcpConfig, TcpSocketConfig, TcpTlsClientAuthConfig, TcpTlsConfig},
 };
 use std::fmt::{Display, Formatter};
 
@@ -21,8 +25,8 @@ impl Display for HttpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, max_request_size: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
-            self.enabled, self.address, self.max_request_size, self.cors, self.jwt, self.metrics, self.tls
+            "{{ enabled: {}, address: {}, max_request_size: {}, ip_filter: {}, cors: {}, jwt: {}, metrics: {}, tls: {} }}",
+            self.enabled, self.address, self.max_request_size, self.ip_filter, self.cors, self.jwt, self.metrics, self.tls
         )
     }
 }
@@ -71,7 +75,7 @@ impl Display for QuicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, certificate: {} }}",
+          "{{ enabled: {}, address: {}, max_concurrent_bidi_streams: {}, datagram_send_buffer_size: {}, initial_mtu: {}, send_window: {}, receive_window: {}, keep_alive_interval: {}, max_idle_timeout: {}, ip_filter: {}, certificate: {} }}",
           self.enabled,
           self.address,
           self.max_concurrent_bidi_streams,
@@ -81,11 +85,44 @@ impl Display for QuicConfig {
           self.receive_window,
           self.keep_alive_interval,
           self.max_idle_timeout,
+          self.ip_filter,
           self.certificate
       )
     }
 }
 
+impl Display for IpFilterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, allow: {:?}, deny: {:?} }}",
+            self.enabled,
+            self.allow
+                .iter()
+                .map(|range| range.to_string())
+                .collect::<Vec<_>>(),
+            self.deny
+                .iter()
+                .map(|range| range.to_string())
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
+impl Display for UserSourcesConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, users: {:?} }}",
+            self.enabled,
+            self.users
+                .iter()
+                .map(|restriction| restriction.to_string())
+                .collect::<Vec<_>>()
+        )
+    }
+}
+
 impl Display for QuicCertificateConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -119,8 +156,8 @@ impl Display for DataMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver: {}, messages: {}, state: {} }}",
-            self.archiver, self.messages, self.state
+            "{{ archiver: {}, messages: {}, state: {}, consumer_offsets: {} }}",
+            self.archiver, self.messages, self.state, self.consumer_offsets
         )
     }
 }
@@ -166,8 +203,17 @@ impl Display for MessagesMaintenanceConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ archiver_enabled: {}, cleaner_enabled: {}, interval: {} }}",
-            self.archiver_enabled, self.cleaner_enabled, self.interval
+            "{{ archiver_enabled: {}, cleaner_enabled: {}, compactor_enabled: {}, reencryptor_enabled: {}, interval: {}, tombstone_retention: {}, windows: {:?} }}",
+            self.archiver_enabled,
+            self.cleaner_enabled,
+            self.compactor_enabled,
+            self.reencryptor_enabled,
+            self.interval,
+            self.tombstone_retention,
+            self.windows
+                .iter()
+                .map(|window| window.to_string())
+                .collect::<Vec<_>>()
         )
     }
 }
@@ -182,12 +228,22 @@ impl Display for StateMaintenanceConfig {
     }
 }
 
+impl Display for ConsumerOffsetsMaintenanceConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ compactor_enabled: {}, interval: {}, min_stale_records: {} }}",
+            self.compactor_enabled, self.interval, self.min_stale_records
+        )
+    }
+}
+
 impl Display for ServerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {} }}",
-            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry
+            "{{ data_maintenance: {}, message_saver: {}, heartbeat: {}, system: {}, quic: {}, tcp: {}, http: {}, telemetry: {}, webhooks: {} }}",
+            self.data_maintenance, self.message_saver, self.heartbeat, self.system, self.quic, self.tcp, self.http, self.telemetry, self.webhooks
         )
     }
 }
@@ -212,15 +268,46 @@ impl Display for HeartbeatConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, interval: {} }}",
-            self.enabled, self.interval
+            "{{ enabled: {}, interval: {}, timeout: {} }}",
+            self.enabled, self.interval, self.timeout
+        )
+    }
+}
+
+impl Display for WebhooksConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, urls: {:?}, events: {:?}, secret: {}, timeout: {}, max_retries: {}, retry_interval: {}, consumer_lag: {} }}",
+            self.enabled,
+            self.urls,
+            self.events,
+            if self.secret.is_empty() { "none" } else { "******" },
+            self.timeout,
+            self.max_retries,
+            self.retry_interval,
+            self.consumer_lag
+        )
+    }
+}
+
+impl Display for ConsumerLagConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ threshold: {}, interval: {} }}",
+            self.threshold, self.interval
         )
     }
 }
 
 impl Display for EncryptionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{{ enabled: {} }}", self.enabled)
+        write!(
+            f,
+            "{{ enabled: {}, provider: {}, topics: {:?} }}",
+            self.enabled, self.provider, self.topics
+        )
     }
 }
 
@@ -230,12 +317,111 @@ impl Display for StreamConfig {
     }
 }
 
+impl Display for EventsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, stream: {}, topic: {}, message_expiry: {} }}",
+            self.enabled, self.stream, self.topic, self.message_expiry
+        )
+    }
+}
+
+impl Display for PlacementConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ paths: [{}], policy: {} }}",
+            self.paths.join(", "),
+            self.policy
+        )
+    }
+}
+
+impl Display for ConsumerGroupConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ session_timeout: {}, cleaner_interval: {} }}",
+            self.session_timeout, self.cleaner_interval
+        )
+    }
+}
+
+impl Display for SlowConsumerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cold_reads_threshold: {}, lag_threshold: {}, deprioritize: {}, deprioritized_poll_count: {} }}",
+            self.enabled,
+            self.cold_reads_threshold,
+            self.lag_threshold,
+            self.deprioritize,
+            self.deprioritized_poll_count
+        )
+    }
+}
+
+impl Display for DiskMonitorConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, interval: {}, min_free_space: {} }}",
+            self.enabled, self.interval, self.min_free_space
+        )
+    }
+}
+
+impl Display for BackgroundIoConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_rate: {}, retention_weight: {}, compaction_weight: {}, archiving_weight: {}, recovery_weight: {} }}",
+            self.max_rate.as_human_string_with_zero_as_unlimited(),
+            self.retention_weight,
+            self.compaction_weight,
+            self.archiving_weight,
+            self.recovery_weight
+        )
+    }
+}
+
+impl Display for PollResponseConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ chunked: {}, chunk_size: {}, max_messages_count: {} }}",
+            self.chunked, self.chunk_size, self.max_messages_count
+        )
+    }
+}
+
+impl Display for LimitsConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ max_streams: {}, max_topics_per_stream: {}, max_partitions_per_topic: {} }}",
+            self.max_streams, self.max_topics_per_stream, self.max_partitions_per_topic
+        )
+    }
+}
+
+impl Display for ClusterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ node_id: {}, name: {}, address: {}, zone: {}, followers: {:?} }}",
+            self.node_id, self.name, self.address, self.zone, self.followers
+        )
+    }
+}
+
 impl Display for TopicConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ path: {}, max_size: {}, delete_oldest_segments: {} }}",
-            self.path, self.max_size, self.delete_oldest_segments
+            "{{ path: {}, max_size: {}, delete_oldest_segments: {}, compression: {} }}",
+            self.path, self.max_size, self.delete_oldest_segments, self.compression
         )
     }
 }
@@ -244,11 +430,14 @@ impl Display for PartitionConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {} }}",
+          "{{ path: {}, messages_required_to_save: {}, enforce_fsync: {}, validate_checksum: {}, read_ahead_depth: {}, message_timestamp_type: {}, replica_lag_timeout: {} }}",
           self.path,
           self.messages_required_to_save,
           self.enforce_fsync,
-          self.validate_checksum
+          self.validate_checksum,
+          self.read_ahead_depth,
+          self.message_timestamp_type,
+          self.replica_lag_timeout
       )
     }
 }
@@ -267,8 +456,13 @@ impl Display for SegmentConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ size_bytes: {}, cache_indexes: {}, message_expiry: {}, archive_expired: {} }}",
-            self.size, self.cache_indexes, self.message_expiry, self.archive_expired
+            "{{ size_bytes: {}, cache_indexes: {}, index_interval: {}, message_expiry: {}, archive_expired: {}, max_open_files: {} }}",
+            self.size,
+            self.cache_indexes,
+            self.index_interval,
+            self.message_expiry,
+            self.archive_expired,
+            self.max_open_files
         )
     }
 }
@@ -290,8 +484,8 @@ impl Display for TcpConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, address: {}, ipv6: {}, tls: {}, socket: {} }}",
-            self.enabled, self.address, self.ipv6, self.tls, self.socket,
+            "{{ enabled: {}, address: {}, ipv6: {}, json_protocol: {}, ip_filter: {}, tls: {}, socket: {} }}",
+            self.enabled, self.address, self.ipv6, self.json_protocol, self.ip_filter, self.tls, self.socket,
         )
     }
 }
@@ -300,8 +494,25 @@ impl Display for TcpTlsConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ enabled: {}, certificate: {} }}",
-            self.enabled, self.certificate
+            "{{ enabled: {}, certificate: {}, client_auth: {} }}",
+            self.enabled, self.certificate, self.client_auth
+        )
+    }
+}
+
+impl Display for TcpTlsClientAuthConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ enabled: {}, cert_file: {}, key_file: {}, ca_file: {}, users: {:?} }}",
+            self.enabled,
+            self.cert_file,
+            self.key_file,
+            self.ca_file,
+            self.users
+                .iter()
+                .map(|mapping| mapping.to_string())
+                .collect::<Vec<_>>()
         )
     }
 }
@@ -350,15 +561,25 @@ impl Display for SystemConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
           f,
-          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, segment: {}, encryption: {} }}",
+          "{{ path: {}, logging: {}, cache: {}, stream: {}, topic: {}, partition: {}, placement: {}, segment: {}, encryption: {}, events: {}, user_sources: {}, slow_consumer: {}, consumer_group: {}, disk_monitor: {}, poll_response: {}, background_io: {}, limits: {}, cluster: {} }}",
           self.path,
           self.logging,
           self.cache,
           self.stream,
           self.topic,
           self.partition,
+          self.placement,
           self.segment,
-          self.encryption
+          self.encryption,
+          self.events,
+          self.user_sources,
+          self.slow_consumer,
+          self.consumer_group,
+          self.disk_monitor,
+          self.poll_response,
+          self.background_io,
+          self.limits,
+          self.cluster
       )
     }
 }
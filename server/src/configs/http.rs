@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
@@ -12,6 +13,7 @@ pub struct HttpConfig {
     pub enabled: bool,
     pub address: String,
     pub max_request_size: IggyByteSize,
+    pub ip_filter: IpFilterConfig,
     pub cors: HttpCorsConfig,
     pub jwt: HttpJwtConfig,
     pub metrics: HttpMetricsConfig,
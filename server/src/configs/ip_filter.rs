@@ -0,0 +1,172 @@
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+use std::fmt::Display;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// Filters the addresses of the clients connecting to the listener by the CIDR ranges.
+/// The denied ranges take precedence over the allowed ones, and the empty allowed ranges allow any address.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct IpFilterConfig {
+    pub enabled: bool,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub allow: Vec<IpNet>,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub deny: Vec<IpNet>,
+}
+
+/// Restricts the users to log in only from the source addresses within the CIDR ranges.
+/// The user without any restriction can log in from any address allowed by the listener.
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct UserSourcesConfig {
+    pub enabled: bool,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub users: Vec<UserSourceRestriction>,
+}
+
+/// Allows the user to log in from the CIDR range, in the `username=range` format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSourceRestriction {
+    pub username: String,
+    pub range: IpNet,
+}
+
+impl IpFilterConfig {
+    pub fn is_allowed(&self, address: &IpAddr) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        if self.deny.iter().any(|range| range.contains(address)) {
+            return false;
+        }
+
+        self.allow.is_empty() || self.allow.iter().any(|range| range.contains(address))
+    }
+}
+
+impl UserSourcesConfig {
+    pub fn is_allowed(&self, username: &str, address: &IpAddr) -> bool {
+        if !self.enabled {
+            return true;
+        }
+
+        let mut ranges = self
+            .users
+            .iter()
+            .filter(|restriction| restriction.username == username)
+            .peekable();
+        if ranges.peek().is_none() {
+            return true;
+        }
+
+        ranges.any(|restriction| restriction.range.contains(address))
+    }
+}
+
+impl FromStr for UserSourceRestriction {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((username, range)) = s.split_once('=') else {
+            return Err(format!(
+                "Invalid user source restriction: {s}, expected format: username=range"
+            ));
+        };
+
+        let username = username.trim();
+        if username.is_empty() {
+            return Err(format!(
+                "Invalid user source restriction: {s}, empty username"
+            ));
+        }
+
+        let range = range
+            .trim()
+            .parse::<IpNet>()
+            .map_err(|error| format!("Invalid user source restriction: {s}, {error}"))?;
+        Ok(UserSourceRestriction {
+            username: username.to_string(),
+            range,
+        })
+    }
+}
+
+impl Display for UserSourceRestriction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.username, self.range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    #[test]
+    fn denied_range_should_take_precedence_over_allowed_range() {
+        let filter = IpFilterConfig {
+            enabled: true,
+            allow: vec!["10.0.0.0/8".parse().unwrap()],
+            deny: vec!["10.1.0.0/16".parse().unwrap()],
+        };
+
+        assert!(filter.is_allowed(&ip("10.2.0.1")));
+        assert!(!filter.is_allowed(&ip("10.1.0.1")));
+        assert!(!filter.is_allowed(&ip("192.168.0.1")));
+        assert!(!filter.is_allowed(&ip("::1")));
+    }
+
+    #[test]
+    fn empty_allowed_ranges_should_allow_any_address_not_denied() {
+        let filter = IpFilterConfig {
+            enabled: true,
+            allow: vec![],
+            deny: vec!["::1/128".parse().unwrap()],
+        };
+
+        assert!(filter.is_allowed(&ip("192.168.0.1")));
+        assert!(!filter.is_allowed(&ip("::1")));
+    }
+
+    #[test]
+    fn disabled_filter_should_allow_any_address() {
+        let filter = IpFilterConfig {
+            enabled: false,
+            allow: vec!["10.0.0.0/8".parse().unwrap()],
+            deny: vec![],
+        };
+
+        assert!(filter.is_allowed(&ip("192.168.0.1")));
+    }
+
+    #[test]
+    fn restricted_user_should_log_in_only_from_allowed_ranges() {
+        let config = UserSourcesConfig {
+            enabled: true,
+            users: vec![
+                "admin=127.0.0.0/8".parse().unwrap(),
+                "admin = 10.0.0.0/8".parse().unwrap(),
+            ],
+        };
+
+        assert!(config.is_allowed("admin", &ip("127.0.0.1")));
+        assert!(config.is_allowed("admin", &ip("10.0.0.1")));
+        assert!(!config.is_allowed("admin", &ip("192.168.0.1")));
+        assert!(config.is_allowed("user", &ip("192.168.0.1")));
+        assert_eq!(config.users[1].to_string(), "admin=10.0.0.0/8");
+    }
+
+    #[test]
+    fn invalid_user_source_restriction_should_be_rejected() {
+        assert!("admin".parse::<UserSourceRestriction>().is_err());
+        assert!("=127.0.0.0/8".parse::<UserSourceRestriction>().is_err());
+        assert!("admin=localhost".parse::<UserSourceRestriction>().is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use chrono::{NaiveTime, Timelike};
+use std::fmt::Display;
+use std::str::FromStr;
+
+const TIME_FORMAT: &str = "%H:%M";
+
+/// The daily time window (in UTC), in the `HH:MM-HH:MM` format, during which the background maintenance is allowed to run.
+/// The window ending before it starts spans midnight, e.g. `22:00-04:00`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaintenanceWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl MaintenanceWindow {
+    /// Returns true if the time falls within the window, including its start and excluding its end.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+
+    /// Returns true if there are no windows configured, or the time falls within any of them.
+    pub fn is_allowed(windows: &[MaintenanceWindow], time: NaiveTime) -> bool {
+        windows.is_empty() || windows.iter().any(|window| window.contains(time))
+    }
+}
+
+impl FromStr for MaintenanceWindow {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((start, end)) = s.split_once('-') else {
+            return Err(format!(
+                "Invalid maintenance window: {s}, expected format: HH:MM-HH:MM"
+            ));
+        };
+
+        let parse = |time: &str| {
+            NaiveTime::parse_from_str(time.trim(), TIME_FORMAT)
+                .map_err(|error| format!("Invalid maintenance window: {s}, {error}"))
+        };
+        let start = parse(start)?;
+        let end = parse(end)?;
+        if start == end {
+            return Err(format!(
+                "Invalid maintenance window: {s}, the start and end cannot be equal"
+            ));
+        }
+
+        Ok(MaintenanceWindow { start, end })
+    }
+}
+
+impl Display for MaintenanceWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start.hour(),
+            self.start.minute(),
+            self.end.hour(),
+            self.end.minute()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(value, TIME_FORMAT).unwrap()
+    }
+
+    #[test]
+    fn window_should_contain_time_between_start_and_end() {
+        let window: MaintenanceWindow = "01:00-05:30".parse().unwrap();
+
+        assert!(window.contains(time("01:00")));
+        assert!(window.contains(time("05:29")));
+        assert!(!window.contains(time("05:30")));
+        assert!(!window.contains(time("00:59")));
+        assert_eq!(window.to_string(), "01:00-05:30");
+    }
+
+    #[test]
+    fn window_ending_before_start_should_span_midnight() {
+        let window: MaintenanceWindow = "22:00 - 04:00".parse().unwrap();
+
+        assert!(window.contains(time("23:15")));
+        assert!(window.contains(time("00:00")));
+        assert!(window.contains(time("03:59")));
+        assert!(!window.contains(time("04:00")));
+        assert!(!window.contains(time("12:00")));
+    }
+
+    #[test]
+    fn maintenance_should_be_allowed_at_any_time_without_windows() {
+        let windows = vec![
+            "01:00-02:00".parse().unwrap(),
+            "13:00-14:00".parse().unwrap(),
+        ];
+
+        assert!(MaintenanceWindow::is_allowed(&[], time("12:00")));
+        assert!(MaintenanceWindow::is_allowed(&windows, time("13:30")));
+        assert!(!MaintenanceWindow::is_allowed(&windows, time("12:00")));
+    }
+
+    #[test]
+    fn invalid_window_should_not_be_parsed() {
+        assert!("01:00".parse::<MaintenanceWindow>().is_err());
+        assert!("25:00-02:00".parse::<MaintenanceWindow>().is_err());
+        assert!("01:00-01:00".parse::<MaintenanceWindow>().is_err());
+    }
+}
@@ -2,6 +2,8 @@ pub mod server;
 pub mod system;
 
 pub mod http;
+pub mod ip_filter;
+pub mod maintenance_window;
 pub mod quic;
 pub mod tcp;
 
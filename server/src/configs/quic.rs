@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::duration::IggyDuration;
 use serde::{Deserialize, Serialize};
@@ -18,6 +19,7 @@ pub struct QuicConfig {
     pub keep_alive_interval: IggyDuration,
     #[serde_as(as = "DisplayFromStr")]
     pub max_idle_timeout: IggyDuration,
+    pub ip_filter: IpFilterConfig,
     pub certificate: QuicCertificateConfig,
 }
 
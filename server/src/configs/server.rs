@@ -1,6 +1,7 @@
 use crate::archiver::ArchiverKind;
 use crate::configs::config_provider::ConfigProvider;
 use crate::configs::http::HttpConfig;
+use crate::configs::maintenance_window::MaintenanceWindow;
 use crate::configs::quic::QuicConfig;
 use crate::configs::system::SystemConfig;
 use crate::configs::tcp::TcpConfig;
@@ -25,6 +26,7 @@ pub struct ServerConfig {
     pub tcp: TcpConfig,
     pub http: HttpConfig,
     pub telemetry: TelemetryConfig,
+    pub webhooks: WebhooksConfig,
 }
 
 #[serde_as]
@@ -33,6 +35,7 @@ pub struct DataMaintenanceConfig {
     pub archiver: ArchiverConfig,
     pub messages: MessagesMaintenanceConfig,
     pub state: StateMaintenanceConfig,
+    pub consumer_offsets: ConsumerOffsetsMaintenanceConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -48,8 +51,14 @@ pub struct ArchiverConfig {
 pub struct MessagesMaintenanceConfig {
     pub archiver_enabled: bool,
     pub cleaner_enabled: bool,
+    pub compactor_enabled: bool,
+    pub reencryptor_enabled: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub tombstone_retention: IggyDuration,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub windows: Vec<MaintenanceWindow>,
 }
 
 #[serde_as]
@@ -61,6 +70,15 @@ pub struct StateMaintenanceConfig {
     pub interval: IggyDuration,
 }
 
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConsumerOffsetsMaintenanceConfig {
+    pub compactor_enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub min_stale_records: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DiskArchiverConfig {
     pub path: String,
@@ -105,6 +123,31 @@ pub struct HeartbeatConfig {
     pub enabled: bool,
     #[serde_as(as = "DisplayFromStr")]
     pub interval: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhooksConfig {
+    pub enabled: bool,
+    pub urls: Vec<String>,
+    pub events: Vec<String>,
+    pub secret: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub timeout: IggyDuration,
+    pub max_retries: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub retry_interval: IggyDuration,
+    pub consumer_lag: ConsumerLagConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConsumerLagConfig {
+    pub threshold: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
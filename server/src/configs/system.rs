@@ -1,6 +1,10 @@
+use crate::configs::ip_filter::UserSourcesConfig;
 use crate::configs::resource_quota::MemoryResourceQuota;
+use crate::streaming::utils::key_provider::KeyProviderKind;
+use derive_more::Display;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::timestamp_type::TimestampType;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy::{
     compression::compression_algorithm::CompressionAlgorithm, utils::duration::IggyDuration,
@@ -8,8 +12,9 @@ use iggy::{
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::str::FromStr;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SystemConfig {
     pub path: String,
     pub backup: BackupConfig,
@@ -21,42 +26,52 @@ pub struct SystemConfig {
     pub stream: StreamConfig,
     pub topic: TopicConfig,
     pub partition: PartitionConfig,
+    pub placement: PlacementConfig,
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
     pub compression: CompressionConfig,
     pub message_deduplication: MessageDeduplicationConfig,
     pub recovery: RecoveryConfig,
+    pub events: EventsConfig,
+    pub user_sources: UserSourcesConfig,
+    pub slow_consumer: SlowConsumerConfig,
+    pub consumer_group: ConsumerGroupConfig,
+    pub disk_monitor: DiskMonitorConfig,
+    pub poll_response: PollResponseConfig,
+    pub background_io: BackgroundIoConfig,
+    pub limits: LimitsConfig,
+    pub cluster: ClusterConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct BackupConfig {
     pub path: String,
     pub compatibility: CompatibilityConfig,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CompatibilityConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct DatabaseConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RuntimeConfig {
     pub path: String,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CompressionConfig {
     pub allow_override: bool,
     pub default_algorithm: CompressionAlgorithm,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct LoggingConfig {
     pub path: String,
     pub level: String,
@@ -67,42 +82,130 @@ pub struct LoggingConfig {
     pub sysinfo_print_interval: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct CacheConfig {
     pub enabled: bool,
     pub size: MemoryResourceQuota,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
+    pub provider: KeyProviderKind,
+    pub file_path: String,
+    pub topics: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StreamConfig {
     pub path: String,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TopicConfig {
     pub path: String,
     #[serde_as(as = "DisplayFromStr")]
     pub max_size: MaxTopicSize,
     pub delete_oldest_segments: bool,
+    pub compression: TopicCompression,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The compression of the messages payloads appended to the topic.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicCompression {
+    /// The payloads are stored as sent, either uncompressed or compressed by the producer.
+    #[default]
+    #[display("producer")]
+    Producer,
+    /// The payloads are recompressed with the compression algorithm of the topic,
+    /// unless already compressed by the producer with the same algorithm.
+    #[display("topic")]
+    Topic,
+}
+
+impl FromStr for TopicCompression {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "producer" => Ok(TopicCompression::Producer),
+            "topic" => Ok(TopicCompression::Topic),
+            _ => Err(format!("Unknown topic compression: {}", s)),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PartitionConfig {
     pub path: String,
     pub messages_required_to_save: u32,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub read_ahead_depth: u32,
+    pub message_timestamp_type: TimestampType,
+    #[serde_as(as = "DisplayFromStr")]
+    pub replica_lag_timeout: IggyDuration,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PlacementConfig {
+    pub paths: Vec<String>,
+    pub policy: PlacementPolicy,
+}
+
+/// The policy of distributing the partitions across the data paths.
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Display, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum PlacementPolicy {
+    /// The partitions of the topic are assigned to the data paths one after another,
+    /// starting from the data path shifted by the topic ID, so that the single partition topics are spread as well.
+    #[default]
+    #[display("round_robin")]
+    RoundRobin,
+    /// All the partitions of the stream are assigned to the same data path.
+    #[display("stream")]
+    Stream,
+}
+
+impl FromStr for PlacementPolicy {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "round_robin" => Ok(PlacementPolicy::RoundRobin),
+            "stream" => Ok(PlacementPolicy::Stream),
+            _ => Err(format!("Unknown placement policy: {}", s)),
+        }
+    }
+}
+
+impl PlacementPolicy {
+    /// Returns the index of the data path, on which the partition is placed, out of the given number of data paths.
+    pub fn get_data_path_index(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        data_paths_count: usize,
+    ) -> usize {
+        if data_paths_count <= 1 {
+            return 0;
+        }
+
+        let position = match self {
+            PlacementPolicy::RoundRobin => {
+                topic_id.saturating_sub(1) as u64 + partition_id.saturating_sub(1) as u64
+            }
+            PlacementPolicy::Stream => stream_id.saturating_sub(1) as u64,
+        };
+        (position % data_paths_count as u64) as usize
+    }
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct MessageDeduplicationConfig {
     pub enabled: bool,
     pub max_entries: u64,
@@ -110,31 +213,139 @@ pub struct MessageDeduplicationConfig {
     pub expiry: IggyDuration,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct RecoveryConfig {
     pub recreate_missing_state: bool,
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct SegmentConfig {
     pub size: IggyByteSize,
     pub cache_indexes: bool,
+    pub index_interval: IggyByteSize,
     #[serde_as(as = "DisplayFromStr")]
     pub message_expiry: IggyExpiry,
     pub archive_expired: bool,
+    pub max_open_files: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EventsConfig {
+    pub enabled: bool,
+    pub stream: String,
+    pub topic: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub message_expiry: IggyExpiry,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SlowConsumerConfig {
+    pub enabled: bool,
+    pub cold_reads_threshold: u32,
+    pub lag_threshold: u64,
+    pub deprioritize: bool,
+    pub deprioritized_poll_count: u32,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ConsumerGroupConfig {
+    #[serde_as(as = "DisplayFromStr")]
+    pub session_timeout: IggyDuration,
+    #[serde_as(as = "DisplayFromStr")]
+    pub cleaner_interval: IggyDuration,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DiskMonitorConfig {
+    pub enabled: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval: IggyDuration,
+    pub min_free_space: IggyByteSize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PollResponseConfig {
+    pub chunked: bool,
+    pub chunk_size: IggyByteSize,
+    pub max_messages_count: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BackgroundIoConfig {
+    pub max_rate: IggyByteSize,
+    pub retention_weight: u32,
+    pub compaction_weight: u32,
+    pub archiving_weight: u32,
+    pub recovery_weight: u32,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LimitsConfig {
+    pub max_streams: u32,
+    pub max_topics_per_stream: u32,
+    pub max_partitions_per_topic: u32,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClusterConfig {
+    pub node_id: u32,
+    pub name: String,
+    pub address: String,
+    pub zone: String,
+    pub followers: Vec<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct StateConfig {
     pub enforce_fsync: bool,
 }
 
+impl EncryptionConfig {
+    /// Returns whether the messages payloads of the topic are encrypted at rest, if encryption is enabled.
+    pub fn is_topic_encrypted(&self, stream_name: &str, topic_name: &str) -> bool {
+        self.enabled
+            && self.topics.iter().any(|pattern| {
+                let (stream, topic) = pattern.split_once('/').unwrap_or((pattern, "*"));
+                (stream == "*" || stream == stream_name) && (topic == "*" || topic == topic_name)
+            })
+    }
+}
+
 impl SystemConfig {
     pub fn get_system_path(&self) -> String {
         self.path.to_string()
     }
 
+    /// Returns all the base paths, across which the partitions are distributed, starting with the system path.
+    /// The rest of the data (state, streams and topics metadata, backups etc.) is always stored in the system path.
+    pub fn get_data_paths(&self) -> Vec<String> {
+        let mut data_paths = Vec::with_capacity(1 + self.placement.paths.len());
+        data_paths.push(self.get_system_path());
+        data_paths.extend(self.placement.paths.iter().cloned());
+        data_paths
+    }
+
+    /// Returns the base path, on which the partition is placed according to the placement policy.
+    pub fn get_partition_data_path(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> String {
+        let mut data_paths = self.get_data_paths();
+        let index = self.placement.policy.get_data_path_index(
+            stream_id,
+            topic_id,
+            partition_id,
+            data_paths.len(),
+        );
+        data_paths.swap_remove(index)
+    }
+
     pub fn get_database_path(&self) -> Option<String> {
         self.database
             .as_ref()
@@ -173,33 +384,67 @@ impl SystemConfig {
     }
 
     pub fn get_streams_path(&self) -> String {
-        format!("{}/{}", self.get_system_path(), self.stream.path)
+        self.get_data_streams_path(&self.get_system_path())
+    }
+
+    pub fn get_data_streams_path(&self, data_path: &str) -> String {
+        format!("{}/{}", data_path, self.stream.path)
     }
 
     pub fn get_stream_path(&self, stream_id: u32) -> String {
-        format!("{}/{}", self.get_streams_path(), stream_id)
+        self.get_data_stream_path(&self.get_system_path(), stream_id)
+    }
+
+    pub fn get_data_stream_path(&self, data_path: &str, stream_id: u32) -> String {
+        format!("{}/{}", self.get_data_streams_path(data_path), stream_id)
     }
 
     pub fn get_topics_path(&self, stream_id: u32) -> String {
-        format!("{}/{}", self.get_stream_path(stream_id), self.topic.path)
+        self.get_data_topics_path(&self.get_system_path(), stream_id)
+    }
+
+    pub fn get_data_topics_path(&self, data_path: &str, stream_id: u32) -> String {
+        format!(
+            "{}/{}",
+            self.get_data_stream_path(data_path, stream_id),
+            self.topic.path
+        )
     }
 
     pub fn get_topic_path(&self, stream_id: u32, topic_id: u32) -> String {
-        format!("{}/{}", self.get_topics_path(stream_id), topic_id)
+        self.get_data_topic_path(&self.get_system_path(), stream_id, topic_id)
+    }
+
+    pub fn get_data_topic_path(&self, data_path: &str, stream_id: u32, topic_id: u32) -> String {
+        format!(
+            "{}/{}",
+            self.get_data_topics_path(data_path, stream_id),
+            topic_id
+        )
     }
 
     pub fn get_partitions_path(&self, stream_id: u32, topic_id: u32) -> String {
+        self.get_data_partitions_path(&self.get_system_path(), stream_id, topic_id)
+    }
+
+    pub fn get_data_partitions_path(
+        &self,
+        data_path: &str,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> String {
         format!(
             "{}/{}",
-            self.get_topic_path(stream_id, topic_id),
+            self.get_data_topic_path(data_path, stream_id, topic_id),
             self.partition.path
         )
     }
 
     pub fn get_partition_path(&self, stream_id: u32, topic_id: u32, partition_id: u32) -> String {
+        let data_path = self.get_partition_data_path(stream_id, topic_id, partition_id);
         format!(
             "{}/{}",
-            self.get_partitions_path(stream_id, topic_id),
+            self.get_data_partitions_path(&data_path, stream_id, topic_id),
             partition_id
         )
     }
@@ -249,3 +494,94 @@ impl SystemConfig {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_should_be_encrypted_only_if_matching_any_pattern() {
+        let config = EncryptionConfig {
+            enabled: true,
+            key: "".to_string(),
+            provider: KeyProviderKind::Config,
+            file_path: "".to_string(),
+            topics: vec!["orders/*".to_string(), "*/payments".to_string()],
+        };
+
+        assert!(config.is_topic_encrypted("orders", "created"));
+        assert!(config.is_topic_encrypted("billing", "payments"));
+        assert!(!config.is_topic_encrypted("billing", "invoices"));
+    }
+
+    #[test]
+    fn all_topics_should_be_encrypted_for_wildcard_pattern() {
+        let mut config = EncryptionConfig {
+            enabled: true,
+            key: "".to_string(),
+            provider: KeyProviderKind::Config,
+            file_path: "".to_string(),
+            topics: vec!["*".to_string()],
+        };
+        assert!(config.is_topic_encrypted("stream", "topic"));
+
+        config.enabled = false;
+        assert!(!config.is_topic_encrypted("stream", "topic"));
+    }
+
+    #[test]
+    fn partitions_should_be_placed_on_system_path_without_additional_data_paths() {
+        let config = SystemConfig::default();
+
+        assert_eq!(config.get_data_paths(), vec![config.get_system_path()]);
+        assert_eq!(
+            config.get_partition_path(1, 2, 3),
+            format!("{}/3", config.get_partitions_path(1, 2))
+        );
+    }
+
+    #[test]
+    fn partitions_of_topic_should_be_placed_in_round_robin() {
+        let mut config = SystemConfig::default();
+        config.placement.paths = vec!["disk_2".to_string(), "disk_3".to_string()];
+        config.placement.policy = PlacementPolicy::RoundRobin;
+
+        let data_paths = (1..=4)
+            .map(|partition_id| config.get_partition_data_path(1, 1, partition_id))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            data_paths,
+            vec![
+                config.get_system_path(),
+                "disk_2".into(),
+                "disk_3".into(),
+                config.get_system_path()
+            ]
+        );
+        assert_eq!(config.get_partition_data_path(1, 2, 1), "disk_2");
+        assert_eq!(
+            config.get_partition_path(1, 2, 1),
+            "disk_2/streams/1/topics/2/partitions/1"
+        );
+    }
+
+    #[test]
+    fn partitions_of_stream_should_be_placed_on_the_same_data_path() {
+        let mut config = SystemConfig::default();
+        config.placement.paths = vec!["disk_2".to_string()];
+        config.placement.policy = PlacementPolicy::Stream;
+
+        for topic_id in 1..=3 {
+            for partition_id in 1..=3 {
+                assert_eq!(
+                    config.get_partition_data_path(1, topic_id, partition_id),
+                    config.get_system_path()
+                );
+                assert_eq!(
+                    config.get_partition_data_path(2, topic_id, partition_id),
+                    "disk_2"
+                );
+            }
+        }
+    }
+}
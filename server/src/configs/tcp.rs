@@ -1,13 +1,18 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use iggy::utils::{byte_size::IggyByteSize, duration::IggyDuration};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::fmt::Display;
+use std::str::FromStr;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct TcpConfig {
     pub enabled: bool,
     pub address: String,
     pub ipv6: bool,
+    pub json_protocol: bool,
+    pub ip_filter: IpFilterConfig,
     pub tls: TcpTlsConfig,
     pub socket: TcpSocketConfig,
 }
@@ -17,6 +22,51 @@ pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub client_auth: TcpTlsClientAuthConfig,
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TcpTlsClientAuthConfig {
+    pub enabled: bool,
+    pub cert_file: String,
+    pub key_file: String,
+    pub ca_file: String,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    pub users: Vec<CertificateUserMapping>,
+}
+
+/// Maps the identity of the client certificate, being its subject common name (CN)
+/// or any of its subject alternative names (SAN), to the username, in the `identity=username` format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertificateUserMapping {
+    pub identity: String,
+    pub username: String,
+}
+
+impl FromStr for CertificateUserMapping {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplit_once('=') {
+            Some((identity, username))
+                if !identity.trim().is_empty() && !username.trim().is_empty() =>
+            {
+                Ok(CertificateUserMapping {
+                    identity: identity.trim().to_string(),
+                    username: username.trim().to_string(),
+                })
+            }
+            _ => Err(format!(
+                "Invalid certificate user mapping: {s}, expected format: identity=username"
+            )),
+        }
+    }
+}
+
+impl Display for CertificateUserMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.identity, self.username)
+    }
 }
 
 #[serde_as]
@@ -30,3 +80,23 @@ pub struct TcpSocketConfig {
     #[serde_as(as = "DisplayFromStr")]
     pub linger: IggyDuration,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn certificate_user_mapping_should_be_parsed() {
+        let mapping = CertificateUserMapping::from_str(" client.iggy.rs = iggy ").unwrap();
+        assert_eq!(mapping.identity, "client.iggy.rs");
+        assert_eq!(mapping.username, "iggy");
+        assert_eq!(mapping.to_string(), "client.iggy.rs=iggy");
+    }
+
+    #[test]
+    fn certificate_user_mapping_without_username_should_be_rejected() {
+        assert!(CertificateUserMapping::from_str("client.iggy.rs").is_err());
+        assert!(CertificateUserMapping::from_str("client.iggy.rs=").is_err());
+        assert!(CertificateUserMapping::from_str("=user").is_err());
+    }
+}
@@ -1,20 +1,29 @@
 extern crate sysinfo;
 
 use super::server::{
-    ArchiverConfig, DataMaintenanceConfig, MessageSaverConfig, MessagesMaintenanceConfig,
-    StateMaintenanceConfig, TelemetryConfig,
+    ArchiverConfig, ConsumerOffsetsMaintenanceConfig, DataMaintenanceConfig, HeartbeatConfig,
+    MessageSaverConfig, MessagesMaintenanceConfig, StateMaintenanceConfig, TelemetryConfig,
+    WebhooksConfig,
 };
 use super::system::CompressionConfig;
 use crate::archiver::ArchiverKind;
 use crate::configs::server::{PersonalAccessTokenConfig, ServerConfig};
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::configs::system::{
+    BackgroundIoConfig, CacheConfig, ClusterConfig, ConsumerGroupConfig, DiskMonitorConfig,
+    EncryptionConfig, EventsConfig, PlacementConfig, PollResponseConfig, SegmentConfig,
+    SlowConsumerConfig,
+};
+use crate::configs::tcp::TcpTlsClientAuthConfig;
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
+use crate::streaming::utils::key_provider::KeyProviderKind;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::models::system_event::SystemEvent;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::topic_size::MaxTopicSize;
 use iggy::validatable::Validatable;
+use std::collections::HashSet;
 use sysinfo::{Pid, ProcessesToUpdate, System};
 use tracing::{info, warn};
 
@@ -22,10 +31,28 @@ impl Validatable<ServerError> for ServerConfig {
     fn validate(&self) -> Result<(), ServerError> {
         self.data_maintenance.validate()?;
         self.personal_access_token.validate()?;
+        self.heartbeat.validate()?;
         self.system.segment.validate()?;
         self.system.cache.validate()?;
         self.system.compression.validate()?;
+        self.system.encryption.validate()?;
+        self.system.events.validate()?;
+        self.system.slow_consumer.validate()?;
+        self.system.consumer_group.validate()?;
+        self.system.disk_monitor.validate()?;
+        self.system.poll_response.validate()?;
+        self.system.background_io.validate()?;
+        self.system.placement.validate()?;
+        self.system.cluster.validate()?;
         self.telemetry.validate()?;
+        self.webhooks.validate()?;
+        self.tcp.tls.client_auth.validate()?;
+
+        if self.system.placement.paths.contains(&self.system.path) {
+            return Err(ServerError::InvalidConfiguration(
+                "Placement paths cannot contain the system path, which is always used for the partitions.".into(),
+            ));
+        }
 
         let topic_size = match self.system.topic.max_size {
             MaxTopicSize::Custom(size) => Ok(size.as_bytes_u64()),
@@ -76,6 +103,287 @@ impl Validatable<ServerError> for CompressionConfig {
     }
 }
 
+impl Validatable<ServerError> for EncryptionConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.provider == KeyProviderKind::File && self.file_path.is_empty() {
+            return Err(ServerError::InvalidConfiguration(
+                "Encryption key file path cannot be empty.".into(),
+            ));
+        }
+
+        if let Some(pattern) = self.topics.iter().find(|pattern| {
+            pattern.split('/').count() > 2 || pattern.split('/').any(|name| name.trim().is_empty())
+        }) {
+            return Err(ServerError::InvalidConfiguration(format!(
+                "Encrypted topic pattern: '{pattern}' is invalid, it must be in the stream/topic format."
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for EventsConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.stream.trim().is_empty() || self.topic.trim().is_empty() {
+            return Err(ServerError::InvalidConfiguration(
+                "Events stream and topic names cannot be empty.".into(),
+            ));
+        }
+
+        if let IggyExpiry::ServerDefault = self.message_expiry {
+            return Err(ServerError::InvalidConfiguration(
+                "Events message expiry cannot be set to server default.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for SlowConsumerConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cold_reads_threshold == 0 {
+            return Err(ServerError::InvalidConfiguration(
+                "Slow consumer cold reads threshold must be greater than 0.".into(),
+            ));
+        }
+
+        if self.deprioritize && self.deprioritized_poll_count == 0 {
+            return Err(ServerError::InvalidConfiguration(
+                "Slow consumer deprioritized poll count must be greater than 0.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for HeartbeatConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.as_micros() == 0 {
+            return Err(ServerError::InvalidConfiguration(
+                "Heartbeat interval cannot be zero.".into(),
+            ));
+        }
+
+        if self.timeout.as_micros() < self.interval.as_micros() {
+            return Err(ServerError::InvalidConfiguration(
+                "Heartbeat timeout cannot be lower than the heartbeat interval.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ConsumerGroupConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.cleaner_interval.get_duration().is_zero() {
+            return Err(ServerError::InvalidConfiguration(
+                "Consumer group cleaner interval cannot be zero.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for DiskMonitorConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.interval.is_zero() {
+            return Err(ServerError::InvalidConfiguration(
+                "Disk monitor interval cannot be zero.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for BackgroundIoConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.retention_weight == 0
+            || self.compaction_weight == 0
+            || self.archiving_weight == 0
+            || self.recovery_weight == 0
+        {
+            return Err(ServerError::InvalidConfiguration(
+                "Background IO weights cannot be zero.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for PollResponseConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.chunked {
+            return Ok(());
+        }
+
+        if self.chunk_size.as_bytes_u64() == 0 {
+            return Err(ServerError::InvalidConfiguration(
+                "Poll response chunk size cannot be zero.".into(),
+            ));
+        }
+
+        if self.chunk_size.as_bytes_u64() >= u32::MAX as u64 {
+            return Err(ServerError::InvalidConfiguration(
+                "Poll response chunk size must be lower than 4 GB.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for PlacementConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.paths.iter().any(|path| path.is_empty()) {
+            return Err(ServerError::InvalidConfiguration(
+                "Placement paths cannot be empty.".into(),
+            ));
+        }
+
+        let unique_paths = self.paths.iter().collect::<HashSet<_>>();
+        if unique_paths.len() != self.paths.len() {
+            return Err(ServerError::InvalidConfiguration(
+                "Placement paths must be unique.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for ClusterConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.node_id == 0 {
+            return Err(ServerError::InvalidConfiguration(
+                "Cluster node ID must be greater than 0.".into(),
+            ));
+        }
+
+        if self.name.is_empty() || self.name.len() > 255 {
+            return Err(ServerError::InvalidConfiguration(
+                "Cluster node name must have between 1 and 255 characters.".into(),
+            ));
+        }
+
+        if self.address.len() > 255 {
+            return Err(ServerError::InvalidConfiguration(
+                "Cluster node address cannot have more than 255 characters.".into(),
+            ));
+        }
+
+        if self.zone.len() > 255 {
+            return Err(ServerError::InvalidConfiguration(
+                "Cluster node zone cannot have more than 255 characters.".into(),
+            ));
+        }
+
+        if self
+            .followers
+            .iter()
+            .any(|follower_id| *follower_id == 0 || *follower_id == self.node_id)
+        {
+            return Err(ServerError::InvalidConfiguration(
+                "Cluster follower IDs must be greater than 0 and different from the node ID."
+                    .into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for TcpTlsClientAuthConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.cert_file.is_empty() || self.key_file.is_empty() || self.ca_file.is_empty() {
+            return Err(ServerError::InvalidConfiguration(
+                "TCP TLS client auth certificate, key and CA files cannot be empty.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for WebhooksConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if self.urls.is_empty() {
+            return Err(ServerError::InvalidConfiguration(
+                "Webhooks URLs cannot be empty.".into(),
+            ));
+        }
+
+        if let Some(url) = self
+            .urls
+            .iter()
+            .find(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+        {
+            return Err(ServerError::InvalidConfiguration(format!(
+                "Webhook URL: {url} must start with http:// or https://."
+            )));
+        }
+
+        if let Some(event) = self
+            .events
+            .iter()
+            .find(|event| !SystemEvent::NAMES.contains(&event.as_str()))
+        {
+            return Err(ServerError::InvalidConfiguration(format!(
+                "Webhook event: {event} is not supported, available events: {:?}.",
+                SystemEvent::NAMES
+            )));
+        }
+
+        if self.timeout.is_zero() {
+            return Err(ServerError::InvalidConfiguration(
+                "Webhooks timeout cannot be zero, it must be greater than 0.".into(),
+            ));
+        }
+
+        if self.consumer_lag.threshold > 0 && self.consumer_lag.interval.is_zero() {
+            return Err(ServerError::InvalidConfiguration(
+                "Webhooks consumer lag interval cannot be zero, it must be greater than 0.".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for TelemetryConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if !self.enabled {
@@ -157,6 +465,13 @@ impl Validatable<ServerError> for SegmentConfig {
             )));
         }
 
+        if self.index_interval > self.size {
+            return Err(ServerError::InvalidConfiguration(format!(
+                "Segment index interval cannot be greater than segment size: {}.",
+                self.size
+            )));
+        }
+
         Ok(())
     }
 }
@@ -178,6 +493,7 @@ impl Validatable<ServerError> for DataMaintenanceConfig {
         self.archiver.validate()?;
         self.messages.validate()?;
         self.state.validate()?;
+        self.consumer_offsets.validate()?;
         Ok(())
     }
 }
@@ -251,7 +567,9 @@ impl Validatable<ServerError> for ArchiverConfig {
 
 impl Validatable<ServerError> for MessagesMaintenanceConfig {
     fn validate(&self) -> Result<(), ServerError> {
-        if self.archiver_enabled && self.interval.is_zero() {
+        if (self.archiver_enabled || self.compactor_enabled || self.reencryptor_enabled)
+            && self.interval.is_zero()
+        {
             return Err(ServerError::InvalidConfiguration(
                 "Message maintenance interval size cannot be zero, it must be greater than 0."
                     .into(),
@@ -274,6 +592,19 @@ impl Validatable<ServerError> for StateMaintenanceConfig {
     }
 }
 
+impl Validatable<ServerError> for ConsumerOffsetsMaintenanceConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.compactor_enabled && self.interval.is_zero() {
+            return Err(ServerError::InvalidConfiguration(
+                "Consumer offsets maintenance interval size cannot be zero, it must be greater than 0."
+                    .into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for PersonalAccessTokenConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.max_tokens_per_user == 0 {
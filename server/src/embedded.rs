@@ -0,0 +1,372 @@
+use crate::channels::commands::compact_consumer_offsets::CompactConsumerOffsetsExecutor;
+use crate::channels::commands::maintain_messages::MaintainMessagesExecutor;
+use crate::channels::commands::publish_events::PublishEventsExecutor;
+use crate::channels::commands::save_messages::SaveMessagesExecutor;
+use crate::channels::handler::{ServerCommandHandler, ServerCommandTasks};
+use crate::configs::server::ServerConfig;
+use crate::server_error::ServerError;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::messages::PollingArgs;
+use crate::streaming::systems::system::{SharedSystem, System};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::consumer::Consumer;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
+use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::messages::PolledMessages;
+use iggy::streams::create_stream::CreateStream;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::info;
+
+/// The streaming engine running in-process as a durable log, without the TCP, QUIC and HTTP servers.
+///
+/// All the operations are performed on behalf of the root user, and the buffered messages are saved
+/// in the background by the message saver, just like in the standalone server.
+/// The underlying `System` is available with `system()` for the operations not covered by this API,
+/// but unlike this API, it's not considered stable.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use iggy::identifier::Identifier;
+/// use iggy::messages::send_messages::{Message, Partitioning};
+/// use iggy::utils::expiry::IggyExpiry;
+/// use server::configs::server::ServerConfig;
+/// use server::embedded::EmbeddedIggy;
+/// use std::str::FromStr;
+///
+/// let iggy = EmbeddedIggy::start(ServerConfig::default()).await?;
+/// let stream_id = iggy.create_stream(None, "orders").await?;
+/// let stream_id = Identifier::numeric(stream_id)?;
+/// let topic_id = iggy
+///     .create_topic(&stream_id, None, "created", 1, IggyExpiry::NeverExpire)
+///     .await?;
+/// iggy.send_messages(
+///     &stream_id,
+///     &Identifier::numeric(topic_id)?,
+///     &Partitioning::balanced(),
+///     vec![Message::from_str("hello")?],
+/// )
+/// .await?;
+/// iggy.shutdown().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct EmbeddedIggy {
+    system: SharedSystem,
+    session: Session,
+    background_tasks: ServerCommandTasks,
+}
+
+impl EmbeddedIggy {
+    /// Loads the streams from the configured system path (creating it if missing) and starts the background tasks.
+    /// Only the `system`, `data_maintenance`, `message_saver` and `personal_access_token` sections of the config are used.
+    pub async fn start(config: ServerConfig) -> Result<Self, ServerError> {
+        let system = SharedSystem::new(System::new(
+            config.system.clone(),
+            config.data_maintenance.clone(),
+            config.personal_access_token.clone(),
+        ));
+        system.write().await.get_stats().await?;
+        system.write().await.init().await?;
+        let background_tasks = ServerCommandHandler::new(system.clone(), &config)
+            .install_handler(SaveMessagesExecutor)
+            .install_handler(MaintainMessagesExecutor)
+            .install_handler(CompactConsumerOffsetsExecutor)
+            .install_handler(PublishEventsExecutor)
+            .into_tasks();
+
+        info!("Embedded Iggy has started.");
+        Ok(Self {
+            system,
+            session: Session::stateless(
+                DEFAULT_ROOT_USER_ID,
+                SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+            ),
+            background_tasks,
+        })
+    }
+
+    /// Returns the underlying system, which API is not considered stable.
+    pub fn system(&self) -> &SharedSystem {
+        &self.system
+    }
+
+    /// Creates the stream and returns its ID.
+    pub async fn create_stream(
+        &self,
+        stream_id: Option<u32>,
+        name: &str,
+    ) -> Result<u32, IggyError> {
+        let mut system = self.system.write().await;
+        let stream = system.create_stream(&self.session, stream_id, name).await?;
+        let command = CreateStream {
+            stream_id: Some(stream.stream_id),
+            name: stream.name.clone(),
+        };
+        let stream_id = stream.stream_id;
+        system
+            .state
+            .apply(DEFAULT_ROOT_USER_ID, EntryCommand::CreateStream(command))
+            .await?;
+        Ok(stream_id)
+    }
+
+    /// Deletes the stream with all its topics and messages.
+    pub async fn delete_stream(&self, stream_id: &Identifier) -> Result<(), IggyError> {
+        let mut system = self.system.write().await;
+        system.delete_stream(&self.session, stream_id).await?;
+        let command = DeleteStream {
+            stream_id: stream_id.clone(),
+        };
+        system
+            .state
+            .apply(DEFAULT_ROOT_USER_ID, EntryCommand::DeleteStream(command))
+            .await
+    }
+
+    /// Creates the topic with the default compression, size and cleanup policy, and returns its ID.
+    pub async fn create_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: Option<u32>,
+        name: &str,
+        partitions_count: u32,
+        message_expiry: IggyExpiry,
+    ) -> Result<u32, IggyError> {
+        let mut system = self.system.write().await;
+        let topic = system
+            .create_topic(
+                &self.session,
+                stream_id,
+                topic_id,
+                name,
+                partitions_count,
+                message_expiry,
+                CompressionAlgorithm::default(),
+                MaxTopicSize::ServerDefault,
+                None,
+                CleanupPolicy::default(),
+                TopicOverrides::default(),
+            )
+            .await?;
+        let command = CreateTopic {
+            stream_id: stream_id.clone(),
+            topic_id: Some(topic.topic_id),
+            partitions_count,
+            compression_algorithm: topic.compression_algorithm,
+            message_expiry: topic.message_expiry,
+            max_topic_size: topic.max_topic_size,
+            replication_factor: None,
+            cleanup_policy: topic.cleanup_policy,
+            overrides: TopicOverrides::default(),
+            name: topic.name.clone(),
+        };
+        let topic_id = topic.topic_id;
+        system
+            .state
+            .apply(DEFAULT_ROOT_USER_ID, EntryCommand::CreateTopic(command))
+            .await?;
+        Ok(topic_id)
+    }
+
+    /// Deletes the topic with all its messages.
+    pub async fn delete_topic(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        let mut system = self.system.write().await;
+        system
+            .delete_topic(&self.session, stream_id, topic_id)
+            .await?;
+        let command = DeleteTopic {
+            stream_id: stream_id.clone(),
+            topic_id: topic_id.clone(),
+        };
+        system
+            .state
+            .apply(DEFAULT_ROOT_USER_ID, EntryCommand::DeleteTopic(command))
+            .await
+    }
+
+    /// Appends the messages to the partition resolved by the partitioning.
+    pub async fn send_messages(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitioning: &Partitioning,
+        messages: Vec<Message>,
+    ) -> Result<(), IggyError> {
+        let system = self.system.read().await;
+        system
+            .append_messages(
+                &self.session,
+                stream_id.clone(),
+                topic_id.clone(),
+                partitioning.clone(),
+                AckLevel::default(),
+                messages,
+            )
+            .await
+    }
+
+    /// Polls the messages for the consumer, optionally storing the offset of the last polled message.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn poll_messages(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<PolledMessages, IggyError> {
+        let system = self.system.read().await;
+        system
+            .poll_messages(
+                &self.session,
+                consumer,
+                stream_id,
+                topic_id,
+                partition_id,
+                PollingArgs::new(strategy, count, 0, auto_commit),
+            )
+            .await
+    }
+
+    /// Stores the offset of the consumer.
+    pub async fn store_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+        offset: u64,
+    ) -> Result<(), IggyError> {
+        let system = self.system.read().await;
+        system
+            .store_consumer_offset(
+                &self.session,
+                consumer.clone(),
+                stream_id,
+                topic_id,
+                partition_id,
+                offset,
+            )
+            .await
+    }
+
+    /// Returns the stored offset of the consumer along with the current offset of the partition.
+    pub async fn get_consumer_offset(
+        &self,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<ConsumerOffsetInfo, IggyError> {
+        let system = self.system.read().await;
+        system
+            .get_consumer_offset(&self.session, consumer, stream_id, topic_id, partition_id)
+            .await
+    }
+
+    /// Saves the buffered messages on disk and returns their number.
+    pub async fn flush(&self) -> Result<usize, IggyError> {
+        self.system.read().await.persist_messages().await
+    }
+
+    /// Stops the background tasks and saves the buffered messages on disk,
+    /// after which the data can be loaded again by starting with the same config.
+    pub async fn shutdown(self) -> Result<(), IggyError> {
+        self.system.clear_event_subscribers();
+        self.background_tasks.stop().await;
+        self.system.write().await.shutdown().await?;
+        info!("Embedded Iggy has shutdown.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use iggy::utils::duration::IggyDuration;
+    use std::env::temp_dir;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn create_config() -> (ServerConfig, PathBuf) {
+        let path = temp_dir().join(format!("iggy-embedded-{}", Uuid::now_v7()));
+        let mut system_config = SystemConfig {
+            path: path.to_string_lossy().to_string(),
+            ..SystemConfig::default()
+        };
+        system_config.placement.paths = system_config
+            .placement
+            .paths
+            .iter()
+            .map(|placement_path| format!("{}/{placement_path}", system_config.path))
+            .collect();
+        let config = ServerConfig {
+            system: Arc::new(system_config),
+            ..ServerConfig::default()
+        };
+        (config, path)
+    }
+
+    #[tokio::test]
+    async fn shutdown_should_stop_background_tasks_before_restarting_on_the_same_path() {
+        let (mut config, path) = create_config();
+        config.message_saver.interval = IggyDuration::from_str("10ms").unwrap();
+        let stream_id = Identifier::numeric(1).unwrap();
+
+        let iggy = EmbeddedIggy::start(config.clone()).await.unwrap();
+        let system = iggy.system().clone();
+        iggy.create_stream(Some(1), "embedded").await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        iggy.shutdown().await.unwrap();
+        assert_eq!(system.handles_count(), 1);
+
+        let iggy = EmbeddedIggy::start(config).await.unwrap();
+        assert!(iggy.system().read().await.get_stream(&stream_id).is_ok());
+        iggy.shutdown().await.unwrap();
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn events_should_be_delivered_only_to_own_subscribers_until_shutdown() {
+        let mut instances = Vec::new();
+        for _ in 0..2 {
+            let (mut config, path) = create_config();
+            let mut system_config = (*config.system).clone();
+            system_config.events.enabled = true;
+            config.system = Arc::new(system_config);
+            instances.push((EmbeddedIggy::start(config).await.unwrap(), path));
+        }
+
+        for (iggy, _) in &instances {
+            assert_eq!(iggy.system().read().await.events_bus.subscribers_count(), 1);
+        }
+
+        for (iggy, path) in instances {
+            let system = iggy.system().clone();
+            iggy.shutdown().await.unwrap();
+            assert_eq!(system.read().await.events_bus.subscribers_count(), 0);
+            std::fs::remove_dir_all(&path).unwrap();
+        }
+    }
+}
@@ -91,6 +91,7 @@ async fn create_consumer_group(
                 &command.topic_id,
                 command.group_id,
                 &command.name,
+                command.rebalance_strategy,
             )
             .await?;
         let consumer_group = consumer_group.read().await;
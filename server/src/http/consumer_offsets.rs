@@ -4,13 +4,19 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router};
 use iggy::consumer::Consumer;
+use iggy::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::models::consumer_lag_info::ConsumerLagInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -18,7 +24,25 @@ pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route(
             "/streams/:stream_id/topics/:topic_id/consumer-offsets",
-            get(get_consumer_offset).put(store_consumer_offset),
+            get(get_consumer_offset)
+                .put(store_consumer_offset)
+                .delete(delete_consumer_offsets),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-offsets/reset",
+            post(reset_consumer_offsets),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/consumers",
+            get(get_consumers),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/consumers/:consumer_id",
+            delete(delete_consumer),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/consumer-lag",
+            get(get_consumer_lag),
         )
         .with_state(state)
 }
@@ -50,6 +74,29 @@ async fn get_consumer_offset(
     Ok(Json(offset?))
 }
 
+async fn get_consumer_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetConsumerLag>,
+) -> Result<Json<Vec<ConsumerLagInfo>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+    let consumer = Consumer::new(query.0.consumer.id);
+    let system = state.system.read().await;
+    let lags = system
+        .get_consumer_lag(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &consumer,
+            &query.0.stream_id,
+            &query.0.topic_id,
+            query.0.partition_id,
+        )
+        .await?;
+    Ok(Json(lags))
+}
+
 async fn store_consumer_offset(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -73,3 +120,91 @@ async fn store_consumer_offset(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn delete_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<DeleteConsumerOffsets>,
+) -> Result<StatusCode, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+    let consumer = Consumer::new(query.0.consumer.id);
+    let system = state.system.read().await;
+    system
+        .delete_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &consumer,
+            &query.0.stream_id,
+            &query.0.topic_id,
+            query.0.partition_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn reset_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<ResetConsumerOffsets>,
+) -> Result<Json<Vec<ConsumerOffsetResetInfo>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let consumer = Consumer::new(command.0.consumer.id);
+    let system = state.system.read().await;
+    let resets = system
+        .reset_consumer_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &consumer,
+            &command.0.stream_id,
+            &command.0.topic_id,
+            &command.0.partitions,
+            &command.0.strategy,
+            command.0.dry_run,
+        )
+        .await?;
+    Ok(Json(resets))
+}
+
+async fn get_consumers(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+) -> Result<Json<Vec<ConsumerInfo>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let consumers = system
+        .get_consumers(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+            partition_id,
+        )
+        .await?;
+    Ok(Json(consumers))
+}
+
+async fn delete_consumer(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id, consumer_id)): Path<(String, String, u32, String)>,
+) -> Result<StatusCode, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let consumer_id = Identifier::from_str_value(&consumer_id)?;
+    let system = state.system.read().await;
+    system
+        .delete_consumer(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &consumer_id,
+            &stream_id,
+            &topic_id,
+            partition_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -33,11 +33,15 @@ impl IntoResponse for CustomError {
                     IggyError::PartitionNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     IggyError::SegmentNotFound => StatusCode::NOT_FOUND,
                     IggyError::ClientNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::SessionNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupNameNotFound(_, _) => StatusCode::NOT_FOUND,
                     IggyError::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
+                    IggyError::ConsumerNotFound(_, _) => StatusCode::NOT_FOUND,
+                    IggyError::ConsumerOffsetNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::CannotLoadResource(_) => StatusCode::NOT_FOUND,
                     IggyError::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    IggyError::NodeNotFound(_) => StatusCode::NOT_FOUND,
                     IggyError::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -45,6 +49,8 @@ impl IntoResponse for CustomError {
                     IggyError::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     IggyError::Unauthenticated => StatusCode::UNAUTHORIZED,
                     IggyError::Unauthorized => StatusCode::FORBIDDEN,
+                    IggyError::UserSourceNotAllowed(_, _) => StatusCode::FORBIDDEN,
+                    IggyError::InsufficientDiskSpace => StatusCode::INSUFFICIENT_STORAGE,
                     _ => StatusCode::BAD_REQUEST,
                 };
                 (status_code, Json(ErrorResponse::from_error(error)))
@@ -75,6 +81,7 @@ impl ErrorResponse {
                 IggyError::PartitionNotFound(_, _, _) => Some("partition_id".to_string()),
                 IggyError::SegmentNotFound => Some("segment_id".to_string()),
                 IggyError::ClientNotFound(_) => Some("client_id".to_string()),
+                IggyError::SessionNotFound(_) => Some("client_id".to_string()),
                 IggyError::InvalidStreamName => Some("name".to_string()),
                 IggyError::StreamNameAlreadyExists(_) => Some("name".to_string()),
                 IggyError::InvalidTopicName => Some("name".to_string()),
@@ -84,7 +91,15 @@ impl ErrorResponse {
                 IggyError::InvalidTopicId => Some("topic_id".to_string()),
                 IggyError::TopicIdAlreadyExists(_, _) => Some("topic_id".to_string()),
                 IggyError::InvalidOffset(_) => Some("offset".to_string()),
+                IggyError::OffsetBelowLowWatermark(_, _, _) => Some("offset".to_string()),
+                IggyError::OffsetAboveHighWatermark(_, _, _) => Some("offset".to_string()),
                 IggyError::InvalidConsumerGroupId => Some("consumer_group_id".to_string()),
+                IggyError::InvalidSchemaType => Some("schema_type".to_string()),
+                IggyError::InvalidContentType => Some("headers".to_string()),
+                IggyError::InvalidContentEncoding => Some("headers".to_string()),
+                IggyError::PayloadContentTypeMismatch(_) => Some("payload".to_string()),
+                IggyError::InvalidSchemaDefinition(_) => Some("definition".to_string()),
+                IggyError::SchemaValidationNotSupported(_) => Some("validate".to_string()),
                 IggyError::ConsumerGroupIdAlreadyExists(_, _) => {
                     Some("consumer_group_id".to_string())
                 }
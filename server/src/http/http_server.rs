@@ -1,5 +1,6 @@
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
 use crate::http::diagnostics::request_diagnostics;
+use crate::http::ip_filter::ip_filter;
 use crate::http::jwt::cleaner::start_expired_tokens_cleaner;
 use crate::http::jwt::jwt_manager::JwtManager;
 use crate::http::jwt::middleware::jwt_auth;
@@ -36,6 +37,7 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
         .merge(consumer_groups::router(app_state.clone()))
         .merge(consumer_offsets::router(app_state.clone()))
         .merge(partitions::router(app_state.clone()))
+        .merge(schemas::router(app_state.clone()))
         .merge(messages::router(app_state.clone()))
         .layer(DefaultBodyLimit::max(
             config.max_request_size.as_bytes_u64() as usize,
@@ -52,6 +54,12 @@ pub async fn start(config: HttpConfig, system: SharedSystem) -> SocketAddr {
 
     start_expired_tokens_cleaner(app_state.clone());
     app = app.layer(middleware::from_fn(request_diagnostics));
+    if config.ip_filter.enabled {
+        app = app.layer(middleware::from_fn_with_state(
+            Arc::new(config.ip_filter.clone()),
+            ip_filter,
+        ));
+    }
 
     if !config.tls.enabled {
         let listener = tokio::net::TcpListener::bind(config.address.clone())
@@ -0,0 +1,25 @@
+use crate::configs::ip_filter::IpFilterConfig;
+use axum::body::Body;
+use axum::{
+    extract::{ConnectInfo, State},
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::warn;
+
+pub async fn ip_filter(
+    State(filter): State<Arc<IpFilterConfig>>,
+    ConnectInfo(ip_address): ConnectInfo<SocketAddr>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if !filter.is_allowed(&ip_address.ip()) {
+        warn!("Rejected HTTP request from not allowed IP address: {ip_address}.");
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(next.run(request).await)
+}
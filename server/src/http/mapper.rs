@@ -3,14 +3,17 @@ use crate::streaming::clients::client_manager::Client;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::schema::Schema;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
+use iggy::command::get_name_from_code;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
 use iggy::models::client_info::ConsumerGroupInfo;
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
 use iggy::models::identity_info::{IdentityInfo, TokenInfo};
 use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::session_info::SessionInfo;
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -64,6 +67,8 @@ pub fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             compression_algorithm: topic.compression_algorithm,
             max_topic_size: topic.max_topic_size,
             replication_factor: topic.replication_factor,
+            cleanup_policy: topic.cleanup_policy,
+            read_only: topic.read_only,
         };
         topics_data.push(topic);
     }
@@ -79,11 +84,14 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         size: topic.get_size_bytes(),
         messages_count: topic.get_messages_count(),
         partitions_count: topic.get_partitions().len() as u32,
+        config: topic.get_config_entries(),
         partitions: Vec::new(),
         message_expiry: topic.message_expiry,
         compression_algorithm: topic.compression_algorithm,
         max_topic_size: topic.max_topic_size,
         replication_factor: topic.replication_factor,
+        cleanup_policy: topic.cleanup_policy,
+        read_only: topic.read_only,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -182,6 +190,32 @@ pub async fn map_clients(
     all_clients
 }
 
+pub async fn map_sessions(clients: &[IggySharedMut<Client>]) -> Vec<SessionInfo> {
+    let mut sessions = Vec::new();
+    for client in clients {
+        let client = client.read().await;
+        let (last_command, last_command_at) = match client.session.get_last_command() {
+            Some((code, timestamp)) => (
+                Some(get_name_from_code(code).unwrap_or("unknown").to_string()),
+                Some(timestamp),
+            ),
+            None => (None, None),
+        };
+        sessions.push(SessionInfo {
+            client_id: client.session.client_id,
+            user_id: client.user_id,
+            address: client.session.ip_address.to_string(),
+            transport: client.transport.to_string(),
+            connected_at: client.session.connected_at,
+            last_command,
+            last_command_at,
+        });
+    }
+
+    sessions.sort_by(|a, b| a.client_id.cmp(&b.client_id));
+    sessions
+}
+
 pub async fn map_consumer_groups(
     consumer_groups: &[&RwLock<ConsumerGroup>],
 ) -> Vec<iggy::models::consumer_group::ConsumerGroup> {
@@ -193,6 +227,7 @@ pub async fn map_consumer_groups(
             name: consumer_group.name.clone(),
             partitions_count: consumer_group.partitions_count,
             members_count: consumer_group.get_members().len() as u32,
+            rebalance_strategy: consumer_group.rebalance_strategy,
         };
         groups.push(consumer_group);
     }
@@ -206,6 +241,7 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         name: consumer_group.name.clone(),
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
+        rebalance_strategy: consumer_group.rebalance_strategy,
         members: Vec::new(),
     };
     let members = consumer_group.get_members();
@@ -221,6 +257,20 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
     consumer_group_details
 }
 
+pub fn map_schema(schema: &Schema) -> iggy::models::schema::Schema {
+    iggy::models::schema::Schema {
+        version: schema.version,
+        schema_type: schema.schema_type,
+        validate: schema.validate,
+        created_at: schema.created_at,
+        definition: schema.definition.clone(),
+    }
+}
+
+pub fn map_schemas(schemas: &[Schema]) -> Vec<iggy::models::schema::Schema> {
+    schemas.iter().map(map_schema).collect()
+}
+
 pub fn map_generated_access_token_to_identity_info(token: GeneratedToken) -> IdentityInfo {
     IdentityInfo {
         user_id: token.user_id,
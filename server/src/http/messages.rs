@@ -6,16 +6,31 @@ use crate::streaming::systems::messages::PollingArgs;
 use crate::streaming::utils::random_id;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
+use bytes::Bytes;
 use iggy::consumer::Consumer;
+use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::messages::copy_messages::CopyMessages;
+use iggy::messages::get_message_by_key::GetMessageByKey;
+use iggy::messages::peek_messages::PeekMessages;
 use iggy::messages::poll_messages::PollMessages;
-use iggy::messages::send_messages::SendMessages;
-use iggy::models::messages::PolledMessages;
+use iggy::messages::poll_messages_from_partitions::PollMessagesFromPartitions;
+use iggy::messages::send_messages::{AckLevel, SendMessages};
+use iggy::models::copied_messages_info::CopiedMessagesInfo;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{MessageState, PolledMessage, PolledMessages};
+use iggy::utils::content_type::ContentType;
+use iggy::utils::payload_format::PayloadFormat;
 use iggy::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::instrument;
+use tracing::{error, instrument};
 
 pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
@@ -23,6 +38,22 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/messages",
             get(poll_messages).post(send_messages),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/poll",
+            post(poll_messages_from_partitions),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/peek",
+            get(peek_messages),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/key",
+            get(get_message_by_key),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/messages/copy",
+            post(copy_messages),
+        )
         .route(
             "/streams/:stream_id/topics/:topic_id/messages/flush/:partition_id/:fsync",
             get(flush_unsaved_buffer),
@@ -30,12 +61,48 @@ pub fn router(state: Arc<AppState>) -> Router {
         .with_state(state)
 }
 
+#[derive(Debug, Deserialize)]
+struct PayloadFormatQuery {
+    #[serde(default)]
+    payload_format: PayloadFormat,
+}
+
+#[derive(Debug, Serialize)]
+struct FormattedPolledMessages {
+    partition_id: u32,
+    current_offset: u64,
+    epoch: u32,
+    next_offset: Option<u64>,
+    messages: Vec<FormattedPolledMessage>,
+}
+
+#[derive(Debug, Serialize)]
+struct FormattedPolledMessage {
+    offset: u64,
+    state: MessageState,
+    timestamp: u64,
+    id: u128,
+    checksum: u32,
+    headers: Option<HashMap<HeaderKey, HeaderValue>>,
+    payload: FormattedPayload,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum FormattedPayload {
+    Json(serde_json::Value),
+    Text(String),
+    Base64(#[serde_as(as = "Base64")] Bytes),
+}
+
 async fn poll_messages(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
-) -> Result<Json<PolledMessages>, CustomError> {
+    Query(format): Query<PayloadFormatQuery>,
+) -> Result<Response, CustomError> {
     query.stream_id = Identifier::from_str_value(&stream_id)?;
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
@@ -49,10 +116,195 @@ async fn poll_messages(
             &query.0.stream_id,
             &query.0.topic_id,
             query.0.partition_id,
-            PollingArgs::new(query.0.strategy, query.0.count, query.0.auto_commit),
+            PollingArgs::new(
+                query.0.strategy,
+                query.0.count,
+                query.0.max_bytes,
+                query.0.auto_commit,
+            ),
         )
         .await?;
-    Ok(Json(polled_messages))
+    if format.payload_format == PayloadFormat::Base64 {
+        return Ok(Json(polled_messages).into_response());
+    }
+
+    let polled_messages = format_polled_messages(polled_messages, format.payload_format)?;
+    Ok(Json(polled_messages).into_response())
+}
+
+async fn poll_messages_from_partitions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<PollMessagesFromPartitions>,
+) -> Result<Json<Vec<PolledMessages>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let consumer = Consumer::new(command.consumer.id);
+    let system = state.system.read().await;
+    let batches = system
+        .poll_messages_from_partitions(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &consumer,
+            &command.stream_id,
+            &command.topic_id,
+            &command.partitions,
+            command.out_of_range,
+            command.count,
+            command.auto_commit,
+        )
+        .await?;
+    Ok(Json(batches))
+}
+
+async fn peek_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<PeekMessages>,
+    Query(format): Query<PayloadFormatQuery>,
+) -> Result<Response, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let system = state.system.read().await;
+    let polled_messages = system
+        .peek_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.0.stream_id,
+            &query.0.topic_id,
+            query.0.partition_id,
+            query.0.kind,
+            query.0.count,
+        )
+        .await?;
+    if format.payload_format == PayloadFormat::Base64 {
+        return Ok(Json(polled_messages).into_response());
+    }
+
+    let polled_messages = format_polled_messages(polled_messages, format.payload_format)?;
+    Ok(Json(polled_messages).into_response())
+}
+
+async fn get_message_by_key(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetMessageByKey>,
+    Query(format): Query<PayloadFormatQuery>,
+) -> Result<Response, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let system = state.system.read().await;
+    let polled_messages = system
+        .get_message_by_key(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.0.stream_id,
+            &query.0.topic_id,
+            query.0.partition_id,
+            query.0.key,
+        )
+        .await?;
+    if format.payload_format == PayloadFormat::Base64 {
+        return Ok(Json(polled_messages).into_response());
+    }
+
+    let polled_messages = format_polled_messages(polled_messages, format.payload_format)?;
+    Ok(Json(polled_messages).into_response())
+}
+
+async fn copy_messages(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<CopyMessages>,
+) -> Result<Json<CopiedMessagesInfo>, CustomError> {
+    command.source_stream_id = Identifier::from_str_value(&stream_id)?;
+    command.source_topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let copied_messages = system
+        .copy_messages(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.source_stream_id,
+            &command.source_topic_id,
+            command.source_partition_id,
+            &command.target_stream_id,
+            &command.target_topic_id,
+            command.target_partition_id,
+            command.kind,
+            command.from,
+            command.to,
+        )
+        .await?;
+    Ok(Json(copied_messages))
+}
+
+fn format_polled_messages(
+    polled_messages: PolledMessages,
+    payload_format: PayloadFormat,
+) -> Result<FormattedPolledMessages, IggyError> {
+    let mut messages = Vec::with_capacity(polled_messages.messages.len());
+    for message in polled_messages.messages {
+        let payload = format_payload(&message, payload_format)?;
+        messages.push(FormattedPolledMessage {
+            offset: message.offset,
+            state: message.state,
+            timestamp: message.timestamp,
+            id: message.id,
+            checksum: message.checksum,
+            headers: message.headers,
+            payload,
+        });
+    }
+
+    Ok(FormattedPolledMessages {
+        partition_id: polled_messages.partition_id,
+        current_offset: polled_messages.current_offset,
+        epoch: polled_messages.epoch,
+        next_offset: polled_messages.next_offset,
+        messages,
+    })
+}
+
+fn format_payload(
+    message: &PolledMessage,
+    payload_format: PayloadFormat,
+) -> Result<FormattedPayload, IggyError> {
+    if message.is_tombstone() {
+        return Ok(FormattedPayload::Json(serde_json::Value::Null));
+    }
+
+    let content_type = match payload_format {
+        PayloadFormat::Base64 => ContentType::Binary,
+        PayloadFormat::Raw => ContentType::Text,
+        PayloadFormat::Json => message.content_type()?.unwrap_or(ContentType::Json),
+    };
+    match content_type {
+        ContentType::Binary => Ok(FormattedPayload::Base64(message.payload.clone())),
+        ContentType::Json => serde_json::from_slice(&message.payload)
+            .map(FormattedPayload::Json)
+            .map_err(|error| {
+                IggyError::PayloadContentTypeMismatch(format!(
+                    "message with offset: {} is not a valid JSON: {error}",
+                    message.offset
+                ))
+            }),
+        ContentType::Text => String::from_utf8(message.payload.to_vec())
+            .map(FormattedPayload::Text)
+            .map_err(|error| {
+                IggyError::PayloadContentTypeMismatch(format!(
+                    "message with offset: {} is not a valid UTF-8 text: {error}",
+                    message.offset
+                ))
+            }),
+    }
 }
 
 async fn send_messages(
@@ -75,15 +327,28 @@ async fn send_messages(
     let stream_id = command.stream_id;
     let topic_id = command.topic_id;
     let partitioning = command.partitioning;
+    let ack = command.ack;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    if ack == AckLevel::None {
+        tokio::spawn(async move {
+            let system = state.system.read().await;
+            let messages_count = messages.len() as u64;
+            if let Err(error) = system
+                .append_messages(&session, stream_id, topic_id, partitioning, ack, messages)
+                .await
+            {
+                error!("Failed to append unacknowledged messages for session: {session}. Error: {error}");
+                system
+                    .metrics
+                    .increment_failed_unacknowledged_appends(messages_count);
+            }
+        });
+        return Ok(StatusCode::ACCEPTED);
+    }
+
     let system = state.system.read().await;
     system
-        .append_messages(
-            &Session::stateless(identity.user_id, identity.ip_address),
-            stream_id,
-            topic_id,
-            partitioning,
-            messages,
-        )
+        .append_messages(&session, stream_id, topic_id, partitioning, ack, messages)
         .await?;
     Ok(StatusCode::CREATED)
 }
@@ -108,3 +373,77 @@ async fn flush_unsaved_buffer(
         .await?;
     Ok(StatusCode::OK)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::timestamp::IggyTimestamp;
+
+    fn create_message(
+        offset: u64,
+        payload: &[u8],
+        content_type: Option<ContentType>,
+    ) -> PolledMessage {
+        let headers = content_type.map(|content_type| {
+            let (key, value) = content_type.to_header().unwrap();
+            HashMap::from([(key, value)])
+        });
+        PolledMessage::create(
+            offset,
+            MessageState::Available,
+            IggyTimestamp::now(),
+            offset as u128 + 1,
+            Bytes::copy_from_slice(payload),
+            0,
+            headers,
+        )
+    }
+
+    #[test]
+    fn should_format_payloads_based_on_content_type() {
+        let polled_messages = PolledMessages {
+            partition_id: 1,
+            current_offset: 2,
+            epoch: 0,
+            next_offset: None,
+            messages: vec![
+                create_message(0, br#"{"id":1}"#, None),
+                create_message(1, b"hello", Some(ContentType::Text)),
+                create_message(2, &[0xff, 0x00], Some(ContentType::Binary)),
+            ],
+        };
+
+        let formatted = format_polled_messages(polled_messages, PayloadFormat::Json).unwrap();
+        let json = serde_json::to_value(&formatted).unwrap();
+        assert_eq!(json["messages"][0]["payload"]["id"], 1);
+        assert_eq!(json["messages"][1]["payload"], "hello");
+        assert_eq!(json["messages"][2]["payload"], "/wA=");
+    }
+
+    #[test]
+    fn should_fail_to_format_invalid_payloads() {
+        let polled_messages = PolledMessages {
+            partition_id: 1,
+            current_offset: 0,
+            epoch: 0,
+            next_offset: None,
+            messages: vec![create_message(0, b"not a json", None)],
+        };
+        assert!(matches!(
+            format_polled_messages(polled_messages, PayloadFormat::Json),
+            Err(IggyError::PayloadContentTypeMismatch(_))
+        ));
+
+        let polled_messages = PolledMessages {
+            partition_id: 1,
+            current_offset: 0,
+            epoch: 0,
+            next_offset: None,
+            messages: vec![create_message(0, &[0xff, 0xfe], None)],
+        };
+        assert!(matches!(
+            format_polled_messages(polled_messages, PayloadFormat::Raw),
+            Err(IggyError::PayloadContentTypeMismatch(_))
+        ));
+    }
+}
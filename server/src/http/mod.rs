@@ -3,12 +3,14 @@ pub mod consumer_offsets;
 pub mod diagnostics;
 pub mod error;
 pub mod http_server;
+pub mod ip_filter;
 pub mod jwt;
 mod mapper;
 pub mod messages;
 pub mod metrics;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 mod shared;
 pub mod streams;
 pub mod system;
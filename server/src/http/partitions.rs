@@ -5,11 +5,21 @@ use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
+use iggy::models::messages_histogram::MessagesHistogram;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::partition_offsets_info::PartitionOffsetsInfo;
+use iggy::models::sealed_partition_info::SealedPartitionInfo;
+use iggy::models::timestamp_offset_info::TimestampOffsetInfo;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_messages_histogram::GetMessagesHistogram;
+use iggy::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
+use iggy::partitions::seal_partition::SealPartition;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 use tracing::instrument;
@@ -20,9 +30,125 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/partitions",
             post(create_partitions).delete(delete_partitions),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/offsets",
+            get(get_partition_offsets),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id",
+            get(get_partition),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/offset-by-timestamp",
+            get(get_offset_by_timestamp),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/histogram",
+            get(get_messages_histogram),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/partitions/:partition_id/seal",
+            post(seal_partition),
+        )
         .with_state(state)
 }
 
+async fn get_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+) -> Result<Json<PartitionDetails>, CustomError> {
+    let system = state.system.read().await;
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let partition = system
+        .get_partition(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &stream_id,
+            &topic_id,
+            partition_id,
+        )
+        .await;
+    if partition.is_err() {
+        return Err(CustomError::ResourceNotFound);
+    }
+
+    Ok(Json(partition?))
+}
+
+async fn get_partition_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetPartitionOffsets>,
+) -> Result<Json<Vec<PartitionOffsetsInfo>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+    let system = state.system.read().await;
+    let offsets = system
+        .get_partition_offsets(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            query.partition_id,
+        )
+        .await?;
+    Ok(Json(offsets))
+}
+
+async fn get_offset_by_timestamp(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    mut query: Query<GetOffsetByTimestamp>,
+) -> Result<Json<TimestampOffsetInfo>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.partition_id = partition_id;
+    query.validate()?;
+    let system = state.system.read().await;
+    let offset = system
+        .get_offset_by_timestamp(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            query.partition_id,
+            query.timestamp,
+        )
+        .await?;
+    let Some(offset) = offset else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(offset))
+}
+
+async fn get_messages_histogram(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    mut query: Query<GetMessagesHistogram>,
+) -> Result<Json<MessagesHistogram>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.partition_id = partition_id;
+    query.validate()?;
+    let system = state.system.read().await;
+    let histogram = system
+        .get_messages_histogram(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &query.stream_id,
+            &query.topic_id,
+            query.partition_id,
+            query.from,
+            query.to,
+            query.bucket_size,
+        )
+        .await?;
+    Ok(Json(histogram))
+}
+
 #[instrument(skip_all, fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
 async fn create_partitions(
     State(state): State<Arc<AppState>>,
@@ -89,3 +215,47 @@ async fn delete_partitions(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn seal_partition(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, partition_id)): Path<(String, String, u32)>,
+    Json(mut command): Json<SealPartition>,
+) -> Result<Json<SealedPartitionInfo>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.partition_id = partition_id;
+    command.validate()?;
+    let session = Session::stateless(identity.user_id, identity.ip_address);
+    if command.read_only {
+        {
+            let mut system = state.system.write().await;
+            system.set_topic_read_only(&session, &command.stream_id, &command.topic_id, true)?;
+        }
+
+        let system = state.system.read().await;
+        system
+            .state
+            .apply(
+                identity.user_id,
+                EntryCommand::SetTopicReadOnly(SetTopicReadOnly {
+                    stream_id: command.stream_id.clone(),
+                    topic_id: command.topic_id.clone(),
+                    read_only: true,
+                }),
+            )
+            .await?;
+    }
+
+    let system = state.system.read().await;
+    let sealed_partition = system
+        .seal_partition(
+            &session,
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+        )
+        .await?;
+    Ok(Json(sealed_partition))
+}
@@ -7,7 +7,7 @@ use crate::state::command::EntryCommand;
 use crate::state::models::CreatePersonalAccessTokenWithHash;
 use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{ConnectInfo, Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post};
 use axum::{Extension, Json, Router};
@@ -17,6 +17,7 @@ use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAc
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
 use iggy::personal_access_tokens::login_with_personal_access_token::LoginWithPersonalAccessToken;
 use iggy::validatable::Validatable;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -113,6 +114,7 @@ async fn delete_personal_access_token(
 #[instrument(skip_all)]
 async fn login_with_personal_access_token(
     State(state): State<Arc<AppState>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(command): Json<LoginWithPersonalAccessToken>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
@@ -120,6 +122,7 @@ async fn login_with_personal_access_token(
     let user = system
         .login_with_personal_access_token(&command.token, None)
         .await?;
+    system.ensure_user_source_allowed(&user.username, &address.ip())?;
     let tokens = state.jwt_manager.generate(user.id)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
@@ -0,0 +1,108 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::shared::AppState;
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::schema::Schema;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route(
+            "/streams/:stream_id/topics/:topic_id/schemas",
+            get(get_schemas).post(register_schema),
+        )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/schemas/:version",
+            get(get_schema),
+        )
+        .with_state(state)
+}
+
+async fn get_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id, version)): Path<(String, String, String)>,
+) -> Result<Json<Schema>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let version = match version.as_str() {
+        "latest" => None,
+        version => Some(
+            version
+                .parse::<u32>()
+                .map_err(|_| CustomError::ResourceNotFound)?,
+        ),
+    };
+    let system = state.system.read().await;
+    let schema = system.get_schema(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &stream_id,
+        &topic_id,
+        version,
+    )?;
+    let Some(schema) = schema else {
+        return Err(CustomError::ResourceNotFound);
+    };
+
+    Ok(Json(mapper::map_schema(schema)))
+}
+
+async fn get_schemas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+) -> Result<Json<Vec<Schema>>, CustomError> {
+    let stream_id = Identifier::from_str_value(&stream_id)?;
+    let topic_id = Identifier::from_str_value(&topic_id)?;
+    let system = state.system.read().await;
+    let schemas = system.get_schemas(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &stream_id,
+        &topic_id,
+    )?;
+    let schemas = mapper::map_schemas(schemas);
+    Ok(Json(schemas))
+}
+
+#[instrument(skip_all, fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn register_schema(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<RegisterSchema>,
+) -> Result<(StatusCode, Json<Schema>), CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    let schema;
+    {
+        let mut system = state.system.write().await;
+        let registered_schema = system.register_schema(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.schema_type,
+            &command.definition,
+            command.validate,
+        )?;
+        schema = mapper::map_schema(registered_schema);
+    }
+
+    let system = state.system.read().await;
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::RegisterSchema(command))
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(schema)))
+}
@@ -3,7 +3,7 @@ use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get};
 use axum::{Extension, Json, Router};
@@ -11,6 +11,7 @@ use iggy::identifier::Identifier;
 use iggy::models::stream::{Stream, StreamDetails};
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_streams::GetStreams;
 use iggy::streams::purge_stream::PurgeStream;
 use iggy::streams::update_stream::UpdateStream;
 use iggy::validatable::Validatable;
@@ -52,10 +53,16 @@ async fn get_stream(
 async fn get_streams(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
+    Query(query): Query<GetStreams>,
 ) -> Result<Json<Vec<Stream>>, CustomError> {
+    query.validate()?;
     let system = state.system.read().await;
-    let streams =
-        system.find_streams(&Session::stateless(identity.user_id, identity.ip_address))?;
+    let streams = system.find_streams(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        query.offset,
+        query.limit,
+        query.name_prefix.as_deref(),
+    )?;
     let streams = mapper::map_streams(&streams);
     Ok(Json(streams))
 }
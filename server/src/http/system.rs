@@ -6,16 +6,22 @@ use crate::http::shared::AppState;
 use crate::streaming::session::Session;
 use axum::body::Body;
 use axum::extract::{Path, State};
+use axum::http::StatusCode;
 use axum::http::{header, HeaderMap};
 use axum::response::IntoResponse;
-use axum::routing::{get, post};
+use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router};
 use bytes::Bytes;
 use chrono::Local;
 use iggy::locking::IggySharedMutFn;
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::cluster_metadata::ClusterMetadata;
+use iggy::models::node_status::NodeStatus;
+use iggy::models::session_info::SessionInfo;
 use iggy::models::stats::Stats;
 use iggy::system::get_snapshot::GetSnapshot;
+use iggy::system::set_background_io_rate::SetBackgroundIoRate;
+use iggy::system::set_log_level::SetLogLevel;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
 
@@ -29,7 +35,15 @@ pub fn router(state: Arc<AppState>, metrics_config: &HttpMetricsConfig) -> Route
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
         .route("/clients/:client_id", get(get_client))
-        .route("/snapshot", post(get_snapshot));
+        .route("/sessions", get(get_sessions))
+        .route("/sessions/:client_id", delete(kill_session))
+        .route("/snapshot", post(get_snapshot))
+        .route("/log_level", put(set_log_level))
+        .route("/background_io_rate", put(set_background_io_rate))
+        .route("/cluster/metadata", get(get_cluster_metadata))
+        .route("/nodes/:node_id/status", get(get_node_status))
+        .route("/nodes/:node_id/drain", put(drain_node))
+        .route("/nodes/:node_id/decommission", put(decommission_node));
     if metrics_config.enabled {
         router = router.route(&metrics_config.endpoint, get(get_metrics));
     }
@@ -82,6 +96,117 @@ async fn get_clients(
     Ok(Json(clients))
 }
 
+async fn get_sessions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<SessionInfo>>, CustomError> {
+    let system = state.system.read().await;
+    let clients = system
+        .get_sessions(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    let sessions = mapper::map_sessions(&clients).await;
+    Ok(Json(sessions))
+}
+
+async fn kill_session(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(client_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .kill_session(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            client_id,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn set_log_level(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetLogLevel>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let system = state.system.read().await;
+    system.set_log_level(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        &command.filter,
+    )?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn set_background_io_rate(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<SetBackgroundIoRate>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let system = state.system.read().await;
+    system.set_background_io_rate(
+        &Session::stateless(identity.user_id, identity.ip_address),
+        command.bytes_per_second,
+    )?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn get_cluster_metadata(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<ClusterMetadata>, CustomError> {
+    let system = state.system.read().await;
+    let metadata = system
+        .get_cluster_metadata(&Session::stateless(identity.user_id, identity.ip_address))
+        .await?;
+    Ok(Json(metadata))
+}
+
+async fn get_node_status(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(node_id): Path<u32>,
+) -> Result<Json<NodeStatus>, CustomError> {
+    let system = state.system.read().await;
+    let status = system
+        .get_node_status(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            node_id,
+        )
+        .await?;
+    Ok(Json(status))
+}
+
+async fn drain_node(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(node_id): Path<u32>,
+) -> Result<Json<NodeStatus>, CustomError> {
+    let mut system = state.system.write().await;
+    let status = system
+        .drain_node(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            node_id,
+        )
+        .await?;
+    Ok(Json(status))
+}
+
+async fn decommission_node(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(node_id): Path<u32>,
+) -> Result<Json<NodeStatus>, CustomError> {
+    let mut system = state.system.write().await;
+    let status = system
+        .decommission_node(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            node_id,
+        )
+        .await?;
+    Ok(Json(status))
+}
+
 async fn get_snapshot(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
@@ -4,15 +4,17 @@ use crate::http::mapper;
 use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::{delete, get};
+use axum::routing::{delete, get, put};
 use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topics::GetTopics;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
@@ -32,6 +34,10 @@ pub fn router(state: Arc<AppState>) -> Router {
             "/streams/:stream_id/topics/:topic_id/purge",
             delete(purge_topic),
         )
+        .route(
+            "/streams/:stream_id/topics/:topic_id/read_only",
+            put(set_topic_read_only),
+        )
         .with_state(state)
 }
 
@@ -60,12 +66,17 @@ async fn get_topics(
     State(state): State<Arc<AppState>>,
     Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
+    mut query: Query<GetTopics>,
 ) -> Result<Json<Vec<Topic>>, CustomError> {
-    let stream_id = Identifier::from_str_value(&stream_id)?;
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.validate()?;
     let system = state.system.read().await;
     let topics = system.find_topics(
         &Session::stateless(identity.user_id, identity.ip_address),
-        &stream_id,
+        &query.stream_id,
+        query.offset,
+        query.limit,
+        query.name_prefix.as_deref(),
     )?;
     let topics = mapper::map_topics(&topics);
     Ok(Json(topics))
@@ -94,6 +105,8 @@ async fn create_topic(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.cleanup_policy,
+                command.overrides,
             )
             .await?;
         command.message_expiry = topic.message_expiry;
@@ -131,6 +144,8 @@ async fn update_topic(
                 command.compression_algorithm,
                 command.max_topic_size,
                 command.replication_factor,
+                command.cleanup_policy,
+                command.overrides,
             )
             .await?;
         command.message_expiry = topic.message_expiry;
@@ -206,3 +221,31 @@ async fn purge_topic(
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[instrument(skip_all, fields(iggy_user_id = identity.user_id, iggy_stream_id = stream_id, iggy_topic_id = topic_id))]
+async fn set_topic_read_only(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<SetTopicReadOnly>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+    {
+        let mut system = state.system.write().await;
+        system.set_topic_read_only(
+            &Session::stateless(identity.user_id, identity.ip_address),
+            &command.stream_id,
+            &command.topic_id,
+            command.read_only,
+        )?;
+    }
+
+    let system = state.system.read().await;
+    system
+        .state
+        .apply(identity.user_id, EntryCommand::SetTopicReadOnly(command))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -6,7 +6,7 @@ use crate::http::shared::AppState;
 use crate::state::command::EntryCommand;
 use crate::streaming::session::Session;
 use crate::streaming::utils::crypto;
-use axum::extract::{Path, State};
+use axum::extract::{ConnectInfo, Path, State};
 use axum::http::StatusCode;
 use axum::routing::{delete, get, post, put};
 use axum::{Extension, Json, Router};
@@ -21,6 +21,7 @@ use iggy::users::update_permissions::UpdatePermissions;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
 use serde::Deserialize;
+use std::net::SocketAddr;
 use std::sync::Arc;
 use tracing::instrument;
 
@@ -235,6 +236,7 @@ async fn delete_user(
 #[instrument(skip_all)]
 async fn login_user(
     State(state): State<Arc<AppState>>,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
     Json(command): Json<LoginUser>,
 ) -> Result<Json<IdentityInfo>, CustomError> {
     command.validate()?;
@@ -242,6 +244,7 @@ async fn login_user(
     let user = system
         .login_user(&command.username, &command.password, None)
         .await?;
+    system.ensure_user_source_allowed(&user.username, &address.ip())?;
     let tokens = state.jwt_manager.generate(user.id)?;
     Ok(Json(map_generated_access_token_to_identity_info(tokens)))
 }
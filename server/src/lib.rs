@@ -12,17 +12,21 @@ pub mod archiver;
 pub mod args;
 pub mod binary;
 pub mod channels;
-mod command;
+pub mod command;
 pub(crate) mod compat;
 pub mod configs;
+#[cfg(feature = "embedded")]
+pub mod embedded;
 pub mod http;
 pub mod log;
+pub mod process;
 pub mod quic;
 pub mod server_error;
 pub mod state;
 pub mod streaming;
 pub mod tcp;
 pub mod versioning;
+pub mod webhooks;
 
 const IGGY_ROOT_USERNAME_ENV: &str = "IGGY_ROOT_USERNAME";
 const IGGY_ROOT_PASSWORD_ENV: &str = "IGGY_ROOT_PASSWORD";
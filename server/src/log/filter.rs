@@ -0,0 +1,36 @@
+use iggy::error::IggyError;
+use std::sync::OnceLock;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+
+type LogFilterUpdater = Box<dyn Fn(EnvFilter) -> Result<(), String> + Send + Sync>;
+
+static LOG_FILTER_UPDATER: OnceLock<LogFilterUpdater> = OnceLock::new();
+
+/// Registers the function replacing the log filter of the installed subscriber, which can be done only once.
+pub fn register_log_filter_updater(
+    updater: impl Fn(EnvFilter) -> Result<(), String> + Send + Sync + 'static,
+) {
+    if LOG_FILTER_UPDATER.set(Box::new(updater)).is_err() {
+        info!("Log filter updater is already registered.");
+    }
+}
+
+/// Replaces the log filter at runtime, e.g. to temporarily enable the trace logs of a specific module,
+/// until the server is restarted or the filter is replaced again.
+/// The filter is either a global level (e.g. `debug`) or the levels per module (e.g. `info,server::streaming::segments=trace`).
+pub fn update_log_filter(filter: &str) -> Result<(), IggyError> {
+    let Some(updater) = LOG_FILTER_UPDATER.get() else {
+        return Err(IggyError::FeatureUnavailable);
+    };
+
+    let env_filter = EnvFilter::builder()
+        .parse(filter)
+        .map_err(|_| IggyError::InvalidLogFilter(filter.to_owned()))?;
+    updater(env_filter).map_err(|error| {
+        info!("Failed to update log filter: {filter}. Error: {error}");
+        IggyError::InvalidLogFilter(filter.to_owned())
+    })?;
+    info!("Log filter updated to: {filter}");
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use crate::configs::server::{TelemetryConfig, TelemetryTransport};
 use crate::configs::system::LoggingConfig;
+use crate::log::filter;
+use crate::log::format::{JsonFormat, LogFormat};
 use crate::server_error::ServerError;
 use opentelemetry::logs::LoggerProvider as _;
 use opentelemetry::trace::TracerProvider as _;
@@ -10,20 +12,22 @@ use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::runtime::Tokio;
 use opentelemetry_sdk::Resource;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
-use tracing::{event, info, trace, Level};
+use std::time::{Duration, SystemTime};
+use tracing::{event, info, trace, warn, Level, Subscriber};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{
-    filter::LevelFilter, fmt, fmt::format::Format, fmt::MakeWriter, reload, reload::Handle,
-    EnvFilter, Layer, Registry,
+    filter::LevelFilter, fmt, fmt::format::DefaultFields, fmt::format::Format, fmt::MakeWriter,
+    reload, reload::Handle, EnvFilter, Layer, Registry,
 };
 
 const IGGY_LOG_FILE_PREFIX: &str = "iggy-server.log";
+const LOG_ROTATION_INTERVAL: Duration = Duration::from_secs(60);
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 // Writer that does nothing
@@ -103,10 +107,11 @@ pub struct Logging {
     early_logs_buffer: Arc<Mutex<Vec<String>>>,
 
     telemetry_config: TelemetryConfig,
+    log_format: LogFormat,
 }
 
 impl Logging {
-    pub fn new(telemetry_config: TelemetryConfig) -> Self {
+    pub fn new(telemetry_config: TelemetryConfig, log_format: LogFormat) -> Self {
         Self {
             stdout_guard: None,
             stdout_reload_handle: None,
@@ -116,6 +121,7 @@ impl Logging {
             filtering_file_reload_handle: None,
             early_logs_buffer: Arc::new(Mutex::new(vec![])),
             telemetry_config,
+            log_format,
         }
     }
 
@@ -136,28 +142,26 @@ impl Logging {
             reload::Layer::new(filtering_level.boxed());
         self.filtering_file_reload_handle = Some(filtering_file_reload_handle);
 
-        let stdout_layer = fmt::Layer::default()
-            .event_format(Self::get_log_format())
-            .with_writer(|| NullWriter);
-        let (stdout_layer, stdout_layer_reload_handle) = reload::Layer::new(stdout_layer.boxed());
+        let stdout_layer = self.with_log_format(fmt::Layer::default().with_writer(|| NullWriter));
+        let (stdout_layer, stdout_layer_reload_handle) = reload::Layer::new(stdout_layer);
         self.stdout_reload_handle = Some(stdout_layer_reload_handle);
         layers.push(stdout_layer.and_then(filtering_stdout_layer));
 
-        let file_layer = fmt::Layer::default()
-            .event_format(Self::get_log_format())
-            .with_target(true)
-            .with_writer(VecStringMakeWriter(self.early_logs_buffer.clone()))
-            .with_ansi(true);
-        let (file_layer, file_layer_reload_handle) = reload::Layer::new(file_layer.boxed());
+        let file_layer = self.with_log_format(
+            fmt::Layer::default()
+                .with_target(true)
+                .with_writer(VecStringMakeWriter(self.early_logs_buffer.clone()))
+                .with_ansi(true),
+        );
+        let (file_layer, file_layer_reload_handle) = reload::Layer::new(file_layer);
         self.file_reload_handle = Some(file_layer_reload_handle);
         layers.push(file_layer.and_then(filtering_file_layer));
 
         if !self.telemetry_config.enabled {
+            let (env_filter, env_filter_reload_handle) = Self::get_env_filter();
             // This is moment when we can start logging something and not worry about losing it.
-            Registry::default()
-                .with(layers)
-                .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
-                .init();
+            Registry::default().with(layers).with(env_filter).init();
+            self.register_log_filter_updater(env_filter_reload_handle);
             Self::print_build_info();
             return;
         }
@@ -241,12 +245,14 @@ impl Logging {
         global::set_text_map_propagator(TraceContextPropagator::new());
         global::shutdown_tracer_provider();
 
+        let (env_filter, env_filter_reload_handle) = Self::get_env_filter();
         Registry::default()
             .with(layers)
             .with(OpenTelemetryTracingBridge::new(logger.provider()))
             .with(OpenTelemetryLayer::new(tracer))
-            .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+            .with(env_filter)
             .init();
+        self.register_log_filter_updater(env_filter_reload_handle);
         Self::print_build_info();
     }
 
@@ -278,10 +284,7 @@ impl Logging {
 
         // Initialize non-blocking stdout layer
         let (_, stdout_guard) = tracing_appender::non_blocking(std::io::stdout());
-        let stdout_layer = fmt::Layer::default()
-            .with_ansi(true)
-            .event_format(Self::get_log_format())
-            .boxed();
+        let stdout_layer = self.with_log_format(fmt::Layer::default().with_ansi(true));
         self.stdout_guard = Some(stdout_guard);
 
         self.stdout_reload_handle
@@ -298,16 +301,17 @@ impl Logging {
         let logs_path = base_directory.join(logs_subdirectory.clone());
         let file_appender =
             tracing_appender::rolling::hourly(logs_path.clone(), IGGY_LOG_FILE_PREFIX);
+        Self::install_log_rotation_handler(logs_path.clone(), config);
         let (mut non_blocking_file, file_guard) = tracing_appender::non_blocking(file_appender);
 
         self.dump_to_file(&mut non_blocking_file);
 
-        let file_layer = fmt::layer()
-            .event_format(Self::get_log_format())
-            .with_target(true)
-            .with_writer(non_blocking_file)
-            .with_ansi(false)
-            .boxed();
+        let file_layer = self.with_log_format(
+            fmt::layer()
+                .with_target(true)
+                .with_writer(non_blocking_file)
+                .with_ansi(false),
+        );
 
         self.file_guard = Some(file_guard);
         self.file_reload_handle
@@ -318,8 +322,8 @@ impl Logging {
         let level = filtering_level.to_string();
 
         let print = format!(
-            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated hourly. Log level is: {}.",
-            logs_path, level
+            "Logging initialized, logs will be stored at: {:?}. Logs will be rotated hourly and retained for: {} up to the total size of: {}. Log level is: {}, format is: {}.",
+            logs_path, config.retention, config.max_size, level, self.log_format
         );
 
         match filtering_level {
@@ -363,12 +367,68 @@ impl Logging {
         }
     }
 
+    // The filter can be replaced at runtime, e.g. to temporarily enable the trace logs of a specific module.
+    fn get_env_filter<S: Subscriber>() -> (reload::Layer<EnvFilter, S>, Handle<EnvFilter, S>) {
+        reload::Layer::new(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+    }
+
+    // The severity filtering layers are opened up to the most verbose level of the new filter, which then decides on its own.
+    fn register_log_filter_updater<S: Subscriber + 'static>(
+        &self,
+        env_filter_reload_handle: Handle<EnvFilter, S>,
+    ) {
+        let filtering_reload_handles = [
+            self.filtering_stdout_reload_handle.clone(),
+            self.filtering_file_reload_handle.clone(),
+        ];
+        filter::register_log_filter_updater(move |env_filter: EnvFilter| {
+            let level = env_filter.max_level_hint().unwrap_or(LevelFilter::TRACE);
+            for handle in filtering_reload_handles.iter().flatten() {
+                handle
+                    .modify(|layer| *layer = level.boxed())
+                    .map_err(|error| error.to_string())?;
+            }
+            env_filter_reload_handle
+                .reload(env_filter)
+                .map_err(|error| error.to_string())
+        });
+    }
+
     fn get_log_format() -> Format {
         Format::default().with_thread_ids(true)
     }
 
-    fn _install_log_rotation_handler(&self) {
-        todo!("Implement log rotation handler based on size and retention time");
+    fn with_log_format<W>(
+        &self,
+        layer: fmt::Layer<Registry, DefaultFields, Format, W>,
+    ) -> Box<dyn Layer<Registry> + Send + Sync>
+    where
+        W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+    {
+        match self.log_format {
+            LogFormat::Text => layer.event_format(Self::get_log_format()).boxed(),
+            LogFormat::Json => layer.event_format(JsonFormat).boxed(),
+        }
+    }
+
+    // The rotated log files are removed once they are older than the retention or exceed the max size in total.
+    fn install_log_rotation_handler(logs_path: PathBuf, config: &LoggingConfig) {
+        let retention = config.retention.get_duration();
+        let max_size = config.max_size.as_bytes_u64();
+        if retention.is_zero() && max_size == 0 {
+            return;
+        }
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(LOG_ROTATION_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(error) = remove_rotated_log_files(&logs_path, retention, max_size).await
+                {
+                    warn!("Failed to remove rotated log files from: {logs_path:?}. Error: {error}");
+                }
+            }
+        });
     }
 
     fn print_build_info() {
@@ -389,6 +449,96 @@ impl Logging {
 
 impl Default for Logging {
     fn default() -> Self {
-        Self::new(TelemetryConfig::default())
+        Self::new(TelemetryConfig::default(), LogFormat::default())
+    }
+}
+
+/// Removes the log files (except the current one) older than the retention (if set),
+/// and then the oldest ones as long as the total size of the log files exceeds the max size (if set).
+async fn remove_rotated_log_files(
+    logs_path: &Path,
+    retention: Duration,
+    max_size: u64,
+) -> Result<(), io::Error> {
+    let mut log_files = vec![];
+    let mut dir = tokio::fs::read_dir(logs_path).await?;
+    while let Some(entry) = dir.next_entry().await? {
+        if !entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(IGGY_LOG_FILE_PREFIX)
+        {
+            continue;
+        }
+        let metadata = entry.metadata().await?;
+        if metadata.is_file() {
+            log_files.push((entry.path(), metadata.modified()?, metadata.len()));
+        }
+    }
+
+    // The most recently modified file is the one being written to.
+    log_files.sort_by_key(|(_, modified, _)| *modified);
+    log_files.pop();
+    let mut total_size = log_files.iter().map(|(_, _, size)| size).sum::<u64>();
+    let now = SystemTime::now();
+    for (path, modified, size) in log_files {
+        let expired =
+            !retention.is_zero() && now.duration_since(modified).unwrap_or_default() > retention;
+        let over_size = max_size > 0 && total_size > max_size;
+        if !expired && !over_size {
+            continue;
+        }
+
+        tokio::fs::remove_file(&path).await?;
+        total_size -= size;
+        trace!("Removed rotated log file: {path:?}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn should_remove_expired_and_oldest_log_files_over_the_max_size() {
+        let dir = temp_dir().join(format!("iggy-logs-{}", Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let now = SystemTime::now();
+        let hour = Duration::from_secs(3600);
+        let files = [
+            ("iggy-server.log.2024-01-01-00", 4 * hour),
+            ("iggy-server.log.2024-01-01-01", 3 * hour),
+            ("iggy-server.log.2024-01-01-02", 2 * hour),
+            ("iggy-server.log.2024-01-01-03", hour),
+            ("iggy-server.log.2024-01-01-04", Duration::ZERO),
+            ("other.log", 10 * hour),
+        ];
+        for (name, age) in files {
+            let file = std::fs::File::create(dir.join(name)).unwrap();
+            file.set_len(100).unwrap();
+            file.set_modified(now - age).unwrap();
+        }
+
+        remove_rotated_log_files(&dir, Duration::from_secs(3 * 3600 + 60), 150)
+            .await
+            .unwrap();
+
+        let mut remaining = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        remaining.sort();
+        assert_eq!(
+            remaining,
+            vec![
+                "iggy-server.log.2024-01-01-03",
+                "iggy-server.log.2024-01-01-04",
+                "other.log"
+            ]
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
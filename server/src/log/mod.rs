@@ -1,3 +1,6 @@
+pub mod filter;
+pub mod format;
+
 #[cfg(not(feature = "tokio-console"))]
 pub mod logger;
 
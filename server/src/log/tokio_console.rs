@@ -1,12 +1,13 @@
 use crate::configs::server::TelemetryConfig;
 use crate::configs::system::LoggingConfig;
+use crate::log::format::LogFormat;
 use crate::server_error::ServerError;
 use tracing_subscriber::prelude::*;
 
 pub struct Logging {}
 
 impl Logging {
-    pub fn new(_: TelemetryConfig) -> Self {
+    pub fn new(_: TelemetryConfig, _: LogFormat) -> Self {
         Self {}
     }
 
@@ -30,6 +31,6 @@ impl Logging {
 
 impl Default for Logging {
     fn default() -> Self {
-        Self::new(TelemetryConfig::default())
+        Self::new(TelemetryConfig::default(), LogFormat::default())
     }
 }
@@ -4,9 +4,14 @@ use dotenvy::dotenv;
 use figlet_rs::FIGfont;
 use server::args::Args;
 use server::channels::commands::archive_state::ArchiveStateExecutor;
+use server::channels::commands::check_disk_space::CheckDiskSpaceExecutor;
 use server::channels::commands::clean_personal_access_tokens::CleanPersonalAccessTokensExecutor;
+use server::channels::commands::compact_consumer_offsets::CompactConsumerOffsetsExecutor;
+use server::channels::commands::deliver_webhooks::DeliverWebhooksExecutor;
+use server::channels::commands::expire_consumer_group_members::ExpireConsumerGroupMembersExecutor;
 use server::channels::commands::maintain_messages::MaintainMessagesExecutor;
 use server::channels::commands::print_sysinfo::SysInfoPrintExecutor;
+use server::channels::commands::publish_events::PublishEventsExecutor;
 use server::channels::commands::save_messages::SaveMessagesExecutor;
 use server::channels::commands::verify_heartbeats::VerifyHeartbeatsExecutor;
 use server::channels::handler::ServerCommandHandler;
@@ -17,6 +22,7 @@ use server::http::http_server;
 use server::log::logger::Logging;
 #[cfg(feature = "tokio-console")]
 use server::log::tokio_console::Logging;
+use server::process::{notify_service_manager, PidFile};
 use server::quic::quic_server;
 use server::server_error::ServerError;
 use server::streaming::systems::system::{SharedSystem, System};
@@ -47,19 +53,29 @@ async fn main() -> Result<(), ServerError> {
     let config_provider = config_provider::resolve(&args.config_provider)?;
     let config = ServerConfig::load(config_provider.as_ref()).await?;
 
-    let mut logging = Logging::new(config.telemetry.clone());
+    let mut logging = Logging::new(config.telemetry.clone(), args.log_format);
     logging.early_init();
 
     // From this point on, we can use tracing macros to log messages.
 
     logging.late_init(config.system.get_system_path(), &config.system.logging)?;
 
+    let _pid_file = match &args.pid_file {
+        Some(path) => Some(PidFile::create(path)?),
+        None => None,
+    };
+
     let system = SharedSystem::new(System::new(
         config.system.clone(),
         config.data_maintenance.clone(),
         config.personal_access_token.clone(),
     ));
 
+    if args.dry_run {
+        system.read().await.dry_run().await?;
+        return Ok(());
+    }
+
     // Workaround to ensure that the statistics are initialized before the server
     // loads streams and starts accepting connections. This is necessary to
     // have the correct statistics when the server starts.
@@ -69,10 +85,15 @@ async fn main() -> Result<(), ServerError> {
     let _command_handler = ServerCommandHandler::new(system.clone(), &config)
         .install_handler(SaveMessagesExecutor)
         .install_handler(MaintainMessagesExecutor)
+        .install_handler(CompactConsumerOffsetsExecutor)
         .install_handler(ArchiveStateExecutor)
         .install_handler(CleanPersonalAccessTokensExecutor)
+        .install_handler(ExpireConsumerGroupMembersExecutor)
         .install_handler(SysInfoPrintExecutor)
-        .install_handler(VerifyHeartbeatsExecutor);
+        .install_handler(VerifyHeartbeatsExecutor)
+        .install_handler(CheckDiskSpaceExecutor)
+        .install_handler(PublishEventsExecutor)
+        .install_handler(DeliverWebhooksExecutor::default());
 
     #[cfg(unix)]
     let (mut ctrl_c, mut sigterm) = {
@@ -111,6 +132,7 @@ async fn main() -> Result<(), ServerError> {
         "Iggy server has started - overall startup took {} ms.",
         elapsed_time.as_millis()
     );
+    notify_service_manager("READY=1");
 
     #[cfg(unix)]
     tokio::select! {
@@ -132,6 +154,7 @@ async fn main() -> Result<(), ServerError> {
         }
     }
 
+    notify_service_manager("STOPPING=1");
     let shutdown_timestamp = Instant::now();
     let mut system = system.write().await;
     system.shutdown().await?;
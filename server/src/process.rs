@@ -0,0 +1,76 @@
+use crate::server_error::ServerError;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+#[cfg(unix)]
+const NOTIFY_SOCKET_ENV: &str = "NOTIFY_SOCKET";
+
+/// The file storing the ID of the server process, so that it can be managed e.g. by init scripts.
+/// It's created on startup and removed once dropped on shutdown.
+#[derive(Debug)]
+pub struct PidFile {
+    path: PathBuf,
+}
+
+impl PidFile {
+    pub fn create(path: &str) -> Result<Self, ServerError> {
+        let path = PathBuf::from(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, format!("{}\n", std::process::id()))?;
+        info!(
+            "Process ID: {} written to file: {path:?}",
+            std::process::id()
+        );
+        Ok(Self { path })
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            warn!("Failed to remove PID file: {:?}. Error: {error}", self.path);
+        }
+    }
+}
+
+/// Notifies the service manager about the server state (e.g. `READY=1` or `STOPPING=1`) using the sd_notify protocol,
+/// when the server is started by systemd with `Type=notify`, otherwise does nothing.
+pub fn notify_service_manager(state: &str) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::net::UnixDatagram;
+
+        let Some(socket_path) = std::env::var_os(NOTIFY_SOCKET_ENV) else {
+            return;
+        };
+
+        let result = UnixDatagram::unbound().and_then(|socket| {
+            let socket_path = socket_path.to_string_lossy();
+            if let Some(abstract_name) = socket_path.strip_prefix('@') {
+                #[cfg(target_os = "linux")]
+                {
+                    use std::os::linux::net::SocketAddrExt;
+                    use std::os::unix::net::SocketAddr;
+
+                    let address = SocketAddr::from_abstract_name(abstract_name.as_bytes())?;
+                    return socket.send_to_addr(state.as_bytes(), &address);
+                }
+                #[cfg(not(target_os = "linux"))]
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    format!("abstract socket: {abstract_name} is not supported"),
+                ));
+            }
+            socket.send_to(state.as_bytes(), socket_path.as_ref())
+        });
+
+        if let Err(error) = result {
+            warn!("Failed to notify service manager about state: {state}. Error: {error}");
+        }
+    }
+
+    #[cfg(not(unix))]
+    let _ = state;
+}
@@ -1,5 +1,8 @@
+use crate::binary::checksum_sender::{self, ChecksumSender};
 use crate::binary::command;
+use crate::binary::sender::Sender;
 use crate::command::ServerCommand;
+use crate::configs::ip_filter::IpFilterConfig;
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
@@ -7,20 +10,31 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
 use anyhow::{anyhow, Context};
 use bytes::Bytes;
+use iggy::error::IggyError;
 use iggy::validatable::Validatable;
 use iggy::{bytes_serializable::BytesSerializable, messages::MAX_PAYLOAD_SIZE};
 use quinn::{Connection, Endpoint, RecvStream, SendStream};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 const LISTENERS_COUNT: u32 = 10;
 const INITIAL_BYTES_LENGTH: usize = 4;
 
-pub fn start(endpoint: Endpoint, system: SharedSystem) {
+pub fn start(endpoint: Endpoint, ip_filter: IpFilterConfig, system: SharedSystem) {
     for _ in 0..LISTENERS_COUNT {
         let endpoint = endpoint.clone();
+        let ip_filter = ip_filter.clone();
         let system = system.clone();
         tokio::spawn(async move {
             while let Some(incoming_connection) = endpoint.accept().await {
+                let remote_address = incoming_connection.remote_address();
+                if !ip_filter.is_allowed(&remote_address.ip()) {
+                    warn!(
+                        "Rejected QUIC connection from not allowed IP address: {remote_address}."
+                    );
+                    incoming_connection.refuse();
+                    continue;
+                }
+
                 info!(
                     "Incoming connection from client: {}",
                     incoming_connection.remote_address()
@@ -59,7 +73,19 @@ async fn handle_connection(
         .await;
 
     let client_id = session.client_id;
-    while let Some(stream) = accept_stream(&connection, &system, client_id).await? {
+    loop {
+        let stream = tokio::select! {
+            stream = accept_stream(&connection, &system, client_id) => stream?,
+            _ = session.terminated() => {
+                info!("Session: {session} has been terminated, closing the connection.");
+                connection.close(0u32.into(), b"session terminated");
+                system.read().await.delete_client(client_id).await;
+                return Ok(());
+            }
+        };
+        let Some(stream) = stream else {
+            break;
+        };
         let system = system.clone();
         let session = session.clone();
 
@@ -119,20 +145,66 @@ async fn handle_stream(
         .try_into()
         .map(u32::from_le_bytes)
         .unwrap_or_default();
-    let command =
-        ServerCommand::from_bytes(Bytes::copy_from_slice(&request[INITIAL_BYTES_LENGTH..]))
-            .with_context(|| "Error when reading the QUIC request command.")?;
-    command
-        .validate()
-        .with_context(|| "Error when validating the QUIC command.")?;
-
-    debug!("Received a QUIC command: {command}, payload size: {length}");
-
     let mut sender = QuicSender {
         send: send_stream,
         recv: recv_stream,
     };
-    command::handle(command, &mut sender, session.as_ref(), system.clone())
+    let command_bytes = &request[INITIAL_BYTES_LENGTH..];
+    if checksum_sender::has_checksum(command_bytes) {
+        let mut sender = ChecksumSender::new(&mut sender);
+        let command_bytes =
+            match checksum_sender::verify_checksum(&request[..INITIAL_BYTES_LENGTH], command_bytes)
+            {
+                Ok(command_bytes) => command_bytes,
+                Err(error) => {
+                    error!("Received a QUIC command with invalid checksum: {error}");
+                    sender
+                        .send_error_response(error)
+                        .await
+                        .with_context(|| "Error when sending the QUIC checksum error response.")?;
+                    return Ok(());
+                }
+            };
+        return handle_command(command_bytes, length, &mut sender, session.as_ref(), system).await;
+    }
+
+    handle_command(
+        Bytes::copy_from_slice(command_bytes),
+        length,
+        &mut sender,
+        session.as_ref(),
+        system,
+    )
+    .await
+}
+
+async fn handle_command(
+    command_bytes: Bytes,
+    length: u32,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: SharedSystem,
+) -> anyhow::Result<()> {
+    let Ok(command) = ServerCommand::from_bytes(command_bytes) else {
+        error!("Received an invalid QUIC command.");
+        sender
+            .send_error_response(IggyError::InvalidCommand)
+            .await
+            .with_context(|| "Error when sending the QUIC invalid command error response.")?;
+        return Ok(());
+    };
+    if let Err(error) = command.validate() {
+        error!("QUIC command validation failed: {error}");
+        sender
+            .send_error_response(error)
+            .await
+            .with_context(|| "Error when sending the QUIC validation error response.")?;
+        return Ok(());
+    }
+
+    debug!("Received a QUIC command: {command}, payload size: {length}");
+
+    command::handle(command, sender, session, system)
         .await
         .with_context(|| "Error when handling the QUIC request.")
 }
@@ -1,6 +1,7 @@
 use crate::binary::sender::Sender;
 use async_trait::async_trait;
 use bytes::{BufMut, BytesMut};
+use iggy::binary::CHUNKED_RESPONSE_LENGTH;
 use iggy::error::IggyError;
 use quinn::{RecvStream, SendStream};
 use std::mem::size_of;
@@ -28,6 +29,11 @@ impl Sender for QuicSender {
         Ok(read_bytes.unwrap().unwrap())
     }
 
+    async fn write(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.send.write_all(payload).await?;
+        Ok(())
+    }
+
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
         self.send_ok_response(&[]).await
     }
@@ -36,6 +42,24 @@ impl Sender for QuicSender {
         self.send_response(STATUS_OK, payload).await
     }
 
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError> {
+        debug!("Sending chunked response with status: {:?}...", STATUS_OK);
+        self.send
+            .write_all(&[STATUS_OK, &CHUNKED_RESPONSE_LENGTH.to_le_bytes()].concat())
+            .await?;
+        Ok(())
+    }
+
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError> {
+        let length = (chunk.len() as u32).to_le_bytes();
+        self.send.write_all(&[&length, chunk].concat()).await?;
+        if chunk.is_empty() {
+            self.send.finish()?;
+            debug!("Sent chunked response with status: {:?}", STATUS_OK);
+        }
+        Ok(())
+    }
+
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
         let error_message = error.to_string();
         let length = error_message.len() as u32;
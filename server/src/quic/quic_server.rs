@@ -18,6 +18,7 @@ use crate::streaming::systems::system::SharedSystem;
 pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing Iggy QUIC server...");
     let address = config.address.parse().unwrap();
+    let ip_filter = config.ip_filter.clone();
     let quic_config = configure_quic(config);
     if let Err(error) = quic_config {
         panic!("Error when configuring QUIC: {:?}", error);
@@ -25,7 +26,7 @@ pub fn start(config: QuicConfig, system: SharedSystem) -> SocketAddr {
 
     let endpoint = Endpoint::server(quic_config.unwrap(), address).unwrap();
     let addr = endpoint.local_addr().unwrap();
-    listener::start(endpoint, system);
+    listener::start(endpoint, ip_filter, system);
     info!("Iggy QUIC server has started on: {:?}", addr);
     addr
 }
@@ -43,4 +43,10 @@ pub enum ServerError {
     FileToArchiveNotFound(String),
     #[error("Index migration error: {0}")]
     IndexMigrationError(String),
+    #[error("Batch migration error: {0}")]
+    BatchMigrationError(String),
+    #[error("Cannot initialize webhooks: {0}")]
+    CannotInitializeWebhooks(String),
+    #[error("Cannot deliver webhook to: {0}, {1}")]
+    CannotDeliverWebhook(String, String),
 }
@@ -5,15 +5,18 @@ use iggy::command::{
     Command, CHANGE_PASSWORD_CODE, CREATE_CONSUMER_GROUP_CODE, CREATE_PARTITIONS_CODE,
     CREATE_PERSONAL_ACCESS_TOKEN_CODE, CREATE_STREAM_CODE, CREATE_TOPIC_CODE, CREATE_USER_CODE,
     DELETE_CONSUMER_GROUP_CODE, DELETE_PARTITIONS_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
-    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, PURGE_STREAM_CODE, PURGE_TOPIC_CODE,
+    DELETE_STREAM_CODE, DELETE_TOPIC_CODE, DELETE_USER_CODE, INIT_PRODUCER_EPOCH_CODE,
+    PURGE_STREAM_CODE, PURGE_TOPIC_CODE, REGISTER_SCHEMA_CODE, SET_TOPIC_READ_ONLY_CODE,
     UPDATE_PERMISSIONS_CODE, UPDATE_STREAM_CODE, UPDATE_TOPIC_CODE, UPDATE_USER_CODE,
 };
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::error::IggyError;
+use iggy::messages::init_producer_epoch::InitProducerEpoch;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
 use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::schemas::register_schema::RegisterSchema;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::streams::purge_stream::PurgeStream;
@@ -21,6 +24,7 @@ use iggy::streams::update_stream::UpdateStream;
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::delete_topic::DeleteTopic;
 use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
@@ -39,10 +43,13 @@ pub enum EntryCommand {
     UpdateTopic(UpdateTopic),
     DeleteTopic(DeleteTopic),
     PurgeTopic(PurgeTopic),
+    SetTopicReadOnly(SetTopicReadOnly),
     CreatePartitions(CreatePartitions),
     DeletePartitions(DeletePartitions),
     CreateConsumerGroup(CreateConsumerGroup),
     DeleteConsumerGroup(DeleteConsumerGroup),
+    RegisterSchema(RegisterSchema),
+    InitProducerEpoch(InitProducerEpoch),
     CreateUser(CreateUser),
     UpdateUser(UpdateUser),
     DeleteUser(DeleteUser),
@@ -63,10 +70,13 @@ impl BytesSerializable for EntryCommand {
             EntryCommand::UpdateTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteTopic(command) => (command.code(), command.to_bytes()),
             EntryCommand::PurgeTopic(command) => (command.code(), command.to_bytes()),
+            EntryCommand::SetTopicReadOnly(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreatePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeletePartitions(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateConsumerGroup(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteConsumerGroup(command) => (command.code(), command.to_bytes()),
+            EntryCommand::RegisterSchema(command) => (command.code(), command.to_bytes()),
+            EntryCommand::InitProducerEpoch(command) => (command.code(), command.to_bytes()),
             EntryCommand::CreateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::UpdateUser(command) => (command.code(), command.to_bytes()),
             EntryCommand::DeleteUser(command) => (command.code(), command.to_bytes()),
@@ -109,6 +119,9 @@ impl BytesSerializable for EntryCommand {
             UPDATE_TOPIC_CODE => Ok(EntryCommand::UpdateTopic(UpdateTopic::from_bytes(payload)?)),
             DELETE_TOPIC_CODE => Ok(EntryCommand::DeleteTopic(DeleteTopic::from_bytes(payload)?)),
             PURGE_TOPIC_CODE => Ok(EntryCommand::PurgeTopic(PurgeTopic::from_bytes(payload)?)),
+            SET_TOPIC_READ_ONLY_CODE => Ok(EntryCommand::SetTopicReadOnly(
+                SetTopicReadOnly::from_bytes(payload)?,
+            )),
             CREATE_PARTITIONS_CODE => Ok(EntryCommand::CreatePartitions(
                 CreatePartitions::from_bytes(payload)?,
             )),
@@ -121,6 +134,12 @@ impl BytesSerializable for EntryCommand {
             DELETE_CONSUMER_GROUP_CODE => Ok(EntryCommand::DeleteConsumerGroup(
                 DeleteConsumerGroup::from_bytes(payload)?,
             )),
+            REGISTER_SCHEMA_CODE => Ok(EntryCommand::RegisterSchema(RegisterSchema::from_bytes(
+                payload,
+            )?)),
+            INIT_PRODUCER_EPOCH_CODE => Ok(EntryCommand::InitProducerEpoch(
+                InitProducerEpoch::from_bytes(payload)?,
+            )),
             CREATE_USER_CODE => Ok(EntryCommand::CreateUser(CreateUser::from_bytes(payload)?)),
             UPDATE_USER_CODE => Ok(EntryCommand::UpdateUser(UpdateUser::from_bytes(payload)?)),
             DELETE_USER_CODE => Ok(EntryCommand::DeleteUser(DeleteUser::from_bytes(payload)?)),
@@ -152,6 +171,7 @@ impl Display for EntryCommand {
             EntryCommand::UpdateTopic(command) => write!(f, "UpdateTopic({})", command),
             EntryCommand::DeleteTopic(command) => write!(f, "DeleteTopic({})", command),
             EntryCommand::PurgeTopic(command) => write!(f, "PurgeTopic({})", command),
+            EntryCommand::SetTopicReadOnly(command) => write!(f, "SetTopicReadOnly({})", command),
             EntryCommand::CreatePartitions(command) => write!(f, "CreatePartitions({})", command),
             EntryCommand::DeletePartitions(command) => write!(f, "DeletePartitions({})", command),
             EntryCommand::CreateConsumerGroup(command) => {
@@ -160,6 +180,10 @@ impl Display for EntryCommand {
             EntryCommand::DeleteConsumerGroup(command) => {
                 write!(f, "DeleteConsumerGroup({})", command)
             }
+            EntryCommand::RegisterSchema(command) => write!(f, "RegisterSchema({})", command),
+            EntryCommand::InitProducerEpoch(command) => {
+                write!(f, "InitProducerEpoch({})", command)
+            }
             EntryCommand::CreateUser(command) => write!(f, "CreateUser({})", command),
             EntryCommand::UpdateUser(command) => write!(f, "UpdateUser({})", command),
             EntryCommand::DeleteUser(command) => write!(f, "DeleteUser({})", command),
@@ -5,8 +5,12 @@ use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
 use iggy::models::user_status::UserStatus;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::schema_type::SchemaType;
 use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::collections::HashMap;
 use std::fmt::Display;
@@ -33,18 +37,24 @@ pub struct TopicState {
     pub name: String,
     pub partitions: HashMap<u32, PartitionState>,
     pub consumer_groups: HashMap<u32, ConsumerGroupState>,
+    pub schemas: Vec<SchemaState>,
     pub compression_algorithm: CompressionAlgorithm,
     pub message_expiry: IggyExpiry,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: Option<u8>,
+    pub cleanup_policy: CleanupPolicy,
+    pub overrides: TopicOverrides,
     pub created_at: IggyTimestamp,
     pub current_consumer_group_id: u32,
+    pub producer_epoch: u32,
+    pub read_only: bool,
 }
 
 #[derive(Debug)]
 pub struct PartitionState {
     pub id: u32,
     pub created_at: IggyTimestamp,
+    pub epoch: u32,
 }
 
 #[derive(Debug)]
@@ -68,6 +78,16 @@ pub struct UserState {
 pub struct ConsumerGroupState {
     pub id: u32,
     pub name: String,
+    pub rebalance_strategy: RebalanceStrategy,
+}
+
+#[derive(Debug)]
+pub struct SchemaState {
+    pub version: u32,
+    pub schema_type: SchemaType,
+    pub validate: bool,
+    pub definition: String,
+    pub created_at: IggyTimestamp,
 }
 
 impl SystemState {
@@ -106,10 +126,17 @@ impl SystemState {
                 }
                 EntryCommand::PurgeStream(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
-                    streams
-                        .get(&stream_id)
+                    let stream = streams
+                        .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
-                    // It only affects the segments which are not part of the state
+                    // The purged segments are not part of the state, only the epochs of the partitions are.
+                    for partition in stream
+                        .topics
+                        .values_mut()
+                        .flat_map(|topic| topic.partitions.values_mut())
+                    {
+                        partition.epoch += 1;
+                    }
                 }
                 EntryCommand::CreateTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -125,10 +152,15 @@ impl SystemState {
                         name: command.name,
                         consumer_groups: HashMap::new(),
                         current_consumer_group_id: 0,
+                        producer_epoch: 0,
+                        read_only: false,
+                        schemas: Vec::new(),
                         compression_algorithm: command.compression_algorithm,
                         message_expiry: command.message_expiry,
                         max_topic_size: command.max_topic_size,
                         replication_factor: command.replication_factor,
+                        cleanup_policy: command.cleanup_policy,
+                        overrides: command.overrides,
                         created_at: entry.timestamp,
                         partitions: if command.partitions_count > 0 {
                             let mut partitions = HashMap::new();
@@ -138,6 +170,7 @@ impl SystemState {
                                     PartitionState {
                                         id: i,
                                         created_at: entry.timestamp,
+                                        epoch: 0,
                                     },
                                 );
                             }
@@ -163,6 +196,8 @@ impl SystemState {
                     topic.message_expiry = command.message_expiry;
                     topic.max_topic_size = command.max_topic_size;
                     topic.replication_factor = command.replication_factor;
+                    topic.cleanup_policy = command.cleanup_policy;
+                    topic.overrides = command.overrides;
                 }
                 EntryCommand::DeleteTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -175,14 +210,17 @@ impl SystemState {
                 EntryCommand::PurgeTopic(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
                     let stream = streams
-                        .get(&stream_id)
+                        .get_mut(&stream_id)
                         .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
                     let topic_id = find_topic_id(&stream.topics, &command.topic_id);
-                    stream
+                    let topic = stream
                         .topics
-                        .get(&topic_id)
+                        .get_mut(&topic_id)
                         .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
-                    // It only affects the segments which are not part of the state
+                    // The purged segments are not part of the state, only the epochs of the partitions are.
+                    for partition in topic.partitions.values_mut() {
+                        partition.epoch += 1;
+                    }
                 }
                 EntryCommand::CreatePartitions(command) => {
                     let stream_id = find_stream_id(&streams, &command.stream_id);
@@ -210,6 +248,7 @@ impl SystemState {
                             PartitionState {
                                 id: last_partition_id + i,
                                 created_at: entry.timestamp,
+                                epoch: 0,
                             },
                         );
                     }
@@ -255,6 +294,7 @@ impl SystemState {
                     let consumer_group = ConsumerGroupState {
                         id: consumer_group_id,
                         name: command.name,
+                        rebalance_strategy: command.rebalance_strategy,
                     };
                     topic
                         .consumer_groups
@@ -274,6 +314,49 @@ impl SystemState {
                         find_consumer_group_id(&topic.consumer_groups, &command.group_id);
                     topic.consumer_groups.remove(&consumer_group_id);
                 }
+                EntryCommand::RegisterSchema(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    let version = topic.schemas.last().map_or(1, |schema| schema.version + 1);
+                    topic.schemas.push(SchemaState {
+                        version,
+                        schema_type: command.schema_type,
+                        validate: command.validate,
+                        definition: command.definition,
+                        created_at: entry.timestamp,
+                    });
+                }
+                EntryCommand::InitProducerEpoch(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.producer_epoch += 1;
+                }
+                EntryCommand::SetTopicReadOnly(command) => {
+                    let stream_id = find_stream_id(&streams, &command.stream_id);
+                    let stream = streams
+                        .get_mut(&stream_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Stream: {stream_id} not found")));
+                    let topic_id = find_topic_id(&stream.topics, &command.topic_id);
+                    let topic = stream
+                        .topics
+                        .get_mut(&topic_id)
+                        .unwrap_or_else(|| panic!("{}", format!("Topic: {topic_id} not found")));
+                    topic.read_only = command.read_only;
+                }
                 EntryCommand::CreateUser(command) => {
                     current_user_id += 1;
                     let user = UserState {
@@ -450,7 +533,11 @@ impl Display for SystemState {
 
 impl Display for ConsumerGroupState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ConsumerGroup -> ID: {}, Name: {}", self.id, self.name)
+        write!(
+            f,
+            "ConsumerGroup -> ID: {}, Name: {}, Rebalance strategy: {}",
+            self.id, self.name, self.rebalance_strategy
+        )
     }
 }
 
@@ -482,6 +569,9 @@ impl Display for StreamState {
 impl Display for TopicState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Topic -> ID: {}, Name: {}", self.id, self.name,)?;
+        if self.read_only {
+            write!(f, ", Read-only")?;
+        }
         for partition in self.partitions.iter() {
             write!(f, "\n  {}", partition.1)?;
         }
@@ -497,8 +587,8 @@ impl Display for PartitionState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Partition -> ID: {}, Created At: {}",
-            self.id, self.created_at
+            "Partition -> ID: {}, Created At: {}, Epoch: {}",
+            self.id, self.created_at, self.epoch
         )
     }
 }
@@ -0,0 +1,179 @@
+use crate::streaming::models::messages::RetainedMessage;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::warn;
+
+/// The number of the appended batches buffered for the slowest subscriber, before it starts lagging behind.
+const APPENDS_CHANNEL_CAPACITY: usize = 1024;
+
+/// The batch of the messages appended to the partition, sharing the references with the cache,
+/// so that the subscribers don't need to copy the messages or re-read the segments.
+/// The payloads are the ones stored in the partition, i.e. encrypted, if the server-side encryption is enabled.
+#[derive(Debug, Clone)]
+pub struct AppendedMessages {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub messages: Arc<[Arc<RetainedMessage>]>,
+}
+
+/// Received by the subscriber of the appended messages.
+#[derive(Debug, Clone)]
+pub enum AppendNotification {
+    /// The batch of the appended messages.
+    Appended(AppendedMessages),
+    /// The subscriber couldn't keep up, and the given number of the batches was skipped for it (and only for it).
+    /// The skipped messages can still be polled from the partition, if needed.
+    Lagged(u64),
+}
+
+/// Broadcasts the messages appended to the partitions of the system to its in-process subscribers, e.g. the server extensions such as the webhooks,
+/// WebSocket push or replication. Appending never waits for the subscribers: each of them has its own position
+/// in the bounded buffer, and the one falling behind by more than its capacity is notified about the skipped batches.
+#[derive(Debug)]
+pub struct AppendsBroadcaster {
+    sender: broadcast::Sender<AppendedMessages>,
+}
+
+/// Receives the messages appended to all the partitions after subscribing.
+#[derive(Debug)]
+pub struct AppendsSubscriber {
+    receiver: broadcast::Receiver<AppendedMessages>,
+    lagged_batches: u64,
+}
+
+impl AppendsBroadcaster {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> AppendsSubscriber {
+        AppendsSubscriber {
+            receiver: self.sender.subscribe(),
+            lagged_batches: 0,
+        }
+    }
+
+    pub fn subscribers_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    /// Publishes the appended messages, doing nothing if there are no subscribers.
+    pub fn publish(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        messages: &[Arc<RetainedMessage>],
+    ) {
+        if self.sender.receiver_count() == 0 || messages.is_empty() {
+            return;
+        }
+
+        // Sending fails only if all the subscribers have been dropped in the meantime.
+        let _ = self.sender.send(AppendedMessages {
+            stream_id,
+            topic_id,
+            partition_id,
+            messages: messages.into(),
+        });
+    }
+}
+
+impl Default for AppendsBroadcaster {
+    fn default() -> Self {
+        Self::new(APPENDS_CHANNEL_CAPACITY)
+    }
+}
+
+impl AppendsSubscriber {
+    /// Waits for the next batch of the appended messages, returns `None` once the broadcaster is dropped.
+    pub async fn recv(&mut self) -> Option<AppendNotification> {
+        match self.receiver.recv().await {
+            Ok(messages) => Some(AppendNotification::Appended(messages)),
+            Err(RecvError::Lagged(skipped)) => {
+                self.lagged_batches += skipped;
+                warn!(
+                    "Appended messages subscriber has lagged behind, skipped {skipped} batch(es)."
+                );
+                Some(AppendNotification::Lagged(skipped))
+            }
+            Err(RecvError::Closed) => None,
+        }
+    }
+
+    /// The total number of the batches skipped for this subscriber.
+    pub fn lagged_batches(&self) -> u64 {
+        self.lagged_batches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::messages::send_messages::Message;
+
+    fn create_messages(offsets: std::ops::Range<u64>) -> Vec<Arc<RetainedMessage>> {
+        offsets
+            .map(|offset| {
+                let message = Message::new(Some(offset as u128), Bytes::from("value"), None);
+                Arc::new(RetainedMessage::new(offset, 0, message))
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn appended_messages_should_be_received_by_all_subscribers() {
+        let broadcaster = AppendsBroadcaster::new(10);
+        let messages = create_messages(0..3);
+        broadcaster.publish(1, 2, 3, &messages);
+
+        let mut first_subscriber = broadcaster.subscribe();
+        let mut second_subscriber = broadcaster.subscribe();
+        assert_eq!(broadcaster.subscribers_count(), 2);
+        broadcaster.publish(1, 2, 3, &messages);
+
+        for subscriber in [&mut first_subscriber, &mut second_subscriber] {
+            let Some(AppendNotification::Appended(appended)) = subscriber.recv().await else {
+                panic!("Expected the appended messages");
+            };
+            assert_eq!(
+                (appended.stream_id, appended.topic_id, appended.partition_id),
+                (1, 2, 3)
+            );
+            assert_eq!(appended.messages.len(), 3);
+            assert!(Arc::ptr_eq(&appended.messages[0], &messages[0]));
+        }
+    }
+
+    #[tokio::test]
+    async fn slow_subscriber_should_be_notified_about_skipped_batches() {
+        let broadcaster = AppendsBroadcaster::new(2);
+        let mut slow_subscriber = broadcaster.subscribe();
+        let mut fast_subscriber = broadcaster.subscribe();
+        for offset in 0..5 {
+            broadcaster.publish(1, 1, 1, &create_messages(offset..offset + 1));
+            assert!(matches!(
+                fast_subscriber.recv().await,
+                Some(AppendNotification::Appended(_))
+            ));
+        }
+
+        assert!(matches!(
+            slow_subscriber.recv().await,
+            Some(AppendNotification::Lagged(3))
+        ));
+        assert_eq!(slow_subscriber.lagged_batches(), 3);
+        let Some(AppendNotification::Appended(appended)) = slow_subscriber.recv().await else {
+            panic!("Expected the appended messages");
+        };
+        assert_eq!(appended.messages[0].offset, 3);
+        assert_eq!(fast_subscriber.lagged_batches(), 0);
+
+        drop(broadcaster);
+        slow_subscriber.recv().await;
+        assert!(slow_subscriber.recv().await.is_none());
+    }
+}
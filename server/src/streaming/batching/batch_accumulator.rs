@@ -32,7 +32,10 @@ impl BatchAccumulator {
         assert!(!items.is_empty());
         self.current_size += batch_size;
         self.current_offset = items.last().unwrap().offset;
-        self.current_timestamp = items.last().unwrap().timestamp;
+        self.current_timestamp = items
+            .iter()
+            .map(|msg| msg.timestamp)
+            .fold(self.current_timestamp, u64::max);
         self.messages.extend(items.iter().cloned());
     }
 
@@ -70,16 +73,20 @@ impl BatchAccumulator {
 
     pub fn materialize_batch_and_maybe_update_state(&mut self) -> (bool, RetainedMessageBatch) {
         let batch_base_offset = self.base_offset;
-        let batch_last_offset_delta = (self.current_offset - self.base_offset) as u32;
-        let split_point = std::cmp::min(self.capacity as usize, self.messages.len());
+        let batch_base_timestamp = self.messages.first().map_or(0, |msg| msg.timestamp);
+        let split_point = self.get_split_point(batch_base_offset, batch_base_timestamp);
 
         let mut bytes = BytesMut::with_capacity(self.current_size.as_bytes_u64() as usize);
-        let last_batch_timestamp = self
-            .messages
-            .get(split_point - 1)
-            .map_or(0, |msg| msg.timestamp);
+        let last_batch_message = &self.messages[split_point - 1];
+        let batch_last_offset_delta = (last_batch_message.offset - batch_base_offset) as u32;
+        // The create time provided by the producer may be out of order, thus the max isn't necessarily the last timestamp.
+        let batch_max_timestamp = self.messages[..split_point]
+            .iter()
+            .map(|msg| msg.timestamp)
+            .max()
+            .unwrap_or(batch_base_timestamp);
         for message in self.messages.drain(..split_point) {
-            message.extend(&mut bytes);
+            message.extend(batch_base_offset, batch_base_timestamp, &mut bytes);
         }
 
         let has_remainder = !self.messages.is_empty();
@@ -91,7 +98,12 @@ impl BatchAccumulator {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             self.current_offset = self.messages.last().unwrap().offset;
-            self.current_timestamp = self.messages.last().unwrap().timestamp;
+            self.current_timestamp = self
+                .messages
+                .iter()
+                .map(|msg| msg.timestamp)
+                .max()
+                .unwrap_or(0);
         }
 
         let batch_payload = bytes.freeze();
@@ -99,12 +111,28 @@ impl BatchAccumulator {
         let batch = RetainedMessageBatch::new(
             batch_base_offset,
             batch_last_offset_delta,
-            last_batch_timestamp,
+            split_point as u32,
+            batch_base_timestamp,
+            batch_max_timestamp,
             batch_payload_len,
             batch_payload,
         );
         (has_remainder, batch)
     }
+
+    // The messages store only the u32 deltas of their offsets and timestamps relative to the batch base,
+    // thus the batch has to be split if any of the deltas doesn't fit (e.g. the clock went backwards).
+    fn get_split_point(&self, base_offset: u64, base_timestamp: u64) -> usize {
+        let max_split_point = std::cmp::min(self.capacity as usize, self.messages.len());
+        self.messages[..max_split_point]
+            .iter()
+            .position(|msg| {
+                msg.offset - base_offset > u32::MAX as u64
+                    || msg.timestamp < base_timestamp
+                    || msg.timestamp - base_timestamp > u32::MAX as u64
+            })
+            .unwrap_or(max_split_point)
+    }
 }
 
 impl Sizeable for BatchAccumulator {
@@ -112,3 +140,112 @@ impl Sizeable for BatchAccumulator {
         self.current_size + RETAINED_BATCH_OVERHEAD.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::batching::iterator::IntoMessagesIterator;
+    use bytes::Bytes;
+    use iggy::error::IggyError;
+    use iggy::messages::send_messages::Message;
+
+    fn create_message(offset: u64, timestamp: u64) -> Arc<RetainedMessage> {
+        let message = Message::new(Some(offset as u128 + 1), Bytes::from("test"), None);
+        Arc::new(RetainedMessage::new(offset, timestamp, message))
+    }
+
+    fn append(accumulator: &mut BatchAccumulator, messages: &[Arc<RetainedMessage>]) {
+        let size = messages.iter().map(|msg| msg.get_size_bytes()).sum();
+        accumulator.append(size, messages);
+    }
+
+    #[test]
+    fn materialized_batch_should_store_messages_relative_to_its_base() {
+        let mut accumulator = BatchAccumulator::new(10, 10);
+        let messages = vec![
+            create_message(10, 1000),
+            create_message(11, 1500),
+            create_message(12, 2000),
+        ];
+        append(&mut accumulator, &messages);
+
+        let (has_remainder, batch) = accumulator.materialize_batch_and_maybe_update_state();
+
+        assert!(!has_remainder);
+        assert_eq!(batch.base_offset, 10);
+        assert_eq!(batch.last_offset_delta, 2);
+        assert_eq!(batch.messages_count, 3);
+        assert_eq!(batch.base_timestamp, 1000);
+        assert_eq!(batch.max_timestamp, 2000);
+        assert!(batch.validate_checksum().is_ok());
+        let loaded_messages = batch.into_messages_iter().collect::<Vec<_>>();
+        assert_eq!(loaded_messages.len(), messages.len());
+        for (loaded_message, message) in loaded_messages.iter().zip(messages.iter()) {
+            assert_eq!(loaded_message.id, message.id);
+            assert_eq!(loaded_message.offset, message.offset);
+            assert_eq!(loaded_message.timestamp, message.timestamp);
+            assert_eq!(loaded_message.checksum, message.checksum);
+            assert_eq!(loaded_message.payload, message.payload);
+        }
+    }
+
+    #[test]
+    fn batch_should_be_split_when_timestamp_delta_does_not_fit() {
+        let mut accumulator = BatchAccumulator::new(0, 10);
+        append(
+            &mut accumulator,
+            &[
+                create_message(0, 1000),
+                create_message(1, 2000),
+                create_message(2, 500),
+            ],
+        );
+
+        let (has_remainder, batch) = accumulator.materialize_batch_and_maybe_update_state();
+        assert!(has_remainder);
+        assert_eq!(batch.messages_count, 2);
+        assert_eq!(batch.get_last_offset(), 1);
+
+        let (has_remainder, batch) = accumulator.materialize_batch_and_maybe_update_state();
+        assert!(!has_remainder);
+        assert_eq!(batch.base_offset, 2);
+        assert_eq!(batch.base_timestamp, 500);
+        assert_eq!(batch.messages_count, 1);
+    }
+
+    #[test]
+    fn batch_max_timestamp_should_be_the_highest_message_timestamp() {
+        let mut accumulator = BatchAccumulator::new(0, 10);
+        append(
+            &mut accumulator,
+            &[
+                create_message(0, 1000),
+                create_message(1, 3000),
+                create_message(2, 2000),
+            ],
+        );
+        assert_eq!(accumulator.batch_max_timestamp(), 3000);
+
+        let (has_remainder, batch) = accumulator.materialize_batch_and_maybe_update_state();
+        assert!(!has_remainder);
+        assert_eq!(batch.base_timestamp, 1000);
+        assert_eq!(batch.max_timestamp, 3000);
+    }
+
+    #[test]
+    fn batch_with_corrupted_payload_should_fail_checksum_validation() {
+        let mut accumulator = BatchAccumulator::new(0, 10);
+        append(&mut accumulator, &[create_message(0, 1000)]);
+
+        let (_, mut batch) = accumulator.materialize_batch_and_maybe_update_state();
+        let mut bytes = batch.bytes.to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        batch.bytes = Bytes::from(bytes);
+
+        assert!(matches!(
+            batch.validate_checksum(),
+            Err(IggyError::InvalidBatchChecksum(_, _, 0))
+        ));
+    }
+}
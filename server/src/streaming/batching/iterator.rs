@@ -30,16 +30,24 @@ impl Iterator for RetainedMessageBatchIterator<'_> {
         if self.current_position < self.batch.length.as_bytes_u64() {
             let start_position = self.current_position as usize;
             let length = u32::from_le_bytes(
-                self.batch.bytes[start_position..start_position + 4]
+                self.batch
+                    .bytes
+                    .get(start_position..start_position + 4)?
                     .try_into()
                     .ok()?,
             );
-            let message = self
-                .batch
-                .bytes
-                .slice(start_position + 4..start_position + 4 + length as usize);
+            let end_position = start_position + 4 + length as usize;
+            if end_position > self.batch.bytes.len() {
+                return None;
+            }
+            let message = self.batch.bytes.slice(start_position + 4..end_position);
             self.current_position += 4 + length as u64;
-            RetainedMessage::try_from_bytes(message).ok()
+            RetainedMessage::try_from_bytes(
+                message,
+                self.batch.base_offset,
+                self.batch.base_timestamp,
+            )
+            .ok()
         } else {
             None
         }
@@ -2,15 +2,31 @@ use crate::streaming::batching::batch_filter::BatchItemizer;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
 use crate::streaming::models::messages::RetainedMessage;
 use bytes::{BufMut, Bytes, BytesMut};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::utils::checksum;
 use iggy::utils::{byte_size::IggyByteSize, sizeable::Sizeable};
 
-pub const RETAINED_BATCH_OVERHEAD: u64 = 8 + 8 + 4 + 4;
+// base offset: 8 bytes, length: 4 bytes, format version: 1 byte, last offset delta: 4 bytes, messages count: 4 bytes,
+// base timestamp: 8 bytes, max timestamp: 8 bytes, attributes: 1 byte, checksum: 4 bytes
+pub const RETAINED_BATCH_OVERHEAD: u64 = 8 + 4 + 1 + 4 + 4 + 8 + 8 + 1 + 4;
 
+/// The version of the batch layout written to the segment log file. The segments written before the batch
+/// header was versioned (absolute offsets and timestamps of the messages) are converted on startup by the migration.
+pub const RETAINED_BATCH_FORMAT_VERSION: u8 = 1;
+
+/// The batch of messages stored in the segment log file.
+/// The header keeps the base offset and base timestamp of the batch, while each message only stores
+/// its offset and timestamp relative to them. The checksum is calculated over the (possibly compressed) payload.
 #[derive(Debug, Clone)]
 pub struct RetainedMessageBatch {
     pub base_offset: u64,
     pub last_offset_delta: u32,
+    pub messages_count: u32,
+    pub base_timestamp: u64,
     pub max_timestamp: u64,
+    pub compression_algorithm: CompressionAlgorithm,
+    pub checksum: u32,
     pub length: IggyByteSize,
     pub bytes: Bytes,
 }
@@ -19,6 +35,8 @@ impl RetainedMessageBatch {
     pub fn new(
         base_offset: u64,
         last_offset_delta: u32,
+        messages_count: u32,
+        base_timestamp: u64,
         max_timestamp: u64,
         length: IggyByteSize,
         bytes: Bytes,
@@ -26,12 +44,41 @@ impl RetainedMessageBatch {
         RetainedMessageBatch {
             base_offset,
             last_offset_delta,
+            messages_count,
+            base_timestamp,
             max_timestamp,
+            compression_algorithm: CompressionAlgorithm::None,
+            checksum: checksum::calculate(&bytes),
             length,
             bytes,
         }
     }
 
+    pub fn validate_checksum(&self) -> Result<(), IggyError> {
+        let calculated_checksum = checksum::calculate(&self.bytes);
+        if calculated_checksum != self.checksum {
+            return Err(IggyError::InvalidBatchChecksum(
+                calculated_checksum,
+                self.checksum,
+                self.base_offset,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_format_version(version: u8, base_offset: u64) -> Result<(), IggyError> {
+        if version != RETAINED_BATCH_FORMAT_VERSION {
+            return Err(IggyError::UnsupportedBatchFormatVersion(
+                version,
+                RETAINED_BATCH_FORMAT_VERSION,
+                base_offset,
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn is_contained_or_overlapping_within_offset_range(
         &self,
         start_offset: u64,
@@ -49,8 +96,13 @@ impl RetainedMessageBatch {
     pub fn extend(&self, bytes: &mut BytesMut) {
         bytes.put_u64_le(self.base_offset);
         bytes.put_u32_le(self.length.as_bytes_u64() as u32);
+        bytes.put_u8(RETAINED_BATCH_FORMAT_VERSION);
         bytes.put_u32_le(self.last_offset_delta);
+        bytes.put_u32_le(self.messages_count);
+        bytes.put_u64_le(self.base_timestamp);
         bytes.put_u64_le(self.max_timestamp);
+        bytes.put_u8(self.compression_algorithm.as_code());
+        bytes.put_u32_le(self.checksum);
         bytes.put_slice(&self.bytes);
     }
 }
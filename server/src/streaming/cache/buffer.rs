@@ -3,33 +3,46 @@ use crate::streaming::local_sizeable::LocalSizeable;
 use super::memory_tracker::CacheMemoryTracker;
 use atone::Vc;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::fmt::Debug;
 use std::ops::Index;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+/// Partition part of the system-wide cache, the used memory is tracked globally by the `CacheMemoryTracker`.
 #[derive(Debug)]
 pub struct SmartCache<T: LocalSizeable + Debug> {
     current_size: IggyByteSize,
     buffer: Vc<T>,
     memory_tracker: Arc<CacheMemoryTracker>,
+    last_access: AtomicU64,
+    limit: Option<IggyByteSize>,
 }
 
 impl<T> SmartCache<T>
 where
     T: LocalSizeable + Clone + Debug,
 {
-    pub fn new() -> Self {
+    pub fn new(memory_tracker: Arc<CacheMemoryTracker>) -> Self {
         let current_size = IggyByteSize::default();
         let buffer = Vc::new();
-        let memory_tracker = CacheMemoryTracker::get_instance().unwrap();
+        memory_tracker.register_partition();
 
         Self {
             current_size,
             buffer,
             memory_tracker,
+            last_access: AtomicU64::new(0),
+            limit: None,
         }
     }
 
+    /// Sets the maximum size of the buffer regardless of the usage of the whole cache, e.g. the cache size overridden for the topic.
+    pub fn set_limit(&mut self, limit: Option<IggyByteSize>) {
+        self.limit = limit;
+        self.evict_over_limit();
+    }
+
     // Used only for cache validation tests
     #[cfg(test)]
     pub fn to_vec(&self) -> Vec<T> {
@@ -45,12 +58,13 @@ where
         let element_size = element.get_size_bytes();
 
         while !self.memory_tracker.will_fit_into_cache(element_size) {
-            if let Some(oldest_element) = self.buffer.pop_front() {
-                let oldest_size = oldest_element.get_size_bytes();
-                self.memory_tracker
-                    .decrement_used_memory(oldest_size.as_bytes_u64());
-                self.current_size -= oldest_size;
-            }
+            let Some(oldest_element) = self.buffer.pop_front() else {
+                break;
+            };
+            let oldest_size = oldest_element.get_size_bytes();
+            self.memory_tracker
+                .decrement_used_memory(oldest_size.as_bytes_u64());
+            self.current_size -= oldest_size;
         }
 
         self.memory_tracker
@@ -59,20 +73,20 @@ where
         self.buffer.push_back(element);
     }
 
-    /// Removes the oldest elements until there's enough space for the new element.
-    pub fn evict_by_size(&mut self, size_to_remove: u64) {
+    /// Removes the oldest elements until the requested size is removed or the buffer is empty, returns the removed size.
+    pub fn evict_by_size(&mut self, size_to_remove: u64) -> u64 {
         let mut removed_size = IggyByteSize::default();
-
-        while let Some(element) = self.buffer.pop_front() {
-            if removed_size >= size_to_remove {
+        while removed_size < size_to_remove {
+            let Some(element) = self.buffer.pop_front() else {
                 break;
-            }
+            };
             let elem_size = element.get_size_bytes();
             self.memory_tracker
                 .decrement_used_memory(elem_size.as_bytes_u64());
             self.current_size -= elem_size;
             removed_size += elem_size;
         }
+        removed_size.as_bytes_u64()
     }
 
     pub fn purge(&mut self) {
@@ -90,8 +104,33 @@ where
         self.current_size
     }
 
-    /// Extends the buffer with the given elements, and always adding the elements,
-    /// even if it exceeds the memory limit.
+    /// Returns `true` if the buffer uses more memory than the equal share of the cache limit.
+    pub fn is_over_budget(&self) -> bool {
+        self.current_size > self.memory_tracker.partition_budget()
+    }
+
+    /// Returns the timestamp in microseconds of the last lookup in the buffer, used to find the least recently used (cold) partitions.
+    pub fn last_access(&self) -> u64 {
+        self.last_access.load(Ordering::Relaxed)
+    }
+
+    pub fn record_hit(&self) {
+        self.memory_tracker.record_hit();
+        self.touch();
+    }
+
+    pub fn record_miss(&self) {
+        self.memory_tracker.record_miss();
+        self.touch();
+    }
+
+    fn touch(&self) {
+        self.last_access
+            .store(IggyTimestamp::now().as_micros(), Ordering::Relaxed);
+    }
+
+    /// Extends the buffer with the given elements, and if the cache is full,
+    /// removes the oldest elements exceeding the budget of the partition.
     pub fn extend(&mut self, elements: impl IntoIterator<Item = T>) {
         let elements = elements.into_iter().inspect(|element| {
             let element_size = element.get_size_bytes();
@@ -100,15 +139,38 @@ where
             self.current_size += element_size;
         });
         self.buffer.extend(elements);
+        self.evict_over_budget();
     }
 
-    /// Always appends the element into the buffer, even if it exceeds the memory limit.
+    /// Appends the element into the buffer, and if the cache is full,
+    /// removes the oldest elements exceeding the budget of the partition.
     pub fn append(&mut self, element: T) {
         let element_size = element.get_size_bytes();
         self.memory_tracker
             .increment_used_memory(element_size.as_bytes_u64());
         self.current_size += element_size;
         self.buffer.push(element);
+        self.evict_over_budget();
+    }
+
+    fn evict_over_limit(&mut self) {
+        if let Some(limit) = self.limit {
+            if self.current_size > limit {
+                self.evict_by_size((self.current_size - limit).as_bytes_u64());
+            }
+        }
+    }
+
+    fn evict_over_budget(&mut self) {
+        self.evict_over_limit();
+        if !self.memory_tracker.is_full() {
+            return;
+        }
+
+        let budget = self.memory_tracker.partition_budget();
+        if self.current_size > budget {
+            self.evict_by_size((self.current_size - budget).as_bytes_u64());
+        }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
@@ -131,8 +193,135 @@ where
     }
 }
 
-impl<T: LocalSizeable + Clone + Debug> Default for SmartCache<T> {
-    fn default() -> Self {
-        Self::new()
+impl<T: LocalSizeable + Debug> Drop for SmartCache<T> {
+    fn drop(&mut self) {
+        self.memory_tracker
+            .decrement_used_memory(self.current_size.as_bytes_u64());
+        self.memory_tracker.unregister_partition();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::resource_quota::MemoryResourceQuota;
+    use crate::streaming::models::messages::RetainedMessage;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+
+    const PAYLOAD: &str = "message";
+    // ID (16) + length (4) + offset delta (4) + timestamp delta (4) + state (1) + headers length (4) + payload
+    const MESSAGE_SIZE: u64 = 16 + 4 + 4 + 4 + 1 + 4 + PAYLOAD.len() as u64;
+
+    #[test]
+    fn should_track_memory_used_by_all_partitions() {
+        let memory_tracker = create_memory_tracker(100);
+        let mut first_cache = SmartCache::new(memory_tracker.clone());
+        let mut second_cache = SmartCache::new(memory_tracker.clone());
+        assert_eq!(memory_tracker.partition_budget(), 50 * MESSAGE_SIZE);
+
+        first_cache.extend(create_messages(0, 3));
+        second_cache.extend(create_messages(0, 2));
+        assert_eq!(memory_tracker.usage_bytes(), 5 * MESSAGE_SIZE);
+
+        drop(second_cache);
+        assert_eq!(memory_tracker.usage_bytes(), 3 * MESSAGE_SIZE);
+        assert_eq!(memory_tracker.partition_budget(), 100 * MESSAGE_SIZE);
+
+        first_cache.purge();
+        assert_eq!(memory_tracker.usage_bytes(), 0);
+    }
+
+    #[test]
+    fn should_evict_oldest_messages_over_partition_budget_only_when_cache_is_full() {
+        let memory_tracker = create_memory_tracker(10);
+        let mut first_cache = SmartCache::new(memory_tracker.clone());
+        let mut second_cache = SmartCache::new(memory_tracker.clone());
+
+        first_cache.extend(create_messages(0, 8));
+        assert_eq!(first_cache.len(), 8);
+        assert!(first_cache.is_over_budget());
+
+        second_cache.extend(create_messages(0, 4));
+        assert_eq!(second_cache.len(), 4);
+        assert!(!second_cache.is_over_budget());
+
+        first_cache.extend(create_messages(8, 1));
+        assert_eq!(first_cache.len(), 5);
+        assert_eq!(first_cache[0].offset, 4);
+        assert!(!first_cache.is_over_budget());
+        assert_eq!(memory_tracker.usage_bytes(), 9 * MESSAGE_SIZE);
+    }
+
+    #[test]
+    fn should_evict_messages_by_size() {
+        let memory_tracker = create_memory_tracker(100);
+        let mut cache = SmartCache::new(memory_tracker.clone());
+        cache.extend(create_messages(0, 5));
+
+        let removed_size = cache.evict_by_size(2 * MESSAGE_SIZE - 1);
+        assert_eq!(removed_size, 2 * MESSAGE_SIZE);
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache[0].offset, 2);
+        assert_eq!(memory_tracker.usage_bytes(), 3 * MESSAGE_SIZE);
+
+        let removed_size = cache.evict_by_size(100 * MESSAGE_SIZE);
+        assert_eq!(removed_size, 3 * MESSAGE_SIZE);
+        assert!(cache.is_empty());
+        assert_eq!(memory_tracker.usage_bytes(), 0);
+    }
+
+    #[test]
+    fn should_record_hits_and_misses() {
+        let memory_tracker = create_memory_tracker(100);
+        let cache = SmartCache::<Arc<RetainedMessage>>::new(memory_tracker.clone());
+        assert_eq!(cache.last_access(), 0);
+
+        cache.record_hit();
+        cache.record_hit();
+        cache.record_miss();
+
+        assert_eq!(memory_tracker.hits(), 2);
+        assert_eq!(memory_tracker.misses(), 1);
+        assert!(cache.last_access() > 0);
+    }
+
+    #[test]
+    fn should_evict_oldest_messages_over_limit_even_when_cache_is_not_full() {
+        let memory_tracker = create_memory_tracker(100);
+        let mut cache = SmartCache::new(memory_tracker.clone());
+        cache.set_limit(Some(IggyByteSize::from(3 * MESSAGE_SIZE)));
+
+        cache.extend(create_messages(0, 5));
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache[0].offset, 2);
+        assert_eq!(memory_tracker.usage_bytes(), 3 * MESSAGE_SIZE);
+
+        cache.set_limit(Some(IggyByteSize::from(MESSAGE_SIZE)));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache[0].offset, 4);
+    }
+
+    fn create_memory_tracker(messages_count: u64) -> Arc<CacheMemoryTracker> {
+        Arc::new(CacheMemoryTracker::new(MemoryResourceQuota::Bytes(
+            IggyByteSize::from(messages_count * MESSAGE_SIZE),
+        )))
+    }
+
+    fn create_messages(start_offset: u64, count: u64) -> Vec<Arc<RetainedMessage>> {
+        (start_offset..start_offset + count)
+            .map(|offset| {
+                Arc::new(RetainedMessage {
+                    id: offset as u128,
+                    offset,
+                    timestamp: offset,
+                    checksum: 0,
+                    message_state: MessageState::Available,
+                    headers: None,
+                    payload: Bytes::from(PAYLOAD),
+                })
+            })
+            .collect()
     }
 }
@@ -3,7 +3,7 @@ extern crate sysinfo;
 use crate::configs::resource_quota::MemoryResourceQuota;
 use crate::configs::system::CacheConfig;
 use iggy::utils::byte_size::IggyByteSize;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::{Arc, Once};
 use sysinfo::System;
 use tracing::info;
@@ -11,10 +11,15 @@ use tracing::info;
 static ONCE: Once = Once::new();
 static mut INSTANCE: Option<Arc<CacheMemoryTracker>> = None;
 
+/// Tracks the memory used by the caches of all the partitions, which together form a single system-wide cache.
+/// Each partition gets an equal share of the cache limit as its budget, which it can exceed only while the cache is not full.
 #[derive(Debug)]
 pub struct CacheMemoryTracker {
     used_memory_bytes: AtomicU64,
     limit_bytes: IggyByteSize,
+    partitions_count: AtomicU32,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 type MessageSize = u64;
@@ -39,7 +44,7 @@ impl CacheMemoryTracker {
         unsafe { INSTANCE.clone() }
     }
 
-    fn new(limit: MemoryResourceQuota) -> Self {
+    pub(crate) fn new(limit: MemoryResourceQuota) -> Self {
         let mut sys = System::new_all();
         sys.refresh_all();
 
@@ -58,6 +63,9 @@ impl CacheMemoryTracker {
         CacheMemoryTracker {
             used_memory_bytes,
             limit_bytes,
+            partitions_count: AtomicU32::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
@@ -97,6 +105,44 @@ impl CacheMemoryTracker {
         IggyByteSize::from(self.used_memory_bytes.load(Ordering::SeqCst))
     }
 
+    pub fn limit_bytes(&self) -> IggyByteSize {
+        self.limit_bytes
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.usage_bytes() >= self.limit_bytes
+    }
+
+    pub fn register_partition(&self) {
+        self.partitions_count.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn unregister_partition(&self) {
+        self.partitions_count.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Returns the equal share of the cache limit for each of the partitions.
+    pub fn partition_budget(&self) -> IggyByteSize {
+        let partitions_count = self.partitions_count.load(Ordering::SeqCst).max(1) as u64;
+        IggyByteSize::from(self.limit_bytes.as_bytes_u64() / partitions_count)
+    }
+
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
     pub fn will_fit_into_cache(&self, requested_size: IggyByteSize) -> bool {
         IggyByteSize::from(self.used_memory_bytes.load(Ordering::SeqCst)) + requested_size
             <= self.limit_bytes
@@ -4,7 +4,6 @@ use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
 use iggy::models::user_info::UserId;
-use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
@@ -21,7 +20,6 @@ pub struct Client {
     pub session: Arc<Session>,
     pub transport: Transport,
     pub consumer_groups: Vec<ConsumerGroup>,
-    pub last_heartbeat: IggyTimestamp,
 }
 
 #[derive(Debug)]
@@ -55,7 +53,6 @@ impl ClientManager {
             session: session.clone(),
             transport,
             consumer_groups: Vec::new(),
-            last_heartbeat: IggyTimestamp::now(),
         };
         self.clients.insert(client_id, IggySharedMut::new(client));
         session
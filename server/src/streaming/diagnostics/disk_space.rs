@@ -0,0 +1,133 @@
+use crate::configs::system::DiskMonitorConfig;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use sysinfo::Disks;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiskSpaceState {
+    Exhausted,
+    Recovered,
+    Unchanged,
+}
+
+/// Switches the server into the read-only mode when the free space of any disk holding the data
+/// drops below the configured threshold, and back once enough space is freed.
+#[derive(Debug)]
+pub(crate) struct DiskSpaceMonitor {
+    config: DiskMonitorConfig,
+    read_only: AtomicBool,
+}
+
+impl DiskSpaceMonitor {
+    pub fn new(config: DiskMonitorConfig) -> Self {
+        Self {
+            config,
+            read_only: AtomicBool::new(false),
+        }
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    /// Returns the `InsufficientDiskSpace` error if the server is in the read-only mode.
+    pub fn ensure_writable(&self) -> Result<(), IggyError> {
+        if self.is_read_only() {
+            return Err(IggyError::InsufficientDiskSpace);
+        }
+
+        Ok(())
+    }
+
+    /// Records the lowest free space across the disks holding the data, entering or leaving the read-only mode.
+    pub fn record_free_space(&self, free_space: IggyByteSize) -> DiskSpaceState {
+        if !self.config.enabled {
+            return DiskSpaceState::Unchanged;
+        }
+
+        let is_exhausted = free_space < self.config.min_free_space;
+        if self.read_only.swap(is_exhausted, Ordering::SeqCst) == is_exhausted {
+            return DiskSpaceState::Unchanged;
+        }
+
+        if is_exhausted {
+            warn!(
+                "Free disk space: {free_space} is below the threshold: {}, the server is read-only and the sent messages will be rejected.",
+                self.config.min_free_space
+            );
+            DiskSpaceState::Exhausted
+        } else {
+            info!(
+                "Free disk space: {free_space} is above the threshold: {}, the server accepts the sent messages again.",
+                self.config.min_free_space
+            );
+            DiskSpaceState::Recovered
+        }
+    }
+}
+
+/// Returns the available space of the disk on which the path is stored, or `None` if it cannot be determined.
+pub(crate) fn get_free_space(disks: &Disks, path: &str) -> Option<IggyByteSize> {
+    let path = std::fs::canonicalize(path).ok()?;
+    disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| IggyByteSize::from(disk.available_space()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(enabled: bool) -> DiskSpaceMonitor {
+        DiskSpaceMonitor::new(DiskMonitorConfig {
+            enabled,
+            interval: "1 s".parse().unwrap(),
+            min_free_space: IggyByteSize::from(1000),
+        })
+    }
+
+    #[test]
+    fn server_should_be_read_only_until_free_space_is_above_threshold() {
+        let monitor = monitor(true);
+        assert!(monitor.ensure_writable().is_ok());
+
+        let state = monitor.record_free_space(IggyByteSize::from(999));
+        assert_eq!(state, DiskSpaceState::Exhausted);
+        assert!(matches!(
+            monitor.ensure_writable(),
+            Err(IggyError::InsufficientDiskSpace)
+        ));
+        let state = monitor.record_free_space(IggyByteSize::from(500));
+        assert_eq!(state, DiskSpaceState::Unchanged);
+
+        let state = monitor.record_free_space(IggyByteSize::from(1000));
+        assert_eq!(state, DiskSpaceState::Recovered);
+        assert!(monitor.ensure_writable().is_ok());
+        let state = monitor.record_free_space(IggyByteSize::from(2000));
+        assert_eq!(state, DiskSpaceState::Unchanged);
+    }
+
+    #[test]
+    fn disabled_monitor_should_never_switch_into_read_only_mode() {
+        let monitor = monitor(false);
+
+        let state = monitor.record_free_space(IggyByteSize::from(0));
+
+        assert_eq!(state, DiskSpaceState::Unchanged);
+        assert!(!monitor.is_read_only());
+    }
+
+    #[test]
+    fn free_space_should_be_resolved_for_existing_path_only() {
+        let disks = Disks::new_with_refreshed_list();
+
+        assert!(get_free_space(&disks, "not_existing_path").is_none());
+        if !disks.list().is_empty() {
+            assert!(get_free_space(&disks, ".").is_some());
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use iggy::utils::duration::IggyDuration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+// The upper bounds of the buckets are the powers of two in microseconds, from 1 us up to ~134 seconds,
+// and the last bucket holds all the greater latencies.
+const BUCKETS: usize = 29;
+
+/// The histogram of the latencies with the exponential buckets, used to estimate the percentiles
+/// without storing the observed values. The estimated percentile is the upper bound of its bucket,
+/// so it's never lower than the actual value and at most twice as high.
+#[derive(Debug)]
+pub(crate) struct LatencyHistogram {
+    buckets: [AtomicU64; BUCKETS],
+    count: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+            count: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    pub fn record(&self, latency: Duration) {
+        let micros = latency.as_micros().max(1) as u64;
+        let bucket = ((u64::BITS - (micros - 1).leading_zeros()) as usize).min(BUCKETS - 1);
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Returns the estimated latency below which the given percentile (0-100) of the observed latencies fall.
+    pub fn percentile(&self, percentile: f64) -> IggyDuration {
+        let count = self.count();
+        if count == 0 {
+            return IggyDuration::from(0);
+        }
+
+        let rank = ((percentile / 100.0) * count as f64).ceil().max(1.0) as u64;
+        let mut observed = 0;
+        for (bucket, bucket_count) in self.buckets.iter().enumerate() {
+            observed += bucket_count.load(Ordering::Relaxed);
+            if observed >= rank {
+                return IggyDuration::from(1u64 << bucket);
+            }
+        }
+        IggyDuration::from(1u64 << (BUCKETS - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_histogram_should_have_zero_percentiles() {
+        let histogram = LatencyHistogram::default();
+
+        assert_eq!(histogram.count(), 0);
+        assert_eq!(histogram.percentile(99.0).as_micros(), 0);
+    }
+
+    #[test]
+    fn percentiles_should_be_estimated_with_bucket_upper_bounds() {
+        let histogram = LatencyHistogram::default();
+        for _ in 0..90 {
+            histogram.record(Duration::from_micros(100));
+        }
+        for _ in 0..9 {
+            histogram.record(Duration::from_millis(3));
+        }
+        histogram.record(Duration::from_secs(1));
+
+        assert_eq!(histogram.count(), 100);
+        assert_eq!(histogram.percentile(50.0).as_micros(), 128);
+        assert_eq!(histogram.percentile(95.0).as_micros(), 4096);
+        assert_eq!(histogram.percentile(99.0).as_micros(), 4096);
+        assert_eq!(histogram.percentile(100.0).as_micros(), 1_048_576);
+    }
+
+    #[test]
+    fn latencies_beyond_last_bucket_should_be_capped() {
+        let histogram = LatencyHistogram::default();
+        histogram.record(Duration::from_secs(1000));
+
+        assert_eq!(histogram.percentile(50.0).as_micros(), 1 << (BUCKETS - 1));
+    }
+}
@@ -1,9 +1,24 @@
+use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::diagnostics::latency::LatencyHistogram;
+use dashmap::DashMap;
+use iggy::command::get_name_from_code;
+use iggy::models::stats::CommandLatency;
+use iggy::utils::byte_size::IggyByteSize;
 use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
 use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
 use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
 use prometheus_client::registry::Registry;
+use std::time::Duration;
 use tracing::error;
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct CommandLabels {
+    command: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct Metrics {
     registry: Registry,
@@ -15,6 +30,15 @@ pub(crate) struct Metrics {
     messages: Gauge,
     users: Gauge,
     clients: Gauge,
+    cache_hits: Gauge,
+    cache_misses: Gauge,
+    cache_size_bytes: Gauge,
+    cold_reads: Counter,
+    failed_unacknowledged_appends: Counter,
+    slow_consumers: Gauge,
+    free_disk_space_bytes: Gauge,
+    command_latency_seconds: Family<CommandLabels, Histogram>,
+    command_latencies: DashMap<u32, LatencyHistogram>,
 }
 
 impl Metrics {
@@ -29,6 +53,17 @@ impl Metrics {
             messages: Gauge::default(),
             users: Gauge::default(),
             clients: Gauge::default(),
+            cache_hits: Gauge::default(),
+            cache_misses: Gauge::default(),
+            cache_size_bytes: Gauge::default(),
+            cold_reads: Counter::default(),
+            failed_unacknowledged_appends: Counter::default(),
+            slow_consumers: Gauge::default(),
+            free_disk_space_bytes: Gauge::default(),
+            command_latency_seconds: Family::new_with_constructor(|| {
+                Histogram::new(exponential_buckets(0.0001, 2.0, 16))
+            }),
+            command_latencies: DashMap::new(),
         };
 
         metrics.register_counter("http_requests", metrics.http_requests.clone());
@@ -39,6 +74,34 @@ impl Metrics {
         metrics.register_gauge("messages", metrics.messages.clone());
         metrics.register_gauge("users", metrics.users.clone());
         metrics.register_gauge("clients", metrics.clients.clone());
+        metrics.register_gauge("cache_hits", metrics.cache_hits.clone());
+        metrics.register_gauge("cache_misses", metrics.cache_misses.clone());
+        metrics.registry.register(
+            "cache_size_bytes",
+            "total size of cached messages in bytes",
+            metrics.cache_size_bytes.clone(),
+        );
+        metrics.registry.register(
+            "cold_reads",
+            "total count of polls which read the messages from disk instead of the cache",
+            metrics.cold_reads.clone(),
+        );
+        metrics.registry.register(
+            "failed_unacknowledged_appends",
+            "total count of the messages sent with no acknowledgement which failed to be appended",
+            metrics.failed_unacknowledged_appends.clone(),
+        );
+        metrics.register_gauge("slow_consumers", metrics.slow_consumers.clone());
+        metrics.registry.register(
+            "free_disk_space_bytes",
+            "lowest free space of the disks holding the data in bytes",
+            metrics.free_disk_space_bytes.clone(),
+        );
+        metrics.registry.register(
+            "command_latency_seconds",
+            "latency of the commands handled by the server in seconds",
+            metrics.command_latency_seconds.clone(),
+        );
 
         metrics
     }
@@ -54,6 +117,7 @@ impl Metrics {
     }
 
     pub fn get_formatted_output(&self) -> String {
+        self.update_cache_metrics();
         let mut buffer = String::new();
         if let Err(err) = encode(&mut buffer, &self.registry) {
             error!("Failed to encode metrics: {}", err);
@@ -61,6 +125,49 @@ impl Metrics {
         buffer
     }
 
+    // The cache is shared by all the partitions, so its metrics are read from the memory tracker on demand.
+    fn update_cache_metrics(&self) {
+        if let Some(memory_tracker) = CacheMemoryTracker::get_instance() {
+            self.cache_hits.set(memory_tracker.hits() as i64);
+            self.cache_misses.set(memory_tracker.misses() as i64);
+            self.cache_size_bytes
+                .set(memory_tracker.usage_bytes().as_bytes_u64() as i64);
+        }
+    }
+
+    pub fn record_command_latency(&self, code: u32, latency: Duration) {
+        self.command_latencies
+            .entry(code)
+            .or_default()
+            .record(latency);
+        let command = get_name_from_code(code).unwrap_or("unknown").to_string();
+        self.command_latency_seconds
+            .get_or_create(&CommandLabels { command })
+            .observe(latency.as_secs_f64());
+    }
+
+    pub fn get_command_latencies(&self) -> Vec<CommandLatency> {
+        let mut latencies = self
+            .command_latencies
+            .iter()
+            .map(|entry| {
+                let histogram = entry.value();
+                CommandLatency {
+                    code: *entry.key(),
+                    name: get_name_from_code(*entry.key())
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    count: histogram.count(),
+                    p50: histogram.percentile(50.0),
+                    p95: histogram.percentile(95.0),
+                    p99: histogram.percentile(99.0),
+                }
+            })
+            .collect::<Vec<_>>();
+        latencies.sort_by_key(|latency| latency.code);
+        latencies
+    }
+
     pub fn increment_http_requests(&self) {
         self.http_requests.inc();
     }
@@ -120,4 +227,25 @@ impl Metrics {
     pub fn decrement_clients(&self, count: u32) {
         self.clients.dec_by(count as i64);
     }
+
+    pub fn increment_cold_reads(&self) {
+        self.cold_reads.inc();
+    }
+
+    pub fn increment_failed_unacknowledged_appends(&self, count: u64) {
+        self.failed_unacknowledged_appends.inc_by(count);
+    }
+
+    pub fn increment_slow_consumers(&self, count: u32) {
+        self.slow_consumers.inc_by(count as i64);
+    }
+
+    pub fn decrement_slow_consumers(&self, count: u32) {
+        self.slow_consumers.dec_by(count as i64);
+    }
+
+    pub fn set_free_disk_space(&self, free_space: IggyByteSize) {
+        self.free_disk_space_bytes
+            .set(free_space.as_bytes_u64() as i64);
+    }
 }
@@ -1 +1,4 @@
+pub(crate) mod disk_space;
+pub(crate) mod latency;
 pub mod metrics;
+pub(crate) mod slow_consumers;
@@ -0,0 +1,275 @@
+use crate::configs::system::SlowConsumerConfig;
+use crate::streaming::polling_consumer::PollingConsumer;
+use dashmap::DashMap;
+use std::fmt::{Display, Formatter};
+use tracing::{info, warn};
+
+/// The consumer tracked by the detector, the members of the consumer group share its offsets,
+/// so the group is tracked as a whole instead of its members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TrackedConsumer {
+    Consumer(u32),
+    ConsumerGroup(u32),
+}
+
+impl From<PollingConsumer> for TrackedConsumer {
+    fn from(consumer: PollingConsumer) -> Self {
+        match consumer {
+            PollingConsumer::Consumer(consumer_id, _) => TrackedConsumer::Consumer(consumer_id),
+            PollingConsumer::ConsumerGroup(consumer_group_id, _) => {
+                TrackedConsumer::ConsumerGroup(consumer_group_id)
+            }
+        }
+    }
+}
+
+impl Display for TrackedConsumer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TrackedConsumer::Consumer(consumer_id) => write!(f, "consumer ID: {consumer_id}"),
+            TrackedConsumer::ConsumerGroup(consumer_group_id) => {
+                write!(f, "consumer group ID: {consumer_group_id}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ConsumerPartition {
+    stream_id: u32,
+    topic_id: u32,
+    partition_id: u32,
+    consumer: TrackedConsumer,
+}
+
+impl Display for ConsumerPartition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, stream ID: {}, topic ID: {}, partition ID: {}",
+            self.consumer, self.stream_id, self.topic_id, self.partition_id
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct ConsumerReads {
+    consecutive_cold_reads: u32,
+    is_slow: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SlowConsumerState {
+    Detected,
+    Recovered,
+    Unchanged,
+}
+
+/// Tracks the consumers which keep polling the messages no longer available in the cache,
+/// only the consumers with at least one cold read in a row are stored.
+#[derive(Debug)]
+pub(crate) struct SlowConsumerDetector {
+    config: SlowConsumerConfig,
+    consumers: DashMap<ConsumerPartition, ConsumerReads>,
+}
+
+impl SlowConsumerDetector {
+    pub fn new(config: SlowConsumerConfig) -> Self {
+        Self {
+            config,
+            consumers: DashMap::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Returns the number of messages to be polled, limited if the consumer is slow and deprioritized.
+    pub fn limit_poll_count(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer: PollingConsumer,
+        count: u32,
+    ) -> u32 {
+        if !self.config.enabled || !self.config.deprioritize {
+            return count;
+        }
+
+        let key = ConsumerPartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer: consumer.into(),
+        };
+        match self.consumers.get(&key) {
+            Some(reads) if reads.is_slow => count.min(self.config.deprioritized_poll_count),
+            _ => count,
+        }
+    }
+
+    /// Records the poll of the consumer, where the cold read means that the messages were read from disk,
+    /// and the lag is the number of messages between the last polled offset and the current partition offset.
+    pub fn record_read(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        partition_id: u32,
+        consumer: PollingConsumer,
+        cold: bool,
+        lag: u64,
+    ) -> SlowConsumerState {
+        if !self.config.enabled {
+            return SlowConsumerState::Unchanged;
+        }
+
+        let key = ConsumerPartition {
+            stream_id,
+            topic_id,
+            partition_id,
+            consumer: consumer.into(),
+        };
+        if !cold || lag < self.config.lag_threshold {
+            return match self.consumers.remove(&key) {
+                Some((_, reads)) if reads.is_slow => {
+                    info!("Slow consumer caught up, {key}, lag: {lag}.");
+                    SlowConsumerState::Recovered
+                }
+                _ => SlowConsumerState::Unchanged,
+            };
+        }
+
+        let mut reads = self.consumers.entry(key).or_default();
+        reads.consecutive_cold_reads = reads.consecutive_cold_reads.saturating_add(1);
+        if reads.is_slow || reads.consecutive_cold_reads < self.config.cold_reads_threshold {
+            return SlowConsumerState::Unchanged;
+        }
+
+        reads.is_slow = true;
+        warn!(
+            "Detected slow consumer, {key}, lag: {lag}, consecutive reads from disk: {}{}.",
+            reads.consecutive_cold_reads,
+            if self.config.deprioritize {
+                format!(
+                    ", its polls are limited to {} messages",
+                    self.config.deprioritized_poll_count
+                )
+            } else {
+                String::new()
+            }
+        );
+        SlowConsumerState::Detected
+    }
+
+    /// Stops tracking the consumers of the deleted topic, or of all the topics of the stream if the topic isn't specified.
+    /// Returns the number of the removed slow consumers.
+    pub fn remove_consumers(&self, stream_id: u32, topic_id: Option<u32>) -> u32 {
+        let mut removed_slow_consumers = 0;
+        self.consumers.retain(|key, reads| {
+            let is_removed = key.stream_id == stream_id
+                && topic_id.is_none_or(|topic_id| key.topic_id == topic_id);
+            if is_removed && reads.is_slow {
+                removed_slow_consumers += 1;
+            }
+            !is_removed
+        });
+        removed_slow_consumers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector(deprioritize: bool) -> SlowConsumerDetector {
+        SlowConsumerDetector::new(SlowConsumerConfig {
+            enabled: true,
+            cold_reads_threshold: 3,
+            lag_threshold: 100,
+            deprioritize,
+            deprioritized_poll_count: 10,
+        })
+    }
+
+    #[test]
+    fn consumer_should_be_detected_after_consecutive_cold_reads_and_recover_after_warm_read() {
+        let detector = detector(false);
+        let consumer = PollingConsumer::Consumer(1, 1);
+
+        for _ in 0..2 {
+            let state = detector.record_read(1, 1, 1, consumer, true, 1000);
+            assert_eq!(state, SlowConsumerState::Unchanged);
+        }
+        let state = detector.record_read(1, 1, 1, consumer, true, 1000);
+        assert_eq!(state, SlowConsumerState::Detected);
+        let state = detector.record_read(1, 1, 1, consumer, true, 1000);
+        assert_eq!(state, SlowConsumerState::Unchanged);
+
+        let state = detector.record_read(1, 1, 1, consumer, false, 1000);
+        assert_eq!(state, SlowConsumerState::Recovered);
+        let state = detector.record_read(1, 1, 1, consumer, false, 1000);
+        assert_eq!(state, SlowConsumerState::Unchanged);
+    }
+
+    #[test]
+    fn cold_reads_below_lag_threshold_should_reset_consecutive_reads() {
+        let detector = detector(false);
+        let consumer = PollingConsumer::Consumer(1, 1);
+
+        detector.record_read(1, 1, 1, consumer, true, 1000);
+        detector.record_read(1, 1, 1, consumer, true, 1000);
+        detector.record_read(1, 1, 1, consumer, true, 10);
+        let state = detector.record_read(1, 1, 1, consumer, true, 1000);
+
+        assert_eq!(state, SlowConsumerState::Unchanged);
+    }
+
+    #[test]
+    fn consumer_group_members_should_be_tracked_as_group() {
+        let detector = detector(true);
+
+        detector.record_read(1, 1, 1, PollingConsumer::ConsumerGroup(1, 1), true, 1000);
+        detector.record_read(1, 1, 1, PollingConsumer::ConsumerGroup(1, 2), true, 1000);
+        let state = detector.record_read(1, 1, 1, PollingConsumer::ConsumerGroup(1, 3), true, 1000);
+
+        assert_eq!(state, SlowConsumerState::Detected);
+        let count = detector.limit_poll_count(1, 1, 1, PollingConsumer::ConsumerGroup(1, 4), 1000);
+        assert_eq!(count, 10);
+    }
+
+    #[test]
+    fn poll_count_should_be_limited_only_for_deprioritized_slow_consumers() {
+        let consumer = PollingConsumer::Consumer(1, 1);
+        for deprioritize in [false, true] {
+            let detector = detector(deprioritize);
+            assert_eq!(detector.limit_poll_count(1, 1, 1, consumer, 1000), 1000);
+            for _ in 0..3 {
+                detector.record_read(1, 1, 1, consumer, true, 1000);
+            }
+
+            let expected_count = if deprioritize { 10 } else { 1000 };
+            assert_eq!(
+                detector.limit_poll_count(1, 1, 1, consumer, 1000),
+                expected_count
+            );
+            assert_eq!(detector.limit_poll_count(1, 1, 2, consumer, 1000), 1000);
+        }
+    }
+
+    #[test]
+    fn consumers_of_deleted_topic_should_be_removed() {
+        let detector = detector(false);
+        let consumer = PollingConsumer::Consumer(1, 1);
+        for topic_id in 1..=2 {
+            for _ in 0..3 {
+                detector.record_read(1, topic_id, 1, consumer, true, 1000);
+            }
+        }
+
+        assert_eq!(detector.remove_consumers(1, Some(1)), 1);
+        assert_eq!(detector.remove_consumers(1, Some(1)), 0);
+        assert_eq!(detector.remove_consumers(1, None), 1);
+    }
+}
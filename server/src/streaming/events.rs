@@ -0,0 +1,97 @@
+use flume::Sender;
+use iggy::models::system_event::SystemEvent;
+use std::sync::RwLock;
+use tracing::{trace, warn};
+
+/// Delivers the events from any part of the system to its subscribers, e.g. the reserved events topic or the webhooks.
+/// Until any subscriber is registered (i.e. when the events and webhooks are disabled), publishing the events does nothing.
+#[derive(Debug, Default)]
+pub struct EventsBus {
+    subscribers: RwLock<Vec<Sender<SystemEvent>>>,
+}
+
+impl EventsBus {
+    /// Registers the channel through which the events are delivered, until its receiver is dropped or the bus is cleared.
+    pub fn subscribe(&self, sender: Sender<SystemEvent>) {
+        self.subscribers
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .push(sender);
+    }
+
+    /// Publishes the event to all the subscribers, without waiting for it to be delivered.
+    /// The subscribers whose receivers have been dropped are removed.
+    pub fn publish(&self, event: SystemEvent) {
+        let has_disconnected_subscribers = {
+            let subscribers = self
+                .subscribers
+                .read()
+                .unwrap_or_else(|error| error.into_inner());
+            if subscribers.is_empty() {
+                return;
+            }
+
+            trace!("Publishing system event: {event:?}");
+            let mut has_disconnected_subscribers = false;
+            for sender in subscribers.iter() {
+                if sender.is_disconnected() {
+                    has_disconnected_subscribers = true;
+                    continue;
+                }
+
+                if let Err(error) = sender.send(event.clone()) {
+                    warn!("Failed to publish system event. Error: {error}");
+                }
+            }
+            has_disconnected_subscribers
+        };
+
+        if has_disconnected_subscribers {
+            self.subscribers
+                .write()
+                .unwrap_or_else(|error| error.into_inner())
+                .retain(|sender| !sender.is_disconnected());
+        }
+    }
+
+    /// Removes all the subscribers, e.g. when the system is shut down.
+    pub fn clear(&self) {
+        self.subscribers
+            .write()
+            .unwrap_or_else(|error| error.into_inner())
+            .clear();
+    }
+
+    pub fn subscribers_count(&self) -> usize {
+        self.subscribers
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn publish_should_remove_disconnected_subscribers() {
+        let events_bus = EventsBus::default();
+        let (sender, receiver) = flume::unbounded();
+        let (disconnected_sender, disconnected_receiver) = flume::unbounded();
+        events_bus.subscribe(sender);
+        events_bus.subscribe(disconnected_sender);
+        drop(disconnected_receiver);
+
+        events_bus.publish(SystemEvent::StreamCreated {
+            stream_id: 1,
+            name: "test".to_string(),
+        });
+
+        assert_eq!(events_bus.subscribers_count(), 1);
+        assert!(receiver.try_recv().is_ok());
+
+        events_bus.clear();
+        assert_eq!(events_bus.subscribers_count(), 0);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::streaming::session::Session;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::models::messages::PolledMessages;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// `InterceptorContext` describes the request for which the interceptor is invoked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterceptorContext {
+    pub user_id: u32,
+    pub client_id: u32,
+    pub stream_id: u32,
+    pub topic_id: u32,
+}
+
+impl InterceptorContext {
+    pub fn new(session: &Session, stream_id: u32, topic_id: u32) -> Self {
+        Self {
+            user_id: session.get_user_id(),
+            client_id: session.client_id,
+            stream_id,
+            topic_id,
+        }
+    }
+}
+
+/// `MessageInterceptor` is the plugin hook invoked for the messages appended by the producers and sent to the consumers,
+/// e.g. for the custom payload encryption, PII scrubbing or enriching the headers, without modifying the handlers.
+/// The interceptors are registered at compile time, when building the custom server, via `System::register_interceptor`.
+/// Returning the error rejects the whole request.
+pub trait MessageInterceptor: Send + Sync {
+    /// The name of the interceptor, used for logging.
+    fn name(&self) -> &str;
+
+    /// Invoked before the messages are validated, encrypted (if enabled) and appended to the topic.
+    fn before_append(
+        &self,
+        _context: &InterceptorContext,
+        _messages: &mut Vec<Message>,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+
+    /// Invoked after the messages are polled and decrypted (if enabled), before they're sent to the consumer.
+    /// The `length` of the message must be updated when the payload is modified.
+    fn before_send(
+        &self,
+        _context: &InterceptorContext,
+        _messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        Ok(())
+    }
+}
+
+impl Debug for dyn MessageInterceptor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MessageInterceptor({})", self.name())
+    }
+}
+
+/// `InterceptorChain` invokes the registered interceptors in the registration order before the append,
+/// and in the reverse order before sending the messages, so that e.g. the transformations can be symmetric.
+#[derive(Debug, Default, Clone)]
+pub struct InterceptorChain {
+    interceptors: Vec<Arc<dyn MessageInterceptor>>,
+}
+
+impl InterceptorChain {
+    pub fn register(&mut self, interceptor: Arc<dyn MessageInterceptor>) {
+        self.interceptors.push(interceptor);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+
+    pub fn before_append(
+        &self,
+        context: &InterceptorContext,
+        messages: &mut Vec<Message>,
+    ) -> Result<(), IggyError> {
+        for interceptor in &self.interceptors {
+            interceptor.before_append(context, messages)?;
+        }
+        Ok(())
+    }
+
+    pub fn before_send(
+        &self,
+        context: &InterceptorContext,
+        messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        for interceptor in self.interceptors.iter().rev() {
+            interceptor.before_send(context, messages)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::{MessageState, PolledMessage};
+    use iggy::utils::byte_size::IggyByteSize;
+    use std::str::FromStr;
+
+    struct SuffixInterceptor {
+        suffix: &'static str,
+    }
+
+    impl MessageInterceptor for SuffixInterceptor {
+        fn name(&self) -> &str {
+            "suffix"
+        }
+
+        fn before_append(
+            &self,
+            _context: &InterceptorContext,
+            messages: &mut Vec<Message>,
+        ) -> Result<(), IggyError> {
+            for message in messages.iter_mut() {
+                let payload = format!(
+                    "{}{}",
+                    String::from_utf8_lossy(&message.payload),
+                    self.suffix
+                );
+                message.payload = Bytes::from(payload);
+                message.length = message.payload.len() as u32;
+            }
+            Ok(())
+        }
+
+        fn before_send(
+            &self,
+            _context: &InterceptorContext,
+            messages: &mut PolledMessages,
+        ) -> Result<(), IggyError> {
+            for message in messages.messages.iter_mut() {
+                let payload = String::from_utf8_lossy(&message.payload).to_string();
+                let Some(payload) = payload.strip_suffix(self.suffix) else {
+                    return Err(IggyError::InvalidMessagePayloadLength);
+                };
+                message.payload = Bytes::from(payload.to_owned());
+                message.length = IggyByteSize::from(message.payload.len() as u64);
+            }
+            Ok(())
+        }
+    }
+
+    struct RejectingInterceptor;
+
+    impl MessageInterceptor for RejectingInterceptor {
+        fn name(&self) -> &str {
+            "rejecting"
+        }
+
+        fn before_append(
+            &self,
+            _context: &InterceptorContext,
+            _messages: &mut Vec<Message>,
+        ) -> Result<(), IggyError> {
+            Err(IggyError::Unauthorized)
+        }
+    }
+
+    #[test]
+    fn interceptors_should_be_invoked_in_order_before_append_and_reverse_order_before_send() {
+        let mut chain = InterceptorChain::default();
+        chain.register(Arc::new(SuffixInterceptor { suffix: "-a" }));
+        chain.register(Arc::new(SuffixInterceptor { suffix: "-b" }));
+        let context = context();
+
+        let mut messages = vec![Message::from_str("test").unwrap()];
+        chain.before_append(&context, &mut messages).unwrap();
+        assert_eq!(messages[0].payload, "test-a-b");
+        assert_eq!(messages[0].length, 8);
+
+        let mut polled_messages = PolledMessages {
+            partition_id: 1,
+            current_offset: 0,
+            epoch: 0,
+            next_offset: None,
+            messages: vec![PolledMessage {
+                offset: 0,
+                state: MessageState::Available,
+                timestamp: 0,
+                id: 0,
+                checksum: 0,
+                headers: None,
+                length: IggyByteSize::from(messages[0].length as u64),
+                payload: messages[0].payload.clone(),
+            }],
+        };
+        chain.before_send(&context, &mut polled_messages).unwrap();
+        assert_eq!(polled_messages.messages[0].payload, "test");
+    }
+
+    #[test]
+    fn error_returned_by_interceptor_should_reject_messages() {
+        let mut chain = InterceptorChain::default();
+        chain.register(Arc::new(RejectingInterceptor));
+        chain.register(Arc::new(SuffixInterceptor { suffix: "-a" }));
+
+        let mut messages = vec![Message::from_str("test").unwrap()];
+        let result = chain.before_append(&context(), &mut messages);
+        assert!(matches!(result, Err(IggyError::Unauthorized)));
+        assert_eq!(messages[0].payload, "test");
+    }
+
+    fn context() -> InterceptorContext {
+        InterceptorContext {
+            user_id: 1,
+            client_id: 1,
+            stream_id: 1,
+            topic_id: 1,
+        }
+    }
+}
@@ -1,8 +1,11 @@
+pub mod appends;
 pub mod batching;
 pub mod cache;
 pub mod clients;
 mod deduplication;
 pub mod diagnostics;
+pub mod events;
+pub mod interceptors;
 pub mod local_sizeable;
 pub mod models;
 pub mod partitions;
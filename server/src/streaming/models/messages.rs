@@ -13,6 +13,12 @@ use std::sync::Arc;
 
 use crate::streaming::local_sizeable::LocalSizeable;
 
+// offset delta: 4 bytes, state: 1 byte, timestamp delta: 4 bytes, checksum: 4 bytes
+pub const RETAINED_MESSAGE_METADATA: u32 = 4 + 1 + 4 + 4;
+
+// offset delta: 4 bytes, state: 1 byte, timestamp delta: 4 bytes, id: 16 bytes, checksum: 4 bytes, headers length: 4 bytes
+const RETAINED_MESSAGE_HEADER_SIZE: usize = 4 + 1 + 4 + 16 + 4 + 4;
+
 // It's the same as PolledMessages from Iggy models, but with the Arc<Message> instead of Message.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PolledMessages {
@@ -62,20 +68,21 @@ impl RetainedMessage {
         }
     }
 
-    pub fn extend(&self, bytes: &mut BytesMut) {
+    /// Writes the message to the batch, storing its offset and timestamp relative to the batch base offset and base timestamp.
+    pub fn extend(&self, base_offset: u64, base_timestamp: u64, bytes: &mut BytesMut) {
         let length = self.get_size_bytes();
         let id = self.id;
-        let offset = self.offset;
-        let timestamp = self.timestamp;
+        let offset_delta = (self.offset - base_offset) as u32;
+        let timestamp_delta = (self.timestamp - base_timestamp) as u32;
         let payload = self.payload.clone();
         let checksum = self.checksum;
         let message_state = self.message_state;
         let headers = &self.headers;
 
         bytes.put_u32_le(length.as_bytes_u64() as u32);
-        bytes.put_u64_le(offset);
+        bytes.put_u32_le(offset_delta);
         bytes.put_u8(message_state.as_code());
-        bytes.put_u64_le(timestamp);
+        bytes.put_u32_le(timestamp_delta);
         bytes.put_u128_le(id);
         bytes.put_u32_le(checksum);
         if let Some(headers) = headers {
@@ -88,25 +95,44 @@ impl RetainedMessage {
         bytes.put_slice(&payload);
     }
 
-    pub fn try_from_bytes(bytes: Bytes) -> Result<Self, IggyError> {
-        let offset = u64::from_le_bytes(bytes[..8].try_into()?);
-        let message_state = MessageState::from_code(bytes[8])?;
-        let timestamp = u64::from_le_bytes(bytes[9..17].try_into()?);
-        let id = u128::from_le_bytes(bytes[17..33].try_into()?);
-        let checksum = u32::from_le_bytes(bytes[33..37].try_into()?);
-        let headers_length = u32::from_le_bytes(bytes[37..41].try_into()?);
+    /// Reads the message written by `extend`, returning an error instead of panicking if the bytes are truncated.
+    pub fn try_from_bytes(
+        bytes: Bytes,
+        base_offset: u64,
+        base_timestamp: u64,
+    ) -> Result<Self, IggyError> {
+        if bytes.len() < RETAINED_MESSAGE_HEADER_SIZE {
+            return Err(IggyError::TruncatedMessage(
+                RETAINED_MESSAGE_HEADER_SIZE as u64,
+                bytes.len() as u64,
+            ));
+        }
+
+        let offset_delta = u32::from_le_bytes(bytes[..4].try_into()?);
+        let message_state = MessageState::from_code(bytes[4])?;
+        let timestamp_delta = u32::from_le_bytes(bytes[5..9].try_into()?);
+        let id = u128::from_le_bytes(bytes[9..25].try_into()?);
+        let checksum = u32::from_le_bytes(bytes[25..29].try_into()?);
+        let headers_length = u32::from_le_bytes(bytes[29..33].try_into()?);
+        let position = RETAINED_MESSAGE_HEADER_SIZE + headers_length as usize;
+        if bytes.len() < position {
+            return Err(IggyError::TruncatedMessage(
+                position as u64,
+                bytes.len() as u64,
+            ));
+        }
+
         let headers = if headers_length > 0 {
-            Some(bytes.slice(41..41 + headers_length as usize))
+            Some(bytes.slice(RETAINED_MESSAGE_HEADER_SIZE..position))
         } else {
             None
         };
-        let position = 41 + headers_length as usize;
         let payload = bytes.slice(position..);
 
         Ok(RetainedMessage {
             id,
-            offset,
-            timestamp,
+            offset: base_offset + offset_delta as u64,
+            timestamp: base_timestamp + timestamp_delta as u64,
             checksum,
             message_state,
             headers,
@@ -118,7 +144,7 @@ impl RetainedMessage {
 impl Sizeable for RetainedMessage {
     fn get_size_bytes(&self) -> IggyByteSize {
         let headers_len = self.headers.as_ref().map(|h| 4 + h.len()).unwrap_or(4);
-        let size = 16 + 8 + 8 + 4 + 1 + headers_len + self.payload.len();
+        let size = 16 + 4 + 4 + 4 + 1 + headers_len + self.payload.len();
         IggyByteSize::from(size as u64)
     }
 }
@@ -129,7 +155,48 @@ where
 {
     fn get_size_bytes(&self) -> IggyByteSize {
         let headers_len = self.headers.as_ref().map(|h| 4 + h.len()).unwrap_or(4);
-        let size = 16 + 8 + 8 + 4 + 1 + headers_len + self.payload.len();
+        let size = 16 + 4 + 4 + 4 + 1 + headers_len + self.payload.len();
         IggyByteSize::from(size as u64)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_message_bytes() -> Bytes {
+        let message = Message::new(Some(1), Bytes::from("test"), None);
+        let message = RetainedMessage::new(10, 1000, message);
+        let mut bytes = BytesMut::new();
+        message.extend(10, 1000, &mut bytes);
+        // Skip the length of the message, which is read by the batch iterator.
+        bytes.freeze().slice(4..)
+    }
+
+    #[test]
+    fn message_should_be_read_relative_to_the_batch_base() {
+        let message = RetainedMessage::try_from_bytes(create_message_bytes(), 10, 1000).unwrap();
+        assert_eq!(message.id, 1);
+        assert_eq!(message.offset, 10);
+        assert_eq!(message.timestamp, 1000);
+        assert_eq!(message.payload, Bytes::from("test"));
+    }
+
+    #[test]
+    fn truncated_message_should_not_be_read() {
+        let bytes = create_message_bytes();
+        for length in [0, 10, RETAINED_MESSAGE_HEADER_SIZE - 1] {
+            assert!(matches!(
+                RetainedMessage::try_from_bytes(bytes.slice(..length), 0, 0),
+                Err(IggyError::TruncatedMessage(_, _))
+            ));
+        }
+
+        let mut bytes = BytesMut::from(&bytes[..RETAINED_MESSAGE_HEADER_SIZE]);
+        bytes[29..33].copy_from_slice(&100u32.to_le_bytes());
+        assert!(matches!(
+            RetainedMessage::try_from_bytes(bytes.freeze(), 0, 0),
+            Err(IggyError::TruncatedMessage(133, 33))
+        ));
+    }
+}
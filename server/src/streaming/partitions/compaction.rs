@@ -0,0 +1,68 @@
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::segments::compaction::CompactedSegment;
+use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+use tracing::trace;
+
+impl Partition {
+    /// Compacts the closed segments of the partition, so that only the latest message for each key (message ID) remains.
+    /// The tombstones are purged once they are older than the provided retention.
+    pub async fn compact(
+        &mut self,
+        tombstone_retention: IggyDuration,
+    ) -> Result<CompactedSegment, IggyError> {
+        if !self.segments.iter().any(|segment| segment.is_closed) {
+            trace!(
+                "No closed segments to compact for partition with ID: {}.",
+                self.partition_id
+            );
+            return Ok(CompactedSegment::default());
+        }
+
+        let latest_offsets = self.get_latest_offsets_by_key().await?;
+        let tombstones_expiry = IggyTimestamp::from(
+            IggyTimestamp::now()
+                .as_micros()
+                .saturating_sub(tombstone_retention.as_micros()),
+        );
+        self.read_ahead.clear();
+        let mut compacted = CompactedSegment::default();
+        for segment in self.segments.iter_mut().filter(|segment| segment.is_closed) {
+            let compacted_segment = segment.compact(&latest_offsets, tombstones_expiry).await?;
+            compacted.removed_messages += compacted_segment.removed_messages;
+            compacted.removed_bytes += compacted_segment.removed_bytes;
+        }
+
+        Ok(compacted)
+    }
+
+    async fn get_latest_offsets_by_key(&self) -> Result<HashMap<u128, u64>, IggyError> {
+        let mut latest_offsets = HashMap::new();
+        for segment in self.segments.iter() {
+            for batch in segment.get_all_batches().await? {
+                for message in batch.into_messages_iter() {
+                    latest_offsets.insert(message.id, message.offset);
+                }
+            }
+
+            if let Some(unsaved_messages) = &segment.unsaved_messages {
+                if unsaved_messages.is_empty() {
+                    continue;
+                }
+
+                let messages = unsaved_messages.get_messages_by_offset(
+                    unsaved_messages.batch_base_offset(),
+                    unsaved_messages.batch_max_offset(),
+                );
+                for message in messages {
+                    latest_offsets.insert(message.id, message.offset);
+                }
+            }
+        }
+
+        Ok(latest_offsets)
+    }
+}
@@ -1,9 +1,20 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, Partition, CONSUMER_OFFSET_RECORD_SIZE,
+};
 use crate::streaming::polling_consumer::PollingConsumer;
 use dashmap::DashMap;
 use iggy::consumer::ConsumerKind;
+use iggy::consumer_offsets::reset_consumer_offsets::{OffsetResetKind, OffsetResetStrategy};
 use iggy::error::IggyError;
-use tracing::trace;
+use iggy::messages::poll_messages::OutOfRangePolicy;
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::models::consumer_lag_info::ConsumerLagInfo;
+use iggy::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
+use iggy::models::consumer_offsets_storage_info::ConsumerOffsetsStorageInfo;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tracing::{info, trace};
 
 impl Partition {
     pub async fn get_consumer_offset(&self, consumer: PollingConsumer) -> Result<u64, IggyError> {
@@ -32,6 +43,74 @@ impl Partition {
         Ok(0)
     }
 
+    pub fn get_consumer_lag(&self, kind: ConsumerKind, consumer_id: u32) -> ConsumerLagInfo {
+        let stored_offset = self
+            .get_consumer_offsets(kind)
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        let lag = match stored_offset {
+            Some(stored_offset) => self.current_offset.saturating_sub(stored_offset),
+            None if self.get_messages_count() == 0 => 0,
+            None => self.current_offset + 1,
+        };
+
+        ConsumerLagInfo {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            stored_offset,
+            lag,
+        }
+    }
+
+    /// Resets the stored offset of the consumer, so that the next polled message is the one resolved by the strategy.
+    /// When the consumption should start from the offset 0, the stored offset is removed.
+    pub async fn reset_consumer_offset(
+        &self,
+        kind: ConsumerKind,
+        consumer_id: u32,
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetResetInfo, IggyError> {
+        let next_offset = match strategy.kind {
+            OffsetResetKind::Earliest => self.get_low_watermark(),
+            OffsetResetKind::Latest => self.get_high_watermark(),
+            OffsetResetKind::Offset => {
+                self.resolve_offset(strategy.value, OutOfRangePolicy::Error)?
+            }
+            OffsetResetKind::Timestamp => self.get_offset_by_timestamp(strategy.value).await?,
+        };
+        let stored_offset = next_offset.checked_sub(1);
+        let previous_offset = self
+            .get_consumer_offsets(kind)
+            .get(&consumer_id)
+            .map(|consumer_offset| consumer_offset.offset);
+        trace!(
+            "Resetting offset: {:?} -> {:?} for {} with ID: {}, partition: {}, dry run: {}...",
+            previous_offset,
+            stored_offset,
+            kind,
+            consumer_id,
+            self.partition_id,
+            dry_run
+        );
+
+        if !dry_run {
+            match stored_offset {
+                Some(offset) => self.store_offset(kind, consumer_id, offset).await?,
+                None => {
+                    self.delete_consumer_offset(kind, consumer_id).await?;
+                }
+            }
+        }
+
+        Ok(ConsumerOffsetResetInfo {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            previous_offset,
+            stored_offset,
+        })
+    }
+
     pub async fn store_consumer_offset(
         &self,
         consumer: PollingConsumer,
@@ -75,6 +154,7 @@ impl Partition {
                 .partition
                 .save_consumer_offset(&consumer_offset)
                 .await?;
+            self.increment_consumer_offsets_records();
             return Ok(());
         }
 
@@ -87,10 +167,179 @@ impl Partition {
             .partition
             .save_consumer_offset(&consumer_offset)
             .await?;
+        self.increment_consumer_offsets_records();
         consumer_offsets.insert(consumer_id, consumer_offset);
         Ok(())
     }
 
+    /// Returns the offset of the first message with the timestamp greater than or equal to the provided one,
+    /// or the high watermark if there is no such message.
+    async fn get_offset_by_timestamp(&self, timestamp: u64) -> Result<u64, IggyError> {
+        let message = self
+            .get_first_message_by_timestamp(timestamp.into())
+            .await?;
+        Ok(message.map_or(self.get_high_watermark(), |message| message.offset))
+    }
+
+    /// Returns the consumers with the stored offset in the partition, ordered by ID, along with their names (if identified by name).
+    pub fn get_consumers(&self) -> Vec<ConsumerInfo> {
+        let mut consumers = self
+            .consumer_offsets
+            .iter()
+            .map(|consumer_offset| ConsumerInfo {
+                id: consumer_offset.consumer_id,
+                name: self
+                    .consumer_names
+                    .get(&consumer_offset.consumer_id)
+                    .map(|name| name.clone()),
+                stored_offset: consumer_offset.offset,
+                lag: self.current_offset.saturating_sub(consumer_offset.offset),
+            })
+            .collect::<Vec<_>>();
+        consumers.sort_by_key(|consumer| consumer.id);
+        consumers
+    }
+
+    /// Stores the name of the consumer identified by name, so that it's known after its ID has been resolved by hashing the name.
+    pub async fn store_consumer_name(&self, consumer_id: u32, name: &str) -> Result<(), IggyError> {
+        if self
+            .consumer_names
+            .get(&consumer_id)
+            .is_some_and(|stored_name| stored_name.as_str() == name)
+        {
+            return Ok(());
+        }
+
+        self.storage
+            .partition
+            .save_consumer_name(&self.consumer_offsets_path, consumer_id, name)
+            .await?;
+        self.consumer_names.insert(consumer_id, name.to_owned());
+        Ok(())
+    }
+
+    /// Deletes the consumer along with its stored offset and name from the partition.
+    pub async fn delete_consumer(&self, consumer_id: u32) -> Result<(), IggyError> {
+        if !self.consumer_offsets.contains_key(&consumer_id) {
+            return Err(IggyError::ConsumerNotFound(consumer_id, self.partition_id));
+        }
+
+        self.delete_consumer_offset(ConsumerKind::Consumer, consumer_id)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes the stored offset (and the name of the regular consumer) from the partition.
+    /// Returns `false` if the consumer had no stored offset in the partition.
+    pub async fn delete_consumer_offset(
+        &self,
+        kind: ConsumerKind,
+        consumer_id: u32,
+    ) -> Result<bool, IggyError> {
+        if kind == ConsumerKind::Consumer {
+            self.consumer_names.remove(&consumer_id);
+        }
+        let Some((_, consumer_offset)) = self.get_consumer_offsets(kind).remove(&consumer_id)
+        else {
+            return Ok(false);
+        };
+
+        trace!(
+            "Deleting offset: {} for {} with ID: {}, partition: {}...",
+            consumer_offset.offset,
+            kind,
+            consumer_id,
+            self.partition_id
+        );
+        self.storage
+            .partition
+            .delete_consumer_offset(&consumer_offset)
+            .await?;
+        self.increment_consumer_offsets_records();
+        Ok(true)
+    }
+
+    pub(crate) fn increment_consumer_offsets_records(&self) {
+        self.consumer_offsets_records_count
+            .fetch_add(1, Ordering::SeqCst);
+        self.consumer_offsets_size_bytes
+            .fetch_add(CONSUMER_OFFSET_RECORD_SIZE, Ordering::SeqCst);
+    }
+
+    /// Returns the number of the persisted consumer offset records, which are no longer the latest value for any consumer.
+    pub fn get_stale_consumer_offsets_count(&self) -> u64 {
+        let live_offsets_count =
+            (self.consumer_offsets.len() + self.consumer_group_offsets.len()) as u64;
+        self.consumer_offsets_records_count
+            .load(Ordering::SeqCst)
+            .saturating_sub(live_offsets_count)
+    }
+
+    /// Rewrites the consumer offsets logs, so that each of them contains a single record with the latest offset per consumer.
+    /// The exclusive access ensures that no offset is stored while the logs are being rewritten.
+    pub async fn compact_consumer_offsets(&mut self) -> Result<(), IggyError> {
+        let stale_offsets_count = self.get_stale_consumer_offsets_count();
+        let mut size_bytes = 0;
+        for kind in [ConsumerKind::Consumer, ConsumerKind::ConsumerGroup] {
+            let path = match kind {
+                ConsumerKind::Consumer => &self.consumer_offsets_path,
+                ConsumerKind::ConsumerGroup => &self.consumer_group_offsets_path,
+            };
+            let mut offsets = self
+                .get_consumer_offsets(kind)
+                .iter()
+                .map(|consumer_offset| consumer_offset.value().clone())
+                .collect::<Vec<_>>();
+            offsets.sort_by_key(|consumer_offset| consumer_offset.consumer_id);
+            let names = match kind {
+                ConsumerKind::Consumer => self
+                    .consumer_names
+                    .iter()
+                    .map(|name| (*name.key(), name.value().clone()))
+                    .collect(),
+                ConsumerKind::ConsumerGroup => HashMap::new(),
+            };
+            size_bytes += self
+                .storage
+                .partition
+                .compact_consumer_offsets(path, &offsets, &names)
+                .await?;
+        }
+
+        self.consumer_offsets_records_count.store(
+            (self.consumer_offsets.len() + self.consumer_group_offsets.len()) as u64,
+            Ordering::SeqCst,
+        );
+        self.consumer_offsets_size_bytes
+            .store(size_bytes, Ordering::SeqCst);
+        self.consumer_offsets_compactions_count
+            .fetch_add(1, Ordering::SeqCst);
+        self.consumer_offsets_compacted_at
+            .store(IggyTimestamp::now().into(), Ordering::SeqCst);
+        info!(
+            "Compacted consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}, removed {} stale records.",
+            self.partition_id, self.topic_id, self.stream_id, stale_offsets_count
+        );
+        Ok(())
+    }
+
+    pub fn get_consumer_offsets_storage_info(&self) -> ConsumerOffsetsStorageInfo {
+        let compacted_at = self.consumer_offsets_compacted_at.load(Ordering::SeqCst);
+        ConsumerOffsetsStorageInfo {
+            consumers_count: self.consumer_offsets.len() as u32,
+            consumer_groups_count: self.consumer_group_offsets.len() as u32,
+            records_count: self.consumer_offsets_records_count.load(Ordering::SeqCst),
+            size: self
+                .consumer_offsets_size_bytes
+                .load(Ordering::SeqCst)
+                .into(),
+            compactions_count: self
+                .consumer_offsets_compactions_count
+                .load(Ordering::SeqCst),
+            last_compacted_at: (compacted_at > 0).then(|| compacted_at.into()),
+        }
+    }
+
     pub async fn load_consumer_offsets(&mut self) -> Result<(), IggyError> {
         trace!(
                 "Loading consumer offsets for partition with ID: {} for topic with ID: {} and stream with ID: {}...",
@@ -117,8 +366,15 @@ impl Partition {
             .partition
             .load_consumer_offsets(kind, path)
             .await?;
+        self.consumer_offsets_records_count
+            .fetch_add(loaded_consumer_offsets.records_count, Ordering::SeqCst);
+        self.consumer_offsets_size_bytes
+            .fetch_add(loaded_consumer_offsets.size_bytes, Ordering::SeqCst);
+        for (consumer_id, name) in loaded_consumer_offsets.names {
+            self.consumer_names.insert(consumer_id, name);
+        }
         let consumer_offsets = self.get_consumer_offsets(kind);
-        for consumer_offset in loaded_consumer_offsets {
+        for consumer_offset in loaded_consumer_offsets.offsets {
             self.log_consumer_offset(&consumer_offset);
             consumer_offsets.insert(consumer_offset.consumer_id, consumer_offset);
         }
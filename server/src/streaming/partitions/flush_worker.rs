@@ -0,0 +1,91 @@
+use crate::streaming::partitions::partition::Partition;
+use anyhow::anyhow;
+use iggy::error::IggyError;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{error, trace};
+
+type FlushAck = oneshot::Sender<Result<(), IggyError>>;
+
+#[derive(Debug)]
+struct FlushRequest {
+    fsync: bool,
+    ack: Option<FlushAck>,
+}
+
+/// Persists the unsaved messages of the partition in the background, so that the append doesn't write to disk
+/// on the caller's path. The requests queued while persisting are coalesced into a single flush.
+/// The worker stops once all the handles are dropped and the queued requests are processed.
+#[derive(Debug, Clone)]
+pub struct PartitionFlushWorker {
+    sender: mpsc::UnboundedSender<FlushRequest>,
+}
+
+impl PartitionFlushWorker {
+    pub fn spawn(partition: IggySharedMut<Partition>) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<FlushRequest>();
+        tokio::spawn(async move {
+            while let Some(request) = receiver.recv().await {
+                let mut fsync = request.fsync;
+                let mut acks = Vec::from_iter(request.ack);
+                while let Ok(request) = receiver.try_recv() {
+                    fsync |= request.fsync;
+                    acks.extend(request.ack);
+                }
+
+                let mut partition = partition.write().await;
+                trace!(
+                    "Flushing unsaved messages of partition with ID: {} for {} waiting append(s)...",
+                    partition.partition_id,
+                    acks.len()
+                );
+                let result = partition.flush_unsaved_buffer(fsync).await;
+                let error = match result {
+                    Ok(()) => None,
+                    Err(error) => {
+                        error!(
+                            "Failed to flush unsaved messages of partition with ID: {}, topic with ID: {}, stream with ID: {}. Error: {error}",
+                            partition.partition_id, partition.topic_id, partition.stream_id
+                        );
+                        // The messages which failed to persist might have been acknowledged before being saved,
+                        // so the partition rejects all the later appends instead of acknowledging them.
+                        partition.mark_failed();
+                        Some(error.to_string())
+                    }
+                };
+                drop(partition);
+
+                for ack in acks {
+                    let result = match &error {
+                        None => Ok(()),
+                        Some(error) => Err(IggyError::CannotSaveMessagesToSegment(anyhow!(
+                            error.clone()
+                        ))),
+                    };
+                    let _ = ack.send(result);
+                }
+            }
+        });
+        Self { sender }
+    }
+
+    /// Requests persisting the unsaved messages without waiting for the result.
+    pub fn request_flush(&self) {
+        let _ = self.sender.send(FlushRequest {
+            fsync: false,
+            ack: None,
+        });
+    }
+
+    /// Requests persisting the unsaved messages and waits until they're persisted, including the optional fsync.
+    pub async fn flush(&self, fsync: bool) -> Result<(), IggyError> {
+        let (ack, receiver) = oneshot::channel();
+        self.sender
+            .send(FlushRequest {
+                fsync,
+                ack: Some(ack),
+            })
+            .map_err(|_| IggyError::CannotAppendMessage)?;
+        receiver.await.map_err(|_| IggyError::CannotAppendMessage)?
+    }
+}
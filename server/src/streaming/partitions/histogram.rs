@@ -0,0 +1,87 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::IggyError;
+use iggy::models::messages_histogram::{MessagesHistogram, MessagesHistogramBucket};
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::sizeable::Sizeable;
+use tracing::trace;
+
+// The length of the message is stored in the batch payload ahead of the message itself.
+const MESSAGE_LENGTH_SIZE: u64 = 4;
+
+impl Partition {
+    /// Aggregates the count and size of the messages per time bucket within the range from the inclusive start
+    /// to the exclusive end, based on the time indexes and the batch headers, thus without reading the messages.
+    /// The persisted batch is accounted as a whole in the bucket of its max timestamp,
+    /// while the messages not yet persisted are accounted one by one.
+    pub async fn get_messages_histogram(
+        &self,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError> {
+        let bucket_size_micros = bucket_size.as_micros();
+        let mut buckets = Vec::new();
+        if bucket_size_micros > 0 {
+            let mut timestamp = from;
+            while timestamp < to {
+                buckets.push(MessagesHistogramBucket {
+                    timestamp,
+                    messages_count: 0,
+                    size: IggyByteSize::from(0),
+                });
+                timestamp = timestamp.saturating_add(bucket_size_micros);
+            }
+        }
+
+        let mut add_to_bucket = |timestamp: u64, messages_count: u64, size: IggyByteSize| {
+            if timestamp < from || timestamp >= to || buckets.is_empty() {
+                return;
+            }
+
+            let bucket = &mut buckets[((timestamp - from) / bucket_size_micros) as usize];
+            bucket.messages_count += messages_count;
+            bucket.size += size;
+        };
+
+        for segment in self.segments.iter() {
+            for batch in segment.get_batches_by_timestamp_range(from, to).await? {
+                add_to_bucket(
+                    batch.max_timestamp,
+                    batch.messages_count as u64,
+                    batch.length,
+                );
+            }
+
+            if let Some(unsaved_messages) = &segment.unsaved_messages {
+                if unsaved_messages.is_empty() {
+                    continue;
+                }
+
+                let messages = unsaved_messages.get_messages_by_offset(
+                    unsaved_messages.batch_base_offset(),
+                    unsaved_messages.batch_max_offset(),
+                );
+                for message in messages {
+                    add_to_bucket(
+                        message.timestamp,
+                        1,
+                        message.get_size_bytes() + MESSAGE_LENGTH_SIZE.into(),
+                    );
+                }
+            }
+        }
+
+        trace!(
+            "Aggregated messages histogram with {} buckets for partition with ID: {}.",
+            buckets.len(),
+            self.partition_id
+        );
+
+        Ok(MessagesHistogram {
+            partition_id: self.partition_id,
+            bucket_size,
+            buckets,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::sync::atomic::Ordering;
+use tracing::info;
+
+/// The value of the transferred leader ID meaning that the partition is led by this node.
+pub const NO_TRANSFERRED_LEADER: u32 = 0;
+
+impl Partition {
+    /// Returns the ID of the node leading the partition, which is this node unless the leadership has been transferred away while draining it.
+    pub fn get_leader_id(&self) -> u32 {
+        match self.transferred_leader_id.load(Ordering::SeqCst) {
+            NO_TRANSFERRED_LEADER => self.config.cluster.node_id,
+            leader_id => leader_id,
+        }
+    }
+
+    pub fn is_led_by_this_node(&self) -> bool {
+        self.transferred_leader_id.load(Ordering::SeqCst) == NO_TRANSFERRED_LEADER
+    }
+
+    /// Rejects the appends and polls once the leadership of the partition has been transferred to another node.
+    pub fn ensure_leader(&self) -> Result<(), IggyError> {
+        if self.is_led_by_this_node() {
+            return Ok(());
+        }
+
+        Err(IggyError::NotLeader(
+            self.config.cluster.node_id,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+        ))
+    }
+
+    /// Transfers the leadership to the in-sync replica which has replicated all the messages of the partition, if there's any,
+    /// so that no acknowledged message is lost. The replica with the lowest ID is chosen if there are many, and its ID is returned.
+    /// The caller must prevent the concurrent appends, e.g. by holding the lock of the partition.
+    pub fn transfer_leadership(&self) -> Option<u32> {
+        if !self.is_led_by_this_node() {
+            return None;
+        }
+
+        let now = IggyTimestamp::now();
+        let replica_lag_timeout = self.config.partition.replica_lag_timeout;
+        let high_watermark = self.get_high_watermark();
+        let leader_id = self
+            .replicas
+            .iter()
+            .filter(|replica| {
+                replica.is_in_sync(now, replica_lag_timeout)
+                    && replica.fetched_offset >= high_watermark
+            })
+            .map(|replica| *replica.key())
+            .min()?;
+        self.transferred_leader_id
+            .store(leader_id, Ordering::SeqCst);
+        info!(
+            "Transferred leadership of partition with ID: {}, topic with ID: {}, stream with ID: {} to replica with ID: {leader_id}.",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+        Some(leader_id)
+    }
+}
@@ -1,28 +1,110 @@
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::batching::iterator::IntoMessagesIterator;
-use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::models::messages::{RetainedMessage, RETAINED_MESSAGE_METADATA};
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::segment::Segment;
+use iggy::messages::poll_messages::OutOfRangePolicy;
 use iggy::messages::send_messages::Message;
-use iggy::models::messages::POLLED_MESSAGE_METADATA;
+use iggy::models::partition_offsets_info::PartitionOffsetsInfo;
+use iggy::models::timestamp_offset_info::TimestampOffsetInfo;
+use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::{get_create_timestamp, TimestampType};
 use iggy::{error::IggyError, utils::duration::IggyDuration};
+use rand::seq::index;
 use std::sync::{atomic::Ordering, Arc};
 use std::time::Duration;
-use tracing::{trace, warn};
+use tracing::{error, trace, warn};
 
 const EMPTY_MESSAGES: Vec<RetainedMessage> = vec![];
+const TIMESTAMP_LOOKUP_COUNT: u32 = 100;
+
+/// Truncates the messages to fit within the maximum size in bytes (`0` means no limit), always keeping at least one message.
+/// Returns `true` if the limit has been reached and no more messages should be read.
+fn truncate_to_max_bytes(messages: &mut Vec<Arc<RetainedMessage>>, max_bytes: u32) -> bool {
+    if max_bytes == 0 {
+        return false;
+    }
+
+    let max_bytes = max_bytes as u64;
+    let mut size_bytes = 0;
+    for (index, message) in messages.iter().enumerate() {
+        size_bytes += message.get_size_bytes().as_bytes_u64();
+        if size_bytes > max_bytes {
+            messages.truncate(index.max(1));
+            return true;
+        }
+    }
+    size_bytes == max_bytes
+}
 
 impl Partition {
     pub fn get_messages_count(&self) -> u64 {
         self.messages_count.load(Ordering::SeqCst)
     }
 
+    /// Returns the first offset still available in the partition, after the retention or purge.
+    pub fn get_low_watermark(&self) -> u64 {
+        self.segments
+            .first()
+            .map_or(0, |segment| segment.start_offset)
+    }
+
+    /// Returns the offset of the next message to be appended to the partition.
+    pub fn get_high_watermark(&self) -> u64 {
+        if self.should_increment_offset {
+            self.current_offset + 1
+        } else {
+            self.current_offset
+        }
+    }
+
+    /// Returns the offset from which the messages should be polled, applying the policy if the requested offset
+    /// is out of the range between the low and high watermarks.
+    pub fn resolve_offset(&self, offset: u64, policy: OutOfRangePolicy) -> Result<u64, IggyError> {
+        let low_watermark = self.get_low_watermark();
+        let high_watermark = self.get_high_watermark();
+        if (low_watermark..=high_watermark).contains(&offset) {
+            return Ok(offset);
+        }
+
+        trace!(
+            "Offset: {} is out of range: [{}, {}] for partition: {}, applying policy: {}...",
+            offset,
+            low_watermark,
+            high_watermark,
+            self.partition_id,
+            policy
+        );
+        match policy {
+            OutOfRangePolicy::Error if offset < low_watermark => Err(
+                IggyError::OffsetBelowLowWatermark(offset, low_watermark, self.partition_id),
+            ),
+            OutOfRangePolicy::Error => Err(IggyError::OffsetAboveHighWatermark(
+                offset,
+                high_watermark,
+                self.partition_id,
+            )),
+            OutOfRangePolicy::Earliest => Ok(low_watermark),
+            OutOfRangePolicy::Latest => Ok(self.current_offset.max(low_watermark)),
+        }
+    }
+
+    pub fn get_offsets_info(&self) -> PartitionOffsetsInfo {
+        PartitionOffsetsInfo {
+            partition_id: self.partition_id,
+            low_watermark: self.get_low_watermark(),
+            high_watermark: self.get_high_watermark(),
+        }
+    }
+
     pub async fn get_messages_by_timestamp(
         &self,
         timestamp: IggyTimestamp,
         count: u32,
+        max_bytes: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         trace!(
             "Getting messages by timestamp: {} for partition: {}...",
@@ -77,28 +159,112 @@ impl Partition {
             start_offset,
             timestamp
         );
-        if found_index.timestamp == timestamp {
-            return Ok(self
-                .get_messages_by_offset(start_offset, count)
-                .await?
-                .into_iter()
-                .filter(|msg| msg.timestamp >= timestamp)
-                .take(count as usize)
-                .collect());
-        }
-
-        let adjusted_count = self.calculate_adjusted_timestamp_message_count(
-            count,
-            timestamp,
-            found_index.timestamp,
-        );
-        Ok(self
-            .get_messages_by_offset(start_offset, adjusted_count)
+        let adjusted_count = if found_index.timestamp == timestamp {
+            count
+        } else {
+            self.calculate_adjusted_timestamp_message_count(count, timestamp, found_index.timestamp)
+        };
+        // The messages preceding the timestamp are filtered out, so the size limit can be applied only afterwards.
+        let mut messages = self
+            .get_messages_by_offset(start_offset, adjusted_count, 0)
             .await?
             .into_iter()
             .filter(|msg| msg.timestamp >= timestamp)
             .take(count as usize)
-            .collect())
+            .collect();
+        truncate_to_max_bytes(&mut messages, max_bytes);
+        Ok(messages)
+    }
+
+    /// Returns the first message with the timestamp greater than or equal to the provided one, if any.
+    pub async fn get_first_message_by_timestamp(
+        &self,
+        timestamp: IggyTimestamp,
+    ) -> Result<Option<Arc<RetainedMessage>>, IggyError> {
+        let messages_count = self.get_messages_count();
+        let mut count = TIMESTAMP_LOOKUP_COUNT;
+        loop {
+            let messages = self.get_messages_by_timestamp(timestamp, count, 0).await?;
+            if let Some(message) = messages.into_iter().next() {
+                return Ok(Some(message));
+            }
+
+            if u64::from(count) >= messages_count {
+                return Ok(None);
+            }
+
+            count = count.saturating_mul(2);
+        }
+    }
+
+    /// Returns the timestamp up to which (inclusive) the messages have expired, if the message expiry is set.
+    fn get_expiry_timestamp(&self, now: IggyTimestamp) -> Option<u64> {
+        match self.message_expiry {
+            IggyExpiry::ExpireDuration(expiry) => {
+                Some(now.as_micros().saturating_sub(expiry.as_micros()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes the expired messages, which are no longer returned by the polls,
+    /// even though they're kept on disk until all the messages of their segment expire.
+    pub fn remove_expired_messages(
+        &self,
+        mut messages: Vec<Arc<RetainedMessage>>,
+    ) -> Vec<Arc<RetainedMessage>> {
+        if let Some(expiry_timestamp) = self.get_expiry_timestamp(IggyTimestamp::now()) {
+            messages.retain(|message| message.timestamp > expiry_timestamp);
+        }
+        messages
+    }
+
+    /// Removes the expired messages, and if the messages polled forward start with the expired ones,
+    /// reads them again from the first message which hasn't expired yet, so that the consumer doesn't get stuck on them.
+    pub async fn skip_expired_messages(
+        &self,
+        messages: Vec<Arc<RetainedMessage>>,
+        count: u32,
+        max_bytes: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        let Some(expiry_timestamp) = self.get_expiry_timestamp(IggyTimestamp::now()) else {
+            return Ok(messages);
+        };
+
+        let Some(first_message) = messages.first() else {
+            return Ok(messages);
+        };
+
+        let mut messages = if first_message.timestamp > expiry_timestamp {
+            messages
+        } else {
+            let start_offset = first_message.offset;
+            match self
+                .get_first_message_by_timestamp((expiry_timestamp + 1).into())
+                .await?
+            {
+                Some(message) if message.offset > start_offset => {
+                    self.get_messages_by_offset(message.offset, count, max_bytes)
+                        .await?
+                }
+                Some(_) => messages,
+                None => EMPTY_MESSAGES.into_iter().map(Arc::new).collect(),
+            }
+        };
+        messages.retain(|message| message.timestamp > expiry_timestamp);
+        Ok(messages)
+    }
+
+    pub async fn get_timestamp_offset_info(
+        &self,
+        timestamp: IggyTimestamp,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError> {
+        let message = self.get_first_message_by_timestamp(timestamp).await?;
+        Ok(message.map(|message| TimestampOffsetInfo {
+            partition_id: self.partition_id,
+            offset: message.offset,
+            timestamp: message.timestamp,
+        }))
     }
 
     fn calculate_adjusted_timestamp_message_count(
@@ -115,13 +281,14 @@ impl Partition {
         let overfetch_value =
             ((timestamp_diff as f64 / self.avg_timestamp_delta.as_micros() as f64) * 1.35).ceil()
                 as u32;
-        count + overfetch_value
+        count.saturating_add(overfetch_value)
     }
 
     pub async fn get_messages_by_offset(
         &self,
         start_offset: u64,
         count: u32,
+        max_bytes: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         trace!(
             "Getting messages for start offset: {} for partition: {}...",
@@ -136,46 +303,342 @@ impl Partition {
             return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
         }
 
-        let end_offset = self.get_end_offset(start_offset, count);
+        let low_watermark = self.get_low_watermark();
+        if start_offset < low_watermark {
+            return Err(IggyError::OffsetBelowLowWatermark(
+                start_offset,
+                low_watermark,
+                self.partition_id,
+            ));
+        }
+
+        let mut end_offset = self.get_end_offset(start_offset, count, max_bytes);
+        let mut range = (end_offset - start_offset + 1) as u32;
+        let mut messages = self
+            .get_messages_in_range(start_offset, end_offset, range)
+            .await?;
+        let mut max_bytes_reached = truncate_to_max_bytes(&mut messages, max_bytes);
+        // Compacted segments might have gaps in offsets and the messages might be smaller than the average,
+        // so keep reading until either the requested count or size is reached,
+        // doubling the range of offsets on each attempt to quickly skip over the larger gaps.
+        while !max_bytes_reached
+            && messages.len() < count as usize
+            && end_offset < self.current_offset
+        {
+            let last_offset = messages
+                .last()
+                .map_or(end_offset, |message| message.offset.max(end_offset));
+            let next_offset = (last_offset + 1).max(self.segments[0].start_offset);
+            let remaining_count = count as usize - messages.len();
+            range = range.saturating_mul(2);
+            end_offset = self.get_end_offset(next_offset, range, 0);
+            messages.extend(
+                self.get_messages_in_range(next_offset, end_offset, range)
+                    .await?
+                    .into_iter()
+                    .take(remaining_count),
+            );
+            max_bytes_reached = truncate_to_max_bytes(&mut messages, max_bytes);
+        }
+
+        Ok(messages)
+    }
 
+    /// Returns up to `count` messages with the offsets lower than or equal to the provided one,
+    /// ordered from the newest to the oldest, e.g. to page back from the head of the partition.
+    pub async fn get_messages_backward(
+        &self,
+        offset: u64,
+        count: u32,
+        max_bytes: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        trace!(
+            "Getting messages backward for end offset: {} for partition: {}...",
+            offset,
+            self.partition_id
+        );
+        let low_watermark = self.get_low_watermark();
+        if self.segments.is_empty() || self.get_messages_count() == 0 || offset < low_watermark {
+            return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
+        }
+
+        let mut end_offset = offset.min(self.current_offset);
+        let mut range = self.get_count_within_max_bytes(count, max_bytes) as u64;
+        let mut messages: Vec<Arc<RetainedMessage>> = Vec::with_capacity(count as usize);
+        // Compacted segments might have gaps in offsets, so keep reading the preceding ranges until either
+        // the requested count or size is reached, doubling the range of offsets on each attempt to quickly skip over the larger gaps.
+        loop {
+            let start_offset = (end_offset + 1).saturating_sub(range).max(low_watermark);
+            let range_messages = self
+                .get_messages_in_range(
+                    start_offset,
+                    end_offset,
+                    (end_offset - start_offset + 1) as u32,
+                )
+                .await?;
+            let remaining_count = count as usize - messages.len();
+            messages.extend(
+                range_messages
+                    .into_iter()
+                    .filter(|message| message.offset <= end_offset)
+                    .rev()
+                    .take(remaining_count),
+            );
+            let max_bytes_reached = truncate_to_max_bytes(&mut messages, max_bytes);
+            if max_bytes_reached
+                || messages.len() >= count as usize
+                || start_offset == low_watermark
+            {
+                return Ok(messages);
+            }
+
+            end_offset = start_offset - 1;
+            range = range.saturating_mul(2);
+        }
+    }
+
+    /// Returns up to `count` messages with the timestamps lower than or equal to the provided one,
+    /// ordered from the newest to the oldest.
+    pub async fn get_messages_backward_by_timestamp(
+        &self,
+        timestamp: IggyTimestamp,
+        count: u32,
+        max_bytes: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        let following_message = self
+            .get_first_message_by_timestamp(timestamp.as_micros().saturating_add(1).into())
+            .await?;
+        let offset = match following_message {
+            Some(message) if message.offset <= self.get_low_watermark() => {
+                return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
+            }
+            Some(message) => message.offset - 1,
+            None => self.current_offset,
+        };
+        self.get_messages_backward(offset, count, max_bytes).await
+    }
+
+    async fn get_messages_in_range(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         let messages = self.try_get_messages_from_cache(start_offset, end_offset);
         if let Some(messages) = messages {
             return Ok(messages);
         }
 
+        let mut messages = Vec::new();
+        let mut start_offset = start_offset;
+        let mut count = count;
+        if let Some((buffered_messages, last_offset)) =
+            self.read_ahead.get(start_offset, end_offset)
+        {
+            if last_offset >= end_offset {
+                return Ok(buffered_messages);
+            }
+
+            messages = buffered_messages;
+            count = count.saturating_sub((last_offset + 1 - start_offset) as u32);
+            start_offset = last_offset + 1;
+        }
+
+        let segments = self.filter_segments_by_offsets(start_offset, end_offset);
+        if segments.first().is_some_and(|segment| segment.is_closed) {
+            self.request_read_ahead(end_offset + 1);
+        }
+        let segments_messages = match segments.len() {
+            0 => EMPTY_MESSAGES.into_iter().map(Arc::new).collect(),
+            1 => segments[0].get_messages(start_offset, count).await?,
+            _ => Self::get_messages_from_segments(segments, start_offset, count).await?,
+        };
+        messages.extend(segments_messages);
+        Ok(messages)
+    }
+
+    /// Requests reading ahead the messages following the ones read from disk, if the offset belongs to a closed segment.
+    fn request_read_ahead(&self, offset: u64) {
+        if self.get_read_ahead_count() == 0 {
+            return;
+        }
+
+        if self.segments.iter().any(|segment| {
+            segment.is_closed && (segment.start_offset..=segment.end_offset).contains(&offset)
+        }) {
+            self.read_ahead.request(offset);
+        }
+    }
+
+    /// Estimates how many messages fit within the configured number of index intervals to be read ahead.
+    fn get_read_ahead_count(&self) -> u32 {
+        let read_ahead_size = self.config.segment.index_interval.as_bytes_u64()
+            * self.config.partition.read_ahead_depth as u64;
+        if read_ahead_size == 0 {
+            return 0;
+        }
+
+        let max_bytes = read_ahead_size.min(u32::MAX as u64) as u32;
+        self.get_count_within_max_bytes(u32::MAX, max_bytes)
+    }
+
+    /// Reads ahead the messages of the closed segments from the offset returned by starting the requested read ahead,
+    /// it's invoked in the background once the poll which requested it has completed.
+    pub async fn read_ahead_messages(&self, start_offset: u64) -> Result<(), IggyError> {
+        let count = self.get_read_ahead_count();
+        let last_closed_offset = self
+            .segments
+            .iter()
+            .filter(|segment| segment.is_closed)
+            .map(|segment| segment.end_offset)
+            .max();
+        let Some(last_closed_offset) = last_closed_offset.filter(|offset| {
+            count > 0 && *offset >= start_offset && start_offset >= self.get_low_watermark()
+        }) else {
+            self.read_ahead.cancel();
+            return Ok(());
+        };
+
+        let end_offset = (start_offset + (count - 1) as u64).min(last_closed_offset);
+        let count = (end_offset - start_offset + 1) as u32;
         let segments = self.filter_segments_by_offsets(start_offset, end_offset);
-        match segments.len() {
+        let messages = match segments.len() {
             0 => Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect()),
             1 => segments[0].get_messages(start_offset, count).await,
             _ => Self::get_messages_from_segments(segments, start_offset, count).await,
+        };
+        match messages {
+            Ok(messages) => {
+                trace!(
+                    "Read ahead {} messages for offsets: [{}, {}] of partition: {}",
+                    messages.len(),
+                    start_offset,
+                    end_offset,
+                    self.partition_id
+                );
+                self.read_ahead.complete(start_offset, end_offset, messages);
+                Ok(())
+            }
+            Err(error) => {
+                self.read_ahead.cancel();
+                Err(error)
+            }
         }
     }
 
     pub async fn get_first_messages(
         &self,
         count: u32,
+        max_bytes: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
-        self.get_messages_by_offset(0, count).await
+        self.get_messages_by_offset(self.get_low_watermark(), count, max_bytes)
+            .await
     }
 
     pub async fn get_last_messages(
         &self,
         count: u32,
+        max_bytes: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         let mut count = count as u64;
         if count > self.current_offset + 1 {
             count = self.current_offset + 1
         }
 
-        let start_offset = 1 + self.current_offset - count;
-        self.get_messages_by_offset(start_offset, count as u32)
+        let start_offset = (1 + self.current_offset - count).max(self.get_low_watermark());
+        self.get_messages_by_offset(start_offset, count as u32, max_bytes)
             .await
     }
 
+    /// Returns the messages at random offsets between the low watermark and the current offset, ordered by the offset.
+    /// For the compacted partitions, the message following the gap is returned instead of the removed one.
+    pub async fn get_random_messages(
+        &self,
+        count: u32,
+    ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
+        if self.segments.is_empty() || self.get_messages_count() == 0 {
+            return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
+        }
+
+        let low_watermark = self.get_low_watermark();
+        let offsets_count = self.current_offset - low_watermark + 1;
+        if offsets_count <= count as u64 {
+            return self.get_messages_by_offset(low_watermark, count, 0).await;
+        }
+
+        let mut offsets = index::sample(
+            &mut rand::thread_rng(),
+            offsets_count as usize,
+            count as usize,
+        )
+        .into_iter()
+        .map(|index| low_watermark + index as u64)
+        .collect::<Vec<_>>();
+        offsets.sort_unstable();
+        let mut messages: Vec<Arc<RetainedMessage>> = Vec::with_capacity(offsets.len());
+        for offset in offsets {
+            if messages
+                .last()
+                .is_some_and(|message| message.offset >= offset)
+            {
+                continue;
+            }
+
+            messages.extend(self.get_messages_by_offset(offset, 1, 0).await?);
+        }
+        Ok(messages)
+    }
+
+    /// Returns the latest message with the given key (message ID), looking through the segments from the newest one,
+    /// and skipping the closed segments whose key filters rule the key out. The latest tombstone means there's no message for the key.
+    pub async fn get_message_by_key(
+        &mut self,
+        key: u128,
+    ) -> Result<Option<Arc<RetainedMessage>>, IggyError> {
+        for segment in self.segments.iter_mut().rev() {
+            if let Some(unsaved_messages) = &segment.unsaved_messages {
+                if !unsaved_messages.is_empty() {
+                    let message = unsaved_messages
+                        .get_messages_by_offset(
+                            unsaved_messages.batch_base_offset(),
+                            unsaved_messages.batch_max_offset(),
+                        )
+                        .into_iter()
+                        .rfind(|message| message.id == key);
+                    if let Some(message) = message {
+                        return Ok((!message.payload.is_empty()).then_some(message));
+                    }
+                }
+            }
+
+            segment.ensure_key_filter().await?;
+            if !segment.might_contain_key(key) {
+                continue;
+            }
+
+            let mut latest_message = None;
+            for batch in segment.get_all_batches().await? {
+                for message in batch.into_messages_iter() {
+                    if message.id == key {
+                        latest_message = Some(message);
+                    }
+                }
+            }
+
+            if let Some(message) = latest_message {
+                return Ok((!message.payload.is_empty()).then(|| Arc::new(message)));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub async fn get_next_messages(
         &self,
         consumer: PollingConsumer,
+        out_of_range: OutOfRangePolicy,
         count: u32,
+        max_bytes: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
         let (consumer_offsets, consumer_id) = match consumer {
             PollingConsumer::Consumer(consumer_id, _) => (&self.consumer_offsets, consumer_id),
@@ -189,7 +652,7 @@ impl Partition {
                 consumer_id,
                 self.partition_id
             );
-            return self.get_first_messages(count).await;
+            return self.get_first_messages(count, max_bytes).await;
         }
 
         let consumer_offset = consumer_offset.unwrap();
@@ -203,7 +666,7 @@ impl Partition {
             return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
         }
 
-        let offset = consumer_offset.offset + 1;
+        let offset = self.resolve_offset(consumer_offset.offset + 1, out_of_range)?;
         trace!(
             "Getting next messages for {} for partition: {} from offset: {}...",
             consumer_id,
@@ -211,10 +674,11 @@ impl Partition {
             offset
         );
 
-        self.get_messages_by_offset(offset, count).await
+        self.get_messages_by_offset(offset, count, max_bytes).await
     }
 
-    fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
+    fn get_end_offset(&self, offset: u64, count: u32, max_bytes: u32) -> u64 {
+        let count = self.get_count_within_max_bytes(count, max_bytes);
         let mut end_offset = offset + (count - 1) as u64;
         let segment = self.segments.last().unwrap();
         let max_offset = segment.current_offset;
@@ -225,6 +689,19 @@ impl Partition {
         end_offset
     }
 
+    /// Estimates how many messages fit within the maximum size in bytes (`0` means no limit)
+    /// based on the average size of the messages in the partition.
+    fn get_count_within_max_bytes(&self, count: u32, max_bytes: u32) -> u32 {
+        let messages_count = self.get_messages_count();
+        if max_bytes == 0 || messages_count == 0 {
+            return count;
+        }
+
+        let avg_message_size = (self.size_bytes.load(Ordering::SeqCst) / messages_count).max(1);
+        let estimated_count = (max_bytes as u64).div_ceil(avg_message_size).max(1);
+        count.min(estimated_count as u32)
+    }
+
     fn filter_segments_by_offsets(&self, start_offset: u64, end_offset: u64) -> Vec<&Segment> {
         let slice_start = self
             .segments
@@ -243,9 +720,14 @@ impl Partition {
         offset: u64,
         count: u32,
     ) -> Result<Vec<Arc<RetainedMessage>>, IggyError> {
-        let mut messages = Vec::with_capacity(segments.len());
+        let mut messages = Vec::with_capacity(count as usize);
         for segment in segments {
-            let segment_messages = segment.get_messages(offset, count).await?;
+            let remaining_count = count - messages.len() as u32;
+            if remaining_count == 0 {
+                break;
+            }
+
+            let segment_messages = segment.get_messages(offset, remaining_count).await?;
             for message in segment_messages {
                 messages.push(message);
             }
@@ -254,13 +736,25 @@ impl Partition {
         Ok(messages)
     }
 
+    /// Returns true if the message with the given offset can be read from the cache instead of the segments on disk.
+    pub fn is_offset_cached(&self, offset: u64) -> bool {
+        self.cache
+            .as_ref()
+            .is_some_and(|cache| !cache.is_empty() && offset >= cache[0].offset)
+    }
+
     fn try_get_messages_from_cache(
         &self,
         start_offset: u64,
         end_offset: u64,
     ) -> Option<Vec<Arc<RetainedMessage>>> {
         let cache = self.cache.as_ref()?;
-        if cache.is_empty() || start_offset > end_offset || end_offset > self.current_offset {
+        if start_offset > end_offset || end_offset > self.current_offset {
+            return None;
+        }
+
+        if cache.is_empty() {
+            cache.record_miss();
             return None;
         }
 
@@ -272,8 +766,11 @@ impl Partition {
         );
 
         if start_offset >= first_buffered_offset {
+            cache.record_hit();
             return Some(self.load_messages_from_cache(start_offset, end_offset));
         }
+
+        cache.record_miss();
         None
     }
 
@@ -374,13 +871,87 @@ impl Partition {
         messages
     }
 
+    /// Appends the messages and persists them right away, if the number of the unsaved messages reached the threshold.
     pub async fn append_messages(
         &mut self,
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
     ) -> Result<(), IggyError> {
+        if !self
+            .append_messages_to_buffer(appendable_batch_info, messages)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        trace!(
+            "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
+            last_segment.start_offset,
+            self.partition_id
+        );
+        last_segment.persist_messages().await?;
+        self.unsaved_messages_count = 0;
+        Ok(())
+    }
+
+    /// Puts the partition in the failed state after its unsaved messages couldn't be persisted,
+    /// so that no more messages are acknowledged on top of the ones which might have been lost.
+    pub fn mark_failed(&mut self) {
+        if self.is_failed {
+            return;
+        }
+
+        self.is_failed = true;
+        error!(
+            "Partition with ID: {} for topic with ID: {} and stream with ID: {} has failed, the appends will be rejected until the server is restarted.",
+            self.partition_id, self.topic_id, self.stream_id
+        );
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.is_failed
+    }
+
+    /// Rejects the appends once the partition has failed to persist its messages.
+    pub fn ensure_not_failed(&self) -> Result<(), IggyError> {
+        if !self.is_failed {
+            return Ok(());
+        }
+
+        Err(IggyError::PartitionFailed(
+            self.partition_id,
+            self.topic_id,
+            self.stream_id,
+        ))
+    }
+
+    /// Appends the messages to the unsaved buffer of the last segment without persisting them,
+    /// returns `true` if the number of the unsaved messages reached the threshold or the segment is full,
+    /// so that the messages should be persisted, e.g. by the flush worker.
+    pub async fn append_messages_to_buffer(
+        &mut self,
+        appendable_batch_info: AppendableBatchInfo,
+        messages: Vec<Message>,
+    ) -> Result<bool, IggyError> {
         {
             let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+            // The rollover is decided here, while holding the partition lock, rather than by the flush worker,
+            // so that the appends never go to the full segment, and it's closed only once all of its messages are saved.
+            if !last_segment.is_closed && last_segment.is_full().await {
+                trace!(
+                    "Current segment with start offset: {} for partition with ID: {} is full, persisting {} unsaved messages and closing it...",
+                    last_segment.start_offset, self.partition_id, self.unsaved_messages_count
+                );
+                while last_segment.unsaved_messages.is_some() {
+                    last_segment.persist_messages().await?;
+                }
+                self.unsaved_messages_count = 0;
+                if !last_segment.is_closed {
+                    last_segment.close();
+                }
+            }
+
             if last_segment.is_closed {
                 let start_offset = last_segment.end_offset + 1;
                 trace!(
@@ -392,7 +963,7 @@ impl Partition {
         }
 
         let batch_size = appendable_batch_info.batch_size
-            + ((POLLED_MESSAGE_METADATA * messages.len() as u32) as u64).into();
+            + ((RETAINED_MESSAGE_METADATA * messages.len() as u32) as u64).into();
         let base_offset = if !self.should_increment_offset {
             0
         } else {
@@ -400,13 +971,18 @@ impl Partition {
         };
 
         let mut messages_count = 0u32;
-        // assume that messages have monotonic timestamps
         let mut max_timestamp = 0;
-        let mut min_timestamp = 0;
+        let mut min_timestamp = u64::MAX;
+        let timestamp_type = self.config.partition.message_timestamp_type;
+        // The append time must not go backwards, even if the clock does (e.g. after the NTP correction).
+        let mut last_append_timestamp = self
+            .segments
+            .last()
+            .map_or(0, |segment| segment.get_max_timestamp());
 
         let mut retained_messages = Vec::with_capacity(messages.len());
-        if let Some(message_deduplicator) = &self.message_deduplicator {
-            for message in messages {
+        for message in messages {
+            if let Some(message_deduplicator) = &self.message_deduplicator {
                 if !message_deduplicator.try_insert(&message.id).await {
                     warn!(
                         "Ignored the duplicated message ID: {} for partition with ID: {}.",
@@ -414,33 +990,28 @@ impl Partition {
                     );
                     continue;
                 }
-                max_timestamp = IggyTimestamp::now().as_micros();
-
-                if messages_count == 0 {
-                    min_timestamp = max_timestamp;
-                }
-                let message_offset = base_offset + messages_count as u64;
-                let message =
-                    Arc::new(RetainedMessage::new(message_offset, max_timestamp, message));
-                retained_messages.push(message.clone());
-                messages_count += 1;
             }
-        } else {
-            for message in messages {
-                max_timestamp = IggyTimestamp::now().as_micros();
 
-                if messages_count == 0 {
-                    min_timestamp = max_timestamp;
+            let append_timestamp = IggyTimestamp::now().as_micros();
+            // The create time provided by the producer may be out of order, thus the batch is split if it goes backwards.
+            let timestamp = match timestamp_type {
+                TimestampType::CreateTime => {
+                    get_create_timestamp(&message.headers)?.unwrap_or(append_timestamp)
                 }
-                let message_offset = base_offset + messages_count as u64;
-                let message =
-                    Arc::new(RetainedMessage::new(message_offset, max_timestamp, message));
-                retained_messages.push(message.clone());
-                messages_count += 1;
-            }
+                TimestampType::LogAppendTime => {
+                    last_append_timestamp = append_timestamp.max(last_append_timestamp);
+                    last_append_timestamp
+                }
+            };
+            max_timestamp = max_timestamp.max(timestamp);
+            min_timestamp = min_timestamp.min(timestamp);
+            let message_offset = base_offset + messages_count as u64;
+            let message = Arc::new(RetainedMessage::new(message_offset, timestamp, message));
+            retained_messages.push(message.clone());
+            messages_count += 1;
         }
         if messages_count == 0 {
-            return Ok(());
+            return Ok(false);
         }
 
         let avg_timestamp_delta =
@@ -466,28 +1037,23 @@ impl Partition {
                 .await?;
         }
 
+        self.appends_broadcaster.publish(
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            &retained_messages,
+        );
         if let Some(cache) = &mut self.cache {
             cache.extend(retained_messages);
         }
+        self.notify_messages_subscribers();
 
         self.unsaved_messages_count += messages_count;
-        {
-            let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
-            if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
-                || last_segment.is_full().await
-            {
-                trace!(
-                    "Segment with start offset: {} for partition with ID: {} will be persisted on disk...",
-                    last_segment.start_offset,
-                    self.partition_id
-                );
-
-                last_segment.persist_messages().await.unwrap();
-                self.unsaved_messages_count = 0;
-            }
-        }
-
-        Ok(())
+        let last_segment = self.segments.last().ok_or(IggyError::SegmentNotFound)?;
+        Ok(
+            self.unsaved_messages_count >= self.config.partition.messages_required_to_save
+                || last_segment.is_full().await,
+        )
     }
 
     pub async fn flush_unsaved_buffer(&mut self, fsync: bool) -> Result<(), IggyError> {
@@ -506,7 +1072,7 @@ impl Partition {
         // Make sure all of the messages from the accumulator are persisted
         // no leftover from one round trip.
         while last_segment.unsaved_messages.is_some() {
-            last_segment.persist_messages().await.unwrap();
+            last_segment.persist_messages().await?;
         }
         self.unsaved_messages_count = 0;
         Ok(())
@@ -541,6 +1107,8 @@ mod tests {
 
     use super::*;
     use crate::configs::system::{MessageDeduplicationConfig, SystemConfig};
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
     use crate::streaming::partitions::create_messages;
     use crate::streaming::storage::tests::get_test_system_storage;
 
@@ -562,7 +1130,7 @@ mod tests {
             .unwrap();
 
         let loaded_messages = partition
-            .get_messages_by_offset(0, messages_count)
+            .get_messages_by_offset(0, messages_count, 0)
             .await
             .unwrap();
         assert_eq!(loaded_messages.len(), messages_count as usize);
@@ -587,14 +1155,63 @@ mod tests {
             .unwrap();
 
         let loaded_messages = partition
-            .get_messages_by_offset(0, messages_count)
+            .get_messages_by_offset(0, messages_count, 0)
             .await
             .unwrap();
         assert_eq!(loaded_messages.len(), unique_messages_count);
     }
 
+    #[tokio::test]
+    async fn given_expired_messages_only_the_unexpired_ones_should_be_polled() {
+        let mut partition = create_partition(false);
+        append_messages(&mut partition).await;
+        partition.message_expiry =
+            IggyExpiry::ExpireDuration(IggyDuration::from(Duration::from_millis(100)));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        append_messages(&mut partition).await;
+        let messages_count = create_messages().len() as u32;
+
+        let polled_messages = partition
+            .get_messages_by_offset(0, messages_count, 0)
+            .await
+            .unwrap();
+        let polled_messages = partition
+            .skip_expired_messages(polled_messages, messages_count, 0)
+            .await
+            .unwrap();
+        assert_eq!(polled_messages.len(), messages_count as usize);
+        assert_eq!(polled_messages[0].offset, messages_count as u64);
+
+        let last_messages = partition
+            .get_last_messages(2 * messages_count, 0)
+            .await
+            .unwrap();
+        let last_messages = partition.remove_expired_messages(last_messages);
+        assert_eq!(last_messages.len(), messages_count as usize);
+        assert!(last_messages
+            .iter()
+            .all(|message| message.offset >= messages_count as u64));
+    }
+
+    async fn append_messages(partition: &mut Partition) {
+        let messages = create_messages();
+        let appendable_batch_info = AppendableBatchInfo {
+            batch_size: messages
+                .iter()
+                .map(|m| m.get_size_bytes())
+                .sum::<IggyByteSize>(),
+            partition_id: partition.partition_id,
+        };
+        partition
+            .append_messages(appendable_batch_info, messages)
+            .await
+            .unwrap();
+    }
+
     fn create_partition(deduplication_enabled: bool) -> Partition {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -613,6 +1230,8 @@ mod tests {
             with_segment,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
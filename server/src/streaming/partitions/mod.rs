@@ -1,10 +1,18 @@
 use bytes::Bytes;
 use iggy::messages::send_messages;
 
+pub mod compaction;
 pub mod consumer_offsets;
+pub mod flush_worker;
+pub mod histogram;
+pub mod leadership;
 pub mod messages;
+pub mod notifier;
 pub mod partition;
 pub mod persistence;
+pub mod read_ahead;
+pub mod reencryption;
+pub mod replication;
 pub mod segments;
 pub mod storage;
 
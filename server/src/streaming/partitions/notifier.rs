@@ -0,0 +1,96 @@
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Notifies the subscribers whenever new messages are appended to the partition, so that the features waiting
+/// for the new messages (e.g. long polling or replication) don't need to poll the partition on their own.
+/// The notified value is the high watermark of the partition, the offset of the next message to be appended.
+#[derive(Debug)]
+pub struct MessagesNotifier {
+    sender: watch::Sender<u64>,
+}
+
+/// Receives the high watermark of the partition on every append, can be awaited without holding the partition lock.
+#[derive(Debug, Clone)]
+pub struct MessagesSubscriber {
+    receiver: watch::Receiver<u64>,
+}
+
+impl MessagesNotifier {
+    pub fn new(high_watermark: u64) -> Self {
+        Self {
+            sender: watch::Sender::new(high_watermark),
+        }
+    }
+
+    pub fn notify(&self, high_watermark: u64) {
+        self.sender.send_replace(high_watermark);
+    }
+
+    pub fn subscribe(&self) -> MessagesSubscriber {
+        MessagesSubscriber {
+            receiver: self.sender.subscribe(),
+        }
+    }
+
+    pub fn subscribers_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+impl MessagesSubscriber {
+    pub fn high_watermark(&self) -> u64 {
+        *self.receiver.borrow()
+    }
+
+    /// Waits until the message with the given offset is appended or the timeout elapses,
+    /// returns `true` if the message is available.
+    pub async fn wait_for_offset(&mut self, offset: u64, timeout: Duration) -> bool {
+        let wait = self
+            .receiver
+            .wait_for(|high_watermark| *high_watermark > offset);
+        matches!(tokio::time::timeout(timeout, wait).await, Ok(Ok(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn should_wake_subscriber_when_messages_are_appended() {
+        let notifier = MessagesNotifier::new(0);
+        let mut subscriber = notifier.subscribe();
+        assert_eq!(notifier.subscribers_count(), 1);
+
+        let waiter = tokio::spawn(async move {
+            let available = subscriber.wait_for_offset(2, Duration::from_secs(5)).await;
+            (available, subscriber.high_watermark())
+        });
+        notifier.notify(1);
+        notifier.notify(3);
+
+        let (available, high_watermark) = waiter.await.unwrap();
+        assert!(available);
+        assert_eq!(high_watermark, 3);
+    }
+
+    #[tokio::test]
+    async fn should_return_immediately_when_message_is_already_available() {
+        let notifier = MessagesNotifier::new(5);
+        let mut subscriber = notifier.subscribe();
+
+        assert!(subscriber.wait_for_offset(4, Duration::ZERO).await);
+    }
+
+    #[tokio::test]
+    async fn should_time_out_when_message_is_not_appended() {
+        let notifier = MessagesNotifier::new(5);
+        let mut subscriber = notifier.subscribe();
+
+        assert!(
+            !subscriber
+                .wait_for_offset(5, Duration::from_millis(10))
+                .await
+        );
+    }
+}
@@ -1,8 +1,15 @@
+use crate::configs::resource_quota::MemoryResourceQuota;
 use crate::configs::system::SystemConfig;
+use crate::streaming::appends::AppendsBroadcaster;
 use crate::streaming::cache::buffer::SmartCache;
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::deduplication::message_deduplicator::MessageDeduplicator;
+use crate::streaming::events::EventsBus;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::leadership::NO_TRANSFERRED_LEADER;
+use crate::streaming::partitions::notifier::{MessagesNotifier, MessagesSubscriber};
+use crate::streaming::partitions::read_ahead::ReadAheadBuffer;
+use crate::streaming::partitions::replication::ReplicaState;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::SystemStorage;
 use dashmap::DashMap;
@@ -12,6 +19,7 @@ use iggy::utils::duration::IggyDuration;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
@@ -25,6 +33,8 @@ pub struct Partition {
     pub consumer_offsets_path: String,
     pub consumer_group_offsets_path: String,
     pub current_offset: u64,
+    /// Incremented whenever the log of the partition is rewritten (e.g. purged), returned along with the polled messages.
+    pub epoch: u32,
     pub cache: Option<SmartCache<Arc<RetainedMessage>>>,
     pub cached_memory_tracker: Option<Arc<CacheMemoryTracker>>,
     pub message_deduplicator: Option<MessageDeduplicator>,
@@ -39,14 +49,40 @@ pub struct Partition {
     pub size_of_parent_topic: Arc<AtomicU64>,
     pub size_bytes: Arc<AtomicU64>,
     pub segments_count_of_parent_stream: Arc<AtomicU32>,
+    pub(crate) consumer_offsets_records_count: AtomicU64,
+    pub(crate) consumer_offsets_size_bytes: AtomicU64,
+    pub(crate) consumer_offsets_compactions_count: AtomicU64,
+    pub(crate) consumer_offsets_compacted_at: AtomicU64,
     pub(crate) message_expiry: IggyExpiry,
     pub(crate) consumer_offsets: DashMap<u32, ConsumerOffset>,
     pub(crate) consumer_group_offsets: DashMap<u32, ConsumerOffset>,
+    pub(crate) consumer_names: DashMap<u32, String>,
+    pub(crate) replicas: DashMap<u32, ReplicaState>,
+    /// Notifies the producers awaiting the acknowledgement of all the in-sync replicas about the visible high watermark.
+    pub(crate) replication_notifier: MessagesNotifier,
+    /// The ID of the node the leadership has been transferred to while draining this node, kept only in memory.
+    pub(crate) transferred_leader_id: AtomicU32,
+    /// Set once the flush worker failed to persist the messages, which might have been already acknowledged, kept only in memory.
+    pub(crate) is_failed: bool,
     pub(crate) segments: Vec<Segment>,
+    pub(crate) messages_notifier: MessagesNotifier,
+    pub read_ahead: ReadAheadBuffer,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) appends_broadcaster: Arc<AppendsBroadcaster>,
+    pub(crate) events_bus: Arc<EventsBus>,
 }
 
+/// The name of the append-only log in the consumer (group) offsets directory of the partition,
+/// storing a record with the consumer ID (u32) and the offset (u64) for each commit.
+pub const CONSUMER_OFFSETS_LOG: &str = "offsets.log";
+pub const CONSUMER_OFFSET_RECORD_SIZE: u64 = 12;
+/// The offset value of the record marking the consumer offset as deleted.
+pub const CONSUMER_OFFSET_TOMBSTONE: u64 = u64::MAX;
+/// The name of the append-only log in the consumer offsets directory of the partition,
+/// storing a record with the consumer ID (u32), the name length (u8) and the name for each named consumer.
+pub const CONSUMER_NAMES_LOG: &str = "names.log";
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ConsumerOffset {
     pub kind: ConsumerKind,
@@ -61,11 +97,21 @@ impl ConsumerOffset {
             kind,
             consumer_id,
             offset,
-            path: format!("{path}/{consumer_id}"),
+            path: format!("{path}/{CONSUMER_OFFSETS_LOG}"),
         }
     }
 }
 
+/// The consumer offsets loaded from the storage, with the latest value per consumer and the names of the named consumers,
+/// along with the number and the size of the persisted records (including the stale ones).
+#[derive(Debug, Default)]
+pub struct LoadedConsumerOffsets {
+    pub offsets: Vec<ConsumerOffset>,
+    pub names: HashMap<u32, String>,
+    pub records_count: u64,
+    pub size_bytes: u64,
+}
+
 impl Partition {
     #[allow(clippy::too_many_arguments)]
     pub fn create(
@@ -75,6 +121,8 @@ impl Partition {
         with_segment: bool,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        appends_broadcaster: Arc<AppendsBroadcaster>,
+        events_bus: Arc<EventsBus>,
         message_expiry: IggyExpiry,
         messages_count_of_parent_stream: Arc<AtomicU64>,
         messages_count_of_parent_topic: Arc<AtomicU64>,
@@ -91,10 +139,15 @@ impl Partition {
             config.get_consumer_group_offsets_path(stream_id, topic_id, partition_id);
         let (cached_memory_tracker, messages) = match config.cache.enabled {
             false => (None, None),
-            true => (
-                CacheMemoryTracker::initialize(&config.cache),
-                Some(SmartCache::new()),
-            ),
+            true => {
+                let memory_tracker = CacheMemoryTracker::initialize(&config.cache);
+                let cache = memory_tracker.clone().map(|memory_tracker| {
+                    let mut cache = SmartCache::new(memory_tracker);
+                    cache.set_limit(Partition::get_cache_limit(&config));
+                    cache
+                });
+                (memory_tracker, cache)
+            }
         };
 
         let mut partition = Partition {
@@ -126,13 +179,27 @@ impl Partition {
                 false => None,
             },
             segments: vec![],
+            messages_notifier: MessagesNotifier::new(0),
+            read_ahead: ReadAheadBuffer::default(),
             current_offset: 0,
+            epoch: 0,
             unsaved_messages_count: 0,
             should_increment_offset: false,
             consumer_offsets: DashMap::new(),
             consumer_group_offsets: DashMap::new(),
+            consumer_names: DashMap::new(),
+            replicas: DashMap::new(),
+            replication_notifier: MessagesNotifier::new(0),
+            transferred_leader_id: AtomicU32::new(NO_TRANSFERRED_LEADER),
+            is_failed: false,
+            consumer_offsets_records_count: AtomicU64::new(0),
+            consumer_offsets_size_bytes: AtomicU64::new(0),
+            consumer_offsets_compactions_count: AtomicU64::new(0),
+            consumer_offsets_compacted_at: AtomicU64::new(0),
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             created_at,
             avg_timestamp_delta: IggyDuration::default(),
             size_of_parent_stream,
@@ -152,6 +219,7 @@ impl Partition {
                 0,
                 partition.config.clone(),
                 partition.storage.clone(),
+                partition.events_bus.clone(),
                 partition.message_expiry,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
@@ -168,6 +236,41 @@ impl Partition {
 
         partition
     }
+
+    /// Replaces the config of the partition and its segments, e.g. once the overrides of the topic are updated.
+    pub fn set_config(&mut self, config: Arc<SystemConfig>) {
+        if let Some(cache) = self.cache.as_mut() {
+            cache.set_limit(Partition::get_cache_limit(&config));
+        }
+        for segment in self.segments.iter_mut() {
+            if !segment.is_closed {
+                segment.max_size_bytes = config.segment.size;
+            }
+            segment.config = config.clone();
+        }
+        self.config = config;
+    }
+
+    fn get_cache_limit(config: &SystemConfig) -> Option<IggyByteSize> {
+        match config.cache.size {
+            MemoryResourceQuota::Bytes(size) => Some(size),
+            MemoryResourceQuota::Percentage(_) => None,
+        }
+    }
+
+    /// Subscribes to the notifications about the new messages appended to the partition.
+    pub fn subscribe_to_messages(&self) -> MessagesSubscriber {
+        self.messages_notifier.subscribe()
+    }
+
+    /// Subscribes to the notifications about the visible high watermark advanced by the in-sync replicas.
+    pub fn subscribe_to_replication(&self) -> MessagesSubscriber {
+        self.replication_notifier.subscribe()
+    }
+
+    pub(crate) fn notify_messages_subscribers(&self) {
+        self.messages_notifier.notify(self.get_high_watermark());
+    }
 }
 
 impl Sizeable for Partition {
@@ -179,6 +282,8 @@ impl Sizeable for Partition {
 #[cfg(test)]
 mod tests {
     use crate::configs::system::{CacheConfig, SystemConfig};
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::storage::tests::get_test_system_storage;
     use iggy::utils::duration::IggyDuration;
@@ -190,6 +295,8 @@ mod tests {
     #[test]
     fn should_be_created_with_a_single_segment_given_valid_parameters() {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -204,6 +311,8 @@ mod tests {
             with_segment,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             message_expiry,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -231,6 +340,8 @@ mod tests {
     #[test]
     fn should_not_initialize_cache_given_zero_capacity() {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let partition = Partition::create(
             1,
             1,
@@ -244,6 +355,8 @@ mod tests {
                 ..Default::default()
             }),
             storage,
+            appends_broadcaster,
+            events_bus,
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -258,6 +371,8 @@ mod tests {
     #[test]
     fn should_not_initialize_segments_given_false_with_segment_parameter() {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let topic_id = 1;
         let partition = Partition::create(
             1,
@@ -266,6 +381,8 @@ mod tests {
             false,
             Arc::new(SystemConfig::default()),
             storage,
+            appends_broadcaster,
+            events_bus,
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -1,6 +1,7 @@
 use crate::state::system::PartitionState;
 use crate::streaming::partitions::partition::Partition;
 use iggy::error::IggyError;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use tokio::fs::create_dir;
@@ -27,13 +28,18 @@ impl Partition {
 
     pub async fn purge(&mut self) -> Result<(), IggyError> {
         self.current_offset = 0;
+        self.epoch += 1;
         self.unsaved_messages_count = 0;
         self.should_increment_offset = false;
         self.consumer_offsets.clear();
         self.consumer_group_offsets.clear();
+        self.consumer_names.clear();
+        self.replicas.clear();
         if let Some(cache) = self.cache.as_mut() {
             cache.purge();
         }
+        self.read_ahead.clear();
+        self.notify_messages_subscribers();
         for segment in &self.segments {
             self.storage.segment.delete(segment).await?;
             self.segments_count_of_parent_stream
@@ -74,6 +80,17 @@ impl Partition {
             ));
         }
 
+        self.storage
+            .partition
+            .compact_consumer_offsets(&self.consumer_offsets_path, &[], &HashMap::new())
+            .await?;
+        self.storage
+            .partition
+            .compact_consumer_offsets(&self.consumer_group_offsets_path, &[], &HashMap::new())
+            .await?;
+        self.consumer_offsets_records_count
+            .store(0, Ordering::SeqCst);
+        self.consumer_offsets_size_bytes.store(0, Ordering::SeqCst);
         Ok(())
     }
 }
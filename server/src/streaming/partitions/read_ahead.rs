@@ -0,0 +1,186 @@
+use crate::streaming::models::messages::RetainedMessage;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// The messages of the closed segments read ahead of the consumer polling them from disk.
+/// It holds a single range of offsets per partition, replaced by every read ahead.
+#[derive(Debug, Default)]
+pub struct ReadAheadBuffer {
+    range: RwLock<Option<ReadAheadRange>>,
+    state: Mutex<ReadAheadState>,
+}
+
+#[derive(Debug)]
+struct ReadAheadRange {
+    start_offset: u64,
+    end_offset: u64,
+    messages: Vec<Arc<RetainedMessage>>,
+}
+
+#[derive(Debug, Default)]
+struct ReadAheadState {
+    requested_offset: Option<u64>,
+    in_progress: bool,
+}
+
+impl ReadAheadBuffer {
+    /// Returns the buffered messages from the start offset up to the end offset, along with the last offset
+    /// covered by the buffer, which might be lower than the end offset, if the buffer contains the start offset.
+    pub fn get(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+    ) -> Option<(Vec<Arc<RetainedMessage>>, u64)> {
+        let range = self.range.read().unwrap();
+        let range = range.as_ref()?;
+        if start_offset < range.start_offset || start_offset > range.end_offset {
+            return None;
+        }
+
+        let last_offset = end_offset.min(range.end_offset);
+        let messages = range
+            .messages
+            .iter()
+            .skip_while(|message| message.offset < start_offset)
+            .take_while(|message| message.offset <= last_offset)
+            .cloned()
+            .collect();
+        Some((messages, last_offset))
+    }
+
+    /// Requests reading ahead from the given offset, unless it's already buffered or another read ahead is in progress.
+    pub fn request(&self, offset: u64) {
+        if self.contains(offset) {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if !state.in_progress {
+            state.requested_offset = Some(offset);
+        }
+    }
+
+    /// Returns the requested offset to read ahead from, marking the read ahead as in progress until it's completed.
+    pub fn start(&self) -> Option<u64> {
+        let mut state = self.state.lock().unwrap();
+        let offset = state.requested_offset.take()?;
+        state.in_progress = true;
+        Some(offset)
+    }
+
+    /// Completes the read ahead, replacing the buffered messages if any were read.
+    pub fn complete(
+        &self,
+        start_offset: u64,
+        end_offset: u64,
+        messages: Vec<Arc<RetainedMessage>>,
+    ) {
+        if !messages.is_empty() {
+            *self.range.write().unwrap() = Some(ReadAheadRange {
+                start_offset,
+                end_offset,
+                messages,
+            });
+        }
+        self.state.lock().unwrap().in_progress = false;
+    }
+
+    /// Cancels the read ahead in progress without replacing the buffered messages.
+    pub fn cancel(&self) {
+        self.state.lock().unwrap().in_progress = false;
+    }
+
+    /// Removes the buffered messages, which must be done whenever the closed segments are modified.
+    pub fn clear(&self) {
+        self.range.write().unwrap().take();
+    }
+
+    fn contains(&self, offset: u64) -> bool {
+        self.range
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|range| (range.start_offset..=range.end_offset).contains(&offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use iggy::models::messages::MessageState;
+
+    fn messages(offsets: impl IntoIterator<Item = u64>) -> Vec<Arc<RetainedMessage>> {
+        offsets
+            .into_iter()
+            .map(|offset| {
+                Arc::new(RetainedMessage {
+                    id: offset as u128,
+                    offset,
+                    timestamp: offset,
+                    checksum: 0,
+                    message_state: MessageState::Available,
+                    headers: None,
+                    payload: Bytes::from("message"),
+                })
+            })
+            .collect()
+    }
+
+    fn offsets(messages: &[Arc<RetainedMessage>]) -> Vec<u64> {
+        messages.iter().map(|message| message.offset).collect()
+    }
+
+    #[test]
+    fn buffered_messages_should_be_returned_from_start_offset() {
+        let buffer = ReadAheadBuffer::default();
+        assert!(buffer.get(10, 20).is_none());
+
+        buffer.request(10);
+        assert_eq!(buffer.start(), Some(10));
+        buffer.complete(10, 19, messages(10..=19));
+
+        let (messages, last_offset) = buffer.get(12, 15).unwrap();
+        assert_eq!(offsets(&messages), vec![12, 13, 14, 15]);
+        assert_eq!(last_offset, 15);
+
+        let (messages, last_offset) = buffer.get(18, 25).unwrap();
+        assert_eq!(offsets(&messages), vec![18, 19]);
+        assert_eq!(last_offset, 19);
+
+        assert!(buffer.get(9, 15).is_none());
+        assert!(buffer.get(20, 25).is_none());
+    }
+
+    #[test]
+    fn buffered_range_should_cover_compacted_gaps() {
+        let buffer = ReadAheadBuffer::default();
+        buffer.request(10);
+        buffer.start();
+        buffer.complete(10, 19, messages([10, 15, 19]));
+
+        let (messages, last_offset) = buffer.get(11, 14).unwrap();
+        assert!(messages.is_empty());
+        assert_eq!(last_offset, 14);
+    }
+
+    #[test]
+    fn read_ahead_should_not_be_requested_while_in_progress_or_already_buffered() {
+        let buffer = ReadAheadBuffer::default();
+        buffer.request(10);
+        assert_eq!(buffer.start(), Some(10));
+
+        buffer.request(20);
+        assert_eq!(buffer.start(), None);
+
+        buffer.complete(10, 19, messages(10..=19));
+        buffer.request(15);
+        assert_eq!(buffer.start(), None);
+
+        buffer.request(20);
+        assert_eq!(buffer.start(), Some(20));
+        buffer.cancel();
+
+        buffer.clear();
+        assert!(buffer.get(10, 19).is_none());
+    }
+}
@@ -0,0 +1,17 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::utils::keyring::Keyring;
+use iggy::error::IggyError;
+
+impl Partition {
+    /// Re-encrypts the closed segments of the partition written with the previous versions of the key.
+    /// The active segment is re-encrypted once it's closed, while it remains readable with all the known keys.
+    pub async fn reencrypt(&mut self, keyring: &Keyring) -> Result<u64, IggyError> {
+        self.read_ahead.clear();
+        let mut reencrypted_messages = 0;
+        for segment in self.segments.iter_mut().filter(|segment| segment.is_closed) {
+            reencrypted_messages += segment.reencrypt(keyring).await?;
+        }
+
+        Ok(reencrypted_messages)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::IggyError;
+use iggy::models::messages::{PolledMessage, PolledMessages};
+use iggy::models::replica_info::ReplicaInfo;
+use iggy::models::replica_messages::{EpochEndOffset, ReplicaMessages};
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::cmp::Ordering;
+use tracing::{info, trace};
+
+/// The replication progress of the follower, tracked by the leader based on the offsets the follower fetches from.
+/// It's kept only in memory, as the followers report their progress with every fetch.
+#[derive(Debug, Clone, Copy)]
+pub struct ReplicaState {
+    /// The offset of the next message to be fetched, all the messages below it have been replicated by the follower.
+    pub fetched_offset: u64,
+    pub last_fetched_at: IggyTimestamp,
+    /// The last time the follower has fetched from the end of the leader's log, if ever.
+    pub last_caught_up_at: Option<IggyTimestamp>,
+}
+
+impl ReplicaState {
+    /// The follower is in sync as long as it has caught up with the leader within the replica lag timeout.
+    pub fn is_in_sync(&self, now: IggyTimestamp, replica_lag_timeout: IggyDuration) -> bool {
+        self.last_caught_up_at.is_some_and(|last_caught_up_at| {
+            now.as_micros()
+                .saturating_sub(last_caught_up_at.as_micros())
+                <= replica_lag_timeout.as_micros()
+        })
+    }
+
+    /// The follower is evicted once it drops out of the in-sync replicas, or if it hasn't fetched within the replica lag timeout
+    /// while still catching up. It's tracked again (as not in sync) with its next fetch.
+    pub fn is_evicted(&self, now: IggyTimestamp, replica_lag_timeout: IggyDuration) -> bool {
+        if self.is_in_sync(now, replica_lag_timeout) {
+            return false;
+        }
+
+        self.last_caught_up_at.is_some()
+            || now
+                .as_micros()
+                .saturating_sub(self.last_fetched_at.as_micros())
+                > replica_lag_timeout.as_micros()
+    }
+}
+
+impl Partition {
+    /// Returns the offset below which the messages have been replicated by all the in-sync replicas, and thus are visible to the consumers.
+    /// Without any in-sync replicas, all the appended messages are visible.
+    pub fn get_visible_high_watermark(&self) -> u64 {
+        let now = IggyTimestamp::now();
+        let replica_lag_timeout = self.config.partition.replica_lag_timeout;
+        self.replicas
+            .iter()
+            .filter(|replica| replica.is_in_sync(now, replica_lag_timeout))
+            .map(|replica| replica.fetched_offset)
+            .fold(self.get_high_watermark(), u64::min)
+    }
+
+    /// Returns the replication progress of the followers ordered by the replica ID.
+    pub fn get_replicas_info(&self) -> Vec<ReplicaInfo> {
+        let now = IggyTimestamp::now();
+        let replica_lag_timeout = self.config.partition.replica_lag_timeout;
+        self.evict_replicas(now, replica_lag_timeout);
+        let mut replicas = self
+            .replicas
+            .iter()
+            .map(|replica| ReplicaInfo {
+                id: *replica.key(),
+                fetched_offset: replica.fetched_offset,
+                last_fetched_at: replica.last_fetched_at,
+                last_caught_up_at: replica.last_caught_up_at,
+                is_in_sync: replica.is_in_sync(now, replica_lag_timeout),
+            })
+            .collect::<Vec<_>>();
+        replicas.sort_by_key(|replica| replica.id);
+        replicas
+    }
+
+    /// Records the offset fetched by the follower, which has replicated all the messages below it.
+    /// The follower catches up (and joins the in-sync replicas) once it fetches from the end of the log.
+    fn update_replica_state(&self, replica_id: u32, offset: u64) {
+        let now = IggyTimestamp::now();
+        self.evict_replicas(now, self.config.partition.replica_lag_timeout);
+        let is_caught_up = offset >= self.get_high_watermark();
+        let mut replica = self.replicas.entry(replica_id).or_insert(ReplicaState {
+            fetched_offset: offset,
+            last_fetched_at: now,
+            last_caught_up_at: None,
+        });
+        let was_in_sync = replica.is_in_sync(now, self.config.partition.replica_lag_timeout);
+        replica.fetched_offset = offset;
+        replica.last_fetched_at = now;
+        if is_caught_up {
+            replica.last_caught_up_at = Some(now);
+            if !was_in_sync {
+                info!(
+                    "Replica with ID: {replica_id} has joined the in-sync replicas of partition with ID: {}, topic with ID: {}, stream with ID: {}.",
+                    self.partition_id, self.topic_id, self.stream_id
+                );
+            }
+        }
+        drop(replica);
+        self.replication_notifier
+            .notify(self.get_visible_high_watermark());
+    }
+
+    fn evict_replicas(&self, now: IggyTimestamp, replica_lag_timeout: IggyDuration) {
+        self.replicas.retain(|replica_id, replica| {
+            if !replica.is_evicted(now, replica_lag_timeout) {
+                return true;
+            }
+
+            info!(
+                "Replica with ID: {replica_id} is out of sync and has been evicted from partition with ID: {}, topic with ID: {}, stream with ID: {}.",
+                self.partition_id, self.topic_id, self.stream_id
+            );
+            false
+        });
+    }
+
+    /// Returns the end offset (exclusive) of the given epoch in the log of the partition, or `None` if the epoch is newer than the current one.
+    /// Every newer epoch starts with the log rewritten from the offset 0 (e.g. after the purge), so all the older epochs end at it.
+    pub fn get_epoch_end_offset(&self, epoch: u32) -> Option<u64> {
+        match epoch.cmp(&self.epoch) {
+            Ordering::Less => Some(0),
+            Ordering::Equal => Some(self.get_high_watermark()),
+            Ordering::Greater => None,
+        }
+    }
+
+    /// Returns up to `count` messages for the follower fetching from the given offset, with the epoch of the last message it has replicated.
+    /// If the follower's log extends beyond the end of its epoch in this log, the end offset of the epoch is returned instead of the messages,
+    /// as the follower has to truncate its log to it before fetching again. Only the followers configured for the cluster can fetch the messages.
+    pub async fn fetch_replica_messages(
+        &self,
+        replica_id: u32,
+        offset: u64,
+        epoch: u32,
+        count: u32,
+    ) -> Result<ReplicaMessages, IggyError> {
+        self.ensure_leader()?;
+        if !self.config.cluster.followers.contains(&replica_id) {
+            return Err(IggyError::NodeNotFound(replica_id));
+        }
+
+        let Some(end_offset) = self.get_epoch_end_offset(epoch) else {
+            return Err(IggyError::UnknownPartitionEpoch(
+                epoch,
+                self.partition_id,
+                self.epoch,
+            ));
+        };
+
+        let high_watermark = self.get_high_watermark();
+        if offset > end_offset {
+            if epoch == self.epoch {
+                return Err(IggyError::OffsetAboveHighWatermark(
+                    offset,
+                    high_watermark,
+                    self.partition_id,
+                ));
+            }
+
+            trace!(
+                "Replica log has diverged at offset: {offset} in epoch: {epoch} from the log of partition with ID: {} in epoch: {}, end offset: {end_offset}.",
+                self.partition_id,
+                self.epoch
+            );
+            return Ok(ReplicaMessages {
+                diverging_epoch: Some(EpochEndOffset { epoch, end_offset }),
+                messages: self.create_replica_polled_messages(Vec::new(), None),
+            });
+        }
+
+        self.update_replica_state(replica_id, offset);
+        let messages = if offset < high_watermark {
+            self.get_messages_by_offset(offset, count, 0)
+                .await?
+                .iter()
+                .map(|message| message.to_polled_message())
+                .collect::<Result<Vec<_>, IggyError>>()?
+        } else {
+            Vec::new()
+        };
+        let next_offset = messages
+            .last()
+            .map(|message| message.offset + 1)
+            .filter(|next_offset| *next_offset < high_watermark);
+        Ok(ReplicaMessages {
+            diverging_epoch: None,
+            messages: self.create_replica_polled_messages(messages, next_offset),
+        })
+    }
+
+    fn create_replica_polled_messages(
+        &self,
+        messages: Vec<PolledMessage>,
+        next_offset: Option<u64>,
+    ) -> PolledMessages {
+        PolledMessages {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            epoch: self.epoch,
+            next_offset,
+            messages,
+        }
+    }
+}
@@ -3,12 +3,16 @@ use std::sync::atomic::Ordering;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::segments::segment::Segment;
 use iggy::error::IggyError;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::sealed_partition_info::SealedPartitionInfo;
+use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
 use tracing::info;
 
 pub struct DeletedSegment {
     pub end_offset: u64,
     pub messages_count: u64,
+    pub size_bytes: u64,
 }
 
 impl Partition {
@@ -20,6 +24,27 @@ impl Partition {
         &self.segments
     }
 
+    pub async fn get_details(&self) -> PartitionDetails {
+        let mut segments = Vec::with_capacity(self.segments.len());
+        for segment in &self.segments {
+            segments.push(segment.get_info().await);
+        }
+        segments.sort_by_key(|segment| segment.start_offset);
+
+        PartitionDetails {
+            id: self.partition_id,
+            created_at: self.created_at,
+            segments_count: self.get_segments_count(),
+            current_offset: self.current_offset,
+            size: self.get_size_bytes(),
+            messages_count: self.get_messages_count(),
+            high_watermark: self.get_visible_high_watermark(),
+            consumer_offsets: self.get_consumer_offsets_storage_info(),
+            replicas: self.get_replicas_info(),
+            segments,
+        }
+    }
+
     pub fn get_segment(&self, start_offset: u64) -> Option<&Segment> {
         self.segments
             .iter()
@@ -47,13 +72,14 @@ impl Partition {
             "Creating the new segment for partition with ID: {}, stream with ID: {}, topic with ID: {}...",
             self.partition_id, self.stream_id, self.topic_id
         );
-        let new_segment = Segment::create(
+        let mut new_segment = Segment::create(
             self.stream_id,
             self.topic_id,
             self.partition_id,
             start_offset,
             self.config.clone(),
             self.storage.clone(),
+            self.events_bus.clone(),
             self.message_expiry,
             self.size_of_parent_stream.clone(),
             self.size_of_parent_topic.clone(),
@@ -62,6 +88,11 @@ impl Partition {
             self.messages_count_of_parent_topic.clone(),
             self.messages_count.clone(),
         );
+        // The timestamps of the partition must not go backwards across the segments either.
+        new_segment.max_timestamp = self
+            .segments
+            .last()
+            .map_or(0, |segment| segment.get_max_timestamp());
         new_segment.persist().await?;
         self.segments.push(new_segment);
         self.segments_count_of_parent_stream
@@ -71,6 +102,25 @@ impl Partition {
         Ok(())
     }
 
+    /// Persists the unsaved messages and closes the active segment at the current offset,
+    /// so that all the messages appended so far belong to the closed segments and the next one starts the new segment.
+    pub async fn seal(&mut self) -> Result<SealedPartitionInfo, IggyError> {
+        let last_segment = self.segments.last_mut().ok_or(IggyError::SegmentNotFound)?;
+        while last_segment.unsaved_messages.is_some() {
+            last_segment.persist_messages().await?;
+        }
+        self.unsaved_messages_count = 0;
+        if !last_segment.is_closed && last_segment.get_messages_count() > 0 {
+            last_segment.close();
+        }
+
+        Ok(SealedPartitionInfo {
+            partition_id: self.partition_id,
+            high_watermark: self.get_high_watermark(),
+            segments_count: self.get_segments_count(),
+        })
+    }
+
     pub async fn delete_segment(&mut self, start_offset: u64) -> Result<DeletedSegment, IggyError> {
         let deleted_segment;
         {
@@ -87,10 +137,12 @@ impl Partition {
             deleted_segment = DeletedSegment {
                 end_offset: segment.end_offset,
                 messages_count: segment.get_messages_count(),
+                size_bytes: segment.size_bytes.as_bytes_u64(),
             };
         }
 
         self.segments.retain(|s| s.start_offset != start_offset);
+        self.read_ahead.clear();
         self.segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
         info!(
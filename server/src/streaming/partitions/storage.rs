@@ -1,20 +1,23 @@
-use crate::compat::index_conversion::index_converter::IndexConverter;
 use crate::state::system::PartitionState;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{
+    ConsumerOffset, LoadedConsumerOffsets, Partition, CONSUMER_NAMES_LOG, CONSUMER_OFFSETS_LOG,
+    CONSUMER_OFFSET_RECORD_SIZE, CONSUMER_OFFSET_TOMBSTONE,
+};
 use crate::streaming::persistence::persister::Persister;
-use crate::streaming::segments::segment::{Segment, INDEX_EXTENSION, LOG_EXTENSION};
+use crate::streaming::segments::segment::{Segment, LOG_EXTENSION};
 use crate::streaming::storage::PartitionStorage;
 use crate::streaming::utils::file;
 use anyhow::Context;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::fs;
-use tokio::fs::create_dir;
+use tokio::fs::{create_dir, create_dir_all};
 use tokio::io::AsyncReadExt;
 use tracing::{error, info, trace, warn};
 
@@ -43,6 +46,7 @@ impl PartitionStorage for FilePartitionStorage {
             partition.partition_id, partition.stream_id, partition.topic_id, partition.partition_path
         );
         partition.created_at = state.created_at;
+        partition.epoch = state.epoch;
         let dir_entries = fs::read_dir(&partition.partition_path).await;
         if let Err(err) = fs::read_dir(&partition.partition_path)
                 .await
@@ -77,6 +81,7 @@ impl PartitionStorage for FilePartitionStorage {
                 start_offset,
                 partition.config.clone(),
                 partition.storage.clone(),
+                partition.events_bus.clone(),
                 partition.message_expiry,
                 partition.size_of_parent_stream.clone(),
                 partition.size_of_parent_topic.clone(),
@@ -86,27 +91,6 @@ impl PartitionStorage for FilePartitionStorage {
                 partition.messages_count.clone(),
             );
 
-            let index_path = segment.index_path.to_owned();
-            let time_index_path = index_path.replace(INDEX_EXTENSION, "timeindex");
-
-            let index_converter = IndexConverter::new(index_path, time_index_path);
-            if let Ok(true) = index_converter.needs_migration().await {
-                match index_converter.migrate().await {
-                    Ok(_) => {
-                        info!(
-                            "Migrated indexes for partition with ID: {} for stream with ID: {} and topic with ID: {}.",
-                            partition.partition_id, partition.stream_id, partition.topic_id
-                        );
-                    }
-                    Err(err) => {
-                        error!(
-                            "Failed to migrate indexes for partition with ID: {} for stream with ID: {} and topic with ID: {}. Error: {}",
-                            partition.partition_id, partition.stream_id, partition.topic_id, err
-                        );
-                    }
-                }
-            }
-
             segment.load().await?;
             let capacity = partition.config.partition.messages_required_to_save;
             if !segment.is_closed {
@@ -152,6 +136,13 @@ impl PartitionStorage for FilePartitionStorage {
             .segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
 
+        // The timestamps of the partition must not go backwards across the segments either.
+        let mut max_timestamp = 0;
+        for segment in partition.segments.iter_mut() {
+            max_timestamp = max_timestamp.max(segment.max_timestamp);
+            segment.max_timestamp = max_timestamp;
+        }
+
         let end_offsets = partition
             .segments
             .iter()
@@ -165,7 +156,10 @@ impl PartitionStorage for FilePartitionStorage {
                 break;
             }
 
+            // The segment followed by another one is closed, even if it was sealed before becoming full.
             segment.end_offset = end_offsets[end_offset_index];
+            segment.is_closed = true;
+            segment.unsaved_messages = None;
         }
 
         if !partition.segments.is_empty() {
@@ -176,6 +170,7 @@ impl PartitionStorage for FilePartitionStorage {
 
             partition.current_offset = last_segment.current_offset;
         }
+        partition.notify_messages_subscribers();
 
         partition.load_consumer_offsets().await?;
         info!(
@@ -192,7 +187,7 @@ impl PartitionStorage for FilePartitionStorage {
             partition.partition_id, partition.stream_id, partition.topic_id
         );
         if !Path::new(&partition.partition_path).exists()
-            && create_dir(&partition.partition_path).await.is_err()
+            && create_dir_all(&partition.partition_path).await.is_err()
         {
             return Err(IggyError::CannotCreatePartitionDirectory(
                 partition.partition_id,
@@ -245,6 +240,9 @@ impl PartitionStorage for FilePartitionStorage {
             ));
         }
 
+        create_consumer_offsets_log(&partition.consumer_offsets_path).await?;
+        create_consumer_offsets_log(&partition.consumer_group_offsets_path).await?;
+
         for segment in partition.get_segments() {
             segment.persist().await?;
         }
@@ -301,7 +299,10 @@ impl PartitionStorage for FilePartitionStorage {
 
     async fn save_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError> {
         self.persister
-            .overwrite(&offset.path, &offset.offset.to_le_bytes())
+            .append(
+                &offset.path,
+                &map_consumer_offset_record(offset.consumer_id, offset.offset),
+            )
             .await?;
         trace!(
             "Stored consumer offset value: {} for {} with ID: {}, path: {}",
@@ -317,14 +318,18 @@ impl PartitionStorage for FilePartitionStorage {
         &self,
         kind: ConsumerKind,
         path: &str,
-    ) -> Result<Vec<ConsumerOffset>, IggyError> {
+    ) -> Result<LoadedConsumerOffsets, IggyError> {
         trace!("Loading consumer offsets from path: {path}...");
         let dir_entries = fs::read_dir(&path).await;
         if dir_entries.is_err() {
             return Err(IggyError::CannotReadConsumerOffsets(path.to_owned()));
         }
 
-        let mut consumer_offsets = Vec::new();
+        let mut offsets = HashMap::new();
+        let mut records_count = 0;
+        let mut size_bytes = 0;
+        // The offsets stored in a separate file per consumer (before the log was introduced)
+        // are loaded first, and then overwritten by the records from the log.
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let metadata = dir_entry.metadata().await;
@@ -332,11 +337,16 @@ impl PartitionStorage for FilePartitionStorage {
                 break;
             }
 
-            if metadata.unwrap().is_dir() {
+            let metadata = metadata.unwrap();
+            if metadata.is_dir() {
                 continue;
             }
 
             let name = dir_entry.file_name().into_string().unwrap();
+            if name.starts_with(CONSUMER_OFFSETS_LOG) || name.starts_with(CONSUMER_NAMES_LOG) {
+                continue;
+            }
+
             let consumer_id = name.parse::<u32>();
             if consumer_id.is_err() {
                 error!("Invalid consumer ID file with name: '{}'.", name);
@@ -350,21 +360,81 @@ impl PartitionStorage for FilePartitionStorage {
                 continue;
             }
 
-            let path = path.unwrap().to_string();
-            let consumer_id = consumer_id.unwrap();
-            let mut file = file::open(&path).await?;
+            let mut file = file::open(path.unwrap()).await?;
             let offset = file.read_u64_le().await?;
+            offsets.insert(consumer_id.unwrap(), offset);
+            records_count += 1;
+            size_bytes += metadata.len();
+        }
 
-            consumer_offsets.push(ConsumerOffset {
-                kind,
-                consumer_id,
-                offset,
-                path,
-            });
+        let log_path = format!("{path}/{CONSUMER_OFFSETS_LOG}");
+        if Path::new(&log_path).exists() {
+            let log = fs::read(&log_path)
+                .await
+                .with_context(|| format!("Failed to read consumer offsets log: {log_path}"))
+                .map_err(|_| IggyError::CannotReadConsumerOffsets(path.to_owned()))?;
+            let records = log.chunks_exact(CONSUMER_OFFSET_RECORD_SIZE as usize);
+            if !records.remainder().is_empty() {
+                warn!(
+                    "Consumer offsets log: {log_path} has an incomplete record of {} bytes, which will be skipped.",
+                    records.remainder().len()
+                );
+            }
+
+            for record in records {
+                let consumer_id = u32::from_le_bytes(record[..4].try_into().unwrap());
+                let offset = u64::from_le_bytes(record[4..].try_into().unwrap());
+                if offset == CONSUMER_OFFSET_TOMBSTONE {
+                    offsets.remove(&consumer_id);
+                } else {
+                    offsets.insert(consumer_id, offset);
+                }
+                records_count += 1;
+            }
+            size_bytes += log.len() as u64;
+        } else {
+            create_log(&log_path).await?;
         }
 
-        consumer_offsets.sort_by(|a, b| a.consumer_id.cmp(&b.consumer_id));
-        Ok(consumer_offsets)
+        let mut names = HashMap::new();
+        let names_log_path = format!("{path}/{CONSUMER_NAMES_LOG}");
+        if Path::new(&names_log_path).exists() {
+            let log = fs::read(&names_log_path)
+                .await
+                .with_context(|| format!("Failed to read consumer names log: {names_log_path}"))
+                .map_err(|_| IggyError::CannotReadConsumerOffsets(path.to_owned()))?;
+            let mut position = 0;
+            while position + 5 <= log.len() {
+                let consumer_id =
+                    u32::from_le_bytes(log[position..position + 4].try_into().unwrap());
+                let name_length = log[position + 4] as usize;
+                let name = log.get(position + 5..position + 5 + name_length);
+                let Some(name) = name else {
+                    break;
+                };
+                names.insert(consumer_id, String::from_utf8_lossy(name).to_string());
+                position += 5 + name_length;
+            }
+            if position < log.len() {
+                warn!(
+                    "Consumer names log: {names_log_path} has an incomplete record of {} bytes, which will be skipped.",
+                    log.len() - position
+                );
+            }
+        }
+        names.retain(|consumer_id, _| offsets.contains_key(consumer_id));
+
+        let mut offsets = offsets
+            .into_iter()
+            .map(|(consumer_id, offset)| ConsumerOffset::new(kind, consumer_id, offset, path))
+            .collect::<Vec<_>>();
+        offsets.sort_by_key(|offset| offset.consumer_id);
+        Ok(LoadedConsumerOffsets {
+            offsets,
+            names,
+            records_count,
+            size_bytes,
+        })
     }
 
     async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError> {
@@ -382,16 +452,135 @@ impl PartitionStorage for FilePartitionStorage {
         Ok(())
     }
 
-    async fn delete_consumer_offset(&self, path: &str) -> Result<(), IggyError> {
-        if !Path::new(path).exists() {
-            trace!("Consumer offset file does not exist: {path}.");
-            return Ok(());
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError> {
+        self.persister
+            .append(
+                &offset.path,
+                &map_consumer_offset_record(offset.consumer_id, CONSUMER_OFFSET_TOMBSTONE),
+            )
+            .await?;
+        trace!(
+            "Deleted consumer offset for {} with ID: {}, path: {}",
+            offset.kind,
+            offset.consumer_id,
+            offset.path
+        );
+        Ok(())
+    }
+
+    async fn compact_consumer_offsets(
+        &self,
+        path: &str,
+        offsets: &[ConsumerOffset],
+        names: &HashMap<u32, String>,
+    ) -> Result<u64, IggyError> {
+        let mut log = Vec::with_capacity(offsets.len() * CONSUMER_OFFSET_RECORD_SIZE as usize);
+        for offset in offsets {
+            log.extend_from_slice(&map_consumer_offset_record(
+                offset.consumer_id,
+                offset.offset,
+            ));
+        }
+
+        let log_path = format!("{path}/{CONSUMER_OFFSETS_LOG}");
+        self.replace_log(path, &log_path, &log).await?;
+
+        let names_log_path = format!("{path}/{CONSUMER_NAMES_LOG}");
+        if !names.is_empty() || Path::new(&names_log_path).exists() {
+            let mut names_log = Vec::new();
+            let mut names = names.iter().collect::<Vec<_>>();
+            names.sort_by_key(|(consumer_id, _)| **consumer_id);
+            for (consumer_id, name) in names {
+                names_log.extend_from_slice(&map_consumer_name_record(*consumer_id, name));
+            }
+            self.replace_log(path, &names_log_path, &names_log).await?;
         }
 
-        if fs::remove_file(path).await.is_err() {
-            error!("Cannot delete consumer offset file: {path}.");
-            return Err(IggyError::CannotDeleteConsumerOffsetFile(path.to_owned()));
+        // The offsets stored in a separate file per consumer are now included in the compacted log.
+        let mut dir_entries = fs::read_dir(&path)
+            .await
+            .map_err(|_| IggyError::CannotCompactConsumerOffsets(path.to_owned()))?;
+        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+            let is_legacy_offset = dir_entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.parse::<u32>().is_ok());
+            if is_legacy_offset && fs::remove_file(dir_entry.path()).await.is_err() {
+                error!(
+                    "Cannot delete consumer offset file: {}.",
+                    dir_entry.path().display()
+                );
+                return Err(IggyError::CannotCompactConsumerOffsets(path.to_owned()));
+            }
         }
+
+        trace!(
+            "Compacted consumer offsets log: {log_path}, offsets: {}",
+            offsets.len()
+        );
+        Ok(log.len() as u64)
+    }
+
+    async fn save_consumer_name(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        name: &str,
+    ) -> Result<(), IggyError> {
+        let names_log_path = format!("{path}/{CONSUMER_NAMES_LOG}");
+        create_log(&names_log_path).await?;
+        self.persister
+            .append(
+                &names_log_path,
+                &map_consumer_name_record(consumer_id, name),
+            )
+            .await?;
+        trace!("Stored consumer name: {name} for consumer with ID: {consumer_id}, path: {names_log_path}");
         Ok(())
     }
 }
+
+impl FilePartitionStorage {
+    /// Writes the log to a temporary file, which then replaces the existing log.
+    async fn replace_log(&self, path: &str, log_path: &str, log: &[u8]) -> Result<(), IggyError> {
+        let compacted_log_path = format!("{log_path}.compacted");
+        if Path::new(&compacted_log_path).exists() {
+            self.persister.delete(&compacted_log_path).await?;
+        }
+        self.persister.overwrite(&compacted_log_path, log).await?;
+        if let Err(err) = fs::rename(&compacted_log_path, log_path).await {
+            error!("Cannot replace log: {log_path} with the compacted one. Error: {err}");
+            return Err(IggyError::CannotCompactConsumerOffsets(path.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+async fn create_consumer_offsets_log(path: &str) -> Result<(), IggyError> {
+    create_log(&format!("{path}/{CONSUMER_OFFSETS_LOG}")).await
+}
+
+async fn create_log(log_path: &str) -> Result<(), IggyError> {
+    if !Path::new(log_path).exists() {
+        file::overwrite(log_path).await?;
+    }
+    Ok(())
+}
+
+fn map_consumer_offset_record(
+    consumer_id: u32,
+    offset: u64,
+) -> [u8; CONSUMER_OFFSET_RECORD_SIZE as usize] {
+    let mut record = [0; CONSUMER_OFFSET_RECORD_SIZE as usize];
+    record[..4].copy_from_slice(&consumer_id.to_le_bytes());
+    record[4..].copy_from_slice(&offset.to_le_bytes());
+    record
+}
+
+fn map_consumer_name_record(consumer_id: u32, name: &str) -> Vec<u8> {
+    let mut record = Vec::with_capacity(5 + name.len());
+    record.extend_from_slice(&consumer_id.to_le_bytes());
+    record.push(name.len() as u8);
+    record.extend_from_slice(name.as_bytes());
+    record
+}
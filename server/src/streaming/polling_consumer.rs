@@ -1,4 +1,5 @@
 use crate::streaming::utils::hash;
+use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::{IdKind, Identifier};
 use std::fmt::{Display, Formatter};
 
@@ -23,6 +24,14 @@ impl PollingConsumer {
             IdKind::String => hash::calculate_32(&identifier.value),
         }
     }
+
+    /// Returns the name of the consumer identified by name, which ID is resolved by hashing the name.
+    pub fn resolve_consumer_name(consumer: &Consumer) -> Option<String> {
+        match (consumer.kind, consumer.id.kind) {
+            (ConsumerKind::Consumer, IdKind::String) => consumer.id.get_string_value().ok(),
+            _ => None,
+        }
+    }
 }
 
 impl Display for PollingConsumer {
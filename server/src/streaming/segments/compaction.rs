@@ -0,0 +1,148 @@
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::segments::index::Index;
+use crate::streaming::segments::key_filter::KeyFilter;
+use crate::streaming::segments::segment::Segment;
+use bytes::BytesMut;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use tracing::{info, trace};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompactedSegment {
+    pub removed_messages: u64,
+    pub removed_bytes: u64,
+}
+
+impl Segment {
+    /// Rewrites the closed segment, keeping only the messages being the latest ones for their keys (message IDs).
+    /// The tombstones (messages with an empty payload) older than `tombstones_expiry` are removed as well.
+    /// The base offset and the last offset delta of each batch are preserved, so the offsets of the remaining messages
+    /// and the indexes stay valid. The last message of the segment is always retained to keep its offsets range intact.
+    /// The segment is skipped without being read if its key filter rules out all the keys having the newer messages
+    /// in the following segments, and it contains neither the tombstones nor the duplicated keys.
+    pub async fn compact(
+        &mut self,
+        latest_offsets: &HashMap<u128, u64>,
+        tombstones_expiry: IggyTimestamp,
+    ) -> Result<CompactedSegment, IggyError> {
+        if !self.is_closed {
+            return Ok(CompactedSegment::default());
+        }
+
+        self.ensure_key_filter().await?;
+        if self.key_filter.as_ref().is_some_and(|key_filter| {
+            key_filter.tombstones_count() == 0
+                && key_filter.duplicates_count() == 0
+                && !latest_offsets.iter().any(|(key, offset)| {
+                    *offset > self.current_offset && key_filter.might_contain(*key)
+                })
+        }) {
+            trace!(
+                "Skipping compaction of segment with start offset: {} for partition with ID: {}, none of its keys has a newer message.",
+                self.start_offset,
+                self.partition_id
+            );
+            return Ok(CompactedSegment::default());
+        }
+
+        let batches = self.get_all_batches().await?;
+        let mut compacted_batches = Vec::with_capacity(batches.len());
+        let mut indexes = Vec::with_capacity(batches.len());
+        let mut position = 0u64;
+        let mut last_indexed_position = None;
+        let mut removed_messages = 0;
+        for batch in batches.iter() {
+            let mut payload = BytesMut::with_capacity(batch.length.as_bytes_usize());
+            let mut messages_count = 0;
+            for message in batch.into_messages_iter() {
+                let is_latest = latest_offsets
+                    .get(&message.id)
+                    .is_none_or(|offset| *offset == message.offset);
+                let is_expired_tombstone = message.payload.is_empty()
+                    && message.timestamp <= tombstones_expiry.as_micros();
+                if (is_latest && !is_expired_tombstone) || message.offset == self.current_offset {
+                    message.extend(batch.base_offset, batch.base_timestamp, &mut payload);
+                    messages_count += 1;
+                } else {
+                    removed_messages += 1;
+                }
+            }
+
+            if payload.is_empty() {
+                continue;
+            }
+
+            let compacted_batch = RetainedMessageBatch::new(
+                batch.base_offset,
+                batch.last_offset_delta,
+                messages_count,
+                batch.base_timestamp,
+                batch.max_timestamp,
+                IggyByteSize::from(payload.len() as u64),
+                payload.freeze(),
+            );
+            if self.is_index_required(position as u32, last_indexed_position) {
+                indexes.push(Index {
+                    offset: (compacted_batch.get_last_offset() - self.start_offset) as u32,
+                    position: position as u32,
+                    timestamp: compacted_batch.max_timestamp,
+                });
+                last_indexed_position = Some(position as u32);
+            }
+            position += compacted_batch.get_size_bytes().as_bytes_u64();
+            compacted_batches.push(compacted_batch);
+        }
+
+        if removed_messages == 0 {
+            trace!(
+                "Nothing to compact in segment with start offset: {} for partition with ID: {}.",
+                self.start_offset,
+                self.partition_id
+            );
+            return Ok(CompactedSegment::default());
+        }
+
+        self.storage
+            .segment
+            .save_compacted_batches(self, &compacted_batches, &indexes, self.key_version)
+            .await?;
+        let key_filter = KeyFilter::build(&compacted_batches);
+        self.storage
+            .segment
+            .save_key_filter(self, &key_filter)
+            .await?;
+        self.key_filter = Some(key_filter);
+
+        let removed_bytes = self.size_bytes.as_bytes_u64().saturating_sub(position);
+        self.size_bytes = IggyByteSize::from(position);
+        self.last_index_position = position as u32;
+        self.last_indexed_position = last_indexed_position;
+        if self.indexes.is_some() {
+            self.indexes = Some(indexes);
+        }
+        self.size_of_parent_stream
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_topic
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+        self.size_of_parent_partition
+            .fetch_sub(removed_bytes, Ordering::SeqCst);
+
+        info!(
+            "Compacted segment with start offset: {} for partition with ID: {}, removed {} messages ({}).",
+            self.start_offset,
+            self.partition_id,
+            removed_messages,
+            IggyByteSize::from(removed_bytes)
+        );
+
+        Ok(CompactedSegment {
+            removed_messages,
+            removed_bytes,
+        })
+    }
+}
@@ -1,6 +1,4 @@
 use crate::streaming::segments::segment::Segment;
-use iggy::error::IggyError;
-use iggy::error::IggyError::InvalidOffset;
 
 #[derive(Debug, Eq, Clone, Copy, Default)]
 pub struct Index {
@@ -22,38 +20,36 @@ pub struct IndexRange {
 }
 
 impl Segment {
+    /// Returns the range of the indexes enclosing the batches which might contain the given offsets.
+    /// Since the index is sparse, the batch containing the start offset may not be indexed itself,
+    /// thus the range starts at the last index entry below the start offset (or the beginning of the segment),
+    /// and ends at the first index entry not below the end offset (or the end of the segment).
     pub fn load_highest_lower_bound_index(
         &self,
         indices: &[Index],
         start_offset: u32,
         end_offset: u32,
-    ) -> Result<IndexRange, IggyError> {
-        let starting_offset_idx = binary_search_index(indices, start_offset);
-        let ending_offset_idx = binary_search_index(indices, end_offset);
-
-        match (starting_offset_idx, ending_offset_idx) {
-            (Some(starting_offset_idx), Some(ending_offset_idx)) => Ok(IndexRange {
-                start: indices[starting_offset_idx],
-                end: indices[ending_offset_idx],
-            }),
-            (Some(starting_offset_idx), None) => Ok(IndexRange {
-                start: indices[starting_offset_idx],
-                end: *indices.last().unwrap(),
-            }),
-            (None, _) => Err(InvalidOffset(start_offset as u64 + self.start_offset)),
-        }
+    ) -> IndexRange {
+        let start = match indices.partition_point(|index| index.offset < start_offset) {
+            0 => Index::default(),
+            idx => indices[idx - 1],
+        };
+        let end = indices
+            .get(indices.partition_point(|index| index.offset < end_offset))
+            .copied()
+            .unwrap_or(IndexRange::max_range().end);
+        IndexRange { start, end }
     }
-}
 
-fn binary_search_index(indices: &[Index], offset: u32) -> Option<usize> {
-    match indices.binary_search_by(|index| index.offset.cmp(&offset)) {
-        Ok(index) => Some(index),
-        Err(index) => {
-            if index < indices.len() {
-                Some(index)
-            } else {
-                None
+    /// Checks whether the index entry should be stored for the batch saved at the given position,
+    /// which is the case for the first batch and then once at least `index_interval` bytes have been written.
+    pub fn is_index_required(&self, position: u32, last_indexed_position: Option<u32>) -> bool {
+        match last_indexed_position {
+            Some(last_indexed_position) => {
+                (position - last_indexed_position) as u64
+                    >= self.config.segment.index_interval.as_bytes_u64()
             }
+            None => true,
         }
     }
 }
@@ -79,13 +75,16 @@ impl IndexRange {
 mod tests {
     use super::*;
     use crate::configs::system::{SegmentConfig, SystemConfig};
+    use crate::streaming::events::EventsBus;
     use crate::streaming::storage::tests::get_test_system_storage;
+    use iggy::utils::byte_size::IggyByteSize;
     use iggy::utils::expiry::IggyExpiry;
     use std::sync::atomic::AtomicU64;
     use std::sync::Arc;
 
     fn create_segment() -> Segment {
         let storage = Arc::new(get_test_system_storage());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -105,6 +104,7 @@ mod tests {
             start_offset,
             config,
             storage,
+            events_bus,
             IggyExpiry::NeverExpire,
             Arc::new(AtomicU64::new(0)),
             Arc::new(AtomicU64::new(0)),
@@ -150,51 +150,63 @@ mod tests {
     fn should_find_both_indices() {
         let mut segment = create_segment();
         create_test_indices(&mut segment);
-        let result = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 15, 45)
-            .unwrap();
+        let result =
+            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 15, 45);
 
-        assert_eq!(result.start.offset, 20);
+        assert_eq!(result.start.offset, 5);
+        assert_eq!(result.start.position, 0);
         assert_eq!(result.end.offset, 50);
     }
 
     #[test]
-    fn start_and_end_index_should_be_equal() {
+    fn should_start_from_previous_index_when_start_offset_is_indexed() {
         let mut segment = create_segment();
         create_test_indices(&mut segment);
-        let result_end_range = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 65, 100)
-            .unwrap();
-
-        assert_eq!(result_end_range.start.offset, 65);
-        assert_eq!(result_end_range.end.offset, 65);
-
-        let result_start_range = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 0, 5)
-            .unwrap();
-        assert_eq!(result_start_range.start.offset, 5);
-        assert_eq!(result_start_range.end.offset, 5);
+        let result =
+            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 20, 35);
+
+        assert_eq!(result.start.offset, 5);
+        assert_eq!(result.end.offset, 35);
     }
 
     #[test]
-    fn should_clamp_last_index_when_out_of_range() {
+    fn should_start_from_beginning_of_segment_when_no_lower_index() {
         let mut segment = create_segment();
         create_test_indices(&mut segment);
-        let result = segment
-            .load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 5, 100)
-            .unwrap();
+        let result =
+            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 0, 5);
 
-        assert_eq!(result.start.offset, 5);
-        assert_eq!(result.end.offset, 65);
+        assert_eq!(result.start.offset, 0);
+        assert_eq!(result.start.position, 0);
+        assert_eq!(result.end.offset, 5);
     }
 
     #[test]
-    fn should_return_err_when_both_indices_out_of_range() {
+    fn should_read_until_end_of_segment_when_end_offset_is_not_indexed() {
         let mut segment = create_segment();
         create_test_indices(&mut segment);
-
         let result =
-            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 100, 200);
-        assert!(result.is_err());
+            segment.load_highest_lower_bound_index(segment.indexes.as_ref().unwrap(), 70, 100);
+
+        assert_eq!(result.start.offset, 65);
+        assert_eq!(result.start.position, 400);
+        assert_eq!(result.end, IndexRange::max_range().end);
+    }
+
+    #[test]
+    fn index_should_be_required_for_first_batch_and_then_every_interval() {
+        let mut segment = create_segment();
+        segment.config = Arc::new(SystemConfig {
+            segment: SegmentConfig {
+                index_interval: IggyByteSize::from(100),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        assert!(segment.is_index_required(0, None));
+        assert!(!segment.is_index_required(99, Some(0)));
+        assert!(segment.is_index_required(100, Some(0)));
+        assert!(segment.is_index_required(250, Some(100)));
     }
 }
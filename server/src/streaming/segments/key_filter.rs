@@ -0,0 +1,227 @@
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::segments::segment::Segment;
+use bytes::{BufMut, Bytes, BytesMut};
+use iggy::error::IggyError;
+use std::collections::HashSet;
+use tracing::trace;
+
+const FALSE_POSITIVE_RATE: f64 = 0.01;
+const MIN_BITS: u64 = 64;
+const MAX_HASHES: u32 = 16;
+const HEADER_SIZE: usize = 16; // hashes count: 4 bytes, tombstones count: 4 bytes, duplicates count: 4 bytes, words count: 4 bytes
+
+/// The bloom filter built over the keys (message IDs) of the closed segment,
+/// so that the segments which definitely don't contain the given key can be skipped
+/// by the key lookups and the compactor. It never yields false negatives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyFilter {
+    words: Vec<u64>,
+    hashes_count: u32,
+    tombstones_count: u32,
+    duplicates_count: u32,
+}
+
+impl KeyFilter {
+    /// Creates the empty filter sized for the expected number of keys with the false positive rate of 1%.
+    pub fn new(expected_keys: usize) -> Self {
+        let expected_keys = expected_keys.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let bits = (-expected_keys * FALSE_POSITIVE_RATE.ln() / (ln2 * ln2)).ceil() as u64;
+        let bits = bits.max(MIN_BITS);
+        let hashes_count = ((bits as f64 / expected_keys) * ln2).round() as u32;
+        Self {
+            words: vec![0; bits.div_ceil(64) as usize],
+            hashes_count: hashes_count.clamp(1, MAX_HASHES),
+            tombstones_count: 0,
+            duplicates_count: 0,
+        }
+    }
+
+    /// Builds the filter over all the messages of the provided batches, counting the tombstones (messages with an empty payload)
+    /// and the duplicates (messages superseded by the newer ones with the same key in the same batches) as well.
+    pub fn build(batches: &[RetainedMessageBatch]) -> Self {
+        let messages_count = batches
+            .iter()
+            .map(|batch| batch.messages_count as usize)
+            .sum();
+        let mut filter = Self::new(messages_count);
+        let mut keys = HashSet::with_capacity(messages_count);
+        for batch in batches {
+            for message in batch.into_messages_iter() {
+                filter.insert(message.id);
+                if !keys.insert(message.id) {
+                    filter.duplicates_count += 1;
+                }
+                if message.payload.is_empty() {
+                    filter.tombstones_count += 1;
+                }
+            }
+        }
+
+        filter
+    }
+
+    pub fn insert(&mut self, key: u128) {
+        let bits = self.bits_count();
+        let (hash, step) = hash_key(key);
+        for index in 0..self.hashes_count as u64 {
+            let bit = hash.wrapping_add(index.wrapping_mul(step)) % bits;
+            self.words[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Returns `false` only if the key is definitely not contained in the segment.
+    pub fn might_contain(&self, key: u128) -> bool {
+        let bits = self.bits_count();
+        let (hash, step) = hash_key(key);
+        (0..self.hashes_count as u64).all(|index| {
+            let bit = hash.wrapping_add(index.wrapping_mul(step)) % bits;
+            self.words[(bit / 64) as usize] & (1 << (bit % 64)) != 0
+        })
+    }
+
+    pub fn tombstones_count(&self) -> u32 {
+        self.tombstones_count
+    }
+
+    pub fn duplicates_count(&self) -> u32 {
+        self.duplicates_count
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut bytes = BytesMut::with_capacity(HEADER_SIZE + 8 * self.words.len());
+        bytes.put_u32_le(self.hashes_count);
+        bytes.put_u32_le(self.tombstones_count);
+        bytes.put_u32_le(self.duplicates_count);
+        bytes.put_u32_le(self.words.len() as u32);
+        for word in &self.words {
+            bytes.put_u64_le(*word);
+        }
+        bytes.freeze()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, IggyError> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(IggyError::InvalidKeyFilter);
+        }
+
+        let hashes_count = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let tombstones_count = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        let duplicates_count = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let words_count = u32::from_le_bytes(bytes[12..16].try_into().unwrap()) as usize;
+        if hashes_count == 0 || words_count == 0 || bytes.len() != HEADER_SIZE + 8 * words_count {
+            return Err(IggyError::InvalidKeyFilter);
+        }
+
+        let words = bytes[HEADER_SIZE..]
+            .chunks_exact(8)
+            .map(|word| u64::from_le_bytes(word.try_into().unwrap()))
+            .collect();
+        Ok(Self {
+            words,
+            hashes_count,
+            tombstones_count,
+            duplicates_count,
+        })
+    }
+
+    fn bits_count(&self) -> u64 {
+        self.words.len() as u64 * 64
+    }
+}
+
+/// Double hashing of the key - the odd step guarantees the distinct bits for the power-of-two filter sizes.
+fn hash_key(key: u128) -> (u64, u64) {
+    let hash = mix(key as u64 ^ mix((key >> 64) as u64));
+    let step = mix(hash ^ 0x9E37_79B9_7F4A_7C15) | 1;
+    (hash, step)
+}
+
+fn mix(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+impl Segment {
+    /// Loads the key filter of the closed segment, or builds it from the persisted messages and saves it alongside the indexes
+    /// if it doesn't exist yet. The filter is kept in memory, and is never built for the segment still being appended to.
+    pub async fn ensure_key_filter(&mut self) -> Result<(), IggyError> {
+        if !self.is_closed || self.key_filter.is_some() {
+            return Ok(());
+        }
+
+        if let Some(key_filter) = self.storage.segment.load_key_filter(self).await? {
+            self.key_filter = Some(key_filter);
+            return Ok(());
+        }
+
+        let key_filter = KeyFilter::build(&self.get_all_batches().await?);
+        self.storage
+            .segment
+            .save_key_filter(self, &key_filter)
+            .await?;
+        trace!(
+            "Built key filter for segment with start offset: {} for partition with ID: {}.",
+            self.start_offset,
+            self.partition_id
+        );
+        self.key_filter = Some(key_filter);
+        Ok(())
+    }
+
+    /// Returns `false` only if the segment definitely doesn't contain the message with the given key (message ID).
+    /// The segment without the key filter (e.g. the one still being appended to) might contain any key.
+    pub fn might_contain_key(&self, key: u128) -> bool {
+        self.key_filter
+            .as_ref()
+            .is_none_or(|key_filter| key_filter.might_contain(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_contain_all_inserted_keys() {
+        let mut filter = KeyFilter::new(1000);
+        for key in 0..1000u128 {
+            filter.insert(key * 7919);
+        }
+
+        for key in 0..1000u128 {
+            assert!(filter.might_contain(key * 7919));
+        }
+    }
+
+    #[test]
+    fn should_rarely_contain_not_inserted_keys() {
+        let mut filter = KeyFilter::new(1000);
+        for key in 0..1000u128 {
+            filter.insert(key);
+        }
+
+        let false_positives = (1000..101_000u128)
+            .filter(|key| filter.might_contain(*key))
+            .count();
+        assert!(false_positives < 2000);
+    }
+
+    #[test]
+    fn should_be_serialized_and_deserialized_from_bytes() {
+        let mut filter = KeyFilter::new(10);
+        filter.insert(1);
+        filter.insert(u128::MAX);
+        filter.tombstones_count = 2;
+        filter.duplicates_count = 3;
+
+        let bytes = filter.to_bytes();
+        let deserialized = KeyFilter::from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, filter);
+        assert!(deserialized.might_contain(u128::MAX));
+        assert!(KeyFilter::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
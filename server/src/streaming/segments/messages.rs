@@ -5,6 +5,7 @@ use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use iggy::error::IggyError;
+use iggy::models::system_event::SystemEvent;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::sizeable::Sizeable;
 use std::sync::atomic::Ordering;
@@ -22,6 +23,15 @@ impl Segment {
         self.current_offset - self.start_offset + 1
     }
 
+    /// Returns the highest timestamp of the messages appended to the segment (or its predecessors), including the unsaved ones.
+    pub fn get_max_timestamp(&self) -> u64 {
+        self.unsaved_messages
+            .as_ref()
+            .map_or(self.max_timestamp, |messages| {
+                messages.batch_max_timestamp().max(self.max_timestamp)
+            })
+    }
+
     pub async fn get_messages(
         &self,
         mut offset: u64,
@@ -89,6 +99,35 @@ impl Segment {
         Ok(messages)
     }
 
+    /// Returns the persisted batches which might contain the messages with the timestamps within the given range.
+    /// The time index (if loaded) is used to skip the batches appended before the start
+    /// and after the end of the range, while the rest of the batches has to be filtered by the caller.
+    pub async fn get_batches_by_timestamp_range(
+        &self,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
+        let index_range = match &self.indexes {
+            Some(indexes) => {
+                let start = match indexes.partition_point(|index| index.timestamp < from) {
+                    0 => Index::default(),
+                    idx => indexes[idx - 1],
+                };
+                let end = indexes
+                    .get(indexes.partition_point(|index| index.timestamp < to))
+                    .copied()
+                    .unwrap_or(IndexRange::max_range().end);
+                IndexRange { start, end }
+            }
+            None => IndexRange::max_range(),
+        };
+
+        self.storage
+            .segment
+            .load_message_batches(self, &index_range)
+            .await
+    }
+
     fn load_messages_from_unsaved_buffer(
         &self,
         start_offset: u64,
@@ -121,21 +160,11 @@ impl Segment {
         if let Some(indices) = &self.indexes {
             let relative_start_offset = (start_offset - self.start_offset) as u32;
             let relative_end_offset = (end_offset - self.start_offset) as u32;
-            let index_range = match self.load_highest_lower_bound_index(
+            let index_range = self.load_highest_lower_bound_index(
                 indices,
                 relative_start_offset,
                 relative_end_offset,
-            ) {
-                Ok(range) => range,
-                Err(_) => {
-                    trace!(
-                        "Cannot load messages from disk, index range not found: {} - {}.",
-                        start_offset,
-                        end_offset
-                    );
-                    return Ok(EMPTY_MESSAGES.into_iter().map(Arc::new).collect());
-                }
-            };
+            );
 
             return self
                 .load_messages_from_segment_file(&index_range, start_offset, end_offset)
@@ -234,7 +263,10 @@ impl Segment {
             position: self.last_index_position,
             timestamp: batch_max_timestamp,
         };
-        self.indexes.as_mut().unwrap().push(index);
+        if let Some(indexes) = self.indexes.as_mut() {
+            indexes.push(index);
+        }
+        self.last_indexed_position = Some(index.position);
         index
     }
 
@@ -248,11 +280,6 @@ impl Segment {
         if batch_accumulator.is_empty() {
             return Ok(0);
         }
-        let batch_max_offset = batch_accumulator.batch_max_offset();
-        let batch_max_timestamp = batch_accumulator.batch_max_timestamp();
-        let index =
-            self.store_offset_and_timestamp_index_for_batch(batch_max_offset, batch_max_timestamp);
-
         let unsaved_messages_number = batch_accumulator.unsaved_messages_count();
         trace!(
             "Saving {} messages on disk in segment with start offset: {} for partition with ID: {}...",
@@ -261,13 +288,37 @@ impl Segment {
             self.partition_id
         );
 
-        let (has_remainder, batch) = batch_accumulator.materialize_batch_and_maybe_update_state();
+        let (has_remainder, mut batch) =
+            batch_accumulator.materialize_batch_and_maybe_update_state();
+        // The time index has to be monotonic, otherwise the lookup by timestamp would miss the messages.
+        if batch.max_timestamp < self.max_timestamp {
+            trace!(
+                "Clamping batch max timestamp: {} to: {} for segment with start offset: {} and partition with ID: {}.",
+                batch.max_timestamp,
+                self.max_timestamp,
+                self.start_offset,
+                self.partition_id
+            );
+            batch.max_timestamp = self.max_timestamp;
+        }
+        self.max_timestamp = batch.max_timestamp;
+        let index = if self.is_index_required(self.last_index_position, self.last_indexed_position)
+        {
+            Some(self.store_offset_and_timestamp_index_for_batch(
+                batch.get_last_offset(),
+                batch.max_timestamp,
+            ))
+        } else {
+            None
+        };
         let batch_size = batch.get_size_bytes();
         if has_remainder {
             self.unsaved_messages = Some(batch_accumulator);
         }
         let saved_bytes = storage.save_batches(self, batch).await?;
-        storage.save_index(&self.index_path, index).await?;
+        if let Some(index) = index {
+            storage.save_index(&self.index_path, index).await?;
+        }
         self.last_index_position += batch_size.as_bytes_u64() as u32;
         self.size_bytes += IggyByteSize::from(RETAINED_BATCH_OVERHEAD);
         self.size_of_parent_stream
@@ -285,15 +336,29 @@ impl Segment {
             saved_bytes
         );
 
-        if self.is_full().await {
-            self.end_offset = self.current_offset;
-            self.is_closed = true;
-            self.unsaved_messages = None;
-            info!(
-                "Closed segment with start offset: {} for partition with ID: {}.",
-                self.start_offset, self.partition_id
-            );
+        // The remaining unsaved messages would be lost if the segment was closed before persisting them.
+        if !has_remainder && self.is_full().await {
+            self.close();
         }
         Ok(unsaved_messages_number)
     }
+
+    /// Closes the segment at its current offset, so that no more messages will be appended to it.
+    /// The unsaved messages must be persisted beforehand, otherwise they will be lost.
+    pub fn close(&mut self) {
+        self.end_offset = self.current_offset;
+        self.is_closed = true;
+        self.unsaved_messages = None;
+        info!(
+            "Closed segment with start offset: {} for partition with ID: {}.",
+            self.start_offset, self.partition_id
+        );
+        self.events_bus.publish(SystemEvent::SegmentClosed {
+            stream_id: self.stream_id,
+            topic_id: self.topic_id,
+            partition_id: self.partition_id,
+            start_offset: self.start_offset,
+            end_offset: self.end_offset,
+        });
+    }
 }
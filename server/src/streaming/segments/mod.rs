@@ -1,5 +1,10 @@
+pub mod compaction;
 pub mod index;
+pub mod key_filter;
 pub mod messages;
+pub mod open_files;
 pub mod persistence;
+pub mod recovery;
+pub mod reencryption;
 pub mod segment;
 pub mod storage;
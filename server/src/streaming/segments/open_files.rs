@@ -0,0 +1,162 @@
+use crate::streaming::utils::file;
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::sync::{Arc, Mutex};
+use tokio::fs::File;
+use tokio::io::AsyncSeekExt;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use tracing::trace;
+
+/// The segment file opened for reading, locked for the exclusive use by a single reader at a time.
+pub type OpenFile = OwnedMutexGuard<File>;
+
+/// `OpenSegmentFiles` keeps the recently read segment files (logs and indexes) open, shared by all the partitions.
+/// Once the number of the open files reaches the limit, the least recently read one is closed,
+/// which happens as soon as the reader still using it (if any) is done.
+#[derive(Debug)]
+pub struct OpenSegmentFiles {
+    max_open_files: usize,
+    state: Mutex<OpenFilesState>,
+}
+
+#[derive(Debug, Default)]
+struct OpenFilesState {
+    files: HashMap<String, OpenFileEntry>,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct OpenFileEntry {
+    file: Arc<AsyncMutex<File>>,
+    last_used: u64,
+}
+
+impl OpenSegmentFiles {
+    pub fn new(max_open_files: u32) -> Self {
+        Self {
+            max_open_files: max_open_files as usize,
+            state: Mutex::new(OpenFilesState::default()),
+        }
+    }
+
+    /// Returns the file opened for reading, rewound to its start, either already open or opened now.
+    pub async fn open(&self, path: &str) -> Result<OpenFile, std::io::Error> {
+        if self.max_open_files == 0 {
+            let file = file::open(path).await?;
+            return Ok(Arc::new(AsyncMutex::new(file)).lock_owned().await);
+        }
+
+        let file = match self.get(path) {
+            Some(file) => file,
+            None => {
+                let file = file::open(path).await?;
+                self.insert(path, Arc::new(AsyncMutex::new(file)))
+            }
+        };
+        let mut file = file.lock_owned().await;
+        file.seek(SeekFrom::Start(0)).await?;
+        Ok(file)
+    }
+
+    /// Closes the file, e.g. once it's deleted or replaced, so that the next read opens the current one.
+    pub fn close(&self, path: &str) {
+        if self.max_open_files == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if state.files.remove(path).is_some() {
+            trace!("Closed segment file: {path}");
+        }
+    }
+
+    fn get(&self, path: &str) -> Option<Arc<AsyncMutex<File>>> {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        let entry = state.files.get_mut(path)?;
+        entry.last_used = clock;
+        Some(entry.file.clone())
+    }
+
+    fn insert(&self, path: &str, file: Arc<AsyncMutex<File>>) -> Arc<AsyncMutex<File>> {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        // The same file might have been opened by another reader in the meantime.
+        if let Some(entry) = state.files.get_mut(path) {
+            entry.last_used = clock;
+            return entry.file.clone();
+        }
+
+        while state.files.len() >= self.max_open_files {
+            let Some(least_recently_used) = state
+                .files
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(path, _)| path.clone())
+            else {
+                break;
+            };
+            state.files.remove(&least_recently_used);
+            trace!("Closed least recently read segment file: {least_recently_used}");
+        }
+
+        state.files.insert(
+            path.to_string(),
+            OpenFileEntry {
+                file: file.clone(),
+                last_used: clock,
+            },
+        );
+        file
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use tokio::io::AsyncReadExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn should_close_least_recently_read_files_over_the_limit() {
+        let dir = temp_dir().join(format!("iggy-open-files-{}", Uuid::now_v7()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let paths = (0..3)
+            .map(|i| dir.join(format!("{i}.log")).to_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+        for path in &paths {
+            tokio::fs::write(path, path.as_bytes()).await.unwrap();
+        }
+        let open_files = OpenSegmentFiles::new(2);
+
+        drop(open_files.open(&paths[0]).await.unwrap());
+        drop(open_files.open(&paths[1]).await.unwrap());
+        drop(open_files.open(&paths[0]).await.unwrap());
+        drop(open_files.open(&paths[2]).await.unwrap());
+
+        {
+            let state = open_files.state.lock().unwrap();
+            assert_eq!(state.files.len(), 2);
+            assert!(state.files.contains_key(&paths[0]));
+            assert!(!state.files.contains_key(&paths[1]));
+            assert!(state.files.contains_key(&paths[2]));
+        }
+
+        let mut file = open_files.open(&paths[0]).await.unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).await.unwrap();
+        drop(file);
+        let mut file = open_files.open(&paths[0]).await.unwrap();
+        let mut content_read_again = String::new();
+        file.read_to_string(&mut content_read_again).await.unwrap();
+        assert_eq!(content, paths[0]);
+        assert_eq!(content_read_again, paths[0]);
+
+        open_files.close(&paths[0]);
+        assert_eq!(open_files.state.lock().unwrap().files.len(), 1);
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
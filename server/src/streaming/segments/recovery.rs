@@ -0,0 +1,226 @@
+use crate::streaming::batching::message_batch::{
+    RETAINED_BATCH_FORMAT_VERSION, RETAINED_BATCH_OVERHEAD,
+};
+use crate::streaming::segments::index::Index;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::storage::INDEX_SIZE;
+use crate::streaming::utils::file;
+use crate::streaming::utils::io_budget::IoThrottler;
+use bytes::{BufMut, BytesMut};
+use iggy::error::IggyError;
+use iggy::utils::checksum;
+use std::fmt::{Display, Formatter};
+use std::io::SeekFrom;
+use tokio::fs;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
+use tracing::warn;
+
+/// The result of recovering the segment which log file ended with a partially written or corrupted batch,
+/// e.g. after a power loss, so that the invalid bytes are neither loaded nor served to the consumers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SegmentRecoveryReport {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+    pub start_offset: u64,
+    pub log_size_before: u64,
+    pub log_size_after: u64,
+    pub indexes_before: u32,
+    pub indexes_after: u32,
+    pub rebuilt_indexes: u32,
+}
+
+impl SegmentRecoveryReport {
+    pub fn get_truncated_bytes(&self) -> u64 {
+        self.log_size_before - self.log_size_after
+    }
+
+    pub fn get_removed_indexes(&self) -> u32 {
+        self.indexes_before + self.rebuilt_indexes - self.indexes_after
+    }
+}
+
+impl Display for SegmentRecoveryReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ stream_id: {}, topic_id: {}, partition_id: {}, start_offset: {}, log_size_before: {}, log_size_after: {}, truncated_bytes: {}, indexes_before: {}, indexes_after: {}, rebuilt_indexes: {} }}",
+            self.stream_id,
+            self.topic_id,
+            self.partition_id,
+            self.start_offset,
+            self.log_size_before,
+            self.log_size_after,
+            self.get_truncated_bytes(),
+            self.indexes_before,
+            self.indexes_after,
+            self.rebuilt_indexes
+        )
+    }
+}
+
+/// The complete batch found by the scan of the segment log file, which checksum matches its payload.
+struct ScannedBatch {
+    position: u64,
+    last_offset: u64,
+    max_timestamp: u64,
+}
+
+/// Truncates the segment log file at the first partially written or corrupted batch (which format version
+/// or checksum doesn't match), along with the indexes pointing at or beyond it (including the partially
+/// written index entry), so that the segment ends with the last valid batch. The indexes missing for the
+/// valid batches saved after the last index entry (e.g. the crash happened before the index was saved)
+/// are rebuilt following the index interval. Returns `None` if the segment didn't need to be recovered.
+/// The scanned bytes of the log file are accounted in the background IO budget of the recovery.
+pub async fn recover_partially_written_batch(
+    segment: &Segment,
+    throttler: &mut IoThrottler,
+) -> Result<Option<SegmentRecoveryReport>, IggyError> {
+    let log_size = fs::metadata(&segment.log_path).await?.len();
+    let index_bytes = fs::read(&segment.index_path).await.unwrap_or_default();
+    let indexes = index_bytes
+        .chunks_exact(INDEX_SIZE as usize)
+        .map(|index| Index {
+            offset: u32::from_le_bytes(index[0..4].try_into().unwrap()),
+            position: u32::from_le_bytes(index[4..8].try_into().unwrap()),
+            timestamp: u64::from_le_bytes(index[8..16].try_into().unwrap()),
+        })
+        .collect::<Vec<_>>();
+    let has_partial_index = index_bytes.len() % INDEX_SIZE as usize != 0;
+
+    // The batches before the last index pointing within the log file are usually complete, as the index is saved
+    // after its batch. However, the index might have been flushed to disk before the batch, thus the scan steps back
+    // to the previous index until it starts at the valid batch (or at the beginning of the log file).
+    let mut scan_positions = indexes
+        .iter()
+        .map(|index| index.position as u64)
+        .filter(|position| *position < log_size)
+        .collect::<Vec<_>>();
+    scan_positions.dedup();
+    let mut scan_positions = scan_positions.into_iter().rev().chain([0]);
+    let (batches, valid_size) = loop {
+        let scan_position = scan_positions.next().unwrap_or(0);
+        throttler.throttle(log_size - scan_position).await;
+        let (batches, valid_size) =
+            scan_valid_batches(&segment.log_path, scan_position, log_size).await?;
+        if scan_position == 0 || valid_size > scan_position {
+            break (batches, valid_size);
+        }
+    };
+
+    let mut valid_indexes = indexes
+        .iter()
+        .take_while(|index| (index.position as u64) < valid_size)
+        .copied()
+        .collect::<Vec<_>>();
+    let indexes_count = valid_indexes.len();
+    let mut last_indexed_position = valid_indexes.last().map(|index| index.position);
+    let mut last_indexed_timestamp = valid_indexes.last().map_or(0, |index| index.timestamp);
+    for batch in &batches {
+        let position = batch.position as u32;
+        if last_indexed_position.is_some_and(|last_position| position <= last_position)
+            || !segment.is_index_required(position, last_indexed_position)
+        {
+            continue;
+        }
+
+        // The time index has to be monotonic, the same as when the index is saved along with its batch.
+        last_indexed_timestamp = last_indexed_timestamp.max(batch.max_timestamp);
+        last_indexed_position = Some(position);
+        valid_indexes.push(Index {
+            offset: (batch.last_offset - segment.start_offset) as u32,
+            position,
+            timestamp: last_indexed_timestamp,
+        });
+    }
+    let rebuilt_indexes = valid_indexes.len() - indexes_count;
+    if valid_size == log_size
+        && indexes_count == indexes.len()
+        && rebuilt_indexes == 0
+        && !has_partial_index
+    {
+        return Ok(None);
+    }
+
+    if valid_size < log_size {
+        let log_file = file::overwrite(&segment.log_path).await?;
+        log_file.set_len(valid_size).await?;
+        log_file.sync_all().await?;
+    }
+
+    let mut index_file = file::overwrite(&segment.index_path).await?;
+    index_file
+        .set_len(indexes_count as u64 * INDEX_SIZE as u64)
+        .await?;
+    if rebuilt_indexes > 0 {
+        let mut bytes = BytesMut::with_capacity(rebuilt_indexes * INDEX_SIZE as usize);
+        for index in &valid_indexes[indexes_count..] {
+            bytes.put_u32_le(index.offset);
+            bytes.put_u32_le(index.position);
+            bytes.put_u64_le(index.timestamp);
+        }
+        index_file
+            .seek(SeekFrom::Start(indexes_count as u64 * INDEX_SIZE as u64))
+            .await?;
+        index_file.write_all(&bytes).await?;
+    }
+    index_file.sync_all().await?;
+
+    Ok(Some(SegmentRecoveryReport {
+        stream_id: segment.stream_id,
+        topic_id: segment.topic_id,
+        partition_id: segment.partition_id,
+        start_offset: segment.start_offset,
+        log_size_before: log_size,
+        log_size_after: valid_size,
+        indexes_before: indexes.len() as u32,
+        indexes_after: valid_indexes.len() as u32,
+        rebuilt_indexes: rebuilt_indexes as u32,
+    }))
+}
+
+/// Returns the valid batches starting at the given position, along with the position right after the last one.
+/// The scan stops at the first batch which header or payload wasn't fully written, or which format version
+/// or checksum doesn't match.
+async fn scan_valid_batches(
+    log_path: &str,
+    start_position: u64,
+    log_size: u64,
+) -> Result<(Vec<ScannedBatch>, u64), IggyError> {
+    let mut reader = BufReader::new(file::open(log_path).await?);
+    reader.seek(SeekFrom::Start(start_position)).await?;
+    let mut batches = Vec::new();
+    let mut position = start_position;
+    let mut header = vec![0; RETAINED_BATCH_OVERHEAD as usize];
+    while log_size - position >= RETAINED_BATCH_OVERHEAD {
+        reader.read_exact(&mut header).await?;
+        let batch_length = u32::from_le_bytes(header[8..12].try_into()?) as u64;
+        let batch_end = position + RETAINED_BATCH_OVERHEAD + batch_length;
+        if header[12] != RETAINED_BATCH_FORMAT_VERSION || batch_end > log_size {
+            break;
+        }
+
+        let mut payload = vec![0; batch_length as usize];
+        reader.read_exact(&mut payload).await?;
+        let batch_checksum = u32::from_le_bytes(header[header.len() - 4..].try_into()?);
+        if checksum::calculate(&payload) != batch_checksum {
+            warn!(
+                "Found corrupted batch at position: {position} of segment log file: {log_path}, the log file will be truncated."
+            );
+            break;
+        }
+
+        // base offset: 8 bytes, length: 4 bytes, format version: 1 byte, last offset delta: 4 bytes,
+        // messages count: 4 bytes, base timestamp: 8 bytes, max timestamp: 8 bytes
+        let base_offset = u64::from_le_bytes(header[0..8].try_into()?);
+        let last_offset_delta = u32::from_le_bytes(header[13..17].try_into()?);
+        batches.push(ScannedBatch {
+            position,
+            last_offset: base_offset + last_offset_delta as u64,
+            max_timestamp: u64::from_le_bytes(header[29..37].try_into()?),
+        });
+        position = batch_end;
+    }
+
+    Ok((batches, position))
+}
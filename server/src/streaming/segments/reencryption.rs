@@ -0,0 +1,86 @@
+use crate::streaming::batching::iterator::IntoMessagesIterator;
+use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::keyring::Keyring;
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::checksum;
+use iggy::utils::crypto::Encryptor;
+use tracing::{info, trace};
+
+impl Segment {
+    /// Rewrites the closed segment, re-encrypting the messages payloads written with the previous versions of the key
+    /// using the current key, and stores the current key version of the segment along with the rewritten files.
+    /// The encrypted payload has the same length regardless of the key, so the positions and the indexes stay valid.
+    /// Returns the number of the re-encrypted messages.
+    pub async fn reencrypt(&mut self, keyring: &Keyring) -> Result<u64, IggyError> {
+        let current_version = keyring.current_version();
+        if !self.is_closed || self.key_version == Some(current_version) {
+            return Ok(0);
+        }
+
+        let batches = self.get_all_batches().await?;
+        let mut reencrypted_batches = Vec::with_capacity(batches.len());
+        let mut reencrypted_messages = 0;
+        for batch in batches.iter() {
+            let mut payload = BytesMut::with_capacity(batch.length.as_bytes_usize());
+            for message in batch.into_messages_iter() {
+                if message.payload.is_empty() {
+                    message.extend(batch.base_offset, batch.base_timestamp, &mut payload);
+                    continue;
+                }
+
+                let (version, decrypted_payload) =
+                    keyring.decrypt_with_version(&message.payload)?;
+                if version == current_version {
+                    message.extend(batch.base_offset, batch.base_timestamp, &mut payload);
+                    continue;
+                }
+
+                let encrypted_payload = Bytes::from(keyring.encrypt(&decrypted_payload)?);
+                let message = RetainedMessage {
+                    checksum: checksum::calculate(&encrypted_payload),
+                    payload: encrypted_payload,
+                    ..message
+                };
+                message.extend(batch.base_offset, batch.base_timestamp, &mut payload);
+                reencrypted_messages += 1;
+            }
+
+            reencrypted_batches.push(RetainedMessageBatch::new(
+                batch.base_offset,
+                batch.last_offset_delta,
+                batch.messages_count,
+                batch.base_timestamp,
+                batch.max_timestamp,
+                IggyByteSize::from(payload.len() as u64),
+                payload.freeze(),
+            ));
+        }
+
+        if reencrypted_messages > 0 {
+            let indexes = self.storage.segment.load_all_indexes(self).await?;
+            self.storage
+                .segment
+                .save_compacted_batches(self, &reencrypted_batches, &indexes, Some(current_version))
+                .await?;
+            self.key_version = Some(current_version);
+            info!(
+                "Re-encrypted {} messages with key version: {} in segment with start offset: {} for partition with ID: {}.",
+                reencrypted_messages, current_version, self.start_offset, self.partition_id
+            );
+        } else {
+            trace!(
+                "Nothing to re-encrypt in segment with start offset: {} for partition with ID: {}.",
+                self.start_offset,
+                self.partition_id
+            );
+            self.key_version = Some(current_version);
+            self.storage.segment.save_key_version(self).await?;
+        }
+
+        Ok(reencrypted_messages)
+    }
+}
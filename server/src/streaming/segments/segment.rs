@@ -1,15 +1,25 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::batching::batch_accumulator::BatchAccumulator;
+use crate::streaming::events::EventsBus;
 use crate::streaming::segments::index::Index;
+use crate::streaming::segments::key_filter::KeyFilter;
+use crate::streaming::segments::storage::INDEX_SIZE;
 use crate::streaming::storage::SystemStorage;
+use iggy::models::segment_info::SegmentInfo;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
+use tokio::fs;
 
 pub const LOG_EXTENSION: &str = "log";
 pub const INDEX_EXTENSION: &str = "index";
+pub const KEY_VERSION_EXTENSION: &str = "key";
+pub const KEY_FILTER_EXTENSION: &str = "bloom";
+/// The extension appended to the paths of the rewritten (compacted or re-encrypted) segment files,
+/// which are written next to the original ones and then renamed over them.
+pub const COMPACTED_EXTENSION: &str = "compacted";
 pub const MAX_SIZE_BYTES: u64 = 1000 * 1000 * 1000;
 
 #[derive(Debug)]
@@ -22,8 +32,11 @@ pub struct Segment {
     pub current_offset: u64,
     pub index_path: String,
     pub log_path: String,
+    pub key_version_path: String,
+    pub key_filter_path: String,
     pub size_bytes: IggyByteSize,
     pub last_index_position: u32,
+    pub last_indexed_position: Option<u32>,
     pub max_size_bytes: IggyByteSize,
     pub size_of_parent_stream: Arc<AtomicU64>,
     pub size_of_parent_topic: Arc<AtomicU64>,
@@ -32,11 +45,22 @@ pub struct Segment {
     pub messages_count_of_parent_topic: Arc<AtomicU64>,
     pub messages_count_of_parent_partition: Arc<AtomicU64>,
     pub is_closed: bool,
+    /// The timestamp of the segment creation, restored from the log file metadata when the segment is loaded.
+    pub created_at: IggyTimestamp,
+    /// The version of the encryption key all the encrypted messages of the segment were written with,
+    /// known only once the closed segment has been re-encrypted after the key rotation.
+    pub key_version: Option<u32>,
+    /// The highest timestamp of the batches persisted in the partition up to (and including) this segment.
+    /// The timestamps written to the index never go below it, even if the clock goes backwards (e.g. after the NTP correction).
+    pub max_timestamp: u64,
     pub(crate) message_expiry: IggyExpiry,
     pub(crate) unsaved_messages: Option<BatchAccumulator>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) indexes: Option<Vec<Index>>,
+    /// The bloom filter over the keys of the closed segment, loaded or built on demand.
+    pub(crate) key_filter: Option<KeyFilter>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) events_bus: Arc<EventsBus>,
 }
 
 impl Segment {
@@ -48,6 +72,7 @@ impl Segment {
         start_offset: u64,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        events_bus: Arc<EventsBus>,
         message_expiry: IggyExpiry,
         size_of_parent_stream: Arc<AtomicU64>,
         size_of_parent_topic: Arc<AtomicU64>,
@@ -67,8 +92,11 @@ impl Segment {
             current_offset: start_offset,
             log_path: Self::get_log_path(&path),
             index_path: Self::get_index_path(&path),
+            key_version_path: Self::get_key_version_path(&path),
+            key_filter_path: Self::get_key_filter_path(&path),
             size_bytes: IggyByteSize::from(0),
             last_index_position: 0,
+            last_indexed_position: None,
             max_size_bytes: config.segment.size,
             message_expiry: match message_expiry {
                 IggyExpiry::ServerDefault => config.segment.message_expiry,
@@ -79,7 +107,11 @@ impl Segment {
                 false => None,
             },
             unsaved_messages: None,
+            key_filter: None,
             is_closed: false,
+            created_at: IggyTimestamp::now(),
+            key_version: None,
+            max_timestamp: 0,
             size_of_parent_stream,
             size_of_parent_partition,
             size_of_parent_topic,
@@ -88,6 +120,7 @@ impl Segment {
             messages_count_of_parent_partition,
             config,
             storage,
+            events_bus,
         }
     }
 
@@ -125,6 +158,25 @@ impl Segment {
         }
     }
 
+    pub async fn get_info(&self) -> SegmentInfo {
+        // The index file might not exist yet if none of the messages were saved to the segment.
+        let index_size_bytes = match fs::metadata(&self.index_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        };
+
+        SegmentInfo {
+            start_offset: self.start_offset,
+            end_offset: self.current_offset,
+            size: self.size_bytes,
+            messages_count: self.get_messages_count(),
+            is_closed: self.is_closed,
+            created_at: self.created_at,
+            index_size: IggyByteSize::from(index_size_bytes),
+            indexes_count: (index_size_bytes / INDEX_SIZE as u64) as u32,
+        }
+    }
+
     fn get_log_path(path: &str) -> String {
         format!("{}.{}", path, LOG_EXTENSION)
     }
@@ -132,6 +184,14 @@ impl Segment {
     fn get_index_path(path: &str) -> String {
         format!("{}.{}", path, INDEX_EXTENSION)
     }
+
+    fn get_key_version_path(path: &str) -> String {
+        format!("{}.{}", path, KEY_VERSION_EXTENSION)
+    }
+
+    fn get_key_filter_path(path: &str) -> String {
+        format!("{}.{}", path, KEY_FILTER_EXTENSION)
+    }
 }
 
 #[cfg(test)]
@@ -144,6 +204,7 @@ mod tests {
     #[tokio::test]
     async fn should_be_created_given_valid_parameters() {
         let storage = Arc::new(get_test_system_storage());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -152,6 +213,8 @@ mod tests {
         let path = config.get_segment_path(stream_id, topic_id, partition_id, start_offset);
         let log_path = Segment::get_log_path(&path);
         let index_path = Segment::get_index_path(&path);
+        let key_version_path = Segment::get_key_version_path(&path);
+        let key_filter_path = Segment::get_key_filter_path(&path);
         let message_expiry = IggyExpiry::ExpireDuration(IggyDuration::from(10));
         let size_of_parent_stream = Arc::new(AtomicU64::new(0));
         let size_of_parent_topic = Arc::new(AtomicU64::new(0));
@@ -167,6 +230,7 @@ mod tests {
             start_offset,
             config,
             storage,
+            events_bus,
             message_expiry,
             size_of_parent_stream,
             size_of_parent_topic,
@@ -185,16 +249,21 @@ mod tests {
         assert_eq!(segment.size_bytes, 0);
         assert_eq!(segment.log_path, log_path);
         assert_eq!(segment.index_path, index_path);
+        assert_eq!(segment.key_version_path, key_version_path);
+        assert_eq!(segment.key_filter_path, key_filter_path);
         assert_eq!(segment.message_expiry, message_expiry);
         assert!(segment.unsaved_messages.is_none());
         assert!(segment.indexes.is_some());
         assert!(!segment.is_closed);
+        assert!(segment.key_version.is_none());
+        assert!(segment.key_filter.is_none());
         assert!(!segment.is_full().await);
     }
 
     #[test]
     fn should_not_initialize_indexes_cache_when_disabled() {
         let storage = Arc::new(get_test_system_storage());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let partition_id = 3;
@@ -221,6 +290,7 @@ mod tests {
             start_offset,
             config,
             storage,
+            events_bus,
             message_expiry,
             size_of_parent_stream,
             size_of_parent_topic,
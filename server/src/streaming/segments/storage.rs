@@ -1,38 +1,79 @@
 use crate::streaming::batching::iterator::IntoMessagesIterator;
-use crate::streaming::batching::message_batch::RetainedMessageBatch;
+use crate::streaming::batching::message_batch::{RetainedMessageBatch, RETAINED_BATCH_OVERHEAD};
 use crate::streaming::models::messages::RetainedMessage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::segments::index::{Index, IndexRange};
-use crate::streaming::segments::segment::Segment;
+use crate::streaming::segments::key_filter::KeyFilter;
+use crate::streaming::segments::open_files::OpenSegmentFiles;
+use crate::streaming::segments::recovery::recover_partially_written_batch;
+use crate::streaming::segments::segment::{Segment, COMPACTED_EXTENSION};
 use crate::streaming::storage::SegmentStorage;
 use crate::streaming::utils::file;
 use crate::streaming::utils::head_tail_buf::HeadTailBuffer;
+use crate::streaming::utils::io_budget::{BackgroundTask, IoBudget};
 use anyhow::Context;
 use async_trait::async_trait;
 use bytes::{BufMut, BytesMut};
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::checksum;
 use iggy::utils::sizeable::Sizeable;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use tokio::fs;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::{error, info, trace, warn};
 
 const EMPTY_INDEXES: Vec<Index> = vec![];
 pub const INDEX_SIZE: u32 = 16; // offset: 4 bytes, position: 4 bytes, timestamp: 8 bytes
 const BUF_READER_CAPACITY_BYTES: usize = 512 * 1000;
+const KEY_VERSION_SIZE: u64 = 4;
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
     persister: Arc<dyn Persister>,
+    open_files: OpenSegmentFiles,
+    io_budget: Arc<IoBudget>,
 }
 
 impl FileSegmentStorage {
-    pub fn new(persister: Arc<dyn Persister>) -> Self {
-        Self { persister }
+    pub fn new(
+        persister: Arc<dyn Persister>,
+        max_open_files: u32,
+        io_budget: Arc<IoBudget>,
+    ) -> Self {
+        Self {
+            persister,
+            open_files: OpenSegmentFiles::new(max_open_files),
+            io_budget,
+        }
+    }
+
+    async fn save_compacted_key_version(
+        &self,
+        segment: &Segment,
+        key_version: u32,
+    ) -> Result<(), IggyError> {
+        let compacted_key_version_path = get_compacted_path(&segment.key_version_path);
+        if let Err(err) = self
+            .persister
+            .overwrite(&compacted_key_version_path, &key_version.to_le_bytes())
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save key version to segment: {}",
+                    segment.key_version_path
+                )
+            })
+        {
+            return Err(IggyError::CannotSaveMessagesToSegment(err));
+        }
+
+        Ok(())
     }
 }
 
@@ -46,20 +87,36 @@ impl SegmentStorage for FileSegmentStorage {
             "Loading segment from disk for start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {} ...",
             segment.start_offset, segment.partition_id, segment.topic_id, segment.stream_id
         );
+        complete_interrupted_rewrite(segment).await?;
+        if let Some(report) = recover_partially_written_batch(
+            segment,
+            &mut self.io_budget.throttler(BackgroundTask::Recovery),
+        )
+        .await?
+        {
+            warn!(
+                "Recovered segment ending with partially written or corrupted batch, truncated {} of log file, removed {} and rebuilt {} index(es). Recovery report: {report}",
+                IggyByteSize::from(report.get_truncated_bytes()),
+                report.get_removed_indexes(),
+                report.rebuilt_indexes
+            );
+        }
+
         let log_file = file::open(&segment.log_path).await?;
-        let file_size = log_file.metadata().await.unwrap().len() as u64;
+        let file_metadata = log_file.metadata().await.unwrap();
+        let file_size = file_metadata.len() as u64;
+        // Not every file system keeps the creation time, so the last modification time is used as a fallback.
+        if let Ok(created_at) = file_metadata
+            .created()
+            .or_else(|_| file_metadata.modified())
+        {
+            segment.created_at = IggyTimestamp::zero() + created_at;
+        }
         segment.size_bytes = IggyByteSize::from(file_size);
         segment.last_index_position = file_size as _;
 
-        if segment.config.segment.cache_indexes {
+        let last_index = if segment.config.segment.cache_indexes {
             segment.indexes = Some(segment.storage.segment.load_all_indexes(segment).await?);
-            let last_index_offset = if segment.indexes.as_ref().unwrap().is_empty() {
-                0_u64
-            } else {
-                segment.indexes.as_ref().unwrap().last().unwrap().offset as u64
-            };
-
-            segment.current_offset = segment.start_offset + last_index_offset;
             info!(
                 "Loaded {} indexes for segment with start offset: {} and partition with ID: {} for topic with ID: {} and stream with ID: {}.",
                 segment.indexes.as_ref().unwrap().len(),
@@ -68,12 +125,39 @@ impl SegmentStorage for FileSegmentStorage {
                 segment.topic_id,
                 segment.stream_id
             );
+            segment.indexes.as_ref().unwrap().last().copied()
+        } else {
+            load_last_index(&self.open_files, segment).await?
+        };
+
+        // The index is sparse, so the batches saved after the last index entry have to be read to find the current offset.
+        if let Some(last_index) = last_index {
+            segment.last_indexed_position = Some(last_index.position);
+            let (last_offset, max_timestamp) =
+                load_last_batch_offset_and_max_timestamp(&self.open_files, segment, last_index)
+                    .await?;
+            segment.current_offset =
+                last_offset.unwrap_or(segment.start_offset + last_index.offset as u64);
+            segment.max_timestamp = max_timestamp;
         }
 
         if segment.is_full().await {
             segment.is_closed = true;
         }
 
+        if Path::new(&segment.key_version_path).exists() {
+            let key_version: [u8; 4] = fs::read(&segment.key_version_path)
+                .await?
+                .try_into()
+                .map_err(|_| {
+                    IggyError::CannotLoadResource(anyhow::anyhow!(
+                        "Invalid key version in file: {}",
+                        segment.key_version_path
+                    ))
+                })?;
+            segment.key_version = Some(u32::from_le_bytes(key_version));
+        }
+
         let messages_count = segment.get_messages_count();
 
         info!(
@@ -143,8 +227,15 @@ impl SegmentStorage for FileSegmentStorage {
             "Deleting segment of size {segment_size} with start offset: {} for partition with ID: {} for stream with ID: {} and topic with ID: {}...",
             segment.start_offset, segment.partition_id, segment.stream_id, segment.topic_id,
         );
+        self.close_files(segment);
         self.persister.delete(&segment.log_path).await?;
         self.persister.delete(&segment.index_path).await?;
+        if Path::new(&segment.key_version_path).exists() {
+            self.persister.delete(&segment.key_version_path).await?;
+        }
+        if Path::new(&segment.key_filter_path).exists() {
+            self.persister.delete(&segment.key_filter_path).await?;
+        }
         let segment_size_bytes = segment.size_bytes.as_bytes_u64();
         segment
             .size_of_parent_stream
@@ -171,13 +262,18 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(())
     }
 
+    fn close_files(&self, segment: &Segment) {
+        self.open_files.close(&segment.log_path);
+        self.open_files.close(&segment.index_path);
+    }
+
     async fn load_message_batches(
         &self,
         segment: &Segment,
         index_range: &IndexRange,
     ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
         let mut batches = Vec::new();
-        load_batches_by_range(segment, index_range, |batch| {
+        load_batches_by_range(&self.open_files, segment, index_range, |batch| {
             batches.push(batch);
             Ok(())
         })
@@ -193,7 +289,7 @@ impl SegmentStorage for FileSegmentStorage {
     ) -> Result<Vec<RetainedMessageBatch>, IggyError> {
         let mut batches = Vec::new();
         let mut total_size_bytes = IggyByteSize::default();
-        load_messages_by_size(segment, size_bytes, |batch| {
+        load_messages_by_size(&self.open_files, segment, size_bytes, |batch| {
             total_size_bytes += batch.get_size_bytes();
             batches.push(batch);
             Ok(())
@@ -229,48 +325,175 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(batch_size)
     }
 
+    async fn save_compacted_batches(
+        &self,
+        segment: &Segment,
+        batches: &[RetainedMessageBatch],
+        indexes: &[Index],
+        key_version: Option<u32>,
+    ) -> Result<(), IggyError> {
+        let mut log_bytes = BytesMut::with_capacity(
+            batches
+                .iter()
+                .map(|batch| batch.get_size_bytes().as_bytes_usize())
+                .sum(),
+        );
+        for batch in batches {
+            batch.extend(&mut log_bytes);
+        }
+
+        let mut index_bytes = BytesMut::with_capacity(indexes.len() * INDEX_SIZE as usize);
+        for index in indexes {
+            index_bytes.put_u32_le(index.offset);
+            index_bytes.put_u32_le(index.position);
+            index_bytes.put_u64_le(index.timestamp);
+        }
+
+        // Write the compacted files next to the original ones first, so that the segment is never left half-written.
+        // The log file is renamed first, so the rewrite interrupted after that is completed on load, see `complete_interrupted_rewrite`.
+        let compacted_log_path = get_compacted_path(&segment.log_path);
+        let compacted_index_path = get_compacted_path(&segment.index_path);
+        let compacted_key_version_path = get_compacted_path(&segment.key_version_path);
+        if let Err(err) = self
+            .persister
+            .overwrite(&compacted_log_path, &log_bytes)
+            .await
+            .with_context(|| format!("Failed to save compacted segment: {}", segment.log_path))
+        {
+            return Err(IggyError::CannotSaveMessagesToSegment(err));
+        }
+
+        if let Err(err) = self
+            .persister
+            .overwrite(&compacted_index_path, &index_bytes)
+            .await
+            .with_context(|| format!("Failed to save compacted index: {}", segment.index_path))
+        {
+            return Err(IggyError::CannotSaveIndexToSegment(err));
+        }
+
+        if let Some(key_version) = key_version {
+            self.save_compacted_key_version(segment, key_version)
+                .await?;
+        }
+
+        self.close_files(segment);
+        fs::rename(&compacted_log_path, &segment.log_path).await?;
+        fs::rename(&compacted_index_path, &segment.index_path).await?;
+        if key_version.is_some() {
+            fs::rename(&compacted_key_version_path, &segment.key_version_path).await?;
+        }
+        Ok(())
+    }
+
+    async fn save_key_version(&self, segment: &Segment) -> Result<(), IggyError> {
+        let Some(key_version) = segment.key_version else {
+            return Ok(());
+        };
+
+        self.save_compacted_key_version(segment, key_version)
+            .await?;
+        fs::rename(
+            get_compacted_path(&segment.key_version_path),
+            &segment.key_version_path,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn save_key_filter(
+        &self,
+        segment: &Segment,
+        key_filter: &KeyFilter,
+    ) -> Result<(), IggyError> {
+        if let Err(err) = self
+            .persister
+            .overwrite(&segment.key_filter_path, &key_filter.to_bytes())
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to save key filter to segment: {}",
+                    segment.key_filter_path
+                )
+            })
+        {
+            return Err(IggyError::CannotSaveIndexToSegment(err));
+        }
+
+        Ok(())
+    }
+
+    async fn load_key_filter(&self, segment: &Segment) -> Result<Option<KeyFilter>, IggyError> {
+        if !Path::new(&segment.key_filter_path).exists() {
+            return Ok(None);
+        }
+
+        let bytes = fs::read(&segment.key_filter_path).await?;
+        match KeyFilter::from_bytes(&bytes) {
+            Ok(key_filter) => Ok(Some(key_filter)),
+            Err(error) => {
+                warn!(
+                    "Invalid key filter in file: {}, it will be rebuilt. {error}",
+                    segment.key_filter_path
+                );
+                Ok(None)
+            }
+        }
+    }
+
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError> {
         let mut message_ids = Vec::new();
-        load_batches_by_range(segment, &IndexRange::max_range(), |batch| {
-            message_ids.extend(
-                batch
-                    .into_messages_iter()
-                    .map(|msg: RetainedMessage| msg.id),
-            );
-            Ok(())
-        })
+        load_batches_by_range(
+            &self.open_files,
+            segment,
+            &IndexRange::max_range(),
+            |batch| {
+                message_ids.extend(
+                    batch
+                        .into_messages_iter()
+                        .map(|msg: RetainedMessage| msg.id),
+                );
+                Ok(())
+            },
+        )
         .await?;
         trace!("Loaded {} message IDs from disk.", message_ids.len());
         Ok(message_ids)
     }
 
     async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError> {
-        load_batches_by_range(segment, &IndexRange::max_range(), |batch| {
-            for message in batch.into_messages_iter() {
-                let calculated_checksum = checksum::calculate(&message.payload);
-                trace!(
-                    "Loaded message for offset: {}, checksum: {}, expected: {}",
-                    message.offset,
-                    calculated_checksum,
-                    message.checksum
-                );
-                if calculated_checksum != message.checksum {
-                    return Err(IggyError::InvalidMessageChecksum(
-                        calculated_checksum,
-                        message.checksum,
+        load_batches_by_range(
+            &self.open_files,
+            segment,
+            &IndexRange::max_range(),
+            |batch| {
+                batch.validate_checksum()?;
+                for message in batch.into_messages_iter() {
+                    let calculated_checksum = checksum::calculate(&message.payload);
+                    trace!(
+                        "Loaded message for offset: {}, checksum: {}, expected: {}",
                         message.offset,
-                    ));
+                        calculated_checksum,
+                        message.checksum
+                    );
+                    if calculated_checksum != message.checksum {
+                        return Err(IggyError::InvalidMessageChecksum(
+                            calculated_checksum,
+                            message.checksum,
+                            message.offset,
+                        ));
+                    }
                 }
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
         .await?;
         Ok(())
     }
 
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError> {
         trace!("Loading indexes from file...");
-        let file = file::open(&segment.index_path).await?;
+        let mut file = self.open_files.open(&segment.index_path).await?;
         let file_size = file.metadata().await?.len() as usize;
         if file_size == 0 {
             trace!("Index file is empty.");
@@ -279,7 +502,7 @@ impl SegmentStorage for FileSegmentStorage {
 
         let indexes_count = file_size / INDEX_SIZE as usize;
         let mut indexes = Vec::with_capacity(indexes_count);
-        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, &mut *file);
         for idx_num in 0..indexes_count {
             let offset = reader.read_u32_le().await.inspect_err(|error| {
                 error!(
@@ -340,7 +563,7 @@ impl SegmentStorage for FileSegmentStorage {
             return Ok(None);
         }
 
-        let file = file::open(&segment.index_path).await?;
+        let mut file = self.open_files.open(&segment.index_path).await?;
         let file_length = file.metadata().await?.len() as u32;
         if file_length == 0 {
             trace!("Index file is empty.");
@@ -355,12 +578,15 @@ impl SegmentStorage for FileSegmentStorage {
             relative_start_offset,
             relative_end_offset,
         );
-        let mut idx_pred = HeadTailBuffer::new();
-        let mut index_range = IndexRange::default();
+        // The index is sparse, see `Segment::load_highest_lower_bound_index()` for the range boundaries.
+        let mut index_range = IndexRange {
+            start: Index::default(),
+            end: IndexRange::max_range().end,
+        };
         let mut read_bytes = 0;
 
-        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
-        loop {
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, &mut *file);
+        while read_bytes < file_length {
             let offset = reader.read_u32_le().await?;
             let position = reader.read_u32_le().await?;
             let timestamp = reader.read_u64_le().await?;
@@ -370,19 +596,14 @@ impl SegmentStorage for FileSegmentStorage {
                 position,
                 timestamp,
             };
-            idx_pred.push(idx);
 
-            if offset >= relative_start_offset {
-                index_range.start = idx_pred.tail().unwrap_or_default();
+            if offset < relative_start_offset {
+                index_range.start = idx;
             }
             if offset >= relative_end_offset {
                 index_range.end = idx;
                 break;
             }
-
-            if read_bytes == file_length {
-                break;
-            }
         }
 
         trace!(
@@ -418,14 +639,14 @@ impl SegmentStorage for FileSegmentStorage {
         timestamp: u64,
     ) -> Result<Option<Index>, IggyError> {
         trace!("Loading time indexes from file...");
-        let file = file::open(&segment.index_path).await?;
+        let mut file = self.open_files.open(&segment.index_path).await?;
         let file_size = file.metadata().await?.len() as usize;
         if file_size == 0 {
             trace!("Time index file is empty.");
             return Ok(Some(Index::default()));
         }
 
-        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+        let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, &mut *file);
         let mut read_bytes = 0;
         let mut idx_pred = HeadTailBuffer::new();
         loop {
@@ -449,12 +670,104 @@ impl SegmentStorage for FileSegmentStorage {
     }
 }
 
+fn get_compacted_path(path: &str) -> String {
+    format!("{path}.{COMPACTED_EXTENSION}")
+}
+
+/// Completes the rewrite of the segment files (compaction or re-encryption) interrupted by the crash.
+/// The compacted files are all written before any of them is renamed, the log file first. So if the compacted
+/// log file still exists, the original files are intact and the compacted ones are discarded, otherwise
+/// the remaining compacted files (index and key version) are complete and renamed over the original ones.
+async fn complete_interrupted_rewrite(segment: &Segment) -> Result<(), IggyError> {
+    let compacted_log_path = get_compacted_path(&segment.log_path);
+    let rewritten_paths = [&segment.index_path, &segment.key_version_path];
+    if file::exists(&compacted_log_path).await? {
+        warn!(
+            "Discarding interrupted rewrite of segment log file: {}",
+            segment.log_path
+        );
+        file::remove(&compacted_log_path).await?;
+        for path in rewritten_paths {
+            let compacted_path = get_compacted_path(path);
+            if file::exists(&compacted_path).await? {
+                file::remove(&compacted_path).await?;
+            }
+        }
+        return Ok(());
+    }
+
+    for path in rewritten_paths {
+        let compacted_path = get_compacted_path(path);
+        if !file::exists(&compacted_path).await? {
+            continue;
+        }
+
+        // The key version alone is also saved this way, e.g. if there was nothing to re-encrypt, so it might be partially written.
+        if path == &segment.key_version_path
+            && fs::metadata(&compacted_path).await?.len() != KEY_VERSION_SIZE
+        {
+            warn!("Discarding partially written segment key version file: {compacted_path}");
+            file::remove(&compacted_path).await?;
+            continue;
+        }
+
+        warn!("Completing interrupted rewrite of segment file: {path}");
+        file::rename(&compacted_path, path).await?;
+    }
+    Ok(())
+}
+
+async fn load_last_index(
+    open_files: &OpenSegmentFiles,
+    segment: &Segment,
+) -> Result<Option<Index>, IggyError> {
+    let mut file = open_files.open(&segment.index_path).await?;
+    let file_size = file.metadata().await?.len();
+    if file_size < INDEX_SIZE as u64 {
+        return Ok(None);
+    }
+
+    let mut reader = BufReader::new(&mut *file);
+    reader
+        .seek(SeekFrom::Start(file_size - INDEX_SIZE as u64))
+        .await?;
+    let offset = reader.read_u32_le().await?;
+    let position = reader.read_u32_le().await?;
+    let timestamp = reader.read_u64_le().await?;
+    Ok(Some(Index {
+        offset,
+        position,
+        timestamp,
+    }))
+}
+
+async fn load_last_batch_offset_and_max_timestamp(
+    open_files: &OpenSegmentFiles,
+    segment: &Segment,
+    last_index: Index,
+) -> Result<(Option<u64>, u64), IggyError> {
+    let index_range = IndexRange {
+        start: last_index,
+        end: IndexRange::max_range().end,
+    };
+    let mut last_offset = None;
+    let mut max_timestamp = last_index.timestamp;
+    load_batches_by_range(open_files, segment, &index_range, |batch| {
+        last_offset = Some(batch.get_last_offset());
+        max_timestamp = max_timestamp.max(batch.max_timestamp);
+        Ok(())
+    })
+    .await?;
+    Ok((last_offset, max_timestamp))
+}
+
 async fn load_batches_by_range(
+    open_files: &OpenSegmentFiles,
     segment: &Segment,
     index_range: &IndexRange,
     mut on_batch: impl FnMut(RetainedMessageBatch) -> Result<(), IggyError>,
 ) -> Result<(), IggyError> {
-    let file = file::open(&segment.log_path).await?;
+    let mut file = open_files.open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
         return Ok(());
@@ -468,7 +781,7 @@ async fn load_batches_by_range(
         index_range.end.offset
     );
 
-    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, &mut *file);
     reader
         .seek(SeekFrom::Start(index_range.start.position as u64))
         .await?;
@@ -483,14 +796,36 @@ async fn load_batches_by_range(
             .read_u32_le()
             .await
             .map_err(|_| IggyError::CannotReadBatchLength)?;
+        let version = reader
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchFormatVersion)?;
+        RetainedMessageBatch::validate_format_version(version, batch_base_offset)?;
         let last_offset_delta = reader
             .read_u32_le()
             .await
             .map_err(|_| IggyError::CannotReadLastOffsetDelta)?;
+        let messages_count = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchMessagesCount)?;
+        let base_timestamp = reader
+            .read_u64_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBaseTimestamp)?;
         let max_timestamp = reader
             .read_u64_le()
             .await
             .map_err(|_| IggyError::CannotReadMaxTimestamp)?;
+        let attributes = reader
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchAttributes)?;
+        let compression_algorithm = CompressionAlgorithm::from_code(attributes)?;
+        let batch_checksum = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchChecksum)?;
 
         let last_offset = batch_base_offset + (last_offset_delta as u64);
         let index_last_offset = index_range.end.offset as u64 + segment.start_offset;
@@ -505,27 +840,32 @@ async fn load_batches_by_range(
             break;
         }
 
-        read_bytes += 8 + 4 + 4 + 8 + payload_len as u64;
+        read_bytes += RETAINED_BATCH_OVERHEAD + payload_len as u64;
         last_batch_to_read = read_bytes >= file_size || last_offset == index_last_offset;
 
-        let batch = RetainedMessageBatch::new(
-            batch_base_offset,
+        let batch = RetainedMessageBatch {
+            base_offset: batch_base_offset,
             last_offset_delta,
+            messages_count,
+            base_timestamp,
             max_timestamp,
-            IggyByteSize::from(batch_length as u64),
-            payload.freeze(),
-        );
+            compression_algorithm,
+            checksum: batch_checksum,
+            length: IggyByteSize::from(batch_length as u64),
+            bytes: payload.freeze(),
+        };
         on_batch(batch)?;
     }
     Ok(())
 }
 
 async fn load_messages_by_size(
+    open_files: &OpenSegmentFiles,
     segment: &Segment,
     size_bytes: u64,
     mut on_batch: impl FnMut(RetainedMessageBatch) -> Result<(), IggyError>,
 ) -> Result<(), IggyError> {
-    let file = file::open(&segment.log_path).await?;
+    let mut file = open_files.open(&segment.log_path).await?;
     let file_size = file.metadata().await?.len();
     if file_size == 0 {
         return Ok(());
@@ -534,7 +874,7 @@ async fn load_messages_by_size(
     let threshold = file_size.saturating_sub(size_bytes);
     let mut accumulated_size = IggyByteSize::default();
 
-    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, file);
+    let mut reader = BufReader::with_capacity(BUF_READER_CAPACITY_BYTES, &mut *file);
     loop {
         let batch_base_offset = reader
             .read_u64_le()
@@ -544,14 +884,36 @@ async fn load_messages_by_size(
             .read_u32_le()
             .await
             .map_err(|_| IggyError::CannotReadBatchLength)?;
+        let version = reader
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchFormatVersion)?;
+        RetainedMessageBatch::validate_format_version(version, batch_base_offset)?;
         let last_offset_delta = reader
             .read_u32_le()
             .await
             .map_err(|_| IggyError::CannotReadLastOffsetDelta)?;
+        let messages_count = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchMessagesCount)?;
+        let base_timestamp = reader
+            .read_u64_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBaseTimestamp)?;
         let max_timestamp = reader
             .read_u64_le()
             .await
             .map_err(|_| IggyError::CannotReadMaxTimestamp)?;
+        let attributes = reader
+            .read_u8()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchAttributes)?;
+        let compression_algorithm = CompressionAlgorithm::from_code(attributes)?;
+        let batch_checksum = reader
+            .read_u32_le()
+            .await
+            .map_err(|_| IggyError::CannotReadBatchChecksum)?;
 
         let payload_len = batch_length as usize;
         let mut payload = BytesMut::with_capacity(payload_len);
@@ -561,13 +923,17 @@ async fn load_messages_by_size(
             .await
             .map_err(|_| IggyError::CannotReadBatchPayload)?;
 
-        let batch = RetainedMessageBatch::new(
-            batch_base_offset,
+        let batch = RetainedMessageBatch {
+            base_offset: batch_base_offset,
             last_offset_delta,
+            messages_count,
+            base_timestamp,
             max_timestamp,
-            IggyByteSize::from(batch_length as u64),
-            payload.freeze(),
-        );
+            compression_algorithm,
+            checksum: batch_checksum,
+            length: IggyByteSize::from(batch_length as u64),
+            bytes: payload.freeze(),
+        };
         let message_size = batch.get_size_bytes();
         if accumulated_size >= threshold {
             on_batch(batch)?;
@@ -1,15 +1,21 @@
 use iggy::models::user_info::{AtomicUserId, UserId};
+use iggy::utils::timestamp::IggyTimestamp;
 use std::fmt::Display;
 use std::net::SocketAddr;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use tokio::sync::Notify;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
 #[derive(Debug)]
 pub struct Session {
     user_id: AtomicUserId,
     active: AtomicBool,
+    last_command_code: AtomicU32,
+    last_command_at: AtomicU64,
+    termination: Notify,
     pub client_id: u32,
     pub ip_address: SocketAddr,
+    pub connected_at: IggyTimestamp,
 }
 
 impl Session {
@@ -17,8 +23,12 @@ impl Session {
         Self {
             client_id,
             active: AtomicBool::new(true),
+            last_command_code: AtomicU32::new(0),
+            last_command_at: AtomicU64::new(0),
+            termination: Notify::new(),
             user_id: AtomicUserId::new(user_id),
             ip_address,
+            connected_at: IggyTimestamp::now(),
         }
     }
 
@@ -42,6 +52,46 @@ impl Session {
         self.active.store(false, Ordering::Release)
     }
 
+    /// Marks the session as stale and wakes up the connection handler waiting for the termination, which closes the connection.
+    pub fn terminate(&self) {
+        self.set_stale();
+        self.termination.notify_waiters();
+    }
+
+    /// Completes once the session is terminated, or immediately if it's not active anymore.
+    pub async fn terminated(&self) {
+        let notified = self.termination.notified();
+        if !self.is_active() {
+            return;
+        }
+        notified.await;
+    }
+
+    pub fn set_last_command(&self, code: u32) {
+        self.last_command_at
+            .store(IggyTimestamp::now().into(), Ordering::Release);
+        self.last_command_code.store(code, Ordering::Release);
+    }
+
+    /// Returns the code and the timestamp of the last command handled for the session, if any.
+    pub fn get_last_command(&self) -> Option<(u32, IggyTimestamp)> {
+        match self.last_command_code.load(Ordering::Acquire) {
+            0 => None,
+            code => Some((
+                code,
+                IggyTimestamp::from(self.last_command_at.load(Ordering::Acquire)),
+            )),
+        }
+    }
+
+    /// Returns the timestamp of the last command handled for the session, or of the connection if there was none yet.
+    pub fn get_last_activity(&self) -> IggyTimestamp {
+        match self.last_command_at.load(Ordering::Acquire) {
+            0 => self.connected_at,
+            last_command_at => IggyTimestamp::from(last_command_at),
+        }
+    }
+
     pub fn clear_user_id(&self) {
         self.set_user_id(0)
     }
@@ -1,10 +1,11 @@
 use super::batching::message_batch::RetainedMessageBatch;
 use crate::configs::system::SystemConfig;
 use crate::state::system::{PartitionState, StreamState, TopicState};
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::partition::{ConsumerOffset, LoadedConsumerOffsets, Partition};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
 use crate::streaming::segments::index::{Index, IndexRange};
+use crate::streaming::segments::key_filter::KeyFilter;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
 use crate::streaming::streams::storage::FileStreamStorage;
@@ -13,10 +14,12 @@ use crate::streaming::systems::info::SystemInfo;
 use crate::streaming::systems::storage::FileSystemInfoStorage;
 use crate::streaming::topics::storage::FileTopicStorage;
 use crate::streaming::topics::topic::Topic;
+use crate::streaming::utils::io_budget::IoBudget;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::IggyError;
 use iggy::utils::byte_size::IggyByteSize;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -51,9 +54,21 @@ pub trait PartitionStorage: Send + Sync {
         &self,
         kind: ConsumerKind,
         path: &str,
-    ) -> Result<Vec<ConsumerOffset>, IggyError>;
+    ) -> Result<LoadedConsumerOffsets, IggyError>;
     async fn delete_consumer_offsets(&self, path: &str) -> Result<(), IggyError>;
-    async fn delete_consumer_offset(&self, path: &str) -> Result<(), IggyError>;
+    async fn delete_consumer_offset(&self, offset: &ConsumerOffset) -> Result<(), IggyError>;
+    async fn compact_consumer_offsets(
+        &self,
+        path: &str,
+        offsets: &[ConsumerOffset],
+        names: &HashMap<u32, String>,
+    ) -> Result<u64, IggyError>;
+    async fn save_consumer_name(
+        &self,
+        path: &str,
+        consumer_id: u32,
+        name: &str,
+    ) -> Result<(), IggyError>;
 }
 
 #[async_trait]
@@ -61,6 +76,7 @@ pub trait SegmentStorage: Send + Sync {
     async fn load(&self, segment: &mut Segment) -> Result<(), IggyError>;
     async fn save(&self, segment: &Segment) -> Result<(), IggyError>;
     async fn delete(&self, segment: &Segment) -> Result<(), IggyError>;
+    fn close_files(&self, segment: &Segment);
     async fn load_message_batches(
         &self,
         segment: &Segment,
@@ -76,6 +92,20 @@ pub trait SegmentStorage: Send + Sync {
         segment: &Segment,
         batch: RetainedMessageBatch,
     ) -> Result<IggyByteSize, IggyError>;
+    async fn save_compacted_batches(
+        &self,
+        segment: &Segment,
+        batches: &[RetainedMessageBatch],
+        indexes: &[Index],
+        key_version: Option<u32>,
+    ) -> Result<(), IggyError>;
+    async fn save_key_version(&self, segment: &Segment) -> Result<(), IggyError>;
+    async fn save_key_filter(
+        &self,
+        segment: &Segment,
+        key_filter: &KeyFilter,
+    ) -> Result<(), IggyError>;
+    async fn load_key_filter(&self, segment: &Segment) -> Result<Option<KeyFilter>, IggyError>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, IggyError>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), IggyError>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, IggyError>;
@@ -101,10 +131,12 @@ pub struct SystemStorage {
     pub partition: Arc<dyn PartitionStorage>,
     pub segment: Arc<dyn SegmentStorage>,
     pub persister: Arc<dyn Persister>,
+    pub io_budget: Arc<IoBudget>,
 }
 
 impl SystemStorage {
     pub fn new(config: Arc<SystemConfig>, persister: Arc<dyn Persister>) -> Self {
+        let io_budget = Arc::new(IoBudget::new(&config.background_io));
         Self {
             info: Arc::new(FileSystemInfoStorage::new(
                 config.get_state_info_path(),
@@ -113,8 +145,13 @@ impl SystemStorage {
             stream: Arc::new(FileStreamStorage),
             topic: Arc::new(FileTopicStorage),
             partition: Arc::new(FilePartitionStorage::new(persister.clone())),
-            segment: Arc::new(FileSegmentStorage::new(persister.clone())),
+            segment: Arc::new(FileSegmentStorage::new(
+                persister.clone(),
+                config.segment.max_open_files,
+                io_budget.clone(),
+            )),
             persister,
+            io_budget,
         }
     }
 }
@@ -153,6 +190,7 @@ impl Debug for dyn SegmentStorage {
 pub(crate) mod tests {
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::segments::index::{Index, IndexRange};
+    use crate::streaming::segments::key_filter::KeyFilter;
     use crate::streaming::segments::segment::Segment;
     use crate::streaming::storage::*;
     use crate::streaming::streams::stream::Stream;
@@ -249,15 +287,33 @@ pub(crate) mod tests {
             &self,
             _kind: ConsumerKind,
             _path: &str,
-        ) -> Result<Vec<ConsumerOffset>, IggyError> {
-            Ok(vec![])
+        ) -> Result<LoadedConsumerOffsets, IggyError> {
+            Ok(LoadedConsumerOffsets::default())
         }
 
         async fn delete_consumer_offsets(&self, _path: &str) -> Result<(), IggyError> {
             Ok(())
         }
 
-        async fn delete_consumer_offset(&self, _path: &str) -> Result<(), IggyError> {
+        async fn delete_consumer_offset(&self, _offset: &ConsumerOffset) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn compact_consumer_offsets(
+            &self,
+            _path: &str,
+            _offsets: &[ConsumerOffset],
+            _names: &HashMap<u32, String>,
+        ) -> Result<u64, IggyError> {
+            Ok(0)
+        }
+
+        async fn save_consumer_name(
+            &self,
+            _path: &str,
+            _consumer_id: u32,
+            _name: &str,
+        ) -> Result<(), IggyError> {
             Ok(())
         }
     }
@@ -276,6 +332,8 @@ pub(crate) mod tests {
             Ok(())
         }
 
+        fn close_files(&self, _segment: &Segment) {}
+
         async fn load_message_batches(
             &self,
             _segment: &Segment,
@@ -300,6 +358,35 @@ pub(crate) mod tests {
             Ok(IggyByteSize::default())
         }
 
+        async fn save_compacted_batches(
+            &self,
+            _segment: &Segment,
+            _batches: &[RetainedMessageBatch],
+            _indexes: &[Index],
+            _key_version: Option<u32>,
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save_key_version(&self, _segment: &Segment) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn save_key_filter(
+            &self,
+            _segment: &Segment,
+            _key_filter: &KeyFilter,
+        ) -> Result<(), IggyError> {
+            Ok(())
+        }
+
+        async fn load_key_filter(
+            &self,
+            _segment: &Segment,
+        ) -> Result<Option<KeyFilter>, IggyError> {
+            Ok(None)
+        }
+
         async fn load_message_ids(&self, _segment: &Segment) -> Result<Vec<u128>, IggyError> {
             Ok(vec![])
         }
@@ -342,6 +429,7 @@ pub(crate) mod tests {
             partition: Arc::new(TestPartitionStorage {}),
             segment: Arc::new(TestSegmentStorage {}),
             persister: Arc::new(TestPersister {}),
+            io_budget: Arc::new(IoBudget::new(&Default::default())),
         }
     }
 }
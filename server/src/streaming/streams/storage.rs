@@ -1,6 +1,7 @@
 use crate::state::system::StreamState;
 use crate::streaming::storage::StreamStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::deletion::is_deleted_topic_directory;
 use crate::streaming::topics::topic::Topic;
 use async_trait::async_trait;
 use futures::future::join_all;
@@ -44,6 +45,14 @@ impl StreamStorage for FileStreamStorage {
         let mut dir_entries = dir_entries.unwrap();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let name = dir_entry.file_name().into_string().unwrap();
+            if is_deleted_topic_directory(&name) {
+                warn!("Removing files of deleted topic from directory with name: '{name}' for stream with ID: '{}'.", stream.stream_id);
+                if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
+                    error!("Cannot remove deleted topic directory: {error}");
+                }
+                continue;
+            }
+
             let topic_id = name.parse::<u32>();
             if topic_id.is_err() {
                 error!("Invalid topic ID file with name: '{}'.", name);
@@ -73,10 +82,37 @@ impl StreamStorage for FileStreamStorage {
                 stream.segments_count.clone(),
                 stream.config.clone(),
                 stream.storage.clone(),
+                stream.appends_broadcaster.clone(),
+                stream.events_bus.clone(),
             );
             unloaded_topics.push(topic);
         }
 
+        for data_path in &stream.config.placement.paths {
+            let topics_path = stream
+                .config
+                .get_data_topics_path(data_path, stream.stream_id);
+            let Ok(mut dir_entries) = fs::read_dir(&topics_path).await else {
+                continue;
+            };
+
+            while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+                let name = dir_entry.file_name().into_string().unwrap();
+                let is_deleted = is_deleted_topic_directory(&name);
+                let is_in_state = name
+                    .parse::<u32>()
+                    .is_ok_and(|topic_id| state.topics.contains_key(&topic_id));
+                if is_in_state && !is_deleted {
+                    continue;
+                }
+
+                warn!("Removing directory with name: '{name}' from data path: '{data_path}' for stream with ID: '{}', as its topic was deleted or not found in state.", stream.stream_id);
+                if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
+                    error!("Cannot remove topic directory: {error}");
+                }
+            }
+        }
+
         let state_topic_ids = state.topics.keys().copied().collect::<HashSet<u32>>();
         let unloaded_topic_ids = unloaded_topics
             .iter()
@@ -113,6 +149,8 @@ impl StreamStorage for FileStreamStorage {
                     stream.segments_count.clone(),
                     stream.config.clone(),
                     stream.storage.clone(),
+                    stream.appends_broadcaster.clone(),
+                    stream.events_bus.clone(),
                 );
                 topic.persist().await?;
                 unloaded_topics.push(topic);
@@ -197,6 +235,16 @@ impl StreamStorage for FileStreamStorage {
         if fs::remove_dir_all(&stream.path).await.is_err() {
             return Err(IggyError::CannotDeleteStreamDirectory(stream.stream_id));
         }
+        for data_path in &stream.config.placement.paths {
+            let path = stream
+                .config
+                .get_data_stream_path(data_path, stream.stream_id);
+            match fs::remove_dir_all(&path).await {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(_) => return Err(IggyError::CannotDeleteStreamDirectory(stream.stream_id)),
+            }
+        }
         info!("Deleted stream with ID: {}.", stream.stream_id);
         Ok(())
     }
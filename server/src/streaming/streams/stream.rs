@@ -1,4 +1,6 @@
 use crate::configs::system::SystemConfig;
+use crate::streaming::appends::AppendsBroadcaster;
+use crate::streaming::events::EventsBus;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
 use iggy::utils::byte_size::IggyByteSize;
@@ -22,6 +24,8 @@ pub struct Stream {
     pub(crate) topics_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) appends_broadcaster: Arc<AppendsBroadcaster>,
+    pub(crate) events_bus: Arc<EventsBus>,
 }
 
 impl Stream {
@@ -30,8 +34,10 @@ impl Stream {
         name: &str,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        appends_broadcaster: Arc<AppendsBroadcaster>,
+        events_bus: Arc<EventsBus>,
     ) -> Self {
-        Stream::create(id, name, config, storage)
+        Stream::create(id, name, config, storage, appends_broadcaster, events_bus)
     }
 
     pub fn create(
@@ -39,6 +45,8 @@ impl Stream {
         name: &str,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        appends_broadcaster: Arc<AppendsBroadcaster>,
+        events_bus: Arc<EventsBus>,
     ) -> Self {
         let path = config.get_stream_path(id);
         let topics_path = config.get_topics_path(id);
@@ -56,6 +64,8 @@ impl Stream {
             topics: HashMap::new(),
             topics_ids: HashMap::new(),
             storage,
+            appends_broadcaster,
+            events_bus,
             created_at: IggyTimestamp::now(),
         }
     }
@@ -73,13 +83,15 @@ mod tests {
     #[test]
     fn should_be_created_given_valid_parameters() {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let id = 1;
         let name = "test";
         let config = Arc::new(SystemConfig::default());
         let path = config.get_stream_path(id);
         let topics_path = config.get_topics_path(id);
 
-        let stream = Stream::create(id, name, config, storage);
+        let stream = Stream::create(id, name, config, storage, appends_broadcaster, events_bus);
 
         assert_eq!(stream.stream_id, id);
         assert_eq!(stream.name, name);
@@ -1,11 +1,14 @@
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::deletion::TopicDeletions;
 use crate::streaming::topics::topic::Topic;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::text;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::sync::atomic::Ordering;
 use tracing::{debug, info};
@@ -25,13 +28,19 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<u32, IggyError> {
-        let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
         let name = text::to_lowercase_non_whitespace(name);
         if self.topics_ids.contains_key(&name) {
             return Err(IggyError::TopicNameAlreadyExists(name, self.stream_id));
         }
 
+        let max_topics = self.config.limits.max_topics_per_stream;
+        if max_topics > 0 && self.topics.len() as u32 >= max_topics {
+            return Err(IggyError::TopicsLimitReached(max_topics, self.stream_id));
+        }
+
         let mut id;
         if topic_id.is_none() {
             id = self.current_topic_id.fetch_add(1, Ordering::SeqCst);
@@ -60,6 +69,8 @@ impl Stream {
             partitions_count,
             self.config.clone(),
             self.storage.clone(),
+            self.appends_broadcaster.clone(),
+            self.events_bus.clone(),
             self.size_bytes.clone(),
             self.messages_count.clone(),
             self.segments_count.clone(),
@@ -67,6 +78,8 @@ impl Stream {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            cleanup_policy,
+            overrides,
         )?;
         topic.persist().await?;
         info!("Created topic {}", topic);
@@ -75,6 +88,7 @@ impl Stream {
         Ok(id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_topic(
         &mut self,
         id: &Identifier,
@@ -83,9 +97,14 @@ impl Stream {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<(), IggyError> {
-        let message_expiry = Topic::get_message_expiry(message_expiry, &self.config);
-        let max_topic_size = Topic::get_max_topic_size(max_topic_size, &self.config)?;
+        let config = Topic::get_effective_config(&self.config, &overrides)?;
+        let message_expiry_source = Topic::get_message_expiry_source(message_expiry);
+        let max_topic_size_source = Topic::get_max_topic_size_source(max_topic_size);
+        let message_expiry = Topic::get_message_expiry(message_expiry, &config);
+        let max_topic_size = Topic::get_max_topic_size(max_topic_size, &config)?;
         let topic_id;
         {
             let topic = self.get_topic(id)?;
@@ -126,7 +145,11 @@ impl Stream {
                 }
             }
             topic.max_topic_size = max_topic_size;
+            topic.message_expiry_source = message_expiry_source;
+            topic.max_topic_size_source = max_topic_size_source;
             topic.replication_factor = replication_factor;
+            topic.cleanup_policy = cleanup_policy;
+            topic.set_config(config, overrides).await;
             topic.persist().await?;
             info!("Updated topic: {topic}");
         }
@@ -208,7 +231,12 @@ impl Stream {
             .ok_or_else(|| IggyError::TopicIdNotFound(topic_id, self.stream_id))
     }
 
-    pub async fn delete_topic(&mut self, id: &Identifier) -> Result<Topic, IggyError> {
+    /// Removes the topic immediately, while its files are removed in the background by the topic deletions.
+    pub async fn delete_topic(
+        &mut self,
+        id: &Identifier,
+        deletions: &TopicDeletions,
+    ) -> Result<Topic, IggyError> {
         let topic = self.remove_topic(id)?;
         let topic_id = topic.topic_id;
         let current_topic_id = self.current_topic_id.load(Ordering::SeqCst);
@@ -216,10 +244,16 @@ impl Stream {
             self.current_topic_id.store(topic_id, Ordering::SeqCst);
         }
 
-        topic.delete().await.map_err(|err| {
+        let deleted_paths = topic.tombstone().await.map_err(|err| {
             debug!("Delete topic failed: {}", err);
             IggyError::CannotDeleteTopic(topic.topic_id, self.stream_id)
         })?;
+        deletions.spawn(
+            self.stream_id,
+            topic_id,
+            deleted_paths,
+            topic.size_bytes.load(Ordering::SeqCst),
+        );
         Ok(topic)
     }
 }
@@ -228,6 +262,8 @@ impl Stream {
 mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
     use crate::streaming::storage::tests::get_test_system_storage;
     use iggy::utils::byte_size::IggyByteSize;
     use std::sync::Arc;
@@ -244,7 +280,16 @@ mod tests {
         let max_topic_size = 2 * config.segment.size.as_bytes_u64();
         let max_topic_size = MaxTopicSize::Custom(IggyByteSize::from(max_topic_size));
         let storage = Arc::new(get_test_system_storage());
-        let mut stream = Stream::create(stream_id, stream_name, config, storage);
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
+        let mut stream = Stream::create(
+            stream_id,
+            stream_name,
+            config,
+            storage,
+            appends_broadcaster,
+            events_bus,
+        );
         stream
             .create_topic(
                 Some(topic_id),
@@ -254,6 +299,8 @@ mod tests {
                 compression_algorithm,
                 max_topic_size,
                 1,
+                Default::default(),
+                Default::default(),
             )
             .await
             .unwrap();
@@ -272,4 +319,54 @@ mod tests {
         assert_eq!(topic.name, topic_name);
         assert_eq!(topic.compression_algorithm, compression_algorithm);
     }
+
+    #[tokio::test]
+    async fn should_not_create_topic_over_the_limit() {
+        let mut config = SystemConfig::default();
+        config.limits.max_topics_per_stream = 1;
+        let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
+        let mut stream = Stream::create(
+            1,
+            "test_stream",
+            Arc::new(config),
+            storage,
+            appends_broadcaster,
+            events_bus,
+        );
+        stream
+            .create_topic(
+                Some(1),
+                "first",
+                1,
+                IggyExpiry::NeverExpire,
+                CompressionAlgorithm::None,
+                MaxTopicSize::ServerDefault,
+                1,
+                Default::default(),
+                Default::default(),
+            )
+            .await
+            .unwrap();
+
+        let result = stream
+            .create_topic(
+                Some(2),
+                "second",
+                1,
+                IggyExpiry::NeverExpire,
+                CompressionAlgorithm::None,
+                MaxTopicSize::ServerDefault,
+                1,
+                Default::default(),
+                Default::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(IggyError::TopicsLimitReached(1, 1))));
+        assert!(stream
+            .get_topic(&Identifier::named("second").unwrap())
+            .is_err());
+    }
 }
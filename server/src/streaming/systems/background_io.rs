@@ -0,0 +1,24 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::utils::byte_size::IggyByteSize;
+use tracing::info;
+
+impl System {
+    /// Replaces the rate of the background IO budget at runtime, until the server is restarted or the rate is replaced again.
+    pub fn set_background_io_rate(
+        &self,
+        session: &Session,
+        bytes_per_second: u64,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .set_background_io_rate(session.get_user_id())?;
+        self.storage.io_budget.set_rate(bytes_per_second);
+        info!(
+            "Set background IO rate to: {}/s",
+            IggyByteSize::from(bytes_per_second).as_human_string_with_zero_as_unlimited()
+        );
+        Ok(())
+    }
+}
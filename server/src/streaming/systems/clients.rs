@@ -2,7 +2,6 @@ use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
-use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
 use std::net::SocketAddr;
@@ -44,14 +43,15 @@ impl System {
             );
         }
 
+        for stream in self.streams.values() {
+            for topic in stream.topics.values() {
+                topic.release_exclusive_producer(client_id);
+            }
+        }
+
         for (stream_id, topic_id, consumer_group_id) in consumer_groups.into_iter() {
             _ = self
-                .leave_consumer_group_by_client(
-                    &Identifier::numeric(stream_id).unwrap(),
-                    &Identifier::numeric(topic_id).unwrap(),
-                    &Identifier::numeric(consumer_group_id).unwrap(),
-                    client_id,
-                )
+                .disconnect_consumer_group_member(stream_id, topic_id, consumer_group_id, client_id)
                 .await
         }
     }
@@ -76,4 +76,44 @@ impl System {
         let client_manager = self.client_manager.read().await;
         Ok(client_manager.get_clients())
     }
+
+    /// Returns the clients having the active session, the terminated ones are about to be removed once their connection is closed.
+    pub async fn get_sessions(
+        &self,
+        session: &Session,
+    ) -> Result<Vec<IggySharedMut<Client>>, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_sessions(session.get_user_id())?;
+        let client_manager = self.client_manager.read().await;
+        let mut clients = Vec::new();
+        for client in client_manager.get_clients() {
+            if client.read().await.session.is_active() {
+                clients.push(client);
+            }
+        }
+        Ok(clients)
+    }
+
+    /// Terminates the session of the client, so that its connection gets closed and the client removed by the connection handler.
+    pub async fn kill_session(&self, session: &Session, client_id: u32) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.kill_session(session.get_user_id())?;
+        let client_manager = self.client_manager.read().await;
+        let client = client_manager
+            .get_client(client_id)
+            .map_err(|_| IggyError::SessionNotFound(client_id))?;
+        let client = client.read().await;
+        if !client.session.is_active() {
+            return Err(IggyError::SessionNotFound(client_id));
+        }
+
+        client.session.terminate();
+        info!(
+            "Terminated session for {} client with ID: {client_id}, IP address: {}, by user with ID: {}.",
+            client.transport,
+            client.session.ip_address,
+            session.get_user_id()
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,186 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+use iggy::locking::IggySharedMutFn;
+use iggy::models::cluster_metadata::{BrokerInfo, ClusterMetadata, PartitionLeadership};
+use iggy::models::node_status::{NodeState, NodeStatus};
+use tracing::info;
+
+impl System {
+    /// Returns the nodes of the cluster and the leaders of the partitions of the topics visible to the user.
+    /// The server runs as a single node, which leads all of its partitions, unless their leadership has been transferred away while draining it.
+    pub async fn get_cluster_metadata(
+        &self,
+        session: &Session,
+    ) -> Result<ClusterMetadata, IggyError> {
+        self.ensure_authenticated(session)?;
+        let user_id = session.get_user_id();
+        let brokers = self.get_cluster_nodes();
+        let mut streams = self.get_streams();
+        streams.sort_unstable_by_key(|stream| stream.stream_id);
+        let mut partitions = Vec::new();
+        for stream in streams {
+            let mut topics = stream.get_topics();
+            topics.sort_unstable_by_key(|topic| topic.topic_id);
+            for topic in topics {
+                let stream_id = stream.stream_id;
+                let topic_id = topic.topic_id;
+                if self
+                    .permissioner
+                    .get_topic(user_id, stream_id, topic_id)
+                    .is_err()
+                    && self
+                        .permissioner
+                        .poll_messages(user_id, stream_id, topic_id)
+                        .is_err()
+                    && self
+                        .permissioner
+                        .append_messages(user_id, stream_id, topic_id)
+                        .is_err()
+                {
+                    continue;
+                }
+
+                let mut topic_partitions = Vec::new();
+                for partition in topic.get_partitions() {
+                    let partition = partition.read().await;
+                    topic_partitions.push(PartitionLeadership {
+                        stream_id,
+                        stream_name: stream.name.clone(),
+                        topic_id,
+                        topic_name: topic.name.clone(),
+                        partition_id: partition.partition_id,
+                        leader_id: partition.get_leader_id(),
+                        leader_epoch: partition.epoch,
+                    });
+                }
+                topic_partitions.sort_unstable_by_key(|partition| partition.partition_id);
+                partitions.extend(topic_partitions);
+            }
+        }
+
+        Ok(ClusterMetadata {
+            node_id: self.config.cluster.node_id,
+            brokers,
+            partitions,
+        })
+    }
+
+    /// Returns the nodes of the cluster known to this node, advertising their addresses and zones.
+    pub fn get_cluster_nodes(&self) -> Vec<BrokerInfo> {
+        vec![BrokerInfo {
+            id: self.config.cluster.node_id,
+            name: self.config.cluster.name.clone(),
+            address: self.config.cluster.address.clone(),
+            zone: self.config.cluster.zone.clone(),
+        }]
+    }
+
+    pub async fn get_node_status(
+        &self,
+        session: &Session,
+        node_id: u32,
+    ) -> Result<NodeStatus, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.get_node_status(session.get_user_id())?;
+        self.ensure_node_exists(node_id)?;
+        Ok(self.create_node_status().await)
+    }
+
+    /// Puts the node in the draining state, in which it accepts no new partitions, and transfers the leadership
+    /// of every partition whose in-sync replica has replicated all of its messages. The leadership of the remaining partitions
+    /// is transferred as soon as their replicas catch up, and the command can be repeated to report the progress.
+    pub async fn drain_node(
+        &mut self,
+        session: &Session,
+        node_id: u32,
+    ) -> Result<NodeStatus, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.drain_node(session.get_user_id())?;
+        self.ensure_node_exists(node_id)?;
+        if self.node_state == NodeState::Active {
+            self.node_state = NodeState::Draining;
+            info!("Node with ID: {node_id} is draining.");
+        }
+
+        if self.node_state == NodeState::Draining {
+            for stream in self.streams.values() {
+                for topic in stream.get_topics() {
+                    for partition in topic.get_partitions() {
+                        partition.read().await.transfer_leadership();
+                    }
+                }
+            }
+        }
+
+        Ok(self.create_node_status().await)
+    }
+
+    /// Decommissions the draining node once it no longer leads any partitions, so that it can be safely removed from the cluster.
+    pub async fn decommission_node(
+        &mut self,
+        session: &Session,
+        node_id: u32,
+    ) -> Result<NodeStatus, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.decommission_node(session.get_user_id())?;
+        self.ensure_node_exists(node_id)?;
+        if self.node_state == NodeState::Active {
+            return Err(IggyError::NodeNotDraining(node_id));
+        }
+
+        let mut status = self.create_node_status().await;
+        if status.led_partitions_count > 0 {
+            return Err(IggyError::NodeNotEmpty(
+                node_id,
+                status.led_partitions_count,
+            ));
+        }
+
+        if self.node_state == NodeState::Draining {
+            self.node_state = NodeState::Decommissioned;
+            status.state = NodeState::Decommissioned;
+            info!("Node with ID: {node_id} has been decommissioned.");
+        }
+        Ok(status)
+    }
+
+    /// Rejects placing the new partitions on the node which is draining or has been decommissioned.
+    pub(crate) fn ensure_node_accepts_partitions(&self) -> Result<(), IggyError> {
+        if self.node_state == NodeState::Active {
+            return Ok(());
+        }
+
+        Err(IggyError::NodeDraining(self.config.cluster.node_id))
+    }
+
+    fn ensure_node_exists(&self, node_id: u32) -> Result<(), IggyError> {
+        if node_id != self.config.cluster.node_id {
+            return Err(IggyError::NodeNotFound(node_id));
+        }
+
+        Ok(())
+    }
+
+    async fn create_node_status(&self) -> NodeStatus {
+        let mut partitions_count = 0;
+        let mut led_partitions_count = 0;
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for partition in topic.get_partitions() {
+                    partitions_count += 1;
+                    if partition.read().await.is_led_by_this_node() {
+                        led_partitions_count += 1;
+                    }
+                }
+            }
+        }
+
+        NodeStatus {
+            node_id: self.config.cluster.node_id,
+            state: self.node_state,
+            partitions_count,
+            led_partitions_count,
+        }
+    }
+}
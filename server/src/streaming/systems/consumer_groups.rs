@@ -4,7 +4,10 @@ use crate::streaming::topics::consumer_group::ConsumerGroup;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::timestamp::IggyTimestamp;
 use tokio::sync::RwLock;
+use tracing::info;
 
 impl System {
     pub fn get_consumer_group(
@@ -49,6 +52,7 @@ impl System {
         topic_id: &Identifier,
         group_id: Option<u32>,
         name: &str,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -61,7 +65,9 @@ impl System {
         }
 
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
-        topic.create_consumer_group(group_id, name).await
+        topic
+            .create_consumer_group(group_id, name, rebalance_strategy)
+            .await
     }
 
     pub async fn delete_consumer_group(
@@ -115,6 +121,7 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: &Identifier,
+        group_instance_id: Option<&str>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let stream_id_value;
@@ -141,7 +148,7 @@ impl System {
             }
 
             topic
-                .join_consumer_group(consumer_group_id, session.client_id)
+                .join_consumer_group(consumer_group_id, session.client_id, group_instance_id)
                 .await?;
         }
 
@@ -210,4 +217,88 @@ impl System {
             .leave_consumer_group(client_id, stream_id_value, topic_id_value, group_id)
             .await
     }
+
+    /// Records the heartbeat of the session's client, which keeps its membership in the consumer group alive,
+    /// and returns the partitions currently assigned to it.
+    pub async fn heartbeat_consumer_group(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: &Identifier,
+    ) -> Result<Vec<u32>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.join_consumer_group(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+        topic
+            .heartbeat_consumer_group(consumer_group_id, session.client_id)
+            .await
+    }
+
+    /// Removes the disconnected client from the consumer group, unless it's a static member of the group,
+    /// which keeps its partitions until it rejoins the group, or the session timeout elapses.
+    pub async fn disconnect_consumer_group_member(
+        &self,
+        stream_id: u32,
+        topic_id: u32,
+        group_id: u32,
+        client_id: u32,
+    ) -> Result<(), IggyError> {
+        {
+            let stream = self.get_stream(&Identifier::numeric(stream_id)?)?;
+            let topic = stream.get_topic(&Identifier::numeric(topic_id)?)?;
+            topic
+                .disconnect_consumer_group_member(&Identifier::numeric(group_id)?, client_id)
+                .await?;
+        }
+
+        let client_manager = self.client_manager.read().await;
+        client_manager
+            .leave_consumer_group(client_id, stream_id, topic_id, group_id)
+            .await
+    }
+
+    /// Removes the members of all the consumer groups, which haven't rejoined their group (static members)
+    /// or sent the heartbeat within the session timeout.
+    pub async fn delete_expired_consumer_group_members(&self) -> usize {
+        let now = IggyTimestamp::now();
+        let session_timeout = self.config.consumer_group.session_timeout;
+        let mut deleted_members = Vec::new();
+        for stream in self.streams.values() {
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let mut consumer_group = consumer_group.write().await;
+                    let expired_members = consumer_group
+                        .delete_expired_members(now, session_timeout)
+                        .await;
+                    for member_id in expired_members {
+                        info!(
+                            "Deleted expired member with ID: {member_id} of consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
+                            consumer_group.group_id, topic.topic_id, stream.stream_id
+                        );
+                        deleted_members.push((
+                            member_id,
+                            stream.stream_id,
+                            topic.topic_id,
+                            consumer_group.group_id,
+                        ));
+                    }
+                }
+            }
+        }
+
+        // The client of the member which has stopped sending the heartbeats might be still connected.
+        let client_manager = self.client_manager.read().await;
+        for (member_id, stream_id, topic_id, group_id) in &deleted_members {
+            _ = client_manager
+                .leave_consumer_group(*member_id, *stream_id, *topic_id, *group_id)
+                .await;
+        }
+
+        deleted_members.len()
+    }
 }
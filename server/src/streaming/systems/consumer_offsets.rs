@@ -1,9 +1,13 @@
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::consumer::Consumer;
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::models::consumer_lag_info::ConsumerLagInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
 
 impl System {
     pub async fn store_consumer_offset(
@@ -48,4 +52,111 @@ impl System {
             .get_consumer_offset(consumer, partition_id, session.client_id)
             .await
     }
+
+    pub async fn get_consumer_lag(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<ConsumerLagInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.get_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.get_consumer_lag(consumer, partition_id).await
+    }
+
+    pub async fn get_consumers(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<Vec<ConsumerInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.get_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.get_consumers(partition_id).await
+    }
+
+    pub async fn delete_consumer(
+        &self,
+        session: &Session,
+        consumer_id: &Identifier,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.delete_consumer(consumer_id, partition_id).await
+    }
+
+    pub async fn delete_consumer_offsets(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.store_consumer_offset(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+
+        topic.delete_consumer_offsets(consumer, partition_id).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn reset_consumer_offsets(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions: &[u32],
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        if dry_run {
+            self.permissioner.get_consumer_offset(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )?;
+        } else {
+            self.permissioner.store_consumer_offset(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )?;
+        }
+
+        topic
+            .reset_consumer_offsets(consumer, partitions, strategy, dry_run)
+            .await
+    }
 }
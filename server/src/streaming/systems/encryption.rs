@@ -0,0 +1,19 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+
+impl System {
+    /// Rotates the server-side encryption key and returns the version of the new key.
+    /// The previous keys remain available for decrypting the existing data, which is re-encrypted
+    /// with the new key by the messages maintainer in the background.
+    pub fn rotate_encryption_key(&self, session: &Session) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .rotate_encryption_key(session.get_user_id())?;
+        let Some(keyring) = &self.keyring else {
+            return Err(IggyError::FeatureUnavailable);
+        };
+
+        keyring.rotate()
+    }
+}
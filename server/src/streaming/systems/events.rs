@@ -0,0 +1,145 @@
+use crate::state::command::EntryCommand;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning};
+use iggy::models::system_event::SystemEvent;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::users::defaults::DEFAULT_ROOT_USER_ID;
+use iggy::utils::cleanup_policy::CleanupPolicy;
+use iggy::utils::content_type::ContentType;
+use iggy::utils::sizeable::Sizeable;
+use iggy::utils::topic_overrides::TopicOverrides;
+use iggy::utils::topic_size::MaxTopicSize;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::info;
+
+impl System {
+    /// Creates the reserved stream and topic for the internal events on startup, if the events are enabled and they don't exist yet.
+    pub(crate) async fn init_events(&mut self) -> Result<(), IggyError> {
+        let config = self.config.clone();
+        if !config.events.enabled {
+            info!("System events are disabled.");
+            return Ok(());
+        }
+
+        let session = Session::stateless(
+            DEFAULT_ROOT_USER_ID,
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        );
+        let stream_id = Identifier::named(&config.events.stream)?;
+        if self.get_stream(&stream_id).is_err() {
+            let stream = self
+                .create_stream(&session, None, &config.events.stream)
+                .await?;
+            let command = CreateStream {
+                stream_id: Some(stream.stream_id),
+                name: stream.name.clone(),
+            };
+            self.state
+                .apply(0, EntryCommand::CreateStream(command))
+                .await?;
+        }
+
+        let topic_id = Identifier::named(&config.events.topic)?;
+        if self.get_stream(&stream_id)?.get_topic(&topic_id).is_err() {
+            let topic = self
+                .create_topic(
+                    &session,
+                    &stream_id,
+                    None,
+                    &config.events.topic,
+                    1,
+                    config.events.message_expiry,
+                    CompressionAlgorithm::None,
+                    MaxTopicSize::ServerDefault,
+                    None,
+                    CleanupPolicy::Delete,
+                    TopicOverrides::default(),
+                )
+                .await?;
+            let command = CreateTopic {
+                stream_id: stream_id.clone(),
+                topic_id: Some(topic.topic_id),
+                partitions_count: 1,
+                compression_algorithm: CompressionAlgorithm::None,
+                message_expiry: config.events.message_expiry,
+                max_topic_size: MaxTopicSize::ServerDefault,
+                replication_factor: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                overrides: TopicOverrides::default(),
+                name: topic.name.clone(),
+            };
+            self.state
+                .apply(0, EntryCommand::CreateTopic(command))
+                .await?;
+        }
+
+        info!(
+            "System events are enabled, stream: {}, topic: {}.",
+            config.events.stream, config.events.topic
+        );
+        Ok(())
+    }
+
+    /// Appends the event as the JSON message to the reserved events topic.
+    pub async fn append_event(&self, event: &SystemEvent) -> Result<(), IggyError> {
+        let topic = self.get_events_topic()?;
+        // Closing the segment of the events topic would otherwise publish yet another event.
+        if let SystemEvent::SegmentClosed {
+            stream_id,
+            topic_id,
+            ..
+        } = event
+        {
+            if *stream_id == topic.stream_id && *topic_id == topic.topic_id {
+                return Ok(());
+            }
+        }
+
+        let payload = serde_json::to_vec(event)
+            .map_err(|error| IggyError::CannotParseJson(error.to_string()))?;
+        let mut message =
+            Message::new(None, Bytes::from(payload), None).with_content_type(ContentType::Json)?;
+        if let Some(encryptor) = self.get_topic_encryptor(topic) {
+            message.payload = Bytes::from(encryptor.encrypt(&message.payload)?);
+            message.length = message.payload.len() as u32;
+        }
+
+        let batch_size = message.get_size_bytes();
+        topic
+            .append_messages(
+                batch_size,
+                Partitioning::balanced(),
+                vec![message],
+                AckLevel::Leader,
+            )
+            .await?;
+        self.metrics.increment_messages(1);
+        Ok(())
+    }
+
+    /// Returns the error if the stream is the reserved one for the internal topics, which cannot be updated or deleted.
+    pub(crate) fn ensure_not_reserved_stream(&self, stream_id: u32) -> Result<(), IggyError> {
+        if !self.config.events.enabled {
+            return Ok(());
+        }
+
+        match self.streams_ids.get(&self.config.events.stream) {
+            Some(reserved_stream_id) if *reserved_stream_id == stream_id => {
+                Err(IggyError::ReservedStream(stream_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn get_events_topic(&self) -> Result<&Topic, IggyError> {
+        self.get_stream(&Identifier::named(&self.config.events.stream)?)?
+            .get_topic(&Identifier::named(&self.config.events.topic)?)
+    }
+}
@@ -29,7 +29,8 @@ pub struct Migration {
 }
 
 impl System {
-    pub(crate) async fn load_version(&mut self) -> Result<(), IggyError> {
+    /// Loads the system info (or creates it on the first start) and updates the stored version to the current one.
+    pub(crate) async fn load_version(&mut self) -> Result<SystemInfo, IggyError> {
         let current_version = SemanticVersion::current()?;
         let mut system_info;
         let load_system_info = self.storage.info.load().await;
@@ -61,7 +62,7 @@ impl System {
                 .await?;
         }
 
-        Ok(())
+        Ok(system_info)
     }
 
     async fn update_system_info(
@@ -0,0 +1,13 @@
+use crate::log::filter;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use iggy::error::IggyError;
+
+impl System {
+    /// Replaces the log filter at runtime, until the server is restarted or the filter is replaced again.
+    pub fn set_log_level(&self, session: &Session, filter: &str) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner.set_log_level(session.get_user_id())?;
+        filter::update_log_filter(filter)
+    }
+}
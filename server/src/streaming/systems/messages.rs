@@ -1,16 +1,33 @@
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
+use crate::streaming::diagnostics::slow_consumers::SlowConsumerState;
+use crate::streaming::interceptors::InterceptorContext;
+use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::Topic;
 use bytes::Bytes;
 use iggy::consumer::Consumer;
-use iggy::messages::poll_messages::PollingStrategy;
-use iggy::messages::send_messages::Message;
+use iggy::locking::IggySharedMutFn;
+use iggy::messages::copy_messages::{CopyRangeKind, COPIED_FROM_HEADER};
+use iggy::messages::peek_messages::PeekKind;
+use iggy::messages::poll_messages::{OutOfRangePolicy, PollingDirection, PollingStrategy};
+use iggy::messages::poll_messages_from_partitions::PartitionOffset;
 use iggy::messages::send_messages::Partitioning;
+use iggy::messages::send_messages::{AckLevel, Message};
+use iggy::models::copied_messages_info::CopiedMessagesInfo;
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{PolledMessage, PolledMessages};
+use iggy::models::node_status::NodeState;
+use iggy::models::replica_messages::ReplicaMessages;
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::crypto::Encryptor;
 use iggy::utils::sizeable::Sizeable;
 use iggy::{error::IggyError, identifier::Identifier};
-use tracing::{error, trace};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info, trace};
+
+const COPIED_MESSAGES_BATCH_SIZE: u32 = 1000;
 
 impl System {
     pub async fn poll_messages(
@@ -39,30 +56,210 @@ impl System {
             .resolve_consumer_with_partition_id(consumer, session.client_id, partition_id, true)
             .await?;
 
+        let max_messages_count = self.config.poll_response.max_messages_count;
+        let count = match max_messages_count {
+            0 => args.count,
+            _ => args.count.min(max_messages_count),
+        };
+        let count = self.slow_consumers.limit_poll_count(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            polling_consumer,
+            count,
+        );
         let mut polled_messages = topic
-            .get_messages(polling_consumer, partition_id, args.strategy, args.count)
+            .get_messages(
+                polling_consumer,
+                partition_id,
+                args.strategy,
+                count,
+                args.max_bytes,
+            )
             .await?;
 
         if polled_messages.messages.is_empty() {
             return Ok(polled_messages);
         }
 
+        // Paging backward is browsing rather than consuming, so it doesn't affect the consumer's lag.
+        if args.strategy.direction == PollingDirection::Forward {
+            self.track_slow_consumer(topic, partition_id, polling_consumer, &polled_messages)
+                .await?;
+        }
+
         let offset = polled_messages.messages.last().unwrap().offset;
+        self.decrypt_messages(topic, &mut polled_messages)?;
+        // The offset is stored only if the messages are not rejected by any interceptor.
+        let context = InterceptorContext::new(session, topic.stream_id, topic.topic_id);
+        self.interceptors
+            .before_send(&context, &mut polled_messages)?;
+
         if args.auto_commit {
             trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
             topic
-                .store_consumer_offset_internal(polling_consumer, offset, partition_id)
+                .store_consumer_offset_internal(
+                    polling_consumer,
+                    PollingConsumer::resolve_consumer_name(consumer).as_deref(),
+                    offset,
+                    partition_id,
+                )
                 .await?;
         }
 
-        if self.encryptor.is_none() {
-            return Ok(polled_messages);
+        Ok(polled_messages)
+    }
+
+    async fn track_slow_consumer(
+        &self,
+        topic: &Topic,
+        partition_id: u32,
+        polling_consumer: PollingConsumer,
+        polled_messages: &PolledMessages,
+    ) -> Result<(), IggyError> {
+        if !self.slow_consumers.is_enabled() {
+            return Ok(());
         }
 
-        let encryptor = self.encryptor.as_ref().unwrap();
+        let first_offset = polled_messages.messages.first().unwrap().offset;
+        let last_offset = polled_messages.messages.last().unwrap().offset;
+        let cold = {
+            let partition = topic.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            !partition.is_offset_cached(first_offset)
+        };
+        if cold {
+            self.metrics.increment_cold_reads();
+        }
+
+        let lag = polled_messages.current_offset.saturating_sub(last_offset);
+        match self.slow_consumers.record_read(
+            topic.stream_id,
+            topic.topic_id,
+            partition_id,
+            polling_consumer,
+            cold,
+            lag,
+        ) {
+            SlowConsumerState::Detected => self.metrics.increment_slow_consumers(1),
+            SlowConsumerState::Recovered => self.metrics.decrement_slow_consumers(1),
+            SlowConsumerState::Unchanged => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the first, last or random messages of the partition without storing or using any consumer offsets.
+    pub async fn peek_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        self.ensure_authenticated(session)?;
+        if count == 0 {
+            return Err(IggyError::InvalidMessagesCount);
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+
+        let mut polled_messages = topic.peek_messages(partition_id, kind, count).await?;
+        self.decrypt_messages(topic, &mut polled_messages)?;
+        let context = InterceptorContext::new(session, topic.stream_id, topic.topic_id);
+        self.interceptors
+            .before_send(&context, &mut polled_messages)?;
+        Ok(polled_messages)
+    }
+
+    /// Returns the latest message for the given key (message ID) in the partition of the compacted topic,
+    /// without storing or using any consumer offsets.
+    pub async fn get_message_by_key(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        key: u128,
+    ) -> Result<PolledMessages, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .poll_messages(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+
+        let mut polled_messages = topic.get_message_by_key(partition_id, key).await?;
+        self.decrypt_messages(topic, &mut polled_messages)?;
+        let context = InterceptorContext::new(session, topic.stream_id, topic.topic_id);
+        self.interceptors
+            .before_send(&context, &mut polled_messages)?;
+        Ok(polled_messages)
+    }
+
+    /// Returns the messages of the partition to the follower replicating it. Unlike the polled messages,
+    /// they're returned as stored (i.e. still encrypted at rest, if so), without being passed through the interceptors.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fetch_replica_messages(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        epoch: u32,
+        count: u32,
+    ) -> Result<ReplicaMessages, IggyError> {
+        self.ensure_authenticated(session)?;
+        self.permissioner
+            .fetch_replica_messages(session.get_user_id())?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+
+        trace!(
+            "Replica with ID: {replica_id} is fetching messages from offset: {offset} in epoch: {epoch} for partition with ID: {partition_id}, topic with ID: {}, stream with ID: {}.",
+            topic.topic_id,
+            topic.stream_id
+        );
+        topic
+            .fetch_replica_messages(
+                partition_id,
+                replica_id,
+                offset,
+                epoch,
+                count,
+                self.node_state == NodeState::Draining,
+            )
+            .await
+    }
+
+    /// Returns the encryptor, if the messages payloads of the topic are encrypted at rest.
+    pub(crate) fn get_topic_encryptor(&self, topic: &Topic) -> Option<&Arc<dyn Encryptor>> {
+        let encryptor = self.encryptor.as_ref()?;
+        let stream = self.streams.get(&topic.stream_id)?;
+        self.config
+            .encryption
+            .is_topic_encrypted(&stream.name, &topic.name)
+            .then_some(encryptor)
+    }
+
+    fn decrypt_messages(
+        &self,
+        topic: &Topic,
+        polled_messages: &mut PolledMessages,
+    ) -> Result<(), IggyError> {
+        let Some(encryptor) = self.get_topic_encryptor(topic) else {
+            return Ok(());
+        };
+
         let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
         for message in polled_messages.messages.iter() {
-            let payload = encryptor.decrypt(&message.payload);
+            let payload = if message.is_tombstone() {
+                Ok(Vec::new())
+            } else {
+                encryptor.decrypt(&message.payload)
+            };
             match payload {
                 Ok(payload) => {
                     decrypted_messages.push(PolledMessage {
@@ -83,7 +280,37 @@ impl System {
             }
         }
         polled_messages.messages = decrypted_messages;
-        Ok(polled_messages)
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn poll_messages_from_partitions(
+        &self,
+        session: &Session,
+        consumer: &Consumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partitions: &[PartitionOffset],
+        out_of_range: OutOfRangePolicy,
+        count: u32,
+        auto_commit: bool,
+    ) -> Result<Vec<PolledMessages>, IggyError> {
+        let mut batches = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let strategy = PollingStrategy::offset(partition.offset).out_of_range(out_of_range);
+            let polled_messages = self
+                .poll_messages(
+                    session,
+                    consumer,
+                    stream_id,
+                    topic_id,
+                    Some(partition.partition_id),
+                    PollingArgs::new(strategy, count, 0, auto_commit),
+                )
+                .await?;
+            batches.push(polled_messages);
+        }
+        Ok(batches)
     }
 
     pub async fn append_messages(
@@ -92,6 +319,7 @@ impl System {
         stream_id: Identifier,
         topic_id: Identifier,
         partitioning: Partitioning,
+        ack: AckLevel,
         messages: Vec<Message>,
     ) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
@@ -101,11 +329,31 @@ impl System {
             topic.stream_id,
             topic.topic_id,
         )?;
+        self.disk_space.ensure_writable()?;
+        topic.ensure_producer(session.client_id)?;
+
+        if !topic.cleanup_policy.is_compact() && messages.iter().any(|msg| msg.is_tombstone()) {
+            return Err(IggyError::EmptyMessagePayload);
+        }
 
-        let mut batch_size_bytes = IggyByteSize::default();
         let mut messages = messages;
-        if let Some(encryptor) = &self.encryptor {
+        if !self.interceptors.is_empty() {
+            let context = InterceptorContext::new(session, topic.stream_id, topic.topic_id);
+            self.interceptors.before_append(&context, &mut messages)?;
+        }
+
+        topic.validate_messages(&messages)?;
+        topic.recompress_messages(&mut messages)?;
+        topic.record_append_timestamps(&mut messages)?;
+
+        let mut batch_size_bytes = IggyByteSize::default();
+        if let Some(encryptor) = self.get_topic_encryptor(topic) {
             for message in messages.iter_mut() {
+                if message.is_tombstone() {
+                    batch_size_bytes += message.get_size_bytes();
+                    continue;
+                }
+
                 let payload = encryptor.encrypt(&message.payload);
                 match payload {
                     Ok(payload) => {
@@ -133,12 +381,172 @@ impl System {
         }
         let messages_count = messages.len() as u64;
         topic
-            .append_messages(batch_size_bytes, partitioning, messages)
+            .append_messages(batch_size_bytes, partitioning, messages, ack)
             .await?;
         self.metrics.increment_messages(messages_count);
         Ok(())
     }
 
+    /// Copies the range of messages from the source partition to the target one, preserving their IDs and headers,
+    /// and recording the origin of each message in the `copied-from` header.
+    /// Only the messages appended before the copying started are copied, so copying within the same partition ends as well.
+    /// The tombstones are copied only to the compacted topics.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn copy_messages(
+        &self,
+        session: &Session,
+        source_stream_id: &Identifier,
+        source_topic_id: &Identifier,
+        source_partition_id: u32,
+        target_stream_id: &Identifier,
+        target_topic_id: &Identifier,
+        target_partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Result<CopiedMessagesInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        if from >= to {
+            return Err(IggyError::InvalidCopyRange);
+        }
+
+        let source_topic = self.find_topic(session, source_stream_id, source_topic_id)?;
+        self.permissioner.poll_messages(
+            session.get_user_id(),
+            source_topic.stream_id,
+            source_topic.topic_id,
+        )?;
+        let target_topic = self.find_topic(session, target_stream_id, target_topic_id)?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            target_topic.stream_id,
+            target_topic.topic_id,
+        )?;
+        target_topic.get_partition(target_partition_id)?;
+        let copy_tombstones = target_topic.cleanup_policy.is_compact();
+
+        let mut copied_messages = CopiedMessagesInfo { messages_count: 0 };
+        let Some((mut offset, end_offset)) = source_topic
+            .get_copied_offsets_range(source_partition_id, kind, from, to)
+            .await?
+        else {
+            return Ok(copied_messages);
+        };
+
+        while offset <= end_offset {
+            let count = (end_offset - offset + 1).min(COPIED_MESSAGES_BATCH_SIZE as u64) as u32;
+            let mut polled_messages = source_topic
+                .get_copied_messages(source_partition_id, offset, count)
+                .await?;
+            let Some(next_offset) = polled_messages.next_offset else {
+                break;
+            };
+
+            offset = next_offset;
+            self.decrypt_messages(source_topic, &mut polled_messages)?;
+            let mut messages = Vec::with_capacity(polled_messages.messages.len());
+            for message in polled_messages.messages {
+                if message.offset > end_offset
+                    || (kind == CopyRangeKind::Timestamp && message.timestamp >= to)
+                {
+                    offset = end_offset + 1;
+                    break;
+                }
+
+                if message.is_tombstone() && !copy_tombstones {
+                    continue;
+                }
+
+                let origin = format!(
+                    "{}/{}/{source_partition_id}/{}",
+                    source_topic.stream_id, source_topic.topic_id, message.offset
+                );
+                let mut headers = message.headers.unwrap_or_default();
+                headers.insert(
+                    HeaderKey::new(COPIED_FROM_HEADER)?,
+                    HeaderValue::from_str(&origin)?,
+                );
+                messages.push(Message::new(
+                    Some(message.id),
+                    message.payload,
+                    Some(headers),
+                ));
+            }
+
+            if messages.is_empty() {
+                continue;
+            }
+
+            let messages_count = messages.len() as u64;
+            self.append_messages(
+                session,
+                target_stream_id.clone(),
+                target_topic_id.clone(),
+                Partitioning::partition_id(target_partition_id),
+                AckLevel::default(),
+                messages,
+            )
+            .await?;
+            copied_messages.messages_count += messages_count;
+        }
+
+        info!(
+            "Copied {} messages from partition with ID: {source_partition_id} of topic with ID: {} for stream with ID: {} to partition with ID: {target_partition_id} of topic with ID: {} for stream with ID: {}.",
+            copied_messages.messages_count,
+            source_topic.topic_id,
+            source_topic.stream_id,
+            target_topic.topic_id,
+            target_topic.stream_id
+        );
+        Ok(copied_messages)
+    }
+
+    pub async fn acquire_exclusive_producer(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        // The stateless sessions (e.g. HTTP) have no connection which would release the topic once closed.
+        if session.client_id == 0 {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner.append_messages(
+            session.get_user_id(),
+            topic.stream_id,
+            topic.topic_id,
+        )?;
+        topic.acquire_exclusive_producer(session.client_id)
+    }
+
+    pub fn init_producer_epoch(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        epoch: u32,
+    ) -> Result<u32, IggyError> {
+        self.ensure_authenticated(session)?;
+        if session.client_id == 0 {
+            return Err(IggyError::FeatureUnavailable);
+        }
+
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)?;
+            self.permissioner.append_messages(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.init_producer_epoch(session.client_id, epoch)
+    }
+
     pub async fn flush_unsaved_buffer(
         &self,
         session: &Session,
@@ -164,14 +572,16 @@ impl System {
 pub struct PollingArgs {
     pub strategy: PollingStrategy,
     pub count: u32,
+    pub max_bytes: u32,
     pub auto_commit: bool,
 }
 
 impl PollingArgs {
-    pub fn new(strategy: PollingStrategy, count: u32, auto_commit: bool) -> Self {
+    pub fn new(strategy: PollingStrategy, count: u32, max_bytes: u32, auto_commit: bool) -> Self {
         Self {
             strategy,
             count,
+            max_bytes,
             auto_commit,
         }
     }
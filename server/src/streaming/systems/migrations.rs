@@ -0,0 +1,365 @@
+use crate::compat::batch_conversion::batch_converter::BatchConverter;
+use crate::compat::index_conversion::index_converter::IndexConverter;
+use crate::configs::system::SystemConfig;
+use crate::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION};
+use crate::streaming::systems::info::{Migration, SystemInfo};
+use crate::streaming::systems::system::System;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use tokio::fs::read_dir;
+use tokio::time::Instant;
+use tracing::{error, info};
+
+const TIME_INDEX_EXTENSION: &str = "timeindex";
+
+/// The migration of the data directory written by the previous versions of the server, e.g. after the format version bump
+/// or the change of the index layout. The migrations are applied once (in the order of their IDs) on startup,
+/// before the streams are loaded, and recorded in the system info, so that they're never applied again.
+#[async_trait]
+pub trait DataMigration: Send + Sync {
+    /// Unique ID of the migration, never reused by another one.
+    fn id(&self) -> u32;
+
+    fn name(&self) -> &'static str;
+
+    /// Returns the paths to migrate, without modifying any of them.
+    async fn plan(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError>;
+
+    /// Migrates the single path returned by the plan.
+    async fn apply(&self, path: &str) -> Result<(), IggyError>;
+}
+
+/// The migration pending on startup, along with the paths to migrate.
+#[derive(Debug)]
+pub struct PendingMigration {
+    pub id: u32,
+    pub name: &'static str,
+    pub paths: Vec<String>,
+}
+
+/// Returns all the registered migrations, sorted by their IDs.
+pub fn get_migrations() -> Vec<Box<dyn DataMigration>> {
+    let mut migrations: Vec<Box<dyn DataMigration>> =
+        vec![Box::new(TimeIndexMigration), Box::new(BatchFormatMigration)];
+    migrations.sort_by_key(|migration| migration.id());
+    migrations
+}
+
+impl System {
+    /// Applies the migrations which haven't been applied yet, logging the progress, and records them in the system info.
+    /// Returns the names of the applied migrations.
+    pub(crate) async fn apply_migrations(
+        &self,
+        system_info: &mut SystemInfo,
+    ) -> Result<Vec<String>, IggyError> {
+        let mut applied_migrations = Vec::new();
+        for migration in get_migrations() {
+            if system_info.is_migration_applied(migration.id()) {
+                continue;
+            }
+
+            let now = Instant::now();
+            let paths = migration.plan(&self.config).await?;
+            info!(
+                "Applying migration with ID: {}, name: {}, {} path(s) to migrate...",
+                migration.id(),
+                migration.name(),
+                paths.len()
+            );
+            for (index, path) in paths.iter().enumerate() {
+                if let Err(error) = migration.apply(path).await {
+                    error!(
+                        "Failed to apply migration with ID: {}, name: {} to path: {path}. Error: {error}",
+                        migration.id(),
+                        migration.name()
+                    );
+                    return Err(error);
+                }
+                info!(
+                    "Migration with ID: {}, name: {} progress: {}/{}, migrated path: {path}",
+                    migration.id(),
+                    migration.name(),
+                    index + 1,
+                    paths.len()
+                );
+            }
+
+            system_info.migrations.push(Migration::new(
+                migration.id(),
+                migration.name(),
+                IggyTimestamp::now().as_micros(),
+            ));
+            self.storage.info.save(system_info).await?;
+            info!(
+                "Applied migration with ID: {}, name: {} in {} ms.",
+                migration.id(),
+                migration.name(),
+                now.elapsed().as_millis()
+            );
+            applied_migrations.push(migration.name().to_string());
+        }
+
+        Ok(applied_migrations)
+    }
+
+    /// Logs the migrations which would be applied on startup along with their paths, without modifying the data directory.
+    pub async fn dry_run(&self) -> Result<(), IggyError> {
+        let pending_migrations = self.get_pending_migrations().await?;
+        for migration in &pending_migrations {
+            info!(
+                "Pending migration with ID: {}, name: {}, {} path(s) to migrate.",
+                migration.id,
+                migration.name,
+                migration.paths.len()
+            );
+            for path in &migration.paths {
+                info!(
+                    "Migration with ID: {}, name: {} would migrate path: {path}",
+                    migration.id, migration.name
+                );
+            }
+        }
+        info!(
+            "Dry run has completed, found {} pending migration(s).",
+            pending_migrations.len()
+        );
+        Ok(())
+    }
+
+    /// Returns the migrations which would be applied on startup, without modifying the data directory.
+    pub async fn get_pending_migrations(&self) -> Result<Vec<PendingMigration>, IggyError> {
+        let system_info = match self.storage.info.load().await {
+            Ok(system_info) => system_info,
+            Err(IggyError::ResourceNotFound(_)) => SystemInfo::default(),
+            Err(error) => return Err(error),
+        };
+
+        let mut pending_migrations = Vec::new();
+        for migration in get_migrations() {
+            if system_info.is_migration_applied(migration.id()) {
+                continue;
+            }
+
+            pending_migrations.push(PendingMigration {
+                id: migration.id(),
+                name: migration.name(),
+                paths: migration.plan(&self.config).await?,
+            });
+        }
+
+        Ok(pending_migrations)
+    }
+}
+
+impl Migration {
+    pub fn new(id: u32, name: &str, applied_at: u64) -> Self {
+        let mut hasher = DefaultHasher::new();
+        id.hash(&mut hasher);
+        name.hash(&mut hasher);
+        Self {
+            id,
+            name: name.to_string(),
+            hash: hasher.finish().to_string(),
+            applied_at,
+        }
+    }
+}
+
+impl SystemInfo {
+    pub fn is_migration_applied(&self, id: u32) -> bool {
+        self.migrations.iter().any(|migration| migration.id == id)
+    }
+}
+
+/// Merges the time index files (`.timeindex`) written by the older versions of the server into the index files,
+/// which store the offset, position and timestamp of each batch.
+pub struct TimeIndexMigration;
+
+#[async_trait]
+impl DataMigration for TimeIndexMigration {
+    fn id(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &'static str {
+        "merge_time_indexes"
+    }
+
+    async fn plan(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError> {
+        let mut paths = Vec::new();
+        for data_path in config.get_data_paths() {
+            let streams_path = config.get_data_streams_path(&data_path);
+            paths.extend(find_files(Path::new(&streams_path), TIME_INDEX_EXTENSION).await?);
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    async fn apply(&self, path: &str) -> Result<(), IggyError> {
+        let index_path = Path::new(path)
+            .with_extension(INDEX_EXTENSION)
+            .to_string_lossy()
+            .to_string();
+        IndexConverter::new(index_path, path.to_string())
+            .migrate()
+            .await
+            .map_err(|error| IggyError::CannotApplyMigration(self.id(), error.to_string()))
+    }
+}
+
+/// Converts the segment log files written before the batch header was versioned, in which the messages store
+/// their absolute offsets and timestamps, into the current batch format, updating the positions of their indexes.
+pub struct BatchFormatMigration;
+
+impl BatchFormatMigration {
+    fn get_converter(path: &str) -> BatchConverter {
+        let index_path = Path::new(path)
+            .with_extension(INDEX_EXTENSION)
+            .to_string_lossy()
+            .to_string();
+        BatchConverter::new(path.to_string(), index_path)
+    }
+}
+
+#[async_trait]
+impl DataMigration for BatchFormatMigration {
+    fn id(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &'static str {
+        "convert_batch_format"
+    }
+
+    async fn plan(&self, config: &SystemConfig) -> Result<Vec<String>, IggyError> {
+        let mut paths = Vec::new();
+        for data_path in config.get_data_paths() {
+            let streams_path = config.get_data_streams_path(&data_path);
+            for path in find_files(Path::new(&streams_path), LOG_EXTENSION).await? {
+                let is_required =
+                    Self::get_converter(&path)
+                        .is_required()
+                        .await
+                        .map_err(|error| {
+                            IggyError::CannotApplyMigration(self.id(), error.to_string())
+                        })?;
+                if is_required {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    async fn apply(&self, path: &str) -> Result<(), IggyError> {
+        Self::get_converter(path)
+            .migrate()
+            .await
+            .map_err(|error| IggyError::CannotApplyMigration(self.id(), error.to_string()))
+    }
+}
+
+/// Returns the paths of the files with the given extension in the directory and all its subdirectories.
+async fn find_files(path: &Path, extension: &str) -> Result<Vec<String>, IggyError> {
+    let mut files = Vec::new();
+    let mut directories = vec![PathBuf::from(path)];
+    while let Some(directory) = directories.pop() {
+        let Ok(mut entries) = read_dir(&directory).await else {
+            continue;
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                directories.push(path);
+            } else if path.extension().is_some_and(|ext| ext == extension) {
+                files.push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::segments::storage::INDEX_SIZE;
+    use std::env::temp_dir;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn time_indexes_should_be_planned_and_merged_into_indexes() {
+        let path = temp_dir().join(format!("iggy-migrations-{}", Uuid::now_v7()));
+        let config = SystemConfig {
+            path: path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let partition_path = Path::new(&config.get_streams_path()).join("1/topics/1/partitions/1");
+        tokio::fs::create_dir_all(&partition_path).await.unwrap();
+        let index_path = partition_path.join("00000000000000000000.index");
+        let time_index_path = partition_path.join("00000000000000000000.timeindex");
+        // relative offset: 4 bytes, position: 4 bytes
+        let index = [
+            0u32.to_le_bytes(),
+            0u32.to_le_bytes(),
+            1u32.to_le_bytes(),
+            100u32.to_le_bytes(),
+        ]
+        .concat();
+        // relative offset: 4 bytes, timestamp: 8 bytes
+        let time_index = [
+            0u32.to_le_bytes().to_vec(),
+            10u64.to_le_bytes().to_vec(),
+            1u32.to_le_bytes().to_vec(),
+            20u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        tokio::fs::write(&index_path, index).await.unwrap();
+        tokio::fs::write(&time_index_path, time_index)
+            .await
+            .unwrap();
+
+        let migration = TimeIndexMigration;
+        let paths = migration.plan(&config).await.unwrap();
+        assert_eq!(paths, vec![time_index_path.to_string_lossy().to_string()]);
+
+        migration.apply(&paths[0]).await.unwrap();
+        let indexes = tokio::fs::read(&index_path).await.unwrap();
+        assert_eq!(indexes.len(), 2 * INDEX_SIZE as usize);
+        assert_eq!(u32::from_le_bytes(indexes[20..24].try_into().unwrap()), 100);
+        assert_eq!(u64::from_le_bytes(indexes[24..32].try_into().unwrap()), 20);
+        assert!(!time_index_path.exists());
+        assert!(migration.plan(&config).await.unwrap().is_empty());
+        tokio::fs::remove_dir_all(&path).await.unwrap();
+    }
+
+    #[test]
+    fn migrations_should_be_registered_in_order_of_their_ids() {
+        let migrations = get_migrations()
+            .iter()
+            .map(|migration| (migration.id(), migration.name()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            migrations,
+            vec![(1, "merge_time_indexes"), (2, "convert_batch_format")]
+        );
+    }
+
+    #[test]
+    fn applied_migration_should_be_recognized() {
+        let mut system_info = SystemInfo::default();
+        assert!(!system_info.is_migration_applied(1));
+
+        system_info
+            .migrations
+            .push(Migration::new(1, "merge_time_indexes", 0));
+        assert!(system_info.is_migration_applied(1));
+        assert!(!system_info.is_migration_applied(2));
+    }
+}
@@ -1,11 +1,19 @@
+pub mod background_io;
 pub mod clients;
+pub mod cluster;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod encryption;
+pub mod events;
 pub mod info;
+pub mod log_level;
 pub mod messages;
+pub mod migrations;
 pub mod partitions;
 pub mod personal_access_tokens;
+pub mod schemas;
 pub mod snapshot;
+pub mod startup_report;
 pub mod stats;
 pub mod storage;
 pub mod streams;
@@ -2,8 +2,99 @@ use crate::streaming::session::Session;
 use crate::streaming::systems::system::System;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
+use iggy::models::messages_histogram::MessagesHistogram;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::partition_offsets_info::PartitionOffsetsInfo;
+use iggy::models::sealed_partition_info::SealedPartitionInfo;
+use iggy::models::timestamp_offset_info::TimestampOffsetInfo;
+use iggy::utils::duration::IggyDuration;
+use tracing::info;
 
 impl System {
+    pub async fn get_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<PartitionDetails, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+        topic.get_partition_details(partition_id).await
+    }
+
+    pub async fn get_partition_offsets(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionOffsetsInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+        topic.get_partition_offsets(partition_id).await
+    }
+
+    pub async fn get_offset_by_timestamp(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        timestamp: u64,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+        topic
+            .get_offset_by_timestamp(partition_id, timestamp.into())
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_messages_histogram(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+        topic
+            .get_messages_histogram(partition_id, from, to, bucket_size)
+            .await
+    }
+
+    pub async fn seal_partition(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<SealedPartitionInfo, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .update_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+        let sealed_partition = topic.seal_partition(partition_id).await?;
+        info!(
+            "Sealed partition with ID: {partition_id} for topic with ID: {} and stream with ID: {} at high watermark: {}.",
+            topic.topic_id, topic.stream_id, sealed_partition.high_watermark
+        );
+        Ok(sealed_partition)
+    }
+
     pub async fn create_partitions(
         &mut self,
         session: &Session,
@@ -21,6 +112,7 @@ impl System {
             )?;
         }
 
+        self.ensure_node_accepts_partitions()?;
         let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
         topic.add_persisted_partitions(partitions_count).await?;
         topic.reassign_consumer_groups().await;
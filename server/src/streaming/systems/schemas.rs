@@ -0,0 +1,61 @@
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::schema::Schema;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::utils::schema_type::SchemaType;
+use iggy::utils::timestamp::IggyTimestamp;
+
+impl System {
+    pub fn get_schema(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        version: Option<u32>,
+    ) -> Result<Option<&Schema>, IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_schemas(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+
+        Ok(topic.get_schema(version))
+    }
+
+    pub fn get_schemas(
+        &self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<&[Schema], IggyError> {
+        self.ensure_authenticated(session)?;
+        let topic = self.find_topic(session, stream_id, topic_id)?;
+        self.permissioner
+            .get_schemas(session.get_user_id(), topic.stream_id, topic.topic_id)?;
+
+        Ok(topic.get_schemas())
+    }
+
+    pub fn register_schema(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        schema_type: SchemaType,
+        definition: &str,
+        validate: bool,
+    ) -> Result<&Schema, IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)?;
+            self.permissioner.register_schema(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.register_schema(schema_type, definition, validate, IggyTimestamp::now())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::streaming::systems::system::System;
+use iggy::locking::IggySharedMutFn;
+use iggy::utils::byte_size::IggyByteSize;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::Ordering;
+use tracing::info;
+
+/// The summary of the data loaded on startup, along with the migrations applied before loading it.
+#[derive(Debug, Default, Serialize)]
+pub struct StartupReport {
+    pub version: String,
+    pub applied_migrations: Vec<String>,
+    pub streams: Vec<StreamReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreamReport {
+    pub id: u32,
+    pub name: String,
+    pub topics: Vec<TopicReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TopicReport {
+    pub id: u32,
+    pub name: String,
+    pub partitions: u32,
+    pub segments: u32,
+    pub messages: u64,
+    pub size: IggyByteSize,
+}
+
+impl System {
+    pub(crate) async fn get_startup_report(
+        &self,
+        version: String,
+        applied_migrations: Vec<String>,
+    ) -> StartupReport {
+        let mut streams = Vec::with_capacity(self.streams.len());
+        for stream in self.streams.values() {
+            let mut topics = Vec::with_capacity(stream.topics.len());
+            for topic in stream.get_topics() {
+                let mut segments = 0;
+                for partition in topic.get_partitions() {
+                    segments += partition.read().await.get_segments_count();
+                }
+                topics.push(TopicReport {
+                    id: topic.topic_id,
+                    name: topic.name.clone(),
+                    partitions: topic.get_partitions_count(),
+                    segments,
+                    messages: topic.get_messages_count(),
+                    size: IggyByteSize::from(topic.size_bytes.load(Ordering::SeqCst)),
+                });
+            }
+            topics.sort_by_key(|topic| topic.id);
+            streams.push(StreamReport {
+                id: stream.stream_id,
+                name: stream.name.clone(),
+                topics,
+            });
+        }
+        streams.sort_by_key(|stream| stream.id);
+
+        StartupReport {
+            version,
+            applied_migrations,
+            streams,
+        }
+    }
+}
+
+impl StartupReport {
+    /// Logs the summary, followed by each of the loaded streams and topics.
+    pub fn log(&self) {
+        info!("Loaded {self}");
+        for stream in &self.streams {
+            info!("Loaded stream: {stream}");
+            for topic in &stream.topics {
+                info!("Loaded topic: {topic} for stream with ID: {}", stream.id);
+            }
+        }
+    }
+
+    fn get_topics(&self) -> impl Iterator<Item = &TopicReport> {
+        self.streams.iter().flat_map(|stream| stream.topics.iter())
+    }
+}
+
+impl Display for StartupReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "startup report {{ version: {}, applied_migrations: {:?}, streams: {}, topics: {}, partitions: {}, segments: {}, messages: {}, size: {} }}",
+            self.version,
+            self.applied_migrations,
+            self.streams.len(),
+            self.get_topics().count(),
+            self.get_topics().map(|topic| topic.partitions).sum::<u32>(),
+            self.get_topics().map(|topic| topic.segments).sum::<u32>(),
+            self.get_topics().map(|topic| topic.messages).sum::<u64>(),
+            self.get_topics().map(|topic| topic.size).sum::<IggyByteSize>()
+        )
+    }
+}
+
+impl Display for StreamReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ stream_id: {}, name: {}, topics: {} }}",
+            self.id,
+            self.name,
+            self.topics.len()
+        )
+    }
+}
+
+impl Display for TopicReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{ topic_id: {}, name: {}, partitions: {}, segments: {}, messages: {}, size: {} }}",
+            self.id, self.name, self.partitions, self.segments, self.messages, self.size
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_should_sum_up_loaded_topics() {
+        let topic = |id, messages| TopicReport {
+            id,
+            name: format!("topic-{id}"),
+            partitions: 2,
+            segments: 3,
+            messages,
+            size: IggyByteSize::from(1000),
+        };
+        let report = StartupReport {
+            version: "0.4.85".to_string(),
+            applied_migrations: vec!["merge_time_indexes".to_string()],
+            streams: vec![
+                StreamReport {
+                    id: 1,
+                    name: "first".to_string(),
+                    topics: vec![topic(1, 10), topic(2, 20)],
+                },
+                StreamReport {
+                    id: 2,
+                    name: "second".to_string(),
+                    topics: Vec::new(),
+                },
+            ],
+        };
+
+        assert_eq!(
+            report.to_string(),
+            "startup report { version: 0.4.85, applied_migrations: [\"merge_time_indexes\"], streams: 2, topics: 2, partitions: 4, segments: 6, messages: 30, size: 2.00 KB }"
+        );
+    }
+}
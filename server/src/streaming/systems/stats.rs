@@ -44,6 +44,9 @@ impl System {
             os_name,
             os_version,
             kernel_version,
+            command_latencies: self.metrics.get_command_latencies(),
+            deleting_topics_count: self.topic_deletions.get_topics_count(),
+            deleting_topics_size_bytes: self.topic_deletions.get_size_bytes().into(),
             ..Default::default()
         };
 
@@ -81,6 +84,9 @@ impl System {
                 .values()
                 .map(|t| t.consumer_groups.len() as u32)
                 .sum::<u32>();
+            for topic in stream.topics.values() {
+                stats.failed_partitions_count += topic.get_failed_partitions_count().await;
+            }
         }
 
         Ok(stats)
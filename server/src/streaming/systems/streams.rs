@@ -6,6 +6,7 @@ use futures::future::try_join_all;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::models::system_event::SystemEvent;
 use iggy::utils::text;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
@@ -56,11 +57,36 @@ impl System {
                 &stream_state.name,
                 self.config.clone(),
                 self.storage.clone(),
+                self.appends_broadcaster.clone(),
+                self.events_bus.clone(),
             );
             stream.created_at = stream_state.created_at;
             unloaded_streams.push(stream);
         }
 
+        for data_path in &self.config.placement.paths {
+            let Ok(mut dir_entries) = read_dir(&self.config.get_data_streams_path(data_path)).await
+            else {
+                continue;
+            };
+
+            while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+                let name = dir_entry.file_name().into_string().unwrap();
+                let Ok(stream_id) = name.parse::<u32>() else {
+                    continue;
+                };
+
+                if streams.iter().any(|stream| stream.id == stream_id) {
+                    continue;
+                }
+
+                error!("Stream with ID: '{stream_id}' was not found in state, but exists on data path: '{data_path}' and will be removed.");
+                if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
+                    error!("Cannot remove stream directory: {error}");
+                }
+            }
+        }
+
         let state_stream_ids = streams
             .iter()
             .map(|stream| stream.id)
@@ -90,6 +116,8 @@ impl System {
                     &stream_state.name,
                     self.config.clone(),
                     self.storage.clone(),
+                    self.appends_broadcaster.clone(),
+                    self.events_bus.clone(),
                 );
                 stream.persist().await?;
                 unloaded_streams.push(stream);
@@ -147,10 +175,33 @@ impl System {
         self.streams.values().collect()
     }
 
-    pub fn find_streams(&self, session: &Session) -> Result<Vec<&Stream>, IggyError> {
+    /// Returns the streams ordered by ID, skipping the `offset` streams and returning at most `limit` of them,
+    /// optionally only the ones which names start with the given prefix.
+    pub fn find_streams(
+        &self,
+        session: &Session,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
+    ) -> Result<Vec<&Stream>, IggyError> {
         self.ensure_authenticated(session)?;
         self.permissioner.get_streams(session.get_user_id())?;
-        Ok(self.get_streams())
+        let name_prefix = name_prefix.map(text::to_lowercase_non_whitespace);
+        let mut streams = self
+            .streams
+            .values()
+            .filter(|stream| {
+                name_prefix
+                    .as_ref()
+                    .is_none_or(|name_prefix| stream.name.starts_with(name_prefix))
+            })
+            .collect::<Vec<_>>();
+        streams.sort_unstable_by_key(|stream| stream.stream_id);
+        Ok(streams
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit.map_or(usize::MAX, |limit| limit as usize))
+            .collect())
     }
 
     pub fn find_stream(
@@ -237,6 +288,11 @@ impl System {
             return Err(IggyError::StreamNameAlreadyExists(name.to_string()));
         }
 
+        let max_streams = self.config.limits.max_streams;
+        if max_streams > 0 && self.streams.len() as u32 >= max_streams {
+            return Err(IggyError::StreamsLimitReached(max_streams));
+        }
+
         let mut id;
         if stream_id.is_none() {
             id = CURRENT_STREAM_ID.fetch_add(1, Ordering::SeqCst);
@@ -258,13 +314,25 @@ impl System {
             return Err(IggyError::StreamIdAlreadyExists(id));
         }
 
-        let stream = Stream::create(id, &name, self.config.clone(), self.storage.clone());
+        let stream = Stream::create(
+            id,
+            &name,
+            self.config.clone(),
+            self.storage.clone(),
+            self.appends_broadcaster.clone(),
+            self.events_bus.clone(),
+        );
         stream.persist().await?;
         info!("Created stream with ID: {id}, name: '{name}'.");
         self.streams_ids.insert(name, stream.stream_id);
         self.streams.insert(stream.stream_id, stream);
         self.metrics.increment_streams(1);
-        self.get_stream_by_id(id)
+        let stream = self.get_stream_by_id(id)?;
+        self.events_bus.publish(SystemEvent::StreamCreated {
+            stream_id: stream.stream_id,
+            name: stream.name.clone(),
+        });
+        Ok(stream)
     }
 
     pub async fn update_stream(
@@ -282,6 +350,7 @@ impl System {
 
         self.permissioner
             .update_stream(session.get_user_id(), stream_id)?;
+        self.ensure_not_reserved_stream(stream_id)?;
         let updated_name = text::to_lowercase_non_whitespace(name);
 
         {
@@ -322,6 +391,7 @@ impl System {
         let stream_id = stream.stream_id;
         self.permissioner
             .delete_stream(session.get_user_id(), stream_id)?;
+        self.ensure_not_reserved_stream(stream_id)?;
         let stream_name = stream.name.clone();
         if stream.delete().await.is_err() {
             return Err(IggyError::CannotDeleteStream(stream_id));
@@ -333,6 +403,8 @@ impl System {
             .decrement_partitions(stream.get_partitions_count());
         self.metrics.decrement_messages(stream.get_messages_count());
         self.metrics.decrement_segments(stream.get_segments_count());
+        self.metrics
+            .decrement_slow_consumers(self.slow_consumers.remove_consumers(stream_id, None));
         self.streams.remove(&stream_id);
         self.streams_ids.remove(&stream_name);
         let current_stream_id = CURRENT_STREAM_ID.load(Ordering::SeqCst);
@@ -344,6 +416,10 @@ impl System {
         client_manager
             .delete_consumer_groups_for_stream(stream_id)
             .await;
+        self.events_bus.publish(SystemEvent::StreamDeleted {
+            stream_id,
+            name: stream_name,
+        });
         Ok(stream_id)
     }
 
@@ -1,20 +1,30 @@
 use crate::configs::server::{DataMaintenanceConfig, PersonalAccessTokenConfig};
 use crate::configs::system::SystemConfig;
+use crate::streaming::appends::{AppendsBroadcaster, AppendsSubscriber};
 use crate::streaming::cache::memory_tracker::CacheMemoryTracker;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::diagnostics::disk_space::DiskSpaceMonitor;
 use crate::streaming::diagnostics::metrics::Metrics;
+use crate::streaming::diagnostics::slow_consumers::SlowConsumerDetector;
+use crate::streaming::events::EventsBus;
+use crate::streaming::interceptors::{InterceptorChain, MessageInterceptor};
 use crate::streaming::persistence::persister::*;
 use crate::streaming::session::Session;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::topics::deletion::TopicDeletions;
 use crate::streaming::users::permissioner::Permissioner;
+use crate::streaming::utils::key_provider;
+use crate::streaming::utils::keyring::Keyring;
+use flume::Sender;
 use iggy::error::IggyError;
+use iggy::models::system_event::SystemEvent;
 use iggy::utils::byte_size::IggyByteSize;
-use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use iggy::utils::crypto::Encryptor;
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
-use tokio::fs::{create_dir, remove_dir_all};
+use tokio::fs::{create_dir, create_dir_all, remove_dir_all};
 use tokio::time::Instant;
 use tracing::{info, instrument, trace};
 
@@ -29,21 +39,34 @@ use crate::versioning::SemanticVersion;
 use crate::{compat, map_toggle_str};
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::node_status::NodeState;
 use iggy::models::user_info::UserId;
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 #[derive(Debug)]
 pub struct SharedSystem {
     system: Arc<RwLock<System>>,
+    events_bus: Arc<EventsBus>,
 }
 
 impl SharedSystem {
     pub fn new(system: System) -> SharedSystem {
         SharedSystem {
+            events_bus: system.events_bus.clone(),
             system: Arc::new(RwLock::new(system)),
         }
     }
 
+    /// Registers the channel receiving the events published by the system, without locking it.
+    pub fn subscribe_to_events(&self, sender: Sender<SystemEvent>) {
+        self.events_bus.subscribe(sender);
+    }
+
+    /// Removes the event subscribers, so that the tasks receiving the events can finish.
+    pub fn clear_event_subscribers(&self) {
+        self.events_bus.clear();
+    }
+
     pub async fn read(&self) -> RwLockReadGuard<System> {
         self.system.read().await
     }
@@ -51,12 +74,19 @@ impl SharedSystem {
     pub async fn write(&self) -> RwLockWriteGuard<System> {
         self.system.write().await
     }
+
+    /// Returns the number of the handles sharing the system, including the ones held by the background tasks.
+    #[cfg(test)]
+    pub(crate) fn handles_count(&self) -> usize {
+        Arc::strong_count(&self.system)
+    }
 }
 
 impl Clone for SharedSystem {
     fn clone(&self) -> Self {
         SharedSystem {
             system: self.system.clone(),
+            events_bus: self.events_bus.clone(),
         }
     }
 }
@@ -65,15 +95,24 @@ impl Clone for SharedSystem {
 pub struct System {
     pub permissioner: Permissioner,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) appends_broadcaster: Arc<AppendsBroadcaster>,
+    pub(crate) events_bus: Arc<EventsBus>,
     pub(crate) streams: HashMap<u32, Stream>,
     pub(crate) streams_ids: HashMap<String, u32>,
     pub(crate) users: HashMap<UserId, User>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: IggySharedMut<ClientManager>,
     pub(crate) encryptor: Option<Arc<dyn Encryptor>>,
+    pub(crate) keyring: Option<Arc<Keyring>>,
     pub(crate) metrics: Metrics,
+    pub(crate) slow_consumers: SlowConsumerDetector,
+    pub(crate) disk_space: DiskSpaceMonitor,
+    pub(crate) topic_deletions: TopicDeletions,
     pub(crate) state: Arc<dyn State>,
     pub(crate) archiver: Option<Arc<dyn Archiver>>,
+    pub(crate) interceptors: InterceptorChain,
+    /// The state of this node in the cluster, kept only in memory, so the restarted node is active again.
+    pub(crate) node_state: NodeState,
     pub personal_access_token: PersonalAccessTokenConfig,
 }
 
@@ -93,12 +132,14 @@ impl System {
             map_toggle_str(config.encryption.enabled)
         );
 
-        let encryptor: Option<Arc<dyn Encryptor>> = match config.encryption.enabled {
+        let keyring = match config.encryption.enabled {
             true => Some(Arc::new(
-                Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
+                Keyring::load(key_provider::resolve(&config.encryption))
+                    .expect("Failed to load the encryption keys"),
             )),
             false => None,
         };
+        let encryptor = keyring.clone().map(|keyring| keyring as Arc<dyn Encryptor>);
 
         let state_persister = Self::resolve_persister(config.state.enforce_fsync);
         let partition_persister = Self::resolve_persister(config.partition.enforce_fsync);
@@ -109,14 +150,16 @@ impl System {
             state_persister,
             encryptor.clone(),
         ));
-        Self::create(
+        let mut system = Self::create(
             config.clone(),
             SystemStorage::new(config, partition_persister),
             state,
             encryptor,
             data_maintenance_config,
             pat_config,
-        )
+        );
+        system.keyring = keyring;
+        system
     }
 
     fn resolve_persister(enforce_fsync: bool) -> Arc<dyn Persister> {
@@ -160,11 +203,17 @@ impl System {
         };
 
         System {
+            slow_consumers: SlowConsumerDetector::new(system_config.slow_consumer.clone()),
+            disk_space: DiskSpaceMonitor::new(system_config.disk_monitor.clone()),
+            topic_deletions: TopicDeletions::default(),
             config: system_config,
             streams: HashMap::new(),
             streams_ids: HashMap::new(),
             storage: Arc::new(storage),
+            appends_broadcaster: Arc::new(AppendsBroadcaster::default()),
+            events_bus: Arc::new(EventsBus::default()),
             encryptor,
+            keyring: None,
             client_manager: IggySharedMut::new(ClientManager::default()),
             permissioner: Permissioner::default(),
             metrics: Metrics::init(),
@@ -172,9 +221,22 @@ impl System {
             state,
             personal_access_token: pat_config,
             archiver,
+            interceptors: InterceptorChain::default(),
+            node_state: NodeState::Active,
         }
     }
 
+    /// Registers the interceptor invoked for the appended and polled messages of all the topics.
+    pub fn register_interceptor(&mut self, interceptor: Arc<dyn MessageInterceptor>) {
+        info!("Registered message interceptor: {}.", interceptor.name());
+        self.interceptors.register(interceptor);
+    }
+
+    /// Subscribes to the messages appended to all the partitions of this system, e.g. to push them to the external systems.
+    pub fn subscribe_to_appended_messages(&self) -> AppendsSubscriber {
+        self.appends_broadcaster.subscribe()
+    }
+
     #[instrument(skip_all)]
     pub async fn init(&mut self) -> Result<(), IggyError> {
         let system_path = self.config.get_system_path();
@@ -192,6 +254,13 @@ impl System {
             return Err(IggyError::CannotCreateStreamsDirectory(streams_path));
         }
 
+        for data_path in &self.config.placement.paths {
+            let streams_path = self.config.get_data_streams_path(data_path);
+            if !Path::new(&streams_path).exists() && create_dir_all(&streams_path).await.is_err() {
+                return Err(IggyError::CannotCreateBaseDirectory(data_path.clone()));
+            }
+        }
+
         let runtime_path = self.config.get_runtime_path();
         if Path::new(&runtime_path).exists() && remove_dir_all(&runtime_path).await.is_err() {
             return Err(IggyError::CannotRemoveRuntimeDirectory(runtime_path));
@@ -218,11 +287,16 @@ impl System {
         let state_entries = self.state.init().await?;
         let system_state = SystemState::init(state_entries).await?;
         let now = Instant::now();
-        self.load_version().await?;
+        let mut system_info = self.load_version().await?;
+        let applied_migrations = self.apply_migrations(&mut system_info).await?;
         self.load_users(system_state.users.into_values().collect())
             .await?;
         self.load_streams(system_state.streams.into_values().collect())
             .await?;
+        self.get_startup_report(system_info.version.version, applied_migrations)
+            .await
+            .log();
+        self.init_events().await?;
         if let Some(archiver) = self.archiver.as_ref() {
             archiver
                 .init()
@@ -236,6 +310,7 @@ impl System {
     #[instrument(skip_all)]
     pub async fn shutdown(&mut self) -> Result<(), IggyError> {
         self.persist_messages().await?;
+        self.events_bus.clear();
         Ok(())
     }
 
@@ -261,21 +336,67 @@ impl System {
         }
     }
 
+    /// Evicts the cached messages from the partitions exceeding their budget first, and then from the least recently used ones,
+    /// so that the hot partitions keep their messages in the cache for as long as possible.
     pub async fn clean_cache(&self, size_to_clean: IggyByteSize) {
+        let mut partitions = Vec::new();
         for stream in self.streams.values() {
             for topic in stream.get_topics() {
-                for partition in topic.get_partitions().into_iter() {
-                    tokio::task::spawn(async move {
-                        let memory_tracker = CacheMemoryTracker::get_instance().unwrap();
-                        let mut partition_guard = partition.write().await;
-                        let cache = &mut partition_guard.cache.as_mut().unwrap();
-                        let size_to_remove = (cache.current_size().as_bytes_u64() as f64
-                            / memory_tracker.usage_bytes().as_bytes_u64() as f64
-                            * size_to_clean.as_bytes_u64() as f64)
-                            .ceil() as u64;
-                        cache.evict_by_size(size_to_remove * CACHE_OVER_EVICTION_FACTOR);
-                    });
+                for partition in topic.get_partitions() {
+                    let partition_guard = partition.read().await;
+                    let Some(cache) = partition_guard.cache.as_ref() else {
+                        continue;
+                    };
+                    if cache.is_empty() {
+                        continue;
+                    }
+
+                    let over_budget = cache.is_over_budget();
+                    let last_access = cache.last_access();
+                    drop(partition_guard);
+                    partitions.push((over_budget, last_access, partition));
+                }
+            }
+        }
+
+        // Partitions exceeding their budget are trimmed to the budget first, then the least recently used ones are evicted.
+        partitions.sort_by_key(|(over_budget, last_access, _)| (!over_budget, *last_access));
+        let mut remaining_size = size_to_clean.as_bytes_u64() * CACHE_OVER_EVICTION_FACTOR;
+        for trim_to_budget in [true, false] {
+            for (over_budget, _, partition) in partitions.iter() {
+                if remaining_size == 0 {
+                    return;
+                }
+
+                if trim_to_budget && !over_budget {
+                    continue;
                 }
+
+                let mut partition_guard = partition.write().await;
+                let Some(cache) = partition_guard.cache.as_mut() else {
+                    continue;
+                };
+                let size_to_remove = if trim_to_budget {
+                    let budget = CacheMemoryTracker::get_instance().map_or(0, |memory_tracker| {
+                        memory_tracker.partition_budget().as_bytes_u64()
+                    });
+                    cache
+                        .current_size()
+                        .as_bytes_u64()
+                        .saturating_sub(budget)
+                        .min(remaining_size)
+                } else {
+                    remaining_size
+                };
+                let removed_size = cache.evict_by_size(size_to_remove);
+                remaining_size = remaining_size.saturating_sub(removed_size);
+                trace!(
+                    "Evicted {} bytes from cache for partition ID: {}, topic ID: {}, stream ID: {}.",
+                    removed_size,
+                    partition_guard.partition_id,
+                    partition_guard.topic_id,
+                    partition_guard.stream_id
+                );
             }
         }
     }
@@ -5,8 +5,13 @@ use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
 use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::system_event::SystemEvent;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::text;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
+use tracing::info;
 
 impl System {
     pub fn find_topic(
@@ -27,16 +32,36 @@ impl System {
         topic
     }
 
+    /// Returns the topics of the stream ordered by ID, skipping the `offset` topics and returning at most `limit` of them,
+    /// optionally only the ones which names start with the given prefix.
     pub fn find_topics(
         &self,
         session: &Session,
         stream_id: &Identifier,
+        offset: u32,
+        limit: Option<u32>,
+        name_prefix: Option<&str>,
     ) -> Result<Vec<&Topic>, IggyError> {
         self.ensure_authenticated(session)?;
         let stream = self.get_stream(stream_id)?;
         self.permissioner
             .get_topics(session.get_user_id(), stream.stream_id)?;
-        Ok(stream.get_topics())
+        let name_prefix = name_prefix.map(text::to_lowercase_non_whitespace);
+        let mut topics = stream
+            .get_topics()
+            .into_iter()
+            .filter(|topic| {
+                name_prefix
+                    .as_ref()
+                    .is_none_or(|name_prefix| topic.name.starts_with(name_prefix))
+            })
+            .collect::<Vec<_>>();
+        topics.sort_unstable_by_key(|topic| topic.topic_id);
+        Ok(topics
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit.map_or(usize::MAX, |limit| limit as usize))
+            .collect())
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -51,14 +76,18 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
+        let replication_factor = Topic::get_replication_factor(replication_factor)?;
         {
             let stream = self.get_stream(stream_id)?;
             self.permissioner
                 .create_topic(session.get_user_id(), stream.stream_id)?;
         }
 
+        self.ensure_node_accepts_partitions()?;
         let created_topic_id = self
             .get_stream_mut(stream_id)?
             .create_topic(
@@ -68,7 +97,9 @@ impl System {
                 message_expiry,
                 compression_algorithm,
                 max_topic_size,
-                replication_factor.unwrap_or(1),
+                replication_factor,
+                cleanup_policy,
+                overrides,
             )
             .await?;
 
@@ -76,8 +107,16 @@ impl System {
         self.metrics.increment_partitions(partitions_count);
         self.metrics.increment_segments(partitions_count);
 
-        self.get_stream(stream_id)?
-            .get_topic(&created_topic_id.try_into()?)
+        let topic = self
+            .get_stream(stream_id)?
+            .get_topic(&created_topic_id.try_into()?)?;
+        self.events_bus.publish(SystemEvent::TopicCreated {
+            stream_id: topic.stream_id,
+            topic_id: topic.topic_id,
+            name: topic.name.clone(),
+            partitions_count,
+        });
+        Ok(topic)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -91,6 +130,8 @@ impl System {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: Option<u8>,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<&Topic, IggyError> {
         self.ensure_authenticated(session)?;
         {
@@ -100,8 +141,10 @@ impl System {
                 topic.stream_id,
                 topic.topic_id,
             )?;
+            self.ensure_not_reserved_stream(topic.stream_id)?;
         }
 
+        let replication_factor = Topic::get_replication_factor(replication_factor)?;
         self.get_stream_mut(stream_id)?
             .update_topic(
                 topic_id,
@@ -109,13 +152,14 @@ impl System {
                 message_expiry,
                 compression_algorithm,
                 max_topic_size,
-                replication_factor.unwrap_or(1),
+                replication_factor,
+                cleanup_policy,
+                overrides,
             )
             .await?;
 
         // TODO: if message_expiry is changed, we need to check if we need to purge messages based on the new expiry
         // TODO: if max_size_bytes is changed, we need to check if we need to purge messages based on the new size
-        // TODO: if replication_factor is changed, we need to do `something`
         self.get_stream(stream_id)?.get_topic(topic_id)
     }
 
@@ -134,12 +178,14 @@ impl System {
                 topic.stream_id,
                 topic.topic_id,
             )?;
+            self.ensure_not_reserved_stream(topic.stream_id)?;
             stream_id_value = topic.stream_id;
         }
 
+        let topic_deletions = self.topic_deletions.clone();
         let topic = self
             .get_stream_mut(stream_id)?
-            .delete_topic(topic_id)
+            .delete_topic(topic_id, &topic_deletions)
             .await?;
 
         self.metrics.decrement_topics(1);
@@ -148,10 +194,19 @@ impl System {
         self.metrics.decrement_messages(topic.get_messages_count());
         self.metrics
             .decrement_segments(topic.get_segments_count().await);
+        self.metrics.decrement_slow_consumers(
+            self.slow_consumers
+                .remove_consumers(stream_id_value, Some(topic.topic_id)),
+        );
         let client_manager = self.client_manager.read().await;
         client_manager
             .delete_consumer_groups_for_topic(stream_id_value, topic.topic_id)
             .await;
+        self.events_bus.publish(SystemEvent::TopicDeleted {
+            stream_id: stream_id_value,
+            topic_id: topic.topic_id,
+            name: topic.name,
+        });
         Ok(())
     }
 
@@ -166,4 +221,31 @@ impl System {
             .purge_topic(session.get_user_id(), topic.stream_id, topic.topic_id)?;
         topic.purge().await
     }
+
+    pub fn set_topic_read_only(
+        &mut self,
+        session: &Session,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        read_only: bool,
+    ) -> Result<(), IggyError> {
+        self.ensure_authenticated(session)?;
+        {
+            let topic = self.find_topic(session, stream_id, topic_id)?;
+            self.permissioner.update_topic(
+                session.get_user_id(),
+                topic.stream_id,
+                topic.topic_id,
+            )?;
+            self.ensure_not_reserved_stream(topic.stream_id)?;
+        }
+
+        let topic = self.get_stream_mut(stream_id)?.get_topic_mut(topic_id)?;
+        topic.read_only = read_only;
+        info!(
+            "Set topic with ID: {} for stream with ID: {} read-only: {read_only}",
+            topic.topic_id, topic.stream_id
+        );
+        Ok(())
+    }
 }
@@ -10,11 +10,13 @@ use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
 use iggy::models::permissions::Permissions;
+use iggy::models::system_event::SystemEvent;
 use iggy::models::user_status::UserStatus;
 use iggy::users::create_user::CreateUser;
 use iggy::users::defaults::*;
 use iggy::utils::text;
 use std::env;
+use std::net::IpAddr;
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::{error, info, warn};
 
@@ -374,7 +376,15 @@ impl System {
             }
         }
 
+        if let Some(session) = session {
+            self.ensure_user_source_allowed(&user.username, &session.ip_address.ip())?;
+        }
+
         info!("Logged in user: {username} with ID: {}.", user.id);
+        self.events_bus.publish(SystemEvent::UserLoggedIn {
+            user_id: user.id,
+            username: user.username.clone(),
+        });
         if session.is_none() {
             return Ok(user);
         }
@@ -397,6 +407,23 @@ impl System {
         Ok(user)
     }
 
+    /// Returns the error if the user is restricted to log in only from the source addresses not including the provided one.
+    pub fn ensure_user_source_allowed(
+        &self,
+        username: &str,
+        address: &IpAddr,
+    ) -> Result<(), IggyError> {
+        if self.config.user_sources.is_allowed(username, address) {
+            return Ok(());
+        }
+
+        warn!("User: {username} is not allowed to log in from address: {address}.");
+        Err(IggyError::UserSourceNotAllowed(
+            username.to_string(),
+            address.to_string(),
+        ))
+    }
+
     pub async fn logout_user(&self, session: &Session) -> Result<(), IggyError> {
         self.ensure_authenticated(session)?;
         let user = self.get_user(&Identifier::numeric(session.get_user_id())?)?;
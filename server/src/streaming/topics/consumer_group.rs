@@ -1,4 +1,7 @@
 use iggy::error::IggyError;
+use iggy::utils::duration::IggyDuration;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::collections::HashMap;
 use tokio::sync::RwLock;
 use tracing::trace;
@@ -9,24 +12,38 @@ pub struct ConsumerGroup {
     pub group_id: u32,
     pub name: String,
     pub partitions_count: u32,
+    pub rebalance_strategy: RebalanceStrategy,
     members: HashMap<u32, RwLock<ConsumerGroupMember>>,
 }
 
 #[derive(Debug)]
 pub struct ConsumerGroupMember {
     pub id: u32,
+    /// The ID of the static member, which keeps its partitions for the session timeout after its client disconnects.
+    pub instance_id: Option<String>,
+    /// The time at which the client of the static member disconnected, if it hasn't rejoined the group yet.
+    pub disconnected_at: Option<IggyTimestamp>,
+    /// The time of the last heartbeat sent by the member, if it has sent any (the older clients don't send them).
+    pub last_heartbeat_at: Option<IggyTimestamp>,
     partitions: HashMap<u32, u32>,
     current_partition_index: u32,
     current_partition_id: u32,
 }
 
 impl ConsumerGroup {
-    pub fn new(topic_id: u32, group_id: u32, name: &str, partitions_count: u32) -> ConsumerGroup {
+    pub fn new(
+        topic_id: u32,
+        group_id: u32,
+        name: &str,
+        partitions_count: u32,
+        rebalance_strategy: RebalanceStrategy,
+    ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             group_id,
             name: name.to_string(),
             partitions_count,
+            rebalance_strategy,
             members: HashMap::new(),
         }
     }
@@ -64,11 +81,20 @@ impl ConsumerGroup {
         ))
     }
 
-    pub async fn add_member(&mut self, member_id: u32) {
+    pub async fn add_member(&mut self, member_id: u32, instance_id: Option<&str>) {
+        if let Some(instance_id) = instance_id {
+            if self.rejoin_static_member(member_id, instance_id).await {
+                return;
+            }
+        }
+
         self.members.insert(
             member_id,
             RwLock::new(ConsumerGroupMember {
                 id: member_id,
+                instance_id: instance_id.map(|instance_id| instance_id.to_string()),
+                disconnected_at: None,
+                last_heartbeat_at: None,
                 partitions: HashMap::new(),
                 current_partition_index: 0,
                 current_partition_id: 0,
@@ -95,7 +121,122 @@ impl ConsumerGroup {
         }
     }
 
+    /// Hands over the partitions of the static member with the same instance ID (if any) to the rejoining member,
+    /// without reassigning the partitions of the other members.
+    async fn rejoin_static_member(&mut self, member_id: u32, instance_id: &str) -> bool {
+        let mut previous_member_id = None;
+        for member in self.members.values() {
+            let member = member.read().await;
+            if member.instance_id.as_deref() == Some(instance_id) {
+                previous_member_id = Some(member.id);
+                break;
+            }
+        }
+
+        let Some(previous_member_id) = previous_member_id else {
+            return false;
+        };
+
+        let Some(mut member) = self.members.remove(&previous_member_id) else {
+            return false;
+        };
+
+        {
+            let member = member.get_mut();
+            member.id = member_id;
+            member.disconnected_at = None;
+            member.last_heartbeat_at = None;
+        }
+        self.members.insert(member_id, member);
+        trace!(
+            "Static member: {instance_id} with ID: {previous_member_id} has rejoined consumer group: {} for topic with ID: {} as member with ID: {member_id}",
+            self.group_id,
+            self.topic_id
+        );
+        true
+    }
+
+    /// Removes the member whose client has disconnected, unless it's a static member,
+    /// which keeps its partitions until it rejoins the group or the session timeout elapses.
+    pub async fn disconnect_member(&mut self, member_id: u32) {
+        let Some(member) = self.members.get(&member_id) else {
+            return;
+        };
+
+        {
+            let mut member = member.write().await;
+            if member.instance_id.is_some() {
+                member.disconnected_at = Some(IggyTimestamp::now());
+                trace!(
+                    "Static member with ID: {member_id} in consumer group: {} for topic with ID: {} has disconnected",
+                    self.group_id,
+                    self.topic_id
+                );
+                return;
+            }
+        }
+
+        self.delete_member(member_id).await;
+    }
+
+    /// Records the heartbeat of the member and returns the partitions currently assigned to it.
+    pub async fn heartbeat(
+        &self,
+        member_id: u32,
+        now: IggyTimestamp,
+    ) -> Result<Vec<u32>, IggyError> {
+        let Some(member) = self.members.get(&member_id) else {
+            return Err(IggyError::ConsumerGroupMemberNotFound(
+                member_id,
+                self.group_id,
+                self.topic_id,
+            ));
+        };
+
+        let mut member = member.write().await;
+        member.last_heartbeat_at = Some(now);
+        let mut partitions = member.get_partitions();
+        partitions.sort_unstable();
+        Ok(partitions)
+    }
+
+    /// Removes the static members which haven't rejoined the group within the session timeout,
+    /// as well as the members which haven't sent the heartbeat within the session timeout, and returns their IDs.
+    pub async fn delete_expired_members(
+        &mut self,
+        now: IggyTimestamp,
+        session_timeout: IggyDuration,
+    ) -> Vec<u32> {
+        let mut expired_members = Vec::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            let Some(expires_from) = member.disconnected_at.or(member.last_heartbeat_at) else {
+                continue;
+            };
+            if expires_from.as_micros() + session_timeout.as_micros() <= now.as_micros() {
+                expired_members.push(member.id);
+            }
+        }
+
+        if expired_members.is_empty() {
+            return expired_members;
+        }
+
+        for member_id in &expired_members {
+            self.members.remove(member_id);
+        }
+        self.assign_partitions().await;
+        expired_members
+    }
+
     async fn assign_partitions(&mut self) {
+        match self.rebalance_strategy {
+            RebalanceStrategy::Eager => self.assign_partitions_eagerly().await,
+            RebalanceStrategy::Sticky => self.assign_partitions_sticky().await,
+        }
+    }
+
+    async fn assign_partitions_eagerly(&mut self) {
         let mut members = self.members.values_mut().collect::<Vec<_>>();
         if members.is_empty() {
             return;
@@ -122,6 +263,87 @@ impl ConsumerGroup {
                 partition_id, member.id, self.topic_id, self.group_id)
         }
     }
+
+    /// Keeps the partitions assigned to the members as long as possible, and moves only the ones
+    /// exceeding the fair share of a member (or not assigned at all) to the members below their share.
+    async fn assign_partitions_sticky(&mut self) {
+        let mut members = self.members.values_mut().collect::<Vec<_>>();
+        if members.is_empty() {
+            return;
+        }
+
+        let mut assignments = Vec::with_capacity(members.len());
+        for member in members.iter_mut() {
+            let member = member.get_mut();
+            let mut partitions = member
+                .partitions
+                .values()
+                .copied()
+                .filter(|partition_id| *partition_id <= self.partitions_count)
+                .collect::<Vec<_>>();
+            partitions.sort_unstable();
+            assignments.push((member.id, partitions));
+        }
+
+        // The members owning the most partitions get the remainder, so that they give up as few as possible.
+        assignments.sort_by(|(id1, partitions1), (id2, partitions2)| {
+            partitions2.len().cmp(&partitions1.len()).then(id1.cmp(id2))
+        });
+        let members_count = assignments.len() as u32;
+        let min_partitions = self.partitions_count / members_count;
+        let members_with_extra_partition = self.partitions_count % members_count;
+        let get_quota = |index: usize| {
+            if (index as u32) < members_with_extra_partition {
+                min_partitions as usize + 1
+            } else {
+                min_partitions as usize
+            }
+        };
+        let mut unassigned_partitions = (1..=self.partitions_count)
+            .filter(|partition_id| {
+                !assignments
+                    .iter()
+                    .any(|(_, partitions)| partitions.contains(partition_id))
+            })
+            .collect::<Vec<_>>();
+        for (index, (_, partitions)) in assignments.iter_mut().enumerate() {
+            let quota = get_quota(index);
+            if partitions.len() > quota {
+                unassigned_partitions.extend(partitions.drain(quota..));
+            }
+        }
+
+        unassigned_partitions.sort_unstable();
+        let mut unassigned_partitions = unassigned_partitions.into_iter();
+        for (index, (_, partitions)) in assignments.iter_mut().enumerate() {
+            let quota = get_quota(index);
+            while partitions.len() < quota {
+                let Some(partition_id) = unassigned_partitions.next() else {
+                    break;
+                };
+                partitions.push(partition_id);
+            }
+            partitions.sort_unstable();
+        }
+
+        for member in members.iter_mut() {
+            let member = member.get_mut();
+            let Some((_, partitions)) = assignments.iter().find(|(id, _)| *id == member.id) else {
+                continue;
+            };
+            if !partitions.contains(&member.current_partition_id) {
+                member.current_partition_id = 0;
+            }
+            member.current_partition_index = 0;
+            member.partitions = partitions
+                .iter()
+                .enumerate()
+                .map(|(index, partition_id)| (index as u32, *partition_id))
+                .collect();
+            trace!("Assigned partitions: {:?} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                partitions, member.id, self.topic_id, self.group_id)
+        }
+    }
 }
 
 impl ConsumerGroupMember {
@@ -168,10 +390,11 @@ mod tests {
             group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            rebalance_strategy: RebalanceStrategy::Eager,
             members: HashMap::new(),
         };
 
-        consumer_group.add_member(member_id).await;
+        consumer_group.add_member(member_id, None).await;
         for i in 0..1000 {
             let partition_id = consumer_group
                 .calculate_partition_id(member_id)
@@ -189,10 +412,11 @@ mod tests {
             group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            rebalance_strategy: RebalanceStrategy::Eager,
             members: HashMap::new(),
         };
 
-        consumer_group.add_member(member_id).await;
+        consumer_group.add_member(member_id, None).await;
         let member = consumer_group.members.get(&member_id).unwrap();
         let member = member.read().await;
         assert_eq!(
@@ -214,11 +438,12 @@ mod tests {
             group_id: 1,
             name: "test".to_string(),
             partitions_count: 3,
+            rebalance_strategy: RebalanceStrategy::Eager,
             members: HashMap::new(),
         };
 
-        consumer_group.add_member(member1_id).await;
-        consumer_group.add_member(member2_id).await;
+        consumer_group.add_member(member1_id, None).await;
+        consumer_group.add_member(member2_id, None).await;
         let member1 = consumer_group.members.get(&member1_id).unwrap();
         let member2 = consumer_group.members.get(&member2_id).unwrap();
         let member1 = member1.read().await;
@@ -251,11 +476,12 @@ mod tests {
             group_id: 1,
             name: "test".to_string(),
             partitions_count: 1,
+            rebalance_strategy: RebalanceStrategy::Eager,
             members: HashMap::new(),
         };
 
-        consumer_group.add_member(member1_id).await;
-        consumer_group.add_member(member2_id).await;
+        consumer_group.add_member(member1_id, None).await;
+        consumer_group.add_member(member2_id, None).await;
         let member1 = consumer_group.members.get(&member1_id).unwrap();
         let member2 = consumer_group.members.get(&member2_id).unwrap();
         let member1 = member1.read().await;
@@ -267,4 +493,197 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_move_only_the_partitions_needed_to_balance_the_members_using_sticky_strategy() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 6, RebalanceStrategy::Sticky);
+
+        consumer_group.add_member(1, None).await;
+        consumer_group.add_member(2, None).await;
+        let member1_partitions = get_sorted_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_sorted_partitions(&consumer_group, 2).await;
+        assert_eq!(member1_partitions.len(), 3);
+        assert_eq!(member2_partitions.len(), 3);
+
+        consumer_group.add_member(3, None).await;
+        let member1_partitions_after_join = get_sorted_partitions(&consumer_group, 1).await;
+        let member2_partitions_after_join = get_sorted_partitions(&consumer_group, 2).await;
+        let member3_partitions = get_sorted_partitions(&consumer_group, 3).await;
+        assert_eq!(member1_partitions_after_join.len(), 2);
+        assert_eq!(member2_partitions_after_join.len(), 2);
+        assert_eq!(member3_partitions.len(), 2);
+        assert!(member1_partitions_after_join
+            .iter()
+            .all(|partition_id| member1_partitions.contains(partition_id)));
+        assert!(member2_partitions_after_join
+            .iter()
+            .all(|partition_id| member2_partitions.contains(partition_id)));
+
+        consumer_group.delete_member(1).await;
+        let member2_partitions_after_leave = get_sorted_partitions(&consumer_group, 2).await;
+        let member3_partitions_after_leave = get_sorted_partitions(&consumer_group, 3).await;
+        assert_eq!(member2_partitions_after_leave.len(), 3);
+        assert_eq!(member3_partitions_after_leave.len(), 3);
+        assert!(member2_partitions_after_join
+            .iter()
+            .all(|partition_id| member2_partitions_after_leave.contains(partition_id)));
+        assert!(member3_partitions
+            .iter()
+            .all(|partition_id| member3_partitions_after_leave.contains(partition_id)));
+        let mut all_partitions = member2_partitions_after_leave
+            .into_iter()
+            .chain(member3_partitions_after_leave)
+            .collect::<Vec<_>>();
+        all_partitions.sort_unstable();
+        assert_eq!(all_partitions, (1..=6).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn should_release_deleted_partitions_using_sticky_strategy() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 4, RebalanceStrategy::Sticky);
+
+        consumer_group.add_member(1, None).await;
+        consumer_group.add_member(2, None).await;
+        consumer_group.reassign_partitions(2).await;
+        let member1_partitions = get_sorted_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_sorted_partitions(&consumer_group, 2).await;
+        assert_eq!(member1_partitions.len(), 1);
+        assert_eq!(member2_partitions.len(), 1);
+        assert_ne!(member1_partitions, member2_partitions);
+        assert!(member1_partitions
+            .iter()
+            .chain(member2_partitions.iter())
+            .all(|partition_id| *partition_id <= 2));
+    }
+
+    async fn get_sorted_partitions(consumer_group: &ConsumerGroup, member_id: u32) -> Vec<u32> {
+        let member = consumer_group.members.get(&member_id).unwrap();
+        let mut partitions = member.read().await.get_partitions();
+        partitions.sort_unstable();
+        partitions
+    }
+
+    #[tokio::test]
+    async fn should_keep_partitions_of_disconnected_static_member_until_it_rejoins() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 4, RebalanceStrategy::Eager);
+
+        consumer_group.add_member(1, Some("instance-1")).await;
+        consumer_group.add_member(2, Some("instance-2")).await;
+        let member1_partitions = get_sorted_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_sorted_partitions(&consumer_group, 2).await;
+
+        consumer_group.disconnect_member(1).await;
+        assert_eq!(consumer_group.members.len(), 2);
+        assert_eq!(
+            get_sorted_partitions(&consumer_group, 1).await,
+            member1_partitions
+        );
+
+        consumer_group.add_member(3, Some("instance-1")).await;
+        assert_eq!(consumer_group.members.len(), 2);
+        assert!(!consumer_group.members.contains_key(&1));
+        assert_eq!(
+            get_sorted_partitions(&consumer_group, 3).await,
+            member1_partitions
+        );
+        assert_eq!(
+            get_sorted_partitions(&consumer_group, 2).await,
+            member2_partitions
+        );
+        let member = consumer_group.members.get(&3).unwrap();
+        assert!(member.read().await.disconnected_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_delete_static_member_once_session_timeout_elapses() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 4, RebalanceStrategy::Eager);
+        let session_timeout = IggyDuration::from(1000);
+
+        consumer_group.add_member(1, Some("instance-1")).await;
+        consumer_group.add_member(2, None).await;
+        consumer_group.disconnect_member(1).await;
+        let now = IggyTimestamp::now();
+        assert!(consumer_group
+            .delete_expired_members(now, session_timeout)
+            .await
+            .is_empty());
+
+        let now = IggyTimestamp::from(now.as_micros() + session_timeout.as_micros());
+        let expired_members = consumer_group
+            .delete_expired_members(now, session_timeout)
+            .await;
+        assert_eq!(expired_members, vec![1]);
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(
+            get_sorted_partitions(&consumer_group, 2).await,
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_delete_dynamic_member_once_disconnected() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 2, RebalanceStrategy::Eager);
+
+        consumer_group.add_member(1, None).await;
+        consumer_group.add_member(2, None).await;
+        consumer_group.disconnect_member(1).await;
+        assert_eq!(consumer_group.members.len(), 1);
+        assert_eq!(get_sorted_partitions(&consumer_group, 2).await, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn should_return_assigned_partitions_on_heartbeat() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 4, RebalanceStrategy::Eager);
+
+        consumer_group.add_member(1, None).await;
+        assert_eq!(
+            consumer_group
+                .heartbeat(1, IggyTimestamp::now())
+                .await
+                .unwrap(),
+            vec![1, 2, 3, 4]
+        );
+
+        consumer_group.add_member(2, None).await;
+        let partitions = consumer_group
+            .heartbeat(1, IggyTimestamp::now())
+            .await
+            .unwrap();
+        assert_eq!(partitions, get_sorted_partitions(&consumer_group, 1).await);
+        assert_eq!(partitions.len(), 2);
+        assert!(matches!(
+            consumer_group.heartbeat(3, IggyTimestamp::now()).await,
+            Err(IggyError::ConsumerGroupMemberNotFound(3, 1, 1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_delete_member_once_session_timeout_elapses_since_its_last_heartbeat() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, "test", 4, RebalanceStrategy::Eager);
+        let session_timeout = IggyDuration::from(1000);
+
+        consumer_group.add_member(1, None).await;
+        consumer_group.add_member(2, None).await;
+        consumer_group.add_member(3, None).await;
+        let now = IggyTimestamp::now();
+        consumer_group.heartbeat(1, now).await.unwrap();
+        consumer_group.heartbeat(2, now).await.unwrap();
+        let now = IggyTimestamp::from(now.as_micros() + session_timeout.as_micros() / 2);
+        consumer_group.heartbeat(2, now).await.unwrap();
+        assert!(consumer_group
+            .delete_expired_members(now, session_timeout)
+            .await
+            .is_empty());
+
+        let now = IggyTimestamp::from(now.as_micros() + session_timeout.as_micros() / 2);
+        let expired_members = consumer_group
+            .delete_expired_members(now, session_timeout)
+            .await;
+        assert_eq!(expired_members, vec![1]);
+        assert_eq!(consumer_group.members.len(), 2);
+        let mut all_partitions = get_sorted_partitions(&consumer_group, 2).await;
+        all_partitions.extend(get_sorted_partitions(&consumer_group, 3).await);
+        all_partitions.sort_unstable();
+        assert_eq!(all_partitions, vec![1, 2, 3, 4]);
+    }
 }
@@ -3,7 +3,9 @@ use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::rebalance_strategy::RebalanceStrategy;
 use iggy::utils::text;
+use iggy::utils::timestamp::IggyTimestamp;
 use std::sync::atomic::Ordering;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -67,6 +69,7 @@ impl Topic {
         &mut self,
         group_id: Option<u32>,
         name: &str,
+        rebalance_strategy: RebalanceStrategy,
     ) -> Result<&RwLock<ConsumerGroup>, IggyError> {
         let name = text::to_lowercase_non_whitespace(name);
         if self.consumer_groups_ids.contains_key(&name) {
@@ -101,8 +104,13 @@ impl Topic {
             return Err(IggyError::ConsumerGroupIdAlreadyExists(id, self.topic_id));
         }
 
-        let consumer_group =
-            ConsumerGroup::new(self.topic_id, id, &name, self.partitions.len() as u32);
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            &name,
+            self.partitions.len() as u32,
+            rebalance_strategy,
+        );
         self.consumer_groups.insert(id, RwLock::new(consumer_group));
         self.consumer_groups_ids.insert(name, id);
         info!(
@@ -143,8 +151,9 @@ impl Topic {
                 if let Some((_, offset)) = partition.consumer_group_offsets.remove(&group_id) {
                     self.storage
                         .partition
-                        .delete_consumer_offset(&offset.path)
+                        .delete_consumer_offset(&offset)
                         .await?;
+                    partition.increment_consumer_offsets_records();
                 }
             }
 
@@ -161,10 +170,13 @@ impl Topic {
         &self,
         group_id: &Identifier,
         member_id: u32,
+        group_instance_id: Option<&str>,
     ) -> Result<(), IggyError> {
         let consumer_group = self.get_consumer_group(group_id)?;
         let mut consumer_group = consumer_group.write().await;
-        consumer_group.add_member(member_id).await;
+        consumer_group
+            .add_member(member_id, group_instance_id)
+            .await;
         info!(
             "Member with ID: {} has joined consumer group with ID: {} for topic with ID: {} and stream with ID: {}.",
             member_id, group_id, self.topic_id, self.stream_id
@@ -186,14 +198,46 @@ impl Topic {
         );
         Ok(())
     }
+
+    /// Records the heartbeat of the member and returns the partitions assigned to it, see `ConsumerGroup::heartbeat`.
+    pub async fn heartbeat_consumer_group(
+        &self,
+        group_id: &Identifier,
+        member_id: u32,
+    ) -> Result<Vec<u32>, IggyError> {
+        let consumer_group = self.get_consumer_group(group_id)?;
+        let consumer_group = consumer_group.read().await;
+        consumer_group
+            .heartbeat(member_id, IggyTimestamp::now())
+            .await
+    }
+
+    /// Handles the disconnection of the member's client, see `ConsumerGroup::disconnect_member`.
+    pub async fn disconnect_consumer_group_member(
+        &self,
+        group_id: &Identifier,
+        member_id: u32,
+    ) -> Result<(), IggyError> {
+        let consumer_group = self.get_consumer_group(group_id)?;
+        let mut consumer_group = consumer_group.write().await;
+        consumer_group.disconnect_member(member_id).await;
+        info!(
+            "Member with ID: {} of consumer group with ID: {} for topic with ID: {} and stream with ID: {} has disconnected.",
+            member_id, group_id, self.topic_id, self.stream_id
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
     use crate::streaming::storage::tests::get_test_system_storage;
     use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::utils::cleanup_policy::CleanupPolicy;
     use iggy::utils::expiry::IggyExpiry;
     use iggy::utils::topic_size::MaxTopicSize;
     use std::sync::atomic::{AtomicU32, AtomicU64};
@@ -205,7 +249,9 @@ mod tests {
         let name = "test";
         let mut topic = get_topic();
         let topic_id = topic.topic_id;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_ok());
         {
             let created_consumer_group = result.unwrap().read().await;
@@ -233,10 +279,14 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(Some(group_id), "test2").await;
+        let result = topic
+            .create_consumer_group(Some(group_id), "test2", RebalanceStrategy::default())
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(err, IggyError::ConsumerGroupIdAlreadyExists(_, _)));
@@ -248,11 +298,15 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(matches!(
@@ -267,7 +321,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic
@@ -282,7 +338,9 @@ mod tests {
         let group_id = 1;
         let name = "test";
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(Some(group_id), name).await;
+        let result = topic
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let group_id = group_id + 1;
@@ -300,11 +358,11 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
             .await
             .unwrap();
         let result = topic
-            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id)
+            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id, None)
             .await;
         assert!(result.is_ok());
         let consumer_group = topic
@@ -323,11 +381,11 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(Some(group_id), name)
+            .create_consumer_group(Some(group_id), name, RebalanceStrategy::default())
             .await
             .unwrap();
         topic
-            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id)
+            .join_consumer_group(&Identifier::numeric(group_id).unwrap(), member_id, None)
             .await
             .unwrap();
         let result = topic
@@ -345,6 +403,8 @@ mod tests {
 
     fn get_topic() -> Topic {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let id = 2;
         let name = "test";
@@ -362,6 +422,8 @@ mod tests {
             partitions_count,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             size_of_parent_stream,
             messages_count_of_parent_stream,
             segments_count_of_parent_stream,
@@ -369,6 +431,8 @@ mod tests {
             compression_algorithm,
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap()
     }
@@ -1,9 +1,14 @@
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
-use iggy::consumer::Consumer;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_offsets::reset_consumer_offsets::OffsetResetStrategy;
 use iggy::error::IggyError;
+use iggy::identifier::Identifier;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::consumer_info::ConsumerInfo;
+use iggy::models::consumer_lag_info::ConsumerLagInfo;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset_info::ConsumerOffsetResetInfo;
 
 impl Topic {
     pub async fn store_consumer_offset(
@@ -16,22 +21,29 @@ impl Topic {
         let (polling_consumer, partition_id) = self
             .resolve_consumer_with_partition_id(&consumer, client_id, partition_id, false)
             .await?;
-        let partition = self.get_partition(partition_id)?;
-        let partition = partition.read().await;
-        partition
-            .store_consumer_offset(polling_consumer, offset)
-            .await
+        self.store_consumer_offset_internal(
+            polling_consumer,
+            PollingConsumer::resolve_consumer_name(&consumer).as_deref(),
+            offset,
+            partition_id,
+        )
+        .await
     }
 
     pub async fn store_consumer_offset_internal(
         &self,
         consumer: PollingConsumer,
+        consumer_name: Option<&str>,
         offset: u64,
         partition_id: u32,
     ) -> Result<(), IggyError> {
         let partition = self.get_partition(partition_id)?;
         let partition = partition.read().await;
-        partition.store_consumer_offset(consumer, offset).await
+        partition.store_consumer_offset(consumer, offset).await?;
+        if let (PollingConsumer::Consumer(consumer_id, _), Some(name)) = (consumer, consumer_name) {
+            partition.store_consumer_name(consumer_id, name).await?;
+        }
+        Ok(())
     }
 
     pub async fn get_consumer_offset(
@@ -52,4 +64,116 @@ impl Topic {
             stored_offset: offset,
         })
     }
+
+    pub async fn get_consumer_lag(
+        &self,
+        consumer: &Consumer,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<ConsumerLagInfo>, IggyError> {
+        let consumer_id = match consumer.kind {
+            ConsumerKind::Consumer => PollingConsumer::resolve_consumer_id(&consumer.id),
+            ConsumerKind::ConsumerGroup => {
+                self.get_consumer_group(&consumer.id)?.read().await.group_id
+            }
+        };
+
+        if let Some(partition_id) = partition_id {
+            let partition = self.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            return Ok(vec![partition.get_consumer_lag(consumer.kind, consumer_id)]);
+        }
+
+        let mut lags = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            lags.push(partition.get_consumer_lag(consumer.kind, consumer_id));
+        }
+        lags.sort_by_key(|lag| lag.partition_id);
+        Ok(lags)
+    }
+
+    pub async fn get_consumers(&self, partition_id: u32) -> Result<Vec<ConsumerInfo>, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        Ok(partition.get_consumers())
+    }
+
+    pub async fn delete_consumer(
+        &self,
+        consumer_id: &Identifier,
+        partition_id: u32,
+    ) -> Result<(), IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition
+            .delete_consumer(PollingConsumer::resolve_consumer_id(consumer_id))
+            .await
+    }
+
+    pub async fn delete_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        partition_id: Option<u32>,
+    ) -> Result<(), IggyError> {
+        let consumer_id = match consumer.kind {
+            ConsumerKind::Consumer => PollingConsumer::resolve_consumer_id(&consumer.id),
+            ConsumerKind::ConsumerGroup => {
+                self.get_consumer_group(&consumer.id)?.read().await.group_id
+            }
+        };
+
+        let partition_ids = match partition_id {
+            Some(partition_id) => vec![partition_id],
+            None => self.partitions.keys().copied().collect(),
+        };
+
+        let mut deleted = false;
+        for partition_id in partition_ids {
+            let partition = self.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            deleted |= partition
+                .delete_consumer_offset(consumer.kind, consumer_id)
+                .await?;
+        }
+
+        if !deleted {
+            return Err(IggyError::ConsumerOffsetNotFound(consumer_id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        partitions: &[u32],
+        strategy: &OffsetResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetResetInfo>, IggyError> {
+        let consumer_id = match consumer.kind {
+            ConsumerKind::Consumer => PollingConsumer::resolve_consumer_id(&consumer.id),
+            ConsumerKind::ConsumerGroup => {
+                self.get_consumer_group(&consumer.id)?.read().await.group_id
+            }
+        };
+
+        let mut partition_ids = if partitions.is_empty() {
+            self.partitions.keys().copied().collect::<Vec<_>>()
+        } else {
+            partitions.to_vec()
+        };
+        partition_ids.sort_unstable();
+
+        let mut resets = Vec::with_capacity(partition_ids.len());
+        for partition_id in partition_ids {
+            let partition = self.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            resets.push(
+                partition
+                    .reset_consumer_offset(consumer.kind, consumer_id, strategy, dry_run)
+                    .await?,
+            );
+        }
+        Ok(resets)
+    }
 }
@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::fs;
+use tracing::{error, info};
+
+const DELETED_TOPIC_DIRECTORY_SUFFIX: &str = ".deleted-";
+
+/// Returns the path to which the directory of the deleted topic is moved before its files are removed,
+/// so that the topic created with the same ID doesn't share the directory with the files being removed.
+pub fn get_deleted_topic_path(path: &str, timestamp: u64) -> String {
+    format!("{path}{DELETED_TOPIC_DIRECTORY_SUFFIX}{timestamp}")
+}
+
+/// Returns true if the directory belongs to the deleted topic, which files weren't fully removed.
+pub fn is_deleted_topic_directory(name: &str) -> bool {
+    name.contains(DELETED_TOPIC_DIRECTORY_SUFFIX)
+}
+
+#[derive(Debug, Default)]
+struct TopicDeletionsProgress {
+    topics_count: AtomicU32,
+    size_bytes: AtomicU64,
+}
+
+/// Tracks the deleted topics, which files are still being removed in the background.
+#[derive(Debug, Default, Clone)]
+pub struct TopicDeletions {
+    progress: Arc<TopicDeletionsProgress>,
+}
+
+impl TopicDeletions {
+    /// Returns the number of the deleted topics, which files are still being removed.
+    pub fn get_topics_count(&self) -> u32 {
+        self.progress.topics_count.load(Ordering::SeqCst)
+    }
+
+    /// Returns the size of the messages of the deleted topics, which files are still being removed.
+    pub fn get_size_bytes(&self) -> u64 {
+        self.progress.size_bytes.load(Ordering::SeqCst)
+    }
+
+    /// Removes the files of the deleted topic from the moved directories in the background,
+    /// the size of the removed files is subtracted from the remaining size as the removal progresses.
+    pub fn spawn(&self, stream_id: u32, topic_id: u32, paths: Vec<String>, size_bytes: u64) {
+        let progress = self.progress.clone();
+        progress.topics_count.fetch_add(1, Ordering::SeqCst);
+        progress.size_bytes.fetch_add(size_bytes, Ordering::SeqCst);
+        tokio::spawn(async move {
+            let mut remaining_size_bytes = size_bytes;
+            for path in paths {
+                info!("Removing files of deleted topic with ID: {topic_id} for stream with ID: {stream_id} from path: {path}...");
+                let mut directories = vec![PathBuf::from(&path)];
+                while let Some(directory) = directories.pop() {
+                    let Ok(mut dir_entries) = fs::read_dir(&directory).await else {
+                        continue;
+                    };
+
+                    while let Ok(Some(dir_entry)) = dir_entries.next_entry().await {
+                        let Ok(metadata) = dir_entry.metadata().await else {
+                            continue;
+                        };
+
+                        if metadata.is_dir() {
+                            directories.push(dir_entry.path());
+                            continue;
+                        }
+
+                        if let Err(error) = fs::remove_file(dir_entry.path()).await {
+                            error!(
+                                "Cannot remove file: {} of deleted topic with ID: {topic_id} for stream with ID: {stream_id}. Error: {error}",
+                                dir_entry.path().display()
+                            );
+                            continue;
+                        }
+
+                        let removed_size_bytes = metadata.len().min(remaining_size_bytes);
+                        remaining_size_bytes -= removed_size_bytes;
+                        progress
+                            .size_bytes
+                            .fetch_sub(removed_size_bytes, Ordering::SeqCst);
+                    }
+                }
+
+                match fs::remove_dir_all(&path).await {
+                    Ok(()) => info!(
+                        "Removed files of deleted topic with ID: {topic_id} for stream with ID: {stream_id}."
+                    ),
+                    Err(error) if error.kind() == std::io::ErrorKind::NotFound => info!(
+                        "Directory: {path} of deleted topic with ID: {topic_id} for stream with ID: {stream_id} was already removed."
+                    ),
+                    Err(error) => error!(
+                        "Cannot remove directory: {path} of deleted topic with ID: {topic_id} for stream with ID: {stream_id}, it will be removed on the next start. Error: {error}"
+                    ),
+                }
+            }
+
+            progress
+                .size_bytes
+                .fetch_sub(remaining_size_bytes, Ordering::SeqCst);
+            progress.topics_count.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn deleted_topic_directory_should_be_recognized() {
+        let path = get_deleted_topic_path("local_data/streams/1/topics/2", 1000);
+
+        assert_eq!(path, "local_data/streams/1/topics/2.deleted-1000");
+        assert!(is_deleted_topic_directory("2.deleted-1000"));
+        assert!(!is_deleted_topic_directory("2"));
+    }
+
+    #[tokio::test]
+    async fn files_of_deleted_topic_should_be_removed_in_background() {
+        let path = format!(
+            "local_data_{}/topics/1.deleted-1",
+            iggy::utils::timestamp::IggyTimestamp::now().as_micros()
+        );
+        let partition_path = format!("{path}/partitions/1");
+        fs::create_dir_all(&partition_path).await.unwrap();
+        fs::write(
+            format!("{partition_path}/00000000000000000000.log"),
+            [0; 100],
+        )
+        .await
+        .unwrap();
+        fs::write(
+            format!("{partition_path}/00000000000000000000.index"),
+            [0; 8],
+        )
+        .await
+        .unwrap();
+
+        let deletions = TopicDeletions::default();
+        deletions.spawn(1, 1, vec![path.clone()], 100);
+        assert_eq!(deletions.get_topics_count(), 1);
+
+        for _ in 0..100 {
+            if deletions.get_topics_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(deletions.get_topics_count(), 0);
+        assert_eq!(deletions.get_size_bytes(), 0);
+        assert!(!PathBuf::from(&path).exists());
+        fs::remove_dir_all(path.split("/topics").next().unwrap())
+            .await
+            .unwrap();
+    }
+}
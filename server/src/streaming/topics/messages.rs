@@ -1,18 +1,28 @@
+use crate::configs::system::TopicCompression;
 use crate::streaming::batching::appendable_batch_info::AppendableBatchInfo;
 use crate::streaming::models::messages::RetainedMessage;
+use crate::streaming::partitions::flush_worker::PartitionFlushWorker;
+use crate::streaming::partitions::notifier::MessagesSubscriber;
+use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::file::folder_size;
 use crate::streaming::utils::hash;
+use bytes::Bytes;
+use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::error::IggyError;
-use iggy::locking::IggySharedMutFn;
-use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Message, Partitioning, PartitioningKind};
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::messages::copy_messages::CopyRangeKind;
+use iggy::messages::peek_messages::PeekKind;
+use iggy::messages::poll_messages::{PollingDirection, PollingKind, PollingStrategy};
+use iggy::messages::send_messages::{AckLevel, Message, Partitioning, PartitioningKind};
 use iggy::models::messages::PolledMessages;
+use iggy::models::replica_messages::ReplicaMessages;
 use iggy::utils::byte_size::IggyByteSize;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::timestamp_type::{append_timestamp_header, get_create_timestamp, TimestampType};
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -29,6 +39,7 @@ impl Topic {
         partition_id: u32,
         strategy: PollingStrategy,
         count: u32,
+        max_bytes: u32,
     ) -> Result<PolledMessages, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
@@ -43,28 +54,271 @@ impl Topic {
             ));
         }
 
-        let partition = partition.unwrap();
-        let partition = partition.read().await;
+        let partition = partition.unwrap().clone();
+        let partition_guard = partition.read().await;
+        partition_guard.ensure_leader()?;
         let value = strategy.value;
-        let messages = match strategy.kind {
-            PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
-            PollingKind::Timestamp => {
-                partition
-                    .get_messages_by_timestamp(value.into(), count)
+        let messages = match (strategy.direction, strategy.kind) {
+            (PollingDirection::Backward, PollingKind::Offset) => {
+                let offset = partition_guard.resolve_offset(value, strategy.out_of_range)?;
+                partition_guard
+                    .get_messages_backward(offset, count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Backward, PollingKind::Timestamp) => {
+                partition_guard
+                    .get_messages_backward_by_timestamp(value.into(), count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Backward, PollingKind::First) => {
+                partition_guard
+                    .get_messages_backward(partition_guard.get_low_watermark(), count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Backward, PollingKind::Last) => {
+                partition_guard
+                    .get_messages_backward(partition_guard.current_offset, count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Backward, PollingKind::Next) => {
+                Err(IggyError::InvalidPollingDirection)
+            }
+            (PollingDirection::Forward, PollingKind::Offset) => {
+                let offset = partition_guard.resolve_offset(value, strategy.out_of_range)?;
+                partition_guard
+                    .get_messages_by_offset(offset, count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Forward, PollingKind::Timestamp) => {
+                partition_guard
+                    .get_messages_by_timestamp(value.into(), count, max_bytes)
+                    .await
+            }
+            (PollingDirection::Forward, PollingKind::First) => {
+                partition_guard.get_first_messages(count, max_bytes).await
+            }
+            (PollingDirection::Forward, PollingKind::Last) => {
+                partition_guard.get_last_messages(count, max_bytes).await
+            }
+            (PollingDirection::Forward, PollingKind::Next) => {
+                partition_guard
+                    .get_next_messages(consumer, strategy.out_of_range, count, max_bytes)
                     .await
             }
-            PollingKind::First => partition.get_first_messages(count).await,
-            PollingKind::Last => partition.get_last_messages(count).await,
-            PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
 
+        let mut messages = match (strategy.direction, strategy.kind) {
+            (
+                PollingDirection::Forward,
+                PollingKind::Offset | PollingKind::First | PollingKind::Next,
+            ) => {
+                partition_guard
+                    .skip_expired_messages(messages, count, max_bytes)
+                    .await?
+            }
+            _ => partition_guard.remove_expired_messages(messages),
+        };
+        // Only the messages replicated by all the in-sync replicas are visible to the consumers.
+        let high_watermark = partition_guard.get_visible_high_watermark();
+        messages.retain(|message| message.offset < high_watermark);
+        // The poll stops at the first message created at or after the end timestamp, so that exactly one time window is returned.
+        let end_timestamp_reached = strategy.end_timestamp > 0
+            && strategy.direction == PollingDirection::Forward
+            && match messages
+                .iter()
+                .position(|msg| msg.timestamp >= strategy.end_timestamp)
+            {
+                Some(position) => {
+                    messages.truncate(position);
+                    true
+                }
+                None => false,
+            };
         let messages = messages
             .into_iter()
             .map(|msg| msg.to_polled_message())
             .collect::<Result<Vec<_>, IggyError>>()?;
+        let current_offset = partition_guard.current_offset;
+        let epoch = partition_guard.epoch;
+        let next_offset = match (strategy.direction, messages.last()) {
+            (PollingDirection::Forward, Some(message))
+                if message.offset < current_offset && !end_timestamp_reached =>
+            {
+                Some(message.offset + 1)
+            }
+            (PollingDirection::Backward, Some(message))
+                if message.offset > partition_guard.get_low_watermark() =>
+            {
+                Some(message.offset - 1)
+            }
+            _ => None,
+        };
+        let read_ahead_offset = partition_guard.read_ahead.start();
+        drop(partition_guard);
+        if let Some(offset) = read_ahead_offset {
+            tokio::spawn(async move {
+                let partition = partition.read().await;
+                if let Err(error) = partition.read_ahead_messages(offset).await {
+                    warn!(
+                        "Failed to read ahead messages from offset: {offset} for partition with ID: {}, error: {error}",
+                        partition.partition_id
+                    );
+                }
+            });
+        }
+
+        Ok(PolledMessages {
+            partition_id,
+            current_offset,
+            epoch,
+            next_offset,
+            messages,
+        })
+    }
+
+    pub async fn peek_messages(
+        &self,
+        partition_id: u32,
+        kind: PeekKind,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        let partition = self.partitions.get(&partition_id).ok_or_else(|| {
+            IggyError::PartitionNotFound(partition_id, self.topic_id, self.stream_id)
+        })?;
+        let partition = partition.read().await;
+        partition.ensure_leader()?;
+        let messages = match kind {
+            PeekKind::First => partition.get_first_messages(count, 0).await,
+            PeekKind::Last => partition.get_last_messages(count, 0).await,
+            PeekKind::Random => partition.get_random_messages(count).await,
+        }?;
+
+        let messages = partition
+            .remove_expired_messages(messages)
+            .into_iter()
+            .map(|msg| msg.to_polled_message())
+            .collect::<Result<Vec<_>, IggyError>>()?;
+        Ok(PolledMessages {
+            partition_id,
+            current_offset: partition.current_offset,
+            epoch: partition.epoch,
+            next_offset: None,
+            messages,
+        })
+    }
+
+    /// Returns the latest message for the given key (message ID) in the partition of the compacted topic, if there's any.
+    pub async fn get_message_by_key(
+        &self,
+        partition_id: u32,
+        key: u128,
+    ) -> Result<PolledMessages, IggyError> {
+        if !self.cleanup_policy.is_compact() {
+            return Err(IggyError::TopicNotCompacted(self.topic_id, self.stream_id));
+        }
+
+        let partition = self.partitions.get(&partition_id).ok_or_else(|| {
+            IggyError::PartitionNotFound(partition_id, self.topic_id, self.stream_id)
+        })?;
+        let mut partition = partition.write().await;
+        let messages = partition
+            .get_message_by_key(key)
+            .await?
+            .into_iter()
+            .collect();
+        let messages = partition
+            .remove_expired_messages(messages)
+            .into_iter()
+            .map(|msg| msg.to_polled_message())
+            .collect::<Result<Vec<_>, IggyError>>()?;
+        Ok(PolledMessages {
+            partition_id,
+            current_offset: partition.current_offset,
+            epoch: partition.epoch,
+            next_offset: None,
+            messages,
+        })
+    }
+
+    /// Returns the messages of the partition for the follower replicating it, validating the epoch of its last replicated message.
+    /// When transferring the leadership (e.g. while draining the node), it's handed over once the follower has replicated all the messages,
+    /// while the lock of the partition prevents any concurrent appends.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fetch_replica_messages(
+        &self,
+        partition_id: u32,
+        replica_id: u32,
+        offset: u64,
+        epoch: u32,
+        count: u32,
+        transfer_leadership: bool,
+    ) -> Result<ReplicaMessages, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        let replica_messages = partition
+            .fetch_replica_messages(replica_id, offset, epoch, count)
+            .await?;
+        if transfer_leadership {
+            partition.transfer_leadership();
+        }
+        Ok(replica_messages)
+    }
+
+    /// Returns the range of offsets (inclusive) of the messages within the provided range of offsets or timestamps,
+    /// limited to the messages appended so far, or `None` if there are no such messages.
+    pub async fn get_copied_offsets_range(
+        &self,
+        partition_id: u32,
+        kind: CopyRangeKind,
+        from: u64,
+        to: u64,
+    ) -> Result<Option<(u64, u64)>, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        if partition.get_messages_count() == 0 || from >= to {
+            return Ok(None);
+        }
+
+        let (start_offset, end_offset) = match kind {
+            CopyRangeKind::Offset => (
+                from.max(partition.get_low_watermark()),
+                (to - 1).min(partition.current_offset),
+            ),
+            CopyRangeKind::Timestamp => {
+                let Some(info) = partition.get_timestamp_offset_info(from.into()).await? else {
+                    return Ok(None);
+                };
+                (info.offset, partition.current_offset)
+            }
+        };
+        Ok((start_offset <= end_offset).then_some((start_offset, end_offset)))
+    }
+
+    /// Returns up to `count` messages to copy starting from the offset, skipping the ones which have expired
+    /// or have been deleted in the meantime. The `next_offset` points to the message following the read ones.
+    pub async fn get_copied_messages(
+        &self,
+        partition_id: u32,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<PolledMessages, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        let start_offset = start_offset.max(partition.get_low_watermark());
+        let messages = partition
+            .get_messages_by_offset(start_offset, count, 0)
+            .await?;
+        let next_offset = messages.last().map(|message| message.offset + 1);
+        let messages = partition
+            .remove_expired_messages(messages)
+            .into_iter()
+            .map(|msg| msg.to_polled_message())
+            .collect::<Result<Vec<_>, IggyError>>()?;
         Ok(PolledMessages {
             partition_id,
             current_offset: partition.current_offset,
+            epoch: partition.epoch,
+            next_offset,
             messages,
         })
     }
@@ -74,17 +328,18 @@ impl Topic {
         batch_size: IggyByteSize,
         partitioning: Partitioning,
         messages: Vec<Message>,
-    ) -> Result<(), IggyError> {
+        ack: AckLevel,
+    ) -> Result<u32, IggyError> {
         if !self.has_partitions() {
             return Err(IggyError::NoPartitions(self.topic_id, self.stream_id));
         }
 
-        if self.is_full() {
-            return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
+        if self.read_only {
+            return Err(IggyError::TopicReadOnly(self.topic_id, self.stream_id));
         }
 
-        if messages.is_empty() {
-            return Ok(());
+        if self.is_full() {
+            return Err(IggyError::TopicFull(self.topic_id, self.stream_id));
         }
 
         let partition_id = match partitioning.kind {
@@ -97,9 +352,69 @@ impl Topic {
             }
         };
 
+        if messages.is_empty() {
+            return Ok(partition_id);
+        }
+
         let appendable_batch_info = AppendableBatchInfo::new(batch_size, partition_id);
-        self.append_messages_to_partition(appendable_batch_info, messages)
-            .await
+        self.append_messages_to_partition(appendable_batch_info, messages, ack)
+            .await?;
+        Ok(partition_id)
+    }
+
+    /// Recompresses the messages payloads with the compression algorithm of the topic, if required by the config,
+    /// recording it in the `content-encoding` header, while the payloads already compressed with it are passed through.
+    pub fn recompress_messages(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if self.config.topic.compression != TopicCompression::Topic {
+            return Ok(());
+        }
+
+        for message in messages
+            .iter_mut()
+            .filter(|message| !message.is_tombstone())
+        {
+            let compression =
+                CompressionAlgorithm::from_headers(&message.headers)?.unwrap_or_default();
+            if compression == self.compression_algorithm {
+                continue;
+            }
+
+            let payload = compression.decompress(&message.payload)?;
+            message.payload = Bytes::from(self.compression_algorithm.compress(&payload)?);
+            message.length = message.payload.len() as u32;
+            let (key, value) = self.compression_algorithm.to_header()?;
+            let headers = message.headers.get_or_insert_with(HashMap::new);
+            if self.compression_algorithm == CompressionAlgorithm::None {
+                headers.remove(&key);
+            } else {
+                headers.insert(key, value);
+            }
+            if headers.is_empty() {
+                message.headers = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the append time in the `append-timestamp` header of the messages carrying the create time,
+    /// if the latter is stored as the message timestamp, so that both of them are available to the consumers.
+    pub fn record_append_timestamps(&self, messages: &mut [Message]) -> Result<(), IggyError> {
+        if self.config.partition.message_timestamp_type != TimestampType::CreateTime {
+            return Ok(());
+        }
+
+        let (key, value) = append_timestamp_header(IggyTimestamp::now())?;
+        for message in messages.iter_mut() {
+            if get_create_timestamp(&message.headers)?.is_none() {
+                continue;
+            }
+
+            message
+                .headers
+                .get_or_insert_with(HashMap::new)
+                .insert(key.clone(), value.clone());
+        }
+        Ok(())
     }
 
     pub async fn flush_unsaved_buffer(
@@ -118,28 +433,87 @@ impl Topic {
             .await
     }
 
+    /// Appends the messages while holding the write lock of the partition, so the concurrent appends to the same partition
+    /// (e.g. from multiple connections) are serialized and each batch is stored contiguously, in the order of acquiring the lock.
+    /// The messages are persisted by the flush worker of the partition once the lock is released, and the caller waits
+    /// for it only if the fsync is required, or if the worker falls behind by more than the number of messages required to save.
     async fn append_messages_to_partition(
         &self,
         appendable_batch_info: AppendableBatchInfo,
         messages: Vec<Message>,
+        ack: AckLevel,
     ) -> Result<(), IggyError> {
-        let partition = self.partitions.get(&appendable_batch_info.partition_id);
-        partition
-            .ok_or_else(|| {
-                IggyError::PartitionNotFound(
-                    appendable_batch_info.partition_id,
-                    self.stream_id,
-                    self.stream_id,
+        let partition_id = appendable_batch_info.partition_id;
+        let partition = self.partitions.get(&partition_id).ok_or_else(|| {
+            IggyError::PartitionNotFound(partition_id, self.stream_id, self.stream_id)
+        })?;
+        let (should_persist, is_behind, replication) = {
+            let mut partition = partition.write().await;
+            partition.ensure_leader()?;
+            partition.ensure_not_failed()?;
+            let should_persist = partition
+                .append_messages_to_buffer(appendable_batch_info, messages)
+                .await?;
+            let is_behind = partition.unsaved_messages_count
+                >= 2 * self.config.partition.messages_required_to_save.max(1);
+            let replication = (ack == AckLevel::All).then(|| {
+                (
+                    partition.subscribe_to_replication(),
+                    partition.get_high_watermark(),
+                    partition.epoch,
                 )
-            })?
-            .write()
-            .await
-            .append_messages(appendable_batch_info, messages)
-            .await?;
+            });
+            (should_persist, is_behind, replication)
+        };
 
+        let fsync = ack.requires_fsync();
+        if fsync || should_persist {
+            let flush_worker = self
+                .flush_workers
+                .entry(partition_id)
+                .or_insert_with(|| PartitionFlushWorker::spawn(partition.clone()))
+                .clone();
+            if fsync || is_behind {
+                flush_worker.flush(fsync).await?;
+            } else {
+                flush_worker.request_flush();
+            }
+        }
+
+        if let Some((subscriber, end_offset, epoch)) = replication {
+            self.wait_for_in_sync_replicas(partition, subscriber, end_offset, epoch)
+                .await;
+        }
         Ok(())
     }
 
+    /// Waits until all the in-sync replicas of the partition have replicated the messages below the end offset.
+    /// The replica which doesn't catch up within the replica lag timeout drops out of the in-sync replicas,
+    /// so the wait is bounded by the timeout, and without any in-sync replicas it ends immediately.
+    /// It ends as well once the log of the partition has been rewritten (e.g. purged) in the meantime.
+    async fn wait_for_in_sync_replicas(
+        &self,
+        partition: &IggySharedMut<Partition>,
+        mut subscriber: MessagesSubscriber,
+        end_offset: u64,
+        epoch: u32,
+    ) {
+        let timeout = self.config.partition.replica_lag_timeout.get_duration();
+        loop {
+            {
+                let partition = partition.read().await;
+                if partition.epoch != epoch || partition.get_visible_high_watermark() >= end_offset
+                {
+                    return;
+                }
+            }
+
+            subscriber
+                .wait_for_offset(end_offset.saturating_sub(1), timeout)
+                .await;
+        }
+    }
+
     fn get_next_partition_id(&self) -> u32 {
         let mut partition_id = self.current_partition_id.fetch_add(1, Ordering::SeqCst);
         let partitions_count = self.partitions.len() as u32;
@@ -172,10 +546,12 @@ impl Topic {
         if !self.config.cache.enabled {
             return Ok(());
         }
-        let path = self.config.get_system_path();
-
         // TODO: load data from database instead of calculating the size on disk
-        let total_size_on_disk_bytes = folder_size(&path).await?;
+        let mut total_size_on_disk_bytes = 0;
+        for path in self.config.get_data_paths() {
+            total_size_on_disk_bytes += folder_size(&path).await?.as_bytes_u64();
+        }
+        let total_size_on_disk_bytes = IggyByteSize::from(total_size_on_disk_bytes);
 
         for partition_lock in self.partitions.values_mut() {
             let mut partition = partition_lock.write().await;
@@ -293,9 +669,11 @@ impl Topic {
 mod tests {
     use super::*;
     use crate::configs::system::SystemConfig;
+    use crate::configs::system::TopicConfig;
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
     use crate::streaming::storage::tests::get_test_system_storage;
-    use bytes::Bytes;
-    use iggy::compression::compression_algorithm::CompressionAlgorithm;
+    use iggy::utils::cleanup_policy::CleanupPolicy;
     use iggy::utils::topic_size::MaxTopicSize;
     use std::sync::atomic::AtomicU32;
     use std::sync::atomic::AtomicU64;
@@ -316,7 +694,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning.clone(), messages)
+                .append_messages(batch_size, partitioning.clone(), messages, AckLevel::Leader)
                 .await
                 .unwrap();
         }
@@ -348,7 +726,7 @@ mod tests {
                 .map(|msg| msg.get_size_bytes())
                 .sum::<IggyByteSize>();
             topic
-                .append_messages(batch_size, partitioning, messages)
+                .append_messages(batch_size, partitioning, messages, AckLevel::Leader)
                 .await
                 .unwrap();
         }
@@ -404,13 +782,85 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn given_topic_compression_messages_should_be_recompressed_with_topic_algorithm() {
+        let config = SystemConfig {
+            topic: TopicConfig {
+                compression: TopicCompression::Topic,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut topic = init_topic_with_config(1, CompressionAlgorithm::Gzip, Arc::new(config));
+        let payload = b"iggy".repeat(100);
+        let mut messages = vec![
+            Message::new(None, Bytes::from(payload.clone()), None),
+            Message::new(None, Bytes::from(payload.clone()), None)
+                .with_compression(CompressionAlgorithm::Gzip)
+                .unwrap(),
+            Message::tombstone(1),
+        ];
+        let producer_compressed_payload = messages[1].payload.clone();
+
+        topic.recompress_messages(&mut messages).unwrap();
+        for message in &messages[..2] {
+            assert_eq!(
+                CompressionAlgorithm::from_headers(&message.headers).unwrap(),
+                Some(CompressionAlgorithm::Gzip)
+            );
+            assert_eq!(message.length, message.payload.len() as u32);
+            assert_eq!(
+                CompressionAlgorithm::Gzip
+                    .decompress(&message.payload)
+                    .unwrap(),
+                payload
+            );
+        }
+        assert_eq!(messages[1].payload, producer_compressed_payload);
+        assert!(messages[2].payload.is_empty());
+        assert!(messages[2].headers.is_none());
+
+        topic.compression_algorithm = CompressionAlgorithm::None;
+        topic.recompress_messages(&mut messages).unwrap();
+        for message in &messages[..2] {
+            assert!(message.headers.is_none());
+            assert_eq!(message.payload, payload);
+        }
+    }
+
+    #[tokio::test]
+    async fn given_producer_compression_messages_should_be_passed_through() {
+        let topic = init_topic_with_config(
+            1,
+            CompressionAlgorithm::Gzip,
+            Arc::new(SystemConfig::default()),
+        );
+        let mut messages = vec![Message::new(None, Bytes::from("iggy"), None)];
+
+        topic.recompress_messages(&mut messages).unwrap();
+        assert_eq!(messages[0].payload, Bytes::from("iggy"));
+        assert!(messages[0].headers.is_none());
+    }
+
     fn init_topic(partitions_count: u32) -> Topic {
+        init_topic_with_config(
+            partitions_count,
+            CompressionAlgorithm::None,
+            Arc::new(SystemConfig::default()),
+        )
+    }
+
+    fn init_topic_with_config(
+        partitions_count: u32,
+        compression_algorithm: CompressionAlgorithm,
+        config: Arc<SystemConfig>,
+    ) -> Topic {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let id = 2;
         let name = "test";
-        let compression_algorithm = CompressionAlgorithm::None;
-        let config = Arc::new(SystemConfig::default());
         let size_of_parent_stream = Arc::new(AtomicU64::new(0));
         let messages_count_of_parent_stream = Arc::new(AtomicU64::new(0));
         let segments_count_of_parent_stream = Arc::new(AtomicU32::new(0));
@@ -422,6 +872,8 @@ mod tests {
             partitions_count,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             size_of_parent_stream,
             messages_count_of_parent_stream,
             segments_count_of_parent_stream,
@@ -429,6 +881,8 @@ mod tests {
             compression_algorithm,
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap()
     }
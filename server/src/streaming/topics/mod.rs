@@ -1,9 +1,13 @@
 pub mod consumer_group;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod deletion;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
+pub mod producers;
+pub mod schema;
+pub mod schemas;
 pub mod segments;
 pub mod storage;
 pub mod topic;
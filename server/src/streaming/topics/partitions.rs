@@ -3,6 +3,12 @@ use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMut;
 use iggy::locking::IggySharedMutFn;
+use iggy::models::messages_histogram::MessagesHistogram;
+use iggy::models::partition::PartitionDetails;
+use iggy::models::partition_offsets_info::PartitionOffsetsInfo;
+use iggy::models::sealed_partition_info::SealedPartitionInfo;
+use iggy::models::timestamp_offset_info::TimestampOffsetInfo;
+use iggy::utils::duration::IggyDuration;
 use iggy::utils::timestamp::IggyTimestamp;
 
 const MAX_PARTITIONS_COUNT: u32 = 100_000;
@@ -16,6 +22,79 @@ impl Topic {
         self.partitions.len() as u32
     }
 
+    /// Returns the number of the partitions which failed to persist their messages and reject the appends.
+    pub async fn get_failed_partitions_count(&self) -> u32 {
+        let mut failed_partitions_count = 0;
+        for partition in self.partitions.values() {
+            if partition.read().await.is_failed() {
+                failed_partitions_count += 1;
+            }
+        }
+
+        failed_partitions_count
+    }
+
+    pub async fn get_partition_details(
+        &self,
+        partition_id: u32,
+    ) -> Result<PartitionDetails, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        Ok(partition.get_details().await)
+    }
+
+    pub async fn get_partition_offsets(
+        &self,
+        partition_id: Option<u32>,
+    ) -> Result<Vec<PartitionOffsetsInfo>, IggyError> {
+        if let Some(partition_id) = partition_id {
+            let partition = self.get_partition(partition_id)?;
+            let partition = partition.read().await;
+            return Ok(vec![partition.get_offsets_info()]);
+        }
+
+        let mut offsets = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            offsets.push(partition.get_offsets_info());
+        }
+        offsets.sort_by_key(|offsets| offsets.partition_id);
+        Ok(offsets)
+    }
+
+    pub async fn get_offset_by_timestamp(
+        &self,
+        partition_id: u32,
+        timestamp: IggyTimestamp,
+    ) -> Result<Option<TimestampOffsetInfo>, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition.get_timestamp_offset_info(timestamp).await
+    }
+
+    pub async fn get_messages_histogram(
+        &self,
+        partition_id: u32,
+        from: u64,
+        to: u64,
+        bucket_size: IggyDuration,
+    ) -> Result<MessagesHistogram, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let partition = partition.read().await;
+        partition
+            .get_messages_histogram(from, to, bucket_size)
+            .await
+    }
+
+    pub async fn seal_partition(
+        &self,
+        partition_id: u32,
+    ) -> Result<SealedPartitionInfo, IggyError> {
+        let partition = self.get_partition(partition_id)?;
+        let mut partition = partition.write().await;
+        partition.seal().await
+    }
+
     pub fn add_partitions(&mut self, count: u32) -> Result<Vec<u32>, IggyError> {
         if count == 0 {
             return Ok(vec![]);
@@ -26,6 +105,15 @@ impl Topic {
             return Err(IggyError::TooManyPartitions);
         }
 
+        let max_partitions = self.config.limits.max_partitions_per_topic;
+        if max_partitions > 0 && current_partitions_count + count > max_partitions {
+            return Err(IggyError::PartitionsLimitReached(
+                max_partitions,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
         let mut partition_ids = Vec::with_capacity(count as usize);
         for partition_id in current_partitions_count + 1..=current_partitions_count + count {
             let partition = Partition::create(
@@ -35,6 +123,8 @@ impl Topic {
                 true,
                 self.config.clone(),
                 self.storage.clone(),
+                self.appends_broadcaster.clone(),
+                self.events_bus.clone(),
                 self.message_expiry,
                 self.messages_count_of_parent_stream.clone(),
                 self.messages_count.clone(),
@@ -78,6 +168,7 @@ impl Topic {
         let mut messages_count = 0;
         for partition_id in current_partitions_count - count + 1..=current_partitions_count {
             let partition = self.partitions.remove(&partition_id).unwrap();
+            self.flush_workers.remove(&partition_id);
             let partition = partition.read().await;
             let partition_messages_count = partition.get_messages_count();
             segments_count += partition.get_segments_count();
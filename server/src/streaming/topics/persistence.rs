@@ -1,7 +1,13 @@
 use crate::state::system::TopicState;
+use crate::streaming::topics::deletion::get_deleted_topic_path;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::IggyError;
 use iggy::locking::IggySharedMutFn;
+use iggy::utils::timestamp::IggyTimestamp;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use tokio::fs;
+use tracing::error;
 
 impl Topic {
     pub async fn load(&mut self, state: TopicState) -> Result<(), IggyError> {
@@ -23,6 +29,63 @@ impl Topic {
         self.storage.topic.delete(self).await
     }
 
+    /// Releases the messages and segments of the topic from its parent stream and moves its directories aside
+    /// (on the system path and on any other data path with its partitions), so that the files can be removed
+    /// in the background. Returns the paths of the moved directories.
+    pub async fn tombstone(&self) -> Result<Vec<String>, IggyError> {
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            partitions.push(partition.write().await);
+        }
+
+        for segment in partitions
+            .iter()
+            .flat_map(|partition| partition.get_segments())
+        {
+            self.storage.segment.close_files(segment);
+        }
+
+        let timestamp = IggyTimestamp::now().as_micros();
+        let mut paths = vec![self.path.clone()];
+        for data_path in &self.config.placement.paths {
+            let path = self
+                .config
+                .get_data_topic_path(data_path, self.stream_id, self.topic_id);
+            if Path::new(&path).exists() {
+                paths.push(path);
+            }
+        }
+
+        let mut deleted_paths = Vec::with_capacity(paths.len());
+        for path in paths {
+            let deleted_path = get_deleted_topic_path(&path, timestamp);
+            if let Err(error) = fs::rename(&path, &deleted_path).await {
+                error!(
+                    "Cannot move directory of topic with ID: {} for stream with ID: {} to path: {deleted_path}. Error: {error}",
+                    self.topic_id, self.stream_id
+                );
+                return Err(IggyError::CannotDeleteTopicDirectory(
+                    self.topic_id,
+                    self.stream_id,
+                    path,
+                ));
+            }
+            deleted_paths.push(deleted_path);
+        }
+
+        let segments_count = partitions
+            .iter()
+            .map(|partition| partition.get_segments_count())
+            .sum::<u32>();
+        self.size_of_parent_stream
+            .fetch_sub(self.size_bytes.load(Ordering::SeqCst), Ordering::SeqCst);
+        self.messages_count_of_parent_stream
+            .fetch_sub(self.get_messages_count(), Ordering::SeqCst);
+        self.segments_count_of_parent_stream
+            .fetch_sub(segments_count, Ordering::SeqCst);
+        Ok(deleted_paths)
+    }
+
     pub async fn persist_messages(&self) -> Result<usize, IggyError> {
         let mut saved_messages_number = 0;
         for partition in self.get_partitions() {
@@ -0,0 +1,176 @@
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use std::sync::atomic::Ordering;
+use tracing::info;
+
+/// The client ID `0` is used by the stateless sessions (e.g. HTTP), so it means no exclusive producer.
+const NO_EXCLUSIVE_PRODUCER: u32 = 0;
+
+impl Topic {
+    /// Makes the client the exclusive producer of the topic, unless another client already is.
+    pub fn acquire_exclusive_producer(&self, client_id: u32) -> Result<(), IggyError> {
+        match self.exclusive_producer.compare_exchange(
+            NO_EXCLUSIVE_PRODUCER,
+            client_id,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(_) => {
+                info!(
+                    "Client with ID: {client_id} is the exclusive producer of topic with ID: {} for stream with ID: {}.",
+                    self.topic_id, self.stream_id
+                );
+                Ok(())
+            }
+            Err(producer_id) if producer_id == client_id => Ok(()),
+            Err(producer_id) => Err(IggyError::ProducerFenced(
+                self.topic_id,
+                self.stream_id,
+                producer_id,
+            )),
+        }
+    }
+
+    /// Makes the client the exclusive producer of the topic bound to the producer epoch, fencing the current one, if any.
+    /// The epoch `0` bumps the epoch for the new producer instance, otherwise the given epoch is resumed (e.g. after reconnecting),
+    /// unless the topic was acquired with a newer epoch in the meantime. Returns the current producer epoch.
+    pub fn init_producer_epoch(&mut self, client_id: u32, epoch: u32) -> Result<u32, IggyError> {
+        if epoch == 0 {
+            self.producer_epoch += 1;
+        } else if epoch < self.producer_epoch {
+            return Err(IggyError::StaleProducerEpoch(epoch));
+        } else if epoch > self.producer_epoch {
+            return Err(IggyError::InvalidProducerEpoch(epoch));
+        }
+
+        let previous_producer_id = self.exclusive_producer.swap(client_id, Ordering::SeqCst);
+        if previous_producer_id != NO_EXCLUSIVE_PRODUCER && previous_producer_id != client_id {
+            info!(
+                "Client with ID: {previous_producer_id} was fenced by the producer epoch: {} of topic with ID: {} for stream with ID: {}.",
+                self.producer_epoch, self.topic_id, self.stream_id
+            );
+        }
+        info!(
+            "Client with ID: {client_id} is the exclusive producer with epoch: {} of topic with ID: {} for stream with ID: {}.",
+            self.producer_epoch, self.topic_id, self.stream_id
+        );
+        Ok(self.producer_epoch)
+    }
+
+    /// Releases the topic if the client is its exclusive producer, e.g. once the client disconnects.
+    pub fn release_exclusive_producer(&self, client_id: u32) {
+        if self
+            .exclusive_producer
+            .compare_exchange(
+                client_id,
+                NO_EXCLUSIVE_PRODUCER,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_ok()
+        {
+            info!(
+                "Client with ID: {client_id} is no longer the exclusive producer of topic with ID: {} for stream with ID: {}.",
+                self.topic_id, self.stream_id
+            );
+        }
+    }
+
+    /// Returns the fencing error if another client is the exclusive producer of the topic.
+    pub fn ensure_producer(&self, client_id: u32) -> Result<(), IggyError> {
+        let producer_id = self.exclusive_producer.load(Ordering::SeqCst);
+        if producer_id == NO_EXCLUSIVE_PRODUCER || producer_id == client_id {
+            return Ok(());
+        }
+
+        Err(IggyError::ProducerFenced(
+            self.topic_id,
+            self.stream_id,
+            producer_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::system::SystemConfig;
+    use crate::streaming::appends::AppendsBroadcaster;
+    use crate::streaming::events::EventsBus;
+    use crate::streaming::storage::tests::get_test_system_storage;
+    use std::sync::atomic::{AtomicU32, AtomicU64};
+    use std::sync::Arc;
+
+    #[test]
+    fn exclusive_producer_should_fence_other_clients_until_released() {
+        let topic = create_topic();
+        assert!(topic.ensure_producer(1).is_ok());
+        assert!(topic.ensure_producer(2).is_ok());
+
+        assert!(topic.acquire_exclusive_producer(1).is_ok());
+        assert!(topic.acquire_exclusive_producer(1).is_ok());
+        assert!(topic.ensure_producer(1).is_ok());
+        assert!(matches!(
+            topic.acquire_exclusive_producer(2),
+            Err(IggyError::ProducerFenced(2, 1, 1))
+        ));
+        assert!(matches!(
+            topic.ensure_producer(2),
+            Err(IggyError::ProducerFenced(2, 1, 1))
+        ));
+
+        topic.release_exclusive_producer(2);
+        assert!(topic.ensure_producer(2).is_err());
+
+        topic.release_exclusive_producer(1);
+        assert!(topic.ensure_producer(2).is_ok());
+        assert!(topic.acquire_exclusive_producer(2).is_ok());
+        assert!(topic.ensure_producer(1).is_err());
+    }
+
+    #[test]
+    fn producer_epoch_should_fence_stale_producer_instances() {
+        let mut topic = create_topic();
+        assert_eq!(topic.init_producer_epoch(1, 0).unwrap(), 1);
+        assert!(topic.ensure_producer(1).is_ok());
+
+        // The new instance bumps the epoch and takes over the topic from the connected one
+        assert_eq!(topic.init_producer_epoch(2, 0).unwrap(), 2);
+        assert!(topic.ensure_producer(2).is_ok());
+        assert!(matches!(
+            topic.ensure_producer(1),
+            Err(IggyError::ProducerFenced(2, 1, 2))
+        ));
+
+        // The stale instance cannot resume its epoch after reconnecting, even if the topic was released
+        topic.release_exclusive_producer(2);
+        assert!(matches!(
+            topic.init_producer_epoch(3, 1),
+            Err(IggyError::StaleProducerEpoch(1))
+        ));
+        assert!(matches!(
+            topic.init_producer_epoch(3, 5),
+            Err(IggyError::InvalidProducerEpoch(5))
+        ));
+
+        // The current instance resumes its epoch after reconnecting
+        assert_eq!(topic.init_producer_epoch(4, 2).unwrap(), 2);
+        assert!(topic.ensure_producer(4).is_ok());
+        assert!(topic.ensure_producer(3).is_err());
+    }
+
+    fn create_topic() -> Topic {
+        Topic::empty(
+            1,
+            2,
+            "test",
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            Arc::new(SystemConfig::default()),
+            Arc::new(get_test_system_storage()),
+            Arc::new(AppendsBroadcaster::default()),
+            Arc::new(EventsBus::default()),
+        )
+    }
+}
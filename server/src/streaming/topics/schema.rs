@@ -0,0 +1,128 @@
+use crate::streaming::utils::json_schema;
+use iggy::error::IggyError;
+use iggy::utils::schema_type::SchemaType;
+use iggy::utils::timestamp::IggyTimestamp;
+use serde_json::Value;
+
+#[derive(Debug)]
+pub struct Schema {
+    pub version: u32,
+    pub schema_type: SchemaType,
+    pub validate: bool,
+    pub definition: String,
+    pub created_at: IggyTimestamp,
+    json_schema: Option<Value>,
+}
+
+impl Schema {
+    pub fn new(
+        version: u32,
+        schema_type: SchemaType,
+        validate: bool,
+        definition: &str,
+        created_at: IggyTimestamp,
+    ) -> Result<Self, IggyError> {
+        if validate && !schema_type.supports_validation() {
+            return Err(IggyError::SchemaValidationNotSupported(
+                schema_type.to_string(),
+            ));
+        }
+
+        // Protobuf definitions are stored as is, while both JSON Schema and Avro are JSON documents.
+        let json_schema = match schema_type {
+            SchemaType::Protobuf => None,
+            SchemaType::JsonSchema | SchemaType::Avro => {
+                let document = serde_json::from_str::<Value>(definition)
+                    .map_err(|error| IggyError::InvalidSchemaDefinition(error.to_string()))?;
+                if schema_type == SchemaType::JsonSchema {
+                    if !json_schema::is_schema_valid(&document) {
+                        return Err(IggyError::InvalidSchemaDefinition(
+                            "JSON Schema must be a valid object or a boolean".to_string(),
+                        ));
+                    }
+
+                    if validate {
+                        if let Some(keyword) = json_schema::find_unsupported_keyword(&document) {
+                            return Err(IggyError::SchemaValidationNotSupported(format!(
+                                "{schema_type} with keyword: {keyword}"
+                            )));
+                        }
+                    }
+                }
+                Some(document).filter(|_| validate)
+            }
+        };
+
+        Ok(Schema {
+            version,
+            schema_type,
+            validate,
+            definition: definition.to_string(),
+            created_at,
+            json_schema,
+        })
+    }
+
+    pub fn validate_payload(&self, payload: &[u8]) -> Result<(), IggyError> {
+        let Some(json_schema) = &self.json_schema else {
+            return Ok(());
+        };
+
+        let instance = serde_json::from_slice::<Value>(payload).map_err(|error| {
+            IggyError::SchemaViolation(self.version, format!("invalid JSON: {error}"))
+        })?;
+        json_schema::validate(json_schema, &instance)
+            .map_err(|reason| IggyError::SchemaViolation(self.version, reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_schema_with_unsupported_keyword_should_be_rejected_when_validation_is_enabled() {
+        let definition =
+            r#"{"type":"object","properties":{"email":{"type":"string","format":"email"}}}"#;
+        let result = Schema::new(
+            1,
+            SchemaType::JsonSchema,
+            true,
+            definition,
+            IggyTimestamp::now(),
+        );
+        assert!(matches!(
+            result,
+            Err(IggyError::SchemaValidationNotSupported(_))
+        ));
+
+        let schema = Schema::new(
+            1,
+            SchemaType::JsonSchema,
+            false,
+            definition,
+            IggyTimestamp::now(),
+        )
+        .unwrap();
+        assert!(schema.validate_payload(br#"{"email":1}"#).is_ok());
+    }
+
+    #[test]
+    fn json_schema_with_supported_keywords_should_validate_payloads() {
+        let definition =
+            r#"{"type":"object","required":["id"],"properties":{"id":{"type":"integer"}}}"#;
+        let schema = Schema::new(
+            1,
+            SchemaType::JsonSchema,
+            true,
+            definition,
+            IggyTimestamp::now(),
+        )
+        .unwrap();
+        assert!(schema.validate_payload(br#"{"id":1}"#).is_ok());
+        assert!(matches!(
+            schema.validate_payload(br#"{"id":"1"}"#),
+            Err(IggyError::SchemaViolation(1, _))
+        ));
+    }
+}
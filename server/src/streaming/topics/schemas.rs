@@ -0,0 +1,48 @@
+use crate::streaming::topics::schema::Schema;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::IggyError;
+use iggy::messages::send_messages::Message;
+use iggy::utils::schema_type::SchemaType;
+use iggy::utils::timestamp::IggyTimestamp;
+use tracing::info;
+
+impl Topic {
+    pub fn get_schemas(&self) -> &[Schema] {
+        &self.schemas
+    }
+
+    pub fn get_schema(&self, version: Option<u32>) -> Option<&Schema> {
+        match version {
+            Some(version) => self.schemas.iter().find(|schema| schema.version == version),
+            None => self.schemas.last(),
+        }
+    }
+
+    pub fn register_schema(
+        &mut self,
+        schema_type: SchemaType,
+        definition: &str,
+        validate: bool,
+        created_at: IggyTimestamp,
+    ) -> Result<&Schema, IggyError> {
+        let version = self.schemas.last().map_or(1, |schema| schema.version + 1);
+        let schema = Schema::new(version, schema_type, validate, definition, created_at)?;
+        self.schemas.push(schema);
+        info!(
+            "Registered schema with version: {version} and type: {schema_type} for topic with ID: {} and stream with ID: {}.",
+            self.topic_id, self.stream_id
+        );
+        Ok(self.schemas.last().unwrap())
+    }
+
+    pub fn validate_messages(&self, messages: &[Message]) -> Result<(), IggyError> {
+        let Some(schema) = self.schemas.last().filter(|schema| schema.validate) else {
+            return Ok(());
+        };
+
+        for message in messages.iter().filter(|message| !message.is_tombstone()) {
+            schema.validate_payload(&message.payload)?;
+        }
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::TopicStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::IggyError;
@@ -38,67 +38,102 @@ impl TopicStorage for FileTopicStorage {
             return Err(IggyError::TopicIdNotFound(topic.topic_id, topic.stream_id));
         }
 
+        topic.config = Topic::get_effective_config(&topic.config, &state.overrides)?;
+        topic.overrides = state.overrides;
         let message_expiry = Topic::get_message_expiry(state.message_expiry, &topic.config);
         let max_topic_size = Topic::get_max_topic_size(state.max_topic_size, &topic.config)?;
         topic.created_at = state.created_at;
         topic.message_expiry = message_expiry;
         topic.max_topic_size = max_topic_size;
+        topic.message_expiry_source = Topic::get_message_expiry_source(state.message_expiry);
+        topic.max_topic_size_source = Topic::get_max_topic_size_source(state.max_topic_size);
         topic.compression_algorithm = state.compression_algorithm;
         topic.replication_factor = state.replication_factor.unwrap_or(1);
+        topic.cleanup_policy = state.cleanup_policy;
+        topic.producer_epoch = state.producer_epoch;
+        topic.read_only = state.read_only;
 
-        let dir_entries = fs::read_dir(&topic.partitions_path).await
-            .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
-                                     topic.topic_id, topic.stream_id, topic.topic_id, &topic.partitions_path));
-        if let Err(err) = dir_entries {
-            return Err(IggyError::CannotReadPartitions(err));
+        let mut partitions_paths = vec![topic.partitions_path.clone()];
+        for data_path in &topic.config.placement.paths {
+            let partitions_path =
+                topic
+                    .config
+                    .get_data_partitions_path(data_path, topic.stream_id, topic.topic_id);
+            if Path::new(&partitions_path).exists() {
+                partitions_paths.push(partitions_path);
+            }
         }
 
         let mut unloaded_partitions = Vec::new();
-        let mut dir_entries = dir_entries.unwrap();
-        while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
-            let metadata = dir_entry.metadata().await;
-            if metadata.is_err() || metadata.unwrap().is_file() {
-                continue;
+        for partitions_path in partitions_paths {
+            let dir_entries = fs::read_dir(&partitions_path).await
+            .with_context(|| format!("Failed to read partition with ID: {} for stream with ID: {} for topic with ID: {} and path: {}",
+                                     topic.topic_id, topic.stream_id, topic.topic_id, &partitions_path));
+            if let Err(err) = dir_entries {
+                return Err(IggyError::CannotReadPartitions(err));
             }
 
-            let name = dir_entry.file_name().into_string().unwrap();
-            let partition_id = name.parse::<u32>();
-            if partition_id.is_err() {
-                error!("Invalid partition ID file with name: '{}'.", name);
-                continue;
-            }
+            let mut dir_entries = dir_entries.unwrap();
+            while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
+                let metadata = dir_entry.metadata().await;
+                if metadata.is_err() || metadata.unwrap().is_file() {
+                    continue;
+                }
 
-            let partition_id = partition_id.unwrap();
-            let partition_state = state.partitions.get(&partition_id);
-            if partition_state.is_none() {
-                let stream_id = topic.stream_id;
-                let topic_id = topic.topic_id;
-                error!("Partition with ID: '{partition_id}' for stream with ID: '{stream_id}' and topic with ID: '{topic_id}' was not found in state, but exists on disk and will be removed.");
-                if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
-                    error!("Cannot remove partition directory: {error}");
-                } else {
-                    warn!("Partition with ID: '{partition_id}' for stream with ID: '{stream_id}' and topic with ID: '{topic_id}' was removed.");
+                let name = dir_entry.file_name().into_string().unwrap();
+                let partition_id = name.parse::<u32>();
+                if partition_id.is_err() {
+                    error!("Invalid partition ID file with name: '{}'.", name);
+                    continue;
                 }
-                continue;
-            }
 
-            let partition_state = partition_state.unwrap();
-            let partition = Partition::create(
-                topic.stream_id,
-                topic.topic_id,
-                partition_id,
-                false,
-                topic.config.clone(),
-                topic.storage.clone(),
-                message_expiry,
-                topic.messages_count_of_parent_stream.clone(),
-                topic.messages_count.clone(),
-                topic.size_of_parent_stream.clone(),
-                topic.size_bytes.clone(),
-                topic.segments_count_of_parent_stream.clone(),
-                partition_state.created_at,
-            );
-            unloaded_partitions.push(partition);
+                let partition_id = partition_id.unwrap();
+                let partition_state = state.partitions.get(&partition_id);
+                if partition_state.is_none() {
+                    let stream_id = topic.stream_id;
+                    let topic_id = topic.topic_id;
+                    error!("Partition with ID: '{partition_id}' for stream with ID: '{stream_id}' and topic with ID: '{topic_id}' was not found in state, but exists on disk and will be removed.");
+                    if let Err(error) = fs::remove_dir_all(&dir_entry.path()).await {
+                        error!("Cannot remove partition directory: {error}");
+                    } else {
+                        warn!("Partition with ID: '{partition_id}' for stream with ID: '{stream_id}' and topic with ID: '{topic_id}' was removed.");
+                    }
+                    continue;
+                }
+
+                let partition_path =
+                    topic
+                        .config
+                        .get_partition_path(topic.stream_id, topic.topic_id, partition_id);
+                if dir_entry.path() != Path::new(&partition_path) {
+                    return Err(IggyError::CannotReadPartitions(anyhow!(
+                    "Partition with ID: {partition_id} for stream with ID: {} and topic with ID: {} was found in path: {}, but should be placed in path: {partition_path}, the placement config has probably changed.",
+                    topic.stream_id,
+                    topic.topic_id,
+                    dir_entry.path().display()
+                )));
+                }
+
+                let partition_state = partition_state.unwrap();
+                let partition = Partition::create(
+                    topic.stream_id,
+                    topic.topic_id,
+                    partition_id,
+                    false,
+                    topic.config.clone(),
+                    topic.storage.clone(),
+                    topic.appends_broadcaster.clone(),
+                    topic.events_bus.clone(),
+                    message_expiry,
+                    topic.messages_count_of_parent_stream.clone(),
+                    topic.messages_count.clone(),
+                    topic.size_of_parent_stream.clone(),
+                    topic.size_bytes.clone(),
+                    topic.segments_count_of_parent_stream.clone(),
+                    partition_state.created_at,
+                );
+                unloaded_partitions.push(partition);
+            }
         }
 
         let state_partition_ids = state.partitions.keys().copied().collect::<HashSet<u32>>();
@@ -143,6 +178,8 @@ impl TopicStorage for FileTopicStorage {
                     true,
                     topic.config.clone(),
                     topic.storage.clone(),
+                    topic.appends_broadcaster.clone(),
+                    topic.events_bus.clone(),
                     message_expiry,
                     topic.messages_count_of_parent_stream.clone(),
                     topic.messages_count.clone(),
@@ -196,6 +233,7 @@ impl TopicStorage for FileTopicStorage {
                 consumer_group.id,
                 &consumer_group.name,
                 topic.get_partitions_count(),
+                consumer_group.rebalance_strategy,
             );
             topic
                 .consumer_groups_ids
@@ -205,6 +243,15 @@ impl TopicStorage for FileTopicStorage {
                 .insert(consumer_group.group_id, RwLock::new(consumer_group));
         }
 
+        for schema in state.schemas {
+            topic.register_schema(
+                schema.schema_type,
+                &schema.definition,
+                schema.validate,
+                schema.created_at,
+            )?;
+        }
+
         topic.load_messages_from_disk_to_cache().await?;
         info!("Loaded topic {topic}");
 
@@ -253,6 +300,23 @@ impl TopicStorage for FileTopicStorage {
             ));
         }
 
+        for data_path in &topic.config.placement.paths {
+            let path = topic
+                .config
+                .get_data_topic_path(data_path, topic.stream_id, topic.topic_id);
+            match fs::remove_dir_all(&path).await {
+                Ok(()) => {}
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+                Err(_) => {
+                    return Err(IggyError::CannotDeleteTopicDirectory(
+                        topic.topic_id,
+                        topic.stream_id,
+                        path,
+                    ));
+                }
+            }
+        }
+
         info!(
             "Deleted topic with ID: {} for stream with ID: {}.",
             topic.topic_id, topic.stream_id
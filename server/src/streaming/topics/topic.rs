@@ -1,17 +1,27 @@
+use crate::configs::resource_quota::MemoryResourceQuota;
 use crate::configs::system::SystemConfig;
+use crate::streaming::appends::AppendsBroadcaster;
+use crate::streaming::events::EventsBus;
+use crate::streaming::partitions::flush_worker::PartitionFlushWorker;
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::segments::segment;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
+use crate::streaming::topics::schema::Schema;
 use core::fmt;
+use dashmap::DashMap;
 use iggy::compression::compression_algorithm::CompressionAlgorithm;
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::error::IggyError;
-use iggy::locking::IggySharedMut;
+use iggy::locking::{IggySharedMut, IggySharedMutFn};
+use iggy::models::topic::{TopicConfigEntry, TopicConfigSource};
 use iggy::utils::byte_size::IggyByteSize;
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
 use iggy::utils::sizeable::Sizeable;
 use iggy::utils::timestamp::IggyTimestamp;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
@@ -20,6 +30,7 @@ use tokio::sync::RwLock;
 use tracing::info;
 
 const ALMOST_FULL_THRESHOLD: f64 = 0.9;
+const SUPPORTED_REPLICATION_FACTOR: u8 = 1;
 
 #[derive(Debug)]
 pub struct Topic {
@@ -35,15 +46,28 @@ pub struct Topic {
     pub(crate) segments_count_of_parent_stream: Arc<AtomicU32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) partitions: HashMap<u32, IggySharedMut<Partition>>,
+    pub(crate) flush_workers: DashMap<u32, PartitionFlushWorker>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) appends_broadcaster: Arc<AppendsBroadcaster>,
+    pub(crate) events_bus: Arc<EventsBus>,
     pub(crate) consumer_groups: HashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) consumer_groups_ids: HashMap<String, u32>,
     pub(crate) current_consumer_group_id: AtomicU32,
     pub(crate) current_partition_id: AtomicU32,
+    pub(crate) exclusive_producer: AtomicU32,
+    pub(crate) producer_epoch: u32,
+    pub(crate) schemas: Vec<Schema>,
     pub message_expiry: IggyExpiry,
     pub compression_algorithm: CompressionAlgorithm,
     pub max_topic_size: MaxTopicSize,
     pub replication_factor: u8,
+    pub cleanup_policy: CleanupPolicy,
+    /// Whether the messages sent to the topic are rejected, while they can still be polled.
+    pub read_only: bool,
+    /// The server configuration values overridden for the topic, already applied to its `config`.
+    pub overrides: TopicOverrides,
+    pub(crate) message_expiry_source: TopicConfigSource,
+    pub(crate) max_topic_size_source: TopicConfigSource,
     pub created_at: IggyTimestamp,
 }
 
@@ -58,6 +82,8 @@ impl Topic {
         segments_count_of_parent_stream: Arc<AtomicU32>,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        appends_broadcaster: Arc<AppendsBroadcaster>,
+        events_bus: Arc<EventsBus>,
     ) -> Topic {
         Topic::create(
             stream_id,
@@ -66,6 +92,8 @@ impl Topic {
             0,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             size_of_parent_stream,
             messages_count_of_parent_stream,
             segments_count_of_parent_stream,
@@ -73,6 +101,8 @@ impl Topic {
             Default::default(),
             MaxTopicSize::ServerDefault,
             1,
+            CleanupPolicy::Delete,
+            TopicOverrides::default(),
         )
         .unwrap()
     }
@@ -85,6 +115,8 @@ impl Topic {
         partitions_count: u32,
         config: Arc<SystemConfig>,
         storage: Arc<SystemStorage>,
+        appends_broadcaster: Arc<AppendsBroadcaster>,
+        events_bus: Arc<EventsBus>,
         size_of_parent_stream: Arc<AtomicU64>,
         messages_count_of_parent_stream: Arc<AtomicU64>,
         segments_count_of_parent_stream: Arc<AtomicU32>,
@@ -92,7 +124,10 @@ impl Topic {
         compression_algorithm: CompressionAlgorithm,
         max_topic_size: MaxTopicSize,
         replication_factor: u8,
+        cleanup_policy: CleanupPolicy,
+        overrides: TopicOverrides,
     ) -> Result<Topic, IggyError> {
+        let config = Topic::get_effective_config(&config, &overrides)?;
         let path = config.get_topic_path(stream_id, topic_id);
         let partitions_path = config.get_partitions_path(stream_id, topic_id);
         let mut topic = Topic {
@@ -100,9 +135,12 @@ impl Topic {
             topic_id,
             name: name.to_string(),
             partitions: HashMap::new(),
+            flush_workers: DashMap::new(),
             path,
             partitions_path,
             storage,
+            appends_broadcaster,
+            events_bus,
             size_bytes: Arc::new(AtomicU64::new(0)),
             size_of_parent_stream,
             messages_count_of_parent_stream,
@@ -112,10 +150,18 @@ impl Topic {
             consumer_groups_ids: HashMap::new(),
             current_consumer_group_id: AtomicU32::new(1),
             current_partition_id: AtomicU32::new(1),
+            exclusive_producer: AtomicU32::new(0),
+            producer_epoch: 0,
+            schemas: Vec::new(),
             message_expiry: Topic::get_message_expiry(message_expiry, &config),
             max_topic_size: Topic::get_max_topic_size(max_topic_size, &config)?,
             compression_algorithm,
             replication_factor,
+            cleanup_policy,
+            read_only: false,
+            overrides,
+            message_expiry_source: Topic::get_message_expiry_source(message_expiry),
+            max_topic_size_source: Topic::get_max_topic_size_source(max_topic_size),
             config,
             created_at: IggyTimestamp::now(),
         };
@@ -225,12 +271,118 @@ impl Topic {
         }
     }
 
+    /// The replication factor is a part of the topic metadata already, but until the replication is available,
+    /// the only supported value is 1, which is also used when the replication factor is not specified.
+    pub fn get_replication_factor(replication_factor: Option<u8>) -> Result<u8, IggyError> {
+        match replication_factor {
+            None | Some(SUPPORTED_REPLICATION_FACTOR) => Ok(SUPPORTED_REPLICATION_FACTOR),
+            Some(0) => Err(IggyError::InvalidReplicationFactor),
+            Some(replication_factor) => {
+                Err(IggyError::ReplicationFactorNotSupported(replication_factor))
+            }
+        }
+    }
+
     pub fn get_message_expiry(message_expiry: IggyExpiry, config: &SystemConfig) -> IggyExpiry {
         match message_expiry {
             IggyExpiry::ServerDefault => config.segment.message_expiry,
             _ => message_expiry,
         }
     }
+
+    pub fn get_message_expiry_source(message_expiry: IggyExpiry) -> TopicConfigSource {
+        match message_expiry {
+            IggyExpiry::ServerDefault => TopicConfigSource::ServerDefault,
+            _ => TopicConfigSource::Topic,
+        }
+    }
+
+    pub fn get_max_topic_size_source(max_topic_size: MaxTopicSize) -> TopicConfigSource {
+        match max_topic_size {
+            MaxTopicSize::ServerDefault => TopicConfigSource::ServerDefault,
+            _ => TopicConfigSource::Topic,
+        }
+    }
+
+    /// Returns the server config with the values overridden for the topic, which is then shared with its partitions and segments.
+    pub fn get_effective_config(
+        config: &Arc<SystemConfig>,
+        overrides: &TopicOverrides,
+    ) -> Result<Arc<SystemConfig>, IggyError> {
+        if overrides.is_empty() {
+            return Ok(config.clone());
+        }
+
+        let mut effective_config = config.as_ref().clone();
+        if let Some(segment_size) = overrides.segment_size {
+            if segment_size > segment::MAX_SIZE_BYTES
+                || segment_size < config.segment.index_interval
+            {
+                return Err(IggyError::InvalidSegmentSize(segment_size.as_bytes_u64()));
+            }
+            effective_config.segment.size = segment_size;
+        }
+        if let Some(messages_required_to_save) = overrides.messages_required_to_save {
+            effective_config.partition.messages_required_to_save = messages_required_to_save;
+        }
+        if let Some(cache_size) = overrides.cache_size {
+            effective_config.cache.size = MemoryResourceQuota::Bytes(cache_size);
+        }
+        if let Some(message_timestamp_type) = overrides.message_timestamp_type {
+            effective_config.partition.message_timestamp_type = message_timestamp_type;
+        }
+        Ok(Arc::new(effective_config))
+    }
+
+    /// Replaces the effective config of the topic and all its partitions and segments,
+    /// the already closed segments keep their size, while the open ones use the new segment size limit.
+    pub async fn set_config(&mut self, config: Arc<SystemConfig>, overrides: TopicOverrides) {
+        self.config = config;
+        self.overrides = overrides;
+        for partition in self.partitions.values() {
+            partition.write().await.set_config(self.config.clone());
+        }
+    }
+
+    /// Returns the effective values of the topic settings, which can be overridden, along with their sources.
+    pub fn get_config_entries(&self) -> Vec<TopicConfigEntry> {
+        let source = |is_overridden: bool| match is_overridden {
+            true => TopicConfigSource::Topic,
+            false => TopicConfigSource::ServerDefault,
+        };
+        vec![
+            TopicConfigEntry {
+                name: "message_expiry".to_string(),
+                value: self.message_expiry.to_string(),
+                source: self.message_expiry_source,
+            },
+            TopicConfigEntry {
+                name: "max_topic_size".to_string(),
+                value: self.max_topic_size.to_string(),
+                source: self.max_topic_size_source,
+            },
+            TopicConfigEntry {
+                name: "segment_size".to_string(),
+                value: self.config.segment.size.as_human_string(),
+                source: source(self.overrides.segment_size.is_some()),
+            },
+            TopicConfigEntry {
+                name: "messages_required_to_save".to_string(),
+                value: self.config.partition.messages_required_to_save.to_string(),
+                source: source(self.overrides.messages_required_to_save.is_some()),
+            },
+            TopicConfigEntry {
+                name: "cache_size".to_string(),
+                value: self.config.cache.size.to_string(),
+                source: source(self.overrides.cache_size.is_some()),
+            },
+            TopicConfigEntry {
+                name: "message_timestamp_type".to_string(),
+                value: self.config.partition.message_timestamp_type.to_string(),
+                source: source(self.overrides.message_timestamp_type.is_some()),
+            },
+        ]
+    }
 }
 
 impl Sizeable for Topic {
@@ -248,13 +400,16 @@ impl fmt::Display for Topic {
         write!(f, "partitions count: {}, ", self.partitions.len())?;
         write!(f, "message expiry: {}, ", self.message_expiry)?;
         write!(f, "max topic size: {}, ", self.max_topic_size)?;
-        write!(f, "replication factor: {}, ", self.replication_factor)
+        write!(f, "replication factor: {}, ", self.replication_factor)?;
+        write!(f, "cleanup policy: {}, ", self.cleanup_policy)?;
+        write!(f, "read-only: {}", self.read_only)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use iggy::locking::IggySharedMutFn;
+    use iggy::utils::timestamp_type::TimestampType;
     use std::str::FromStr;
 
     use super::*;
@@ -263,6 +418,8 @@ mod tests {
     #[tokio::test]
     async fn should_be_created_given_valid_parameters() {
         let storage = Arc::new(get_test_system_storage());
+        let appends_broadcaster = Arc::new(AppendsBroadcaster::default());
+        let events_bus = Arc::new(EventsBus::default());
         let stream_id = 1;
         let topic_id = 2;
         let name = "test";
@@ -284,6 +441,8 @@ mod tests {
             partitions_count,
             config,
             storage,
+            appends_broadcaster,
+            events_bus,
             messages_count_of_parent_stream,
             size_of_parent_stream,
             segments_count_of_parent_stream,
@@ -291,6 +450,8 @@ mod tests {
             compression_algorithm,
             max_topic_size,
             replication_factor,
+            CleanupPolicy::Delete,
+            Default::default(),
         )
         .unwrap();
 
@@ -309,4 +470,83 @@ mod tests {
             assert_eq!(partition.segments.len(), 1);
         }
     }
+
+    #[test]
+    fn should_override_server_defaults_and_report_their_sources() {
+        let config = Arc::new(SystemConfig::default());
+        let overrides = TopicOverrides {
+            segment_size: Some(IggyByteSize::from_str("10 MB").unwrap()),
+            messages_required_to_save: Some(10),
+            cache_size: None,
+            message_timestamp_type: Some(TimestampType::CreateTime),
+        };
+
+        let topic = Topic::create(
+            1,
+            2,
+            "test",
+            1,
+            config.clone(),
+            Arc::new(get_test_system_storage()),
+            Arc::new(AppendsBroadcaster::default()),
+            Arc::new(EventsBus::default()),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(0)),
+            IggyExpiry::ServerDefault,
+            CompressionAlgorithm::None,
+            MaxTopicSize::ServerDefault,
+            1,
+            CleanupPolicy::Delete,
+            overrides,
+        )
+        .unwrap();
+
+        assert_eq!(topic.config.segment.size, overrides.segment_size.unwrap());
+        assert_eq!(topic.config.partition.messages_required_to_save, 10);
+        assert_eq!(topic.config.cache.size, config.cache.size);
+        assert_eq!(
+            topic.config.partition.message_timestamp_type,
+            TimestampType::CreateTime
+        );
+
+        let sources = topic
+            .get_config_entries()
+            .into_iter()
+            .map(|entry| (entry.name, entry.source))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(sources["segment_size"], TopicConfigSource::Topic);
+        assert_eq!(
+            sources["messages_required_to_save"],
+            TopicConfigSource::Topic
+        );
+        assert_eq!(sources["cache_size"], TopicConfigSource::ServerDefault);
+        assert_eq!(sources["message_timestamp_type"], TopicConfigSource::Topic);
+        assert_eq!(sources["message_expiry"], TopicConfigSource::ServerDefault);
+    }
+
+    #[test]
+    fn should_not_allow_overriding_segment_size_over_the_limit() {
+        let config = Arc::new(SystemConfig::default());
+        let overrides = TopicOverrides {
+            segment_size: Some(IggyByteSize::from(segment::MAX_SIZE_BYTES + 1)),
+            ..Default::default()
+        };
+
+        assert!(Topic::get_effective_config(&config, &overrides).is_err());
+    }
+
+    #[test]
+    fn should_allow_only_replication_factor_of_one() {
+        assert_eq!(Topic::get_replication_factor(None).unwrap(), 1);
+        assert_eq!(Topic::get_replication_factor(Some(1)).unwrap(), 1);
+        assert!(matches!(
+            Topic::get_replication_factor(Some(0)),
+            Err(IggyError::InvalidReplicationFactor)
+        ));
+        assert!(matches!(
+            Topic::get_replication_factor(Some(3)),
+            Err(IggyError::ReplicationFactorNotSupported(3))
+        ));
+    }
 }
@@ -14,6 +14,52 @@ impl Permissioner {
         self.get_server_info(user_id)
     }
 
+    pub fn get_sessions(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn kill_session(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn rotate_encryption_key(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn set_log_level(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn set_background_io_rate(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn get_node_status(&self, user_id: u32) -> Result<(), IggyError> {
+        self.get_server_info(user_id)
+    }
+
+    pub fn drain_node(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn decommission_node(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    pub fn fetch_replica_messages(&self, user_id: u32) -> Result<(), IggyError> {
+        self.manage_server(user_id)
+    }
+
+    fn manage_server(&self, user_id: u32) -> Result<(), IggyError> {
+        if let Some(global_permissions) = self.users_permissions.get(&user_id) {
+            if global_permissions.manage_servers {
+                return Ok(());
+            }
+        }
+
+        Err(IggyError::Unauthorized)
+    }
+
     fn get_server_info(&self, user_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_servers || global_permissions.read_servers {
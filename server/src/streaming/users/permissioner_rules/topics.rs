@@ -19,8 +19,10 @@ impl Permissioner {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&topic_id))
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -48,8 +50,10 @@ impl Permissioner {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&stream_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&stream_id))
             {
                 if topic_permissions.manage_topic || topic_permissions.read_topic {
                     return Ok(());
@@ -104,6 +108,24 @@ impl Permissioner {
         self.manage_topic(user_id, stream_id, topic_id)
     }
 
+    pub fn get_schemas(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.get_topic(user_id, stream_id, topic_id)
+    }
+
+    pub fn register_schema(
+        &self,
+        user_id: u32,
+        stream_id: u32,
+        topic_id: u32,
+    ) -> Result<(), IggyError> {
+        self.manage_topic(user_id, stream_id, topic_id)
+    }
+
     fn manage_topic(&self, user_id: u32, stream_id: u32, topic_id: u32) -> Result<(), IggyError> {
         if let Some(global_permissions) = self.users_permissions.get(&user_id) {
             if global_permissions.manage_streams || global_permissions.manage_topics {
@@ -117,8 +139,10 @@ impl Permissioner {
                 return Ok(());
             }
 
-            if let Some(topic_permissions) =
-                stream_permissions.topics.as_ref().unwrap().get(&topic_id)
+            if let Some(topic_permissions) = stream_permissions
+                .topics
+                .as_ref()
+                .and_then(|topics| topics.get(&topic_id))
             {
                 if topic_permissions.manage_topic {
                     return Ok(());
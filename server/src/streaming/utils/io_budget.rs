@@ -0,0 +1,185 @@
+use crate::configs::system::BackgroundIoConfig;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const TASKS_COUNT: usize = 4;
+/// The maximum time of a single sleep, after which the rate is read again, so that its change is applied to the throttled tasks.
+const MAX_DELAY: Duration = Duration::from_secs(1);
+
+/// The background task reading and writing the segments, sharing the IO budget with the other ones.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BackgroundTask {
+    Retention,
+    Compaction,
+    Archiving,
+    Recovery,
+}
+
+impl BackgroundTask {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Display for BackgroundTask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackgroundTask::Retention => write!(f, "retention"),
+            BackgroundTask::Compaction => write!(f, "compaction"),
+            BackgroundTask::Archiving => write!(f, "archiving"),
+            BackgroundTask::Recovery => write!(f, "recovery"),
+        }
+    }
+}
+
+/// `IoBudget` limits the total number of bytes read and written per second by all the background tasks,
+/// so that they don't compete with the foreground appends and polls for the disk bandwidth.
+/// The rate is split between the tasks running at the same time proportionally to their weights,
+/// and it can be changed at runtime, where the rate of 0 means no limit.
+#[derive(Debug)]
+pub struct IoBudget {
+    bytes_per_second: AtomicU64,
+    weights: [u64; TASKS_COUNT],
+    active_tasks: [AtomicU32; TASKS_COUNT],
+}
+
+impl IoBudget {
+    pub fn new(config: &BackgroundIoConfig) -> Self {
+        Self {
+            bytes_per_second: AtomicU64::new(config.max_rate.as_bytes_u64()),
+            weights: [
+                config.retention_weight as u64,
+                config.compaction_weight as u64,
+                config.archiving_weight as u64,
+                config.recovery_weight as u64,
+            ],
+            active_tasks: Default::default(),
+        }
+    }
+
+    pub fn get_rate(&self) -> u64 {
+        self.bytes_per_second.load(Ordering::SeqCst)
+    }
+
+    pub fn set_rate(&self, bytes_per_second: u64) {
+        self.bytes_per_second
+            .store(bytes_per_second, Ordering::SeqCst);
+    }
+
+    /// Returns the throttler of the task, which counts as running until the throttler is dropped.
+    pub fn throttler(self: &Arc<Self>, task: BackgroundTask) -> IoThrottler {
+        self.active_tasks[task.index()].fetch_add(1, Ordering::SeqCst);
+        IoThrottler {
+            budget: self.clone(),
+            task,
+        }
+    }
+
+    /// Returns the share of the rate available to the task, or `None` if there's no limit.
+    fn get_task_rate(&self, task: BackgroundTask) -> Option<u64> {
+        let bytes_per_second = self.get_rate();
+        if bytes_per_second == 0 {
+            return None;
+        }
+
+        let weight = self.weights[task.index()];
+        let active_weights = self
+            .active_tasks
+            .iter()
+            .zip(self.weights)
+            .filter(|(active_tasks, _)| active_tasks.load(Ordering::SeqCst) > 0)
+            .map(|(_, weight)| weight)
+            .sum::<u64>()
+            .max(weight);
+        Some((bytes_per_second as u128 * weight as u128 / active_weights as u128).max(1) as u64)
+    }
+}
+
+/// Delays the background task, so that the bytes it reads and writes don't exceed its share of the IO budget.
+#[derive(Debug)]
+pub struct IoThrottler {
+    budget: Arc<IoBudget>,
+    task: BackgroundTask,
+}
+
+impl IoThrottler {
+    /// Waits for as long as it takes to process the bytes at the current share of the rate (if any).
+    pub async fn throttle(&mut self, bytes: u64) {
+        let mut pending_bytes = bytes as f64;
+        while pending_bytes >= 1.0 {
+            let Some(bytes_per_second) = self.budget.get_task_rate(self.task) else {
+                return;
+            };
+
+            let delay =
+                Duration::from_secs_f64(pending_bytes / bytes_per_second as f64).min(MAX_DELAY);
+            sleep(delay).await;
+            pending_bytes -= delay.as_secs_f64() * bytes_per_second as f64;
+        }
+    }
+}
+
+impl Drop for IoThrottler {
+    fn drop(&mut self) {
+        self.budget.active_tasks[self.task.index()].fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn get_budget(max_rate: u64) -> Arc<IoBudget> {
+        Arc::new(IoBudget::new(&BackgroundIoConfig {
+            max_rate: max_rate.into(),
+            retention_weight: 1,
+            compaction_weight: 2,
+            archiving_weight: 1,
+            recovery_weight: 4,
+        }))
+    }
+
+    #[test]
+    fn rate_should_be_split_between_running_tasks_by_weights() {
+        let budget = get_budget(3000);
+        let compaction = budget.throttler(BackgroundTask::Compaction);
+        assert_eq!(budget.get_task_rate(BackgroundTask::Compaction), Some(3000));
+
+        let archiving = budget.throttler(BackgroundTask::Archiving);
+        assert_eq!(budget.get_task_rate(BackgroundTask::Compaction), Some(2000));
+        assert_eq!(budget.get_task_rate(BackgroundTask::Archiving), Some(1000));
+
+        drop(compaction);
+        assert_eq!(budget.get_task_rate(BackgroundTask::Archiving), Some(3000));
+        drop(archiving);
+        assert!(budget
+            .active_tasks
+            .iter()
+            .all(|active_tasks| active_tasks.load(Ordering::SeqCst) == 0));
+    }
+
+    #[tokio::test]
+    async fn bytes_over_the_rate_should_be_delayed() {
+        let budget = get_budget(10_000);
+        let mut throttler = budget.throttler(BackgroundTask::Retention);
+        let start = Instant::now();
+        throttler.throttle(1000).await;
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn bytes_should_not_be_delayed_without_rate() {
+        let budget = get_budget(0);
+        let mut throttler = budget.throttler(BackgroundTask::Recovery);
+        let start = Instant::now();
+        throttler.throttle(u64::MAX / 2).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        budget.set_rate(1000);
+        assert_eq!(budget.get_task_rate(BackgroundTask::Recovery), Some(1000));
+    }
+}
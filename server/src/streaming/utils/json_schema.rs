@@ -0,0 +1,342 @@
+use serde_json::{Map, Value};
+
+// A lightweight validator supporting the most commonly used subset of the JSON Schema keywords:
+// `type`, `enum`, `const`, `properties`, `required`, `additionalProperties`, `items`,
+// `minItems`, `maxItems`, `minLength`, `maxLength`, `minimum` and `maximum`.
+// The schemas using any other keyword must be rejected with `find_unsupported_keyword`
+// before validating the payloads, otherwise such keywords would be silently ignored.
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "type",
+    "enum",
+    "const",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+    "minItems",
+    "maxItems",
+    "minLength",
+    "maxLength",
+    "minimum",
+    "maximum",
+];
+
+// Annotations have no effect on the validation, so they can be safely skipped.
+const ANNOTATION_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "default",
+    "examples",
+];
+
+const TYPES: &[&str] = &[
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+pub fn validate(schema: &Value, instance: &Value) -> Result<(), String> {
+    validate_at(schema, instance, "$")
+}
+
+pub fn is_schema_valid(schema: &Value) -> bool {
+    let schema = match schema {
+        Value::Bool(_) => return true,
+        Value::Object(schema) => schema,
+        _ => return false,
+    };
+
+    schema
+        .iter()
+        .all(|(keyword, value)| match keyword.as_str() {
+            "type" => match value {
+                Value::String(name) => TYPES.contains(&name.as_str()),
+                Value::Array(names) => names
+                    .iter()
+                    .all(|name| name.as_str().is_some_and(|name| TYPES.contains(&name))),
+                _ => false,
+            },
+            "enum" => value.is_array(),
+            "required" => value
+                .as_array()
+                .is_some_and(|required| required.iter().all(Value::is_string)),
+            "properties" => value
+                .as_object()
+                .is_some_and(|properties| properties.values().all(is_schema_valid)),
+            "additionalProperties" | "items" => is_schema_valid(value),
+            "minItems" | "maxItems" | "minLength" | "maxLength" => value.is_u64(),
+            "minimum" | "maximum" => value.is_number(),
+            _ => true,
+        })
+}
+
+pub fn find_unsupported_keyword(schema: &Value) -> Option<&str> {
+    let Value::Object(schema) = schema else {
+        return None;
+    };
+
+    for (keyword, value) in schema {
+        if ANNOTATION_KEYWORDS.contains(&keyword.as_str()) {
+            continue;
+        }
+
+        if !SUPPORTED_KEYWORDS.contains(&keyword.as_str()) {
+            return Some(keyword);
+        }
+
+        let unsupported_keyword = match keyword.as_str() {
+            "properties" => value
+                .as_object()
+                .and_then(|properties| properties.values().find_map(find_unsupported_keyword)),
+            "additionalProperties" | "items" => find_unsupported_keyword(value),
+            _ => None,
+        };
+        if unsupported_keyword.is_some() {
+            return unsupported_keyword;
+        }
+    }
+
+    None
+}
+
+fn validate_at(schema: &Value, instance: &Value, path: &str) -> Result<(), String> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok(()),
+        Value::Bool(false) => return Err(format!("{path}: no value is allowed")),
+        Value::Object(schema) => schema,
+        _ => return Ok(()),
+    };
+
+    if let Some(expected_type) = schema.get("type") {
+        validate_type(expected_type, instance, path)?;
+    }
+
+    if let Some(Value::Array(allowed_values)) = schema.get("enum") {
+        if !allowed_values.contains(instance) {
+            return Err(format!("{path}: value is not one of the allowed values"));
+        }
+    }
+
+    if let Some(expected_value) = schema.get("const") {
+        if expected_value != instance {
+            return Err(format!("{path}: value is not equal to the constant"));
+        }
+    }
+
+    match instance {
+        Value::Object(object) => validate_object(schema, object, path),
+        Value::Array(items) => validate_array(schema, items, path),
+        Value::String(value) => validate_string(schema, value, path),
+        Value::Number(value) => validate_number(schema, value.as_f64().unwrap_or_default(), path),
+        _ => Ok(()),
+    }
+}
+
+fn validate_type(expected_type: &Value, instance: &Value, path: &str) -> Result<(), String> {
+    let matches_type = match expected_type {
+        Value::String(expected_type) => is_of_type(expected_type, instance),
+        Value::Array(expected_types) => expected_types
+            .iter()
+            .filter_map(|expected_type| expected_type.as_str())
+            .any(|expected_type| is_of_type(expected_type, instance)),
+        _ => true,
+    };
+    if !matches_type {
+        return Err(format!("{path}: expected type: {expected_type}"));
+    }
+
+    Ok(())
+}
+
+fn is_of_type(expected_type: &str, instance: &Value) -> bool {
+    match expected_type {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "number" => instance.is_number(),
+        "integer" => {
+            instance.is_i64()
+                || instance.is_u64()
+                || instance.as_f64().is_some_and(|value| value.fract() == 0.0)
+        }
+        "string" => instance.is_string(),
+        _ => true,
+    }
+}
+
+fn validate_object(
+    schema: &Map<String, Value>,
+    object: &Map<String, Value>,
+    path: &str,
+) -> Result<(), String> {
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for property in required.iter().filter_map(|property| property.as_str()) {
+            if !object.contains_key(property) {
+                return Err(format!("{path}: missing required property: {property}"));
+            }
+        }
+    }
+
+    let properties = schema.get("properties").and_then(|value| value.as_object());
+    let additional_properties = schema.get("additionalProperties");
+    for (name, value) in object {
+        let property_path = format!("{path}.{name}");
+        if let Some(property_schema) = properties.and_then(|properties| properties.get(name)) {
+            validate_at(property_schema, value, &property_path)?;
+            continue;
+        }
+
+        if let Some(additional_properties) = additional_properties {
+            validate_at(additional_properties, value, &property_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_array(schema: &Map<String, Value>, items: &[Value], path: &str) -> Result<(), String> {
+    if let Some(min_items) = schema.get("minItems").and_then(|value| value.as_u64()) {
+        if (items.len() as u64) < min_items {
+            return Err(format!("{path}: expected at least {min_items} items"));
+        }
+    }
+
+    if let Some(max_items) = schema.get("maxItems").and_then(|value| value.as_u64()) {
+        if items.len() as u64 > max_items {
+            return Err(format!("{path}: expected at most {max_items} items"));
+        }
+    }
+
+    if let Some(items_schema) = schema.get("items") {
+        for (index, item) in items.iter().enumerate() {
+            validate_at(items_schema, item, &format!("{path}[{index}]"))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_string(schema: &Map<String, Value>, value: &str, path: &str) -> Result<(), String> {
+    let length = value.chars().count() as u64;
+    if let Some(min_length) = schema.get("minLength").and_then(|value| value.as_u64()) {
+        if length < min_length {
+            return Err(format!("{path}: expected at least {min_length} characters"));
+        }
+    }
+
+    if let Some(max_length) = schema.get("maxLength").and_then(|value| value.as_u64()) {
+        if length > max_length {
+            return Err(format!("{path}: expected at most {max_length} characters"));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_number(schema: &Map<String, Value>, value: f64, path: &str) -> Result<(), String> {
+    if let Some(minimum) = schema.get("minimum").and_then(|value| value.as_f64()) {
+        if value < minimum {
+            return Err(format!(
+                "{path}: expected value greater or equal to {minimum}"
+            ));
+        }
+    }
+
+    if let Some(maximum) = schema.get("maximum").and_then(|value| value.as_f64()) {
+        if value > maximum {
+            return Err(format!("{path}: expected value less or equal to {maximum}"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn get_schema() -> Value {
+        json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "name": { "type": "string", "minLength": 1, "maxLength": 10 },
+                "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 2 },
+                "kind": { "enum": ["a", "b"] }
+            },
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn valid_instance_should_pass_validation() {
+        let instance = json!({ "id": 1, "name": "test", "tags": ["x"], "kind": "a" });
+        assert!(validate(&get_schema(), &instance).is_ok());
+    }
+
+    #[test]
+    fn invalid_instances_should_fail_validation() {
+        let schema = get_schema();
+        for instance in [
+            json!([]),
+            json!({ "id": 1 }),
+            json!({ "id": 0, "name": "test" }),
+            json!({ "id": 1.5, "name": "test" }),
+            json!({ "id": 1, "name": "" }),
+            json!({ "id": 1, "name": "test", "tags": [1] }),
+            json!({ "id": 1, "name": "test", "tags": ["x", "y", "z"] }),
+            json!({ "id": 1, "name": "test", "kind": "c" }),
+            json!({ "id": 1, "name": "test", "other": true }),
+        ] {
+            assert!(validate(&schema, &instance).is_err(), "{instance}");
+        }
+    }
+
+    #[test]
+    fn supported_schema_should_not_contain_unsupported_keywords() {
+        let schema = get_schema();
+        assert!(is_schema_valid(&schema));
+        assert!(find_unsupported_keyword(&schema).is_none());
+    }
+
+    #[test]
+    fn unsupported_keywords_should_be_found_in_nested_schemas() {
+        for (schema, keyword) in [
+            (json!({ "$ref": "#/definitions/user" }), "$ref"),
+            (json!({ "anyOf": [{ "type": "string" }] }), "anyOf"),
+            (
+                json!({ "properties": { "email": { "type": "string", "format": "email" } } }),
+                "format",
+            ),
+            (
+                json!({ "items": { "type": "string", "pattern": "^[a-z]+$" } }),
+                "pattern",
+            ),
+            (
+                json!({ "additionalProperties": { "exclusiveMinimum": 0 } }),
+                "exclusiveMinimum",
+            ),
+        ] {
+            assert_eq!(find_unsupported_keyword(&schema), Some(keyword), "{schema}");
+        }
+    }
+
+    #[test]
+    fn malformed_schemas_should_be_invalid() {
+        for schema in [
+            json!([]),
+            json!({ "type": "text" }),
+            json!({ "required": "id" }),
+            json!({ "properties": { "id": 1 } }),
+            json!({ "items": [{ "type": "string" }] }),
+            json!({ "minLength": -1 }),
+            json!({ "maximum": "10" }),
+        ] {
+            assert!(!is_schema_valid(&schema), "{schema}");
+        }
+    }
+}
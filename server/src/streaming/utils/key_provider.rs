@@ -0,0 +1,232 @@
+use crate::configs::system::EncryptionConfig;
+use derive_more::Display;
+use iggy::error::IggyError;
+use iggy::utils::text;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::str::FromStr;
+use tracing::error;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Display, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyProviderKind {
+    #[default]
+    #[display("config")]
+    Config,
+    #[display("file")]
+    File,
+}
+
+impl FromStr for KeyProviderKind {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "config" => Ok(KeyProviderKind::Config),
+            "file" => Ok(KeyProviderKind::File),
+            _ => Err(format!("Unknown key provider kind: {}", s)),
+        }
+    }
+}
+
+/// `KeyProvider` resolves the server-managed encryption keys, e.g. from the configuration, the key file
+/// or the external key management service, so that the keys don't have to be stored along with the data.
+/// Each key has a version, which is bumped when the key is rotated.
+pub trait KeyProvider: Send + Sync + Debug {
+    /// Returns all the versions of the key, ordered from the oldest to the latest one.
+    fn get_keys(&self) -> Result<Vec<(u32, Vec<u8>)>, IggyError>;
+
+    /// Stores the new version of the key, so that it's available after the server restart.
+    fn add_key(&self, _version: u32, _key: &[u8]) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+}
+
+/// Provides the single base64 encoded key (version 1) from the configuration, which cannot be rotated.
+#[derive(Debug)]
+pub struct ConfigKeyProvider {
+    key: String,
+}
+
+/// Provides the base64 encoded keys from the file, e.g. mounted from the secrets store.
+/// Each line contains a single key in the `version:key` format, while a line with just the key
+/// (e.g. the file with a single key) gets the version based on its position, starting from 1.
+/// The rotated keys are appended to the file.
+#[derive(Debug)]
+pub struct FileKeyProvider {
+    path: String,
+}
+
+impl ConfigKeyProvider {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_owned(),
+        }
+    }
+}
+
+impl FileKeyProvider {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.to_owned(),
+        }
+    }
+}
+
+impl KeyProvider for ConfigKeyProvider {
+    fn get_keys(&self) -> Result<Vec<(u32, Vec<u8>)>, IggyError> {
+        Ok(vec![(1, text::from_base64_as_bytes(&self.key)?)])
+    }
+}
+
+impl FileKeyProvider {
+    fn read(&self) -> Result<String, IggyError> {
+        std::fs::read_to_string(&self.path).map_err(|error| {
+            error!(
+                "Cannot read the encryption keys from file: {}. Error: {}",
+                self.path, error
+            );
+            IggyError::InvalidEncryptionKey
+        })
+    }
+}
+
+impl KeyProvider for FileKeyProvider {
+    fn get_keys(&self) -> Result<Vec<(u32, Vec<u8>)>, IggyError> {
+        let mut keys = Vec::new();
+        for (index, line) in self
+            .read()?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .enumerate()
+        {
+            let (version, key) = match line.split_once(':') {
+                Some((version, key)) => (
+                    version
+                        .trim()
+                        .parse::<u32>()
+                        .map_err(|_| IggyError::InvalidEncryptionKey)?,
+                    key.trim(),
+                ),
+                None => (index as u32 + 1, line),
+            };
+            keys.push((version, text::from_base64_as_bytes(key)?));
+        }
+
+        keys.sort_by_key(|(version, _)| *version);
+        if keys.is_empty() || keys.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            error!(
+                "Invalid encryption keys in file: {}, the versions must be unique.",
+                self.path
+            );
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+
+        Ok(keys)
+    }
+
+    fn add_key(&self, version: u32, key: &[u8]) -> Result<(), IggyError> {
+        let mut content = self.read()?;
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&format!("{version}:{}\n", text::as_base64(key)));
+        std::fs::write(&self.path, content).map_err(|error| {
+            error!(
+                "Cannot store the encryption key in file: {}. Error: {}",
+                self.path, error
+            );
+            IggyError::CannotEncryptData
+        })
+    }
+}
+
+pub fn resolve(config: &EncryptionConfig) -> Box<dyn KeyProvider> {
+    match config.provider {
+        KeyProviderKind::Config => Box::new(ConfigKeyProvider::new(&config.key)),
+        KeyProviderKind::File => Box::new(FileKeyProvider::new(&config.file_path)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+    use uuid::Uuid;
+
+    const KEY: &str = "/rvT1xP4V8u1EAhk4xDdqzqM2UOPXyy9XYkl4uRShgE=";
+
+    const ROTATED_KEY: &str = "W3FhnOn2wcWN8dbY4XKCEwFFWWfs3pCkM1ahNNXmEhU=";
+
+    #[test]
+    fn config_key_provider_should_decode_base64_key() {
+        let keys = ConfigKeyProvider::new(KEY).get_keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, 1);
+        assert_eq!(keys[0].1.len(), 32);
+    }
+
+    #[test]
+    fn config_key_provider_should_not_add_key() {
+        let result = ConfigKeyProvider::new(KEY).add_key(2, &[1; 32]);
+        assert!(matches!(result, Err(IggyError::FeatureUnavailable)));
+    }
+
+    #[test]
+    fn file_key_provider_should_read_and_decode_key() {
+        let path = temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+        std::fs::write(&path, format!("{KEY}\n")).unwrap();
+
+        let keys = FileKeyProvider::new(path.to_str().unwrap())
+            .get_keys()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(keys, ConfigKeyProvider::new(KEY).get_keys().unwrap());
+    }
+
+    #[test]
+    fn file_key_provider_should_read_versioned_keys_ordered_by_version() {
+        let path = temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+        std::fs::write(&path, format!("3:{ROTATED_KEY}\n1:{KEY}\n")).unwrap();
+
+        let keys = FileKeyProvider::new(path.to_str().unwrap())
+            .get_keys()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].0, 1);
+        assert_eq!(keys[1].0, 3);
+        assert_eq!(keys[1].1, text::from_base64_as_bytes(ROTATED_KEY).unwrap());
+    }
+
+    #[test]
+    fn file_key_provider_should_append_added_key() {
+        let path = temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+        std::fs::write(&path, KEY).unwrap();
+        let provider = FileKeyProvider::new(path.to_str().unwrap());
+        let rotated_key = text::from_base64_as_bytes(ROTATED_KEY).unwrap();
+
+        provider.add_key(2, &rotated_key).unwrap();
+        let keys = provider.get_keys().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0].0, 1);
+        assert_eq!(keys[1], (2, rotated_key));
+    }
+
+    #[test]
+    fn file_key_provider_should_fail_for_duplicated_versions() {
+        let path = temp_dir().join(format!("iggy-key-{}", Uuid::now_v7()));
+        std::fs::write(&path, format!("1:{KEY}\n1:{ROTATED_KEY}\n")).unwrap();
+
+        let result = FileKeyProvider::new(path.to_str().unwrap()).get_keys();
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(result, Err(IggyError::InvalidEncryptionKey)));
+    }
+
+    #[test]
+    fn file_key_provider_should_fail_for_missing_file() {
+        let result = FileKeyProvider::new("/missing/iggy.key").get_keys();
+        assert!(matches!(result, Err(IggyError::InvalidEncryptionKey)));
+    }
+}
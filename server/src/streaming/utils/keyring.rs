@@ -0,0 +1,149 @@
+use crate::streaming::utils::key_provider::KeyProvider;
+use iggy::error::IggyError;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use rand::RngCore;
+use std::fmt::{Debug, Formatter};
+use std::sync::RwLock;
+use tracing::info;
+
+const KEY_SIZE: usize = 32;
+
+/// `Keyring` holds all the versions of the server-side encryption key provided by the `KeyProvider`.
+/// The data is always encrypted with the current (latest) key, while the previous keys remain available
+/// for decrypting the data written before the rotation, until it's re-encrypted with the current key.
+pub struct Keyring {
+    provider: Box<dyn KeyProvider>,
+    keys: RwLock<Vec<(u32, Aes256GcmEncryptor)>>,
+}
+
+impl Debug for Keyring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keyring")
+            .field("provider", &self.provider)
+            .field("current_version", &self.current_version())
+            .finish()
+    }
+}
+
+impl Keyring {
+    pub fn load(provider: Box<dyn KeyProvider>) -> Result<Self, IggyError> {
+        let mut keys = Vec::new();
+        for (version, key) in provider.get_keys()? {
+            keys.push((version, Aes256GcmEncryptor::new(&key)?));
+        }
+
+        if keys.is_empty() {
+            return Err(IggyError::InvalidEncryptionKey);
+        }
+
+        Ok(Self {
+            provider,
+            keys: RwLock::new(keys),
+        })
+    }
+
+    /// Returns the version of the key used for encrypting the data.
+    pub fn current_version(&self) -> u32 {
+        let keys = self.keys.read().unwrap();
+        keys.last().map(|(version, _)| *version).unwrap_or_default()
+    }
+
+    /// Generates the new key, which becomes the current one once stored by the provider, and returns its version.
+    pub fn rotate(&self) -> Result<u32, IggyError> {
+        let mut key = [0u8; KEY_SIZE];
+        rand::thread_rng().fill_bytes(&mut key);
+        let encryptor = Aes256GcmEncryptor::new(&key)?;
+        let mut keys = self.keys.write().unwrap();
+        let version = keys.last().map(|(version, _)| *version).unwrap_or_default() + 1;
+        self.provider.add_key(version, &key)?;
+        keys.push((version, encryptor));
+        info!("Rotated the encryption key, current version: {version}.");
+        Ok(version)
+    }
+
+    /// Decrypts the data with the matching key, starting from the current one, and returns the version of that key.
+    pub fn decrypt_with_version(&self, data: &[u8]) -> Result<(u32, Vec<u8>), IggyError> {
+        let keys = self.keys.read().unwrap();
+        for (version, encryptor) in keys.iter().rev() {
+            if let Ok(decrypted_data) = encryptor.decrypt(data) {
+                return Ok((*version, decrypted_data));
+            }
+        }
+
+        Err(IggyError::CannotDecryptData)
+    }
+}
+
+impl Encryptor for Keyring {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        let keys = self.keys.read().unwrap();
+        match keys.last() {
+            Some((_, encryptor)) => encryptor.encrypt(data),
+            None => Err(IggyError::CannotEncryptData),
+        }
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, IggyError> {
+        self.decrypt_with_version(data)
+            .map(|(_, decrypted_data)| decrypted_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streaming::utils::key_provider::{ConfigKeyProvider, FileKeyProvider};
+    use std::env::temp_dir;
+    use uuid::Uuid;
+
+    const KEY: &str = "/rvT1xP4V8u1EAhk4xDdqzqM2UOPXyy9XYkl4uRShgE=";
+
+    #[test]
+    fn rotated_key_should_encrypt_data_while_previous_key_remains_readable() {
+        let path = temp_dir().join(format!("iggy-keyring-{}", Uuid::now_v7()));
+        std::fs::write(&path, KEY).unwrap();
+        let keyring =
+            Keyring::load(Box::new(FileKeyProvider::new(path.to_str().unwrap()))).unwrap();
+        let data = b"Hello World!";
+        let old_encrypted_data = keyring.encrypt(data).unwrap();
+
+        let version = keyring.rotate().unwrap();
+        let new_encrypted_data = keyring.encrypt(data).unwrap();
+        let reloaded_keyring =
+            Keyring::load(Box::new(FileKeyProvider::new(path.to_str().unwrap()))).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(version, 2);
+        assert_eq!(keyring.current_version(), 2);
+        assert_eq!(reloaded_keyring.current_version(), 2);
+        let (old_version, old_data) = keyring.decrypt_with_version(&old_encrypted_data).unwrap();
+        let (new_version, new_data) = reloaded_keyring
+            .decrypt_with_version(&new_encrypted_data)
+            .unwrap();
+        assert_eq!(old_version, 1);
+        assert_eq!(old_data, data);
+        assert_eq!(new_version, 2);
+        assert_eq!(new_data, data);
+    }
+
+    #[test]
+    fn data_encrypted_with_unknown_key_should_not_be_decrypted() {
+        let keyring = Keyring::load(Box::new(ConfigKeyProvider::new(KEY))).unwrap();
+        let encrypted_data = Aes256GcmEncryptor::new(&[1; KEY_SIZE])
+            .unwrap()
+            .encrypt(b"Hello World!")
+            .unwrap();
+
+        let result = keyring.decrypt(&encrypted_data);
+        assert!(matches!(result, Err(IggyError::CannotDecryptData)));
+    }
+
+    #[test]
+    fn key_provided_by_config_should_not_be_rotated() {
+        let keyring = Keyring::load(Box::new(ConfigKeyProvider::new(KEY))).unwrap();
+
+        let result = keyring.rotate();
+        assert!(matches!(result, Err(IggyError::FeatureUnavailable)));
+        assert_eq!(keyring.current_version(), 1);
+    }
+}
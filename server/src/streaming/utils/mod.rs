@@ -2,4 +2,8 @@ pub mod crypto;
 pub mod file;
 pub mod hash;
 pub mod head_tail_buf;
+pub mod io_budget;
+pub mod json_schema;
+pub mod key_provider;
+pub mod keyring;
 pub mod random_id;
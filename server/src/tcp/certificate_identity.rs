@@ -0,0 +1,190 @@
+use crate::configs::tcp::CertificateUserMapping;
+use simple_asn1::{from_der, oid, ASN1Block, ASN1Class, BigUint};
+
+const SAN_EMAIL_TAG: u8 = 1;
+const SAN_DNS_TAG: u8 = 2;
+const SAN_URI_TAG: u8 = 6;
+
+/// Returns the identities of the DER encoded X.509 certificate, being the subject common names (CN)
+/// followed by the email, DNS and URI subject alternative names (SAN), in the order of their appearance.
+/// The certificate that cannot be parsed has no identities.
+pub(crate) fn get_identities(certificate: &[u8]) -> Vec<String> {
+    let Ok(blocks) = from_der(certificate) else {
+        return Vec::new();
+    };
+    let Some(ASN1Block::Sequence(_, certificate)) = blocks.first() else {
+        return Vec::new();
+    };
+    let Some(ASN1Block::Sequence(_, tbs_certificate)) = certificate.first() else {
+        return Vec::new();
+    };
+
+    // The version is optional and explicitly tagged, followed by the serial number, signature, issuer, validity and subject.
+    let fields = match tbs_certificate.first() {
+        Some(ASN1Block::Explicit(ASN1Class::ContextSpecific, _, tag, _))
+            if *tag == BigUint::from(0u8) =>
+        {
+            &tbs_certificate[1..]
+        }
+        _ => &tbs_certificate[..],
+    };
+
+    let mut identities = Vec::new();
+    if let Some(ASN1Block::Sequence(_, subject)) = fields.get(4) {
+        identities.extend(get_common_names(subject));
+    }
+
+    for field in fields.iter().skip(6) {
+        if let ASN1Block::Explicit(ASN1Class::ContextSpecific, _, tag, extensions) = field {
+            if *tag == BigUint::from(3u8) {
+                identities.extend(get_alternative_names(extensions));
+            }
+        }
+    }
+
+    identities
+}
+
+/// Returns the username mapped to the first of the identities having the mapping, if any.
+pub(crate) fn map_username<'a>(
+    identities: &[String],
+    users: &'a [CertificateUserMapping],
+) -> Option<&'a str> {
+    identities.iter().find_map(|identity| {
+        users
+            .iter()
+            .find(|mapping| &mapping.identity == identity)
+            .map(|mapping| mapping.username.as_str())
+    })
+}
+
+fn get_common_names(subject: &[ASN1Block]) -> Vec<String> {
+    let common_name = oid!(2, 5, 4, 3);
+    let mut common_names = Vec::new();
+    for attributes in subject {
+        let ASN1Block::Set(_, attributes) = attributes else {
+            continue;
+        };
+        for attribute in attributes {
+            let ASN1Block::Sequence(_, attribute) = attribute else {
+                continue;
+            };
+            match attribute.as_slice() {
+                [ASN1Block::ObjectIdentifier(_, oid), value] if *oid == common_name => {
+                    if let Some(value) = get_string(value) {
+                        common_names.push(value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    common_names
+}
+
+fn get_alternative_names(extensions: &ASN1Block) -> Vec<String> {
+    let subject_alternative_name = oid!(2, 5, 29, 17);
+    let ASN1Block::Sequence(_, extensions) = extensions else {
+        return Vec::new();
+    };
+
+    let mut alternative_names = Vec::new();
+    for extension in extensions {
+        let ASN1Block::Sequence(_, extension) = extension else {
+            continue;
+        };
+        // The critical flag is optional and placed between the extension ID and its value.
+        let (Some(ASN1Block::ObjectIdentifier(_, oid)), Some(ASN1Block::OctetString(_, value))) =
+            (extension.first(), extension.last())
+        else {
+            continue;
+        };
+        if *oid != subject_alternative_name {
+            continue;
+        }
+
+        let Ok(blocks) = from_der(value) else {
+            continue;
+        };
+        let Some(ASN1Block::Sequence(_, names)) = blocks.first() else {
+            continue;
+        };
+        for name in names {
+            if let ASN1Block::Unknown(ASN1Class::ContextSpecific, false, _, tag, value) = name {
+                let is_supported = [SAN_EMAIL_TAG, SAN_DNS_TAG, SAN_URI_TAG]
+                    .iter()
+                    .any(|supported_tag| *tag == BigUint::from(*supported_tag));
+                if !is_supported {
+                    continue;
+                }
+                if let Ok(value) = String::from_utf8(value.clone()) {
+                    alternative_names.push(value);
+                }
+            }
+        }
+    }
+    alternative_names
+}
+
+fn get_string(block: &ASN1Block) -> Option<String> {
+    match block {
+        ASN1Block::UTF8String(_, value)
+        | ASN1Block::PrintableString(_, value)
+        | ASN1Block::TeletexString(_, value)
+        | ASN1Block::IA5String(_, value)
+        | ASN1Block::UniversalString(_, value)
+        | ASN1Block::BMPString(_, value) => Some(value.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{CertificateParams, DnType, KeyPair, SanType};
+
+    fn generate_certificate() -> Vec<u8> {
+        let mut params = CertificateParams::new(vec!["client.iggy.rs".to_string()]).unwrap();
+        params
+            .distinguished_name
+            .push(DnType::OrganizationName, "iggy");
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "iggy-client");
+        params
+            .subject_alt_names
+            .push(SanType::Rfc822Name("client@iggy.rs".try_into().unwrap()));
+        let key_pair = KeyPair::generate().unwrap();
+        params.self_signed(&key_pair).unwrap().der().to_vec()
+    }
+
+    #[test]
+    fn common_name_and_alternative_names_should_be_read_from_certificate() {
+        let identities = get_identities(&generate_certificate());
+
+        assert_eq!(
+            identities,
+            vec!["iggy-client", "client.iggy.rs", "client@iggy.rs"]
+        );
+    }
+
+    #[test]
+    fn invalid_certificate_should_have_no_identities() {
+        let identities = get_identities(&[1, 2, 3]);
+
+        assert!(identities.is_empty());
+    }
+
+    #[test]
+    fn first_mapped_identity_should_be_used() {
+        let users = vec![
+            "client@iggy.rs=user1".parse().unwrap(),
+            "client.iggy.rs=user2".parse().unwrap(),
+        ];
+        let identities = get_identities(&generate_certificate());
+
+        let username = map_username(&identities, &users);
+        assert_eq!(username, Some("user2"));
+        assert_eq!(map_username(&identities[..1], &users), None);
+    }
+}
@@ -1,10 +1,12 @@
+use crate::binary::checksum_sender::{self, ChecksumSender};
 use crate::binary::command;
 use crate::binary::sender::Sender;
 use crate::command::ServerCommand;
 use crate::server_error::ServerError;
 use crate::streaming::session::Session;
 use crate::streaming::systems::system::SharedSystem;
-use bytes::{BufMut, BytesMut};
+use crate::tcp::json_connection_handler::{self, JSON_PROTOCOL_PREAMBLE};
+use bytes::{BufMut, Bytes, BytesMut};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::IggyError;
 use iggy::validatable::Validatable;
@@ -18,10 +20,19 @@ pub(crate) async fn handle_connection(
     session: Arc<Session>,
     sender: &mut dyn Sender,
     system: SharedSystem,
+    json_protocol: bool,
 ) -> Result<(), ServerError> {
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let mut is_first_request = true;
     loop {
-        let read_length = match sender.read(&mut initial_buffer).await {
+        let read_result = tokio::select! {
+            read_result = sender.read(&mut initial_buffer) => read_result,
+            _ = session.terminated() => {
+                info!("Session: {session} has been terminated, closing the connection.");
+                return Err(ServerError::from(IggyError::StaleClient));
+            }
+        };
+        let read_length = match read_result {
             Ok(read_length) => read_length,
             Err(error) => {
                 if error.as_code() == IggyError::ConnectionClosed.as_code() {
@@ -40,29 +51,61 @@ pub(crate) async fn handle_connection(
             continue;
         }
 
+        if is_first_request && json_protocol && &initial_buffer == JSON_PROTOCOL_PREAMBLE {
+            info!("Switching to JSON protocol for session: {session}");
+            return json_connection_handler::handle_connection(session, sender, system).await;
+        }
+
+        is_first_request = false;
         let length = u32::from_le_bytes(initial_buffer);
         debug!("Received a TCP request, length: {length}");
         let mut command_buffer = BytesMut::with_capacity(length as usize);
         command_buffer.put_bytes(0, length as usize);
         sender.read(&mut command_buffer).await?;
-        let command = ServerCommand::from_bytes(command_buffer.freeze());
-        if command.is_err() {
-            error!("Received an invalid TCP command.");
-            sender
-                .send_error_response(IggyError::InvalidCommand)
-                .await?;
-            continue;
-        }
-        let command = command?;
-        if let Err(error) = command.validate() {
-            error!("Command validation failed: {error}");
-            sender.send_error_response(error).await?;
+        if checksum_sender::has_checksum(&command_buffer) {
+            let mut sender = ChecksumSender::new(sender);
+            let command_buffer =
+                match checksum_sender::verify_checksum(&initial_buffer, &command_buffer) {
+                    Ok(command_buffer) => command_buffer,
+                    Err(error) => {
+                        error!("Received a TCP command with invalid checksum: {error}");
+                        sender.send_error_response(error).await?;
+                        continue;
+                    }
+                };
+            handle_command(command_buffer, length, &mut sender, &session, &system).await?;
             continue;
         }
 
-        debug!("Received a TCP command: {command}, payload size: {length}");
-        command::handle(command, sender, &session, system.clone()).await?;
+        handle_command(command_buffer.freeze(), length, sender, &session, &system).await?;
+    }
+}
+
+async fn handle_command(
+    command_buffer: Bytes,
+    length: u32,
+    sender: &mut dyn Sender,
+    session: &Session,
+    system: &SharedSystem,
+) -> Result<(), ServerError> {
+    let command = ServerCommand::from_bytes(command_buffer);
+    if command.is_err() {
+        error!("Received an invalid TCP command.");
+        sender
+            .send_error_response(IggyError::InvalidCommand)
+            .await?;
+        return Ok(());
     }
+    let command = command?;
+    if let Err(error) = command.validate() {
+        error!("Command validation failed: {error}");
+        sender.send_error_response(error).await?;
+        return Ok(());
+    }
+
+    debug!("Received a TCP command: {command}, payload size: {length}");
+    command::handle(command, sender, session, system.clone()).await?;
+    Ok(())
 }
 
 pub(crate) fn handle_error(error: ServerError) {
@@ -88,6 +131,9 @@ pub(crate) fn handle_error(error: ServerError) {
             IggyError::ConnectionClosed => {
                 debug!("Client closed connection.");
             }
+            IggyError::StaleClient => {
+                info!("Connection has been closed for the terminated session.");
+            }
             _ => {
                 error!("Failure in internal SDK call: {sdk_error}");
             }
@@ -0,0 +1,642 @@
+use crate::command::ServerCommand;
+use crate::streaming::utils::random_id;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use bytes::Bytes;
+use iggy::binary::mapper;
+use iggy::command::*;
+use iggy::consumer::ConsumerKind;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_offsets::delete_consumer::DeleteConsumer;
+use iggy::consumer_offsets::delete_consumer_offsets::DeleteConsumerOffsets;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::get_consumers::GetConsumers;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::error::IggyError;
+use iggy::identifier::Identifier;
+use iggy::messages::acquire_exclusive_producer::AcquireExclusiveProducer;
+use iggy::messages::copy_messages::CopyMessages;
+use iggy::messages::fetch_replica_messages::FetchReplicaMessages;
+use iggy::messages::flush_unsaved_buffer::FlushUnsavedBuffer;
+use iggy::messages::get_message_by_key::GetMessageByKey;
+use iggy::messages::init_producer_epoch::InitProducerEpoch;
+use iggy::messages::peek_messages::PeekMessages;
+use iggy::messages::poll_messages::PollMessages;
+use iggy::messages::poll_messages_from_partitions::PollMessagesFromPartitions;
+use iggy::messages::send_messages::SendMessages;
+use iggy::partitions::create_partitions::CreatePartitions;
+use iggy::partitions::delete_partitions::DeletePartitions;
+use iggy::partitions::get_messages_histogram::GetMessagesHistogram;
+use iggy::partitions::get_offset_by_timestamp::GetOffsetByTimestamp;
+use iggy::partitions::get_partition::GetPartition;
+use iggy::partitions::get_partition_offsets::GetPartitionOffsets;
+use iggy::partitions::seal_partition::SealPartition;
+use iggy::schemas::get_schema::GetSchema;
+use iggy::schemas::get_schemas::GetSchemas;
+use iggy::schemas::register_schema::RegisterSchema;
+use iggy::streams::delete_stream::DeleteStream;
+use iggy::streams::get_stream::GetStream;
+use iggy::streams::purge_stream::PurgeStream;
+use iggy::streams::update_stream::UpdateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::delete_topic::DeleteTopic;
+use iggy::topics::get_topic::GetTopic;
+use iggy::topics::get_topics::GetTopics;
+use iggy::topics::purge_topic::PurgeTopic;
+use iggy::topics::set_topic_read_only::SetTopicReadOnly;
+use iggy::topics::update_topic::UpdateTopic;
+use iggy::users::change_password::ChangePassword;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_user::UpdateUser;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Command received with the JSON protocol, the name is the same as the one used by the CLI and the binary protocol,
+/// e.g. `stream.get`, and is needed to map the binary response to JSON.
+#[derive(Debug)]
+pub(crate) struct JsonCommand {
+    pub name: String,
+    pub command: ServerCommand,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonErrorResponse {
+    status: u32,
+    error: String,
+}
+
+/// Parses the JSON object into the command, its fields are the same as the ones of the command payload
+/// (e.g. as used by the HTTP API) and the identifiers are provided as the `stream_id`, `topic_id`, `group_id`
+/// or `user_id` fields, either as the numbers or the strings. For example:
+/// `{"command": "topic.get", "stream_id": 1, "topic_id": "orders"}`.
+pub(crate) fn parse(line: &[u8]) -> Result<JsonCommand, IggyError> {
+    let mut fields: Map<String, Value> = serde_json::from_slice(line)
+        .map_err(|error| IggyError::CannotParseJson(error.to_string()))?;
+    let Some(Value::String(name)) = fields.remove("command") else {
+        return Err(IggyError::CannotParseJson(
+            "missing `command` field".to_owned(),
+        ));
+    };
+
+    let command = match name.as_str() {
+        PING => ServerCommand::Ping(deserialize(&fields)?),
+        GET_STATS => ServerCommand::GetStats(deserialize(&fields)?),
+        GET_SNAPSHOT_FILE => ServerCommand::GetSnapshotFile(deserialize(&fields)?),
+        ROTATE_ENCRYPTION_KEY => ServerCommand::RotateEncryptionKey(deserialize(&fields)?),
+        SET_LOG_LEVEL => ServerCommand::SetLogLevel(deserialize(&fields)?),
+        SET_BACKGROUND_IO_RATE => ServerCommand::SetBackgroundIoRate(deserialize(&fields)?),
+        GET_CLUSTER_METADATA => ServerCommand::GetClusterMetadata(deserialize(&fields)?),
+        DRAIN_NODE => ServerCommand::DrainNode(deserialize(&fields)?),
+        DECOMMISSION_NODE => ServerCommand::DecommissionNode(deserialize(&fields)?),
+        GET_NODE_STATUS => ServerCommand::GetNodeStatus(deserialize(&fields)?),
+        GET_ME => ServerCommand::GetMe(deserialize(&fields)?),
+        GET_CLIENT => ServerCommand::GetClient(deserialize(&fields)?),
+        GET_CLIENTS => ServerCommand::GetClients(deserialize(&fields)?),
+        GET_SESSIONS => ServerCommand::GetSessions(deserialize(&fields)?),
+        KILL_SESSION => ServerCommand::KillSession(deserialize(&fields)?),
+        GET_USER => {
+            let mut command: GetUser = deserialize(&fields)?;
+            command.user_id = identifier(&fields, "user_id")?;
+            ServerCommand::GetUser(command)
+        }
+        GET_USERS => ServerCommand::GetUsers(deserialize(&fields)?),
+        CREATE_USER => ServerCommand::CreateUser(deserialize(&fields)?),
+        DELETE_USER => {
+            let mut command: DeleteUser = deserialize(&fields)?;
+            command.user_id = identifier(&fields, "user_id")?;
+            ServerCommand::DeleteUser(command)
+        }
+        UPDATE_USER => {
+            let mut command: UpdateUser = deserialize(&fields)?;
+            command.user_id = identifier(&fields, "user_id")?;
+            ServerCommand::UpdateUser(command)
+        }
+        UPDATE_PERMISSIONS => {
+            let mut command: UpdatePermissions = deserialize(&fields)?;
+            command.user_id = identifier(&fields, "user_id")?;
+            ServerCommand::UpdatePermissions(command)
+        }
+        CHANGE_PASSWORD => {
+            let mut command: ChangePassword = deserialize(&fields)?;
+            command.user_id = identifier(&fields, "user_id")?;
+            ServerCommand::ChangePassword(command)
+        }
+        LOGIN_USER => ServerCommand::LoginUser(deserialize(&fields)?),
+        LOGOUT_USER => ServerCommand::LogoutUser(deserialize(&fields)?),
+        GET_PERSONAL_ACCESS_TOKENS => ServerCommand::GetPersonalAccessTokens(deserialize(&fields)?),
+        CREATE_PERSONAL_ACCESS_TOKEN => {
+            ServerCommand::CreatePersonalAccessToken(deserialize(&fields)?)
+        }
+        DELETE_PERSONAL_ACCESS_TOKEN => {
+            ServerCommand::DeletePersonalAccessToken(deserialize(&fields)?)
+        }
+        LOGIN_WITH_PERSONAL_ACCESS_TOKEN => {
+            ServerCommand::LoginWithPersonalAccessToken(deserialize(&fields)?)
+        }
+        SEND_MESSAGES => {
+            let mut command: SendMessages = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partitioning.length = command.partitioning.value.len() as u8;
+            command.messages.iter_mut().for_each(|message| {
+                if message.id == 0 {
+                    message.id = random_id::get_uuid();
+                }
+                message.length = message.payload.len() as u32;
+            });
+            ServerCommand::SendMessages(command)
+        }
+        POLL_MESSAGES => {
+            stringify_numbers(&mut fields, &["id", "value"]);
+            let mut command: PollMessages = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::PollMessages(command)
+        }
+        POLL_MESSAGES_FROM_PARTITIONS => {
+            stringify_numbers(&mut fields, &["id", "value"]);
+            let mut command: PollMessagesFromPartitions = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::PollMessagesFromPartitions(command)
+        }
+        PEEK_MESSAGES => {
+            let mut command: PeekMessages = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::PeekMessages(command)
+        }
+        GET_MESSAGE_BY_KEY => {
+            stringify_numbers(&mut fields, &["key"]);
+            let mut command: GetMessageByKey = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::GetMessageByKey(command)
+        }
+        FETCH_REPLICA_MESSAGES => {
+            let mut command: FetchReplicaMessages = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::FetchReplicaMessages(command)
+        }
+        ACQUIRE_EXCLUSIVE_PRODUCER => {
+            ServerCommand::AcquireExclusiveProducer(AcquireExclusiveProducer {
+                stream_id: identifier(&fields, "stream_id")?,
+                topic_id: identifier(&fields, "topic_id")?,
+            })
+        }
+        INIT_PRODUCER_EPOCH => {
+            let mut command: InitProducerEpoch = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::InitProducerEpoch(command)
+        }
+        COPY_MESSAGES => {
+            stringify_numbers(&mut fields, &["target_stream_id", "target_topic_id"]);
+            let mut command: CopyMessages = deserialize(&fields)?;
+            command.source_stream_id = identifier(&fields, "source_stream_id")?;
+            command.source_topic_id = identifier(&fields, "source_topic_id")?;
+            ServerCommand::CopyMessages(command)
+        }
+        FLUSH_UNSAVED_BUFFER => ServerCommand::FlushUnsavedBuffer(FlushUnsavedBuffer {
+            stream_id: identifier(&fields, "stream_id")?,
+            topic_id: identifier(&fields, "topic_id")?,
+            partition_id: deserialize_field(&fields, "partition_id")?,
+            fsync: deserialize_field(&fields, "fsync")?,
+        }),
+        GET_CONSUMER_OFFSET => {
+            stringify_numbers(&mut fields, &["id"]);
+            let mut command: GetConsumerOffset = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::GetConsumerOffset(command)
+        }
+        STORE_CONSUMER_OFFSET => {
+            stringify_numbers(&mut fields, &["id"]);
+            let mut command: StoreConsumerOffset = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::StoreConsumerOffset(command)
+        }
+        GET_CONSUMER_LAG => {
+            stringify_numbers(&mut fields, &["id"]);
+            let mut command: GetConsumerLag = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::GetConsumerLag(command)
+        }
+        RESET_CONSUMER_OFFSETS => {
+            stringify_numbers(&mut fields, &["id"]);
+            let mut command: ResetConsumerOffsets = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::ResetConsumerOffsets(command)
+        }
+        GET_CONSUMERS => {
+            let mut command: GetConsumers = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::GetConsumers(command)
+        }
+        DELETE_CONSUMER => {
+            let mut command: DeleteConsumer = deserialize(&fields)?;
+            command.consumer_id = identifier(&fields, "consumer_id")?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::DeleteConsumer(command)
+        }
+        DELETE_CONSUMER_OFFSETS => {
+            stringify_numbers(&mut fields, &["id"]);
+            let mut command: DeleteConsumerOffsets = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.consumer.kind = consumer_kind(&fields)?;
+            ServerCommand::DeleteConsumerOffsets(command)
+        }
+        GET_STREAM => {
+            let mut command: GetStream = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::GetStream(command)
+        }
+        GET_STREAMS => ServerCommand::GetStreams(deserialize(&fields)?),
+        CREATE_STREAM => ServerCommand::CreateStream(deserialize(&fields)?),
+        DELETE_STREAM => {
+            let mut command: DeleteStream = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::DeleteStream(command)
+        }
+        UPDATE_STREAM => {
+            let mut command: UpdateStream = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::UpdateStream(command)
+        }
+        PURGE_STREAM => {
+            let mut command: PurgeStream = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::PurgeStream(command)
+        }
+        GET_TOPIC => {
+            let mut command: GetTopic = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::GetTopic(command)
+        }
+        GET_TOPICS => {
+            let mut command: GetTopics = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::GetTopics(command)
+        }
+        CREATE_TOPIC => {
+            let mut command: CreateTopic = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            ServerCommand::CreateTopic(command)
+        }
+        DELETE_TOPIC => {
+            let mut command: DeleteTopic = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::DeleteTopic(command)
+        }
+        UPDATE_TOPIC => {
+            let mut command: UpdateTopic = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::UpdateTopic(command)
+        }
+        PURGE_TOPIC => {
+            let mut command: PurgeTopic = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::PurgeTopic(command)
+        }
+        SET_TOPIC_READ_ONLY => {
+            let mut command: SetTopicReadOnly = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::SetTopicReadOnly(command)
+        }
+        GET_SCHEMA => {
+            let mut command: GetSchema = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.version = deserialize_field(&fields, "version")?;
+            ServerCommand::GetSchema(command)
+        }
+        GET_SCHEMAS => {
+            let mut command: GetSchemas = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::GetSchemas(command)
+        }
+        REGISTER_SCHEMA => {
+            let mut command: RegisterSchema = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::RegisterSchema(command)
+        }
+        GET_PARTITION => {
+            let mut command: GetPartition = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::GetPartition(command)
+        }
+        CREATE_PARTITIONS => {
+            let mut command: CreatePartitions = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::CreatePartitions(command)
+        }
+        DELETE_PARTITIONS => {
+            let mut command: DeletePartitions = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::DeletePartitions(command)
+        }
+        GET_PARTITION_OFFSETS => {
+            let mut command: GetPartitionOffsets = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::GetPartitionOffsets(command)
+        }
+        GET_OFFSET_BY_TIMESTAMP => {
+            let mut command: GetOffsetByTimestamp = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::GetOffsetByTimestamp(command)
+        }
+        GET_MESSAGES_HISTOGRAM => {
+            let mut command: GetMessagesHistogram = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::GetMessagesHistogram(command)
+        }
+        SEAL_PARTITION => {
+            let mut command: SealPartition = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.partition_id = deserialize_field(&fields, "partition_id")?;
+            ServerCommand::SealPartition(command)
+        }
+        GET_CONSUMER_GROUP => {
+            let mut command: GetConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.group_id = identifier(&fields, "group_id")?;
+            ServerCommand::GetConsumerGroup(command)
+        }
+        GET_CONSUMER_GROUPS => {
+            let mut command: GetConsumerGroups = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::GetConsumerGroups(command)
+        }
+        CREATE_CONSUMER_GROUP => {
+            let mut command: CreateConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            ServerCommand::CreateConsumerGroup(command)
+        }
+        DELETE_CONSUMER_GROUP => {
+            let mut command: DeleteConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.group_id = identifier(&fields, "group_id")?;
+            ServerCommand::DeleteConsumerGroup(command)
+        }
+        JOIN_CONSUMER_GROUP => {
+            let mut command: JoinConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.group_id = identifier(&fields, "group_id")?;
+            ServerCommand::JoinConsumerGroup(command)
+        }
+        LEAVE_CONSUMER_GROUP => {
+            let mut command: LeaveConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.group_id = identifier(&fields, "group_id")?;
+            ServerCommand::LeaveConsumerGroup(command)
+        }
+        HEARTBEAT_CONSUMER_GROUP => {
+            let mut command: HeartbeatConsumerGroup = deserialize(&fields)?;
+            command.stream_id = identifier(&fields, "stream_id")?;
+            command.topic_id = identifier(&fields, "topic_id")?;
+            command.group_id = identifier(&fields, "group_id")?;
+            ServerCommand::HeartbeatConsumerGroup(command)
+        }
+        _ => return Err(IggyError::InvalidCommand),
+    };
+
+    Ok(JsonCommand { name, command })
+}
+
+/// Maps the binary response of the command into the JSON line, the payload is decoded the same way as by the SDK.
+/// The payloads without the JSON representation (e.g. the snapshot archive) are returned as the Base64 string.
+pub(crate) fn map_response(name: &str, response: Result<Bytes, IggyError>) -> Vec<u8> {
+    // The data is serialized directly, as `Value` can't represent the `u128` message IDs.
+    let mut line = match response.and_then(|payload| map_payload(name, payload)) {
+        Ok(Some(data)) => format!("{{\"status\":0,\"data\":{data}}}").into_bytes(),
+        Ok(None) => b"{\"status\":0}".to_vec(),
+        Err(error) => {
+            let response = JsonErrorResponse {
+                status: error.as_code(),
+                error: error.to_string(),
+            };
+            serde_json::to_vec(&response).unwrap_or_default()
+        }
+    };
+    line.push(b'\n');
+    line
+}
+
+/// Maps the error into the JSON line, used when the command couldn't be parsed or handled.
+pub(crate) fn map_error_response(error: IggyError) -> Vec<u8> {
+    map_response("", Err(error))
+}
+
+fn map_payload(name: &str, payload: Bytes) -> Result<Option<String>, IggyError> {
+    if payload.is_empty() {
+        return Ok(None);
+    }
+
+    let data = match name {
+        GET_STATS => to_json(mapper::map_stats(payload)?),
+        GET_CLUSTER_METADATA => to_json(mapper::map_cluster_metadata(payload)?),
+        DRAIN_NODE | DECOMMISSION_NODE | GET_NODE_STATUS => {
+            to_json(mapper::map_node_status(payload)?)
+        }
+        GET_ME | GET_CLIENT => to_json(mapper::map_client(payload)?),
+        GET_CLIENTS => to_json(mapper::map_clients(payload)?),
+        GET_SESSIONS => to_json(mapper::map_sessions(payload)?),
+        GET_USER | CREATE_USER => to_json(mapper::map_user(payload)?),
+        GET_USERS => to_json(mapper::map_users(payload)?),
+        LOGIN_USER | LOGIN_WITH_PERSONAL_ACCESS_TOKEN => {
+            to_json(mapper::map_identity_info(payload)?)
+        }
+        GET_PERSONAL_ACCESS_TOKENS => to_json(mapper::map_personal_access_tokens(payload)?),
+        CREATE_PERSONAL_ACCESS_TOKEN => to_json(mapper::map_raw_pat(payload)?),
+        POLL_MESSAGES | PEEK_MESSAGES | GET_MESSAGE_BY_KEY => {
+            to_json(mapper::map_polled_messages(payload)?)
+        }
+        FETCH_REPLICA_MESSAGES => to_json(mapper::map_replica_messages(payload)?),
+        POLL_MESSAGES_FROM_PARTITIONS => {
+            to_json(mapper::map_polled_messages_from_partitions(payload)?)
+        }
+        INIT_PRODUCER_EPOCH => to_json(mapper::map_producer_epoch(payload)?),
+        COPY_MESSAGES => to_json(mapper::map_copied_messages(payload)?),
+        ROTATE_ENCRYPTION_KEY => to_json(mapper::map_key_version(payload)?),
+        GET_CONSUMER_OFFSET => to_json(mapper::map_consumer_offset(payload)?),
+        GET_CONSUMER_LAG => to_json(mapper::map_consumer_lags(payload)?),
+        RESET_CONSUMER_OFFSETS => to_json(mapper::map_consumer_offset_resets(payload)?),
+        GET_CONSUMERS => to_json(mapper::map_consumers(payload)?),
+        GET_STREAM | CREATE_STREAM => to_json(mapper::map_stream(payload)?),
+        GET_STREAMS => to_json(mapper::map_streams(payload)?),
+        GET_TOPIC | CREATE_TOPIC => to_json(mapper::map_topic(payload)?),
+        GET_TOPICS => to_json(mapper::map_topics(payload)?),
+        GET_PARTITION => to_json(mapper::map_partition(payload)?),
+        GET_PARTITION_OFFSETS => to_json(mapper::map_partition_offsets(payload)?),
+        GET_OFFSET_BY_TIMESTAMP => to_json(mapper::map_timestamp_offset(payload)?),
+        GET_MESSAGES_HISTOGRAM => to_json(mapper::map_messages_histogram(payload)?),
+        SEAL_PARTITION => to_json(mapper::map_sealed_partition(payload)?),
+        GET_SCHEMA | REGISTER_SCHEMA => to_json(mapper::map_schema(payload)?),
+        GET_SCHEMAS => to_json(mapper::map_schemas(payload)?),
+        GET_CONSUMER_GROUP | CREATE_CONSUMER_GROUP => to_json(mapper::map_consumer_group(payload)?),
+        GET_CONSUMER_GROUPS => to_json(mapper::map_consumer_groups(payload)?),
+        _ => to_json(STANDARD.encode(payload)),
+    }?;
+    Ok(Some(data))
+}
+
+fn to_json<T: Serialize>(data: T) -> Result<String, IggyError> {
+    serde_json::to_string(&data).map_err(|error| IggyError::CannotParseJson(error.to_string()))
+}
+
+fn deserialize<T: DeserializeOwned>(fields: &Map<String, Value>) -> Result<T, IggyError> {
+    serde_json::from_value(Value::Object(fields.clone()))
+        .map_err(|error| IggyError::CannotParseJson(error.to_string()))
+}
+
+fn deserialize_field<T: DeserializeOwned + Default>(
+    fields: &Map<String, Value>,
+    name: &str,
+) -> Result<T, IggyError> {
+    let Some(value) = fields.get(name) else {
+        return Ok(T::default());
+    };
+
+    serde_json::from_value(value.clone())
+        .map_err(|error| IggyError::CannotParseJson(format!("invalid `{name}` field: {error}")))
+}
+
+fn identifier(fields: &Map<String, Value>, name: &str) -> Result<Identifier, IggyError> {
+    match fields.get(name) {
+        Some(Value::Number(id)) => Identifier::from_str_value(&id.to_string()),
+        Some(Value::String(id)) => Identifier::from_str_value(id),
+        Some(_) => Err(IggyError::InvalidIdentifier),
+        None => Err(IggyError::CannotParseJson(format!(
+            "missing `{name}` field"
+        ))),
+    }
+}
+
+fn consumer_kind(fields: &Map<String, Value>) -> Result<ConsumerKind, IggyError> {
+    deserialize_field(fields, "consumer_kind")
+}
+
+/// The fields parsed from the strings by the HTTP API (query parameters) accept the JSON numbers as well.
+fn stringify_numbers(fields: &mut Map<String, Value>, names: &[&str]) {
+    for name in names {
+        if let Some(value) = fields.get_mut(*name) {
+            if value.is_number() {
+                *value = Value::String(value.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::messages::poll_messages::PollingKind;
+
+    #[test]
+    fn should_parse_command_with_identifiers() {
+        let json_command =
+            parse(br#"{"command": "topic.get", "stream_id": 1, "topic_id": "orders"}"#).unwrap();
+
+        assert_eq!(json_command.name, GET_TOPIC);
+        let ServerCommand::GetTopic(command) = json_command.command else {
+            panic!("Invalid command");
+        };
+        assert_eq!(command.stream_id, Identifier::numeric(1).unwrap());
+        assert_eq!(command.topic_id, Identifier::named("orders").unwrap());
+    }
+
+    #[test]
+    fn should_parse_poll_messages_command_with_numeric_values() {
+        let json_command = parse(
+            br#"{"command": "message.poll", "stream_id": 1, "topic_id": 2, "consumer_kind": "consumer_group",
+                "id": 3, "partition_id": 1, "kind": "offset", "value": 10, "count": 5}"#,
+        )
+        .unwrap();
+
+        let ServerCommand::PollMessages(command) = json_command.command else {
+            panic!("Invalid command");
+        };
+        assert_eq!(command.consumer.kind, ConsumerKind::ConsumerGroup);
+        assert_eq!(command.consumer.id, Identifier::numeric(3).unwrap());
+        assert_eq!(command.strategy.kind, PollingKind::Offset);
+        assert_eq!(command.strategy.value, 10);
+        assert_eq!(command.count, 5);
+    }
+
+    #[test]
+    fn should_fail_to_parse_command_without_required_identifier() {
+        let result = parse(br#"{"command": "stream.get"}"#);
+
+        assert!(matches!(result, Err(IggyError::CannotParseJson(_))));
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_command() {
+        let result = parse(br#"{"command": "stream.unknown"}"#);
+
+        assert!(matches!(result, Err(IggyError::InvalidCommand)));
+    }
+
+    #[test]
+    fn should_map_empty_and_error_responses() {
+        let response = map_response(PING, Ok(Bytes::new()));
+        assert_eq!(response, b"{\"status\":0}\n");
+
+        let response = map_error_response(IggyError::StreamIdNotFound(1));
+        let response: Value = serde_json::from_slice(&response).unwrap();
+        assert_eq!(response["status"], IggyError::StreamIdNotFound(1).as_code());
+        assert_eq!(
+            response["error"],
+            IggyError::StreamIdNotFound(1).to_string()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::binary::command;
+use crate::binary::sender::Sender;
+use crate::server_error::ServerError;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::json_command;
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use iggy::error::IggyError;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+use tracing::{debug, error, info};
+
+/// Sent by the client as the first line instead of the binary request length to switch to the JSON protocol.
+pub(crate) const JSON_PROTOCOL_PREAMBLE: &[u8; 4] = b"JSON";
+const MAX_LINE_LENGTH: usize = 10 * 1024 * 1024;
+
+/// Handles the connection using the newline-delimited JSON protocol, each line is a single command
+/// and each response is a single line with the `status` and either the `data` or the `error` field.
+pub(crate) async fn handle_connection(
+    session: Arc<Session>,
+    sender: &mut dyn Sender,
+    system: SharedSystem,
+) -> Result<(), ServerError> {
+    // The rest of the preamble line (if any, e.g. the carriage return) is skipped.
+    read_line(sender).await?;
+    loop {
+        let line = tokio::select! {
+            line = read_line(sender) => line?,
+            _ = session.terminated() => {
+                info!("Session: {session} has been terminated, closing the connection.");
+                return Err(ServerError::from(IggyError::StaleClient));
+            }
+        };
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            continue;
+        }
+
+        let json_command = match json_command::parse(line) {
+            Ok(json_command) => json_command,
+            Err(error) => {
+                error!("Received an invalid JSON command: {error}");
+                sender
+                    .write(&json_command::map_error_response(error))
+                    .await?;
+                continue;
+            }
+        };
+
+        if let Err(error) = json_command.command.validate() {
+            error!("Command validation failed: {error}");
+            sender
+                .write(&json_command::map_error_response(error))
+                .await?;
+            continue;
+        }
+
+        debug!("Received a JSON command: {}", json_command.command);
+        let mut response = ResponseCapture::default();
+        let result = command::handle(
+            json_command.command,
+            &mut response,
+            &session,
+            system.clone(),
+        )
+        .await;
+        let response = response.response.unwrap_or(Ok(Bytes::new()));
+        sender
+            .write(&json_command::map_response(&json_command.name, response))
+            .await?;
+        result?;
+    }
+}
+
+async fn read_line(sender: &mut dyn Sender) -> Result<Vec<u8>, IggyError> {
+    let mut line = Vec::new();
+    let mut byte = [0u8; 1];
+    loop {
+        sender.read(&mut byte).await?;
+        if byte[0] == b'\n' {
+            return Ok(line);
+        }
+
+        if line.len() == MAX_LINE_LENGTH {
+            return Err(IggyError::CommandLengthError(format!(
+                "JSON command exceeds the maximum length of {MAX_LINE_LENGTH} bytes."
+            )));
+        }
+
+        line.push(byte[0]);
+    }
+}
+
+/// Captures the binary response of the command handler, so it can be mapped to JSON.
+#[derive(Debug, Default)]
+struct ResponseCapture {
+    response: Option<Result<Bytes, IggyError>>,
+    chunks: BytesMut,
+}
+
+#[async_trait]
+impl Sender for ResponseCapture {
+    async fn read(&mut self, _buffer: &mut [u8]) -> Result<usize, IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn write(&mut self, _payload: &[u8]) -> Result<(), IggyError> {
+        Err(IggyError::FeatureUnavailable)
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        self.response = Some(Ok(Bytes::new()));
+        Ok(())
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        self.response = Some(Ok(Bytes::copy_from_slice(payload)));
+        Ok(())
+    }
+
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError> {
+        self.chunks.clear();
+        Ok(())
+    }
+
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError> {
+        if chunk.is_empty() {
+            self.response = Some(Ok(self.chunks.split().freeze()));
+            return Ok(());
+        }
+
+        self.chunks.extend_from_slice(chunk);
+        Ok(())
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        self.response = Some(Err(error));
+        Ok(())
+    }
+}
@@ -1,6 +1,11 @@
+mod certificate_identity;
 pub mod connection_handler;
+mod json_command;
+mod json_connection_handler;
 pub mod sender;
 pub mod tcp_listener;
+pub mod tcp_mtls_listener;
+pub mod tcp_mtls_sender;
 mod tcp_sender;
 pub mod tcp_server;
 mod tcp_socket;
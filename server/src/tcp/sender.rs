@@ -1,4 +1,5 @@
 use bytes::{BufMut, BytesMut};
+use iggy::binary::CHUNKED_RESPONSE_LENGTH;
 use iggy::error::IggyError;
 use std::mem::size_of;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
@@ -23,6 +24,14 @@ where
     }
 }
 
+pub(crate) async fn write<T>(stream: &mut T, payload: &[u8]) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    stream.write_all(payload).await?;
+    Ok(())
+}
+
 pub(crate) async fn send_empty_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
 where
     T: AsyncRead + AsyncWrite + Unpin,
@@ -37,6 +46,29 @@ where
     send_response(stream, STATUS_OK, payload).await
 }
 
+pub(crate) async fn send_chunked_ok_response<T>(stream: &mut T) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    debug!("Sending chunked response with status: {:?}...", STATUS_OK);
+    stream
+        .write_all(&[STATUS_OK, &CHUNKED_RESPONSE_LENGTH.to_le_bytes()].concat())
+        .await?;
+    Ok(())
+}
+
+pub(crate) async fn send_response_chunk<T>(stream: &mut T, chunk: &[u8]) -> Result<(), IggyError>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    let length = (chunk.len() as u32).to_le_bytes();
+    stream.write_all(&[&length, chunk].concat()).await?;
+    if chunk.is_empty() {
+        debug!("Sent chunked response with status: {:?}", STATUS_OK);
+    }
+    Ok(())
+}
+
 pub(crate) async fn send_error_response<T>(
     stream: &mut T,
     error: IggyError,
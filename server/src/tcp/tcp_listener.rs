@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
@@ -6,9 +7,15 @@ use std::net::SocketAddr;
 use tokio::io::AsyncWriteExt;
 use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> SocketAddr {
+pub async fn start(
+    address: &str,
+    json_protocol: bool,
+    ip_filter: IpFilterConfig,
+    socket: TcpSocket,
+    system: SharedSystem,
+) -> SocketAddr {
     let address = address.to_string();
     let (tx, rx) = oneshot::channel();
     tokio::spawn(async move {
@@ -37,6 +44,11 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
+                    if !ip_filter.is_allowed(&address.ip()) {
+                        warn!("Rejected TCP connection from not allowed IP address: {address}.");
+                        continue;
+                    }
+
                     info!("Accepted new TCP connection: {address}");
                     let session = system
                         .read()
@@ -50,7 +62,8 @@ pub async fn start(address: &str, socket: TcpSocket, system: SharedSystem) -> So
                     let mut sender = TcpSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
+                            handle_connection(session, &mut sender, system.clone(), json_protocol)
+                                .await
                         {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
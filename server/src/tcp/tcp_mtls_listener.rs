@@ -0,0 +1,185 @@
+use crate::configs::ip_filter::IpFilterConfig;
+use crate::configs::tcp::{CertificateUserMapping, TcpTlsClientAuthConfig};
+use crate::streaming::clients::client_manager::Transport;
+use crate::streaming::session::Session;
+use crate::streaming::systems::system::SharedSystem;
+use crate::tcp::certificate_identity;
+use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::tcp_mtls_sender::TcpMtlsSender;
+use rustls::pki_types::CertificateDer;
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpSocket;
+use tokio::sync::oneshot;
+use tokio_rustls::TlsAcceptor;
+use tracing::{error, info, warn};
+
+/// Starts the TCP TLS listener requiring the clients to present the certificate signed by the trusted CA.
+/// The client whose certificate identity is mapped to the user gets authenticated as that user right after the handshake.
+pub(crate) async fn start(
+    address: &str,
+    config: TcpTlsClientAuthConfig,
+    json_protocol: bool,
+    ip_filter: IpFilterConfig,
+    socket: TcpSocket,
+    system: SharedSystem,
+) -> SocketAddr {
+    let address = address.to_string();
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(async move {
+        let server_config = configure_tls(&config)
+            .unwrap_or_else(|error| panic!("Unable to configure TCP mutual TLS. {error}"));
+        let acceptor = TlsAcceptor::from(Arc::new(server_config));
+        let users = Arc::new(config.users);
+
+        let addr = address.parse();
+        if addr.is_err() {
+            panic!("Unable to parse address {:?}", address);
+        }
+
+        socket
+            .bind(addr.unwrap())
+            .expect("Unable to bind socket to address");
+
+        let listener = socket
+            .listen(1024)
+            .expect("Unable to start TCP mutual TLS server.");
+
+        let local_addr = listener
+            .local_addr()
+            .expect("Failed to get local address for TCP mutual TLS listener");
+
+        tx.send(local_addr).unwrap_or_else(|_| {
+            panic!(
+                "Failed to send the local address {:?} for TCP mutual TLS listener",
+                local_addr
+            )
+        });
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    if !ip_filter.is_allowed(&address.ip()) {
+                        warn!("Rejected TCP mutual TLS connection from not allowed IP address: {address}.");
+                        continue;
+                    }
+
+                    info!("Accepted new TCP mutual TLS connection: {}", address);
+                    let acceptor = acceptor.clone();
+                    let system = system.clone();
+                    let users = users.clone();
+                    tokio::spawn(async move {
+                        let stream = match acceptor.accept(stream).await {
+                            Ok(stream) => stream,
+                            Err(error) => {
+                                error!("Failed to complete TLS handshake with TCP client, address: {address}. {error}");
+                                return;
+                            }
+                        };
+
+                        let session = system
+                            .read()
+                            .await
+                            .add_client(&address, Transport::Tcp)
+                            .await;
+                        let identities = stream
+                            .get_ref()
+                            .1
+                            .peer_certificates()
+                            .and_then(|certificates| certificates.first())
+                            .map(|certificate| certificate_identity::get_identities(certificate))
+                            .unwrap_or_default();
+                        authenticate(&system, &session, &identities, &users).await;
+
+                        let client_id = session.client_id;
+                        let mut sender = TcpMtlsSender { stream };
+                        if let Err(error) =
+                            handle_connection(session, &mut sender, system.clone(), json_protocol)
+                                .await
+                        {
+                            handle_error(error);
+                            system.read().await.delete_client(client_id).await;
+                            if let Err(error) = sender.stream.shutdown().await {
+                                error!("Failed to shutdown TCP stream for client: {client_id}, address: {address}. {error}");
+                            } else {
+                                info!("Successfully closed TCP stream for client: {client_id}, address: {address}.");
+                            }
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept TCP mutual TLS socket. {error}"),
+            }
+        }
+    });
+    match rx.await {
+        Ok(addr) => addr,
+        Err(_) => panic!("Failed to get the local address for TCP mutual TLS listener."),
+    }
+}
+
+async fn authenticate(
+    system: &SharedSystem,
+    session: &Session,
+    identities: &[String],
+    users: &[CertificateUserMapping],
+) {
+    let Some(username) = certificate_identity::map_username(identities, users) else {
+        info!(
+            "Client certificate with identities: {identities:?} is not mapped to any user, client with ID: {} has to log in with the credentials.",
+            session.client_id
+        );
+        return;
+    };
+
+    match system
+        .read()
+        .await
+        .login_user_with_credentials(username, None, Some(session))
+        .await
+    {
+        Ok(user) => info!(
+            "Client with ID: {} was authenticated with the certificate as user: {} with ID: {}.",
+            session.client_id, user.username, user.id
+        ),
+        Err(error) => warn!(
+            "Failed to authenticate client with ID: {} with the certificate as user: {username}. {error}",
+            session.client_id
+        ),
+    }
+}
+
+fn configure_tls(config: &TcpTlsClientAuthConfig) -> Result<ServerConfig, Box<dyn Error>> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let certificates = load_certificates(&config.cert_file)?;
+    let mut key_reader = BufReader::new(File::open(&config.key_file)?);
+    let key = rustls_pemfile::private_key(&mut key_reader)?
+        .ok_or_else(|| format!("No private key found in file: {}", config.key_file))?;
+
+    let mut roots = RootCertStore::empty();
+    for certificate in load_certificates(&config.ca_file)? {
+        roots.add(certificate)?;
+    }
+
+    let verifier =
+        WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider.clone()).build()?;
+    let server_config = ServerConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .with_client_cert_verifier(verifier)
+        .with_single_cert(certificates, key)?;
+    Ok(server_config)
+}
+
+fn load_certificates(file: &str) -> Result<Vec<CertificateDer<'static>>, Box<dyn Error>> {
+    let mut reader = BufReader::new(File::open(file)?);
+    let certificates = rustls_pemfile::certs(&mut reader).collect::<Result<Vec<_>, _>>()?;
+    if certificates.is_empty() {
+        return Err(format!("No certificates found in file: {file}").into());
+    }
+    Ok(certificates)
+}
@@ -0,0 +1,42 @@
+use crate::binary::sender::Sender;
+use crate::tcp::sender;
+use async_trait::async_trait;
+use iggy::error::IggyError;
+use tokio::net::TcpStream;
+use tokio_rustls::server::TlsStream;
+
+#[derive(Debug)]
+pub struct TcpMtlsSender {
+    pub(crate) stream: TlsStream<TcpStream>,
+}
+
+#[async_trait]
+impl Sender for TcpMtlsSender {
+    async fn read(&mut self, buffer: &mut [u8]) -> Result<usize, IggyError> {
+        sender::read(&mut self.stream, buffer).await
+    }
+
+    async fn write(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        sender::write(&mut self.stream, payload).await
+    }
+
+    async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
+        sender::send_empty_ok_response(&mut self.stream).await
+    }
+
+    async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        sender::send_ok_response(&mut self.stream, payload).await
+    }
+
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError> {
+        sender::send_chunked_ok_response(&mut self.stream).await
+    }
+
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError> {
+        sender::send_response_chunk(&mut self.stream, chunk).await
+    }
+
+    async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
+        sender::send_error_response(&mut self.stream, error).await
+    }
+}
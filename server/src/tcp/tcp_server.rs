@@ -1,13 +1,15 @@
 use crate::configs::tcp::TcpConfig;
 use crate::streaming::systems::system::SharedSystem;
-use crate::tcp::{tcp_listener, tcp_socket, tcp_tls_listener};
+use crate::tcp::{tcp_listener, tcp_mtls_listener, tcp_socket, tcp_tls_listener};
 use std::net::SocketAddr;
 use tracing::info;
 
 /// Starts the TCP server.
 /// Returns the address the server is listening on.
 pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
-    let server_name = if config.tls.enabled {
+    let server_name = if config.tls.enabled && config.tls.client_auth.enabled {
+        "Iggy TCP mutual TLS"
+    } else if config.tls.enabled {
         "Iggy TCP TLS"
     } else {
         "Iggy TCP"
@@ -15,8 +17,38 @@ pub async fn start(config: TcpConfig, system: SharedSystem) -> SocketAddr {
     info!("Initializing {server_name} server...");
     let socket = tcp_socket::build(config.ipv6, config.socket);
     let addr = match config.tls.enabled {
-        true => tcp_tls_listener::start(&config.address, config.tls, socket, system).await,
-        false => tcp_listener::start(&config.address, socket, system).await,
+        true if config.tls.client_auth.enabled => {
+            tcp_mtls_listener::start(
+                &config.address,
+                config.tls.client_auth,
+                config.json_protocol,
+                config.ip_filter,
+                socket,
+                system,
+            )
+            .await
+        }
+        true => {
+            tcp_tls_listener::start(
+                &config.address,
+                config.tls,
+                config.json_protocol,
+                config.ip_filter,
+                socket,
+                system,
+            )
+            .await
+        }
+        false => {
+            tcp_listener::start(
+                &config.address,
+                config.json_protocol,
+                config.ip_filter,
+                socket,
+                system,
+            )
+            .await
+        }
     };
     info!("{server_name} server has started on: {:?}", addr);
     addr
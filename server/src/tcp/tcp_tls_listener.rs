@@ -1,3 +1,4 @@
+use crate::configs::ip_filter::IpFilterConfig;
 use crate::configs::tcp::TcpTlsConfig;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::SharedSystem;
@@ -9,11 +10,13 @@ use tokio::net::TcpSocket;
 use tokio::sync::oneshot;
 use tokio_native_tls::native_tls;
 use tokio_native_tls::native_tls::Identity;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 pub(crate) async fn start(
     address: &str,
     config: TcpTlsConfig,
+    json_protocol: bool,
+    ip_filter: IpFilterConfig,
     socket: TcpSocket,
     system: SharedSystem,
 ) -> SocketAddr {
@@ -63,6 +66,13 @@ pub(crate) async fn start(
         loop {
             match listener.accept().await {
                 Ok((stream, address)) => {
+                    if !ip_filter.is_allowed(&address.ip()) {
+                        warn!(
+                            "Rejected TCP TLS connection from not allowed IP address: {address}."
+                        );
+                        continue;
+                    }
+
                     info!("Accepted new TCP TLS connection: {}", address);
                     let session = system
                         .read()
@@ -77,7 +87,8 @@ pub(crate) async fn start(
                     let mut sender = TcpTlsSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(session, &mut sender, system.clone()).await
+                            handle_connection(session, &mut sender, system.clone(), json_protocol)
+                                .await
                         {
                             handle_error(error);
                             system.read().await.delete_client(client_id).await;
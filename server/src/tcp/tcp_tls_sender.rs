@@ -19,6 +19,10 @@ impl Sender for TcpTlsSender {
         sender::read(&mut self.stream, buffer).await
     }
 
+    async fn write(&mut self, payload: &[u8]) -> Result<(), IggyError> {
+        sender::write(&mut self.stream, payload).await
+    }
+
     async fn send_empty_ok_response(&mut self) -> Result<(), IggyError> {
         sender::send_empty_ok_response(&mut self.stream).await
     }
@@ -27,6 +31,14 @@ impl Sender for TcpTlsSender {
         sender::send_ok_response(&mut self.stream, payload).await
     }
 
+    async fn send_chunked_ok_response(&mut self) -> Result<(), IggyError> {
+        sender::send_chunked_ok_response(&mut self.stream).await
+    }
+
+    async fn send_response_chunk(&mut self, chunk: &[u8]) -> Result<(), IggyError> {
+        sender::send_response_chunk(&mut self.stream, chunk).await
+    }
+
     async fn send_error_response(&mut self, error: IggyError) -> Result<(), IggyError> {
         sender::send_error_response(&mut self.stream, error).await
     }
@@ -0,0 +1,256 @@
+use crate::configs::server::WebhooksConfig;
+use crate::server_error::ServerError;
+use iggy::models::system_event::SystemEvent;
+use iggy::utils::timestamp::IggyTimestamp;
+use reqwest::{Client, StatusCode};
+use ring::hmac;
+use std::fmt::Write;
+use tokio::time::sleep;
+use tracing::{debug, error, warn};
+
+pub const EVENT_HEADER: &str = "X-Iggy-Event";
+pub const TIMESTAMP_HEADER: &str = "X-Iggy-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-Iggy-Signature";
+
+/// `WebhookDispatcher` sends the JSON notifications about the selected system events to the configured URLs.
+#[derive(Debug)]
+pub struct WebhookDispatcher {
+    client: Client,
+    config: WebhooksConfig,
+}
+
+impl WebhookDispatcher {
+    pub fn new(config: WebhooksConfig) -> Result<Self, ServerError> {
+        let client = Client::builder()
+            .timeout(config.timeout.get_duration())
+            .build()
+            .map_err(|error| ServerError::CannotInitializeWebhooks(error.to_string()))?;
+        Ok(Self { client, config })
+    }
+
+    pub fn is_subscribed(&self, event: &SystemEvent) -> bool {
+        self.config.events.iter().any(|name| name == event.name())
+    }
+
+    /// Sends the notification to all the URLs, retrying the failed requests, if the event is selected.
+    pub async fn dispatch(&self, event: &SystemEvent) {
+        if !self.is_subscribed(event) {
+            return;
+        }
+
+        let body = match serde_json::to_string(event) {
+            Ok(body) => body,
+            Err(error) => {
+                error!("Failed to serialize webhook event: {event:?}. Error: {error}");
+                return;
+            }
+        };
+
+        let timestamp = IggyTimestamp::now().as_micros();
+        for url in &self.config.urls {
+            if let Err(error) = self.deliver(url, event.name(), timestamp, &body).await {
+                error!("{error}");
+            }
+        }
+    }
+
+    async fn deliver(
+        &self,
+        url: &str,
+        event_name: &str,
+        timestamp: u64,
+        body: &str,
+    ) -> Result<(), ServerError> {
+        let signature = if self.config.secret.is_empty() {
+            None
+        } else {
+            Some(sign(&self.config.secret, timestamp, body))
+        };
+
+        let mut retry_interval = self.config.retry_interval.get_duration();
+        let mut attempt = 0;
+        loop {
+            let mut request = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(EVENT_HEADER, event_name)
+                .header(TIMESTAMP_HEADER, timestamp)
+                .body(body.to_owned());
+            if let Some(signature) = &signature {
+                request = request.header(SIGNATURE_HEADER, signature);
+            }
+
+            let (reason, retryable) = match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    debug!("Delivered webhook event: {event_name} to: {url}.");
+                    return Ok(());
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable =
+                        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+                    (format!("status: {status}"), retryable)
+                }
+                Err(error) => (error.to_string(), true),
+            };
+
+            if !retryable || attempt >= self.config.max_retries {
+                return Err(ServerError::CannotDeliverWebhook(
+                    url.to_owned(),
+                    format!("event: {event_name}, attempts: {}, {reason}", attempt + 1),
+                ));
+            }
+
+            attempt += 1;
+            warn!(
+                "Failed to deliver webhook event: {event_name} to: {url}, {reason}, retrying in: {retry_interval:?} ({attempt}/{})...",
+                self.config.max_retries
+            );
+            sleep(retry_interval).await;
+            retry_interval *= 2;
+        }
+    }
+}
+
+/// Returns the signature of the notification as `sha256=` followed by the hex-encoded HMAC-SHA256 of `{timestamp}.{body}`.
+pub fn sign(secret: &str, timestamp: u64, body: &str) -> String {
+    let payload = format!("{timestamp}.{body}");
+    format!("sha256={}", hmac_sha256_hex(secret, payload.as_bytes()))
+}
+
+fn hmac_sha256_hex(secret: &str, data: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, data);
+    tag.as_ref()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::server::ConsumerLagConfig;
+    use axum::extract::State;
+    use axum::http::HeaderMap;
+    use axum::routing::post;
+    use axum::Router;
+    use iggy::utils::duration::IggyDuration;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+
+    #[derive(Default)]
+    struct Received {
+        attempts: u32,
+        headers: Option<HeaderMap>,
+        body: Option<String>,
+    }
+
+    #[test]
+    fn hmac_should_match_known_test_vector() {
+        // RFC 4231, test case 2.
+        let hex = hmac_sha256_hex("Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn signature_should_cover_timestamp_and_body() {
+        let signature = sign("secret", 1, "{}");
+        assert_eq!(
+            signature,
+            format!("sha256={}", hmac_sha256_hex("secret", b"1.{}"))
+        );
+        assert_ne!(signature, sign("secret", 2, "{}"));
+        assert_ne!(signature, sign("other", 1, "{}"));
+    }
+
+    #[test]
+    fn only_selected_events_should_be_subscribed() {
+        let dispatcher = WebhookDispatcher::new(config(vec![])).unwrap();
+        assert!(dispatcher.is_subscribed(&topic_created()));
+        assert!(!dispatcher.is_subscribed(&SystemEvent::StreamCreated {
+            stream_id: 1,
+            name: "stream".to_string(),
+        }));
+    }
+
+    #[tokio::test]
+    async fn failed_notification_should_be_retried_and_signed() {
+        let received = Arc::new(Mutex::new(Received::default()));
+        let router = Router::new()
+            .route(
+                "/webhooks",
+                post(
+                    |State(received): State<Arc<Mutex<Received>>>,
+                     headers: HeaderMap,
+                     body: String| async move {
+                        let mut received = received.lock().unwrap();
+                        received.attempts += 1;
+                        if received.attempts == 1 {
+                            return StatusCode::SERVICE_UNAVAILABLE;
+                        }
+                        received.headers = Some(headers);
+                        received.body = Some(body);
+                        StatusCode::NO_CONTENT
+                    },
+                ),
+            )
+            .with_state(received.clone());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let dispatcher =
+            WebhookDispatcher::new(config(vec![format!("http://{address}/webhooks")])).unwrap();
+        let event = topic_created();
+        dispatcher.dispatch(&event).await;
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.attempts, 2);
+        let headers = received.headers.as_ref().unwrap();
+        let body = received.body.as_ref().unwrap();
+        let timestamp = headers[TIMESTAMP_HEADER]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        assert_eq!(headers[EVENT_HEADER], "topic_created");
+        assert_eq!(
+            headers[SIGNATURE_HEADER].to_str().unwrap(),
+            sign("secret", timestamp, body)
+        );
+        assert_eq!(serde_json::from_str::<SystemEvent>(body).unwrap(), event);
+    }
+
+    fn topic_created() -> SystemEvent {
+        SystemEvent::TopicCreated {
+            stream_id: 1,
+            topic_id: 1,
+            name: "topic".to_string(),
+            partitions_count: 1,
+        }
+    }
+
+    fn config(urls: Vec<String>) -> WebhooksConfig {
+        WebhooksConfig {
+            enabled: true,
+            urls,
+            events: vec!["topic_created".to_string()],
+            secret: "secret".to_string(),
+            timeout: IggyDuration::from_str("1 s").unwrap(),
+            max_retries: 2,
+            retry_interval: IggyDuration::from_str("10 ms").unwrap(),
+            consumer_lag: ConsumerLagConfig {
+                threshold: 0,
+                interval: IggyDuration::from_str("1 s").unwrap(),
+            },
+        }
+    }
+}
@@ -0,0 +1,69 @@
+mod runner;
+mod script;
+
+use crate::runner::Transport;
+use crate::script::Script;
+use anyhow::Result;
+use clap::Parser;
+use std::process::ExitCode;
+use tracing::{error, info};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry};
+
+/// The script bundled with the binary, used when no other script is provided.
+const DEFAULT_SCRIPT: &str = include_str!("scripts/default.toml");
+const DEFAULT_TCP_SERVER_ADDRESS: &str = "127.0.0.1:8090";
+const DEFAULT_QUIC_SERVER_ADDRESS: &str = "127.0.0.1:8080";
+
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about = "Runs the scripted sequence of raw commands against the server and validates the byte-exact responses.", long_about = None)]
+pub struct ConformanceArgs {
+    /// The binary transport to use: tcp or quic
+    #[arg(long, default_value = "tcp")]
+    pub transport: String,
+
+    /// The address of the server, the default one of the selected transport is used if not provided
+    #[arg(long)]
+    pub server_address: Option<String>,
+
+    /// The path to the TOML script, the bundled one is used if not provided
+    #[arg(long)]
+    pub script: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<ExitCode> {
+    let args = ConformanceArgs::parse();
+    Registry::default()
+        .with(tracing_subscriber::fmt::layer())
+        .with(EnvFilter::try_from_default_env().unwrap_or(EnvFilter::new("INFO")))
+        .init();
+
+    let script = match &args.script {
+        Some(path) => Script::parse(&std::fs::read_to_string(path)?)?,
+        None => Script::parse(DEFAULT_SCRIPT)?,
+    };
+    let server_address = args.server_address.unwrap_or_else(|| {
+        match args.transport.as_str() {
+            "quic" => DEFAULT_QUIC_SERVER_ADDRESS,
+            _ => DEFAULT_TCP_SERVER_ADDRESS,
+        }
+        .to_string()
+    });
+    info!(
+        "Selected transport: {}, server address: {server_address}",
+        args.transport
+    );
+    let transport = Transport::create(&args.transport, &server_address)?;
+    let failures = runner::run(&transport, &script).await?;
+    if failures > 0 {
+        error!(
+            "The server does not conform to the script: {}.",
+            script.name
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
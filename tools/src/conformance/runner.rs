@@ -0,0 +1,112 @@
+use crate::script::{self, ExpectedBytes, Script, Step};
+use anyhow::{anyhow, Result};
+use bytes::Bytes;
+use iggy::binary::BinaryTransport;
+use iggy::client::Client;
+use iggy::error::IggyError;
+use iggy::quic::client::QuicClient;
+use iggy::quic::config::{QuicClientConfig, QuicClientReconnectionConfig};
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::{TcpClientConfig, TcpClientReconnectionConfig};
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// The binary transport, over which the raw commands are sent as they are, without any reconnection or retries.
+pub enum Transport {
+    Tcp(TcpClient),
+    Quic(QuicClient),
+}
+
+impl Transport {
+    pub fn create(transport: &str, server_address: &str) -> Result<Self> {
+        match transport {
+            "tcp" => Ok(Transport::Tcp(TcpClient::create(Arc::new(
+                TcpClientConfig {
+                    server_address: server_address.to_string(),
+                    reconnection: TcpClientReconnectionConfig {
+                        enabled: false,
+                        ..TcpClientReconnectionConfig::default()
+                    },
+                    ..TcpClientConfig::default()
+                },
+            ))?)),
+            "quic" => Ok(Transport::Quic(QuicClient::create(Arc::new(
+                QuicClientConfig {
+                    server_address: server_address.to_string(),
+                    reconnection: QuicClientReconnectionConfig {
+                        enabled: false,
+                        ..QuicClientReconnectionConfig::default()
+                    },
+                    ..QuicClientConfig::default()
+                },
+            ))?)),
+            _ => Err(anyhow!("Unsupported transport: {transport}")),
+        }
+    }
+
+    async fn connect(&self) -> Result<(), IggyError> {
+        match self {
+            Transport::Tcp(client) => client.connect().await,
+            Transport::Quic(client) => client.connect().await,
+        }
+    }
+
+    /// Sends the raw command and returns the status and payload of the response.
+    async fn send(&self, code: u32, payload: Bytes) -> Result<(u32, Bytes), IggyError> {
+        let response = match self {
+            Transport::Tcp(client) => client.send_raw_with_response(code, payload).await,
+            Transport::Quic(client) => client.send_raw_with_response(code, payload).await,
+        };
+        match response {
+            Ok(response) => Ok((0, response)),
+            Err(IggyError::InvalidResponse(status, _, _)) => Ok((status, Bytes::new())),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Runs all the steps of the script and returns the number of the failed ones.
+pub async fn run(transport: &Transport, script: &Script) -> Result<usize> {
+    transport.connect().await?;
+    info!("Running conformance script: {}...", script.name);
+    let mut failures = 0;
+    for (index, step) in script.steps.iter().enumerate() {
+        match run_step(transport, step).await {
+            Ok(()) => info!("[{}] {}: OK", index + 1, step.name),
+            Err(error) => {
+                failures += 1;
+                error!("[{}] {}: FAILED, {error}", index + 1, step.name);
+            }
+        }
+    }
+    info!(
+        "Conformance script: {} has finished, passed: {}, failed: {failures}.",
+        script.name,
+        script.steps.len() - failures
+    );
+    Ok(failures)
+}
+
+async fn run_step(transport: &Transport, step: &Step) -> Result<()> {
+    let payload = script::decode(&step.payload)?;
+    let (status, response) = transport.send(step.code, Bytes::from(payload)).await?;
+    if status != step.status {
+        return Err(anyhow!(
+            "invalid status: {status}, expected: {}",
+            step.status
+        ));
+    }
+
+    let Some(expected_response) = &step.response else {
+        return Ok(());
+    };
+
+    if let Some(offset) = ExpectedBytes::parse(expected_response)?.mismatch(&response) {
+        return Err(anyhow!(
+            "invalid response at byte: {offset}, received: [{}], expected: [{expected_response}]",
+            script::encode(&response)
+        ));
+    }
+
+    Ok(())
+}
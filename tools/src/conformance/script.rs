@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+
+/// The wildcard matching any byte of the expected response, e.g. the timestamp assigned by the server.
+const ANY_BYTE: &str = "??";
+
+/// The scripted sequence of commands, sent in order over the single connection.
+#[derive(Debug, Deserialize)]
+pub struct Script {
+    pub name: String,
+    #[serde(rename = "step")]
+    pub steps: Vec<Step>,
+}
+
+/// The raw command with its expected response.
+/// The payloads are encoded as hex strings, with the optional whitespace between the bytes.
+#[derive(Debug, Deserialize)]
+pub struct Step {
+    pub name: String,
+    pub code: u32,
+    #[serde(default)]
+    pub payload: String,
+    #[serde(default)]
+    pub status: u32,
+    /// The expected response payload, not verified if missing.
+    pub response: Option<String>,
+}
+
+/// The expected response payload, where `None` matches any byte.
+#[derive(Debug)]
+pub struct ExpectedBytes(Vec<Option<u8>>);
+
+impl Script {
+    pub fn parse(content: &str) -> Result<Self> {
+        let script: Script = toml::from_str(content)?;
+        for step in &script.steps {
+            decode(&step.payload)
+                .map_err(|error| anyhow!("Invalid payload of step: {}, {error}", step.name))?;
+            if let Some(response) = &step.response {
+                ExpectedBytes::parse(response)
+                    .map_err(|error| anyhow!("Invalid response of step: {}, {error}", step.name))?;
+            }
+        }
+        Ok(script)
+    }
+}
+
+impl ExpectedBytes {
+    pub fn parse(value: &str) -> Result<Self> {
+        hex_pairs(value)?
+            .into_iter()
+            .map(|pair| {
+                if pair == ANY_BYTE {
+                    return Ok(None);
+                }
+                decode_byte(&pair).map(Some)
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(ExpectedBytes)
+    }
+
+    /// Returns the offset of the first mismatched byte, or the shorter length if the lengths differ.
+    pub fn mismatch(&self, actual: &[u8]) -> Option<usize> {
+        let position =
+            self.0.iter().zip(actual).position(|(expected, actual)| {
+                expected.is_some_and(|expected| expected != *actual)
+            });
+        if position.is_some() {
+            return position;
+        }
+
+        if self.0.len() != actual.len() {
+            return Some(self.0.len().min(actual.len()));
+        }
+
+        None
+    }
+}
+
+pub fn decode(value: &str) -> Result<Vec<u8>> {
+    hex_pairs(value)?
+        .iter()
+        .map(|pair| decode_byte(pair))
+        .collect()
+}
+
+pub fn encode(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn hex_pairs(value: &str) -> Result<Vec<String>> {
+    let digits = value
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .collect::<Vec<_>>();
+    if digits.len() % 2 != 0 {
+        return Err(anyhow!("odd number of hex digits"));
+    }
+
+    Ok(digits
+        .chunks(2)
+        .map(|pair| pair.iter().collect::<String>())
+        .collect())
+}
+
+fn decode_byte(pair: &str) -> Result<u8> {
+    u8::from_str_radix(pair, 16).map_err(|_| anyhow!("invalid hex byte: {pair}"))
+}
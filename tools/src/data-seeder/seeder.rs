@@ -3,7 +3,9 @@ use iggy::clients::client::IggyClient;
 use iggy::error::IggyError;
 use iggy::messages::send_messages::{Message, Partitioning};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::utils::cleanup_policy::CleanupPolicy;
 use iggy::utils::expiry::IggyExpiry;
+use iggy::utils::topic_overrides::TopicOverrides;
 use iggy::utils::topic_size::MaxTopicSize;
 use rand::Rng;
 use std::collections::HashMap;
@@ -41,6 +43,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await?;
 
@@ -54,6 +58,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await?;
 
@@ -67,6 +73,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await?;
 
@@ -80,6 +88,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await?;
 
@@ -93,6 +103,8 @@ async fn create_topics(client: &IggyClient) -> Result<(), IggyError> {
                 None,
                 IggyExpiry::NeverExpire,
                 MaxTopicSize::ServerDefault,
+                CleanupPolicy::Delete,
+                TopicOverrides::default(),
             )
             .await?;
     }